@@ -618,12 +618,8 @@ fn generate_enum(out: &mut String, def: &EnumDef, _fqn: &str) {
         // Generate match arms for string values
         for variant in &def.variants {
             let rust_name = &variant.name;
-            let serde_name =
-                if rust_name.chars().all(|c| c.is_uppercase() || c == '_') && rust_name.len() > 1 {
-                    rust_name.clone() // Keep as-is for HRTF, etc.
-                } else {
-                    rust_name.clone()
-                };
+            // Variant names match their serialized form (including all-caps ones like HRTF).
+            let serde_name = rust_name.clone();
             writeln!(
                 out,
                 "                    \"{}\" => Ok({}::{}),",
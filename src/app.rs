@@ -12,13 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use clap::{Parser, Subcommand};
+use std::collections::BTreeMap;
+
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
 use clap_complete::Shell;
 use rust_embed::RustEmbed;
 
 use crate::commands::{
-    asset::AssetCommands, project::ProjectCommands, sudo::SudoCommands, template::TemplateCommands,
+    asset::AssetCommands, examples::with_examples, introspect::IntrospectCommands,
+    project::ProjectCommands, stats::StatsCommands, sudo::SudoCommands, template::TemplateCommands,
 };
+use crate::common::settings::CONFIG_VERBOSITY;
+use crate::common::update::CONFIG_AUTO_UPDATE;
 
 #[derive(RustEmbed)]
 #[folder = "resources/"]
@@ -54,14 +59,70 @@ pub struct App {
     #[arg(long, global = true)]
     pub json: bool,
 
+    /// With --json, print only the value at this JSON Pointer of the result (e.g. /0/path)
+    #[arg(long, global = true, value_name = "POINTER", requires = "json")]
+    pub query: Option<String>,
+
     /// Disable interactive prompts (fail if input required)
     #[arg(long, global = true)]
     pub non_interactive: bool,
 
+    /// Don't take the project lock (only if no other command is modifying the project)
+    #[arg(long, global = true)]
+    pub no_lock: bool,
+
+    /// Work on this project instead of the one in the current directory: the name of a
+    /// registered project, or the path of its directory
+    #[arg(long, global = true, value_name = "NAME|PATH")]
+    pub project: Option<String>,
+
+    /// Run without the project registry (filesystem-only features, e.g. validate or info)
+    #[arg(long, global = true)]
+    pub no_db: bool,
+
+    /// Don't check whether a newer version of the CLI is available
+    #[arg(long, global = true)]
+    pub no_update_check: bool,
+
+    /// Follow symlinked directories when walking project files
+    #[arg(long, global = true)]
+    pub follow_symlinks: bool,
+
+    /// Maximum number of directory levels walked below asset and data directories (default: 32)
+    #[arg(long, global = true, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    pub max_depth: Option<u32>,
+
+    /// Export trace spans over OTLP even if no OTEL_* endpoint is set (needs the otel feature)
+    #[arg(long, global = true)]
+    pub trace: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
 
+impl App {
+    /// Parse the command line, showing the [examples](crate::commands::examples) of
+    /// each command in its help.
+    pub fn parse_with_examples() -> Self {
+        let matches = with_examples(Self::command()).get_matches();
+        Self::from_arg_matches(&matches).unwrap_or_else(|e| e.exit())
+    }
+
+    /// The [settings](crate::common::settings) set by the global flags, by key.
+    pub fn setting_flags(&self) -> BTreeMap<&'static str, String> {
+        let mut flags = BTreeMap::new();
+        if self.verbose {
+            flags.insert(CONFIG_VERBOSITY, "verbose".to_string());
+        } else if self.quiet {
+            flags.insert(CONFIG_VERBOSITY, "quiet".to_string());
+        }
+        if self.no_update_check {
+            flags.insert(CONFIG_AUTO_UPDATE, "false".to_string());
+        }
+        flags
+    }
+}
+
 #[derive(Subcommand, Debug)]
 pub enum Commands {
     /// Manage audio assets (sounds, collections, etc.)
@@ -94,12 +155,43 @@ pub enum Commands {
         command: TemplateCommands,
     },
 
+    /// Usage statistics computed from the local command history
+    Stats {
+        #[command(subcommand)]
+        command: StatsCommands,
+    },
+
+    /// Describe the CLI surface for external tools
+    Introspect {
+        #[command(subcommand)]
+        command: IntrospectCommands,
+    },
+
+    /// Show example command lines for a command
+    Examples {
+        /// The command to show examples for, e.g. `project init` (default: every command)
+        command: Vec<String>,
+
+        /// Print only the command line of the Nth example, for copy-paste
+        #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+        copy: Option<u32>,
+    },
+
+    /// Explain an error code: its causes, how to fix it and the exit code it maps to
+    Explain {
+        /// The error code, with or without its minus sign (e.g. -29002), or its type
+        /// (e.g. project_not_registered)
+        #[arg(allow_hyphen_values = true)]
+        code: String,
+    },
+
     /// Generate shell completion scripts
     ///
     /// Outputs a completion script for the specified shell.
     /// Pipe to a file or source directly for tab-completion support.
     ///
     /// Installation:
+    ///   am completions --install     (detects the shell, or pass it e.g. 'zsh --install')
     ///   bash:  am completions bash > ~/.local/share/bash-completion/completions/am
     ///   zsh:   am completions zsh > ~/.zfunc/_am
     ///   fish:  am completions fish > ~/.config/fish/completions/am.fish
@@ -107,7 +199,81 @@ pub enum Commands {
     /// Note: Asset names and project names require manual typing;
     /// completions cover commands, subcommands, and flags.
     Completions {
-        /// Shell to generate completions for (bash, zsh, fish)
-        shell: Shell,
+        /// Shell to generate completions for (bash, zsh, fish, powershell, elvish)
+        #[arg(required_unless_present = "install")]
+        shell: Option<Shell>,
+
+        /// Write the script where the shell loads completions from, backing up any
+        /// existing file (detects the shell if not given)
+        #[arg(long)]
+        install: bool,
+
+        /// Only show where --install would write the script
+        #[arg(long, requires = "install")]
+        dry_run: bool,
     },
 }
+
+impl Commands {
+    /// Whether this command writes to the files of the project in the current directory
+    /// and must hold the project lock.
+    pub fn mutates_project_files(&self) -> bool {
+        match self {
+            Commands::Asset { command } => command.mutates_project_files(),
+            Commands::Project { command } => command.mutates_project_files(),
+            Commands::Sudo { .. }
+            | Commands::Sdk { .. }
+            | Commands::Template { .. }
+            | Commands::Stats { .. }
+            | Commands::Introspect { .. }
+            | Commands::Examples { .. }
+            | Commands::Explain { .. }
+            | Commands::Completions { .. } => false,
+        }
+    }
+
+    /// Whether this command can't run without the database, and so rejects `--no-db`.
+    ///
+    /// Commands only reading project files run without it, skipping the features that
+    /// need the registry (registration status, registered project lookups).
+    pub fn requires_database(&self) -> bool {
+        match self {
+            Commands::Project { command } => command.requires_database(),
+            Commands::Sudo { command } => command.requires_database(),
+            Commands::Sdk { command } => command.requires_database(),
+            Commands::Template { .. } | Commands::Stats { .. } => true,
+            Commands::Asset { .. }
+            | Commands::Introspect { .. }
+            | Commands::Examples { .. }
+            | Commands::Explain { .. }
+            | Commands::Completions { .. } => false,
+        }
+    }
+
+    /// Whether this command works on a project, the one of the current directory unless
+    /// the global `--project` option gives another.
+    ///
+    /// `am stats` summarizes the history of every project, or only of the one given.
+    pub fn is_project_scoped(&self) -> bool {
+        match self {
+            Commands::Asset { .. } | Commands::Stats { .. } => true,
+            Commands::Project { command } => command.is_project_scoped(),
+            Commands::Sudo { .. }
+            | Commands::Sdk { .. }
+            | Commands::Template { .. }
+            | Commands::Introspect { .. }
+            | Commands::Examples { .. }
+            | Commands::Explain { .. }
+            | Commands::Completions { .. } => false,
+        }
+    }
+
+    /// Whether the arguments of this command already name its project, so that
+    /// `--project` would conflict with them.
+    pub fn names_project(&self) -> bool {
+        match self {
+            Commands::Project { command } => command.names_project(),
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,821 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Asset dependency graph.
+//!
+//! Assets reference each other either by ID or, for soundbanks, by file path:
+//!
+//! - sounds, collections and switch containers may use an effect (`effect`);
+//! - collections list the sounds they play (`sounds`);
+//! - switch containers are driven by a switch (`switch_group`) and play sounds or
+//!   collections (`entries[].object`);
+//! - events target sounds, collections or switch containers (`actions[].targets`);
+//! - soundbanks list the files of every asset they package.
+//!
+//! [`extract_references`] is the single place defining what counts as a reference.
+//! [`DependencyGraph`] resolves the references of every asset of a project into edges
+//! from the referencing asset (the dependent) to the referenced one (the dependency), and
+//! exports them with [`DependencyGraph::to_dot`] and [`DependencyGraph::to_mermaid`].
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use super::AssetType;
+use super::validator::runtime_path_to_source;
+use crate::common::files::parse_asset_json;
+use crate::common::ignore::{IgnoreRules, WalkOptions, walk_project_files, warn_skipped};
+use crate::common::utils::read_amproject_file;
+use crate::presentation::Output;
+
+/// Asset types an event action can target.
+const PLAYABLE_TYPES: &[AssetType] = &[
+    AssetType::Sound,
+    AssetType::Collection,
+    AssetType::SwitchContainer,
+];
+
+/// Asset types a switch container entry can play.
+const ENTRY_TYPES: &[AssetType] = &[AssetType::Sound, AssetType::Collection];
+
+/// Soundbank fields listing asset files, with the type of the listed assets.
+const SOUNDBANK_FIELDS: &[(&str, AssetType)] = &[
+    ("sounds", AssetType::Sound),
+    ("collections", AssetType::Collection),
+    ("events", AssetType::Event),
+    ("switches", AssetType::Switch),
+    ("switch_containers", AssetType::SwitchContainer),
+    ("effects", AssetType::Effect),
+];
+
+/// Identifies an asset of a project by its type and name.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AssetKey {
+    pub asset_type: AssetType,
+    pub name: String,
+}
+
+impl AssetKey {
+    pub fn new(asset_type: AssetType, name: impl Into<String>) -> Self {
+        Self {
+            asset_type,
+            name: name.into(),
+        }
+    }
+
+    /// The asset type as written on the command line and in JSON, e.g. `switch_container`.
+    pub fn type_key(&self) -> String {
+        serde_json::to_value(self.asset_type)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Display for AssetKey {
+    /// Formats the key as `<type>/<name>`, e.g. `switch_container/footsteps`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.type_key(), self.name)
+    }
+}
+
+impl Serialize for AssetKey {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// What a reference found in an asset file points to, before resolution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReferenceTarget {
+    /// The ID of an asset of one of `types`.
+    Id {
+        id: u64,
+        types: &'static [AssetType],
+    },
+    /// The file of a `asset_type` asset, as listed by a soundbank.
+    Path { asset_type: AssetType, path: String },
+}
+
+impl fmt::Display for ReferenceTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Id { id, .. } => write!(f, "ID {}", id),
+            Self::Path { path, .. } => write!(f, "'{}'", path),
+        }
+    }
+}
+
+/// A reference found in an asset file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reference {
+    /// JSON field holding the reference, e.g. `actions[0].targets[1]`.
+    pub field: String,
+    pub target: ReferenceTarget,
+}
+
+/// Extract the references an asset of type `asset_type` makes to other assets.
+///
+/// Works on the raw JSON so that assets failing full deserialization still contribute
+/// their references. Zero IDs mean "no reference" and are skipped.
+pub fn extract_references(asset_type: AssetType, asset: &Value) -> Vec<Reference> {
+    let mut references = Vec::new();
+    match asset_type {
+        AssetType::Sound => {
+            push_id_reference(
+                &mut references,
+                "effect".into(),
+                asset["effect"].as_u64(),
+                &[AssetType::Effect],
+            );
+        }
+        AssetType::Collection => {
+            push_id_reference(
+                &mut references,
+                "effect".into(),
+                asset["effect"].as_u64(),
+                &[AssetType::Effect],
+            );
+            for (idx, entry) in array(&asset["sounds"]).iter().enumerate() {
+                // Entries are either plain IDs or objects carrying the sound ID.
+                let id = entry
+                    .as_u64()
+                    .or_else(|| entry["sound"].as_u64())
+                    .or_else(|| entry["id"].as_u64());
+                push_id_reference(
+                    &mut references,
+                    format!("sounds[{}]", idx),
+                    id,
+                    &[AssetType::Sound],
+                );
+            }
+        }
+        AssetType::SwitchContainer => {
+            push_id_reference(
+                &mut references,
+                "effect".into(),
+                asset["effect"].as_u64(),
+                &[AssetType::Effect],
+            );
+            push_id_reference(
+                &mut references,
+                "switch_group".into(),
+                asset["switch_group"].as_u64(),
+                &[AssetType::Switch],
+            );
+            for (idx, entry) in array(&asset["entries"]).iter().enumerate() {
+                push_id_reference(
+                    &mut references,
+                    format!("entries[{}].object", idx),
+                    entry["object"].as_u64(),
+                    ENTRY_TYPES,
+                );
+            }
+        }
+        AssetType::Event => {
+            for (idx, action) in array(&asset["actions"]).iter().enumerate() {
+                for (target_idx, target) in array(&action["targets"]).iter().enumerate() {
+                    push_id_reference(
+                        &mut references,
+                        format!("actions[{}].targets[{}]", idx, target_idx),
+                        target.as_u64(),
+                        PLAYABLE_TYPES,
+                    );
+                }
+            }
+        }
+        AssetType::Soundbank => {
+            for &(field, target_type) in SOUNDBANK_FIELDS {
+                for (idx, path) in array(&asset[field]).iter().enumerate() {
+                    if let Some(path) = path.as_str() {
+                        references.push(Reference {
+                            field: format!("{}[{}]", field, idx),
+                            target: ReferenceTarget::Path {
+                                asset_type: target_type,
+                                path: path.to_string(),
+                            },
+                        });
+                    }
+                }
+            }
+        }
+        AssetType::Switch | AssetType::Effect => {}
+    }
+
+    references
+}
+
+fn push_id_reference(
+    references: &mut Vec<Reference>,
+    field: String,
+    id: Option<u64>,
+    types: &'static [AssetType],
+) {
+    if let Some(id) = id.filter(|id| *id != 0) {
+        references.push(Reference {
+            field,
+            target: ReferenceTarget::Id { id, types },
+        });
+    }
+}
+
+fn array(value: &Value) -> &[Value] {
+    value.as_array().map(Vec::as_slice).unwrap_or(&[])
+}
+
+/// An asset of the project.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AssetNode {
+    #[serde(rename = "type")]
+    pub asset_type: AssetType,
+    pub name: String,
+    pub id: Option<u64>,
+    /// Path of the asset file relative to the sources directory, with `/` separators.
+    pub path: String,
+}
+
+impl AssetNode {
+    pub fn key(&self) -> AssetKey {
+        AssetKey::new(self.asset_type, &self.name)
+    }
+}
+
+/// A resolved reference: `from` depends on `to` through `field`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Dependency {
+    pub from: AssetKey,
+    pub to: AssetKey,
+    pub field: String,
+}
+
+/// A reference that doesn't point to any asset of the project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnresolvedReference {
+    pub from: AssetKey,
+    pub reference: Reference,
+}
+
+/// Dependencies between the assets of a project.
+#[derive(Debug, Default)]
+pub struct DependencyGraph {
+    nodes: BTreeMap<AssetKey, AssetNode>,
+    edges: Vec<Dependency>,
+    unresolved: Vec<UnresolvedReference>,
+}
+
+impl DependencyGraph {
+    /// Scan the assets of the project at `project_root` and resolve their references.
+    ///
+    /// Honors `sources_dir`, `asset_dirs`, `allow_json_comments` and `.amignore`, and
+    /// walks the asset directories with `walk`. Files that can't be read or parsed, and
+    /// directories the walk skipped, are reported as warnings and left out of the graph.
+    pub fn build(
+        project_root: &Path,
+        walk: WalkOptions,
+        output: &dyn Output,
+    ) -> anyhow::Result<Self> {
+        let config = read_amproject_file(project_root)?;
+        let sources_dir = if config.sources_dir.is_empty() {
+            project_root.to_path_buf()
+        } else {
+            project_root.join(&config.sources_dir)
+        };
+        let ignore = IgnoreRules::load(project_root)?;
+
+        let mut assets = Vec::new();
+        for asset_type in AssetType::ALL {
+            let dir = sources_dir.join(config.asset_dir(asset_type));
+            let files = walk_project_files(&dir, &ignore, walk);
+            warn_skipped(&files.skipped, output);
+            for path in files.files {
+                if path.extension().is_none_or(|ext| ext != "json") {
+                    continue;
+                }
+
+                let parsed = fs::read_to_string(&path)
+                    .map_err(|e| e.to_string())
+                    .and_then(|content| {
+                        parse_asset_json::<Value>(&content, config.allow_json_comments)
+                            .map_err(|e| e.to_string())
+                    });
+                let value = match parsed {
+                    Ok(value) => value,
+                    Err(e) => {
+                        output.warning(&format!(
+                            "Skipping unreadable asset file {}: {}",
+                            path.display(),
+                            e
+                        ));
+                        continue;
+                    }
+                };
+
+                let relative = path.strip_prefix(&sources_dir).unwrap_or(&path);
+                let name = value["name"]
+                    .as_str()
+                    .map(str::to_string)
+                    .or_else(|| Some(path.file_stem()?.to_string_lossy().into_owned()))
+                    .unwrap_or_default();
+                let node = AssetNode {
+                    asset_type,
+                    name,
+                    id: value["id"].as_u64(),
+                    path: relative.to_string_lossy().replace('\\', "/"),
+                };
+                assets.push((node, value));
+            }
+        }
+
+        let dirs: HashMap<AssetType, String> = AssetType::ALL
+            .iter()
+            .map(|t| (*t, config.asset_dir(*t).to_string()))
+            .collect();
+        Ok(Self::from_assets(assets, &dirs))
+    }
+
+    /// Build the graph of already loaded assets. `dirs` maps each type to its directory
+    /// relative to the sources directory, to resolve the paths listed by soundbanks.
+    fn from_assets(assets: Vec<(AssetNode, Value)>, dirs: &HashMap<AssetType, String>) -> Self {
+        let mut by_id: HashMap<u64, Vec<AssetKey>> = HashMap::new();
+        let mut by_path: HashMap<&str, AssetKey> = HashMap::new();
+        for (node, _) in &assets {
+            if let Some(id) = node.id {
+                by_id.entry(id).or_default().push(node.key());
+            }
+            by_path.insert(&node.path, node.key());
+        }
+
+        let resolve = |target: &ReferenceTarget| -> Option<AssetKey> {
+            match target {
+                ReferenceTarget::Id { id, types } => by_id
+                    .get(id)?
+                    .iter()
+                    .find(|key| types.contains(&key.asset_type))
+                    .cloned(),
+                ReferenceTarget::Path { asset_type, path } => {
+                    // Soundbanks list files relative to the type directory or to the
+                    // sources directory, with source or runtime extensions.
+                    let dir = dirs.get(asset_type).map(String::as_str).unwrap_or_default();
+                    [path.clone(), format!("{}/{}", dir, path)]
+                        .iter()
+                        .flat_map(|p| [p.clone(), runtime_path_to_source(p)])
+                        .find_map(|p| by_path.get(p.as_str()))
+                        .filter(|key| key.asset_type == *asset_type)
+                        .cloned()
+                }
+            }
+        };
+
+        let mut edges = Vec::new();
+        let mut unresolved = Vec::new();
+        for (node, value) in &assets {
+            for reference in extract_references(node.asset_type, value) {
+                match resolve(&reference.target) {
+                    Some(to) => edges.push(Dependency {
+                        from: node.key(),
+                        to,
+                        field: reference.field,
+                    }),
+                    None => unresolved.push(UnresolvedReference {
+                        from: node.key(),
+                        reference,
+                    }),
+                }
+            }
+        }
+
+        let nodes = assets
+            .into_iter()
+            .map(|(node, _)| (node.key(), node))
+            .collect();
+
+        Self {
+            nodes,
+            edges,
+            unresolved,
+        }
+    }
+
+    /// The asset identified by `key`, if the project has it.
+    pub fn node(&self, key: &AssetKey) -> Option<&AssetNode> {
+        self.nodes.get(key)
+    }
+
+    /// Every asset of the project, sorted by type and name.
+    pub fn nodes(&self) -> impl Iterator<Item = &AssetNode> {
+        self.nodes.values()
+    }
+
+    /// Every resolved reference, in scan order.
+    pub fn edges(&self) -> &[Dependency] {
+        &self.edges
+    }
+
+    /// References pointing to no asset of the project.
+    pub fn unresolved(&self) -> &[UnresolvedReference] {
+        &self.unresolved
+    }
+
+    /// References made by other assets to the asset identified by `key`.
+    pub fn references_to(&self, key: &AssetKey) -> Vec<&Dependency> {
+        self.edges
+            .iter()
+            .filter(|e| &e.to == key && &e.from != key)
+            .collect()
+    }
+
+    /// Assets the asset identified by `key` references directly.
+    pub fn dependencies(&self, key: &AssetKey) -> BTreeSet<&AssetKey> {
+        self.edges
+            .iter()
+            .filter(|e| &e.from == key)
+            .map(|e| &e.to)
+            .collect()
+    }
+
+    /// Assets referencing the asset identified by `key` directly.
+    pub fn dependents(&self, key: &AssetKey) -> BTreeSet<&AssetKey> {
+        self.edges
+            .iter()
+            .filter(|e| &e.to == key)
+            .map(|e| &e.from)
+            .collect()
+    }
+
+    /// Adjacency lists of the dependencies of `key`: only its own entry, or with
+    /// `transitive` the entries of every asset it depends on, directly or not.
+    pub fn dependency_lists(
+        &self,
+        key: &AssetKey,
+        transitive: bool,
+    ) -> BTreeMap<&AssetKey, BTreeSet<&AssetKey>> {
+        self.adjacency(key, transitive, |k| self.dependencies(k))
+    }
+
+    /// Adjacency lists of the dependents of `key`, see [`Self::dependency_lists`].
+    pub fn dependent_lists(
+        &self,
+        key: &AssetKey,
+        transitive: bool,
+    ) -> BTreeMap<&AssetKey, BTreeSet<&AssetKey>> {
+        self.adjacency(key, transitive, |k| self.dependents(k))
+    }
+
+    fn adjacency<'a>(
+        &'a self,
+        key: &AssetKey,
+        transitive: bool,
+        neighbors: impl Fn(&AssetKey) -> BTreeSet<&'a AssetKey>,
+    ) -> BTreeMap<&'a AssetKey, BTreeSet<&'a AssetKey>> {
+        let mut lists = BTreeMap::new();
+        let Some((start, _)) = self.nodes.get_key_value(key) else {
+            return lists;
+        };
+
+        let mut queue = VecDeque::from([start]);
+        while let Some(current) = queue.pop_front() {
+            if lists.contains_key(current) {
+                continue;
+            }
+            let next = neighbors(current);
+            if transitive {
+                queue.extend(next.iter().copied());
+            }
+            lists.insert(current, next);
+        }
+
+        lists
+    }
+
+    /// The subgraph made of the asset identified by `key` and of the assets it references
+    /// or is referenced by directly. Empty if the project has no such asset.
+    pub fn neighborhood(&self, key: &AssetKey) -> Self {
+        if !self.nodes.contains_key(key) {
+            return Self::default();
+        }
+
+        let mut keys = self.dependencies(key);
+        keys.extend(self.dependents(key));
+        keys.insert(key);
+
+        let nodes = keys
+            .iter()
+            .filter_map(|k| self.nodes.get_key_value(*k))
+            .map(|(k, node)| (k.clone(), node.clone()))
+            .collect();
+        let edges = self
+            .edges
+            .iter()
+            .filter(|e| keys.contains(&e.from) && keys.contains(&e.to))
+            .cloned()
+            .collect();
+        let unresolved = self
+            .unresolved
+            .iter()
+            .filter(|u| keys.contains(&u.from))
+            .cloned()
+            .collect();
+
+        Self {
+            nodes,
+            edges,
+            unresolved,
+        }
+    }
+
+    /// Render the graph in Graphviz DOT format.
+    ///
+    /// Each asset type has its own node shape, and references to missing assets point to
+    /// dashed red nodes. The output only depends on the graph, not on scan order.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph assets {\n");
+        dot.push_str("  rankdir=LR;\n");
+        dot.push_str("  node [fontname=\"Helvetica\"];\n");
+        dot.push_str("  edge [fontname=\"Helvetica\", fontsize=10];\n");
+
+        if !self.nodes.is_empty() {
+            dot.push('\n');
+        }
+        for (key, node) in &self.nodes {
+            dot.push_str(&format!(
+                "  {} [label={}, shape={}];\n",
+                dot_quote(&key.to_string()),
+                dot_quote(&format!("{}\n{}", node.name, key.type_key())),
+                dot_shape(node.asset_type)
+            ));
+        }
+        for (id, label) in self.missing_targets() {
+            dot.push_str(&format!(
+                "  {} [label={}, shape=box, style=dashed, color=red, fontcolor=red];\n",
+                dot_quote(&id),
+                dot_quote(&format!("missing\n{}", label))
+            ));
+        }
+
+        if !self.edges.is_empty() || !self.unresolved.is_empty() {
+            dot.push('\n');
+        }
+        for edge in self.sorted_edges() {
+            dot.push_str(&format!(
+                "  {} -> {} [label={}];\n",
+                dot_quote(&edge.from.to_string()),
+                dot_quote(&edge.to.to_string()),
+                dot_quote(&edge.field)
+            ));
+        }
+        for reference in self.sorted_unresolved() {
+            dot.push_str(&format!(
+                "  {} -> {} [label={}, color=red, fontcolor=red];\n",
+                dot_quote(&reference.from.to_string()),
+                dot_quote(&missing_id(&reference.reference.target)),
+                dot_quote(&reference.reference.field)
+            ));
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Render the graph as a Mermaid flowchart, see [`Self::to_dot`].
+    pub fn to_mermaid(&self) -> String {
+        let mut ids: HashMap<String, String> = HashMap::new();
+        let mut mermaid = String::from("flowchart LR\n");
+
+        for (idx, (key, node)) in self.nodes.iter().enumerate() {
+            let id = format!("n{}", idx);
+            let (open, close) = mermaid_shape(node.asset_type);
+            mermaid.push_str(&format!(
+                "  {}{}\"{}<br/>{}\"{}\n",
+                id,
+                open,
+                mermaid_escape(&node.name),
+                key.type_key(),
+                close
+            ));
+            ids.insert(key.to_string(), id);
+        }
+        for (idx, (missing, label)) in self.missing_targets().into_iter().enumerate() {
+            let id = format!("m{}", idx);
+            mermaid.push_str(&format!(
+                "  {}[\"missing<br/>{}\"]:::dangling\n",
+                id,
+                mermaid_escape(&label)
+            ));
+            ids.insert(missing, id);
+        }
+
+        for edge in self.sorted_edges() {
+            mermaid.push_str(&format!(
+                "  {} -->|\"{}\"| {}\n",
+                ids[&edge.from.to_string()],
+                mermaid_escape(&edge.field),
+                ids[&edge.to.to_string()]
+            ));
+        }
+        for reference in self.sorted_unresolved() {
+            mermaid.push_str(&format!(
+                "  {} -.->|\"{}\"| {}\n",
+                ids[&reference.from.to_string()],
+                mermaid_escape(&reference.reference.field),
+                ids[&missing_id(&reference.reference.target)]
+            ));
+        }
+
+        if !self.unresolved.is_empty() {
+            mermaid.push_str("  classDef dangling stroke:#d00,color:#d00,stroke-dasharray:4;\n");
+        }
+        mermaid
+    }
+
+    fn sorted_edges(&self) -> Vec<&Dependency> {
+        let mut edges: Vec<&Dependency> = self.edges.iter().collect();
+        edges.sort_by(|a, b| (&a.from, &a.to, &a.field).cmp(&(&b.from, &b.to, &b.field)));
+        edges
+    }
+
+    fn sorted_unresolved(&self) -> Vec<&UnresolvedReference> {
+        let mut unresolved: Vec<&UnresolvedReference> = self.unresolved.iter().collect();
+        unresolved
+            .sort_by(|a, b| (&a.from, &a.reference.field).cmp(&(&b.from, &b.reference.field)));
+        unresolved
+    }
+
+    /// Node IDs and labels of the missing assets referenced by unresolved references.
+    fn missing_targets(&self) -> BTreeMap<String, String> {
+        self.unresolved
+            .iter()
+            .map(|u| {
+                let label = match &u.reference.target {
+                    ReferenceTarget::Id { id, .. } => format!("ID {}", id),
+                    ReferenceTarget::Path { path, .. } => path.clone(),
+                };
+                (missing_id(&u.reference.target), label)
+            })
+            .collect()
+    }
+}
+
+/// Node ID of a missing asset in exported graphs.
+fn missing_id(target: &ReferenceTarget) -> String {
+    match target {
+        ReferenceTarget::Id { id, .. } => format!("missing/{}", id),
+        ReferenceTarget::Path { asset_type, path } => {
+            format!("missing/{}", AssetKey::new(*asset_type, path.as_str()))
+        }
+    }
+}
+
+fn dot_quote(value: &str) -> String {
+    format!(
+        "\"{}\"",
+        value
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")
+    )
+}
+
+fn dot_shape(asset_type: AssetType) -> &'static str {
+    match asset_type {
+        AssetType::Sound => "ellipse",
+        AssetType::Collection => "box3d",
+        AssetType::Effect => "diamond",
+        AssetType::Switch => "hexagon",
+        AssetType::SwitchContainer => "component",
+        AssetType::Event => "box",
+        AssetType::Soundbank => "cylinder",
+    }
+}
+
+fn mermaid_escape(value: &str) -> String {
+    value.replace('"', "#quot;")
+}
+
+fn mermaid_shape(asset_type: AssetType) -> (&'static str, &'static str) {
+    match asset_type {
+        AssetType::Sound => ("([", "])"),
+        AssetType::Collection => ("[[", "]]"),
+        AssetType::Effect => ("{", "}"),
+        AssetType::Switch => ("{{", "}}"),
+        AssetType::SwitchContainer => ("[/", "/]"),
+        AssetType::Event => ("[", "]"),
+        AssetType::Soundbank => ("[(", ")]"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn node(asset_type: AssetType, name: &str, id: u64) -> (AssetNode, Value) {
+        let path = format!("{}/{}.json", asset_type.directory_name(), name);
+        (
+            AssetNode {
+                asset_type,
+                name: name.to_string(),
+                id: Some(id),
+                path,
+            },
+            json!({ "id": id, "name": name }),
+        )
+    }
+
+    fn dirs() -> HashMap<AssetType, String> {
+        AssetType::ALL
+            .iter()
+            .map(|t| (*t, t.directory_name().to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_p0_extract_references_skips_zero_ids() {
+        let sound = json!({ "id": 1, "effect": 0 });
+        assert!(extract_references(AssetType::Sound, &sound).is_empty());
+
+        let sound = json!({ "id": 1, "effect": 7 });
+        assert_eq!(
+            extract_references(AssetType::Sound, &sound),
+            vec![Reference {
+                field: "effect".to_string(),
+                target: ReferenceTarget::Id {
+                    id: 7,
+                    types: &[AssetType::Effect],
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn test_p0_graph_resolves_ids_and_soundbank_paths() {
+        let (sound, _) = node(AssetType::Sound, "step", 1);
+        let (event, _) = node(AssetType::Event, "play_step", 2);
+        let (bank, _) = node(AssetType::Soundbank, "main", 3);
+
+        let graph = DependencyGraph::from_assets(
+            vec![
+                (sound.clone(), json!({ "id": 1, "name": "step" })),
+                (
+                    event,
+                    json!({ "id": 2, "actions": [{ "type": "Play", "targets": [1, 99] }] }),
+                ),
+                (
+                    bank,
+                    json!({ "id": 3, "sounds": ["step.amsound"], "events": ["events/play_step.json"] }),
+                ),
+            ],
+            &dirs(),
+        );
+
+        let step = sound.key();
+        let dependents: Vec<String> = graph
+            .dependents(&step)
+            .iter()
+            .map(|k| k.to_string())
+            .collect();
+        assert_eq!(dependents, vec!["soundbank/main", "event/play_step"]);
+        assert_eq!(
+            graph
+                .dependencies(&AssetKey::new(AssetType::Soundbank, "main"))
+                .len(),
+            2
+        );
+        assert_eq!(graph.unresolved().len(), 1);
+        assert_eq!(
+            graph.unresolved()[0].reference.field,
+            "actions[0].targets[1]"
+        );
+    }
+
+    #[test]
+    fn test_p1_transitive_lists_follow_indirect_dependents() {
+        let (a, _) = node(AssetType::Collection, "a", 1);
+        let (b, _) = node(AssetType::SwitchContainer, "b", 2);
+        let graph = DependencyGraph::from_assets(
+            vec![
+                (a.clone(), json!({ "id": 1, "sounds": [] })),
+                (b, json!({ "id": 2, "entries": [{ "object": 1 }] })),
+            ],
+            &dirs(),
+        );
+
+        let lists = graph.dependent_lists(&a.key(), true);
+        assert_eq!(lists.len(), 2);
+        assert!(graph.dependency_lists(&a.key(), true)[&a.key()].is_empty());
+    }
+}
@@ -64,6 +64,10 @@ mod effect;
 mod event;
 /// FaderAlgorithm enum and convenience extension methods for generated SDK types.
 pub mod extensions;
+/// Asset dependency graph built from cross-asset references.
+pub mod graph;
+/// Renaming asset files while keeping the soundbanks listing them.
+pub mod rename;
 mod sound;
 mod soundbank;
 mod switch;
@@ -191,7 +195,7 @@ pub trait Asset: Serialize + DeserializeOwned + Send + Sync {
 ///
 /// Each variant corresponds to a specific asset type with its own
 /// file format, directory location, and validation rules.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AssetType {
     /// Individual sound definitions
@@ -211,9 +215,23 @@ pub enum AssetType {
 }
 
 impl AssetType {
-    /// Returns the directory name in the sources/ folder.
+    /// All asset types, in the order projects are validated.
+    pub const ALL: [AssetType; 7] = [
+        Self::Sound,
+        Self::Collection,
+        Self::Effect,
+        Self::Switch,
+        Self::SwitchContainer,
+        Self::Event,
+        Self::Soundbank,
+    ];
+
+    /// Returns the default directory name in the sources/ folder.
     ///
-    /// This matches the SDK's expected directory structure.
+    /// This matches the SDK's expected directory structure. Projects can override it
+    /// through `asset_dirs` in `.amproject`, see [`ProjectConfiguration::asset_dir`].
+    ///
+    /// [`ProjectConfiguration::asset_dir`]: crate::database::entities::ProjectConfiguration::asset_dir
     ///
     /// # Examples
     ///
@@ -235,6 +253,29 @@ impl AssetType {
         }
     }
 
+    /// Returns the asset type whose default directory name is `name`, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use am::assets::AssetType;
+    ///
+    /// assert_eq!(AssetType::from_directory_name("events"), Some(AssetType::Event));
+    /// assert_eq!(AssetType::from_directory_name("rtpc"), None);
+    /// ```
+    pub fn from_directory_name(name: &str) -> Option<Self> {
+        match name {
+            ASSET_DIR_SOUNDS => Some(Self::Sound),
+            ASSET_DIR_COLLECTIONS => Some(Self::Collection),
+            ASSET_DIR_SWITCHES => Some(Self::Switch),
+            ASSET_DIR_SWITCH_CONTAINERS => Some(Self::SwitchContainer),
+            ASSET_DIR_SOUNDBANKS => Some(Self::Soundbank),
+            ASSET_DIR_EVENTS => Some(Self::Event),
+            ASSET_DIR_EFFECTS => Some(Self::Effect),
+            _ => None,
+        }
+    }
+
     /// Returns the file extension for this asset type.
     ///
     /// # Examples
@@ -497,6 +538,8 @@ pub struct ProjectContext {
     pub name_registry: HashMap<AssetType, HashSet<String>>,
     /// Optional cross-asset reference validator for checking inter-asset dependencies.
     pub validator: Option<validator::ProjectValidator>,
+    /// Whether the project accepts comments in asset files (`allow_json_comments`).
+    pub allow_json_comments: bool,
 }
 
 impl ProjectContext {
@@ -505,16 +548,18 @@ impl ProjectContext {
     /// Reads `.amproject` to resolve the data directory path.
     /// Falls back to `<project_root>/data` if `.amproject` is unavailable.
     pub fn new(project_root: PathBuf) -> Self {
-        let data_dir = match crate::common::utils::read_amproject_file(&project_root) {
-            Ok(config) => {
-                if config.data_dir.is_empty() {
-                    project_root.clone()
-                } else {
-                    project_root.join(&config.data_dir)
+        let (data_dir, allow_json_comments) =
+            match crate::common::utils::read_amproject_file(&project_root) {
+                Ok(config) => {
+                    let data_dir = if config.data_dir.is_empty() {
+                        project_root.clone()
+                    } else {
+                        project_root.join(&config.data_dir)
+                    };
+                    (data_dir, config.allow_json_comments)
                 }
-            }
-            Err(_) => project_root.join("data"),
-        };
+                Err(_) => (project_root.join("data"), false),
+            };
 
         Self {
             project_root,
@@ -522,6 +567,7 @@ impl ProjectContext {
             id_registry: HashSet::new(),
             name_registry: HashMap::new(),
             validator: None,
+            allow_json_comments,
         }
     }
 
@@ -536,9 +582,16 @@ impl ProjectContext {
             id_registry: HashSet::new(),
             name_registry: HashMap::new(),
             validator: None,
+            allow_json_comments: false,
         }
     }
 
+    /// Parse the content of one of this project's asset files, honoring the project's
+    /// `allow_json_comments` setting.
+    pub fn parse_asset_json<T: DeserializeOwned>(&self, content: &str) -> serde_json::Result<T> {
+        crate::common::files::parse_asset_json(content, self.allow_json_comments)
+    }
+
     /// Attaches a `ProjectValidator` for cross-asset reference checking.
     ///
     /// When set, `validate_rules()` implementations can use it to verify
@@ -0,0 +1,172 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Renaming asset files without breaking the references to them.
+//!
+//! Most references are asset IDs, which a rename leaves alone. Soundbanks list the files
+//! of the assets they package instead, so [`rename_asset_file`] rewrites the soundbank
+//! entries pointing to the renamed file. Each entry keeps its form: relative to the type
+//! directory or to the sources directory, with the source or the runtime extension.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde_json::Value;
+
+use super::graph::{AssetKey, DependencyGraph};
+use crate::common::errors::{asset_already_exists, asset_not_found};
+use crate::common::files::{atomic_write, parse_asset_json, to_json_pretty};
+use crate::common::utils::read_amproject_file;
+
+/// The result of [`rename_asset_file`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RenamedAsset {
+    /// Previous path of the file, relative to the sources directory.
+    pub from: String,
+    /// New path of the file, relative to the sources directory.
+    pub to: String,
+    /// Soundbank entries rewritten to the new path, as `<type>/<name> (<field>)`.
+    pub updated_references: Vec<String>,
+}
+
+/// Rename the file of the asset `key` of `graph` so its stem becomes `new_stem`, keeping
+/// its directory and extension, and update the soundbanks listing it.
+///
+/// `graph` must describe the project at `project_root` as it is on disk. Renaming
+/// several assets therefore renames the soundbanks last, as the paths of the other
+/// assets lead to their files.
+///
+/// # Errors
+///
+/// Returns an `ERR_ASSET_NOT_FOUND` error if `graph` has no asset `key`, an
+/// `ERR_ASSET_ALREADY_EXISTS` error if a file already has the new name, or the
+/// underlying I/O error.
+pub fn rename_asset_file(
+    project_root: &Path,
+    graph: &DependencyGraph,
+    key: &AssetKey,
+    new_stem: &str,
+) -> Result<RenamedAsset> {
+    let node = graph
+        .node(key)
+        .ok_or_else(|| asset_not_found(&key.asset_type.to_string(), &key.name))?;
+    let config = read_amproject_file(project_root)?;
+    let sources_dir = if config.sources_dir.is_empty() {
+        project_root.to_path_buf()
+    } else {
+        project_root.join(&config.sources_dir)
+    };
+
+    let (dir, file_name) = match node.path.rsplit_once('/') {
+        Some((dir, file_name)) => (Some(dir), file_name),
+        None => (None, node.path.as_str()),
+    };
+    let (old_stem, new_file_name) = replace_stem(file_name, new_stem);
+    let new_path = match dir {
+        Some(dir) => format!("{}/{}", dir, new_file_name),
+        None => new_file_name.clone(),
+    };
+
+    let source = sources_dir.join(&node.path);
+    let destination = sources_dir.join(&new_path);
+    if has_entry(&destination, &new_file_name)? {
+        return Err(asset_already_exists(&key.asset_type.to_string(), new_stem)
+            .with_context(destination.display().to_string())
+            .into());
+    }
+    fs::rename(&source, &destination).with_context(|| {
+        format!(
+            "Failed to rename {} to {}",
+            source.display(),
+            destination.display()
+        )
+    })?;
+
+    let mut updated_references = Vec::new();
+    for reference in graph.references_to(key) {
+        let Some(soundbank) = graph.node(&reference.from) else {
+            continue;
+        };
+        let soundbank_path = sources_dir.join(&soundbank.path);
+        let content = fs::read_to_string(&soundbank_path)
+            .with_context(|| format!("Failed to read {}", soundbank_path.display()))?;
+        let mut value: Value = parse_asset_json(&content, config.allow_json_comments)
+            .with_context(|| format!("Failed to parse {}", soundbank_path.display()))?;
+
+        let Some(entry) = field_entry(&mut value, &reference.field) else {
+            continue;
+        };
+        let Some(listed) = entry.as_str() else {
+            continue;
+        };
+        let (listed_dir, listed_name) = match listed.rsplit_once('/') {
+            Some((dir, name)) => (Some(dir), name),
+            None => (None, listed),
+        };
+        let (listed_stem, renamed) = replace_stem(listed_name, new_stem);
+        if listed_stem != old_stem {
+            continue;
+        }
+        *entry = Value::from(match listed_dir {
+            Some(dir) => format!("{}/{}", dir, renamed),
+            None => renamed,
+        });
+
+        let json = to_json_pretty(&value)?;
+        atomic_write(&soundbank_path, json.as_bytes())?;
+        updated_references.push(format!("{} ({})", reference.from, reference.field));
+    }
+
+    Ok(RenamedAsset {
+        from: node.path.clone(),
+        to: new_path,
+        updated_references,
+    })
+}
+
+/// The stem of `file_name` and `file_name` with that stem replaced by `new_stem`.
+fn replace_stem<'a>(file_name: &'a str, new_stem: &str) -> (&'a str, String) {
+    match file_name.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => {
+            (stem, format!("{}.{}", new_stem, extension))
+        }
+        _ => (file_name, new_stem.to_string()),
+    }
+}
+
+/// Whether the directory of `path` has an entry named exactly `file_name`.
+///
+/// Unlike [`Path::exists`], this doesn't match the file being renamed on filesystems
+/// ignoring case, where a rename may only change the case of the name.
+fn has_entry(path: &Path, file_name: &str) -> Result<bool> {
+    let dir = path
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_else(PathBuf::new);
+    for entry in fs::read_dir(&dir).with_context(|| format!("Failed to read {}", dir.display()))? {
+        if entry?.file_name() == file_name {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// The array entry designated by `field`, e.g. `sounds[3]`.
+fn field_entry<'a>(value: &'a mut Value, field: &str) -> Option<&'a mut Value> {
+    let (name, index) = field.strip_suffix(']')?.split_once('[')?;
+    let index: usize = index.parse().ok()?;
+    value.get_mut(name)?.get_mut(index)
+}
@@ -356,12 +356,18 @@ impl Asset for Soundbank {
             let mut missing_assets: Vec<String> = Vec::new();
 
             let checks: &[(&str, &Option<Vec<String>>)] = &[
-                ("sounds", &self.sounds),
-                ("collections", &self.collections),
-                ("events", &self.events),
-                ("switches", &self.switches),
-                ("switch_containers", &self.switch_containers),
-                ("effects", &self.effects),
+                (validator.asset_dir(AssetType::Sound), &self.sounds),
+                (
+                    validator.asset_dir(AssetType::Collection),
+                    &self.collections,
+                ),
+                (validator.asset_dir(AssetType::Event), &self.events),
+                (validator.asset_dir(AssetType::Switch), &self.switches),
+                (
+                    validator.asset_dir(AssetType::SwitchContainer),
+                    &self.switch_containers,
+                ),
+                (validator.asset_dir(AssetType::Effect), &self.effects),
                 ("attenuators", &self.attenuators),
                 ("rtpc", &self.rtpc),
             ];
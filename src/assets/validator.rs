@@ -36,12 +36,14 @@
 //! validator.validate_sound_exists(999)?;       // Err if no sound with ID 999
 //! ```
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 
 use super::{AssetType, ValidationError, ValidationLayer};
 use crate::common::errors::codes;
+use crate::common::files::parse_asset_json;
+use crate::common::ignore::{IgnoreRules, WalkOptions, walk_project_files, warn_skipped};
 use crate::presentation::Output;
 
 /// Runtime binary extensions mapped to their source equivalent.
@@ -66,7 +68,7 @@ const RUNTIME_EXTENSIONS: &[&str] = &[
 /// Convert a runtime binary asset path to its source `.json` path.
 ///
 /// Example: `sounds/throw_01.wav.amsound` → `sounds/throw_01.wav.json`
-fn runtime_path_to_source(path: &str) -> String {
+pub(crate) fn runtime_path_to_source(path: &str) -> String {
     for ext in RUNTIME_EXTENSIONS {
         if path.ends_with(ext) {
             return format!("{}.json", &path[..path.len() - ext.len()]);
@@ -101,6 +103,10 @@ pub struct ProjectValidator {
     /// All known asset file paths relative to the project sources directory.
     /// Used for path-based reference validation (e.g., soundbank asset references).
     asset_paths: HashSet<String>,
+    /// Whether asset files may contain comments (`allow_json_comments` in .amproject).
+    allow_json_comments: bool,
+    /// Directory overrides per asset type (`asset_dirs` in .amproject).
+    asset_dirs: BTreeMap<AssetType, String>,
 }
 
 // ValidationError is the project-wide error type for all validation methods.
@@ -110,32 +116,42 @@ impl ProjectValidator {
     /// Creates a new `ProjectValidator` by scanning the project's asset directories.
     ///
     /// Reads all JSON files in each `sources/<type>/` directory, extracting
-    /// `id` and `name` fields to build the registry.
+    /// `id` and `name` fields to build the registry. Files ignored by the
+    /// project's `.amignore` rules are not registered.
     ///
     /// Missing directories are silently skipped (a fresh project may not have
-    /// all asset type directories). Malformed JSON files are logged as warnings
-    /// and skipped.
+    /// all asset type directories). Malformed JSON files, and directories the
+    /// walk didn't descend into, are logged as warnings and skipped.
     ///
     /// # Arguments
     ///
     /// * `project_root` - Path to the project root (where `.amproject` lives)
+    /// * `walk` - How to walk the asset directories
     ///
     /// # Errors
     ///
     /// Returns an error only for I/O failures that prevent scanning entirely
     /// (e.g., permission denied on the sources directory itself).
-    pub fn new(project_root: PathBuf, output: &dyn Output) -> anyhow::Result<Self> {
+    pub fn new(
+        project_root: PathBuf,
+        walk: WalkOptions,
+        output: &dyn Output,
+    ) -> anyhow::Result<Self> {
         // Read .amproject to get the configured sources directory
-        let sources_dir = match crate::common::utils::read_amproject_file(&project_root) {
-            Ok(config) => {
-                if config.sources_dir.is_empty() {
-                    project_root.clone()
-                } else {
-                    project_root.join(&config.sources_dir)
+        let (sources_dir, allow_json_comments, asset_dirs) =
+            match crate::common::utils::read_amproject_file(&project_root) {
+                Ok(config) => {
+                    let sources_dir = if config.sources_dir.is_empty() {
+                        project_root.clone()
+                    } else {
+                        project_root.join(&config.sources_dir)
+                    };
+                    (sources_dir, config.allow_json_comments, config.asset_dirs)
                 }
-            }
-            Err(_) => project_root.join("sources"), // fallback for projects without .amproject
-        };
+                // fallback for projects without .amproject
+                Err(_) => (project_root.join("sources"), false, BTreeMap::new()),
+            };
+        let ignore = IgnoreRules::load(&project_root)?;
 
         let mut validator = Self {
             project_root,
@@ -144,6 +160,8 @@ impl ProjectValidator {
             asset_names: HashMap::new(),
             asset_locations: HashMap::new(),
             asset_paths: HashSet::new(),
+            allow_json_comments,
+            asset_dirs,
         };
 
         // Scan all asset types
@@ -158,13 +176,13 @@ impl ProjectValidator {
         ];
 
         for asset_type in &asset_types {
-            validator.scan_assets_of_type(*asset_type, output)?;
+            validator.scan_assets_of_type(*asset_type, &ignore, walk, output)?;
         }
 
         // Scan additional directories that don't have AssetType variants
         // but are referenced by soundbanks (attenuators, pipelines, rtpc)
         for extra_dir in &["attenuators", "pipelines", "rtpc"] {
-            validator.scan_directory_paths(extra_dir);
+            validator.scan_directory_paths(extra_dir, &ignore, walk, output);
         }
 
         Ok(validator)
@@ -181,6 +199,8 @@ impl ProjectValidator {
             asset_names: HashMap::new(),
             asset_locations: HashMap::new(),
             asset_paths: HashSet::new(),
+            allow_json_comments: false,
+            asset_dirs: BTreeMap::new(),
         }
     }
 
@@ -259,13 +279,13 @@ impl ProjectValidator {
                     ),
                     format!(
                         "The asset was found in sources/{} instead of sources/{}",
-                        actual_type.directory_name(),
-                        asset_type.directory_name()
+                        self.asset_dir(*actual_type),
+                        self.asset_dir(asset_type)
                     ),
                 )
                 .with_suggestion(format!(
                     "Move the asset to sources/{} or use the correct asset type reference",
-                    asset_type.directory_name()
+                    self.asset_dir(asset_type)
                 )));
             }
         }
@@ -368,9 +388,18 @@ impl ProjectValidator {
         &self.sources_dir
     }
 
+    /// Returns the name of the directory holding assets of the given type, honoring
+    /// the project's `asset_dirs` overrides.
+    pub fn asset_dir(&self, asset_type: AssetType) -> &str {
+        self.asset_dirs
+            .get(&asset_type)
+            .map(String::as_str)
+            .unwrap_or_else(|| asset_type.directory_name())
+    }
+
     /// Scans a single asset type directory and populates the registries.
     ///
-    /// Reads all `.json` files from `sources/<type>/`, extracting `id` and
+    /// Reads all `.json` files from the type's directory, extracting `id` and
     /// `name` fields using `serde_json::Value` (avoids requiring full struct
     /// deserialization for asset types not yet implemented).
     ///
@@ -379,20 +408,22 @@ impl ProjectValidator {
     fn scan_assets_of_type(
         &mut self,
         asset_type: AssetType,
+        ignore: &IgnoreRules,
+        walk: WalkOptions,
         output: &dyn Output,
     ) -> anyhow::Result<()> {
-        let dir = self.sources_dir.join(asset_type.directory_name());
+        let dir = self.sources_dir.join(self.asset_dir(asset_type));
 
         if !dir.exists() {
             return Ok(());
         }
 
         // Walk recursively to handle subdirectories (e.g., sounds/footsteps/grass/)
-        for entry in walkdir::WalkDir::new(&dir).into_iter().flatten() {
-            let path = entry.into_path();
-
+        let files = walk_project_files(&dir, ignore, walk);
+        warn_skipped(&files.skipped, output);
+        for path in files.files {
             // Only process .json files
-            if !path.is_file() || path.extension().is_none_or(|ext| ext != "json") {
+            if path.extension().is_none_or(|ext| ext != "json") {
                 continue;
             }
 
@@ -409,17 +440,18 @@ impl ProjectValidator {
                 }
             };
 
-            let value: serde_json::Value = match serde_json::from_str(&content) {
-                Ok(v) => v,
-                Err(err) => {
-                    output.warning(&format!(
-                        "Malformed JSON in asset file {}: {}",
-                        path.display(),
-                        err
-                    ));
-                    continue;
-                }
-            };
+            let value: serde_json::Value =
+                match parse_asset_json(&content, self.allow_json_comments) {
+                    Ok(v) => v,
+                    Err(err) => {
+                        output.warning(&format!(
+                            "Malformed JSON in asset file {}: {}",
+                            path.display(),
+                            err
+                        ));
+                        continue;
+                    }
+                };
 
             // Extract id (u64) and name (String)
             if let Some(id) = value.get("id").and_then(|v| v.as_u64()) {
@@ -448,16 +480,23 @@ impl ProjectValidator {
     ///
     /// Used for asset types that don't have `AssetType` variants (attenuators,
     /// pipelines, rtpc) but are referenced by soundbanks.
-    fn scan_directory_paths(&mut self, dir_name: &str) {
+    fn scan_directory_paths(
+        &mut self,
+        dir_name: &str,
+        ignore: &IgnoreRules,
+        walk: WalkOptions,
+        output: &dyn Output,
+    ) {
         let dir = self.sources_dir.join(dir_name);
 
         if !dir.exists() {
             return;
         }
 
-        for entry in walkdir::WalkDir::new(&dir).into_iter().flatten() {
-            let path = entry.into_path();
-            if !path.is_file() || path.extension().is_none_or(|ext| ext != "json") {
+        let files = walk_project_files(&dir, ignore, walk);
+        warn_skipped(&files.skipped, output);
+        for path in files.files {
+            if path.extension().is_none_or(|ext| ext != "json") {
                 continue;
             }
 
@@ -525,6 +564,7 @@ mod tests {
 
         let validator = ProjectValidator::new(
             dir.path().to_path_buf(),
+            WalkOptions::default(),
             &crate::presentation::JsonOutput::new(),
         )
         .unwrap();
@@ -550,6 +590,7 @@ mod tests {
 
         let validator = ProjectValidator::new(
             dir.path().to_path_buf(),
+            WalkOptions::default(),
             &crate::presentation::JsonOutput::new(),
         )
         .unwrap();
@@ -565,6 +606,7 @@ mod tests {
 
         let validator = ProjectValidator::new(
             dir.path().to_path_buf(),
+            WalkOptions::default(),
             &crate::presentation::JsonOutput::new(),
         )
         .unwrap();
@@ -600,6 +642,7 @@ mod tests {
 
         let validator = ProjectValidator::new(
             dir.path().to_path_buf(),
+            WalkOptions::default(),
             &crate::presentation::JsonOutput::new(),
         )
         .unwrap();
@@ -639,6 +682,7 @@ mod tests {
         // Don't create any sources/ directories at all
         let validator = ProjectValidator::new(
             dir.path().to_path_buf(),
+            WalkOptions::default(),
             &crate::presentation::JsonOutput::new(),
         )
         .unwrap();
@@ -663,6 +707,7 @@ mod tests {
 
         let validator = ProjectValidator::new(
             dir.path().to_path_buf(),
+            WalkOptions::default(),
             &crate::presentation::JsonOutput::new(),
         )
         .unwrap();
@@ -676,6 +721,7 @@ mod tests {
         // Project root exists but no sources/ directory at all
         let validator = ProjectValidator::new(
             dir.path().to_path_buf(),
+            WalkOptions::default(),
             &crate::presentation::JsonOutput::new(),
         )
         .unwrap();
@@ -692,6 +738,7 @@ mod tests {
 
         let validator = ProjectValidator::new(
             dir.path().to_path_buf(),
+            WalkOptions::default(),
             &crate::presentation::JsonOutput::new(),
         )
         .unwrap();
@@ -747,6 +794,7 @@ mod tests {
 
         let validator = ProjectValidator::new(
             dir.path().to_path_buf(),
+            WalkOptions::default(),
             &crate::presentation::JsonOutput::new(),
         )
         .unwrap();
@@ -769,6 +817,7 @@ mod tests {
 
         let validator = ProjectValidator::new(
             dir.path().to_path_buf(),
+            WalkOptions::default(),
             &crate::presentation::JsonOutput::new(),
         )
         .unwrap();
@@ -791,6 +840,7 @@ mod tests {
 
         let validator = ProjectValidator::new(
             dir.path().to_path_buf(),
+            WalkOptions::default(),
             &crate::presentation::JsonOutput::new(),
         )
         .unwrap();
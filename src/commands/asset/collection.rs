@@ -16,8 +16,8 @@
 //!
 //! Implements CRUD operations for Collection assets in Amplitude projects.
 
-use std::env;
 use std::fs;
+use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::{Context, Result};
@@ -26,23 +26,26 @@ use colored::Colorize;
 use inquire::validator::Validation;
 use serde_json::json;
 
+use crate::common::ignore::WalkOptions;
+use crate::common::project_option::project_dir;
 use crate::common::utils::generate_unique_id;
 use crate::{
     assets::{
-        Asset, Collection, CollectionPlayMode, ProjectContext, ProjectValidator,
-        RtpcCompatibleValue, SoundSchedulerMode, Spatialization,
+        Asset, AssetType, Collection, CollectionPlayMode, ProjectContext, ProjectValidator,
+        RtpcCompatibleValue, SoundSchedulerMode, Spatialization, graph::AssetKey,
     },
     common::{
         errors::{CliError, asset_already_exists, asset_not_found, codes},
-        files::atomic_write,
+        files::{atomic_write, safe_remove_within, to_json_pretty},
+        fs_ctx,
         utils::read_amproject_file,
     },
     database::Database,
     input::{Input, select_index},
-    presentation::{Output, OutputMode},
+    presentation::{ListLimit, Output, OutputMode, insert_os_str},
 };
 
-use super::{find_json_files_recursive, parse_spatialization};
+use super::{ensure_not_referenced, find_json_files_recursive, parse_spatialization};
 
 /// The name of the current asset.
 const ASSET_NAME: &str = "Collection";
@@ -54,10 +57,6 @@ const MAX_ID_RETRIES: u32 = 3;
 #[derive(Subcommand, Debug)]
 pub enum CollectionCommands {
     /// Create a new collection asset
-    #[command(after_help = "Examples:
-  am asset collection create footsteps
-  am asset collection create ambience --play-mode PlayAll --gain 0.7
-")]
     Create {
         /// Name of the collection asset
         name: String,
@@ -88,17 +87,12 @@ pub enum CollectionCommands {
     },
 
     /// List all collection assets in the project
-    #[command(after_help = "Examples:
-  am asset collection list
-  am asset collection list --json
-")]
-    List {},
+    List {
+        #[command(flatten)]
+        limit: ListLimit,
+    },
 
     /// Update an existing collection asset
-    #[command(after_help = "Examples:
-  am asset collection update footsteps --play-mode PlayAll
-  am asset collection update ambience --gain 0.5
-")]
     Update {
         /// Name of the collection asset to update
         name: String,
@@ -129,10 +123,6 @@ pub enum CollectionCommands {
     },
 
     /// Delete a collection asset
-    #[command(after_help = "Examples:
-  am asset collection delete footsteps
-  am asset collection delete footsteps --force
-")]
     Delete {
         /// Name of the collection asset to delete
         name: String,
@@ -146,6 +136,8 @@ pub enum CollectionCommands {
 /// Handle collection commands by routing to the appropriate handler.
 pub async fn handler(
     command: &CollectionCommands,
+    project: Option<&Path>,
+    walk: WalkOptions,
     _database: Option<Arc<Database>>,
     input: &dyn Input,
     output: &dyn Output,
@@ -161,6 +153,8 @@ pub async fn handler(
             spatialization,
         } => {
             create_collection(
+                project,
+                walk,
                 name,
                 play_mode.clone(),
                 scheduler_mode.clone(),
@@ -173,7 +167,7 @@ pub async fn handler(
             )
             .await
         }
-        CollectionCommands::List {} => list_collections(output).await,
+        CollectionCommands::List { limit } => list_collections(project, walk, limit, output).await,
         CollectionCommands::Update {
             name,
             play_mode,
@@ -184,6 +178,8 @@ pub async fn handler(
             spatialization,
         } => {
             update_collection(
+                project,
+                walk,
                 name,
                 play_mode.clone(),
                 scheduler_mode.clone(),
@@ -197,7 +193,7 @@ pub async fn handler(
             .await
         }
         CollectionCommands::Delete { name, force } => {
-            delete_collection(name, *force, input, output).await
+            delete_collection(project, walk, name, *force, input, output).await
         }
     }
 }
@@ -209,6 +205,8 @@ pub async fn handler(
 /// Create a new collection asset.
 #[allow(clippy::too_many_arguments)]
 async fn create_collection(
+    project: Option<&Path>,
+    walk: WalkOptions,
     name: &str,
     play_mode: Option<String>,
     scheduler_mode: Option<String>,
@@ -231,7 +229,7 @@ async fn create_collection(
     }
 
     // Step 2: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     output.progress(&format!(
@@ -245,7 +243,7 @@ async fn create_collection(
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let collections_dir = sources_base.join("collections");
+    let collections_dir = sources_base.join(project_config.asset_dir(AssetType::Collection));
     let collection_file_path = collections_dir.join(format!("{}.json", name));
 
     if collection_file_path.exists() {
@@ -258,11 +256,11 @@ async fn create_collection(
     }
 
     // Build populated ProjectContext for validation
-    let validator = ProjectValidator::new(current_dir.clone(), output)?;
+    let validator = ProjectValidator::new(current_dir.clone(), walk, output)?;
     let context = ProjectContext::new(current_dir.clone()).with_validator(validator);
 
     // Check name uniqueness via ProjectContext registry
-    if context.has_name(crate::assets::AssetType::Collection, name) {
+    if context.has_name(AssetType::Collection, name) {
         return Err(asset_already_exists(ASSET_NAME, name)
             .with_suggestion(format!(
                 "Use 'am asset collection update {}' to modify it, or choose a different name",
@@ -348,26 +346,24 @@ async fn create_collection(
     collection.validate_rules(&context)?;
 
     // Step 12: Serialize to JSON
-    let json_content = serde_json::to_string_pretty(&collection)
-        .context("Failed to serialize collection to JSON")?;
+    let json_content =
+        to_json_pretty(&collection).context("Failed to serialize collection to JSON")?;
 
     // Step 13: Ensure directory exists and write atomically
-    fs::create_dir_all(&collections_dir)?;
+    fs_ctx::create_dir_all(&collections_dir)?;
     atomic_write(&collection_file_path, json_content.as_bytes())?;
 
     // Step 14: Output success
     match output.mode() {
         OutputMode::Json => {
-            output.success(
-                json!({
-                    "id": collection.id,
-                    "name": collection.name(),
-                    "path": collection_file_path.to_string_lossy(),
-                    "play_mode": play_mode_value.to_string(),
-                    "scheduler_mode": scheduler_mode_value.to_string(),
-                }),
-                None,
-            );
+            let mut value = json!({
+                "id": collection.id,
+                "name": collection.name(),
+                "play_mode": play_mode_value.to_string(),
+                "scheduler_mode": scheduler_mode_value.to_string(),
+            });
+            insert_os_str(&mut value, "path", collection_file_path.as_os_str());
+            output.success(value, None);
         }
         OutputMode::Interactive => {
             output.success(
@@ -410,9 +406,14 @@ fn spatialization_to_string(spatialization: &Spatialization) -> &'static str {
 }
 
 /// List all collection assets in the current project.
-async fn list_collections(output: &dyn Output) -> Result<()> {
+async fn list_collections(
+    project: Option<&Path>,
+    walk: WalkOptions,
+    limit: &ListLimit,
+    output: &dyn Output,
+) -> Result<()> {
     // Step 1: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     // Step 2: Scan collections directory
@@ -421,7 +422,7 @@ async fn list_collections(output: &dyn Output) -> Result<()> {
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let collections_dir = sources_base.join("collections");
+    let collections_dir = sources_base.join(project_config.asset_dir(AssetType::Collection));
 
     // Step 3: Handle missing directory
     if !collections_dir.exists() {
@@ -451,7 +452,7 @@ async fn list_collections(output: &dyn Output) -> Result<()> {
     let mut collections: Vec<Collection> = Vec::new();
     let mut warnings: Vec<String> = Vec::new();
 
-    let json_files = match find_json_files_recursive(&collections_dir) {
+    let json_files = match find_json_files_recursive(&current_dir, &collections_dir, walk, output) {
         Ok(files) => files,
         Err(e) => {
             return Err(CliError::new(
@@ -468,7 +469,7 @@ async fn list_collections(output: &dyn Output) -> Result<()> {
     for path in json_files {
         if path.extension().map(|e| e == "json").unwrap_or(false) {
             match fs::read_to_string(&path) {
-                Ok(content) => match serde_json::from_str::<Collection>(&content) {
+                Ok(content) => match project_config.parse_asset_json::<Collection>(&content) {
                     Ok(collection) => {
                         collections.push(collection);
                     }
@@ -510,6 +511,7 @@ async fn list_collections(output: &dyn Output) -> Result<()> {
 
     // Step 5: Sort by name
     collections.sort_by(|a, b| a.name().cmp(b.name()));
+    let truncation = limit.apply(&mut collections);
 
     // Step 6: Handle empty
     if collections.is_empty() {
@@ -558,14 +560,13 @@ async fn list_collections(output: &dyn Output) -> Result<()> {
                 })
                 .collect();
 
-            output.success(
-                json!({
-                    "collections": collection_data,
-                    "count": collections.len(),
-                    "warnings": warnings
-                }),
-                None,
-            );
+            let mut payload = json!({
+                "collections": collection_data,
+                "count": collections.len(),
+                "warnings": warnings
+            });
+            truncation.annotate(&mut payload);
+            output.success(payload, None);
         }
         OutputMode::Interactive => {
             for warning in &warnings {
@@ -586,7 +587,8 @@ async fn list_collections(output: &dyn Output) -> Result<()> {
 
             output.table(None, json!(table_data));
             output.progress("");
-            output.progress(&format!("{} collection(s) found", collections.len()));
+            output.progress(&format!("{} collection(s) found", truncation.total));
+            truncation.notify("collections", output);
         }
     }
 
@@ -600,6 +602,8 @@ async fn list_collections(output: &dyn Output) -> Result<()> {
 /// Update an existing collection asset.
 #[allow(clippy::too_many_arguments)]
 async fn update_collection(
+    project: Option<&Path>,
+    walk: WalkOptions,
     name: &str,
     play_mode: Option<String>,
     scheduler_mode: Option<String>,
@@ -611,7 +615,7 @@ async fn update_collection(
     output: &dyn Output,
 ) -> Result<()> {
     // Step 1: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     output.progress(&format!(
@@ -625,7 +629,7 @@ async fn update_collection(
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let collections_dir = sources_base.join("collections");
+    let collections_dir = sources_base.join(project_config.asset_dir(AssetType::Collection));
     let collection_file_path = collections_dir.join(format!("{}.json", name));
 
     if !collection_file_path.exists() {
@@ -642,7 +646,7 @@ async fn update_collection(
         "Failed to read collection file: {}",
         collection_file_path.display()
     ))?;
-    let mut collection: Collection = serde_json::from_str(&content).context(format!(
+    let mut collection: Collection = project_config.parse_asset_json(&content).context(format!(
         "Failed to parse collection file: {}",
         collection_file_path.display()
     ))?;
@@ -671,27 +675,25 @@ async fn update_collection(
     };
 
     // Step 6: Validate
-    let validator = ProjectValidator::new(current_dir.clone(), output)?;
+    let validator = ProjectValidator::new(current_dir.clone(), walk, output)?;
     let context = ProjectContext::new(current_dir.clone()).with_validator(validator);
     collection.validate_rules(&context)?;
 
     // Step 7: Serialize and write atomically
-    let json_content = serde_json::to_string_pretty(&collection)
-        .context("Failed to serialize collection to JSON")?;
+    let json_content =
+        to_json_pretty(&collection).context("Failed to serialize collection to JSON")?;
     atomic_write(&collection_file_path, json_content.as_bytes())?;
 
     // Step 8: Output success
     match output.mode() {
         OutputMode::Json => {
-            output.success(
-                json!({
-                    "id": collection.id,
-                    "name": collection.name(),
-                    "path": collection_file_path.to_string_lossy(),
-                    "updated_fields": updated_fields,
-                }),
-                None,
-            );
+            let mut value = json!({
+                "id": collection.id,
+                "name": collection.name(),
+                "updated_fields": updated_fields,
+            });
+            insert_os_str(&mut value, "path", collection_file_path.as_os_str());
+            output.success(value, None);
         }
         OutputMode::Interactive => {
             output.success(
@@ -852,13 +854,15 @@ fn prompt_collection_updates(
 
 /// Delete a collection asset.
 async fn delete_collection(
+    project: Option<&Path>,
+    walk: WalkOptions,
     name: &str,
     force: bool,
     input: &dyn Input,
     output: &dyn Output,
 ) -> Result<()> {
     // Step 1: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     // Step 2: Locate collection file
@@ -867,7 +871,7 @@ async fn delete_collection(
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let collections_dir = sources_base.join("collections");
+    let collections_dir = sources_base.join(project_config.asset_dir(AssetType::Collection));
     let collection_file_path = collections_dir.join(format!("{}.json", name));
 
     if !collection_file_path.exists() {
@@ -881,12 +885,20 @@ async fn delete_collection(
         "Failed to read collection file: {}",
         collection_file_path.display()
     ))?;
-    let collection: Collection = serde_json::from_str(&content).context(format!(
+    let collection: Collection = project_config.parse_asset_json(&content).context(format!(
         "Failed to parse collection file: {}",
         collection_file_path.display()
     ))?;
 
-    // Step 4: Confirm deletion
+    // Step 4: Refuse to delete a collection other assets reference
+    ensure_not_referenced(
+        &current_dir,
+        walk,
+        &AssetKey::new(AssetType::Collection, collection.name()),
+        output,
+    )?;
+
+    // Step 5: Confirm deletion
     let confirmed = if force {
         true
     } else {
@@ -916,13 +928,13 @@ async fn delete_collection(
         return Ok(());
     }
 
-    // Step 5: Remove file
-    fs::remove_file(&collection_file_path).context(format!(
+    // Step 6: Remove file
+    safe_remove_within(&current_dir, &collection_file_path).context(format!(
         "Failed to delete collection file: {}",
         collection_file_path.display()
     ))?;
 
-    // Step 6: Output success
+    // Step 7: Output success
     match output.mode() {
         OutputMode::Json => {
             output.success(
@@ -1003,13 +1015,13 @@ fn prompt_gain(input: &dyn Input) -> Result<f32> {
         "Volume gain [0.0-1.0]",
         Some("1.0"),
         None,
-        Some(&|value: &str| match value.trim().parse::<f32>() {
+        &[&|value: &str| match value.trim().parse::<f32>() {
             Ok(g) if (0.0..=1.0).contains(&g) => Ok(Validation::Valid),
             Ok(g) => Ok(Validation::Invalid(
                 format!("Gain must be between 0.0 and 1.0, got {}", g).into(),
             )),
             Err(_) => Ok(Validation::Invalid("Must be a number".into())),
-        }),
+        }],
     );
 
     match result {
@@ -1024,12 +1036,12 @@ fn prompt_priority(input: &dyn Input) -> Result<u8> {
         "Playback priority [0-255]",
         Some("128"),
         None,
-        Some(&|value: &str| match value.trim().parse::<u8>() {
+        &[&|value: &str| match value.trim().parse::<u8>() {
             Ok(_) => Ok(Validation::Valid),
             Err(_) => Ok(Validation::Invalid(
                 "Must be a number between 0 and 255".into(),
             )),
-        }),
+        }],
     );
 
     match result {
@@ -1076,11 +1088,11 @@ fn prompt_update_number(
         &prompt,
         Some(&current_str),
         None,
-        Some(&move |value: &str| match value.trim().parse::<f32>() {
+        &[&move |value: &str| match value.trim().parse::<f32>() {
             Ok(v) if validator(v) => Ok(Validation::Valid),
             Ok(_) => Ok(Validation::Invalid(error_msg.clone().into())),
             Err(_) => Ok(Validation::Invalid("Must be a number".into())),
-        }),
+        }],
     );
 
     match result {
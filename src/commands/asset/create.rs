@@ -0,0 +1,368 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `am asset create`: creating an asset from a complete JSON document.
+//!
+//! Tools generating asset definitions elsewhere hand the whole body to the CLI, from a
+//! file or stdin, instead of creating a skeleton with the typed `create` subcommands and
+//! editing it afterwards. The body is validated like `am project validate` does before
+//! it's written.
+
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use serde_json::{Value, json};
+
+use crate::common::ignore::WalkOptions;
+use crate::common::project_option::project_dir;
+use crate::{
+    assets::{
+        Asset, AssetType, Collection, Effect, Event, ProjectContext, ProjectValidator, Sound,
+        Soundbank, Switch, SwitchContainer, graph::AssetKey,
+    },
+    common::{
+        errors::{CliError, asset_already_exists, codes},
+        files::{atomic_write, to_json_pretty},
+        utils::{read_amproject_file, validate_name},
+    },
+    database::Database,
+    input::Input,
+    presentation::{Output, OutputMode, insert_os_str},
+    schema::{cache::resolve_schemas, loader::load_schemas_from},
+};
+
+use super::deps::parse_asset_type;
+
+/// What to do when the asset to create already exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OnConflict {
+    /// Fail without writing anything.
+    Error,
+    /// Keep the existing asset and succeed.
+    Skip,
+    /// Replace the existing asset.
+    Overwrite,
+}
+
+/// Parse an `--on-conflict` value: `error`, `skip` or `overwrite`.
+pub(crate) fn parse_on_conflict(value: &str) -> Result<OnConflict> {
+    match value.trim().to_lowercase().as_str() {
+        "error" => Ok(OnConflict::Error),
+        "skip" => Ok(OnConflict::Skip),
+        "overwrite" => Ok(OnConflict::Overwrite),
+        _ => Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            format!("Invalid conflict policy: '{}'", value),
+            "The conflict policy must be one of: error, skip, overwrite",
+        )
+        .into()),
+    }
+}
+
+/// Create the asset `name` of type `asset_type` from the JSON document in `from_file`,
+/// or read from stdin when it's `None`.
+///
+/// The `name` field of the document must match `name`, unless `rename_body` is set, in
+/// which case it's replaced. The document is checked against the asset type, its
+/// business rules and, when the SDK schemas are available, the required fields of the
+/// schema.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn handle_create_command(
+    project: Option<&Path>,
+    walk: WalkOptions,
+    asset_type: &str,
+    name: &str,
+    from_file: Option<&Path>,
+    rename_body: bool,
+    on_conflict: &str,
+    database: Option<Arc<Database>>,
+    input: &dyn Input,
+    output: &dyn Output,
+) -> Result<()> {
+    let asset_type = parse_asset_type(asset_type)?;
+    let on_conflict = parse_on_conflict(on_conflict)?;
+    validate_name(name, false, "Asset").map_err(|why| {
+        CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            format!("Invalid asset name: '{}'", name),
+            why,
+        )
+    })?;
+    let key = AssetKey::new(asset_type, name);
+
+    let current_dir = project_dir(project)?;
+    let project_config = read_amproject_file(&current_dir)?;
+    let sources_dir = if project_config.sources_dir.is_empty() {
+        current_dir.clone()
+    } else {
+        current_dir.join(&project_config.sources_dir)
+    };
+    let asset_file = sources_dir
+        .join(project_config.asset_dir(asset_type))
+        .join(format!("{}.json", name));
+
+    let (content, source) = match from_file {
+        Some(path) => (
+            fs::read_to_string(path)
+                .with_context(|| format!("Failed to read {}", path.display()))?,
+            path.display().to_string(),
+        ),
+        None => (input.read_stdin()?, "stdin".to_string()),
+    };
+    let mut body = parse_body(&content, &source)?;
+    apply_name(&mut body, name, rename_body, &source)?;
+
+    output.progress(&format!(
+        "Creating {} '{}' in project '{}' from {}...",
+        key.type_key(),
+        name,
+        project_config.name,
+        source
+    ));
+
+    let validator = ProjectValidator::new(current_dir.clone(), walk, output)?;
+    let context = ProjectContext::new(current_dir.clone()).with_validator(validator);
+
+    let exists = asset_file.exists();
+    if exists || context.has_name(asset_type, name) {
+        match on_conflict {
+            OnConflict::Error => {
+                return Err(asset_already_exists(&asset_type.to_string(), name)
+                    .with_suggestion(
+                        "Use --on-conflict overwrite to replace it, or --on-conflict skip to keep it",
+                    )
+                    .into());
+            }
+            OnConflict::Skip => {
+                report(&key, &asset_file, None, "skipped", output);
+                return Ok(());
+            }
+            OnConflict::Overwrite if !exists => {
+                return Err(asset_already_exists(&asset_type.to_string(), name)
+                    .with_suggestion(format!(
+                        "The asset is defined in another file than {}: rename or remove it first",
+                        asset_file.display()
+                    ))
+                    .into());
+            }
+            OnConflict::Overwrite => {}
+        }
+    }
+
+    check_schema(asset_type, &body, database, output)?;
+    let (id, asset) = validate_body(asset_type, &body, &context)?;
+
+    // Overwriting an asset may keep its ID, any other asset may not share it.
+    let replaced_id = if exists {
+        fs::read_to_string(&asset_file)
+            .ok()
+            .and_then(|existing| context.parse_asset_json::<Value>(&existing).ok())
+            .and_then(|existing| existing["id"].as_u64())
+    } else {
+        None
+    };
+    if context.has_id(id) && replaced_id != Some(id) {
+        return Err(CliError::new(
+            codes::ERR_ASSET_ALREADY_EXISTS,
+            format!("ID {} is already used by another asset", id),
+            "Asset IDs must be unique across all the assets of a project",
+        )
+        .with_suggestion("Change the 'id' field of the document")
+        .into());
+    }
+
+    // Fields the asset type doesn't define aren't written.
+    if let (Some(given), Some(kept)) = (body.as_object(), asset.as_object()) {
+        for field in given.keys().filter(|field| !kept.contains_key(*field)) {
+            output.warning(&format!(
+                "Field '{}' is not part of a {} and was dropped",
+                field,
+                key.type_key()
+            ));
+        }
+    }
+
+    let json_content = to_json_pretty(&asset)?;
+    atomic_write(&asset_file, json_content.as_bytes())?;
+
+    let status = if exists { "overwritten" } else { "created" };
+    report(&key, &asset_file, Some(id), status, output);
+
+    Ok(())
+}
+
+/// Parse the asset document read from `source`, which must be a JSON object.
+fn parse_body(content: &str, source: &str) -> Result<Value, CliError> {
+    let body: Value = serde_json::from_str(content).map_err(|e| {
+        CliError::new(
+            codes::ERR_VALIDATION_SCHEMA,
+            format!("Invalid JSON in {}", source),
+            e.to_string(),
+        )
+        .with_suggestion("Provide the asset definition as a JSON object")
+    })?;
+
+    if !body.is_object() {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_SCHEMA,
+            format!("Invalid JSON in {}", source),
+            "Expected the asset definition as a JSON object",
+        )
+        .with_suggestion("Provide the asset definition as a JSON object"));
+    }
+
+    Ok(body)
+}
+
+/// Make the `name` field of `body` match `name`: set it when missing, and replace it
+/// when different only with `rename_body`.
+fn apply_name(body: &mut Value, name: &str, rename_body: bool, source: &str) -> Result<()> {
+    let Some(fields) = body.as_object_mut() else {
+        return Ok(());
+    };
+
+    match fields.get("name") {
+        Some(Value::String(given)) if given == name => {}
+        Some(Value::String(given)) if !rename_body => {
+            return Err(CliError::new(
+                codes::ERR_VALIDATION_FIELD,
+                format!("The name in {} doesn't match the asset name", source),
+                format!("The document is named '{}', not '{}'", given, name),
+            )
+            .with_suggestion("Fix the 'name' field, or use --rename-body to replace it")
+            .into());
+        }
+        _ => {
+            fields.insert("name".to_string(), json!(name));
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that `body` has the fields the SDK schema of `asset_type` requires.
+///
+/// Skipped when no schema is available, like the schema checks of `am project validate`.
+pub(super) fn check_schema(
+    asset_type: AssetType,
+    body: &Value,
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> Result<()> {
+    let Some(selection) = resolve_schemas(database) else {
+        return Ok(());
+    };
+    let Ok(registry) = load_schemas_from(&selection.schemas_dir, output) else {
+        return Ok(());
+    };
+    let Some(schema) = registry.get(asset_type) else {
+        return Ok(());
+    };
+
+    let Some(fields) = body.as_object() else {
+        return Ok(());
+    };
+    let missing: Vec<&str> = schema
+        .required_fields()
+        .into_iter()
+        .filter(|field| !fields.contains_key(*field))
+        .collect();
+    if !missing.is_empty() {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_SCHEMA,
+            format!(
+                "The document doesn't match the {} schema",
+                schema.table_name
+            ),
+            format!("Missing required field(s): {}", missing.join(", ")),
+        )
+        .with_context(schema.source_file.display().to_string())
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Check `body` against the asset type and its business rules.
+///
+/// Returns the ID of the asset and its definition as written by the CLI.
+pub(super) fn validate_body(
+    asset_type: AssetType,
+    body: &Value,
+    context: &ProjectContext,
+) -> Result<(u64, Value)> {
+    match asset_type {
+        AssetType::Sound => validate_typed::<Sound>(asset_type, body, context),
+        AssetType::Collection => validate_typed::<Collection>(asset_type, body, context),
+        AssetType::Effect => validate_typed::<Effect>(asset_type, body, context),
+        AssetType::Switch => validate_typed::<Switch>(asset_type, body, context),
+        AssetType::SwitchContainer => validate_typed::<SwitchContainer>(asset_type, body, context),
+        AssetType::Event => validate_typed::<Event>(asset_type, body, context),
+        AssetType::Soundbank => validate_typed::<Soundbank>(asset_type, body, context),
+    }
+}
+
+fn validate_typed<T: Asset>(
+    asset_type: AssetType,
+    body: &Value,
+    context: &ProjectContext,
+) -> Result<(u64, Value)> {
+    let asset: T = serde_json::from_value(body.clone()).map_err(|e| {
+        CliError::new(
+            codes::ERR_VALIDATION_SCHEMA,
+            format!("Invalid {} definition", asset_type),
+            e.to_string(),
+        )
+        .with_suggestion("Check that all required fields are present and correctly typed")
+    })?;
+    asset.validate_rules(context).map_err(|e| e.inner)?;
+
+    Ok((asset.id(), serde_json::to_value(&asset)?))
+}
+
+/// Report the outcome for the asset `key` written (or not) to `file`.
+fn report(key: &AssetKey, file: &Path, id: Option<u64>, status: &str, output: &dyn Output) {
+    match output.mode() {
+        OutputMode::Json => {
+            let mut value = json!({
+                "type": key.type_key(),
+                "name": key.name,
+                "id": id,
+                "status": status,
+            });
+            insert_os_str(&mut value, "path", file.as_os_str());
+            output.success(value, None);
+        }
+        OutputMode::Interactive => {
+            let message = match status {
+                "skipped" => format!(
+                    "{} '{}' already exists at {}, skipped",
+                    key.asset_type,
+                    key.name,
+                    file.display()
+                ),
+                _ => format!(
+                    "{} '{}' {} successfully at {}",
+                    key.asset_type,
+                    key.name,
+                    status,
+                    file.display()
+                ),
+            };
+            output.success(json!(message), None);
+        }
+    }
+}
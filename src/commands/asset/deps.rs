@@ -0,0 +1,157 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `am asset deps`: dependencies and dependents of an asset.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use anyhow::Result;
+use serde_json::json;
+
+use crate::common::ignore::WalkOptions;
+use crate::common::project_option::project_dir;
+use crate::{
+    assets::{
+        AssetType,
+        graph::{AssetKey, DependencyGraph},
+    },
+    common::errors::{CliError, asset_not_found, codes},
+    presentation::{Output, OutputMode},
+};
+
+/// Adjacency lists, from an asset to its neighbors.
+type AdjacencyLists<'a> = BTreeMap<&'a AssetKey, BTreeSet<&'a AssetKey>>;
+
+/// Parse an asset type given on the command line (`sound`, `switch_container`, ...).
+pub(crate) fn parse_asset_type(value: &str) -> Result<AssetType> {
+    serde_json::from_value(json!(value.trim().to_lowercase().replace('-', "_"))).map_err(|_| {
+        CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            format!("Invalid asset type: '{}'", value),
+            "Asset type must be one of: sound, collection, effect, switch, switch_container, event, soundbank",
+        )
+        .into()
+    })
+}
+
+/// Parse an asset given as `<type>/<name>`, e.g. `event/play_footstep`.
+pub(crate) fn parse_asset_key(value: &str) -> Result<AssetKey> {
+    let Some((asset_type, name)) = value.split_once('/') else {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            format!("Invalid asset: '{}'", value),
+            "Assets are given as <type>/<name>",
+        )
+        .with_suggestion("Use for example 'event/play_footstep'")
+        .into());
+    };
+
+    Ok(AssetKey::new(parse_asset_type(asset_type)?, name))
+}
+
+/// Show the direct (or, with `transitive`, all) dependencies and dependents of an asset.
+pub(crate) async fn handle_deps_command(
+    project: Option<&Path>,
+    walk: WalkOptions,
+    asset_type: &str,
+    name: &str,
+    transitive: bool,
+    output: &dyn Output,
+) -> Result<()> {
+    let asset_type = parse_asset_type(asset_type)?;
+    let current_dir = project_dir(project)?;
+    let graph = DependencyGraph::build(&current_dir, walk, output)?;
+
+    let key = AssetKey::new(asset_type, name);
+    let Some(node) = graph.node(&key) else {
+        return Err(asset_not_found(&asset_type.to_string(), name).into());
+    };
+
+    let dependencies = graph.dependency_lists(&key, transitive);
+    let dependents = graph.dependent_lists(&key, transitive);
+
+    match output.mode() {
+        OutputMode::Json => {
+            let nodes: BTreeMap<String, _> = dependencies
+                .keys()
+                .chain(dependents.keys())
+                .chain(dependencies.values().flatten())
+                .chain(dependents.values().flatten())
+                .filter_map(|k| graph.node(k))
+                .map(|n| (n.key().to_string(), n))
+                .collect();
+
+            output.success(
+                json!({
+                    "asset": node,
+                    "transitive": transitive,
+                    "nodes": nodes,
+                    "dependencies": dependencies,
+                    "dependents": dependents,
+                }),
+                None,
+            );
+        }
+        OutputMode::Interactive => {
+            output.print(&format!("{} '{}' ({})", asset_type, name, node.path));
+            output.print("Dependencies:");
+            print_tree(&dependencies, &key, output);
+            output.print("Dependents:");
+            print_tree(&dependents, &key, output);
+        }
+    }
+
+    Ok(())
+}
+
+/// Print the neighbors of `root` as an indented tree, expanding every asset that has
+/// adjacency lists of its own.
+fn print_tree<'a>(lists: &AdjacencyLists<'a>, root: &'a AssetKey, output: &dyn Output) {
+    let children = lists.get(root).cloned().unwrap_or_default();
+    if children.is_empty() {
+        output.print("  (none)");
+        return;
+    }
+
+    let mut path = vec![root];
+    for child in children {
+        print_subtree(lists, child, 1, &mut path, output);
+    }
+}
+
+fn print_subtree<'a>(
+    lists: &AdjacencyLists<'a>,
+    key: &'a AssetKey,
+    depth: usize,
+    path: &mut Vec<&'a AssetKey>,
+    output: &dyn Output,
+) {
+    let indent = "  ".repeat(depth);
+    if path.contains(&key) {
+        output.print(&format!(
+            "{}{} '{}' (cycle)",
+            indent, key.asset_type, key.name
+        ));
+        return;
+    }
+
+    output.print(&format!("{}{} '{}'", indent, key.asset_type, key.name));
+
+    path.push(key);
+    for child in lists.get(key).into_iter().flatten() {
+        print_subtree(lists, child, depth + 1, path, output);
+    }
+    path.pop();
+}
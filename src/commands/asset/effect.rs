@@ -16,8 +16,8 @@
 //!
 //! Implements CRUD operations for Effect assets in Amplitude projects.
 
-use std::env;
 use std::fs;
+use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::{Context, Result};
@@ -26,20 +26,26 @@ use colored::Colorize;
 use inquire::validator::Validation;
 use serde_json::json;
 
+use crate::common::ignore::WalkOptions;
+use crate::common::project_option::project_dir;
 use crate::common::utils::generate_unique_id;
 use crate::{
-    assets::{Asset, Effect, ProjectContext, ProjectValidator, RtpcCompatibleValue},
+    assets::{
+        Asset, AssetType, Effect, ProjectContext, ProjectValidator, RtpcCompatibleValue,
+        graph::AssetKey,
+    },
     common::{
         errors::{CliError, asset_already_exists, asset_not_found, codes},
-        files::atomic_write,
+        files::{atomic_write, safe_remove_within, to_json_pretty},
+        fs_ctx,
         utils::read_amproject_file,
     },
     database::Database,
     input::Input,
-    presentation::{Output, OutputMode},
+    presentation::{ListLimit, Output, OutputMode, insert_os_str},
 };
 
-use super::find_json_files_recursive;
+use super::{ensure_not_referenced, find_json_files_recursive};
 
 /// The name of the current asset.
 const ASSET_NAME: &str = "Effect";
@@ -51,10 +57,6 @@ const MAX_ID_RETRIES: u32 = 3;
 #[derive(Subcommand, Debug)]
 pub enum EffectCommands {
     /// Create a new effect asset
-    #[command(after_help = "Examples:
-  am asset effect create reverb
-  am asset effect create eq --effect-type equalizer
-")]
     Create {
         /// Name of the effect asset
         name: String,
@@ -69,16 +71,12 @@ pub enum EffectCommands {
     },
 
     /// List all effect assets in the project
-    #[command(after_help = "Examples:
-  am asset effect list
-  am asset effect list --json
-")]
-    List {},
+    List {
+        #[command(flatten)]
+        limit: ListLimit,
+    },
 
     /// Update an existing effect asset
-    #[command(after_help = "Examples:
-  am asset effect update reverb --effect-type hall_reverb
-")]
     Update {
         /// Name of the effect asset to update
         name: String,
@@ -93,10 +91,6 @@ pub enum EffectCommands {
     },
 
     /// Delete an effect asset
-    #[command(after_help = "Examples:
-  am asset effect delete reverb
-  am asset effect delete reverb --force
-")]
     Delete {
         /// Name of the effect asset to delete
         name: String,
@@ -110,6 +104,8 @@ pub enum EffectCommands {
 /// Handle effect commands by routing to the appropriate handler.
 pub async fn handler(
     command: &EffectCommands,
+    project: Option<&Path>,
+    walk: WalkOptions,
     _database: Option<Arc<Database>>,
     input: &dyn Input,
     output: &dyn Output,
@@ -119,14 +115,38 @@ pub async fn handler(
             name,
             effect_type,
             param,
-        } => create_effect(name, effect_type.clone(), param.clone(), input, output).await,
-        EffectCommands::List {} => list_effects(output).await,
+        } => {
+            create_effect(
+                project,
+                walk,
+                name,
+                effect_type.clone(),
+                param.clone(),
+                input,
+                output,
+            )
+            .await
+        }
+        EffectCommands::List { limit } => list_effects(project, walk, limit, output).await,
         EffectCommands::Update {
             name,
             effect_type,
             param,
-        } => update_effect(name, effect_type.clone(), param.clone(), input, output).await,
-        EffectCommands::Delete { name, force } => delete_effect(name, *force, input, output).await,
+        } => {
+            update_effect(
+                project,
+                walk,
+                name,
+                effect_type.clone(),
+                param.clone(),
+                input,
+                output,
+            )
+            .await
+        }
+        EffectCommands::Delete { name, force } => {
+            delete_effect(project, walk, name, *force, input, output).await
+        }
     }
 }
 
@@ -136,6 +156,8 @@ pub async fn handler(
 
 /// Create a new effect asset.
 async fn create_effect(
+    project: Option<&Path>,
+    walk: WalkOptions,
     name: &str,
     effect_type: Option<String>,
     param: Option<Vec<f32>>,
@@ -163,7 +185,7 @@ async fn create_effect(
     }
 
     // Step 2: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     output.progress(&format!(
@@ -177,7 +199,7 @@ async fn create_effect(
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let effects_dir = sources_base.join("effects");
+    let effects_dir = sources_base.join(project_config.asset_dir(AssetType::Effect));
     let effect_file_path = effects_dir.join(format!("{}.json", name));
 
     if effect_file_path.exists() {
@@ -190,11 +212,11 @@ async fn create_effect(
     }
 
     // Build populated ProjectContext for validation
-    let validator = ProjectValidator::new(current_dir.clone(), output)?;
+    let validator = ProjectValidator::new(current_dir.clone(), walk, output)?;
     let context = ProjectContext::new(current_dir.clone()).with_validator(validator);
 
     // Check name uniqueness via ProjectContext registry
-    if context.has_name(crate::assets::AssetType::Effect, name) {
+    if context.has_name(AssetType::Effect, name) {
         return Err(asset_already_exists(ASSET_NAME, name)
             .with_suggestion(format!(
                 "Use 'am asset effect update {}' to modify it, or choose a different name",
@@ -272,25 +294,22 @@ async fn create_effect(
     effect.validate_rules(&context)?;
 
     // Step 9: Serialize to JSON
-    let json_content =
-        serde_json::to_string_pretty(&effect).context("Failed to serialize effect to JSON")?;
+    let json_content = to_json_pretty(&effect).context("Failed to serialize effect to JSON")?;
 
     // Step 10: Ensure directory exists and write atomically
-    fs::create_dir_all(&effects_dir)?;
+    fs_ctx::create_dir_all(&effects_dir)?;
     atomic_write(&effect_file_path, json_content.as_bytes())?;
 
     // Step 11: Output success
     match output.mode() {
         OutputMode::Json => {
-            output.success(
-                json!({
-                    "id": effect.id,
-                    "name": effect.name(),
-                    "path": effect_file_path.to_string_lossy(),
-                    "effect_type": effect.effect,
-                }),
-                None,
-            );
+            let mut value = json!({
+                "id": effect.id,
+                "name": effect.name(),
+                "effect_type": effect.effect,
+            });
+            insert_os_str(&mut value, "path", effect_file_path.as_os_str());
+            output.success(value, None);
         }
         OutputMode::Interactive => {
             output.success(
@@ -312,9 +331,14 @@ async fn create_effect(
 // =============================================================================
 
 /// List all effect assets in the current project.
-async fn list_effects(output: &dyn Output) -> Result<()> {
+async fn list_effects(
+    project: Option<&Path>,
+    walk: WalkOptions,
+    limit: &ListLimit,
+    output: &dyn Output,
+) -> Result<()> {
     // Step 1: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     // Step 2: Scan effects directory using sources_dir from config
@@ -323,7 +347,7 @@ async fn list_effects(output: &dyn Output) -> Result<()> {
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let effects_dir = sources_base.join("effects");
+    let effects_dir = sources_base.join(project_config.asset_dir(AssetType::Effect));
 
     // Step 3: Handle missing directory
     if !effects_dir.exists() {
@@ -353,7 +377,7 @@ async fn list_effects(output: &dyn Output) -> Result<()> {
     let mut effects: Vec<Effect> = Vec::new();
     let mut warnings: Vec<String> = Vec::new();
 
-    let json_files = match find_json_files_recursive(&effects_dir) {
+    let json_files = match find_json_files_recursive(&current_dir, &effects_dir, walk, output) {
         Ok(files) => files,
         Err(e) => {
             return Err(CliError::new(
@@ -370,7 +394,7 @@ async fn list_effects(output: &dyn Output) -> Result<()> {
     for path in json_files {
         if path.extension().map(|e| e == "json").unwrap_or(false) {
             match fs::read_to_string(&path) {
-                Ok(content) => match serde_json::from_str::<Effect>(&content) {
+                Ok(content) => match project_config.parse_asset_json::<Effect>(&content) {
                     Ok(effect) => {
                         effects.push(effect);
                     }
@@ -407,6 +431,7 @@ async fn list_effects(output: &dyn Output) -> Result<()> {
 
     // Step 5: Sort by name
     effects.sort_by(|a, b| a.name().cmp(b.name()));
+    let truncation = limit.apply(&mut effects);
 
     // Step 6: Handle empty
     if effects.is_empty() {
@@ -453,14 +478,13 @@ async fn list_effects(output: &dyn Output) -> Result<()> {
                 })
                 .collect();
 
-            output.success(
-                json!({
-                    "effects": effect_data,
-                    "count": effects.len(),
-                    "warnings": warnings
-                }),
-                None,
-            );
+            let mut payload = json!({
+                "effects": effect_data,
+                "count": effects.len(),
+                "warnings": warnings
+            });
+            truncation.annotate(&mut payload);
+            output.success(payload, None);
         }
         OutputMode::Interactive => {
             for warning in &warnings {
@@ -480,7 +504,8 @@ async fn list_effects(output: &dyn Output) -> Result<()> {
 
             output.table(None, json!(table_data));
             output.progress("");
-            output.progress(&format!("{} effect(s) found", effects.len()));
+            output.progress(&format!("{} effect(s) found", truncation.total));
+            truncation.notify("effects", output);
         }
     }
 
@@ -493,6 +518,8 @@ async fn list_effects(output: &dyn Output) -> Result<()> {
 
 /// Update an existing effect asset.
 async fn update_effect(
+    project: Option<&Path>,
+    walk: WalkOptions,
     name: &str,
     effect_type: Option<String>,
     param: Option<Vec<f32>>,
@@ -500,7 +527,7 @@ async fn update_effect(
     output: &dyn Output,
 ) -> Result<()> {
     // Step 1: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     output.progress(&format!(
@@ -514,7 +541,7 @@ async fn update_effect(
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let effects_dir = sources_base.join("effects");
+    let effects_dir = sources_base.join(project_config.asset_dir(AssetType::Effect));
     let effect_file_path = effects_dir.join(format!("{}.json", name));
 
     if !effect_file_path.exists() {
@@ -531,7 +558,7 @@ async fn update_effect(
         "Failed to read effect file: {}",
         effect_file_path.display()
     ))?;
-    let mut effect: Effect = serde_json::from_str(&content).context(format!(
+    let mut effect: Effect = project_config.parse_asset_json(&content).context(format!(
         "Failed to parse effect file: {}",
         effect_file_path.display()
     ))?;
@@ -547,27 +574,24 @@ async fn update_effect(
     };
 
     // Step 6: Validate
-    let validator = ProjectValidator::new(current_dir.clone(), output)?;
+    let validator = ProjectValidator::new(current_dir.clone(), walk, output)?;
     let context = ProjectContext::new(current_dir.clone()).with_validator(validator);
     effect.validate_rules(&context)?;
 
     // Step 7: Serialize and write atomically
-    let json_content =
-        serde_json::to_string_pretty(&effect).context("Failed to serialize effect to JSON")?;
+    let json_content = to_json_pretty(&effect).context("Failed to serialize effect to JSON")?;
     atomic_write(&effect_file_path, json_content.as_bytes())?;
 
     // Step 8: Output success
     match output.mode() {
         OutputMode::Json => {
-            output.success(
-                json!({
-                    "id": effect.id,
-                    "name": effect.name(),
-                    "path": effect_file_path.to_string_lossy(),
-                    "updated_fields": updated_fields,
-                }),
-                None,
-            );
+            let mut value = json!({
+                "id": effect.id,
+                "name": effect.name(),
+                "updated_fields": updated_fields,
+            });
+            insert_os_str(&mut value, "path", effect_file_path.as_os_str());
+            output.success(value, None);
         }
         OutputMode::Interactive => {
             output.success(
@@ -642,7 +666,7 @@ fn prompt_effect_updates(effect: &mut Effect, input: &dyn Input) -> Result<Vec<S
             &current_effect_type
         }),
         None,
-        None,
+        &[],
     ) {
         let trimmed = value.trim().to_string();
         if trimmed != current_effect_type {
@@ -689,7 +713,7 @@ fn prompt_effect_updates(effect: &mut Effect, input: &dyn Input) -> Result<Vec<S
             &current_params_str
         }),
         None,
-        Some(&|value: &str| {
+        &[&|value: &str| {
             if value.trim().is_empty() {
                 return Ok(Validation::Valid);
             }
@@ -701,7 +725,7 @@ fn prompt_effect_updates(effect: &mut Effect, input: &dyn Input) -> Result<Vec<S
                 }
             }
             Ok(Validation::Valid)
-        }),
+        }],
     ) {
         let trimmed = value.trim().to_string();
         if trimmed != current_params_str {
@@ -737,13 +761,15 @@ fn prompt_effect_updates(effect: &mut Effect, input: &dyn Input) -> Result<Vec<S
 
 /// Delete an effect asset.
 async fn delete_effect(
+    project: Option<&Path>,
+    walk: WalkOptions,
     name: &str,
     force: bool,
     input: &dyn Input,
     output: &dyn Output,
 ) -> Result<()> {
     // Step 1: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     // Step 2: Locate effect file
@@ -752,7 +778,7 @@ async fn delete_effect(
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let effects_dir = sources_base.join("effects");
+    let effects_dir = sources_base.join(project_config.asset_dir(AssetType::Effect));
     let effect_file_path = effects_dir.join(format!("{}.json", name));
 
     if !effect_file_path.exists() {
@@ -766,12 +792,20 @@ async fn delete_effect(
         "Failed to read effect file: {}",
         effect_file_path.display()
     ))?;
-    let effect: Effect = serde_json::from_str(&content).context(format!(
+    let effect: Effect = project_config.parse_asset_json(&content).context(format!(
         "Failed to parse effect file: {}",
         effect_file_path.display()
     ))?;
 
-    // Step 4: Confirm deletion
+    // Step 4: Refuse to delete an effect other assets reference
+    ensure_not_referenced(
+        &current_dir,
+        walk,
+        &AssetKey::new(AssetType::Effect, effect.name()),
+        output,
+    )?;
+
+    // Step 5: Confirm deletion
     let confirmed = if force {
         true
     } else {
@@ -801,13 +835,13 @@ async fn delete_effect(
         return Ok(());
     }
 
-    // Step 5: Remove file
-    fs::remove_file(&effect_file_path).context(format!(
+    // Step 6: Remove file
+    safe_remove_within(&current_dir, &effect_file_path).context(format!(
         "Failed to delete effect file: {}",
         effect_file_path.display()
     ))?;
 
-    // Step 6: Output success
+    // Step 7: Output success
     match output.mode() {
         OutputMode::Json => {
             output.success(
@@ -838,7 +872,7 @@ async fn delete_effect(
 ///
 /// In non-interactive mode, defaults to None (per AC2: sensible defaults).
 fn prompt_effect_type(input: &dyn Input) -> Result<Option<String>> {
-    let result = input.prompt_text("Effect type (e.g., reverb, eq)", Some("reverb"), None, None);
+    let result = input.prompt_text("Effect type (e.g., reverb, eq)", Some("reverb"), None, &[]);
 
     match result {
         Ok(value) => {
@@ -864,7 +898,7 @@ fn prompt_parameters(input: &dyn Input) -> Result<Option<Vec<RtpcCompatibleValue
         "Parameter values (comma-separated floats)",
         Some("0.8, 0.5"),
         None,
-        Some(&|value: &str| {
+        &[&|value: &str| {
             if value.trim().is_empty() {
                 return Ok(Validation::Valid);
             }
@@ -884,7 +918,7 @@ fn prompt_parameters(input: &dyn Input) -> Result<Option<Vec<RtpcCompatibleValue
                 }
             }
             Ok(Validation::Valid)
-        }),
+        }],
     );
 
     match result {
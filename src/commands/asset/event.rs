@@ -17,8 +17,8 @@
 //! Implements CRUD operations for Event assets in Amplitude projects.
 //! Events are triggerable audio actions that can be called from game code.
 
-use std::env;
 use std::fs;
+use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::{Context, Result};
@@ -27,23 +27,28 @@ use colored::Colorize;
 use inquire::validator::Validation;
 use serde_json::json;
 
+use crate::common::ignore::WalkOptions;
+use crate::common::project_option::project_dir;
 use crate::common::utils::generate_unique_id;
 use crate::{
     assets::{
         Asset, AssetType, Event, ProjectContext, ProjectValidator, Scope,
-        generated::{EventActionDefinition, EventActionRunningMode, EventActionType},
+        generated::{
+            EventActionDefinition, EventActionRunningMode, EventActionType, graph::AssetKey,
+        },
     },
     common::{
         errors::{CliError, asset_already_exists, asset_not_found, codes},
-        files::atomic_write,
+        files::{atomic_write, safe_remove_within, to_json_pretty},
+        fs_ctx,
         utils::{read_amproject_file, truncate_string},
     },
     database::Database,
     input::{Input, select_index},
-    presentation::{Output, OutputMode},
+    presentation::{ListLimit, Output, OutputMode, insert_os_str},
 };
 
-use super::find_json_files_recursive;
+use super::{ensure_not_referenced, find_json_files_recursive};
 
 /// The name of the current asset.
 const ASSET_NAME: &str = "Event";
@@ -52,9 +57,6 @@ const ASSET_NAME: &str = "Event";
 #[derive(Subcommand, Debug)]
 pub enum EventCommands {
     /// Create a new event asset
-    #[command(
-        after_help = "Examples:\n  am asset event create play_music\n  am asset event create play_music --action play:12345\n"
-    )]
     Create {
         /// Name of the event asset
         name: String,
@@ -69,13 +71,12 @@ pub enum EventCommands {
     },
 
     /// List all event assets in the project
-    #[command(after_help = "Examples:\n  am asset event list\n")]
-    List {},
+    List {
+        #[command(flatten)]
+        limit: ListLimit,
+    },
 
     /// Update an existing event asset
-    #[command(
-        after_help = "Examples:\n  am asset event update play_music\n  am asset event update play_music --run-mode sequential\n"
-    )]
     Update {
         /// Name of the event asset to update
         name: String,
@@ -98,9 +99,6 @@ pub enum EventCommands {
     },
 
     /// Delete an event asset
-    #[command(
-        after_help = "Examples:\n  am asset event delete play_music --yes\n  am asset event delete play_music --yes --force\n"
-    )]
     Delete {
         /// Name of the event asset to delete
         name: String,
@@ -109,7 +107,7 @@ pub enum EventCommands {
         #[arg(short, long)]
         yes: bool,
 
-        /// Force deletion even if referenced by soundbanks
+        /// Force deletion even if referenced by other assets
         #[arg(short, long)]
         force: bool,
     },
@@ -118,6 +116,8 @@ pub enum EventCommands {
 /// Handle event commands by routing to the appropriate handler.
 pub async fn handler(
     command: &EventCommands,
+    project: Option<&Path>,
+    walk: WalkOptions,
     _database: Option<Arc<Database>>,
     input: &dyn Input,
     output: &dyn Output,
@@ -127,8 +127,19 @@ pub async fn handler(
             name,
             run_mode,
             action,
-        } => create_event(name, run_mode.clone(), action.clone(), input, output).await,
-        EventCommands::List {} => list_events(output).await,
+        } => {
+            create_event(
+                project,
+                walk,
+                name,
+                run_mode.clone(),
+                action.clone(),
+                input,
+                output,
+            )
+            .await
+        }
+        EventCommands::List { limit } => list_events(project, walk, limit, output).await,
         EventCommands::Update {
             name,
             run_mode,
@@ -137,6 +148,8 @@ pub async fn handler(
             clear_actions,
         } => {
             update_event(
+                project,
+                walk,
                 name,
                 run_mode.clone(),
                 add_action.clone(),
@@ -148,7 +161,7 @@ pub async fn handler(
             .await
         }
         EventCommands::Delete { name, yes, force } => {
-            delete_event(name, *yes, *force, input, output).await
+            delete_event(project, walk, name, *yes, *force, input, output).await
         }
     }
 }
@@ -239,6 +252,8 @@ fn format_run_mode(mode: &EventActionRunningMode) -> &'static str {
 
 /// Create a new event asset.
 async fn create_event(
+    project: Option<&Path>,
+    walk: WalkOptions,
     name: &str,
     run_mode: Option<String>,
     actions: Vec<String>,
@@ -246,7 +261,7 @@ async fn create_event(
     output: &dyn Output,
 ) -> Result<()> {
     // Step 1: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     output.progress(&format!(
@@ -260,7 +275,7 @@ async fn create_event(
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let events_dir = sources_base.join("events");
+    let events_dir = sources_base.join(project_config.asset_dir(AssetType::Event));
     let event_file_path = events_dir.join(format!("{}.json", name));
 
     if event_file_path.exists() {
@@ -273,7 +288,7 @@ async fn create_event(
     }
 
     // Build populated ProjectContext for validation
-    let validator = ProjectValidator::new(current_dir.clone(), output)?;
+    let validator = ProjectValidator::new(current_dir.clone(), walk, output)?;
     let context = ProjectContext::new(current_dir.clone()).with_validator(validator);
 
     // Check name uniqueness
@@ -338,26 +353,23 @@ async fn create_event(
     event.validate_rules(&context)?;
 
     // Step 8: Serialize to JSON
-    let json_content =
-        serde_json::to_string_pretty(&event).context("Failed to serialize event to JSON")?;
+    let json_content = to_json_pretty(&event).context("Failed to serialize event to JSON")?;
 
     // Step 9: Write using atomic write pattern
-    fs::create_dir_all(&events_dir)?;
+    fs_ctx::create_dir_all(&events_dir)?;
     atomic_write(&event_file_path, json_content.as_bytes())?;
 
     // Step 10: Output success
     match output.mode() {
         OutputMode::Json => {
-            output.success(
-                json!({
-                    "id": event.id,
-                    "name": event.name(),
-                    "path": event_file_path.to_string_lossy(),
-                    "action_count": event.actions.as_ref().map(|a| a.len()).unwrap_or(0),
-                    "run_mode": format_run_mode(&event.run_mode),
-                }),
-                None,
-            );
+            let mut value = json!({
+                "id": event.id,
+                "name": event.name(),
+                "action_count": event.actions.as_ref().map(|a| a.len()).unwrap_or(0),
+                "run_mode": format_run_mode(&event.run_mode),
+            });
+            insert_os_str(&mut value, "path", event_file_path.as_os_str());
+            output.success(value, None);
         }
         OutputMode::Interactive => {
             output.success(
@@ -543,7 +555,7 @@ fn prompt_actions_interactive(
             "Target asset IDs (comma-separated)",
             None,
             None,
-            Some(&|value: &str| {
+            &[&|value: &str| {
                 if value.trim().is_empty() {
                     return Ok(Validation::Invalid(
                         "At least one target ID is required".into(),
@@ -557,7 +569,7 @@ fn prompt_actions_interactive(
                     }
                 }
                 Ok(Validation::Valid)
-            }),
+            }],
         ) {
             Ok(val) => val,
             Err(_) => break,
@@ -600,9 +612,14 @@ fn prompt_actions_interactive(
 const PATH_MAX_LENGTH: usize = 40;
 
 /// List all event assets in the current project.
-async fn list_events(output: &dyn Output) -> Result<()> {
+async fn list_events(
+    project: Option<&Path>,
+    walk: WalkOptions,
+    limit: &ListLimit,
+    output: &dyn Output,
+) -> Result<()> {
     // Step 1: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     // Step 2: Scan events directory
@@ -611,7 +628,7 @@ async fn list_events(output: &dyn Output) -> Result<()> {
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let events_dir = sources_base.join("events");
+    let events_dir = sources_base.join(project_config.asset_dir(AssetType::Event));
 
     // Step 3: Handle missing or unreadable directory
     if !events_dir.exists() {
@@ -641,7 +658,7 @@ async fn list_events(output: &dyn Output) -> Result<()> {
     let mut events: Vec<Event> = Vec::new();
     let mut warnings: Vec<String> = Vec::new();
 
-    let json_files = match find_json_files_recursive(&events_dir) {
+    let json_files = match find_json_files_recursive(&current_dir, &events_dir, walk, output) {
         Ok(files) => files,
         Err(e) => {
             return Err(CliError::new(
@@ -658,7 +675,7 @@ async fn list_events(output: &dyn Output) -> Result<()> {
     for path in json_files {
         if path.extension().map(|e| e == "json").unwrap_or(false) {
             match fs::read_to_string(&path) {
-                Ok(content) => match serde_json::from_str::<Event>(&content) {
+                Ok(content) => match project_config.parse_asset_json::<Event>(&content) {
                     Ok(event) => {
                         events.push(event);
                     }
@@ -694,6 +711,7 @@ async fn list_events(output: &dyn Output) -> Result<()> {
 
     // Step 5: Sort by name for consistent output
     events.sort_by(|a, b| a.name().cmp(b.name()));
+    let truncation = limit.apply(&mut events);
 
     // Step 6: Handle empty directory
     if events.is_empty() {
@@ -726,7 +744,7 @@ async fn list_events(output: &dyn Output) -> Result<()> {
     }
 
     // Build validator for resolving target names
-    let validator = ProjectValidator::new(current_dir, output).ok();
+    let validator = ProjectValidator::new(current_dir, walk, output).ok();
 
     // Step 7: Output based on mode
     match output.mode() {
@@ -753,14 +771,13 @@ async fn list_events(output: &dyn Output) -> Result<()> {
                 })
                 .collect();
 
-            output.success(
-                json!({
-                    "events": event_data,
-                    "count": events.len(),
-                    "warnings": warnings
-                }),
-                None,
-            );
+            let mut payload = json!({
+                "events": event_data,
+                "count": events.len(),
+                "warnings": warnings
+            });
+            truncation.annotate(&mut payload);
+            output.success(payload, None);
         }
         OutputMode::Interactive => {
             for warning in &warnings {
@@ -800,7 +817,8 @@ async fn list_events(output: &dyn Output) -> Result<()> {
 
             output.table(None, json!(table_data));
             output.progress("");
-            output.progress(&format!("{} event(s) found", events.len()));
+            output.progress(&format!("{} event(s) found", truncation.total));
+            truncation.notify("events", output);
         }
     }
 
@@ -808,7 +826,10 @@ async fn list_events(output: &dyn Output) -> Result<()> {
 }
 
 /// Update an existing event asset.
+#[allow(clippy::too_many_arguments)]
 async fn update_event(
+    project: Option<&Path>,
+    walk: WalkOptions,
     name: &str,
     run_mode: Option<String>,
     add_actions: Vec<String>,
@@ -818,7 +839,7 @@ async fn update_event(
     output: &dyn Output,
 ) -> Result<()> {
     // Step 1: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     output.progress(&format!(
@@ -832,7 +853,7 @@ async fn update_event(
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let events_dir = sources_base.join("events");
+    let events_dir = sources_base.join(project_config.asset_dir(AssetType::Event));
     let event_file_path = events_dir.join(format!("{}.json", name));
 
     if !event_file_path.exists() {
@@ -849,13 +870,13 @@ async fn update_event(
         "Failed to read event file: {}",
         event_file_path.display()
     ))?;
-    let mut event: Event = serde_json::from_str(&content).context(format!(
+    let mut event: Event = project_config.parse_asset_json(&content).context(format!(
         "Failed to parse event file: {}",
         event_file_path.display()
     ))?;
 
     // Step 4: Build context for validation
-    let validator = ProjectValidator::new(current_dir.clone(), output)?;
+    let validator = ProjectValidator::new(current_dir.clone(), walk, output)?;
     let context = ProjectContext::new(current_dir.clone()).with_validator(validator);
 
     // Step 5: Track updated fields
@@ -917,23 +938,20 @@ async fn update_event(
     event.validate_rules(&context)?;
 
     // Step 7: Serialize and write atomically
-    let json_content =
-        serde_json::to_string_pretty(&event).context("Failed to serialize event to JSON")?;
+    let json_content = to_json_pretty(&event).context("Failed to serialize event to JSON")?;
     atomic_write(&event_file_path, json_content.as_bytes())?;
 
     // Step 8: Output success
     match output.mode() {
         OutputMode::Json => {
-            output.success(
-                json!({
-                    "id": event.id,
-                    "name": event.name(),
-                    "path": event_file_path.to_string_lossy(),
-                    "action_count": event.actions.as_ref().map(|a| a.len()).unwrap_or(0),
-                    "updated_fields": updated_fields,
-                }),
-                None,
-            );
+            let mut value = json!({
+                "id": event.id,
+                "name": event.name(),
+                "action_count": event.actions.as_ref().map(|a| a.len()).unwrap_or(0),
+                "updated_fields": updated_fields,
+            });
+            insert_os_str(&mut value, "path", event_file_path.as_os_str());
+            output.success(value, None);
         }
         OutputMode::Interactive => {
             output.success(
@@ -1050,7 +1068,7 @@ fn prompt_modify_actions(
                     "Target asset IDs (comma-separated)",
                     None,
                     None,
-                    Some(&|value: &str| {
+                    &[&|value: &str| {
                         if value.trim().is_empty() {
                             return Ok(Validation::Invalid(
                                 "At least one target ID is required".into(),
@@ -1064,7 +1082,7 @@ fn prompt_modify_actions(
                             }
                         }
                         Ok(Validation::Valid)
-                    }),
+                    }],
                 ) {
                     Ok(val) => val,
                     Err(_) => continue,
@@ -1124,6 +1142,8 @@ fn prompt_modify_actions(
 
 /// Delete an event asset.
 async fn delete_event(
+    project: Option<&Path>,
+    walk: WalkOptions,
     name: &str,
     yes: bool,
     force: bool,
@@ -1131,7 +1151,7 @@ async fn delete_event(
     output: &dyn Output,
 ) -> Result<()> {
     // Step 1: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     // Step 2: Locate event file
@@ -1140,7 +1160,7 @@ async fn delete_event(
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let events_dir = sources_base.join("events");
+    let events_dir = sources_base.join(project_config.asset_dir(AssetType::Event));
     let event_file_path = events_dir.join(format!("{}.json", name));
 
     if !event_file_path.exists() {
@@ -1151,15 +1171,17 @@ async fn delete_event(
 
     // Step 3: Parse event to get details
     let content = fs::read_to_string(&event_file_path)?;
-    let event: Event = serde_json::from_str(&content)?;
-
-    // Step 4: Check for dependencies (soundbanks that include this event)
-    let validator = ProjectValidator::new(current_dir.clone(), output)?;
-    let dependent_soundbanks: Vec<String> = validator
-        .asset_ids
-        .get(&AssetType::Soundbank)
-        .map(|ids| ids.iter().map(|id| id.to_string()).collect())
-        .unwrap_or_default();
+    let event: Event = project_config.parse_asset_json(&content)?;
+
+    // Step 4: Refuse to delete an event other assets reference, unless forced
+    if !force {
+        ensure_not_referenced(
+            &current_dir,
+            walk,
+            &AssetKey::new(AssetType::Event, event.name()),
+            output,
+        )?;
+    }
 
     // Step 5: Confirmation prompt
     if !yes {
@@ -1175,15 +1197,6 @@ async fn delete_event(
         ));
         output.print(&format!("  File: {}\n", event_file_path.display()));
 
-        if !dependent_soundbanks.is_empty() && !force {
-            output.print(&format!(
-                "{} This event is included in {} soundbank(s).",
-                "⚠".yellow(),
-                dependent_soundbanks.len()
-            ));
-            output.print("  Use --force to delete anyway.\n");
-        }
-
         let confirmed =
             match input.confirm("Are you sure you want to delete this event?", Some(false)) {
                 Ok(val) => val,
@@ -1205,7 +1218,7 @@ async fn delete_event(
     }
 
     // Step 6: Delete the file
-    fs::remove_file(&event_file_path)?;
+    safe_remove_within(&current_dir, &event_file_path)?;
 
     // Step 7: Output success
     match output.mode() {
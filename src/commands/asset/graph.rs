@@ -0,0 +1,109 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `am asset graph`: export of the project dependency graph.
+
+use std::path::Path;
+
+use anyhow::Result;
+use serde_json::json;
+
+use crate::common::ignore::WalkOptions;
+use crate::common::project_option::project_dir;
+use crate::{
+    assets::graph::DependencyGraph,
+    common::errors::{CliError, asset_not_found, codes},
+    presentation::{Output, OutputMode},
+};
+
+use super::deps::parse_asset_key;
+
+/// Text formats the graph can be exported to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GraphFormat {
+    Dot,
+    Mermaid,
+}
+
+fn parse_format(value: &str) -> Result<GraphFormat> {
+    match value.to_lowercase().as_str() {
+        "dot" => Ok(GraphFormat::Dot),
+        "mermaid" => Ok(GraphFormat::Mermaid),
+        _ => Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            format!("Invalid graph format: '{}'", value),
+            "Graph format must be one of: dot, mermaid",
+        )
+        .into()),
+    }
+}
+
+/// Print the dependency graph of the whole project, or with `focus` of the neighborhood
+/// of one asset.
+pub(crate) async fn handle_graph_command(
+    project: Option<&Path>,
+    walk: WalkOptions,
+    format: &str,
+    focus: Option<&str>,
+    output: &dyn Output,
+) -> Result<()> {
+    let format = parse_format(format)?;
+    let focus = focus.map(parse_asset_key).transpose()?;
+
+    let current_dir = project_dir(project)?;
+    let mut graph = DependencyGraph::build(&current_dir, walk, output)?;
+
+    if let Some(key) = &focus {
+        if graph.node(key).is_none() {
+            return Err(asset_not_found(&key.asset_type.to_string(), &key.name).into());
+        }
+        graph = graph.neighborhood(key);
+    }
+
+    match output.mode() {
+        OutputMode::Json => {
+            let nodes: Vec<_> = graph.nodes().collect();
+            let unresolved: Vec<_> = graph
+                .unresolved()
+                .iter()
+                .map(|u| {
+                    json!({
+                        "from": u.from,
+                        "field": u.reference.field,
+                        "target": u.reference.target.to_string(),
+                    })
+                })
+                .collect();
+
+            output.success(
+                json!({
+                    "focus": focus,
+                    "nodes": nodes,
+                    "edges": graph.edges(),
+                    "unresolved": unresolved,
+                }),
+                None,
+            );
+        }
+        OutputMode::Interactive => {
+            let rendered = match format {
+                GraphFormat::Dot => graph.to_dot(),
+                GraphFormat::Mermaid => graph.to_mermaid(),
+            };
+            output.print(rendered.trim_end());
+        }
+    }
+
+    Ok(())
+}
@@ -18,14 +18,20 @@
 //! switch containers, events, and soundbanks.
 
 mod collection;
+mod create;
+mod deps;
 mod effect;
 mod event;
+mod graph;
+mod open;
 mod sound;
 mod soundbank;
 mod switch;
 mod switch_container;
+mod touch;
 
 pub use collection::{CollectionCommands, handler as handle_collection_command};
+pub(crate) use deps::parse_asset_type;
 pub use effect::{EffectCommands, handler as handle_effect_command};
 pub use event::{EventCommands, handler as handle_event_command};
 pub use sound::{SoundCommands, handler as handle_sound_command};
@@ -34,34 +40,79 @@ pub use switch::{SwitchCommands, handler as handle_switch_command};
 pub use switch_container::{SwitchContainerCommands, handler as handle_switch_container_command};
 
 use anyhow::Result;
-use clap::Subcommand;
+use clap::{Subcommand, value_parser};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use crate::{
-    assets::Spatialization,
-    common::errors::{CliError, codes},
+    assets::{
+        Spatialization,
+        graph::{AssetKey, DependencyGraph},
+    },
+    common::{
+        errors::{CliError, asset_in_use, codes},
+        ignore::{IgnoreRules, WalkOptions, walk_project_files, warn_skipped},
+    },
     database::Database,
     input::Input,
     presentation::Output,
 };
 
-/// Recursively find all `.json` files under `dir`. Returns empty vec if `dir` doesn't exist.
-pub(crate) fn find_json_files_recursive(dir: &std::path::Path) -> Result<Vec<std::path::PathBuf>> {
-    let mut files = Vec::new();
+/// Recursively find all `.json` files under `dir` that are not ignored by the `.amignore`
+/// rules of the project at `project_root`, warning about the directories `walk` skips.
+/// Returns empty vec if `dir` doesn't exist.
+pub(crate) fn find_json_files_recursive(
+    project_root: &std::path::Path,
+    dir: &std::path::Path,
+    walk: WalkOptions,
+    output: &dyn Output,
+) -> Result<Vec<std::path::PathBuf>> {
     if !dir.exists() {
-        return Ok(files);
+        return Ok(Vec::new());
     }
-    for entry in walkdir::WalkDir::new(dir)
-        .follow_links(true)
+    let ignore = IgnoreRules::load(project_root)?;
+    let files = walk_project_files(dir, &ignore, walk);
+    warn_skipped(&files.skipped, output);
+    Ok(files
+        .files
         .into_iter()
-        .filter_map(|e| e.ok())
-    {
-        let path = entry.path();
-        if path.is_file() && path.extension().map(|e| e == "json").unwrap_or(false) {
-            files.push(path.to_path_buf());
-        }
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect())
+}
+
+/// Fail with `ERR_ASSET_IN_USE` when other assets of the project reference `key`.
+///
+/// Used by the `delete` subcommands, so that deleting never leaves dangling references.
+pub(crate) fn ensure_not_referenced(
+    project_root: &std::path::Path,
+    walk: WalkOptions,
+    key: &AssetKey,
+    output: &dyn Output,
+) -> Result<()> {
+    let graph = DependencyGraph::build(project_root, walk, output)?;
+    let references = graph.references_to(key);
+    if references.is_empty() {
+        return Ok(());
     }
-    Ok(files)
+
+    let referenced_by: std::collections::BTreeSet<&AssetKey> =
+        references.iter().map(|r| &r.from).collect();
+    let context = references
+        .iter()
+        .map(|r| format!("{} ({})", r.from, r.field))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    Err(
+        asset_in_use(&key.asset_type.to_string(), &key.name, referenced_by.len())
+            .with_suggestion(format!(
+                "Remove the references first, see 'am asset deps {} {}'",
+                key.type_key(),
+                key.name
+            ))
+            .with_context(context)
+            .into(),
+    )
 }
 
 /// Parse spatialization mode from string.
@@ -122,36 +173,231 @@ pub enum AssetCommands {
         #[command(subcommand)]
         command: SoundbankCommands,
     },
+    /// Create an asset from a JSON document read from a file or stdin
+    Create {
+        /// Asset type: sound, collection, effect, switch, switch_container, event, soundbank
+        asset_type: String,
+
+        /// Name of the asset
+        name: String,
+
+        /// Read the asset definition from this JSON file
+        #[arg(
+            long,
+            value_name = "PATH",
+            value_parser = value_parser!(PathBuf),
+            required_unless_present = "from_stdin"
+        )]
+        from_file: Option<PathBuf>,
+
+        /// Read the asset definition from stdin
+        #[arg(long, conflicts_with = "from_file")]
+        from_stdin: bool,
+
+        /// Replace the name in the definition when it differs from the asset name
+        #[arg(long)]
+        rename_body: bool,
+
+        /// What to do when the asset already exists: error, skip or overwrite
+        #[arg(long, value_name = "POLICY", default_value = "error")]
+        on_conflict: String,
+    },
+    /// Show the dependencies and dependents of an asset
+    Deps {
+        /// Asset type: sound, collection, effect, switch, switch_container, event, soundbank
+        asset_type: String,
+
+        /// Name of the asset
+        name: String,
+
+        /// Include indirect dependencies and dependents
+        #[arg(long)]
+        transitive: bool,
+    },
+    /// Export the dependency graph of the project
+    Graph {
+        /// Output format: dot or mermaid
+        #[arg(long, default_value = "dot")]
+        format: String,
+
+        /// Only show an asset and the assets it's directly linked to, as <type>/<name>
+        #[arg(long)]
+        focus: Option<String>,
+    },
+    /// Open the file of an asset in $VISUAL or $EDITOR
+    Open {
+        /// Asset type: sound, collection, effect, switch, switch_container, event, soundbank
+        asset_type: String,
+
+        /// Name of the asset
+        name: String,
+
+        /// Wait for the editor to exit, then validate the file
+        #[arg(long)]
+        wait: bool,
+
+        /// Print the path of the file instead of opening it
+        #[arg(long, conflicts_with = "wait")]
+        print_path: bool,
+    },
+    /// Increment the version field of an asset
+    Touch {
+        /// Asset type: sound, collection, effect, switch, switch_container, event, soundbank
+        #[arg(required_unless_present = "all_referencing")]
+        asset_type: Option<String>,
+
+        /// Name of the asset
+        #[arg(required_unless_present = "all_referencing")]
+        name: Option<String>,
+
+        /// Increment the numeric `version` or `revision` field
+        #[arg(long, required = true)]
+        bump_version: bool,
+
+        /// Add the field at 1 when the schema has it but the asset doesn't
+        #[arg(long)]
+        create: bool,
+
+        /// Bump every asset depending on an asset, directly or not, given as <type>/<name>
+        #[arg(long, conflicts_with_all = ["asset_type", "name"])]
+        all_referencing: Option<String>,
+    },
+}
+
+impl AssetCommands {
+    /// Whether this command writes to project files and must hold the project lock.
+    ///
+    /// Only the `list` subcommands, `deps`, `graph` and `open` are read-only: the file
+    /// `open` hands over is written by the editor, which may stay open long after the
+    /// command returns.
+    pub fn mutates_project_files(&self) -> bool {
+        !matches!(
+            self,
+            AssetCommands::Deps { .. }
+                | AssetCommands::Graph { .. }
+                | AssetCommands::Open { .. }
+                | AssetCommands::Sound {
+                    command: SoundCommands::List { .. }
+                }
+                | AssetCommands::Collection {
+                    command: CollectionCommands::List { .. }
+                }
+                | AssetCommands::Effect {
+                    command: EffectCommands::List { .. }
+                }
+                | AssetCommands::Switch {
+                    command: SwitchCommands::List { .. }
+                }
+                | AssetCommands::SwitchContainer {
+                    command: SwitchContainerCommands::List { .. }
+                }
+                | AssetCommands::Event {
+                    command: EventCommands::List { .. }
+                }
+                | AssetCommands::Soundbank {
+                    command: SoundbankCommands::List { .. }
+                }
+        )
+    }
 }
 
 /// Handle asset commands by routing to the appropriate subcommand handler.
 pub async fn handler(
     command: &AssetCommands,
+    project: Option<&Path>,
+    walk: WalkOptions,
     database: Option<Arc<Database>>,
     input: &dyn Input,
     output: &dyn Output,
 ) -> Result<()> {
     match command {
         AssetCommands::Sound { command } => {
-            handle_sound_command(command, database, input, output).await
+            handle_sound_command(command, project, walk, database, input, output).await
         }
         AssetCommands::Collection { command } => {
-            handle_collection_command(command, database, input, output).await
+            handle_collection_command(command, project, walk, database, input, output).await
         }
         AssetCommands::Effect { command } => {
-            handle_effect_command(command, database, input, output).await
+            handle_effect_command(command, project, walk, database, input, output).await
         }
         AssetCommands::Switch { command } => {
-            handle_switch_command(command, database, input, output).await
+            handle_switch_command(command, project, walk, database, input, output).await
         }
         AssetCommands::SwitchContainer { command } => {
-            handle_switch_container_command(command, database, input, output).await
+            handle_switch_container_command(command, project, walk, database, input, output).await
         }
         AssetCommands::Event { command } => {
-            handle_event_command(command, database, input, output).await
+            handle_event_command(command, project, walk, database, input, output).await
         }
         AssetCommands::Soundbank { command } => {
-            handle_soundbank_command(command, database, input, output).await
+            handle_soundbank_command(command, project, walk, database, input, output).await
+        }
+        AssetCommands::Create {
+            asset_type,
+            name,
+            from_file,
+            from_stdin: _,
+            rename_body,
+            on_conflict,
+        } => {
+            create::handle_create_command(
+                project,
+                walk,
+                asset_type,
+                name,
+                from_file.as_deref(),
+                *rename_body,
+                on_conflict,
+                database,
+                input,
+                output,
+            )
+            .await
+        }
+        AssetCommands::Deps {
+            asset_type,
+            name,
+            transitive,
+        } => deps::handle_deps_command(project, walk, asset_type, name, *transitive, output).await,
+        AssetCommands::Graph { format, focus } => {
+            graph::handle_graph_command(project, walk, format, focus.as_deref(), output).await
+        }
+        AssetCommands::Open {
+            asset_type,
+            name,
+            wait,
+            print_path,
+        } => {
+            open::handle_open_command(
+                project,
+                walk,
+                asset_type,
+                name,
+                *wait,
+                *print_path,
+                database,
+                input,
+                output,
+            )
+            .await
+        }
+        AssetCommands::Touch {
+            asset_type,
+            name,
+            bump_version: _,
+            create,
+            all_referencing,
+        } => {
+            touch::handle_touch_command(
+                project,
+                walk,
+                asset_type.as_deref(),
+                name.as_deref(),
+                *create,
+                all_referencing.as_deref(),
+                output,
+            )
+            .await
         }
     }
 }
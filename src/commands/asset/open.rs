@@ -0,0 +1,186 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `am asset open`: editing an asset file in the user's editor.
+//!
+//! Finds the file of an asset without knowing the layout of the project and opens it in
+//! the [editor](crate::common::editor). With `--wait`, the command returns once the
+//! editor exits and checks the file like `am asset create` checks a new asset, so a
+//! mistake made while editing is reported right away. Scripts get the path of the file
+//! with `--print-path` instead.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use serde_json::{Value, json};
+
+use crate::common::ignore::WalkOptions;
+use crate::common::project_option::project_dir;
+use crate::{
+    assets::{
+        ProjectContext, ProjectValidator,
+        graph::{AssetKey, DependencyGraph},
+    },
+    common::{
+        editor::EditorCommand,
+        errors::{CliError, asset_not_found, codes},
+        utils::read_amproject_file,
+    },
+    database::Database,
+    input::Input,
+    presentation::{Output, OutputMode, insert_os_str},
+};
+
+use super::create::{check_schema, validate_body};
+use super::deps::parse_asset_type;
+
+/// Open the file of the asset `name` of type `asset_type` in the editor, or with
+/// `print_path` report its path.
+///
+/// With `wait`, the file is validated once the editor exits.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn handle_open_command(
+    project: Option<&Path>,
+    walk: WalkOptions,
+    asset_type: &str,
+    name: &str,
+    wait: bool,
+    print_path: bool,
+    database: Option<Arc<Database>>,
+    input: &dyn Input,
+    output: &dyn Output,
+) -> Result<()> {
+    let asset_type = parse_asset_type(asset_type)?;
+    let key = AssetKey::new(asset_type, name);
+    let current_dir = project_dir(project)?;
+    let file = resolve_asset_file(&current_dir, walk, &key, output)?;
+
+    if print_path {
+        match output.mode() {
+            OutputMode::Json => {
+                let mut value = json!({
+                    "type": key.type_key(),
+                    "name": key.name,
+                });
+                insert_os_str(&mut value, "path", file.as_os_str());
+                output.success(value, None);
+            }
+            OutputMode::Interactive => output.print(&file.display().to_string()),
+        }
+        return Ok(());
+    }
+
+    if !input.is_interactive() {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            format!("Can't open {} '{}' in an editor", asset_type, name),
+            "Opening an editor needs an interactive terminal, and the input is non-interactive",
+        )
+        .with_suggestion("Use --print-path to get the path of the file instead")
+        .into());
+    }
+
+    let editor = EditorCommand::from_env(wait);
+    output.progress(&format!("Opening {} with {}...", file.display(), editor));
+    editor.open(&file, wait)?;
+
+    if !wait {
+        output.success(
+            json!(format!("Opened {} '{}' in {}", asset_type, name, editor)),
+            None,
+        );
+        return Ok(());
+    }
+
+    validate_edited_file(&current_dir, walk, &key, &file, database, output)?;
+    output.success(
+        json!(format!("{} '{}' is valid after editing", asset_type, name)),
+        None,
+    );
+
+    Ok(())
+}
+
+/// The file defining the asset `key`: `<asset dir>/<name>.json` by default, or wherever
+/// the asset named `name` is defined in the asset directory.
+fn resolve_asset_file(
+    project_root: &Path,
+    walk: WalkOptions,
+    key: &AssetKey,
+    output: &dyn Output,
+) -> Result<PathBuf> {
+    let config = read_amproject_file(project_root)?;
+    let sources_dir = if config.sources_dir.is_empty() {
+        project_root.to_path_buf()
+    } else {
+        project_root.join(&config.sources_dir)
+    };
+
+    // Checked first, so a file broken beyond parsing can still be opened to be fixed
+    let default_file = sources_dir
+        .join(config.asset_dir(key.asset_type))
+        .join(format!("{}.json", key.name));
+    if default_file.is_file() {
+        return Ok(default_file);
+    }
+
+    let graph = DependencyGraph::build(project_root, walk, output)?;
+    match graph.node(key) {
+        Some(node) => Ok(sources_dir.join(&node.path)),
+        None => Err(asset_not_found(&key.asset_type.to_string(), &key.name).into()),
+    }
+}
+
+/// Check the edited `file` of the asset `key` like `am asset create` checks a new asset:
+/// against the SDK schema when available, its type and its business rules.
+fn validate_edited_file(
+    project_root: &Path,
+    walk: WalkOptions,
+    key: &AssetKey,
+    file: &Path,
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> Result<()> {
+    let content =
+        fs::read_to_string(file).with_context(|| format!("Failed to read {}", file.display()))?;
+
+    // Parsed before the project is scanned, which would warn about the same error
+    let context = ProjectContext::new(project_root.to_path_buf());
+    let body: Value = context.parse_asset_json(&content).map_err(|e| {
+        CliError::new(
+            codes::ERR_VALIDATION_SCHEMA,
+            format!("Invalid JSON in {}", file.display()),
+            e.to_string(),
+        )
+        .with_suggestion(format!(
+            "Fix the file, e.g. with 'am asset open {} {} --wait' again",
+            key.type_key(),
+            key.name
+        ))
+    })?;
+
+    let validator = ProjectValidator::new(project_root.to_path_buf(), walk, output)?;
+    let context = context.with_validator(validator);
+
+    check_schema(key.asset_type, &body, database, output)
+        .and_then(|()| validate_body(key.asset_type, &body, &context).map(|_| ()))
+        .map_err(|e| match e.downcast::<CliError>() {
+            Ok(error) => error.with_context(file.display().to_string()).into(),
+            Err(e) => e,
+        })?;
+
+    Ok(())
+}
@@ -16,8 +16,8 @@
 //!
 //! Implements CRUD operations for Sound assets in Amplitude projects.
 
-use std::env;
 use std::fs;
+use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::{Context, Result};
@@ -26,20 +26,22 @@ use colored::Colorize;
 use inquire::validator::Validation;
 use serde_json::json;
 
+use crate::common::ignore::WalkOptions;
+use crate::common::project_option::project_dir;
 use crate::common::utils::generate_unique_id;
 use crate::{
     assets::{
-        Asset, ProjectContext, ProjectValidator, RtpcCompatibleValue, Sound, SoundLoopConfig,
-        Spatialization,
+        Asset, AssetType, ProjectContext, ProjectValidator, RtpcCompatibleValue, Sound,
+        SoundLoopConfig, Spatialization,
     },
     common::{
         errors::{CliError, asset_already_exists, asset_not_found, codes},
-        files::atomic_write,
+        files::{atomic_write, to_json_pretty},
         utils::{read_amproject_file, truncate_string},
     },
     database::Database,
-    input::{Input, select_index},
-    presentation::{Output, OutputMode},
+    input::{Input, PromptBlocked, select_index},
+    presentation::{ListLimit, Output, OutputMode, insert_os_str},
 };
 
 use super::parse_spatialization;
@@ -53,11 +55,6 @@ const ASSET_NAME: &str = "Sound";
 #[derive(Subcommand, Debug)]
 pub enum SoundCommands {
     /// Create a new sound asset
-    #[command(after_help = "Examples:
-  am asset sound create explosion --file audio/explosion.wav
-  am asset sound create footstep --file audio/step.wav --gain 0.8 --loop
-  am asset sound create ambient --stream --spatialization position
-")]
     Create {
         /// Name of the sound asset
         name: String,
@@ -96,17 +93,12 @@ pub enum SoundCommands {
     },
 
     /// List all sound assets in the project
-    #[command(after_help = "Examples:
-  am asset sound list
-  am asset sound list --json
-")]
-    List {},
+    List {
+        #[command(flatten)]
+        limit: ListLimit,
+    },
 
     /// Update an existing sound asset
-    #[command(after_help = "Examples:
-  am asset sound update explosion --gain 0.5
-  am asset sound update ambient --file audio/new_ambient.wav --stream
-")]
     Update {
         /// Name of the sound asset to update
         name: String,
@@ -148,6 +140,8 @@ pub enum SoundCommands {
 /// Handle sound commands by routing to the appropriate handler.
 pub async fn handler(
     command: &SoundCommands,
+    project: Option<&Path>,
+    walk: WalkOptions,
     _database: Option<Arc<Database>>,
     input: &dyn Input,
     output: &dyn Output,
@@ -165,6 +159,8 @@ pub async fn handler(
             spatialization,
         } => {
             create_sound(
+                project,
+                walk,
                 name,
                 file.clone(),
                 *gain,
@@ -179,7 +175,7 @@ pub async fn handler(
             )
             .await
         }
-        SoundCommands::List {} => list_sounds(output).await,
+        SoundCommands::List { limit } => list_sounds(project, walk, limit, output).await,
         SoundCommands::Update {
             name,
             file,
@@ -192,6 +188,8 @@ pub async fn handler(
             spatialization,
         } => {
             update_sound(
+                project,
+                walk,
                 name,
                 file.clone(),
                 *gain,
@@ -212,6 +210,8 @@ pub async fn handler(
 /// Create a new sound asset.
 #[allow(clippy::too_many_arguments)]
 async fn create_sound(
+    project: Option<&Path>,
+    walk: WalkOptions,
     name: &str,
     file: Option<String>,
     gain: Option<f32>,
@@ -225,7 +225,7 @@ async fn create_sound(
     output: &dyn Output,
 ) -> Result<()> {
     // Step 1: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     output.progress(&format!(
@@ -239,7 +239,7 @@ async fn create_sound(
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let sounds_dir = sources_base.join("sounds");
+    let sounds_dir = sources_base.join(project_config.asset_dir(AssetType::Sound));
     let sound_file_path = sounds_dir.join(format!("{}.json", name));
 
     if sound_file_path.exists() {
@@ -252,11 +252,11 @@ async fn create_sound(
     }
 
     // Build populated ProjectContext for validation (used throughout)
-    let validator = ProjectValidator::new(current_dir.clone(), output)?;
+    let validator = ProjectValidator::new(current_dir.clone(), walk, output)?;
     let context = ProjectContext::new(current_dir.clone()).with_validator(validator);
 
     // Check name uniqueness via ProjectContext registry
-    if context.has_name(crate::assets::AssetType::Sound, name) {
+    if context.has_name(AssetType::Sound, name) {
         return Err(asset_already_exists(ASSET_NAME, name)
             .with_suggestion(format!(
                 "Use 'am asset sound update {}' to modify it, or choose a different name",
@@ -364,8 +364,7 @@ async fn create_sound(
     sound.validate_rules(&context)?;
 
     // Step 14: Serialize to JSON
-    let json_content =
-        serde_json::to_string_pretty(&sound).context("Failed to serialize sound to JSON")?;
+    let json_content = to_json_pretty(&sound).context("Failed to serialize sound to JSON")?;
 
     // Step 15: Write using atomic write pattern
     atomic_write(&sound_file_path, json_content.as_bytes())?;
@@ -373,15 +372,13 @@ async fn create_sound(
     // Step 16: Output success
     match output.mode() {
         OutputMode::Json => {
-            output.success(
-                json!({
-                    "id": sound.id,
-                    "name": sound.name(),
-                    "path": sound_file_path.to_string_lossy(),
-                    "audio_file": sound.path.as_deref().unwrap_or(""),
-                }),
-                None,
-            );
+            let mut value = json!({
+                "id": sound.id,
+                "name": sound.name(),
+                "audio_file": sound.path.as_deref().unwrap_or(""),
+            });
+            insert_os_str(&mut value, "path", sound_file_path.as_os_str());
+            output.success(value, None);
         }
         OutputMode::Interactive => {
             output.success(
@@ -441,9 +438,14 @@ fn spatialization_to_string(spatialization: &Spatialization) -> &'static str {
 use super::find_json_files_recursive;
 
 /// List all sound assets in the current project.
-async fn list_sounds(output: &dyn Output) -> Result<()> {
+async fn list_sounds(
+    project: Option<&Path>,
+    walk: WalkOptions,
+    limit: &ListLimit,
+    output: &dyn Output,
+) -> Result<()> {
     // Step 1: Detect project (validates we're in a project directory)
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     // Step 2: Scan sounds directory using sources_dir from config
@@ -452,7 +454,7 @@ async fn list_sounds(output: &dyn Output) -> Result<()> {
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let sounds_dir = sources_base.join("sounds");
+    let sounds_dir = sources_base.join(project_config.asset_dir(AssetType::Sound));
 
     // Step 3: Handle missing or unreadable directory
     if !sounds_dir.exists() {
@@ -482,7 +484,7 @@ async fn list_sounds(output: &dyn Output) -> Result<()> {
     let mut sounds: Vec<Sound> = Vec::new();
     let mut warnings: Vec<String> = Vec::new();
 
-    let json_files = match find_json_files_recursive(&sounds_dir) {
+    let json_files = match find_json_files_recursive(&current_dir, &sounds_dir, walk, output) {
         Ok(files) => files,
         Err(e) => {
             return Err(CliError::new(
@@ -499,7 +501,7 @@ async fn list_sounds(output: &dyn Output) -> Result<()> {
     for path in json_files {
         if path.extension().map(|e| e == "json").unwrap_or(false) {
             match fs::read_to_string(&path) {
-                Ok(content) => match serde_json::from_str::<Sound>(&content) {
+                Ok(content) => match project_config.parse_asset_json::<Sound>(&content) {
                     Ok(sound) => {
                         // Check if referenced audio file exists
                         let path_str = sound.path.as_deref().unwrap_or("");
@@ -552,6 +554,7 @@ async fn list_sounds(output: &dyn Output) -> Result<()> {
 
     // Step 4: Sort by name for consistent output
     sounds.sort_by(|a, b| a.name().cmp(b.name()));
+    let truncation = limit.apply(&mut sounds);
 
     // Step 5: Handle empty directory
     if sounds.is_empty() {
@@ -601,14 +604,13 @@ async fn list_sounds(output: &dyn Output) -> Result<()> {
                 })
                 .collect();
 
-            output.success(
-                json!({
-                    "sounds": sound_data,
-                    "count": sounds.len(),
-                    "warnings": warnings
-                }),
-                None,
-            );
+            let mut payload = json!({
+                "sounds": sound_data,
+                "count": sounds.len(),
+                "warnings": warnings
+            });
+            truncation.annotate(&mut payload);
+            output.success(payload, None);
         }
         OutputMode::Interactive => {
             // Print warnings if any
@@ -631,7 +633,8 @@ async fn list_sounds(output: &dyn Output) -> Result<()> {
 
             output.table(None, json!(table_data));
             output.progress("");
-            output.progress(&format!("{} sound(s) found", sounds.len()));
+            output.progress(&format!("{} sound(s) found", truncation.total));
+            truncation.notify("sounds", output);
         }
     }
 
@@ -659,16 +662,16 @@ fn prompt_audio_file(input: &dyn Input) -> Result<String> {
             "Path to audio file (relative to data/)",
             Some("sfx/sound.wav"),
             None,
-            Some(&|value: &str| {
+            &[&|value: &str| {
                 if value.trim().is_empty() {
                     return Ok(Validation::Invalid("Audio file path is required".into()));
                 }
                 Ok(Validation::Valid)
-            }),
+            }],
         )
         .map_err(|e| {
             // In non-interactive mode, provide a helpful error
-            if e.to_string().contains("non-interactive") || e.to_string().contains("blocked") {
+            if e.is::<PromptBlocked>() {
                 CliError::new(
                     codes::ERR_VALIDATION_FIELD,
                     "Audio file path is required",
@@ -692,13 +695,13 @@ fn prompt_gain(input: &dyn Input) -> Result<f32> {
         "Volume gain [0.0-1.0]",
         Some("1.0"),
         None,
-        Some(&|value: &str| match value.trim().parse::<f32>() {
+        &[&|value: &str| match value.trim().parse::<f32>() {
             Ok(g) if (0.0..=1.0).contains(&g) => Ok(Validation::Valid),
             Ok(g) => Ok(Validation::Invalid(
                 format!("Gain must be between 0.0 and 1.0, got {}", g).into(),
             )),
             Err(_) => Ok(Validation::Invalid("Must be a number".into())),
-        }),
+        }],
     );
 
     match result {
@@ -717,12 +720,12 @@ fn prompt_priority(input: &dyn Input) -> Result<u8> {
         "Playback priority [0-255]",
         Some("128"),
         None,
-        Some(&|value: &str| match value.trim().parse::<u8>() {
+        &[&|value: &str| match value.trim().parse::<u8>() {
             Ok(_) => Ok(Validation::Valid),
             Err(_) => Ok(Validation::Invalid(
                 "Must be a number between 0 and 255".into(),
             )),
-        }),
+        }],
     );
 
     match result {
@@ -755,10 +758,10 @@ fn prompt_loop_config(input: &dyn Input) -> Result<SoundLoopConfig> {
         "Loop count (0=infinite)",
         Some("0"),
         None,
-        Some(&|value: &str| match value.trim().parse::<u32>() {
+        &[&|value: &str| match value.trim().parse::<u32>() {
             Ok(_) => Ok(Validation::Valid),
             Err(_) => Ok(Validation::Invalid("Must be a non-negative number".into())),
-        }),
+        }],
     );
 
     match result {
@@ -799,6 +802,8 @@ fn prompt_spatialization(input: &dyn Input) -> Result<Spatialization> {
 /// Update an existing sound asset.
 #[allow(clippy::too_many_arguments)]
 async fn update_sound(
+    project: Option<&Path>,
+    walk: WalkOptions,
     name: &str,
     file: Option<String>,
     gain: Option<f32>,
@@ -812,7 +817,7 @@ async fn update_sound(
     output: &dyn Output,
 ) -> Result<()> {
     // Step 1: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     output.progress(&format!(
@@ -826,7 +831,7 @@ async fn update_sound(
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let sounds_dir = sources_base.join("sounds");
+    let sounds_dir = sources_base.join(project_config.asset_dir(AssetType::Sound));
     let sound_file_path = sounds_dir.join(format!("{}.json", name));
 
     if !sound_file_path.exists() {
@@ -843,7 +848,7 @@ async fn update_sound(
         "Failed to read sound file: {}",
         sound_file_path.display()
     ))?;
-    let mut sound: Sound = serde_json::from_str(&content).context(format!(
+    let mut sound: Sound = project_config.parse_asset_json(&content).context(format!(
         "Failed to parse sound file: {}",
         sound_file_path.display()
     ))?;
@@ -878,28 +883,25 @@ async fn update_sound(
     };
 
     // Step 6: Validate the updated sound with populated context
-    let validator = ProjectValidator::new(current_dir.clone(), output)?;
+    let validator = ProjectValidator::new(current_dir.clone(), walk, output)?;
     let context = ProjectContext::new(current_dir.clone()).with_validator(validator);
     sound.validate_rules(&context)?;
 
     // Step 7: Serialize and write atomically
-    let json_content =
-        serde_json::to_string_pretty(&sound).context("Failed to serialize sound to JSON")?;
+    let json_content = to_json_pretty(&sound).context("Failed to serialize sound to JSON")?;
     atomic_write(&sound_file_path, json_content.as_bytes())?;
 
     // Step 8: Output success
     match output.mode() {
         OutputMode::Json => {
-            output.success(
-                json!({
-                    "id": sound.id,
-                    "name": sound.name(),
-                    "path": sound_file_path.to_string_lossy(),
-                    "audio_file": sound.path.as_deref().unwrap_or(""),
-                    "updated_fields": updated_fields,
-                }),
-                None,
-            );
+            let mut value = json!({
+                "id": sound.id,
+                "name": sound.name(),
+                "audio_file": sound.path.as_deref().unwrap_or(""),
+                "updated_fields": updated_fields,
+            });
+            insert_os_str(&mut value, "path", sound_file_path.as_os_str());
+            output.success(value, None);
         }
         OutputMode::Interactive => {
             output.success(
@@ -1090,7 +1092,7 @@ fn prompt_update_text(
     current_value: &str,
 ) -> Result<Option<String>> {
     let prompt = format!("{} (current: {}, Enter to keep)", label, current_value);
-    match input.prompt_text(&prompt, Some(current_value), None, None) {
+    match input.prompt_text(&prompt, Some(current_value), None, &[]) {
         Ok(value) if value == current_value => Ok(None), // No change
         Ok(value) => Ok(Some(value)),
         Err(_) => Ok(None), // Non-interactive, keep current
@@ -1113,11 +1115,11 @@ fn prompt_update_number(
         &prompt,
         Some(&current_str),
         None,
-        Some(&move |value: &str| match value.trim().parse::<f32>() {
+        &[&move |value: &str| match value.trim().parse::<f32>() {
             Ok(v) if validator(v) => Ok(Validation::Valid),
             Ok(_) => Ok(Validation::Invalid(error_msg.clone().into())),
             Err(_) => Ok(Validation::Invalid("Must be a number".into())),
-        }),
+        }],
     );
 
     match result {
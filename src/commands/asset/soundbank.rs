@@ -17,8 +17,8 @@
 //! Implements CRUD operations for Soundbank assets in Amplitude projects.
 //! Soundbanks package multiple assets together for efficient runtime loading.
 
-use std::env;
 use std::fs;
+use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::{Context, Result};
@@ -26,17 +26,20 @@ use clap::Subcommand;
 use colored::Colorize;
 use serde_json::json;
 
+use crate::common::ignore::WalkOptions;
+use crate::common::project_option::project_dir;
 use crate::common::utils::generate_unique_id;
 use crate::{
     assets::{Asset, AssetType, ProjectContext, ProjectValidator, Soundbank, SoundbankBuilder},
     common::{
         errors::{CliError, asset_already_exists, asset_not_found, codes},
-        files::atomic_write,
+        files::{atomic_write, parse_asset_json, safe_remove_within, to_json_pretty},
+        fs_ctx,
         utils::read_amproject_file,
     },
-    database::Database,
+    database::{Database, entities::ProjectConfiguration},
     input::{Input, select_index},
-    presentation::{Output, OutputMode},
+    presentation::{ListLimit, Output, OutputMode, insert_os_str},
 };
 
 use super::find_json_files_recursive;
@@ -48,9 +51,6 @@ const ASSET_NAME: &str = "Soundbank";
 #[derive(Subcommand, Debug)]
 pub enum SoundbankCommands {
     /// Create a new soundbank asset
-    #[command(
-        after_help = "Examples:\n  am asset soundbank create main_bank\n  am asset soundbank create main_bank --include sound:explosion,footstep\n"
-    )]
     Create {
         /// Name of the soundbank asset
         name: String,
@@ -62,13 +62,12 @@ pub enum SoundbankCommands {
     },
 
     /// List all soundbank assets in the project
-    #[command(after_help = "Examples:\n  am asset soundbank list\n")]
-    List {},
+    List {
+        #[command(flatten)]
+        limit: ListLimit,
+    },
 
     /// Update an existing soundbank asset
-    #[command(
-        after_help = "Examples:\n  am asset soundbank update main_bank\n  am asset soundbank update main_bank --add sound:newsfx\n"
-    )]
     Update {
         /// Name of the soundbank asset to update
         name: String,
@@ -83,9 +82,6 @@ pub enum SoundbankCommands {
     },
 
     /// Delete a soundbank asset
-    #[command(
-        after_help = "Examples:\n  am asset soundbank delete main_bank\n  am asset soundbank delete main_bank --yes\n"
-    )]
     Delete {
         /// Name of the soundbank asset to delete
         name: String,
@@ -99,27 +95,33 @@ pub enum SoundbankCommands {
 /// Handle soundbank commands by routing to the appropriate handler.
 pub async fn handler(
     command: &SoundbankCommands,
+    project: Option<&Path>,
+    walk: WalkOptions,
     _database: Option<Arc<Database>>,
     input: &dyn Input,
     output: &dyn Output,
 ) -> Result<()> {
     match command {
         SoundbankCommands::Create { name, include } => {
-            create_soundbank(name, include.clone(), input, output).await
+            create_soundbank(project, walk, name, include.clone(), input, output).await
         }
-        SoundbankCommands::List {} => list_soundbanks(output).await,
+        SoundbankCommands::List { limit } => list_soundbanks(project, walk, limit, output).await,
         SoundbankCommands::Update { name, add, remove } => {
-            update_soundbank(name, add.clone(), remove.clone(), input, output).await
+            update_soundbank(project, name, add.clone(), remove.clone(), input, output).await
         }
         SoundbankCommands::Delete { name, yes } => {
-            delete_soundbank(name, *yes, input, output).await
+            delete_soundbank(project, name, *yes, input, output).await
         }
     }
 }
 
 /// Parse include specification string in format "type:name[,name,...]"
 /// Returns (asset_type_key, Vec<asset_paths>)
-fn parse_include_spec(spec: &str, sources_dir: &std::path::Path) -> Result<Vec<(String, String)>> {
+fn parse_include_spec(
+    spec: &str,
+    sources_dir: &std::path::Path,
+    project_config: &ProjectConfiguration,
+) -> Result<Vec<(String, String)>> {
     let parts: Vec<&str> = spec.splitn(2, ':').collect();
     if parts.len() != 2 {
         return Err(CliError::new(
@@ -137,12 +139,12 @@ fn parse_include_spec(spec: &str, sources_dir: &std::path::Path) -> Result<Vec<(
 
     // Validate the type key
     let dir_name = match type_key.as_str() {
-        "sound" => "sounds",
-        "collection" => "collections",
-        "event" => "events",
-        "switch" => "switches",
-        "switch_container" => "switch_containers",
-        "effect" => "effects",
+        "sound" => project_config.asset_dir(AssetType::Sound),
+        "collection" => project_config.asset_dir(AssetType::Collection),
+        "event" => project_config.asset_dir(AssetType::Event),
+        "switch" => project_config.asset_dir(AssetType::Switch),
+        "switch_container" => project_config.asset_dir(AssetType::SwitchContainer),
+        "effect" => project_config.asset_dir(AssetType::Effect),
         "attenuator" => "attenuators",
         "rtpc" => "rtpc",
         _ => {
@@ -186,15 +188,24 @@ fn parse_include_spec(spec: &str, sources_dir: &std::path::Path) -> Result<Vec<(
 }
 
 /// Collect all available assets from project sources directory, grouped by type.
-fn collect_available_assets(sources_dir: &std::path::Path) -> Vec<(String, String, String)> {
+fn collect_available_assets(
+    sources_dir: &std::path::Path,
+    project_config: &ProjectConfiguration,
+) -> Vec<(String, String, String)> {
     // Returns (type_label, asset_name, relative_path)
     let type_dirs = [
-        ("sound", "sounds"),
-        ("collection", "collections"),
-        ("event", "events"),
-        ("switch", "switches"),
-        ("switch_container", "switch_containers"),
-        ("effect", "effects"),
+        ("sound", project_config.asset_dir(AssetType::Sound)),
+        (
+            "collection",
+            project_config.asset_dir(AssetType::Collection),
+        ),
+        ("event", project_config.asset_dir(AssetType::Event)),
+        ("switch", project_config.asset_dir(AssetType::Switch)),
+        (
+            "switch_container",
+            project_config.asset_dir(AssetType::SwitchContainer),
+        ),
+        ("effect", project_config.asset_dir(AssetType::Effect)),
         ("attenuator", "attenuators"),
         ("rtpc", "rtpc"),
     ];
@@ -397,13 +408,15 @@ fn count_assets_by_type(soundbank: &Soundbank) -> Vec<(String, usize)> {
 
 /// Create a new soundbank asset.
 async fn create_soundbank(
+    project: Option<&Path>,
+    walk: WalkOptions,
     name: &str,
     includes: Vec<String>,
     input: &dyn Input,
     output: &dyn Output,
 ) -> Result<()> {
     // Step 1: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     output.progress(&format!(
@@ -417,7 +430,7 @@ async fn create_soundbank(
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let soundbanks_dir = sources_base.join("soundbanks");
+    let soundbanks_dir = sources_base.join(project_config.asset_dir(AssetType::Soundbank));
     let soundbank_file_path = soundbanks_dir.join(format!("{}.json", name));
 
     if soundbank_file_path.exists() {
@@ -430,7 +443,7 @@ async fn create_soundbank(
     }
 
     // Build context for validation
-    let validator = ProjectValidator::new(current_dir.clone(), output)?;
+    let validator = ProjectValidator::new(current_dir.clone(), walk, output)?;
     let context = ProjectContext::new(current_dir.clone()).with_validator(validator);
 
     if context.has_name(AssetType::Soundbank, name) {
@@ -447,12 +460,12 @@ async fn create_soundbank(
     // Step 3: Get assets to include
     let asset_refs: Vec<(String, String)> = if includes.is_empty() {
         // Interactive mode: prompt for assets
-        prompt_select_assets(input, output, &sources_dir)?
+        prompt_select_assets(input, output, &sources_dir, &project_config)?
     } else {
         // Non-interactive: parse --include flags
         let mut refs = Vec::new();
         for spec in &includes {
-            let parsed = parse_include_spec(spec, &sources_dir)?;
+            let parsed = parse_include_spec(spec, &sources_dir, &project_config)?;
             refs.extend(parsed);
         }
         refs
@@ -504,11 +517,11 @@ async fn create_soundbank(
     }
 
     // Step 7: Serialize to JSON
-    let json_content = serde_json::to_string_pretty(&soundbank)
-        .context("Failed to serialize soundbank to JSON")?;
+    let json_content =
+        to_json_pretty(&soundbank).context("Failed to serialize soundbank to JSON")?;
 
     // Step 8: Write atomically
-    fs::create_dir_all(&soundbanks_dir)?;
+    fs_ctx::create_dir_all(&soundbanks_dir)?;
     atomic_write(&soundbank_file_path, json_content.as_bytes())?;
 
     // Step 9: Output success
@@ -521,16 +534,14 @@ async fn create_soundbank(
                 .collect::<serde_json::Map<String, serde_json::Value>>()
                 .into();
 
-            output.success(
-                json!({
-                    "id": soundbank.id,
-                    "name": soundbank.name(),
-                    "path": soundbank_file_path.to_string_lossy(),
-                    "asset_count": soundbank.asset_count(),
-                    "assets_by_type": type_counts,
-                }),
-                None,
-            );
+            let mut value = json!({
+                "id": soundbank.id,
+                "name": soundbank.name(),
+                "asset_count": soundbank.asset_count(),
+                "assets_by_type": type_counts,
+            });
+            insert_os_str(&mut value, "path", soundbank_file_path.as_os_str());
+            output.success(value, None);
         }
         OutputMode::Interactive => {
             output.success(
@@ -553,8 +564,9 @@ fn prompt_select_assets(
     input: &dyn Input,
     output: &dyn Output,
     sources_dir: &std::path::Path,
+    project_config: &ProjectConfiguration,
 ) -> Result<Vec<(String, String)>> {
-    let available = collect_available_assets(sources_dir);
+    let available = collect_available_assets(sources_dir, project_config);
 
     if available.is_empty() {
         return Err(CliError::new(
@@ -632,9 +644,14 @@ fn prompt_select_assets(
 // =============================================================================
 
 /// List all soundbank assets in the current project.
-async fn list_soundbanks(output: &dyn Output) -> Result<()> {
+async fn list_soundbanks(
+    project: Option<&Path>,
+    walk: WalkOptions,
+    limit: &ListLimit,
+    output: &dyn Output,
+) -> Result<()> {
     // Step 1: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     // Step 2: Scan soundbanks directory
@@ -643,7 +660,7 @@ async fn list_soundbanks(output: &dyn Output) -> Result<()> {
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let soundbanks_dir = sources_base.join("soundbanks");
+    let soundbanks_dir = sources_base.join(project_config.asset_dir(AssetType::Soundbank));
 
     if !soundbanks_dir.exists() {
         match output.mode() {
@@ -672,7 +689,7 @@ async fn list_soundbanks(output: &dyn Output) -> Result<()> {
     let mut soundbanks: Vec<Soundbank> = Vec::new();
     let mut warnings: Vec<String> = Vec::new();
 
-    let json_files = match find_json_files_recursive(&soundbanks_dir) {
+    let json_files = match find_json_files_recursive(&current_dir, &soundbanks_dir, walk, output) {
         Ok(files) => files,
         Err(e) => {
             return Err(CliError::new(
@@ -689,7 +706,7 @@ async fn list_soundbanks(output: &dyn Output) -> Result<()> {
     for path in json_files {
         if path.extension().map(|e| e == "json").unwrap_or(false) {
             match fs::read_to_string(&path) {
-                Ok(content) => match serde_json::from_str::<Soundbank>(&content) {
+                Ok(content) => match project_config.parse_asset_json::<Soundbank>(&content) {
                     Ok(soundbank) => {
                         soundbanks.push(soundbank);
                     }
@@ -731,6 +748,7 @@ async fn list_soundbanks(output: &dyn Output) -> Result<()> {
 
     // Step 4: Sort by name
     soundbanks.sort_by(|a, b| a.name().cmp(b.name()));
+    let truncation = limit.apply(&mut soundbanks);
 
     // Step 5: Handle empty directory
     if soundbanks.is_empty() {
@@ -784,14 +802,13 @@ async fn list_soundbanks(output: &dyn Output) -> Result<()> {
                 })
                 .collect();
 
-            output.success(
-                json!({
-                    "soundbanks": soundbank_data,
-                    "count": soundbanks.len(),
-                    "warnings": warnings
-                }),
-                None,
-            );
+            let mut payload = json!({
+                "soundbanks": soundbank_data,
+                "count": soundbanks.len(),
+                "warnings": warnings
+            });
+            truncation.annotate(&mut payload);
+            output.success(payload, None);
         }
         OutputMode::Interactive => {
             for warning in &warnings {
@@ -819,7 +836,8 @@ async fn list_soundbanks(output: &dyn Output) -> Result<()> {
 
             output.table(None, json!(table_data));
             output.progress("");
-            output.progress(&format!("{} soundbank(s) found", soundbanks.len()));
+            output.progress(&format!("{} soundbank(s) found", truncation.total));
+            truncation.notify("soundbanks", output);
         }
     }
 
@@ -832,6 +850,7 @@ async fn list_soundbanks(output: &dyn Output) -> Result<()> {
 
 /// Update an existing soundbank asset.
 async fn update_soundbank(
+    project: Option<&Path>,
     name: &str,
     add_specs: Vec<String>,
     remove_specs: Vec<String>,
@@ -839,7 +858,7 @@ async fn update_soundbank(
     output: &dyn Output,
 ) -> Result<()> {
     // Step 1: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     output.progress(&format!(
@@ -853,7 +872,7 @@ async fn update_soundbank(
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let soundbanks_dir = sources_base.join("soundbanks");
+    let soundbanks_dir = sources_base.join(project_config.asset_dir(AssetType::Soundbank));
     let soundbank_file_path = soundbanks_dir.join(format!("{}.json", name));
 
     if !soundbank_file_path.exists() {
@@ -870,7 +889,7 @@ async fn update_soundbank(
         "Failed to read soundbank file: {}",
         soundbank_file_path.display()
     ))?;
-    let mut soundbank: Soundbank = serde_json::from_str(&content).context(format!(
+    let mut soundbank: Soundbank = project_config.parse_asset_json(&content).context(format!(
         "Failed to parse soundbank file: {}",
         soundbank_file_path.display()
     ))?;
@@ -889,7 +908,7 @@ async fn update_soundbank(
 
         // Process additions
         for spec in &add_specs {
-            let parsed = parse_include_spec(spec, &sources_dir)?;
+            let parsed = parse_include_spec(spec, &sources_dir, &project_config)?;
             for (type_key, path) in parsed {
                 add_asset_to_soundbank(&mut soundbank, &type_key, path);
             }
@@ -898,7 +917,7 @@ async fn update_soundbank(
 
         // Process removals
         for spec in &remove_specs {
-            let parsed = parse_include_spec(spec, &sources_dir)?;
+            let parsed = parse_include_spec(spec, &sources_dir, &project_config)?;
             for (type_key, path) in parsed {
                 if !remove_asset_from_soundbank(&mut soundbank, &type_key, &path) {
                     return Err(CliError::new(
@@ -914,7 +933,8 @@ async fn update_soundbank(
         }
     } else {
         // Interactive mode: prompt for modifications
-        let modified = prompt_modify_soundbank(input, output, &mut soundbank, &sources_dir)?;
+        let modified =
+            prompt_modify_soundbank(input, output, &mut soundbank, &sources_dir, &project_config)?;
         if modified {
             updated_fields.push("assets".to_string());
         }
@@ -932,23 +952,21 @@ async fn update_soundbank(
     }
 
     // Serialize and write atomically
-    let json_content = serde_json::to_string_pretty(&soundbank)
-        .context("Failed to serialize soundbank to JSON")?;
+    let json_content =
+        to_json_pretty(&soundbank).context("Failed to serialize soundbank to JSON")?;
     atomic_write(&soundbank_file_path, json_content.as_bytes())?;
 
     // Output success
     match output.mode() {
         OutputMode::Json => {
-            output.success(
-                json!({
-                    "id": soundbank.id,
-                    "name": soundbank.name(),
-                    "path": soundbank_file_path.to_string_lossy(),
-                    "asset_count": soundbank.asset_count(),
-                    "updated_fields": updated_fields,
-                }),
-                None,
-            );
+            let mut value = json!({
+                "id": soundbank.id,
+                "name": soundbank.name(),
+                "asset_count": soundbank.asset_count(),
+                "updated_fields": updated_fields,
+            });
+            insert_os_str(&mut value, "path", soundbank_file_path.as_os_str());
+            output.success(value, None);
         }
         OutputMode::Interactive => {
             output.success(
@@ -972,6 +990,7 @@ fn prompt_modify_soundbank(
     output: &dyn Output,
     soundbank: &mut Soundbank,
     sources_dir: &std::path::Path,
+    project_config: &ProjectConfiguration,
 ) -> Result<bool> {
     let mut modified = false;
 
@@ -1002,7 +1021,7 @@ fn prompt_modify_soundbank(
         match choice {
             0 => {
                 // Add asset
-                let available = collect_available_assets(sources_dir);
+                let available = collect_available_assets(sources_dir, project_config);
                 let current = get_soundbank_assets(soundbank);
 
                 let add_options: Vec<String> = available
@@ -1065,13 +1084,14 @@ fn prompt_modify_soundbank(
 
 /// Delete a soundbank asset.
 async fn delete_soundbank(
+    project: Option<&Path>,
     name: &str,
     yes: bool,
     input: &dyn Input,
     output: &dyn Output,
 ) -> Result<()> {
     // Step 1: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     // Step 2: Locate soundbank file
@@ -1080,7 +1100,7 @@ async fn delete_soundbank(
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let soundbanks_dir = sources_base.join("soundbanks");
+    let soundbanks_dir = sources_base.join(project_config.asset_dir(AssetType::Soundbank));
     let soundbank_file_path = soundbanks_dir.join(format!("{}.json", name));
 
     if !soundbank_file_path.exists() {
@@ -1091,7 +1111,7 @@ async fn delete_soundbank(
 
     // Step 3: Parse soundbank for details
     let content = fs::read_to_string(&soundbank_file_path)?;
-    let soundbank: Soundbank = serde_json::from_str(&content)?;
+    let soundbank: Soundbank = project_config.parse_asset_json(&content)?;
 
     // Step 4: Check for orphaned assets (informational only)
     // An asset is "orphaned" if this is the only soundbank containing it.
@@ -1143,7 +1163,7 @@ async fn delete_soundbank(
     }
 
     // Step 6: Delete the file
-    fs::remove_file(&soundbank_file_path)?;
+    safe_remove_within(&current_dir, &soundbank_file_path)?;
 
     // Step 7: Output success
     match output.mode() {
@@ -1178,17 +1198,26 @@ async fn delete_soundbank(
 
 /// Check which assets would become orphaned (not in any other soundbank) after deletion.
 fn check_orphaned_assets(soundbank: &Soundbank, project_root: &std::path::Path) -> Vec<String> {
-    let sources_dir = match crate::common::utils::read_amproject_file(project_root) {
-        Ok(config) => {
-            if config.sources_dir.is_empty() {
-                project_root.to_path_buf()
-            } else {
-                project_root.join(&config.sources_dir)
+    let (soundbanks_dir, allow_json_comments) =
+        match crate::common::utils::read_amproject_file(project_root) {
+            Ok(config) => {
+                let sources_dir = if config.sources_dir.is_empty() {
+                    project_root.to_path_buf()
+                } else {
+                    project_root.join(&config.sources_dir)
+                };
+                (
+                    sources_dir.join(config.asset_dir(AssetType::Soundbank)),
+                    config.allow_json_comments,
+                )
             }
-        }
-        Err(_) => project_root.join("sources"),
-    };
-    let soundbanks_dir = sources_dir.join("soundbanks");
+            Err(_) => (
+                project_root
+                    .join("sources")
+                    .join(AssetType::Soundbank.directory_name()),
+                false,
+            ),
+        };
 
     // Load all other soundbanks
     let mut other_assets: std::collections::HashSet<String> = std::collections::HashSet::new();
@@ -1198,7 +1227,8 @@ fn check_orphaned_assets(soundbank: &Soundbank, project_root: &std::path::Path)
             let path = entry.path();
             if path.extension().is_some_and(|e| e == "json") {
                 if let Ok(content) = fs::read_to_string(&path) {
-                    if let Ok(other) = serde_json::from_str::<Soundbank>(&content) {
+                    if let Ok(other) = parse_asset_json::<Soundbank>(&content, allow_json_comments)
+                    {
                         // Skip self
                         if other.id == soundbank.id {
                             continue;
@@ -16,8 +16,8 @@
 //!
 //! Implements CRUD operations for Switch assets in Amplitude projects.
 
-use std::env;
 use std::fs;
+use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::{Context, Result};
@@ -26,23 +26,26 @@ use colored::Colorize;
 use inquire::validator::Validation;
 use serde_json::json;
 
+use crate::common::ignore::WalkOptions;
+use crate::common::project_option::project_dir;
 use crate::common::utils::generate_unique_id;
 use crate::{
     assets::{
         Asset, AssetType, ProjectContext, ProjectValidator, Switch,
-        generated::SwitchStateDefinition,
+        generated::SwitchStateDefinition, graph::AssetKey,
     },
     common::{
         errors::{CliError, asset_already_exists, asset_not_found, codes},
-        files::atomic_write,
+        files::{atomic_write, safe_remove_within, to_json_pretty},
+        fs_ctx,
         utils::read_amproject_file,
     },
     database::Database,
-    input::{Input, select_index},
-    presentation::{Output, OutputMode},
+    input::{Input, select_index, with_flag_hint},
+    presentation::{ListLimit, Output, OutputMode, insert_os_str},
 };
 
-use super::find_json_files_recursive;
+use super::{ensure_not_referenced, find_json_files_recursive};
 
 /// The name of the current asset.
 const ASSET_NAME: &str = "Switch";
@@ -54,9 +57,6 @@ const MAX_ID_RETRIES: u32 = 3;
 #[derive(Subcommand, Debug)]
 pub enum SwitchCommands {
     /// Create a new switch asset
-    #[command(
-        after_help = "Examples:\n  am asset switch create surface_type\n  am asset switch create surface_type --states wood,stone,metal\n"
-    )]
     Create {
         /// Name of the switch asset
         name: String,
@@ -67,13 +67,12 @@ pub enum SwitchCommands {
     },
 
     /// List all switch assets in the project
-    #[command(after_help = "Examples:\n  am asset switch list\n")]
-    List {},
+    List {
+        #[command(flatten)]
+        limit: ListLimit,
+    },
 
     /// Update an existing switch asset
-    #[command(
-        after_help = "Examples:\n  am asset switch update surface_type\n  am asset switch update surface_type --states wood,stone,grass\n"
-    )]
     Update {
         /// Name of the switch asset to update
         name: String,
@@ -84,9 +83,6 @@ pub enum SwitchCommands {
     },
 
     /// Delete a switch asset
-    #[command(
-        after_help = "Examples:\n  am asset switch delete surface_type\n  am asset switch delete surface_type --force\n"
-    )]
     Delete {
         /// Name of the switch asset to delete
         name: String,
@@ -100,19 +96,23 @@ pub enum SwitchCommands {
 /// Handle switch commands by routing to the appropriate handler.
 pub async fn handler(
     command: &SwitchCommands,
+    project: Option<&Path>,
+    walk: WalkOptions,
     _database: Option<Arc<Database>>,
     input: &dyn Input,
     output: &dyn Output,
 ) -> Result<()> {
     match command {
         SwitchCommands::Create { name, states } => {
-            create_switch(name, states.clone(), input, output).await
+            create_switch(project, walk, name, states.clone(), input, output).await
         }
-        SwitchCommands::List {} => list_switches(output).await,
+        SwitchCommands::List { limit } => list_switches(project, walk, limit, output).await,
         SwitchCommands::Update { name, states } => {
-            update_switch(name, states.clone(), input, output).await
+            update_switch(project, walk, name, states.clone(), input, output).await
+        }
+        SwitchCommands::Delete { name, force } => {
+            delete_switch(project, walk, name, *force, input, output).await
         }
-        SwitchCommands::Delete { name, force } => delete_switch(name, *force, input, output).await,
     }
 }
 
@@ -122,6 +122,8 @@ pub async fn handler(
 
 /// Create a new switch asset.
 async fn create_switch(
+    project: Option<&Path>,
+    walk: WalkOptions,
     name: &str,
     states: Option<Vec<String>>,
     input: &dyn Input,
@@ -139,7 +141,7 @@ async fn create_switch(
     }
 
     // Step 2: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     output.progress(&format!(
@@ -153,7 +155,7 @@ async fn create_switch(
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let switches_dir = sources_base.join("switches");
+    let switches_dir = sources_base.join(project_config.asset_dir(AssetType::Switch));
     let switch_file_path = switches_dir.join(format!("{}.json", name));
 
     if switch_file_path.exists() {
@@ -166,7 +168,7 @@ async fn create_switch(
     }
 
     // Build populated ProjectContext for validation
-    let validator = ProjectValidator::new(current_dir.clone(), output)?;
+    let validator = ProjectValidator::new(current_dir.clone(), walk, output)?;
     let context = ProjectContext::new(current_dir.clone()).with_validator(validator);
 
     // Check name uniqueness via ProjectContext registry
@@ -185,7 +187,7 @@ async fn create_switch(
         parse_state_names(&s)?
     } else {
         // Interactive mode: prompt for states one by one
-        prompt_states(input)?
+        prompt_states(&with_flag_hint(input, "--states <state,...>"))?
     };
 
     // Validate at least one state
@@ -259,25 +261,22 @@ async fn create_switch(
     switch.validate_rules(&context)?;
 
     // Step 9: Serialize to JSON
-    let json_content =
-        serde_json::to_string_pretty(&switch).context("Failed to serialize switch to JSON")?;
+    let json_content = to_json_pretty(&switch).context("Failed to serialize switch to JSON")?;
 
     // Step 10: Ensure directory exists and write atomically
-    fs::create_dir_all(&switches_dir)?;
+    fs_ctx::create_dir_all(&switches_dir)?;
     atomic_write(&switch_file_path, json_content.as_bytes())?;
 
     // Step 11: Output success
     match output.mode() {
         OutputMode::Json => {
-            output.success(
-                json!({
-                    "id": switch.id,
-                    "name": switch.name(),
-                    "path": switch_file_path.to_string_lossy(),
-                    "state_count": state_list.len(),
-                }),
-                None,
-            );
+            let mut value = json!({
+                "id": switch.id,
+                "name": switch.name(),
+                "state_count": state_list.len(),
+            });
+            insert_os_str(&mut value, "path", switch_file_path.as_os_str());
+            output.success(value, None);
         }
         OutputMode::Interactive => {
             output.success(
@@ -321,12 +320,12 @@ fn prompt_states(input: &dyn Input) -> Result<Vec<String>> {
             &prompt,
             None,
             None,
-            Some(&|value: &str| {
+            &[&|value: &str| {
                 if value.trim().is_empty() && states.is_empty() {
                     return Ok(Validation::Invalid("At least one state is required".into()));
                 }
                 Ok(Validation::Valid)
-            }),
+            }],
         )?;
 
         let trimmed = state_name.trim();
@@ -373,9 +372,14 @@ fn prompt_states(input: &dyn Input) -> Result<Vec<String>> {
 // =============================================================================
 
 /// List all switch assets in the current project.
-async fn list_switches(output: &dyn Output) -> Result<()> {
+async fn list_switches(
+    project: Option<&Path>,
+    walk: WalkOptions,
+    limit: &ListLimit,
+    output: &dyn Output,
+) -> Result<()> {
     // Step 1: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     // Step 2: Scan switches directory
@@ -384,7 +388,7 @@ async fn list_switches(output: &dyn Output) -> Result<()> {
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let switches_dir = sources_base.join("switches");
+    let switches_dir = sources_base.join(project_config.asset_dir(AssetType::Switch));
 
     // Step 3: Handle missing directory
     if !switches_dir.exists() {
@@ -414,7 +418,7 @@ async fn list_switches(output: &dyn Output) -> Result<()> {
     let mut switches: Vec<Switch> = Vec::new();
     let mut warnings: Vec<String> = Vec::new();
 
-    let json_files = match find_json_files_recursive(&switches_dir) {
+    let json_files = match find_json_files_recursive(&current_dir, &switches_dir, walk, output) {
         Ok(files) => files,
         Err(e) => {
             return Err(CliError::new(
@@ -431,7 +435,7 @@ async fn list_switches(output: &dyn Output) -> Result<()> {
     for path in json_files {
         if path.extension().map(|e| e == "json").unwrap_or(false) {
             match fs::read_to_string(&path) {
-                Ok(content) => match serde_json::from_str::<Switch>(&content) {
+                Ok(content) => match project_config.parse_asset_json::<Switch>(&content) {
                     Ok(switch) => {
                         switches.push(switch);
                     }
@@ -468,6 +472,7 @@ async fn list_switches(output: &dyn Output) -> Result<()> {
 
     // Step 5: Sort by name
     switches.sort_by(|a, b| a.name().cmp(b.name()));
+    let truncation = limit.apply(&mut switches);
 
     // Step 6: Handle empty
     if switches.is_empty() {
@@ -522,14 +527,13 @@ async fn list_switches(output: &dyn Output) -> Result<()> {
                 })
                 .collect();
 
-            output.success(
-                json!({
-                    "switches": switch_data,
-                    "count": switches.len(),
-                    "warnings": warnings
-                }),
-                None,
-            );
+            let mut payload = json!({
+                "switches": switch_data,
+                "count": switches.len(),
+                "warnings": warnings
+            });
+            truncation.annotate(&mut payload);
+            output.success(payload, None);
         }
         OutputMode::Interactive => {
             for warning in &warnings {
@@ -550,7 +554,8 @@ async fn list_switches(output: &dyn Output) -> Result<()> {
 
             output.table(None, json!(table_data));
             output.progress("");
-            output.progress(&format!("{} switch(es) found", switches.len()));
+            output.progress(&format!("{} switch(es) found", truncation.total));
+            truncation.notify("switches", output);
         }
     }
 
@@ -563,13 +568,15 @@ async fn list_switches(output: &dyn Output) -> Result<()> {
 
 /// Update an existing switch asset.
 async fn update_switch(
+    project: Option<&Path>,
+    walk: WalkOptions,
     name: &str,
     states: Option<Vec<String>>,
     input: &dyn Input,
     output: &dyn Output,
 ) -> Result<()> {
     // Step 1: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     output.progress(&format!(
@@ -583,7 +590,7 @@ async fn update_switch(
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let switches_dir = sources_base.join("switches");
+    let switches_dir = sources_base.join(project_config.asset_dir(AssetType::Switch));
     let switch_file_path = switches_dir.join(format!("{}.json", name));
 
     if !switch_file_path.exists() {
@@ -600,7 +607,7 @@ async fn update_switch(
         "Failed to read switch file: {}",
         switch_file_path.display()
     ))?;
-    let mut switch: Switch = serde_json::from_str(&content).context(format!(
+    let mut switch: Switch = project_config.parse_asset_json(&content).context(format!(
         "Failed to parse switch file: {}",
         switch_file_path.display()
     ))?;
@@ -609,7 +616,7 @@ async fn update_switch(
     let has_any_flag = states.is_some();
 
     // Step 5: Apply updates
-    let validator = ProjectValidator::new(current_dir.clone(), output)?;
+    let validator = ProjectValidator::new(current_dir.clone(), walk, output)?;
     let context = ProjectContext::new(current_dir.clone()).with_validator(validator);
 
     let updated_fields: Vec<String> = if has_any_flag {
@@ -624,22 +631,19 @@ async fn update_switch(
     switch.validate_rules(&context)?;
 
     // Step 7: Serialize and write atomically
-    let json_content =
-        serde_json::to_string_pretty(&switch).context("Failed to serialize switch to JSON")?;
+    let json_content = to_json_pretty(&switch).context("Failed to serialize switch to JSON")?;
     atomic_write(&switch_file_path, json_content.as_bytes())?;
 
     // Step 8: Output success
     match output.mode() {
         OutputMode::Json => {
-            output.success(
-                json!({
-                    "id": switch.id,
-                    "name": switch.name(),
-                    "path": switch_file_path.to_string_lossy(),
-                    "updated_fields": updated_fields,
-                }),
-                None,
-            );
+            let mut value = json!({
+                "id": switch.id,
+                "name": switch.name(),
+                "updated_fields": updated_fields,
+            });
+            insert_os_str(&mut value, "path", switch_file_path.as_os_str());
+            output.success(value, None);
         }
         OutputMode::Interactive => {
             output.success(
@@ -806,7 +810,7 @@ fn prompt_states_for_update(input: &dyn Input, current_states: &[String]) -> Res
             &prompt,
             Some("d"),
             None,
-            Some(&|value: &str| {
+            &[&|value: &str| {
                 let trimmed = value.trim().to_lowercase();
                 if trimmed.is_empty()
                     || ["a", "add", "r", "remove", "d", "done"].contains(&trimmed.as_str())
@@ -817,7 +821,7 @@ fn prompt_states_for_update(input: &dyn Input, current_states: &[String]) -> Res
                         "Enter 'a' to add, 'r' to remove, or 'd' to done".into(),
                     ))
                 }
-            }),
+            }],
         )?;
 
         match choice.trim().to_lowercase().as_str() {
@@ -826,12 +830,12 @@ fn prompt_states_for_update(input: &dyn Input, current_states: &[String]) -> Res
                     "Enter new state name:",
                     None,
                     None,
-                    Some(&|value: &str| {
+                    &[&|value: &str| {
                         if value.trim().is_empty() {
                             return Ok(Validation::Invalid("State name cannot be empty".into()));
                         }
                         Ok(Validation::Valid)
-                    }),
+                    }],
                 )?;
                 let trimmed = new_state.trim();
                 if states.contains(&trimmed.to_string()) {
@@ -871,13 +875,15 @@ fn prompt_states_for_update(input: &dyn Input, current_states: &[String]) -> Res
 
 /// Delete a switch asset.
 async fn delete_switch(
+    project: Option<&Path>,
+    walk: WalkOptions,
     name: &str,
     force: bool,
     input: &dyn Input,
     output: &dyn Output,
 ) -> Result<()> {
     // Step 1: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     // Step 2: Locate switch file
@@ -886,7 +892,7 @@ async fn delete_switch(
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let switches_dir = sources_base.join("switches");
+    let switches_dir = sources_base.join(project_config.asset_dir(AssetType::Switch));
     let switch_file_path = switches_dir.join(format!("{}.json", name));
 
     if !switch_file_path.exists() {
@@ -900,25 +906,27 @@ async fn delete_switch(
         "Failed to read switch file: {}",
         switch_file_path.display()
     ))?;
-    let switch: Switch = serde_json::from_str(&content).context(format!(
+    let switch: Switch = project_config.parse_asset_json(&content).context(format!(
         "Failed to parse switch file: {}",
         switch_file_path.display()
     ))?;
 
-    // Step 4: Check for switch container references
-    let validator = ProjectValidator::new(current_dir.clone(), output)?;
-    let has_references = check_switch_references(&validator, switch.id);
+    // Step 4: Refuse to delete a switch other assets reference
+    ensure_not_referenced(
+        &current_dir,
+        walk,
+        &AssetKey::new(AssetType::Switch, switch.name()),
+        output,
+    )?;
 
     // Step 5: Confirm deletion
     let confirmed = if force {
         true
     } else {
-        let mut prompt = format!("Delete switch '{}'? This cannot be undone.", name);
-        if has_references {
-            prompt.push_str("\n⚠️  Warning: This switch is referenced by switch containers.");
-        }
-
-        match input.confirm(&prompt, Some(false)) {
+        match input.confirm(
+            &format!("Delete switch '{}'? This cannot be undone.", name),
+            Some(false),
+        ) {
             Ok(value) => value,
             Err(_) => {
                 // Non-interactive mode without --force
@@ -942,7 +950,7 @@ async fn delete_switch(
     }
 
     // Step 6: Remove file
-    fs::remove_file(&switch_file_path).context(format!(
+    safe_remove_within(&current_dir, &switch_file_path).context(format!(
         "Failed to delete switch file: {}",
         switch_file_path.display()
     ))?;
@@ -969,10 +977,3 @@ async fn delete_switch(
 
     Ok(())
 }
-
-/// Check if a switch is referenced by any switch containers.
-fn check_switch_references(_validator: &ProjectValidator, _switch_id: u64) -> bool {
-    // For now, return false as switch container implementation is in Story 4.3
-    // This will be enhanced when switch containers are implemented
-    false
-}
@@ -16,8 +16,8 @@
 //!
 //! Implements CRUD operations for SwitchContainer assets in Amplitude projects.
 
-use std::env;
 use std::fs;
+use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::{Context, Result};
@@ -26,23 +26,26 @@ use colored::Colorize;
 use inquire::validator::Validation;
 use serde_json::json;
 
+use crate::common::ignore::WalkOptions;
+use crate::common::project_option::project_dir;
 use crate::common::utils::generate_unique_id;
 use crate::{
     assets::{
         Asset, AssetType, ProjectContext, ProjectValidator, SwitchContainer,
-        generated::SwitchContainerEntry,
+        generated::SwitchContainerEntry, graph::AssetKey,
     },
     common::{
         errors::{CliError, asset_already_exists, asset_not_found, codes},
-        files::atomic_write,
+        files::{atomic_write, safe_remove_within, to_json_pretty},
+        fs_ctx,
         utils::read_amproject_file,
     },
     database::Database,
-    input::Input,
-    presentation::{Output, OutputMode},
+    input::{Input, with_flag_hint},
+    presentation::{ListLimit, Output, OutputMode, insert_os_str},
 };
 
-use super::find_json_files_recursive;
+use super::{ensure_not_referenced, find_json_files_recursive};
 
 /// The name of the current asset.
 const ASSET_NAME: &str = "Switch Container";
@@ -57,9 +60,6 @@ const NO_REFERENCE: u64 = 0;
 #[derive(Subcommand, Debug)]
 pub enum SwitchContainerCommands {
     /// Create a new switch container asset
-    #[command(
-        after_help = "Examples:\n  am asset switch-container create footsteps\n  am asset switch-container create footsteps --switch surface_type --map wood=wood_step\n"
-    )]
     Create {
         /// Name of the switch container asset
         name: String,
@@ -74,15 +74,12 @@ pub enum SwitchContainerCommands {
     },
 
     /// List all switch container assets in the project
-    #[command(
-        after_help = "Examples:\n  am asset switch-container list\n  am asset switch-container list --json\n"
-    )]
-    List {},
+    List {
+        #[command(flatten)]
+        limit: ListLimit,
+    },
 
     /// Update an existing switch container asset
-    #[command(
-        after_help = "Examples:\n  am asset switch-container update footsteps\n  am asset switch-container update footsteps --map stone=stone_step\n"
-    )]
     Update {
         /// Name of the switch container asset to update
         name: String,
@@ -93,9 +90,6 @@ pub enum SwitchContainerCommands {
     },
 
     /// Delete a switch container asset
-    #[command(
-        after_help = "Examples:\n  am asset switch-container delete footsteps\n  am asset switch-container delete footsteps --force\n"
-    )]
     Delete {
         /// Name of the switch container asset to delete
         name: String,
@@ -109,6 +103,8 @@ pub enum SwitchContainerCommands {
 /// Handle switch container commands by routing to the appropriate handler.
 pub async fn handler(
     command: &SwitchContainerCommands,
+    project: Option<&Path>,
+    walk: WalkOptions,
     _database: Option<Arc<Database>>,
     input: &dyn Input,
     output: &dyn Output,
@@ -118,13 +114,26 @@ pub async fn handler(
             name,
             switch,
             mappings,
-        } => create_switch_container(name, switch.clone(), mappings.clone(), input, output).await,
-        SwitchContainerCommands::List {} => list_switch_containers(output).await,
+        } => {
+            create_switch_container(
+                project,
+                walk,
+                name,
+                switch.clone(),
+                mappings.clone(),
+                input,
+                output,
+            )
+            .await
+        }
+        SwitchContainerCommands::List { limit } => {
+            list_switch_containers(project, walk, limit, output).await
+        }
         SwitchContainerCommands::Update { name, mappings } => {
-            update_switch_container(name, mappings.clone(), input, output).await
+            update_switch_container(project, walk, name, mappings.clone(), input, output).await
         }
         SwitchContainerCommands::Delete { name, force } => {
-            delete_switch_container(name, *force, input, output).await
+            delete_switch_container(project, walk, name, *force, input, output).await
         }
     }
 }
@@ -135,6 +144,8 @@ pub async fn handler(
 
 /// Create a new switch container asset.
 async fn create_switch_container(
+    project: Option<&Path>,
+    walk: WalkOptions,
     name: &str,
     switch: Option<String>,
     mappings: Option<Vec<String>>,
@@ -153,7 +164,7 @@ async fn create_switch_container(
     }
 
     // Step 2: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     output.progress(&format!(
@@ -167,7 +178,8 @@ async fn create_switch_container(
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let switch_containers_dir = sources_base.join("switch_containers");
+    let switch_containers_dir =
+        sources_base.join(project_config.asset_dir(AssetType::SwitchContainer));
     let container_file_path = switch_containers_dir.join(format!("{}.json", name));
 
     if container_file_path.exists() {
@@ -180,7 +192,7 @@ async fn create_switch_container(
     }
 
     // Build populated ProjectContext for validation
-    let validator = ProjectValidator::new(current_dir.clone(), output)?;
+    let validator = ProjectValidator::new(current_dir.clone(), walk, output)?;
     let context = ProjectContext::new(current_dir.clone()).with_validator(validator);
 
     // Check name uniqueness via ProjectContext registry
@@ -206,7 +218,7 @@ async fn create_switch_container(
         })?
     } else {
         // Interactive mode: prompt for switch selection
-        prompt_switch_selection(input, output, &context)?
+        prompt_switch_selection(&with_flag_hint(input, "--switch <name>"), output, &context)?
     };
 
     // Step 5: Get state-to-sound mappings
@@ -256,27 +268,25 @@ async fn create_switch_container(
     container.validate_rules(&context)?;
 
     // Step 9: Serialize to JSON
-    let json_content = serde_json::to_string_pretty(&container)
-        .context("Failed to serialize switch container to JSON")?;
+    let json_content =
+        to_json_pretty(&container).context("Failed to serialize switch container to JSON")?;
 
     // Step 10: Ensure directory exists and write atomically
-    fs::create_dir_all(&switch_containers_dir)?;
+    fs_ctx::create_dir_all(&switch_containers_dir)?;
     atomic_write(&container_file_path, json_content.as_bytes())?;
 
     // Step 11: Output success
     let entry_count = container.entries.as_ref().map(|e| e.len()).unwrap_or(0);
     match output.mode() {
         OutputMode::Json => {
-            output.success(
-                json!({
-                    "id": container.id,
-                    "name": container.name(),
-                    "path": container_file_path.to_string_lossy(),
-                    "switch_group": switch_info.name,
-                    "mapping_count": entry_count,
-                }),
-                None,
-            );
+            let mut value = json!({
+                "id": container.id,
+                "name": container.name(),
+                "switch_group": switch_info.name,
+                "mapping_count": entry_count,
+            });
+            insert_os_str(&mut value, "path", container_file_path.as_os_str());
+            output.success(value, None);
         }
         OutputMode::Interactive => {
             output.success(
@@ -308,24 +318,26 @@ struct SwitchStateInfo {
     name: String,
 }
 
-/// Resolve the sources directory from the project config.
-fn resolve_sources_dir(project_root: &std::path::Path) -> std::path::PathBuf {
+/// Resolve the directory holding assets of the given type from the project config.
+fn resolve_asset_dir(project_root: &std::path::Path, asset_type: AssetType) -> std::path::PathBuf {
     match read_amproject_file(project_root) {
         Ok(config) => {
-            if config.sources_dir.is_empty() {
+            let sources_dir = if config.sources_dir.is_empty() {
                 project_root.to_path_buf()
             } else {
                 project_root.join(&config.sources_dir)
-            }
+            };
+            sources_dir.join(config.asset_dir(asset_type))
         }
-        Err(_) => project_root.join("sources"),
+        Err(_) => project_root
+            .join("sources")
+            .join(asset_type.directory_name()),
     }
 }
 
 /// Find a switch by name and return its info.
 fn find_switch_by_name(context: &ProjectContext, name: &str) -> Result<Option<SwitchInfo>> {
-    let sources_dir = resolve_sources_dir(&context.project_root);
-    let switches_dir = sources_dir.join("switches");
+    let switches_dir = resolve_asset_dir(&context.project_root, AssetType::Switch);
     let switch_file = switches_dir.join(format!("{}.json", name));
 
     if !switch_file.exists() {
@@ -334,7 +346,8 @@ fn find_switch_by_name(context: &ProjectContext, name: &str) -> Result<Option<Sw
 
     let content = fs::read_to_string(&switch_file)
         .with_context(|| format!("Failed to read switch file: {}", switch_file.display()))?;
-    let switch: crate::assets::Switch = serde_json::from_str(&content)
+    let switch: crate::assets::Switch = context
+        .parse_asset_json(&content)
         .with_context(|| format!("Failed to parse switch file: {}", switch_file.display()))?;
 
     let states: Vec<SwitchStateInfo> = switch
@@ -365,8 +378,7 @@ fn get_available_switches(
     context: &ProjectContext,
     output: &dyn Output,
 ) -> Result<Vec<SwitchInfo>> {
-    let sources_dir = resolve_sources_dir(&context.project_root);
-    let switches_dir = sources_dir.join("switches");
+    let switches_dir = resolve_asset_dir(&context.project_root, AssetType::Switch);
 
     if !switches_dir.exists() {
         return Ok(Vec::new());
@@ -380,7 +392,7 @@ fn get_available_switches(
 
         if path.extension().map(|e| e == "json").unwrap_or(false) {
             match fs::read_to_string(&path) {
-                Ok(content) => match serde_json::from_str::<crate::assets::Switch>(&content) {
+                Ok(content) => match context.parse_asset_json::<crate::assets::Switch>(&content) {
                     Ok(switch) => {
                         let states: Vec<SwitchStateInfo> = switch
                             .states
@@ -645,25 +657,23 @@ fn prompt_mappings(
 
 /// Find an asset (sound or collection) ID by name.
 fn find_asset_id_by_name(context: &ProjectContext, name: &str) -> Result<Option<u64>> {
-    let sources_dir = resolve_sources_dir(&context.project_root);
-
     // Try sounds first
-    let sounds_dir = sources_dir.join("sounds");
+    let sounds_dir = resolve_asset_dir(&context.project_root, AssetType::Sound);
     let sound_file = sounds_dir.join(format!("{}.json", name));
 
     if sound_file.exists() {
         let content = fs::read_to_string(&sound_file)?;
-        let sound: crate::assets::Sound = serde_json::from_str(&content)?;
+        let sound: crate::assets::Sound = context.parse_asset_json(&content)?;
         return Ok(Some(sound.id));
     }
 
     // Try collections
-    let collections_dir = sources_dir.join("collections");
+    let collections_dir = resolve_asset_dir(&context.project_root, AssetType::Collection);
     let collection_file = collections_dir.join(format!("{}.json", name));
 
     if collection_file.exists() {
         let content = fs::read_to_string(&collection_file)?;
-        let collection: crate::assets::Collection = serde_json::from_str(&content)?;
+        let collection: crate::assets::Collection = context.parse_asset_json(&content)?;
         return Ok(Some(collection.id));
     }
 
@@ -672,8 +682,7 @@ fn find_asset_id_by_name(context: &ProjectContext, name: &str) -> Result<Option<
 
 /// Get all available sounds in the project.
 fn get_available_sounds(context: &ProjectContext) -> Result<Vec<(String, u64)>> {
-    let sources_dir = resolve_sources_dir(&context.project_root);
-    let sounds_dir = sources_dir.join("sounds");
+    let sounds_dir = resolve_asset_dir(&context.project_root, AssetType::Sound);
 
     if !sounds_dir.exists() {
         return Ok(Vec::new());
@@ -687,7 +696,7 @@ fn get_available_sounds(context: &ProjectContext) -> Result<Vec<(String, u64)>>
 
         if path.extension().map(|e| e == "json").unwrap_or(false) {
             if let Ok(content) = fs::read_to_string(&path) {
-                if let Ok(sound) = serde_json::from_str::<crate::assets::Sound>(&content) {
+                if let Ok(sound) = context.parse_asset_json::<crate::assets::Sound>(&content) {
                     sounds.push((sound.name().to_string(), sound.id));
                 }
             }
@@ -700,8 +709,7 @@ fn get_available_sounds(context: &ProjectContext) -> Result<Vec<(String, u64)>>
 
 /// Get all available collections in the project.
 fn get_available_collections(context: &ProjectContext) -> Result<Vec<(String, u64)>> {
-    let sources_dir = resolve_sources_dir(&context.project_root);
-    let collections_dir = sources_dir.join("collections");
+    let collections_dir = resolve_asset_dir(&context.project_root, AssetType::Collection);
 
     if !collections_dir.exists() {
         return Ok(Vec::new());
@@ -715,7 +723,8 @@ fn get_available_collections(context: &ProjectContext) -> Result<Vec<(String, u6
 
         if path.extension().map(|e| e == "json").unwrap_or(false) {
             if let Ok(content) = fs::read_to_string(&path) {
-                if let Ok(collection) = serde_json::from_str::<crate::assets::Collection>(&content)
+                if let Ok(collection) =
+                    context.parse_asset_json::<crate::assets::Collection>(&content)
                 {
                     collections.push((collection.name().to_string(), collection.id));
                 }
@@ -732,9 +741,14 @@ fn get_available_collections(context: &ProjectContext) -> Result<Vec<(String, u6
 // =============================================================================
 
 /// List all switch container assets in the current project.
-async fn list_switch_containers(output: &dyn Output) -> Result<()> {
+async fn list_switch_containers(
+    project: Option<&Path>,
+    walk: WalkOptions,
+    limit: &ListLimit,
+    output: &dyn Output,
+) -> Result<()> {
     // Step 1: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     // Step 2: Scan switch_containers directory
@@ -743,7 +757,7 @@ async fn list_switch_containers(output: &dyn Output) -> Result<()> {
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let containers_dir = sources_base.join("switch_containers");
+    let containers_dir = sources_base.join(project_config.asset_dir(AssetType::SwitchContainer));
 
     // Step 3: Handle missing directory
     if !containers_dir.exists() {
@@ -770,14 +784,14 @@ async fn list_switch_containers(output: &dyn Output) -> Result<()> {
     }
 
     // Build context for resolving references
-    let validator = ProjectValidator::new(current_dir.clone(), output)?;
+    let validator = ProjectValidator::new(current_dir.clone(), walk, output)?;
     let context = ProjectContext::new(current_dir.clone()).with_validator(validator);
 
     // Step 4: Read and parse all .json files recursively
     let mut containers: Vec<SwitchContainer> = Vec::new();
     let mut warnings: Vec<String> = Vec::new();
 
-    let json_files = match find_json_files_recursive(&containers_dir) {
+    let json_files = match find_json_files_recursive(&current_dir, &containers_dir, walk, output) {
         Ok(files) => files,
         Err(e) => {
             return Err(CliError::new(
@@ -794,7 +808,7 @@ async fn list_switch_containers(output: &dyn Output) -> Result<()> {
     for path in json_files {
         if path.extension().map(|e| e == "json").unwrap_or(false) {
             match fs::read_to_string(&path) {
-                Ok(content) => match serde_json::from_str::<SwitchContainer>(&content) {
+                Ok(content) => match project_config.parse_asset_json::<SwitchContainer>(&content) {
                     Ok(container) => {
                         containers.push(container);
                     }
@@ -836,6 +850,7 @@ async fn list_switch_containers(output: &dyn Output) -> Result<()> {
 
     // Step 5: Sort by name
     containers.sort_by(|a, b| a.name().cmp(b.name()));
+    let truncation = limit.apply(&mut containers);
 
     // Step 6: Handle empty
     if containers.is_empty() {
@@ -886,14 +901,13 @@ async fn list_switch_containers(output: &dyn Output) -> Result<()> {
                 })
                 .collect();
 
-            output.success(
-                json!({
-                    "switch_containers": container_data,
-                    "count": containers.len(),
-                    "warnings": warnings
-                }),
-                None,
-            );
+            let mut payload = json!({
+                "switch_containers": container_data,
+                "count": containers.len(),
+                "warnings": warnings
+            });
+            truncation.annotate(&mut payload);
+            output.success(payload, None);
         }
         OutputMode::Interactive => {
             for warning in &warnings {
@@ -916,7 +930,8 @@ async fn list_switch_containers(output: &dyn Output) -> Result<()> {
 
             output.table(None, json!(table_data));
             output.progress("");
-            output.progress(&format!("{} switch container(s) found", containers.len()));
+            output.progress(&format!("{} switch container(s) found", truncation.total));
+            truncation.notify("switch containers", output);
         }
     }
 
@@ -951,13 +966,15 @@ fn get_switch_name(context: &ProjectContext, switch_id: u64) -> String {
 
 /// Update an existing switch container asset.
 async fn update_switch_container(
+    project: Option<&Path>,
+    walk: WalkOptions,
     name: &str,
     mappings: Option<Vec<String>>,
     input: &dyn Input,
     output: &dyn Output,
 ) -> Result<()> {
     // Step 1: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     output.progress(&format!(
@@ -971,7 +988,7 @@ async fn update_switch_container(
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let containers_dir = sources_base.join("switch_containers");
+    let containers_dir = sources_base.join(project_config.asset_dir(AssetType::SwitchContainer));
     let container_file_path = containers_dir.join(format!("{}.json", name));
 
     if !container_file_path.exists() {
@@ -988,13 +1005,14 @@ async fn update_switch_container(
         "Failed to read switch container file: {}",
         container_file_path.display()
     ))?;
-    let mut container: SwitchContainer = serde_json::from_str(&content).context(format!(
-        "Failed to parse switch container file: {}",
-        container_file_path.display()
-    ))?;
+    let mut container: SwitchContainer =
+        project_config.parse_asset_json(&content).context(format!(
+            "Failed to parse switch container file: {}",
+            container_file_path.display()
+        ))?;
 
     // Step 4: Build context and get switch info
-    let validator = ProjectValidator::new(current_dir.clone(), output)?;
+    let validator = ProjectValidator::new(current_dir.clone(), walk, output)?;
     let context = ProjectContext::new(current_dir.clone()).with_validator(validator);
 
     let switch_info =
@@ -1030,22 +1048,20 @@ async fn update_switch_container(
     container.validate_rules(&context)?;
 
     // Step 7: Serialize and write atomically
-    let json_content = serde_json::to_string_pretty(&container)
-        .context("Failed to serialize switch container to JSON")?;
+    let json_content =
+        to_json_pretty(&container).context("Failed to serialize switch container to JSON")?;
     atomic_write(&container_file_path, json_content.as_bytes())?;
 
     // Step 8: Output success
     match output.mode() {
         OutputMode::Json => {
-            output.success(
-                json!({
-                    "id": container.id,
-                    "name": container.name(),
-                    "path": container_file_path.to_string_lossy(),
-                    "updated_fields": updated_fields,
-                }),
-                None,
-            );
+            let mut value = json!({
+                "id": container.id,
+                "name": container.name(),
+                "updated_fields": updated_fields,
+            });
+            insert_os_str(&mut value, "path", container_file_path.as_os_str());
+            output.success(value, None);
         }
         OutputMode::Interactive => {
             output.success(
@@ -1178,7 +1194,7 @@ fn prompt_mappings_for_update(
             prompt,
             Some("d"),
             None,
-            Some(&|value: &str| {
+            &[&|value: &str| {
                 let trimmed = value.trim().to_lowercase();
                 if trimmed.is_empty()
                     || ["a", "add", "r", "remove", "d", "done"].contains(&trimmed.as_str())
@@ -1189,7 +1205,7 @@ fn prompt_mappings_for_update(
                         "Enter 'a' to add, 'r' to remove, or 'd' to done".into(),
                     ))
                 }
-            }),
+            }],
         )?;
 
         match choice.trim().to_lowercase().as_str() {
@@ -1375,13 +1391,15 @@ fn find_name_by_id(context: &ProjectContext, id: u64) -> Option<String> {
 
 /// Delete a switch container asset.
 async fn delete_switch_container(
+    project: Option<&Path>,
+    walk: WalkOptions,
     name: &str,
     force: bool,
     input: &dyn Input,
     output: &dyn Output,
 ) -> Result<()> {
     // Step 1: Detect project
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     // Step 2: Locate container file
@@ -1390,7 +1408,7 @@ async fn delete_switch_container(
     } else {
         current_dir.join(&project_config.sources_dir)
     };
-    let containers_dir = sources_base.join("switch_containers");
+    let containers_dir = sources_base.join(project_config.asset_dir(AssetType::SwitchContainer));
     let container_file_path = containers_dir.join(format!("{}.json", name));
 
     if !container_file_path.exists() {
@@ -1404,24 +1422,27 @@ async fn delete_switch_container(
         "Failed to read switch container file: {}",
         container_file_path.display()
     ))?;
-    let container: SwitchContainer = serde_json::from_str(&content).context(format!(
+    let container: SwitchContainer = project_config.parse_asset_json(&content).context(format!(
         "Failed to parse switch container file: {}",
         container_file_path.display()
     ))?;
 
-    // Step 4: Check for references (events and soundbanks - placeholder for now)
-    let has_references = check_container_references(&container);
+    // Step 4: Refuse to delete a switch container other assets reference
+    ensure_not_referenced(
+        &current_dir,
+        walk,
+        &AssetKey::new(AssetType::SwitchContainer, container.name()),
+        output,
+    )?;
 
     // Step 5: Confirm deletion
     let confirmed = if force {
         true
     } else {
-        let mut prompt = format!("Delete switch container '{}'? This cannot be undone.", name);
-        if has_references {
-            prompt.push_str("\n⚠️  Warning: This container is referenced by events or soundbanks.");
-        }
-
-        match input.confirm(&prompt, Some(false)) {
+        match input.confirm(
+            &format!("Delete switch container '{}'? This cannot be undone.", name),
+            Some(false),
+        ) {
             Ok(value) => value,
             Err(_) => {
                 // Non-interactive mode without --force
@@ -1445,7 +1466,7 @@ async fn delete_switch_container(
     }
 
     // Step 6: Remove file
-    fs::remove_file(&container_file_path).context(format!(
+    safe_remove_within(&current_dir, &container_file_path).context(format!(
         "Failed to delete switch container file: {}",
         container_file_path.display()
     ))?;
@@ -1472,10 +1493,3 @@ async fn delete_switch_container(
 
     Ok(())
 }
-
-/// Check if a switch container is referenced by events or soundbanks.
-fn check_container_references(_container: &SwitchContainer) -> bool {
-    // Placeholder - will be implemented when events and soundbanks are available
-    // For now, return false to not block deletion
-    false
-}
@@ -0,0 +1,273 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `am asset touch`: bumping the version field of assets.
+//!
+//! Build tooling uses the numeric `version` or `revision` field some asset schemas carry
+//! to invalidate its caches. Bumping it through the CLI keeps the edit atomic and lets
+//! every asset depending on a changed one be bumped along.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde_json::{Value, json};
+
+use crate::common::ignore::WalkOptions;
+use crate::common::project_option::project_dir;
+use crate::{
+    assets::{
+        Asset, AssetType, Collection, Effect, Event, Sound, Soundbank, Switch, SwitchContainer,
+        graph::{AssetKey, DependencyGraph},
+    },
+    common::{
+        errors::{CliError, asset_not_found, codes},
+        files::{atomic_write, parse_asset_json, to_json_pretty},
+        utils::read_amproject_file,
+    },
+    presentation::{Output, OutputMode},
+};
+
+use super::deps::{parse_asset_key, parse_asset_type};
+
+/// Fields holding the version of an asset, in order of preference.
+const VERSION_FIELDS: &[&str] = &["version", "revision"];
+
+/// A version bump computed for one asset, written once every bump is known to succeed.
+struct Bump {
+    key: AssetKey,
+    path: String,
+    file: PathBuf,
+    value: Value,
+    field: &'static str,
+    from: Option<u64>,
+    to: u64,
+}
+
+/// Increment the version field of an asset, or with `all_referencing` of every asset
+/// depending on the given one, directly or not.
+pub(crate) async fn handle_touch_command(
+    project: Option<&Path>,
+    walk: WalkOptions,
+    asset_type: Option<&str>,
+    name: Option<&str>,
+    create: bool,
+    all_referencing: Option<&str>,
+    output: &dyn Output,
+) -> Result<()> {
+    let current_dir = project_dir(project)?;
+    let graph = DependencyGraph::build(&current_dir, walk, output)?;
+
+    let keys: Vec<AssetKey> = match (asset_type, name, all_referencing) {
+        (Some(asset_type), Some(name), None) => {
+            let key = AssetKey::new(parse_asset_type(asset_type)?, name);
+            if graph.node(&key).is_none() {
+                return Err(asset_not_found(&key.asset_type.to_string(), &key.name).into());
+            }
+            vec![key]
+        }
+        (None, None, Some(asset)) => {
+            let key = parse_asset_key(asset)?;
+            if graph.node(&key).is_none() {
+                return Err(asset_not_found(&key.asset_type.to_string(), &key.name).into());
+            }
+            let dependents: BTreeSet<&AssetKey> = graph
+                .dependent_lists(&key, true)
+                .into_values()
+                .flatten()
+                .filter(|k| **k != key)
+                .collect();
+            dependents.into_iter().cloned().collect()
+        }
+        _ => {
+            return Err(CliError::new(
+                codes::ERR_VALIDATION_FIELD,
+                "Invalid asset selection",
+                "Give either an asset type and name, or --all-referencing <type>/<name>",
+            )
+            .with_suggestion("Use for example 'am asset touch --bump-version sound footstep'")
+            .into());
+        }
+    };
+
+    let config = read_amproject_file(&current_dir)?;
+    let sources_dir = if config.sources_dir.is_empty() {
+        current_dir.clone()
+    } else {
+        current_dir.join(&config.sources_dir)
+    };
+
+    // Plan every bump first, so that one asset without a version field leaves the
+    // project untouched.
+    let mut bumps = Vec::with_capacity(keys.len());
+    for key in keys {
+        let Some(node) = graph.node(&key) else {
+            continue;
+        };
+        let file = sources_dir.join(&node.path);
+        bumps.push(plan_bump(
+            key,
+            node.path.clone(),
+            file,
+            create,
+            config.allow_json_comments,
+        )?);
+    }
+
+    let mut rows = Vec::with_capacity(bumps.len());
+    for bump in bumps {
+        let json = to_json_pretty(&bump.value)?;
+        atomic_write(&bump.file, json.as_bytes())?;
+        output.progress(&format!(
+            "{} {}: {} → {}",
+            bump.key,
+            bump.field,
+            bump.from
+                .map_or_else(|| "(none)".to_string(), |v| v.to_string()),
+            bump.to
+        ));
+        rows.push(json!({
+            "type": bump.key.type_key(),
+            "name": bump.key.name,
+            "path": bump.path,
+            "field": bump.field,
+            "from": bump.from,
+            "to": bump.to,
+        }));
+    }
+
+    match output.mode() {
+        OutputMode::Json => output.success(json!({ "assets": rows }), None),
+        OutputMode::Interactive => {
+            let message = if rows.is_empty() {
+                "No asset references it, nothing to bump".to_string()
+            } else {
+                format!("Bumped the version of {} asset(s)", rows.len())
+            };
+            output.success(json!(message), None);
+        }
+    }
+
+    Ok(())
+}
+
+/// Read the asset file and compute its bumped content.
+fn plan_bump(
+    key: AssetKey,
+    path: String,
+    file: PathBuf,
+    create: bool,
+    allow_json_comments: bool,
+) -> Result<Bump> {
+    let content =
+        fs::read_to_string(&file).with_context(|| format!("Failed to read {}", file.display()))?;
+    let mut value: Value = parse_asset_json(&content, allow_json_comments)
+        .with_context(|| format!("Failed to parse {}", file.display()))?;
+
+    let existing = VERSION_FIELDS
+        .iter()
+        .copied()
+        .find(|field| value.get(field).is_some_and(|v| !v.is_null()));
+
+    let (field, from, to) = match existing {
+        Some(field) => {
+            let Some(current) = value[field].as_u64() else {
+                return Err(CliError::new(
+                    codes::ERR_VALIDATION_FIELD,
+                    format!("Invalid {} of {} '{}'", field, key.asset_type, key.name),
+                    format!("The '{}' field must be a non-negative integer", field),
+                )
+                .with_context(file.display().to_string())
+                .into());
+            };
+            let next = current.checked_add(1).ok_or_else(|| {
+                CliError::new(
+                    codes::ERR_VALIDATION_FIELD,
+                    format!("Invalid {} of {} '{}'", field, key.asset_type, key.name),
+                    format!(
+                        "The '{}' field can't be incremented past {}",
+                        field,
+                        u64::MAX
+                    ),
+                )
+            })?;
+            (field, Some(current), next)
+        }
+        None => {
+            let permitted = VERSION_FIELDS
+                .iter()
+                .copied()
+                .find(|field| schema_keeps_field(key.asset_type, &value, field));
+            match (permitted, create) {
+                (Some(field), true) => (field, None, 1),
+                (Some(field), false) => {
+                    return Err(CliError::new(
+                        codes::ERR_VALIDATION_FIELD,
+                        format!("{} '{}' has no {} field", key.asset_type, key.name, field),
+                        "Only assets carrying a version field can be bumped",
+                    )
+                    .with_suggestion("Pass --create to add the field, starting at 1")
+                    .with_context(file.display().to_string())
+                    .into());
+                }
+                (None, _) => {
+                    return Err(CliError::new(
+                        codes::ERR_VALIDATION_FIELD,
+                        format!("{} '{}' has no version field", key.asset_type, key.name),
+                        format!(
+                            "The {} schema has no 'version' or 'revision' field",
+                            key.asset_type
+                        ),
+                    )
+                    .with_context(file.display().to_string())
+                    .into());
+                }
+            }
+        }
+    };
+
+    value[field] = json!(to);
+    Ok(Bump {
+        key,
+        path,
+        file,
+        value,
+        field,
+        from,
+        to,
+    })
+}
+
+/// Whether the schema of `asset_type` has `field`: adding it to `value` must survive a
+/// round trip through the typed asset, which drops the fields it doesn't know.
+fn schema_keeps_field(asset_type: AssetType, value: &Value, field: &str) -> bool {
+    let mut probe = value.clone();
+    probe[field] = json!(1);
+    let round_trip = match asset_type {
+        AssetType::Sound => round_trip::<Sound>(probe),
+        AssetType::Collection => round_trip::<Collection>(probe),
+        AssetType::Effect => round_trip::<Effect>(probe),
+        AssetType::Switch => round_trip::<Switch>(probe),
+        AssetType::SwitchContainer => round_trip::<SwitchContainer>(probe),
+        AssetType::Event => round_trip::<Event>(probe),
+        AssetType::Soundbank => round_trip::<Soundbank>(probe),
+    };
+    round_trip.is_some_and(|v| v.get(field).is_some_and(|v| !v.is_null()))
+}
+
+fn round_trip<T: Asset>(value: Value) -> Option<Value> {
+    let asset: T = serde_json::from_value(value).ok()?;
+    serde_json::to_value(asset).ok()
+}
@@ -0,0 +1,255 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `am completions`: shell completion scripts.
+//!
+//! The script is printed to stdout, or with `--install` written where the shell loads
+//! completions from, for the shell given or else the one running the CLI:
+//!
+//! - bash: `$XDG_DATA_HOME/bash-completion/completions/am`, loaded by bash-completion.
+//! - zsh: `~/.zfunc/_am`, which must be in `fpath`.
+//! - fish: `~/.config/fish/completions/am.fish`.
+//! - PowerShell: a script next to the user profile, which must dot-source it.
+//!
+//! An existing file is backed up to `<file>.bak` before being replaced.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use clap::CommandFactory;
+use clap_complete::{Shell, generate};
+use serde_json::json;
+
+use crate::app::App;
+use crate::common::errors::{CliError, codes};
+use crate::common::fs_ctx;
+use crate::common::paths::HomeEnv;
+use crate::common::process::parent_process_name;
+use crate::presentation::{Output, OutputMode};
+
+/// Where the completion script of a shell is installed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletionTarget {
+    /// File the script is written to.
+    pub path: PathBuf,
+    /// What the user still has to do for the shell to load the script, if anything.
+    pub manual_step: Option<String>,
+}
+
+/// Print the completion script of `shell`, or install it with `install`.
+pub fn handler(
+    shell: Option<Shell>,
+    install: bool,
+    dry_run: bool,
+    output: &dyn Output,
+) -> Result<()> {
+    if !install {
+        let shell = shell.expect("clap requires a shell without --install");
+        generate(shell, &mut App::command(), "am", &mut std::io::stdout());
+        return Ok(());
+    }
+
+    let shell = match shell {
+        Some(shell) => shell,
+        None => detect_shell(
+            std::env::var("SHELL").ok().as_deref(),
+            parent_process_name().as_deref(),
+        )
+        .ok_or_else(|| {
+            CliError::new(
+                codes::ERR_VALIDATION_FIELD,
+                "Cannot detect the shell",
+                "SHELL is not set and the parent process is not a known shell",
+            )
+            .with_suggestion("Pass the shell, e.g. 'am completions zsh --install'")
+        })?,
+    };
+
+    install_completions(shell, &HomeEnv::current(), dry_run, output)
+}
+
+/// The shell named by the `SHELL` variable, or else by the name of the parent process.
+pub fn detect_shell(shell_var: Option<&str>, parent_process: Option<&str>) -> Option<Shell> {
+    shell_var
+        .and_then(shell_from_program)
+        .or_else(|| parent_process.and_then(shell_from_program))
+}
+
+/// The shell whose executable is `program`, a path or a name like `pwsh.exe`.
+fn shell_from_program(program: &str) -> Option<Shell> {
+    let name = Path::new(program)
+        .file_stem()?
+        .to_str()?
+        .to_ascii_lowercase();
+    // Login shells are reported with a leading dash, e.g. `-zsh`
+    match name.trim_start_matches('-') {
+        "bash" => Some(Shell::Bash),
+        "zsh" => Some(Shell::Zsh),
+        "fish" => Some(Shell::Fish),
+        "pwsh" | "powershell" => Some(Shell::PowerShell),
+        "elvish" => Some(Shell::Elvish),
+        _ => None,
+    }
+}
+
+/// Where the completion script of `shell` is installed for the user of `env`.
+///
+/// `None` if the home directory is unknown or the shell has no conventional location.
+pub fn completion_target(shell: Shell, env: &HomeEnv) -> Option<CompletionTarget> {
+    let home = env.home.as_ref()?;
+
+    let target = match shell {
+        Shell::Bash => CompletionTarget {
+            path: env
+                .xdg_data_home
+                .clone()
+                .unwrap_or_else(|| home.join(".local").join("share"))
+                .join("bash-completion")
+                .join("completions")
+                .join("am"),
+            manual_step: None,
+        },
+        Shell::Zsh => CompletionTarget {
+            path: home.join(".zfunc").join("_am"),
+            manual_step: Some(
+                "Add 'fpath+=~/.zfunc' to ~/.zshrc, before 'autoload -Uz compinit && compinit'"
+                    .to_string(),
+            ),
+        },
+        Shell::Fish => CompletionTarget {
+            path: home
+                .join(".config")
+                .join("fish")
+                .join("completions")
+                .join("am.fish"),
+            manual_step: None,
+        },
+        Shell::PowerShell => {
+            let profile_dir = if cfg!(windows) {
+                home.join("Documents").join("PowerShell")
+            } else {
+                home.join(".config").join("powershell")
+            };
+            let path = profile_dir.join("am-completions.ps1");
+            CompletionTarget {
+                manual_step: Some(format!(
+                    "Add \". '{}'\" to your PowerShell profile ($PROFILE)",
+                    path.display()
+                )),
+                path,
+            }
+        }
+        _ => return None,
+    };
+
+    Some(target)
+}
+
+/// Write the completion script of `shell` to its [`completion_target`], backing up the
+/// file it replaces. With `dry_run` only the target is reported.
+pub fn install_completions(
+    shell: Shell,
+    env: &HomeEnv,
+    dry_run: bool,
+    output: &dyn Output,
+) -> Result<()> {
+    if env.home.is_none() {
+        return Err(CliError::new(
+            codes::ERR_HOME_NOT_FOUND,
+            "Cannot install completions",
+            "The home directory can't be determined",
+        )
+        .with_suggestion(format!(
+            "Run 'am completions {}' and write the script where your shell loads it",
+            shell
+        ))
+        .into());
+    }
+
+    let Some(target) = completion_target(shell, env) else {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            format!("Cannot install completions for {}", shell),
+            "The shell has no conventional location for completion scripts",
+        )
+        .with_suggestion(format!(
+            "Run 'am completions {}' and load the script from your shell configuration",
+            shell
+        ))
+        .into());
+    };
+
+    let backup = target.path.exists().then(|| backup_path(&target.path));
+
+    if !dry_run {
+        let mut script = Vec::new();
+        generate(shell, &mut App::command(), "am", &mut script);
+
+        if let Some(parent) = target.path.parent() {
+            fs_ctx::create_dir_all(parent)?;
+        }
+        if let Some(backup) = &backup {
+            fs_ctx::copy(&target.path, backup)?;
+        }
+        fs_ctx::write(&target.path, script)?;
+    }
+
+    match output.mode() {
+        OutputMode::Json => output.success(
+            json!({
+                "shell": shell.to_string(),
+                "path": target.path,
+                "backup": backup,
+                "manual_step": target.manual_step,
+                "dry_run": dry_run,
+            }),
+            None,
+        ),
+        OutputMode::Interactive => {
+            if let Some(backup) = &backup {
+                output.progress(&format!(
+                    "{} the existing file to {}",
+                    if dry_run {
+                        "Would back up"
+                    } else {
+                        "Backed up"
+                    },
+                    backup.display()
+                ));
+            }
+            if let Some(step) = &target.manual_step {
+                output.print(&format!("Remaining step: {}", step));
+            }
+            let message = if dry_run {
+                format!(
+                    "Would write {} completions to {}",
+                    shell,
+                    target.path.display()
+                )
+            } else {
+                format!("Wrote {} completions to {}", shell, target.path.display())
+            };
+            output.success(json!(message), None);
+        }
+    }
+
+    Ok(())
+}
+
+/// The backup of `path`: the same file name with a `.bak` suffix.
+fn backup_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".bak");
+    path.with_file_name(name)
+}
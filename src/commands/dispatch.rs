@@ -0,0 +1,139 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Dispatch of parsed commands to their handlers.
+//!
+//! Every command enum implements [`CommandHandler`], which forwards it to the handler
+//! of its group. `main` runs the parsed [`Commands`] through it, and so does the test
+//! harness, so the two cannot route a command differently. The `match` over
+//! [`Commands`] is exhaustive: a new command does not compile until it is routed.
+//!
+//! [`method_name`] derives the stable method name of a command from its path
+//! (`am project init` is `project.init`), as reported by `am introspect commands`.
+
+use std::future::Future;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::app::Commands;
+use crate::commands::{
+    asset::AssetCommands, introspect::IntrospectCommands, project::ProjectCommands,
+    sdk::SdkCommands, stats::StatsCommands, sudo::SudoCommands, template::TemplateCommands,
+};
+use crate::common::ignore::WalkOptions;
+use crate::database::Database;
+use crate::input::Input;
+use crate::presentation::Output;
+
+/// Boxed future returned by [`CommandHandler::dispatch`].
+pub type HandlerFuture<'a> = Pin<Box<dyn Future<Output = Result<()>> + 'a>>;
+
+/// A command enum that can be dispatched to its handler.
+pub trait CommandHandler {
+    /// Invoke the handler for this command.
+    ///
+    /// `project` is the directory given with `--project`, for the commands working on
+    /// the project of the current directory. `walk` is how the project files are walked,
+    /// from `--follow-symlinks` and `--max-depth`.
+    fn dispatch<'a>(
+        &'a self,
+        project: Option<&'a Path>,
+        walk: WalkOptions,
+        database: Option<Arc<Database>>,
+        input: &'a dyn Input,
+        output: &'a dyn Output,
+    ) -> HandlerFuture<'a>;
+}
+
+macro_rules! impl_command_handler {
+    ($($command:ty => $handler:path),* $(,)?) => {
+        $(
+            impl CommandHandler for $command {
+                fn dispatch<'a>(
+                    &'a self,
+                    project: Option<&'a Path>,
+                    walk: WalkOptions,
+                    database: Option<Arc<Database>>,
+                    input: &'a dyn Input,
+                    output: &'a dyn Output,
+                ) -> HandlerFuture<'a> {
+                    Box::pin($handler(self, project, walk, database, input, output))
+                }
+            }
+        )*
+    };
+}
+
+impl_command_handler! {
+    AssetCommands => crate::commands::asset::handler,
+    ProjectCommands => crate::commands::project::handler,
+    SdkCommands => crate::commands::sdk::handler,
+    SudoCommands => crate::commands::sudo::handler,
+    TemplateCommands => crate::commands::template::handler,
+    StatsCommands => crate::commands::stats::handler,
+    IntrospectCommands => crate::commands::introspect::handler,
+}
+
+impl CommandHandler for Commands {
+    fn dispatch<'a>(
+        &'a self,
+        project: Option<&'a Path>,
+        walk: WalkOptions,
+        database: Option<Arc<Database>>,
+        input: &'a dyn Input,
+        output: &'a dyn Output,
+    ) -> HandlerFuture<'a> {
+        match self {
+            Commands::Asset { command } => command.dispatch(project, walk, database, input, output),
+            Commands::Project { command } => {
+                command.dispatch(project, walk, database, input, output)
+            }
+            Commands::Sdk { command } => command.dispatch(project, walk, database, input, output),
+            Commands::Sudo { command } => command.dispatch(project, walk, database, input, output),
+            Commands::Template { command } => {
+                command.dispatch(project, walk, database, input, output)
+            }
+            Commands::Stats { command } => command.dispatch(project, walk, database, input, output),
+            Commands::Introspect { command } => {
+                command.dispatch(project, walk, database, input, output)
+            }
+            Commands::Examples { command, copy } => {
+                Box::pin(async move { crate::commands::examples::handler(command, *copy, output) })
+            }
+            Commands::Explain { code } => {
+                Box::pin(async move { crate::commands::explain::handler(code, output) })
+            }
+            Commands::Completions {
+                shell,
+                install,
+                dry_run,
+            } => Box::pin(async move {
+                crate::commands::completions::handler(*shell, *install, *dry_run, output)
+            }),
+        }
+    }
+}
+
+/// The method name of the command at `path`, e.g. `project.init` for `am project init`.
+///
+/// The binary name is dropped, and the remaining words are joined with dots.
+pub fn method_name(path: &str) -> String {
+    path.split_whitespace()
+        .skip(1)
+        .collect::<Vec<_>>()
+        .join(".")
+}
@@ -0,0 +1,1151 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Curated example command lines.
+//!
+//! [`EXAMPLES`] is the single source of the examples of every command: the first two of
+//! a command are shown in its `--help` by [`with_examples`], and `am examples` lists
+//! them all.
+
+use clap::Command;
+use serde::Serialize;
+use serde_json::json;
+
+use crate::common::errors::{CliError, codes};
+use crate::presentation::{Output, OutputMode};
+
+/// Number of examples shown in the help of a command.
+pub const HELP_EXAMPLES: usize = 2;
+
+/// An example command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Example {
+    /// Path of the command, without `am`, e.g. `project init`.
+    pub command: &'static str,
+    /// The command line, as typed in a shell.
+    pub line: &'static str,
+    /// What the command line does, in one sentence.
+    pub description: &'static str,
+}
+
+const fn example(command: &'static str, line: &'static str, description: &'static str) -> Example {
+    Example {
+        command,
+        line,
+        description,
+    }
+}
+
+/// Every example, grouped by command with the most useful ones first.
+pub const EXAMPLES: &[Example] = &[
+    // Project
+    example(
+        "project init",
+        "am project init my_game",
+        "Create a project from the default template",
+    ),
+    example(
+        "project init",
+        "am project init my_game --template o3de",
+        "Create a project from the o3de template",
+    ),
+    example(
+        "project init",
+        "am project init my_game --asset-dir sound=sfx --asset-dir event=triggers",
+        "Store sounds in sfx/ and events in triggers/ instead of the default directories",
+    ),
+    example(
+        "project init",
+        "am project init my_game --template studio --var author='Jane Doe'",
+        "Fill in the {{author}} variable of the studio template",
+    ),
+    example(
+        "project init",
+        "am project init my_game --with-examples",
+        "Create a project with example assets to start from",
+    ),
+    example(
+        "project init",
+        "am project init my_game --overwrite-dir --replace-registration",
+        "Recreate a project, replacing its directory and its registration",
+    ),
+    example(
+        "project init",
+        "am project init my_game --template my_template --verify",
+        "Create a project from a template on a network drive, checking every copied file",
+    ),
+    example(
+        "project init",
+        "am project init my_game --template my_template --dry-run",
+        "Show the directories and files a project would get, without creating it",
+    ),
+    example(
+        "project register",
+        "am project register /path/to/project",
+        "Register an existing project",
+    ),
+    example(
+        "project register",
+        "am project register /path/to/projects --recursive",
+        "Register every project found below a directory",
+    ),
+    example(
+        "project register",
+        "am project register /path/to/projects --recursive --rename-on-conflict",
+        "Register every project below a directory, numbering names already taken",
+    ),
+    example(
+        "project register",
+        "am project register /path/to/moved_project --force",
+        "Point an existing registration to a project that moved",
+    ),
+    example(
+        "project register",
+        "am project register /path/to/project --name my_game_feature",
+        "Register a project under another name than the one in its .amproject",
+    ),
+    example(
+        "project register",
+        "echo '[{\"path\": \"/path/to/project\"}]' | am --json project register --stdin",
+        "Register the projects listed as JSON on the standard input",
+    ),
+    example(
+        "project copy",
+        "am project copy my_game my_prototype",
+        "Copy a registered project next to it and register the copy",
+    ),
+    example(
+        "project copy",
+        "am project copy my_game my_prototype --path /path/to/my_prototype",
+        "Copy a registered project to another directory",
+    ),
+    example(
+        "project copy",
+        "am project copy my_game my_prototype --no-register",
+        "Copy a registered project without registering the copy",
+    ),
+    example(
+        "project copy",
+        "am project copy my_game my_prototype --verify",
+        "Copy a project over a network drive, checking the checksum of every copied file",
+    ),
+    example(
+        "project unregister",
+        "am project unregister my_game",
+        "Remove a project from the registry, keeping its files",
+    ),
+    example(
+        "project unregister",
+        "am project unregister my_game --delete-files",
+        "Remove a project from the registry and delete its files",
+    ),
+    example(
+        "project unregister",
+        "am project unregister",
+        "Pick a registered project to unregister",
+    ),
+    example(
+        "project unregister",
+        "am --json project unregister my_game --yes",
+        "Unregister a project without confirmation, reporting its name and path as JSON",
+    ),
+    example(
+        "project unregister",
+        "am project unregister --match 'test_*'",
+        "Unregister every project whose name matches a pattern",
+    ),
+    example(
+        "project unregister",
+        "am project unregister --match 'test_*' --yes",
+        "Unregister the matching projects without confirmation",
+    ),
+    example(
+        "project list",
+        "am project list",
+        "List the registered projects, favorites first",
+    ),
+    example(
+        "project list",
+        "am project list --json",
+        "List the registered projects as JSON",
+    ),
+    example(
+        "project list",
+        "am project list --favorite",
+        "List the favorite projects only",
+    ),
+    example(
+        "project list",
+        "am project list --no-favorite",
+        "List the projects that aren't favorites",
+    ),
+    example(
+        "project list",
+        "am project list --all --json",
+        "List every registered project as JSON, without the default size guard",
+    ),
+    example(
+        "project list",
+        "am project list --tree",
+        "Group the registered projects by directory",
+    ),
+    example(
+        "project list",
+        "am project list --tree ~/studio --json",
+        "Output the projects below a directory as a nested JSON tree",
+    ),
+    example(
+        "project list",
+        "am project list --sort recent",
+        "List the most recently registered projects first, after the favorites",
+    ),
+    example(
+        "project favorite",
+        "am project favorite my_game",
+        "Mark a project as a favorite",
+    ),
+    example(
+        "project favorite",
+        "am project favorite my_game --set",
+        "Mark a project as a favorite, stating it explicitly",
+    ),
+    example(
+        "project favorite",
+        "am project favorite my_game --unset",
+        "Remove a project from the favorites",
+    ),
+    example(
+        "project unfavorite",
+        "am project unfavorite my_game",
+        "Remove a project from the favorites",
+    ),
+    example(
+        "project info",
+        "am project info",
+        "Show the project in the current directory",
+    ),
+    example(
+        "project info",
+        "am project info my_game",
+        "Show a registered project",
+    ),
+    example(
+        "project info",
+        "am project info --paths",
+        "Show the resolved directories of the project in the current directory",
+    ),
+    example(
+        "project info",
+        "am project info my_game --paths --json",
+        "Get the resolved directories of a registered project as JSON",
+    ),
+    example(
+        "project info",
+        "am project info --path ~/games/my_game/.amproject",
+        "Show the project of a .amproject file",
+    ),
+    example(
+        "project info",
+        "am project info --check-paths",
+        "Check that the configured directories exist inside the project",
+    ),
+    example(
+        "project info",
+        "am project info --check-paths --create-missing",
+        "Create the configured directories that are missing",
+    ),
+    example(
+        "project size",
+        "am project size --human",
+        "Show how much disk space each part of the project uses",
+    ),
+    example(
+        "project size",
+        "am project size --by-type --human --threshold 10MiB",
+        "Find the asset types and data file types taking more than 10 MiB",
+    ),
+    example(
+        "project size",
+        "am project size --by-type --json",
+        "Get the disk usage of the project as nested JSON",
+    ),
+    example(
+        "project env",
+        "am project env",
+        "Print the variables the project's hooks get from .amenv",
+    ),
+    example(
+        "project env",
+        "am project env --mask --json",
+        "Get the .amenv variables as JSON, hiding secrets",
+    ),
+    example(
+        "project sync-registration",
+        "am project sync-registration --prefer-file",
+        "Rename the registration after the name in .amproject",
+    ),
+    example(
+        "project sync-registration",
+        "am project sync-registration --prefer-db",
+        "Rename the project in .amproject after its registration",
+    ),
+    example(
+        "project verify-registration",
+        "am project verify-registration",
+        "Check that every registered project is healthy",
+    ),
+    example(
+        "project verify-registration",
+        "am project verify-registration --prune-missing --sync-names --json",
+        "Unregister deleted projects and fix renamed ones, reporting JSON",
+    ),
+    example(
+        "project validate",
+        "am project validate",
+        "Validate every asset of the project in the current directory",
+    ),
+    example(
+        "project validate",
+        "am project validate --summary",
+        "Print a single line with the number of errors, for commit hooks",
+    ),
+    example(
+        "project validate",
+        "am project validate --sounds-only",
+        "Validate the sounds only",
+    ),
+    example(
+        "project validate",
+        "am project validate --no-orphan-check",
+        "Validate without reporting unused assets",
+    ),
+    example(
+        "project validate",
+        "am project validate --json",
+        "Get the validation report as JSON",
+    ),
+    example(
+        "project validate",
+        "am project validate --json --limit 50",
+        "Get the validation report as JSON with at most 50 issues listed",
+    ),
+    example(
+        "project validate",
+        "am project validate --project ~/games/my_game",
+        "Validate the project at a path, from any directory",
+    ),
+    example(
+        "project lint-names",
+        "am project lint-names",
+        "List the asset files whose name breaks the underscore_lowercase convention",
+    ),
+    example(
+        "project lint-names",
+        "am project lint-names --type sound",
+        "Check the sound file names only",
+    ),
+    example(
+        "project lint-names",
+        "am project lint-names --fix --yes",
+        "Rename the reported files and update the soundbanks listing them",
+    ),
+    example(
+        "project build",
+        "am project build",
+        "Compile the project assets into its build directory",
+    ),
+    example(
+        "project build",
+        "am project build --output dist/",
+        "Compile the project assets into dist/",
+    ),
+    example(
+        "project build",
+        "am project build --clean",
+        "Remove the previous build before compiling",
+    ),
+    example(
+        "project run-hook",
+        "am project run-hook post-validate",
+        "Run the post-validate hook without validating",
+    ),
+    example(
+        "project run-hook",
+        "am project run-hook generate-ids",
+        "Run a custom hook declared in .amproject",
+    ),
+    // Assets
+    example(
+        "asset sound create",
+        "am asset sound create explosion --file audio/explosion.wav",
+        "Create a sound playing data/audio/explosion.wav",
+    ),
+    example(
+        "asset sound create",
+        "am asset sound create footstep --file audio/step.wav --gain 0.8 --loop",
+        "Create a looping sound at 80% gain",
+    ),
+    example(
+        "asset sound create",
+        "am asset sound create ambient --stream --spatialization position",
+        "Create a streamed sound positioned in the world",
+    ),
+    example(
+        "asset sound list",
+        "am asset sound list",
+        "List the sounds of the project",
+    ),
+    example(
+        "asset sound list",
+        "am asset sound list --json",
+        "List the sounds of the project as JSON",
+    ),
+    example(
+        "asset sound list",
+        "am asset sound list --json --limit 100",
+        "List the first 100 sounds of the project as JSON",
+    ),
+    example(
+        "asset sound list",
+        "am asset sound list --project my_game",
+        "List the sounds of a registered project, from any directory",
+    ),
+    example(
+        "asset sound update",
+        "am asset sound update explosion --gain 0.5",
+        "Change the gain of a sound",
+    ),
+    example(
+        "asset sound update",
+        "am asset sound update ambient --file audio/new_ambient.wav --stream",
+        "Replace the audio file of a sound and stream it",
+    ),
+    example(
+        "asset collection create",
+        "am asset collection create footsteps",
+        "Create a collection playing one of its sounds",
+    ),
+    example(
+        "asset collection create",
+        "am asset collection create ambience --play-mode PlayAll --gain 0.7",
+        "Create a collection playing all of its sounds at 70% gain",
+    ),
+    example(
+        "asset collection list",
+        "am asset collection list",
+        "List the collections of the project",
+    ),
+    example(
+        "asset collection list",
+        "am asset collection list --json",
+        "List the collections of the project as JSON",
+    ),
+    example(
+        "asset collection update",
+        "am asset collection update footsteps --play-mode PlayAll",
+        "Make a collection play all of its sounds",
+    ),
+    example(
+        "asset collection update",
+        "am asset collection update ambience --gain 0.5",
+        "Change the gain of a collection",
+    ),
+    example(
+        "asset collection delete",
+        "am asset collection delete footsteps",
+        "Delete a collection after confirmation",
+    ),
+    example(
+        "asset collection delete",
+        "am asset collection delete footsteps --force",
+        "Delete a collection even if other assets use it",
+    ),
+    example(
+        "asset effect create",
+        "am asset effect create reverb",
+        "Create a reverb effect",
+    ),
+    example(
+        "asset effect create",
+        "am asset effect create eq --effect-type equalizer",
+        "Create an effect of a given type",
+    ),
+    example(
+        "asset effect list",
+        "am asset effect list",
+        "List the effects of the project",
+    ),
+    example(
+        "asset effect list",
+        "am asset effect list --json",
+        "List the effects of the project as JSON",
+    ),
+    example(
+        "asset effect update",
+        "am asset effect update reverb --effect-type hall_reverb",
+        "Change the type of an effect",
+    ),
+    example(
+        "asset effect delete",
+        "am asset effect delete reverb",
+        "Delete an effect after confirmation",
+    ),
+    example(
+        "asset effect delete",
+        "am asset effect delete reverb --force",
+        "Delete an effect even if other assets use it",
+    ),
+    example(
+        "asset switch create",
+        "am asset switch create surface_type",
+        "Create a switch, prompting for its states",
+    ),
+    example(
+        "asset switch create",
+        "am asset switch create surface_type --states wood,stone,metal",
+        "Create a switch with the given states",
+    ),
+    example(
+        "asset switch list",
+        "am asset switch list",
+        "List the switches of the project",
+    ),
+    example(
+        "asset switch update",
+        "am asset switch update surface_type",
+        "Update a switch interactively",
+    ),
+    example(
+        "asset switch update",
+        "am asset switch update surface_type --states wood,stone,grass",
+        "Replace the states of a switch",
+    ),
+    example(
+        "asset switch delete",
+        "am asset switch delete surface_type",
+        "Delete a switch after confirmation",
+    ),
+    example(
+        "asset switch delete",
+        "am asset switch delete surface_type --force",
+        "Delete a switch even if other assets use it",
+    ),
+    example(
+        "asset switch-container create",
+        "am asset switch-container create footsteps",
+        "Create a switch container, prompting for its switch",
+    ),
+    example(
+        "asset switch-container create",
+        "am asset switch-container create footsteps --switch surface_type --map wood=wood_step",
+        "Create a switch container playing wood_step for the wood state",
+    ),
+    example(
+        "asset switch-container list",
+        "am asset switch-container list",
+        "List the switch containers of the project",
+    ),
+    example(
+        "asset switch-container list",
+        "am asset switch-container list --json",
+        "List the switch containers of the project as JSON",
+    ),
+    example(
+        "asset switch-container update",
+        "am asset switch-container update footsteps",
+        "Update a switch container interactively",
+    ),
+    example(
+        "asset switch-container update",
+        "am asset switch-container update footsteps --map stone=stone_step",
+        "Play stone_step for the stone state",
+    ),
+    example(
+        "asset switch-container delete",
+        "am asset switch-container delete footsteps",
+        "Delete a switch container after confirmation",
+    ),
+    example(
+        "asset switch-container delete",
+        "am asset switch-container delete footsteps --force",
+        "Delete a switch container even if other assets use it",
+    ),
+    example(
+        "asset event create",
+        "am asset event create play_music",
+        "Create an event, prompting for its actions",
+    ),
+    example(
+        "asset event create",
+        "am asset event create play_music --action play:12345",
+        "Create an event playing the asset with id 12345",
+    ),
+    example(
+        "asset event list",
+        "am asset event list",
+        "List the events of the project",
+    ),
+    example(
+        "asset event update",
+        "am asset event update play_music",
+        "Update an event interactively",
+    ),
+    example(
+        "asset event update",
+        "am asset event update play_music --run-mode sequential",
+        "Run the actions of an event one after the other",
+    ),
+    example(
+        "asset event delete",
+        "am asset event delete play_music --yes",
+        "Delete an event without confirmation",
+    ),
+    example(
+        "asset event delete",
+        "am asset event delete play_music --yes --force",
+        "Delete an event even if other assets use it",
+    ),
+    example(
+        "asset soundbank create",
+        "am asset soundbank create main_bank",
+        "Create an empty soundbank",
+    ),
+    example(
+        "asset soundbank create",
+        "am asset soundbank create main_bank --include sound:explosion,footstep",
+        "Create a soundbank including two sounds",
+    ),
+    example(
+        "asset soundbank list",
+        "am asset soundbank list",
+        "List the soundbanks of the project",
+    ),
+    example(
+        "asset soundbank update",
+        "am asset soundbank update main_bank",
+        "Update a soundbank interactively",
+    ),
+    example(
+        "asset soundbank update",
+        "am asset soundbank update main_bank --add sound:newsfx",
+        "Add a sound to a soundbank",
+    ),
+    example(
+        "asset soundbank delete",
+        "am asset soundbank delete main_bank",
+        "Delete a soundbank after confirmation",
+    ),
+    example(
+        "asset soundbank delete",
+        "am asset soundbank delete main_bank --yes",
+        "Delete a soundbank without confirmation",
+    ),
+    example(
+        "asset create",
+        "am asset create sound footstep --from-file footstep.json",
+        "Create a sound from a complete JSON definition",
+    ),
+    example(
+        "asset create",
+        "generate-sound | am asset create sound footstep --from-stdin --on-conflict overwrite",
+        "Create or replace a sound with a definition piped by another tool",
+    ),
+    example(
+        "asset deps",
+        "am asset deps sound footstep",
+        "Show which assets use a sound",
+    ),
+    example(
+        "asset deps",
+        "am asset deps switch_container surfaces --transitive",
+        "Show the direct and indirect dependencies of a switch container",
+    ),
+    example(
+        "asset deps",
+        "am asset deps event play_footstep --json",
+        "Get the dependencies of an event as JSON",
+    ),
+    example(
+        "asset graph",
+        "am asset graph > assets.dot",
+        "Write the dependency graph of the project as Graphviz",
+    ),
+    example(
+        "asset graph",
+        "am asset graph --format mermaid",
+        "Print the dependency graph as a Mermaid diagram",
+    ),
+    example(
+        "asset graph",
+        "am asset graph --focus event/play_footstep | dot -Tsvg -o footstep.svg",
+        "Render the assets linked to an event as an SVG image",
+    ),
+    example(
+        "asset open",
+        "am asset open sound footstep --wait",
+        "Edit a sound in your editor and validate it once the editor exits",
+    ),
+    example(
+        "asset open",
+        "am asset open event play_footstep --print-path",
+        "Print the path of the file defining an event",
+    ),
+    example(
+        "asset touch",
+        "am asset touch --bump-version sound footstep",
+        "Increment the version field of a sound",
+    ),
+    example(
+        "asset touch",
+        "am asset touch --bump-version --create event play_footstep",
+        "Add a version field starting at 1 to an event",
+    ),
+    example(
+        "asset touch",
+        "am asset touch --bump-version --all-referencing sound/footstep",
+        "Bump every asset depending on a sound",
+    ),
+    // Templates
+    example(
+        "template list",
+        "am template list",
+        "List the available templates",
+    ),
+    example(
+        "template info",
+        "am template info default",
+        "Show the files and metadata of a template",
+    ),
+    example(
+        "template register",
+        "am template register /path/to/template --name my_template",
+        "Register a custom template under a name",
+    ),
+    example(
+        "template add",
+        "am template add --scan /shared/amplitude-templates",
+        "Register every template found in a shared folder",
+    ),
+    example(
+        "template add",
+        "am template add --scan /shared/amplitude-templates --prune-missing",
+        "Sync the registered templates with a shared folder",
+    ),
+    example(
+        "template unregister",
+        "am template unregister my_template",
+        "Unregister a custom template, keeping its files",
+    ),
+    example(
+        "template update",
+        "am template update my_template --path /new/path/to/template",
+        "Point a template to its new location",
+    ),
+    example(
+        "template update",
+        "am template update my_template --path /new/path/to/template --rename new_name",
+        "Move and rename a template at once",
+    ),
+    example(
+        "template update",
+        "am template update --revalidate-all",
+        "Check that every registered template is still valid",
+    ),
+    example(
+        "template export",
+        "am template export my_template",
+        "Package a template as my_template.amtpl to share it",
+    ),
+    example(
+        "template export",
+        "am template export my_template --output shared/my_template.amtpl",
+        "Package a template into a given archive",
+    ),
+    example(
+        "template export",
+        "am template export my_template --verify",
+        "Package a template, then read the archive back to check every file",
+    ),
+    example(
+        "template import",
+        "am template import my_template.amtpl",
+        "Install and register a shared template",
+    ),
+    example(
+        "template import",
+        "am template import my_template.amtpl --name team_template",
+        "Install a shared template under another name",
+    ),
+    example(
+        "template import",
+        "am template import my_template.amtpl --verify",
+        "Install a shared template, checking the checksum of every extracted file",
+    ),
+    // SDK
+    example(
+        "sdk check",
+        "am sdk check",
+        "Check that the Amplitude SDK is found and show its path",
+    ),
+    example(
+        "sdk schemas sync",
+        "am sdk schemas sync",
+        "Cache the SDK schemas locally, so validation works without the SDK",
+    ),
+    example(
+        "sdk schemas sync",
+        "am sdk schemas sync --clear",
+        "Delete every cached schema version",
+    ),
+    // Administration
+    example(
+        "sudo database reset",
+        "am sudo database reset",
+        "Reset the database, forgetting every registered project and setting",
+    ),
+    example(
+        "sudo database reset",
+        "am sudo database reset --yes --i-know-what-im-doing",
+        "Reset the database from a script, where --yes alone is refused",
+    ),
+    example(
+        "sudo database migrate",
+        "am sudo database migrate --to 4",
+        "Migrate the database schema to version 4",
+    ),
+    example(
+        "sudo database migrate",
+        "am sudo database migrate --yes",
+        "Apply every pending migration without confirmation",
+    ),
+    example(
+        "sudo database export",
+        "am sudo database export registry.json",
+        "Write the registered projects and templates to a file",
+    ),
+    example(
+        "sudo database import",
+        "am sudo database import registry.json --yes",
+        "Replace the registry with an exported one, without confirmation",
+    ),
+    example(
+        "sudo database maintain",
+        "am sudo database maintain",
+        "Prune the history tables down to their retention limits now",
+    ),
+    example(
+        "sudo database maintain",
+        "am sudo database maintain --json",
+        "Get the number of rows pruned per table as JSON",
+    ),
+    example(
+        "sudo self diagnose-db-lock",
+        "am sudo self diagnose-db-lock",
+        "Find out which process holds the database lock",
+    ),
+    example(
+        "sudo self diagnose-db-lock",
+        "am sudo self diagnose-db-lock --json",
+        "Get the lock diagnosis as JSON",
+    ),
+    example(
+        "sudo config list",
+        "am sudo config list",
+        "List the settings stored in the configuration table",
+    ),
+    example(
+        "sudo config list",
+        "am sudo config list --effective",
+        "Show the value of every setting and whether it comes from a flag, AM_* or the configuration",
+    ),
+    example(
+        "sudo logs tail",
+        "am sudo logs tail --lines 50",
+        "Show the last 50 lines of the most recent log",
+    ),
+    example(
+        "sudo logs tail",
+        "am sudo logs tail --follow",
+        "Watch the log while reproducing a problem, until Ctrl-C",
+    ),
+    example(
+        "sudo logs tail",
+        "am sudo logs tail --follow --json",
+        "Stream the log as one JSON object per line",
+    ),
+    example(
+        "sudo setup",
+        "am sudo setup",
+        "Run the setup wizard if it wasn't completed yet",
+    ),
+    example(
+        "sudo setup",
+        "am sudo setup --rerun",
+        "Run the setup wizard again",
+    ),
+    example(
+        "sudo migrate-home",
+        "am sudo migrate-home",
+        "Move ~/.amplitude to the XDG directories after confirmation",
+    ),
+    example(
+        "sudo migrate-home",
+        "am sudo migrate-home --yes",
+        "Move ~/.amplitude without confirmation",
+    ),
+    example(
+        "sudo import-legacy",
+        "am sudo import-legacy ~/.amplitude-editor/projects.json",
+        "Register the projects known to the legacy Amplitude editor",
+    ),
+    example(
+        "sudo import-legacy",
+        "am --json sudo import-legacy projects.json --initialize-missing",
+        "Also initialize the listed directories without a .amproject, reporting each entry as JSON",
+    ),
+    example(
+        "sudo register-file-types",
+        "am sudo register-file-types",
+        "Open .amproject and .amtpl files with the CLI",
+    ),
+    example(
+        "sudo register-file-types",
+        "am sudo register-file-types --unregister --dry-run",
+        "Show what removing the file associations would change",
+    ),
+    // Statistics
+    example(
+        "stats usage",
+        "am stats usage",
+        "Show which commands ran in the last 30 days, their failure rate and median duration",
+    ),
+    example(
+        "stats usage",
+        "am stats usage --days 7 --by-project",
+        "Break the last week of usage down by registered project",
+    ),
+    example(
+        "stats usage",
+        "am stats usage --project my_game",
+        "Show the usage of a single registered project",
+    ),
+    // Tooling
+    example(
+        "introspect commands",
+        "am introspect commands",
+        "Describe every command and argument as JSON",
+    ),
+    example(
+        "introspect commands",
+        "am introspect commands --json",
+        "Describe the CLI in the JSON output envelope",
+    ),
+    example(
+        "introspect envelope-schema",
+        "am introspect envelope-schema",
+        "Print the JSON Schema of the --json envelopes",
+    ),
+    example(
+        "introspect error-codes",
+        "am introspect error-codes",
+        "List the error codes with their type, suggestion and exit code",
+    ),
+    example(
+        "introspect error-codes",
+        "am introspect error-codes --json",
+        "Get the error code table as JSON, e.g. to map -29002 to its exit code",
+    ),
+    example(
+        "examples",
+        "am examples project init",
+        "List the examples of a command",
+    ),
+    example(
+        "examples",
+        "am examples project init --copy 2",
+        "Print only the command line of the second example",
+    ),
+    example(
+        "examples",
+        "am examples",
+        "List the examples of every command",
+    ),
+    example(
+        "explain",
+        "am explain -29002",
+        "Explain an error code reported by a failed command",
+    ),
+    example(
+        "explain",
+        "am explain project_not_registered",
+        "Explain an error by its type",
+    ),
+    example(
+        "explain",
+        "am explain 29002 --json",
+        "Get the causes and remediation of an error code as JSON",
+    ),
+    example(
+        "completions",
+        "am completions bash > ~/.local/share/bash-completion/completions/am",
+        "Install the bash completions of the current user",
+    ),
+    example(
+        "completions",
+        "am completions zsh > ~/.zfunc/_am",
+        "Install the zsh completions of the current user",
+    ),
+    example(
+        "completions",
+        "am completions --install",
+        "Install the completions of the current shell where it loads them from",
+    ),
+    example(
+        "completions",
+        "am completions fish --install --dry-run",
+        "Show where the fish completions would be installed",
+    ),
+];
+
+/// The examples of `command` and of its subcommands, in registry order.
+///
+/// `command` is a command path like `project init`, optionally starting with `am`. An
+/// empty path returns every example.
+pub fn examples_for(command: &str) -> Vec<&'static Example> {
+    let words: Vec<&str> = command.split_whitespace().collect();
+    let words = match words.split_first() {
+        Some((&"am", rest)) => rest,
+        _ => &words[..],
+    };
+    let path = words.join(" ");
+
+    EXAMPLES
+        .iter()
+        .filter(|example| {
+            path.is_empty()
+                || example.command == path
+                || example
+                    .command
+                    .strip_prefix(path.as_str())
+                    .is_some_and(|rest| rest.starts_with(' '))
+        })
+        .collect()
+}
+
+/// Add the first [`HELP_EXAMPLES`] examples of each subcommand of `cli` to its help.
+///
+/// The examples come before any help already set with `after_help`.
+pub fn with_examples(cli: Command) -> Command {
+    add_examples(cli, "")
+}
+
+fn add_examples(mut command: Command, path: &str) -> Command {
+    let names: Vec<String> = command
+        .get_subcommands()
+        .map(|subcommand| subcommand.get_name().to_string())
+        .collect();
+
+    for name in names {
+        let subcommand_path = if path.is_empty() {
+            name.clone()
+        } else {
+            format!("{} {}", path, name)
+        };
+        command = command.mut_subcommand(&name, |subcommand| {
+            add_examples(subcommand, &subcommand_path)
+        });
+    }
+
+    let examples = examples_for(path);
+    if path.is_empty() || examples.is_empty() {
+        return command;
+    }
+
+    let mut help = String::from("Examples:\n");
+    for example in examples.iter().take(HELP_EXAMPLES) {
+        help.push_str(&format!(
+            "  {}\n      {}\n",
+            example.line, example.description
+        ));
+    }
+    if examples.len() > HELP_EXAMPLES {
+        help.push_str(&format!("\nMore examples: am examples {}\n", path));
+    }
+    if let Some(after_help) = command.get_after_help() {
+        help.push_str(&format!("\n{}", after_help));
+    }
+
+    command.after_help(help)
+}
+
+/// Handle `am examples`.
+///
+/// Lists the examples of `command` (every command when empty), or with `copy`, only
+/// the command line of the example at this 1-based position in the list.
+pub fn handler(command: &[String], copy: Option<u32>, output: &dyn Output) -> anyhow::Result<()> {
+    let path = command.join(" ");
+    let examples = examples_for(&path);
+    if examples.is_empty() {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            format!("No examples for '{}'", path),
+            "It is not a command of am, or it has no examples",
+        )
+        .with_suggestion("Run 'am examples' to list the examples of every command")
+        .into());
+    }
+
+    if let Some(n) = copy {
+        let example = (n as usize)
+            .checked_sub(1)
+            .and_then(|i| examples.get(i))
+            .ok_or_else(|| {
+                CliError::new(
+                    codes::ERR_VALIDATION_FIELD,
+                    format!("There is no example {}", n),
+                    format!("Only {} examples are listed", examples.len()),
+                )
+                .with_suggestion(format!(
+                    "Run '{}' to list them",
+                    format!("am examples {}", path).trim_end()
+                ))
+            })?;
+
+        match output.mode() {
+            OutputMode::Json => output.success(json!(example.line), None),
+            OutputMode::Interactive => output.print(example.line),
+        }
+        return Ok(());
+    }
+
+    match output.mode() {
+        OutputMode::Json => output.success(json!(examples), None),
+        OutputMode::Interactive => {
+            let rows: Vec<serde_json::Value> = examples
+                .iter()
+                .enumerate()
+                .map(|(i, example)| {
+                    json!({
+                        "#": i + 1,
+                        "command": example.line,
+                        "description": example.description,
+                    })
+                })
+                .collect();
+            output.table(Some("Examples"), json!(rows));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,182 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `am explain <code>`: what an error code means and how to fix it.
+//!
+//! The code can be given with or without its minus sign (`-29002` or `29002`), or by its
+//! type (`project_not_registered`). Everything printed comes from [`ERROR_CODES`]; codes
+//! missing from it are explained by the range they fall in.
+
+use colored::Colorize;
+use serde_json::{Value, json};
+
+use crate::common::errors::{
+    ERROR_CODE_RANGES, ERROR_CODES, ErrorCodeInfo, ErrorCodeRange, error_code_info,
+    error_code_info_by_name, error_code_range, exit_codes,
+};
+use crate::presentation::{Output, OutputMode};
+
+/// What `query` designates: a known error code, or an unknown one (with the number it
+/// parses to, if any).
+enum Explained {
+    Known(&'static ErrorCodeInfo),
+    Unknown(Option<i32>),
+}
+
+fn lookup(query: &str) -> Explained {
+    let query = query.trim();
+    let digits = query.strip_prefix(['-', '+']).unwrap_or(query);
+
+    if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+        // Error codes are negative, a code pasted without its sign means the same one
+        return match digits.parse::<i32>() {
+            Ok(number) => match error_code_info(-number) {
+                Some(info) => Explained::Known(info),
+                None => Explained::Unknown(Some(-number)),
+            },
+            Err(_) => Explained::Unknown(None),
+        };
+    }
+
+    match error_code_info_by_name(query) {
+        Some(info) => Explained::Known(info),
+        None => Explained::Unknown(None),
+    }
+}
+
+fn exit_code_label(exit_code: i32) -> &'static str {
+    if exit_code == exit_codes::SYSTEM_ERROR {
+        "system error"
+    } else {
+        "user error"
+    }
+}
+
+fn range_to_json(range: &ErrorCodeRange) -> Value {
+    json!({
+        "start": range.start,
+        "end": range.end,
+        "description": range.description,
+    })
+}
+
+pub fn handler(query: &str, output: &dyn Output) -> anyhow::Result<()> {
+    match lookup(query) {
+        Explained::Known(info) => explain_code(info, output),
+        Explained::Unknown(code) => explain_ranges(query, code, output),
+    }
+
+    Ok(())
+}
+
+fn explain_code(info: &ErrorCodeInfo, output: &dyn Output) {
+    let range = error_code_range(info.code);
+
+    match output.mode() {
+        OutputMode::Json => output.success(
+            json!({
+                "known": true,
+                "code": info.code,
+                "name": info.name,
+                "type": info.type_name,
+                "category": range.map(|r| r.description),
+                "causes": info.causes,
+                "suggestion": info.suggestion,
+                "exit_code": info.exit_code,
+                "related_commands": info.related_commands,
+            }),
+            None,
+        ),
+        OutputMode::Interactive => {
+            output.print(&format!(
+                "{} {}",
+                format!("Error {}", info.code).bold(),
+                format!("({})", info.type_name).cyan()
+            ));
+            if let Some(range) = range {
+                output.print(&format!("  {}", range.description.dimmed()));
+            }
+            output.print("");
+            output.print(&format!("  Constant:   {}", info.name));
+            output.print(&format!(
+                "  Exit code:  {} ({})",
+                info.exit_code,
+                exit_code_label(info.exit_code)
+            ));
+
+            if !info.causes.is_empty() {
+                output.print("");
+                output.print(&"Typical causes:".bold().to_string());
+                for cause in info.causes {
+                    output.print(&format!("  {} {}", "•".cyan(), cause));
+                }
+            }
+
+            output.print("");
+            output.print(&"Suggestion:".bold().to_string());
+            output.print(&format!("  {}", info.suggestion));
+
+            if !info.related_commands.is_empty() {
+                output.print("");
+                output.print(&"Related commands:".bold().to_string());
+                for command in info.related_commands {
+                    output.print(&format!("  {} {}", "•".cyan(), command.green()));
+                }
+            }
+        }
+    }
+}
+
+fn explain_ranges(query: &str, code: Option<i32>, output: &dyn Output) {
+    let range = code.and_then(error_code_range);
+
+    match output.mode() {
+        OutputMode::Json => output.success(
+            json!({
+                "known": false,
+                "query": query.trim(),
+                "code": code,
+                "range": range.map(range_to_json),
+                "ranges": ERROR_CODE_RANGES.iter().map(range_to_json).collect::<Vec<_>>(),
+                "known_codes": ERROR_CODES.len(),
+            }),
+            None,
+        ),
+        OutputMode::Interactive => {
+            output.print(&format!(
+                "'{}' is not a known error code of this version of am.",
+                query.trim()
+            ));
+            if let Some(range) = range {
+                output.print(&format!(
+                    "It falls in the range {}..={}: {}.",
+                    range.start, range.end, range.description
+                ));
+            }
+            output.print("");
+            output.print("Error codes are negative and grouped by range:");
+            for range in ERROR_CODE_RANGES {
+                output.print(&format!(
+                    "  {:>6}..={:<6}  {}",
+                    range.start, range.end, range.description
+                ));
+            }
+            output.print("");
+            output.print(&format!(
+                "Run {} to list the known codes.",
+                "am introspect error-codes".green()
+            ));
+        }
+    }
+}
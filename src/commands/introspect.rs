@@ -0,0 +1,245 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Machine-readable description of the CLI surface.
+//!
+//! `am introspect commands` walks the clap command tree at runtime and describes every
+//! subcommand, its arguments and the global flags as JSON, so external tools can build
+//! forms and validate argv without hard-coding the commands of a given CLI release. Each
+//! command carries its dispatch method name (e.g. `project.init`).
+//!
+//! `am introspect envelope-schema` prints the JSON Schema of the `--json` envelopes.
+//!
+//! `am introspect error-codes` lists the error codes with their type, default suggestion
+//! and exit code, read from [`ERROR_CODES`].
+
+use std::any::TypeId;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use clap::{Arg, ArgAction, Command, CommandFactory, Subcommand};
+use serde_json::{Value, json};
+
+use crate::app::App;
+use crate::commands::dispatch::method_name;
+use crate::common::errors::{ERROR_CODES, exit_codes};
+use crate::common::files::to_json_pretty;
+use crate::common::ignore::WalkOptions;
+use crate::database::Database;
+use crate::input::Input;
+use crate::presentation::{ColumnSpec, JsonOutput, Output, OutputMode};
+
+/// Version of the introspection format, bumped on breaking changes to its layout.
+pub const INTROSPECTION_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Subcommand, Debug)]
+pub enum IntrospectCommands {
+    /// Describe every command, its arguments and the global flags as JSON
+    Commands,
+
+    /// Print the JSON Schema of the envelopes written with --json
+    EnvelopeSchema,
+
+    /// List the error codes with their type, default suggestion and exit code
+    #[command(visible_alias = "exit-codes")]
+    ErrorCodes,
+}
+
+pub async fn handler(
+    command: &IntrospectCommands,
+    _project: Option<&Path>,
+    _walk: WalkOptions,
+    _database: Option<Arc<Database>>,
+    _input: &dyn Input,
+    output: &dyn Output,
+) -> anyhow::Result<()> {
+    let description = match command {
+        IntrospectCommands::Commands => describe_cli(&App::command()),
+        IntrospectCommands::EnvelopeSchema => JsonOutput::envelope_schema(),
+        IntrospectCommands::ErrorCodes => {
+            let description = describe_error_codes();
+            if output.mode() == OutputMode::Interactive {
+                let columns = [
+                    ColumnSpec::new("code", "Code"),
+                    ColumnSpec::new("name", "Constant"),
+                    ColumnSpec::new("type", "Type"),
+                    ColumnSpec::new("exit_code", "Exit code"),
+                    ColumnSpec::new("suggestion", "Suggestion"),
+                ];
+                output.table_with_columns(
+                    Some("Error codes"),
+                    &columns,
+                    description["error_codes"].clone(),
+                );
+                return Ok(());
+            }
+            description
+        }
+    };
+
+    match output.mode() {
+        OutputMode::Json => output.success(description, None),
+        OutputMode::Interactive => output.print(to_json_pretty(&description)?.trim_end()),
+    }
+
+    Ok(())
+}
+
+/// Describe the command tree rooted at `cli`.
+///
+/// The arguments of the root command are reported as `global_flags`, and every
+/// subcommand is described recursively under `commands`.
+pub fn describe_cli(cli: &Command) -> Value {
+    let name = cli.get_name();
+
+    json!({
+        "schema": INTROSPECTION_SCHEMA_VERSION,
+        "name": name,
+        "version": cli.get_version(),
+        "global_flags": cli
+            .get_arguments()
+            .filter(|arg| !arg.is_hide_set())
+            .map(describe_argument)
+            .collect::<Vec<_>>(),
+        "commands": cli
+            .get_subcommands()
+            .filter(|command| !command.is_hide_set())
+            .map(|command| describe_command(command, name))
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Describe every error code of [`ERROR_CODES`], and the exit codes they map to.
+pub fn describe_error_codes() -> Value {
+    json!({
+        "schema": INTROSPECTION_SCHEMA_VERSION,
+        "exit_codes": {
+            "success": exit_codes::SUCCESS,
+            "user_error": exit_codes::USER_ERROR,
+            "system_error": exit_codes::SYSTEM_ERROR,
+        },
+        "error_codes": ERROR_CODES
+            .iter()
+            .map(|info| json!({
+                "name": info.name,
+                "code": info.code,
+                "type": info.type_name,
+                "suggestion": info.suggestion,
+                "exit_code": info.exit_code,
+            }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn describe_command(command: &Command, parent_path: &str) -> Value {
+    let path = format!("{} {}", parent_path, command.get_name());
+
+    json!({
+        "name": command.get_name(),
+        "path": path,
+        "method": method_name(&path),
+        "about": command.get_about().map(|about| about.to_string()),
+        // Global flags are already reported at the root.
+        "arguments": command
+            .get_arguments()
+            .filter(|arg| !arg.is_hide_set() && !arg.is_global_set())
+            .map(describe_argument)
+            .collect::<Vec<_>>(),
+        "subcommands": command
+            .get_subcommands()
+            .filter(|subcommand| !subcommand.is_hide_set())
+            .map(|subcommand| describe_command(subcommand, &path))
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn describe_argument(arg: &Arg) -> Value {
+    let takes_values = arg.get_action().takes_values();
+    let kind = if arg.is_positional() {
+        "positional"
+    } else if takes_values {
+        "option"
+    } else {
+        "flag"
+    };
+
+    let value_type = argument_type(arg);
+    let possible_values: Vec<String> = if value_type == "enum" {
+        arg.get_possible_values()
+            .iter()
+            .filter(|value| !value.is_hide_set())
+            .map(|value| value.get_name().to_string())
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    // Flags only get their implicit default once the command is built.
+    let default = match arg.get_action() {
+        ArgAction::SetTrue => Some("false".to_string()),
+        ArgAction::SetFalse => Some("true".to_string()),
+        _ => arg
+            .get_default_values()
+            .first()
+            .map(|value| value.to_string_lossy().to_string()),
+    };
+
+    json!({
+        "name": arg.get_id().as_str(),
+        "kind": kind,
+        "long": arg.get_long(),
+        "short": arg.get_short().map(String::from),
+        "type": value_type,
+        "required": arg.is_required_set(),
+        "multiple": matches!(arg.get_action(), ArgAction::Append),
+        "default": default,
+        "possible_values": possible_values,
+        "help": arg.get_help().map(|help| help.to_string()),
+    })
+}
+
+/// Name of the type of value taken by `arg`.
+fn argument_type(arg: &Arg) -> &'static str {
+    if !arg.get_action().takes_values() {
+        return "boolean";
+    }
+
+    let type_id = arg.get_value_parser().type_id();
+    let is_any = |ids: &[TypeId]| ids.iter().any(|id| type_id == *id);
+
+    if is_any(&[TypeId::of::<bool>()]) {
+        "boolean"
+    } else if is_any(&[
+        TypeId::of::<u8>(),
+        TypeId::of::<u16>(),
+        TypeId::of::<u32>(),
+        TypeId::of::<u64>(),
+        TypeId::of::<usize>(),
+        TypeId::of::<i8>(),
+        TypeId::of::<i16>(),
+        TypeId::of::<i32>(),
+        TypeId::of::<i64>(),
+        TypeId::of::<isize>(),
+    ]) {
+        "integer"
+    } else if is_any(&[TypeId::of::<f32>(), TypeId::of::<f64>()]) {
+        "number"
+    } else if is_any(&[TypeId::of::<PathBuf>()]) {
+        "path"
+    } else if !arg.get_possible_values().is_empty() {
+        "enum"
+    } else {
+        "string"
+    }
+}
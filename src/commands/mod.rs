@@ -13,7 +13,14 @@
 // limitations under the License.
 
 pub mod asset;
+pub mod completions;
+pub mod dispatch;
+pub mod examples;
+pub mod explain;
+pub mod introspect;
 pub mod project;
 pub mod sdk;
+pub mod setup;
+pub mod stats;
 pub mod sudo;
 pub mod template;
@@ -12,15 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use colored::*;
-use log::info;
-use std::collections::HashMap;
+use log::{debug, info};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::env;
 use std::fs;
-use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use crate::compiler;
 use crate::{
@@ -28,29 +28,63 @@ use crate::{
     assets::{
         Asset, AssetType, Collection, Effect, Event, ProjectContext, ProjectValidator, Sound,
         Soundbank, Switch, SwitchContainer,
+        graph::{AssetKey, DependencyGraph},
+        rename::rename_asset_file,
     },
+    commands::asset::parse_asset_type,
     common::{
-        errors::{CliError, codes, project_already_exists, project_not_initialized},
+        env_file::{AMENV_FILE, EnvFile, EnvVar, MASKED_VALUE, is_secret_key},
+        errors::{
+            CliError, codes, project_already_exists, project_not_initialized,
+            project_not_registered,
+        },
+        files::{
+            JSON_COMMENTS_SUPPORTED, has_json_comments, json_preserving_unknown_fields,
+            safe_remove_in_parent, safe_remove_within,
+        },
+        format::{MIN_TRUNCATED_PATH_WIDTH, humanize_timestamp, terminal_width, truncate_path},
+        fs_ctx,
+        glob::glob_match,
+        hashing::{CopyVerifier, VerifyReport, copy_file_hashed},
+        hooks::{HOOK_POST_INIT, HOOK_POST_VALIDATE, run_hook_if_declared},
+        ignore::{IgnoreRules, SkippedPath, WalkOptions, walk_project_files, warn_skipped},
+        lock::{DEFAULT_LOCK_TIMEOUT, LOCK_FILE, ProjectLock},
+        paths::user_home,
+        project_option::project_dir,
+        stdin::parse_json_entries,
+        template_vars::{
+            VARS_MANIFEST, builtin_variables, parse_var_specs, read_vars_manifest, render,
+            render_files, resolve_variables, scan_template,
+        },
         utils::{
-            ASSET_DIR_ATTENUATORS, ASSET_DIR_COLLECTIONS, ASSET_DIR_EFFECTS, ASSET_DIR_EVENTS,
-            ASSET_DIR_PIPELINES, ASSET_DIR_RTPC, ASSET_DIR_SOUNDBANKS, ASSET_DIR_SOUNDS,
-            ASSET_DIR_SWITCH_CONTAINERS, ASSET_DIR_SWITCHES, count_assets_by_type,
-            read_amproject_file, validate_project_name,
+            AMPROJECT_VERSION, ASSET_DIR_ATTENUATORS, ASSET_DIR_PIPELINES, ASSET_DIR_RTPC,
+            ASSET_DIRECTORIES, SNAPSHOTS_DIR, canonical_project_path, check_asset_dir,
+            check_name_characters, check_name_length, check_name_not_reserved, check_name_present,
+            count_assets_by_type, discard_partial_project, expand_home, format_size,
+            normalize_path, parse_size, read_amproject_file, read_amproject_file_strict,
+            transform_name, transform_project_name, utf8_path, validate_project_name,
+            write_amproject_file,
         },
     },
     config::sdk::discover_sdk,
     database::{
-        Database, db_create_project, db_forget_project, db_get_project_by_name,
-        db_get_project_by_path, db_get_projects_filtered, db_get_template_by_name,
-        db_get_templates, db_set_project_favorite,
-        entities::{ProjectConfiguration, Template},
+        Database, db_create_project, db_forget_project, db_forget_projects,
+        db_get_project_alias_of_file_name, db_get_project_by_name, db_get_project_by_path,
+        db_get_projects_filtered, db_get_templates, db_rename_project,
+        db_set_project_alias_of_file_name, db_set_project_favorite, db_set_project_path,
+        entities::{Project, ProjectConfiguration, Template},
+    },
+    events::{self, CliEvent},
+    input::{Input, SelectOption, to_validation, with_flag_hint},
+    presentation::{
+        ColumnSpec, ListLimit, Output, OutputMode, PathTree, SummaryOutput, TreeLeaf, Truncation,
+        insert_os_str,
     },
-    input::Input,
-    presentation::{Output, OutputMode},
-    schema::loader::load_schemas,
+    schema::{cache::resolve_schemas, loader::load_schemas_from},
 };
+use chrono::Utc;
 use clap::{Subcommand, value_parser};
-use inquire::{CustomUserError, validator::Validation};
+use serde::Deserialize;
 use serde_json::json;
 
 const DEFAULT_TEMPLATE: &str = "default";
@@ -58,12 +92,22 @@ const DEFAULT_TEMPLATE: &str = "default";
 /// Width of the separator line in project info display.
 const PROJECT_INFO_SEPARATOR_WIDTH: usize = 40;
 
+/// Width of the console prefix and labels before the paths in project info display.
+const PROJECT_INFO_PATH_INDENT: usize = 20;
+
+/// Stages of `project init`, see [`Output::begin_stages`].
+const INIT_STAGES: &[&str] = &["Check", "Create", "Finalize"];
+
+/// Stages of `project copy`, the last one only when the copy is registered.
+const COPY_STAGES: &[&str] = &["Scan", "Copy", "Register"];
+
+/// Notice of `project info` when the database is not available.
+const REGISTRATION_STATUS_UNKNOWN: &str =
+    "The database is not available, the registration status of this project is unknown.";
+
 #[derive(Subcommand, Debug)]
 pub enum ProjectCommands {
     /// Create a new project
-    #[command(
-        after_help = "Examples:\n  am project init my_game\n  am project init my_game --template o3de\n"
-    )]
     Init {
         /// The name of the project to create
         name: Option<String>,
@@ -72,49 +116,157 @@ pub enum ProjectCommands {
         #[arg(short, long)]
         template: Option<String>,
 
+        /// Use DIR instead of the default directory for assets of TYPE (e.g. sound=sfx)
+        #[arg(long = "asset-dir", value_name = "TYPE=DIR")]
+        asset_dirs: Vec<String>,
+
         /// Create a new project without registering it
         #[arg(long, value_parser = value_parser!(bool))]
         no_register: bool,
+
+        /// Add a small set of example assets, prefixed with 'example_', to the project
+        #[arg(long)]
+        with_examples: bool,
+
+        /// Set the template variable NAME, substituted for {{NAME}} in the template files
+        #[arg(long = "var", value_name = "NAME=VALUE")]
+        vars: Vec<String>,
+
+        /// Delete the content of the project directory if it already exists
+        #[arg(long)]
+        overwrite_dir: bool,
+
+        /// Unregister any project already registered with the same name
+        #[arg(long, conflicts_with = "no_register")]
+        replace_registration: bool,
+
+        /// Check the checksum of every file copied from the template
+        #[arg(long)]
+        verify: bool,
+
+        /// Print the directories, files and registrations init would create, without
+        /// creating anything
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// Register an existing project
-    #[command(after_help = "Examples:\n  am project register /path/to/project\n")]
     Register {
+        /// Directory of the project, by default the current directory when it holds a
+        /// project
         #[arg(value_parser = value_parser!(PathBuf))]
         path: Option<PathBuf>,
+
+        /// Register every project found in the directory and its subdirectories
+        #[arg(short, long)]
+        recursive: bool,
+
+        /// Register projects whose name is taken under a numbered name (e.g. my_game_2),
+        /// updating their .amproject
+        #[arg(long)]
+        rename_on_conflict: bool,
+
+        /// Point the registration of a project registered under the same name at
+        /// another directory to this one
+        #[arg(long, conflicts_with_all = ["rename_on_conflict", "recursive", "stdin"])]
+        force: bool,
+
+        /// Register the project under this name instead of the one in its .amproject,
+        /// which is left unchanged
+        #[arg(long, conflicts_with_all = ["recursive", "stdin"])]
+        name: Option<String>,
+
+        /// Read the projects to register from stdin, as a JSON object ({"path": "...",
+        /// "name": "..."}) or an array of such objects. Requires --json
+        #[arg(long, conflicts_with_all = ["path", "recursive"])]
+        stdin: bool,
+
+        /// Reject .amproject files with unknown fields, which are otherwise ignored
+        #[arg(long, conflicts_with = "stdin")]
+        strict: bool,
+    },
+
+    /// Duplicate a registered project under a new name
+    Copy {
+        /// The name of the registered project to copy
+        source: String,
+
+        /// The name of the copy
+        new_name: String,
+
+        /// Directory of the copy (defaults to a sibling of the project named after the copy)
+        #[arg(long, value_parser = value_parser!(PathBuf))]
+        path: Option<PathBuf>,
+
+        /// Register the copy (default)
+        #[arg(long, overrides_with = "no_register")]
+        register: bool,
+
+        /// Copy the project without registering it
+        #[arg(long)]
+        no_register: bool,
+
+        /// Delete the content of the destination directory if it already exists
+        #[arg(long)]
+        overwrite_dir: bool,
+
+        /// Unregister any project already registered with the same name
+        #[arg(long, conflicts_with = "no_register")]
+        replace_registration: bool,
+
+        /// Check the checksum of every copied file
+        #[arg(long)]
+        verify: bool,
     },
 
     /// Unregister a project
-    #[command(
-        after_help = "Examples:\n  am project unregister my_game\n  am project unregister my_game --delete-files\n"
-    )]
     Unregister {
-        /// The name of the project to unregister
-        name: String,
+        /// The name of the project to unregister (prompts for a registered project if not
+        /// provided)
+        #[arg(conflicts_with = "pattern")]
+        name: Option<String>,
+
+        /// Unregister every project whose name matches this glob (e.g. 'test_*')
+        #[arg(long = "match", value_name = "GLOB")]
+        pattern: Option<String>,
 
         /// Delete the project files as well
         #[arg(long, value_parser = value_parser!(bool))]
         delete_files: bool,
+
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Fail if the glob matches no registered project
+        #[arg(long, requires = "pattern")]
+        fail_empty: bool,
     },
 
     /// List all registered projects
-    #[command(
-        after_help = "Examples:\n  am project list\n  am project list --json\n  am project list --favorite\n  am project list --no-favorite\n"
-    )]
     List {
         /// Show only favorite projects
-        #[arg(long, conflicts_with = "no_favorite")]
+        #[arg(long, visible_alias = "favorites", conflicts_with = "no_favorite")]
         favorite: bool,
 
         /// Show only non-favorite projects
         #[arg(long = "no-favorite", conflicts_with = "favorite")]
         no_favorite: bool,
+
+        /// Group the projects by directory below ROOT [default: their deepest common
+        /// ancestor]
+        #[arg(long, value_name = "ROOT", num_args = 0..=1)]
+        tree: Option<Option<String>>,
+
+        /// Order of the projects, favorites always coming first
+        #[arg(long, value_enum, default_value_t = ProjectSort::Name)]
+        sort: ProjectSort,
+
+        #[command(flatten)]
+        limit: ListLimit,
     },
 
     /// Mark or unmark a project as favorite
-    #[command(
-        after_help = "Examples:\n  am project favorite my_game\n  am project favorite my_game --set\n  am project favorite my_game --unset\n"
-    )]
     Favorite {
         /// The name of the project to update
         name: String,
@@ -128,17 +280,92 @@ pub enum ProjectCommands {
         unset: bool,
     },
 
+    /// Unmark a project as favorite
+    Unfavorite {
+        /// The name of the project to update
+        name: String,
+    },
+
     /// Show details of a project
-    #[command(after_help = "Examples:\n  am project info\n  am project info my_game\n")]
     Info {
-        /// The name of the project (uses current directory if not provided)
+        /// The name of the project (uses --project, or else the current directory, if not
+        /// provided)
         name: Option<String>,
+
+        /// Show the project at PATH, its directory or its .amproject file, instead of
+        /// the one in the current directory
+        #[arg(long, value_name = "PATH", conflicts_with = "name")]
+        path: Option<PathBuf>,
+
+        /// Only print the resolved absolute project directories
+        #[arg(long)]
+        paths: bool,
+
+        /// Check that the directories of .amproject exist inside the project root
+        #[arg(long, conflicts_with = "paths")]
+        check_paths: bool,
+
+        /// Create the missing directories found by --check-paths
+        #[arg(long, requires = "check_paths")]
+        create_missing: bool,
+
+        /// Reject a .amproject file with unknown fields, which are otherwise ignored
+        #[arg(long)]
+        strict: bool,
     },
 
-    /// Validate all assets in a project
+    /// Report the disk usage of the current project
+    Size {
+        /// Break sources down per asset type and data down per file extension
+        #[arg(long)]
+        by_type: bool,
+
+        /// Print sizes in KiB, MiB and GiB instead of bytes
+        #[arg(long)]
+        human: bool,
+
+        /// Only show entries of at least SIZE (e.g. 500K, 10MiB, 2G)
+        #[arg(long, value_name = "SIZE", value_parser = parse_size)]
+        threshold: Option<u64>,
+    },
+
+    /// Print the variables of the current project's .amenv file
     #[command(
-        after_help = "Examples:\n  am project validate\n  am project validate --sounds-only\n  am project validate --json\n"
+        after_help = "The .amenv file at the project root holds KEY=VALUE lines, which are set in\nthe environment of hooks. Lines starting with # are comments, values can be\nquoted, and a leading 'export' is accepted.\n"
     )]
+    Env {
+        /// Hide the values of keys that look like secrets (e.g. API_TOKEN, DB_PASSWORD)
+        #[arg(long)]
+        mask: bool,
+    },
+
+    /// Give the current project the same name in .amproject and in the registry
+    SyncRegistration {
+        /// Keep the name from .amproject and rename the registration
+        #[arg(
+            long,
+            conflicts_with = "prefer_db",
+            required_unless_present = "prefer_db"
+        )]
+        prefer_file: bool,
+
+        /// Keep the registered name and rename the project in .amproject
+        #[arg(long)]
+        prefer_db: bool,
+    },
+
+    /// Check every registered project: its directory, .amproject, name and version
+    VerifyRegistration {
+        /// Unregister the projects whose directory no longer exists
+        #[arg(long)]
+        prune_missing: bool,
+
+        /// Rename the registrations of projects named otherwise in their .amproject
+        #[arg(long)]
+        sync_names: bool,
+    },
+
+    /// Validate all assets in a project
     Validate {
         /// Validate only sounds
         #[arg(long)]
@@ -167,12 +394,36 @@ pub enum ProjectCommands {
         /// Validate only soundbanks
         #[arg(long)]
         soundbanks_only: bool,
+
+        /// Don't report assets no other asset references
+        #[arg(long)]
+        no_orphan_check: bool,
+
+        /// Print a single line with the number of errors and warnings, for commit hooks
+        #[arg(long)]
+        summary: bool,
+
+        #[command(flatten)]
+        limit: ListLimit,
+    },
+
+    /// Report the asset files whose name breaks the underscore_lowercase convention
+    LintNames {
+        /// Only check assets of this type: sound, collection, effect, switch,
+        /// switch_container, event, soundbank
+        #[arg(long = "type", value_name = "TYPE")]
+        asset_type: Option<String>,
+
+        /// Rename the reported files, updating the soundbanks listing them
+        #[arg(long)]
+        fix: bool,
+
+        /// Skip confirmation prompt
+        #[arg(short = 'y', long = "yes", requires = "fix")]
+        skip_confirmation: bool,
     },
 
     /// Build project assets for runtime consumption
-    #[command(
-        after_help = "Examples:\n  am project build\n  am project build --output dist/\n  am project build --clean\n"
-    )]
     Build {
         /// Output directory (defaults to project's build directory)
         #[arg(short, long)]
@@ -186,10 +437,97 @@ pub enum ProjectCommands {
         #[arg(long)]
         fail_fast: bool,
     },
+
+    /// Run a hook declared in the project's .amproject
+    #[command(
+        after_help = "Hooks are declared in .amproject as a map from hook name to a script path\nrelative to the project root:\n  \"hooks\": { \"post-init\": \"scripts/setup.sh\" }\n\nThe post-init and post-validate hooks also run automatically after the\nmatching command.\n"
+    )]
+    RunHook {
+        /// The name of the hook to run
+        name: String,
+    },
+}
+
+impl ProjectCommands {
+    /// Whether this command writes to the files of the current project and must hold
+    /// the project lock.
+    ///
+    /// `init` creates a new directory and the other commands only read project files or
+    /// update the registry, so only `build`, hooks and `lint-names --fix` take the lock.
+    /// `sync-registration` takes it itself, and only when it rewrites `.amproject`.
+    pub fn mutates_project_files(&self) -> bool {
+        matches!(
+            self,
+            ProjectCommands::Build { .. }
+                | ProjectCommands::RunHook { .. }
+                | ProjectCommands::LintNames { fix: true, .. }
+                | ProjectCommands::Info {
+                    create_missing: true,
+                    ..
+                }
+        )
+    }
+
+    /// Whether this command reads or writes the project registry, and so can't run
+    /// with `--no-db`.
+    ///
+    /// `info` only needs it to look a project up by name, `init` for the registered
+    /// templates.
+    pub fn requires_database(&self) -> bool {
+        match self {
+            ProjectCommands::Info { name, .. } => name.is_some(),
+            ProjectCommands::Size { .. }
+            | ProjectCommands::Env { .. }
+            | ProjectCommands::Validate { .. }
+            | ProjectCommands::LintNames { .. }
+            | ProjectCommands::Build { .. }
+            | ProjectCommands::RunHook { .. } => false,
+            ProjectCommands::Init { .. }
+            | ProjectCommands::Register { .. }
+            | ProjectCommands::Copy { .. }
+            | ProjectCommands::Unregister { .. }
+            | ProjectCommands::List { .. }
+            | ProjectCommands::Favorite { .. }
+            | ProjectCommands::Unfavorite { .. }
+            | ProjectCommands::SyncRegistration { .. }
+            | ProjectCommands::VerifyRegistration { .. } => true,
+        }
+    }
+
+    /// Whether this command works on the project of the current directory, and so
+    /// accepts the global `--project` option instead.
+    pub fn is_project_scoped(&self) -> bool {
+        match self {
+            ProjectCommands::Info { .. }
+            | ProjectCommands::Size { .. }
+            | ProjectCommands::Env { .. }
+            | ProjectCommands::SyncRegistration { .. }
+            | ProjectCommands::Validate { .. }
+            | ProjectCommands::LintNames { .. }
+            | ProjectCommands::Build { .. }
+            | ProjectCommands::RunHook { .. } => true,
+            ProjectCommands::Init { .. }
+            | ProjectCommands::Register { .. }
+            | ProjectCommands::Copy { .. }
+            | ProjectCommands::Unregister { .. }
+            | ProjectCommands::List { .. }
+            | ProjectCommands::Favorite { .. }
+            | ProjectCommands::Unfavorite { .. }
+            | ProjectCommands::VerifyRegistration { .. } => false,
+        }
+    }
+
+    /// Whether the arguments of this command already name its project, which then
+    /// can't be given with `--project` too.
+    pub fn names_project(&self) -> bool {
+        matches!(self, ProjectCommands::Info { name, path, .. } if name.is_some() || path.is_some())
+    }
 }
 
 pub async fn handler(
     command: &ProjectCommands,
+    project: Option<&Path>,
+    walk: WalkOptions,
     database: Option<Arc<Database>>,
     input: &dyn Input,
     output: &dyn Output,
@@ -198,99 +536,294 @@ pub async fn handler(
         ProjectCommands::Init {
             name,
             template,
+            asset_dirs,
             no_register,
+            with_examples,
+            vars,
+            overwrite_dir,
+            replace_registration,
+            verify,
+            dry_run,
         } => {
-            let mut templates = db_get_templates(database.clone())?;
-
-            templates.insert(
-                0,
-                Template {
-                    id: Some(0),
-                    name: DEFAULT_TEMPLATE.into(),
-                    path: "bundled".to_string(),
-                    engine: Some("generic".to_string()),
-                    description: Some("Default project template for any engine".to_string()),
-                    source: crate::database::entities::TemplateSource::Embedded,
-                },
-            );
+            let asset_dirs = parse_asset_dir_specs(asset_dirs)?;
+            let variables = parse_var_specs(vars)?;
 
-            let mut project_name = name.clone();
-            let mut project_template = template.clone();
+            // Loaded once: the prompt and the dispatch only work on these choices.
+            let choices = TemplateChoice::load_all(database.clone())?;
 
-            if project_template.is_some()
-                && !templates
-                    .iter()
-                    .any(|t| t.name == *project_template.as_ref().unwrap())
-            {
-                return Err(CliError::new(
-                    codes::ERR_VALIDATION_FIELD,
-                    "Invalid project template",
-                    "The specified template does not exist",
-                )
-                .with_suggestion("Use 'am template list' to see available templates")
-                .into());
-            }
+            let mut project_name = name.clone();
+            let project_template = match template {
+                Some(template) => {
+                    Some(TemplateChoice::find(&choices, template).ok_or_else(|| {
+                        CliError::new(
+                            codes::ERR_VALIDATION_FIELD,
+                            "Invalid project template",
+                            "The specified template does not exist",
+                        )
+                        .with_context(template)
+                        .with_suggestion("Use 'am template list' to see available templates")
+                    })?)
+                }
+                None => None,
+            };
 
             if project_name.is_none() {
-                let ret = input.prompt_text(
+                // Each rule is checked on the name as it will be transformed.
+                let present = |value: &str| {
+                    to_validation(check_name_present(
+                        &transform_project_name(value),
+                        "project",
+                    ))
+                };
+                let characters = |value: &str| {
+                    to_validation(check_name_characters(
+                        &transform_project_name(value),
+                        true,
+                        "project",
+                    ))
+                };
+                let not_reserved = |value: &str| {
+                    to_validation(check_name_not_reserved(
+                        &transform_project_name(value),
+                        "project",
+                    ))
+                };
+                let length = |value: &str| {
+                    to_validation(check_name_length(&transform_project_name(value), "project"))
+                };
+
+                let ret = with_flag_hint(input, "the <name> argument").prompt_text(
                     "Project Name",
                     Some("my_project"),
-                    Some(&transform_name),
-                    Some(&validate_name),
+                    Some(&transform_project_name),
+                    &[&present, &characters, &not_reserved, &length],
                 )?;
 
                 project_name = Some(ret);
             }
 
-            if project_template.is_none() {
-                let selected_idx =
-                    crate::input::select_index(input, "Project Template", &templates)?;
-                project_template = Some(templates[selected_idx].name.clone());
-            }
+            let project_template = match project_template {
+                Some(choice) => choice,
+                None => prompt_template_choice(&choices, input)?,
+            };
 
             handle_init_project_command(
                 project_name.as_deref().unwrap(),
-                project_template.as_deref().unwrap_or(""),
+                project_template,
+                asset_dirs,
                 no_register,
+                *with_examples,
+                variables,
+                InitResolutions {
+                    overwrite_dir: *overwrite_dir,
+                    replace_registration: *replace_registration,
+                },
+                *verify,
+                *dry_run,
+                walk,
                 database,
                 input,
                 output,
             )
             .await
         }
-        ProjectCommands::Register { path } => {
-            let cwd = env::current_dir()?;
-            let project_path = match path {
-                Some(path) => path,
-                None => &cwd,
-            };
+        ProjectCommands::Register {
+            path,
+            recursive,
+            rename_on_conflict,
+            force,
+            name,
+            stdin,
+            strict,
+        } => {
+            if *stdin {
+                return handle_register_stdin_command(*rename_on_conflict, database, input, output)
+                    .await;
+            }
 
-            handle_register_project_command(project_path, database, input, output).await
+            let project_path = register_target(path.as_deref(), *recursive, input)?;
+
+            if *recursive {
+                handle_register_recursive_command(
+                    &project_path,
+                    *rename_on_conflict,
+                    *strict,
+                    database,
+                    output,
+                )
+                .await
+            } else {
+                handle_register_project_command(
+                    &project_path,
+                    name.as_deref(),
+                    *rename_on_conflict,
+                    *force,
+                    *strict,
+                    database,
+                    input,
+                    output,
+                )
+                .await
+            }
+        }
+        ProjectCommands::Copy {
+            source,
+            new_name,
+            path,
+            no_register,
+            overwrite_dir,
+            replace_registration,
+            verify,
+            ..
+        } => {
+            handle_copy_project_command(
+                source,
+                new_name,
+                path.as_deref(),
+                !*no_register,
+                InitResolutions {
+                    overwrite_dir: *overwrite_dir,
+                    replace_registration: *replace_registration,
+                },
+                *verify,
+                walk,
+                database,
+                input,
+                output,
+            )
+            .await
         }
         ProjectCommands::Unregister {
             name,
+            pattern,
             delete_files: delete,
-        } => {
-            handle_unregister_project_command(name.as_str(), delete, database, input, output).await
-        }
+            yes,
+            fail_empty,
+        } => match (name, pattern) {
+            (None, Some(pattern)) => {
+                handle_unregister_matching_command(
+                    pattern,
+                    *delete,
+                    *yes,
+                    *fail_empty,
+                    database,
+                    input,
+                    output,
+                )
+                .await
+            }
+            (name, _) => {
+                handle_unregister_project_command(
+                    name.clone(),
+                    *delete,
+                    *yes,
+                    database,
+                    input,
+                    output,
+                )
+                .await
+            }
+        },
         ProjectCommands::List {
             favorite,
             no_favorite,
+            tree,
+            sort,
+            limit,
         } => {
             let filter = match (*favorite, *no_favorite) {
                 (true, _) => Some(true),
                 (_, true) => Some(false),
                 _ => None,
             };
-            handle_list_projects_command(filter, database, output).await
+            match tree {
+                Some(root) => {
+                    handle_list_projects_tree_command(
+                        filter,
+                        root.as_deref(),
+                        *sort,
+                        limit,
+                        database,
+                        output,
+                    )
+                    .await
+                }
+                None => handle_list_projects_command(filter, *sort, limit, database, output).await,
+            }
         }
         ProjectCommands::Favorite { name, unset, .. } => {
             // --set and --unset are mutually exclusive (clap enforces);
             // defaults to setting when neither flag is provided.
             handle_favorite_project_command(name, !*unset, database, output).await
         }
-        ProjectCommands::Info { name } => {
-            handle_info_project_command(name.clone(), database, input, output).await
+        ProjectCommands::Unfavorite { name } => {
+            handle_favorite_project_command(name, false, database, output).await
+        }
+        ProjectCommands::Info {
+            name,
+            path,
+            paths,
+            check_paths,
+            create_missing,
+            strict,
+        } => {
+            // The global --project can't be combined with NAME or --path, see
+            // `names_project`
+            let project_dir = path
+                .as_deref()
+                .map(project_dir_of)
+                .or_else(|| project.map(Path::to_path_buf));
+            if *paths {
+                return handle_info_paths_command(
+                    name.as_deref(),
+                    project_dir,
+                    *strict,
+                    database,
+                    output,
+                )
+                .await;
+            }
+
+            let path_check = check_paths.then_some(*create_missing);
+            handle_info_project_command(
+                name.clone(),
+                project_dir,
+                path_check,
+                *strict,
+                database,
+                input,
+                output,
+            )
+            .await
+        }
+        ProjectCommands::Size {
+            by_type,
+            human,
+            threshold,
+        } => handle_size_project_command(
+            project,
+            walk,
+            *by_type,
+            *human,
+            threshold.unwrap_or(0),
+            output,
+        ),
+        ProjectCommands::Env { mask } => handle_env_project_command(project, *mask, output),
+        ProjectCommands::SyncRegistration { prefer_db, .. } => {
+            // --prefer-file and --prefer-db are mutually exclusive and one is required
+            // (clap enforces).
+            let prefer = if *prefer_db {
+                RegistrationSource::Database
+            } else {
+                RegistrationSource::File
+            };
+            handle_sync_registration_command(project, prefer, database, output).await
+        }
+        ProjectCommands::VerifyRegistration {
+            prune_missing,
+            sync_names,
+        } => {
+            handle_verify_registration_command(*prune_missing, *sync_names, database, output).await
         }
         ProjectCommands::Validate {
             sounds_only,
@@ -300,6 +833,9 @@ pub async fn handler(
             switch_containers_only,
             events_only,
             soundbanks_only,
+            no_orphan_check,
+            summary,
+            limit,
         } => {
             let filter = resolve_type_filter(
                 *sounds_only,
@@ -310,780 +846,3864 @@ pub async fn handler(
                 *events_only,
                 *soundbanks_only,
             );
-            handle_validate_project_command(filter, output).await
+            handle_validate_project_command(
+                project,
+                walk,
+                filter,
+                !*no_orphan_check,
+                *summary,
+                limit,
+                database,
+                output,
+            )
+            .await
+        }
+        ProjectCommands::LintNames {
+            asset_type,
+            fix,
+            skip_confirmation,
+        } => {
+            handle_lint_names_command(
+                project,
+                walk,
+                asset_type.as_deref(),
+                *fix,
+                *skip_confirmation,
+                input,
+                output,
+            )
+            .await
         }
         ProjectCommands::Build {
             output: output_dir,
             clean,
             fail_fast,
-        } => handle_build_project_command(output_dir.clone(), *clean, *fail_fast, output).await,
-    }
-}
-
-async fn handle_init_project_command(
-    name: &str,
-    template: &str,
-    no_register: &bool,
-    database: Option<Arc<Database>>,
-    input: &dyn Input,
-    output: &dyn Output,
-) -> anyhow::Result<()> {
-    let project_name = transform_name(name);
-
-    if !no_register
-        && let Ok(Some(p)) = db_get_project_by_name(project_name.as_str(), database.clone())
-    {
-        output.warning(&format!(
-            "A project with the name {} is already registered at path {}",
-            project_name.cyan(),
-            p.path.cyan()
-        ));
-
-        if input.confirm(
-            "Do you want to forget that project and create this new one?",
-            None,
-        )? {
-            info!("Unregistering previous project...");
-            db_forget_project(p.id.unwrap(), database.clone())?;
-        } else {
-            return Err(project_already_exists(&project_name)
-                .with_suggestion("Use the --no-register flag to create without registering, or choose a different name")
-                .into());
+        } => {
+            handle_build_project_command(
+                project,
+                walk,
+                output_dir.clone(),
+                *clean,
+                *fail_fast,
+                output,
+            )
+            .await
         }
+        ProjectCommands::RunHook { name } => handle_run_hook_command(project, name, output).await,
     }
+}
 
-    output.progress(
-        format!(
-            "Initializing project {} using template {}...",
-            project_name.cyan(),
-            template.cyan()
-        )
-        .as_str(),
-    );
-
-    let cwd = env::current_dir()?;
-    let project_path = &cwd.join(&project_name);
-
-    if project_path.exists() && project_path.read_dir()?.next().is_some() {
-        output.warning(&format!(
-            "The project path {} already exists and is not empty",
-            project_path.to_str().unwrap_or_default().cyan()
-        ));
+/// Parse `--asset-dir TYPE=DIR` values into `asset_dirs` overrides.
+fn parse_asset_dir_specs(specs: &[String]) -> Result<BTreeMap<AssetType, String>> {
+    let mut asset_dirs = BTreeMap::new();
 
-        if input.confirm(
-            "Do you want to overwrite the directory? All existing content will be deleted!",
-            None,
-        )? {
-            fs::remove_dir_all(project_path)?;
-        } else {
+    for spec in specs {
+        let Some((type_key, dir)) = spec.split_once('=') else {
             return Err(CliError::new(
-                codes::ERR_PROJECT_ALREADY_EXISTS,
-                "Cannot create project",
-                "The project directory already exists and is not empty",
+                codes::ERR_VALIDATION_FIELD,
+                format!("Invalid asset directory: '{}'", spec),
+                "Asset directories must be in format 'type=dir'",
             )
-            .with_context(project_path.display().to_string())
+            .with_suggestion("Example: --asset-dir sound=sfx --asset-dir event=triggers")
             .into());
-        }
-    }
-
-    fs::create_dir_all(project_path)?;
+        };
 
-    if template != DEFAULT_TEMPLATE {
-        if let Some(t) = db_get_template_by_name(template, database.clone())? {
-            let template_path = PathBuf::from(t.path);
-            if !template_path.exists() {
-                return Err(CliError::new(
+        let asset_type: AssetType =
+            serde_json::from_value(json!(type_key.trim())).map_err(|e| {
+                CliError::new(
                     codes::ERR_VALIDATION_FIELD,
-                    "Template directory does not exist",
-                    "The registered template path is invalid or has been moved",
+                    format!("Invalid asset type: '{}'", type_key),
+                    e.to_string(),
                 )
-                .with_context(template_path.display().to_string())
-                .into());
-            }
+            })?;
 
-            fs::copy(&template_path, project_path).map_err(|e| {
-                CliError::new(
-                    codes::ERR_TEMPLATE_COPY_FAILED,
-                    format!("Failed to copy template from {}", template_path.display()),
-                    format!("Underlying OS error: {}", e),
-                )
-            })?;
-        } else {
-            return Err(CliError::new(
-                codes::ERR_VALIDATION_FIELD,
-                "Template not found",
-                "The selected template is not registered",
-            )
-            .with_context(template)
-            .with_suggestion("Use 'am template list' to see available templates")
-            .into());
-        }
-    } else {
-        let sources_dir = project_path.join("sources");
+        asset_dirs.insert(asset_type, dir.trim().to_string());
+    }
 
-        fs::create_dir_all(sources_dir.join(ASSET_DIR_ATTENUATORS))?;
-        fs::create_dir_all(sources_dir.join(ASSET_DIR_COLLECTIONS))?;
-        fs::create_dir_all(sources_dir.join(ASSET_DIR_EFFECTS))?;
-        fs::create_dir_all(sources_dir.join(ASSET_DIR_EVENTS))?;
-        fs::create_dir_all(sources_dir.join(ASSET_DIR_PIPELINES))?;
-        fs::create_dir_all(sources_dir.join(ASSET_DIR_RTPC))?;
-        fs::create_dir_all(sources_dir.join(ASSET_DIR_SOUNDBANKS))?;
-        fs::create_dir_all(sources_dir.join(ASSET_DIR_SOUNDS))?;
-        fs::create_dir_all(sources_dir.join(ASSET_DIR_SWITCH_CONTAINERS))?;
-        fs::create_dir_all(sources_dir.join(ASSET_DIR_SWITCHES))?;
+    let config = ProjectConfiguration {
+        asset_dirs,
+        ..Default::default()
+    };
+    for asset_type in AssetType::ALL {
+        check_asset_dir(&config, asset_type)?;
+    }
 
-        if let Some(file) = Resource::get("default.config.json") {
-            fs::write(sources_dir.join("pc.config.json"), file.data)?;
-        }
+    // Overrides repeating the default name are dropped to keep .amproject minimal.
+    Ok(config
+        .asset_dirs
+        .into_iter()
+        .filter(|(asset_type, dir)| dir != asset_type.directory_name())
+        .collect())
+}
 
-        if let Some(file) = Resource::get("default.buses.json") {
-            fs::write(sources_dir.join("pc.buses.json"), file.data)?;
-        }
+/// A template `am project init` can start from.
+#[derive(Debug, Clone)]
+pub enum TemplateChoice {
+    /// The default template bundled with the CLI.
+    BuiltinDefault,
+    /// A template registered with `am template register`.
+    Registered(Template),
+}
 
-        if let Some(file) = Resource::get("default.pipeline.json") {
-            fs::write(
-                sources_dir
-                    .join(ASSET_DIR_PIPELINES)
-                    .join("pc.pipeline.json"),
-                file.data,
-            )?;
-        }
+impl TemplateChoice {
+    /// The bundled default template first, then the registered templates by name.
+    pub fn load_all(database: Option<Arc<Database>>) -> Result<Vec<Self>> {
+        let registered = db_get_templates(database)?;
 
-        fs::create_dir_all(project_path.join("build"))?;
-        fs::create_dir_all(project_path.join("data"))?;
-        fs::create_dir_all(project_path.join("plugins"))?;
+        Ok(std::iter::once(Self::BuiltinDefault)
+            .chain(registered.into_iter().map(Self::Registered))
+            .collect())
+    }
 
-        let mut amproject = fs::File::create(project_path.join(".amproject"))?;
+    /// The choice named `name` in `choices`, as given with `--template`.
+    ///
+    /// The bundled template always answers to [`DEFAULT_TEMPLATE`]. A registered template
+    /// with that name, which `am template register` refuses but older registries may
+    /// hold, can only be chosen at the prompt.
+    pub fn find<'a>(choices: &'a [Self], name: &str) -> Option<&'a Self> {
+        if name == DEFAULT_TEMPLATE {
+            return choices.iter().find(|c| matches!(c, Self::BuiltinDefault));
+        }
 
-        let project = &ProjectConfiguration {
-            name: project_name,
-            default_configuration: "pc.config.amconfig".to_string(),
-            build_dir: "build".to_string(),
-            data_dir: "data".to_string(),
-            sources_dir: "sources".to_string(),
-            version: 1,
-        };
+        choices
+            .iter()
+            .find(|c| matches!(c, Self::Registered(t) if t.name == name))
+    }
 
-        if !no_register {
-            register_project(project, project_path, database)?;
+    /// Name of the template.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::BuiltinDefault => DEFAULT_TEMPLATE,
+            Self::Registered(template) => &template.name,
         }
+    }
 
-        amproject.write_all(serde_json::to_string(project)?.as_bytes())?;
+    /// The registered template, or `None` for the bundled one.
+    fn registered(&self) -> Option<&Template> {
+        match self {
+            Self::BuiltinDefault => None,
+            Self::Registered(template) => Some(template),
+        }
     }
 
-    output.success(
-        json!(format!("Project {} created successfully", name)),
-        None,
-    );
+    /// The option offering this choice at the prompt, at `index` in the choices.
+    fn select_option(&self, index: usize) -> SelectOption {
+        let description = match self {
+            Self::BuiltinDefault => "Default project template for any engine (bundled)".to_string(),
+            Self::Registered(t) => match &t.description {
+                Some(d) => format!("{} ({})", d, t.path),
+                None => t.path.clone(),
+            },
+        };
 
-    Ok(())
+        SelectOption::new(self.name(), index).with_description(description)
+    }
 }
 
-async fn handle_register_project_command(
-    path: &std::path::Path,
-    database: Option<Arc<Database>>,
+/// Ask the user to pick one of `choices`.
+///
+/// Choices are told apart by their position, so a registered template named like the
+/// bundled one is still offered on its own.
+pub fn prompt_template_choice<'a>(
+    choices: &'a [TemplateChoice],
     input: &dyn Input,
-    output: &dyn Output,
-) -> anyhow::Result<()> {
-    output.progress(&format!("Registering project '{}'...", path.display()));
-    let amproject = path.join(".amproject");
-
-    if !amproject.exists() {
-        return Err(project_not_initialized(path.to_str().unwrap_or_default()).into());
-    }
+) -> Result<&'a TemplateChoice> {
+    let options: Vec<SelectOption> = choices
+        .iter()
+        .enumerate()
+        .map(|(i, choice)| choice.select_option(i))
+        .collect();
+
+    let selected =
+        with_flag_hint(input, "--template <name>").select_rich("Project Template", &options)?;
+    choices
+        .get(selected)
+        .ok_or_else(|| anyhow::anyhow!("Selected template {} is out of range", selected))
+}
 
-    let amproject_content = fs::read_to_string(&amproject)?;
-    let project_config: ProjectConfiguration = serde_json::from_str(&amproject_content)?;
-    let project_name = project_config.name.clone();
+/// A condition preventing `am project init` from creating the project as requested.
+///
+/// Conflicts are detected, reported and resolved in declaration order.
+#[derive(Debug, Clone)]
+enum InitConflict {
+    /// Another project is registered with the name of the new project.
+    RegisteredName { id: i32, path: String },
+    /// The project directory already exists and is not empty.
+    ExistingDirectory { path: PathBuf },
+    /// The template is registered, but its directory no longer exists.
+    StaleTemplate { name: String, path: PathBuf },
+}
 
-    if let Ok(Some(p)) = db_get_project_by_name(project_name.as_str(), database.clone()) {
-        let normalized_existing = normalize_path(std::path::Path::new(&p.path));
-        let normalized_new = normalize_path(path);
+/// Conflicts the user agreed to resolve through `am project init` flags.
+#[derive(Debug, Clone, Copy, Default)]
+struct InitResolutions {
+    overwrite_dir: bool,
+    replace_registration: bool,
+}
 
-        if normalized_existing == normalized_new {
-            output.success(
-                json!(format!(
-                    "Project {} is already registered",
-                    project_config.name
-                )),
-                None,
-            );
-            return Ok(());
+impl InitConflict {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::RegisteredName { .. } => "registered_name",
+            Self::ExistingDirectory { .. } => "existing_directory",
+            Self::StaleTemplate { .. } => "stale_template",
         }
+    }
 
-        output.warning(&format!(
-            "A project with the name {} is already registered at path {}",
-            project_name.cyan(),
-            p.path.cyan()
-        ));
-
-        if input.confirm(
-            "Do you want to forget that project and register this one?",
-            None,
-        )? {
-            info!("Unregistering previous project...");
-            db_forget_project(p.id.unwrap(), database.clone())?;
-        } else {
-            return Err(project_already_exists(&project_name)
-                .with_suggestion("Unregister the existing project first, or use a different name")
-                .into());
+    fn message(&self, project_name: &str) -> String {
+        match self {
+            Self::RegisteredName { path, .. } => format!(
+                "A project with the name {} is already registered at path {}",
+                project_name, path
+            ),
+            Self::ExistingDirectory { path } => format!(
+                "The project path {} already exists and is not empty",
+                path.display()
+            ),
+            Self::StaleTemplate { name, path } => format!(
+                "The directory {} of template {} does not exist",
+                path.display(),
+                name
+            ),
         }
     }
 
-    register_project(&project_config, path, database)?;
-
-    output.success(
-        json!(format!(
-            "Project {} registered successfully",
-            project_config.name
-        )),
-        None,
-    );
+    /// The flag resolving this conflict, if it can be resolved at all.
+    fn resolution_flag(&self) -> Option<&'static str> {
+        match self {
+            Self::RegisteredName { .. } => Some("--replace-registration"),
+            Self::ExistingDirectory { .. } => Some("--overwrite-dir"),
+            Self::StaleTemplate { .. } => None,
+        }
+    }
 
-    Ok(())
-}
+    fn is_resolved_by(&self, resolutions: InitResolutions) -> bool {
+        match self {
+            Self::RegisteredName { .. } => resolutions.replace_registration,
+            Self::ExistingDirectory { .. } => resolutions.overwrite_dir,
+            Self::StaleTemplate { .. } => false,
+        }
+    }
 
-async fn handle_unregister_project_command(
-    name: &str,
-    delete: &bool,
-    database: Option<Arc<Database>>,
-    _input: &dyn Input,
-    output: &dyn Output,
-) -> anyhow::Result<()> {
-    if let Ok(Some(p)) = db_get_project_by_name(name, database.clone()) {
-        output.progress("Unregistering project...");
-        db_forget_project(p.id.unwrap(), database.clone())?;
+    fn prompt(&self) -> Option<&'static str> {
+        match self {
+            Self::RegisteredName { .. } => {
+                Some("Do you want to forget that project and create this new one?")
+            }
+            Self::ExistingDirectory { .. } => Some(
+                "Do you want to overwrite the directory? All existing content will be deleted!",
+            ),
+            Self::StaleTemplate { .. } => None,
+        }
+    }
 
-        if *delete && fs::exists(p.path.clone())? {
-            output.progress("Deleting project directory...");
-            fs::remove_dir_all(p.path)?;
+    /// The error reported when this conflict stays unresolved.
+    fn error(&self, project_name: &str) -> CliError {
+        match self {
+            Self::RegisteredName { .. } => project_already_exists(project_name).with_suggestion(
+                "Use --replace-registration, --no-register to skip registration, or choose a different name",
+            ),
+            Self::ExistingDirectory { path } => CliError::new(
+                codes::ERR_PROJECT_ALREADY_EXISTS,
+                "Cannot create project",
+                "The project directory already exists and is not empty",
+            )
+            .with_suggestion("Use --overwrite-dir to replace its content, or choose another name")
+            .with_context(path.display().to_string()),
+            Self::StaleTemplate { path, .. } => CliError::new(
+                codes::ERR_VALIDATION_FIELD,
+                "Template directory does not exist",
+                "The registered template path is invalid or has been moved",
+            )
+            .with_context(path.display().to_string()),
         }
     }
 
-    output.success(
-        json!(format!("Project {} unregistered successfully", name)),
-        None,
-    );
+    fn to_json(&self, project_name: &str) -> serde_json::Value {
+        let path = match self {
+            Self::RegisteredName { path, .. } => path.clone(),
+            Self::ExistingDirectory { path } | Self::StaleTemplate { path, .. } => {
+                path.display().to_string()
+            }
+        };
 
-    Ok(())
+        let mut conflict = json!({
+            "kind": self.kind(),
+            "message": self.message(project_name),
+            "path": path,
+            "resolution": self.resolution_flag(),
+        });
+        if let Self::StaleTemplate { name, .. } = self {
+            conflict["template"] = json!(name);
+        }
+        conflict
+    }
 }
 
-async fn handle_list_projects_command(
-    favorite_filter: Option<bool>,
+/// Find every conflict preventing the project from being created, without changing
+/// anything on disk or in the registry.
+///
+/// `template` is the registered template the project starts from, `None` for the bundled
+/// one. Only projects created from the bundled template are registered, so only they can
+/// conflict with a registered project of the same name.
+fn detect_init_conflicts(
+    project_name: &str,
+    project_path: &std::path::Path,
+    template: Option<&Template>,
+    no_register: bool,
     database: Option<Arc<Database>>,
-    output: &dyn Output,
-) -> anyhow::Result<()> {
-    let projects = db_get_projects_filtered(favorite_filter, database)?;
+) -> Result<Vec<InitConflict>> {
+    let mut conflicts = Vec::new();
 
-    if projects.is_empty() {
-        output.table(Some("Registered Projects"), json!([]));
+    if !no_register
+        && template.is_none()
+        && let Some(p) = db_get_project_by_name(project_name, database.clone())?
+    {
+        conflicts.push(InitConflict::RegisteredName {
+            id: p.id.unwrap_or_default(),
+            path: p.path,
+        });
+    }
 
-        output.progress("No projects registered.");
-        output.progress("");
-        output.progress("To get started:");
-        output.progress(&format!(
-            "  {} Create a new project: {} {}",
-            "•".cyan(),
-            "am project init".green(),
-            "<name>".white()
-        ));
-        output.progress(&format!(
-            "  {} Register an existing project: {} {}",
-            "•".cyan(),
-            "am project register".green(),
-            "<path>".white()
-        ));
-    } else {
-        let is_json = matches!(output.mode(), crate::presentation::OutputMode::Json);
-        let display_data: Vec<serde_json::Value> = projects
-            .iter()
-            .map(|p| {
-                let display_name = if !is_json && p.is_favorite {
-                    format!("★ {}", p.name)
-                } else {
-                    p.name.clone()
-                };
-                let mut row = json!({
-                    "name": display_name,
-                    "path": p.path,
-                    "registered_at": p.registered_at.clone().unwrap_or_else(|| "-".to_string()),
-                });
-                if is_json {
-                    row["favorite"] = json!(p.is_favorite);
-                }
-                row
-            })
-            .collect();
+    if project_path.exists() && project_path.read_dir()?.next().is_some() {
+        conflicts.push(InitConflict::ExistingDirectory {
+            path: project_path.to_path_buf(),
+        });
+    }
 
-        output.table(Some("Registered Projects"), json!(display_data));
+    if let Some(t) = template
+        && !std::path::Path::new(&t.path).exists()
+    {
+        conflicts.push(InitConflict::StaleTemplate {
+            name: t.name.clone(),
+            path: PathBuf::from(&t.path),
+        });
     }
 
-    Ok(())
+    Ok(conflicts)
 }
 
-async fn handle_favorite_project_command(
-    name: &str,
-    target: bool,
-    database: Option<Arc<Database>>,
+/// Settle the conflicts not already resolved by flags, by asking the user.
+///
+/// Nothing is asked when the input is non-interactive or when a conflict can't be
+/// resolved; the conflicts left are then returned so they can be reported together.
+/// Declining a prompt fails the command.
+fn confirm_init_resolutions<'a>(
+    project_name: &str,
+    conflicts: &'a [InitConflict],
+    resolutions: InitResolutions,
+    input: &dyn Input,
     output: &dyn Output,
-) -> anyhow::Result<()> {
-    let project = db_get_project_by_name(name, database.clone())?.ok_or_else(|| {
-        CliError::new(
-            codes::ERR_PROJECT_NOT_REGISTERED,
-            format!("Project '{}' not found", name),
-            "The project is not registered in the database",
-        )
-        .with_suggestion("Use 'am project list' to see registered projects")
-    })?;
+) -> Result<Vec<&'a InitConflict>> {
+    let pending: Vec<&InitConflict> = conflicts
+        .iter()
+        .filter(|c| !c.is_resolved_by(resolutions))
+        .collect();
+
+    if !input.is_interactive() || pending.iter().any(|c| c.prompt().is_none()) {
+        return Ok(pending);
+    }
 
-    let id = project.id.expect("registered project must have an id");
+    for conflict in pending {
+        output.warning(&conflict.message(project_name));
+        if let Some(prompt) = conflict.prompt()
+            && !input.confirm(prompt, None)?
+        {
+            return Err(conflict.error(project_name).into());
+        }
+    }
 
-    if project.is_favorite == target {
-        let msg = if target {
-            format!("Project {} is already marked as favorite", name)
-        } else {
-            format!("Project {} is not marked as favorite", name)
-        };
-        output.success(json!(msg), None);
-        return Ok(());
+    Ok(Vec::new())
+}
+
+/// Report the conflicts left unresolved, as a `conflicts` list in JSON mode, and build
+/// the error failing the command.
+fn report_init_conflicts(
+    project_name: &str,
+    conflicts: &[&InitConflict],
+    output: &dyn Output,
+) -> anyhow::Error {
+    match output.mode() {
+        OutputMode::Json => output.success(
+            json!({
+                "name": project_name,
+                "created": false,
+                "conflicts": conflicts
+                    .iter()
+                    .map(|c| c.to_json(project_name))
+                    .collect::<Vec<_>>(),
+            }),
+            None,
+        ),
+        OutputMode::Interactive => {
+            for conflict in conflicts {
+                output.warning(&conflict.message(project_name));
+            }
+        }
     }
 
-    db_set_project_favorite(id, target, database)?;
+    if let [conflict] = conflicts {
+        return conflict.error(project_name).into();
+    }
 
-    let msg = if target {
-        format!("Project {} marked as favorite", name)
-    } else {
-        format!("Project {} unmarked as favorite", name)
-    };
-    output.success(json!(msg), None);
+    if let Some(unresolvable) = conflicts.iter().find(|c| c.prompt().is_none()) {
+        return unresolvable.error(project_name).into();
+    }
 
-    Ok(())
+    let flags: Vec<&str> = conflicts
+        .iter()
+        .filter_map(|c| c.resolution_flag())
+        .collect();
+    CliError::new(
+        codes::ERR_PROJECT_ALREADY_EXISTS,
+        "Cannot create project",
+        format!("{} conflicts must be resolved first", conflicts.len()),
+    )
+    .with_suggestion(format!("Re-run with {} to resolve them", flags.join(" ")))
+    .into()
 }
 
-async fn handle_info_project_command(
-    name: Option<String>,
-    database: Option<Arc<Database>>,
-    input: &dyn Input,
-    output: &dyn Output,
-) -> anyhow::Result<()> {
-    if let Some(project_name) = name {
-        return handle_info_by_name(&project_name, database, output).await;
+/// Directory of the registered `template`.
+fn registered_template_path(template: &Template) -> Result<PathBuf> {
+    let template_path = PathBuf::from(&template.path);
+    if !template_path.exists() {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            "Template directory does not exist",
+            "The registered template path is invalid or has been moved",
+        )
+        .with_context(template_path.display().to_string())
+        .into());
     }
 
-    let cwd = env::current_dir()?;
-    handle_info_current_dir(&cwd, database, input, output).await
+    Ok(template_path)
 }
 
-async fn handle_info_by_name(
+#[allow(clippy::too_many_arguments)]
+async fn handle_init_project_command(
     name: &str,
+    template: &TemplateChoice,
+    asset_dirs: BTreeMap<AssetType, String>,
+    no_register: &bool,
+    with_examples: bool,
+    variables: BTreeMap<String, String>,
+    resolutions: InitResolutions,
+    verify: bool,
+    dry_run: bool,
+    walk: WalkOptions,
     database: Option<Arc<Database>>,
+    input: &dyn Input,
     output: &dyn Output,
 ) -> anyhow::Result<()> {
-    let lookup_result = db_get_project_by_name(name, database.clone())?;
+    let project_name = project_name_from(name)?;
 
-    match lookup_result {
-        Some(project) => {
-            let project_path = PathBuf::from(&project.path);
-            let asset_counts = count_assets_by_type(&project_path).unwrap_or_default();
+    output.begin_stages(INIT_STAGES);
+    output.enter_stage(0);
 
-            // Read the .amproject file to get the actual directory configuration
-            let config = read_amproject_file(&project_path)?;
+    // Checked before anything is changed on disk or in the registry.
+    let cwd = env::current_dir()?;
+    let project_path = &cwd.join(&project_name);
+    utf8_path(project_path)?;
+
+    let conflicts = detect_init_conflicts(
+        &project_name,
+        project_path,
+        template.registered(),
+        *no_register,
+        database.clone(),
+    )?;
+    let unresolved =
+        confirm_init_resolutions(&project_name, &conflicts, resolutions, input, output)?;
+    if !unresolved.is_empty() {
+        return Err(report_init_conflicts(&project_name, &unresolved, output));
+    }
 
-            display_project_info(
-                &project.name,
-                &project_path,
-                &config.sources_dir,
-                &config.data_dir,
-                &config.build_dir,
-                true,
-                project.registered_at.as_deref(),
-                project.is_favorite,
-                &asset_counts,
-                output,
-            );
+    // Registered templates and their variables are resolved before anything is written,
+    // so a missing template or variable leaves no partial project behind.
+    let template_source = if let Some(template) = template.registered() {
+        let template_path = registered_template_path(template)?;
+        let manifest = read_vars_manifest(&template_path)?;
+        let scan = scan_template(&template_path, walk)?;
+        let values = resolve_variables(
+            &manifest,
+            &scan,
+            builtin_variables(&project_name),
+            &variables,
+            input,
+            output,
+        )?;
+        Some((template_path, scan.files, values))
+    } else {
+        if !variables.is_empty() {
+            output.warning("The default template has no variables: --var values are ignored");
+        }
+        None
+    };
 
-            Ok(())
+    if dry_run {
+        let plan = plan_init(
+            &project_name,
+            project_path,
+            template_source.as_ref(),
+            &asset_dirs,
+            *no_register,
+            with_examples,
+            &conflicts,
+            walk,
+        )?;
+        present_init_plan(&project_name, &plan, output);
+        return Ok(());
+    }
+
+    for conflict in &conflicts {
+        match conflict {
+            InitConflict::RegisteredName { id, .. } => {
+                info!("Unregistering previous project...");
+                db_forget_project(*id, database.clone())?;
+            }
+            InitConflict::ExistingDirectory { path } => safe_remove_in_parent(path)?,
+            InitConflict::StaleTemplate { .. } => {}
         }
-        None => Err(CliError::new(
-            codes::ERR_PROJECT_NOT_REGISTERED,
-            format!("Project '{}' not found", name),
-            "The project is not registered in the database",
-        )
-        .with_suggestion("Use 'am project list' to see registered projects")
-        .into()),
     }
-}
 
-async fn handle_info_current_dir(
-    cwd: &std::path::Path,
-    database: Option<Arc<Database>>,
-    input: &dyn Input,
-    output: &dyn Output,
-) -> anyhow::Result<()> {
-    let amproject_path = cwd.join(".amproject");
-    if !amproject_path.exists() {
-        return Err(CliError::new(
-            codes::ERR_PROJECT_NOT_INITIALIZED,
-            "No project found in current directory",
-            "The current directory does not contain a .amproject file",
-        )
-        .with_suggestion(
-            "Create a new project with 'am project init <name>' or provide a project name",
+    output.enter_stage(1);
+    output.progress(
+        format!(
+            "Initializing project {} using template {}...",
+            project_name.cyan(),
+            template.name().cyan()
         )
-        .into());
-    }
-
-    let config = read_amproject_file(cwd)?;
-    let asset_counts = count_assets_by_type(cwd).unwrap_or_default();
-    let cwd_str = cwd.to_str().unwrap_or_default();
-    let registered_project = db_get_project_by_path(cwd_str, database.clone())?;
+        .as_str(),
+    );
 
-    match registered_project {
-        Some(project) => {
-            display_project_info(
-                &config.name,
-                cwd,
-                &config.sources_dir,
-                &config.data_dir,
-                &config.build_dir,
-                true,
-                project.registered_at.as_deref(),
-                project.is_favorite,
-                &asset_counts,
+    let created_dir = !project_path.exists();
+    fs_ctx::create_dir_all(project_path)?;
+
+    // Everything written from here on is discarded when init fails, so it can be re-run.
+    let materialize = || -> Result<()> {
+        if let Some((template_path, files, values)) = template_source {
+            let verifier = verify.then(CopyVerifier::start);
+            copy_template(
+                &template_path,
+                project_path,
+                walk,
+                verifier.as_ref(),
                 output,
-            );
-        }
-        None => match output.mode() {
-            crate::presentation::OutputMode::Json => {
-                display_project_info(
-                    &config.name,
-                    cwd,
-                    &config.sources_dir,
-                    &config.data_dir,
-                    &config.build_dir,
-                    false,
-                    None,
-                    false,
-                    &asset_counts,
-                    output,
-                );
+            )?;
+            // Verified before the variables are substituted, which changes the files.
+            if let Some(verifier) = verifier {
+                let report = verifier.finish().into_result(project_path)?;
+                output.progress(&format!(
+                    "Verified {} file(s) ({} bytes) copied from the template",
+                    report.files, report.bytes
+                ));
             }
-            crate::presentation::OutputMode::Interactive => {
-                display_project_info_interactive(
-                    &config.name,
-                    cwd,
-                    &config.sources_dir,
-                    &config.data_dir,
-                    &config.build_dir,
-                    false,
-                    None,
-                    false,
-                    &asset_counts,
-                    output,
-                );
+            render_files(project_path, &files, &values)?;
 
-                output.progress("");
-                output.progress("This project is not registered in the database.");
+            // The project is named after the transformed name, not after the template.
+            if project_path.join(".amproject").is_file() {
+                let mut project_config = read_amproject_file(project_path)?;
+                project_config.name = project_name.clone();
+                project_config.asset_dirs.extend(asset_dirs);
 
-                match input.confirm("Would you like to register it now?", Some(false)) {
-                    Ok(true) => {
-                        let project = config.to_project(cwd_str);
-                        db_create_project(&project, database)?;
-                        output.success(json!("Project registered successfully!"), None);
-                    }
-                    Ok(false) | Err(_) => {
-                        output.progress(&format!(
-                            "  Run {} to register this project",
-                            "am project register".green()
-                        ));
-                    }
+                let sources_dir = project_path.join(&project_config.sources_dir);
+                for dir in project_config.asset_dirs.values() {
+                    fs_ctx::create_dir_all(sources_dir.join(dir))?;
                 }
+
+                write_amproject_file(project_path, &project_config)?;
             }
-        },
+        } else {
+            let project = &default_project_configuration(project_name, asset_dirs);
+
+            for dir in default_project_dirs(project_path, project) {
+                fs_ctx::create_dir_all(&dir)?;
+            }
+
+            for (resource, destination) in default_project_files(project_path) {
+                if let Some(file) = Resource::get(resource) {
+                    fs_ctx::write(&destination, file.data)?;
+                    events::emit(CliEvent::FileWritten { path: destination });
+                }
+            }
+
+            write_amproject_file(project_path, project)?;
+
+            // Last, so a failure can't leave a registration without its project.
+            if !no_register {
+                register_project(project, project_path, database)?;
+            }
+        }
+
+        Ok(())
+    };
+    if let Err(e) = materialize() {
+        discard_partial_project(project_path, created_dir);
+        return Err(e);
+    }
+
+    output.enter_stage(2);
+
+    if with_examples && !project_path.join(".amproject").is_file() {
+        output.warning(&format!(
+            "Template {} has no .amproject file: example assets were not added",
+            template.name().cyan()
+        ));
+    }
+
+    if project_path.join(".amproject").is_file() {
+        let project_config = read_amproject_file(project_path)?;
+
+        if with_examples {
+            let created = write_example_assets(project_path, &project_config)?;
+            output.progress(&format!(
+                "Added {} example file(s) prefixed with 'example_'",
+                created
+            ));
+        }
+
+        run_hook_if_declared(
+            project_path,
+            &project_config,
+            HOOK_POST_INIT,
+            "init",
+            output,
+        )?;
     }
 
+    output.success(
+        json!(format!("Project {} created successfully", name)),
+        None,
+    );
+
     Ok(())
 }
 
-#[allow(clippy::too_many_arguments)]
-fn display_project_info(
-    name: &str,
-    path: &std::path::Path,
-    sources_dir: &str,
-    data_dir: &str,
-    build_dir: &str,
-    registered: bool,
-    registered_at: Option<&str>,
-    favorite: bool,
-    asset_counts: &HashMap<String, usize>,
+/// Rules leaving the variables manifest, the `.git` directories and the `build`
+/// directory out of the copy of the template at `template_path`.
+fn template_ignore(template_path: &Path) -> IgnoreRules {
+    IgnoreRules::from_patterns(
+        template_path,
+        [format!("/{}", VARS_MANIFEST).as_str(), ".git/", "/build/"],
+    )
+}
+
+/// Copy the directories, empty ones included, and the files of the template at
+/// `template_path` into `project_path`, reporting progress for each file and warning about
+/// the directories `walk` skips.
+///
+/// Fails with `ERR_TEMPLATE_COPY_FAILED` on the first path that can't be copied, given as
+/// context. With a `verifier`, every copied file is queued for verification.
+fn copy_template(
+    template_path: &Path,
+    project_path: &Path,
+    walk: WalkOptions,
+    verifier: Option<&CopyVerifier>,
     output: &dyn Output,
-) {
-    match output.mode() {
-        crate::presentation::OutputMode::Json => {
-            let json_data = build_project_info_json(
-                name,
-                path,
-                sources_dir,
-                data_dir,
-                build_dir,
-                registered,
-                registered_at,
-                favorite,
-                asset_counts,
-            );
-            output.success(json_data, None);
-        }
-        crate::presentation::OutputMode::Interactive => {
-            display_project_info_interactive(
-                name,
-                path,
-                sources_dir,
-                data_dir,
-                build_dir,
-                registered,
-                registered_at,
-                favorite,
-                asset_counts,
-                output,
-            );
-        }
+) -> Result<()> {
+    let copy_failed = |path: &Path, error: String| -> anyhow::Error {
+        CliError::new(
+            codes::ERR_TEMPLATE_COPY_FAILED,
+            format!("Failed to copy template from {}", template_path.display()),
+            error,
+        )
+        .with_context(path.display().to_string())
+        .into()
+    };
+
+    let walk = walk_project_files(template_path, &template_ignore(template_path), walk);
+    warn_skipped(&walk.skipped, output);
+    for dir in &walk.dirs {
+        let dest_dir = project_path.join(dir.strip_prefix(template_path).unwrap_or(dir));
+        fs_ctx::create_dir_all(&dest_dir)
+            .map_err(|e| copy_failed(&dest_dir, e.root_cause().to_string()))?;
     }
-}
 
-fn normalize_path(path: &std::path::Path) -> String {
-    // Normalize the path by resolving . and .. components
-    // without requiring the path to exist (unlike canonicalize)
-    let mut components = Vec::new();
-    for component in path.components() {
-        match component {
-            std::path::Component::Prefix(_) | std::path::Component::RootDir => {
-                components.push(component);
-            }
-            std::path::Component::CurDir => {
-                // Skip . components
-            }
-            std::path::Component::ParentDir => {
-                // Pop the last component if it's a normal directory
-                if let Some(last) = components.last() {
-                    if matches!(last, std::path::Component::Normal(_)) {
-                        components.pop();
-                    } else {
-                        components.push(component);
-                    }
-                } else {
-                    components.push(component);
-                }
-            }
-            std::path::Component::Normal(_) => {
-                components.push(component);
+    let total = walk.files.len();
+    for (index, file) in walk.files.iter().enumerate() {
+        let relative = file.strip_prefix(template_path).unwrap_or(file);
+        output.progress_with(&format!("Copying {}", relative.display()), index + 1, total);
+
+        let dest_path = project_path.join(relative);
+        match verifier {
+            Some(verifier) => copy_file_hashed(file, &dest_path)
+                .map(|(bytes, hash)| {
+                    verifier.check(relative.display().to_string(), &dest_path, hash, bytes)
+                })
+                .map_err(|e| copy_failed(file, e.to_string()))?,
+            None => {
+                fs_ctx::copy(file, &dest_path)
+                    .map_err(|e| copy_failed(file, e.root_cause().to_string()))?;
             }
         }
     }
 
-    let normalized: std::path::PathBuf = components.iter().collect();
-    normalized.to_str().unwrap_or_default().to_string()
+    Ok(())
 }
 
-#[allow(clippy::too_many_arguments)]
-fn build_project_info_json(
-    name: &str,
-    path: &std::path::Path,
-    sources_dir: &str,
-    data_dir: &str,
-    build_dir: &str,
+/// Configuration of a project created from the default template.
+fn default_project_configuration(
+    name: String,
+    asset_dirs: BTreeMap<AssetType, String>,
+) -> ProjectConfiguration {
+    ProjectConfiguration {
+        name,
+        default_configuration: "pc.config.amconfig".to_string(),
+        build_dir: "build".to_string(),
+        data_dir: "data".to_string(),
+        sources_dir: "sources".to_string(),
+        version: AMPROJECT_VERSION,
+        hooks: Default::default(),
+        allow_json_comments: false,
+        asset_dirs,
+    }
+}
+
+/// Directories created in a project at `project_path` made from the default template.
+fn default_project_dirs(project_path: &Path, project: &ProjectConfiguration) -> Vec<PathBuf> {
+    let sources_dir = project_path.join(&project.sources_dir);
+
+    vec![
+        sources_dir.join(ASSET_DIR_ATTENUATORS),
+        sources_dir.join(project.asset_dir(AssetType::Collection)),
+        sources_dir.join(project.asset_dir(AssetType::Effect)),
+        sources_dir.join(project.asset_dir(AssetType::Event)),
+        sources_dir.join(ASSET_DIR_PIPELINES),
+        sources_dir.join(ASSET_DIR_RTPC),
+        sources_dir.join(project.asset_dir(AssetType::Soundbank)),
+        sources_dir.join(project.asset_dir(AssetType::Sound)),
+        sources_dir.join(project.asset_dir(AssetType::SwitchContainer)),
+        sources_dir.join(project.asset_dir(AssetType::Switch)),
+        project_path.join(&project.build_dir),
+        project_path.join(&project.data_dir),
+        project_path.join(PLUGINS_DIR),
+    ]
+}
+
+/// Files written in a project at `project_path` made from the default template, each
+/// with the embedded resource it is written from.
+fn default_project_files(project_path: &Path) -> [(&'static str, PathBuf); 3] {
+    let sources_dir = project_path.join("sources");
+
+    [
+        ("default.config.json", sources_dir.join("pc.config.json")),
+        ("default.buses.json", sources_dir.join("pc.buses.json")),
+        (
+            "default.pipeline.json",
+            sources_dir
+                .join(ASSET_DIR_PIPELINES)
+                .join("pc.pipeline.json"),
+        ),
+    ]
+}
+
+/// What `am project init` does, reported instead of done with `--dry-run`.
+#[derive(Debug)]
+struct InitPlan {
+    /// Directory of the new project.
+    project_path: PathBuf,
+    /// Directories deleted by `--overwrite-dir`.
+    deleted: Vec<PathBuf>,
+    /// Registrations removed by `--replace-registration`, as (name, path).
+    unregistered: Vec<(String, String)>,
+    /// Directories created, relative to the project directory.
+    directories: BTreeSet<PathBuf>,
+    /// Files written, relative to the project directory, with their size in bytes.
+    files: BTreeMap<PathBuf, u64>,
+    /// Whether the project is registered.
     registered: bool,
-    registered_at: Option<&str>,
-    favorite: bool,
-    asset_counts: &HashMap<String, usize>,
-) -> serde_json::Value {
-    let path_str = path.to_str().unwrap_or_default();
+    /// Hook run once the project is created.
+    hook: Option<&'static str>,
+    /// Template directories the walk didn't descend into.
+    skipped: Vec<SkippedPath>,
+}
 
-    // Resolve relative paths against the project root and normalize
-    let sources_path = if sources_dir.is_empty() {
-        path_str.to_string()
-    } else {
-        normalize_path(&path.join(sources_dir))
-    };
-    let data_path = if data_dir.is_empty() {
-        path_str.to_string()
-    } else {
-        normalize_path(&path.join(data_dir))
-    };
-    let build_path = if build_dir.is_empty() {
-        path_str.to_string()
-    } else {
-        normalize_path(&path.join(build_dir))
-    };
+impl InitPlan {
+    fn new(project_path: &Path) -> Self {
+        Self {
+            project_path: project_path.to_path_buf(),
+            deleted: Vec::new(),
+            unregistered: Vec::new(),
+            directories: BTreeSet::new(),
+            files: BTreeMap::new(),
+            registered: false,
+            hook: None,
+            skipped: Vec::new(),
+        }
+    }
 
-    let mut json_value = json!({
-        "name": name,
-        "path": path_str,
-        "registered": registered,
-        "favorite": favorite,
-        "paths": {
-            "sources": sources_path,
-            "data": data_path,
-            "build": build_path,
-        },
-        "assets": {
-            "sounds": asset_counts.get("sounds").unwrap_or(&0),
-            "collections": asset_counts.get("collections").unwrap_or(&0),
-            "events": asset_counts.get("events").unwrap_or(&0),
-            "effects": asset_counts.get("effects").unwrap_or(&0),
-            "switches": asset_counts.get("switches").unwrap_or(&0),
-            "switch_containers": asset_counts.get("switch_containers").unwrap_or(&0),
-            "soundbanks": asset_counts.get("soundbanks").unwrap_or(&0),
-            "attenuators": asset_counts.get("attenuators").unwrap_or(&0),
-            "rtpc": asset_counts.get("rtpc").unwrap_or(&0),
-            "pipelines": asset_counts.get("pipelines").unwrap_or(&0),
+    /// Plan the creation of `dir` and of its missing parents in the project directory.
+    fn add_dir(&mut self, dir: &Path) {
+        let Ok(relative) = dir.strip_prefix(&self.project_path) else {
+            return;
+        };
+        for ancestor in relative.ancestors() {
+            if !ancestor.as_os_str().is_empty() {
+                self.directories.insert(ancestor.to_path_buf());
+            }
         }
-    });
+    }
 
-    if registered {
-        if let Some(date) = registered_at {
-            json_value["registered_at"] = json!(date);
+    /// Plan the writing of `size` bytes to `path`, and the creation of its parents.
+    fn add_file(&mut self, path: &Path, size: u64) {
+        if let Some(parent) = path.parent() {
+            self.add_dir(parent);
+        }
+        if let Ok(relative) = path.strip_prefix(&self.project_path) {
+            self.files.insert(relative.to_path_buf(), size);
         }
-    } else {
-        json_value["notice"] = json!(
-            "This project is not registered in the database. Run 'am project register' to register it."
-        );
     }
 
-    json_value
-}
+    fn to_json(&self, project_name: &str) -> serde_json::Value {
+        let relative = |path: &Path| path.to_string_lossy().replace('\\', "/");
 
-#[allow(clippy::too_many_arguments)]
-fn display_project_info_interactive(
-    name: &str,
-    path: &std::path::Path,
-    sources_dir: &str,
-    data_dir: &str,
-    build_dir: &str,
-    registered: bool,
-    registered_at: Option<&str>,
-    favorite: bool,
-    asset_counts: &HashMap<String, usize>,
-    output: &dyn Output,
-) {
-    let path_str = path.to_str().unwrap_or_default();
+        json!({
+            "dry_run": true,
+            "name": project_name,
+            "path": self.project_path.to_string_lossy(),
+            "deleted": self
+                .deleted
+                .iter()
+                .map(|path| path.to_string_lossy())
+                .collect::<Vec<_>>(),
+            "unregistered": self
+                .unregistered
+                .iter()
+                .map(|(name, path)| json!({ "name": name, "path": path }))
+                .collect::<Vec<_>>(),
+            "directories": self.directories.iter().map(|dir| relative(dir)).collect::<Vec<_>>(),
+            "files": self
+                .files
+                .iter()
+                .map(|(path, size)| json!({ "path": relative(path), "size": size }))
+                .collect::<Vec<_>>(),
+            "registered": self.registered,
+            "hook": self.hook,
+        })
+    }
 
-    // Resolve relative paths against the project root and normalize
-    let sources_path = if sources_dir.is_empty() {
-        path_str.to_string()
+    /// The project directory and its content as an indented tree, with file sizes.
+    fn tree_lines(&self) -> Vec<String> {
+        let entries: BTreeMap<&Path, Option<u64>> = self
+            .directories
+            .iter()
+            .map(|dir| (dir.as_path(), None))
+            .chain(
+                self.files
+                    .iter()
+                    .map(|(path, size)| (path.as_path(), Some(*size))),
+            )
+            .collect();
+
+        let mut lines = vec![format!("{}/", self.project_path.display())];
+        for (path, size) in entries {
+            let indent = "  ".repeat(path.components().count());
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            lines.push(match size {
+                Some(size) => format!("{}{} ({} bytes)", indent, name, size),
+                None => format!("{}{}/", indent, name),
+            });
+        }
+        lines
+    }
+}
+
+/// Plan what `am project init` does for the project `project_name` at `project_path`,
+/// once its `conflicts` are resolved, without touching the disk or the registry.
+///
+/// `template` is the directory, the files with placeholders and the variable values of
+/// a registered template, or `None` for the default template, walked with `walk`.
+#[allow(clippy::too_many_arguments)]
+fn plan_init(
+    project_name: &str,
+    project_path: &Path,
+    template: Option<&(PathBuf, Vec<PathBuf>, BTreeMap<String, String>)>,
+    asset_dirs: &BTreeMap<AssetType, String>,
+    no_register: bool,
+    with_examples: bool,
+    conflicts: &[InitConflict],
+    walk: WalkOptions,
+) -> Result<InitPlan> {
+    let mut plan = InitPlan::new(project_path);
+
+    for conflict in conflicts {
+        match conflict {
+            InitConflict::RegisteredName { path, .. } => plan
+                .unregistered
+                .push((project_name.to_string(), path.clone())),
+            InitConflict::ExistingDirectory { path } => plan.deleted.push(path.clone()),
+            InitConflict::StaleTemplate { .. } => {}
+        }
+    }
+
+    let config = if let Some((template_path, files, values)) = template {
+        let walk = walk_project_files(template_path, &template_ignore(template_path), walk);
+        plan.skipped = walk.skipped;
+        for dir in &walk.dirs {
+            plan.add_dir(&project_path.join(dir.strip_prefix(template_path).unwrap_or(dir)));
+        }
+        for path in walk.files {
+            let relative = path.strip_prefix(template_path).unwrap_or(&path);
+            let size = if files.iter().any(|file| file == relative) {
+                render(&fs::read_to_string(&path)?, values).len() as u64
+            } else {
+                fs::metadata(&path)?.len()
+            };
+            plan.add_file(&project_path.join(relative), size);
+        }
+
+        let amproject = template_path.join(".amproject");
+        if amproject.is_file() {
+            let mut text = fs::read_to_string(&amproject)?;
+            if files.iter().any(|file| file == Path::new(".amproject")) {
+                text = render(&text, values);
+            }
+            let mut config: ProjectConfiguration = serde_json::from_str(&text)
+                .with_context(|| format!("Failed to parse {}", amproject.display()))?;
+            config.name = project_name.to_string();
+            config.asset_dirs.extend(asset_dirs.clone());
+
+            let sources_dir = project_path.join(&config.sources_dir);
+            for dir in config.asset_dirs.values() {
+                plan.add_dir(&sources_dir.join(dir));
+            }
+            let json = json_preserving_unknown_fields(Some(&text), &config)?;
+            plan.add_file(&project_path.join(".amproject"), json.len() as u64);
+            Some(config)
+        } else {
+            None
+        }
     } else {
-        normalize_path(&path.join(sources_dir))
+        let config = default_project_configuration(project_name.to_string(), asset_dirs.clone());
+        for dir in default_project_dirs(project_path, &config) {
+            plan.add_dir(&dir);
+        }
+        for (resource, destination) in default_project_files(project_path) {
+            if let Some(file) = Resource::get(resource) {
+                plan.add_file(&destination, file.data.len() as u64);
+            }
+        }
+        let json = json_preserving_unknown_fields(None, &config)?;
+        plan.add_file(&project_path.join(".amproject"), json.len() as u64);
+        plan.registered = !no_register;
+        Some(config)
     };
-    let data_path = if data_dir.is_empty() {
-        path_str.to_string()
-    } else {
-        normalize_path(&path.join(data_dir))
+
+    if let Some(config) = config {
+        if with_examples {
+            for (path, resource) in example_asset_targets(project_path, &config) {
+                let planned = path
+                    .strip_prefix(project_path)
+                    .is_ok_and(|relative| plan.files.contains_key(relative));
+                if !planned && let Some(file) = Resource::get(resource) {
+                    plan.add_file(&path, file.data.len() as u64);
+                }
+            }
+        }
+        if config.hooks.contains_key(HOOK_POST_INIT) {
+            plan.hook = Some(HOOK_POST_INIT);
+        }
+    }
+
+    Ok(plan)
+}
+
+/// Report the `plan` of `am project init --dry-run`, as a tree interactively.
+fn present_init_plan(project_name: &str, plan: &InitPlan, output: &dyn Output) {
+    warn_skipped(&plan.skipped, output);
+    match output.mode() {
+        OutputMode::Json => output.success(plan.to_json(project_name), None),
+        OutputMode::Interactive => {
+            for path in &plan.deleted {
+                output.print(&format!("Would delete {}", path.display()));
+            }
+            for (name, path) in &plan.unregistered {
+                output.print(&format!("Would unregister project {} at {}", name, path));
+            }
+            output.print(&plan.tree_lines().join("\n"));
+            if plan.registered {
+                output.print(&format!("Would register project {}", project_name));
+            }
+            if let Some(hook) = plan.hook {
+                output.print(&format!("Would run the '{}' hook", hook));
+            }
+            output.success(
+                json!(format!(
+                    "Dry run: project {} was not created ({} directories, {} files planned)",
+                    project_name,
+                    plan.directories.len(),
+                    plan.files.len()
+                )),
+                None,
+            );
+        }
+    }
+}
+
+/// The directory `am project register` works on, absolute and normalized.
+///
+/// Without `path`, this is the current directory when it holds a project or is scanned
+/// with `recursive`. Otherwise the user is prompted for the directory, and a
+/// non-interactive run fails with `ERR_VALIDATION_FIELD`.
+fn register_target(path: Option<&Path>, recursive: bool, input: &dyn Input) -> Result<PathBuf> {
+    let cwd = env::current_dir()?;
+    let path = match path {
+        Some(path) => path.to_path_buf(),
+        None if recursive || cwd.join(".amproject").exists() => cwd.clone(),
+        None if input.is_interactive() => {
+            let exists = |value: &str| {
+                let answer = resolve_register_path(Path::new(value.trim()), &cwd);
+                to_validation(if answer.is_dir() {
+                    Ok(())
+                } else {
+                    Err("This directory does not exist".to_string())
+                })
+            };
+            let answer = input.prompt_text(
+                "Project directory",
+                Some(&cwd.display().to_string()),
+                None,
+                &[&exists],
+            )?;
+            PathBuf::from(answer.trim())
+        }
+        None => {
+            return Err(CliError::new(
+                codes::ERR_VALIDATION_FIELD,
+                "No project to register",
+                format!(
+                    "No path was given and the current directory '{}' is not an Amplitude project",
+                    cwd.display()
+                ),
+            )
+            .with_suggestion("Pass the project directory: am project register <path>")
+            .into());
+        }
     };
-    let build_path = if build_dir.is_empty() {
-        path_str.to_string()
-    } else {
-        normalize_path(&path.join(build_dir))
+
+    // Checked before normalizing, which would replace the invalid bytes
+    utf8_path(&path)?;
+    Ok(resolve_register_path(&path, &cwd))
+}
+
+/// `path` with a leading `~` expanded, made absolute against `cwd`, and normalized.
+fn resolve_register_path(path: &Path, cwd: &Path) -> PathBuf {
+    let path = expand_home(path, user_home().as_deref());
+    PathBuf::from(normalize_path(&cwd.join(path)))
+}
+
+/// Register the project at `path`.
+///
+/// With `name_override`, the project is registered under that name instead of the one in
+/// its `.amproject`, which is left unchanged. The registration is then marked as an alias
+/// of the declared name, so it isn't reported as a mismatch.
+///
+/// When the name is registered for another directory, the project is registered under a
+/// numbered name with `rename_on_conflict`, and the registration is pointed to `path`
+/// with `force`.
+async fn handle_register_project_command(
+    path: &std::path::Path,
+    name_override: Option<&str>,
+    rename_on_conflict: bool,
+    force: bool,
+    strict: bool,
+    database: Option<Arc<Database>>,
+    input: &dyn Input,
+    output: &dyn Output,
+) -> anyhow::Result<()> {
+    output.progress(&format!("Registering project '{}'...", path.display()));
+    utf8_path(path)?;
+    let amproject = path.join(".amproject");
+
+    if !amproject.exists() {
+        return Err(project_not_initialized(&path.display().to_string()).into());
+    }
+    if strict {
+        read_amproject_file_strict(path)?;
+    }
+
+    let mut project_config = read_amproject_file(path)?;
+    let mut project_name = match name_override {
+        Some(name) => project_name_from(name)?,
+        None => project_config.name.clone(),
     };
 
-    output.progress(&format!("Project: {}", name.cyan().bold()));
-    output.progress(&"─".repeat(PROJECT_INFO_SEPARATOR_WIDTH));
-    output.progress("");
-    output.progress("Details:");
-    output.progress(&format!("  Root Path:      {}", path_str));
-    if registered {
-        output.progress(&format!(
-            "  Registered:     {} ({})",
-            "Yes".green(),
-            registered_at.unwrap_or("-")
-        ));
-    } else {
-        output.progress(&format!("  Registered:     {}", "No".yellow()));
+    if let Some(p) = db_get_project_by_name(project_name.as_str(), database.clone())? {
+        let normalized_existing = normalize_path(std::path::Path::new(&p.path));
+        let normalized_new = normalize_path(path);
+
+        if normalized_existing == normalized_new {
+            output.success(
+                json!(format!("Project {} is already registered", project_name)),
+                None,
+            );
+            return Ok(());
+        }
+
+        if force {
+            let id = p.id.expect("registered project must have an id");
+            db_set_project_path(id, utf8_path(path)?, database)?;
+            output.success(
+                json!(format!(
+                    "Project {} now points to {} instead of {}",
+                    project_name,
+                    path.display(),
+                    p.path
+                )),
+                None,
+            );
+            return Ok(());
+        }
+
+        if rename_on_conflict {
+            let new_name = unique_project_name(&project_name, &HashSet::new(), database.clone())?;
+            if name_override.is_some() {
+                project_name = new_name;
+            } else {
+                rename_declared_project(path, &mut project_config, new_name, output)?;
+                project_name = project_config.name.clone();
+            }
+        } else if !input.is_interactive() {
+            let conflict = NameConflict {
+                name: project_name.clone(),
+                registered_path: Some(p.path.clone()),
+                conflicting_paths: vec![path.display().to_string()],
+            };
+            present_name_conflicts(&[conflict], output);
+            return Err(project_already_exists(&project_name)
+                .with_context(p.path)
+                .with_suggestion(
+                    "Use --force to point the registration to this directory, or --rename-on-conflict to register it under a numbered name",
+                )
+                .into());
+        } else {
+            output.warning(&format!(
+                "A project with the name {} is already registered at path {}",
+                project_name.cyan(),
+                p.path.cyan()
+            ));
+
+            if input.confirm(
+                "Do you want to forget that project and register this one?",
+                None,
+            )? {
+                info!("Unregistering previous project...");
+                db_forget_project(p.id.unwrap(), database.clone())?;
+            } else {
+                return Err(project_already_exists(&project_name)
+                    .with_suggestion(
+                        "Use --force to point the registration to this directory, --rename-on-conflict, or a different name",
+                    )
+                    .into());
+            }
+        }
+    }
+
+    let mut project = project_config.to_project(utf8_path(path)?);
+    project.name = project_name.clone();
+    db_create_project(&project, database.clone())?;
+
+    if project_name != project_config.name {
+        db_set_project_alias_of_file_name(&project_name, Some(&project_config.name), database)?;
+        output.success(
+            json!(format!(
+                "Project {} registered successfully as an alias of {}",
+                project_name, project_config.name
+            )),
+            None,
+        );
+    } else {
+        output.success(
+            json!(format!("Project {} registered successfully", project_name)),
+            None,
+        );
+    }
+
+    Ok(())
+}
+
+/// A project name declared by more than one project.
+#[derive(Debug)]
+struct NameConflict {
+    name: String,
+    /// Path the name is registered for, if it is registered.
+    registered_path: Option<String>,
+    /// Paths of the projects being registered that declare the name.
+    conflicting_paths: Vec<String>,
+}
+
+/// How a project found by `am project register --recursive` gets registered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CandidateStatus {
+    /// The project is registered with its declared name.
+    Register,
+    /// The project is already registered at its path.
+    AlreadyRegistered,
+    /// The declared name is taken, by the registry or by an earlier project of the batch.
+    NameTaken,
+}
+
+#[derive(Debug)]
+struct RegistrationCandidate {
+    path: PathBuf,
+    config: ProjectConfiguration,
+    status: CandidateStatus,
+}
+
+/// Register every project found under `root`.
+///
+/// Declared names are checked against the registry and within the batch before anything
+/// is registered: unless `rename_on_conflict` is set, a single conflict leaves every
+/// project unregistered.
+async fn handle_register_recursive_command(
+    root: &std::path::Path,
+    rename_on_conflict: bool,
+    strict: bool,
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> anyhow::Result<()> {
+    let root = std::path::absolute(root)?;
+    utf8_path(&root)?;
+    output.progress(&format!("Searching projects under '{}'...", root.display()));
+
+    let mut candidates = Vec::new();
+    for dir in find_project_dirs(&root)? {
+        utf8_path(&dir)?;
+        let config = read_project_config(&dir, strict)?;
+        candidates.push(RegistrationCandidate {
+            path: dir,
+            config,
+            status: CandidateStatus::Register,
+        });
+    }
+
+    let conflicts = find_name_conflicts(&mut candidates, database.clone())?;
+    if !conflicts.is_empty() && !rename_on_conflict {
+        return Err(report_name_conflicts(&conflicts, output));
+    }
+
+    let mut taken: HashSet<String> = candidates.iter().map(|c| c.config.name.clone()).collect();
+    let mut results = Vec::with_capacity(candidates.len());
+    let mut registered = 0;
+
+    for candidate in &mut candidates {
+        let mut result = json!({
+            "path": candidate.path.display().to_string(),
+            "registered": candidate.status != CandidateStatus::AlreadyRegistered,
+        });
+
+        match candidate.status {
+            CandidateStatus::AlreadyRegistered => {
+                output.progress(&format!("{} is already registered", candidate.config.name));
+            }
+            CandidateStatus::NameTaken => {
+                let new_name =
+                    unique_project_name(&candidate.config.name, &taken, database.clone())?;
+                taken.insert(new_name.clone());
+                result["renamed_from"] = json!(rename_declared_project(
+                    &candidate.path,
+                    &mut candidate.config,
+                    new_name,
+                    output
+                )?);
+            }
+            CandidateStatus::Register => {}
+        }
+
+        if candidate.status != CandidateStatus::AlreadyRegistered {
+            register_project(&candidate.config, &candidate.path, database.clone())?;
+            registered += 1;
+            output.progress(&format!(
+                "{} {} registered",
+                "✓".green(),
+                candidate.config.name
+            ));
+        }
+
+        result["name"] = json!(candidate.config.name);
+        results.push(result);
+    }
+
+    match output.mode() {
+        OutputMode::Json => output.success(
+            json!({
+                "root": root.display().to_string(),
+                "projects": results,
+                "conflicts": conflicts.iter().map(name_conflict_json).collect::<Vec<_>>(),
+            }),
+            None,
+        ),
+        OutputMode::Interactive if candidates.is_empty() => output.success(
+            json!(format!("No project found under {}", root.display())),
+            None,
+        ),
+        OutputMode::Interactive => output.success(
+            json!(format!("{} projects registered successfully", registered)),
+            None,
+        ),
+    }
+
+    Ok(())
+}
+
+/// A project to register, as read from stdin by `am project register --stdin`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RegisterEntry {
+    /// The project directory.
+    pub(crate) path: PathBuf,
+    /// The name the project is expected to declare in its `.amproject`.
+    #[serde(default)]
+    pub(crate) name: Option<String>,
+}
+
+/// Register the projects described on stdin, reporting a result for each of them.
+///
+/// Malformed input is rejected as a whole before anything is registered. Entries are
+/// then registered in order, a failed entry not preventing the next ones.
+async fn handle_register_stdin_command(
+    rename_on_conflict: bool,
+    database: Option<Arc<Database>>,
+    input: &dyn Input,
+    output: &dyn Output,
+) -> anyhow::Result<()> {
+    if output.mode() != OutputMode::Json {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            "Reading projects from stdin requires JSON mode",
+            "Results of --stdin are reported for each entry as JSON",
+        )
+        .with_suggestion("Run the command with --json: am --json project register --stdin")
+        .into());
+    }
+
+    let entries: Vec<RegisterEntry> = parse_json_entries(&input.read_stdin()?)?;
+
+    let mut results = Vec::with_capacity(entries.len());
+    let mut failed = 0;
+
+    for entry in &entries {
+        let mut result = match register_entry(entry, rename_on_conflict, database.clone(), output) {
+            Ok(result) => result,
+            Err(e) => {
+                failed += 1;
+                json!({ "registered": false, "error": entry_error_json(&e) })
+            }
+        };
+
+        result["path"] = json!(entry.path.display().to_string());
+        results.push(result);
+    }
+
+    output.success(json!({ "projects": results }), None);
+
+    if failed > 0 {
+        return Err(CliError::new(
+            codes::ERR_PROJECT_NOT_REGISTERED,
+            format!(
+                "Failed to register {} of {} project(s)",
+                failed,
+                results.len()
+            ),
+            "Some entries read from stdin could not be registered",
+        )
+        .with_suggestion("See the 'error' of each failed entry for details")
+        .into());
+    }
+
+    Ok(())
+}
+
+/// The `error` reported for an entry of a batch registration that failed.
+pub(crate) fn entry_error_json(e: &anyhow::Error) -> serde_json::Value {
+    match e.downcast_ref::<CliError>() {
+        Some(cli_err) => json!({
+            "code": cli_err.code,
+            "message": cli_err.what,
+            "why": cli_err.why,
+        }),
+        None => json!({ "code": -1, "message": e.to_string() }),
+    }
+}
+
+/// Register the project described by a stdin entry, without prompting.
+pub(crate) fn register_entry(
+    entry: &RegisterEntry,
+    rename_on_conflict: bool,
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> Result<serde_json::Value> {
+    let path = std::path::absolute(&entry.path)?;
+    utf8_path(&path)?;
+
+    if !path.join(".amproject").is_file() {
+        return Err(project_not_initialized(&path.display().to_string()).into());
+    }
+
+    let mut config = read_amproject_file(&path)?;
+    if let Some(name) = &entry.name
+        && *name != config.name
+    {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            format!("Project at '{}' is not named '{}'", path.display(), name),
+            format!("Its .amproject declares the name '{}'", config.name),
+        )
+        .with_suggestion("Fix the 'name' of the entry, or omit it")
+        .into());
+    }
+
+    let mut result = json!({ "name": config.name, "registered": true });
+
+    if let Some(p) = db_get_project_by_name(&config.name, database.clone())? {
+        if normalize_path(std::path::Path::new(&p.path)) == normalize_path(&path) {
+            result["registered"] = json!(false);
+            return Ok(result);
+        }
+
+        if !rename_on_conflict {
+            return Err(project_already_exists(&config.name)
+                .with_suggestion("Use --rename-on-conflict to register it under a numbered name")
+                .with_context(p.path)
+                .into());
+        }
+
+        let new_name = unique_project_name(&config.name, &HashSet::new(), database.clone())?;
+        result["renamed_from"] = json!(rename_declared_project(
+            &path,
+            &mut config,
+            new_name,
+            output
+        )?);
+        result["name"] = json!(config.name);
+    }
+
+    register_project(&config, &path, database)?;
+    Ok(result)
+}
+
+/// Directories under `root`, `root` included, containing a `.amproject` file, sorted by
+/// path.
+///
+/// Project directories, hidden directories and symbolic links aren't searched.
+fn find_project_dirs(root: &std::path::Path) -> Result<Vec<PathBuf>> {
+    let mut found = Vec::new();
+    let mut pending = vec![root.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        if dir.join(".amproject").is_file() {
+            found.push(dir);
+            continue;
+        }
+
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let hidden = entry.file_name().to_string_lossy().starts_with('.');
+            if !hidden && entry.file_type()?.is_dir() {
+                pending.push(entry.path());
+            }
+        }
+    }
+
+    found.sort();
+    Ok(found)
+}
+
+/// Set the status of each candidate and list the names declared more than once.
+///
+/// For each name, a project already registered at its path keeps it; otherwise the first
+/// candidate declaring it keeps it, unless the name is registered for another path.
+fn find_name_conflicts(
+    candidates: &mut [RegistrationCandidate],
+    database: Option<Arc<Database>>,
+) -> Result<Vec<NameConflict>> {
+    let mut names: Vec<String> = Vec::new();
+    for candidate in candidates.iter() {
+        if !names.contains(&candidate.config.name) {
+            names.push(candidate.config.name.clone());
+        }
+    }
+
+    let mut conflicts = Vec::new();
+    for name in names {
+        let registered = db_get_project_by_name(&name, database.clone())?;
+        let group: Vec<usize> = (0..candidates.len())
+            .filter(|&i| candidates[i].config.name == name)
+            .collect();
+
+        let holder = match &registered {
+            Some(p) => group.iter().copied().find(|&i| {
+                normalize_path(std::path::Path::new(&p.path)) == normalize_path(&candidates[i].path)
+            }),
+            None => group.first().copied(),
+        };
+
+        let mut conflicting_paths = Vec::new();
+        for &i in &group {
+            if Some(i) == holder {
+                if registered.is_some() {
+                    candidates[i].status = CandidateStatus::AlreadyRegistered;
+                }
+                continue;
+            }
+
+            candidates[i].status = CandidateStatus::NameTaken;
+            conflicting_paths.push(candidates[i].path.display().to_string());
+        }
+
+        if !conflicting_paths.is_empty() {
+            if registered.is_none()
+                && let Some(first) = holder
+            {
+                conflicting_paths.insert(0, candidates[first].path.display().to_string());
+            }
+
+            conflicts.push(NameConflict {
+                name,
+                registered_path: registered.map(|p| p.path),
+                conflicting_paths,
+            });
+        }
+    }
+
+    Ok(conflicts)
+}
+
+fn name_conflict_json(conflict: &NameConflict) -> serde_json::Value {
+    json!({
+        "name": conflict.name,
+        "registered_path": conflict.registered_path,
+        "conflicting_paths": conflict.conflicting_paths,
+    })
+}
+
+/// Report name conflicts, as a `conflicts` list in JSON mode, and build the error failing
+/// the command.
+fn report_name_conflicts(conflicts: &[NameConflict], output: &dyn Output) -> anyhow::Error {
+    present_name_conflicts(conflicts, output);
+
+    CliError::new(
+        codes::ERR_PROJECT_ALREADY_EXISTS,
+        "Cannot register projects",
+        format!(
+            "{} project name(s) are declared by more than one project",
+            conflicts.len()
+        ),
+    )
+    .with_suggestion(
+        "Rename the conflicting projects in their .amproject, or use --rename-on-conflict",
+    )
+    .into()
+}
+
+/// Show name conflicts, as a `conflicts` list in JSON mode and as warnings otherwise.
+fn present_name_conflicts(conflicts: &[NameConflict], output: &dyn Output) {
+    match output.mode() {
+        OutputMode::Json => output.success(
+            json!({
+                "projects": [],
+                "conflicts": conflicts.iter().map(name_conflict_json).collect::<Vec<_>>(),
+            }),
+            None,
+        ),
+        OutputMode::Interactive => {
+            for conflict in conflicts {
+                let mut paths = conflict.conflicting_paths.clone();
+                if let Some(registered) = &conflict.registered_path {
+                    paths.insert(0, format!("{} (registered)", registered));
+                }
+                output.warning(&format!(
+                    "The name {} is declared by more than one project: {}",
+                    conflict.name.cyan(),
+                    paths.join(", ")
+                ));
+            }
+        }
+    }
+}
+
+/// First name of the form `<name>_<n>`, starting from 2, that is neither in `taken` nor
+/// registered.
+fn unique_project_name(
+    name: &str,
+    taken: &HashSet<String>,
+    database: Option<Arc<Database>>,
+) -> Result<String> {
+    for n in 2.. {
+        let candidate = format!("{}_{}", name, n);
+        if !taken.contains(&candidate)
+            && db_get_project_by_name(&candidate, database.clone())?.is_none()
+        {
+            return Ok(candidate);
+        }
+    }
+
+    unreachable!("an unused project name always exists")
+}
+
+/// Rename the project at `project_root` to `new_name` in its `.amproject`, returning the
+/// previous name.
+fn rename_declared_project(
+    project_root: &std::path::Path,
+    config: &mut ProjectConfiguration,
+    new_name: String,
+    output: &dyn Output,
+) -> Result<String> {
+    let _lock = ProjectLock::acquire(
+        project_root,
+        "am project register",
+        DEFAULT_LOCK_TIMEOUT,
+        output,
+    )?;
+
+    let previous_name = std::mem::replace(&mut config.name, new_name);
+    write_amproject_file(project_root, config)?;
+
+    output.progress(&format!(
+        "Renamed {} to {} in {}",
+        previous_name,
+        config.name,
+        project_root.join(".amproject").display()
+    ));
+
+    Ok(previous_name)
+}
+
+/// Unregister the project named `name`, or the one the user picks among the registered
+/// projects, after the user confirmed it (or `yes` was given).
+///
+/// Only the registry entry is removed: the project files are kept unless `delete` is set.
+async fn handle_unregister_project_command(
+    name: Option<String>,
+    delete: bool,
+    yes: bool,
+    database: Option<Arc<Database>>,
+    input: &dyn Input,
+    output: &dyn Output,
+) -> anyhow::Result<()> {
+    let name = match name {
+        Some(name) => name,
+        None => prompt_registered_project(database.clone(), input).ok_or_else(|| {
+            CliError::new(
+                codes::ERR_VALIDATION_FIELD,
+                "No project to unregister",
+                "No project name was given and no registered project was selected",
+            )
+            .with_suggestion("Pass the name of the project, see 'am project list'")
+        })?,
+    };
+
+    let project = db_get_project_by_name(&name, database.clone())?.ok_or_else(|| {
+        CliError::new(
+            codes::ERR_PROJECT_NOT_REGISTERED,
+            format!("Project '{}' not found", name),
+            "The project is not registered in the database",
+        )
+        .with_suggestion("Use 'am project list' to see registered projects")
+    })?;
+
+    if !yes {
+        let prompt = if delete {
+            format!(
+                "Unregister {} and delete its files at {}?",
+                project.name, project.path
+            )
+        } else {
+            format!("Unregister {} ({})?", project.name, project.path)
+        };
+
+        let confirmed = input.confirm(&prompt, Some(false)).map_err(|_| {
+            CliError::new(
+                codes::ERR_VALIDATION_FIELD,
+                "Unregistering requires confirmation",
+                "The --yes flag is required in non-interactive mode",
+            )
+            .with_suggestion("Use --yes to confirm unregistering the project")
+        })?;
+
+        if !confirmed {
+            output.progress("Unregister cancelled.");
+            return Ok(());
+        }
+    }
+
+    output.progress("Unregistering project...");
+    db_forget_project(
+        project.id.expect("registered project must have an id"),
+        database,
+    )?;
+
+    let files_deleted = delete && fs::exists(&project.path)?;
+    if files_deleted {
+        output.progress("Deleting project directory...");
+        safe_remove_in_parent(std::path::Path::new(&project.path))?;
+    }
+
+    match output.mode() {
+        OutputMode::Json => output.success(
+            json!({
+                "name": project.name,
+                "path": project.path,
+                "files_deleted": files_deleted,
+            }),
+            None,
+        ),
+        OutputMode::Interactive => output.success(
+            json!(format!(
+                "Project {} unregistered successfully",
+                project.name
+            )),
+            None,
+        ),
+    }
+
+    Ok(())
+}
+
+/// Unregister every project whose name matches `pattern`, after the user confirmed the
+/// list of matched projects (or `yes` was given).
+///
+/// All matched projects are removed from the registry in one transaction. With `delete`,
+/// their directories are then deleted one by one and failures are reported per project.
+async fn handle_unregister_matching_command(
+    pattern: &str,
+    delete: bool,
+    yes: bool,
+    fail_empty: bool,
+    database: Option<Arc<Database>>,
+    input: &dyn Input,
+    output: &dyn Output,
+) -> anyhow::Result<()> {
+    let mut projects: Vec<Project> = db_get_projects_filtered(None, database.clone())?
+        .into_iter()
+        .filter(|p| glob_match(pattern, &p.name))
+        .collect();
+    projects.sort_by(|a, b| a.name.cmp(&b.name));
+
+    if projects.is_empty() {
+        if fail_empty {
+            return Err(CliError::new(
+                codes::ERR_PROJECT_NOT_REGISTERED,
+                format!("No registered project matches '{}'", pattern),
+                "The glob doesn't match the name of any registered project",
+            )
+            .with_suggestion("Use 'am project list' to see registered projects")
+            .into());
+        }
+
+        match output.mode() {
+            OutputMode::Json => output.success(json!({ "pattern": pattern, "projects": [] }), None),
+            OutputMode::Interactive => {
+                output.progress(&format!("No registered project matches '{}'", pattern))
+            }
+        }
+        return Ok(());
+    }
+
+    if !yes {
+        output.print(&format!(
+            "\n{} You are about to unregister the following projects:",
+            "⚠".yellow()
+        ));
+        for project in &projects {
+            output.print(&format!("  {}  {}", project.name, project.path.dimmed()));
+        }
+        output.print("");
+
+        let prompt = if delete {
+            format!(
+                "Unregister these {} projects and delete their files?",
+                projects.len()
+            )
+        } else {
+            format!("Unregister these {} projects?", projects.len())
+        };
+
+        let confirmed = match input.confirm(&prompt, Some(false)) {
+            Ok(val) => val,
+            Err(_) => {
+                return Err(CliError::new(
+                    codes::ERR_VALIDATION_FIELD,
+                    "Unregistering requires confirmation",
+                    "The --yes flag is required in non-interactive mode",
+                )
+                .with_suggestion("Use --yes to confirm unregistering the matched projects")
+                .into());
+            }
+        };
+
+        if !confirmed {
+            output.progress("Unregister cancelled.");
+            return Ok(());
+        }
+    }
+
+    let ids: Vec<i32> = projects
+        .iter()
+        .map(|p| p.id.expect("registered project must have an id"))
+        .collect();
+    db_forget_projects(&ids, database)?;
+
+    let mut results = Vec::with_capacity(projects.len());
+    for project in &projects {
+        let mut result = json!({
+            "name": project.name,
+            "path": project.path,
+            "unregistered": true,
+            "files_deleted": false,
+        });
+
+        if delete && fs::exists(&project.path)? {
+            match safe_remove_in_parent(std::path::Path::new(&project.path)) {
+                Ok(()) => {
+                    result["files_deleted"] = json!(true);
+                    output.progress(&format!(
+                        "{} {} unregistered and deleted",
+                        "✓".green(),
+                        project.name
+                    ));
+                }
+                Err(e) => {
+                    result["error"] = json!(e.to_string());
+                    output.warning(&format!(
+                        "{} was unregistered but its files could not be deleted: {}",
+                        project.name, e
+                    ));
+                }
+            }
+        } else {
+            output.progress(&format!("{} {} unregistered", "✓".green(), project.name));
+        }
+
+        results.push(result);
+    }
+
+    match output.mode() {
+        OutputMode::Json => {
+            output.success(json!({ "pattern": pattern, "projects": results }), None)
+        }
+        OutputMode::Interactive => output.success(
+            json!(format!(
+                "{} projects unregistered successfully",
+                projects.len()
+            )),
+            None,
+        ),
+    }
+
+    Ok(())
+}
+
+/// Order of the projects listed by `am project list`.
+#[derive(clap::ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ProjectSort {
+    /// Alphabetically by name
+    #[default]
+    Name,
+    /// Most recently registered first
+    Recent,
+}
+
+/// Sort `projects` by `sort`, favorites first. The registry returns them by name.
+fn sort_projects(projects: &mut [Project], sort: ProjectSort) {
+    match sort {
+        ProjectSort::Name => projects.sort_by(|a, b| {
+            b.is_favorite
+                .cmp(&a.is_favorite)
+                .then_with(|| a.name.cmp(&b.name))
+        }),
+        // Registration dates are ISO 8601, so they sort as strings
+        ProjectSort::Recent => projects.sort_by(|a, b| {
+            b.is_favorite
+                .cmp(&a.is_favorite)
+                .then_with(|| b.registered_at.cmp(&a.registered_at))
+                .then_with(|| a.name.cmp(&b.name))
+        }),
+    }
+}
+
+async fn handle_list_projects_command(
+    favorite_filter: Option<bool>,
+    sort: ProjectSort,
+    limit: &ListLimit,
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> anyhow::Result<()> {
+    let mut projects = db_get_projects_filtered(favorite_filter, database)?;
+    sort_projects(&mut projects, sort);
+    let truncation = limit.apply(&mut projects);
+
+    let is_json = output.mode() == OutputMode::Json;
+    let mut columns = vec![
+        ColumnSpec::new("name", "Name"),
+        ColumnSpec::new("path", "Path")
+            .with_priority(1)
+            .with_path_truncation(),
+        ColumnSpec::new("registered_at", "Registered").with_priority(2),
+    ];
+    if is_json {
+        columns.push(ColumnSpec::new("favorite", "Favorite"));
+    }
+
+    if projects.is_empty() {
+        output.table_with_columns(Some("Registered Projects"), &columns, json!([]));
+
+        output.progress("No projects registered.");
+        output.progress("");
+        output.progress("To get started:");
+        output.progress(&format!(
+            "  {} Create a new project: {} {}",
+            "•".cyan(),
+            "am project init".green(),
+            "<name>".white()
+        ));
+        output.progress(&format!(
+            "  {} Register an existing project: {} {}",
+            "•".cyan(),
+            "am project register".green(),
+            "<path>".white()
+        ));
+    } else {
+        let now = Utc::now();
+        let display_data: Vec<serde_json::Value> = projects
+            .iter()
+            .map(|p| {
+                let display_name = if !is_json && p.is_favorite {
+                    format!("★ {}", p.name)
+                } else {
+                    p.name.clone()
+                };
+                let registered_at = match &p.registered_at {
+                    Some(date) if is_json => date.clone(),
+                    Some(date) => humanize_timestamp(date, now),
+                    None => "-".to_string(),
+                };
+                let mut row = json!({
+                    "name": display_name,
+                    "path": p.path,
+                    "registered_at": registered_at,
+                });
+                if is_json {
+                    row["favorite"] = json!(p.is_favorite);
+                }
+                row
+            })
+            .collect();
+
+        truncation.report(output);
+        output.table_with_columns(Some("Registered Projects"), &columns, json!(display_data));
+        truncation.notify("projects", output);
+    }
+
+    Ok(())
+}
+
+/// List the registered projects grouped by directory below `root`, see [`PathTree`].
+async fn handle_list_projects_tree_command(
+    favorite_filter: Option<bool>,
+    root: Option<&str>,
+    sort: ProjectSort,
+    limit: &ListLimit,
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> anyhow::Result<()> {
+    let mut projects = db_get_projects_filtered(favorite_filter, database)?;
+    sort_projects(&mut projects, sort);
+    let truncation = limit.apply(&mut projects);
+
+    let root = root.map(|root| canonical_project_path(std::path::Path::new(root)));
+    let leaves = projects
+        .into_iter()
+        .map(|p| TreeLeaf {
+            name: p.name,
+            path: p.path,
+            favorite: p.is_favorite,
+        })
+        .collect();
+    let tree = PathTree::build(leaves, root.as_deref());
+
+    match output.mode() {
+        OutputMode::Json => {
+            let mut payload = tree.to_json();
+            truncation.annotate(&mut payload);
+            output.success(payload, None);
+        }
+        OutputMode::Interactive if tree.is_empty() => {
+            output.progress("No projects registered.");
+        }
+        OutputMode::Interactive => {
+            output.print(&tree.render());
+            truncation.notify("projects", output);
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_favorite_project_command(
+    name: &str,
+    target: bool,
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> anyhow::Result<()> {
+    let project = db_get_project_by_name(name, database.clone())?.ok_or_else(|| {
+        CliError::new(
+            codes::ERR_PROJECT_NOT_REGISTERED,
+            format!("Project '{}' not found", name),
+            "The project is not registered in the database",
+        )
+        .with_suggestion("Use 'am project list' to see registered projects")
+    })?;
+
+    let id = project.id.expect("registered project must have an id");
+
+    if project.is_favorite == target {
+        let msg = if target {
+            format!("Project {} is already marked as favorite", name)
+        } else {
+            format!("Project {} is not marked as favorite", name)
+        };
+        output.success(json!(msg), None);
+        return Ok(());
+    }
+
+    db_set_project_favorite(id, target, database)?;
+
+    let msg = if target {
+        format!("Project {} marked as favorite", name)
+    } else {
+        format!("Project {} unmarked as favorite", name)
+    };
+    output.success(json!(msg), None);
+
+    Ok(())
+}
+
+/// The directory of the project at `path`, which is either the directory or its
+/// `.amproject` file (as passed by a file association).
+fn project_dir_of(path: &Path) -> PathBuf {
+    if path.file_name().is_some_and(|name| name == ".amproject") && !path.is_dir() {
+        path.parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .map_or_else(|| PathBuf::from("."), Path::to_path_buf)
+    } else {
+        path.to_path_buf()
+    }
+}
+
+/// The closest directory holding a `.amproject` file, starting from `dir` itself.
+fn find_project_root(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .find(|ancestor| ancestor.join(".amproject").is_file())
+        .map(Path::to_path_buf)
+}
+
+/// Show the details of a project: the one named `name`, or else the one in
+/// `project_dir` (by default the current directory, or the closest of its parents holding
+/// a `.amproject` file). With `path_check`, the directories
+/// of `.amproject` are checked too, creating the missing ones when it holds `true`.
+async fn handle_info_project_command(
+    name: Option<String>,
+    project_dir: Option<PathBuf>,
+    path_check: Option<bool>,
+    strict: bool,
+    database: Option<Arc<Database>>,
+    input: &dyn Input,
+    output: &dyn Output,
+) -> anyhow::Result<()> {
+    if let Some(project_name) = name {
+        return handle_info_by_name(&project_name, path_check, strict, database, output).await;
+    }
+
+    let cwd = match project_dir {
+        // An explicit directory never falls back to picking a registered project
+        Some(dir) if !dir.join(".amproject").exists() => {
+            return Err(CliError::new(
+                codes::ERR_PROJECT_NOT_INITIALIZED,
+                "No project found at the given path",
+                format!("'{}' does not contain a .amproject file", dir.display()),
+            )
+            .into());
+        }
+        Some(dir) => dir,
+        // From a subdirectory, the project is the closest ancestor with a .amproject
+        None => {
+            let cwd = env::current_dir()?;
+            find_project_root(&cwd).unwrap_or(cwd)
+        }
+    };
+    handle_info_current_dir(&cwd, path_check, strict, database, input, output).await
+}
+
+async fn handle_info_by_name(
+    name: &str,
+    path_check: Option<bool>,
+    strict: bool,
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> anyhow::Result<()> {
+    let lookup_result = db_get_project_by_name(name, database.clone())?;
+
+    match lookup_result {
+        Some(project) => {
+            let project_path = PathBuf::from(&project.path);
+            let asset_counts = count_assets_by_type(&project_path).unwrap_or_default();
+
+            // Read the .amproject file to get the actual directory configuration
+            let config = read_project_config(&project_path, strict)?;
+            let path_checks = match path_check {
+                Some(create_missing) => {
+                    check_project_paths(&project_path, &config, create_missing)?
+                }
+                None => Vec::new(),
+            };
+
+            display_project_info(
+                &project.name,
+                &project_path,
+                &config.sources_dir,
+                &config.data_dir,
+                &config.build_dir,
+                true,
+                project.registered_at.as_deref(),
+                project.is_favorite,
+                &asset_counts,
+                &path_checks,
+                output,
+            );
+
+            Ok(())
+        }
+        None => Err(CliError::new(
+            codes::ERR_PROJECT_NOT_REGISTERED,
+            format!("Project '{}' not found", name),
+            "The project is not registered in the database",
+        )
+        .with_suggestion("Use 'am project list' to see registered projects")
+        .into()),
+    }
+}
+
+async fn handle_info_paths_command(
+    name: Option<&str>,
+    project_dir: Option<PathBuf>,
+    strict: bool,
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> anyhow::Result<()> {
+    let project_root = match name {
+        Some(name) => match db_get_project_by_name(name, database)? {
+            Some(project) => PathBuf::from(&project.path),
+            None => {
+                return Err(CliError::new(
+                    codes::ERR_PROJECT_NOT_REGISTERED,
+                    format!("Project '{}' not found", name),
+                    "The project is not registered in the database",
+                )
+                .with_suggestion("Use 'am project list' to see registered projects")
+                .into());
+            }
+        },
+        None => match project_dir {
+            Some(dir) => dir,
+            None => env::current_dir()?,
+        },
+    };
+
+    if !project_root.join(".amproject").exists() {
+        return Err(project_not_initialized(&project_root.display().to_string()).into());
+    }
+
+    let config = read_project_config(&project_root, strict)?;
+    let root = PathBuf::from(normalize_path(&std::path::absolute(&project_root)?));
+
+    let paths = [
+        ("root", root.clone()),
+        (
+            "sources_dir",
+            resolve_project_dir(&root, &config.sources_dir, "sources_dir")?,
+        ),
+        (
+            "build_dir",
+            resolve_project_dir(&root, &config.build_dir, "build_dir")?,
+        ),
+        (
+            "data_dir",
+            resolve_project_dir(&root, &config.data_dir, "data_dir")?,
+        ),
+    ];
+
+    match output.mode() {
+        crate::presentation::OutputMode::Json => {
+            let mut data = json!({});
+            for (key, path) in &paths {
+                insert_os_str(&mut data, key, path.as_os_str());
+            }
+            output.success(data, None);
+        }
+        crate::presentation::OutputMode::Interactive => {
+            for (key, path) in &paths {
+                output.print(&format!("{}={}", key, path.display()));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Read the `.amproject` file of `path`, rejecting unknown fields with `--strict`.
+fn read_project_config(path: &std::path::Path, strict: bool) -> Result<ProjectConfiguration> {
+    if strict {
+        read_amproject_file_strict(path)
+    } else {
+        read_amproject_file(path)
+    }
+}
+
+/// Resolve a directory value from `.amproject` against the project root.
+///
+/// Absolute values are returned as they are, normalized. Relative values escaping the
+/// project root (e.g. `../elsewhere`) are rejected, so a typo can't make commands write to
+/// or delete a directory next to the project.
+fn resolve_project_dir(
+    project_root: &std::path::Path,
+    value: &str,
+    field: &str,
+) -> anyhow::Result<PathBuf> {
+    if std::path::Path::new(value).is_absolute() {
+        return Ok(PathBuf::from(normalize_path(std::path::Path::new(value))));
+    }
+
+    let root = normalize_path(project_root);
+    let resolved = normalize_path(&project_root.join(value));
+
+    if !std::path::Path::new(&resolved).starts_with(&root) {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            format!("Invalid '{}' in .amproject", field),
+            format!("'{}' resolves outside of the project directory", value),
+        )
+        .with_suggestion(format!(
+            "Set '{}' to a path inside the project root, or to an absolute path",
+            field
+        ))
+        .with_context(root)
+        .into());
+    }
+
+    Ok(PathBuf::from(resolved))
+}
+
+/// State of one directory of `.amproject`, as reported by `am project info --check-paths`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PathStatus {
+    Ok,
+    Created,
+    Missing,
+    NotADirectory,
+    OutsideRoot,
+}
+
+impl PathStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            PathStatus::Ok => "ok",
+            PathStatus::Created => "created",
+            PathStatus::Missing => "missing",
+            PathStatus::NotADirectory => "not_a_directory",
+            PathStatus::OutsideRoot => "outside_root",
+        }
+    }
+}
+
+/// Result of checking one directory of `.amproject`.
+#[derive(Debug, Clone)]
+struct PathCheck {
+    field: &'static str,
+    value: String,
+    path: String,
+    status: PathStatus,
+}
+
+impl PathCheck {
+    /// The warning to report for this directory, if it isn't usable as configured.
+    fn warning(&self) -> Option<String> {
+        match self.status {
+            PathStatus::Ok | PathStatus::Created => None,
+            PathStatus::Missing => Some(format!(
+                "'{}' points to {}, which does not exist",
+                self.field, self.path
+            )),
+            PathStatus::NotADirectory => Some(format!(
+                "'{}' points to {}, which is not a directory",
+                self.field, self.path
+            )),
+            PathStatus::OutsideRoot => Some(format!(
+                "'{}' is set to '{}', which resolves outside of the project directory",
+                self.field, self.value
+            )),
+        }
+    }
+}
+
+/// Check that the sources, data and build directories of `.amproject` exist, are
+/// directories and, when relative, stay inside `project_root`.
+///
+/// With `create_missing`, the missing directories inside the project root are created.
+/// Directories resolving outside of the project are never created.
+fn check_project_paths(
+    project_root: &std::path::Path,
+    config: &ProjectConfiguration,
+    create_missing: bool,
+) -> anyhow::Result<Vec<PathCheck>> {
+    let mut checks = Vec::new();
+    for (field, value) in [
+        ("sources_dir", &config.sources_dir),
+        ("data_dir", &config.data_dir),
+        ("build_dir", &config.build_dir),
+    ] {
+        let (path, status) = match resolve_project_dir(project_root, value, field) {
+            Ok(path) if path.is_dir() => (path, PathStatus::Ok),
+            Ok(path) if path.exists() => (path, PathStatus::NotADirectory),
+            Ok(path) if create_missing => {
+                fs_ctx::create_dir_all(&path)?;
+                (path, PathStatus::Created)
+            }
+            Ok(path) => (path, PathStatus::Missing),
+            Err(_) => (
+                PathBuf::from(normalize_path(&project_root.join(value))),
+                PathStatus::OutsideRoot,
+            ),
+        };
+
+        checks.push(PathCheck {
+            field,
+            value: value.clone(),
+            path: path.to_string_lossy().into_owned(),
+            status,
+        });
+    }
+
+    Ok(checks)
+}
+
+/// Let the user pick one of the registered projects, showing each project's path and
+/// registration date. Favorites are listed first, marked with a star.
+///
+/// Returns `None` when no project is registered or no selection could be made.
+fn prompt_registered_project(database: Option<Arc<Database>>, input: &dyn Input) -> Option<String> {
+    let mut projects = db_get_projects_filtered(None, database).ok()?;
+    if projects.is_empty() {
+        return None;
+    }
+    sort_projects(&mut projects, ProjectSort::Name);
+
+    let options: Vec<SelectOption> = projects
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let description = match &p.registered_at {
+                Some(date) => format!(
+                    "{} (registered {})",
+                    p.path,
+                    humanize_timestamp(date, Utc::now())
+                ),
+                None => p.path.clone(),
+            };
+            let description = if p.is_favorite {
+                format!("★ {}", description)
+            } else {
+                description
+            };
+            SelectOption::new(&p.name, i).with_description(description)
+        })
+        .collect();
+
+    match with_flag_hint(input, "the <name> argument").select_rich("Project", &options) {
+        Ok(idx) => Some(projects[idx].name.clone()),
+        Err(e) => {
+            debug!("No project selected: {}", e);
+            None
+        }
+    }
+}
+
+async fn handle_info_current_dir(
+    cwd: &std::path::Path,
+    path_check: Option<bool>,
+    strict: bool,
+    database: Option<Arc<Database>>,
+    input: &dyn Input,
+    output: &dyn Output,
+) -> anyhow::Result<()> {
+    let amproject_path = cwd.join(".amproject");
+    if !amproject_path.exists() {
+        if let Some(name) = prompt_registered_project(database.clone(), input) {
+            return handle_info_by_name(&name, path_check, strict, database, output).await;
+        }
+
+        return Err(CliError::new(
+            codes::ERR_PROJECT_NOT_INITIALIZED,
+            "No project found in current directory",
+            "The current directory does not contain a .amproject file",
+        )
+        .with_suggestion(
+            "Create a new project with 'am project init <name>' or provide a project name",
+        )
+        .into());
+    }
+
+    let mut config = read_project_config(cwd, strict)?;
+    let asset_counts = count_assets_by_type(cwd).unwrap_or_default();
+    let cwd_str = utf8_path(cwd)?;
+    let path_checks = match path_check {
+        Some(create_missing) => check_project_paths(cwd, &config, create_missing)?,
+        None => Vec::new(),
+    };
+
+    // Without the database (--no-db, or it failed to open) the registration status is
+    // unknown: only the filesystem details are reported.
+    if database.is_none() {
+        match output.mode() {
+            OutputMode::Json => {
+                let mut json_data = build_project_info_json(
+                    &config.name,
+                    cwd,
+                    &config.sources_dir,
+                    &config.data_dir,
+                    &config.build_dir,
+                    false,
+                    None,
+                    false,
+                    &asset_counts,
+                    &path_checks,
+                );
+                json_data["registered"] = serde_json::Value::Null;
+                json_data["notice"] = json!(REGISTRATION_STATUS_UNKNOWN);
+                output.success(json_data, None);
+            }
+            OutputMode::Interactive => {
+                display_project_info_interactive(
+                    &config.name,
+                    cwd,
+                    &config.sources_dir,
+                    &config.data_dir,
+                    &config.build_dir,
+                    false,
+                    None,
+                    false,
+                    &asset_counts,
+                    &path_checks,
+                    output,
+                );
+                output.progress("");
+                output.progress(REGISTRATION_STATUS_UNKNOWN);
+            }
+        }
+        return Ok(());
+    }
+
+    let registered_project = db_get_project_by_path(cwd_str, database.clone())?;
+
+    match registered_project {
+        Some(project) => match output.mode() {
+            OutputMode::Json => {
+                let mut json_data = build_project_info_json(
+                    &config.name,
+                    cwd,
+                    &config.sources_dir,
+                    &config.data_dir,
+                    &config.build_dir,
+                    true,
+                    project.registered_at.as_deref(),
+                    project.is_favorite,
+                    &asset_counts,
+                    &path_checks,
+                );
+                // Reported only: syncing the names is left to `sync-registration`.
+                if is_registration_mismatch(&config, &project, database.clone()) {
+                    push_info_warning(
+                        &mut json_data,
+                        registration_mismatch_warning(&config.name, &project.name),
+                    );
+                }
+                output.success(json_data, None);
+            }
+            OutputMode::Interactive => {
+                if is_registration_mismatch(&config, &project, database.clone()) {
+                    prompt_sync_registration(
+                        cwd,
+                        &mut config,
+                        &project,
+                        database.clone(),
+                        input,
+                        output,
+                    )?;
+                }
+
+                display_project_info_interactive(
+                    &config.name,
+                    cwd,
+                    &config.sources_dir,
+                    &config.data_dir,
+                    &config.build_dir,
+                    true,
+                    project.registered_at.as_deref(),
+                    project.is_favorite,
+                    &asset_counts,
+                    &path_checks,
+                    output,
+                );
+            }
+        },
+        None => match output.mode() {
+            crate::presentation::OutputMode::Json => {
+                display_project_info(
+                    &config.name,
+                    cwd,
+                    &config.sources_dir,
+                    &config.data_dir,
+                    &config.build_dir,
+                    false,
+                    None,
+                    false,
+                    &asset_counts,
+                    &path_checks,
+                    output,
+                );
+            }
+            crate::presentation::OutputMode::Interactive => {
+                display_project_info_interactive(
+                    &config.name,
+                    cwd,
+                    &config.sources_dir,
+                    &config.data_dir,
+                    &config.build_dir,
+                    false,
+                    None,
+                    false,
+                    &asset_counts,
+                    &path_checks,
+                    output,
+                );
+
+                output.progress("");
+                output.progress("This project is not registered in the database.");
+
+                match input.confirm("Would you like to register it now?", Some(false)) {
+                    Ok(true) => {
+                        let project = config.to_project(cwd_str);
+                        db_create_project(&project, database)?;
+                        output.success(json!("Project registered successfully!"), None);
+                    }
+                    Ok(false) | Err(_) => {
+                        output.progress(&format!(
+                            "  Run {} to register this project",
+                            "am project register".green()
+                        ));
+                    }
+                }
+            }
+        },
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn display_project_info(
+    name: &str,
+    path: &std::path::Path,
+    sources_dir: &str,
+    data_dir: &str,
+    build_dir: &str,
+    registered: bool,
+    registered_at: Option<&str>,
+    favorite: bool,
+    asset_counts: &HashMap<String, usize>,
+    path_checks: &[PathCheck],
+    output: &dyn Output,
+) {
+    match output.mode() {
+        crate::presentation::OutputMode::Json => {
+            let json_data = build_project_info_json(
+                name,
+                path,
+                sources_dir,
+                data_dir,
+                build_dir,
+                registered,
+                registered_at,
+                favorite,
+                asset_counts,
+                path_checks,
+            );
+            output.success(json_data, None);
+        }
+        crate::presentation::OutputMode::Interactive => {
+            display_project_info_interactive(
+                name,
+                path,
+                sources_dir,
+                data_dir,
+                build_dir,
+                registered,
+                registered_at,
+                favorite,
+                asset_counts,
+                path_checks,
+                output,
+            );
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn build_project_info_json(
+    name: &str,
+    path: &std::path::Path,
+    sources_dir: &str,
+    data_dir: &str,
+    build_dir: &str,
+    registered: bool,
+    registered_at: Option<&str>,
+    favorite: bool,
+    asset_counts: &HashMap<String, usize>,
+    path_checks: &[PathCheck],
+) -> serde_json::Value {
+    let path_str = path.to_string_lossy();
+
+    // Resolve relative paths against the project root and normalize
+    let sources_path = if sources_dir.is_empty() {
+        path_str.to_string()
+    } else {
+        normalize_path(&path.join(sources_dir))
+    };
+    let data_path = if data_dir.is_empty() {
+        path_str.to_string()
+    } else {
+        normalize_path(&path.join(data_dir))
+    };
+    let build_path = if build_dir.is_empty() {
+        path_str.to_string()
+    } else {
+        normalize_path(&path.join(build_dir))
+    };
+
+    let mut json_value = json!({
+        "name": name,
+        "path": path_str,
+        "registered": registered,
+        "favorite": favorite,
+        "paths": {
+            "sources": sources_path,
+            "data": data_path,
+            "build": build_path,
+        },
+        "assets": {
+            "sounds": asset_counts.get("sounds").unwrap_or(&0),
+            "collections": asset_counts.get("collections").unwrap_or(&0),
+            "events": asset_counts.get("events").unwrap_or(&0),
+            "effects": asset_counts.get("effects").unwrap_or(&0),
+            "switches": asset_counts.get("switches").unwrap_or(&0),
+            "switch_containers": asset_counts.get("switch_containers").unwrap_or(&0),
+            "soundbanks": asset_counts.get("soundbanks").unwrap_or(&0),
+            "attenuators": asset_counts.get("attenuators").unwrap_or(&0),
+            "rtpc": asset_counts.get("rtpc").unwrap_or(&0),
+            "pipelines": asset_counts.get("pipelines").unwrap_or(&0),
+        }
+    });
+
+    if registered {
+        if let Some(date) = registered_at {
+            json_value["registered_at"] = json!(date);
+        }
+    } else {
+        json_value["notice"] = json!(
+            "This project is not registered in the database. Run 'am project register' to register it."
+        );
+    }
+
+    if !path_checks.is_empty() {
+        json_value["path_checks"] = path_checks
+            .iter()
+            .map(|check| {
+                json!({
+                    "field": check.field,
+                    "value": check.value,
+                    "path": check.path,
+                    "status": check.status.as_str(),
+                })
+            })
+            .collect();
+        for warning in path_checks.iter().filter_map(PathCheck::warning) {
+            push_info_warning(&mut json_value, warning);
+        }
+    }
+
+    json_value
+}
+
+/// Append `warning` to the `warnings` array of a `project info` JSON payload.
+fn push_info_warning(json_value: &mut serde_json::Value, warning: String) {
+    match json_value["warnings"].as_array_mut() {
+        Some(warnings) => warnings.push(json!(warning)),
+        None => json_value["warnings"] = json!([warning]),
+    }
+}
+
+/// Asset types counted by `am project info`, with their labels in the interactive table.
+const PROJECT_INFO_ASSET_TYPES: &[(&str, &str)] = &[
+    ("sounds", "Sounds"),
+    ("collections", "Collections"),
+    ("events", "Events"),
+    ("effects", "Effects"),
+    ("switches", "Switches"),
+    ("switch_containers", "Switch Containers"),
+    ("soundbanks", "Soundbanks"),
+    ("attenuators", "Attenuators"),
+    ("rtpc", "RTPC"),
+    ("pipelines", "Pipelines"),
+];
+
+#[allow(clippy::too_many_arguments)]
+fn display_project_info_interactive(
+    name: &str,
+    path: &std::path::Path,
+    sources_dir: &str,
+    data_dir: &str,
+    build_dir: &str,
+    registered: bool,
+    registered_at: Option<&str>,
+    favorite: bool,
+    asset_counts: &HashMap<String, usize>,
+    path_checks: &[PathCheck],
+    output: &dyn Output,
+) {
+    let path_str = path.to_string_lossy();
+
+    // Resolve relative paths against the project root and normalize
+    let sources_path = if sources_dir.is_empty() {
+        path_str.to_string()
+    } else {
+        normalize_path(&path.join(sources_dir))
+    };
+    let data_path = if data_dir.is_empty() {
+        path_str.to_string()
+    } else {
+        normalize_path(&path.join(data_dir))
+    };
+    let build_path = if build_dir.is_empty() {
+        path_str.to_string()
+    } else {
+        normalize_path(&path.join(build_dir))
+    };
+
+    // Shorten the paths that don't fit in a narrow terminal after their label
+    let fit = |path: &str| match terminal_width() {
+        Some(width) => truncate_path(
+            path,
+            width
+                .saturating_sub(PROJECT_INFO_PATH_INDENT)
+                .max(MIN_TRUNCATED_PATH_WIDTH),
+        ),
+        None => path.to_string(),
+    };
+    let (path_str, sources_path, data_path, build_path) = (
+        fit(&path_str),
+        fit(&sources_path),
+        fit(&data_path),
+        fit(&build_path),
+    );
+
+    output.progress(&format!("Project: {}", name.cyan().bold()));
+    output.progress(&"─".repeat(PROJECT_INFO_SEPARATOR_WIDTH));
+    output.progress("");
+    output.progress("Details:");
+    output.progress(&format!("  Root Path:      {}", path_str));
+    if registered {
+        output.progress(&format!(
+            "  Registered:     {} ({})",
+            "Yes".green(),
+            registered_at.map_or_else(
+                || "-".to_string(),
+                |date| humanize_timestamp(date, Utc::now())
+            )
+        ));
+    } else {
+        output.progress(&format!("  Registered:     {}", "No".yellow()));
+    }
+    let favorite_label = if favorite {
+        "Yes".green()
+    } else {
+        "No".white()
+    };
+    output.progress(&format!("  Favorite:       {}", favorite_label));
+    output.progress("");
+    output.progress("Paths:");
+    // With --check-paths, each directory is followed by its status
+    let status = |field: &str| match path_checks.iter().find(|check| check.field == field) {
+        Some(check) => match check.status {
+            PathStatus::Ok => format!(" {}", "✓".green()),
+            PathStatus::Created => format!(" {}", "(created)".green()),
+            PathStatus::Missing => format!(" {}", "✗ missing".red()),
+            PathStatus::NotADirectory => format!(" {}", "✗ not a directory".red()),
+            PathStatus::OutsideRoot => format!(" {}", "✗ outside the project".red()),
+        },
+        None => String::new(),
+    };
+    output.progress(&format!(
+        "  Sources:        {}{}",
+        sources_path,
+        status("sources_dir")
+    ));
+    output.progress(&format!(
+        "  Data:           {}{}",
+        data_path,
+        status("data_dir")
+    ));
+    output.progress(&format!(
+        "  Build:          {}{}",
+        build_path,
+        status("build_dir")
+    ));
+    for warning in path_checks.iter().filter_map(PathCheck::warning) {
+        output.warning(&warning);
+    }
+
+    let rows: Vec<serde_json::Value> = PROJECT_INFO_ASSET_TYPES
+        .iter()
+        .filter_map(|&(key, label)| {
+            let count = asset_counts.get(key).copied().unwrap_or(0);
+            (count > 0).then(|| json!({ "type": label, "count": count }))
+        })
+        .collect();
+    if !rows.is_empty() {
+        output.progress("");
+        output.table(Some("Assets"), json!(rows));
+    }
+}
+
+/// Validate and transform a project name given on the command line.
+fn project_name_from(name: &str) -> Result<String> {
+    let project_name = transform_project_name(name);
+    validate_project_name(&project_name).map_err(|e| {
+        CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            format!("Invalid project name: '{}'", name),
+            e,
+        )
+    })?;
+
+    Ok(project_name)
+}
+
+/// Example assets added by `am project init --with-examples`, with the embedded resource
+/// holding each of them.
+///
+/// The event plays the sound, which references [`EXAMPLE_AUDIO_RESOURCE`] in the data
+/// directory, so the set passes validation as is.
+const EXAMPLE_ASSET_RESOURCES: [(AssetType, &str); 3] = [
+    (AssetType::Sound, "examples/example_sound.json"),
+    (AssetType::Collection, "examples/example_collection.json"),
+    (AssetType::Event, "examples/example_event.json"),
+];
+
+/// Silent audio file referenced by the example sound.
+const EXAMPLE_AUDIO_RESOURCE: &str = "examples/example_sound.wav";
+
+/// Write the example assets and their audio file into the project at `project_path`,
+/// honoring its `asset_dirs` overrides.
+///
+/// Files already present, e.g. provided by the template, are left untouched. Returns the
+/// number of files written.
+fn write_example_assets(
+    project_path: &std::path::Path,
+    config: &ProjectConfiguration,
+) -> Result<usize> {
+    let mut created = 0;
+    for (path, resource) in example_asset_targets(project_path, config) {
+        let Some(file) = Resource::get(resource) else {
+            debug!("Embedded resource {} not found", resource);
+            continue;
+        };
+
+        if path.exists() {
+            debug!("Keeping existing {}", path.display());
+            continue;
+        }
+
+        if let Some(dir) = path.parent() {
+            fs_ctx::create_dir_all(dir)?;
+        }
+        fs_ctx::write(&path, file.data)?;
+        created += 1;
+    }
+
+    Ok(created)
+}
+
+/// The files of the example assets and their audio file in the project at
+/// `project_path`, each with the embedded resource it is written from.
+fn example_asset_targets(
+    project_path: &std::path::Path,
+    config: &ProjectConfiguration,
+) -> Vec<(PathBuf, &'static str)> {
+    let sources_dir = project_path.join(&config.sources_dir);
+    let file_name = |resource: &str| {
+        Path::new(resource)
+            .file_name()
+            .unwrap_or_default()
+            .to_os_string()
+    };
+
+    EXAMPLE_ASSET_RESOURCES
+        .iter()
+        .map(|(asset_type, resource)| {
+            (
+                sources_dir
+                    .join(config.asset_dir(*asset_type))
+                    .join(file_name(resource)),
+                *resource,
+            )
+        })
+        .chain(std::iter::once((
+            project_path
+                .join(&config.data_dir)
+                .join(file_name(EXAMPLE_AUDIO_RESOURCE)),
+            EXAMPLE_AUDIO_RESOURCE,
+        )))
+        .collect()
+}
+
+fn register_project(
+    config: &ProjectConfiguration,
+    path: &std::path::Path,
+    database: Option<Arc<Database>>,
+) -> Result<bool> {
+    db_create_project(&config.to_project(utf8_path(path)?), database.clone())
+}
+
+// =============================================================================
+// Copy Command
+// =============================================================================
+
+/// Copy the registered project `source` to a new project named `new_name`.
+///
+/// The copy goes to `path`, or next to the source project by default. Build output,
+/// snapshots, the project lock and the files ignored by `.amignore` are left out. The
+/// name is validated and the destination checked for conflicts like `am project init`
+/// does.
+#[allow(clippy::too_many_arguments)]
+async fn handle_copy_project_command(
+    source: &str,
+    new_name: &str,
+    path: Option<&std::path::Path>,
+    register: bool,
+    resolutions: InitResolutions,
+    verify: bool,
+    walk: WalkOptions,
+    database: Option<Arc<Database>>,
+    input: &dyn Input,
+    output: &dyn Output,
+) -> anyhow::Result<()> {
+    output.begin_stages(if register {
+        COPY_STAGES
+    } else {
+        &COPY_STAGES[..2]
+    });
+    output.enter_stage(0);
+
+    let Some(project) = db_get_project_by_name(source, database.clone())? else {
+        return Err(CliError::new(
+            codes::ERR_PROJECT_NOT_REGISTERED,
+            format!("Project '{}' not found", source),
+            "The project is not registered in the database",
+        )
+        .with_suggestion("Use 'am project list' to see registered projects")
+        .into());
+    };
+
+    let project_name = project_name_from(new_name)?;
+
+    let source_path = PathBuf::from(&project.path);
+    let source_config = read_amproject_file(&source_path)?;
+
+    let destination = match path {
+        Some(path) => std::path::absolute(path)?,
+        None => source_path
+            .parent()
+            .unwrap_or(&source_path)
+            .join(&project_name),
+    };
+    utf8_path(&destination)?;
+
+    if destination.starts_with(&source_path) || source_path.starts_with(&destination) {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            "Cannot copy the project into itself",
+            "The destination directory contains or is inside the project directory",
+        )
+        .with_context(destination.display().to_string())
+        .with_suggestion("Use --path to choose a directory outside of the project")
+        .into());
+    }
+
+    // Checked before anything is changed on disk or in the registry.
+    let conflicts = detect_init_conflicts(
+        &project_name,
+        &destination,
+        None,
+        !register,
+        database.clone(),
+    )?;
+    let unresolved =
+        confirm_init_resolutions(&project_name, &conflicts, resolutions, input, output)?;
+    if !unresolved.is_empty() {
+        return Err(report_init_conflicts(&project_name, &unresolved, output));
+    }
+
+    for conflict in &conflicts {
+        match conflict {
+            InitConflict::RegisteredName { id, .. } => {
+                output.progress("Unregistering previous project...");
+                db_forget_project(*id, database.clone())?;
+            }
+            InitConflict::ExistingDirectory { path } => safe_remove_in_parent(path)?,
+            InitConflict::StaleTemplate { .. } => {}
+        }
+    }
+
+    let ignore = IgnoreRules::load(&source_path)?.with_patterns([
+        format!("/{}/", source_config.build_dir.trim_matches('/')).as_str(),
+        format!("/{}/", SNAPSHOTS_DIR).as_str(),
+        format!("/{}", LOCK_FILE).as_str(),
+    ]);
+    let walk = walk_project_files(&source_path, &ignore, walk);
+    warn_skipped(&walk.skipped, output);
+
+    output.enter_stage(1);
+    output.progress(&format!(
+        "Copying project {} to {}...",
+        project.name.cyan(),
+        destination.display()
+    ));
+
+    fs_ctx::create_dir_all(&destination)?;
+    for dir in &walk.dirs {
+        fs_ctx::create_dir_all(destination.join(dir.strip_prefix(&source_path).unwrap_or(dir)))?;
+    }
+
+    // Files are sorted by path, so the files of each top-level directory follow each other.
+    let mut files = 0;
+    let mut bytes = 0u64;
+    let mut current_dir: Option<(String, usize)> = None;
+    let verifier = verify.then(CopyVerifier::start);
+
+    for file in walk.files {
+        let relative = file.strip_prefix(&source_path).unwrap_or(&file);
+        let top_level = relative
+            .components()
+            .next()
+            .filter(|_| relative.components().count() > 1)
+            .map(|c| c.as_os_str().to_string_lossy().to_string());
+
+        if current_dir.as_ref().map(|(dir, _)| dir) != top_level.as_ref() {
+            if let Some((dir, count)) = current_dir.take() {
+                output.progress(&format!("Copied {}/ ({} file(s))", dir, count));
+            }
+            current_dir = top_level.map(|dir| (dir, 0));
+        }
+
+        let dest_path = destination.join(relative);
+        if let Some(parent) = dest_path.parent() {
+            fs_ctx::create_dir_all(parent)?;
+        }
+        bytes += match &verifier {
+            Some(verifier) => {
+                let (copied, hash) = copy_file_hashed(&file, &dest_path)
+                    .with_context(|| format!("Failed to copy {}", file.display()))?;
+                verifier.check(relative.display().to_string(), &dest_path, hash, copied);
+                copied
+            }
+            None => fs_ctx::copy(&file, &dest_path)?,
+        };
+        files += 1;
+        if let Some((_, count)) = current_dir.as_mut() {
+            *count += 1;
+        }
+    }
+    if let Some((dir, count)) = current_dir {
+        output.progress(&format!("Copied {}/ ({} file(s))", dir, count));
+    }
+    let verified = verifier
+        .map(|verifier| verifier.finish().into_result(&destination))
+        .transpose()?;
+
+    let mut config = read_amproject_file(&destination)?;
+    config.name = project_name.clone();
+    write_amproject_file(&destination, &config)?;
+
+    if register {
+        output.enter_stage(2);
+        register_project(&config, &destination, database)?;
+    }
+
+    match output.mode() {
+        OutputMode::Json => output.success(
+            json!({
+                "name": project_name,
+                "source": source_path.display().to_string(),
+                "destination": destination.display().to_string(),
+                "files_copied": files,
+                "bytes_copied": bytes,
+                "verified": verified.as_ref().map(VerifyReport::to_json),
+                "registered": register,
+            }),
+            None,
+        ),
+        OutputMode::Interactive => output.success(
+            json!(format!(
+                "Project {} copied to {} ({} file(s), {} bytes{})",
+                project_name,
+                destination.display(),
+                files,
+                bytes,
+                verified.map_or(String::new(), |report| format!(
+                    ", {} bytes verified",
+                    report.bytes
+                ))
+            )),
+            None,
+        ),
+    }
+
+    Ok(())
+}
+
+// =============================================================================
+// Size Command
+// =============================================================================
+
+/// Directory at the root of a project holding its plugins.
+const PLUGINS_DIR: &str = "plugins";
+
+/// Key of the sources files outside of an asset directory, and of the data files without
+/// an extension, with `--by-type`.
+const OTHER_SIZE_ENTRY: &str = "other";
+
+/// Disk usage of a part of a project.
+struct SizeSection {
+    name: &'static str,
+    size: u64,
+    /// Usage of the part per asset type or file extension, with `--by-type`.
+    entries: BTreeMap<String, u64>,
+}
+
+/// Whether the file of `metadata` wasn't counted yet, recording it in `seen`.
+///
+/// Files are told apart by device and inode, which std only exposes on Unix; elsewhere
+/// every path is counted.
+#[cfg(unix)]
+fn count_file_once(metadata: &fs::Metadata, seen: &mut HashSet<(u64, u64)>) -> bool {
+    use std::os::unix::fs::MetadataExt;
+    seen.insert((metadata.dev(), metadata.ino()))
+}
+
+#[cfg(not(unix))]
+fn count_file_once(_metadata: &fs::Metadata, _seen: &mut HashSet<(u64, u64)>) -> bool {
+    true
+}
+
+/// Report the disk usage of the sources, data, build, plugins and snapshots of the project
+/// in the current directory.
+///
+/// Sizes are file lengths. A file reached through several hard links or symlinks is only
+/// counted once, in the first part of the project it is found in. Entries smaller than
+/// `threshold` are left out, but still count in the total.
+fn handle_size_project_command(
+    project: Option<&Path>,
+    walk: WalkOptions,
+    by_type: bool,
+    human: bool,
+    threshold: u64,
+    output: &dyn Output,
+) -> Result<()> {
+    let root = project_dir(project)?;
+    let config = read_amproject_file(&root)?;
+
+    let parts = [
+        (
+            "sources",
+            resolve_project_dir(&root, &config.sources_dir, "sources_dir")?,
+        ),
+        (
+            "data",
+            resolve_project_dir(&root, &config.data_dir, "data_dir")?,
+        ),
+        (
+            "build",
+            resolve_project_dir(&root, &config.build_dir, "build_dir")?,
+        ),
+        ("plugins", root.join(PLUGINS_DIR)),
+        ("snapshots", root.join(SNAPSHOTS_DIR)),
+    ];
+
+    // Directories of the sources, as named in this project, to the asset type they hold.
+    let asset_dirs: HashMap<&str, &str> = ASSET_DIRECTORIES
+        .iter()
+        .map(|&name| match AssetType::from_directory_name(name) {
+            Some(asset_type) => (config.asset_dir(asset_type), name),
+            None => (name, name),
+        })
+        .collect();
+
+    let mut seen = HashSet::new();
+    let mut sections = Vec::new();
+    for (name, dir) in parts {
+        let mut section = SizeSection {
+            name,
+            size: 0,
+            entries: BTreeMap::new(),
+        };
+
+        let files = walk_project_files(&dir, &IgnoreRules::none(), walk);
+        warn_skipped(&files.skipped, output);
+        for path in files.files {
+            let Ok(metadata) = fs::metadata(&path) else {
+                continue;
+            };
+            if !count_file_once(&metadata, &mut seen) {
+                continue;
+            }
+            section.size += metadata.len();
+
+            let entry = match name {
+                "sources" if by_type => path
+                    .strip_prefix(&dir)
+                    .ok()
+                    .filter(|relative| relative.components().count() > 1)
+                    .and_then(|relative| relative.components().next())
+                    .and_then(|first| asset_dirs.get(first.as_os_str().to_str()?))
+                    .unwrap_or(&OTHER_SIZE_ENTRY)
+                    .to_string(),
+                "data" if by_type => path
+                    .extension()
+                    .map(|extension| extension.to_string_lossy().to_lowercase())
+                    .unwrap_or_else(|| OTHER_SIZE_ENTRY.to_string()),
+                _ => continue,
+            };
+            *section.entries.entry(entry).or_default() += metadata.len();
+        }
+
+        sections.push(section);
+    }
+
+    let total: u64 = sections.iter().map(|section| section.size).sum();
+    sections.retain(|section| section.size >= threshold);
+    for section in &mut sections {
+        section.entries.retain(|_, size| *size >= threshold);
+    }
+
+    match output.mode() {
+        OutputMode::Json => {
+            let mut data = serde_json::Map::new();
+            for section in &sections {
+                let mut value = json!({ "size": section.size });
+                if by_type && !section.entries.is_empty() {
+                    value["entries"] = json!(section.entries);
+                }
+                data.insert(section.name.to_string(), value);
+            }
+            let mut value = json!({
+                "total": total,
+                "sections": data,
+            });
+            insert_os_str(&mut value, "path", root.as_os_str());
+            output.success(value, None);
+        }
+        OutputMode::Interactive => {
+            let size_value = |size: u64| {
+                if human {
+                    json!(format_size(size))
+                } else {
+                    json!(size)
+                }
+            };
+
+            sections.sort_by(|a, b| b.size.cmp(&a.size));
+            let mut rows = Vec::new();
+            for section in &sections {
+                rows.push(json!({ "entry": section.name, "size": size_value(section.size) }));
+
+                let mut entries: Vec<(&String, &u64)> = section.entries.iter().collect();
+                entries.sort_by(|a, b| b.1.cmp(a.1));
+                for (entry, size) in entries {
+                    let label = match section.name {
+                        "data" if entry != OTHER_SIZE_ENTRY => format!("data/*.{}", entry),
+                        _ => format!("{}/{}", section.name, entry),
+                    };
+                    rows.push(json!({ "entry": label, "size": size_value(*size) }));
+                }
+            }
+
+            output.table(Some("Project Size"), json!(rows));
+            output.success(
+                json!(format!(
+                    "Project {} uses {}",
+                    config.name,
+                    if human {
+                        format_size(total)
+                    } else {
+                        format!("{} bytes", total)
+                    }
+                )),
+                None,
+            );
+        }
+    }
+
+    Ok(())
+}
+
+// =============================================================================
+// Sync Registration Command
+// =============================================================================
+
+/// Which name a project keeps when its `.amproject` and its registration disagree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RegistrationSource {
+    /// Keep the name from `.amproject` and rename the registration.
+    File,
+    /// Keep the registered name and rename the project in `.amproject`.
+    Database,
+}
+
+/// Whether `project` is registered under another name than the one in its `.amproject`,
+/// without being an alias registered with `am project register --name`.
+fn is_registration_mismatch(
+    config: &ProjectConfiguration,
+    project: &Project,
+    database: Option<Arc<Database>>,
+) -> bool {
+    if project.name == config.name {
+        return false;
+    }
+
+    let alias_of = db_get_project_alias_of_file_name(&project.name, database).unwrap_or_else(|e| {
+        debug!(
+            "Failed to read the alias of project {}: {}",
+            project.name, e
+        );
+        None
+    });
+    alias_of.as_deref() != Some(config.name.as_str())
+}
+
+fn registration_mismatch_warning(config_name: &str, registered_name: &str) -> String {
+    format!(
+        "This project is named '{}' in .amproject but registered as '{}'",
+        config_name, registered_name
+    )
+}
+
+async fn handle_sync_registration_command(
+    project: Option<&Path>,
+    prefer: RegistrationSource,
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> anyhow::Result<()> {
+    let cwd = project_dir(project)?;
+    let cwd_str = utf8_path(&cwd)?;
+
+    if !cwd.join(".amproject").exists() {
+        return Err(project_not_initialized(cwd_str).into());
+    }
+
+    let mut config = read_amproject_file(&cwd)?;
+    let project = db_get_project_by_path(cwd_str, database.clone())?.ok_or_else(|| {
+        project_not_registered(&config.name)
+            .with_suggestion("Register the project with 'am project register'")
+    })?;
+
+    if project.name == config.name {
+        output.success(
+            json!(format!(
+                "Project {} has the same name in .amproject and in the registry",
+                config.name
+            )),
+            None,
+        );
+        return Ok(());
+    }
+
+    let message = sync_registration(
+        &cwd,
+        &mut config,
+        &project,
+        prefer,
+        "am project sync-registration",
+        database,
+        output,
+    )?;
+    output.success(json!(message), None);
+
+    Ok(())
+}
+
+/// Warn that the project at `project_root` is registered under another name than the one
+/// in its `.amproject`, and offer to keep either name.
+fn prompt_sync_registration(
+    project_root: &std::path::Path,
+    config: &mut ProjectConfiguration,
+    project: &Project,
+    database: Option<Arc<Database>>,
+    input: &dyn Input,
+    output: &dyn Output,
+) -> anyhow::Result<()> {
+    output.warning(&registration_mismatch_warning(&config.name, &project.name));
+
+    let options = [
+        SelectOption::new(format!("Keep '{}'", config.name), 0)
+            .with_description("rename the registration to match .amproject"),
+        SelectOption::new(format!("Keep '{}'", project.name), 1)
+            .with_description("rename the project in .amproject to match the registration"),
+        SelectOption::new("Leave both unchanged", 2),
+    ];
+
+    let prefer = match input.select_rich("Which name should the project keep?", &options) {
+        Ok(0) => RegistrationSource::File,
+        Ok(1) => RegistrationSource::Database,
+        _ => {
+            output.progress(&format!(
+                "  Run {} or {} to fix it later",
+                "am project sync-registration --prefer-file".green(),
+                "--prefer-db".green()
+            ));
+            return Ok(());
+        }
+    };
+
+    let message = sync_registration(
+        project_root,
+        config,
+        project,
+        prefer,
+        "am project info",
+        database,
+        output,
+    )?;
+    output.success(json!(message), None);
+
+    Ok(())
+}
+
+/// Give the project at `project_root` the same name in `.amproject` and in the registry,
+/// keeping the name from `prefer`.
+///
+/// `command` identifies the invocation in the project lock taken while `.amproject` is
+/// rewritten. Returns a message describing the change.
+fn sync_registration(
+    project_root: &std::path::Path,
+    config: &mut ProjectConfiguration,
+    project: &Project,
+    prefer: RegistrationSource,
+    command: &str,
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> anyhow::Result<String> {
+    match prefer {
+        RegistrationSource::File => {
+            let id = project.id.expect("registered project must have an id");
+            db_rename_project(id, &config.name, database)?;
+
+            Ok(format!(
+                "Registration of project {} renamed from {}",
+                config.name, project.name
+            ))
+        }
+        RegistrationSource::Database => {
+            validate_project_name(&project.name).map_err(|e| {
+                CliError::new(
+                    codes::ERR_VALIDATION_FIELD,
+                    format!("Cannot rename the project to '{}'", project.name),
+                    e,
+                )
+                .with_suggestion("Use --prefer-file to rename the registration instead")
+            })?;
+
+            let _lock = ProjectLock::acquire(project_root, command, DEFAULT_LOCK_TIMEOUT, output)?;
+
+            let previous_name = std::mem::replace(&mut config.name, project.name.clone());
+            write_amproject_file(project_root, config)?;
+
+            Ok(format!(
+                "Project {} renamed to {} in .amproject",
+                previous_name, config.name
+            ))
+        }
+    }
+}
+
+// =============================================================================
+// Verify Registration Command
+// =============================================================================
+
+/// Outcome of the check of a registered project by `am project verify-registration`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RegistrationStatus {
+    /// No issue was found.
+    Ok,
+    /// Issues were found and left as they are.
+    Failed,
+    /// The project directory is missing, and the project was unregistered.
+    Pruned,
+    /// The registration was renamed after the name in `.amproject`.
+    Synced,
+}
+
+impl RegistrationStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Failed => "failed",
+            Self::Pruned => "pruned",
+            Self::Synced => "synced",
+        }
+    }
+}
+
+/// Run the cheap checks of a registered project: its directory exists, its `.amproject`
+/// parses with a supported version, and it has the registered name.
+///
+/// Returns the issues found, and the configuration when it could be read.
+fn check_registration(
+    project: &Project,
+    database: Option<Arc<Database>>,
+) -> (Option<ProjectConfiguration>, Vec<(IssueCategory, String)>) {
+    let path = std::path::Path::new(&project.path);
+    if !path.is_dir() {
+        return (
+            None,
+            vec![(
+                IssueCategory::MissingDirectory,
+                format!("Directory {} does not exist", project.path),
+            )],
+        );
+    }
+
+    let config = match read_amproject_file(path) {
+        Ok(config) => config,
+        Err(e) => {
+            return (
+                None,
+                vec![(IssueCategory::InvalidConfig, format!("{:#}", e))],
+            );
+        }
+    };
+
+    let mut issues = Vec::new();
+    if config.version > AMPROJECT_VERSION {
+        issues.push((
+            IssueCategory::UnsupportedVersion,
+            format!(
+                ".amproject version {} is newer than the supported version {}",
+                config.version, AMPROJECT_VERSION
+            ),
+        ));
+    }
+    if is_registration_mismatch(&config, project, database) {
+        issues.push((
+            IssueCategory::NameMismatch,
+            registration_mismatch_warning(&config.name, &project.name),
+        ));
+    }
+
+    (Some(config), issues)
+}
+
+/// Check every registered project, see [`check_registration`], and fail when any
+/// project is left with issues.
+///
+/// With `prune_missing`, projects whose directory is missing are unregistered. With
+/// `sync_names`, projects whose only issue is a name mismatch get their registration
+/// renamed after their `.amproject`, as by `am project sync-registration --prefer-file`.
+async fn handle_verify_registration_command(
+    prune_missing: bool,
+    sync_names: bool,
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> anyhow::Result<()> {
+    let projects = db_get_projects_filtered(None, database.clone())?;
+    let is_json = output.mode() == OutputMode::Json;
+
+    let mut rows = Vec::with_capacity(projects.len());
+    let mut failed = 0;
+    for project in &projects {
+        let (config, mut issues) = check_registration(project, database.clone());
+        let only = |category: IssueCategory| issues.iter().all(|(c, _)| *c == category);
+
+        let mut status = if issues.is_empty() {
+            RegistrationStatus::Ok
+        } else {
+            RegistrationStatus::Failed
+        };
+        if status == RegistrationStatus::Failed
+            && prune_missing
+            && only(IssueCategory::MissingDirectory)
+        {
+            db_forget_project(project.id.unwrap(), database.clone())?;
+            status = RegistrationStatus::Pruned;
+        } else if status == RegistrationStatus::Failed
+            && sync_names
+            && only(IssueCategory::NameMismatch)
+            && let Some(mut config) = config
+        {
+            match sync_registration(
+                std::path::Path::new(&project.path),
+                &mut config,
+                project,
+                RegistrationSource::File,
+                "am project verify-registration",
+                database.clone(),
+                output,
+            ) {
+                Ok(_) => status = RegistrationStatus::Synced,
+                Err(e) => issues.push((
+                    IssueCategory::NameMismatch,
+                    format!("Failed to rename the registration: {:#}", e),
+                )),
+            }
+        }
+
+        if status == RegistrationStatus::Failed {
+            failed += 1;
+        }
+
+        let issues_value = if is_json {
+            json!(
+                issues
+                    .iter()
+                    .map(|(category, message)| json!({
+                        "category": category.as_str(),
+                        "message": message,
+                    }))
+                    .collect::<Vec<_>>()
+            )
+        } else {
+            json!(
+                issues
+                    .iter()
+                    .map(|(_, message)| message.as_str())
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            )
+        };
+        rows.push(json!({
+            "name": project.name,
+            "path": project.path,
+            "status": status.as_str(),
+            "issues": issues_value,
+        }));
+    }
+
+    let columns = [
+        ColumnSpec::new("name", "Name"),
+        ColumnSpec::new("path", "Path")
+            .with_priority(1)
+            .with_path_truncation(),
+        ColumnSpec::new("status", "Status"),
+        ColumnSpec::new("issues", "Issues").with_priority(2),
+    ];
+    output.table_with_columns(Some("Registered Projects"), &columns, json!(rows));
+
+    if failed > 0 {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_SCHEMA,
+            format!(
+                "{} of {} registered project(s) failed verification",
+                failed,
+                projects.len()
+            ),
+            "Fix the reported issues, or run again with --prune-missing or --sync-names",
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+// =============================================================================
+// Lint Names Command
+// =============================================================================
+
+/// An asset file whose name breaks the underscore_lowercase naming convention.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct NameViolation {
+    key: AssetKey,
+    /// Path of the file, relative to the sources directory.
+    path: String,
+    /// Stem of the file name.
+    current: String,
+    /// The stem following the convention.
+    proposed: String,
+}
+
+impl NameViolation {
+    fn to_json(&self) -> serde_json::Value {
+        json!({
+            "type": self.key.type_key(),
+            "name": self.key.name,
+            "path": self.path,
+            "current": self.current,
+            "proposed": self.proposed,
+        })
+    }
+}
+
+/// The assets of `graph`, of type `type_filter` if given, whose file stem changes when
+/// [`transform_name`] is applied to it. Soundbanks come last, the order to rename them
+/// in, see [`rename_asset_file`].
+fn find_name_violations(
+    graph: &DependencyGraph,
+    type_filter: Option<AssetType>,
+) -> Vec<NameViolation> {
+    let mut violations: Vec<NameViolation> = graph
+        .nodes()
+        .filter(|node| type_filter.is_none_or(|t| node.asset_type == t))
+        .filter_map(|node| {
+            let current = std::path::Path::new(&node.path)
+                .file_stem()?
+                .to_string_lossy()
+                .into_owned();
+            let proposed = transform_name(&current);
+            (!proposed.is_empty() && proposed != current).then(|| NameViolation {
+                key: node.key(),
+                path: node.path.clone(),
+                current,
+                proposed,
+            })
+        })
+        .collect();
+    violations.sort_by_key(|v| v.key.asset_type == AssetType::Soundbank);
+    violations
+}
+
+async fn handle_lint_names_command(
+    project: Option<&Path>,
+    walk: WalkOptions,
+    asset_type: Option<&str>,
+    fix: bool,
+    skip_confirmation: bool,
+    input: &dyn Input,
+    output: &dyn Output,
+) -> Result<()> {
+    let type_filter = asset_type.map(parse_asset_type).transpose()?;
+    let current_dir = project_dir(project)?;
+    let graph = DependencyGraph::build(&current_dir, walk, output)?;
+    let violations = find_name_violations(&graph, type_filter);
+
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    for violation in &violations {
+        *counts.entry(violation.key.type_key()).or_default() += 1;
+    }
+    let summary = counts
+        .iter()
+        .map(|(asset_type, count)| format!("{}: {}", asset_type, count))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    if violations.is_empty() {
+        if output.mode() == OutputMode::Json {
+            output.success(
+                json!({ "violations": [], "counts": counts, "fixed": false }),
+                None,
+            );
+        } else {
+            output.success(
+                json!("Every asset file follows the naming convention"),
+                None,
+            );
+        }
+        return Ok(());
+    }
+
+    if output.mode() == OutputMode::Interactive {
+        let columns = [
+            ColumnSpec::new("type", "Type"),
+            ColumnSpec::new("path", "Path"),
+            ColumnSpec::new("proposed", "Proposed name"),
+        ];
+        let rows: Vec<serde_json::Value> = violations.iter().map(NameViolation::to_json).collect();
+        output.table_with_columns(Some("Naming violations"), &columns, json!(rows));
     }
-    let favorite_label = if favorite {
-        "Yes".green()
-    } else {
-        "No".white()
-    };
-    output.progress(&format!("  Favorite:       {}", favorite_label));
-    output.progress("");
-    output.progress("Paths:");
-    output.progress(&format!("  Sources:        {}", sources_path));
-    output.progress(&format!("  Data:           {}", data_path));
-    output.progress(&format!("  Build:          {}", build_path));
 
-    let has_assets = asset_counts.values().any(|&v| v > 0);
-    if has_assets {
-        output.progress("");
-        output.progress("Assets:");
-        if let Some(&count) = asset_counts.get("sounds") {
-            if count > 0 {
-                output.progress(&format!("  Sounds:         {}", count));
-            }
-        }
-        if let Some(&count) = asset_counts.get("collections") {
-            if count > 0 {
-                output.progress(&format!("  Collections:    {}", count));
-            }
-        }
-        if let Some(&count) = asset_counts.get("events") {
-            if count > 0 {
-                output.progress(&format!("  Events:         {}", count));
-            }
-        }
-        if let Some(&count) = asset_counts.get("effects") {
-            if count > 0 {
-                output.progress(&format!("  Effects:        {}", count));
-            }
-        }
-        if let Some(&count) = asset_counts.get("switches") {
-            if count > 0 {
-                output.progress(&format!("  Switches:       {}", count));
-            }
-        }
-        if let Some(&count) = asset_counts.get("switch_containers") {
-            if count > 0 {
-                output.progress(&format!("  Switch Cont.:   {}", count));
-            }
-        }
-        if let Some(&count) = asset_counts.get("soundbanks") {
-            if count > 0 {
-                output.progress(&format!("  Soundbanks:     {}", count));
-            }
-        }
-        if let Some(&count) = asset_counts.get("attenuators") {
-            if count > 0 {
-                output.progress(&format!("  Attenuators:    {}", count));
-            }
-        }
-        if let Some(&count) = asset_counts.get("rtpc") {
-            if count > 0 {
-                output.progress(&format!("  RTPC:           {}", count));
-            }
-        }
-        if let Some(&count) = asset_counts.get("pipelines") {
-            if count > 0 {
-                output.progress(&format!("  Pipelines:      {}", count));
-            }
+    if !fix {
+        if output.mode() == OutputMode::Json {
+            let rows: Vec<serde_json::Value> =
+                violations.iter().map(NameViolation::to_json).collect();
+            output.success(
+                json!({ "violations": rows, "counts": counts, "fixed": false }),
+                None,
+            );
+        } else {
+            output.success(
+                json!(format!(
+                    "{} asset file(s) break the naming convention ({}). Run with --fix to rename them",
+                    violations.len(),
+                    summary
+                )),
+                None,
+            );
         }
+        return Ok(());
     }
-}
 
-fn validate_name(name: &str) -> Result<Validation, CustomUserError> {
-    match validate_project_name(name) {
-        Ok(()) => Ok(Validation::Valid),
-        Err(msg) => Ok(Validation::Invalid(msg.into())),
+    if !skip_confirmation
+        && !with_flag_hint(input, "--yes").confirm(
+            &format!(
+                "Rename {} asset file(s), updating the soundbanks listing them?",
+                violations.len()
+            ),
+            None,
+        )?
+    {
+        output.success(json!("Rename cancelled."), None);
+        return Ok(());
     }
-}
 
-fn transform_name(name: &str) -> String {
-    name.to_lowercase().replace([' ', '-'], "_")
-}
+    let mut rows = Vec::with_capacity(violations.len());
+    for violation in &violations {
+        let renamed = rename_asset_file(&current_dir, &graph, &violation.key, &violation.proposed)?;
+        output.progress(&format!(
+            "Renamed {} to {} ({} reference(s) updated)",
+            renamed.from,
+            renamed.to,
+            renamed.updated_references.len()
+        ));
 
-fn register_project(
-    config: &ProjectConfiguration,
-    path: &std::path::Path,
-    database: Option<Arc<Database>>,
-) -> Result<bool> {
-    db_create_project(&config.to_project(path.to_str().unwrap()), database.clone())
+        let mut row = violation.to_json();
+        row["updated_references"] = json!(renamed.updated_references);
+        rows.push(row);
+    }
+
+    if output.mode() == OutputMode::Json {
+        output.success(
+            json!({ "violations": rows, "counts": counts, "fixed": true }),
+            None,
+        );
+    } else {
+        output.success(
+            json!(format!(
+                "Renamed {} asset file(s) ({})",
+                violations.len(),
+                summary
+            )),
+            None,
+        );
+    }
+
+    Ok(())
 }
 
 // =============================================================================
@@ -1128,36 +4748,111 @@ fn resolve_type_filter(
     if types.is_empty() { None } else { Some(types) }
 }
 
+/// Kind of problem reported by project validation and registration checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IssueCategory {
+    /// The asset file can't be read, parsed, or breaks a rule of its type.
+    InvalidAsset,
+    /// The asset references an asset the project doesn't have.
+    DanglingReference,
+    /// No other asset references the asset (informational).
+    Orphan,
+    /// The directory of a registered project doesn't exist.
+    MissingDirectory,
+    /// The `.amproject` of a registered project can't be read or parsed.
+    InvalidConfig,
+    /// A project is registered under another name than the one in its `.amproject`.
+    NameMismatch,
+    /// The `.amproject` was written in a format newer than this CLI supports.
+    UnsupportedVersion,
+}
+
+impl IssueCategory {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::InvalidAsset => "invalid_asset",
+            Self::DanglingReference => "dangling_reference",
+            Self::Orphan => "orphan",
+            Self::MissingDirectory => "missing_directory",
+            Self::InvalidConfig => "invalid_config",
+            Self::NameMismatch => "name_mismatch",
+            Self::UnsupportedVersion => "unsupported_version",
+        }
+    }
+}
+
 /// A single validation error with file context.
 #[derive(Debug)]
 struct ValidationResult {
     file: String,
     asset_type: AssetType,
+    category: IssueCategory,
     error: String,
     why: String,
     suggestion: String,
     field: Option<String>,
 }
 
+/// Asset types referenced from game code rather than by other assets, which are never
+/// reported as orphans.
+const ROOT_ASSET_TYPES: &[AssetType] = &[AssetType::Event, AssetType::Soundbank];
+
 /// Validate all assets in the current project.
+///
+/// Besides the rules of each asset type, reports references to missing assets as errors
+/// and, when `orphan_check` is set, assets no other asset references as information.
+///
+/// With `summary`, the full report is still computed but only a one-line summary is
+/// presented, see [`present_validation_summary`]. The exit code is the same.
+#[allow(clippy::too_many_arguments)]
 async fn handle_validate_project_command(
+    project: Option<&Path>,
+    walk: WalkOptions,
     type_filter: Option<Vec<AssetType>>,
+    orphan_check: bool,
+    summary: bool,
+    limit: &ListLimit,
+    database: Option<Arc<Database>>,
     output: &dyn Output,
 ) -> Result<()> {
-    let current_dir = env::current_dir()?;
-    let project_config = read_amproject_file(&current_dir)?;
+    let started = Instant::now();
+    let full_output = output;
+    let summary_output = SummaryOutput::new(full_output);
+    let output: &dyn Output = if summary {
+        &summary_output
+    } else {
+        full_output
+    };
+
+    let current_dir = project_dir(project)?;
+    let project_config = read_amproject_file_strict(&current_dir)?;
 
     output.progress(&format!("Validating project '{}'...", project_config.name));
 
-    // Try to discover SDK for schema validation
-    let sdk_available = match discover_sdk() {
-        Ok(sdk) => match load_schemas(&sdk, output) {
+    // Load the schemas for schema validation, from the local cache or the SDK
+    let sdk_available = match resolve_schemas(database) {
+        Some(selection) => match load_schemas_from(&selection.schemas_dir, output) {
             Ok(registry) => {
-                output.progress(&format!(
-                    "SDK found: loaded {} schema(s) from {}",
-                    registry.schema_count(),
-                    sdk.schemas_dir().display()
-                ));
+                match &selection.cached_version {
+                    Some(version) => output.progress(&format!(
+                        "Loaded {} schema(s) cached for SDK {}",
+                        registry.schema_count(),
+                        version
+                    )),
+                    None => output.progress(&format!(
+                        "SDK found: loaded {} schema(s) from {}",
+                        registry.schema_count(),
+                        selection.schemas_dir.display()
+                    )),
+                }
+                if let (Some(cached), Some(newer)) =
+                    (&selection.cached_version, &selection.newer_sdk_version)
+                {
+                    output.warning(&format!(
+                        "The cached schemas are for SDK {} but the SDK is now {}: run 'am sdk schemas sync' to update them",
+                        cached, newer
+                    ));
+                }
                 for (path, err) in registry.failed_files() {
                     output.progress(&format!(
                         "{} Failed to load schema {}: {}",
@@ -1177,7 +4872,7 @@ async fn handle_validate_project_command(
                 false
             }
         },
-        Err(_) => {
+        None => {
             output.progress(&format!(
                 "{} SDK not found. Schema validation will be skipped.",
                 "⚠".yellow()
@@ -1188,7 +4883,7 @@ async fn handle_validate_project_command(
     };
 
     // Build project context with validator for cross-reference checking
-    let validator = ProjectValidator::new(current_dir.clone(), output)?;
+    let validator = ProjectValidator::new(current_dir.clone(), walk, output)?;
     let context = ProjectContext::new(current_dir.clone()).with_validator(validator);
 
     // Determine which types to validate
@@ -1209,13 +4904,69 @@ async fn handle_validate_project_command(
     } else {
         current_dir.join(&project_config.sources_dir)
     };
+    let ignore = IgnoreRules::load(&current_dir)?;
     let mut errors: Vec<ValidationResult> = Vec::new();
     let mut warnings: Vec<String> = Vec::new();
     let mut asset_summary: HashMap<String, usize> = HashMap::new();
     let mut total_validated: usize = 0;
 
+    let accepts_json_comments = project_config.allow_json_comments && JSON_COMMENTS_SUPPORTED;
+    if project_config.allow_json_comments && !JSON_COMMENTS_SUPPORTED {
+        warnings.push(
+            "The project sets 'allow_json_comments', but this build of am doesn't include the \
+             json-comments feature: asset files are parsed as strict JSON"
+                .to_string(),
+        );
+    }
+    warnings.extend(
+        check_project_paths(&current_dir, &project_config, false)?
+            .iter()
+            .filter_map(PathCheck::warning),
+    );
+    warnings.extend(
+        EnvFile::load(&current_dir)?
+            .issues
+            .iter()
+            .map(ToString::to_string),
+    );
+
     for asset_type in &types_to_validate {
-        let dir = sources_dir.join(asset_type.directory_name());
+        if let Err(e) = check_asset_dir(&project_config, *asset_type) {
+            errors.push(ValidationResult {
+                file: ".amproject".to_string(),
+                asset_type: *asset_type,
+                category: IssueCategory::InvalidAsset,
+                error: e.what,
+                why: e.why,
+                suggestion: e.suggestion,
+                field: Some("asset_dirs".to_string()),
+            });
+            continue;
+        }
+
+        let asset_dir = project_config.asset_dir(*asset_type);
+        if asset_dir != asset_type.directory_name() {
+            let stray = walk_project_files(
+                &sources_dir.join(asset_type.directory_name()),
+                &ignore,
+                walk,
+            )
+            .files
+            .into_iter()
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .count();
+            if stray > 0 {
+                warnings.push(format!(
+                    "sources/{} contains {} asset file(s) that are ignored: {} assets are read from sources/{} (see 'asset_dirs' in .amproject)",
+                    asset_type.directory_name(),
+                    stray,
+                    asset_type,
+                    asset_dir
+                ));
+            }
+        }
+
+        let dir = sources_dir.join(asset_dir);
         if !dir.exists() {
             continue;
         }
@@ -1223,11 +4974,7 @@ async fn handle_validate_project_command(
         let entries = match fs::read_dir(&dir) {
             Ok(e) => e,
             Err(e) => {
-                warnings.push(format!(
-                    "Cannot read {} directory: {}",
-                    asset_type.directory_name(),
-                    e
-                ));
+                warnings.push(format!("Cannot read {} directory: {}", asset_dir, e));
                 continue;
             }
         };
@@ -1241,7 +4988,9 @@ async fn handle_validate_project_command(
             };
 
             let path = entry.path();
-            if path.extension().is_none_or(|ext| ext != "json") {
+            if path.extension().is_none_or(|ext| ext != "json")
+                || ignore.is_ignored(&path, path.is_dir())
+            {
                 continue;
             }
 
@@ -1251,7 +5000,7 @@ async fn handle_validate_project_command(
                 .to_string_lossy()
                 .to_string();
 
-            let relative_path = format!("sources/{}/{}", asset_type.directory_name(), filename);
+            let relative_path = format!("sources/{}/{}", asset_dir, filename);
 
             output.progress(&format!("  Validating {}...", relative_path));
 
@@ -1262,6 +5011,7 @@ async fn handle_validate_project_command(
                     errors.push(ValidationResult {
                         file: relative_path,
                         asset_type: *asset_type,
+                        category: IssueCategory::InvalidAsset,
                         error: format!("Failed to read file: {}", e),
                         why: "The file could not be read".to_string(),
                         suggestion: "Check file permissions".to_string(),
@@ -1273,6 +5023,13 @@ async fn handle_validate_project_command(
 
             type_count += 1;
 
+            if !accepts_json_comments && has_json_comments(&content) {
+                warnings.push(format!(
+                    "{} contains comments or trailing commas, which strict JSON doesn't allow",
+                    relative_path
+                ));
+            }
+
             // Validate based on asset type
             let validation_errors =
                 validate_asset_file(*asset_type, &content, &relative_path, &context);
@@ -1283,36 +5040,71 @@ async fn handle_validate_project_command(
         total_validated += type_count;
     }
 
+    // Check references between assets
+    let graph = DependencyGraph::build(&current_dir, walk, output)?;
+    check_dangling_references(&graph, &types_to_validate, &mut errors);
+    let orphans = if orphan_check {
+        find_orphans(&graph, &types_to_validate)
+    } else {
+        Vec::new()
+    };
+
+    let count_category = |category: IssueCategory| {
+        errors
+            .iter()
+            .chain(&orphans)
+            .filter(|e| e.category == category)
+            .count()
+    };
+    let issue_counts = json!({
+        "invalid_asset": count_category(IssueCategory::InvalidAsset),
+        "dangling_reference": count_category(IssueCategory::DanglingReference),
+        "orphan": count_category(IssueCategory::Orphan),
+    });
+
     // Output results
     let is_valid = errors.is_empty();
 
+    // Only the first issues are listed, errors before orphans
+    let max_issues = limit.max_items().unwrap_or(usize::MAX);
+    let listed_errors = &errors[..errors.len().min(max_issues)];
+    let listed_orphans = &orphans[..orphans.len().min(max_issues - listed_errors.len())];
+    let truncation = Truncation {
+        shown: listed_errors.len() + listed_orphans.len(),
+        total: errors.len() + orphans.len(),
+    };
+
     match output.mode() {
         OutputMode::Json => {
-            let error_data: Vec<serde_json::Value> = errors
-                .iter()
-                .map(|e| {
-                    let mut obj = json!({
-                        "file": e.file,
-                        "type": format!("{}", e.asset_type),
-                        "error": e.error,
-                        "why": e.why,
-                        "fix": e.suggestion,
-                    });
-                    if let Some(ref field) = e.field {
-                        obj["field"] = json!(field);
-                    }
-                    obj
-                })
-                .collect();
+            let to_json = |e: &ValidationResult| {
+                let mut obj = json!({
+                    "file": e.file,
+                    "type": format!("{}", e.asset_type),
+                    "category": e.category.as_str(),
+                    "error": e.error,
+                    "why": e.why,
+                    "fix": e.suggestion,
+                });
+                if let Some(ref field) = e.field {
+                    obj["field"] = json!(field);
+                }
+                obj
+            };
+            let error_data: Vec<serde_json::Value> = listed_errors.iter().map(to_json).collect();
+            let orphan_data: Vec<serde_json::Value> = listed_orphans.iter().map(to_json).collect();
 
-            let result = json!({
+            let mut result = json!({
                 "valid": is_valid,
                 "errors": error_data,
                 "warnings": warnings,
+                "orphans": orphan_data,
+                "orphan_check": orphan_check,
+                "issue_counts": issue_counts,
                 "summary": asset_summary,
                 "total_validated": total_validated,
                 "sdk_available": sdk_available,
             });
+            truncation.annotate(&mut result);
 
             // In JSON mode, always output the structured result
             output.success(result, None);
@@ -1325,6 +5117,13 @@ async fn handle_validate_project_command(
                 output.progress("");
             }
 
+            if !listed_orphans.is_empty() {
+                for orphan in listed_orphans {
+                    output.progress(&format!("{} {}: {}", "ℹ".blue(), orphan.file, orphan.error));
+                }
+                output.progress("");
+            }
+
             if is_valid {
                 output.progress("");
                 output.success(
@@ -1343,6 +5142,12 @@ async fn handle_validate_project_command(
                         output.progress(&format!("  {}: {} {}", type_name, count, "✓".green()));
                     }
                 }
+                if orphan_check {
+                    output.progress(&format!(
+                        "  orphans: {}",
+                        issue_counts["orphan"].as_u64().unwrap_or(0)
+                    ));
+                }
                 if !sdk_available {
                     output.progress(&format!(
                         "\n{} Schema validation was skipped (SDK not available)",
@@ -1352,12 +5157,14 @@ async fn handle_validate_project_command(
             } else {
                 output.progress("");
                 output.progress(&format!(
-                    "{} Validation failed: {} error(s) found\n",
+                    "{} Validation failed: {} error(s) found ({} invalid asset(s), {} dangling reference(s))\n",
                     "✗".red(),
-                    errors.len()
+                    errors.len(),
+                    issue_counts["invalid_asset"].as_u64().unwrap_or(0),
+                    issue_counts["dangling_reference"].as_u64().unwrap_or(0)
                 ));
 
-                for err in &errors {
+                for err in listed_errors {
                     output.progress(&format!("  {} {}", "Error:".red().bold(), err.error));
                     output.progress(&format!("    File:  {}", err.file));
                     if let Some(ref field) = err.field {
@@ -1368,11 +5175,22 @@ async fn handle_validate_project_command(
                     output.progress("");
                 }
             }
+            truncation.notify("issues", output);
         }
     }
 
+    if summary {
+        present_validation_summary(
+            full_output,
+            total_validated,
+            errors.len(),
+            warnings.len(),
+            started.elapsed(),
+        );
+    }
+
     if !is_valid {
-        return Err(CliError::new(
+        let error = CliError::new(
             codes::ERR_VALIDATION_SCHEMA,
             format!(
                 "Project validation failed: {} error(s) in {} asset(s)",
@@ -1380,13 +5198,229 @@ async fn handle_validate_project_command(
                 total_validated
             ),
             "Fix the reported errors and run validation again",
+        );
+        return Err(if summary { error.reported() } else { error }.into());
+    }
+
+    run_hook_if_declared(
+        &current_dir,
+        &project_config,
+        HOOK_POST_VALIDATE,
+        "validate",
+        output,
+    )?;
+
+    Ok(())
+}
+
+/// Present the result of `am project validate --summary`: a single
+/// `validate: 312 assets, 0 errors, 2 warnings (1.2s)` line, or an
+/// `{errors, warnings, duration_ms}` object in JSON mode.
+fn present_validation_summary(
+    output: &dyn Output,
+    assets: usize,
+    errors: usize,
+    warnings: usize,
+    duration: Duration,
+) {
+    match output.mode() {
+        OutputMode::Json => output.success(
+            json!({
+                "errors": errors,
+                "warnings": warnings,
+                "duration_ms": duration.as_millis() as u64,
+            }),
+            None,
+        ),
+        OutputMode::Interactive => output.print(&format!(
+            "validate: {} assets, {} errors, {} warnings ({:.1}s)",
+            assets,
+            errors,
+            warnings,
+            duration.as_secs_f64()
+        )),
+    }
+}
+
+/// Run a hook declared in the `.amproject` of the current project.
+async fn handle_run_hook_command(
+    project: Option<&Path>,
+    name: &str,
+    output: &dyn Output,
+) -> Result<()> {
+    let current_dir = project_dir(project)?;
+    let project_config = read_amproject_file(&current_dir)?;
+
+    if !run_hook_if_declared(&current_dir, &project_config, name, "run-hook", output)? {
+        let declared = if project_config.hooks.is_empty() {
+            "none".to_string()
+        } else {
+            project_config
+                .hooks
+                .keys()
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            format!("Hook '{}' is not declared", name),
+            format!(
+                "The 'hooks' map in .amproject has no '{}' entry (declared hooks: {})",
+                name, declared
+            ),
         )
+        .with_suggestion("Add the hook to the 'hooks' map in .amproject")
         .into());
     }
 
+    output.success(
+        json!({
+            "hook": name,
+            "script": project_config.hooks[name],
+            "status": "success",
+        }),
+        None,
+    );
+
+    Ok(())
+}
+
+fn handle_env_project_command(
+    project: Option<&Path>,
+    mask: bool,
+    output: &dyn Output,
+) -> Result<()> {
+    let current_dir = project_dir(project)?;
+    // Only a project's .amenv is read
+    read_amproject_file(&current_dir)?;
+
+    let env_path = current_dir.join(AMENV_FILE);
+    let env_file = EnvFile::load(&current_dir)?;
+    let display_value = |var: &EnvVar| {
+        if mask && is_secret_key(&var.key) {
+            MASKED_VALUE.to_string()
+        } else {
+            var.value.clone()
+        }
+    };
+
+    match output.mode() {
+        OutputMode::Json => {
+            let variables: Vec<serde_json::Value> = env_file
+                .vars
+                .iter()
+                .map(|var| {
+                    json!({
+                        "key": var.key,
+                        "value": display_value(var),
+                        "line": var.line,
+                    })
+                })
+                .collect();
+            let warnings: Vec<String> = env_file.issues.iter().map(ToString::to_string).collect();
+            let mut value = json!({
+                "exists": env_path.is_file(),
+                "variables": variables,
+                "warnings": warnings,
+            });
+            insert_os_str(&mut value, "file", env_path.as_os_str());
+            output.success(value, None);
+        }
+        OutputMode::Interactive => {
+            if !env_path.is_file() {
+                output.progress(&format!(
+                    "The project has no {} file: hooks only get the AM_* variables",
+                    AMENV_FILE
+                ));
+                return Ok(());
+            }
+
+            for var in &env_file.vars {
+                output.print(&format!("{}={}", var.key, display_value(var)));
+            }
+            for issue in &env_file.issues {
+                output.warning(&format!("Skipped malformed line: {}", issue));
+            }
+        }
+    }
+
     Ok(())
 }
 
+/// Report references to missing assets made by assets of `types`.
+///
+/// Type rules already reject some of them, only the first one per asset: the matching
+/// errors are recategorized, and the other references are reported as new errors.
+fn check_dangling_references(
+    graph: &DependencyGraph,
+    types: &[AssetType],
+    errors: &mut Vec<ValidationResult>,
+) {
+    for unresolved in graph.unresolved() {
+        let from = &unresolved.from;
+        if !types.contains(&from.asset_type) {
+            continue;
+        }
+        let Some(node) = graph.node(from) else {
+            continue;
+        };
+
+        let file = format!("sources/{}", node.path);
+        let field = &unresolved.reference.field;
+        // Soundbanks report every missing asset in a single error on `assets`.
+        let matching = errors.iter_mut().find(|e| {
+            e.file == file
+                && (e.field.as_ref() == Some(field)
+                    || (from.asset_type == AssetType::Soundbank
+                        && e.field.as_deref() == Some("assets")))
+        });
+        if let Some(error) = matching {
+            error.category = IssueCategory::DanglingReference;
+            continue;
+        }
+
+        errors.push(ValidationResult {
+            file,
+            asset_type: from.asset_type,
+            category: IssueCategory::DanglingReference,
+            error: format!(
+                "{} '{}' references a missing asset: {}",
+                from.asset_type, from.name, unresolved.reference.target
+            ),
+            why: "Every asset referenced by another asset must exist in the project".to_string(),
+            suggestion: format!("Fix the '{}' field, or restore the referenced asset", field),
+            field: Some(field.clone()),
+        });
+    }
+}
+
+/// Find the assets of `types` no other asset references. Events and soundbanks are used
+/// from game code and are never orphans.
+fn find_orphans(graph: &DependencyGraph, types: &[AssetType]) -> Vec<ValidationResult> {
+    graph
+        .nodes()
+        .filter(|node| {
+            types.contains(&node.asset_type) && !ROOT_ASSET_TYPES.contains(&node.asset_type)
+        })
+        .filter(|node| graph.references_to(&node.key()).is_empty())
+        .map(|node| ValidationResult {
+            file: format!("sources/{}", node.path),
+            asset_type: node.asset_type,
+            category: IssueCategory::Orphan,
+            error: format!(
+                "{} '{}' is not referenced by any asset",
+                node.asset_type, node.name
+            ),
+            why: "Assets no event, container or soundbank uses are never played".to_string(),
+            suggestion: "Reference it from another asset, delete it, or run with --no-orphan-check if it's unused on purpose"
+                .to_string(),
+            field: None,
+        })
+        .collect()
+}
+
 /// Validate a single asset file by deserializing and running type rules.
 fn validate_asset_file(
     asset_type: AssetType,
@@ -1425,12 +5459,13 @@ fn validate_typed_asset<T: Asset>(
     let mut results = Vec::new();
 
     // Step 1: Deserialize
-    let asset: T = match serde_json::from_str(content) {
+    let asset: T = match context.parse_asset_json(content) {
         Ok(a) => a,
         Err(e) => {
             results.push(ValidationResult {
                 file: file_path.to_string(),
                 asset_type,
+                category: IssueCategory::InvalidAsset,
                 error: format!("Invalid JSON structure: {}", e),
                 why: "The file does not match the expected schema for this asset type".to_string(),
                 suggestion: "Check JSON syntax and ensure all required fields are present"
@@ -1446,6 +5481,7 @@ fn validate_typed_asset<T: Asset>(
         results.push(ValidationResult {
             file: file_path.to_string(),
             asset_type,
+            category: IssueCategory::InvalidAsset,
             error: validation_err.what().to_string(),
             why: validation_err.why().to_string(),
             suggestion: validation_err.suggestion().to_string(),
@@ -1469,12 +5505,14 @@ fn validate_typed_asset<T: Asset>(
 /// 4. Copy audio data files to build output directory
 /// 5. Report summary
 async fn handle_build_project_command(
+    project: Option<&Path>,
+    walk: WalkOptions,
     output_dir: Option<PathBuf>,
     clean: bool,
     fail_fast: bool,
     output: &dyn Output,
 ) -> Result<()> {
-    let current_dir = env::current_dir()?;
+    let current_dir = project_dir(project)?;
     let project_config = read_amproject_file(&current_dir)?;
 
     output.progress(&format!("Building project '{}'...", project_config.name));
@@ -1500,10 +5538,11 @@ async fn handle_build_project_command(
     // Step 2: Validate all assets first
     output.progress("Running validation...");
 
-    let validator = ProjectValidator::new(current_dir.clone(), output)?;
+    let validator = ProjectValidator::new(current_dir.clone(), walk, output)?;
     let context = ProjectContext::new(current_dir.clone()).with_validator(validator);
 
     let sources_dir = current_dir.join(&project_config.sources_dir);
+    let ignore = IgnoreRules::load(&current_dir)?;
     let asset_types = vec![
         AssetType::Sound,
         AssetType::Collection,
@@ -1514,23 +5553,25 @@ async fn handle_build_project_command(
         AssetType::Soundbank,
     ];
 
+    for asset_type in &asset_types {
+        check_asset_dir(&project_config, *asset_type)?;
+    }
+
     let mut validation_errors: Vec<ValidationResult> = Vec::new();
 
     for asset_type in &asset_types {
-        let dir = sources_dir.join(asset_type.directory_name());
+        let dir = sources_dir.join(project_config.asset_dir(*asset_type));
         if !dir.exists() {
             continue;
         }
 
-        let walk = walkdir::WalkDir::new(&dir)
+        // The validator already warned about the directories the walk skips
+        let files = walk_project_files(&dir, &ignore, walk)
+            .files
             .into_iter()
-            .filter_map(|e| e.ok())
-            .filter(|e| {
-                e.file_type().is_file() && e.path().extension().is_some_and(|ext| ext == "json")
-            });
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"));
 
-        for entry in walk {
-            let path = entry.path().to_owned();
+        for path in files {
             let relative_path = path
                 .strip_prefix(&current_dir)
                 .unwrap_or(&path)
@@ -1607,7 +5648,9 @@ async fn handle_build_project_command(
 
     output.progress("Validation passed.");
 
-    // Step 3: Determine output directory
+    // Step 3: Determine output directory. An explicit output directory may be anywhere,
+    // but the configured one can only be cleaned if it is inside the project.
+    let explicit_output_dir = output_dir.is_some();
     let build_dir = match output_dir {
         Some(dir) => dir,
         None => current_dir.join(&project_config.build_dir),
@@ -1619,16 +5662,29 @@ async fn handle_build_project_command(
             "Cleaning build directory: {}...",
             build_dir.display()
         ));
-        fs::remove_dir_all(&build_dir)?;
+        if explicit_output_dir {
+            safe_remove_in_parent(&build_dir)?;
+        } else {
+            safe_remove_within(&current_dir, &build_dir)?;
+        }
     }
 
-    fs::create_dir_all(&build_dir)?;
+    fs_ctx::create_dir_all(&build_dir)?;
 
     // Step 5: Compile assets to FlatBuffers binaries
     output.progress("Compiling assets...");
 
-    let build_summary =
-        compiler::compile_project(&sources_dir, &build_dir, &sdk, fail_fast, output)?;
+    let build_summary = compiler::compile_project(
+        &sources_dir,
+        &build_dir,
+        &sdk,
+        &ignore,
+        walk,
+        project_config.allow_json_comments,
+        &project_config.asset_dirs,
+        fail_fast,
+        output,
+    )?;
 
     // Step 6: Copy data files (audio files)
     let data_dir = current_dir.join(&project_config.data_dir);
@@ -1638,10 +5694,10 @@ async fn handle_build_project_command(
 
     if data_dir.exists() {
         let dest_data_dir = build_dir.join(&project_config.data_dir);
-        fs::create_dir_all(&dest_data_dir)?;
+        fs_ctx::create_dir_all(&dest_data_dir)?;
 
         output.progress("Copying data files...");
-        match copy_dir_recursive(&data_dir, &dest_data_dir, fail_fast) {
+        match copy_dir_recursive(&data_dir, &dest_data_dir, &ignore, walk, fail_fast, output) {
             Ok((count, bytes, errors)) => {
                 data_files_copied = count;
                 total_size += bytes;
@@ -1670,19 +5726,17 @@ async fn handle_build_project_command(
 
     match output.mode() {
         OutputMode::Json => {
-            output.success(
-                json!({
-                    "built": !has_errors,
-                    "output_path": build_dir.to_string_lossy(),
-                    "compiled": total_compiled,
-                    "skipped": total_skipped,
-                    "assets": build_summary.type_counts,
-                    "data_files": data_files_copied,
-                    "size_bytes": total_size,
-                    "errors": all_errors.iter().map(|(f, e)| json!({"file": f, "error": e})).collect::<Vec<_>>(),
-                }),
-                None,
-            );
+            let mut value = json!({
+                "built": !has_errors,
+                "compiled": total_compiled,
+                "skipped": total_skipped,
+                "assets": build_summary.type_counts,
+                "data_files": data_files_copied,
+                "size_bytes": total_size,
+                "errors": all_errors.iter().map(|(f, e)| json!({"file": f, "error": e})).collect::<Vec<_>>(),
+            });
+            insert_os_str(&mut value, "output_path", build_dir.as_os_str());
+            output.success(value, None);
         }
         OutputMode::Interactive => {
             output.progress("");
@@ -1735,49 +5789,44 @@ async fn handle_build_project_command(
     Ok(())
 }
 
-/// Recursively copy a directory's contents.
+/// Recursively copy a directory's contents, skipping paths ignored by `ignore`.
 /// Returns (files_copied, total_bytes, errors).
+///
+/// Goes through the shared project walker with `walk`, so symlink cycles and
+/// `--max-depth` are handled like everywhere else, and the skipped directories warned
+/// about.
 fn copy_dir_recursive(
     src: &std::path::Path,
     dest: &std::path::Path,
+    ignore: &IgnoreRules,
+    walk: WalkOptions,
     fail_fast: bool,
+    output: &dyn Output,
 ) -> Result<(usize, u64, Vec<(String, String)>)> {
     let mut count = 0;
     let mut bytes = 0u64;
     let mut errors = Vec::new();
 
-    let entries = fs::read_dir(src)?;
-
-    for entry in entries {
-        let entry = entry?;
-        let path = entry.path();
-        let filename = path.file_name().unwrap_or_default();
-        let dest_path = dest.join(filename);
-
-        if path.is_dir() {
-            fs::create_dir_all(&dest_path)?;
-            let (sub_count, sub_bytes, sub_errors) =
-                copy_dir_recursive(&path, &dest_path, fail_fast)?;
-            count += sub_count;
-            bytes += sub_bytes;
-            errors.extend(sub_errors);
+    let files = walk_project_files(src, ignore, walk);
+    warn_skipped(&files.skipped, output);
+    for path in files.files {
+        let dest_path = dest.join(path.strip_prefix(src).unwrap_or(&path));
+        if let Some(parent) = dest_path.parent() {
+            fs_ctx::create_dir_all(parent)?;
+        }
 
-            if fail_fast && !errors.is_empty() {
-                return Ok((count, bytes, errors));
+        match fs_ctx::copy(&path, &dest_path) {
+            Ok(b) => {
+                count += 1;
+                bytes += b;
             }
-        } else {
-            match fs::copy(&path, &dest_path) {
-                Ok(b) => {
-                    count += 1;
-                    bytes += b;
-                }
-                Err(e) => {
-                    let rel = path.to_string_lossy().to_string();
-                    errors.push((rel.clone(), format!("Failed to copy {}: {}", rel, e)));
+            Err(e) => {
+                let rel = path.to_string_lossy().to_string();
+                let message = format!("Failed to copy {}: {}", rel, e.root_cause());
+                errors.push((rel, message));
 
-                    if fail_fast {
-                        return Ok((count, bytes, errors));
-                    }
+                if fail_fast {
+                    return Ok((count, bytes, errors));
                 }
             }
         }
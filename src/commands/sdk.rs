@@ -12,23 +12,56 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::common::ignore::WalkOptions;
 use crate::config::sdk::discover_sdk;
-use crate::database::Database;
+use crate::database::{Database, db_delete_config, db_get_config, db_set_config};
 use crate::input::Input;
-use crate::presentation::{Output, OutputMode};
+use crate::presentation::{Output, OutputMode, insert_os_str};
+use crate::schema::cache::{CONFIG_SCHEMA_CACHE_VERSION, SchemaCache};
 use clap::Subcommand;
 use serde_json::json;
+use std::path::Path;
 use std::sync::Arc;
 
 #[derive(Subcommand, Debug)]
 pub enum SdkCommands {
     /// Check if the Amplitude SDK is properly configured
     Check,
+
+    /// Manage the local cache of the SDK schemas
+    Schemas {
+        #[command(subcommand)]
+        command: SchemasCommands,
+    },
+}
+
+impl SdkCommands {
+    /// Whether this command can't run with `--no-db`.
+    ///
+    /// The schema cache records its active version in the configuration table.
+    pub fn requires_database(&self) -> bool {
+        match self {
+            SdkCommands::Schemas { .. } => true,
+            SdkCommands::Check => false,
+        }
+    }
+}
+
+#[derive(Subcommand, Debug)]
+pub enum SchemasCommands {
+    /// Copy the SDK schemas to the local cache, used by validation instead of the SDK
+    Sync {
+        /// Delete every cached version instead of syncing
+        #[arg(long)]
+        clear: bool,
+    },
 }
 
 pub async fn handler(
     command: &SdkCommands,
-    _database: Option<Arc<Database>>,
+    _project: Option<&Path>,
+    _walk: WalkOptions,
+    database: Option<Arc<Database>>,
     _input: &dyn Input,
     output: &dyn Output,
 ) -> anyhow::Result<()> {
@@ -37,13 +70,11 @@ pub async fn handler(
             let location = discover_sdk()?;
 
             if output.mode() == OutputMode::Json {
-                output.success(
-                    json!({
-                        "message": "SDK is properly configured",
-                        "path": location.root().to_string_lossy(),
-                    }),
-                    None,
-                );
+                let mut value = json!({
+                    "message": "SDK is properly configured",
+                });
+                insert_os_str(&mut value, "path", location.root().as_os_str());
+                output.success(value, None);
             } else {
                 let message = format!(
                     "SDK is properly configured\n  Path: {}",
@@ -53,5 +84,86 @@ pub async fn handler(
             }
             Ok(())
         }
+        SdkCommands::Schemas {
+            command: SchemasCommands::Sync { clear },
+        } => {
+            let cache = SchemaCache::open()?;
+            if *clear {
+                clear_schemas_command(&cache, database, output)
+            } else {
+                sync_schemas_command(&cache, database, output)
+            }
+        }
     }
 }
+
+/// Copy the schemas of the discovered SDK into `cache` and make their version the
+/// active one.
+pub fn sync_schemas_command(
+    cache: &SchemaCache,
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> anyhow::Result<()> {
+    let sdk = discover_sdk()?;
+    output.progress(&format!(
+        "Caching the schemas of {}...",
+        sdk.schemas_dir().display()
+    ));
+
+    let previous = db_get_config(CONFIG_SCHEMA_CACHE_VERSION, database.clone())?;
+    let synced = cache.sync(&sdk)?;
+    db_set_config(
+        CONFIG_SCHEMA_CACHE_VERSION,
+        &synced.version,
+        "string",
+        database,
+    )?;
+
+    if output.mode() == OutputMode::Json {
+        let mut value = json!({
+            "version": synced.version,
+            "previous_version": previous,
+            "files": synced.files,
+        });
+        insert_os_str(&mut value, "path", synced.dir.as_os_str());
+        output.success(value, None);
+    } else {
+        output.success(
+            json!(format!(
+                "Cached {} schema file(s) of SDK {} in {}",
+                synced.files.len(),
+                synced.version,
+                synced.dir.display()
+            )),
+            None,
+        );
+    }
+
+    Ok(())
+}
+
+/// Delete every version cached in `cache`, so validation reads the SDK again.
+pub fn clear_schemas_command(
+    cache: &SchemaCache,
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> anyhow::Result<()> {
+    let cleared = cache.clear()?;
+    db_delete_config(CONFIG_SCHEMA_CACHE_VERSION, database)?;
+
+    if output.mode() == OutputMode::Json {
+        output.success(json!({ "cleared": cleared }), None);
+    } else if cleared.is_empty() {
+        output.success(json!("No cached schemas to clear"), None);
+    } else {
+        output.success(
+            json!(format!(
+                "Cleared the cached schemas of SDK {}",
+                cleared.join(", ")
+            )),
+            None,
+        );
+    }
+
+    Ok(())
+}
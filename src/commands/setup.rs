@@ -0,0 +1,205 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! First-run setup wizard.
+//!
+//! The first time an interactive command runs, the CLI offers a short wizard storing the
+//! user's preferences in the `configuration` table:
+//!
+//! - `sdk_path`: SDK installation used when `AM_SDK_PATH` is not set.
+//! - `projects_root`: directory whose projects were registered during setup.
+//! - `color`: `auto`, `always` or `never`.
+//! - `verbosity`: `normal`, `verbose` or `quiet`, used when neither `--verbose` nor
+//!   `--quiet` is given.
+//! - `setup_completed`: set once the wizard ran or was skipped.
+//!
+//! `am sudo setup --rerun` runs the wizard again. Every question goes through [`Input`].
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::Result;
+use inquire::validator::Validation;
+use serde_json::json;
+
+pub use crate::common::settings::{CONFIG_COLOR, CONFIG_SDK_PATH, CONFIG_VERBOSITY};
+
+use crate::{
+    commands::project::{ProjectCommands, handler as handle_project_command},
+    common::logger::Logger,
+    common::settings::Settings,
+    config::sdk::{discover_sdk, is_sdk_path, set_configured_sdk_path},
+    database::{Database, db_get_config, db_set_config},
+    input::Input,
+    presentation::Output,
+};
+
+/// Configuration key set once the setup wizard ran or was skipped.
+pub const CONFIG_SETUP_COMPLETED: &str = "setup_completed";
+
+/// Configuration key holding the projects root registered during setup.
+pub const CONFIG_PROJECTS_ROOT: &str = "projects_root";
+
+/// Values accepted for the `color` setting, the first being the default.
+pub const COLOR_CHOICES: &[&str] = &["auto", "always", "never"];
+
+/// Values accepted for the `verbosity` setting, the first being the default.
+pub const VERBOSITY_CHOICES: &[&str] = &["normal", "verbose", "quiet"];
+
+/// Whether the setup wizard has neither run nor been skipped on this machine.
+pub fn needs_setup(database: Option<Arc<Database>>) -> Result<bool> {
+    Ok(db_get_config(CONFIG_SETUP_COMPLETED, database)?.is_none())
+}
+
+/// Offer the setup wizard on the first run, and run it if the user accepts.
+///
+/// Declining marks the setup as completed, so the offer is only made once.
+pub async fn offer_first_run_setup(
+    database: Option<Arc<Database>>,
+    input: &dyn Input,
+    output: &dyn Output,
+) -> Result<()> {
+    output.print("Welcome to am! It looks like this is the first time it runs on this machine.");
+
+    if input.confirm("Do you want to run the setup now?", Some(true))? {
+        run_setup_wizard(database, input, output).await
+    } else {
+        db_set_config(CONFIG_SETUP_COMPLETED, "true", "boolean", database)?;
+        output.print("Setup skipped, run `am sudo setup` to start it later.");
+        Ok(())
+    }
+}
+
+/// Ask for the SDK path, an optional projects root to register, and the color and
+/// verbosity defaults, then store the answers and mark the setup as completed.
+pub async fn run_setup_wizard(
+    database: Option<Arc<Database>>,
+    input: &dyn Input,
+    output: &dyn Output,
+) -> Result<()> {
+    // Step 1: SDK path, detected from AM_SDK_PATH or the common installation paths
+    let detected = discover_sdk()
+        .ok()
+        .map(|sdk| sdk.root().display().to_string());
+    let sdk_path = input.prompt_text_with_default(
+        "SDK path (leave empty to skip):",
+        detected.as_deref().unwrap_or_default(),
+        &[&validate_sdk_answer],
+    )?;
+    let sdk_path = sdk_path.trim();
+    if !sdk_path.is_empty() {
+        db_set_config(CONFIG_SDK_PATH, sdk_path, "string", database.clone())?;
+    }
+
+    // Step 2: Register the projects found under an existing root
+    let mut projects_root = None;
+    if input.confirm(
+        "Do you want to register the projects of an existing directory?",
+        Some(false),
+    )? {
+        let root = input.prompt_text(
+            "Projects directory:",
+            Some("/path/to/projects"),
+            None,
+            &[&validate_directory_answer],
+        )?;
+        let root = std::path::absolute(root.trim())?;
+
+        let register = ProjectCommands::Register {
+            path: Some(root.clone()),
+            recursive: true,
+            rename_on_conflict: false,
+            force: false,
+            name: None,
+            stdin: false,
+            strict: false,
+        };
+        // A failed registration is reported without aborting the rest of the setup.
+        if let Err(e) = handle_project_command(&register, database.clone(), input, output).await {
+            output.warning(&format!("Projects could not be registered: {}", e));
+        }
+
+        db_set_config(
+            CONFIG_PROJECTS_ROOT,
+            &root.display().to_string(),
+            "string",
+            database.clone(),
+        )?;
+        projects_root = Some(root);
+    }
+
+    // Step 3: Output defaults
+    let color = input.select("Color output:", &choices(COLOR_CHOICES))?;
+    db_set_config(CONFIG_COLOR, &color, "string", database.clone())?;
+
+    let verbosity = input.select("Default verbosity:", &choices(VERBOSITY_CHOICES))?;
+    db_set_config(CONFIG_VERBOSITY, &verbosity, "string", database.clone())?;
+
+    db_set_config(CONFIG_SETUP_COMPLETED, "true", "boolean", database)?;
+
+    output.success(
+        json!({
+            "sdk_path": (!sdk_path.is_empty()).then_some(sdk_path),
+            "projects_root": projects_root.map(|r| r.display().to_string()),
+            "color": color,
+            "verbosity": verbosity,
+        }),
+        None,
+    );
+
+    Ok(())
+}
+
+/// Apply the effective settings of the invocation to this process.
+///
+/// The verbosity flags were already applied when the logger was set up, so the
+/// verbosity only changes when it comes from elsewhere.
+pub fn apply_settings(settings: &Settings) {
+    set_configured_sdk_path(settings.sdk_path().map(PathBuf::from));
+
+    match settings.color() {
+        "always" => colored::control::set_override(true),
+        "never" => colored::control::set_override(false),
+        _ => {}
+    }
+
+    match settings.verbosity() {
+        "verbose" => Logger::set_verbose(true),
+        "quiet" => Logger::set_quiet(true),
+        _ => {}
+    }
+}
+
+fn choices(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+fn validate_sdk_answer(value: &str) -> Result<Validation, inquire::CustomUserError> {
+    let value = value.trim();
+    if value.is_empty() || is_sdk_path(Path::new(value)) {
+        Ok(Validation::Valid)
+    } else {
+        Ok(Validation::Invalid(
+            "The SDK directory must contain a 'schemas/' directory".into(),
+        ))
+    }
+}
+
+fn validate_directory_answer(value: &str) -> Result<Validation, inquire::CustomUserError> {
+    if Path::new(value.trim()).is_dir() {
+        Ok(Validation::Valid)
+    } else {
+        Ok(Validation::Invalid("This directory does not exist".into()))
+    }
+}
@@ -0,0 +1,241 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Usage statistics of the CLI on this machine.
+//!
+//! `am stats usage` summarizes the [command history](crate::database::history): how
+//! often each command ran, how often it failed and how long it usually took, and with
+//! `--by-project` the same per registered project. With the global `--project` option,
+//! only the runs in that project are counted. Everything is computed from the local
+//! database, nothing is sent anywhere.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::Utc;
+use clap::Subcommand;
+use serde_json::{Value, json};
+
+use crate::common::errors::{database_unavailable, project_not_registered};
+use crate::common::format::utc_timestamp;
+use crate::common::ignore::WalkOptions;
+use crate::common::settings::{self, Settings};
+use crate::database::history::{
+    CONFIG_HISTORY_ENABLED, CommandUsage, command_usage, history_setting_changed_since,
+    project_usage,
+};
+use crate::database::retention::retention_policy;
+use crate::database::{Database, db_get_project_by_path};
+use crate::input::Input;
+use crate::presentation::{Alignment, ColumnSpec, Output, OutputMode};
+
+#[derive(Subcommand, Debug)]
+pub enum StatsCommands {
+    /// Summarize how often each command ran, failed, and how long it took
+    Usage {
+        /// Number of days of history to summarize
+        #[arg(long, value_name = "N", default_value = "30", value_parser = clap::value_parser!(u32).range(1..))]
+        days: u32,
+
+        /// Break the usage down by registered project
+        #[arg(long)]
+        by_project: bool,
+    },
+}
+
+pub async fn handler(
+    command: &StatsCommands,
+    project: Option<&Path>,
+    _walk: WalkOptions,
+    database: Option<Arc<Database>>,
+    _input: &dyn Input,
+    output: &dyn Output,
+) -> Result<()> {
+    match command {
+        StatsCommands::Usage { days, by_project } => {
+            handle_usage_command(project, *days, *by_project, database, output)
+        }
+    }
+}
+
+/// Summarize the command history of the last `days` days.
+fn handle_usage_command(
+    project: Option<&Path>,
+    days: u32,
+    by_project: bool,
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> Result<()> {
+    let database = database.ok_or_else(database_unavailable)?;
+
+    // With --project, only the runs in that project are summarized
+    let project = match project {
+        Some(dir) => {
+            let path = dir.display().to_string();
+            let project =
+                db_get_project_by_path(&path, Some(database.clone()))?.ok_or_else(|| {
+                    project_not_registered(&path)
+                        .with_suggestion("Register it with 'am project register'")
+                })?;
+            Some(project)
+        }
+        None => None,
+    };
+    let project_id = project.as_ref().and_then(|project| project.id);
+
+    let since = Utc::now() - chrono::Duration::days(days.into());
+    let commands = command_usage(&database, since, project_id)?;
+    let projects = if by_project {
+        project_usage(&database, since, project_id)?
+    } else {
+        Vec::new()
+    };
+    let notices = usage_notices(&database, days, since)?;
+    let runs: usize = commands.iter().map(|usage| usage.runs).sum();
+
+    match output.mode() {
+        OutputMode::Json => {
+            let mut data = json!({
+                "days": days,
+                "since": utc_timestamp(since),
+                "runs": runs,
+                "commands": commands.iter().map(usage_json).collect::<Vec<_>>(),
+                "notices": notices,
+            });
+            if let Some(project) = &project {
+                data["project"] = json!(project.name);
+            }
+            if by_project {
+                data["projects"] = projects.iter().map(usage_json).collect();
+            }
+            output.success(data, None);
+        }
+        OutputMode::Interactive => {
+            if commands.is_empty() {
+                output.progress(&format!("No commands recorded in the last {} days", days));
+            } else {
+                let title = match &project {
+                    Some(project) => {
+                        format!("Command usage in {}, last {} days", project.name, days)
+                    }
+                    None => format!("Command usage, last {} days", days),
+                };
+                output.table_with_columns(
+                    Some(&title),
+                    &usage_columns(false),
+                    commands.iter().map(usage_row).collect(),
+                );
+            }
+            if by_project && !projects.is_empty() {
+                output.progress("");
+                output.table_with_columns(
+                    Some("Usage by project"),
+                    &usage_columns(true),
+                    projects.iter().map(usage_row).collect(),
+                );
+            }
+            if !notices.is_empty() {
+                output.progress("");
+            }
+            for notice in &notices {
+                output.progress(notice);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Why the history of the window may be incomplete.
+///
+/// Only changes of the configuration table are dated: commands run with
+/// `AM_HISTORY_ENABLED=false` leave no trace at all.
+fn usage_notices(
+    database: &Arc<Database>,
+    days: u32,
+    since: chrono::DateTime<Utc>,
+) -> Result<Vec<String>> {
+    let mut notices = Vec::new();
+
+    // Outside of a full invocation (e.g. in tests), resolve them without flags.
+    let loaded;
+    let settings = match settings::current() {
+        Some(settings) => settings,
+        None => {
+            loaded = Settings::load(&Default::default(), Some(database.clone()));
+            &loaded
+        }
+    };
+    if !settings.history_enabled() {
+        notices.push(format!(
+            "Command history is disabled ('{}' is false): new commands aren't recorded",
+            CONFIG_HISTORY_ENABLED
+        ));
+    }
+    if let Some(changed_at) = history_setting_changed_since(database, since)? {
+        notices.push(format!(
+            "The '{}' setting changed on {}: commands run while it was false are missing",
+            CONFIG_HISTORY_ENABLED, changed_at
+        ));
+    }
+    if let Some(max_age_days) = retention_policy(database, "command_history")?.max_age_days
+        && max_age_days < u64::from(days)
+    {
+        notices.push(format!(
+            "History older than {} days is pruned by the retention limits",
+            max_age_days
+        ));
+    }
+
+    Ok(notices)
+}
+
+fn usage_json(usage: &CommandUsage) -> Value {
+    let mut value = json!({
+        "command": usage.command,
+        "runs": usage.runs,
+        "failures": usage.failures,
+        "failure_rate": usage.failure_rate(),
+        "median_ms": usage.median_ms,
+    });
+    if let Some(project) = &usage.project {
+        value["project"] = json!(project);
+    }
+    value
+}
+
+fn usage_row(usage: &CommandUsage) -> Value {
+    json!({
+        "project": usage.project,
+        "command": usage.command,
+        "runs": usage.runs,
+        "failures": format!("{} ({:.1}%)", usage.failures, usage.failure_rate() * 100.0),
+        "median": format!("{:.1}s", usage.median_ms as f64 / 1000.0),
+    })
+}
+
+fn usage_columns(by_project: bool) -> Vec<ColumnSpec> {
+    let mut columns = Vec::new();
+    if by_project {
+        columns.push(ColumnSpec::new("project", "Project"));
+    }
+    columns.extend([
+        ColumnSpec::new("command", "Command"),
+        ColumnSpec::new("runs", "Runs").with_alignment(Alignment::Right),
+        ColumnSpec::new("failures", "Failures").with_alignment(Alignment::Right),
+        ColumnSpec::new("median", "Median").with_alignment(Alignment::Right),
+    ]);
+    columns
+}
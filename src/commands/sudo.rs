@@ -12,40 +12,216 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::Subcommand;
+use colored::Colorize;
+use log::debug;
+use rusqlite::{Connection, ErrorCode, OpenFlags};
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::{
-    database::{Database, get_database_path},
-    input::Input,
-    presentation::Output,
+    commands::project::{RegisterEntry, entry_error_json, register_entry},
+    commands::setup::{needs_setup, run_setup_wizard},
+    common::errors::{CliError, codes, database_unavailable},
+    common::file_types::{FILE_TYPES, FileAssociations, native_associations},
+    common::files::safe_remove_in_parent,
+    common::fs_ctx,
+    common::ignore::WalkOptions,
+    common::lock::{LOCK_FILE, read_lock_holder},
+    common::log_tail::{FOLLOW_POLL_INTERVAL, LogFollower, latest_log_file, log_line_to_json},
+    common::paths::{self, HomeEnv},
+    common::process::{ProcessInfo, find_cli_processes, process_is_alive},
+    common::settings::{self, Settings},
+    common::utils::{
+        AMPROJECT_VERSION, transform_project_name, validate_project_name, write_amproject_file,
+    },
+    database::{
+        Database,
+        backup::{backup_before_migration, migration_failed},
+        db_get_all_config, db_get_project_by_name, db_set_project_favorite,
+        entities::ProjectConfiguration,
+        get_database_path,
+        legacy::{LegacyProject, parse_legacy_registry},
+        migrations::MigrationManager,
+        retention::run_maintenance,
+        transfer::{RegistryTransfer, TransferCounts},
+    },
+    input::{Input, with_flag_hint},
+    presentation::{ColumnSpec, Output, OutputMode, OutputWriter},
 };
 use serde_json::json;
 
+/// How long the write lock probe waits for the database before reporting it as locked.
+const WRITE_PROBE_TIMEOUT: Duration = Duration::from_millis(500);
+
 #[derive(Subcommand, Debug)]
 pub enum SudoCommands {
     /// Database management operations
-    #[command(after_help = "Examples:\n  am sudo database reset\n")]
     Database {
         #[command(subcommand)]
         command: DatabaseCommands,
     },
+
+    /// Diagnostics about the CLI installation
+    #[command(name = "self")]
+    Cli {
+        #[command(subcommand)]
+        command: SelfCommands,
+    },
+
+    /// Inspect the settings of the CLI
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
+
+    /// Read the logs and crash logs of the CLI
+    Logs {
+        #[command(subcommand)]
+        command: LogsCommands,
+    },
+
+    /// Run the setup wizard choosing the SDK path, projects and output defaults
+    Setup {
+        /// Run the wizard again even if the setup was already completed
+        #[arg(long)]
+        rerun: bool,
+    },
+
+    /// Move the legacy ~/.amplitude directory to the XDG base directories
+    MigrateHome {
+        /// Skip confirmation prompt
+        #[arg(short = 'y', long = "yes")]
+        skip_confirmation: bool,
+    },
+
+    /// Register the projects listed in the projects.json file of the legacy Amplitude editor
+    ImportLegacy {
+        /// The projects.json file to read
+        file: PathBuf,
+
+        /// Write a .amproject named after the directory in the projects missing one
+        #[arg(long)]
+        initialize_missing: bool,
+    },
+
+    /// Associate .amproject and .amtpl files with the CLI for the current user
+    RegisterFileTypes {
+        /// Remove the associations instead
+        #[arg(long)]
+        unregister: bool,
+
+        /// Show the changes without making them
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+impl SudoCommands {
+    /// Whether this command can't run with `--no-db`.
+    ///
+    /// The lock diagnosis inspects the database file without opening it, and
+    /// `migrate-home` always runs with the database closed.
+    pub fn requires_database(&self) -> bool {
+        match self {
+            SudoCommands::Database { .. }
+            | SudoCommands::Config { .. }
+            | SudoCommands::Setup { .. }
+            | SudoCommands::ImportLegacy { .. } => true,
+            SudoCommands::Cli { .. }
+            | SudoCommands::Logs { .. }
+            | SudoCommands::MigrateHome { .. }
+            | SudoCommands::RegisterFileTypes { .. } => false,
+        }
+    }
 }
 
 #[derive(Subcommand, Debug)]
 pub enum DatabaseCommands {
     /// Reset the database (destructive operation)
-    #[command(after_help = "Examples:\n  am sudo database reset\n")]
     Reset {
         /// Skip confirmation prompt
         #[arg(short = 'y', long = "yes")]
         skip_confirmation: bool,
+
+        /// Allow the reset in non-interactive sessions (or set AM_ALLOW_DESTRUCTIVE=1)
+        #[arg(long = "i-know-what-im-doing")]
+        allow_destructive: bool,
+    },
+
+    /// Apply pending schema migrations up to a specific version
+    Migrate {
+        /// Schema version to migrate to (defaults to the latest version)
+        #[arg(long = "to", value_name = "VERSION")]
+        target_version: Option<u32>,
+
+        /// Skip confirmation prompt
+        #[arg(short = 'y', long = "yes")]
+        skip_confirmation: bool,
+    },
+
+    /// Write the project registry to a JSON file
+    Export {
+        /// The file to write
+        file: PathBuf,
+    },
+
+    /// Replace the project registry with one written by 'am sudo database export'
+    Import {
+        /// The file to read
+        file: PathBuf,
+
+        /// Skip confirmation prompt
+        #[arg(short = 'y', long = "yes")]
+        skip_confirmation: bool,
+
+        /// Allow the import in non-interactive sessions (or set AM_ALLOW_DESTRUCTIVE=1)
+        #[arg(long = "i-know-what-im-doing")]
+        allow_destructive: bool,
     },
+
+    /// Delete the rows of the history tables beyond their retention limits
+    Maintain,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommands {
+    /// List the settings stored in the configuration table
+    List {
+        /// List the value of every setting for this invocation instead, with where it comes
+        /// from: a flag, an AM_* environment variable, the configuration table or the default
+        #[arg(long)]
+        effective: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum LogsCommands {
+    /// Print the last lines of the most recent log
+    Tail {
+        /// Keep printing the lines appended to the log until interrupted with Ctrl-C
+        #[arg(short = 'f', long)]
+        follow: bool,
+
+        /// Number of lines to print from the end of the log
+        #[arg(short = 'n', long, value_name = "N", default_value = "10")]
+        lines: usize,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum SelfCommands {
+    /// Find out which process holds the database lock
+    DiagnoseDbLock,
 }
 
 pub async fn handler(
     command: &SudoCommands,
+    _project: Option<&Path>,
+    _walk: WalkOptions,
     database: Option<Arc<Database>>,
     input: &dyn Input,
     output: &dyn Output,
@@ -54,9 +230,62 @@ pub async fn handler(
         SudoCommands::Database { command } => {
             handle_database_command(command, database, input, output).await
         }
+        SudoCommands::Cli { command } => match command {
+            SelfCommands::DiagnoseDbLock => diagnose_db_lock_command(output),
+        },
+        SudoCommands::Config { command } => match command {
+            ConfigCommands::List { effective } => list_config_command(*effective, database, output),
+        },
+        SudoCommands::Logs { command } => match command {
+            LogsCommands::Tail { follow, lines } => {
+                tail_logs_command(*follow, *lines, output).await
+            }
+        },
+        SudoCommands::Setup { rerun } => setup_command(*rerun, database, input, output).await,
+        SudoCommands::MigrateHome { skip_confirmation } => migrate_home(
+            &HomeEnv::current(),
+            &find_cli_processes(),
+            *skip_confirmation,
+            input,
+            output,
+        ),
+        SudoCommands::ImportLegacy {
+            file,
+            initialize_missing,
+        } => import_legacy_registry(file, *initialize_missing, database, output),
+        SudoCommands::RegisterFileTypes {
+            unregister,
+            dry_run,
+        } => {
+            let exe = std::env::current_exe().context("Failed to locate the am executable")?;
+            register_file_types(
+                native_associations().as_ref(),
+                &exe,
+                *unregister,
+                *dry_run,
+                output,
+            )
+        }
     }
 }
 
+async fn setup_command(
+    rerun: bool,
+    database: Option<Arc<Database>>,
+    input: &dyn Input,
+    output: &dyn Output,
+) -> Result<()> {
+    if !rerun && !needs_setup(database.clone())? {
+        output.success(
+            json!("Setup was already completed, use --rerun to run it again"),
+            None,
+        );
+        return Ok(());
+    }
+
+    run_setup_wizard(database, input, output).await
+}
+
 async fn handle_database_command(
     command: &DatabaseCommands,
     database: Option<Arc<Database>>,
@@ -64,14 +293,473 @@ async fn handle_database_command(
     output: &dyn Output,
 ) -> Result<()> {
     match command {
-        DatabaseCommands::Reset { skip_confirmation } => {
-            reset_database(*skip_confirmation, database, input, output).await
+        DatabaseCommands::Reset {
+            skip_confirmation,
+            allow_destructive,
+        } => {
+            reset_database(
+                *skip_confirmation,
+                *allow_destructive,
+                database,
+                input,
+                output,
+            )
+            .await
+        }
+        DatabaseCommands::Migrate {
+            target_version,
+            skip_confirmation,
+        } => migrate_database(*target_version, *skip_confirmation, database, input, output).await,
+        DatabaseCommands::Export { file } => export_registry(file, database, output),
+        DatabaseCommands::Import {
+            file,
+            skip_confirmation,
+            allow_destructive,
+        } => import_registry(
+            file,
+            *skip_confirmation,
+            *allow_destructive,
+            database,
+            input,
+            output,
+        ),
+        DatabaseCommands::Maintain => maintain_database(database, output),
+    }
+}
+
+/// Enforce the retention limits of the history tables now, see [`run_maintenance`].
+pub fn maintain_database(database: Option<Arc<Database>>, output: &dyn Output) -> Result<()> {
+    let database = database.ok_or_else(database_unavailable)?;
+
+    let pruned = run_maintenance(&database)?;
+
+    if output.mode() == OutputMode::Json {
+        output.success(json!({ "pruned": pruned }), None);
+    } else if pruned.values().all(|count| *count == 0) {
+        output.success(json!("No rows beyond the retention limits"), None);
+    } else {
+        let summary = pruned
+            .iter()
+            .filter(|(_, count)| **count > 0)
+            .map(|(table, count)| format!("{}: {}", table, count))
+            .collect::<Vec<_>>()
+            .join(", ");
+        output.success(
+            json!(format!(
+                "Pruned {} row(s) ({})",
+                pruned.values().sum::<usize>(),
+                summary
+            )),
+            None,
+        );
+    }
+
+    Ok(())
+}
+
+/// Write the registry to `file`, through a temporary file so a failed export never
+/// leaves a truncated file behind.
+pub fn export_registry(
+    file: &Path,
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> Result<()> {
+    let database = database.ok_or_else(database_unavailable)?;
+
+    let tmp_path = file.with_extension("tmp");
+    let exported = fs::File::create(&tmp_path)
+        .with_context(|| format!("Failed to create {}", tmp_path.display()))
+        .and_then(|tmp| {
+            let mut writer = std::io::BufWriter::new(tmp);
+            RegistryTransfer::new(&database)
+                .on_progress(|table, current, total| {
+                    output.progress_with(&format!("Exporting {}", table), current, total)
+                })
+                .export(&mut writer)
+        })
+        .and_then(|counts| {
+            fs::rename(&tmp_path, file)
+                .with_context(|| format!("Failed to write {}", file.display()))?;
+            Ok(counts)
+        });
+    let counts = match exported {
+        Ok(counts) => counts,
+        Err(e) => {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(e);
+        }
+    };
+
+    if output.mode() == OutputMode::Json {
+        output.success(
+            json!({ "path": file.to_string_lossy(), "tables": counts }),
+            None,
+        );
+    } else {
+        output.success(
+            json!(format!(
+                "Exported {} to {}",
+                describe_counts(&counts),
+                file.display()
+            )),
+            None,
+        );
+    }
+
+    Ok(())
+}
+
+/// Replace the registry with the one exported to `file`.
+pub fn import_registry(
+    file: &Path,
+    skip_confirmation: bool,
+    allow_destructive: bool,
+    database: Option<Arc<Database>>,
+    input: &dyn Input,
+    output: &dyn Output,
+) -> Result<()> {
+    let database = database.ok_or_else(database_unavailable)?;
+    ensure_destructive_allowed("am sudo database import", allow_destructive, input)?;
+
+    let reader =
+        fs::File::open(file).with_context(|| format!("Failed to open {}", file.display()))?;
+
+    if !skip_confirmation {
+        output.progress(
+            "Every registered project and template will be replaced by those of the file.",
+        );
+        let confirmed = with_flag_hint(input, "--yes").confirm(
+            &format!("Replace the registry with {}?", file.display()),
+            None,
+        )?;
+
+        if !confirmed {
+            output.success(json!("Registry import cancelled."), None);
+            return Ok(());
+        }
+    }
+
+    // Ctrl-C ends the process, which leaves the registry untouched: it is only replaced
+    // in the last transaction of the import.
+    let counts = RegistryTransfer::new(&database)
+        .on_progress(|table, current, total| {
+            output.progress_with(&format!("Importing {}", table), current, total)
+        })
+        .import(reader)
+        .map_err(|e| match e.downcast::<CliError>() {
+            Ok(error) => error.with_context(file.display().to_string()).into(),
+            Err(e) => e,
+        })?;
+
+    if output.mode() == OutputMode::Json {
+        output.success(
+            json!({ "path": file.to_string_lossy(), "tables": counts }),
+            None,
+        );
+    } else {
+        output.success(
+            json!(format!(
+                "Imported {} from {}",
+                describe_counts(&counts),
+                file.display()
+            )),
+            None,
+        );
+    }
+
+    Ok(())
+}
+
+fn describe_counts(counts: &TransferCounts) -> String {
+    counts
+        .iter()
+        .map(|(table, count)| format!("{} {}", count, table))
+        .collect::<Vec<_>>()
+        .join(" and ")
+}
+
+/// Register the projects listed in the legacy registry `file`, see
+/// [`parse_legacy_registry`], reporting a result for each of them.
+///
+/// A malformed file is rejected before anything is registered. Entries are then
+/// registered like those of `am project register --stdin`, a failed entry not preventing
+/// the next ones. With `initialize_missing`, project directories without a `.amproject`
+/// get one named after the directory.
+pub fn import_legacy_registry(
+    file: &Path,
+    initialize_missing: bool,
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> Result<()> {
+    let content =
+        fs::read_to_string(file).with_context(|| format!("Failed to read {}", file.display()))?;
+    let entries =
+        parse_legacy_registry(&content).map_err(|e| e.with_context(file.display().to_string()))?;
+
+    let mut results = Vec::with_capacity(entries.len());
+    let mut registered = 0;
+    let mut failed = 0;
+
+    for (index, entry) in entries.iter().enumerate() {
+        let mut result =
+            match import_legacy_entry(entry, initialize_missing, database.clone(), output) {
+                Ok(result) => {
+                    let name = result["name"].as_str().unwrap_or_default();
+                    if result["registered"] == true {
+                        registered += 1;
+                        output.progress(&format!("{} {} registered", "✓".green(), name));
+                    } else {
+                        output.progress(&format!("{} is already registered", name));
+                    }
+                    result
+                }
+                Err(e) => {
+                    failed += 1;
+                    output.warning(&format!(
+                        "Entry {} ({}) was not registered: {}",
+                        index,
+                        entry.path.display(),
+                        e
+                    ));
+                    json!({ "registered": false, "error": entry_error_json(&e) })
+                }
+            };
+
+        result["index"] = json!(index);
+        result["path"] = json!(entry.path.display().to_string());
+        results.push(result);
+    }
+
+    if output.mode() == OutputMode::Json {
+        output.success(
+            json!({ "path": file.to_string_lossy(), "projects": results }),
+            None,
+        );
+    } else {
+        output.success(
+            json!(format!(
+                "Registered {} of the {} projects of {}",
+                registered,
+                results.len(),
+                file.display()
+            )),
+            None,
+        );
+    }
+
+    if failed > 0 {
+        return Err(CliError::new(
+            codes::ERR_PROJECT_NOT_REGISTERED,
+            format!(
+                "Failed to register {} of {} legacy project(s)",
+                failed,
+                results.len()
+            ),
+            "Some entries of the legacy registry could not be registered",
+        )
+        .with_suggestion(
+            "See the error of each failed entry, or pass --initialize-missing for the projects without a .amproject",
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Register the project of a legacy registry entry, writing its `.amproject` first with
+/// `initialize_missing`.
+fn import_legacy_entry(
+    entry: &LegacyProject,
+    initialize_missing: bool,
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> Result<serde_json::Value> {
+    let path = std::path::absolute(&entry.path)?;
+    if !path.is_dir() {
+        return Err(CliError::new(
+            codes::ERR_PROJECT_NOT_INITIALIZED,
+            format!("Project directory of '{}' not found", entry.name),
+            "The directory listed in the legacy registry doesn't exist anymore",
+        )
+        .with_context(path.display().to_string())
+        .into());
+    }
+
+    let initialized = initialize_missing && !path.join(".amproject").exists();
+    if initialized {
+        initialize_legacy_project(&path)?;
+    }
+
+    let mut result = register_entry(
+        &RegisterEntry { path, name: None },
+        false,
+        database.clone(),
+        output,
+    )?;
+    if initialized {
+        result["initialized"] = json!(true);
+    }
+
+    if entry.favorite
+        && result["registered"] == true
+        && let Some(name) = result["name"].as_str()
+        && let Some(project) = db_get_project_by_name(name, database.clone())?
+        && let Some(id) = project.id
+    {
+        db_set_project_favorite(id, true, database)?;
+    }
+
+    Ok(result)
+}
+
+/// Write a `.amproject` named after the directory at `path`, with the default layout of
+/// `am project init`.
+fn initialize_legacy_project(path: &Path) -> Result<()> {
+    let dir_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let name = transform_project_name(&dir_name);
+    validate_project_name(&name).map_err(|reason| {
+        CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            format!("Can't name a project after the directory '{}'", dir_name),
+            reason,
+        )
+        .with_suggestion("Run 'am project init' in the directory, then import it again")
+    })?;
+
+    let config = ProjectConfiguration {
+        name,
+        default_configuration: "pc.config.amconfig".to_string(),
+        build_dir: "build".to_string(),
+        data_dir: "data".to_string(),
+        sources_dir: "sources".to_string(),
+        version: AMPROJECT_VERSION,
+        hooks: Default::default(),
+        allow_json_comments: false,
+        asset_dirs: Default::default(),
+    };
+    write_amproject_file(path, &config)
+}
+
+async fn migrate_database(
+    target_version: Option<u32>,
+    skip_confirmation: bool,
+    database: Option<Arc<Database>>,
+    input: &dyn Input,
+    output: &dyn Output,
+) -> Result<()> {
+    let database = database.ok_or_else(database_unavailable)?;
+
+    let manager = MigrationManager::new();
+    let current_version = manager.get_current_version(&database)?;
+    let target_version = target_version.unwrap_or_else(|| manager.latest_version());
+    let pending = manager.pending_migrations_to(&database, target_version)?;
+
+    if pending.is_empty() {
+        output.success(
+            json!({
+                "from_version": current_version,
+                "to_version": target_version,
+                "applied": [],
+            }),
+            None,
+        );
+        return Ok(());
+    }
+
+    output.progress(&format!(
+        "The following migration(s) will be applied (version {} -> {}):",
+        current_version, target_version
+    ));
+    for migration in &pending {
+        output.progress(&format!(
+            "  • {}: {}",
+            migration.version, migration.description
+        ));
+    }
+    output.progress("");
+
+    if !skip_confirmation {
+        let confirmed = with_flag_hint(input, "--yes")
+            .confirm(&format!("Apply {} migration(s)?", pending.len()), None)?;
+
+        if !confirmed {
+            output.success(json!("Database migration cancelled."), None);
+            return Ok(());
         }
     }
+
+    let applied: Vec<serde_json::Value> = pending
+        .iter()
+        .map(|m| json!({ "version": m.version, "description": m.description }))
+        .collect();
+
+    let backup =
+        backup_before_migration(&database, &paths::resolve()?.backups_dir(), target_version)?;
+    if let Some(backup) = &backup {
+        output.progress(&format!("Backed up the database to {}", backup.display()));
+    }
+
+    output.progress("Migrating database...");
+    if let Err(e) = manager.run_migrations_to(&database, target_version) {
+        return Err(match &backup {
+            Some(backup) => migration_failed(e, backup, database.path()).into(),
+            None => e,
+        });
+    }
+
+    output.success(
+        json!({
+            "from_version": current_version,
+            "to_version": target_version,
+            "applied": applied,
+            "backup": backup,
+        }),
+        None,
+    );
+
+    Ok(())
+}
+
+/// Environment variable allowing destructive commands in non-interactive sessions, like
+/// their `--i-know-what-im-doing` flag.
+pub const ALLOW_DESTRUCTIVE_ENV: &str = "AM_ALLOW_DESTRUCTIVE";
+
+/// Fail with `ERR_DESTRUCTIVE_NOT_ALLOWED` when `command` runs non-interactively without
+/// `--i-know-what-im-doing` or [`ALLOW_DESTRUCTIVE_ENV`] set to `1`.
+///
+/// `--yes` alone is not enough: it is easily copied along with a command into a script,
+/// which then wipes data nobody was asked about. Interactive sessions rely on the
+/// confirmation prompt instead.
+fn ensure_destructive_allowed(
+    command: &str,
+    allow_destructive: bool,
+    input: &dyn Input,
+) -> Result<()> {
+    if input.is_interactive()
+        || allow_destructive
+        || std::env::var(ALLOW_DESTRUCTIVE_ENV).is_ok_and(|v| v.trim() == "1")
+    {
+        return Ok(());
+    }
+
+    Err(CliError::new(
+        codes::ERR_DESTRUCTIVE_NOT_ALLOWED,
+        format!("Refusing to run '{}' non-interactively", command),
+        "It destroys data and nobody can confirm it, --yes alone doesn't allow it",
+    )
+    .with_suggestion(format!(
+        "Pass --i-know-what-im-doing or set {}=1 if the script really means to run it",
+        ALLOW_DESTRUCTIVE_ENV
+    ))
+    .into())
 }
 
 async fn reset_database(
     skip_confirmation: bool,
+    allow_destructive: bool,
     database: Option<Arc<Database>>,
     input: &dyn Input,
     output: &dyn Output,
@@ -84,9 +772,11 @@ async fn reset_database(
     output.progress("This action cannot be undone!");
     output.progress("");
 
+    ensure_destructive_allowed("am sudo database reset", allow_destructive, input)?;
+
     // Check if we should ask for confirmation
     if !skip_confirmation {
-        let confirmed = input.confirm(
+        let confirmed = with_flag_hint(input, "--yes").confirm(
             "Are you absolutely sure you want to reset the database?",
             None,
         )?;
@@ -101,37 +791,41 @@ async fn reset_database(
 
     // Get the database path
     let db_path = get_database_path()?;
+    let wal_path = db_path.with_extension("db-wal");
 
-    // Close the current database connection if it exists
+    // Checkpoint the write-ahead log before closing the current connection, so that only
+    // the writes of other processes can make it grow afterwards.
     if let Some(db) = database {
+        if let Err(e) = db.checkpoint() {
+            debug!("Checkpoint before the database reset failed: {:#}", e);
+        }
         // The database will be closed when the Arc is dropped
         drop(db);
     }
+    ensure_wal_idle(&wal_path, WAL_GROWTH_INTERVAL)?;
 
     // Delete the database file
     if db_path.exists() {
-        std::fs::remove_file(&db_path)
-            .map_err(|e| anyhow::anyhow!("Failed to delete database file: {}", e))?;
+        safe_remove_in_parent(&db_path)?;
         output.progress("Database file deleted");
     } else {
         output.warning("Database file does not exist, skipping deletion");
     }
 
     // Also clean up any WAL or journal files that might exist
-    let wal_path = db_path.with_extension("db-wal");
     let shm_path = db_path.with_extension("db-shm");
     let journal_path = db_path.with_extension("db-journal");
 
     if wal_path.exists() {
-        std::fs::remove_file(&wal_path).ok();
+        safe_remove_in_parent(&wal_path).ok();
     }
 
     if shm_path.exists() {
-        std::fs::remove_file(&shm_path).ok();
+        safe_remove_in_parent(&shm_path).ok();
     }
 
     if journal_path.exists() {
-        std::fs::remove_file(&journal_path).ok();
+        safe_remove_in_parent(&journal_path).ok();
     }
 
     // Recreate and initialize a fresh database
@@ -145,3 +839,532 @@ async fn reset_database(
 
     Ok(())
 }
+
+/// How long `am sudo database reset` watches the write-ahead log for writes of other
+/// processes.
+const WAL_GROWTH_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Fail with `ERR_DATABASE_IN_USE` if the write-ahead log at `wal_path` grows within
+/// `interval`, as another process is then writing to the database.
+///
+/// A missing or empty log is idle without waiting: after a complete checkpoint, writes
+/// of other processes are the only way for it to have content.
+pub fn ensure_wal_idle(wal_path: &Path, interval: Duration) -> Result<()> {
+    let wal_size = |path: &Path| fs::metadata(path).map_or(0, |m| m.len());
+
+    let before = wal_size(wal_path);
+    if before == 0 {
+        return Ok(());
+    }
+    std::thread::sleep(interval);
+    let after = wal_size(wal_path);
+    if after <= before {
+        return Ok(());
+    }
+
+    Err(CliError::new(
+        codes::ERR_DATABASE_IN_USE,
+        "Cannot reset the database while it is being written",
+        format!(
+            "Its write-ahead log grew from {} to {} bytes, another process is writing to it",
+            before, after
+        ),
+    )
+    .with_context(wal_path.display().to_string())
+    .into())
+}
+
+fn diagnose_db_lock_command(output: &dyn Output) -> Result<()> {
+    let db_path = get_database_path()?;
+    let project_root = std::env::current_dir()?;
+    let diagnosis = diagnose_db_lock(&db_path, &project_root, &find_cli_processes());
+
+    if output.mode() == OutputMode::Interactive {
+        for finding in diagnosis["findings"].as_array().into_iter().flatten() {
+            output.print(&format!("  • {}", finding.as_str().unwrap_or_default()));
+        }
+        for suggestion in diagnosis["suggestions"].as_array().into_iter().flatten() {
+            output.print(&format!("  → {}", suggestion.as_str().unwrap_or_default()));
+        }
+        output.success(
+            json!(match diagnosis["write_lock"]["status"].as_str() {
+                Some("held") => "The database is currently locked",
+                _ => "The database is not locked",
+            }),
+            None,
+        );
+    } else {
+        output.success(diagnosis, None);
+    }
+
+    Ok(())
+}
+
+/// List the configuration table, or with `effective` the settings of this invocation
+/// and their sources.
+fn list_config_command(
+    effective: bool,
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> Result<()> {
+    if !effective {
+        let rows: Vec<serde_json::Value> = db_get_all_config(database)?
+            .into_iter()
+            .map(|(key, value)| json!({ "key": key, "value": value }))
+            .collect();
+        let columns = [
+            ColumnSpec::new("key", "Key"),
+            ColumnSpec::new("value", "Value"),
+        ];
+        output.table_with_columns(Some("Configuration"), &columns, json!(rows));
+        return Ok(());
+    }
+
+    // Outside of a full invocation (e.g. in tests), resolve them without flags.
+    let loaded;
+    let settings = match settings::current() {
+        Some(settings) => settings,
+        None => {
+            loaded = Settings::load(&Default::default(), database);
+            &loaded
+        }
+    };
+
+    let rows: Vec<serde_json::Value> = settings
+        .all()
+        .iter()
+        .map(|setting| {
+            json!({
+                "key": setting.key,
+                "value": setting.value,
+                "source": setting.source.as_str(),
+            })
+        })
+        .collect();
+    let columns = [
+        ColumnSpec::new("key", "Setting"),
+        ColumnSpec::new("value", "Value").with_priority(1),
+        ColumnSpec::new("source", "Source"),
+    ];
+    output.table_with_columns(Some("Effective Settings"), &columns, json!(rows));
+
+    Ok(())
+}
+
+/// Print the last `lines` lines of the most recent log of the state directory, then the
+/// lines appended to it with `follow`.
+///
+/// In JSON mode, each line is printed as its own JSON object rather than in a response
+/// envelope, so the output can be consumed while it is being followed.
+async fn tail_logs_command(follow: bool, lines: usize, output: &dyn Output) -> Result<()> {
+    let state_dir = paths::resolve()?.state_dir;
+    let Some(log_file) = latest_log_file(&state_dir)? else {
+        output.success(
+            json!(format!("There are no logs in {}", state_dir.display())),
+            None,
+        );
+        return Ok(());
+    };
+
+    let writer = OutputWriter::stdout();
+    let print = |line: &str| match output.mode() {
+        OutputMode::Json => writer.write_line(&log_line_to_json(line).to_string()),
+        _ => output.print(line),
+    };
+
+    output.progress(&format!("Showing {}", log_file.display()));
+
+    // The existing lines and the appended ones are read by the same follower, so no line
+    // written in between is missed or printed twice.
+    let mut follower = LogFollower::new(&log_file)?;
+    let existing = follower.read_new_lines()?;
+    for line in &existing[existing.len().saturating_sub(lines)..] {
+        print(line);
+    }
+
+    if !follow {
+        return Ok(());
+    }
+
+    tokio::select! {
+        result = follow_log(&mut follower, print) => result,
+        _ = tokio::signal::ctrl_c() => Ok(()),
+    }
+}
+
+/// Print the lines appended to the log read by `follower`, forever.
+async fn follow_log(follower: &mut LogFollower, print: impl Fn(&str)) -> Result<()> {
+    loop {
+        tokio::time::sleep(FOLLOW_POLL_INTERVAL).await;
+        for line in follower.read_new_lines()? {
+            print(&line);
+        }
+    }
+}
+
+/// Inspect what may be holding the database at `db_path`.
+///
+/// Reports the WAL and SHM files next to the database, the lock of the project at
+/// `project_root` if it has one, the other running CLI `processes`, and whether a write
+/// transaction can be started right now, with the findings and suggested remediation.
+pub fn diagnose_db_lock(
+    db_path: &Path,
+    project_root: &Path,
+    processes: &[ProcessInfo],
+) -> serde_json::Value {
+    let mut findings = Vec::new();
+    let mut suggestions = Vec::new();
+
+    let sidecar = |extension: &str| {
+        let path = db_path.with_extension(extension);
+        let size = std::fs::metadata(&path).ok().map(|m| m.len());
+        json!({
+            "path": path.display().to_string(),
+            "exists": size.is_some(),
+            "size": size,
+        })
+    };
+
+    let (status, message) = if !db_path.is_file() {
+        findings.push(format!(
+            "The database file '{}' does not exist",
+            db_path.display()
+        ));
+        suggestions.push("Run any am command to create a fresh database".to_string());
+        ("missing", None)
+    } else {
+        match probe_write_lock(db_path) {
+            Ok(()) => {
+                findings.push("No process is currently holding the database lock".to_string());
+                ("free", None)
+            }
+            Err(e) if is_busy(&e) => {
+                if processes.is_empty() {
+                    findings.push("The database is locked by a process other than am".to_string());
+                    suggestions.push(
+                        "Close Amplitude Studio and pause backup or sync agents that may have the database open, then retry"
+                            .to_string(),
+                    );
+                } else {
+                    findings.push(format!(
+                        "The database is locked while {} other am process(es) are running",
+                        processes.len()
+                    ));
+                    suggestions.push(format!(
+                        "Wait for the other am process(es) to finish, or stop them (PID {})",
+                        processes
+                            .iter()
+                            .map(|p| p.pid.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ));
+                }
+                ("held", Some(e.to_string()))
+            }
+            Err(e) => {
+                findings.push(format!("Failed to start a write transaction: {}", e));
+                suggestions.push(
+                    "Check the permissions of the database file and of its directory".to_string(),
+                );
+                ("error", Some(e.to_string()))
+            }
+        }
+    };
+
+    let lock_path = project_root.join(LOCK_FILE);
+    let project_lock = if lock_path.is_file() {
+        let holder = read_lock_holder(project_root);
+        let stale = holder.as_ref().is_some_and(|h| !process_is_alive(h.pid));
+        match &holder {
+            Some(h) if stale => {
+                findings.push(format!(
+                    "The project lock was left behind by '{}' (PID {}), which is no longer running",
+                    h.command, h.pid
+                ));
+                suggestions.push(
+                    "The next command modifying the project reclaims the stale lock".to_string(),
+                );
+            }
+            Some(h) => findings.push(format!(
+                "'{}' (PID {}) holds the project lock since {}",
+                h.command, h.pid, h.acquired_at
+            )),
+            None => findings.push(format!(
+                "The project lock file '{}' can't be read",
+                lock_path.display()
+            )),
+        }
+        json!({
+            "path": lock_path.display().to_string(),
+            "holder": holder,
+            "stale": stale,
+        })
+    } else {
+        serde_json::Value::Null
+    };
+
+    json!({
+        "database": {
+            "path": db_path.display().to_string(),
+            "exists": db_path.is_file(),
+            "wal": sidecar("db-wal"),
+            "shm": sidecar("db-shm"),
+        },
+        "write_lock": {
+            "status": status,
+            "message": message,
+        },
+        "project_lock": project_lock,
+        "processes": processes,
+        "findings": findings,
+        "suggestions": suggestions,
+    })
+}
+
+/// Move the legacy `~/.amplitude` directory of `env` to the XDG base directories.
+///
+/// Log files go to the state directory and everything else, the database included, to
+/// the data directory. Runs with the database closed, and refuses to run while other
+/// `processes` of the CLI may have it open.
+pub fn migrate_home(
+    env: &HomeEnv,
+    processes: &[ProcessInfo],
+    skip_confirmation: bool,
+    input: &dyn Input,
+    output: &dyn Output,
+) -> Result<()> {
+    if env.am_home.is_some() {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            "Cannot migrate the Amplitude home",
+            "AM_HOME is set, so the home directory is not resolved from the XDG base directories",
+        )
+        .with_suggestion("Unset AM_HOME and run 'am sudo migrate-home' again")
+        .into());
+    }
+
+    let (Some(legacy), Some(target)) = (env.legacy_dir(), env.xdg_paths()) else {
+        return Err(anyhow::anyhow!("Could not determine home directory"));
+    };
+
+    if !env.use_xdg {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            "Cannot migrate the Amplitude home",
+            "The XDG base directories are only used on Linux",
+        )
+        .into());
+    }
+
+    if !legacy.is_dir() {
+        output.success(
+            json!(format!(
+                "Nothing to migrate, '{}' does not exist",
+                legacy.display()
+            )),
+            None,
+        );
+        return Ok(());
+    }
+
+    if !processes.is_empty() {
+        return Err(anyhow::anyhow!(
+            "{} other am process(es) are running (PID {}), wait for them to finish before migrating",
+            processes.len(),
+            processes
+                .iter()
+                .map(|p| p.pid.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+
+    // Plan every move first, so nothing is moved when one of them would overwrite a file
+    let mut moves = Vec::new();
+    for entry in fs::read_dir(&legacy)? {
+        let from = entry?.path();
+        let is_log = from.is_file() && from.extension().is_some_and(|e| e == "log");
+        let dir = if is_log {
+            &target.state_dir
+        } else {
+            &target.data_dir
+        };
+        let to = dir.join(from.file_name().unwrap_or_default());
+        moves.push((from, to));
+    }
+    moves.sort();
+
+    let conflicts: Vec<_> = moves.iter().filter(|(_, to)| to.exists()).collect();
+    if let Some((_, to)) = conflicts.first() {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            "Cannot migrate the Amplitude home",
+            format!(
+                "'{}' already exists ({} conflicting path(s))",
+                to.display(),
+                conflicts.len()
+            ),
+        )
+        .with_suggestion("Move or delete the conflicting paths, then run the migration again")
+        .into());
+    }
+
+    output.progress(&format!(
+        "This operation will move '{}' to:",
+        legacy.display()
+    ));
+    output.progress(&format!(
+        "  • {} (database and data)",
+        target.data_dir.display()
+    ));
+    output.progress(&format!("  • {} (logs)", target.state_dir.display()));
+    output.progress("");
+
+    if !skip_confirmation
+        && !with_flag_hint(input, "--yes")
+            .confirm("Do you want to migrate the Amplitude home?", Some(true))?
+    {
+        output.success(json!("Home migration cancelled."), None);
+        return Ok(());
+    }
+
+    fs_ctx::create_dir_all(&target.data_dir)?;
+    fs_ctx::create_dir_all(&target.state_dir)?;
+
+    let mut moved = Vec::new();
+    for (from, to) in &moves {
+        move_path(from, to).map_err(|e| {
+            CliError::new(
+                codes::ERR_BUILD_IO,
+                format!("Failed to move '{}'", from.display()),
+                e.to_string(),
+            )
+            .with_suggestion("Move the remaining files manually, the others were migrated")
+        })?;
+        output.progress(&format!("Moved {}", to.display()));
+        moved.push(to.display().to_string());
+    }
+
+    fs::remove_dir(&legacy)?;
+
+    output.success(
+        json!({
+            "from": legacy.display().to_string(),
+            "data_dir": target.data_dir.display().to_string(),
+            "state_dir": target.state_dir.display().to_string(),
+            "moved": moved,
+        }),
+        None,
+    );
+
+    Ok(())
+}
+
+/// Associate the [`FILE_TYPES`] with the CLI at `exe` through `backend`, or remove
+/// their associations with `unregister`.
+///
+/// With `dry_run` the planned changes are only reported. Failing to refresh the desktop
+/// caches is only warned about, the associations being picked up on the next login.
+pub fn register_file_types(
+    backend: &dyn FileAssociations,
+    exe: &Path,
+    unregister: bool,
+    dry_run: bool,
+    output: &dyn Output,
+) -> Result<()> {
+    let action = if unregister { "unregister" } else { "register" };
+
+    let Some(changes) = backend.plan(exe, FILE_TYPES, unregister) else {
+        output.success(
+            json!({
+                "platform": backend.platform(),
+                "action": action,
+                "dry_run": dry_run,
+                "supported": false,
+                "changes": [],
+                "message": format!(
+                    "File associations are not supported on {}, nothing was changed",
+                    backend.platform()
+                ),
+            }),
+            None,
+        );
+        return Ok(());
+    };
+
+    for change in &changes {
+        if dry_run {
+            output.progress(&format!("Would {}", change));
+            continue;
+        }
+
+        if let Err(e) = backend.apply(change) {
+            if change.is_optional() {
+                output.warning(&format!("Could not {}: {}", change, e));
+                continue;
+            }
+            return Err(CliError::new(
+                codes::ERR_BUILD_IO,
+                format!("Failed to {} the file types", action),
+                format!("Could not {}: {}", change, e),
+            )
+            .with_suggestion(format!(
+                "Run 'am sudo register-file-types{} --dry-run' to see every change",
+                if unregister { " --unregister" } else { "" }
+            ))
+            .into());
+        }
+        output.progress(&format!("Done: {}", change));
+    }
+
+    output.success(
+        json!({
+            "platform": backend.platform(),
+            "action": action,
+            "dry_run": dry_run,
+            "supported": true,
+            "changes": changes.iter().map(|c| c.to_json()).collect::<Vec<_>>(),
+        }),
+        None,
+    );
+
+    Ok(())
+}
+
+/// Move `from` to `to`, copying it when they're on different file systems.
+fn move_path(from: &Path, to: &Path) -> Result<()> {
+    if fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+
+    if from.is_dir() {
+        for entry in walkdir::WalkDir::new(from) {
+            let entry = entry?;
+            let target = to.join(entry.path().strip_prefix(from)?);
+            if entry.file_type().is_dir() {
+                fs_ctx::create_dir_all(&target)?;
+            } else {
+                fs_ctx::copy(entry.path(), &target)?;
+            }
+        }
+    } else {
+        fs_ctx::copy(from, to)?;
+    }
+    safe_remove_in_parent(from)?;
+
+    Ok(())
+}
+
+/// Start and roll back a write transaction on a dedicated connection.
+fn probe_write_lock(db_path: &Path) -> rusqlite::Result<()> {
+    let conn = Connection::open_with_flags(db_path, OpenFlags::SQLITE_OPEN_READ_WRITE)?;
+    conn.busy_timeout(WRITE_PROBE_TIMEOUT)?;
+    conn.execute_batch("BEGIN IMMEDIATE; ROLLBACK;")
+}
+
+fn is_busy(error: &rusqlite::Error) -> bool {
+    matches!(
+        error.sqlite_error_code(),
+        Some(ErrorCode::DatabaseBusy | ErrorCode::DatabaseLocked)
+    )
+}
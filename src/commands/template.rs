@@ -22,15 +22,27 @@ use crate::{
     app::Resource,
     common::{
         errors::{CliError, codes},
-        utils::{truncate_string_at_word, validate_template_directory, validate_template_name},
+        files::safe_remove_within,
+        hashing::VerifyReport,
+        ignore::WalkOptions,
+        paths,
+        template_archive::{
+            TEMPLATE_ARCHIVE_EXTENSION, check_archive_cli_version, read_template_archive,
+            verify_template_archive, write_template_archive,
+        },
+        utils::{
+            check_name_characters, check_name_length, check_name_not_reserved, check_name_present,
+            transform_name, truncate_string_at_word, utf8_path, validate_template_directory,
+            validate_template_name,
+        },
     },
     database::{
         Database, db_create_template, db_delete_template_by_name, db_get_template_by_name,
-        db_get_templates,
+        db_get_templates, db_update_template,
         entities::{Template, TemplateSource},
     },
-    input::Input,
-    presentation::{Output, OutputMode},
+    input::{Input, to_validation, with_flag_hint},
+    presentation::{ColumnSpec, Output, OutputMode},
 };
 use clap::Subcommand;
 
@@ -79,20 +91,15 @@ impl EmbeddedTemplate {
 #[derive(Subcommand, Debug)]
 pub enum TemplateCommands {
     /// List all available templates
-    #[command(after_help = "Examples:\n  am template list\n")]
     List {},
 
     /// Display detailed information about a template
-    #[command(after_help = "Examples:\n  am template info default\n")]
     Info {
         /// Name of the template to display
         name: String,
     },
 
     /// Register a custom template from a directory
-    #[command(
-        after_help = "Examples:\n  am template register /path/to/template --name my_template\n"
-    )]
     Register {
         /// Path to the template directory
         path: String,
@@ -106,8 +113,18 @@ pub enum TemplateCommands {
         force: bool,
     },
 
+    /// Register every template found in the subdirectories of a directory
+    Add {
+        /// Directory whose immediate subdirectories are candidate templates
+        #[arg(long)]
+        scan: PathBuf,
+
+        /// Unregister the templates of the scanned directory whose subdirectory is gone
+        #[arg(long, default_value = "false")]
+        prune_missing: bool,
+    },
+
     /// Unregister a custom template
-    #[command(after_help = "Examples:\n  am template unregister my_template\n")]
     Unregister {
         /// Name of the template to unregister
         name: String,
@@ -116,10 +133,67 @@ pub enum TemplateCommands {
         #[arg(short, long, default_value = "false")]
         force: bool,
     },
+
+    /// Update the path of a custom template, optionally renaming it
+    Update {
+        /// Name of the template to update
+        #[arg(required_unless_present = "revalidate_all")]
+        name: Option<String>,
+
+        /// New path to the template directory
+        #[arg(short, long, required_unless_present = "revalidate_all")]
+        path: Option<String>,
+
+        /// New name for the template
+        #[arg(long)]
+        rename: Option<String>,
+
+        /// Check every registered template and report the broken ones
+        #[arg(long, default_value = "false", conflicts_with_all = ["name", "path", "rename"])]
+        revalidate_all: bool,
+    },
+
+    /// Package a custom template into a shareable .amtpl archive
+    Export {
+        /// Name of the template to export
+        name: String,
+
+        /// Path of the archive (defaults to <name>.amtpl in the current directory)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Overwrite an existing archive
+        #[arg(short, long, default_value = "false")]
+        force: bool,
+
+        /// Read the archive back and check the checksum of every template file
+        #[arg(long)]
+        verify: bool,
+    },
+
+    /// Import and register a template from a .amtpl archive
+    Import {
+        /// Path of the archive created by 'am template export'
+        archive: PathBuf,
+
+        /// Template name (defaults to the name stored in the archive)
+        #[arg(short, long)]
+        name: Option<String>,
+
+        /// Overwrite an existing template with the same name
+        #[arg(short, long, default_value = "false")]
+        force: bool,
+
+        /// Check the checksum of every extracted file
+        #[arg(long)]
+        verify: bool,
+    },
 }
 
 pub async fn handler(
     command: &TemplateCommands,
+    _project: Option<&Path>,
+    _walk: WalkOptions,
     database: Option<Arc<Database>>,
     input: &dyn Input,
     output: &dyn Output,
@@ -133,9 +207,64 @@ pub async fn handler(
             handle_template_register_command(path, name.clone(), *force, database, input, output)
                 .await
         }
+        TemplateCommands::Add {
+            scan,
+            prune_missing,
+        } => handle_template_scan_command(scan, *prune_missing, database, output).await,
         TemplateCommands::Unregister { name, force } => {
             handle_template_unregister_command(name, *force, database, input, output).await
         }
+        TemplateCommands::Update {
+            name,
+            path,
+            rename,
+            revalidate_all,
+        } => {
+            if *revalidate_all {
+                return handle_template_revalidate_all_command(database, output).await;
+            }
+
+            match (name, path) {
+                (Some(name), Some(path)) => {
+                    handle_template_update_command(name, path, rename.as_deref(), database, output)
+                        .await
+                }
+                _ => Err(CliError::new(
+                    codes::ERR_VALIDATION_FIELD,
+                    "Template name and path are required",
+                    "No template name or --path provided, and --revalidate-all is not set",
+                )
+                .with_suggestion("Run 'am template update <name> --path <new-path>'")
+                .into()),
+            }
+        }
+        TemplateCommands::Export {
+            name,
+            output: archive,
+            force,
+            verify,
+        } => handle_template_export_command(
+            name,
+            archive.as_deref(),
+            *force,
+            *verify,
+            database,
+            output,
+        ),
+        TemplateCommands::Import {
+            archive,
+            name,
+            force,
+            verify,
+        } => handle_template_import_command(
+            archive,
+            name.as_deref(),
+            *force,
+            *verify,
+            &paths::resolve()?.templates_dir(),
+            database,
+            output,
+        ),
     }
 }
 
@@ -440,19 +569,7 @@ async fn handle_template_register_command(
     output: &dyn Output,
 ) -> Result<()> {
     // Step 1: Normalize and validate the path
-    let template_path = PathBuf::from(path);
-    let normalized_path = template_path
-        .canonicalize()
-        .map_err(|_| {
-            CliError::new(
-                codes::ERR_INVALID_TEMPLATE_STRUCTURE,
-                format!("Template path '{}' does not exist", path),
-                "The specified path does not exist on the filesystem",
-            )
-            .with_suggestion("Verify the path is correct and the directory exists")
-        })?
-        .to_string_lossy()
-        .to_string();
+    let normalized_path = normalize_template_path(path)?;
 
     // Step 2: Validate template structure (checks for required files)
     let validation_result = validate_template_directory(Path::new(&normalized_path))?;
@@ -474,25 +591,8 @@ async fn handle_template_register_command(
         prompt_for_template_name(input, output)?
     };
 
-    validate_template_name(&template_name).map_err(|msg| {
-        CliError::new(
-            codes::ERR_VALIDATION_FIELD,
-            format!("Invalid template name '{}'", template_name),
-            msg,
-        )
-        .with_suggestion("Use only letters, numbers, underscores, and hyphens")
-    })?;
-
-    // Step 4: Check for name conflict with embedded templates
-    if EMBEDDED_TEMPLATES.iter().any(|t| t.name == template_name) {
-        return Err(CliError::new(
-            codes::ERR_TEMPLATE_NAME_CONFLICT,
-            format!("Template '{}' is a built-in template", template_name),
-            "Cannot overwrite embedded templates",
-        )
-        .with_suggestion("Choose a different name for your custom template")
-        .into());
-    }
+    // Step 4: Validate the name and check for conflicts with embedded templates
+    validate_custom_template_name(&template_name)?;
 
     // Step 5: Check for name conflict with existing custom templates
     if let Some(existing) = db_get_template_by_name(&template_name, database.clone())? {
@@ -566,6 +666,197 @@ async fn handle_template_register_command(
     Ok(())
 }
 
+/// Outcome of `am template add --scan` for one directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScanStatus {
+    Registered,
+    Skipped,
+    Invalid,
+    Pruned,
+}
+
+impl ScanStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            ScanStatus::Registered => "registered",
+            ScanStatus::Skipped => "skipped",
+            ScanStatus::Invalid => "invalid",
+            ScanStatus::Pruned => "pruned",
+        }
+    }
+}
+
+/// Handle the `am template add --scan <dir>` command.
+///
+/// Every immediate subdirectory of `scan`, hidden ones aside, is a candidate template
+/// named after the directory with [`transform_name`] applied. Valid candidates are
+/// registered one by one, so an invalid directory or an already registered name only
+/// skips that directory. With `prune_missing`, templates registered from a subdirectory
+/// of `scan` that no longer exists are unregistered.
+async fn handle_template_scan_command(
+    scan: &Path,
+    prune_missing: bool,
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> Result<()> {
+    let root = normalize_template_path(&scan.to_string_lossy())?;
+    let root = PathBuf::from(root);
+    if !root.is_dir() {
+        return Err(CliError::new(
+            codes::ERR_INVALID_TEMPLATE_STRUCTURE,
+            format!("'{}' is not a directory", scan.display()),
+            "The scanned path must be a directory containing one template per subdirectory",
+        )
+        .into());
+    }
+
+    let mut directories: Vec<PathBuf> = std::fs::read_dir(&root)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .filter(|path| {
+            path.file_name()
+                .is_some_and(|name| !name.to_string_lossy().starts_with('.'))
+        })
+        .collect();
+    directories.sort();
+
+    let mut results = Vec::with_capacity(directories.len());
+    for (index, directory) in directories.iter().enumerate() {
+        output.progress_with("Scanning templates", index + 1, directories.len());
+        let (name, status, reason) = register_scanned_template(directory, database.clone())?;
+        results.push(json!({
+            "directory": directory.file_name().map(|n| n.to_string_lossy().into_owned()),
+            "name": name,
+            "status": status.as_str(),
+            "reason": reason,
+        }));
+    }
+
+    if prune_missing {
+        for template in db_get_templates(database.clone())? {
+            let path = Path::new(&template.path);
+            if path.parent() != Some(root.as_path()) || path.exists() {
+                continue;
+            }
+            db_delete_template_by_name(&template.name, database.clone())?;
+            results.push(json!({
+                "directory": path.file_name().map(|n| n.to_string_lossy().into_owned()),
+                "name": template.name,
+                "status": ScanStatus::Pruned.as_str(),
+                "reason": "Directory no longer exists",
+            }));
+        }
+    }
+
+    let count = |status: ScanStatus| {
+        results
+            .iter()
+            .filter(|r| r["status"] == status.as_str())
+            .count()
+    };
+    let counts = json!({
+        "registered": count(ScanStatus::Registered),
+        "skipped": count(ScanStatus::Skipped),
+        "invalid": count(ScanStatus::Invalid),
+        "pruned": count(ScanStatus::Pruned),
+    });
+
+    match output.mode() {
+        OutputMode::Json => {
+            output.success(
+                json!({
+                    "root": utf8_path(&root)?,
+                    "results": results,
+                    "counts": counts,
+                }),
+                None,
+            );
+        }
+        OutputMode::Interactive => {
+            if results.is_empty() {
+                output.success(
+                    json!(format!(
+                        "No template directories found in {}",
+                        root.display()
+                    )),
+                    None,
+                );
+                return Ok(());
+            }
+
+            let columns = [
+                ColumnSpec::new("directory", "Directory"),
+                ColumnSpec::new("name", "Name"),
+                ColumnSpec::new("status", "Status"),
+                ColumnSpec::new("reason", "Reason"),
+            ];
+            output.table_with_columns(Some("Template scan"), &columns, json!(results));
+            output.success(
+                json!(format!(
+                    "Registered {} template(s), skipped {}, {} invalid, pruned {}",
+                    counts["registered"], counts["skipped"], counts["invalid"], counts["pruned"]
+                )),
+                None,
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate and register the template in `directory`, returning its name, the outcome
+/// and the reason for skipping it.
+fn register_scanned_template(
+    directory: &Path,
+    database: Option<Arc<Database>>,
+) -> Result<(String, ScanStatus, Option<String>)> {
+    let directory_name = directory
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let name = transform_name(&directory_name);
+
+    let reason_of = |e: anyhow::Error| match e.downcast_ref::<CliError>() {
+        Some(cli_err) => cli_err.what.clone(),
+        None => e.to_string(),
+    };
+
+    if let Err(e) = validate_custom_template_name(&name) {
+        return Ok((name, ScanStatus::Invalid, Some(reason_of(e))));
+    }
+    let validation_result = match validate_template_directory(directory) {
+        Ok(result) => result,
+        Err(e) => return Ok((name, ScanStatus::Invalid, Some(reason_of(e)))),
+    };
+    if db_get_template_by_name(&name, database.clone())?.is_some() {
+        return Ok((
+            name,
+            ScanStatus::Skipped,
+            Some("Already registered".to_string()),
+        ));
+    }
+
+    let template = Template {
+        id: None,
+        name: name.clone(),
+        path: utf8_path(directory)?.to_string(),
+        engine: validation_result
+            .manifest
+            .as_ref()
+            .and_then(|m| m.engine.clone())
+            .or(Some("generic".to_string())),
+        description: validation_result
+            .manifest
+            .as_ref()
+            .and_then(|m| m.description.clone()),
+        source: TemplateSource::Custom,
+    };
+    db_create_template(&template, database)?;
+
+    Ok((name, ScanStatus::Registered, None))
+}
+
 /// Handle the `am template unregister <name>` command.
 ///
 /// Unregisters a custom template from the database.
@@ -603,8 +894,8 @@ async fn handle_template_unregister_command(
     // Step 3: Handle confirmation
     if !force {
         // Try to get confirmation from user
-        let confirm_result =
-            input.confirm(&format!("Unregister template '{}'?", name), Some(false));
+        let confirm_result = with_flag_hint(input, "--force")
+            .confirm(&format!("Unregister template '{}'?", name), Some(false));
 
         match confirm_result {
             Ok(confirmed) => {
@@ -685,6 +976,451 @@ async fn handle_template_unregister_command(
     Ok(())
 }
 
+/// Canonicalize a template directory path given on the command line.
+fn normalize_template_path(path: &str) -> Result<String> {
+    let normalized_path = PathBuf::from(path).canonicalize().map_err(|_| {
+        CliError::new(
+            codes::ERR_INVALID_TEMPLATE_STRUCTURE,
+            format!("Template path '{}' does not exist", path),
+            "The specified path does not exist on the filesystem",
+        )
+        .with_suggestion("Verify the path is correct and the directory exists")
+    })?;
+
+    Ok(utf8_path(&normalized_path)?.to_string())
+}
+
+/// Check that `name` is a valid name for a custom template and doesn't shadow an
+/// embedded template.
+fn validate_custom_template_name(name: &str) -> Result<()> {
+    validate_template_name(name).map_err(|msg| {
+        CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            format!("Invalid template name '{}'", name),
+            msg,
+        )
+        .with_suggestion("Use only letters, numbers, underscores, and hyphens")
+    })?;
+
+    if EMBEDDED_TEMPLATES.iter().any(|t| t.name == name) {
+        return Err(CliError::new(
+            codes::ERR_TEMPLATE_NAME_CONFLICT,
+            format!("Template '{}' is a built-in template", name),
+            "Cannot overwrite embedded templates",
+        )
+        .with_suggestion("Choose a different name for your custom template")
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Handle the `am template update <name> --path <new-path>` command.
+///
+/// Points a custom template to a new directory, optionally renaming it. The new directory
+/// goes through the same validation as `am template register`, and the template keeps its
+/// creation date and metadata.
+async fn handle_template_update_command(
+    name: &str,
+    path: &str,
+    rename: Option<&str>,
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> Result<()> {
+    // Step 1: Embedded templates have no path to update
+    if EMBEDDED_TEMPLATES.iter().any(|t| t.name == name) {
+        return Err(CliError::new(
+            codes::ERR_TEMPLATE_OPERATION_NOT_ALLOWED,
+            format!("Cannot update embedded template '{}'", name),
+            "Embedded templates are bundled with the CLI and cannot be modified",
+        )
+        .into());
+    }
+
+    // Step 2: Check if template exists in database
+    let Some(existing) = db_get_template_by_name(name, database.clone())? else {
+        return Err(CliError::new(
+            codes::ERR_TEMPLATE_NOT_FOUND,
+            format!("Template '{}' not found", name),
+            "No registered template matches this name",
+        )
+        .with_suggestion("Register it with 'am template register <path>'")
+        .into());
+    };
+
+    // Step 3: Normalize and validate the new path
+    let normalized_path = normalize_template_path(path)?;
+    validate_template_directory(Path::new(&normalized_path))?;
+
+    // Step 4: Validate the new name, if any
+    let new_name = rename.unwrap_or(name);
+    if new_name != name {
+        validate_custom_template_name(new_name)?;
+    }
+
+    // Step 5: Update the database
+    if !db_update_template(name, new_name, &normalized_path, database)? {
+        // Should be unreachable if step 2 succeeded, but handle safely
+        return Err(CliError::new(
+            codes::ERR_TEMPLATE_NOT_FOUND,
+            format!("Template '{}' not found", name),
+            "Template may have been removed by another process",
+        )
+        .into());
+    }
+
+    // Step 6: Output success
+    match output.mode() {
+        OutputMode::Json => {
+            output.success(
+                json!({
+                    "name": new_name,
+                    "previous_name": existing.name,
+                    "path": normalized_path,
+                    "previous_path": existing.path
+                }),
+                None,
+            );
+        }
+        OutputMode::Interactive => {
+            output.success(
+                json!(format!("Template '{}' updated successfully.", new_name)),
+                None,
+            );
+            output.progress("");
+            if new_name != name {
+                output.progress(&format!("  Name:  {} → {}", name, new_name));
+            }
+            output.progress(&format!("  Path:  {} → {}", existing.path, normalized_path));
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle the `am template update --revalidate-all` command.
+///
+/// Validates the directory of every registered template and reports all broken templates
+/// at once, failing with `ERR_INVALID_TEMPLATE_STRUCTURE` if any is found.
+async fn handle_template_revalidate_all_command(
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> Result<()> {
+    let templates = db_get_templates(database)?;
+
+    let broken: Vec<(Template, String)> = templates
+        .iter()
+        .filter_map(|t| {
+            validate_template_directory(Path::new(&t.path))
+                .err()
+                .map(|e| {
+                    let reason = match e.downcast_ref::<CliError>() {
+                        Some(cli_err) => cli_err.what.clone(),
+                        None => e.to_string(),
+                    };
+                    (t.clone(), reason)
+                })
+        })
+        .collect();
+
+    match output.mode() {
+        OutputMode::Json => {
+            let broken_data: Vec<serde_json::Value> = broken
+                .iter()
+                .map(|(t, reason)| {
+                    json!({
+                        "name": t.name,
+                        "path": t.path,
+                        "error": reason
+                    })
+                })
+                .collect();
+
+            output.success(
+                json!({
+                    "valid": broken.is_empty(),
+                    "checked": templates.len(),
+                    "broken": broken_data
+                }),
+                None,
+            );
+        }
+        OutputMode::Interactive => {
+            if broken.is_empty() {
+                output.success(
+                    json!(format!(
+                        "All {} registered template(s) are valid.",
+                        templates.len()
+                    )),
+                    None,
+                );
+            } else {
+                output.progress(&format!(
+                    "{} {} of {} registered template(s) are broken\n",
+                    "✗".red(),
+                    broken.len(),
+                    templates.len()
+                ));
+
+                for (t, reason) in &broken {
+                    output.progress(&format!("  {} {}", "Template:".red().bold(), t.name));
+                    output.progress(&format!("    Path:  {}", t.path));
+                    output.progress(&format!("    Error: {}", reason));
+                    output.progress("");
+                }
+            }
+        }
+    }
+
+    if !broken.is_empty() {
+        return Err(CliError::new(
+            codes::ERR_INVALID_TEMPLATE_STRUCTURE,
+            format!("{} registered template(s) are broken", broken.len()),
+            "Their directories were moved or no longer contain a valid template",
+        )
+        .with_suggestion(
+            "Point them to their new location with 'am template update <name> --path <new-path>'",
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Handle the `am template export <name>` command.
+///
+/// Packages the directory of a custom template, with a manifest holding its name, the
+/// CLI version and a checksum, into a `.amtpl` archive. With `verify`, the archive is read
+/// back and removed if it doesn't hold the template files unchanged.
+fn handle_template_export_command(
+    name: &str,
+    archive: Option<&Path>,
+    force: bool,
+    verify: bool,
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> Result<()> {
+    // Step 1: Embedded templates ship with every CLI
+    if EMBEDDED_TEMPLATES.iter().any(|t| t.name == name) {
+        return Err(CliError::new(
+            codes::ERR_TEMPLATE_OPERATION_NOT_ALLOWED,
+            format!("Cannot export embedded template '{}'", name),
+            "Embedded templates are bundled with every CLI",
+        )
+        .into());
+    }
+
+    // Step 2: Check if template exists in database
+    let Some(template) = db_get_template_by_name(name, database)? else {
+        return Err(CliError::new(
+            codes::ERR_TEMPLATE_NOT_FOUND,
+            format!("Template '{}' not found", name),
+            "No registered template matches this name",
+        )
+        .with_suggestion("Use 'am template list' to see available templates")
+        .into());
+    };
+
+    // Step 3: Only export templates that could be registered again
+    validate_template_directory(Path::new(&template.path))?;
+
+    // Step 4: Refuse to overwrite an existing archive
+    let archive = archive
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| PathBuf::from(format!("{}.{}", name, TEMPLATE_ARCHIVE_EXTENSION)));
+    if archive.exists() && !force {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            format!("'{}' already exists", archive.display()),
+            "Exporting would overwrite an existing file",
+        )
+        .with_suggestion("Choose another --output or use --force to overwrite it")
+        .into());
+    }
+
+    // Step 5: Write the archive
+    let manifest = write_template_archive(
+        Path::new(&template.path),
+        &template.name,
+        template.engine.as_deref(),
+        template.description.as_deref(),
+        &archive,
+    )?;
+
+    // Step 6: Read it back
+    let verified = if verify {
+        let verified = verify_template_archive(&archive, Path::new(&template.path))
+            .and_then(|report| report.into_result(&archive).map_err(Into::into));
+        if verified.is_err() {
+            let _ = std::fs::remove_file(&archive);
+        }
+        Some(verified?)
+    } else {
+        None
+    };
+
+    // Step 7: Output success
+    match output.mode() {
+        OutputMode::Json => {
+            output.success(
+                json!({
+                    "name": manifest.name,
+                    "archive": archive.display().to_string(),
+                    "cli_version": manifest.cli_version,
+                    "checksum": manifest.checksum,
+                    "verified": verified.as_ref().map(VerifyReport::to_json),
+                }),
+                None,
+            );
+        }
+        OutputMode::Interactive => {
+            output.success(
+                json!(format!(
+                    "Template '{}' exported to {}.",
+                    name,
+                    archive.display()
+                )),
+                None,
+            );
+            if let Some(report) = &verified {
+                output.progress(&format!(
+                    "Verified {} file(s) ({} bytes) in the archive.",
+                    report.files, report.bytes
+                ));
+            }
+            output.progress("");
+            output.progress(&format!(
+                "Import it with '{}'.",
+                format!("am template import {}", archive.display()).green()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle the `am template import <archive>` command.
+///
+/// Verifies the archive checksum and CLI version, extracts the template into
+/// `templates_dir/<name>` and registers it. With `verify`, the extracted files are
+/// checked against the archive before the template is registered.
+pub fn handle_template_import_command(
+    archive: &Path,
+    name: Option<&str>,
+    force: bool,
+    verify: bool,
+    templates_dir: &Path,
+    database: Option<Arc<Database>>,
+    output: &dyn Output,
+) -> Result<()> {
+    // Step 1: Read and verify the archive
+    let template_archive = read_template_archive(archive)?;
+    let manifest = &template_archive.manifest;
+    check_archive_cli_version(manifest, env!("CARGO_PKG_VERSION"))?;
+
+    // Step 2: Validate the name and check for conflicts with embedded templates
+    let template_name = name.unwrap_or(&manifest.name);
+    validate_custom_template_name(template_name)?;
+
+    // Step 3: Check for conflicts with existing custom templates and directories
+    let existing = db_get_template_by_name(template_name, database.clone())?;
+    let destination = templates_dir.join(template_name);
+    if !force && (existing.is_some() || destination.exists()) {
+        return Err(CliError::new(
+            codes::ERR_TEMPLATE_NAME_CONFLICT,
+            format!("Template '{}' already exists", template_name),
+            if existing.is_some() {
+                "A custom template with this name is already registered"
+            } else {
+                "The template directory already exists"
+            },
+        )
+        .with_context(destination.display().to_string())
+        .with_suggestion("Use --name to import it under another name or --force to overwrite")
+        .into());
+    }
+
+    // Step 4: Extract the template and check it is usable
+    if destination.exists() {
+        safe_remove_within(templates_dir, &destination)?;
+    }
+    template_archive.extract(&destination)?;
+    let verified = if verify {
+        match template_archive
+            .verify_extracted(&destination)
+            .into_result(&destination)
+        {
+            Ok(report) => Some(report),
+            Err(e) => {
+                let _ = safe_remove_within(templates_dir, &destination);
+                return Err(e.into());
+            }
+        }
+    } else {
+        None
+    };
+    if let Err(e) = validate_template_directory(&destination) {
+        let _ = safe_remove_within(templates_dir, &destination);
+        return Err(e);
+    }
+    let template_path = utf8_path(&destination.canonicalize()?)?.to_string();
+
+    // Step 5: Register it, replacing the previous registration
+    if let Some(existing) = existing {
+        db_delete_template_by_name(&existing.name, database.clone())?;
+    }
+    db_create_template(
+        &Template {
+            id: None,
+            name: template_name.to_string(),
+            path: template_path.clone(),
+            engine: manifest.engine.clone().or(Some("generic".to_string())),
+            description: manifest.description.clone(),
+            source: TemplateSource::Custom,
+        },
+        database,
+    )?;
+
+    // Step 6: Output success
+    match output.mode() {
+        OutputMode::Json => {
+            output.success(
+                json!({
+                    "name": template_name,
+                    "path": template_path,
+                    "files": template_archive.file_count(),
+                    "cli_version": manifest.cli_version,
+                    "checksum": manifest.checksum,
+                    "verified": verified.as_ref().map(VerifyReport::to_json),
+                }),
+                None,
+            );
+        }
+        OutputMode::Interactive => {
+            output.success(
+                json!(format!(
+                    "Template '{}' imported into {}.",
+                    template_name, template_path
+                )),
+                None,
+            );
+            if let Some(report) = &verified {
+                output.progress(&format!(
+                    "Verified {} file(s) ({} bytes) extracted from the archive.",
+                    report.files, report.bytes
+                ));
+            }
+            output.progress("");
+            output.progress(&format!(
+                "Use '{}' to create a project from it.",
+                format!("am project init --template {}", template_name).green()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 /// Prompt for template name in interactive mode.
 ///
 /// Returns an error in non-interactive mode when name is required.
@@ -694,15 +1430,17 @@ fn prompt_for_template_name(input: &dyn Input, _output: &dyn Output) -> Result<S
     // Attempt to prompt for template name.
     // If input is NonInteractiveInput (due to --json or --non-interactive flags),
     // the prompt will fail. We catch that and return a more helpful error.
+    let present = |value: &str| to_validation(check_name_present(value, "template"));
+    let characters = |value: &str| to_validation(check_name_characters(value, false, "template"));
+    let not_reserved = |value: &str| to_validation(check_name_not_reserved(value, "template"));
+    let length = |value: &str| to_validation(check_name_length(value, "template"));
+
     input
         .prompt_text(
             "Template name",
             None,
             None,
-            Some(&|value: &str| match validate_template_name(value) {
-                Ok(()) => Ok(inquire::validator::Validation::Valid),
-                Err(msg) => Ok(inquire::validator::Validation::Invalid(msg.into())),
-            }),
+            &[&present, &characters, &not_reserved, &length],
         )
         .map_err(|_| {
             // Convert the generic "blocked" error to a more helpful CliError
@@ -0,0 +1,163 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Opening files in the user's editor.
+//!
+//! The editor is the command of the `VISUAL` environment variable, then of `EDITOR`, like
+//! for git and most Unix tools. Their value may carry arguments (e.g. `code --wait`).
+//! Without either, the file is handed to the opener of the platform: `open -t` on macOS,
+//! `start` on Windows and `xdg-open` elsewhere.
+
+use std::fmt;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::Result;
+
+use crate::common::errors::{CliError, codes};
+
+/// Environment variables naming the editor, in order of precedence.
+pub const EDITOR_VARIABLES: &[&str] = &["VISUAL", "EDITOR"];
+
+/// The command opening a file in an editor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EditorCommand {
+    /// Program to run.
+    pub program: String,
+    /// Arguments given before the path of the file.
+    pub args: Vec<String>,
+    /// Whether the command is the opener of the platform rather than a configured editor.
+    pub platform_opener: bool,
+}
+
+impl EditorCommand {
+    /// The editor named by the first of [`EDITOR_VARIABLES`] that `var` returns a
+    /// non-blank value for, or the opener of the platform.
+    ///
+    /// With `wait`, the platform opener is asked to return once the file is closed, which
+    /// `xdg-open` can't do: it returns as soon as the file is handed over.
+    pub fn resolve(var: impl Fn(&str) -> Option<String>, wait: bool) -> Self {
+        let configured = EDITOR_VARIABLES
+            .iter()
+            .filter_map(|name| var(name))
+            .find(|value| !value.trim().is_empty());
+
+        match configured {
+            Some(value) => {
+                let mut words = value.split_whitespace().map(str::to_string);
+                let program = words.next().unwrap_or_default();
+                Self {
+                    program,
+                    args: words.collect(),
+                    platform_opener: false,
+                }
+            }
+            None => Self::platform_opener(wait),
+        }
+    }
+
+    /// The editor of the environment of the current process.
+    pub fn from_env(wait: bool) -> Self {
+        Self::resolve(|name| std::env::var(name).ok(), wait)
+    }
+
+    #[cfg(target_os = "macos")]
+    fn platform_opener(wait: bool) -> Self {
+        let mut args = vec!["-t".to_string()];
+        if wait {
+            args.push("-W".to_string());
+        }
+        Self {
+            program: "open".to_string(),
+            args,
+            platform_opener: true,
+        }
+    }
+
+    #[cfg(windows)]
+    fn platform_opener(wait: bool) -> Self {
+        // `start` takes the window title as its first quoted argument, empty here
+        let mut args = vec!["/C".to_string(), "start".to_string(), String::new()];
+        if wait {
+            args.push("/WAIT".to_string());
+        }
+        Self {
+            program: "cmd".to_string(),
+            args,
+            platform_opener: true,
+        }
+    }
+
+    #[cfg(not(any(target_os = "macos", windows)))]
+    fn platform_opener(_wait: bool) -> Self {
+        Self {
+            program: "xdg-open".to_string(),
+            args: Vec::new(),
+            platform_opener: true,
+        }
+    }
+
+    /// Start the editor on `file`, and with `wait` wait for it to exit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ERR_EDITOR_FAILED` error when the editor can't be started, or exits
+    /// with a failure status while waited for.
+    pub fn open(&self, file: &Path, wait: bool) -> Result<()> {
+        let mut child = Command::new(&self.program)
+            .args(&self.args)
+            .arg(file)
+            .spawn()
+            .map_err(|e| {
+                CliError::new(
+                    codes::ERR_EDITOR_FAILED,
+                    format!("Failed to start the editor '{}'", self.program),
+                    e.to_string(),
+                )
+                .with_context(self.to_string())
+            })?;
+
+        if !wait {
+            return Ok(());
+        }
+
+        let status = child.wait()?;
+        if !status.success() {
+            let exit = match status.code() {
+                Some(code) => format!("exit code {}", code),
+                None => "terminated by a signal".to_string(),
+            };
+
+            return Err(CliError::new(
+                codes::ERR_EDITOR_FAILED,
+                format!("The editor '{}' failed", self.program),
+                format!("It exited with {} while editing {}", exit, file.display()),
+            )
+            .with_context(self.to_string())
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for EditorCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.program)?;
+        for arg in &self.args {
+            write!(f, " {}", arg)?;
+        }
+        Ok(())
+    }
+}
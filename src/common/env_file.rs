@@ -0,0 +1,236 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Project environment files.
+//!
+//! Projects can contain an `.amenv` file at their root defining environment variables
+//! for the scripts run on their behalf (hooks), e.g. asset server URLs or platform
+//! toggles. `am project env` prints them.
+//!
+//! # Syntax
+//! - One `KEY=VALUE` per line. Keys are made of ASCII letters, digits and `_`, and don't
+//!   start with a digit. Whitespace around the key and the `=` is ignored
+//! - Blank lines and lines starting with `#` are ignored
+//! - A leading `export ` is accepted, so the file can be sourced by a shell
+//! - Unquoted values end at a ` #` comment, and their trailing whitespace is dropped
+//! - Values in single quotes are taken literally
+//! - Values in double quotes can contain `#` and the escapes `\n`, `\r`, `\t`, `\"`
+//!   and `\\`
+//!
+//! Files with CRLF line endings are accepted. Malformed lines are skipped and reported
+//! with their line number; when a key is defined twice, the last definition wins.
+
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use anyhow::Context;
+
+/// Name of the environment file at the project root.
+pub const AMENV_FILE: &str = ".amenv";
+
+/// Words which, as a part of a key, mark its value as a secret.
+pub const SECRET_KEY_PARTS: &[&str] = &[
+    "SECRET",
+    "TOKEN",
+    "PASSWORD",
+    "PASSWD",
+    "PASS",
+    "KEY",
+    "APIKEY",
+    "CREDENTIAL",
+    "CREDENTIALS",
+    "AUTH",
+    "PRIVATE",
+];
+
+/// Replacement of secret values printed with `--mask`.
+pub const MASKED_VALUE: &str = "********";
+
+/// A variable defined by an environment file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnvVar {
+    pub key: String,
+    pub value: String,
+    /// Line of the definition, starting at 1.
+    pub line: usize,
+}
+
+/// A line of an environment file that couldn't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnvFileIssue {
+    /// Line number, starting at 1.
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for EnvFileIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} line {}: {}", AMENV_FILE, self.line, self.message)
+    }
+}
+
+/// The content of an environment file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EnvFile {
+    /// Variables in the order of the file, a key appearing only once.
+    pub vars: Vec<EnvVar>,
+    pub issues: Vec<EnvFileIssue>,
+}
+
+impl EnvFile {
+    /// Read the `.amenv` file of the project at `project_root`, which is empty when the
+    /// project has none.
+    pub fn load(project_root: &Path) -> anyhow::Result<Self> {
+        let path = project_root.join(AMENV_FILE);
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Ok(Self::parse(&content))
+    }
+
+    /// Parse the content of an environment file.
+    pub fn parse(content: &str) -> Self {
+        let mut file = Self::default();
+
+        for (index, line) in content.lines().enumerate() {
+            let line_number = index + 1;
+            match parse_line(line) {
+                Ok(None) => {}
+                Ok(Some((key, value))) => {
+                    file.vars.retain(|var| var.key != key);
+                    file.vars.push(EnvVar {
+                        key,
+                        value,
+                        line: line_number,
+                    });
+                }
+                Err(message) => file.issues.push(EnvFileIssue {
+                    line: line_number,
+                    message,
+                }),
+            }
+        }
+
+        file
+    }
+
+    /// The variables as `(key, value)` pairs, e.g. for [`std::process::Command::envs`].
+    pub fn pairs(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.vars
+            .iter()
+            .map(|var| (var.key.as_str(), var.value.as_str()))
+    }
+}
+
+/// Whether the value of `key` looks like a secret, i.e. one of the `_`-separated parts
+/// of the key is one of [`SECRET_KEY_PARTS`] (e.g. `ASSET_SERVER_TOKEN`).
+pub fn is_secret_key(key: &str) -> bool {
+    key.split('_').any(|part| {
+        SECRET_KEY_PARTS
+            .iter()
+            .any(|secret| part.eq_ignore_ascii_case(secret))
+    })
+}
+
+/// Parse one line into its key and value, or `None` for blank and comment lines.
+fn parse_line(line: &str) -> Result<Option<(String, String)>, String> {
+    // Trimming also drops the `\r` of CRLF line endings
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return Ok(None);
+    }
+
+    let line = line
+        .strip_prefix("export")
+        .filter(|rest| rest.starts_with([' ', '\t']))
+        .map_or(line, str::trim_start);
+
+    let Some((key, value)) = line.split_once('=') else {
+        return Err(format!("expected KEY=VALUE, found '{}'", line));
+    };
+
+    let key = key.trim_end();
+    if !is_valid_key(key) {
+        return Err(format!(
+            "'{}' is not a valid variable name (use letters, digits and '_')",
+            key
+        ));
+    }
+
+    Ok(Some((key.to_string(), parse_value(value.trim_start())?)))
+}
+
+fn is_valid_key(key: &str) -> bool {
+    let mut chars = key.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn parse_value(raw: &str) -> Result<String, String> {
+    let (value, rest) = if let Some(quoted) = raw.strip_prefix('\'') {
+        let end = quoted
+            .find('\'')
+            .ok_or_else(|| "missing closing single quote".to_string())?;
+        (quoted[..end].to_string(), &quoted[end + 1..])
+    } else if let Some(quoted) = raw.strip_prefix('"') {
+        parse_double_quoted(quoted)?
+    } else {
+        let end = raw
+            .char_indices()
+            .find(|&(i, c)| c == '#' && (i == 0 || raw[..i].ends_with([' ', '\t'])))
+            .map_or(raw.len(), |(i, _)| i);
+        return Ok(raw[..end].trim_end().to_string());
+    };
+
+    let rest = rest.trim_start();
+    if !rest.is_empty() && !rest.starts_with('#') {
+        return Err(format!("unexpected '{}' after the quoted value", rest));
+    }
+
+    Ok(value)
+}
+
+/// Parse a double-quoted value, without its opening quote. Returns the value and what
+/// follows the closing quote.
+fn parse_double_quoted(quoted: &str) -> Result<(String, &str), String> {
+    let mut value = String::new();
+    let mut chars = quoted.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Ok((value, &quoted[i + 1..])),
+            '\\' => match chars.next() {
+                Some((_, 'n')) => value.push('\n'),
+                Some((_, 'r')) => value.push('\r'),
+                Some((_, 't')) => value.push('\t'),
+                Some((_, '"')) => value.push('"'),
+                Some((_, '\\')) => value.push('\\'),
+                Some((_, other)) => {
+                    value.push('\\');
+                    value.push(other);
+                }
+                None => break,
+            },
+            _ => value.push(c),
+        }
+    }
+
+    Err("missing closing double quote".to_string())
+}
@@ -29,6 +29,10 @@ use std::fmt;
 /// - `-30xxx`: Asset errors (not found, already exists, in use)
 /// - `-29xxx`: Project errors (not initialized, not registered, already exists)
 /// - `-28xxx`: SDK errors (not found, schema load failed)
+/// - `-27xxx`: Build and environment errors (compile failed, schema not found, home directory
+///   not found, database unavailable, editor failed)
+/// - `-26xxx`: I/O errors (permission denied, cancelled, outdated database schema, failed
+///   migration)
 pub mod codes {
     // =========================================================================
     // Validation errors (-31xxx)
@@ -52,6 +56,10 @@ pub mod codes {
     /// Circular dependency detected between assets
     pub const ERR_VALIDATION_CIRCULAR_REFERENCE: i32 = -31006;
 
+    /// A destructive command ran non-interactively without being explicitly allowed
+    /// (e.g., `am sudo database reset --yes` in a script)
+    pub const ERR_DESTRUCTIVE_NOT_ALLOWED: i32 = -31007;
+
     // =========================================================================
     // Asset errors (-30xxx)
     // =========================================================================
@@ -93,6 +101,12 @@ pub mod codes {
     /// Template operation isn't allowed (e.g., unregistering embedded templates)
     pub const ERR_TEMPLATE_OPERATION_NOT_ALLOWED: i32 = -29008;
 
+    /// Another command holds the project lock
+    pub const ERR_PROJECT_LOCKED: i32 = -29009;
+
+    /// A project hook script failed to start or exited with a failure status
+    pub const ERR_HOOK_FAILED: i32 = -29010;
+
     // =========================================================================
     // SDK errors (-28xxx)
     // =========================================================================
@@ -103,6 +117,9 @@ pub mod codes {
     /// Failed to load SDK schema files (.bfbs files)
     pub const ERR_SDK_SCHEMA_LOAD_FAILED: i32 = -28002;
 
+    /// The SDK doesn't report a usable version (missing or malformed VERSION file)
+    pub const ERR_SDK_VERSION_UNKNOWN: i32 = -28003;
+
     // =========================================================================
     // Build/compile errors (-27xxx)
     // =========================================================================
@@ -115,6 +132,49 @@ pub mod codes {
 
     /// Build I/O error (e.g., cannot write output file)
     pub const ERR_BUILD_IO: i32 = -27003;
+
+    /// The user's home directory can't be determined, so there is nowhere to store the
+    /// database, logs and templates (e.g., HOME unset in a container)
+    pub const ERR_HOME_NOT_FOUND: i32 = -27004;
+
+    /// The command needs the database, but there is none: it failed to open at startup
+    /// (e.g., a corrupted file) or the command ran with `--no-db`
+    pub const ERR_DATABASE_UNAVAILABLE: i32 = -27005;
+
+    /// The editor opening a file failed to start or exited with a failure status (e.g.,
+    /// `$EDITOR` naming a program that isn't installed)
+    pub const ERR_EDITOR_FAILED: i32 = -27006;
+
+    // =========================================================================
+    // I/O errors (-26xxx)
+    // =========================================================================
+
+    /// A directory the command writes to is not writable (e.g., read-only mount)
+    pub const ERR_IO_PERMISSION_DENIED: i32 = -26001;
+
+    /// A long-running operation was cancelled before it completed, leaving the data as it
+    /// was (e.g., a registry import)
+    pub const ERR_IO_CANCELLED: i32 = -26002;
+
+    /// The database lacks a table the command reads, as its schema is empty or older than
+    /// the CLI (e.g., an empty file or an old database copied in place)
+    pub const ERR_DATABASE_SCHEMA_OUTDATED: i32 = -26003;
+
+    /// Another process keeps writing to the database, so it can't be replaced (e.g., a
+    /// reset while its write-ahead log grows)
+    pub const ERR_DATABASE_IN_USE: i32 = -26004;
+
+    /// A file copied with `--verify` doesn't match its source once written (e.g., a copy
+    /// truncated by a network drive)
+    pub const ERR_IO_CHECKSUM_MISMATCH: i32 = -26005;
+
+    /// A filesystem operation failed for another reason than permissions (e.g., disk
+    /// full, or a file in place of a directory)
+    pub const ERR_IO_FAILED: i32 = -26006;
+
+    /// A schema migration of the database failed, leaving it at the last version applied
+    /// (e.g., a disk full while migrating)
+    pub const ERR_DATABASE_MIGRATION_FAILED: i32 = -26007;
 }
 
 /// Structured CLI error with What/Why/Fix components.
@@ -149,6 +209,9 @@ pub struct CliError {
     pub suggestion: String,
     /// Optional context (file path, asset name, etc.)
     pub context: Option<String>,
+    /// Whether the command already presented the failure, so only the exit code is
+    /// left to report.
+    pub reported: bool,
 }
 
 impl CliError {
@@ -163,6 +226,7 @@ impl CliError {
             why: why.into(),
             suggestion: error_suggestion(code),
             context: None,
+            reported: false,
         }
     }
 
@@ -178,6 +242,13 @@ impl CliError {
         self
     }
 
+    /// Mark the failure as already presented by the command, e.g. in a one-line
+    /// summary: the CLI exits with the matching code without displaying the error.
+    pub fn reported(mut self) -> Self {
+        self.reported = true;
+        self
+    }
+
     /// Get the error type name for JSON serialization.
     ///
     /// Maps the error code to a human-readable type string like
@@ -199,141 +270,508 @@ impl fmt::Display for CliError {
 
 impl std::error::Error for CliError {}
 
+// =============================================================================
+// Error Code Registry
+// =============================================================================
+
+/// Description of an error code: its constant, type name, default suggestion, the exit
+/// code of the process failing with it, and what `am explain` tells about it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorCodeInfo {
+    /// Name of the constant in the [`codes`] module, e.g. `ERR_PROJECT_NOT_REGISTERED`.
+    pub name: &'static str,
+    /// The error code.
+    pub code: i32,
+    /// Type name written in the `type` field of JSON errors.
+    pub type_name: &'static str,
+    /// Suggestion used unless the error overrides it.
+    pub suggestion: &'static str,
+    /// Exit code of the process, see [`exit_codes`].
+    pub exit_code: i32,
+    /// Typical reasons of the error, as given by the `why` of the errors built with it.
+    pub causes: &'static [&'static str],
+    /// Commands helping to investigate or fix the error.
+    pub related_commands: &'static [&'static str],
+}
+
+macro_rules! error_code {
+    (
+        $name:ident,
+        $type_name:literal,
+        $exit_code:ident,
+        $suggestion:expr,
+        causes: [$($cause:expr),* $(,)?],
+        related: [$($related:expr),* $(,)?] $(,)?
+    ) => {
+        ErrorCodeInfo {
+            name: stringify!($name),
+            code: codes::$name,
+            type_name: $type_name,
+            suggestion: $suggestion,
+            exit_code: exit_codes::$exit_code,
+            causes: &[$($cause),*],
+            related_commands: &[$($related),*],
+        }
+    };
+}
+
+/// Every error code of the [`codes`] module, from which [`error_type_name`],
+/// [`error_suggestion`] and [`determine_exit_code`] read, and `am introspect error-codes`
+/// prints.
+pub const ERROR_CODES: &[ErrorCodeInfo] = &[
+    // Validation errors (-31xxx)
+    error_code!(
+        ERR_VALIDATION_SCHEMA,
+        "schema_validation_error",
+        USER_ERROR,
+        "Check that your JSON structure matches the expected schema",
+        causes: [
+            "The JSON file does not match the schema of its asset type",
+            "A field has the wrong type (e.g., a string where a number is expected)",
+        ],
+        related: ["am project validate", "am sdk schemas sync"],
+    ),
+    error_code!(
+        ERR_VALIDATION_FIELD,
+        "field_validation_error",
+        USER_ERROR,
+        "Check your input values and correct the invalid field",
+        causes: [
+            "A command-line value is out of range or malformed",
+            "A required value was neither given as a flag nor answered at a prompt",
+        ],
+        related: ["am examples"],
+    ),
+    error_code!(
+        ERR_VALIDATION_FORMAT,
+        "format_validation_error",
+        USER_ERROR,
+        "Check the format of your input and try again",
+        causes: ["A value does not follow the expected format (e.g., a malformed ID or date)"],
+        related: ["am examples"],
+    ),
+    error_code!(
+        ERR_VALIDATION_REFERENCE,
+        "reference_validation_error",
+        USER_ERROR,
+        "Verify the referenced asset exists or create it first",
+        causes: [
+            "An asset references an ID that no asset of the project has",
+            "The referenced asset was deleted or renamed",
+        ],
+        related: ["am project validate", "am asset deps"],
+    ),
+    error_code!(
+        ERR_VALIDATION_EMPTY_REFERENCE,
+        "empty_reference_validation_error",
+        USER_ERROR,
+        "Add at least one reference to the required list (e.g., add sounds to a collection)",
+        causes: [
+            "A list that needs at least one entry is empty (e.g., a collection without sounds)",
+        ],
+        related: ["am project validate"],
+    ),
+    error_code!(
+        ERR_VALIDATION_CIRCULAR_REFERENCE,
+        "circular_reference_validation_error",
+        USER_ERROR,
+        "Remove the circular dependency between assets to break the cycle",
+        causes: ["Assets reference each other in a cycle"],
+        related: ["am asset graph", "am project validate"],
+    ),
+    error_code!(
+        ERR_DESTRUCTIVE_NOT_ALLOWED,
+        "destructive_not_allowed",
+        USER_ERROR,
+        "Pass --i-know-what-im-doing or set AM_ALLOW_DESTRUCTIVE=1 to run it non-interactively",
+        causes: ["It destroys data and nobody can confirm it, --yes alone doesn't allow it"],
+        related: ["am sudo database export"],
+    ),
+    // Asset errors (-30xxx)
+    error_code!(
+        ERR_ASSET_NOT_FOUND,
+        "asset_not_found",
+        USER_ERROR,
+        "Verify the asset name or create it with the appropriate create command",
+        causes: [
+            "The requested asset does not exist in this project",
+            "The command ran outside of the project directory",
+        ],
+        related: ["am asset sound list", "am project validate"],
+    ),
+    error_code!(
+        ERR_ASSET_ALREADY_EXISTS,
+        "asset_already_exists",
+        USER_ERROR,
+        "Use a different name or delete the existing asset first",
+        causes: ["An asset with this name already exists in the project"],
+        related: ["am asset sound list"],
+    ),
+    error_code!(
+        ERR_ASSET_IN_USE,
+        "asset_in_use",
+        USER_ERROR,
+        "Remove references to this asset from other assets before modifying",
+        causes: ["Other assets reference it"],
+        related: ["am asset deps", "am asset graph"],
+    ),
+    // Project errors (-29xxx)
+    error_code!(
+        ERR_PROJECT_NOT_INITIALIZED,
+        "project_not_initialized",
+        USER_ERROR,
+        "Initialize a project with 'am project init <name>'",
+        causes: [
+            "The directory does not contain a .amproject file",
+            "The command ran outside of the project directory",
+        ],
+        related: ["am project init", "am project list"],
+    ),
+    error_code!(
+        ERR_PROJECT_NOT_REGISTERED,
+        "project_not_registered",
+        USER_ERROR,
+        "Register the project with 'am project register <path>'",
+        causes: [
+            "The project directory exists but is not tracked in the database",
+            "The project was unregistered, or registered under another name",
+        ],
+        related: ["am project register", "am project list", "am project sync-registration"],
+    ),
+    error_code!(
+        ERR_PROJECT_ALREADY_EXISTS,
+        "project_already_exists",
+        USER_ERROR,
+        "Use a different name or remove the existing project first",
+        causes: [
+            "A project with this name is already registered",
+            "The project directory already exists and is not empty",
+        ],
+        related: ["am project list", "am project unregister"],
+    ),
+    error_code!(
+        ERR_TEMPLATE_COPY_FAILED,
+        "template_copy_failed",
+        USER_ERROR,
+        "Check file permissions and ensure the template path is correct",
+        causes: ["A template file can't be read, or the project directory can't be written"],
+        related: ["am template info"],
+    ),
+    error_code!(
+        ERR_TEMPLATE_NOT_FOUND,
+        "template_not_found",
+        USER_ERROR,
+        "Use 'am template list' to see available templates",
+        causes: ["No registered template matches this name"],
+        related: ["am template list", "am template register"],
+    ),
+    error_code!(
+        ERR_TEMPLATE_NAME_CONFLICT,
+        "template_name_conflict",
+        USER_ERROR,
+        "Use a different name or --force to overwrite the existing template",
+        causes: ["A template with this name is already registered"],
+        related: ["am template list", "am template update"],
+    ),
+    error_code!(
+        ERR_INVALID_TEMPLATE_STRUCTURE,
+        "invalid_template_structure",
+        USER_ERROR,
+        "Ensure template directory contains .amproject, *.buses.json, and *.config.json",
+        causes: ["The template directory lacks one of the files a project needs"],
+        related: ["am template info"],
+    ),
+    error_code!(
+        ERR_TEMPLATE_OPERATION_NOT_ALLOWED,
+        "template_operation_not_allowed",
+        USER_ERROR,
+        "Embedded templates are bundled with the CLI and cannot be removed",
+        causes: ["The operation targets a template bundled with the CLI"],
+        related: ["am template list"],
+    ),
+    error_code!(
+        ERR_PROJECT_LOCKED,
+        "project_locked",
+        USER_ERROR,
+        "Wait for the other command to finish, or pass --no-lock if no other command is running on this project",
+        causes: [
+            "Another command is modifying the project",
+            "A command was killed and left its lock behind",
+        ],
+        related: [],
+    ),
+    error_code!(
+        ERR_HOOK_FAILED,
+        "hook_failed",
+        USER_ERROR,
+        "Check the hook output above and fix the script, or remove it from the 'hooks' map in .amproject",
+        causes: [
+            "A hook script exited with a failure status",
+            "A hook script can't be started (missing file or interpreter)",
+        ],
+        related: ["am project run-hook", "am project env"],
+    ),
+    // SDK errors (-28xxx) are system/environment issues
+    error_code!(
+        ERR_SDK_NOT_FOUND,
+        "sdk_not_found",
+        SYSTEM_ERROR,
+        "Set the AM_SDK_PATH environment variable to your SDK installation",
+        causes: ["AM_SDK_PATH is not set, or doesn't point to an SDK installation"],
+        related: ["am sdk check"],
+    ),
+    error_code!(
+        ERR_SDK_SCHEMA_LOAD_FAILED,
+        "schema_load_failed",
+        SYSTEM_ERROR,
+        "Verify your SDK installation is complete and AM_SDK_PATH is correct",
+        causes: ["The schema files of the SDK are missing or unreadable"],
+        related: ["am sdk check", "am sdk schemas sync"],
+    ),
+    error_code!(
+        ERR_SDK_VERSION_UNKNOWN,
+        "sdk_version_unknown",
+        SYSTEM_ERROR,
+        "Check that the SDK installation has a VERSION file at its root",
+        causes: ["The VERSION file of the SDK is missing or malformed"],
+        related: ["am sdk check"],
+    ),
+    // Build/compile errors (-27xxx)
+    error_code!(
+        ERR_BUILD_COMPILE_FAILED,
+        "build_compile_failed",
+        USER_ERROR,
+        "Check that your JSON files match the expected schema format",
+        causes: ["An asset file doesn't match the schema it is compiled with"],
+        related: ["am project validate", "am project build"],
+    ),
+    error_code!(
+        ERR_BUILD_SCHEMA_NOT_FOUND,
+        "build_schema_not_found",
+        USER_ERROR,
+        "Verify that AM_SDK_PATH points to an SDK with schemas/ directory",
+        causes: ["The SDK has no schema for one of the asset types"],
+        related: ["am sdk check", "am sdk schemas sync"],
+    ),
+    error_code!(
+        ERR_BUILD_IO,
+        "build_io_error",
+        USER_ERROR,
+        "Check file permissions and disk space",
+        causes: ["The build directory can't be written"],
+        related: ["am project info"],
+    ),
+    error_code!(
+        ERR_HOME_NOT_FOUND,
+        "home_not_found",
+        SYSTEM_ERROR,
+        "Set the AM_HOME environment variable to the directory where the CLI should keep its data",
+        causes: ["HOME is unset (e.g., in a container) and AM_HOME isn't set either"],
+        related: ["am sudo migrate-home"],
+    ),
+    error_code!(
+        ERR_DATABASE_UNAVAILABLE,
+        "database_unavailable",
+        SYSTEM_ERROR,
+        "Run the command without --no-db, or fix the database error reported as a warning before it",
+        causes: [
+            "The database failed to open at startup (e.g., a corrupted or locked file)",
+            "The command ran with --no-db",
+        ],
+        related: ["am sudo self diagnose-db-lock", "am sudo database reset"],
+    ),
+    error_code!(
+        ERR_EDITOR_FAILED,
+        "editor_failed",
+        USER_ERROR,
+        "Set VISUAL or EDITOR to an installed editor, or use --print-path to open the file yourself",
+        causes: [
+            "VISUAL or EDITOR names a program that isn't installed",
+            "The editor exited with a failure status",
+        ],
+        related: ["am asset open"],
+    ),
+    // I/O errors (-26xxx) come from the environment, e.g. a read-only mount
+    error_code!(
+        ERR_IO_PERMISSION_DENIED,
+        "permission_denied",
+        SYSTEM_ERROR,
+        "Check the directory permissions, or whether the project is on a read-only mount",
+        causes: ["A directory the command writes to is not writable (e.g., read-only mount)"],
+        related: ["am project info"],
+    ),
+    error_code!(
+        ERR_IO_CANCELLED,
+        "cancelled",
+        SYSTEM_ERROR,
+        "Run the command again to start over",
+        causes: ["The operation was interrupted before it completed, leaving the data as it was"],
+        related: [],
+    ),
+    error_code!(
+        ERR_DATABASE_SCHEMA_OUTDATED,
+        "database_schema_outdated",
+        SYSTEM_ERROR,
+        "Run 'am sudo database migrate' to create the missing tables",
+        causes: [
+            "The database has no table the command reads, as it is empty or older than the CLI",
+        ],
+        related: ["am sudo database migrate"],
+    ),
+    error_code!(
+        ERR_DATABASE_IN_USE,
+        "database_in_use",
+        SYSTEM_ERROR,
+        "Close the other 'am' processes using the database, see 'am sudo self diagnose-db-lock'",
+        causes: ["Another process keeps writing to the database"],
+        related: ["am sudo self diagnose-db-lock"],
+    ),
+    error_code!(
+        ERR_IO_CHECKSUM_MISMATCH,
+        "checksum_mismatch",
+        SYSTEM_ERROR,
+        "Run the command again, and check the drive holding the files if the copies keep failing",
+        causes: [
+            "A copied file was truncated or altered by the storage (e.g., a flaky network drive)",
+            "The source file was modified while it was copied",
+        ],
+        related: [],
+    ),
+    error_code!(
+        ERR_IO_FAILED,
+        "io_failed",
+        SYSTEM_ERROR,
+        "Check that the path in the error is valid and that the disk isn't full",
+        causes: [
+            "The disk is full",
+            "A file exists where the command creates a directory",
+        ],
+        related: [],
+    ),
+    error_code!(
+        ERR_DATABASE_MIGRATION_FAILED,
+        "database_migration_failed",
+        SYSTEM_ERROR,
+        "Restore the backup made before the migration over the database file, then retry",
+        causes: [
+            "The disk is full or the database file isn't writable",
+            "The database was modified by hand and conflicts with the migration",
+        ],
+        related: ["am sudo database migrate", "am sudo database reset"],
+    ),
+];
+
+/// The [`ERROR_CODES`] entry of `code`, if it is a known code.
+pub fn error_code_info(code: i32) -> Option<&'static ErrorCodeInfo> {
+    ERROR_CODES.iter().find(|info| info.code == code)
+}
+
+/// The [`ERROR_CODES`] entry whose type name (e.g. `project_not_registered`) or constant
+/// name (e.g. `ERR_PROJECT_NOT_REGISTERED`) is `name`, ignoring case.
+pub fn error_code_info_by_name(name: &str) -> Option<&'static ErrorCodeInfo> {
+    ERROR_CODES.iter().find(|info| {
+        info.type_name.eq_ignore_ascii_case(name) || info.name.eq_ignore_ascii_case(name)
+    })
+}
+
+/// A range of error codes sharing a category, see the [`codes`] module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorCodeRange {
+    /// Lowest code of the range, e.g. `-29999`.
+    pub start: i32,
+    /// Highest code of the range, e.g. `-29000`.
+    pub end: i32,
+    /// What the errors of the range are about.
+    pub description: &'static str,
+}
+
+impl ErrorCodeRange {
+    /// Whether `code` is in this range.
+    pub fn contains(&self, code: i32) -> bool {
+        (self.start..=self.end).contains(&code)
+    }
+}
+
+/// The ranges of the error codes, from the first to the last one allocated.
+pub const ERROR_CODE_RANGES: &[ErrorCodeRange] = &[
+    ErrorCodeRange {
+        start: -31999,
+        end: -31000,
+        description: "Validation errors (schema, field, format)",
+    },
+    ErrorCodeRange {
+        start: -30999,
+        end: -30000,
+        description: "Asset errors (not found, already exists, in use)",
+    },
+    ErrorCodeRange {
+        start: -29999,
+        end: -29000,
+        description: "Project errors (not initialized, not registered, already exists)",
+    },
+    ErrorCodeRange {
+        start: -28999,
+        end: -28000,
+        description: "SDK errors (not found, schema load failed)",
+    },
+    ErrorCodeRange {
+        start: -27999,
+        end: -27000,
+        description: "Build and environment errors (compile failed, schema not found, home directory not found, database unavailable)",
+    },
+    ErrorCodeRange {
+        start: -26999,
+        end: -26000,
+        description: "I/O errors (permission denied, cancelled, outdated database schema)",
+    },
+];
+
+/// The range `code` belongs to, if any.
+pub fn error_code_range(code: i32) -> Option<&'static ErrorCodeRange> {
+    ERROR_CODE_RANGES.iter().find(|range| range.contains(code))
+}
+
 /// Map error code to a human-readable error type name.
 ///
 /// Used for JSON serialization to provide a consistent type field
-/// that machines can parse and humans can read.
+/// that machines can parse and humans can read. Known codes use the
+/// type name of their [`ERROR_CODES`] entry.
 ///
 /// # Error Code Ranges
 ///
+/// Other codes fall back to the type name of their range:
+///
 /// - `-31xxx` → validation errors
 /// - `-30xxx` → asset errors
 /// - `-29xxx` → project errors
 /// - `-28xxx` → SDK errors
+/// - `-26xxx` → I/O errors
 pub fn error_type_name(code: i32) -> String {
+    if let Some(info) = error_code_info(code) {
+        return info.type_name.to_string();
+    }
+
     match code {
-        // Validation errors (-31xxx)
-        codes::ERR_VALIDATION_SCHEMA => "schema_validation_error".to_string(),
-        codes::ERR_VALIDATION_FIELD => "field_validation_error".to_string(),
-        codes::ERR_VALIDATION_FORMAT => "format_validation_error".to_string(),
-        codes::ERR_VALIDATION_REFERENCE => "reference_validation_error".to_string(),
-        codes::ERR_VALIDATION_EMPTY_REFERENCE => "empty_reference_validation_error".to_string(),
-        codes::ERR_VALIDATION_CIRCULAR_REFERENCE => {
-            "circular_reference_validation_error".to_string()
-        }
         -31999..=-31000 => "validation_error".to_string(),
-
-        // Asset errors (-30xxx)
-        codes::ERR_ASSET_NOT_FOUND => "asset_not_found".to_string(),
-        codes::ERR_ASSET_ALREADY_EXISTS => "asset_already_exists".to_string(),
-        codes::ERR_ASSET_IN_USE => "asset_in_use".to_string(),
         -30999..=-30000 => "asset_error".to_string(),
-
-        // Project errors (-29xxx)
-        codes::ERR_PROJECT_NOT_INITIALIZED => "project_not_initialized".to_string(),
-        codes::ERR_PROJECT_NOT_REGISTERED => "project_not_registered".to_string(),
-        codes::ERR_PROJECT_ALREADY_EXISTS => "project_already_exists".to_string(),
-        codes::ERR_TEMPLATE_COPY_FAILED => "template_copy_failed".to_string(),
-        codes::ERR_TEMPLATE_NOT_FOUND => "template_not_found".to_string(),
-        codes::ERR_TEMPLATE_NAME_CONFLICT => "template_name_conflict".to_string(),
-        codes::ERR_INVALID_TEMPLATE_STRUCTURE => "invalid_template_structure".to_string(),
-        codes::ERR_TEMPLATE_OPERATION_NOT_ALLOWED => "template_operation_not_allowed".to_string(),
         -29999..=-29000 => "project_error".to_string(),
-
-        // SDK errors (-28xxx)
-        codes::ERR_SDK_NOT_FOUND => "sdk_not_found".to_string(),
-        codes::ERR_SDK_SCHEMA_LOAD_FAILED => "schema_load_failed".to_string(),
         -28999..=-28000 => "sdk_error".to_string(),
-
-        // Build/compile errors (-27xxx)
-        codes::ERR_BUILD_COMPILE_FAILED => "build_compile_failed".to_string(),
-        codes::ERR_BUILD_SCHEMA_NOT_FOUND => "build_schema_not_found".to_string(),
-        codes::ERR_BUILD_IO => "build_io_error".to_string(),
-
+        -26999..=-26000 => "io_error".to_string(),
         _ => "unknown_error".to_string(),
     }
 }
 
 /// Get a default suggestion based on error code.
 ///
-/// Provides actionable suggestions for common error scenarios.
+/// Provides actionable suggestions for common error scenarios, read from
+/// [`ERROR_CODES`] for known codes and by range otherwise.
 /// These can be overridden using `CliError::with_suggestion()`.
 pub fn error_suggestion(code: i32) -> String {
-    match code {
-        // Specific project errors
-        codes::ERR_PROJECT_NOT_REGISTERED => {
-            "Register the project with 'am project register <path>'".to_string()
-        }
-        codes::ERR_PROJECT_NOT_INITIALIZED => {
-            "Initialize a project with 'am project init <name>'".to_string()
-        }
-        codes::ERR_PROJECT_ALREADY_EXISTS => {
-            "Use a different name or remove the existing project first".to_string()
-        }
-        codes::ERR_TEMPLATE_COPY_FAILED => {
-            "Check file permissions and ensure the template path is correct".to_string()
-        }
-        codes::ERR_TEMPLATE_NOT_FOUND => {
-            "Use 'am template list' to see available templates".to_string()
-        }
-        codes::ERR_TEMPLATE_NAME_CONFLICT => {
-            "Use a different name or --force to overwrite the existing template".to_string()
-        }
-        codes::ERR_INVALID_TEMPLATE_STRUCTURE => {
-            "Ensure template directory contains .amproject, *.buses.json, and *.config.json"
-                .to_string()
-        }
-        codes::ERR_TEMPLATE_OPERATION_NOT_ALLOWED => {
-            "Embedded templates are bundled with the CLI and cannot be removed".to_string()
-        }
-
-        // Specific SDK errors
-        codes::ERR_SDK_NOT_FOUND => {
-            "Set the AM_SDK_PATH environment variable to your SDK installation".to_string()
-        }
-        codes::ERR_SDK_SCHEMA_LOAD_FAILED => {
-            "Verify your SDK installation is complete and AM_SDK_PATH is correct".to_string()
-        }
-
-        // Specific asset errors
-        codes::ERR_ASSET_NOT_FOUND => {
-            "Verify the asset name or create it with the appropriate create command".to_string()
-        }
-        codes::ERR_ASSET_ALREADY_EXISTS => {
-            "Use a different name or delete the existing asset first".to_string()
-        }
-        codes::ERR_ASSET_IN_USE => {
-            "Remove references to this asset from other assets before modifying".to_string()
-        }
-
-        // Specific validation errors
-        codes::ERR_VALIDATION_SCHEMA => {
-            "Check that your JSON structure matches the expected schema".to_string()
-        }
-        codes::ERR_VALIDATION_FIELD => {
-            "Check your input values and correct the invalid field".to_string()
-        }
-        codes::ERR_VALIDATION_FORMAT => "Check the format of your input and try again".to_string(),
-        codes::ERR_VALIDATION_REFERENCE => {
-            "Verify the referenced asset exists or create it first".to_string()
-        }
-        codes::ERR_VALIDATION_EMPTY_REFERENCE => {
-            "Add at least one reference to the required list (e.g., add sounds to a collection)"
-                .to_string()
-        }
-        codes::ERR_VALIDATION_CIRCULAR_REFERENCE => {
-            "Remove the circular dependency between assets to break the cycle".to_string()
-        }
-
-        // Build/compile errors
-        codes::ERR_BUILD_COMPILE_FAILED => {
-            "Check that your JSON files match the expected schema format".to_string()
-        }
-        codes::ERR_BUILD_SCHEMA_NOT_FOUND => {
-            "Verify that AM_SDK_PATH points to an SDK with schemas/ directory".to_string()
-        }
-        codes::ERR_BUILD_IO => "Check file permissions and disk space".to_string(),
+    if let Some(info) = error_code_info(code) {
+        return info.suggestion.to_string();
+    }
 
+    match code {
         // Generic fallbacks by range
         -31999..=-31000 => "Check your input values and try again".to_string(),
         -30999..=-30000 => "Verify the asset exists or create it first".to_string(),
@@ -403,6 +841,33 @@ pub fn asset_already_exists(asset_type: &str, name: &str) -> CliError {
     )
 }
 
+/// Create an error for an asset that other assets still reference.
+pub fn asset_in_use(asset_type: &str, name: &str, referenced_by: usize) -> CliError {
+    CliError::new(
+        codes::ERR_ASSET_IN_USE,
+        format!("{} '{}' is in use", asset_type, name),
+        format!("{} other asset(s) reference it", referenced_by),
+    )
+}
+
+/// Create an error for a database missing the table `table`.
+pub fn database_schema_outdated(table: &str) -> CliError {
+    CliError::new(
+        codes::ERR_DATABASE_SCHEMA_OUTDATED,
+        "The database schema is outdated or empty",
+        format!("The database has no '{}' table", table),
+    )
+}
+
+/// Create an error for a command that needs the database when there is none.
+pub fn database_unavailable() -> CliError {
+    CliError::new(
+        codes::ERR_DATABASE_UNAVAILABLE,
+        "The database is not available",
+        "This command reads or writes the project registry, but the database isn't open",
+    )
+}
+
 // =============================================================================
 // Macro for quick error construction
 // =============================================================================
@@ -469,12 +934,9 @@ pub mod exit_codes {
 
 /// Determine the appropriate exit code based on an error.
 ///
-/// Maps error codes to exit codes according to these rules:
-/// - `-28xxx` (SDK errors) → exit code 2 (system error)
-/// - `-29xxx` (Project errors) → exit code 1 (user error)
-/// - `-30xxx` (Asset errors) → exit code 1 (user error)
-/// - `-31xxx` (Validation errors) → exit code 1 (user error)
-/// - Unknown/other errors → exit code 1 (user error, safe default)
+/// Known [`CliError`] codes use the exit code of their [`ERROR_CODES`] entry, see
+/// [`exit_code_for`] for the others. Errors that aren't a [`CliError`] are unexpected,
+/// so they exit with code 2 (system error).
 ///
 /// # Arguments
 ///
@@ -485,12 +947,7 @@ pub mod exit_codes {
 /// The appropriate exit code (0, 1, or 2)
 pub fn determine_exit_code(error: &anyhow::Error) -> i32 {
     if let Some(cli_err) = error.downcast_ref::<CliError>() {
-        match cli_err.code {
-            // SDK errors (-28xxx) are system/environment issues
-            -28999..=-28000 => exit_codes::SYSTEM_ERROR,
-            // All other CliError codes are user errors
-            _ => exit_codes::USER_ERROR,
-        }
+        exit_code_for(cli_err.code)
     } else {
         // Non-CliError errors default to system error
         // (conservative choice: unexpected errors are more likely system/environment issues)
@@ -498,6 +955,24 @@ pub fn determine_exit_code(error: &anyhow::Error) -> i32 {
     }
 }
 
+/// The exit code of a process failing with the error code `code`.
+///
+/// Known codes use the exit code of their [`ERROR_CODES`] entry. Other codes map by
+/// range:
+/// - `-28xxx` (SDK errors) → exit code 2 (system error)
+/// - `-26xxx` (I/O errors) → exit code 2 (system error)
+/// - Anything else → exit code 1 (user error, safe default)
+pub fn exit_code_for(code: i32) -> i32 {
+    if let Some(info) = error_code_info(code) {
+        return info.exit_code;
+    }
+
+    match code {
+        -28999..=-28000 | -26999..=-26000 => exit_codes::SYSTEM_ERROR,
+        _ => exit_codes::USER_ERROR,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -517,6 +992,7 @@ mod tests {
         assert!((-30999..=-30000).contains(&codes::ERR_ASSET_NOT_FOUND));
         assert!((-29999..=-29000).contains(&codes::ERR_PROJECT_NOT_REGISTERED));
         assert!((-28999..=-28000).contains(&codes::ERR_SDK_NOT_FOUND));
+        assert!((-26999..=-26000).contains(&codes::ERR_IO_PERMISSION_DENIED));
     }
 
     #[test]
@@ -0,0 +1,438 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! File associations of the CLI with the desktop.
+//!
+//! `am sudo register-file-types` associates the files of [`FILE_TYPES`] with the CLI,
+//! so that opening a `.amproject` file shows the project and opening a `.amtpl` archive
+//! imports the template. Associations are per user and written through a
+//! [`FileAssociations`] backend:
+//!
+//! - [`WindowsAssociations`]: keys under `HKEY_CURRENT_USER\Software\Classes`.
+//! - [`LinuxAssociations`]: a shared MIME-info package and one `.desktop` entry per type
+//!   in the XDG data directory, then the desktop caches are refreshed.
+//! - [`UnsupportedAssociations`]: platforms without a supported mechanism (macOS), where
+//!   nothing is written.
+//!
+//! A backend first [plans](FileAssociations::plan) the changes, which are shown as they
+//! are by `--dry-run`, then [applies](FileAssociations::apply) them one by one.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{Context, Result, anyhow};
+use serde_json::{Value, json};
+
+use crate::common::files::atomic_write;
+use crate::common::paths::HomeEnv;
+use crate::common::template_archive::TEMPLATE_ARCHIVE_EXTENSION;
+
+/// A type of file the CLI can open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileType {
+    /// The extension, without its dot.
+    pub extension: &'static str,
+    pub mime_type: &'static str,
+    /// Name of the type shown by the file manager.
+    pub description: &'static str,
+    /// Arguments of the CLI opening a file, which is passed after them.
+    pub args: &'static [&'static str],
+}
+
+/// The types of file associated with the CLI.
+pub const FILE_TYPES: &[FileType] = &[
+    FileType {
+        extension: "amproject",
+        mime_type: "application/x-amplitude-project",
+        description: "Amplitude project",
+        args: &["project", "info", "--path"],
+    },
+    FileType {
+        extension: TEMPLATE_ARCHIVE_EXTENSION,
+        mime_type: "application/x-amplitude-template",
+        description: "Amplitude project template",
+        args: &["template", "import"],
+    },
+];
+
+/// A single change made to register or remove file associations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssociationChange {
+    /// Set the default value of a key under `HKEY_CURRENT_USER`, creating it.
+    SetRegistryValue { key: String, value: String },
+    /// Delete a key under `HKEY_CURRENT_USER` with its subkeys, if it exists.
+    DeleteRegistryKey { key: String },
+    /// Write a file, creating its directory.
+    WriteFile { path: PathBuf, contents: String },
+    /// Remove a file, if it exists.
+    RemoveFile { path: PathBuf },
+    /// Run a helper refreshing the caches of the desktop. Its failure is only warned
+    /// about, as the helper may not be installed.
+    RunCommand { program: String, args: Vec<String> },
+}
+
+impl AssociationChange {
+    /// Whether the associations are complete even if this change fails.
+    pub fn is_optional(&self) -> bool {
+        matches!(self, Self::RunCommand { .. })
+    }
+
+    /// The change as JSON, with the file contents so a dry run shows exactly what would
+    /// be written.
+    pub fn to_json(&self) -> Value {
+        match self {
+            Self::SetRegistryValue { key, value } => {
+                json!({ "action": "set_registry_value", "key": key, "value": value })
+            }
+            Self::DeleteRegistryKey { key } => {
+                json!({ "action": "delete_registry_key", "key": key })
+            }
+            Self::WriteFile { path, contents } => {
+                json!({ "action": "write_file", "path": path, "contents": contents })
+            }
+            Self::RemoveFile { path } => json!({ "action": "remove_file", "path": path }),
+            Self::RunCommand { program, args } => {
+                json!({ "action": "run_command", "program": program, "args": args })
+            }
+        }
+    }
+}
+
+impl fmt::Display for AssociationChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SetRegistryValue { key, value } => write!(f, "set HKCU\\{} to {}", key, value),
+            Self::DeleteRegistryKey { key } => write!(f, "delete HKCU\\{}", key),
+            Self::WriteFile { path, .. } => write!(f, "write {}", path.display()),
+            Self::RemoveFile { path } => write!(f, "remove {}", path.display()),
+            Self::RunCommand { program, args } => write!(f, "run {} {}", program, args.join(" ")),
+        }
+    }
+}
+
+/// Writes and removes the file associations of a platform.
+pub trait FileAssociations {
+    /// Name of the platform, as shown to the user.
+    fn platform(&self) -> &'static str;
+
+    /// The changes associating `types` with the CLI at `exe`, or removing their
+    /// associations with `unregister`. `None` if the platform isn't supported.
+    fn plan(
+        &self,
+        exe: &Path,
+        types: &[FileType],
+        unregister: bool,
+    ) -> Option<Vec<AssociationChange>>;
+
+    /// Make `change`.
+    fn apply(&self, change: &AssociationChange) -> Result<()>;
+}
+
+/// The backend of the platform the CLI runs on.
+pub fn native_associations() -> Box<dyn FileAssociations> {
+    if cfg!(windows) {
+        Box::new(WindowsAssociations)
+    } else if cfg!(target_os = "macos") {
+        Box::new(UnsupportedAssociations { platform: "macos" })
+    } else {
+        let env = HomeEnv::current();
+        match env
+            .xdg_data_home
+            .or_else(|| env.home.map(|home| home.join(".local").join("share")))
+        {
+            Some(data_home) => Box::new(LinuxAssociations { data_home }),
+            None => Box::new(UnsupportedAssociations { platform: "linux" }),
+        }
+    }
+}
+
+/// Name of the desktop entry or program identifier of `file_type`.
+fn handler_id(file_type: &FileType) -> String {
+    format!("amplitude-cli-{}", file_type.extension)
+}
+
+// =============================================================================
+// Windows
+// =============================================================================
+
+/// Associations under `HKEY_CURRENT_USER\Software\Classes`, written with `reg.exe`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WindowsAssociations;
+
+impl FileAssociations for WindowsAssociations {
+    fn platform(&self) -> &'static str {
+        "windows"
+    }
+
+    fn plan(
+        &self,
+        exe: &Path,
+        types: &[FileType],
+        unregister: bool,
+    ) -> Option<Vec<AssociationChange>> {
+        let mut changes = Vec::new();
+        for file_type in types {
+            let extension_key = format!(r"Software\Classes\.{}", file_type.extension);
+            let program_key = format!(r"Software\Classes\{}", handler_id(file_type));
+
+            if unregister {
+                changes.push(AssociationChange::DeleteRegistryKey { key: extension_key });
+                changes.push(AssociationChange::DeleteRegistryKey { key: program_key });
+                continue;
+            }
+
+            changes.push(AssociationChange::SetRegistryValue {
+                key: extension_key,
+                value: handler_id(file_type),
+            });
+            changes.push(AssociationChange::SetRegistryValue {
+                key: program_key.clone(),
+                value: file_type.description.to_string(),
+            });
+            changes.push(AssociationChange::SetRegistryValue {
+                key: format!(r"{}\shell\open\command", program_key),
+                value: format!("\"{}\" {} \"%1\"", exe.display(), file_type.args.join(" ")),
+            });
+        }
+
+        Some(changes)
+    }
+
+    fn apply(&self, change: &AssociationChange) -> Result<()> {
+        match change {
+            AssociationChange::SetRegistryValue { key, value } => run(
+                "reg",
+                &["add", &format!(r"HKCU\{}", key), "/ve", "/d", value, "/f"],
+            ),
+            AssociationChange::DeleteRegistryKey { key } => {
+                let key = format!(r"HKCU\{}", key);
+                // `reg delete` fails on a missing key, which is already the wanted state.
+                if run("reg", &["query", &key]).is_err() {
+                    return Ok(());
+                }
+                run("reg", &["delete", &key, "/f"])
+            }
+            _ => apply_common(change),
+        }
+    }
+}
+
+// =============================================================================
+// Linux
+// =============================================================================
+
+/// Associations in the XDG data directory `data_home` (e.g. `~/.local/share`): a MIME
+/// package declaring the types and a `.desktop` entry opening each of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinuxAssociations {
+    pub data_home: PathBuf,
+}
+
+impl LinuxAssociations {
+    fn mime_dir(&self) -> PathBuf {
+        self.data_home.join("mime")
+    }
+
+    fn applications_dir(&self) -> PathBuf {
+        self.data_home.join("applications")
+    }
+
+    fn mime_package(&self) -> PathBuf {
+        self.mime_dir().join("packages").join("amplitude-cli.xml")
+    }
+
+    fn desktop_entry(&self, file_type: &FileType) -> PathBuf {
+        self.applications_dir()
+            .join(format!("{}.desktop", handler_id(file_type)))
+    }
+
+    fn refresh_caches(&self, changes: &mut Vec<AssociationChange>) {
+        changes.push(AssociationChange::RunCommand {
+            program: "update-mime-database".to_string(),
+            args: vec![self.mime_dir().display().to_string()],
+        });
+        changes.push(AssociationChange::RunCommand {
+            program: "update-desktop-database".to_string(),
+            args: vec![self.applications_dir().display().to_string()],
+        });
+    }
+}
+
+impl FileAssociations for LinuxAssociations {
+    fn platform(&self) -> &'static str {
+        "linux"
+    }
+
+    fn plan(
+        &self,
+        exe: &Path,
+        types: &[FileType],
+        unregister: bool,
+    ) -> Option<Vec<AssociationChange>> {
+        let mut changes = Vec::new();
+
+        if unregister {
+            changes.push(AssociationChange::RemoveFile {
+                path: self.mime_package(),
+            });
+            for file_type in types {
+                changes.push(AssociationChange::RemoveFile {
+                    path: self.desktop_entry(file_type),
+                });
+            }
+            self.refresh_caches(&mut changes);
+            return Some(changes);
+        }
+
+        changes.push(AssociationChange::WriteFile {
+            path: self.mime_package(),
+            contents: mime_package(types),
+        });
+        for file_type in types {
+            changes.push(AssociationChange::WriteFile {
+                path: self.desktop_entry(file_type),
+                contents: desktop_entry(exe, file_type),
+            });
+        }
+        self.refresh_caches(&mut changes);
+        for file_type in types {
+            changes.push(AssociationChange::RunCommand {
+                program: "xdg-mime".to_string(),
+                args: vec![
+                    "default".to_string(),
+                    format!("{}.desktop", handler_id(file_type)),
+                    file_type.mime_type.to_string(),
+                ],
+            });
+        }
+
+        Some(changes)
+    }
+
+    fn apply(&self, change: &AssociationChange) -> Result<()> {
+        apply_common(change)
+    }
+}
+
+/// The shared MIME-info package declaring `types`.
+fn mime_package(types: &[FileType]) -> String {
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <mime-info xmlns=\"http://www.freedesktop.org/standards/shared-mime-info\">\n",
+    );
+    for file_type in types {
+        xml.push_str(&format!(
+            "  <mime-type type=\"{}\">\n    <comment>{}</comment>\n    <glob pattern=\"*.{}\"/>\n  </mime-type>\n",
+            file_type.mime_type, file_type.description, file_type.extension
+        ));
+    }
+    xml.push_str("</mime-info>\n");
+    xml
+}
+
+/// The desktop entry opening files of `file_type` with the CLI at `exe`, in a terminal.
+fn desktop_entry(exe: &Path, file_type: &FileType) -> String {
+    format!(
+        "[Desktop Entry]\n\
+         Type=Application\n\
+         Name=Amplitude CLI ({})\n\
+         Exec={} {} %f\n\
+         MimeType={};\n\
+         Terminal=true\n\
+         NoDisplay=true\n",
+        file_type.description,
+        desktop_quote(&exe.display().to_string()),
+        file_type.args.join(" "),
+        file_type.mime_type
+    )
+}
+
+/// Quote an argument of the `Exec` key of a desktop entry.
+fn desktop_quote(arg: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in arg.chars() {
+        if matches!(c, '"' | '`' | '$' | '\\') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+// =============================================================================
+// Unsupported Platforms
+// =============================================================================
+
+/// A platform without supported file associations, where nothing is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnsupportedAssociations {
+    pub platform: &'static str,
+}
+
+impl FileAssociations for UnsupportedAssociations {
+    fn platform(&self) -> &'static str {
+        self.platform
+    }
+
+    fn plan(
+        &self,
+        _exe: &Path,
+        _types: &[FileType],
+        _unregister: bool,
+    ) -> Option<Vec<AssociationChange>> {
+        None
+    }
+
+    fn apply(&self, change: &AssociationChange) -> Result<()> {
+        Err(anyhow!("Cannot {} on {}", change, self.platform))
+    }
+}
+
+/// Apply the changes shared by every platform: files and helper commands.
+fn apply_common(change: &AssociationChange) -> Result<()> {
+    match change {
+        AssociationChange::WriteFile { path, contents } => atomic_write(path, contents.as_bytes()),
+        AssociationChange::RemoveFile { path } => match fs::remove_file(path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                Err(e).with_context(|| format!("Failed to remove {}", path.display()))
+            }
+            _ => Ok(()),
+        },
+        AssociationChange::RunCommand { program, args } => {
+            let args: Vec<&str> = args.iter().map(String::as_str).collect();
+            run(program, &args)
+        }
+        AssociationChange::SetRegistryValue { .. }
+        | AssociationChange::DeleteRegistryKey { .. } => Err(anyhow!(
+            "Cannot {}: the registry only exists on Windows",
+            change
+        )),
+    }
+}
+
+/// Run `program` with `args`, failing if it can't be started or exits unsuccessfully.
+fn run(program: &str, args: &[&str]) -> Result<()> {
+    let status = Command::new(program)
+        .args(args)
+        .output()
+        .with_context(|| format!("Failed to run {}", program))?
+        .status;
+    if !status.success() {
+        return Err(anyhow!("{} exited with {}", program, status));
+    }
+    Ok(())
+}
@@ -15,12 +15,18 @@
 //! File operation utilities.
 //!
 //! Provides safe file operations including atomic writes to prevent
-//! data corruption from interrupted operations.
+//! data corruption from interrupted operations, and the canonical JSON
+//! formatting used for every file the CLI generates.
 
 use std::fs;
+use std::io::ErrorKind;
 use std::path::Path;
 
 use anyhow::{Context, Result};
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::common::errors::{CliError, codes};
+use crate::common::fs_ctx;
 
 /// Atomically write content to a file.
 ///
@@ -75,6 +81,300 @@ pub fn atomic_write(path: &Path, content: &[u8]) -> Result<()> {
     Ok(())
 }
 
+/// Check that files can be written in `dir` by creating and removing a probe file.
+///
+/// Commands modifying a project call this before changing anything, so a project on a
+/// read-only mount fails upfront with [`codes::ERR_IO_PERMISSION_DENIED`] rather than
+/// with a raw I/O error halfway through.
+///
+/// # Errors
+///
+/// Returns an `ERR_IO_PERMISSION_DENIED` error if `dir` is read-only or not writable by
+/// the current user, or the underlying I/O error for any other failure.
+pub fn ensure_writable(dir: &Path) -> Result<()> {
+    let probe = dir.join(format!(".am-write-probe-{}", std::process::id()));
+
+    match fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&probe)
+    {
+        Ok(file) => {
+            drop(file);
+            fs::remove_file(&probe).with_context(|| format!("Failed to remove {}", probe.display()))
+        }
+        Err(e)
+            if matches!(
+                e.kind(),
+                ErrorKind::PermissionDenied | ErrorKind::ReadOnlyFilesystem
+            ) =>
+        {
+            Err(CliError::new(
+                codes::ERR_IO_PERMISSION_DENIED,
+                "Cannot write to the project",
+                format!("The directory is not writable: {}", e),
+            )
+            .with_context(dir.display().to_string())
+            .with_suggestion(
+                "The project may be on a read-only mount: run this command on a writable copy. \
+                 Read-only commands like 'am project validate' still work",
+            )
+            .into())
+        }
+        Err(e) => Err(e).with_context(|| format!("Failed to write in {}", dir.display())),
+    }
+}
+
+/// Delete the file or directory at `target`, which must be strictly inside `root`.
+///
+/// Every command deleting something from the filesystem goes through this, so a
+/// misconfigured path (`..` components, an absolute path elsewhere, a symbolic link
+/// pointing out of the tree) can never delete more than what the command owns.
+/// Both paths are canonicalized before being compared, and `target` itself must not
+/// be a symbolic link: it is refused rather than resolved.
+///
+/// # Errors
+///
+/// Returns an `ERR_VALIDATION_FIELD` error, before deleting anything, if `target` is a
+/// symbolic link, is `root` itself or is not inside `root`. Returns the underlying I/O
+/// error if either path doesn't exist, and an `ERR_IO_*` error naming the path if the
+/// deletion fails.
+pub fn safe_remove_within(root: &Path, target: &Path) -> Result<()> {
+    let refuse = |why: String| -> anyhow::Error {
+        CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            "Refusing to delete a path outside its expected directory",
+            why,
+        )
+        .with_context(target.display().to_string())
+        .with_suggestion(format!(
+            "Only paths inside {} can be deleted by this command: check the configured paths",
+            root.display()
+        ))
+        .into()
+    };
+
+    let metadata = fs::symlink_metadata(target)
+        .with_context(|| format!("Failed to read {}", target.display()))?;
+    if metadata.file_type().is_symlink() {
+        return Err(refuse(format!(
+            "{} is a symbolic link and may point outside of {}",
+            target.display(),
+            root.display()
+        )));
+    }
+
+    let canonical_root =
+        fs::canonicalize(root).with_context(|| format!("Failed to resolve {}", root.display()))?;
+    let canonical_target = fs::canonicalize(target)
+        .with_context(|| format!("Failed to resolve {}", target.display()))?;
+
+    if canonical_target == canonical_root {
+        return Err(refuse(format!(
+            "{} is the directory containing what should be deleted",
+            canonical_target.display()
+        )));
+    }
+    if !canonical_target.starts_with(&canonical_root) {
+        return Err(refuse(format!(
+            "{} is not inside {}",
+            canonical_target.display(),
+            canonical_root.display()
+        )));
+    }
+
+    if metadata.is_dir() {
+        fs_ctx::remove_dir_all(&canonical_target)
+    } else {
+        fs_ctx::remove_file(&canonical_target)
+    }
+}
+
+/// Delete the file or directory at `target` with [`safe_remove_within`], using its
+/// parent directory as root.
+///
+/// For paths chosen explicitly by the user, like a project directory: `/`, a path ending
+/// with `..` or a symbolic link are still refused.
+pub fn safe_remove_in_parent(target: &Path) -> Result<()> {
+    let parent = target
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+
+    safe_remove_within(parent, target)
+}
+
+/// Serialize a value to the JSON format used for every generated file.
+///
+/// Output is pretty-printed with fields in struct declaration order and
+/// terminated by a newline, so rewriting a file only changes the lines of
+/// the fields that actually changed.
+pub fn to_json_pretty<T: Serialize + ?Sized>(value: &T) -> Result<String> {
+    let mut json = serde_json::to_string_pretty(value)?;
+    json.push('\n');
+    Ok(json)
+}
+
+/// Atomically write a value as JSON, keeping fields of the existing file that
+/// `T` doesn't know about.
+///
+/// Known fields are written first in struct declaration order, followed by the
+/// preserved unknown fields. This lets files written by newer CLI versions (or
+/// edited by hand) survive a rewrite by this version. Fields that `T` skips
+/// when serializing (e.g. `None` options) are treated as unknown.
+///
+/// # Errors
+///
+/// Returns an error if the existing file cannot be read, or if the value
+/// cannot be serialized or written. An existing file that is not a JSON
+/// object is overwritten.
+pub fn write_json_preserving_unknown_fields<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    let existing = if path.exists() {
+        Some(
+            fs::read_to_string(path)
+                .with_context(|| format!("Failed to read {}", path.display()))?,
+        )
+    } else {
+        None
+    };
+
+    let json = json_preserving_unknown_fields(existing.as_deref(), value)?;
+    atomic_write(path, json.as_bytes())
+}
+
+/// The JSON [`write_json_preserving_unknown_fields`] writes over a file holding
+/// `existing`, or over no file.
+pub fn json_preserving_unknown_fields<T: Serialize>(
+    existing: Option<&str>,
+    value: &T,
+) -> Result<String> {
+    #[derive(Serialize)]
+    struct WithUnknownFields<'a, T> {
+        #[serde(flatten)]
+        value: &'a T,
+        #[serde(flatten)]
+        unknown: serde_json::Map<String, serde_json::Value>,
+    }
+
+    let mut unknown = serde_json::Map::new();
+
+    if let Some(content) = existing
+        && let Ok(serde_json::Value::Object(existing)) = serde_json::from_str(content)
+    {
+        let known = serde_json::to_value(value)?;
+        unknown = existing
+            .into_iter()
+            .filter(|(key, _)| known.get(key).is_none())
+            .collect();
+    }
+
+    to_json_pretty(&WithUnknownFields { value, unknown })
+}
+
+/// Whether this build of the CLI can parse JSON files containing comments
+/// (the `json-comments` feature).
+pub const JSON_COMMENTS_SUPPORTED: bool = cfg!(feature = "json-comments");
+
+/// Parse a hand-edited asset file.
+///
+/// When `allow_comments` is set and the CLI is built with the `json-comments`
+/// feature, `//` and `/* */` comments and trailing commas are accepted.
+/// Otherwise the content must be strict JSON.
+pub fn parse_asset_json<T: DeserializeOwned>(
+    content: &str,
+    allow_comments: bool,
+) -> serde_json::Result<T> {
+    if allow_comments && JSON_COMMENTS_SUPPORTED {
+        serde_json::from_str(&strip_json_comments(content))
+    } else {
+        serde_json::from_str(content)
+    }
+}
+
+/// Whether `content` contains comments or trailing commas outside of strings.
+pub fn has_json_comments(content: &str) -> bool {
+    strip_json_comments(content) != content
+}
+
+/// Replace `//` and `/* */` comments and trailing commas with whitespace.
+///
+/// String literals are left untouched. Each removed character is replaced by a
+/// space (newlines are kept), so line and column numbers reported by serde
+/// still point to the original file.
+pub fn strip_json_comments(content: &str) -> String {
+    let chars: Vec<char> = content.chars().collect();
+    let mut out = String::with_capacity(content.len());
+    let mut in_string = false;
+    // Byte offset in `out` of a comma only followed by whitespace and comments so far.
+    let mut pending_comma: Option<usize> = None;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+
+        if in_string {
+            out.push(c);
+            match (c, next) {
+                ('\\', Some(escaped)) => {
+                    out.push(escaped);
+                    i += 1;
+                }
+                ('"', _) => in_string = false,
+                _ => {}
+            }
+            i += 1;
+            continue;
+        }
+
+        match (c, next) {
+            ('/', Some('/')) => {
+                while i < chars.len() && chars[i] != '\n' {
+                    out.push(' ');
+                    i += 1;
+                }
+                continue;
+            }
+            ('/', Some('*')) => {
+                out.push_str("  ");
+                i += 2;
+                while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                    out.push(if chars[i] == '\n' { '\n' } else { ' ' });
+                    i += 1;
+                }
+                if i < chars.len() {
+                    out.push_str("  ");
+                    i += 2;
+                }
+                continue;
+            }
+            ('"', _) => {
+                in_string = true;
+                pending_comma = None;
+                out.push(c);
+            }
+            (',', _) => {
+                pending_comma = Some(out.len());
+                out.push(c);
+            }
+            ('}' | ']', _) => {
+                if let Some(offset) = pending_comma.take() {
+                    out.replace_range(offset..offset + 1, " ");
+                }
+                out.push(c);
+            }
+            _ if c.is_whitespace() => out.push(c),
+            _ => {
+                pending_comma = None;
+                out.push(c);
+            }
+        }
+        i += 1;
+    }
+
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -130,4 +430,58 @@ mod tests {
         // Temp file should not exist after successful write
         assert!(!tmp_path.exists());
     }
+
+    const COMMENTED: &str = r#"{
+  // Footstep on grass
+  "name": "grass", /* legacy */
+  "url": "http://example.com/a//b",
+  "note": "/* not a comment */",
+  "gains": [1, 2,],
+}
+"#;
+
+    #[test]
+    fn test_strip_json_comments_removes_comments_and_trailing_commas() {
+        let stripped = strip_json_comments(COMMENTED);
+        let value: serde_json::Value = serde_json::from_str(&stripped).unwrap();
+
+        assert_eq!(value["name"], "grass");
+        assert_eq!(value["url"], "http://example.com/a//b");
+        assert_eq!(value["note"], "/* not a comment */");
+        assert_eq!(value["gains"], serde_json::json!([1, 2]));
+    }
+
+    #[test]
+    fn test_strip_json_comments_preserves_line_numbers() {
+        let stripped = strip_json_comments("{\n/* a\nb */\n\"x\": nope\n}");
+        let err = serde_json::from_str::<serde_json::Value>(&stripped).unwrap_err();
+
+        assert_eq!(stripped.lines().count(), 5);
+        assert_eq!(err.line(), 4);
+    }
+
+    #[test]
+    fn test_has_json_comments() {
+        assert!(has_json_comments(COMMENTED));
+        assert!(has_json_comments("{\"a\": [1,]}"));
+        assert!(!has_json_comments("{\"a\": \"// b, ]\"}"));
+    }
+
+    #[test]
+    fn test_parse_asset_json_is_strict_when_not_allowed() {
+        assert!(parse_asset_json::<serde_json::Value>(COMMENTED, false).is_err());
+    }
+
+    #[cfg(feature = "json-comments")]
+    #[test]
+    fn test_parse_asset_json_accepts_comments_when_allowed() {
+        let value: serde_json::Value = parse_asset_json(COMMENTED, true).unwrap();
+        assert_eq!(value["name"], "grass");
+    }
+
+    #[cfg(not(feature = "json-comments"))]
+    #[test]
+    fn test_parse_asset_json_needs_feature_to_accept_comments() {
+        assert!(parse_asset_json::<serde_json::Value>(COMMENTED, true).is_err());
+    }
 }
@@ -0,0 +1,167 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Formatting of values for interactive output.
+//!
+//! Timestamps are emitted in UTC as RFC 3339 wherever they can be read by a program
+//! (JSON output, lock and log files), see [`utc_timestamp`]. Only interactive output
+//! renders them in local time, through [`humanize_time`].
+
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, SecondsFormat, Utc};
+
+/// Character replacing the part of a value left out.
+pub const ELLIPSIS: char = '…';
+
+/// Width under which paths are never shortened to fit the terminal.
+pub const MIN_TRUNCATED_PATH_WIDTH: usize = 24;
+
+/// Age under which [`humanize_time`] renders a time relative to now.
+pub const RELATIVE_TIME_LIMIT_DAYS: i64 = 7;
+
+/// Format of the local times rendered by [`humanize_time`].
+pub const HUMAN_TIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+/// Width of the terminal, read from the `COLUMNS` environment variable when set.
+pub fn terminal_width() -> Option<usize> {
+    std::env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.parse().ok())
+        .filter(|&width| width > 0)
+}
+
+/// Shorten `path` to at most `max` characters by replacing components in its middle with
+/// an ellipsis, e.g. `/home/u/…/projects/mygame`.
+///
+/// The root, drive (`C:`) or UNC prefix and the final components are kept, as they tell
+/// paths apart. When even the last component doesn't fit, its end is kept. Widths are
+/// counted in characters, so a multi-byte character is never split.
+pub fn truncate_path(path: &str, max: usize) -> String {
+    if path.chars().count() <= max {
+        return path.to_string();
+    }
+
+    let separator = if path.contains('/') {
+        '/'
+    } else if path.contains('\\') {
+        '\\'
+    } else {
+        return truncate_start(path, max);
+    };
+
+    let components: Vec<&str> = path.split(separator).collect();
+    // The empty components before a root or UNC prefix, and a drive, always stay.
+    let mut head = components.iter().take_while(|c| c.is_empty()).count();
+    if components.get(head).is_some_and(|c| c.ends_with(':')) {
+        head += 1;
+    }
+    head = head.min(components.len() - 1);
+    let mut tail = 1;
+
+    let render = |head: usize, tail: usize| -> String {
+        let mut shortened = components[..head].join(&separator.to_string());
+        if head > 0 {
+            shortened.push(separator);
+        }
+        shortened.push(ELLIPSIS);
+        shortened.push(separator);
+        shortened.push_str(&components[components.len() - tail..].join(&separator.to_string()));
+        shortened
+    };
+
+    if head + tail >= components.len() || render(head, tail).chars().count() > max {
+        return truncate_start(path, max);
+    }
+
+    // Keep as many final components as fit, then as many leading ones.
+    while head + tail + 1 < components.len() && render(head, tail + 1).chars().count() <= max {
+        tail += 1;
+    }
+    while head + tail + 1 < components.len() && render(head + 1, tail).chars().count() <= max {
+        head += 1;
+    }
+
+    render(head, tail)
+}
+
+/// The end of `value` after an ellipsis, in at most `max` characters.
+fn truncate_start(value: &str, max: usize) -> String {
+    if max == 0 {
+        return String::new();
+    }
+    let count = value.chars().count();
+    let kept: String = value.chars().skip(count + 1 - max).collect();
+    format!("{}{}", ELLIPSIS, kept)
+}
+
+/// `time` as an RFC 3339 UTC timestamp to the second, e.g. `2026-03-04T05:06:07Z`.
+pub fn utc_timestamp(time: DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// Parse a timestamp read back from the database or a file.
+///
+/// Accepts RFC 3339, and the UTC `YYYY-MM-DD HH:MM:SS` and `YYYY-MM-DD` forms SQLite
+/// stores and returns for `CURRENT_TIMESTAMP` and `date()`.
+pub fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Some(time.with_timezone(&Utc));
+    }
+    if let Ok(time) = NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S") {
+        return Some(time.and_utc());
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|time| time.and_utc())
+}
+
+/// Render `time` for interactive output, as seen at `now`.
+///
+/// Times of the last week are relative (`just now`, `5 minutes ago`, `3 days ago`).
+/// Older and future times are shown in the local timezone, e.g. `2026-03-04 06:06`.
+pub fn humanize_time(time: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let age = now.signed_duration_since(time);
+    if age < chrono::Duration::zero() || age.num_days() >= RELATIVE_TIME_LIMIT_DAYS {
+        return time
+            .with_timezone(&Local)
+            .format(HUMAN_TIME_FORMAT)
+            .to_string();
+    }
+
+    let (count, unit) = if age.num_days() > 0 {
+        (age.num_days(), "day")
+    } else if age.num_hours() > 0 {
+        (age.num_hours(), "hour")
+    } else if age.num_minutes() > 0 {
+        (age.num_minutes(), "minute")
+    } else {
+        return "just now".to_string();
+    };
+
+    format!(
+        "{} {}{} ago",
+        count,
+        unit,
+        if count == 1 { "" } else { "s" }
+    )
+}
+
+/// Like [`humanize_time`] for a stored timestamp, which is returned as-is when it can't
+/// be [parsed](parse_timestamp).
+pub fn humanize_timestamp(value: &str, now: DateTime<Utc>) -> String {
+    match parse_timestamp(value) {
+        Some(time) => humanize_time(time, now),
+        None => value.to_string(),
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Filesystem operations whose errors name the operation and the path.
+//!
+//! Drop-in replacements for the [`std::fs`] functions the project, template and asset
+//! commands use to create and delete files. A bare `Permission denied (os error 13)` doesn't say
+//! which of the directories of a project failed to be created: these functions fail
+//! with a [`CliError`] like `Failed to create directory '<path>'`, whose context is the
+//! path, so JSON error envelopes carry it too. The [`std::io::Error`] stays the source
+//! of the error.
+
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::common::errors::{CliError, codes};
+
+/// Like [`fs::create_dir_all`].
+pub fn create_dir_all(path: impl AsRef<Path>) -> Result<()> {
+    let path = path.as_ref();
+    fs::create_dir_all(path).map_err(|e| io_error("create directory", path, e))
+}
+
+/// Like [`fs::write`].
+pub fn write(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> Result<()> {
+    let path = path.as_ref();
+    fs::write(path, contents).map_err(|e| io_error("write", path, e))
+}
+
+/// Like [`fs::copy`]. The error names the destination when it can't be written, and
+/// the source otherwise.
+pub fn copy(from: impl AsRef<Path>, to: impl AsRef<Path>) -> Result<u64> {
+    let (from, to) = (from.as_ref(), to.as_ref());
+    fs::copy(from, to).map_err(|e| {
+        if from.is_file() {
+            io_error(&format!("copy '{}' to", from.display()), to, e)
+        } else {
+            io_error("copy", from, e)
+        }
+    })
+}
+
+/// Like [`fs::remove_dir_all`].
+pub fn remove_dir_all(path: impl AsRef<Path>) -> Result<()> {
+    let path = path.as_ref();
+    fs::remove_dir_all(path).map_err(|e| io_error("remove directory", path, e))
+}
+
+/// Like [`fs::remove_file`].
+pub fn remove_file(path: impl AsRef<Path>) -> Result<()> {
+    let path = path.as_ref();
+    fs::remove_file(path).map_err(|e| io_error("remove file", path, e))
+}
+
+/// The error of `operation` failing on `path`.
+///
+/// A read-only target is reported as `ERR_IO_PERMISSION_DENIED`, any other failure as
+/// `ERR_IO_FAILED`.
+fn io_error(operation: &str, path: &Path, e: io::Error) -> anyhow::Error {
+    let (code, suggestion) = match e.kind() {
+        ErrorKind::PermissionDenied | ErrorKind::ReadOnlyFilesystem => (
+            codes::ERR_IO_PERMISSION_DENIED,
+            "Check the permissions of the path, or whether it is on a read-only mount",
+        ),
+        _ => (
+            codes::ERR_IO_FAILED,
+            "Check that the path is valid and that the disk isn't full",
+        ),
+    };
+
+    let error = CliError::new(
+        code,
+        format!("Failed to {} '{}'", operation, path.display()),
+        e.to_string(),
+    )
+    .with_context(path.display().to_string())
+    .with_suggestion(suggestion);
+
+    anyhow::Error::new(e).context(error)
+}
@@ -0,0 +1,69 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Glob matching for names.
+//!
+//! Shared by the `.amignore` rules, which match each path component separately, and by
+//! commands selecting registered projects by name (e.g. `am project unregister --match`).
+
+/// Match `name` against a glob supporting `*`, `?` and `[...]`.
+///
+/// The whole name must match. `*` matches any sequence of characters (including none),
+/// `?` a single character, and `[...]` a character of the set, which may contain ranges
+/// (`[a-z]`) and be negated with `!` or `^`.
+pub fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    glob_match_chars(&pattern, &name)
+}
+
+fn glob_match_chars(pattern: &[char], name: &[char]) -> bool {
+    match pattern.first() {
+        None => name.is_empty(),
+        Some('*') => (0..=name.len()).any(|skip| glob_match_chars(&pattern[1..], &name[skip..])),
+        Some('?') => !name.is_empty() && glob_match_chars(&pattern[1..], &name[1..]),
+        Some('[') => match (pattern.iter().position(|&c| c == ']'), name.first()) {
+            (Some(end), Some(&c)) if end > 1 => {
+                let (negated, set) = match pattern[1] {
+                    '!' | '^' => (true, &pattern[2..end]),
+                    _ => (false, &pattern[1..end]),
+                };
+                class_contains(set, c) != negated
+                    && glob_match_chars(&pattern[end + 1..], &name[1..])
+            }
+            (Some(_), _) => false,
+            (None, Some('[')) => glob_match_chars(&pattern[1..], &name[1..]),
+            (None, _) => false,
+        },
+        Some(&p) => name.first() == Some(&p) && glob_match_chars(&pattern[1..], &name[1..]),
+    }
+}
+
+fn class_contains(set: &[char], c: char) -> bool {
+    let mut i = 0;
+    while i < set.len() {
+        if i + 2 < set.len() && set[i + 1] == '-' {
+            if set[i] <= c && c <= set[i + 2] {
+                return true;
+            }
+            i += 3;
+        } else {
+            if set[i] == c {
+                return true;
+            }
+            i += 1;
+        }
+    }
+    false
+}
@@ -0,0 +1,193 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! SHA-256 digests of files, and the verification of copies asked with `--verify`.
+//!
+//! Copies made with [`copy_file_hashed`] hash the bytes of the source as they are
+//! written. A [`CopyVerifier`] then re-hashes each destination file on a background
+//! thread, while the command goes on copying the next ones, and reports the files whose
+//! content doesn't match their source. Comparing digests catches copies truncated or
+//! altered by the storage, e.g. over a flaky network drive, that would otherwise only
+//! surface as corrupt assets much later.
+
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread::JoinHandle;
+
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+
+use crate::common::errors::{CliError, codes};
+
+/// Size of the buffer files are read with.
+const BUFFER_SIZE: usize = 64 * 1024;
+
+/// Number of mismatched files listed in the error of [`VerifyReport::into_result`].
+const LISTED_MISMATCHES: usize = 20;
+
+/// SHA-256 of `content`, as hex.
+pub fn sha256_bytes(content: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(content))
+}
+
+/// SHA-256 of the content of the file at `path`, as hex.
+pub fn sha256_file(path: &Path) -> io::Result<String> {
+    let mut file = fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0; BUFFER_SIZE];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Copy the file `source` to `destination`, hashing its content on the way.
+///
+/// Returns the number of bytes copied and the SHA-256 of the source, as hex.
+pub fn copy_file_hashed(source: &Path, destination: &Path) -> io::Result<(u64, String)> {
+    let mut reader = fs::File::open(source)?;
+    let mut writer = fs::File::create(destination)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0; BUFFER_SIZE];
+    let mut bytes = 0u64;
+    loop {
+        let read = reader.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+        writer.write_all(&buffer[..read])?;
+        bytes += read as u64;
+    }
+    writer.sync_all()?;
+
+    // Like fs::copy, the copy keeps the permissions of the source.
+    fs::set_permissions(destination, reader.metadata()?.permissions())?;
+    Ok((bytes, format!("{:x}", hasher.finalize())))
+}
+
+/// Outcome of the verification of copied files.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerifyReport {
+    /// Number of files verified.
+    pub files: usize,
+    /// Number of bytes of the files matching their source.
+    pub bytes: u64,
+    /// Files that don't match their source, or couldn't be read back.
+    pub mismatches: Vec<String>,
+}
+
+impl VerifyReport {
+    /// Fail with `ERR_IO_CHECKSUM_MISMATCH`, listing the mismatched files, if any file
+    /// doesn't match its source. `destination` is where the files were copied to.
+    pub fn into_result(self, destination: &Path) -> Result<Self, CliError> {
+        if self.mismatches.is_empty() {
+            return Ok(self);
+        }
+
+        let mut listed = self.mismatches[..self.mismatches.len().min(LISTED_MISMATCHES)].join(", ");
+        if self.mismatches.len() > LISTED_MISMATCHES {
+            listed.push_str(&format!(
+                " and {} more",
+                self.mismatches.len() - LISTED_MISMATCHES
+            ));
+        }
+
+        Err(CliError::new(
+            codes::ERR_IO_CHECKSUM_MISMATCH,
+            format!(
+                "{} of {} copied file(s) don't match their source",
+                self.mismatches.len(),
+                self.files
+            ),
+            format!("Mismatched files: {}", listed),
+        )
+        .with_context(destination.display().to_string()))
+    }
+
+    /// The `verified` statistics of a command summary.
+    pub fn to_json(&self) -> Value {
+        json!({
+            "files": self.files,
+            "bytes": self.bytes,
+        })
+    }
+}
+
+/// A destination file waiting for verification.
+struct PendingCheck {
+    label: String,
+    path: PathBuf,
+    expected: String,
+    bytes: u64,
+}
+
+/// Re-hashes copied files on a background thread, see the [module](self) documentation.
+pub struct CopyVerifier {
+    sender: mpsc::Sender<PendingCheck>,
+    worker: JoinHandle<VerifyReport>,
+}
+
+impl CopyVerifier {
+    /// Start the verification thread.
+    pub fn start() -> Self {
+        let (sender, receiver) = mpsc::channel::<PendingCheck>();
+        let worker = std::thread::spawn(move || {
+            let mut report = VerifyReport::default();
+            for check in receiver {
+                report.files += 1;
+                match sha256_file(&check.path) {
+                    Ok(actual) if actual == check.expected => report.bytes += check.bytes,
+                    _ => report.mismatches.push(check.label),
+                }
+            }
+            report
+        });
+
+        Self { sender, worker }
+    }
+
+    /// Queue the verification of the file at `path`, which must have the SHA-256
+    /// `expected` of its `bytes`-long source. `label` names it in the report, usually by
+    /// its path relative to the destination.
+    pub fn check(
+        &self,
+        label: impl Into<String>,
+        path: impl Into<PathBuf>,
+        expected: String,
+        bytes: u64,
+    ) {
+        // The worker only stops once every sender is dropped, so the send can't fail.
+        let _ = self.sender.send(PendingCheck {
+            label: label.into(),
+            path: path.into(),
+            expected,
+            bytes,
+        });
+    }
+
+    /// Wait for the files queued so far to be verified.
+    pub fn finish(self) -> VerifyReport {
+        drop(self.sender);
+        self.worker
+            .join()
+            .expect("The copy verification thread panicked")
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Project lifecycle hooks.
+//!
+//! A project can declare hooks in the `hooks` map of its `.amproject`, from a hook name to
+//! a script path relative to the project root:
+//!
+//! ```json
+//! "hooks": {
+//!   "post-init": "scripts/setup.sh",
+//!   "post-validate": "scripts/lint.sh"
+//! }
+//! ```
+//!
+//! Hooks run with the project root as working directory, with the variables of the
+//! project's [`.amenv`](crate::common::env_file) file and with `AM_PROJECT_NAME`,
+//! `AM_PROJECT_PATH` and `AM_COMMAND` set. Their stdout and stderr are streamed through
+//! [`Output::progress`], and a non-zero exit fails the command that triggered them.
+
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Component, Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+
+use log::debug;
+
+use crate::common::env_file::EnvFile;
+use crate::common::errors::{CliError, codes};
+use crate::database::entities::ProjectConfiguration;
+use crate::presentation::Output;
+
+/// Hook run by `am project init` once the project is created.
+pub const HOOK_POST_INIT: &str = "post-init";
+
+/// Hook run by `am project validate` once every asset passed validation.
+pub const HOOK_POST_VALIDATE: &str = "post-validate";
+
+/// Run the hook `name` of the project at `project_root`, if the project declares it.
+///
+/// `command` identifies the CLI command triggering the hook and is passed to the script
+/// as `AM_COMMAND`. Returns `Ok(false)` when the hook is not declared.
+pub fn run_hook_if_declared(
+    project_root: &Path,
+    config: &ProjectConfiguration,
+    name: &str,
+    command: &str,
+    output: &dyn Output,
+) -> anyhow::Result<bool> {
+    let Some(script) = config.hooks.get(name) else {
+        debug!("No '{}' hook declared", name);
+        return Ok(false);
+    };
+
+    let script_path = resolve_hook_script(project_root, name, script)?;
+    let env_file = EnvFile::load(project_root)?;
+    for issue in &env_file.issues {
+        debug!("Skipped malformed line: {}", issue);
+    }
+
+    output.progress(&format!("Running '{}' hook ({})...", name, script));
+
+    let mut child = Command::new(&script_path)
+        .current_dir(project_root)
+        .envs(env_file.pairs())
+        .env("AM_PROJECT_NAME", &config.name)
+        .env("AM_PROJECT_PATH", project_root)
+        .env("AM_COMMAND", command)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| {
+            CliError::new(
+                codes::ERR_HOOK_FAILED,
+                format!("Failed to start '{}' hook", name),
+                e.to_string(),
+            )
+            .with_suggestion("Make sure the hook script is executable")
+            .with_context(script_path.display().to_string())
+        })?;
+
+    let (sender, receiver) = mpsc::channel();
+    let readers = [
+        child
+            .stdout
+            .take()
+            .map(|s| forward_lines(s, sender.clone())),
+        child
+            .stderr
+            .take()
+            .map(|s| forward_lines(s, sender.clone())),
+    ];
+    drop(sender);
+
+    // Ends once both streams are closed.
+    for line in receiver {
+        output.progress(&line);
+    }
+
+    for reader in readers.into_iter().flatten() {
+        let _ = reader.join();
+    }
+
+    let status = child.wait()?;
+    if !status.success() {
+        let exit = match status.code() {
+            Some(code) => format!("exit code {}", code),
+            None => "terminated by a signal".to_string(),
+        };
+
+        return Err(CliError::new(
+            codes::ERR_HOOK_FAILED,
+            format!("The '{}' hook failed", name),
+            format!("{} exited with a failure status", script),
+        )
+        .with_context(exit)
+        .into());
+    }
+
+    Ok(true)
+}
+
+/// Resolve a hook script path declared in `.amproject` against the project root.
+fn resolve_hook_script(project_root: &Path, name: &str, script: &str) -> anyhow::Result<PathBuf> {
+    let relative = Path::new(script);
+    let escapes = relative.is_absolute()
+        || relative
+            .components()
+            .any(|c| matches!(c, Component::ParentDir | Component::Prefix(_)));
+
+    if escapes {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            format!("Invalid '{}' hook in .amproject", name),
+            format!("'{}' is not a path inside the project directory", script),
+        )
+        .with_suggestion("Set the hook to a script path relative to the project root")
+        .into());
+    }
+
+    let path = project_root.join(relative);
+    if !path.is_file() {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            format!("Script of the '{}' hook not found", name),
+            format!("'{}' does not exist in the project directory", script),
+        )
+        .with_context(path.display().to_string())
+        .into());
+    }
+
+    Ok(path)
+}
+
+fn forward_lines(
+    stream: impl Read + Send + 'static,
+    sender: mpsc::Sender<String>,
+) -> std::thread::JoinHandle<()> {
+    std::thread::spawn(move || {
+        for line in BufReader::new(stream).lines() {
+            let Ok(line) = line else { break };
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    })
+}
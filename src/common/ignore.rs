@@ -0,0 +1,393 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Project ignore rules and the shared project file walker.
+//!
+//! Projects can contain an `.amignore` file at their root listing gitignore-style
+//! patterns. Files matched by these patterns (plus a set of built-in defaults for
+//! common editor and OS junk) are skipped by every command that walks project files:
+//! asset counting, validation, asset listing and builds.
+//!
+//! # Pattern syntax
+//! - Blank lines and lines starting with `#` are ignored
+//! - `*` matches anything except `/`, `?` matches a single character, `[abc]` a set
+//! - `**` matches any number of directories
+//! - A trailing `/` only matches directories (and everything below them)
+//! - A pattern containing a `/` is anchored to the project root, otherwise it matches
+//!   a file or directory name at any depth
+//! - A leading `!` re-includes paths matched by an earlier pattern, including the
+//!   built-in defaults (e.g. `!*.bak`)
+//!
+//! The last matching pattern wins. As in git, a file cannot be re-included when one
+//! of its parent directories is ignored.
+//!
+//! # Walking
+//! [`walk_project_files`] doesn't follow symlinked directories unless `--follow-symlinks`
+//! is given, never visits a directory twice, and stops `--max-depth` levels down. The
+//! directories it skips are returned with the files, for the caller to report with
+//! [`warn_skipped`].
+
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+use crate::common::glob::glob_match;
+use crate::presentation::Output;
+
+/// Name of the ignore file at the project root.
+pub const AMIGNORE_FILE: &str = ".amignore";
+
+/// Patterns applied before the project's `.amignore` rules.
+pub const DEFAULT_IGNORE_PATTERNS: &[&str] = &[
+    ".DS_Store",
+    "Thumbs.db",
+    "desktop.ini",
+    "*.bak",
+    "*.tmp",
+    "*.swp",
+    "*~",
+];
+
+/// A single parsed ignore pattern.
+#[derive(Debug, Clone)]
+struct IgnoreRule {
+    /// Pattern split on `/`.
+    segments: Vec<String>,
+    /// Pattern started with `!`.
+    negated: bool,
+    /// Pattern ended with `/`.
+    directory_only: bool,
+    /// Pattern is matched against the full path relative to the root.
+    anchored: bool,
+}
+
+impl IgnoreRule {
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim_end();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+
+        let (negated, pattern) = match line.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, line.strip_prefix('\\').unwrap_or(line)),
+        };
+        let (directory_only, pattern) = match pattern.strip_suffix('/') {
+            Some(rest) => (true, rest),
+            None => (false, pattern),
+        };
+        let anchored = pattern.contains('/');
+        let pattern = pattern.strip_prefix('/').unwrap_or(pattern);
+
+        if pattern.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            segments: pattern.split('/').map(str::to_string).collect(),
+            negated,
+            directory_only,
+            anchored,
+        })
+    }
+
+    fn matches(&self, components: &[&str], is_dir: bool) -> bool {
+        if self.directory_only && !is_dir {
+            return false;
+        }
+
+        if self.anchored {
+            match_segments(&self.segments, components)
+        } else {
+            components
+                .last()
+                .is_some_and(|name| glob_match(&self.segments[0], name))
+        }
+    }
+}
+
+/// Ignore rules of a project, resolved against its root directory.
+#[derive(Debug, Clone, Default)]
+pub struct IgnoreRules {
+    root: PathBuf,
+    rules: Vec<IgnoreRule>,
+}
+
+impl IgnoreRules {
+    /// Load the built-in defaults followed by the project's `.amignore`, if any.
+    pub fn load(project_root: &Path) -> anyhow::Result<Self> {
+        let amignore = project_root.join(AMIGNORE_FILE);
+        let content = if amignore.is_file() {
+            fs::read_to_string(&amignore)
+                .with_context(|| format!("Failed to read {}", amignore.display()))?
+        } else {
+            String::new()
+        };
+
+        Ok(Self::from_patterns(
+            project_root,
+            DEFAULT_IGNORE_PATTERNS
+                .iter()
+                .copied()
+                .chain(content.lines()),
+        ))
+    }
+
+    /// Build rules from explicit patterns, without the built-in defaults.
+    pub fn from_patterns<'a>(root: &Path, patterns: impl IntoIterator<Item = &'a str>) -> Self {
+        Self {
+            root: root.to_path_buf(),
+            rules: patterns.into_iter().filter_map(IgnoreRule::parse).collect(),
+        }
+    }
+
+    /// Add rules taking precedence over the existing ones.
+    pub fn with_patterns<'a>(mut self, patterns: impl IntoIterator<Item = &'a str>) -> Self {
+        self.rules
+            .extend(patterns.into_iter().filter_map(IgnoreRule::parse));
+        self
+    }
+
+    /// Rules that ignore nothing.
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    /// Whether `path` is ignored, either directly or through one of its parent directories.
+    ///
+    /// `path` may be absolute (under the project root) or relative to the project root.
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        if self.rules.is_empty() {
+            return false;
+        }
+
+        let relative = path.strip_prefix(&self.root).unwrap_or(path);
+        let components: Vec<&str> = relative
+            .components()
+            .filter_map(|c| match c {
+                std::path::Component::Normal(s) => s.to_str(),
+                _ => None,
+            })
+            .collect();
+
+        (1..components.len()).any(|len| self.matches(&components[..len], true))
+            || self.matches(&components, is_dir)
+    }
+
+    fn matches(&self, components: &[&str], is_dir: bool) -> bool {
+        if components.is_empty() {
+            return false;
+        }
+
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.matches(components, is_dir))
+            .is_some_and(|rule| !rule.negated)
+    }
+}
+
+/// Default value of [`WalkOptions::max_depth`].
+pub const DEFAULT_MAX_WALK_DEPTH: usize = 32;
+
+/// How the project file walkers treat symlinked directories and deep trees.
+///
+/// Built from the global `--follow-symlinks` and `--max-depth` flags and passed down to
+/// the handlers along with `--project`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WalkOptions {
+    /// Descend into symlinked directories. Directories reached twice are skipped, so
+    /// symlink cycles never loop.
+    pub follow_symlinks: bool,
+    /// Number of directory levels below the walked directory to descend into.
+    pub max_depth: usize,
+}
+
+impl Default for WalkOptions {
+    fn default() -> Self {
+        DEFAULT_WALK_OPTIONS
+    }
+}
+
+const DEFAULT_WALK_OPTIONS: WalkOptions = WalkOptions {
+    follow_symlinks: false,
+    max_depth: DEFAULT_MAX_WALK_DEPTH,
+};
+
+/// Why a walker didn't descend into a directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipReason {
+    /// The directory was already visited, through a symlink cycle or another link.
+    Cycle,
+    /// The directory is deeper than [`WalkOptions::max_depth`].
+    MaxDepth,
+}
+
+/// A directory a walker didn't descend into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedPath {
+    pub path: PathBuf,
+    pub reason: SkipReason,
+}
+
+impl fmt::Display for SkippedPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.reason {
+            SkipReason::Cycle => write!(
+                f,
+                "Skipped {}: directory already visited (symlink cycle)",
+                self.path.display()
+            ),
+            SkipReason::MaxDepth => write!(
+                f,
+                "Skipped {}: deeper than the maximum walk depth (raise it with --max-depth)",
+                self.path.display()
+            ),
+        }
+    }
+}
+
+/// Files and directories found by [`walk_project_files`], and the directories it skipped.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProjectWalk {
+    pub files: Vec<PathBuf>,
+    /// Directories walked, the root excepted.
+    pub dirs: Vec<PathBuf>,
+    pub skipped: Vec<SkippedPath>,
+}
+
+/// Recursively collect files under `dir` that are not ignored by `rules`.
+///
+/// Ignored directories are not descended into. Symlinks to files are returned,
+/// symlinked directories are only followed with [`WalkOptions::follow_symlinks`].
+/// Directories skipped because of a cycle or [`WalkOptions::max_depth`] are returned in
+/// [`ProjectWalk::skipped`]. Returns an empty walk if `dir` doesn't exist.
+pub fn walk_project_files(dir: &Path, rules: &IgnoreRules, options: WalkOptions) -> ProjectWalk {
+    walk(dir, rules, options, true)
+}
+
+/// Warn about each directory a walk skipped.
+pub fn warn_skipped(skipped: &[SkippedPath], output: &dyn Output) {
+    for path in skipped {
+        output.warning(&path.to_string());
+    }
+}
+
+/// Collect the files directly inside `dir` that are not ignored by `rules`.
+pub fn list_project_files(dir: &Path, rules: &IgnoreRules) -> Vec<PathBuf> {
+    let options = WalkOptions {
+        follow_symlinks: false,
+        max_depth: 1,
+    };
+    walk(dir, rules, options, false).files
+}
+
+#[tracing::instrument(name = "fs.walk", skip_all, fields(path = %dir.display(), files))]
+fn walk(dir: &Path, rules: &IgnoreRules, options: WalkOptions, report_depth: bool) -> ProjectWalk {
+    if !dir.is_dir() {
+        return ProjectWalk::default();
+    }
+
+    let mut visited = HashSet::new();
+    let mut skipped = Vec::new();
+    let mut loops = Vec::new();
+    let mut files = Vec::new();
+    let mut dirs = Vec::new();
+
+    let entries = walkdir::WalkDir::new(dir)
+        .follow_links(options.follow_symlinks)
+        .max_depth(options.max_depth)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|e| {
+            let is_dir = e.file_type().is_dir();
+            if e.depth() > 0 && rules.is_ignored(e.path(), is_dir) {
+                return false;
+            }
+            if !is_dir {
+                return true;
+            }
+
+            if let Ok(canonical) = e.path().canonicalize()
+                && !visited.insert(canonical)
+            {
+                skipped.push(SkippedPath {
+                    path: e.path().to_path_buf(),
+                    reason: SkipReason::Cycle,
+                });
+                return false;
+            }
+
+            if report_depth
+                && e.depth() == options.max_depth
+                && fs::read_dir(e.path()).is_ok_and(|mut entries| entries.next().is_some())
+            {
+                skipped.push(SkippedPath {
+                    path: e.path().to_path_buf(),
+                    reason: SkipReason::MaxDepth,
+                });
+            }
+            true
+        });
+
+    for entry in entries {
+        match entry {
+            Ok(entry) => {
+                if entry.path().is_file() {
+                    files.push(entry.into_path());
+                } else if entry.depth() > 0 && entry.file_type().is_dir() {
+                    dirs.push(entry.into_path());
+                }
+            }
+            // A symlink back to one of the directories being walked.
+            Err(e) if e.loop_ancestor().is_some() => {
+                if let Some(path) = e.path() {
+                    loops.push(SkippedPath {
+                        path: path.to_path_buf(),
+                        reason: SkipReason::Cycle,
+                    });
+                }
+            }
+            Err(_) => {}
+        }
+    }
+
+    skipped.extend(loops);
+    skipped.sort_by(|a, b| a.path.cmp(&b.path));
+    files.sort();
+    dirs.sort();
+    tracing::Span::current().record("files", files.len());
+
+    ProjectWalk {
+        files,
+        dirs,
+        skipped,
+    }
+}
+
+/// Match pattern segments against path components, expanding `**`.
+fn match_segments(pattern: &[String], components: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => components.is_empty(),
+        Some((first, rest)) if first == "**" => {
+            (0..=components.len()).any(|skip| match_segments(rest, &components[skip..]))
+        }
+        Some((first, rest)) => components
+            .split_first()
+            .is_some_and(|(name, tail)| glob_match(first, name) && match_segments(rest, tail)),
+    }
+}
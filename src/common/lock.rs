@@ -0,0 +1,220 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Advisory per-project lock.
+//!
+//! Commands that modify project files take a lock by creating `.amproject.lock` at the
+//! project root, containing the PID and command line of the holder. Another invocation
+//! waits for the lock to be released, up to a timeout, and then fails with
+//! `ERR_PROJECT_LOCKED` naming the holder. Read-only commands never take the lock.
+//!
+//! The lock is advisory: it only coordinates CLI invocations (and tools that honor the
+//! file). Locks left behind by a process that no longer exists are reclaimed.
+
+use std::fs;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+use anyhow::Context;
+use log::debug;
+use serde::{Deserialize, Serialize};
+
+use crate::common::errors::{CliError, codes};
+use crate::common::files::to_json_pretty;
+use crate::common::format::utc_timestamp;
+use crate::common::process::process_is_alive;
+use crate::presentation::Output;
+
+/// Name of the lock file at the project root.
+pub const LOCK_FILE: &str = ".amproject.lock";
+
+/// How long a command waits for another invocation to release the lock.
+pub const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Delay between two attempts to take the lock.
+const RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Age after which a lock file that can't be parsed is considered abandoned.
+///
+/// The holder writes its details right after creating the file, so an unreadable lock
+/// is only legitimate for a very short time.
+const UNREADABLE_LOCK_GRACE: Duration = Duration::from_secs(5);
+
+/// The invocation holding a project lock, as stored in the lock file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockHolder {
+    /// Process ID of the holder.
+    pub pid: u32,
+    /// Command line of the holder.
+    pub command: String,
+    /// When the lock was taken (RFC 3339, UTC).
+    pub acquired_at: String,
+}
+
+/// A held project lock. The lock file is removed when this value is dropped.
+#[derive(Debug)]
+pub struct ProjectLock {
+    path: PathBuf,
+    holder: LockHolder,
+}
+
+impl ProjectLock {
+    /// Take the lock of the project at `project_root` on behalf of `command`.
+    ///
+    /// If another live process holds the lock, retries until `timeout` elapses, reporting
+    /// the wait once through `output`. Locks held by dead processes are reclaimed.
+    pub fn acquire(
+        project_root: &Path,
+        command: &str,
+        timeout: Duration,
+        output: &dyn Output,
+    ) -> anyhow::Result<Self> {
+        let path = project_root.join(LOCK_FILE);
+        let holder = LockHolder {
+            pid: std::process::id(),
+            command: command.to_string(),
+            acquired_at: utc_timestamp(chrono::Utc::now()),
+        };
+
+        let deadline = Instant::now() + timeout;
+        let mut waiting = false;
+
+        loop {
+            match create_lock_file(&path, &holder) {
+                Ok(()) => {
+                    debug!("Acquired project lock {}", path.display());
+                    return Ok(Self { path, holder });
+                }
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => {}
+                Err(e) => {
+                    return Err(e)
+                        .with_context(|| format!("Failed to create lock file {}", path.display()));
+                }
+            }
+
+            let current = read_lock_file(&path);
+            if is_stale(&path, current.as_ref()) {
+                debug!(
+                    "Reclaiming stale project lock {} ({:?})",
+                    path.display(),
+                    current
+                );
+                match fs::remove_file(&path) {
+                    Ok(()) => continue,
+                    Err(e) if e.kind() == ErrorKind::NotFound => continue,
+                    Err(e) => {
+                        return Err(e).with_context(|| {
+                            format!("Failed to remove stale lock file {}", path.display())
+                        });
+                    }
+                }
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(project_locked(project_root, &path, current.as_ref()).into());
+            }
+
+            if !waiting {
+                waiting = true;
+                output.progress(&match &current {
+                    Some(h) => format!(
+                        "Waiting for '{}' (PID {}) to release the project lock...",
+                        h.command, h.pid
+                    ),
+                    None => "Waiting for the project lock to be released...".to_string(),
+                });
+            }
+
+            std::thread::sleep(RETRY_INTERVAL.min(deadline - now));
+        }
+    }
+}
+
+impl Drop for ProjectLock {
+    fn drop(&mut self) {
+        // Only remove the file if it is still ours, in case it was reclaimed meanwhile.
+        if read_lock_file(&self.path).as_ref() != Some(&self.holder) {
+            return;
+        }
+
+        if let Err(e) = fs::remove_file(&self.path) {
+            debug!(
+                "Failed to remove project lock {}: {}",
+                self.path.display(),
+                e
+            );
+        }
+    }
+}
+
+/// Read the holder of the lock of the project at `project_root`.
+///
+/// Returns `None` when the project isn't locked or the lock file can't be parsed.
+pub fn read_lock_holder(project_root: &Path) -> Option<LockHolder> {
+    read_lock_file(&project_root.join(LOCK_FILE))
+}
+
+fn create_lock_file(path: &Path, holder: &LockHolder) -> std::io::Result<()> {
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)?;
+
+    let content = to_json_pretty(holder).map_err(std::io::Error::other)?;
+    if let Err(e) = file.write_all(content.as_bytes()) {
+        let _ = fs::remove_file(path);
+        return Err(e);
+    }
+
+    Ok(())
+}
+
+fn read_lock_file(path: &Path) -> Option<LockHolder> {
+    let content = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Whether the lock file at `path` was left behind by a process that no longer runs.
+fn is_stale(path: &Path, holder: Option<&LockHolder>) -> bool {
+    match holder {
+        Some(holder) => !process_is_alive(holder.pid),
+        None => fs::metadata(path)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+            .is_some_and(|age| age > UNREADABLE_LOCK_GRACE),
+    }
+}
+
+fn project_locked(project_root: &Path, path: &Path, holder: Option<&LockHolder>) -> CliError {
+    let why = match holder {
+        Some(h) => format!(
+            "'{}' (PID {}) has held the project lock since {}",
+            h.command, h.pid, h.acquired_at
+        ),
+        None => "Another command holds the project lock".to_string(),
+    };
+
+    CliError::new(
+        codes::ERR_PROJECT_LOCKED,
+        format!(
+            "Project at '{}' is locked by another command",
+            project_root.display()
+        ),
+        why,
+    )
+    .with_context(path.display().to_string())
+}
@@ -0,0 +1,124 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reading and following the log files of the state directory.
+//!
+//! Used by `am sudo logs tail`. Following polls the size of the file rather than
+//! relying on filesystem notifications, so it works the same on every platform and
+//! on network mounts.
+
+use std::fs;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::common::logger::LogEntry;
+
+/// Extension of the log files.
+pub const LOG_EXTENSION: &str = "log";
+
+/// How often a followed log file is checked for new lines.
+pub const FOLLOW_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// The most recently modified log file of `dir`, or `None` if there is none.
+pub fn latest_log_file(dir: &Path) -> io::Result<Option<PathBuf>> {
+    if !dir.is_dir() {
+        return Ok(None);
+    }
+
+    let mut latest = None;
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.extension().is_none_or(|ext| ext != LOG_EXTENSION) || !path.is_file() {
+            continue;
+        }
+
+        let modified = entry.metadata()?.modified()?;
+        if latest.as_ref().is_none_or(|(time, _)| modified > *time) {
+            latest = Some((modified, path));
+        }
+    }
+
+    Ok(latest.map(|(_, path)| path))
+}
+
+/// The structured form of a log line: its [`LogEntry`] fields, or only a `message` for
+/// lines that aren't log entries.
+pub fn log_line_to_json(line: &str) -> serde_json::Value {
+    match LogEntry::parse_file_line(line) {
+        Some(entry) => entry.to_json(),
+        None => serde_json::json!({ "message": line }),
+    }
+}
+
+/// Reads the lines appended to a log file since the last read.
+#[derive(Debug)]
+pub struct LogFollower {
+    path: PathBuf,
+    offset: u64,
+    /// Bytes of a line whose end wasn't written yet.
+    partial: Vec<u8>,
+}
+
+impl LogFollower {
+    /// Follow the file at `path`, whose existing lines are returned by the first read.
+    pub fn new(path: &Path) -> io::Result<Self> {
+        if !path.is_file() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("No log file at {}", path.display()),
+            ));
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            offset: 0,
+            partial: Vec::new(),
+        })
+    }
+
+    /// The complete lines appended since the last call.
+    ///
+    /// A file that shrank was truncated or replaced, and is read again from its start.
+    pub fn read_new_lines(&mut self) -> io::Result<Vec<String>> {
+        let mut file = fs::File::open(&self.path)?;
+        let len = file.metadata()?.len();
+
+        if len < self.offset {
+            self.offset = 0;
+            self.partial.clear();
+        }
+        if len == self.offset {
+            return Ok(Vec::new());
+        }
+
+        file.seek(SeekFrom::Start(self.offset))?;
+        let read = file
+            .take(len - self.offset)
+            .read_to_end(&mut self.partial)?;
+        self.offset += read as u64;
+
+        let Some(end) = self.partial.iter().rposition(|&b| b == b'\n') else {
+            return Ok(Vec::new());
+        };
+        let rest = self.partial.split_off(end + 1);
+        let complete = std::mem::replace(&mut self.partial, rest);
+
+        Ok(String::from_utf8_lossy(&complete)
+            .lines()
+            .map(|line| line.to_string())
+            .collect())
+    }
+}
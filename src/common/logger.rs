@@ -12,13 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use chrono::{DateTime, Local};
+use crate::common::format::utc_timestamp;
+use crate::common::paths;
+use chrono::{DateTime, Local, NaiveDateTime, SecondsFormat, Utc};
 use colored::*;
 use log::{Level, Log, Metadata, Record};
 use std::collections::VecDeque;
 use std::fs;
 use std::io::Write;
 use std::path::PathBuf;
+use std::str::FromStr;
 use std::sync::{Mutex, RwLock};
 
 #[derive(Debug, Clone)]
@@ -36,10 +39,27 @@ impl std::fmt::Display for LogLevel {
     }
 }
 
+impl FromStr for LogLevel {
+    type Err = log::ParseLevelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "SUCCESS" => Ok(LogLevel::Success),
+            _ => Level::from_str(s).map(LogLevel::Standard),
+        }
+    }
+}
+
 const MAX_LOG_BUFFER_SIZE: usize = 1000;
 
+/// Timestamp format of the lines of log files, in UTC.
+const FILE_TIMESTAMP_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.3fZ";
+
+/// Local time format of the lines written by versions of am before log files were in UTC.
+const LEGACY_FILE_TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.3f";
+
 pub struct LogEntry {
-    timestamp: DateTime<Local>,
+    timestamp: DateTime<Utc>,
     level: LogLevel,
     target: String,
     message: String,
@@ -48,7 +68,7 @@ pub struct LogEntry {
 impl LogEntry {
     pub fn new(record: &Record) -> Self {
         Self {
-            timestamp: Local::now(),
+            timestamp: Utc::now(),
             level: LogLevel::Standard(record.level()),
             target: record.target().to_string(),
             message: record.args().to_string(),
@@ -57,7 +77,7 @@ impl LogEntry {
 
     pub fn new_success(target: String, message: String) -> Self {
         Self {
-            timestamp: Local::now(),
+            timestamp: Utc::now(),
             level: LogLevel::Success,
             target,
             message,
@@ -67,12 +87,49 @@ impl LogEntry {
     pub fn format_for_file(&self) -> String {
         format!(
             "[{}] [{}] [{}] {}\n",
-            self.timestamp.format("%Y-%m-%d %H:%M:%S%.3f"),
+            self.timestamp.format(FILE_TIMESTAMP_FORMAT),
             self.level,
             self.target,
             self.message
         )
     }
+
+    /// Parse a line written by [`LogEntry::format_for_file`], without its newline.
+    ///
+    /// Returns `None` for lines of another format, like the header of crash logs or the
+    /// continuation lines of multi-line messages.
+    pub fn parse_file_line(line: &str) -> Option<Self> {
+        let rest = line.strip_prefix('[')?;
+        let (timestamp, rest) = rest.split_once("] [")?;
+        let (level, rest) = rest.split_once("] [")?;
+        let (target, message) = rest.split_once("] ")?;
+
+        let timestamp = match NaiveDateTime::parse_from_str(timestamp, FILE_TIMESTAMP_FORMAT) {
+            Ok(time) => time.and_utc(),
+            Err(_) => NaiveDateTime::parse_from_str(timestamp, LEGACY_FILE_TIMESTAMP_FORMAT)
+                .ok()?
+                .and_local_timezone(Local)
+                .earliest()?
+                .with_timezone(&Utc),
+        };
+
+        Some(Self {
+            timestamp,
+            level: level.parse().ok()?,
+            target: target.to_string(),
+            message: message.to_string(),
+        })
+    }
+
+    /// The structured form of the entry, as written by `am sudo logs tail --json`.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "timestamp": self.timestamp.to_rfc3339_opts(SecondsFormat::Millis, true),
+            "level": self.level.to_string(),
+            "target": self.target,
+            "message": self.message,
+        })
+    }
 }
 
 static LOG_BUFFER: Mutex<Option<VecDeque<LogEntry>>> = Mutex::new(None);
@@ -121,10 +178,8 @@ impl Logger {
         }
     }
 
-    fn format_console_message(record: &Record) -> String {
-        let message = record.args().to_string();
-
-        match record.level() {
+    fn format_console_message(level: Level, message: &str) -> String {
+        match level {
             Level::Debug => format!("{} {}", "*".black(), message.black()),
             Level::Trace => format!("{} {}", "-".black(), message),
             Level::Info => format!("{} {}", "+".blue(), message),
@@ -150,37 +205,50 @@ impl Logger {
     }
 
     pub fn log_success(target: &str, message: &str) {
+        if let Some(line) = Self::success_line(target, message) {
+            println!("{}", line);
+        }
+    }
+
+    /// Record a message in the crash log buffer and return its console line, or `None`
+    /// if `level` is not displayed.
+    ///
+    /// Lets output implementations write the line themselves, to their own writer.
+    pub fn console_line(level: Level, target: &str, message: &str) -> Option<String> {
+        Self::add_to_buffer(LogEntry {
+            timestamp: Utc::now(),
+            level: LogLevel::Standard(level),
+            target: target.to_string(),
+            message: message.to_string(),
+        });
+
+        Self::should_display(level).then(|| Self::format_console_message(level, message))
+    }
+
+    /// Like [`Logger::console_line`] for a SUCCESS message, displayed unless in quiet mode.
+    pub fn success_line(target: &str, message: &str) -> Option<String> {
         let entry = LogEntry::new_success(target.to_string(), message.to_string());
 
         // Add to buffer for crash logging
         Self::add_to_buffer(entry);
 
-        // Display SUCCESS messages unless in quiet mode
-        if !Self::is_quiet() {
-            let formatted_message = Self::format_success_message(message);
-            println!("{}", formatted_message);
-        }
+        (!Self::is_quiet()).then(|| Self::format_success_message(message))
     }
 
     pub fn write_crash_log() -> Result<PathBuf, Box<dyn std::error::Error>> {
-        let home_dir = dirs::home_dir().ok_or("Could not find home directory")?;
-        let amplitude_dir = home_dir.join(".amplitude");
+        let state_dir = paths::resolve()?.state_dir;
 
-        // Create .amplitude directory if it doesn't exist
-        fs::create_dir_all(&amplitude_dir)?;
+        // Create the state directory if it doesn't exist
+        fs::create_dir_all(&state_dir)?;
 
-        let timestamp = Local::now().format("%Y%m%d_%H%M%S%.3f");
-        let log_file_path = amplitude_dir.join(format!("{}.log", timestamp));
+        let now = Utc::now();
+        let log_file_path = state_dir.join(format!("{}.log", now.format("%Y%m%dT%H%M%S%.3fZ")));
 
         let mut file = fs::File::create(&log_file_path)?;
 
         // Write crash header
         writeln!(file, "=== AMPLITUDE CLI CRASH LOG ===")?;
-        writeln!(
-            file,
-            "Crash time: {}",
-            Local::now().format("%Y-%m-%d %H:%M:%S")
-        )?;
+        writeln!(file, "Crash time: {}", utc_timestamp(now))?;
         writeln!(file, "================================\n")?;
 
         if let Ok(buffer_opt) = LOG_BUFFER.lock() {
@@ -216,7 +284,8 @@ impl Log for Logger {
 
             // Display to console based on level and verbose mode
             if Self::should_display(record.level()) {
-                let formatted_message = Self::format_console_message(record);
+                let message = record.args().to_string();
+                let formatted_message = Self::format_console_message(record.level(), &message);
                 println!("{}", formatted_message);
             }
         }
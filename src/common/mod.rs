@@ -12,7 +12,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod cancellation;
+pub mod env_file;
+pub mod editor;
 pub mod errors;
+pub mod file_types;
 pub mod files;
+pub mod format;
+pub mod fs_ctx;
+pub mod glob;
+pub mod hashing;
+pub mod hooks;
+pub mod ignore;
+pub mod lock;
+pub mod log_tail;
 pub mod logger;
+pub mod paths;
+pub mod process;
+pub mod project_option;
+pub mod settings;
+pub mod stdin;
+pub mod telemetry;
+pub mod template_archive;
+pub mod template_vars;
+pub mod update;
 pub mod utils;
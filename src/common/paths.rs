@@ -0,0 +1,236 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Location of the Amplitude home directories.
+//!
+//! The CLI keeps two kinds of files outside of projects:
+//!
+//! - data: the `am.db` database, and anything that must survive (templates, snapshots,
+//!   cached SDK schemas, database backups).
+//! - state: logs and crash logs, which may be deleted at any time.
+//!
+//! [`resolve`] chooses where they live, in order of precedence:
+//!
+//! 1. `AM_HOME`, holding both data and state.
+//! 2. The legacy `~/.amplitude` directory, holding both, when it already exists.
+//! 3. On Linux, `$XDG_DATA_HOME/amplitude` and `$XDG_STATE_HOME/amplitude`, defaulting to
+//!    `~/.local/share/amplitude` and `~/.local/state/amplitude`.
+//! 4. `~/.amplitude` on the other platforms.
+//!
+//! `am sudo migrate-home` moves an existing legacy directory to the XDG layout.
+//!
+//! The home directories are resolved once per process. When the user's home directory
+//! can't be determined and `AM_HOME` isn't set, every lookup fails with the same
+//! [`codes::ERR_HOME_NOT_FOUND`] error.
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use anyhow::Result;
+
+use crate::common::errors::{CliError, codes};
+
+/// Name of the legacy home directory, in the user's home directory.
+pub const LEGACY_HOME_DIR: &str = ".amplitude";
+
+/// Name of the directory created under the XDG base directories.
+pub const XDG_APP_DIR: &str = "amplitude";
+
+/// Name of the directory of the imported templates, in the data directory.
+pub const TEMPLATES_DIR: &str = "templates";
+
+/// Name of the directory of the SDK schemas cached with `am sdk schemas sync`, in the
+/// data directory.
+pub const SCHEMAS_DIR: &str = "schemas";
+
+/// Name of the directory of the database backups made before migrations, in the data
+/// directory.
+pub const BACKUPS_DIR: &str = "backups";
+
+/// Where the home directories were resolved from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HomeLayout {
+    /// `AM_HOME` is set.
+    AmHome,
+    /// The legacy `~/.amplitude` directory.
+    Legacy,
+    /// The XDG base directories.
+    Xdg,
+}
+
+/// The resolved Amplitude home directories.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AmPaths {
+    /// Directory of the database and of the other persistent files.
+    pub data_dir: PathBuf,
+    /// Directory of the logs and crash logs.
+    pub state_dir: PathBuf,
+    /// Where the directories were resolved from.
+    pub layout: HomeLayout,
+}
+
+impl AmPaths {
+    /// Path of the database file.
+    pub fn database_path(&self) -> PathBuf {
+        self.data_dir.join("am.db")
+    }
+
+    /// Directory of the templates imported with `am template import`.
+    pub fn templates_dir(&self) -> PathBuf {
+        self.data_dir.join(TEMPLATES_DIR)
+    }
+
+    /// Directory of the SDK schemas cached with `am sdk schemas sync`, one subdirectory
+    /// per SDK version.
+    pub fn schemas_dir(&self) -> PathBuf {
+        self.data_dir.join(SCHEMAS_DIR)
+    }
+
+    /// Directory of the database backups made before migrations.
+    pub fn backups_dir(&self) -> PathBuf {
+        self.data_dir.join(BACKUPS_DIR)
+    }
+}
+
+/// The environment [`resolve_from`] resolves the home directories from.
+#[derive(Debug, Clone, Default)]
+pub struct HomeEnv {
+    /// Value of `AM_HOME`.
+    pub am_home: Option<PathBuf>,
+    /// The user's home directory.
+    pub home: Option<PathBuf>,
+    /// Value of `XDG_DATA_HOME`.
+    pub xdg_data_home: Option<PathBuf>,
+    /// Value of `XDG_STATE_HOME`.
+    pub xdg_state_home: Option<PathBuf>,
+    /// Whether the XDG layout is used when there is no legacy directory.
+    pub use_xdg: bool,
+}
+
+impl HomeEnv {
+    /// Read the environment of the current process.
+    pub fn current() -> Self {
+        Self {
+            am_home: env_path("AM_HOME"),
+            home: user_home(),
+            xdg_data_home: env_path("XDG_DATA_HOME"),
+            xdg_state_home: env_path("XDG_STATE_HOME"),
+            use_xdg: cfg!(target_os = "linux"),
+        }
+    }
+
+    /// The legacy `~/.amplitude` directory, whether it exists or not.
+    pub fn legacy_dir(&self) -> Option<PathBuf> {
+        self.home.as_ref().map(|home| home.join(LEGACY_HOME_DIR))
+    }
+
+    /// The XDG layout, whether its directories exist or not.
+    pub fn xdg_paths(&self) -> Option<AmPaths> {
+        let home = self.home.as_deref()?;
+        Some(AmPaths {
+            data_dir: xdg_base(self.xdg_data_home.as_deref(), home, ".local/share")
+                .join(XDG_APP_DIR),
+            state_dir: xdg_base(self.xdg_state_home.as_deref(), home, ".local/state")
+                .join(XDG_APP_DIR),
+            layout: HomeLayout::Xdg,
+        })
+    }
+}
+
+/// Variable holding the user's home directory.
+#[cfg(windows)]
+pub const HOME_VAR: &str = "USERPROFILE";
+/// Variable holding the user's home directory.
+#[cfg(not(windows))]
+pub const HOME_VAR: &str = "HOME";
+
+/// The user's home directory, read once per process from [`HOME_VAR`].
+///
+/// Only the environment is consulted, not the password database: a container running
+/// under an arbitrary uid gets the same answer whatever the uid.
+pub fn user_home() -> Option<PathBuf> {
+    static HOME: OnceLock<Option<PathBuf>> = OnceLock::new();
+    HOME.get_or_init(|| env_path(HOME_VAR)).clone()
+}
+
+/// Resolve the home directories of the current process.
+///
+/// The result, success or failure, is computed once and reused by every later call.
+pub fn resolve() -> Result<AmPaths> {
+    static RESOLVED: OnceLock<Result<AmPaths, CliError>> = OnceLock::new();
+    RESOLVED
+        .get_or_init(|| resolve_from(&HomeEnv::current()))
+        .clone()
+        .map_err(Into::into)
+}
+
+/// Resolve the home directories from `env`, see the module documentation.
+///
+/// # Errors
+///
+/// Returns an `ERR_HOME_NOT_FOUND` error when neither `AM_HOME` nor the user's home
+/// directory is known.
+pub fn resolve_from(env: &HomeEnv) -> Result<AmPaths, CliError> {
+    if let Some(am_home) = &env.am_home {
+        return Ok(AmPaths {
+            data_dir: am_home.clone(),
+            state_dir: am_home.clone(),
+            layout: HomeLayout::AmHome,
+        });
+    }
+
+    let legacy = env.legacy_dir().ok_or_else(home_not_found)?;
+
+    if env.use_xdg
+        && !legacy.exists()
+        && let Some(xdg) = env.xdg_paths()
+    {
+        return Ok(xdg);
+    }
+
+    Ok(AmPaths {
+        data_dir: legacy.clone(),
+        state_dir: legacy,
+        layout: HomeLayout::Legacy,
+    })
+}
+
+fn home_not_found() -> CliError {
+    CliError::new(
+        codes::ERR_HOME_NOT_FOUND,
+        "Could not determine the home directory",
+        "The CLI keeps its database, logs and templates in the user's home directory, \
+         which is not set in this environment (HOME, or USERPROFILE on Windows)",
+    )
+    .with_suggestion(
+        "Set the AM_HOME environment variable to the directory where the CLI should keep its data",
+    )
+}
+
+fn env_path(name: &str) -> Option<PathBuf> {
+    std::env::var_os(name)
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+}
+
+/// The XDG base directory from its variable, or `home/fallback`.
+///
+/// The specification requires the variables to hold absolute paths, relative ones are
+/// ignored.
+fn xdg_base(value: Option<&Path>, home: &Path, fallback: &str) -> PathBuf {
+    match value {
+        Some(path) if path.is_absolute() => path.to_path_buf(),
+        _ => home.join(fallback),
+    }
+}
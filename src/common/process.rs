@@ -0,0 +1,109 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Inspection of the system process table.
+//!
+//! Used to reclaim locks left behind by dead processes, to find other running CLI
+//! invocations when diagnosing a locked database, and to detect the shell running the
+//! CLI.
+
+use serde::Serialize;
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+
+/// A running process, as listed by [`find_cli_processes`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProcessInfo {
+    /// Process ID.
+    pub pid: u32,
+    /// Executable name.
+    pub name: String,
+    /// Full command line, when the system exposes it.
+    pub command: String,
+}
+
+/// Check whether a process with the given PID is running.
+///
+/// On systems where the process table can't be read the process is assumed alive, so a
+/// lock is never reclaimed by mistake.
+pub fn process_is_alive(pid: u32) -> bool {
+    if pid == std::process::id() || !sysinfo::IS_SUPPORTED_SYSTEM {
+        return true;
+    }
+
+    let pid = Pid::from_u32(pid);
+    let mut system = System::new();
+    system.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+
+    system.process(pid).is_some()
+}
+
+/// List the running `am` processes other than the current one, sorted by PID.
+pub fn find_cli_processes() -> Vec<ProcessInfo> {
+    let mut system = System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        true,
+        ProcessRefreshKind::nothing().with_cmd(UpdateKind::OnlyIfNotSet),
+    );
+
+    let current = std::process::id();
+    let mut processes: Vec<ProcessInfo> = system
+        .processes()
+        .iter()
+        .filter(|(pid, _)| pid.as_u32() != current)
+        .filter_map(|(pid, process)| {
+            let name = process.name().to_string_lossy().into_owned();
+            if !is_cli_executable(&name) {
+                return None;
+            }
+
+            let command = process
+                .cmd()
+                .iter()
+                .map(|arg| arg.to_string_lossy())
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            Some(ProcessInfo {
+                pid: pid.as_u32(),
+                name,
+                command,
+            })
+        })
+        .collect();
+
+    processes.sort_by_key(|p| p.pid);
+    processes
+}
+
+/// Executable name of the process that started the current one, e.g. the shell running
+/// the CLI, if the process table can be read.
+pub fn parent_process_name() -> Option<String> {
+    let current = Pid::from_u32(std::process::id());
+    let mut system = System::new();
+    system.refresh_processes(ProcessesToUpdate::Some(&[current]), true);
+    let parent = system.process(current)?.parent()?;
+
+    system.refresh_processes(ProcessesToUpdate::Some(&[parent]), true);
+    let name = system
+        .process(parent)?
+        .name()
+        .to_string_lossy()
+        .into_owned();
+    Some(name)
+}
+
+fn is_cli_executable(name: &str) -> bool {
+    name == "am" || name.eq_ignore_ascii_case("am.exe")
+}
@@ -0,0 +1,116 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The global `--project <NAME|PATH>` option.
+//!
+//! Project-scoped commands (`am project info`, `validate`, `build`, the `am asset`
+//! commands, ...) work on the project of the current directory. With `--project`, they
+//! work on the given project instead: `main` [resolves](resolve_project_option) the
+//! option once per invocation and hands the directory to the handlers, like the
+//! database, and [`project_dir`] returns it in place of the current directory.
+//!
+//! The value is a path when it contains a path separator or names an existing
+//! directory, relative to the current directory, and the name of a registered project
+//! otherwise. A path is either the project directory or its `.amproject` file.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::common::errors::{CliError, codes};
+use crate::database::{Database, db_get_project_by_name};
+
+/// How the value of `--project` designates a project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProjectSelector {
+    /// The name of a registered project.
+    Name(String),
+    /// A path to the project directory or its `.amproject` file.
+    Path(PathBuf),
+}
+
+impl ProjectSelector {
+    /// Read `value` as a path when it contains a path separator or names an existing
+    /// directory of `base`, and as a project name otherwise.
+    pub fn parse(value: &str, base: &Path) -> Self {
+        if value.chars().any(std::path::is_separator) || base.join(value).is_dir() {
+            ProjectSelector::Path(PathBuf::from(value))
+        } else {
+            ProjectSelector::Name(value.to_string())
+        }
+    }
+}
+
+/// The directory of the project `value` designates, with relative paths resolved
+/// against `base`.
+///
+/// # Errors
+///
+/// - `ERR_PROJECT_NOT_INITIALIZED` when the path doesn't hold a `.amproject` file.
+/// - `ERR_PROJECT_NOT_REGISTERED` when no project is registered under the name.
+/// - `ERR_DATABASE_UNAVAILABLE` for a name without the database.
+pub fn resolve_project_option(
+    value: &str,
+    base: &Path,
+    database: Option<Arc<Database>>,
+) -> Result<PathBuf> {
+    match ProjectSelector::parse(value, base) {
+        ProjectSelector::Path(path) => {
+            let path = base.join(path);
+            let is_amproject = path.file_name().is_some_and(|name| name == ".amproject");
+            let dir = match path.parent() {
+                Some(parent) if is_amproject && path.is_file() => parent.to_path_buf(),
+                _ => path,
+            };
+            // An explicit directory never falls back to one of its parents
+            if !dir.join(".amproject").is_file() {
+                return Err(CliError::new(
+                    codes::ERR_PROJECT_NOT_INITIALIZED,
+                    "No project found at the --project path",
+                    format!("'{}' does not contain a .amproject file", dir.display()),
+                )
+                .with_suggestion("Pass the project directory or the name of a registered project")
+                .into());
+            }
+            Ok(std::path::absolute(dir)?)
+        }
+        ProjectSelector::Name(name) if database.is_none() => Err(CliError::new(
+            codes::ERR_DATABASE_UNAVAILABLE,
+            format!("Can't look up the project '{}'", name),
+            "Looking a project up by name reads the registry, but the database isn't open",
+        )
+        .with_suggestion("Pass the path of the project to --project instead")
+        .into()),
+        ProjectSelector::Name(name) => match db_get_project_by_name(&name, database)? {
+            Some(project) => Ok(PathBuf::from(project.path)),
+            None => Err(CliError::new(
+                codes::ERR_PROJECT_NOT_REGISTERED,
+                format!("Project '{}' not found", name),
+                "The value of --project is neither a directory nor a registered project",
+            )
+            .with_suggestion("Use 'am project list' to see registered projects, or pass a path")
+            .into()),
+        },
+    }
+}
+
+/// The directory project-scoped commands work in: the `project` given with `--project`,
+/// or else the current directory.
+pub fn project_dir(project: Option<&Path>) -> std::io::Result<PathBuf> {
+    match project {
+        Some(dir) => Ok(dir.to_path_buf()),
+        None => std::env::current_dir(),
+    }
+}
@@ -0,0 +1,275 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Effective settings of an invocation.
+//!
+//! Each setting of [`SETTINGS`] can be set at four levels, the first one found winning:
+//!
+//! 1. A command line flag, for the settings that have one.
+//! 2. The `AM_<KEY>` environment variable, e.g. `AM_SDK_PATH` for `sdk_path`.
+//! 3. The `configuration` table of the database.
+//! 4. The built-in default, if any.
+//!
+//! [`Settings`] resolves them once per invocation and records where each value came
+//! from, shown by `am sudo config list --effective`. [`install`] makes them available
+//! to the rest of the process through [`current`].
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, OnceLock};
+
+use log::debug;
+
+use crate::common::update::{
+    CONFIG_AUTO_UPDATE, CONFIG_UPDATE_CHECK_URL, DEFAULT_UPDATE_CHECK_URL,
+};
+use crate::database::backup::CONFIG_AUTO_BACKUP_BEFORE_MIGRATION;
+use crate::database::history::CONFIG_HISTORY_ENABLED;
+use crate::database::size::{
+    CONFIG_DB_SIZE_LIMIT, CONFIG_DB_SIZE_WARNING, DEFAULT_DB_SIZE_LIMIT, DEFAULT_DB_SIZE_WARNING,
+};
+use crate::database::{Database, db_get_all_config};
+
+/// Configuration key holding the SDK installation path.
+pub const CONFIG_SDK_PATH: &str = "sdk_path";
+
+/// Configuration key holding the color preference.
+pub const CONFIG_COLOR: &str = "color";
+
+/// Configuration key holding the default verbosity.
+pub const CONFIG_VERBOSITY: &str = "verbosity";
+
+/// Prefix of the environment variables overriding settings.
+pub const ENV_PREFIX: &str = "AM_";
+
+/// A setting resolved by [`Settings`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SettingDef {
+    /// Key of the setting in the `configuration` table.
+    pub key: &'static str,
+    /// Value used when the setting is set nowhere.
+    pub default: Option<&'static str>,
+}
+
+impl SettingDef {
+    /// Name of the environment variable overriding the setting.
+    pub fn env_var(&self) -> String {
+        format!("{}{}", ENV_PREFIX, self.key.to_ascii_uppercase())
+    }
+}
+
+/// Every setting resolved by [`Settings`].
+pub const SETTINGS: &[SettingDef] = &[
+    SettingDef {
+        key: CONFIG_SDK_PATH,
+        default: None,
+    },
+    SettingDef {
+        key: CONFIG_COLOR,
+        default: Some("auto"),
+    },
+    SettingDef {
+        key: CONFIG_VERBOSITY,
+        default: Some("normal"),
+    },
+    SettingDef {
+        key: CONFIG_AUTO_UPDATE,
+        default: Some("true"),
+    },
+    SettingDef {
+        key: CONFIG_UPDATE_CHECK_URL,
+        default: Some(DEFAULT_UPDATE_CHECK_URL),
+    },
+    SettingDef {
+        key: CONFIG_DB_SIZE_WARNING,
+        default: Some(DEFAULT_DB_SIZE_WARNING),
+    },
+    SettingDef {
+        key: CONFIG_DB_SIZE_LIMIT,
+        default: Some(DEFAULT_DB_SIZE_LIMIT),
+    },
+    SettingDef {
+        key: CONFIG_HISTORY_ENABLED,
+        default: Some("true"),
+    },
+    SettingDef {
+        key: CONFIG_AUTO_BACKUP_BEFORE_MIGRATION,
+        default: Some("true"),
+    },
+];
+
+/// Where the value of a setting came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingSource {
+    Default,
+    Config,
+    Env,
+    Flag,
+}
+
+impl SettingSource {
+    /// Name of the source, as shown by `am sudo config list --effective`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SettingSource::Default => "default",
+            SettingSource::Config => "config",
+            SettingSource::Env => "env",
+            SettingSource::Flag => "flag",
+        }
+    }
+}
+
+impl fmt::Display for SettingSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The value of a setting and where it came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedSetting {
+    pub key: &'static str,
+    /// `None` for a setting without default that is set nowhere.
+    pub value: Option<String>,
+    pub source: SettingSource,
+}
+
+/// The settings of an invocation, merged from flags, environment, configuration table
+/// and defaults.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Settings {
+    resolved: Vec<ResolvedSetting>,
+}
+
+impl Settings {
+    /// Resolve every setting of [`SETTINGS`].
+    ///
+    /// `flags` maps keys to the values given on the command line, `env` returns the
+    /// value of an environment variable and `config` holds the configuration table.
+    /// Empty environment variables are ignored.
+    pub fn resolve(
+        flags: &BTreeMap<&str, String>,
+        env: impl Fn(&str) -> Option<String>,
+        config: &BTreeMap<String, String>,
+    ) -> Self {
+        let resolved = SETTINGS
+            .iter()
+            .map(|def| {
+                let (value, source) = if let Some(value) = flags.get(def.key) {
+                    (Some(value.clone()), SettingSource::Flag)
+                } else if let Some(value) = env(&def.env_var()).filter(|v| !v.is_empty()) {
+                    (Some(value), SettingSource::Env)
+                } else if let Some(value) = config.get(def.key) {
+                    (Some(value.clone()), SettingSource::Config)
+                } else {
+                    (def.default.map(str::to_string), SettingSource::Default)
+                };
+
+                ResolvedSetting {
+                    key: def.key,
+                    value,
+                    source,
+                }
+            })
+            .collect();
+
+        Self { resolved }
+    }
+
+    /// Resolve the settings from `flags`, the environment of the process and the
+    /// configuration table of `database`, if there is one.
+    ///
+    /// A configuration table that can't be read is skipped, like a missing database.
+    pub fn load(flags: &BTreeMap<&str, String>, database: Option<Arc<Database>>) -> Self {
+        let config = match database {
+            Some(_) => db_get_all_config(database).unwrap_or_else(|e| {
+                debug!("Failed to read the configuration table: {}", e);
+                BTreeMap::new()
+            }),
+            None => BTreeMap::new(),
+        };
+
+        Self::resolve(flags, |name| std::env::var(name).ok(), &config)
+    }
+
+    /// Every setting with its value and source, in the order of [`SETTINGS`].
+    pub fn all(&self) -> &[ResolvedSetting] {
+        &self.resolved
+    }
+
+    /// The resolved setting `key`, if it is one of [`SETTINGS`].
+    pub fn setting(&self, key: &str) -> Option<&ResolvedSetting> {
+        self.resolved.iter().find(|setting| setting.key == key)
+    }
+
+    /// The value of the setting `key`.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.setting(key)?.value.as_deref()
+    }
+
+    /// Where the value of the setting `key` came from.
+    pub fn source(&self, key: &str) -> Option<SettingSource> {
+        self.setting(key).map(|setting| setting.source)
+    }
+
+    /// The configured SDK installation path.
+    pub fn sdk_path(&self) -> Option<&str> {
+        self.get(CONFIG_SDK_PATH)
+    }
+
+    /// The color preference: `auto`, `always` or `never`.
+    pub fn color(&self) -> &str {
+        self.get(CONFIG_COLOR).unwrap_or("auto")
+    }
+
+    /// The verbosity: `normal`, `verbose` or `quiet`.
+    pub fn verbosity(&self) -> &str {
+        self.get(CONFIG_VERBOSITY).unwrap_or("normal")
+    }
+
+    /// Whether the update check is enabled, unless set to `false`.
+    pub fn auto_update(&self) -> bool {
+        !self
+            .get(CONFIG_AUTO_UPDATE)
+            .is_some_and(|v| v.trim().eq_ignore_ascii_case("false"))
+    }
+
+    /// Whether commands are recorded in the [history](crate::database::history), unless
+    /// set to `false`.
+    pub fn history_enabled(&self) -> bool {
+        !self
+            .get(CONFIG_HISTORY_ENABLED)
+            .is_some_and(|v| v.trim().eq_ignore_ascii_case("false"))
+    }
+
+    /// Whether the database is [backed up](crate::database::backup) before migrations,
+    /// unless set to `false`.
+    pub fn auto_backup_before_migration(&self) -> bool {
+        !self
+            .get(CONFIG_AUTO_BACKUP_BEFORE_MIGRATION)
+            .is_some_and(|v| v.trim().eq_ignore_ascii_case("false"))
+    }
+}
+
+static CURRENT: OnceLock<Settings> = OnceLock::new();
+
+/// Make `settings` the settings of this process. Only the first call has an effect.
+pub fn install(settings: Settings) {
+    let _ = CURRENT.set(settings);
+}
+
+/// The settings installed for this process, if any.
+pub fn current() -> Option<&'static Settings> {
+    CURRENT.get()
+}
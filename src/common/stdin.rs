@@ -0,0 +1,65 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Command entries read from stdin.
+//!
+//! Commands accepting `--stdin` read either a single JSON object or an array of objects,
+//! so other tools can pipe their requests without going through shell quoting.
+
+use serde::de::DeserializeOwned;
+
+use crate::common::errors::{CliError, codes};
+
+/// Parse `content` as one entry or an array of entries.
+///
+/// The whole input is rejected when it isn't valid JSON or when any entry doesn't match
+/// `T`, before any entry is processed.
+pub fn parse_json_entries<T: DeserializeOwned>(content: &str) -> Result<Vec<T>, CliError> {
+    let value: serde_json::Value = serde_json::from_str(content).map_err(|e| {
+        CliError::new(
+            codes::ERR_VALIDATION_SCHEMA,
+            "Invalid JSON on stdin",
+            e.to_string(),
+        )
+        .with_suggestion("Pipe a JSON object, or an array of JSON objects")
+    })?;
+
+    let (values, is_array) = match value {
+        serde_json::Value::Array(values) => (values, true),
+        value @ serde_json::Value::Object(_) => (vec![value], false),
+        _ => {
+            return Err(CliError::new(
+                codes::ERR_VALIDATION_SCHEMA,
+                "Invalid JSON on stdin",
+                "Expected a JSON object or an array of JSON objects",
+            )
+            .with_suggestion("Pipe a JSON object, or an array of JSON objects"));
+        }
+    };
+
+    values
+        .into_iter()
+        .enumerate()
+        .map(|(index, value)| {
+            serde_json::from_value(value).map_err(|e| {
+                let entry = if is_array {
+                    format!("Invalid entry {} on stdin", index)
+                } else {
+                    "Invalid entry on stdin".to_string()
+                };
+                CliError::new(codes::ERR_VALIDATION_SCHEMA, entry, e.to_string())
+            })
+        })
+        .collect()
+}
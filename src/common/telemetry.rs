@@ -0,0 +1,161 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Trace spans of the CLI, exported over OTLP.
+//!
+//! The CLI always creates `tracing` spans for the command run, the database queries and
+//! migrations, and the project file walks. They are only recorded when the CLI is built
+//! with the `otel` feature and tracing is enabled, either with `--trace` or by pointing
+//! the standard `OTEL_EXPORTER_OTLP_*` environment variables to a collector. Otherwise no
+//! subscriber is installed and the spans cost next to nothing.
+//!
+//! The exporter is configured from the standard `OTEL_*` environment variables
+//! (endpoint, headers, service name, resource attributes...).
+
+use anyhow::Result;
+use clap::CommandFactory;
+use tracing::Span;
+
+use crate::app::App;
+
+/// Exports the recorded spans until dropped.
+///
+/// Dropping the guard flushes the spans not exported yet.
+#[derive(Default)]
+pub struct TelemetryGuard {
+    #[cfg(feature = "otel")]
+    provider: Option<opentelemetry_sdk::trace::SdkTracerProvider>,
+}
+
+#[cfg(feature = "otel")]
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        if let Some(provider) = self.provider.take()
+            && let Err(e) = provider.shutdown()
+        {
+            log::debug!("Failed to flush the trace spans: {}", e);
+        }
+    }
+}
+
+/// Whether spans should be exported: with `--trace` (`force`), or when an OTLP endpoint
+/// is set in the environment and `OTEL_SDK_DISABLED` isn't `true`.
+pub fn tracing_requested(force: bool) -> bool {
+    let is_set = |name: &str| std::env::var_os(name).is_some_and(|value| !value.is_empty());
+    let disabled = std::env::var("OTEL_SDK_DISABLED")
+        .is_ok_and(|value| value.trim().eq_ignore_ascii_case("true"));
+
+    force
+        || (!disabled
+            && (is_set("OTEL_EXPORTER_OTLP_ENDPOINT")
+                || is_set("OTEL_EXPORTER_OTLP_TRACES_ENDPOINT")))
+}
+
+/// Start exporting spans if [requested](tracing_requested).
+///
+/// Fails when `--trace` is given to a CLI built without the `otel` feature; an OTLP
+/// endpoint set in the environment is ignored by such a build.
+pub fn init(force: bool) -> Result<TelemetryGuard> {
+    if !tracing_requested(force) {
+        return Ok(TelemetryGuard::default());
+    }
+
+    #[cfg(feature = "otel")]
+    {
+        let exporter = opentelemetry_otlp::SpanExporter::builder()
+            .with_http()
+            .build()?;
+        let provider = opentelemetry_sdk::trace::SdkTracerProvider::builder()
+            .with_batch_exporter(exporter)
+            .with_resource(otel::resource())
+            .build();
+        tracing::subscriber::set_global_default(otel::subscriber(&provider))?;
+
+        Ok(TelemetryGuard {
+            provider: Some(provider),
+        })
+    }
+
+    #[cfg(not(feature = "otel"))]
+    {
+        if force {
+            return Err(crate::common::errors::CliError::new(
+                crate::common::errors::codes::ERR_VALIDATION_FIELD,
+                "Tracing is not available",
+                "This build of am doesn't include the otel feature",
+            )
+            .with_suggestion("Build am with 'cargo build --features otel', or run without --trace")
+            .into());
+        }
+        Ok(TelemetryGuard::default())
+    }
+}
+
+/// The root span of the command run by `args`, e.g. `am project list`.
+///
+/// Only the subcommands are recorded, not their arguments and flags, which may hold
+/// paths and names.
+pub fn command_span<I, S>(args: I) -> Span
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    tracing::info_span!("command", command = %command_path(args))
+}
+
+/// The command run by `args` without its arguments and flags, e.g. `am project list`.
+pub fn command_path<I, S>(args: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut command = App::command();
+    let mut path = vec![command.get_name().to_string()];
+    for arg in args.into_iter().skip(1) {
+        let Some(subcommand) = command.find_subcommand(arg.as_ref()).cloned() else {
+            continue;
+        };
+        path.push(subcommand.get_name().to_string());
+        command = subcommand;
+    }
+
+    path.join(" ")
+}
+
+#[cfg(feature = "otel")]
+pub use otel::subscriber;
+
+#[cfg(feature = "otel")]
+mod otel {
+    use opentelemetry::trace::TracerProvider as _;
+    use opentelemetry_sdk::Resource;
+    use opentelemetry_sdk::trace::SdkTracerProvider;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    /// Subscriber recording the spans of the CLI into `provider`.
+    pub fn subscriber(provider: &SdkTracerProvider) -> impl tracing::Subscriber + Send + Sync {
+        tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("am")))
+    }
+
+    /// Resource describing the CLI, unless `OTEL_SERVICE_NAME` names it otherwise.
+    pub(super) fn resource() -> Resource {
+        let builder = Resource::builder();
+        if std::env::var_os("OTEL_SERVICE_NAME").is_some() {
+            builder.build()
+        } else {
+            builder.with_service_name("am").build()
+        }
+    }
+}
@@ -0,0 +1,375 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Shareable template archives, written by `am template export` and read by
+//! `am template import`.
+//!
+//! An archive is a gzip-compressed tar file with the `.amtpl` extension holding:
+//!
+//! - `manifest.json`: the [`TemplateArchiveManifest`] describing the template.
+//! - `template/`: the files of the template directory.
+//!
+//! The manifest checksum is the SHA-256 of the template files, so a corrupted or altered
+//! archive is refused before anything is extracted.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{Context, Result};
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::common::errors::{CliError, codes};
+use crate::common::files::safe_remove_in_parent;
+use crate::common::fs_ctx;
+use crate::common::hashing::{CopyVerifier, VerifyReport, sha256_bytes};
+use crate::common::update::parse_version;
+
+/// Extension of template archives.
+pub const TEMPLATE_ARCHIVE_EXTENSION: &str = "amtpl";
+
+/// Version of the archive layout written by this CLI.
+pub const TEMPLATE_ARCHIVE_FORMAT: u32 = 1;
+
+/// Name of the manifest entry of an archive.
+const MANIFEST_ENTRY: &str = "manifest.json";
+
+/// Directory of the archive holding the template files.
+const TEMPLATE_ENTRY_DIR: &str = "template";
+
+/// Description of the template stored in an archive.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TemplateArchiveManifest {
+    /// Version of the archive layout.
+    pub format: u32,
+    /// Name the template was registered under.
+    pub name: String,
+    /// Version of the CLI that wrote the archive.
+    pub cli_version: String,
+    /// SHA-256 of the template files, see [`checksum`].
+    pub checksum: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub engine: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// The verified content of a template archive.
+#[derive(Debug)]
+pub struct TemplateArchive {
+    pub manifest: TemplateArchiveManifest,
+    /// Files of the template, relative to its directory, sorted by path.
+    files: Vec<(PathBuf, Vec<u8>)>,
+}
+
+impl TemplateArchive {
+    /// Number of files in the template.
+    pub fn file_count(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Write the template files into `destination`, which must not exist yet.
+    ///
+    /// The directory is removed if any file can't be written.
+    pub fn extract(&self, destination: &Path) -> Result<()> {
+        fs_ctx::create_dir_all(destination)?;
+
+        let written = self.files.iter().try_for_each(|(path, content)| {
+            let target = destination.join(path);
+            if let Some(parent) = target.parent() {
+                fs_ctx::create_dir_all(parent)?;
+            }
+            fs_ctx::write(&target, content)
+        });
+
+        if written.is_err() {
+            let _ = safe_remove_in_parent(destination);
+        }
+        written
+    }
+
+    /// Check that the files extracted into `destination` match those of the archive.
+    pub fn verify_extracted(&self, destination: &Path) -> VerifyReport {
+        let verifier = CopyVerifier::start();
+        for (path, content) in &self.files {
+            verifier.check(
+                entry_name(path),
+                destination.join(path),
+                sha256_bytes(content),
+                content.len() as u64,
+            );
+        }
+        verifier.finish()
+    }
+}
+
+/// Package the template directory `template_dir` into an archive at `destination`.
+///
+/// `name`, `engine` and `description` are stored in the manifest. Returns the manifest
+/// written.
+pub fn write_template_archive(
+    template_dir: &Path,
+    name: &str,
+    engine: Option<&str>,
+    description: Option<&str>,
+    destination: &Path,
+) -> Result<TemplateArchiveManifest> {
+    let files = read_template_files(template_dir)?;
+    let manifest = TemplateArchiveManifest {
+        format: TEMPLATE_ARCHIVE_FORMAT,
+        name: name.to_string(),
+        cli_version: env!("CARGO_PKG_VERSION").to_string(),
+        checksum: checksum(&files),
+        engine: engine.map(str::to_string),
+        description: description.map(str::to_string),
+    };
+
+    let file = fs::File::create(destination)
+        .with_context(|| format!("Failed to create {}", destination.display()))?;
+    let mut builder = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+
+    append_entry(
+        &mut builder,
+        Path::new(MANIFEST_ENTRY),
+        &serde_json::to_vec_pretty(&manifest)?,
+    )?;
+    for (path, content) in &files {
+        append_entry(
+            &mut builder,
+            &Path::new(TEMPLATE_ENTRY_DIR).join(path),
+            content,
+        )?;
+    }
+
+    builder.into_inner()?.finish()?;
+    Ok(manifest)
+}
+
+/// Read back the archive written at `archive` from `template_dir`, and check that it
+/// holds every file of the template unchanged.
+///
+/// An archive that can't be read back fails with `ERR_IO_CHECKSUM_MISMATCH`.
+pub fn verify_template_archive(archive: &Path, template_dir: &Path) -> Result<VerifyReport> {
+    let written = read_template_archive(archive).map_err(|e| {
+        CliError::new(
+            codes::ERR_IO_CHECKSUM_MISMATCH,
+            "The written archive can't be read back",
+            format!("{:#}", e),
+        )
+        .with_context(archive.display().to_string())
+    })?;
+    let written: BTreeMap<&Path, String> = written
+        .files
+        .iter()
+        .map(|(path, content)| (path.as_path(), sha256_bytes(content)))
+        .collect();
+
+    let mut report = VerifyReport::default();
+    for (path, content) in read_template_files(template_dir)? {
+        report.files += 1;
+        if written.get(path.as_path()) == Some(&sha256_bytes(&content)) {
+            report.bytes += content.len() as u64;
+        } else {
+            report.mismatches.push(entry_name(&path));
+        }
+    }
+    Ok(report)
+}
+
+/// Read the archive at `path` and verify its checksum.
+///
+/// Fails if the archive is not a template archive, uses a newer layout, holds paths
+/// escaping the template directory, or doesn't match its checksum.
+pub fn read_template_archive(path: &Path) -> Result<TemplateArchive> {
+    let file =
+        fs::File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+    let mut archive = tar::Archive::new(GzDecoder::new(file));
+
+    let mut manifest = None;
+    let mut files = Vec::new();
+    let entries = archive
+        .entries()
+        .map_err(|e| invalid_archive(path, e.to_string()))?;
+    for entry in entries {
+        let mut entry = entry.map_err(|e| invalid_archive(path, e.to_string()))?;
+        if entry.header().entry_type().is_dir() {
+            continue;
+        }
+
+        let entry_path = entry
+            .path()
+            .map_err(|e| invalid_archive(path, e.to_string()))?
+            .into_owned();
+        let mut content = Vec::new();
+        entry
+            .read_to_end(&mut content)
+            .map_err(|e| invalid_archive(path, e.to_string()))?;
+
+        if entry_path == Path::new(MANIFEST_ENTRY) {
+            manifest = Some(
+                serde_json::from_slice::<TemplateArchiveManifest>(&content)
+                    .map_err(|e| invalid_archive(path, format!("Invalid manifest: {}", e)))?,
+            );
+            continue;
+        }
+
+        let relative = entry_path
+            .strip_prefix(TEMPLATE_ENTRY_DIR)
+            .ok()
+            .filter(|p| is_safe_relative_path(p))
+            .ok_or_else(|| {
+                invalid_archive(path, format!("Unexpected entry '{}'", entry_path.display()))
+            })?;
+        if !entry.header().entry_type().is_file() {
+            return Err(invalid_archive(
+                path,
+                format!("'{}' is not a regular file", entry_path.display()),
+            ));
+        }
+        files.push((relative.to_path_buf(), content));
+    }
+
+    let manifest =
+        manifest.ok_or_else(|| invalid_archive(path, "The archive has no manifest".into()))?;
+    if manifest.format > TEMPLATE_ARCHIVE_FORMAT {
+        return Err(newer_archive(&manifest).into());
+    }
+
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+    if checksum(&files) != manifest.checksum {
+        return Err(CliError::new(
+            codes::ERR_INVALID_TEMPLATE_STRUCTURE,
+            format!("Template archive '{}' is corrupted", path.display()),
+            "The template files don't match the checksum of the manifest",
+        )
+        .with_suggestion("Export the template again")
+        .into());
+    }
+
+    Ok(TemplateArchive { manifest, files })
+}
+
+/// Check that the archive described by `manifest` was written by a CLI no newer than
+/// `current_version`.
+pub fn check_archive_cli_version(
+    manifest: &TemplateArchiveManifest,
+    current_version: &str,
+) -> Result<()> {
+    match (
+        parse_version(&manifest.cli_version),
+        parse_version(current_version),
+    ) {
+        (Some(archive), Some(current)) if archive <= current => Ok(()),
+        _ => Err(newer_archive(manifest).into()),
+    }
+}
+
+/// SHA-256 of the template files, as hex.
+///
+/// Each file contributes its relative path, with `/` separators, and its content, so
+/// renaming a file changes the checksum as well.
+pub fn checksum(files: &[(PathBuf, Vec<u8>)]) -> String {
+    let mut hasher = Sha256::new();
+    for (path, content) in files {
+        hasher.update(entry_name(path).as_bytes());
+        hasher.update([0]);
+        hasher.update((content.len() as u64).to_le_bytes());
+        hasher.update(content);
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+/// The files of `template_dir`, relative to it and sorted by path.
+///
+/// Symbolic links are not followed, the archive holds regular files only.
+fn read_template_files(template_dir: &Path) -> Result<Vec<(PathBuf, Vec<u8>)>> {
+    let mut files = Vec::new();
+    for entry in walkdir::WalkDir::new(template_dir).sort_by_file_name() {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let relative = entry
+            .path()
+            .strip_prefix(template_dir)
+            .unwrap_or(entry.path())
+            .to_path_buf();
+        let content = fs::read(entry.path())
+            .with_context(|| format!("Failed to read {}", entry.path().display()))?;
+        files.push((relative, content));
+    }
+
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(files)
+}
+
+fn append_entry<W: std::io::Write>(
+    builder: &mut tar::Builder<W>,
+    path: &Path,
+    content: &[u8],
+) -> Result<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(content.len() as u64);
+    header.set_mode(0o644);
+    header.set_entry_type(tar::EntryType::Regular);
+    builder.append_data(&mut header, entry_name(path), content)?;
+    Ok(())
+}
+
+/// `path` with `/` separators, as stored in archives and hashed on every platform.
+fn entry_name(path: &Path) -> String {
+    path.components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn is_safe_relative_path(path: &Path) -> bool {
+    path.components().next().is_some()
+        && path.components().all(|c| matches!(c, Component::Normal(_)))
+}
+
+fn invalid_archive(path: &Path, why: String) -> anyhow::Error {
+    CliError::new(
+        codes::ERR_INVALID_TEMPLATE_STRUCTURE,
+        format!("'{}' is not a valid template archive", path.display()),
+        why,
+    )
+    .with_suggestion(format!(
+        "Use a .{} file created by 'am template export'",
+        TEMPLATE_ARCHIVE_EXTENSION
+    ))
+    .into()
+}
+
+fn newer_archive(manifest: &TemplateArchiveManifest) -> CliError {
+    CliError::new(
+        codes::ERR_TEMPLATE_OPERATION_NOT_ALLOWED,
+        format!(
+            "Template '{}' was exported by a newer CLI ({})",
+            manifest.name, manifest.cli_version
+        ),
+        format!(
+            "This CLI ({}) may not support everything the template uses",
+            env!("CARGO_PKG_VERSION")
+        ),
+    )
+    .with_suggestion("Update the CLI to import this template")
+}
@@ -0,0 +1,382 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Variables substituted into the files of a template when a project is created from it.
+//!
+//! Template files reference variables as `{{name}}`, with optional spaces inside the
+//! braces. The [built-in variables](BUILTIN_VARIABLES) are always defined; the others are
+//! given with `--var name=value` or, when declared in the [`VARS_MANIFEST`] at the
+//! template root, prompted for. A literal `{{` is written `\{{` (or `\{\{`).
+//!
+//! The manifest lists the variables in the order they are prompted for:
+//!
+//! ```json
+//! {
+//!   "variables": [
+//!     { "name": "author", "description": "Author of the project" },
+//!     { "name": "studio", "default": "Sparky Studios" },
+//!     { "name": "license", "required": false }
+//!   ]
+//! }
+//! ```
+//!
+//! A variable is required unless it has a default or is declared with `"required": false`,
+//! in which case it is replaced by an empty string. Variables used in the files but not
+//! declared in the manifest are required.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use inquire::validator::Validation;
+use serde::Deserialize;
+
+use crate::common::errors::{CliError, codes};
+use crate::common::fs_ctx;
+use crate::common::ignore::{IgnoreRules, WalkOptions, walk_project_files};
+use crate::input::{Input, TextValidator, with_flag_hint};
+use crate::presentation::Output;
+
+/// Name of the manifest declaring the variables of a template, at the template root.
+///
+/// The manifest is not copied into the projects created from the template.
+pub const VARS_MANIFEST: &str = "template.vars.json";
+
+/// Variables defined for every template: the name of the created project, the current
+/// date (`YYYY-MM-DD`) and the version of the CLI.
+pub const BUILTIN_VARIABLES: &[&str] = &["project_name", "date", "cli_version"];
+
+/// Content of a [`VARS_MANIFEST`].
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TemplateVarsManifest {
+    #[serde(default)]
+    pub variables: Vec<TemplateVariable>,
+}
+
+/// A variable declared in a [`VARS_MANIFEST`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct TemplateVariable {
+    pub name: String,
+    /// Shown instead of the name when prompting for the variable.
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub default: Option<String>,
+    #[serde(default = "required_by_default")]
+    pub required: bool,
+}
+
+fn required_by_default() -> bool {
+    true
+}
+
+/// The template files referencing variables, found before anything is copied.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateScan {
+    /// Text files holding placeholders or escapes, relative to the template directory.
+    pub files: Vec<PathBuf>,
+    /// Names of the variables used in the files.
+    pub variables: BTreeSet<String>,
+}
+
+/// Whether `name` can name a variable: ASCII letters, digits and underscores, not
+/// starting with a digit.
+pub fn is_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Parse `--var` specs in format `name=value`.
+///
+/// Built-in variables can't be given. A name given several times keeps its last value.
+pub fn parse_var_specs(specs: &[String]) -> Result<BTreeMap<String, String>> {
+    let mut variables = BTreeMap::new();
+
+    for spec in specs {
+        let Some((name, value)) = spec.split_once('=') else {
+            return Err(CliError::new(
+                codes::ERR_VALIDATION_FIELD,
+                format!("Invalid template variable: '{}'", spec),
+                "Template variables must be in format 'name=value'",
+            )
+            .with_suggestion("Example: --var author='Jane Doe' --var studio=Sparky")
+            .into());
+        };
+
+        let name = name.trim();
+        if !is_variable_name(name) {
+            return Err(CliError::new(
+                codes::ERR_VALIDATION_FIELD,
+                format!("Invalid template variable name: '{}'", name),
+                "Variable names may only contain letters, digits and underscores",
+            )
+            .into());
+        }
+        if BUILTIN_VARIABLES.contains(&name) {
+            return Err(CliError::new(
+                codes::ERR_VALIDATION_FIELD,
+                format!("Cannot set the template variable '{}'", name),
+                "It is a built-in variable, set by the CLI",
+            )
+            .into());
+        }
+
+        variables.insert(name.to_string(), value.to_string());
+    }
+
+    Ok(variables)
+}
+
+/// Values of the [built-in variables](BUILTIN_VARIABLES) for `project_name`.
+pub fn builtin_variables(project_name: &str) -> BTreeMap<String, String> {
+    BTreeMap::from([
+        ("project_name".to_string(), project_name.to_string()),
+        (
+            "date".to_string(),
+            chrono::Local::now().format("%Y-%m-%d").to_string(),
+        ),
+        (
+            "cli_version".to_string(),
+            env!("CARGO_PKG_VERSION").to_string(),
+        ),
+    ])
+}
+
+/// Read the [`VARS_MANIFEST`] of the template at `template_dir`, if any.
+pub fn read_vars_manifest(template_dir: &Path) -> Result<TemplateVarsManifest> {
+    let path = template_dir.join(VARS_MANIFEST);
+    if !path.is_file() {
+        return Ok(TemplateVarsManifest::default());
+    }
+
+    let invalid = |why: String| {
+        CliError::new(
+            codes::ERR_INVALID_TEMPLATE_STRUCTURE,
+            format!("Invalid {}", VARS_MANIFEST),
+            why,
+        )
+        .with_context(path.display().to_string())
+    };
+
+    let manifest: TemplateVarsManifest =
+        serde_json::from_str(&fs::read_to_string(&path)?).map_err(|e| invalid(e.to_string()))?;
+    if let Some(variable) = manifest
+        .variables
+        .iter()
+        .find(|variable| !is_variable_name(&variable.name))
+    {
+        return Err(invalid(format!("'{}' is not a valid variable name", variable.name)).into());
+    }
+
+    Ok(manifest)
+}
+
+/// Find the files of the template at `template_dir` to substitute variables into.
+///
+/// Files that aren't UTF-8 text, like audio files, are left out. The directories `walk`
+/// skips are reported when the template is copied, not here.
+pub fn scan_template(template_dir: &Path, walk: WalkOptions) -> Result<TemplateScan> {
+    let mut scan = TemplateScan::default();
+
+    for path in walk_project_files(template_dir, &IgnoreRules::none(), walk).files {
+        let relative = path.strip_prefix(template_dir).unwrap_or(&path);
+        if relative == Path::new(VARS_MANIFEST) {
+            continue;
+        }
+        let Ok(text) = String::from_utf8(fs::read(&path)?) else {
+            continue;
+        };
+
+        if text.contains("{{") || text.contains(r"\{\{") {
+            scan.variables.extend(placeholders(&text));
+            scan.files.push(relative.to_path_buf());
+        }
+    }
+
+    Ok(scan)
+}
+
+/// Resolve the value of every variable of a template.
+///
+/// Declared variables are taken from `given`, prompted for when `input` is interactive, or
+/// fall back to their default. Fails listing the variables left without a value, so
+/// nothing is written for a project that couldn't be completed.
+pub fn resolve_variables(
+    manifest: &TemplateVarsManifest,
+    scan: &TemplateScan,
+    builtins: BTreeMap<String, String>,
+    given: &BTreeMap<String, String>,
+    input: &dyn Input,
+    output: &dyn Output,
+) -> Result<BTreeMap<String, String>> {
+    let mut values = builtins;
+    let mut unresolved = Vec::new();
+
+    for variable in &manifest.variables {
+        if values.contains_key(&variable.name) {
+            continue;
+        }
+
+        let value = if let Some(value) = given.get(&variable.name) {
+            Some(value.clone())
+        } else if input.is_interactive() {
+            Some(prompt_variable(variable, input)?)
+        } else if variable.default.is_some() {
+            variable.default.clone()
+        } else if !variable.required {
+            Some(String::new())
+        } else {
+            None
+        };
+
+        match value {
+            Some(value) => {
+                values.insert(variable.name.clone(), value);
+            }
+            None => unresolved.push(variable.name.clone()),
+        }
+    }
+
+    for name in &scan.variables {
+        if values.contains_key(name) {
+            continue;
+        }
+
+        match given.get(name) {
+            Some(value) => {
+                values.insert(name.clone(), value.clone());
+            }
+            None => unresolved.push(name.clone()),
+        }
+    }
+
+    if !unresolved.is_empty() {
+        let flags: Vec<String> = unresolved
+            .iter()
+            .map(|name| format!("--var {}=<value>", name))
+            .collect();
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            "Unresolved template variables",
+            format!("No value was given for {}", unresolved.join(", ")),
+        )
+        .with_suggestion(format!("Re-run with {}", flags.join(" ")))
+        .into());
+    }
+
+    for name in given.keys().filter(|name| !values.contains_key(*name)) {
+        output.warning(&format!(
+            "Template variable '{}' is not used by the template",
+            name
+        ));
+    }
+
+    Ok(values)
+}
+
+fn prompt_variable(variable: &TemplateVariable, input: &dyn Input) -> Result<String> {
+    let prompt = variable.description.as_deref().unwrap_or(&variable.name);
+    let flag = format!("--var {}=<value>", variable.name);
+    let input = with_flag_hint(input, &flag);
+
+    if let Some(default) = &variable.default {
+        return input.prompt_text_with_default(prompt, default, &[]);
+    }
+
+    let not_empty = |value: &str| -> Result<Validation, inquire::CustomUserError> {
+        if value.trim().is_empty() {
+            Ok(Validation::Invalid("A value is required".into()))
+        } else {
+            Ok(Validation::Valid)
+        }
+    };
+    let validators: &[TextValidator] = if variable.required {
+        &[&not_empty]
+    } else {
+        &[]
+    };
+    input.prompt_text(prompt, None, None, validators)
+}
+
+/// Substitute `values` into the `files` of a [`TemplateScan`], once copied to `dir`.
+pub fn render_files(
+    dir: &Path,
+    files: &[PathBuf],
+    values: &BTreeMap<String, String>,
+) -> Result<()> {
+    for file in files {
+        let path = dir.join(file);
+        let text = fs::read_to_string(&path)?;
+        fs_ctx::write(&path, render(&text, values))?;
+    }
+
+    Ok(())
+}
+
+/// Replace the placeholders of `text` with their value and unescape `\{{`.
+///
+/// Placeholders of variables missing from `values` are left untouched.
+pub fn render(text: &str, values: &BTreeMap<String, String>) -> String {
+    substitute(text, |name| values.get(name).cloned())
+}
+
+/// Names of the variables used in `text`.
+pub fn placeholders(text: &str) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    substitute(text, |name| {
+        names.insert(name.to_string());
+        Some(String::new())
+    });
+    names
+}
+
+fn substitute(text: &str, mut value: impl FnMut(&str) -> Option<String>) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find(['\\', '{']) {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        if let Some(after) = rest
+            .strip_prefix(r"\{{")
+            .or_else(|| rest.strip_prefix(r"\{\{"))
+        {
+            result.push_str("{{");
+            rest = after;
+            continue;
+        }
+
+        if let Some(after) = rest.strip_prefix("{{")
+            && let Some(end) = after.find("}}")
+            && is_variable_name(after[..end].trim())
+            && let Some(value) = value(after[..end].trim())
+        {
+            result.push_str(&value);
+            rest = &after[end + 2..];
+            continue;
+        }
+
+        // Not a placeholder: `\` and `{` are both a single byte.
+        result.push_str(&rest[..1]);
+        rest = &rest[1..];
+    }
+
+    result.push_str(rest);
+    result
+}
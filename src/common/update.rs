@@ -0,0 +1,171 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! New version notices.
+//!
+//! After a successful interactive command, the CLI checks at most once a day whether a
+//! newer release is published. The check is driven by the `configuration` table:
+//!
+//! - `auto_update`: set to `false` to disable the check entirely.
+//! - `update_check_url`: URL of a plain text file holding the latest version number.
+//! - `last_update_check`: RFC 3339 timestamp of the last check, maintained by the CLI.
+//!
+//! Fetching goes through [`VersionFetcher`] so the network can be stubbed out. The CLI
+//! fetches over HTTPS in process, on its tokio runtime, so the check doesn't depend on
+//! any program of the `PATH`.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use anyhow::Context;
+use chrono::{DateTime, Duration, Utc};
+use log::debug;
+
+use crate::database::{Database, db_get_config, db_set_config};
+
+/// Configuration key enabling the update check.
+pub const CONFIG_AUTO_UPDATE: &str = "auto_update";
+
+/// Configuration key overriding the URL of the latest version file.
+pub const CONFIG_UPDATE_CHECK_URL: &str = "update_check_url";
+
+/// Configuration key holding when the last update check happened.
+pub const CONFIG_LAST_UPDATE_CHECK: &str = "last_update_check";
+
+/// Latest version file published with every release, by the release workflow.
+pub const DEFAULT_UPDATE_CHECK_URL: &str =
+    "https://github.com/AmplitudeAudio/cli/releases/latest/download/VERSION";
+
+/// Minimum delay between two update checks.
+pub const UPDATE_CHECK_INTERVAL_HOURS: i64 = 24;
+
+/// Maximum time spent fetching the latest version, in seconds.
+const FETCH_TIMEOUT_SECS: u64 = 3;
+
+/// Boxed future returned by [`VersionFetcher::fetch_latest_version`].
+pub type FetchFuture<'a> = Pin<Box<dyn Future<Output = anyhow::Result<String>> + 'a>>;
+
+/// Source of the latest published version.
+pub trait VersionFetcher {
+    /// Fetch the content of the version file at `url`.
+    fn fetch_latest_version<'a>(&'a self, url: &'a str) -> FetchFuture<'a>;
+}
+
+/// Fetches the version file over HTTP(S).
+pub struct HttpVersionFetcher;
+
+impl VersionFetcher for HttpVersionFetcher {
+    fn fetch_latest_version<'a>(&'a self, url: &'a str) -> FetchFuture<'a> {
+        Box::pin(async move {
+            let client = reqwest::Client::builder()
+                .timeout(std::time::Duration::from_secs(FETCH_TIMEOUT_SECS))
+                .user_agent(concat!("am/", env!("CARGO_PKG_VERSION")))
+                .build()
+                .context("Failed to create the HTTP client")?;
+
+            let response = client
+                .get(url)
+                .send()
+                .await
+                .and_then(|response| response.error_for_status())
+                .with_context(|| format!("Failed to fetch {}", url))?;
+
+            response
+                .text()
+                .await
+                .with_context(|| format!("Failed to read {}", url))
+        })
+    }
+}
+
+/// Check whether a version newer than `current_version` is published.
+///
+/// Returns `Ok(None)` without fetching anything when `auto_update` is `false`, when no
+/// URL is configured, or when the last check is less than a day older than `now`. The
+/// check time is only recorded once the version is fetched, so a failed check isn't
+/// mistaken for a successful one and runs again on the next command.
+pub async fn check_for_update(
+    current_version: &str,
+    now: DateTime<Utc>,
+    fetcher: &dyn VersionFetcher,
+    database: Option<Arc<Database>>,
+) -> anyhow::Result<Option<String>> {
+    let enabled = db_get_config(CONFIG_AUTO_UPDATE, database.clone())?;
+    if enabled.is_some_and(|v| v.trim().eq_ignore_ascii_case("false")) {
+        debug!(
+            "Update check disabled by the '{}' setting",
+            CONFIG_AUTO_UPDATE
+        );
+        return Ok(None);
+    }
+
+    let url = db_get_config(CONFIG_UPDATE_CHECK_URL, database.clone())?
+        .unwrap_or_else(|| DEFAULT_UPDATE_CHECK_URL.to_string());
+    if url.trim().is_empty() {
+        debug!(
+            "Update check disabled by an empty '{}'",
+            CONFIG_UPDATE_CHECK_URL
+        );
+        return Ok(None);
+    }
+
+    let last_check = db_get_config(CONFIG_LAST_UPDATE_CHECK, database.clone())?
+        .and_then(|v| DateTime::parse_from_rfc3339(&v).ok());
+    if let Some(last_check) = last_check
+        && now.signed_duration_since(last_check) < Duration::hours(UPDATE_CHECK_INTERVAL_HOURS)
+    {
+        debug!("Skipping update check, last one ran at {}", last_check);
+        return Ok(None);
+    }
+
+    let latest = fetcher.fetch_latest_version(url.trim()).await?;
+    db_set_config(
+        CONFIG_LAST_UPDATE_CHECK,
+        &now.to_rfc3339(),
+        "string",
+        database,
+    )?;
+    let latest = latest.trim().trim_start_matches('v');
+
+    match (parse_version(latest), parse_version(current_version)) {
+        (Some(latest_version), Some(current)) if latest_version > current => {
+            Ok(Some(latest.to_string()))
+        }
+        (None, _) => {
+            debug!("Ignoring unrecognized latest version '{}'", latest);
+            Ok(None)
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Parse the `major.minor.patch` part of a version, ignoring any pre-release or build suffix.
+pub(crate) fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let core = version
+        .trim()
+        .trim_start_matches('v')
+        .split(['-', '+'])
+        .next()?;
+
+    let mut parts = core.split('.').map(|p| p.parse::<u64>().ok());
+    let major = parts.next()??;
+    let minor = parts.next().unwrap_or(Some(0))?;
+    let patch = parts.next().unwrap_or(Some(0))?;
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some((major, minor, patch))
+}
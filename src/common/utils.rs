@@ -21,14 +21,21 @@ use std::collections::HashMap;
 use std::collections::hash_map::DefaultHasher;
 use std::fs;
 use std::hash::{Hash, Hasher};
-use std::path::Path;
+use std::path::{Component, Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use anyhow::Context;
+use log::debug;
 use serde::{Deserialize, Serialize};
 
+use crate::assets::AssetType;
 use crate::common::errors::{CliError, codes, project_not_initialized};
+use crate::common::files::{
+    safe_remove_in_parent, safe_remove_within, write_json_preserving_unknown_fields,
+};
+use crate::common::ignore::{IgnoreRules, list_project_files};
 use crate::database::entities::ProjectConfiguration;
+use crate::events::{self, CliEvent};
 
 // =============================================================================
 // String Truncation Utilities
@@ -86,15 +93,91 @@ pub fn truncate_string_at_word(s: &str, max_len: usize) -> String {
     }
 }
 
+// =============================================================================
+// Size Utilities
+// =============================================================================
+
+/// Binary units of [`format_size`] and [`parse_size`], each 1024 times the previous one.
+const SIZE_UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+/// Format a number of bytes with the largest binary unit it reaches.
+///
+/// # Example
+/// ```ignore
+/// assert_eq!(format_size(512), "512 B");
+/// assert_eq!(format_size(1536), "1.5 KiB");
+/// ```
+pub fn format_size(bytes: u64) -> String {
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < SIZE_UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, SIZE_UNITS[unit])
+}
+
+/// Parse a size such as `500`, `64K`, `1.5MiB` or `2 GB` into bytes.
+///
+/// Units are binary whatever their spelling: `1K`, `1KB` and `1KiB` are all 1024 bytes.
+/// Used as a clap value parser.
+pub fn parse_size(value: &str) -> Result<u64, String> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+
+    let number: f64 = number
+        .parse()
+        .map_err(|_| format!("'{}' is not a size (e.g. 500K, 10MiB, 2G)", value))?;
+    let unit = unit.trim().to_ascii_uppercase();
+    let exponent = match unit.as_str() {
+        "" | "B" => 0,
+        _ => SIZE_UNITS
+            .iter()
+            .position(|u| {
+                let u = u.to_ascii_uppercase();
+                unit == u || unit == u.replace('I', "") || unit == u[..1]
+            })
+            .map(|position| position as i32 + 1)
+            .ok_or_else(|| format!("Unknown size unit '{}' (use B, K, M, G or T)", unit))?,
+    };
+
+    Ok((number * 1024f64.powi(exponent)).round() as u64)
+}
+
 // =============================================================================
 // Name Validation Utilities
 // =============================================================================
 
+/// Maximum length of a name, in bytes, so it fits in a single path component.
+pub const MAX_NAME_BYTES: usize = 255;
+
+/// Device names Windows reserves in every directory, with or without an extension.
+const RESERVED_NAMES: [&str; 22] = [
+    "con", "prn", "aux", "nul", "com1", "com2", "com3", "com4", "com5", "com6", "com7", "com8",
+    "com9", "lpt1", "lpt2", "lpt3", "lpt4", "lpt5", "lpt6", "lpt7", "lpt8", "lpt9",
+];
+
 /// Validate a name for use as a project or template identifier.
 ///
 /// Names must:
 /// - Not be empty (after trimming whitespace)
 /// - Only contain alphanumeric characters, hyphens, underscores, and optionally spaces
+/// - Contain at least one alphanumeric character
+/// - Not be a device name reserved by Windows, nor longer than [`MAX_NAME_BYTES`]
+///
+/// A name accepted by this function is safe to use as a directory name on every
+/// platform. Project names are validated after [`transform_project_name`].
+///
+/// Each rule is also available on its own, so prompts can report them as separate
+/// validators: [`check_name_present`], [`check_name_characters`],
+/// [`check_name_not_reserved`] and [`check_name_length`], applied in this order.
 ///
 /// # Arguments
 /// * `name` - The name to validate
@@ -105,12 +188,30 @@ pub fn truncate_string_at_word(s: &str, max_len: usize) -> String {
 /// * `Ok(())` if valid
 /// * `Err(String)` with error message if invalid
 pub fn validate_name(name: &str, allow_spaces: bool, entity_type: &str) -> Result<(), String> {
-    let trimmed = name.trim();
+    check_name_present(name, entity_type)?;
+    check_name_characters(name, allow_spaces, entity_type)?;
+    check_name_not_reserved(name, entity_type)?;
+    check_name_length(name, entity_type)
+}
 
-    if trimmed.is_empty() {
+/// Check that a name is not empty after trimming whitespace.
+pub fn check_name_present(name: &str, entity_type: &str) -> Result<(), String> {
+    if name.trim().is_empty() {
         return Err(format!("{} name is required", entity_type));
     }
 
+    Ok(())
+}
+
+/// Check that a name only contains alphanumeric characters, hyphens, underscores and,
+/// with `allow_spaces`, spaces, and at least one letter or digit.
+pub fn check_name_characters(
+    name: &str,
+    allow_spaces: bool,
+    entity_type: &str,
+) -> Result<(), String> {
+    let trimmed = name.trim();
+
     let invalid_char = if allow_spaces {
         trimmed
             .chars()
@@ -133,9 +234,71 @@ pub fn validate_name(name: &str, allow_spaces: bool, entity_type: &str) -> Resul
         ));
     }
 
+    if !trimmed.chars().any(char::is_alphanumeric) {
+        return Err(format!(
+            "The {} name must contain at least one letter or digit.",
+            entity_type
+        ));
+    }
+
     Ok(())
 }
 
+/// Check that a name is not a device name reserved by Windows.
+pub fn check_name_not_reserved(name: &str, entity_type: &str) -> Result<(), String> {
+    let trimmed = name.trim();
+
+    if RESERVED_NAMES.contains(&trimmed.to_lowercase().as_str()) {
+        return Err(format!(
+            "'{}' is reserved by Windows and can't be used as a {} name.",
+            trimmed, entity_type
+        ));
+    }
+
+    Ok(())
+}
+
+/// Check that a name is at most [`MAX_NAME_BYTES`] long, after trimming whitespace.
+pub fn check_name_length(name: &str, entity_type: &str) -> Result<(), String> {
+    if name.trim().len() > MAX_NAME_BYTES {
+        return Err(format!(
+            "The {} name must be at most {} bytes long.",
+            entity_type, MAX_NAME_BYTES
+        ));
+    }
+
+    Ok(())
+}
+
+/// Apply the underscore_lowercase naming convention to `name`.
+///
+/// Surrounding whitespace is removed, letters are lowercased and the remaining whitespace
+/// and hyphens become underscores. Other characters are kept as is, so validation can
+/// report them instead of silently dropping them. Transforming a transformed name returns
+/// it unchanged.
+pub fn transform_name(name: &str) -> String {
+    name.trim()
+        .chars()
+        .flat_map(|c| {
+            let replaced = if c.is_whitespace() || c == '-' {
+                '_'
+            } else {
+                c
+            };
+            replaced.to_lowercase()
+        })
+        .collect()
+}
+
+/// Transform a name entered by the user into the name of a project.
+///
+/// Applies [`transform_name`]. The result is the name stored in the registry and in the
+/// `.amproject` file, and the name of the project directory: it is what
+/// [`validate_project_name`] must accept.
+pub fn transform_project_name(name: &str) -> String {
+    transform_name(name)
+}
+
 /// Validate a project name (allows spaces).
 ///
 /// This is a convenience wrapper around `validate_name` for projects.
@@ -176,6 +339,151 @@ pub const ASSET_DIRECTORIES: &[&str] = &[
     ASSET_DIR_SWITCHES,
 ];
 
+/// Directory at the root of a project holding its snapshots.
+pub const SNAPSHOTS_DIR: &str = "snapshots";
+
+/// Check the directory holding assets of the given type in a project.
+///
+/// An `asset_dirs` override must be a plain directory name, and must not be shared
+/// with another asset type or with one of the other sources directories.
+pub fn check_asset_dir(
+    config: &ProjectConfiguration,
+    asset_type: AssetType,
+) -> Result<(), CliError> {
+    let dir = config.asset_dir(asset_type);
+
+    let mut components = Path::new(dir).components();
+    let is_plain_name = matches!(components.next(), Some(Component::Normal(_)))
+        && components.next().is_none()
+        && !dir.contains(['/', '\\']);
+    if !is_plain_name {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            format!("Invalid {} directory in .amproject", asset_type),
+            format!("'{}' is not a plain directory name", dir),
+        )
+        .with_suggestion(
+            "Set each 'asset_dirs' entry to a directory name inside the sources directory, e.g. \"sound\": \"sfx\"",
+        ));
+    }
+
+    // Attenuators, pipelines and rtpc have fixed directories.
+    let reserved =
+        ASSET_DIRECTORIES.contains(&dir) && AssetType::from_directory_name(dir).is_none();
+    let shared_with = AssetType::ALL
+        .into_iter()
+        .find(|other| *other != asset_type && config.asset_dir(*other) == dir)
+        .map(|other| format!("{} assets", other))
+        .or_else(|| reserved.then(|| format!("the {} assets", dir)));
+    if let Some(owner) = shared_with {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            format!("Invalid {} directory in .amproject", asset_type),
+            format!("'{}' is already used for {}", dir, owner),
+        )
+        .with_suggestion("Give each asset type its own directory in 'asset_dirs'"));
+    }
+
+    Ok(())
+}
+
+/// Get `path` as a UTF-8 string.
+///
+/// Project and template paths are stored as text in the registry, so the CLI only
+/// supports directories whose path is valid UTF-8. Other paths are rejected with
+/// `ERR_VALIDATION_FORMAT` rather than being stored lossily.
+pub fn utf8_path(path: &Path) -> Result<&str, CliError> {
+    path.to_str().ok_or_else(|| {
+        CliError::new(
+            codes::ERR_VALIDATION_FORMAT,
+            "Unsupported path",
+            format!("'{}' is not valid UTF-8", path.display()),
+        )
+        .with_suggestion("Use a directory whose path only contains valid UTF-8 characters")
+    })
+}
+
+/// Replace a leading `~` component of `path` with the user's `home` directory.
+///
+/// Shells expand `~` before the CLI sees it, but not when it is quoted or typed at a
+/// prompt. `~user` forms are left as they are, as is `path` when `home` is unknown.
+pub fn expand_home(path: &Path, home: Option<&Path>) -> PathBuf {
+    let mut components = path.components();
+    match (components.next(), home) {
+        (Some(Component::Normal(first)), Some(home)) if first == "~" => {
+            match components.as_path() {
+                rest if rest.as_os_str().is_empty() => home.to_path_buf(),
+                rest => home.join(rest),
+            }
+        }
+        _ => path.to_path_buf(),
+    }
+}
+
+/// Resolve the `.` and `..` components of `path` lexically.
+///
+/// Unlike [`Path::canonicalize`], the path doesn't need to exist and symbolic links are
+/// left untouched. Trailing separators are dropped.
+pub fn normalize_path(path: &Path) -> String {
+    let mut components = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                // Pop the last component if it's a normal directory
+                if matches!(components.last(), Some(Component::Normal(_))) {
+                    components.pop();
+                } else {
+                    components.push(component);
+                }
+            }
+            _ => components.push(component),
+        }
+    }
+
+    let normalized: std::path::PathBuf = components.iter().collect();
+    normalized.to_string_lossy().into_owned()
+}
+
+/// Get `path` in the form project paths are stored and looked up in the registry.
+///
+/// Relative paths are resolved against the current directory. When the path exists,
+/// symbolic links are resolved as well; otherwise it is only [normalized](normalize_path),
+/// so projects whose directory was removed can still be found.
+pub fn canonical_project_path(path: &Path) -> String {
+    if let Ok(canonical) = path.canonicalize() {
+        return canonical.to_string_lossy().into_owned();
+    }
+
+    normalize_path(&std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf()))
+}
+
+/// Delete what a failed `am project init` wrote to `project_path`, and the directory
+/// itself when init `created` it.
+///
+/// Failures are only logged: the error that made init fail is the one reported.
+pub fn discard_partial_project(project_path: &Path, created: bool) {
+    let result = if created {
+        safe_remove_in_parent(project_path)
+    } else {
+        fs::read_dir(project_path)
+            .map_err(anyhow::Error::from)
+            .and_then(|entries| {
+                entries
+                    .flatten()
+                    .try_for_each(|entry| safe_remove_within(project_path, &entry.path()))
+            })
+    };
+
+    if let Err(e) = result {
+        debug!(
+            "Failed to clean up partial project {}: {}",
+            project_path.display(),
+            e
+        );
+    }
+}
+
 /// Read and parse the `.amproject` file from the given directory.
 ///
 /// # Arguments
@@ -194,7 +502,7 @@ pub fn read_amproject_file(path: &Path) -> anyhow::Result<ProjectConfiguration>
     let amproject_path = path.join(".amproject");
 
     if !amproject_path.exists() {
-        return Err(project_not_initialized(path.to_str().unwrap_or_default()).into());
+        return Err(project_not_initialized(&path.display().to_string()).into());
     }
 
     let content = fs::read_to_string(&amproject_path).with_context(|| {
@@ -214,10 +522,145 @@ pub fn read_amproject_file(path: &Path) -> anyhow::Result<ProjectConfiguration>
     Ok(config)
 }
 
+/// The latest version of the `.amproject` format, the one written by this CLI.
+pub const AMPROJECT_VERSION: u32 = 1;
+
+/// The fields of `.amproject`, those of [`ProjectConfiguration`].
+pub const AMPROJECT_FIELDS: &[&str] = &[
+    "name",
+    "default_configuration",
+    "sources_dir",
+    "data_dir",
+    "build_dir",
+    "version",
+    "hooks",
+    "allow_json_comments",
+    "asset_dirs",
+];
+
+/// Read and parse the `.amproject` file from the given directory, rejecting the fields
+/// that aren't [known](AMPROJECT_FIELDS).
+///
+/// [`read_amproject_file`] ignores unknown fields, so a typo like `source_dir` goes
+/// unnoticed until the misspelled field is reported missing or silently takes its
+/// default. Each unknown field is reported with the known field it most likely
+/// misspells.
+pub fn read_amproject_file_strict(path: &Path) -> anyhow::Result<ProjectConfiguration> {
+    let amproject_path = path.join(".amproject");
+
+    // Unreadable and invalid files are reported by the lenient parse.
+    if let Ok(content) = fs::read_to_string(&amproject_path)
+        && let Ok(value) = serde_json::from_str::<serde_json::Value>(&content)
+    {
+        let unknown = unknown_amproject_fields(&value);
+        if !unknown.is_empty() {
+            return Err(unknown_fields_error(&amproject_path, &unknown).into());
+        }
+    }
+
+    read_amproject_file(path)
+}
+
+/// The fields of the `.amproject` content `value` that aren't [known](AMPROJECT_FIELDS),
+/// sorted by name, each with the known field it most likely misspells.
+pub fn unknown_amproject_fields(value: &serde_json::Value) -> Vec<(String, Option<&'static str>)> {
+    let Some(fields) = value.as_object() else {
+        return Vec::new();
+    };
+
+    fields
+        .keys()
+        .filter(|key| !AMPROJECT_FIELDS.contains(&key.as_str()))
+        .map(|key| (key.clone(), closest_match(key, AMPROJECT_FIELDS)))
+        .collect()
+}
+
+fn unknown_fields_error(path: &Path, unknown: &[(String, Option<&str>)]) -> CliError {
+    let reasons = unknown
+        .iter()
+        .map(|(field, suggestion)| match suggestion {
+            Some(known) => format!(
+                "'{}' is not a known field, did you mean '{}'?",
+                field, known
+            ),
+            None => format!("'{}' is not a known field", field),
+        })
+        .collect::<Vec<_>>()
+        .join("; ");
+
+    CliError::new(
+        codes::ERR_VALIDATION_FIELD,
+        format!("Invalid .amproject file at {}", path.display()),
+        reasons,
+    )
+    .with_suggestion(format!(
+        "Rename or remove the unknown fields. The known fields are: {}",
+        AMPROJECT_FIELDS.join(", ")
+    ))
+}
+
+/// The Levenshtein distance between `a` and `b`: the number of single character
+/// insertions, deletions and substitutions turning one into the other.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+/// The candidate closest to `name`, if close enough to be a likely misspelling: at most
+/// a third of the characters of `name` differ, and at least one may.
+pub fn closest_match<'a>(name: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let max_distance = (name.chars().count() / 3).max(1);
+
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(name, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Write the `.amproject` file in the given directory.
+///
+/// The file is pretty-printed with a stable field order and a trailing newline.
+/// Fields already present in the file that `ProjectConfiguration` doesn't know
+/// about are preserved.
+///
+/// # Arguments
+/// * `path` - Path to the directory containing the `.amproject` file
+/// * `config` - The project configuration to write
+///
+/// # Example
+/// ```ignore
+/// let mut config = read_amproject_file(Path::new("/path/to/project"))?;
+/// config.build_dir = "out".to_string();
+/// write_amproject_file(Path::new("/path/to/project"), &config)?;
+/// ```
+pub fn write_amproject_file(path: &Path, config: &ProjectConfiguration) -> anyhow::Result<()> {
+    let amproject = path.join(".amproject");
+    write_json_preserving_unknown_fields(&amproject, config)?;
+    events::emit(CliEvent::FileWritten { path: amproject });
+    Ok(())
+}
+
 /// Count assets by type in a project.
 ///
 /// Scans the `sources/` directory for each asset type subdirectory and counts
-/// the number of `.json` files in each.
+/// the number of `.json` files in each. Files ignored by the project's
+/// `.amignore` rules are not counted. Directories overridden through `asset_dirs`
+/// in `.amproject` are scanned instead of the default ones, but counts remain
+/// keyed by the default directory names.
 ///
 /// # Arguments
 /// * `project_path` - Path to the project root directory
@@ -232,15 +675,16 @@ pub fn read_amproject_file(path: &Path) -> anyhow::Result<ProjectConfiguration>
 /// println!("Sounds: {}", counts.get("sounds").unwrap_or(&0));
 /// ```
 pub fn count_assets_by_type(project_path: &Path) -> anyhow::Result<HashMap<String, usize>> {
-    let sources_dir = match read_amproject_file(project_path) {
-        Ok(config) => {
+    let config = read_amproject_file(project_path).ok();
+    let sources_dir = match &config {
+        Some(config) => {
             if config.sources_dir.is_empty() {
                 project_path.to_path_buf()
             } else {
                 project_path.join(&config.sources_dir)
             }
         }
-        Err(_) => project_path.join("sources"),
+        None => project_path.join("sources"),
     };
     let mut counts = HashMap::new();
 
@@ -254,25 +698,20 @@ pub fn count_assets_by_type(project_path: &Path) -> anyhow::Result<HashMap<Strin
         return Ok(counts);
     }
 
-    // Count .json files in each asset directory
+    let ignore = IgnoreRules::load(project_path)?;
+
+    // Count .json files in each asset directory, skipping files matched by .amignore
     // Note: We follow symlinks (is_file() resolves symlinks) and only count regular files
     for &asset_type in ASSET_DIRECTORIES {
-        let asset_dir = sources_dir.join(asset_type);
+        let dir_name = match (&config, AssetType::from_directory_name(asset_type)) {
+            (Some(config), Some(t)) => config.asset_dir(t),
+            _ => asset_type,
+        };
+        let asset_dir = sources_dir.join(dir_name);
         if asset_dir.exists() && asset_dir.is_dir() {
-            let count = fs::read_dir(&asset_dir)?
-                .filter_map(|entry| entry.ok())
-                .filter(|entry| {
-                    // Use file_type() to properly handle symlinks and special files
-                    let file_type = match entry.file_type() {
-                        Ok(ft) => ft,
-                        Err(_) => return false,
-                    };
-                    // Only count regular files and symlinks pointing to files
-                    // Skip directories, sockets, pipes, and other special files
-                    let is_regular_file =
-                        file_type.is_file() || (file_type.is_symlink() && entry.path().is_file());
-                    is_regular_file && entry.path().extension().is_some_and(|ext| ext == "json")
-                })
+            let count = list_project_files(&asset_dir, &ignore)
+                .iter()
+                .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
                 .count();
             counts.insert(asset_type.to_string(), count);
         }
@@ -20,14 +20,16 @@
 
 pub mod flatc;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
 use anyhow::{Context, Result};
-use walkdir::WalkDir;
 
+use crate::assets::AssetType;
+use crate::common::files::{JSON_COMMENTS_SUPPORTED, strip_json_comments};
+use crate::common::ignore::{IgnoreRules, WalkOptions, list_project_files, walk_project_files};
 use crate::config::sdk::SdkLocation;
 
 /// Maps a source asset type to its schema and output format.
@@ -190,8 +192,15 @@ pub fn output_path_for(
 ///
 /// For root-level entries (empty `subdir`), matches files in `sources_dir`
 /// by the pattern suffix. For subdirectory entries, recursively walks the
-/// subdirectory collecting all `.json` files.
-pub fn discover_files(sources_dir: &Path, entry: &ConversionEntry) -> Vec<PathBuf> {
+/// subdirectory with `walk`, collecting all `.json` files. Paths matched by
+/// `ignore` are skipped. The directories the walk skips aren't reported here:
+/// validating the project before a build reports them already.
+pub fn discover_files(
+    sources_dir: &Path,
+    entry: &ConversionEntry,
+    ignore: &IgnoreRules,
+    walk: WalkOptions,
+) -> Vec<PathBuf> {
     if entry.subdir.is_empty() {
         // Root-level pattern matching (e.g. "*.config.json").
         let suffix = entry
@@ -199,52 +208,68 @@ pub fn discover_files(sources_dir: &Path, entry: &ConversionEntry) -> Vec<PathBu
             .strip_prefix('*')
             .unwrap_or(&entry.pattern_suffix);
 
-        match fs::read_dir(sources_dir) {
-            Ok(entries) => entries
-                .filter_map(|e| e.ok())
-                .map(|e| e.path())
-                .filter(|p| {
-                    p.is_file()
-                        && p.file_name()
-                            .and_then(|n| n.to_str())
-                            .is_some_and(|n| n.ends_with(suffix))
-                })
-                .collect(),
-            Err(_) => Vec::new(),
-        }
+        list_project_files(sources_dir, ignore)
+            .into_iter()
+            .filter(|p| {
+                p.file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| n.ends_with(suffix))
+            })
+            .collect()
     } else {
-        let subdir_path = sources_dir.join(&entry.subdir);
-        if !subdir_path.is_dir() {
-            return Vec::new();
-        }
-
-        WalkDir::new(&subdir_path)
+        walk_project_files(&sources_dir.join(&entry.subdir), ignore, walk)
+            .files
             .into_iter()
-            .filter_map(|e| e.ok())
-            .filter(|e| {
-                e.file_type().is_file()
-                    && e.path()
-                        .extension()
-                        .and_then(|ext| ext.to_str())
-                        .is_some_and(|ext| ext == "json")
+            .filter(|p| {
+                p.extension()
+                    .and_then(|ext| ext.to_str())
+                    .is_some_and(|ext| ext == "json")
             })
-            .map(|e| e.into_path())
             .collect()
     }
 }
 
 /// Compile all assets in a project from JSON to FlatBuffers binary.
 ///
+/// Returns a copy of `entry` reading from the `asset_dirs` override of its asset type,
+/// if one is configured.
+fn with_asset_dir(
+    entry: &ConversionEntry,
+    asset_dirs: &BTreeMap<AssetType, String>,
+) -> ConversionEntry {
+    let mut entry = entry.clone();
+    if let Some(dir) =
+        AssetType::from_directory_name(&entry.subdir).and_then(|t| asset_dirs.get(&t))
+    {
+        entry.subdir = dir.clone();
+    }
+    entry
+}
+
 /// Iterates every conversion entry, discovers matching source files,
 /// checks for incremental rebuild, compiles changed files, and writes
 /// the binary output to `build_dir`.
 ///
 /// When `fail_fast` is `true`, the first compilation error aborts the
 /// entire build. Otherwise errors are collected in `BuildSummary.errors`.
+///
+/// When `allow_json_comments` is `true` (and the `json-comments` feature is
+/// enabled), comments and trailing commas are stripped from source files
+/// before compilation.
+///
+/// `asset_dirs` holds the project's directory overrides per asset type (see
+/// `asset_dirs` in `.amproject`). Type counts stay keyed by the default names.
+///
+/// `walk` sets how the asset directories are walked, see [`discover_files`].
+#[allow(clippy::too_many_arguments)]
 pub fn compile_project(
     sources_dir: &Path,
     build_dir: &Path,
     sdk: &SdkLocation,
+    ignore: &IgnoreRules,
+    walk: WalkOptions,
+    allow_json_comments: bool,
+    asset_dirs: &BTreeMap<AssetType, String>,
     fail_fast: bool,
     output: &dyn crate::presentation::Output,
 ) -> Result<BuildSummary> {
@@ -263,12 +288,17 @@ pub fn compile_project(
             }
         };
 
-        let files = discover_files(sources_dir, entry);
         let type_key = if entry.subdir.is_empty() {
             entry.pattern_suffix.clone()
         } else {
             entry.subdir.clone()
         };
+        let files = discover_files(
+            sources_dir,
+            &with_asset_dir(entry, asset_dirs),
+            ignore,
+            walk,
+        );
 
         for source in &files {
             let target = output_path_for(source, sources_dir, build_dir, &entry.output_extension);
@@ -289,6 +319,11 @@ pub fn compile_project(
                     continue;
                 }
             };
+            let json_str = if allow_json_comments && JSON_COMMENTS_SUPPORTED {
+                strip_json_comments(&json_str)
+            } else {
+                json_str
+            };
 
             match flatc::compile_json_to_binary(&schema_bytes, &json_str, output) {
                 Ok(binary) => {
@@ -421,7 +456,12 @@ mod tests {
             output_extension: ".amconfig".into(),
         };
 
-        let files = discover_files(dir.path(), &entry);
+        let files = discover_files(
+            dir.path(),
+            &entry,
+            &IgnoreRules::none(),
+            WalkOptions::default(),
+        );
         assert_eq!(files.len(), 1);
         assert!(
             files[0]
@@ -450,10 +490,30 @@ mod tests {
             output_extension: ".amsound".into(),
         };
 
-        let files = discover_files(dir.path(), &entry);
+        let files = discover_files(
+            dir.path(),
+            &entry,
+            &IgnoreRules::none(),
+            WalkOptions::default(),
+        );
         assert_eq!(files.len(), 2);
     }
 
+    #[test]
+    fn test_with_asset_dir_applies_override() {
+        let asset_dirs = BTreeMap::from([(AssetType::Sound, "sfx".to_string())]);
+        let entries = get_conversion_entries();
+
+        let sounds = entries.iter().find(|e| e.subdir == "sounds").unwrap();
+        assert_eq!(with_asset_dir(sounds, &asset_dirs).subdir, "sfx");
+
+        let events = entries.iter().find(|e| e.subdir == "events").unwrap();
+        assert_eq!(with_asset_dir(events, &asset_dirs).subdir, "events");
+
+        let config = entries.iter().find(|e| e.subdir.is_empty()).unwrap();
+        assert!(with_asset_dir(config, &asset_dirs).subdir.is_empty());
+    }
+
     #[test]
     fn test_discover_files_missing_subdir() {
         let dir = tempdir().unwrap();
@@ -464,7 +524,33 @@ mod tests {
             output_extension: ".amsound".into(),
         };
 
-        let files = discover_files(dir.path(), &entry);
+        let files = discover_files(
+            dir.path(),
+            &entry,
+            &IgnoreRules::none(),
+            WalkOptions::default(),
+        );
         assert!(files.is_empty());
     }
+
+    #[test]
+    fn test_discover_files_skips_ignored_paths() {
+        let dir = tempdir().unwrap();
+        let sounds = dir.path().join("sounds");
+        let old = sounds.join("old");
+        fs::create_dir_all(&old).unwrap();
+        fs::write(sounds.join("music.json"), "{}").unwrap();
+        fs::write(old.join("legacy.json"), "{}").unwrap();
+
+        let entry = ConversionEntry {
+            subdir: "sounds".into(),
+            pattern_suffix: "**/*.json".into(),
+            schema_file: "test.bfbs".into(),
+            output_extension: ".amsound".into(),
+        };
+        let ignore = IgnoreRules::from_patterns(dir.path(), ["old/"]);
+
+        let files = discover_files(dir.path(), &entry, &ignore, WalkOptions::default());
+        assert_eq!(files, vec![sounds.join("music.json")]);
+    }
 }
@@ -16,7 +16,8 @@
 //!
 //! Locates the SDK installation at runtime by checking:
 //! 1. `AM_SDK_PATH` environment variable (preferred)
-//! 2. Common installation paths (platform-specific fallbacks)
+//! 2. The `sdk_path` setting chosen during `am sudo setup`
+//! 3. Common installation paths (platform-specific fallbacks)
 //!
 //! The SDK path is primarily needed at runtime for:
 //! - Loading schemas for runtime validation of user-edited JSON files
@@ -29,8 +30,23 @@
 
 use std::env;
 use std::path::{Path, PathBuf};
+use std::sync::RwLock;
 
 use crate::common::errors::{CliError, codes};
+use crate::common::paths::HomeLayout;
+
+/// File at the root of an SDK installation holding its version, e.g. `1.2.0`.
+pub const SDK_VERSION_FILE: &str = "VERSION";
+
+static CONFIGURED_SDK_PATH: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Set the SDK path from the configuration, checked by [`discover_sdk`] when
+/// `AM_SDK_PATH` is not set.
+pub fn set_configured_sdk_path(path: Option<PathBuf>) {
+    *CONFIGURED_SDK_PATH
+        .write()
+        .unwrap_or_else(|e| e.into_inner()) = path;
+}
 
 /// Result of SDK discovery, containing the validated SDK path.
 #[derive(Debug, Clone)]
@@ -52,6 +68,15 @@ impl SdkLocation {
         &self.schemas_dir
     }
 
+    /// Returns the version of the SDK, read from its [`SDK_VERSION_FILE`], or `None` if
+    /// the SDK doesn't report one.
+    pub fn version(&self) -> Option<String> {
+        std::fs::read_to_string(self.root.join(SDK_VERSION_FILE))
+            .ok()
+            .map(|content| content.trim().to_string())
+            .filter(|version| !version.is_empty())
+    }
+
     /// Creates an SdkLocation for testing without validation.
     ///
     /// Assumes the schemas directory is at `<root>/schemas/`.
@@ -66,7 +91,8 @@ impl SdkLocation {
 ///
 /// Checks the following locations in order:
 /// 1. `AM_SDK_PATH` environment variable
-/// 2. Platform-specific common installation paths
+/// 2. The SDK path from the configuration, see [`set_configured_sdk_path`]
+/// 3. Platform-specific common installation paths
 ///
 /// Returns `Ok(SdkLocation)` if the SDK is found with a valid schemas directory.
 /// Returns `Err` with a helpful error message if the SDK is not found.
@@ -95,7 +121,18 @@ pub fn discover_sdk() -> Result<SdkLocation, CliError> {
         .with_suggestion("Verify your AM_SDK_PATH points to a valid Amplitude SDK installation"));
     }
 
-    // 2. Check common installation paths
+    // 2. Check the configured SDK path
+    let configured = CONFIGURED_SDK_PATH
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+    if let Some(path) = configured
+        && let Some(location) = validate_sdk_path(&path)
+    {
+        return Ok(location);
+    }
+
+    // 3. Check common installation paths
     for candidate in common_sdk_paths() {
         if let Some(location) = validate_sdk_path(&candidate) {
             return Ok(location);
@@ -106,14 +143,19 @@ pub fn discover_sdk() -> Result<SdkLocation, CliError> {
     Err(CliError::new(
         codes::ERR_SDK_NOT_FOUND,
         "Amplitude SDK installation not found",
-        "The CLI checked AM_SDK_PATH and common installation directories but found no SDK",
+        "The CLI checked AM_SDK_PATH, the configured SDK path and common installation directories but found no SDK",
     )
     .with_suggestion(
-        "Set the AM_SDK_PATH environment variable to your SDK installation path.\n\
+        "Set the AM_SDK_PATH environment variable to your SDK installation path, or choose it with `am sudo setup --rerun`.\n\
          See https://github.com/AmplitudeAudio/sdk for installation instructions.",
     ))
 }
 
+/// Whether `path` looks like an SDK installation, see [`validate_sdk_path`].
+pub fn is_sdk_path(path: &Path) -> bool {
+    validate_sdk_path(path).is_some()
+}
+
 /// Validate that a path is a valid SDK installation.
 ///
 /// A valid SDK path must:
@@ -142,12 +184,19 @@ fn common_sdk_paths() -> Vec<PathBuf> {
     let mut paths = Vec::new();
 
     // User-local paths
-    if let Some(home) = dirs::home_dir() {
+    if let Some(home) = crate::common::paths::user_home() {
         paths.push(home.join("amplitude-sdk"));
         paths.push(home.join("AmplitudeAudio").join("sdk"));
         paths.push(home.join(".amplitude").join("sdk"));
     }
 
+    // SDK installed in the data directory, e.g. after `am sudo migrate-home`
+    if let Ok(am_paths) = crate::common::paths::resolve()
+        && am_paths.layout == HomeLayout::Xdg
+    {
+        paths.push(am_paths.data_dir.join("sdk"));
+    }
+
     // Platform-specific paths
     #[cfg(target_os = "linux")]
     {
@@ -158,7 +207,7 @@ fn common_sdk_paths() -> Vec<PathBuf> {
     #[cfg(target_os = "macos")]
     {
         paths.push(PathBuf::from("/usr/local/share/amplitude-sdk"));
-        if let Some(home) = dirs::home_dir() {
+        if let Some(home) = crate::common::paths::user_home() {
             paths.push(home.join("Library").join("AmplitudeAudio").join("sdk"));
         }
     }
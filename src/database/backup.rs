@@ -0,0 +1,223 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Backups of the database made before schema migrations.
+//!
+//! Migrating is the riskiest moment for the registry: a migration failing halfway, or a
+//! bug in one, may lose the only copy of it. Before pending migrations are applied to a
+//! database that already has a schema, [`backup_before_migration`] copies it into the
+//! `backups` directory of the [data directory](crate::common::paths) as
+//! `pre-migration-<from>-<to>-<timestamp>.db`, a regular SQLite file which can be copied
+//! back over `am.db`. Only the [`PRE_MIGRATION_BACKUPS_KEPT`] most recent ones are kept.
+//!
+//! The backups are on unless the `auto_backup_before_migration`
+//! [setting](crate::common::settings) is `false`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use log::debug;
+use rusqlite::OptionalExtension;
+
+use crate::common::errors::{CliError, codes};
+use crate::common::settings::Settings;
+use crate::common::utils::utf8_path;
+use crate::database::Database;
+use crate::database::migrations::MigrationManager;
+
+/// Configuration key enabling the backups before migrations.
+pub const CONFIG_AUTO_BACKUP_BEFORE_MIGRATION: &str = "auto_backup_before_migration";
+
+/// Number of pre-migration backups kept, the oldest ones being deleted.
+pub const PRE_MIGRATION_BACKUPS_KEPT: usize = 5;
+
+/// Prefix of the file names of pre-migration backups.
+const PRE_MIGRATION_PREFIX: &str = "pre-migration-";
+
+/// Copy `database` into `dest`, which must not exist yet, as a consistent SQLite file.
+///
+/// # Errors
+///
+/// Returns an `ERR_VALIDATION_FORMAT` error when `dest` isn't valid UTF-8, as SQLite
+/// takes the path as text.
+pub fn backup_database(database: &Database, dest: &Path) -> Result<()> {
+    let dest_str = utf8_path(dest)?;
+    let conn = database.get_connection();
+    let conn = conn
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
+
+    // Unlike a file copy, this includes the pages still in the write-ahead log
+    conn.execute("VACUUM INTO ?1", [dest_str])
+        .with_context(|| format!("Failed to back up the database to {}", dest.display()))?;
+
+    Ok(())
+}
+
+/// Back up `database` into `backups_dir` before migrating it to `to_version`, and
+/// delete the oldest pre-migration backups beyond [`PRE_MIGRATION_BACKUPS_KEPT`].
+///
+/// Returns the path of the backup, or `None` when there was nothing to back up: the
+/// database is empty or already at `to_version`, or the backups are disabled.
+///
+/// # Errors
+///
+/// Returns an `ERR_IO_FAILED` error when the backup can't be written, and an
+/// `ERR_VALIDATION_FORMAT` error when `backups_dir` isn't valid UTF-8. The database must
+/// not be migrated then.
+pub fn backup_before_migration(
+    database: &Database,
+    backups_dir: &Path,
+    to_version: u32,
+) -> Result<Option<PathBuf>> {
+    let manager = MigrationManager::new();
+    // The cached version keeps the check of an up-to-date database cheap
+    if manager.get_cached_version(database)? >= to_version {
+        return Ok(None);
+    }
+    let from_version = manager.get_current_version(database)?;
+    if from_version == 0 || from_version >= to_version {
+        return Ok(None);
+    }
+    if !auto_backup_enabled(database) {
+        debug!("Skipping the backup before migrating, as it is disabled");
+        return Ok(None);
+    }
+
+    let path = pre_migration_backup_path(backups_dir, from_version, to_version);
+    utf8_path(&path)?;
+    fs::create_dir_all(backups_dir)
+        .map_err(anyhow::Error::from)
+        .and_then(|()| backup_database(database, &path))
+        .map_err(|e| {
+            CliError::new(
+                codes::ERR_IO_FAILED,
+                "Failed to back up the database before migrating it",
+                format!("{:#}", e),
+            )
+            .with_suggestion(format!(
+                "Free some disk space, or set '{}' to false to migrate without a backup",
+                CONFIG_AUTO_BACKUP_BEFORE_MIGRATION
+            ))
+            .with_context(path.display().to_string())
+        })?;
+    debug!("Backed up the database to {}", path.display());
+
+    // Pruning is housekeeping, failing it doesn't prevent the migration
+    if let Err(e) = prune_pre_migration_backups(backups_dir, PRE_MIGRATION_BACKUPS_KEPT) {
+        debug!("Failed to delete old pre-migration backups: {}", e);
+    }
+
+    Ok(Some(path))
+}
+
+/// A path of `backups_dir`, not taken yet, for a backup made now before migrating from
+/// `from_version` to `to_version`.
+///
+/// The timestamp has milliseconds, and a counter follows it when a backup was already
+/// made within the same millisecond, as `VACUUM INTO` fails on an existing file.
+fn pre_migration_backup_path(backups_dir: &Path, from_version: u32, to_version: u32) -> PathBuf {
+    let stem = format!(
+        "{}{}-{}-{}",
+        PRE_MIGRATION_PREFIX,
+        from_version,
+        to_version,
+        Utc::now().format("%Y%m%dT%H%M%S%.3fZ")
+    );
+
+    let mut path = backups_dir.join(format!("{}.db", stem));
+    let mut counter = 1;
+    while path.exists() {
+        path = backups_dir.join(format!("{}_{}.db", stem, counter));
+        counter += 1;
+    }
+    path
+}
+
+/// Delete the pre-migration backups of `backups_dir` but the `keep` most recent ones,
+/// returning the deleted files.
+pub fn prune_pre_migration_backups(backups_dir: &Path, keep: usize) -> Result<Vec<PathBuf>> {
+    // Ordered by the timestamp ending their name, and its counter, oldest first
+    let mut backups: Vec<(String, PathBuf)> = fs::read_dir(backups_dir)?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let stem = name
+                .strip_prefix(PRE_MIGRATION_PREFIX)?
+                .strip_suffix(".db")?;
+            let timestamp = stem.rsplit('-').next()?.to_string();
+            Some((timestamp, entry.path()))
+        })
+        .collect();
+    backups.sort();
+
+    let excess = backups.len().saturating_sub(keep);
+    let mut deleted = Vec::new();
+    for (_, path) in backups.into_iter().take(excess) {
+        fs::remove_file(&path).with_context(|| format!("Failed to delete {}", path.display()))?;
+        deleted.push(path);
+    }
+
+    Ok(deleted)
+}
+
+/// The error of a migration that failed after the database was backed up to `backup`,
+/// suggesting to restore it over `database_path`.
+pub fn migration_failed(error: anyhow::Error, backup: &Path, database_path: &Path) -> CliError {
+    CliError::new(
+        codes::ERR_DATABASE_MIGRATION_FAILED,
+        "Failed to migrate the database",
+        format!("{:#}", error),
+    )
+    .with_suggestion(format!(
+        "Restore the backup made before the migration by copying '{}' over '{}'",
+        backup.display(),
+        database_path.display()
+    ))
+    .with_context(backup.display().to_string())
+}
+
+/// Whether the `auto_backup_before_migration` setting allows the backup.
+///
+/// The settings of the invocation aren't loaded before the database is migrated, so
+/// the setting is resolved from the environment and the configuration table, which
+/// older schemas may lack.
+fn auto_backup_enabled(database: &Database) -> bool {
+    let mut config = BTreeMap::new();
+    if let Some(value) = configured_value(database, CONFIG_AUTO_BACKUP_BEFORE_MIGRATION) {
+        config.insert(CONFIG_AUTO_BACKUP_BEFORE_MIGRATION.to_string(), value);
+    }
+
+    Settings::resolve(&BTreeMap::new(), |name| std::env::var(name).ok(), &config)
+        .auto_backup_before_migration()
+}
+
+fn configured_value(database: &Database, key: &str) -> Option<String> {
+    let conn = database.get_connection();
+    let conn = conn.lock().ok()?;
+
+    conn.query_row(
+        "SELECT value FROM configuration WHERE key = ?1",
+        [key],
+        |row| row.get(0),
+    )
+    .optional()
+    .unwrap_or_else(|e| {
+        debug!("Failed to read the '{}' setting: {}", key, e);
+        None
+    })
+}
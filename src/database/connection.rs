@@ -13,53 +13,219 @@
 // limitations under the License.
 
 use anyhow::{Context, Result};
-use rusqlite::{Connection, OpenFlags};
-use std::path::Path;
-use std::sync::{Arc, Mutex};
+use log::warn;
+use rusqlite::{Connection, ErrorCode, OpenFlags, StatementStatus};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, TryLockError};
 
 use super::migrations::MigrationManager;
+use super::size::SizeReport;
+use crate::common::errors::database_schema_outdated;
+
+/// Number of prepared statements kept by each connection, the least recently used one
+/// being finalized beyond it.
+///
+/// Each query of the registry has its own SQL, so this holds every query a command runs.
+pub const STATEMENT_CACHE_CAPACITY: usize = 64;
 
 /// Wrapper around the SQLite connection
 pub struct Database {
     connection: Arc<Mutex<Connection>>,
-    path: String,
+    path: PathBuf,
+    /// Identity of the database file when the connection was opened.
+    identity: Mutex<Option<FileIdentity>>,
+    /// Whether the file is checked against `identity` before each statement.
+    guard_replacement: AtomicBool,
+    /// Size of the database file, checked by [`initialize`](super::initialize).
+    size_report: Option<SizeReport>,
+    /// Backup made by [`initialize`](super::initialize) before migrating the database.
+    migration_backup: Option<PathBuf>,
+}
+
+/// Identifies a file on disk independently of its path: device and inode on Unix,
+/// creation time on Windows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FileIdentity {
+    #[cfg(unix)]
+    device: u64,
+    #[cfg(unix)]
+    inode: u64,
+    #[cfg(windows)]
+    created: u64,
+}
+
+impl FileIdentity {
+    /// Identity of the file at `path`, or `None` if it doesn't exist.
+    fn of(path: &Path) -> Option<Self> {
+        let _metadata = std::fs::metadata(path).ok()?;
+
+        Some(Self {
+            #[cfg(unix)]
+            device: std::os::unix::fs::MetadataExt::dev(&_metadata),
+            #[cfg(unix)]
+            inode: std::os::unix::fs::MetadataExt::ino(&_metadata),
+            #[cfg(windows)]
+            created: std::os::windows::fs::MetadataExt::creation_time(&_metadata),
+        })
+    }
+}
+
+/// Open a connection to the database at `path`, creating the file if needed.
+fn open_connection(path: &Path) -> Result<Connection> {
+    let conn = Connection::open_with_flags(
+        path,
+        OpenFlags::SQLITE_OPEN_READ_WRITE
+            | OpenFlags::SQLITE_OPEN_CREATE
+            | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )
+    .context("Failed to open database connection")?;
+
+    // Set pragmas for better performance and reliability
+    conn.execute_batch(
+        "
+        PRAGMA journal_mode = WAL;
+        PRAGMA synchronous = NORMAL;
+        PRAGMA cache_size = -64000;
+        PRAGMA foreign_keys = ON;
+        PRAGMA busy_timeout = 5000;
+        ",
+    )
+    .context("Failed to set database pragmas")?;
+    conn.set_prepared_statement_cache_capacity(STATEMENT_CACHE_CAPACITY);
+
+    Ok(conn)
+}
+
+/// Run `PRAGMA wal_checkpoint(TRUNCATE)` on `conn`, failing if the checkpoint couldn't
+/// complete because another connection was reading or writing.
+fn checkpoint_wal(conn: &Connection) -> Result<()> {
+    let (busy, log_frames, checkpointed_frames): (i64, i64, i64) = conn
+        .query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+        })
+        .context("Failed to checkpoint the write-ahead log")?;
+
+    if busy != 0 {
+        anyhow::bail!(
+            "Failed to checkpoint the write-ahead log: another connection is using the database ({} of {} frame(s) checkpointed)",
+            checkpointed_frames.max(0),
+            log_frames.max(0)
+        );
+    }
+
+    Ok(())
+}
+
+/// Convert an error of SQLite into the error of a query.
+///
+/// A missing table means the database wasn't migrated, e.g. because an empty or old
+/// database file was copied in place: it becomes an `ERR_DATABASE_SCHEMA_OUTDATED` error
+/// suggesting the migration instead of SQLite's "no such table". Other errors get
+/// `context`.
+pub(crate) fn query_error(error: rusqlite::Error, context: &'static str) -> anyhow::Error {
+    match missing_table(&error) {
+        Some(table) => database_schema_outdated(table).into(),
+        None => anyhow::Error::new(error).context(context),
+    }
+}
+
+/// The table named by a "no such table" error of SQLite.
+fn missing_table(error: &rusqlite::Error) -> Option<&str> {
+    let message = match error {
+        rusqlite::Error::SqliteFailure(e, Some(message)) if e.code == ErrorCode::Unknown => message,
+        rusqlite::Error::SqlInputError { error: e, msg, .. } if e.code == ErrorCode::Unknown => msg,
+        _ => return None,
+    };
+    message.strip_prefix("no such table: ")
 }
 
 impl Database {
     /// Create a new database connection
+    #[tracing::instrument(name = "db.open", skip_all, fields(db.system = "sqlite"))]
     pub fn new<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let path_str = path
-            .as_ref()
-            .to_str()
-            .ok_or_else(|| anyhow::anyhow!("Invalid database path"))?
-            .to_string();
-
-        let conn = Connection::open_with_flags(
-            &path_str,
-            OpenFlags::SQLITE_OPEN_READ_WRITE
-                | OpenFlags::SQLITE_OPEN_CREATE
-                | OpenFlags::SQLITE_OPEN_NO_MUTEX,
-        )
-        .context("Failed to open database connection")?;
-
-        // Set pragmas for better performance and reliability
-        conn.execute_batch(
-            "
-            PRAGMA journal_mode = WAL;
-            PRAGMA synchronous = NORMAL;
-            PRAGMA cache_size = -64000;
-            PRAGMA foreign_keys = ON;
-            PRAGMA busy_timeout = 5000;
-            ",
-        )
-        .context("Failed to set database pragmas")?;
+        let path = path.as_ref().to_path_buf();
+        let conn = open_connection(&path)?;
 
         Ok(Self {
             connection: Arc::new(Mutex::new(conn)),
-            path: path_str,
+            identity: Mutex::new(FileIdentity::of(&path)),
+            path,
+            guard_replacement: AtomicBool::new(false),
+            size_report: None,
+            migration_backup: None,
         })
     }
 
+    /// How the size of the database file compared with its thresholds when it was
+    /// initialized, `None` if it wasn't checked.
+    pub fn size_report(&self) -> Option<&SizeReport> {
+        self.size_report.as_ref()
+    }
+
+    pub(crate) fn set_size_report(&mut self, report: SizeReport) {
+        self.size_report = Some(report);
+    }
+
+    /// Where the database was [backed up](super::backup) before it was migrated when it
+    /// was initialized, `None` if it wasn't migrated or backed up.
+    pub fn migration_backup(&self) -> Option<&Path> {
+        self.migration_backup.as_deref()
+    }
+
+    pub(crate) fn set_migration_backup(&mut self, backup: PathBuf) {
+        self.migration_backup = Some(backup);
+    }
+
+    /// Check before each statement that the database file is still the one that was
+    /// opened, and reopen it otherwise.
+    ///
+    /// Long-running processes enable this: when the file is deleted or replaced
+    /// underneath them (e.g. by `am sudo database reset` from another terminal), their
+    /// writes would otherwise go to the deleted file and silently vanish. The check costs
+    /// a `stat` of the file.
+    pub fn guard_file_replacement(&self) {
+        self.guard_replacement.store(true, Ordering::Relaxed);
+    }
+
+    /// Reopen the database and run the pending migrations if the guard is enabled and
+    /// the file changed since it was opened.
+    fn ensure_file_unchanged(&self) -> Result<()> {
+        if !self.guard_replacement.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+
+        {
+            let mut identity = self
+                .identity
+                .lock()
+                .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
+            let current = FileIdentity::of(&self.path);
+            if current.is_some() && current == *identity {
+                return Ok(());
+            }
+
+            warn!(
+                "The database file '{}' was replaced or deleted by another process, reopening it",
+                self.path.display()
+            );
+
+            let mut conn = self
+                .connection
+                .lock()
+                .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
+            // Close the stale connection before opening the new one: closing it removes
+            // the WAL and shared memory files by name, which must not happen once they
+            // belong to the new file.
+            drop(std::mem::replace(&mut *conn, Connection::open_in_memory()?));
+            *conn = open_connection(&self.path)?;
+            *identity = FileIdentity::of(&self.path);
+        }
+
+        // A deleted file is recreated empty, and a replaced one may be older.
+        MigrationManager::new().run_migrations(self)
+    }
+
     /// Run all pending migrations
     pub async fn run_migrations(&mut self) -> Result<()> {
         let migration_manager = MigrationManager::new();
@@ -67,43 +233,69 @@ impl Database {
         Ok(())
     }
 
+    /// Run pending migrations up to and including `target_version`
+    pub async fn run_migrations_to(&mut self, target_version: u32) -> Result<()> {
+        let migration_manager = MigrationManager::new();
+        migration_manager.run_migrations_to(self, target_version)?;
+        Ok(())
+    }
+
     /// Get a connection for executing queries
     pub fn get_connection(&self) -> Arc<Mutex<Connection>> {
+        if let Err(e) = self.ensure_file_unchanged() {
+            warn!("Failed to reopen the database: {}", e);
+        }
+
         Arc::clone(&self.connection)
     }
 
     /// Execute a query that doesn't return results
+    #[tracing::instrument(name = "db.query", skip_all, fields(db.system = "sqlite", db.query.text = sql))]
     pub fn execute<P>(&self, sql: &str, params: P) -> Result<usize>
     where
         P: rusqlite::Params,
     {
+        self.ensure_file_unchanged()?;
+
         let conn = self
             .connection
             .lock()
             .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
 
-        conn.execute(sql, params).context("Failed to execute query")
+        conn.execute(sql, params)
+            .map_err(|e| query_error(e, "Failed to execute query"))
     }
 
     /// Execute a batch of SQL statements
+    #[tracing::instrument(name = "db.query", skip_all, fields(db.system = "sqlite", db.query.text = sql))]
     pub fn execute_batch(&self, sql: &str) -> Result<()> {
+        self.ensure_file_unchanged()?;
+
         let conn = self
             .connection
             .lock()
             .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
 
         conn.execute_batch(sql)
-            .context("Failed to execute batch query")
+            .map_err(|e| query_error(e, "Failed to execute batch query"))
     }
 
-    /// Prepare a statement for execution
+    /// Prepare a statement for execution.
+    ///
+    /// The statement is compiled once and kept in the statement cache of the connection,
+    /// keyed by `sql`: running it, or preparing the same SQL again, reuses the compiled
+    /// statement instead of parsing the SQL again. Invalid SQL fails here rather than
+    /// when the statement runs.
     pub fn prepare(&self, sql: &str) -> Result<DatabaseStatement> {
+        self.ensure_file_unchanged()?;
+
         let conn = self
             .connection
             .lock()
             .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
 
-        let stmt = conn.prepare(sql).context("Failed to prepare statement")?;
+        conn.prepare_cached(sql)
+            .map_err(|e| query_error(e, "Failed to prepare statement"))?;
 
         Ok(DatabaseStatement {
             connection: Arc::clone(&self.connection),
@@ -113,22 +305,74 @@ impl Database {
 
     /// Begin a transaction
     pub fn transaction(&self) -> Result<DatabaseTransaction> {
+        self.ensure_file_unchanged()?;
         DatabaseTransaction::new(Arc::clone(&self.connection))
     }
 
     /// Get the database path
-    pub fn path(&self) -> &str {
+    pub fn path(&self) -> &Path {
         &self.path
     }
 
-    /// Close the database connection
-    pub fn close(self) {
-        // Connection will be closed when dropped
-        drop(self.connection);
+    /// Checkpoint the write-ahead log into the database file and truncate it.
+    ///
+    /// Fails when another connection keeps the checkpoint from completing, e.g. a reader
+    /// in another `am` process.
+    pub fn checkpoint(&self) -> Result<()> {
+        let conn = self
+            .connection
+            .lock()
+            .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
+        checkpoint_wal(&conn)
+    }
+
+    /// Checkpoint the write-ahead log without owning the database, for a process about to
+    /// end without closing it (a panic or Ctrl-C).
+    ///
+    /// Unlike [`Database::checkpoint`], this doesn't wait for the connection: it fails when
+    /// the connection is in use, possibly by the thread that is panicking, instead of
+    /// deadlocking. A connection poisoned by a panic is still checkpointed.
+    pub fn emergency_checkpoint(&self) -> Result<()> {
+        let conn = match self.connection.try_lock() {
+            Ok(conn) => conn,
+            Err(TryLockError::Poisoned(e)) => e.into_inner(),
+            Err(TryLockError::WouldBlock) => {
+                anyhow::bail!("The database connection is in use, skipping the checkpoint")
+            }
+        };
+        checkpoint_wal(&conn)
+    }
+
+    /// Checkpoint the write-ahead log and close the database connection.
+    ///
+    /// SQLite removes the `-wal` file when its last connection closes after a complete
+    /// checkpoint, so the file is left behind when the checkpoint fails or when
+    /// statements or transactions still hold the connection. Both are reported as
+    /// errors; the connection is dropped either way.
+    pub fn close(self) -> Result<()> {
+        let checkpoint = self.checkpoint();
+
+        let connection = Arc::try_unwrap(self.connection).map_err(|connection| {
+            anyhow::anyhow!(
+                "The database connection is still used by {} other handle(s)",
+                Arc::strong_count(&connection) - 1
+            )
+        })?;
+        let conn = connection.into_inner().unwrap_or_else(|e| e.into_inner());
+        conn.close()
+            .map_err(|(_, e)| e)
+            .context("Failed to close the database connection")?;
+
+        checkpoint
     }
 }
 
-/// Wrapper for a prepared statement
+/// Wrapper for a prepared statement.
+///
+/// The compiled statement lives in the statement cache of the connection, which it
+/// can't borrow from here while other statements use the connection: each run takes it
+/// from the cache by its SQL, and puts it back once done. It is compiled again only if
+/// it was evicted from the cache in between.
 pub struct DatabaseStatement {
     connection: Arc<Mutex<Connection>>,
     sql: String,
@@ -136,6 +380,7 @@ pub struct DatabaseStatement {
 
 impl DatabaseStatement {
     /// Execute the prepared statement
+    #[tracing::instrument(name = "db.query", skip_all, fields(db.system = "sqlite", db.query.text = %self.sql))]
     pub fn execute<P>(&self, params: P) -> Result<usize>
     where
         P: rusqlite::Params,
@@ -145,11 +390,15 @@ impl DatabaseStatement {
             .lock()
             .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
 
-        conn.execute(&self.sql, params)
-            .context("Failed to execute prepared statement")
+        let mut stmt = conn
+            .prepare_cached(&self.sql)
+            .map_err(|e| query_error(e, "Failed to prepare statement"))?;
+        stmt.execute(params)
+            .map_err(|e| query_error(e, "Failed to execute prepared statement"))
     }
 
     /// Query the prepared statement
+    #[tracing::instrument(name = "db.query", skip_all, fields(db.system = "sqlite", db.query.text = %self.sql))]
     pub fn query_map<T, P, F>(&self, params: P, f: F) -> Result<Vec<T>>
     where
         P: rusqlite::Params,
@@ -160,8 +409,12 @@ impl DatabaseStatement {
             .lock()
             .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
 
-        let mut stmt = conn.prepare(&self.sql)?;
-        let rows = stmt.query_map(params, f)?;
+        let mut stmt = conn
+            .prepare_cached(&self.sql)
+            .map_err(|e| query_error(e, "Failed to prepare statement"))?;
+        let rows = stmt
+            .query_map(params, f)
+            .map_err(|e| query_error(e, "Failed to run query"))?;
 
         let mut results = Vec::new();
         for row in rows {
@@ -170,6 +423,22 @@ impl DatabaseStatement {
 
         Ok(results)
     }
+
+    /// Number of times the compiled statement ran since it was compiled.
+    ///
+    /// Runs of statements preparing the same SQL add up, as they share the compiled
+    /// statement; the count starts over when it is compiled again.
+    pub fn run_count(&self) -> Result<u32> {
+        let conn = self
+            .connection
+            .lock()
+            .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
+
+        let stmt = conn
+            .prepare_cached(&self.sql)
+            .map_err(|e| query_error(e, "Failed to prepare statement"))?;
+        Ok(stmt.get_status(StatementStatus::Run).max(0) as u32)
+    }
 }
 
 /// Wrapper for a database transaction
@@ -197,6 +466,7 @@ impl DatabaseTransaction {
     }
 
     /// Execute a query within the transaction
+    #[tracing::instrument(name = "db.query", skip_all, fields(db.system = "sqlite", db.query.text = sql))]
     pub fn execute<P>(&self, sql: &str, params: P) -> Result<usize>
     where
         P: rusqlite::Params,
@@ -207,10 +477,11 @@ impl DatabaseTransaction {
             .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
 
         conn.execute(sql, params)
-            .context("Failed to execute query in transaction")
+            .map_err(|e| query_error(e, "Failed to execute query in transaction"))
     }
 
     /// Execute a batch of SQL statements within the transaction
+    #[tracing::instrument(name = "db.query", skip_all, fields(db.system = "sqlite", db.query.text = sql))]
     pub fn execute_batch(&self, sql: &str) -> Result<()> {
         let conn = self
             .connection
@@ -218,7 +489,7 @@ impl DatabaseTransaction {
             .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
 
         conn.execute_batch(sql)
-            .context("Failed to execute batch query in transaction")
+            .map_err(|e| query_error(e, "Failed to execute batch query in transaction"))
     }
 
     /// Commit the transaction
@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use serde::{Deserialize, Serialize};
+use crate::assets::AssetType;
+use crate::common::files::parse_asset_json;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::collections::BTreeMap;
 use std::fmt::Display;
 
 /// Source type for templates - embedded in binary or custom user-registered.
@@ -44,6 +47,18 @@ pub struct ProjectConfiguration {
     pub data_dir: String,
     pub build_dir: String,
     pub version: u32,
+    /// Lifecycle hooks, from hook name (e.g. `post-init`) to a script path relative to
+    /// the project root.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub hooks: BTreeMap<String, String>,
+    /// Accept comments and trailing commas in hand-edited asset files. Requires the
+    /// `json-comments` feature; files written by the CLI are always strict JSON.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub allow_json_comments: bool,
+    /// Directory names overriding the default one of an asset type in the sources
+    /// directory (e.g. `"sound": "sfx"`).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub asset_dirs: BTreeMap<AssetType, String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -77,7 +92,39 @@ pub struct Template {
     pub source: TemplateSource,
 }
 
+impl Project {
+    /// Table storing registered projects.
+    pub const TABLE: &'static str = "projects";
+
+    /// Table columns read by the project row mapper.
+    pub const COLUMNS: &'static [&'static str] =
+        &["id", "name", "path", "created_at", "is_favorite"];
+}
+
+impl Template {
+    /// Table storing registered templates.
+    pub const TABLE: &'static str = "templates";
+
+    /// Table columns read by the template row mapper.
+    pub const COLUMNS: &'static [&'static str] = &["id", "name", "path", "engine", "description"];
+}
+
 impl ProjectConfiguration {
+    /// Parse the content of one of this project's asset files, honoring
+    /// `allow_json_comments`.
+    pub fn parse_asset_json<T: DeserializeOwned>(&self, content: &str) -> serde_json::Result<T> {
+        parse_asset_json(content, self.allow_json_comments)
+    }
+
+    /// Name of the directory holding assets of the given type in the sources directory,
+    /// honoring `asset_dirs` overrides.
+    pub fn asset_dir(&self, asset_type: AssetType) -> &str {
+        self.asset_dirs
+            .get(&asset_type)
+            .map(String::as_str)
+            .unwrap_or_else(|| asset_type.directory_name())
+    }
+
     pub fn to_project(&self, path: &str) -> Project {
         Project {
             id: None,
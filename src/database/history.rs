@@ -0,0 +1,215 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Local history of the commands run.
+//!
+//! Every invocation is [recorded](record_command) in the `command_history` table: the
+//! command path (e.g. `project init`), whether it succeeded and how long it took. When
+//! it ran inside a registered project, a row of `project_usage` links it to the project.
+//! Nothing leaves the machine: `am stats usage` summarizes these tables with
+//! [`command_usage`] and [`project_usage`].
+//!
+//! Recording is on unless the `history_enabled` [setting](crate::common::settings) is
+//! `false`. Both tables are kept within their [retention limits](super::retention).
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use rusqlite::OptionalExtension;
+
+use crate::database::Database;
+
+/// Configuration key enabling the command history.
+pub const CONFIG_HISTORY_ENABLED: &str = "history_enabled";
+
+/// A command to add to the history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandRecord {
+    /// The command path without the binary name, e.g. `project init`.
+    pub command: String,
+    pub success: bool,
+    pub duration_ms: u64,
+    /// The registered project the command ran in, if any.
+    pub project_id: Option<i32>,
+}
+
+/// Usage of one command over a window of the history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandUsage {
+    pub command: String,
+    /// The project the runs were made in, for [`project_usage`].
+    pub project: Option<String>,
+    pub runs: usize,
+    pub failures: usize,
+    pub median_ms: u64,
+}
+
+impl CommandUsage {
+    /// Share of the runs that failed, from 0 to 1.
+    pub fn failure_rate(&self) -> f64 {
+        if self.runs == 0 {
+            0.0
+        } else {
+            self.failures as f64 / self.runs as f64
+        }
+    }
+}
+
+/// Add `record` to the history, with its project usage in the same transaction.
+pub fn record_command(database: &Database, record: &CommandRecord) -> Result<()> {
+    let conn = database.get_connection();
+    let conn = conn
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
+
+    let transaction = conn.unchecked_transaction()?;
+    transaction.execute(
+        "INSERT INTO command_history (command, success, duration_ms) VALUES (?1, ?2, ?3)",
+        rusqlite::params![record.command, record.success, record.duration_ms as i64],
+    )?;
+    if let Some(project_id) = record.project_id {
+        let command_id = transaction.last_insert_rowid();
+        transaction.execute(
+            "INSERT INTO project_usage (project_id, command_id) VALUES (?1, ?2)",
+            rusqlite::params![project_id, command_id],
+        )?;
+    }
+    transaction.commit()?;
+
+    Ok(())
+}
+
+/// Usage of every command recorded since `since`, most run first. With `project_id`,
+/// only the runs in that registered project are counted.
+pub fn command_usage(
+    database: &Database,
+    since: DateTime<Utc>,
+    project_id: Option<i32>,
+) -> Result<Vec<CommandUsage>> {
+    let query = database.prepare(
+        "SELECT command, NULL, success, duration_ms FROM command_history
+         WHERE created_at >= ?1
+           AND (?2 IS NULL OR id IN (SELECT command_id FROM project_usage WHERE project_id = ?2))",
+    )?;
+    let runs = query.query_map(
+        rusqlite::params![sql_timestamp(since), project_id],
+        run_from_row,
+    )?;
+
+    Ok(summarize(runs))
+}
+
+/// Usage of every command recorded since `since` in a registered project, by project
+/// and then most run first. With `project_id`, only that project is included.
+pub fn project_usage(
+    database: &Database,
+    since: DateTime<Utc>,
+    project_id: Option<i32>,
+) -> Result<Vec<CommandUsage>> {
+    let query = database.prepare(
+        "SELECT h.command, p.name, h.success, h.duration_ms FROM project_usage u
+         JOIN command_history h ON h.id = u.command_id
+         JOIN projects p ON p.id = u.project_id
+         WHERE u.created_at >= ?1 AND (?2 IS NULL OR u.project_id = ?2)",
+    )?;
+    let runs = query.query_map(
+        rusqlite::params![sql_timestamp(since), project_id],
+        run_from_row,
+    )?;
+
+    let mut usage = summarize(runs);
+    usage.sort_by(|a, b| a.project.cmp(&b.project));
+    Ok(usage)
+}
+
+/// When the `history_enabled` setting of the configuration table was last changed, if
+/// that was after `since`: commands run before or after then may be missing.
+pub fn history_setting_changed_since(
+    database: &Database,
+    since: DateTime<Utc>,
+) -> Result<Option<String>> {
+    let conn = database.get_connection();
+    let conn = conn
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
+
+    Ok(conn
+        .query_row(
+            "SELECT strftime('%Y-%m-%dT%H:%M:%SZ', updated_at) FROM configuration
+             WHERE key = ?1 AND updated_at >= ?2",
+            rusqlite::params![CONFIG_HISTORY_ENABLED, sql_timestamp(since)],
+            |row| row.get(0),
+        )
+        .optional()?)
+}
+
+/// The median of `durations`, the mean of the two middle values for an even count.
+pub fn median(durations: &mut [u64]) -> u64 {
+    if durations.is_empty() {
+        return 0;
+    }
+    durations.sort_unstable();
+    let middle = durations.len() / 2;
+    if durations.len() % 2 == 0 {
+        (durations[middle - 1] + durations[middle]) / 2
+    } else {
+        durations[middle]
+    }
+}
+
+/// A run read from the history: command, project, success and duration.
+type Run = (String, Option<String>, bool, u64);
+
+fn run_from_row(row: &rusqlite::Row) -> rusqlite::Result<Run> {
+    Ok((
+        row.get(0)?,
+        row.get(1)?,
+        row.get(2)?,
+        row.get::<_, i64>(3)?.max(0) as u64,
+    ))
+}
+
+/// Group `runs` by project and command, most run first.
+fn summarize(runs: Vec<Run>) -> Vec<CommandUsage> {
+    let mut groups: BTreeMap<(Option<String>, String), (usize, Vec<u64>)> = BTreeMap::new();
+    for (command, project, success, duration_ms) in runs {
+        let (failures, durations) = groups.entry((project, command)).or_default();
+        if !success {
+            *failures += 1;
+        }
+        durations.push(duration_ms);
+    }
+
+    let mut usage: Vec<CommandUsage> = groups
+        .into_iter()
+        .map(
+            |((project, command), (failures, mut durations))| CommandUsage {
+                command,
+                project,
+                runs: durations.len(),
+                failures,
+                median_ms: median(&mut durations),
+            },
+        )
+        .collect();
+    // Stable, so commands run as often stay sorted by name
+    usage.sort_by(|a, b| b.runs.cmp(&a.runs));
+    usage
+}
+
+/// `time` in the format of SQLite's `CURRENT_TIMESTAMP`, to compare with `created_at`.
+fn sql_timestamp(time: DateTime<Utc>) -> String {
+    time.format("%Y-%m-%d %H:%M:%S").to_string()
+}
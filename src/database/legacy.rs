@@ -0,0 +1,83 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The project registry of the legacy Amplitude editor.
+//!
+//! Before the SQLite registry, the Amplitude editor kept the projects it knew about in a
+//! `projects.json` file: an array of objects holding the `name` and `path` of each
+//! project, and optionally whether it is a `favorite` and when it was `lastOpened`.
+//! `am sudo import-legacy` registers the projects of such a file.
+
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::common::errors::{CliError, codes};
+
+/// A project of a legacy registry.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyProject {
+    /// The name the editor showed for the project.
+    pub name: String,
+    /// The project directory.
+    pub path: PathBuf,
+    /// Whether the project was pinned in the editor.
+    #[serde(default)]
+    pub favorite: bool,
+    /// When the editor last opened the project. Read but not imported, the registry
+    /// doesn't track it.
+    #[serde(default)]
+    pub last_opened: Option<String>,
+}
+
+/// Parse the content of a legacy `projects.json` file.
+///
+/// Fields the CLI doesn't know of are ignored, as the editor stored its own settings
+/// along with the projects. An entry that isn't a valid project fails the whole file
+/// with an `ERR_VALIDATION_SCHEMA` error naming its index.
+pub fn parse_legacy_registry(content: &str) -> Result<Vec<LegacyProject>, CliError> {
+    let value: serde_json::Value = serde_json::from_str(content).map_err(|e| {
+        CliError::new(
+            codes::ERR_VALIDATION_SCHEMA,
+            "Invalid legacy registry",
+            e.to_string(),
+        )
+        .with_suggestion("Pass the projects.json file of the legacy Amplitude editor")
+    })?;
+
+    let serde_json::Value::Array(values) = value else {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_SCHEMA,
+            "Invalid legacy registry",
+            "Expected an array of projects",
+        )
+        .with_suggestion("Pass the projects.json file of the legacy Amplitude editor"));
+    };
+
+    values
+        .into_iter()
+        .enumerate()
+        .map(|(index, value)| {
+            serde_json::from_value(value).map_err(|e| {
+                CliError::new(
+                    codes::ERR_VALIDATION_SCHEMA,
+                    format!("Invalid entry {} of the legacy registry", index),
+                    e.to_string(),
+                )
+                .with_suggestion("Each entry needs a 'name' and a 'path' string")
+            })
+        })
+        .collect()
+}
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use super::Database;
+use crate::common::errors::{CliError, codes};
+use crate::events::{self, CliEvent};
 use anyhow::{Context, Result};
 use log::debug;
 use std::collections::BTreeMap;
@@ -188,6 +190,43 @@ impl MigrationManager {
             },
         );
 
+        migrations.insert(
+            7,
+            Migration {
+                version: 7,
+                description: "Create command_history and project_usage tables".to_string(),
+                up_sql: r#"
+                    CREATE TABLE IF NOT EXISTS command_history (
+                        id INTEGER PRIMARY KEY AUTOINCREMENT,
+                        command TEXT NOT NULL,
+                        success INTEGER NOT NULL,
+                        duration_ms INTEGER NOT NULL,
+                        created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+                    );
+
+                    CREATE INDEX IF NOT EXISTS idx_command_history_created_at
+                    ON command_history(created_at);
+
+                    CREATE TABLE IF NOT EXISTS project_usage (
+                        id INTEGER PRIMARY KEY AUTOINCREMENT,
+                        project_id INTEGER NOT NULL,
+                        command_id INTEGER NOT NULL,
+                        created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                        FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE,
+                        FOREIGN KEY (command_id) REFERENCES command_history(id) ON DELETE CASCADE
+                    );
+
+                    CREATE INDEX IF NOT EXISTS idx_project_usage_created_at
+                    ON project_usage(created_at);
+                "#
+                .to_string(),
+                down_sql: Some(
+                    "DROP TABLE IF EXISTS project_usage; DROP TABLE IF EXISTS command_history;"
+                        .to_string(),
+                ),
+            },
+        );
+
         Self { migrations }
     }
 
@@ -229,8 +268,33 @@ impl MigrationManager {
         Ok(version)
     }
 
+    /// Get the schema version cached in the database header (`PRAGMA user_version`).
+    ///
+    /// Every applied migration updates it in the same transaction, so it matches the
+    /// latest recorded migration. Databases migrated before the cache existed report 0
+    /// until the next full migration check.
+    pub fn get_cached_version(&self, db: &Database) -> Result<u32> {
+        let conn = db.get_connection();
+        let conn = conn
+            .lock()
+            .map_err(|e| anyhow::anyhow!("Failed to acquire lock: {}", e))?;
+
+        let version: u32 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+        Ok(version)
+    }
+
     /// Run all pending migrations
+    ///
+    /// When the cached schema version already matches the latest migration, the
+    /// migrations table isn't queried at all, which keeps startup fast.
+    #[tracing::instrument(name = "db.migrate", skip_all)]
     pub fn run_migrations(&self, db: &Database) -> Result<()> {
+        let latest_version = self.latest_version();
+        if self.get_cached_version(db)? == latest_version {
+            debug!("Database is up to date (cached version {})", latest_version);
+            return Ok(());
+        }
+
         let current_version = self.get_current_version(db)?;
 
         debug!("Current database version: {}", current_version);
@@ -243,6 +307,8 @@ impl MigrationManager {
 
         if pending_migrations.is_empty() {
             debug!("Database is up to date");
+            // Backfill the cache of databases migrated before it existed.
+            db.execute_batch(&format!("PRAGMA user_version = {}", current_version))?;
             return Ok(());
         }
 
@@ -257,7 +323,80 @@ impl MigrationManager {
         Ok(())
     }
 
+    /// Get the version of the most recent migration known to this manager
+    pub fn latest_version(&self) -> u32 {
+        self.migrations.keys().next_back().copied().unwrap_or(0)
+    }
+
+    /// Get the migrations that would be applied to reach `target_version`, in order.
+    ///
+    /// Fails if `target_version` is not a known migration version or is older than the
+    /// current schema version, since migrations only run forward.
+    pub fn pending_migrations_to(
+        &self,
+        db: &Database,
+        target_version: u32,
+    ) -> Result<Vec<&Migration>> {
+        let current_version = self.get_current_version(db)?;
+
+        if !self.migrations.contains_key(&target_version) {
+            return Err(CliError::new(
+                codes::ERR_VALIDATION_FIELD,
+                format!("Unknown schema version {}", target_version),
+                format!(
+                    "Available schema versions are 1 to {}",
+                    self.latest_version()
+                ),
+            )
+            .into());
+        }
+
+        if target_version < current_version {
+            return Err(CliError::new(
+                codes::ERR_VALIDATION_FIELD,
+                format!("Cannot migrate to schema version {}", target_version),
+                format!(
+                    "The database is already at schema version {} and migrations only run forward",
+                    current_version
+                ),
+            )
+            .with_suggestion("Use 'am sudo database reset' to start over from a fresh database")
+            .into());
+        }
+
+        Ok(self
+            .migrations
+            .range(current_version + 1..=target_version)
+            .map(|(_, migration)| migration)
+            .collect())
+    }
+
+    /// Run pending migrations up to and including `target_version`
+    #[tracing::instrument(name = "db.migrate", skip(self, db))]
+    pub fn run_migrations_to(&self, db: &Database, target_version: u32) -> Result<()> {
+        let pending_migrations = self.pending_migrations_to(db, target_version)?;
+
+        if pending_migrations.is_empty() {
+            debug!("Database is already at version {}", target_version);
+            return Ok(());
+        }
+
+        debug!(
+            "Applying {} migration(s) up to version {}",
+            pending_migrations.len(),
+            target_version
+        );
+
+        for migration in pending_migrations {
+            self.apply_migration(db, migration)
+                .with_context(|| format!("Failed to apply migration {}", migration.version))?;
+        }
+
+        Ok(())
+    }
+
     /// Apply a single migration
+    #[tracing::instrument(name = "db.migration", skip_all, fields(version = migration.version))]
     fn apply_migration(&self, db: &Database, migration: &Migration) -> Result<()> {
         debug!(
             "Applying migration {}: {}",
@@ -287,9 +426,18 @@ impl MigrationManager {
             )
             .with_context(|| format!("Failed to record migration {}", migration.version))?;
 
+        // Keep the cached schema version in sync for the startup fast path
+        transaction
+            .execute_batch(&format!("PRAGMA user_version = {}", migration.version))
+            .with_context(|| format!("Failed to record migration {}", migration.version))?;
+
         transaction.commit()?;
 
         debug!("Migration {} applied successfully", migration.version);
+        events::emit(CliEvent::MigrationApplied {
+            version: migration.version,
+            description: migration.description.clone(),
+        });
         Ok(())
     }
 
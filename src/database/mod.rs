@@ -12,143 +12,282 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod backup;
 mod connection;
 pub mod entities;
-mod migrations;
+pub mod history;
+pub mod legacy;
+pub mod migrations;
+pub mod retention;
+pub mod size;
+pub mod transfer;
 
-pub use connection::Database;
+pub use connection::{Database, STATEMENT_CACHE_CAPACITY};
 
+use crate::common::errors::{CliError, codes, database_unavailable, project_already_exists};
+use crate::common::utils::canonical_project_path;
 use crate::database::entities::{Project, Template};
-use anyhow::{Context, Result};
-use std::path::PathBuf;
-use std::sync::Arc;
-
-/// Error message for when database is required but not available.
-const ERR_DATABASE_NOT_AVAILABLE: &str =
-    "Database is not available. This operation requires a database connection.";
+use crate::events::{self, CliEvent};
+use anyhow::Result;
+use log::debug;
+use rusqlite::OptionalExtension;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Weak};
+
+/// Convert an error of SQLite returned by a query of the registry, reporting a missing
+/// table as an `ERR_DATABASE_SCHEMA_OUTDATED` error.
+fn sqlite_error(error: rusqlite::Error) -> anyhow::Error {
+    connection::query_error(error, "Failed to query the database")
+}
 
 /// Initialize the database system
+///
+/// Before pending migrations are applied, the database is [backed up](backup), and a
+/// failed migration suggests restoring the backup. Once migrated, the size of the
+/// database file is checked, see [`size`].
 pub async fn initialize() -> Result<Database> {
+    let mut database = open()?;
+
+    let backups_dir = crate::common::paths::resolve()?.backups_dir();
+    let latest_version = migrations::MigrationManager::new().latest_version();
+    let backup = backup::backup_before_migration(&database, &backups_dir, latest_version)?;
+    if let Err(e) = database.run_migrations().await {
+        return Err(match backup {
+            Some(backup) => backup::migration_failed(e, &backup, database.path()).into(),
+            None => e,
+        });
+    }
+    if let Some(backup) = backup {
+        database.set_migration_backup(backup);
+    }
+
+    // The check is informational, failing it never prevents the command from running.
+    match size::check_database_size(&database) {
+        Ok(report) => database.set_size_report(report),
+        Err(e) => debug!("Failed to check the database size: {}", e),
+    }
+
+    Ok(database)
+}
+
+/// Open the database without running migrations.
+///
+/// Used by commands that manage migrations themselves.
+pub fn open() -> Result<Database> {
     let db_path = get_database_path()?;
 
-    // Ensure the .amplitude directory exists
+    // Ensure the data directory exists
     if let Some(parent) = db_path.parent() {
         std::fs::create_dir_all(parent)?;
     }
 
-    let mut database = Database::new(&db_path)?;
-    database.run_migrations().await?;
-
-    Ok(database)
+    Database::new(&db_path)
 }
 
-/// Get the path to the database file. The database file is stored in the user's directory, in
-/// an `.amplitude` folder.
+/// Get the path to the database file, in the data directory chosen by
+/// [`paths::resolve`](crate::common::paths::resolve).
 pub fn get_database_path() -> Result<PathBuf> {
-    let home_dir =
-        dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Could not determine home directory"))?;
-
-    Ok(home_dir.join(".amplitude").join("am.db"))
+    Ok(crate::common::paths::resolve()?.database_path())
 }
 
-/// Cleanup function to be called on application exit. Gracefully closes the database.
+/// Cleanup function to be called on application exit. Gracefully closes the database,
+/// logging whether the write-ahead log could be checkpointed.
 pub fn cleanup(database: Option<Database>) {
     if let Some(db) = database {
-        db.close();
+        match db.close() {
+            Ok(()) => debug!("Database closed, write-ahead log checkpointed"),
+            Err(e) => debug!("Database closed without a complete checkpoint: {:#}", e),
+        }
+    }
+}
+
+/// Cleanup function for a process ending abruptly (panic or Ctrl-C): checkpoints the
+/// write-ahead log of the database behind `db` if it is still open, see
+/// [`Database::emergency_checkpoint`].
+///
+/// `db` is weak so that holding it doesn't keep [`cleanup`] from closing the database on
+/// a normal exit.
+pub fn emergency_cleanup(db: &Weak<Database>) {
+    let Some(db) = db.upgrade() else {
+        return;
+    };
+
+    match db.emergency_checkpoint() {
+        Ok(()) => debug!("Write-ahead log checkpointed before exiting"),
+        Err(e) => debug!("Exiting without a complete checkpoint: {:#}", e),
     }
 }
 
-/// Cleanup the given database on application panic
-pub fn setup_crash_db_cleanup(db: Option<Arc<Database>>) {
+/// Checkpoint the given database on application panic, see [`emergency_cleanup`].
+pub fn setup_crash_db_cleanup(db: Option<&Arc<Database>>) {
     let default_hook = std::panic::take_hook();
-    let db_clone = db.clone();
+    let db = db.map(Arc::downgrade);
     std::panic::set_hook(Box::new(move |panic_info| {
         eprintln!("Application panicked: {}", panic_info);
 
-        if let Some(db) = &db_clone {
-            if let Ok(db) = Arc::try_unwrap(db.clone()) {
-                cleanup(Some(db));
-            }
+        if let Some(db) = &db {
+            emergency_cleanup(db);
         }
 
         default_hook(panic_info);
     }));
 }
 
+/// Map a `projects` row to a [`Project`].
+///
+/// Columns are read by name so reordering columns in a migration cannot shift values
+/// into the wrong fields. Queries must select `registered_at` as an RFC 3339 UTC timestamp:
+/// `strftime('%Y-%m-%dT%H:%M:%SZ', created_at) as registered_at`.
+fn project_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<Project> {
+    Ok(Project {
+        id: row.get("id")?,
+        name: row.get("name")?,
+        path: row.get("path")?,
+        registered_at: row.get("registered_at")?,
+        is_favorite: row.get::<_, i32>("is_favorite")? != 0,
+    })
+}
+
+/// Map a `templates` row to a custom [`Template`].
+fn template_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<Template> {
+    Ok(Template {
+        id: row.get("id")?,
+        name: row.get("name")?,
+        path: row.get("path")?,
+        engine: row.get("engine")?,
+        description: row.get("description")?,
+        source: entities::TemplateSource::Custom,
+    })
+}
+
+/// Compare the columns read by the entity row mappers with the actual table schemas.
+///
+/// Returns one message per missing table or column. An empty list means every column
+/// the row mappers read exists. Since the mappers read columns by name, the position of
+/// columns in the table doesn't matter.
+pub fn db_check_schema_drift(database: Option<Arc<Database>>) -> Result<Vec<String>> {
+    let db = database.as_ref().ok_or_else(database_unavailable)?;
+
+    let mut drift = Vec::new();
+
+    for (table, expected) in [
+        (Project::TABLE, Project::COLUMNS),
+        (Template::TABLE, Template::COLUMNS),
+    ] {
+        let query = db.prepare(&format!("PRAGMA table_info({})", table))?;
+        let actual: Vec<String> = query.query_map([], |row| row.get("name"))?;
+
+        if actual.is_empty() {
+            drift.push(format!("Table '{}' does not exist", table));
+            continue;
+        }
+
+        for column in expected {
+            if !actual.iter().any(|c| c == column) {
+                drift.push(format!(
+                    "Column '{}.{}' is read by the row mapper but missing from the table (columns: {})",
+                    table,
+                    column,
+                    actual.join(", ")
+                ));
+            }
+        }
+    }
+
+    Ok(drift)
+}
+
 /// Get all templates from the database
 pub fn db_get_templates(database: Option<Arc<Database>>) -> Result<Vec<entities::Template>> {
-    let db = database.as_ref().context(ERR_DATABASE_NOT_AVAILABLE)?;
+    let db = database.as_ref().ok_or_else(database_unavailable)?;
 
     let query =
         db.prepare("SELECT id, name, path, engine, description FROM templates ORDER BY name ASC")?;
 
-    query.query_map([], |row| {
-        Ok(Template {
-            id: row.get(0)?,
-            name: row.get(1)?,
-            path: row.get(2)?,
-            engine: row.get(3)?,
-            description: row.get(4)?,
-            source: entities::TemplateSource::Custom,
-        })
-    })
+    query.query_map([], template_from_row)
 }
 
-/// Get a template by name from the database. Returns `Ok(None)` if the template is not found.
+/// Get a template by name from the database.
+///
+/// # Returns
+/// * `Ok(Some(template))` - A template is registered under `name`
+/// * `Ok(None)` - No template is registered under `name`
+/// * `Err` - Database error occurred
 pub fn db_get_template_by_name(
     name: &str,
     database: Option<Arc<Database>>,
 ) -> Result<Option<entities::Template>> {
-    let db = database.as_ref().context(ERR_DATABASE_NOT_AVAILABLE)?;
+    let db = database.as_ref().ok_or_else(database_unavailable)?;
 
     let query =
         db.prepare("SELECT id, name, path, engine, description FROM templates WHERE name = $1")?;
 
-    let results = query.query_map([name], |row| {
-        Ok(Template {
-            id: row.get(0)?,
-            name: row.get(1)?,
-            path: row.get(2)?,
-            engine: row.get(3)?,
-            description: row.get(4)?,
-            source: entities::TemplateSource::Custom,
-        })
-    })?;
+    let results = query.query_map([name], template_from_row)?;
 
     Ok(results.first().cloned())
 }
 
 /// Inserts a new project into the database.
+///
+/// The insert and the conflict lookup run under the same connection lock, so concurrent
+/// registrations of the same name cannot both succeed. A name conflict is reported as
+/// `ERR_PROJECT_ALREADY_EXISTS` with the path of the already registered project.
+///
+/// The path is stored as given by [`canonical_project_path`], see
+/// [`db_get_project_by_path`].
 pub fn db_create_project(project: &Project, database: Option<Arc<Database>>) -> Result<bool> {
-    let db = database.as_ref().context(ERR_DATABASE_NOT_AVAILABLE)?;
+    let db = database.as_ref().ok_or_else(database_unavailable)?;
+    let path = canonical_project_path(Path::new(&project.path));
 
-    let query = db.prepare("INSERT INTO projects (name, path) VALUES ($1, $2)")?;
+    let conn = db.get_connection();
+    let conn = conn
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
 
-    query
-        .execute([project.name.clone(), project.path.clone()])
-        .map(|_| true)
+    let inserted = conn
+        .execute(
+            "INSERT INTO projects (name, path) VALUES (?1, ?2) ON CONFLICT(name) DO NOTHING",
+            rusqlite::params![project.name, path],
+        )
+        .map_err(sqlite_error)?;
+
+    if inserted == 0 {
+        let existing_path: String = conn.query_row(
+            "SELECT path FROM projects WHERE name = ?1",
+            [&project.name],
+            |row| row.get(0),
+        )?;
+
+        return Err(project_already_exists(&project.name)
+            .with_context(existing_path)
+            .into());
+    }
+
+    events::emit(CliEvent::ProjectRegistered {
+        name: project.name.clone(),
+        path,
+    });
+    Ok(true)
 }
 
 /// Get a project by name from the database.
+///
+/// # Returns
+/// * `Ok(Some(project))` - A project is registered under `name`
+/// * `Ok(None)` - No project is registered under `name`
+/// * `Err` - Database error occurred
 pub fn db_get_project_by_name(
     name: &str,
     database: Option<Arc<Database>>,
 ) -> Result<Option<entities::Project>> {
-    let db = database.as_ref().context(ERR_DATABASE_NOT_AVAILABLE)?;
+    let db = database.as_ref().ok_or_else(database_unavailable)?;
 
     let query = db.prepare(
-        "SELECT id, name, path, date(created_at) as registered_at, is_favorite FROM projects WHERE name = $1",
+        "SELECT id, name, path, strftime('%Y-%m-%dT%H:%M:%SZ', created_at) as registered_at, is_favorite FROM projects WHERE name = $1",
     )?;
 
-    let results = query.query_map([name], |row| {
-        Ok(Project {
-            id: row.get(0)?,
-            name: row.get(1)?,
-            path: row.get(2)?,
-            registered_at: row.get(3)?,
-            is_favorite: row.get::<_, i32>(4)? != 0,
-        })
-    })?;
+    let results = query.query_map([name], project_from_row)?;
 
     Ok(results.first().cloned())
 }
@@ -171,7 +310,7 @@ pub fn db_get_projects_filtered(
     favorite_only: Option<bool>,
     database: Option<Arc<Database>>,
 ) -> Result<Vec<entities::Project>> {
-    let db = database.as_ref().context(ERR_DATABASE_NOT_AVAILABLE)?;
+    let db = database.as_ref().ok_or_else(database_unavailable)?;
 
     let (where_clause, order_clause) = match favorite_only {
         None => ("", "is_favorite DESC, name ASC"),
@@ -180,38 +319,46 @@ pub fn db_get_projects_filtered(
     };
 
     let sql = format!(
-        "SELECT id, name, path, date(created_at) as registered_at, is_favorite \
+        "SELECT id, name, path, strftime('%Y-%m-%dT%H:%M:%SZ', created_at) as registered_at, is_favorite \
          FROM projects {where_clause} ORDER BY {order_clause}"
     );
 
     let query = db.prepare(&sql)?;
 
-    query.query_map([], |row| {
-        Ok(Project {
-            id: row.get(0)?,
-            name: row.get(1)?,
-            path: row.get(2)?,
-            registered_at: row.get(3)?,
-            is_favorite: row.get::<_, i32>(4)? != 0,
-        })
-    })
+    query.query_map([], project_from_row)
 }
 
 pub fn db_forget_project(id: i32, database: Option<Arc<Database>>) -> Result<bool> {
-    let db = database.as_ref().context(ERR_DATABASE_NOT_AVAILABLE)?;
+    let db = database.as_ref().ok_or_else(database_unavailable)?;
 
     let query = db.prepare("DELETE FROM projects WHERE id = $1")?;
 
     query.execute([id]).map(|_| true)
 }
 
+/// Unregister several projects at once. Either all of them are removed or none is.
+///
+/// Returns the number of projects removed; ids that don't exist are skipped.
+pub fn db_forget_projects(ids: &[i32], database: Option<Arc<Database>>) -> Result<usize> {
+    let db = database.as_ref().ok_or_else(database_unavailable)?;
+
+    let transaction = db.transaction()?;
+    let mut removed = 0;
+    for id in ids {
+        removed += transaction.execute("DELETE FROM projects WHERE id = ?1", [id])?;
+    }
+    transaction.commit()?;
+
+    Ok(removed)
+}
+
 /// Update the `is_favorite` flag for a project. Returns whether a row was affected.
 pub fn db_set_project_favorite(
     id: i32,
     value: bool,
     database: Option<Arc<Database>>,
 ) -> Result<bool> {
-    let db = database.as_ref().context(ERR_DATABASE_NOT_AVAILABLE)?;
+    let db = database.as_ref().ok_or_else(database_unavailable)?;
 
     let query = db.prepare("UPDATE projects SET is_favorite = ?1 WHERE id = ?2")?;
 
@@ -219,28 +366,161 @@ pub fn db_set_project_favorite(
     Ok(rows > 0)
 }
 
+/// Key of the project metadata marking a registration made with
+/// `am project register --name`: the name declared in `.amproject`, which the registered
+/// name intentionally differs from.
+pub const PROJECT_METADATA_ALIAS_OF_FILE_NAME: &str = "alias_of_file_name";
+
+/// Mark the project registered as `name` as an alias of the project named `file_name` in
+/// its `.amproject`, or remove the mark with `None`. Returns whether a row was affected.
+pub fn db_set_project_alias_of_file_name(
+    name: &str,
+    file_name: Option<&str>,
+    database: Option<Arc<Database>>,
+) -> Result<bool> {
+    let db = database.as_ref().ok_or_else(database_unavailable)?;
+    let key = format!("$.{}", PROJECT_METADATA_ALIAS_OF_FILE_NAME);
+
+    let rows = match file_name {
+        Some(file_name) => db.execute(
+            "UPDATE projects SET metadata = json_set(COALESCE(metadata, '{}'), ?1, ?2) \
+             WHERE name = ?3",
+            rusqlite::params![key, file_name, name],
+        )?,
+        None => db.execute(
+            "UPDATE projects SET metadata = json_remove(metadata, ?1) WHERE name = ?2",
+            rusqlite::params![key, name],
+        )?,
+    };
+
+    Ok(rows > 0)
+}
+
+/// The name declared in `.amproject` that the project registered as `name` is an alias
+/// of, see [`db_set_project_alias_of_file_name`].
+pub fn db_get_project_alias_of_file_name(
+    name: &str,
+    database: Option<Arc<Database>>,
+) -> Result<Option<String>> {
+    let db = database.as_ref().ok_or_else(database_unavailable)?;
+
+    let conn = db.get_connection();
+    let conn = conn
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
+
+    let alias: Option<Option<String>> = conn
+        .query_row(
+            "SELECT json_extract(metadata, ?1) FROM projects WHERE name = ?2",
+            rusqlite::params![format!("$.{}", PROJECT_METADATA_ALIAS_OF_FILE_NAME), name],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(sqlite_error)?;
+
+    Ok(alias.flatten())
+}
+
+/// Rename a registered project, keeping its path, favorite flag and registration date.
+///
+/// # Returns
+/// * `Ok(true)` - Project was renamed
+/// * `Ok(false)` - No project with this id exists
+/// * `Err` - Another project is already registered as `new_name` (`ERR_PROJECT_ALREADY_EXISTS`),
+///   or a database error occurred
+pub fn db_rename_project(id: i32, new_name: &str, database: Option<Arc<Database>>) -> Result<bool> {
+    let db = database.as_ref().ok_or_else(database_unavailable)?;
+
+    let conn = db.get_connection();
+    let conn = conn
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
+
+    let existing_path: Option<String> = conn
+        .query_row(
+            "SELECT path FROM projects WHERE name = ?1 AND id != ?2",
+            rusqlite::params![new_name, id],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(sqlite_error)?;
+
+    if let Some(existing_path) = existing_path {
+        return Err(project_already_exists(new_name)
+            .with_context(existing_path)
+            .into());
+    }
+
+    let rows_affected = conn.execute(
+        "UPDATE projects SET name = ?1 WHERE id = ?2",
+        rusqlite::params![new_name, id],
+    )?;
+
+    Ok(rows_affected > 0)
+}
+
+/// Point a registered project to the directory at `path`, keeping its name, favorite
+/// flag and registration date. The path is stored as given by [`canonical_project_path`].
+///
+/// # Returns
+/// * `Ok(true)` - The project now points to `path`
+/// * `Ok(false)` - No project with this id exists
+/// * `Err` - Database error occurred
+pub fn db_set_project_path(id: i32, path: &str, database: Option<Arc<Database>>) -> Result<bool> {
+    let db = database.as_ref().ok_or_else(database_unavailable)?;
+    let path = canonical_project_path(Path::new(path));
+
+    let rows_affected = db.execute(
+        "UPDATE projects SET path = ?1 WHERE id = ?2",
+        rusqlite::params![path, id],
+    )?;
+
+    Ok(rows_affected > 0)
+}
+
 /// Get a project by its filesystem path from the database.
+///
+/// `path` is compared in its [canonical](canonical_project_path) form, so trailing
+/// separators, relative paths and symbolic links to a registered project all find it.
+/// Registrations whose stored path isn't canonical, like ones made before paths were
+/// canonicalized, are matched by comparing their normalized form. Paths are compared
+/// case-insensitively on Windows.
+///
+/// # Returns
+/// * `Ok(Some(project))` - A project is registered at `path`
+/// * `Ok(None)` - No project is registered at `path`
+/// * `Err` - Database error occurred
 pub fn db_get_project_by_path(
     path: &str,
     database: Option<Arc<Database>>,
 ) -> Result<Option<entities::Project>> {
-    let db = database.as_ref().context(ERR_DATABASE_NOT_AVAILABLE)?;
+    let db = database.as_ref().ok_or_else(database_unavailable)?;
+    let path = canonical_project_path(Path::new(path));
 
     let query = db.prepare(
-        "SELECT id, name, path, date(created_at) as registered_at, is_favorite FROM projects WHERE path = $1",
+        "SELECT id, name, path, strftime('%Y-%m-%dT%H:%M:%SZ', created_at) as registered_at, is_favorite FROM projects WHERE path = $1",
     )?;
 
-    let results = query.query_map([path], |row| {
-        Ok(Project {
-            id: row.get(0)?,
-            name: row.get(1)?,
-            path: row.get(2)?,
-            registered_at: row.get(3)?,
-            is_favorite: row.get::<_, i32>(4)? != 0,
-        })
-    })?;
+    if let Some(project) = query.query_map([&path], project_from_row)?.first() {
+        return Ok(Some(project.clone()));
+    }
 
-    Ok(results.first().cloned())
+    let key = path_key(&path);
+    Ok(db_get_all_projects(database.clone())?
+        .into_iter()
+        .find(|p| path_key(&canonical_project_path(Path::new(&p.path))) == key))
+}
+
+/// Key under which two canonical project paths are considered the same.
+///
+/// Windows paths are case-insensitive and `canonicalize` returns them with a verbatim
+/// `\\?\` prefix, which a path that doesn't exist anymore lacks.
+fn path_key(path: &str) -> String {
+    if cfg!(windows) {
+        path.strip_prefix(r"\\?\").unwrap_or(path).to_lowercase()
+    } else {
+        path.to_string()
+    }
 }
 
 /// Inserts a new template into the database.
@@ -253,7 +533,7 @@ pub fn db_get_project_by_path(
 /// * `Ok(true)` - Template was inserted successfully
 /// * `Err` - Database error occurred
 pub fn db_create_template(template: &Template, database: Option<Arc<Database>>) -> Result<bool> {
-    let db = database.as_ref().context(ERR_DATABASE_NOT_AVAILABLE)?;
+    let db = database.as_ref().ok_or_else(database_unavailable)?;
 
     let conn = db.get_connection();
     let conn = conn
@@ -268,11 +548,75 @@ pub fn db_create_template(template: &Template, database: Option<Arc<Database>>)
             template.engine,
             template.description,
         ],
-    )?;
+    )
+    .map_err(sqlite_error)?;
 
     Ok(true)
 }
 
+/// Update the path of a registered template, renaming it to `new_name` at the same time.
+///
+/// The row is updated in place, so the template keeps its id, creation date and metadata.
+/// Pass the current name as `new_name` to only change the path.
+///
+/// # Returns
+/// * `Ok(true)` - Template was updated
+/// * `Ok(false)` - No template named `name` exists
+/// * `Err` - Another template is already named `new_name` (`ERR_TEMPLATE_NAME_CONFLICT`),
+///   or a database error occurred
+pub fn db_update_template(
+    name: &str,
+    new_name: &str,
+    path: &str,
+    database: Option<Arc<Database>>,
+) -> Result<bool> {
+    let db = database.as_ref().ok_or_else(database_unavailable)?;
+
+    let conn = db.get_connection();
+    let conn = conn
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
+
+    let exists: bool = conn
+        .query_row(
+            "SELECT EXISTS(SELECT 1 FROM templates WHERE name = ?1)",
+            [name],
+            |row| row.get(0),
+        )
+        .map_err(sqlite_error)?;
+    if !exists {
+        return Ok(false);
+    }
+
+    if new_name != name {
+        let existing_path: Option<String> = conn
+            .query_row(
+                "SELECT path FROM templates WHERE name = ?1",
+                [new_name],
+                |row| row.get(0),
+            )
+            .optional()?;
+
+        if let Some(existing_path) = existing_path {
+            return Err(CliError::new(
+                codes::ERR_TEMPLATE_NAME_CONFLICT,
+                format!("Template '{}' already exists", new_name),
+                "A custom template with this name is already registered",
+            )
+            .with_suggestion("Choose a different name, or unregister the other template first")
+            .with_context(existing_path)
+            .into());
+        }
+    }
+
+    let rows_affected = conn.execute(
+        "UPDATE templates SET name = ?1, path = ?2 WHERE name = ?3",
+        rusqlite::params![new_name, path, name],
+    )?;
+
+    Ok(rows_affected > 0)
+}
+
 /// Delete a template by name from the database.
 ///
 /// # Arguments
@@ -284,14 +628,73 @@ pub fn db_create_template(template: &Template, database: Option<Arc<Database>>)
 /// * `Ok(false)` - No template with that name existed
 /// * `Err` - Database error occurred
 pub fn db_delete_template_by_name(name: &str, database: Option<Arc<Database>>) -> Result<bool> {
-    let db = database.as_ref().context(ERR_DATABASE_NOT_AVAILABLE)?;
+    let db = database.as_ref().ok_or_else(database_unavailable)?;
 
     let conn = db.get_connection();
     let conn = conn
         .lock()
         .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
 
-    let rows_affected = conn.execute("DELETE FROM templates WHERE name = ?1", [name])?;
+    let rows_affected = conn
+        .execute("DELETE FROM templates WHERE name = ?1", [name])
+        .map_err(sqlite_error)?;
 
     Ok(rows_affected > 0)
 }
+
+/// Get the value of a configuration setting. Returns `Ok(None)` if the key is not set.
+pub fn db_get_config(key: &str, database: Option<Arc<Database>>) -> Result<Option<String>> {
+    let db = database.as_ref().ok_or_else(database_unavailable)?;
+
+    let query = db.prepare("SELECT value FROM configuration WHERE key = $1")?;
+
+    let results = query.query_map([key], |row| row.get::<_, String>(0))?;
+
+    Ok(results.into_iter().next())
+}
+
+/// Get every configuration setting, by key.
+pub fn db_get_all_config(database: Option<Arc<Database>>) -> Result<BTreeMap<String, String>> {
+    let db = database.as_ref().ok_or_else(database_unavailable)?;
+
+    let query = db.prepare("SELECT key, value FROM configuration")?;
+
+    let results = query.query_map([], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+    })?;
+
+    Ok(results.into_iter().collect())
+}
+
+/// Set a configuration setting, creating it if it doesn't exist yet.
+///
+/// `value_type` is one of `string`, `number`, `boolean` or `json`. The description of an
+/// existing setting is kept.
+pub fn db_set_config(
+    key: &str,
+    value: &str,
+    value_type: &str,
+    database: Option<Arc<Database>>,
+) -> Result<()> {
+    let db = database.as_ref().ok_or_else(database_unavailable)?;
+
+    db.execute(
+        "INSERT INTO configuration (key, value, type) VALUES (?1, ?2, ?3)
+         ON CONFLICT(key) DO UPDATE SET value = excluded.value, type = excluded.type",
+        rusqlite::params![key, value, value_type],
+    )?;
+
+    Ok(())
+}
+
+/// Remove a configuration setting. Removing a setting that isn't set does nothing.
+pub fn db_delete_config(key: &str, database: Option<Arc<Database>>) -> Result<()> {
+    let db = database.as_ref().ok_or_else(database_unavailable)?;
+
+    db.execute(
+        "DELETE FROM configuration WHERE key = ?1",
+        rusqlite::params![key],
+    )?;
+
+    Ok(())
+}
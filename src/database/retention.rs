@@ -0,0 +1,224 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Retention limits of the append-only tables.
+//!
+//! The tables of [`RETAINED_TABLES`] only ever get new rows, so each has a retention
+//! policy: a maximum number of rows and a maximum age, stored in the configuration table
+//! under `retention_<table>_max_rows` and `retention_<table>_max_age_days`. A limit of `0`
+//! disables it.
+//!
+//! [`run_maintenance`] enforces the policies, deleting the oldest rows in batches of
+//! [`MAINTENANCE_BATCH_SIZE`], each in its own statement so the write lock is never held
+//! for long. It runs on `am sudo database maintain`, and on about one invocation out of
+//! [`MAINTENANCE_INTERVAL`] through [`maybe_run_maintenance`]. Tables missing from the
+//! database are skipped.
+
+use std::collections::BTreeMap;
+use std::hash::{BuildHasher, RandomState};
+
+use anyhow::Result;
+use rusqlite::{Connection, OptionalExtension};
+
+use crate::database::Database;
+
+/// An append-only table, and the column holding the creation time of its rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetainedTable {
+    pub name: &'static str,
+    pub timestamp_column: &'static str,
+}
+
+/// Tables whose rows are pruned by [`run_maintenance`].
+pub const RETAINED_TABLES: &[RetainedTable] = &[
+    RetainedTable {
+        name: "audit_log",
+        timestamp_column: "created_at",
+    },
+    RetainedTable {
+        name: "command_history",
+        timestamp_column: "created_at",
+    },
+    RetainedTable {
+        name: "telemetry_events",
+        timestamp_column: "created_at",
+    },
+    RetainedTable {
+        name: "project_usage",
+        timestamp_column: "created_at",
+    },
+];
+
+/// Rows kept per table when the configuration doesn't set a limit.
+pub const DEFAULT_MAX_ROWS: u64 = 10_000;
+
+/// Age in days after which rows are deleted when the configuration doesn't set a limit.
+pub const DEFAULT_MAX_AGE_DAYS: u64 = 90;
+
+/// Number of rows deleted by a single statement.
+pub const MAINTENANCE_BATCH_SIZE: usize = 500;
+
+/// [`maybe_run_maintenance`] runs the maintenance once every this many calls on average.
+pub const MAINTENANCE_INTERVAL: u64 = 100;
+
+/// Number of rows deleted, by table.
+pub type PrunedCounts = BTreeMap<String, usize>;
+
+/// The limits enforced on a table. `None` means unlimited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    pub max_rows: Option<u64>,
+    pub max_age_days: Option<u64>,
+}
+
+/// Key of the configuration setting limiting the number of rows of `table`.
+pub fn max_rows_key(table: &str) -> String {
+    format!("retention_{}_max_rows", table)
+}
+
+/// Key of the configuration setting limiting the age of the rows of `table`.
+pub fn max_age_days_key(table: &str) -> String {
+    format!("retention_{}_max_age_days", table)
+}
+
+/// The retention policy of `table`, read from the configuration table.
+pub fn retention_policy(database: &Database, table: &str) -> Result<RetentionPolicy> {
+    let conn = database.get_connection();
+    let conn = conn
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
+    read_policy(&conn, table)
+}
+
+/// Enforce the retention policy of every table of [`RETAINED_TABLES`] in the database.
+///
+/// Rows older than the maximum age are deleted first, then the oldest rows beyond the
+/// maximum number of rows.
+pub fn run_maintenance(database: &Database) -> Result<PrunedCounts> {
+    run_maintenance_in_batches(database, MAINTENANCE_BATCH_SIZE)
+}
+
+/// [`run_maintenance`] deleting at most `batch_size` rows, at least 1, per statement.
+pub fn run_maintenance_in_batches(database: &Database, batch_size: usize) -> Result<PrunedCounts> {
+    let batch_size = batch_size.max(1);
+    let conn = database.get_connection();
+    let conn = conn
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
+
+    let mut pruned = PrunedCounts::new();
+    for table in RETAINED_TABLES {
+        if !table_exists(&conn, table.name)? {
+            continue;
+        }
+        let policy = read_policy(&conn, table.name)?;
+        pruned.insert(
+            table.name.to_string(),
+            prune_table(&conn, table, policy, batch_size)?,
+        );
+    }
+
+    Ok(pruned)
+}
+
+/// Run [`run_maintenance`] on about one call out of [`MAINTENANCE_INTERVAL`], returning
+/// `None` when it didn't run.
+pub fn maybe_run_maintenance(database: &Database) -> Result<Option<PrunedCounts>> {
+    // A fresh `RandomState` is randomly seeded, which is all the randomness needed here.
+    if RandomState::new().hash_one(0u8) % MAINTENANCE_INTERVAL != 0 {
+        return Ok(None);
+    }
+    run_maintenance(database).map(Some)
+}
+
+fn read_policy(conn: &Connection, table: &str) -> Result<RetentionPolicy> {
+    Ok(RetentionPolicy {
+        max_rows: read_limit(conn, &max_rows_key(table), DEFAULT_MAX_ROWS)?,
+        max_age_days: read_limit(conn, &max_age_days_key(table), DEFAULT_MAX_AGE_DAYS)?,
+    })
+}
+
+/// The limit set under `key`, `default` if unset or invalid, and `None` if set to `0`.
+fn read_limit(conn: &Connection, key: &str, default: u64) -> Result<Option<u64>> {
+    let value: Option<String> = conn
+        .query_row(
+            "SELECT value FROM configuration WHERE key = ?1",
+            [key],
+            |row| row.get(0),
+        )
+        .optional()?;
+    let limit = value
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .unwrap_or(default);
+    Ok((limit > 0).then_some(limit))
+}
+
+fn table_exists(conn: &Connection, table: &str) -> Result<bool> {
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = ?1",
+        [table],
+        |row| row.get(0),
+    )?;
+    Ok(count > 0)
+}
+
+fn prune_table(
+    conn: &Connection,
+    table: &RetainedTable,
+    policy: RetentionPolicy,
+    batch_size: usize,
+) -> Result<usize> {
+    let mut pruned = 0;
+
+    if let Some(max_age_days) = policy.max_age_days {
+        let delete = format!(
+            "DELETE FROM \"{table}\" WHERE rowid IN (SELECT rowid FROM \"{table}\" \
+             WHERE \"{column}\" < datetime('now', ?1) ORDER BY rowid LIMIT ?2)",
+            table = table.name,
+            column = table.timestamp_column,
+        );
+        let modifier = format!("-{} days", max_age_days);
+        loop {
+            let deleted = conn.execute(&delete, rusqlite::params![modifier, batch_size])?;
+            pruned += deleted;
+            if deleted < batch_size {
+                break;
+            }
+        }
+    }
+
+    if let Some(max_rows) = policy.max_rows {
+        let count: u64 = conn.query_row(
+            &format!("SELECT COUNT(*) FROM \"{}\"", table.name),
+            [],
+            |row| row.get(0),
+        )?;
+        let delete = format!(
+            "DELETE FROM \"{table}\" WHERE rowid IN (SELECT rowid FROM \"{table}\" \
+             ORDER BY rowid LIMIT ?1)",
+            table = table.name,
+        );
+        let mut excess = count.saturating_sub(max_rows) as usize;
+        while excess > 0 {
+            let deleted = conn.execute(&delete, [excess.min(batch_size)])?;
+            if deleted == 0 {
+                break;
+            }
+            pruned += deleted;
+            excess -= deleted;
+        }
+    }
+
+    Ok(pruned)
+}
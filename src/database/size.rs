@@ -0,0 +1,212 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Size limits of the database file.
+//!
+//! A runaway history or telemetry table can grow the database to gigabytes unnoticed.
+//! [`check_database_size`] runs when the database is [initialized](super::initialize)
+//! and compares the size of its files with two thresholds, read like other
+//! [settings](crate::common::settings) from the `AM_DB_SIZE_WARNING` and
+//! `AM_DB_SIZE_LIMIT` environment variables, then the configuration table:
+//!
+//! - Above `db_size_warning`, the CLI warns and lists the tables with the most rows.
+//! - Above `db_size_limit`, commands still run, but JSON envelopes carry
+//!   `"db_oversized": true` in their `meta`.
+//!
+//! `am sudo database maintain` brings the history tables back within their
+//! [retention limits](super::retention).
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use log::debug;
+use rusqlite::Connection;
+
+use crate::common::settings::Settings;
+use crate::common::utils::{format_size, parse_size};
+use crate::database::Database;
+use crate::presentation::Output;
+
+/// Configuration key holding the size above which the CLI warns about the database.
+pub const CONFIG_DB_SIZE_WARNING: &str = "db_size_warning";
+
+/// Configuration key holding the size above which the database is reported oversized.
+pub const CONFIG_DB_SIZE_LIMIT: &str = "db_size_limit";
+
+/// Size above which the CLI warns, when the setting is unset or invalid.
+pub const DEFAULT_DB_SIZE_WARNING: &str = "256MiB";
+
+/// Size above which the database is oversized, when the setting is unset or invalid.
+pub const DEFAULT_DB_SIZE_LIMIT: &str = "1GiB";
+
+/// Number of tables listed in the warning.
+pub const LARGEST_TABLES_SHOWN: usize = 3;
+
+/// The sizes, in bytes, the database file is checked against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizeThresholds {
+    pub warning: u64,
+    pub limit: u64,
+}
+
+impl SizeThresholds {
+    /// The thresholds set in `settings`, their defaults for unset or invalid values.
+    pub fn from_settings(settings: &Settings) -> Self {
+        let threshold = |key: &str, default: &str| {
+            let default = parse_size(default).unwrap_or(u64::MAX);
+            match settings.get(key).map(parse_size) {
+                Some(Ok(size)) => size,
+                Some(Err(e)) => {
+                    debug!("Ignoring invalid {}: {}", key, e);
+                    default
+                }
+                None => default,
+            }
+        };
+
+        Self {
+            warning: threshold(CONFIG_DB_SIZE_WARNING, DEFAULT_DB_SIZE_WARNING),
+            limit: threshold(CONFIG_DB_SIZE_LIMIT, DEFAULT_DB_SIZE_LIMIT),
+        }
+    }
+}
+
+/// How the size of the database compares with its [`SizeThresholds`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SizeStatus {
+    /// Below the warning threshold.
+    Normal,
+    /// Above the warning threshold.
+    Large,
+    /// Above the hard limit.
+    Oversized,
+}
+
+/// The result of [`check_database_size`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SizeReport {
+    /// Size of the database file and its write-ahead log, in bytes.
+    pub size: u64,
+    pub status: SizeStatus,
+    /// The tables with the most rows, with their row count, largest first. Only
+    /// counted when the size is above the warning threshold.
+    pub largest_tables: Vec<(String, u64)>,
+}
+
+/// Size of the database file at `path` and of its write-ahead log, in bytes.
+pub fn database_file_size(path: &Path) -> u64 {
+    let mut wal = path.as_os_str().to_owned();
+    wal.push("-wal");
+
+    [path.to_path_buf(), PathBuf::from(wal)]
+        .iter()
+        .filter_map(|file| fs::metadata(file).ok())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+/// Compare the size of `database` with the thresholds set in the environment and its
+/// configuration table.
+pub fn check_database_size(database: &Database) -> Result<SizeReport> {
+    let conn = database.get_connection();
+    let conn = conn
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
+
+    let config = read_config(&conn)?;
+    let settings = Settings::resolve(&BTreeMap::new(), |name| std::env::var(name).ok(), &config);
+    let thresholds = SizeThresholds::from_settings(&settings);
+
+    let size = database_file_size(database.path());
+    let status = if size > thresholds.limit {
+        SizeStatus::Oversized
+    } else if size > thresholds.warning {
+        SizeStatus::Large
+    } else {
+        SizeStatus::Normal
+    };
+
+    let largest_tables = if status == SizeStatus::Normal {
+        Vec::new()
+    } else {
+        largest_tables(&conn, LARGEST_TABLES_SHOWN)?
+    };
+
+    Ok(SizeReport {
+        size,
+        status,
+        largest_tables,
+    })
+}
+
+/// Warn about a database above its warning threshold, and report one above its hard
+/// limit to [`Output::database_oversized`].
+pub fn present_size_report(report: &SizeReport, output: &dyn Output) {
+    if report.status == SizeStatus::Normal {
+        return;
+    }
+
+    let tables = report
+        .largest_tables
+        .iter()
+        .map(|(table, rows)| format!("{} ({} rows)", table, rows))
+        .collect::<Vec<_>>()
+        .join(", ");
+    output.warning(&format!(
+        "The database is {}{}: largest tables are {}. Run 'am sudo database maintain' to prune its history",
+        format_size(report.size),
+        if report.status == SizeStatus::Oversized {
+            ", above its size limit"
+        } else {
+            ""
+        },
+        if tables.is_empty() { "-" } else { &tables }
+    ));
+
+    if report.status == SizeStatus::Oversized {
+        output.database_oversized();
+    }
+}
+
+fn read_config(conn: &Connection) -> Result<BTreeMap<String, String>> {
+    let mut statement = conn.prepare("SELECT key, value FROM configuration")?;
+    let rows = statement.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+    Ok(rows.collect::<rusqlite::Result<_>>()?)
+}
+
+/// The `count` tables with the most rows, largest first.
+fn largest_tables(conn: &Connection, count: usize) -> Result<Vec<(String, u64)>> {
+    let mut statement = conn.prepare(
+        "SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%'",
+    )?;
+    let names = statement
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let mut tables = Vec::with_capacity(names.len());
+    for name in names {
+        let rows: i64 = conn.query_row(
+            &format!("SELECT COUNT(*) FROM \"{}\"", name.replace('"', "\"\"")),
+            [],
+            |row| row.get(0),
+        )?;
+        tables.push((name, rows as u64));
+    }
+
+    tables.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    tables.truncate(count);
+    Ok(tables)
+}
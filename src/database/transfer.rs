@@ -0,0 +1,425 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Export and import of the project registry as JSON.
+//!
+//! An export holds every row of the [`REGISTRY_TABLES`], along with the schema version of
+//! the database it comes from. Rows are read and written in batches, reporting the
+//! progress of each table and checking for cancellation between batches, so registries
+//! with thousands of rows give feedback and can be interrupted.
+//!
+//! An import doesn't touch the registry until every row is read: rows go to staging
+//! tables first, which replace the content of the registry tables in a single
+//! transaction at the end. A cancelled, failed or interrupted import leaves the registry
+//! as it was, and the staging tables left behind by an interrupted import are dropped by
+//! the next one.
+
+use std::collections::BTreeMap;
+use std::io::{BufReader, Read, Write};
+
+use anyhow::{Context, Result};
+use rusqlite::Connection;
+use rusqlite::types::{Value as SqlValue, ValueRef};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::common::cancellation::CancellationToken;
+use crate::common::errors::{CliError, codes};
+use crate::database::Database;
+use crate::database::entities::{Project, Template};
+use crate::database::migrations::MigrationManager;
+
+/// Version of the export format, bumped on incompatible changes.
+pub const REGISTRY_EXPORT_FORMAT: u32 = 1;
+
+/// Tables holding the registry, in the order they are exported and imported.
+pub const REGISTRY_TABLES: &[&str] = &[Project::TABLE, Template::TABLE];
+
+/// Number of rows read or written between two progress reports.
+pub const TRANSFER_BATCH_SIZE: usize = 500;
+
+/// Prefix of the staging tables an import writes to.
+const STAGING_PREFIX: &str = "_import_";
+
+/// Number of rows transferred, by table.
+pub type TransferCounts = BTreeMap<String, usize>;
+
+/// The content of an export file.
+#[derive(Debug, Deserialize)]
+struct RegistryExport {
+    format: u32,
+    schema_version: u32,
+    tables: BTreeMap<String, Vec<Map<String, Value>>>,
+}
+
+/// Exports or imports the registry of a database.
+///
+/// # Example
+///
+/// ```ignore
+/// let counts = RegistryTransfer::new(&database)
+///     .on_progress(|table, current, total| println!("{}: {}/{}", table, current, total))
+///     .export(&mut file)?;
+/// ```
+pub struct RegistryTransfer<'a> {
+    database: &'a Database,
+    batch_size: usize,
+    progress: Box<dyn Fn(&str, usize, usize) + 'a>,
+    cancellation: CancellationToken,
+}
+
+impl<'a> RegistryTransfer<'a> {
+    /// A transfer of the registry of `database`, in batches of [`TRANSFER_BATCH_SIZE`]
+    /// rows, which can't be cancelled.
+    pub fn new(database: &'a Database) -> Self {
+        Self {
+            database,
+            batch_size: TRANSFER_BATCH_SIZE,
+            progress: Box::new(|_, _, _| {}),
+            cancellation: CancellationToken::new(),
+        }
+    }
+
+    /// Transfer the rows in batches of `batch_size`, at least 1.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Call `progress` with the table, the rows transferred so far and the rows of the
+    /// table, after each batch.
+    pub fn on_progress(mut self, progress: impl Fn(&str, usize, usize) + 'a) -> Self {
+        self.progress = Box::new(progress);
+        self
+    }
+
+    /// Stop between two batches once `cancellation` is cancelled.
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// Write the registry to `writer` as JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ERR_IO_CANCELLED` error if the transfer is cancelled, in which case
+    /// `writer` holds a truncated export, or the underlying database or I/O error.
+    pub fn export(&self, writer: &mut dyn Write) -> Result<TransferCounts> {
+        let schema_version = MigrationManager::new().get_current_version(self.database)?;
+
+        let conn = self.database.get_connection();
+        let conn = conn
+            .lock()
+            .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
+
+        write!(
+            writer,
+            "{{\"format\":{},\"schema_version\":{},\"tables\":{{",
+            REGISTRY_EXPORT_FORMAT, schema_version
+        )?;
+
+        let mut counts = TransferCounts::new();
+        for (index, table) in REGISTRY_TABLES.iter().enumerate() {
+            if index > 0 {
+                writer.write_all(b",")?;
+            }
+            write!(writer, "\n{}:[", Value::from(*table))?;
+            let count = self.export_table(&conn, table, writer)?;
+            writer.write_all(b"\n]")?;
+            counts.insert(table.to_string(), count);
+        }
+
+        writer.write_all(b"}}\n")?;
+        writer.flush()?;
+
+        Ok(counts)
+    }
+
+    fn export_table(
+        &self,
+        conn: &Connection,
+        table: &str,
+        writer: &mut dyn Write,
+    ) -> Result<usize> {
+        let total: usize = conn.query_row(
+            &format!("SELECT COUNT(*) FROM {}", quote(table)),
+            [],
+            |row| row.get(0),
+        )?;
+
+        // The rowid is selected last under its own name, as a column declared
+        // `INTEGER PRIMARY KEY` would otherwise report it under the name of that column.
+        let mut statement = conn.prepare(&format!(
+            "SELECT *, rowid AS \"_transfer_rowid\" FROM {} WHERE rowid > ?1 \
+             ORDER BY rowid LIMIT ?2",
+            quote(table)
+        ))?;
+        let columns: Vec<String> = statement
+            .column_names()
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+        let rowid_index = columns.len() - 1;
+
+        let mut last_rowid = i64::MIN;
+        let mut exported = 0;
+        loop {
+            self.check_cancelled()?;
+
+            let mut rows = statement.query(rusqlite::params![last_rowid, self.batch_size])?;
+            let mut batch = 0;
+            while let Some(row) = rows.next()? {
+                let mut object = Map::new();
+                for (index, column) in columns[..rowid_index].iter().enumerate() {
+                    object.insert(
+                        column.clone(),
+                        json_value(row.get_ref(index)?, table, column)?,
+                    );
+                }
+                last_rowid = row.get(rowid_index)?;
+
+                if exported + batch > 0 {
+                    writer.write_all(b",")?;
+                }
+                write!(writer, "\n{}", Value::Object(object))?;
+                batch += 1;
+            }
+
+            if batch == 0 {
+                break;
+            }
+            exported += batch;
+            (self.progress)(table, exported, total.max(exported));
+        }
+
+        Ok(exported)
+    }
+
+    /// Replace the registry with the one read from `reader`, written by
+    /// [`RegistryTransfer::export`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ERR_VALIDATION_FORMAT` error if `reader` doesn't hold an export of a
+    /// database at the same schema version, an `ERR_IO_CANCELLED` error if the transfer is
+    /// cancelled, or the underlying database error. The registry is unchanged in every
+    /// case.
+    pub fn import(&self, reader: impl Read) -> Result<TransferCounts> {
+        let export: RegistryExport = serde_json::from_reader(BufReader::new(reader))
+            .map_err(|e| invalid_export(format!("It isn't a registry export: {}", e)))?;
+        let schema_version = MigrationManager::new().get_current_version(self.database)?;
+
+        if export.format != REGISTRY_EXPORT_FORMAT {
+            return Err(invalid_export(format!(
+                "It uses version {} of the export format, this CLI reads version {}",
+                export.format, REGISTRY_EXPORT_FORMAT
+            ))
+            .into());
+        }
+        if export.schema_version != schema_version {
+            return Err(invalid_export(format!(
+                "It comes from a database at schema version {}, this one is at version {}",
+                export.schema_version, schema_version
+            ))
+            .with_suggestion(
+                "Import it with the CLI version that exported it, or migrate both databases to the same version with 'am sudo database migrate'",
+            )
+            .into());
+        }
+        if let Some(table) = export
+            .tables
+            .keys()
+            .find(|table| !REGISTRY_TABLES.contains(&table.as_str()))
+        {
+            return Err(invalid_export(format!("It holds the unknown table '{}'", table)).into());
+        }
+
+        let conn = self.database.get_connection();
+        let conn = conn
+            .lock()
+            .map_err(|e| anyhow::anyhow!("Failed to acquire database lock: {}", e))?;
+
+        drop_staging_tables(&conn)?;
+        let result = self.stage_and_swap(&conn, &export);
+        if result.is_err() {
+            drop_staging_tables(&conn)?;
+        }
+        result
+    }
+
+    fn stage_and_swap(&self, conn: &Connection, export: &RegistryExport) -> Result<TransferCounts> {
+        let mut counts = TransferCounts::new();
+        for table in REGISTRY_TABLES {
+            let rows = export
+                .tables
+                .get(*table)
+                .map(Vec::as_slice)
+                .ok_or_else(|| invalid_export(format!("It doesn't hold the table '{}'", table)))?;
+            create_staging_table(conn, table)?;
+            self.stage_rows(conn, table, rows)?;
+            counts.insert(table.to_string(), rows.len());
+        }
+
+        self.check_cancelled()?;
+
+        let transaction = conn.unchecked_transaction()?;
+        for table in REGISTRY_TABLES {
+            transaction.execute_batch(&format!(
+                "DELETE FROM {table}; INSERT INTO {table} SELECT * FROM {staging}; DROP TABLE {staging};",
+                table = quote(table),
+                staging = quote(&staging_name(table)),
+            ))?;
+        }
+        transaction.commit()?;
+
+        Ok(counts)
+    }
+
+    fn stage_rows(
+        &self,
+        conn: &Connection,
+        table: &str,
+        rows: &[Map<String, Value>],
+    ) -> Result<()> {
+        let columns = table_columns(conn, table)?;
+        let staging = quote(&staging_name(table));
+
+        for (batch_index, batch) in rows.chunks(self.batch_size).enumerate() {
+            self.check_cancelled()?;
+
+            let transaction = conn.unchecked_transaction()?;
+            for row in batch {
+                if let Some(column) = row.keys().find(|column| !columns.contains(column)) {
+                    return Err(invalid_export(format!(
+                        "A row of the table '{}' has the unknown column '{}'",
+                        table, column
+                    ))
+                    .into());
+                }
+
+                let names: Vec<String> = row.keys().map(|column| quote(column)).collect();
+                let placeholders = vec!["?"; names.len()].join(", ");
+                let values: Vec<SqlValue> = row.values().map(sql_value).collect();
+                transaction
+                    .prepare_cached(&format!(
+                        "INSERT INTO {} ({}) VALUES ({})",
+                        staging,
+                        names.join(", "),
+                        placeholders
+                    ))?
+                    .execute(rusqlite::params_from_iter(values))
+                    .with_context(|| format!("Failed to import a row of the table '{}'", table))?;
+            }
+            transaction.commit()?;
+
+            let imported = (batch_index * self.batch_size + batch.len()).min(rows.len());
+            (self.progress)(table, imported, rows.len());
+        }
+
+        Ok(())
+    }
+
+    fn check_cancelled(&self) -> Result<(), CliError> {
+        if self.cancellation.is_cancelled() {
+            return Err(CliError::new(
+                codes::ERR_IO_CANCELLED,
+                "Registry transfer cancelled",
+                "The transfer was cancelled before it completed",
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn invalid_export(why: impl Into<String>) -> CliError {
+    CliError::new(codes::ERR_VALIDATION_FORMAT, "Invalid registry export", why)
+}
+
+/// `name` quoted as an SQL identifier.
+fn quote(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+fn staging_name(table: &str) -> String {
+    format!("{}{}", STAGING_PREFIX, table)
+}
+
+fn table_columns(conn: &Connection, table: &str) -> Result<Vec<String>> {
+    let statement = conn.prepare(&format!("SELECT * FROM {} LIMIT 0", quote(table)))?;
+    Ok(statement
+        .column_names()
+        .iter()
+        .map(|name| name.to_string())
+        .collect())
+}
+
+/// Create the staging table of `table`, with the same columns, defaults and constraints.
+fn create_staging_table(conn: &Connection, table: &str) -> Result<()> {
+    let sql: String = conn
+        .query_row(
+            "SELECT sql FROM sqlite_master WHERE type = 'table' AND name = ?1",
+            [table],
+            |row| row.get(0),
+        )
+        .with_context(|| format!("Failed to read the definition of the table '{}'", table))?;
+    let definition = sql
+        .find('(')
+        .map(|start| &sql[start..])
+        .with_context(|| format!("Unexpected definition of the table '{}'", table))?;
+
+    conn.execute_batch(&format!(
+        "CREATE TABLE {} {}",
+        quote(&staging_name(table)),
+        definition
+    ))?;
+    Ok(())
+}
+
+fn drop_staging_tables(conn: &Connection) -> Result<()> {
+    for table in REGISTRY_TABLES {
+        conn.execute_batch(&format!(
+            "DROP TABLE IF EXISTS {}",
+            quote(&staging_name(table))
+        ))?;
+    }
+    Ok(())
+}
+
+fn json_value(value: ValueRef<'_>, table: &str, column: &str) -> Result<Value> {
+    Ok(match value {
+        ValueRef::Null => Value::Null,
+        ValueRef::Integer(i) => Value::from(i),
+        ValueRef::Real(f) => Value::from(f),
+        ValueRef::Text(text) => Value::from(String::from_utf8_lossy(text).into_owned()),
+        ValueRef::Blob(_) => anyhow::bail!(
+            "The column '{}' of the table '{}' holds binary data, which can't be exported",
+            column,
+            table
+        ),
+    })
+}
+
+fn sql_value(value: &Value) -> SqlValue {
+    match value {
+        Value::Null => SqlValue::Null,
+        Value::Bool(b) => SqlValue::Integer(i64::from(*b)),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => SqlValue::Integer(i),
+            None => SqlValue::Real(n.as_f64().unwrap_or_default()),
+        },
+        Value::String(s) => SqlValue::Text(s.clone()),
+        other => SqlValue::Text(other.to_string()),
+    }
+}
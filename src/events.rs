@@ -0,0 +1,109 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Lifecycle events of the CLI, for embedders of the library.
+//!
+//! Commands [emit](emit) a [`CliEvent`] when they start and finish, when a database
+//! migration is applied, when a project is registered and when a project file is written.
+//! The events go to the [`EventSink`] [installed](install) for the process, and are
+//! dropped when none is. The `am` binary installs a [`NoopSink`].
+
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+
+use serde_json::{Value, json};
+
+/// Something that happened while running a command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliEvent {
+    /// A command started, e.g. `am project init`.
+    CommandStarted { command: String },
+    /// A migration of the database schema was applied.
+    MigrationApplied { version: u32, description: String },
+    /// A project was added to the registry.
+    ProjectRegistered { name: String, path: String },
+    /// A project file was written.
+    FileWritten { path: PathBuf },
+    /// A command returned, successfully or not.
+    CommandFinished { command: String, success: bool },
+}
+
+#[allow(dead_code)] // Used by library embedders
+impl CliEvent {
+    /// The name of the event, e.g. `command_started`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            CliEvent::CommandStarted { .. } => "command_started",
+            CliEvent::MigrationApplied { .. } => "migration_applied",
+            CliEvent::ProjectRegistered { .. } => "project_registered",
+            CliEvent::FileWritten { .. } => "file_written",
+            CliEvent::CommandFinished { .. } => "command_finished",
+        }
+    }
+
+    /// The event as a JSON object, with its [name](CliEvent::name) under `event`.
+    pub fn to_json(&self) -> Value {
+        match self {
+            CliEvent::CommandStarted { command } => {
+                json!({ "event": self.name(), "command": command })
+            }
+            CliEvent::MigrationApplied {
+                version,
+                description,
+            } => json!({ "event": self.name(), "version": version, "description": description }),
+            CliEvent::ProjectRegistered { name, path } => {
+                json!({ "event": self.name(), "name": name, "path": path })
+            }
+            CliEvent::FileWritten { path } => {
+                json!({ "event": self.name(), "path": path.display().to_string() })
+            }
+            CliEvent::CommandFinished { command, success } => {
+                json!({ "event": self.name(), "command": command, "success": success })
+            }
+        }
+    }
+}
+
+/// Receives the events of the CLI.
+///
+/// Events may be emitted from several threads at once, and while the database or a
+/// project file is in use: implementations should return quickly and must not call
+/// back into the CLI.
+pub trait EventSink: Send + Sync {
+    /// Handle `event`.
+    fn on_event(&self, event: &CliEvent);
+}
+
+/// Ignores every event.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopSink;
+
+impl EventSink for NoopSink {
+    fn on_event(&self, _event: &CliEvent) {}
+}
+
+static SINK: RwLock<Option<Arc<dyn EventSink>>> = RwLock::new(None);
+
+/// Send the events of this process to `sink`, replacing the sink installed before.
+pub fn install(sink: Arc<dyn EventSink>) {
+    *SINK.write().unwrap_or_else(|e| e.into_inner()) = Some(sink);
+}
+
+/// Send `event` to the installed sink, if any.
+pub fn emit(event: CliEvent) {
+    let sink = SINK.read().unwrap_or_else(|e| e.into_inner()).clone();
+    if let Some(sink) = sink {
+        sink.on_event(&event);
+    }
+}
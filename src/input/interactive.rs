@@ -16,11 +16,26 @@
 //!
 //! This is the default input provider when neither `--json` nor `--non-interactive` is set.
 
-use crate::input::Input;
+use std::fmt;
+
+use crate::input::{Input, SelectOption, TextValidator};
 use anyhow::Result;
-use inquire::validator::Validation;
+use colored::Colorize;
+use inquire::list_option::ListOption;
 use inquire::{Confirm, MultiSelect, Select, Text};
 
+/// A [`SelectOption`] rendered as its label followed by its dimmed description.
+struct RichOption<'a>(&'a SelectOption);
+
+impl fmt::Display for RichOption<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0.description {
+            Some(description) => write!(f, "{}  {}", self.0.label, description.dimmed()),
+            None => write!(f, "{}", self.0.label),
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy)]
 pub struct InteractiveInput;
 
@@ -36,7 +51,7 @@ impl Input for InteractiveInput {
         prompt: &str,
         placeholder: Option<&str>,
         formatter: Option<&dyn Fn(&str) -> String>,
-        validator: Option<&dyn Fn(&str) -> Result<Validation, inquire::CustomUserError>>,
+        validators: &[TextValidator<'_>],
     ) -> Result<String> {
         let mut t = Text::new(prompt);
 
@@ -48,8 +63,10 @@ impl Input for InteractiveInput {
             t = t.with_formatter(f);
         }
 
-        if let Some(v) = validator {
-            t = t.with_validator(v);
+        // inquire runs its validators in the order they were added and reports the
+        // first failure.
+        for v in validators {
+            t = t.with_validator(*v);
         }
 
         Ok(t.prompt()?)
@@ -60,6 +77,13 @@ impl Input for InteractiveInput {
         Ok(s.prompt()?)
     }
 
+    fn select_rich(&self, prompt: &str, options: &[SelectOption]) -> Result<usize> {
+        let formatter = |selected: ListOption<&RichOption>| selected.value.0.label.clone();
+        let s = Select::new(prompt, options.iter().map(RichOption).collect())
+            .with_formatter(&formatter);
+        Ok(s.prompt()?.0.value_index)
+    }
+
     fn confirm(&self, prompt: &str, default: Option<bool>) -> Result<bool> {
         let mut c = Confirm::new(prompt);
 
@@ -74,12 +98,12 @@ impl Input for InteractiveInput {
         &self,
         prompt: &str,
         default: &str,
-        validator: Option<&dyn Fn(&str) -> Result<Validation, inquire::CustomUserError>>,
+        validators: &[TextValidator<'_>],
     ) -> Result<String> {
         let mut t = Text::new(prompt).with_default(default);
 
-        if let Some(v) = validator {
-            t = t.with_validator(v);
+        for v in validators {
+            t = t.with_validator(*v);
         }
 
         Ok(t.prompt()?)
@@ -0,0 +1,274 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Line-based input for terminals that cannot render `inquire` prompts.
+//!
+//! Emacs shells, some CI consoles and `TERM=dumb` terminals don't understand the cursor
+//! movements of `inquire`, which then draws garbage or fails. [`LineInput`] asks its
+//! questions as plain lines instead: text prompts read a line, selections print a
+//! numbered list and read the number of the choice, and confirmations read `y` or `n`.
+//! Invalid answers are reported and the question is asked again, with the same validators
+//! as the `inquire` prompts.
+//!
+//! [`TerminalInfo::supports_prompt_ui`] decides which of the two is used in interactive
+//! mode (see [`create_input`](crate::input::create_input)).
+
+use std::io::{self, BufRead, BufReader, IsTerminal, Stderr, Stdin, Write};
+use std::sync::Mutex;
+
+use crate::input::{Input, SelectOption, TextValidator, run_validators, validation_message};
+use anyhow::{Result, anyhow};
+use inquire::validator::Validation;
+
+/// What is known of the terminal the CLI runs in.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TerminalInfo {
+    /// The value of `TERM`, if set.
+    pub term: Option<String>,
+    /// Whether the CLI runs inside an Emacs shell (`INSIDE_EMACS` is set).
+    pub inside_emacs: bool,
+    /// Whether stdin is a terminal.
+    pub stdin_tty: bool,
+    /// Whether stdout is a terminal.
+    pub stdout_tty: bool,
+    /// Whether stderr, where prompts are drawn, is a terminal.
+    pub stderr_tty: bool,
+}
+
+impl TerminalInfo {
+    /// Inspect the environment and the standard streams of this process.
+    pub fn detect() -> Self {
+        Self {
+            term: std::env::var("TERM").ok(),
+            inside_emacs: std::env::var_os("INSIDE_EMACS").is_some(),
+            stdin_tty: io::stdin().is_terminal(),
+            stdout_tty: io::stdout().is_terminal(),
+            stderr_tty: io::stderr().is_terminal(),
+        }
+    }
+
+    /// Whether `inquire` prompts can be drawn.
+    ///
+    /// They can't with `TERM=dumb`, without `TERM` (no terminfo entry to draw with, except
+    /// on Windows where the console doesn't use it), inside Emacs, or when only some of the
+    /// standard streams are terminals (e.g. `am project init | tee init.log`).
+    pub fn supports_prompt_ui(&self) -> bool {
+        let term_supported = match self.term.as_deref() {
+            Some("dumb") | Some("") => false,
+            Some(_) => true,
+            None => cfg!(windows),
+        };
+
+        term_supported
+            && !self.inside_emacs
+            && self.stdin_tty == self.stdout_tty
+            && self.stdin_tty == self.stderr_tty
+    }
+}
+
+/// An [`Input`] asking its questions as plain lines, read from `R` and written to `W`.
+pub struct LineInput<R, W> {
+    io: Mutex<(R, W)>,
+}
+
+impl LineInput<BufReader<Stdin>, Stderr> {
+    /// Read answers from stdin and write the questions to stderr, like `inquire`.
+    pub fn stdio() -> Self {
+        Self::new(BufReader::new(io::stdin()), io::stderr())
+    }
+}
+
+impl<R: BufRead + Send, W: Write + Send> LineInput<R, W> {
+    /// Read answers from `reader` and write the questions to `writer`.
+    pub fn new(reader: R, writer: W) -> Self {
+        Self {
+            io: Mutex::new((reader, writer)),
+        }
+    }
+
+    /// Give back the reader and the writer.
+    pub fn into_inner(self) -> (R, W) {
+        self.io.into_inner().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Write `lines`, then `question` without line break, and read the answer without
+    /// its line break.
+    fn ask(&self, lines: &[String], question: &str, prompt: &str) -> Result<String> {
+        let mut io = self.io.lock().unwrap_or_else(|e| e.into_inner());
+        let (reader, writer) = &mut *io;
+
+        for line in lines {
+            writeln!(writer, "{}", line)?;
+        }
+        write!(writer, "{} ", question)?;
+        writer.flush()?;
+
+        let mut answer = String::new();
+        if reader.read_line(&mut answer)? == 0 {
+            return Err(anyhow!(
+                "Input ended before prompt '{}' was answered",
+                prompt
+            ));
+        }
+
+        Ok(answer.trim_end_matches(['\r', '\n']).to_string())
+    }
+
+    /// Report an invalid answer before the question is asked again.
+    fn reject(&self, message: &str) -> Result<()> {
+        let mut io = self.io.lock().unwrap_or_else(|e| e.into_inner());
+        writeln!(io.1, "{}", message)?;
+        Ok(())
+    }
+
+    /// Ask for text until an answer passes `validators`; an empty answer is `default`
+    /// when there is one.
+    fn ask_text(
+        &self,
+        question: &str,
+        prompt: &str,
+        default: Option<&str>,
+        validators: &[TextValidator<'_>],
+    ) -> Result<String> {
+        loop {
+            let mut answer = self.ask(&[], question, prompt)?;
+            if answer.is_empty()
+                && let Some(default) = default
+            {
+                answer = default.to_string();
+            }
+
+            match run_validators(&answer, validators).map_err(|e| anyhow!(e.to_string()))? {
+                Validation::Valid => return Ok(answer),
+                Validation::Invalid(reason) => self.reject(validation_message(&reason))?,
+            }
+        }
+    }
+
+    /// Print `labels` as a numbered list and ask for the numbers of the chosen ones, until
+    /// every number is in the list and, when `single`, exactly one is given.
+    fn ask_numbers(&self, prompt: &str, labels: &[String], single: bool) -> Result<Vec<usize>> {
+        if labels.is_empty() {
+            return Err(anyhow!("No options to choose from for prompt '{}'", prompt));
+        }
+
+        let mut lines = vec![prompt.to_string()];
+        lines.extend(
+            labels
+                .iter()
+                .enumerate()
+                .map(|(i, label)| format!("  {}) {}", i + 1, label)),
+        );
+        let question = if single {
+            format!("Enter a number (1-{}):", labels.len())
+        } else {
+            format!(
+                "Enter numbers separated by spaces or commas (1-{}, empty for none):",
+                labels.len()
+            )
+        };
+
+        loop {
+            let answer = self.ask(&lines, &question, prompt)?;
+            let numbers: Option<Vec<usize>> = answer
+                .split([' ', ','])
+                .filter(|part| !part.is_empty())
+                .map(|part| {
+                    part.parse::<usize>()
+                        .ok()
+                        .filter(|n| (1..=labels.len()).contains(n))
+                        .map(|n| n - 1)
+                })
+                .collect();
+
+            match numbers {
+                Some(numbers) if !single || numbers.len() == 1 => return Ok(numbers),
+                _ => self.reject(&format!(
+                    "'{}' is not a valid choice, enter {} between 1 and {}",
+                    answer,
+                    if single { "a number" } else { "numbers" },
+                    labels.len()
+                ))?,
+            }
+        }
+    }
+}
+
+impl<R: BufRead + Send, W: Write + Send> Input for LineInput<R, W> {
+    fn prompt_text(
+        &self,
+        prompt: &str,
+        placeholder: Option<&str>,
+        _formatter: Option<&dyn Fn(&str) -> String>,
+        validators: &[TextValidator<'_>],
+    ) -> Result<String> {
+        let question = match placeholder {
+            Some(placeholder) => format!("{} (e.g. {})", prompt, placeholder),
+            None => prompt.to_string(),
+        };
+        self.ask_text(&question, prompt, None, validators)
+    }
+
+    fn select(&self, prompt: &str, options: &[String]) -> Result<String> {
+        let index = self.ask_numbers(prompt, options, true)?[0];
+        Ok(options[index].clone())
+    }
+
+    fn select_rich(&self, prompt: &str, options: &[SelectOption]) -> Result<usize> {
+        let labels: Vec<String> = options
+            .iter()
+            .map(|option| match &option.description {
+                Some(description) => format!("{}  {}", option.label, description),
+                None => option.label.clone(),
+            })
+            .collect();
+        let index = self.ask_numbers(prompt, &labels, true)?[0];
+        Ok(options[index].value_index)
+    }
+
+    fn confirm(&self, prompt: &str, default: Option<bool>) -> Result<bool> {
+        let question = match default {
+            Some(true) => format!("{} [Y/n]", prompt),
+            Some(false) => format!("{} [y/N]", prompt),
+            None => format!("{} [y/n]", prompt),
+        };
+
+        loop {
+            let answer = self.ask(&[], &question, prompt)?;
+            match (answer.trim().to_lowercase().as_str(), default) {
+                ("y" | "yes", _) => return Ok(true),
+                ("n" | "no", _) => return Ok(false),
+                ("", Some(default)) => return Ok(default),
+                _ => self.reject("Answer 'y' or 'n'")?,
+            }
+        }
+    }
+
+    fn prompt_text_with_default(
+        &self,
+        prompt: &str,
+        default: &str,
+        validators: &[TextValidator<'_>],
+    ) -> Result<String> {
+        let question = format!("{} [{}]", prompt, default);
+        self.ask_text(&question, prompt, Some(default), validators)
+    }
+
+    fn multi_select(&self, prompt: &str, options: &[String]) -> Result<Vec<String>> {
+        let mut indices = self.ask_numbers(prompt, options, false)?;
+        indices.sort_unstable();
+        indices.dedup();
+        Ok(indices.into_iter().map(|i| options[i].clone()).collect())
+    }
+}
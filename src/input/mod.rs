@@ -20,18 +20,220 @@
 //! Design goals:
 //! - Commands call `&dyn Input` for all user input.
 //! - `InteractiveInput` wraps `inquire` and supports validators/formatters/placeholders.
+//! - `LineInput` asks the same questions as plain lines, for terminals that cannot render
+//!   `inquire` prompts (`TERM=dumb`, Emacs shells, some CI consoles).
 //! - `NonInteractiveInput` always fails with a helpful error suggesting CLI args.
+//! - Prompt sites wrap their input with `with_flag_hint` so that this error names the exact
+//!   flag answering the prompt.
 //! - `--json` implies non-interactive input (handled by mode selection in main).
 
 mod interactive;
+mod line;
 mod non_interactive;
 
 pub use interactive::InteractiveInput;
+pub use line::{LineInput, TerminalInfo};
 pub use non_interactive::NonInteractiveInput;
 
 use anyhow::Result;
-use inquire::validator::Validation;
-use std::fmt::Display;
+use inquire::CustomUserError;
+use inquire::validator::{ErrorMessage, Validation};
+use log::debug;
+use std::fmt::{self, Display};
+use std::io::Read;
+
+/// A check of the text entered at a prompt: `Valid`, or `Invalid` with the message shown
+/// to the user.
+///
+/// A plain function or closure reference, to match the generic bounds of
+/// `inquire::Text::with_validator` (trait objects of `StringValidator` do not work there).
+pub type TextValidator<'a> = &'a dyn Fn(&str) -> Result<Validation, CustomUserError>;
+
+/// Run `validators` on `value` in order, stopping at the first one that rejects it.
+///
+/// This is how every [`Input`] applies the validators of a prompt.
+pub fn run_validators(
+    value: &str,
+    validators: &[TextValidator<'_>],
+) -> Result<Validation, CustomUserError> {
+    for validator in validators {
+        if let Validation::Invalid(reason) = validator(value)? {
+            return Ok(Validation::Invalid(reason));
+        }
+    }
+
+    Ok(Validation::Valid)
+}
+
+/// The message of a failed validation, as shown to the user.
+pub fn validation_message(reason: &ErrorMessage) -> &str {
+    match reason {
+        ErrorMessage::Custom(message) => message.as_str(),
+        ErrorMessage::Default => "Invalid value",
+    }
+}
+
+/// Turn the result of a check returning its failure message, like
+/// [`validate_name`](crate::common::utils::validate_name), into a [`Validation`].
+pub fn to_validation(
+    result: std::result::Result<(), String>,
+) -> Result<Validation, CustomUserError> {
+    Ok(match result {
+        Ok(()) => Validation::Valid,
+        Err(message) => Validation::Invalid(message.into()),
+    })
+}
+
+/// An option offered by [`Input::select_rich`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectOption {
+    /// Label identifying the option.
+    pub label: String,
+    /// Context shown with the label (e.g. a path or a date).
+    pub description: Option<String>,
+    /// Index of the option in the caller's own list, returned when it is selected.
+    pub value_index: usize,
+}
+
+impl SelectOption {
+    /// Create an option without description.
+    pub fn new(label: impl Into<String>, value_index: usize) -> Self {
+        Self {
+            label: label.into(),
+            description: None,
+            value_index,
+        }
+    }
+
+    /// Set the description shown with the label.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// The error of a prompt that cannot be shown because input is non-interactive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PromptBlocked {
+    /// What was asked: "prompt", "selection", "confirmation" or "multi-select".
+    pub kind: &'static str,
+    /// The prompt message.
+    pub prompt: String,
+    /// The command-line argument answering the prompt (e.g. `--template <name>`).
+    pub flag_hint: Option<String>,
+}
+
+impl PromptBlocked {
+    /// Create the error of a prompt without flag hint.
+    pub fn new(kind: &'static str, prompt: impl Into<String>) -> Self {
+        Self {
+            kind,
+            prompt: prompt.into(),
+            flag_hint: None,
+        }
+    }
+}
+
+impl fmt::Display for PromptBlocked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Interactive {} '{}' blocked: non-interactive mode is active. ",
+            self.kind, self.prompt
+        )?;
+        match &self.flag_hint {
+            Some(flag) => write!(f, "Pass {} to avoid this prompt.", flag),
+            None => write!(
+                f,
+                "Provide the required value via command-line arguments instead. \
+                 Use --help on the command to see available flags."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PromptBlocked {}
+
+/// An [`Input`] whose prompts can be answered by a command-line flag instead, created by
+/// [`with_flag_hint`].
+///
+/// Prompts are delegated to the wrapped input; when one is blocked, the [`PromptBlocked`]
+/// error gets the flag as hint, unless a hint was already given closer to the prompt.
+pub struct FlagHinted<'a> {
+    input: &'a dyn Input,
+    flag: &'a str,
+}
+
+/// Wrap `input` for a prompt site answered on the command line by `flag` (e.g.
+/// `"--template <name>"` or `"--yes"`).
+pub fn with_flag_hint<'a>(input: &'a dyn Input, flag: &'a str) -> FlagHinted<'a> {
+    FlagHinted { input, flag }
+}
+
+impl FlagHinted<'_> {
+    fn hint<T>(&self, result: Result<T>) -> Result<T> {
+        result.map_err(|e| match e.downcast::<PromptBlocked>() {
+            Ok(mut blocked) => {
+                blocked
+                    .flag_hint
+                    .get_or_insert_with(|| self.flag.to_string());
+                blocked.into()
+            }
+            Err(e) => e,
+        })
+    }
+}
+
+impl Input for FlagHinted<'_> {
+    fn prompt_text(
+        &self,
+        prompt: &str,
+        placeholder: Option<&str>,
+        formatter: Option<&dyn Fn(&str) -> String>,
+        validators: &[TextValidator<'_>],
+    ) -> Result<String> {
+        self.hint(
+            self.input
+                .prompt_text(prompt, placeholder, formatter, validators),
+        )
+    }
+
+    fn select(&self, prompt: &str, options: &[String]) -> Result<String> {
+        self.hint(self.input.select(prompt, options))
+    }
+
+    fn select_rich(&self, prompt: &str, options: &[SelectOption]) -> Result<usize> {
+        self.hint(self.input.select_rich(prompt, options))
+    }
+
+    fn confirm(&self, prompt: &str, default: Option<bool>) -> Result<bool> {
+        self.hint(self.input.confirm(prompt, default))
+    }
+
+    fn prompt_text_with_default(
+        &self,
+        prompt: &str,
+        default: &str,
+        validators: &[TextValidator<'_>],
+    ) -> Result<String> {
+        self.hint(
+            self.input
+                .prompt_text_with_default(prompt, default, validators),
+        )
+    }
+
+    fn multi_select(&self, prompt: &str, options: &[String]) -> Result<Vec<String>> {
+        self.hint(self.input.multi_select(prompt, options))
+    }
+
+    fn is_interactive(&self) -> bool {
+        self.input.is_interactive()
+    }
+
+    fn read_stdin(&self) -> Result<String> {
+        self.input.read_stdin()
+    }
+}
 
 /// Input mode for CLI prompting.
 ///
@@ -57,20 +259,18 @@ pub trait Input: Send + Sync {
     /// This should support the core `inquire::Text` capabilities:
     /// - placeholder
     /// - formatter
-    /// - validator
+    /// - validators
     ///
     /// Notes:
-    /// - Validators are optional; pass `None` to skip validation.
+    /// - `validators` run in order and the first failure is reported; pass `&[]` to skip
+    ///   validation.
     /// - `formatter` receives the current input and should return the display string.
-    ///
-    /// `validator` is a function pointer/closure to match `inquire::Text::with_validator`'s
-    /// generic bounds (trait objects do not work for `StringValidator` here).
     fn prompt_text(
         &self,
         prompt: &str,
         placeholder: Option<&str>,
         formatter: Option<&dyn Fn(&str) -> String>,
-        validator: Option<&dyn Fn(&str) -> Result<Validation, inquire::CustomUserError>>,
+        validators: &[TextValidator<'_>],
     ) -> Result<String>;
 
     /// Prompt the user to select one option from a list.
@@ -78,30 +278,72 @@ pub trait Input: Send + Sync {
     /// `options` is a slice of owned option labels. The return value is the selected label.
     fn select(&self, prompt: &str, options: &[String]) -> Result<String>;
 
+    /// Prompt the user to select one option from a list, showing each option's description
+    /// along with its label.
+    ///
+    /// Returns the `value_index` of the selected option. The default implementation
+    /// delegates to `select` with the option labels.
+    fn select_rich(&self, prompt: &str, options: &[SelectOption]) -> Result<usize> {
+        let labels: Vec<String> = options.iter().map(|o| o.label.clone()).collect();
+        let selected = self.select(prompt, &labels)?;
+
+        options
+            .iter()
+            .find(|o| o.label == selected)
+            .map(|o| o.value_index)
+            .ok_or_else(|| anyhow::anyhow!("Selection '{}' not found in options list", selected))
+    }
+
     /// Prompt the user for confirmation (yes/no).
     fn confirm(&self, prompt: &str, default: Option<bool>) -> Result<bool>;
 
     /// Prompt the user for text input with a default value pre-filled.
     ///
     /// The default value is editable by the user. If they press Enter without
-    /// changes, the default is used.
+    /// changes, the default is used. `validators` are applied as by `prompt_text`.
     fn prompt_text_with_default(
         &self,
         prompt: &str,
         default: &str,
-        validator: Option<&dyn Fn(&str) -> Result<Validation, inquire::CustomUserError>>,
+        validators: &[TextValidator<'_>],
     ) -> Result<String>;
 
     /// Prompt the user to select multiple items from a list.
     ///
     /// Returns the selected items' labels.
     fn multi_select(&self, prompt: &str, options: &[String]) -> Result<Vec<String>>;
+
+    /// Whether prompts can be answered.
+    ///
+    /// Commands can check it to report what they would have asked, instead of failing on
+    /// the first prompt.
+    fn is_interactive(&self) -> bool {
+        true
+    }
+
+    /// Read everything piped to the standard input, for commands run with `--stdin`.
+    ///
+    /// Unlike prompts, this works in non-interactive mode.
+    fn read_stdin(&self) -> Result<String> {
+        let mut content = String::new();
+        std::io::stdin().read_to_string(&mut content)?;
+        Ok(content)
+    }
 }
 
 /// Create an `Input` implementation based on `InputMode`.
+///
+/// In interactive mode, terminals that cannot render `inquire` prompts get a [`LineInput`]
+/// instead (see [`TerminalInfo::supports_prompt_ui`]).
 pub fn create_input(mode: InputMode) -> Box<dyn Input> {
     match mode {
-        InputMode::Interactive => Box::new(InteractiveInput::new()),
+        InputMode::Interactive if TerminalInfo::detect().supports_prompt_ui() => {
+            Box::new(InteractiveInput::new())
+        }
+        InputMode::Interactive => {
+            debug!("The terminal cannot render prompts — falling back to line-based input.");
+            Box::new(LineInput::stdio())
+        }
         InputMode::NonInteractive => Box::new(NonInteractiveInput::new()),
     }
 }
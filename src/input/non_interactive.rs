@@ -18,10 +18,14 @@
 //! e.g. when `--non-interactive` is provided or when `--json` output mode
 //! is active (JSON implies non-interactive).
 //!
-//! All input methods fail with a clear error message suggesting the user
-//! provide the required value via command-line arguments.
+//! All input methods fail with a [`PromptBlocked`] error suggesting the user provide the
+//! required value via command-line arguments (naming the flag when the prompt site is
+//! wrapped with [`with_flag_hint`](crate::input::with_flag_hint)), except text prompts with a
+//! default value, which answer with that default once it passes the prompt's validators.
 
-use crate::input::Input;
+use crate::input::{
+    Input, PromptBlocked, SelectOption, TextValidator, run_validators, validation_message,
+};
 use anyhow::Result;
 use inquire::validator::Validation;
 
@@ -33,14 +37,8 @@ impl NonInteractiveInput {
         Self
     }
 
-    fn blocked(&self, kind: &str, prompt: &str) -> anyhow::Error {
-        anyhow::anyhow!(
-            "Interactive {} '{}' blocked: non-interactive mode is active. \
-             Provide the required value via command-line flags instead. \
-             Use --help on the command to see available flags.",
-            kind,
-            prompt
-        )
+    fn blocked(&self, kind: &'static str, prompt: &str) -> anyhow::Error {
+        PromptBlocked::new(kind, prompt).into()
     }
 }
 
@@ -50,7 +48,7 @@ impl Input for NonInteractiveInput {
         prompt: &str,
         _placeholder: Option<&str>,
         _formatter: Option<&dyn Fn(&str) -> String>,
-        _validator: Option<&dyn Fn(&str) -> Result<Validation, inquire::CustomUserError>>,
+        _validators: &[TextValidator<'_>],
     ) -> Result<String> {
         Err(self.blocked("prompt", prompt))
     }
@@ -59,6 +57,10 @@ impl Input for NonInteractiveInput {
         Err(self.blocked("selection", prompt))
     }
 
+    fn select_rich(&self, prompt: &str, _options: &[SelectOption]) -> Result<usize> {
+        Err(self.blocked("selection", prompt))
+    }
+
     fn confirm(&self, prompt: &str, _default: Option<bool>) -> Result<bool> {
         Err(self.blocked("confirmation", prompt))
     }
@@ -66,13 +68,26 @@ impl Input for NonInteractiveInput {
     fn prompt_text_with_default(
         &self,
         prompt: &str,
-        _default: &str,
-        _validator: Option<&dyn Fn(&str) -> Result<Validation, inquire::CustomUserError>>,
+        default: &str,
+        validators: &[TextValidator<'_>],
     ) -> Result<String> {
-        Err(self.blocked("prompt", prompt))
+        match run_validators(default, validators).map_err(|e| anyhow::anyhow!(e.to_string()))? {
+            Validation::Valid => Ok(default.to_string()),
+            Validation::Invalid(reason) => Err(anyhow::anyhow!(
+                "The default value '{}' of prompt '{}' is invalid: {}. \
+                 Provide the value via command-line arguments instead.",
+                default,
+                prompt,
+                validation_message(&reason)
+            )),
+        }
     }
 
     fn multi_select(&self, prompt: &str, _options: &[String]) -> Result<Vec<String>> {
         Err(self.blocked("multi-select", prompt))
     }
+
+    fn is_interactive(&self) -> bool {
+        false
+    }
 }
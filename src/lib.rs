@@ -22,15 +22,25 @@ pub mod common;
 pub mod compiler;
 pub mod config;
 pub mod database;
+pub mod events;
 pub mod input;
 pub mod presentation;
 pub mod schema;
 
+#[cfg(feature = "test-util")]
+pub mod testing;
+
 // Re-export commands for testing
 pub mod commands {
     pub mod asset;
+    pub mod dispatch;
+    pub mod examples;
+    pub mod explain;
+    pub mod introspect;
     pub mod project;
     pub mod sdk;
+    pub mod setup;
+    pub mod stats;
     pub mod sudo;
     pub mod template;
 }
@@ -19,6 +19,7 @@ mod common;
 mod compiler;
 mod config;
 mod database;
+mod events;
 mod input;
 mod presentation;
 mod schema;
@@ -26,21 +27,40 @@ mod schema;
 use crate::{
     app::{App, Commands},
     commands::{
-        asset::handler as handle_asset_command, project::handler as handle_project_command,
-        sdk::handler as handle_sdk_command, sudo::handler as handle_sudo_command,
-        template::handler as handle_template_command,
+        dispatch::CommandHandler,
+        setup::{apply_settings, needs_setup, offer_first_run_setup},
+        sudo::{DatabaseCommands, SudoCommands},
     },
-    common::errors::{CliError, determine_exit_code, exit_codes},
+    common::errors::{CliError, codes, determine_exit_code, exit_codes},
+    common::files::ensure_writable,
+    common::ignore::{DEFAULT_MAX_WALK_DEPTH, WalkOptions},
+    common::lock::{DEFAULT_LOCK_TIMEOUT, ProjectLock},
     common::logger::{init_logger, setup_crash_logging, write_crash_log_on_error},
-    database::{Database, setup_crash_db_cleanup},
+    common::paths,
+    common::project_option::{project_dir, resolve_project_option},
+    common::settings::{self, Settings},
+    common::telemetry::{self, command_path, command_span},
+    common::update::{HttpVersionFetcher, check_for_update},
+    common::utils::read_amproject_file,
+    database::{
+        Database, db_get_project_by_path,
+        history::{self, CommandRecord},
+        retention, setup_crash_db_cleanup,
+        size::present_size_report,
+    },
+    events::{CliEvent, NoopSink},
     input::{Input, InputMode, create_input},
-    presentation::{Output, OutputMode, create_output},
+    presentation::{JsonOutput, Output, OutputMode, create_output},
+};
+use log::{debug, warn};
+use std::{
+    panic,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Instant,
 };
-use clap::{CommandFactory, Parser};
-use clap_complete::generate;
-use log::{debug, error};
-use std::{panic, sync::Arc};
 use tokio::signal;
+use tracing::Instrument;
 
 fn main() {
     // We manually create a runtime to be able to use `catch_unwind` on the async logic.
@@ -55,7 +75,7 @@ fn main() {
         Ok(Err(e)) => {
             // The program returned a normal error, determine exit code from it.
             // We need to re-parse CLI args to get the output mode.
-            let cli = App::parse();
+            let cli = App::parse_with_examples();
             let output_mode = if cli.json {
                 OutputMode::Json
             } else {
@@ -65,18 +85,22 @@ fn main() {
             let error_code = e.downcast_ref::<CliError>().map(|ce| ce.code).unwrap_or(-1);
             let exit_code = determine_exit_code(&e);
 
-            output.error(&e, error_code, None);
+            // A failure the command already presented only sets the exit code.
+            let reported = e.downcast_ref::<CliError>().is_some_and(|ce| ce.reported);
+            if !reported {
+                output.error(&e, error_code, None);
 
-            if output_mode == OutputMode::Interactive {
-                if let Some(log_path) = write_crash_log_on_error() {
-                    eprintln!("Error log written to: {}", log_path.display());
+                if output_mode == OutputMode::Interactive {
+                    if let Some(log_path) = write_crash_log_on_error() {
+                        eprintln!("Error log written to: {}", log_path.display());
+                    }
                 }
             }
             exit_code
         }
         // The program panicked
         Err(panic_payload) => {
-            let cli = App::parse();
+            let cli = App::parse_with_examples();
             let output_mode = if cli.json {
                 OutputMode::Json
             } else {
@@ -111,7 +135,7 @@ fn main() {
 
 async fn async_main() -> anyhow::Result<()> {
     // Parse CLI arguments first to get verbose flag
-    let cli = App::parse();
+    let cli = App::parse_with_examples();
 
     // Initialize logging system
     if let Err(e) = init_logger(cli.verbose, cli.quiet) {
@@ -122,21 +146,108 @@ async fn async_main() -> anyhow::Result<()> {
     // Setup crash logging
     setup_crash_logging();
 
-    // Initialize the database
-    let database = match database::initialize().await {
-        Ok(db) => {
-            debug!("Successfully initialized database");
-            Some(Arc::<Database>::new(db))
-        }
-        Err(e) => {
-            error!("Failed to initialize database: {}", e);
-            error!("  The application will continue but some features may not work properly.");
-            None
+    // Export trace spans if requested, the guard flushes them once the command is done.
+    let _telemetry = telemetry::init(cli.trace)?;
+
+    // Library embedders install their own sink, the binary has no use for the events.
+    events::install(Arc::new(NoopSink));
+    let command = command_path(std::env::args());
+    events::emit(CliEvent::CommandStarted {
+        command: command.clone(),
+    });
+
+    let result = run_cli(cli)
+        .instrument(command_span(std::env::args()))
+        .await;
+
+    events::emit(CliEvent::CommandFinished {
+        command,
+        success: result.is_ok(),
+    });
+    result
+}
+
+/// Run the command parsed into `cli`, once logging is set up.
+async fn run_cli(cli: App) -> anyhow::Result<()> {
+    // With --no-db the database isn't opened at all, and only commands working on the
+    // filesystem run.
+    if cli.no_db && cli.command.requires_database() {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            "This command can't run with --no-db",
+            "It reads or writes the project registry, which is stored in the database",
+        )
+        .with_suggestion("Run the command again without --no-db")
+        .into());
+    }
+
+    // --project stands for the project of the current directory, so it needs a command
+    // working on one, which doesn't name it otherwise.
+    if cli.project.is_some() && !cli.command.is_project_scoped() {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            "This command doesn't take --project",
+            "It doesn't work on the project of the current directory",
+        )
+        .with_suggestion("Run the command again without --project")
+        .into());
+    }
+    if cli.project.is_some() && cli.command.names_project() {
+        return Err(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            "The project is given twice",
+            "--project can't be combined with a project name or --path",
+        )
+        .with_suggestion("Give the project either with --project or as an argument")
+        .into());
+    }
+
+    // The database lives in the home directory: without one, fail once and before
+    // anything else rather than with whichever lookup of the home directory comes first.
+    if !cli.no_db && cli.command.requires_database() {
+        paths::resolve()?;
+    }
+
+    // Initialize the database. `am sudo database migrate` chooses which migrations to
+    // apply, so it gets the database as-is instead of fully migrated, and
+    // `am sudo migrate-home` moves the database file, so it runs without it.
+    let mut database_error = None;
+    let database = if cli.no_db || is_migrate_home_command(&cli.command) {
+        None
+    } else {
+        let initialized = if is_migrate_command(&cli.command) {
+            database::open()
+        } else {
+            database::initialize().await
+        };
+        match initialized {
+            Ok(db) => {
+                debug!("Successfully initialized database");
+                Some(Arc::<Database>::new(db))
+            }
+            // Fall back to running without the database, like --no-db would. The warning
+            // is reported once the output exists.
+            Err(e) => {
+                debug!("Failed to initialize database: {}", e);
+                database_error = Some(e);
+                None
+            }
         }
     };
 
-    setup_crash_db_cleanup(database.clone());
-    let db_for_handler = database.clone();
+    if cli.verbose {
+        match database::db_check_schema_drift(database.clone()) {
+            Ok(drift) => {
+                for message in drift {
+                    warn!("Database schema drift: {}", message);
+                }
+            }
+            Err(e) => debug!("Skipping database schema drift check: {}", e),
+        }
+    }
+
+    setup_crash_db_cleanup(database.as_ref());
+    let db_for_handler = database.as_ref().map(Arc::downgrade);
 
     // Create output handler based on --json flag
     let output_mode = if cli.json {
@@ -144,20 +255,34 @@ async fn async_main() -> anyhow::Result<()> {
     } else {
         OutputMode::Interactive
     };
-    let output: Arc<dyn Output> = Arc::from(create_output(output_mode));
-    let output_for_handler = output.clone();
+    let output: Arc<dyn Output> = match &cli.query {
+        Some(query) => Arc::new(JsonOutput::with_query(query)?),
+        None => Arc::from(create_output(output_mode)),
+    };
+
+    if let Some(e) = database_error {
+        output.warning(&format!(
+            "Failed to open the database ({}), continuing without it: features using the project registry are unavailable",
+            e
+        ));
+    }
+    if let Some(report) = database.as_ref().and_then(|db| db.size_report()) {
+        present_size_report(report, output.as_ref());
+    }
+    if let Some(backup) = database.as_ref().and_then(|db| db.migration_backup()) {
+        output.progress(&format!(
+            "Migrated the database to the latest schema, after backing it up to {}",
+            backup.display()
+        ));
+    }
 
     // Set up signal handlers for graceful shutdown
     tokio::spawn(async move {
         let _ = signal::ctrl_c().await;
         debug!("\nReceived shutdown signal, cleaning up...");
 
-        if let Some(db) = db_for_handler {
-            if let Ok(db) = Arc::try_unwrap(db) {
-                database::cleanup(Some(db));
-            } else {
-                output_for_handler.warning("Database connections still active, forcing shutdown");
-            }
+        if let Some(db) = &db_for_handler {
+            database::emergency_cleanup(db);
         }
 
         std::process::exit(0);
@@ -178,12 +303,68 @@ async fn async_main() -> anyhow::Result<()> {
     };
     let input = create_input(input_mode);
 
-    let result = run_command(&cli, database.clone(), input.as_ref(), output.as_ref()).await;
+    if output_mode == OutputMode::Interactive
+        && input_mode == InputMode::Interactive
+        && offers_setup(&cli.command)
+        && needs_setup(database.clone()).unwrap_or(false)
+    {
+        offer_first_run_setup(database.clone(), input.as_ref(), output.as_ref()).await?;
+    }
+    let settings = Settings::load(&cli.setting_flags(), database.clone());
+    apply_settings(&settings);
+    settings::install(settings.clone());
+
+    let started = Instant::now();
+    // Registered names need the database, so --project is resolved once it's open
+    let (project, result) = match resolve_project(&cli, database.clone()) {
+        Ok(project) => {
+            let result = run_command(
+                &cli,
+                project.as_deref(),
+                database.clone(),
+                input.as_ref(),
+                output.as_ref(),
+            )
+            .await;
+            (project, result)
+        }
+        Err(e) => (None, Err(e)),
+    };
+    let result = result.and_then(|()| output.finish());
+
+    if result.is_ok()
+        && output_mode == OutputMode::Interactive
+        && input_mode == InputMode::Interactive
+        && settings.auto_update()
+    {
+        notify_new_version(database.clone(), output.as_ref()).await;
+    }
+
+    if settings.history_enabled()
+        && !is_migrate_command(&cli.command)
+        && let Some(db) = &database
+    {
+        record_history(db, project.as_deref(), result.is_ok(), started);
+    }
+
+    // Keep the history tables within their retention limits, now and then. This runs
+    // after the command so its output isn't delayed, and never fails it.
+    if result.is_ok()
+        && !is_migrate_command(&cli.command)
+        && let Some(db) = &database
+    {
+        match retention::maybe_run_maintenance(db) {
+            Ok(Some(pruned)) => debug!("Database maintenance pruned {:?}", pruned),
+            Ok(None) => {}
+            Err(e) => debug!("Skipping database maintenance: {}", e),
+        }
+    }
 
     // Clean up database on normal exit
     if let Some(db) = database {
-        if let Ok(db) = Arc::try_unwrap(db) {
-            database::cleanup(Some(db));
+        match Arc::try_unwrap(db) {
+            Ok(db) => database::cleanup(Some(db)),
+            Err(_) => debug!("Database connections still active, skipping the checkpoint"),
         }
     }
 
@@ -191,26 +372,176 @@ async fn async_main() -> anyhow::Result<()> {
     result
 }
 
+/// Add the command to the local [history](history), with the registered project it ran
+/// in (the current directory, or `--project`). Like the maintenance, this never fails
+/// the command.
+fn record_history(
+    database: &Arc<Database>,
+    project: Option<&Path>,
+    success: bool,
+    started: Instant,
+) {
+    let command = command_path(std::env::args())
+        .split_whitespace()
+        .skip(1)
+        .collect::<Vec<_>>()
+        .join(" ");
+    let project_id = match project_dir(project) {
+        Ok(dir) if dir.join(".amproject").is_file() => {
+            db_get_project_by_path(&dir.to_string_lossy(), Some(database.clone()))
+                .ok()
+                .flatten()
+                .and_then(|project| project.id)
+        }
+        _ => None,
+    };
+
+    let record = CommandRecord {
+        command,
+        success,
+        duration_ms: started.elapsed().as_millis() as u64,
+        project_id,
+    };
+    if let Err(e) = history::record_command(database, &record) {
+        debug!("Skipping the command history: {}", e);
+    }
+}
+
+fn is_migrate_command(command: &Commands) -> bool {
+    matches!(
+        command,
+        Commands::Sudo {
+            command: SudoCommands::Database {
+                command: DatabaseCommands::Migrate { .. }
+            }
+        }
+    )
+}
+
+fn is_migrate_home_command(command: &Commands) -> bool {
+    matches!(
+        command,
+        Commands::Sudo {
+            command: SudoCommands::MigrateHome { .. }
+        }
+    )
+}
+
+/// Whether the first-run setup wizard may be offered before `command`.
+///
+/// Not before `sudo` commands, which include `am sudo setup` itself, nor before the
+/// completion scripts, which are usually evaluated by the shell, or the examples and
+/// error explanations.
+fn offers_setup(command: &Commands) -> bool {
+    !matches!(
+        command,
+        Commands::Sudo { .. }
+            | Commands::Examples { .. }
+            | Commands::Explain { .. }
+            | Commands::Completions { .. }
+    )
+}
+
+/// Take the lock of the project (the current directory, or `--project`) if the command
+/// modifies it.
+///
+/// Returns `None` for read-only commands, with `--no-lock`, or outside of a project
+/// (in which case the command itself reports the missing project).
+fn acquire_project_lock(
+    cli: &App,
+    project: Option<&Path>,
+    output: &dyn Output,
+) -> anyhow::Result<Option<ProjectLock>> {
+    if cli.no_lock || !cli.command.mutates_project_files() {
+        return Ok(None);
+    }
+
+    let current_dir = project_dir(project)?;
+    if !current_dir.join(".amproject").is_file() {
+        return Ok(None);
+    }
+
+    let command = std::iter::once("am".to_string())
+        .chain(std::env::args().skip(1))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    ProjectLock::acquire(&current_dir, &command, DEFAULT_LOCK_TIMEOUT, output).map(Some)
+}
+
+/// Fail before a command modifying the project (the current directory, or `--project`)
+/// changes anything when the project can't be written to, e.g. on a read-only mount.
+///
+/// Read-only commands and commands run outside of a project don't probe.
+fn check_project_writable(cli: &App, project: Option<&Path>) -> anyhow::Result<()> {
+    if !cli.command.mutates_project_files() {
+        return Ok(());
+    }
+
+    let current_dir = project_dir(project)?;
+    if !current_dir.join(".amproject").is_file() {
+        return Ok(());
+    }
+    ensure_writable(&current_dir)?;
+
+    // Asset commands write to the sources, which may be mounted separately.
+    if let Commands::Asset { .. } = cli.command
+        && let Ok(config) = read_amproject_file(&current_dir)
+    {
+        let sources_dir = current_dir.join(&config.sources_dir);
+        if sources_dir.is_dir() {
+            ensure_writable(&sources_dir)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Print a one-line notice when a newer version of the CLI is published.
+///
+/// Only runs after successful interactive commands; any failure of the check is ignored.
+async fn notify_new_version(database: Option<Arc<Database>>, output: &dyn Output) {
+    let current = env!("CARGO_PKG_VERSION");
+    match check_for_update(current, chrono::Utc::now(), &HttpVersionFetcher, database).await {
+        Ok(Some(latest)) => output.print(&format!(
+            "A new version of am is available: {} -> {} (disable with `--no-update-check`)",
+            current, latest
+        )),
+        Ok(None) => {}
+        Err(e) => debug!("Update check failed: {}", e),
+    }
+}
+
+/// The directory of the project given with `--project`, if any.
+fn resolve_project(cli: &App, database: Option<Arc<Database>>) -> anyhow::Result<Option<PathBuf>> {
+    match &cli.project {
+        Some(project) => {
+            let base = std::env::current_dir()?;
+            resolve_project_option(project, &base, database).map(Some)
+        }
+        None => Ok(None),
+    }
+}
+
 async fn run_command(
     cli: &App,
+    project: Option<&Path>,
     database: Option<Arc<Database>>,
     input: &dyn Input,
     output: &dyn Output,
 ) -> anyhow::Result<()> {
-    match &cli.command {
-        Commands::Asset { command } => handle_asset_command(command, database, input, output).await,
-        Commands::Project { command } => {
-            handle_project_command(command, database, input, output).await
-        }
-        Commands::Sdk { command } => handle_sdk_command(command, database, input, output).await,
-        Commands::Sudo { command } => handle_sudo_command(command, database, input, output).await,
-        Commands::Template { command } => {
-            handle_template_command(command, database, input, output).await
-        }
-        Commands::Completions { shell } => {
-            let mut cmd = App::command();
-            generate(*shell, &mut cmd, "am", &mut std::io::stdout());
-            Ok(())
-        }
-    }
+    check_project_writable(cli, project)?;
+
+    // Held until the command returns so concurrent invocations don't interleave writes.
+    let _project_lock = acquire_project_lock(cli, project, output)?;
+
+    let walk = WalkOptions {
+        follow_symlinks: cli.follow_symlinks,
+        max_depth: cli
+            .max_depth
+            .map_or(DEFAULT_MAX_WALK_DEPTH, |depth| depth as usize),
+    };
+    cli.command
+        .dispatch(project, walk, database, input, output)
+        .await
 }
@@ -15,178 +15,312 @@
 //! Interactive terminal output implementation.
 //!
 //! This module provides colored terminal output matching the existing
-//! CLI patterns using the `success!` macro and `log` macros.
+//! CLI patterns of the logger: messages are recorded for crash logging and
+//! formatted like the `success!` macro and `log` macros would.
 
 use crate::common::errors::CliError;
-use crate::presentation::Output;
-use crate::success;
+use crate::common::format::{MIN_TRUNCATED_PATH_WIDTH, terminal_width, truncate_path};
+use crate::common::logger::Logger;
+use crate::presentation::stages::StageTracker;
+use crate::presentation::{Alignment, ColumnSpec, Output, OutputWriter};
 use anyhow::Error;
 use colored::Colorize;
-use log::{error, info, warn};
+use log::Level;
+use std::io::Write;
 
 /// Interactive terminal output with colored formatting.
 ///
 /// This implementation wraps existing colored terminal behavior,
 /// matching the patterns established in `src/common/logger.rs`.
+/// Multi-line messages (errors, tables) are written as one block.
 #[derive(Debug, Default)]
-pub struct InteractiveOutput;
+pub struct InteractiveOutput {
+    writer: OutputWriter,
+    stages: StageTracker,
+    /// Width of the terminal, tables wider than it shorten their paths and drop their
+    /// optional columns.
+    width: Option<usize>,
+}
 
 impl InteractiveOutput {
-    /// Create a new InteractiveOutput instance.
+    /// Create a new InteractiveOutput instance writing to stdout.
+    ///
+    /// The terminal width is read from the `COLUMNS` environment variable, when set.
     pub fn new() -> Self {
-        Self
+        Self {
+            width: terminal_width(),
+            ..Self::default()
+        }
+    }
+
+    /// Write to `writer` instead of stdout.
+    pub fn with_writer(mut self, writer: impl Write + Send + 'static) -> Self {
+        self.writer = OutputWriter::new(writer);
+        self
+    }
+
+    /// Fit tables in `width` characters instead of the width of the terminal.
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Console line of a message at `level`, if the level is displayed.
+    fn line(level: Level, message: &str) -> Option<String> {
+        Logger::console_line(level, module_path!(), message)
     }
 }
 
 impl Output for InteractiveOutput {
     fn success(&self, data: serde_json::Value, _request_id: Option<i64>) {
-        // Use the success! macro for consistent formatting and crash logging
-        if let Some(s) = data.as_str() {
-            success!("{}", s);
-            return;
-        }
-
         // For complex data, pretty-print the JSON
-        match serde_json::to_string_pretty(&data) {
-            Ok(json) => success!("{}", json),
-            Err(_) => success!("Operation completed successfully"),
+        let message = match data.as_str() {
+            Some(s) => s.to_string(),
+            None => serde_json::to_string_pretty(&data)
+                .unwrap_or_else(|_| "Operation completed successfully".to_string()),
+        };
+
+        if let Some(line) = Logger::success_line(module_path!(), &message) {
+            self.writer.write_line(&line);
         }
     }
 
     fn error(&self, err: &Error, _code: i32, _request_id: Option<i64>) {
+        let mut lines = Vec::new();
+
         // Try to downcast to CliError for structured display with What/Why/Fix
         if let Some(cli_err) = err.downcast_ref::<CliError>() {
             // Display "What failed" in red
-            error!("{}: {}", "Error".red().bold(), cli_err.what);
+            lines.push(Self::line(
+                Level::Error,
+                &format!("{}: {}", "Error".red().bold(), cli_err.what),
+            ));
 
             // Display context if provided
             if let Some(ctx) = &cli_err.context {
-                error!("  {}: {}", "Context".dimmed(), ctx);
+                lines.push(Self::line(
+                    Level::Error,
+                    &format!("  {}: {}", "Context".dimmed(), ctx),
+                ));
             }
 
-            error!("");
+            lines.push(Self::line(Level::Error, ""));
 
             // Display "Why" with the reason
-            error!("{}: {}", "Why".yellow(), cli_err.why);
+            lines.push(Self::line(
+                Level::Error,
+                &format!("{}: {}", "Why".yellow(), cli_err.why),
+            ));
 
             // Display "Suggestion" with the fix in cyan
-            error!("{}: {}", "Suggestion".cyan(), cli_err.suggestion);
+            lines.push(Self::line(
+                Level::Error,
+                &format!("{}: {}", "Suggestion".cyan(), cli_err.suggestion),
+            ));
         } else {
             // Fallback for non-CliError: display error with chain
-            error!("{}", err);
+            lines.push(Self::line(Level::Error, &err.to_string()));
 
             // Display the error chain if present
             for cause in err.chain().skip(1) {
-                warn!("  caused by: {}", cause);
+                lines.push(Self::line(Level::Warn, &format!("  caused by: {}", cause)));
             }
         }
+
+        self.writer.write_lines(lines.into_iter().flatten());
     }
 
     fn progress(&self, message: &str) {
-        // Use info! macro for consistent formatting and crash logging
-        info!("{}", message);
+        if let Some(line) = Self::line(Level::Info, message) {
+            self.writer.write_line(&line);
+        }
     }
 
     fn print(&self, message: &str) {
-        println!("{}", message);
+        self.writer.write_line(message);
     }
 
     fn warning(&self, message: &str) {
-        // Use warn! macro for consistent formatting and crash logging
-        warn!("{}", message);
+        if let Some(line) = Self::line(Level::Warn, message) {
+            self.writer.write_line(&line);
+        }
     }
 
     fn table(&self, title: Option<&str>, data: serde_json::Value) {
-        // Display title if provided
-        if let Some(t) = title {
-            info!("{}", t.cyan().bold());
-        }
+        let columns = ColumnSpec::from_rows(&data);
+        self.table_with_columns(title, &columns, data);
+    }
 
-        // Extract rows from JSON array
-        let rows = match data.as_array() {
-            Some(arr) => arr,
-            None => return,
-        };
+    fn table_with_columns(
+        &self,
+        title: Option<&str>,
+        columns: &[ColumnSpec],
+        rows: serde_json::Value,
+    ) {
+        let lines = table_lines(title, columns, &rows, self.width);
+        self.writer
+            .write_lines(lines.iter().filter_map(|l| Self::line(Level::Info, l)));
+    }
+
+    fn mode(&self) -> super::OutputMode {
+        super::OutputMode::Interactive
+    }
 
-        if rows.is_empty() {
-            return;
+    fn begin_stages(&self, stages: &[&str]) {
+        self.stages.begin(stages);
+    }
+
+    fn enter_stage(&self, index: usize) {
+        if let Some(stage) = self.stages.enter(index) {
+            self.progress(&format!(
+                "{} {}…",
+                format!("[{}/{}]", stage.position, stage.total).bold(),
+                stage.name
+            ));
         }
+    }
+}
 
-        // Extract headers from first row's keys
-        let first_row = match rows.first().and_then(|r| r.as_object()) {
-            Some(obj) => obj,
-            None => return,
-        };
+/// Width of the prefix of the console lines of tables.
+const LINE_PREFIX_WIDTH: usize = 2;
 
-        let headers: Vec<&str> = first_row.keys().map(|k| k.as_str()).collect();
+/// Lines of a table with an optional title, sized to fit the widest cells.
+///
+/// With a `max_width`, the paths of the columns truncating them are shortened from the
+/// middle, down to [`MIN_TRUNCATED_PATH_WIDTH`] characters. Then the columns with the
+/// highest priority are dropped until the table fits in it, or only the columns with
+/// priority 0 are left.
+fn table_lines(
+    title: Option<&str>,
+    columns: &[ColumnSpec],
+    data: &serde_json::Value,
+    max_width: Option<usize>,
+) -> Vec<String> {
+    let mut lines = Vec::new();
 
-        // Convert rows to string values
-        let row_data: Vec<Vec<String>> = rows
-            .iter()
-            .filter_map(|r| r.as_object())
-            .map(|obj| {
-                headers
-                    .iter()
-                    .map(|h| {
-                        obj.get(*h)
-                            .map(|v| match v {
-                                serde_json::Value::String(s) => s.clone(),
-                                serde_json::Value::Null => "-".to_string(),
-                                other => other.to_string(),
-                            })
-                            .unwrap_or_else(|| "-".to_string())
-                    })
-                    .collect()
-            })
-            .collect();
-
-        // Calculate column widths based on headers and data
-        let mut widths: Vec<usize> = headers.iter().map(|h| h.len()).collect();
-        for row in &row_data {
-            for (i, cell) in row.iter().enumerate() {
-                if i < widths.len() {
-                    widths[i] = widths[i].max(cell.len());
+    // Display title if provided
+    if let Some(t) = title {
+        lines.push(t.cyan().bold().to_string());
+    }
+
+    // Extract rows from JSON array
+    let rows = match data.as_array() {
+        Some(arr) => arr,
+        None => return lines,
+    };
+
+    if rows.is_empty() || columns.is_empty() {
+        return lines;
+    }
+
+    // Convert rows to string values
+    let row_data: Vec<Vec<String>> = rows
+        .iter()
+        .filter_map(|r| r.as_object())
+        .map(|obj| {
+            columns
+                .iter()
+                .map(|column| {
+                    obj.get(&column.key)
+                        .map(|v| match v {
+                            serde_json::Value::String(s) => s.clone(),
+                            serde_json::Value::Null => "-".to_string(),
+                            other => other.to_string(),
+                        })
+                        .unwrap_or_else(|| "-".to_string())
+                })
+                .collect()
+        })
+        .collect();
+
+    // Calculate column widths based on headers and data, in characters
+    let mut widths: Vec<usize> = columns.iter().map(|c| c.header.chars().count()).collect();
+    for row in &row_data {
+        for (i, cell) in row.iter().enumerate() {
+            widths[i] = widths[i].max(cell.chars().count());
+        }
+    }
+
+    let mut shown: Vec<usize> = (0..columns.len()).collect();
+    let table_width = |widths: &[usize], shown: &[usize]| -> usize {
+        shown.iter().map(|&i| widths[i]).sum::<usize>() + shown.len().saturating_sub(1) * 2 + 2
+    };
+    if let Some(max_width) = max_width {
+        // Shorten the paths first, the widest column first
+        let mut excess =
+            (table_width(&widths, &shown) + LINE_PREFIX_WIDTH).saturating_sub(max_width);
+        while excess > 0 {
+            let floor = |i: usize| MIN_TRUNCATED_PATH_WIDTH.max(columns[i].header.chars().count());
+            let Some(i) = shown
+                .iter()
+                .copied()
+                .filter(|&i| columns[i].truncate_paths && widths[i] > floor(i))
+                .max_by_key(|&i| widths[i])
+            else {
+                break;
+            };
+            let shortened = excess.min(widths[i] - floor(i));
+            widths[i] -= shortened;
+            excess -= shortened;
+        }
+
+        // Then drop optional columns until the table fits, the rightmost first among equals
+        while table_width(&widths, &shown) + LINE_PREFIX_WIDTH > max_width {
+            let dropped = shown
+                .iter()
+                .enumerate()
+                .filter(|&(_, &i)| columns[i].priority > 0)
+                .max_by_key(|&(position, &i)| (columns[i].priority, position))
+                .map(|(position, _)| position);
+            match dropped {
+                Some(position) => {
+                    shown.remove(position);
                 }
+                None => break,
             }
         }
+    }
 
-        let total_width: usize = widths.iter().sum::<usize>() + (widths.len() - 1) * 2 + 2;
-        let separator = "─".repeat(total_width);
+    let separator = "─".repeat(table_width(&widths, &shown));
+    let pad = |text: String, i: usize| match columns[i].alignment {
+        Alignment::Left => format!("{:<width$}", text, width = widths[i]),
+        Alignment::Right => format!("{:>width$}", text, width = widths[i]),
+    };
 
-        // Print header
-        info!("{}", separator);
-        let header_line: String = headers
+    // Print header
+    lines.push(separator.clone());
+    let header_line: String = shown
+        .iter()
+        .map(|&i| pad(columns[i].header.clone(), i).bold().to_string())
+        .collect::<Vec<_>>()
+        .join("  ");
+    lines.push(format!(" {}", header_line));
+    lines.push(separator.clone());
+
+    // Print rows
+    for row in &row_data {
+        let row_line: String = shown
             .iter()
             .enumerate()
-            .map(|(i, h)| format!("{:<width$}", h.bold(), width = widths[i]))
+            .map(|(position, &i)| {
+                let cell = if columns[i].truncate_paths {
+                    pad(truncate_path(&row[i], widths[i]), i)
+                } else {
+                    pad(row[i].clone(), i)
+                };
+                if position == 0 {
+                    // The first column (name) is green
+                    cell.green().to_string()
+                } else {
+                    cell
+                }
+            })
             .collect::<Vec<_>>()
             .join("  ");
-        info!(" {}", header_line);
-        info!("{}", separator);
-
-        // Print rows
-        for row in &row_data {
-            let row_line: String = row
-                .iter()
-                .enumerate()
-                .map(|(i, cell)| {
-                    let width = widths.get(i).copied().unwrap_or(cell.len());
-                    if i == 0 {
-                        // The first column (name) is green
-                        format!("{:<width$}", cell.green(), width = width)
-                    } else {
-                        format!("{:<width$}", cell, width = width)
-                    }
-                })
-                .collect::<Vec<_>>()
-                .join("  ");
-            info!(" {}", row_line);
-        }
-
-        info!("{}", separator);
+        lines.push(format!(" {}", row_line));
     }
 
-    fn mode(&self) -> super::OutputMode {
-        super::OutputMode::Interactive
-    }
+    lines.push(separator);
+
+    lines
 }
@@ -19,16 +19,46 @@
 //!
 //! Unlike InteractiveOutput which uses log macros, JsonOutput writes directly
 //! to stdout to ensure the output is valid parseable JSON.
+//!
+//! With `--query <pointer>`, only the part of each success value selected by the
+//! JSON Pointer (RFC 6901) is printed, so scripts don't need `jq` to extract a field.
+//!
+//! # Envelope versioning
+//!
+//! Every envelope carries the [`ENVELOPE_SCHEMA_VERSION`] it follows in its `schema`
+//! field. Adding a field, or a new value to an open set like the error `type`, keeps the
+//! version: consumers must ignore fields they don't know. Removing or renaming a field,
+//! or changing its type or meaning, bumps the version. The golden envelopes of
+//! `tests/snapshots/envelopes/` pin the current layout, and `am introspect
+//! envelope-schema` prints its JSON Schema.
+//!
+//! # User data
+//!
+//! Envelopes may carry paths and file contents. Their control characters are escaped
+//! when serialized, and those of error messages are escaped in the message itself, see
+//! [`escape_control_characters`](crate::presentation::escape_control_characters).
 
-use crate::common::errors::{CliError, error_suggestion, error_type_name};
-use crate::presentation::Output;
+use crate::common::errors::{CliError, codes, error_suggestion, error_type_name};
+use crate::presentation::sanitize::{self, escape_control_characters};
+use crate::presentation::stages::StageTracker;
+use crate::presentation::{ColumnSpec, Output, OutputWriter};
 use anyhow::{Error, Result};
-use serde::Serialize;
-use std::io::{self, Write};
+use schemars::JsonSchema;
+use serde::ser::SerializeMap;
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+use std::io::Write;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Version of the layout of the JSON envelopes, bumped when a field is removed or renamed.
+pub const ENVELOPE_SCHEMA_VERSION: u32 = 1;
 
 /// JSON response envelope for success responses.
-#[derive(Serialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, JsonSchema, Debug, Clone, PartialEq)]
 pub struct JsonResponse<T: Serialize> {
+    /// Version of the envelope layout ([`ENVELOPE_SCHEMA_VERSION`])
+    pub schema: u32,
     /// Indicates success (true) or failure (false)
     pub ok: bool,
     /// The success value (present when ok=true)
@@ -37,10 +67,14 @@ pub struct JsonResponse<T: Serialize> {
     /// The error details (present when ok=false)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<JsonErrorDetails>,
+    /// Details about the run, e.g. the stages declared with `begin_stages` and the
+    /// completed ones
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Value>,
 }
 
 /// Structured error information for JSON error responses.
-#[derive(Serialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, JsonSchema, Debug, Clone, PartialEq)]
 pub struct JsonErrorDetails {
     /// Numeric error code (from error code ranges)
     pub code: i32,
@@ -61,28 +95,256 @@ pub struct JsonErrorDetails {
 /// JSON output implementation for machine-parseable CLI responses.
 ///
 /// This implementation outputs structured JSON to stdout in the envelope format:
-/// - Success: `{ "ok": true, "value": {...} }`
-/// - Error: `{ "ok": false, "error": { "code": ..., "type": ..., "message": ..., "suggestion": ... } }`
+/// - Success: `{ "schema": 1, "ok": true, "value": {...} }`
+/// - Error: `{ "schema": 1, "ok": false, "error": { "code": ..., "type": ..., "message": ..., "suggestion": ... } }`
 ///
 /// Unlike InteractiveOutput, this writes directly to stdout (not via log macros)
 /// to ensure the output is valid, parseable JSON without any prefixes or formatting.
+///
+/// When created with a query, success values are replaced by the value the query
+/// selects, printed raw for scalars. Errors are always printed as envelopes.
+///
+/// Each envelope is written as one block, so envelopes written from concurrent threads
+/// never interleave.
 #[derive(Debug, Default)]
-pub struct JsonOutput;
+pub struct JsonOutput {
+    /// Destination of the envelopes, stdout unless set with `with_writer`.
+    writer: OutputWriter,
+    /// JSON Pointer applied to success values (`--query`).
+    query: Option<String>,
+    /// First error raised while applying `query`, reported by `finish`.
+    query_error: Mutex<Option<CliError>>,
+    /// Stages declared by the command, reported in the `meta` of the envelopes.
+    stages: StageTracker,
+    /// `truncated` and `total` of a capped list, reported in the `meta` of the next
+    /// success envelope.
+    truncation: Mutex<Option<Value>>,
+    /// Whether `db_oversized` is reported in the `meta` of the envelopes.
+    db_oversized: AtomicBool,
+}
 
 impl JsonOutput {
     /// Create a new JsonOutput instance.
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Create a JsonOutput printing only the value selected by the JSON Pointer `query`
+    /// in each success value.
+    ///
+    /// Fails with `ERR_VALIDATION_FORMAT` if `query` is not a valid JSON Pointer.
+    pub fn with_query(query: &str) -> std::result::Result<Self, CliError> {
+        validate_json_pointer(query)?;
+
+        Ok(Self {
+            query: Some(query.to_string()),
+            ..Self::default()
+        })
+    }
+
+    /// Write to `writer` instead of stdout.
+    pub fn with_writer(mut self, writer: impl Write + Send + 'static) -> Self {
+        self.writer = OutputWriter::new(writer);
+        self
+    }
+
+    /// Render the value selected by the JSON Pointer `query` in `data`.
+    ///
+    /// Strings are returned unquoted and other scalars as their JSON text, while
+    /// objects and arrays are pretty-printed. Fails with `ERR_VALIDATION_FIELD` if
+    /// `data` has no value at `query`.
+    pub fn apply_query(data: &Value, query: &str) -> std::result::Result<String, CliError> {
+        let selected = data.pointer(query).ok_or_else(|| {
+            CliError::new(
+                codes::ERR_VALIDATION_FIELD,
+                format!("Query '{}' matches nothing", query),
+                "The command result has no value at this JSON Pointer",
+            )
+            .with_suggestion("Run the command without --query to see the whole result")
+        })?;
+
+        Ok(match selected {
+            Value::String(s) => s.clone(),
+            Value::Array(_) | Value::Object(_) => {
+                sanitize::to_string_pretty(selected).unwrap_or_default()
+            }
+            scalar => scalar.to_string(),
+        })
+    }
+
+    /// The `meta` of a success envelope: the stages and the truncation of a list.
+    fn success_meta(&self) -> Option<Value> {
+        let truncation = self.truncation.lock().ok().and_then(|mut t| t.take());
+        let meta = match (self.stages.meta(true), truncation) {
+            (Some(mut meta), Some(truncation)) => {
+                if let (Some(meta), Some(truncation)) =
+                    (meta.as_object_mut(), truncation.as_object())
+                {
+                    meta.extend(truncation.clone());
+                }
+                Some(meta)
+            }
+            (meta, truncation) => meta.or(truncation),
+        };
+        self.with_database_meta(meta)
+    }
+
+    /// Add `db_oversized` to `meta` once the database was reported oversized.
+    fn with_database_meta(&self, meta: Option<Value>) -> Option<Value> {
+        if !self.db_oversized.load(Ordering::Relaxed) {
+            return meta;
+        }
+
+        let mut meta = meta.unwrap_or_else(|| Value::Object(Default::default()));
+        if let Some(object) = meta.as_object_mut() {
+            object.insert("db_oversized".to_string(), Value::Bool(true));
+        }
+        Some(meta)
+    }
+
+    /// Write a success value, applying the query if there is one.
+    ///
+    /// A success value that is a single string is a message, its control characters are
+    /// escaped.
+    fn write_success(&self, data: Value) {
+        let data = match data {
+            Value::String(message) => {
+                Value::String(escape_control_characters(&message).into_owned())
+            }
+            data => data,
+        };
+
+        let Some(query) = &self.query else {
+            let mut response = Self::build_success_response(data);
+            response.meta = self.success_meta();
+            self.write_envelope(&response);
+            return;
+        };
+
+        match Self::apply_query(&data, query) {
+            Ok(text) => self.writer.write_line(&text),
+            Err(e) => {
+                if let Ok(mut query_error) = self.query_error.lock() {
+                    query_error.get_or_insert(e);
+                }
+            }
+        }
+    }
+
+    /// Write the rows of a table with the fields of each row in the order of `columns`.
+    ///
+    /// A query is applied to the rows as they are, field order doesn't matter to it.
+    fn write_table(&self, columns: &[ColumnSpec], rows: Value) {
+        if self.query.is_some() {
+            self.write_success(rows);
+            return;
+        }
+
+        let response = JsonResponse {
+            schema: ENVELOPE_SCHEMA_VERSION,
+            ok: true,
+            value: Some(OrderedRows {
+                keys: columns.iter().map(|c| c.key.as_str()).collect(),
+                rows: &rows,
+            }),
+            error: None,
+            meta: self.success_meta(),
+        };
+        self.write_envelope(&response);
     }
+
+    /// Write a response envelope as a single block.
+    fn write_envelope<T: Serialize>(&self, response: &JsonResponse<T>) {
+        // Silently ignore serialization errors to avoid panic in the output path
+        if let Ok(json) = Self::serialize_response(response) {
+            self.writer.write_line(&json);
+        }
+    }
+}
+
+/// Table rows serialized with the fields of each object in the order of `keys`, followed
+/// by the fields that aren't columns. Values other than arrays of objects are
+/// serialized as they are.
+struct OrderedRows<'a> {
+    keys: Vec<&'a str>,
+    rows: &'a Value,
+}
+
+impl Serialize for OrderedRows<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let Some(rows) = self.rows.as_array() else {
+            return self.rows.serialize(serializer);
+        };
+
+        serializer.collect_seq(rows.iter().map(|row| OrderedRow {
+            keys: &self.keys,
+            row,
+        }))
+    }
+}
+
+/// A row of [`OrderedRows`].
+struct OrderedRow<'a> {
+    keys: &'a [&'a str],
+    row: &'a Value,
+}
+
+impl Serialize for OrderedRow<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let Some(row) = self.row.as_object() else {
+            return self.row.serialize(serializer);
+        };
+
+        let mut map = serializer.serialize_map(Some(row.len()))?;
+        for key in self.keys {
+            if let Some(value) = row.get(*key) {
+                map.serialize_entry(key, value)?;
+            }
+        }
+        for (key, value) in row {
+            if !self.keys.contains(&key.as_str()) {
+                map.serialize_entry(key, value)?;
+            }
+        }
+        map.end()
+    }
+}
+
+/// Check that `pointer` is a JSON Pointer: empty, or `/`-separated reference tokens in
+/// which `~` only appears as the `~0` and `~1` escapes.
+fn validate_json_pointer(pointer: &str) -> std::result::Result<(), CliError> {
+    let mut chars = pointer.chars();
+    let mut valid = pointer.is_empty() || pointer.starts_with('/');
+
+    while valid && let Some(c) = chars.next() {
+        if c == '~' {
+            valid = matches!(chars.next(), Some('0' | '1'));
+        }
+    }
+
+    if valid {
+        return Ok(());
+    }
+
+    Err(CliError::new(
+        codes::ERR_VALIDATION_FORMAT,
+        format!("Invalid query: '{}'", pointer),
+        "The query must be a JSON Pointer (RFC 6901)",
+    )
+    .with_suggestion(
+        "Start the query with '/' and escape '~' as '~0' and '/' as '~1' (e.g. --query /0/path)",
+    ))
 }
 
 impl JsonOutput {
     /// Build a success response structure without writing to stdout.
     pub fn build_success_response(data: serde_json::Value) -> JsonResponse<serde_json::Value> {
         JsonResponse {
+            schema: ENVELOPE_SCHEMA_VERSION,
             ok: true,
             value: Some(data),
             error: None,
+            meta: None,
         }
     }
 
@@ -96,33 +358,49 @@ impl JsonOutput {
             JsonErrorDetails {
                 code: cli_err.code,
                 type_: cli_err.type_name(),
-                message: cli_err.what.clone(),
-                why: cli_err.why.clone(),
-                suggestion: cli_err.suggestion.clone(),
-                context: cli_err.context.clone(),
+                message: escape_control_characters(&cli_err.what).into_owned(),
+                why: escape_control_characters(&cli_err.why).into_owned(),
+                suggestion: escape_control_characters(&cli_err.suggestion).into_owned(),
+                context: cli_err
+                    .context
+                    .as_deref()
+                    .map(|context| escape_control_characters(context).into_owned()),
             }
         } else {
             // Fallback for non-CliError: use provided code and generic mappings
             JsonErrorDetails {
                 code,
                 type_: error_type_name(code),
-                message: err.to_string(),
-                why: err.to_string(),
+                message: escape_control_characters(&err.to_string()).into_owned(),
+                why: escape_control_characters(&err.to_string()).into_owned(),
                 suggestion: error_suggestion(code),
                 context: None,
             }
         };
 
         JsonResponse {
+            schema: ENVELOPE_SCHEMA_VERSION,
             ok: false,
             value: None,
             error: Some(error),
+            meta: None,
         }
     }
 
+    /// The JSON Schema of the envelopes, generated from [`JsonResponse`].
+    ///
+    /// The `value` of success envelopes is left open, as it depends on the command.
+    pub fn envelope_schema() -> Value {
+        let schema = schemars::schema_for!(JsonResponse<Value>);
+        serde_json::to_value(&schema).unwrap_or(Value::Null)
+    }
+
     /// Serialize a response to a pretty-printed JSON string.
+    ///
+    /// Control characters and the U+2028 and U+2029 separators are escaped, so the
+    /// envelope parses with strict and JavaScript parsers alike.
     pub fn serialize_response<T: Serialize>(response: &JsonResponse<T>) -> Result<String> {
-        serde_json::to_string_pretty(response)
+        sanitize::to_string_pretty(response)
             .map_err(|e| anyhow::anyhow!("JSON serialization failed: {}", e))
     }
 
@@ -140,17 +418,15 @@ impl JsonOutput {
 
 impl Output for JsonOutput {
     fn success(&self, data: serde_json::Value, _request_id: Option<i64>) {
-        let response = Self::build_success_response(data);
         // Write directly to stdout, not via log macros, for parseable JSON
-        // Silently ignore write errors to avoid panic in the output path
-        let _ = Self::write_response(&mut io::stdout(), &response);
+        self.write_success(data);
     }
 
     fn error(&self, err: &Error, code: i32, _request_id: Option<i64>) {
-        let response = Self::build_error_response(err, code);
         // Write directly to stdout for parseable JSON
-        // Silently ignore write errors to avoid panic in the output path
-        let _ = Self::write_response(&mut io::stdout(), &response);
+        let mut response = Self::build_error_response(err, code);
+        response.meta = self.with_database_meta(self.stages.meta(false));
+        self.write_envelope(&response);
     }
 
     fn progress(&self, _message: &str) {
@@ -169,11 +445,52 @@ impl Output for JsonOutput {
 
     fn table(&self, _title: Option<&str>, data: serde_json::Value) {
         // In JSON mode, output the data in the success envelope format
-        let response = Self::build_success_response(data);
-        let _ = Self::write_response(&mut io::stdout(), &response);
+        self.write_success(data);
+    }
+
+    fn table_with_columns(
+        &self,
+        _title: Option<&str>,
+        columns: &[ColumnSpec],
+        rows: serde_json::Value,
+    ) {
+        self.write_table(columns, rows);
     }
 
     fn mode(&self) -> super::OutputMode {
         super::OutputMode::Json
     }
+
+    fn begin_stages(&self, stages: &[&str]) {
+        self.stages.begin(stages);
+    }
+
+    fn enter_stage(&self, index: usize) {
+        self.stages.enter(index);
+    }
+
+    fn list_truncated(&self, shown: usize, total: usize) {
+        if shown < total
+            && let Ok(mut truncation) = self.truncation.lock()
+        {
+            *truncation = Some(serde_json::json!({ "truncated": true, "total": total }));
+        }
+    }
+
+    fn database_oversized(&self) {
+        self.db_oversized.store(true, Ordering::Relaxed);
+    }
+
+    fn finish(&self) -> Result<()> {
+        let query_error = self
+            .query_error
+            .lock()
+            .map_err(|e| anyhow::anyhow!("Failed to acquire query error lock: {}", e))?
+            .take();
+
+        match query_error {
+            Some(e) => Err(e.into()),
+            None => Ok(()),
+        }
+    }
 }
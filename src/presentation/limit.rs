@@ -0,0 +1,117 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Size guard of list responses.
+//!
+//! Commands listing an unbounded number of items (assets, projects, validation issues)
+//! return at most [`DEFAULT_LIST_LIMIT`] of them unless `--limit` or `--all` is given,
+//! so a large project doesn't produce a multi-megabyte JSON envelope. When items are left
+//! out, the payload gets `"truncated": true` and the `total` number of items (the `meta`
+//! of the envelope for lists output as a table), and a notice is printed in interactive
+//! mode.
+
+use clap::Args;
+use serde_json::json;
+
+use super::{Output, OutputMode};
+
+/// Number of items a list response holds when neither `--limit` nor `--all` is given.
+pub const DEFAULT_LIST_LIMIT: usize = 1000;
+
+/// The `--limit` and `--all` arguments of list commands.
+#[derive(Args, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ListLimit {
+    /// Return at most N items [default: 1000]
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    pub limit: Option<u64>,
+
+    /// Return every item, however many there are
+    #[arg(long, conflicts_with = "limit")]
+    pub all: bool,
+}
+
+impl ListLimit {
+    /// The limit given with `--limit`.
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit: Some(limit as u64),
+            all: false,
+        }
+    }
+
+    /// The maximum number of items to return, or `None` with `--all`.
+    pub fn max_items(&self) -> Option<usize> {
+        if self.all {
+            return None;
+        }
+        Some(
+            self.limit
+                .map_or(DEFAULT_LIST_LIMIT, |limit| limit as usize),
+        )
+    }
+
+    /// Drop the items of `items` past the limit.
+    pub fn apply<T>(&self, items: &mut Vec<T>) -> Truncation {
+        let total = items.len();
+        if let Some(max) = self.max_items() {
+            items.truncate(max);
+        }
+
+        Truncation {
+            shown: items.len(),
+            total,
+        }
+    }
+}
+
+/// How many items of a list were kept by [`ListLimit::apply`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Truncation {
+    pub shown: usize,
+    pub total: usize,
+}
+
+impl Truncation {
+    /// Whether items were left out.
+    pub fn is_truncated(&self) -> bool {
+        self.shown < self.total
+    }
+
+    /// Add `"truncated": true` and the `total` number of items to `payload` when items
+    /// were left out.
+    pub fn annotate(&self, payload: &mut serde_json::Value) {
+        if self.is_truncated() {
+            payload["truncated"] = json!(true);
+            payload["total"] = json!(self.total);
+        }
+    }
+
+    /// For a list output as a table, report the truncation through
+    /// [`Output::list_truncated`]. Must be called before the table is output.
+    pub fn report(&self, output: &dyn Output) {
+        if self.is_truncated() {
+            output.list_truncated(self.shown, self.total);
+        }
+    }
+
+    /// In interactive mode, tell that only some of the `items` (e.g. "sounds") are shown.
+    pub fn notify(&self, items: &str, output: &dyn Output) {
+        if self.is_truncated() && output.mode() == OutputMode::Interactive {
+            output.progress(&format!(
+                "Showing {} of {} {}: use --limit N or --all to see more",
+                self.shown, self.total, items
+            ));
+        }
+    }
+}
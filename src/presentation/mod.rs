@@ -20,10 +20,25 @@
 
 mod interactive;
 pub mod json;
+mod limit;
+mod sanitize;
+mod stages;
+mod summary;
+mod table;
+mod tree;
+mod writer;
 
 pub use interactive::InteractiveOutput;
 #[allow(unused_imports)] // Exported for library consumers and tests
-pub use json::{JsonErrorDetails, JsonOutput, JsonResponse};
+pub use json::{ENVELOPE_SCHEMA_VERSION, JsonErrorDetails, JsonOutput, JsonResponse};
+pub use limit::{DEFAULT_LIST_LIMIT, ListLimit, Truncation};
+pub use sanitize::{
+    LossyString, escape_control_characters, insert_os_str, lossy_os_str, to_string_pretty,
+};
+pub use summary::SummaryOutput;
+pub use table::{Alignment, ColumnSpec};
+pub use tree::{PathTree, TreeLeaf, path_components};
+pub use writer::OutputWriter;
 
 use anyhow::Error;
 
@@ -52,6 +67,13 @@ pub enum OutputMode {
 ///
 /// Note: Uses `serde_json::Value` instead of generics to maintain dyn-compatibility.
 /// Callers should use `serde_json::to_value()` or `json!()` macro to convert their data.
+///
+/// # Concurrency
+///
+/// Parallel workers may call the same output from several threads at once.
+/// Implementations must write what each call produces as a whole, so lines (and
+/// multi-line blocks such as JSON envelopes or tables) from concurrent calls never
+/// interleave. Writing through an [`OutputWriter`] provides this guarantee.
 pub trait Output: Send + Sync {
     /// Display a successful result.
     ///
@@ -75,6 +97,14 @@ pub trait Output: Send + Sync {
     /// * `message` - Progress message to display
     fn progress(&self, message: &str);
 
+    /// Display the progress of a step over `total` items, `current` of which are done.
+    ///
+    /// Defaults to [`Output::progress`] with the count appended, so JSON output
+    /// suppresses it like any progress message.
+    fn progress_with(&self, message: &str, current: usize, total: usize) {
+        self.progress(&format!("{} ({}/{})", message, current, total));
+    }
+
     /// Print a plain line of output, without log-level prefixes.
     ///
     /// In interactive mode, writes to stdout verbatim. In JSON mode, the
@@ -104,12 +134,65 @@ pub trait Output: Send + Sync {
     /// * `data` - The data to display as a JSON array of objects
     fn table(&self, title: Option<&str>, data: serde_json::Value);
 
+    /// Display tabular data with explicit columns.
+    ///
+    /// Unlike [`Output::table`], which shows the fields of the first row in whatever
+    /// order its object keeps them, the columns are shown in the order of `columns`, with
+    /// their header labels and alignment. Interactive output drops the columns with the
+    /// highest priority when the table is wider than the terminal. JSON output ignores
+    /// these cosmetic parts, but writes the fields of each row in the order of the
+    /// columns.
+    ///
+    /// Defaults to [`Output::table`].
+    fn table_with_columns(
+        &self,
+        title: Option<&str>,
+        _columns: &[ColumnSpec],
+        rows: serde_json::Value,
+    ) {
+        self.table(title, rows);
+    }
+
     /// Get the current output mode.
     ///
     /// Commands can use this to conditionally format output based on the mode,
     /// avoiding duplicate output in interactive mode where progress messages
     /// already display the information.
     fn mode(&self) -> OutputMode;
+
+    /// Declare the stages of the command, in order, so tools can show step indicators.
+    ///
+    /// Commands opt in by calling this once, then [`Output::enter_stage`] at each
+    /// transition. Interactive output prints a `[2/3] Copy…` line when a stage is
+    /// entered, and JSON output lists the declared and completed stages in the `meta`
+    /// of its envelopes. Does nothing by default.
+    fn begin_stages(&self, _stages: &[&str]) {}
+
+    /// Mark the transition to the stage at `index` of those declared with
+    /// [`Output::begin_stages`]. Does nothing by default.
+    fn enter_stage(&self, _index: usize) {}
+
+    /// Report that the list about to be output holds only the first `shown` of `total`
+    /// items, as capped by [`ListLimit`].
+    ///
+    /// For lists output as a table, whose JSON value is the list itself: JSON output adds
+    /// `truncated` and `total` to the `meta` of its next envelope. Does nothing by default.
+    fn list_truncated(&self, _shown: usize, _total: usize) {}
+
+    /// Report that the database file is above its hard size limit, see
+    /// [`size`](crate::database::size).
+    ///
+    /// JSON output adds `db_oversized: true` to the `meta` of every following envelope.
+    /// Does nothing by default.
+    fn database_oversized(&self) {}
+
+    /// Report an error that occurred while presenting results, once the command returned.
+    ///
+    /// Output methods can't fail, so an implementation unable to present a result
+    /// (e.g. JSON output whose `--query` matches nothing) keeps the error until then.
+    fn finish(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
 }
 
 /// Create an Output implementation based on the requested mode.
@@ -0,0 +1,198 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sanitization of user data at the JSON output boundary.
+//!
+//! Paths and file contents may hold characters some JSON consumers choke on: invalid
+//! UTF-8 in Linux file names, or control characters rewriting the terminal of whoever
+//! prints a message. Strings derived from an `OsStr` are converted lossily with
+//! [`insert_os_str`], which keeps the original bytes in a `<key>_raw_bytes_b64` field
+//! when the conversion lost information. Envelopes are serialized with
+//! [`to_string_pretty`], which escapes every control character, and the line and
+//! paragraph separators rejected by JavaScript parsers, as `\uXXXX`.
+
+use serde::Serialize;
+use serde_json::ser::{Formatter, PrettyFormatter};
+use serde_json::{Serializer, Value};
+use std::borrow::Cow;
+use std::ffi::OsStr;
+use std::io::{self, Write};
+
+/// A string converted lossily from an `OsStr`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LossyString {
+    /// The string, invalid sequences replaced with U+FFFD
+    pub text: String,
+    /// The original bytes in base64, when `text` lost information
+    pub raw_bytes_b64: Option<String>,
+}
+
+/// Convert `value` to a string, replacing invalid sequences with U+FFFD.
+///
+/// The bytes are those of [`OsStr::as_encoded_bytes`]: the raw bytes on Unix, WTF-8 on
+/// Windows.
+pub fn lossy_os_str(value: &OsStr) -> LossyString {
+    match value.to_str() {
+        Some(text) => LossyString {
+            text: text.to_string(),
+            raw_bytes_b64: None,
+        },
+        None => LossyString {
+            text: value.to_string_lossy().into_owned(),
+            raw_bytes_b64: Some(base64(value.as_encoded_bytes())),
+        },
+    }
+}
+
+/// Set the field `key` of the object `object` to `value`, converted lossily, and its
+/// `<key>_raw_bytes_b64` field to the original bytes if the conversion lost information.
+///
+/// Does nothing if `object` isn't an object.
+pub fn insert_os_str(object: &mut Value, key: &str, value: &OsStr) {
+    let Some(object) = object.as_object_mut() else {
+        return;
+    };
+
+    let lossy = lossy_os_str(value);
+    object.insert(key.to_string(), Value::String(lossy.text));
+    if let Some(raw) = lossy.raw_bytes_b64 {
+        object.insert(format!("{}_raw_bytes_b64", key), Value::String(raw));
+    }
+}
+
+/// Escape the control characters of the message `text`, except line feeds and tabs, as
+/// `\u{..}`, so printing the message can't move the cursor or recolor the terminal.
+pub fn escape_control_characters(text: &str) -> Cow<'_, str> {
+    if !text.chars().any(is_escaped_in_messages) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if is_escaped_in_messages(c) {
+            escaped.extend(c.escape_unicode());
+        } else {
+            escaped.push(c);
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+/// Serialize `value` to pretty-printed JSON, escaping every control character and the
+/// U+2028 and U+2029 separators.
+pub fn to_string_pretty<T: Serialize + ?Sized>(value: &T) -> serde_json::Result<String> {
+    let mut buffer = Vec::new();
+    let mut serializer =
+        Serializer::with_formatter(&mut buffer, EscapingFormatter(PrettyFormatter::new()));
+    value.serialize(&mut serializer)?;
+    // The serializer only writes valid UTF-8
+    Ok(String::from_utf8(buffer).unwrap_or_default())
+}
+
+fn is_escaped_in_messages(c: char) -> bool {
+    c.is_control() && c != '\n' && c != '\t'
+}
+
+fn is_escaped_in_json(c: char) -> bool {
+    c.is_control() || c == '\u{2028}' || c == '\u{2029}'
+}
+
+/// A [`PrettyFormatter`] also escaping the characters `serde_json` writes as they are.
+///
+/// `serde_json` escapes the C0 controls itself, this formatter escapes the DEL and C1
+/// controls and the U+2028 and U+2029 separators.
+struct EscapingFormatter<'a>(PrettyFormatter<'a>);
+
+impl Formatter for EscapingFormatter<'_> {
+    fn write_string_fragment<W: ?Sized + Write>(
+        &mut self,
+        writer: &mut W,
+        fragment: &str,
+    ) -> io::Result<()> {
+        let mut start = 0;
+        for (index, c) in fragment.char_indices() {
+            if is_escaped_in_json(c) {
+                writer.write_all(fragment[start..index].as_bytes())?;
+                write!(writer, "\\u{:04x}", c as u32)?;
+                start = index + c.len_utf8();
+            }
+        }
+        writer.write_all(fragment[start..].as_bytes())
+    }
+
+    fn begin_array<W: ?Sized + Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.0.begin_array(writer)
+    }
+
+    fn end_array<W: ?Sized + Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.0.end_array(writer)
+    }
+
+    fn begin_array_value<W: ?Sized + Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> io::Result<()> {
+        self.0.begin_array_value(writer, first)
+    }
+
+    fn end_array_value<W: ?Sized + Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.0.end_array_value(writer)
+    }
+
+    fn begin_object<W: ?Sized + Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.0.begin_object(writer)
+    }
+
+    fn end_object<W: ?Sized + Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.0.end_object(writer)
+    }
+
+    fn begin_object_key<W: ?Sized + Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> io::Result<()> {
+        self.0.begin_object_key(writer, first)
+    }
+
+    fn begin_object_value<W: ?Sized + Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.0.begin_object_value(writer)
+    }
+
+    fn end_object_value<W: ?Sized + Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.0.end_object_value(writer)
+    }
+}
+
+/// Encode `bytes` in standard base64, with padding.
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, b)| n | ((*b as u32) << (16 - 8 * i)));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[((n >> (18 - 6 * i)) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
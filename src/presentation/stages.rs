@@ -0,0 +1,94 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Stages declared by a command through [`Output::begin_stages`](super::Output::begin_stages).
+
+use std::sync::Mutex;
+
+/// The stages of the running command and the one it is in, shared by its threads.
+#[derive(Debug, Default)]
+pub(crate) struct StageTracker {
+    inner: Mutex<Stages>,
+}
+
+#[derive(Debug, Default)]
+struct Stages {
+    names: Vec<String>,
+    current: Option<usize>,
+}
+
+/// A stage transition, as displayed to the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct StageEntry {
+    /// 1-based position of the stage.
+    pub position: usize,
+    /// Number of declared stages.
+    pub total: usize,
+    /// Name of the stage.
+    pub name: String,
+}
+
+impl StageTracker {
+    /// Declare `stages`, replacing any previous declaration.
+    pub fn begin(&self, stages: &[&str]) {
+        *self.lock() = Stages {
+            names: stages.iter().map(|s| s.to_string()).collect(),
+            current: None,
+        };
+    }
+
+    /// Enter the stage at `index`, or `None` if no such stage was declared.
+    pub fn enter(&self, index: usize) -> Option<StageEntry> {
+        let mut stages = self.lock();
+        let name = stages.names.get(index)?.clone();
+        stages.current = Some(index);
+
+        Some(StageEntry {
+            position: index + 1,
+            total: stages.names.len(),
+            name,
+        })
+    }
+
+    /// The `meta` of a JSON envelope: the declared stages and the completed ones.
+    ///
+    /// The current stage is completed when the command `succeeded`, and reported as the
+    /// failed stage otherwise. `None` if no stage was declared.
+    pub fn meta(&self, succeeded: bool) -> Option<serde_json::Value> {
+        let stages = self.lock();
+        if stages.names.is_empty() {
+            return None;
+        }
+
+        let completed = match stages.current {
+            Some(current) if succeeded => &stages.names[..=current],
+            Some(current) => &stages.names[..current],
+            None => &[],
+        };
+
+        let mut meta = serde_json::json!({
+            "stages": stages.names,
+            "completed_stages": completed,
+        });
+        if !succeeded && let Some(current) = stages.current {
+            meta["failed_stage"] = serde_json::json!(stages.names[current]);
+        }
+
+        Some(meta)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Stages> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Output of commands run with `--summary`.
+
+use anyhow::Error;
+
+use crate::presentation::{Output, OutputMode};
+
+/// Output silencing everything a command reports while it runs.
+///
+/// Commands run with `--summary` still compute their full results, but do so through a
+/// `SummaryOutput` and present only a one-line summary through the wrapped output once
+/// done. Progress, warnings, tables and even results are dropped; only errors, which
+/// can't be summarized, go through.
+pub struct SummaryOutput<'a> {
+    inner: &'a dyn Output,
+}
+
+impl<'a> SummaryOutput<'a> {
+    /// Silence `inner` until the summary is presented through it.
+    pub fn new(inner: &'a dyn Output) -> Self {
+        Self { inner }
+    }
+}
+
+impl Output for SummaryOutput<'_> {
+    fn success(&self, _data: serde_json::Value, _request_id: Option<i64>) {}
+
+    fn error(&self, err: &Error, code: i32, request_id: Option<i64>) {
+        self.inner.error(err, code, request_id);
+    }
+
+    fn progress(&self, _message: &str) {}
+
+    fn print(&self, _message: &str) {}
+
+    fn warning(&self, _message: &str) {}
+
+    fn table(&self, _title: Option<&str>, _data: serde_json::Value) {}
+
+    fn mode(&self) -> OutputMode {
+        self.inner.mode()
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Column definitions of the tables displayed with `Output::table_with_columns`.
+
+/// Horizontal alignment of the cells of a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Alignment {
+    #[default]
+    Left,
+    Right,
+}
+
+/// A column of a table: which field of the rows it shows, and how.
+///
+/// Only `key` matters to JSON output, which writes the fields of each row in the order
+/// of the columns. The header, alignment and priority are cosmetic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnSpec {
+    /// Field of the row objects shown in the column.
+    pub key: String,
+    /// Label of the column in the header line.
+    pub header: String,
+    /// Alignment of the cells.
+    pub alignment: Alignment,
+    /// When the table is wider than the terminal, columns with the highest priority are
+    /// dropped first. Columns with priority 0 are always shown.
+    pub priority: u8,
+    /// Whether the cells are paths, shortened from the middle when the table is wider
+    /// than the terminal instead of only being dropped.
+    pub truncate_paths: bool,
+}
+
+impl ColumnSpec {
+    /// A left-aligned column of the `key` field, always shown.
+    pub fn new(key: impl Into<String>, header: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            header: header.into(),
+            alignment: Alignment::Left,
+            priority: 0,
+            truncate_paths: false,
+        }
+    }
+
+    /// Set the alignment of the cells.
+    pub fn with_alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Set the priority of the column, see [`ColumnSpec::priority`].
+    pub fn with_priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Shorten the paths of the cells when the table is too wide, see
+    /// [`truncate_path`](crate::common::format::truncate_path).
+    pub fn with_path_truncation(mut self) -> Self {
+        self.truncate_paths = true;
+        self
+    }
+
+    /// One column per field of the first row of `rows`, labelled with the field name.
+    ///
+    /// Used by `Output::table`, for tables whose columns are those of their data.
+    pub fn from_rows(rows: &serde_json::Value) -> Vec<Self> {
+        rows.as_array()
+            .and_then(|rows| rows.first())
+            .and_then(|row| row.as_object())
+            .map(|row| row.keys().map(|key| Self::new(key, key)).collect())
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,293 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Directory hierarchy of registered projects, output by `am project list --tree`.
+//!
+//! Projects are grouped by the directories of their path below a root directory, the
+//! deepest common ancestor of the projects unless one is given. Projects outside the
+//! root are gathered in a separate "other" branch. Paths are split on both `/` and `\`,
+//! so projects registered on Windows and Unix are grouped the same way.
+
+use std::collections::BTreeMap;
+
+use serde_json::{Value, json};
+
+/// A project, leaf of a [`PathTree`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeLeaf {
+    pub name: String,
+    pub path: String,
+    pub favorite: bool,
+}
+
+impl TreeLeaf {
+    fn to_json(&self) -> Value {
+        json!({
+            "type": "project",
+            "name": self.name,
+            "path": self.path,
+            "favorite": self.favorite,
+        })
+    }
+
+    fn label(&self) -> String {
+        if self.favorite {
+            format!("★ {}", self.name)
+        } else {
+            self.name.clone()
+        }
+    }
+}
+
+/// A directory of a [`PathTree`].
+#[derive(Debug, Default)]
+struct Branch {
+    directories: BTreeMap<String, Branch>,
+    projects: Vec<TreeLeaf>,
+}
+
+/// An entry of a directory, in display order.
+enum Entry<'a> {
+    Directory(&'a str, &'a Branch),
+    Project(&'a TreeLeaf),
+}
+
+impl Entry<'_> {
+    fn name(&self) -> &str {
+        match self {
+            Entry::Directory(name, _) => *name,
+            Entry::Project(leaf) => &leaf.name,
+        }
+    }
+}
+
+impl Branch {
+    fn insert(&mut self, directories: &[String], leaf: TreeLeaf) {
+        match directories.split_first() {
+            Some((first, rest)) => self
+                .directories
+                .entry(first.clone())
+                .or_default()
+                .insert(rest, leaf),
+            None => self.projects.push(leaf),
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.projects.len() + self.directories.values().map(Branch::count).sum::<usize>()
+    }
+
+    /// Directories and projects, sorted alphabetically regardless of their kind.
+    fn entries(&self) -> Vec<Entry<'_>> {
+        let mut entries: Vec<Entry> = self
+            .directories
+            .iter()
+            .map(|(name, branch)| Entry::Directory(name, branch))
+            .chain(self.projects.iter().map(Entry::Project))
+            .collect();
+        entries.sort_by(|a, b| {
+            a.name()
+                .to_lowercase()
+                .cmp(&b.name().to_lowercase())
+                .then_with(|| a.name().cmp(b.name()))
+        });
+        entries
+    }
+
+    fn children_json(&self) -> Vec<Value> {
+        self.entries()
+            .into_iter()
+            .map(|entry| match entry {
+                Entry::Directory(name, branch) => json!({
+                    "type": "directory",
+                    "name": name,
+                    "count": branch.count(),
+                    "children": branch.children_json(),
+                }),
+                Entry::Project(leaf) => leaf.to_json(),
+            })
+            .collect()
+    }
+
+    /// Append the lines of the entries, drawn as the last siblings unless `more` entries
+    /// follow them.
+    fn render(&self, prefix: &str, more: bool, lines: &mut Vec<String>) {
+        let entries = self.entries();
+        let last = entries.len().saturating_sub(1);
+        for (i, entry) in entries.into_iter().enumerate() {
+            let (connector, indent) = if i == last && !more {
+                ("└── ", "    ")
+            } else {
+                ("├── ", "│   ")
+            };
+            match entry {
+                Entry::Directory(name, branch) => {
+                    lines.push(format!(
+                        "{}{}{} ({})",
+                        prefix,
+                        connector,
+                        name,
+                        branch.count()
+                    ));
+                    branch.render(&format!("{}{}", prefix, indent), false, lines);
+                }
+                Entry::Project(leaf) => {
+                    lines.push(format!("{}{}{}", prefix, connector, leaf.label()));
+                }
+            }
+        }
+    }
+}
+
+/// Projects grouped by directory below a root directory.
+#[derive(Debug)]
+pub struct PathTree {
+    root: Vec<String>,
+    separator: char,
+    branch: Branch,
+    other: Vec<TreeLeaf>,
+}
+
+impl PathTree {
+    /// Group `leaves` below `root`, or when `None` below the deepest common ancestor of
+    /// their directories.
+    pub fn build(leaves: Vec<TreeLeaf>, root: Option<&str>) -> Self {
+        let separator = root
+            .into_iter()
+            .chain(leaves.iter().map(|leaf| leaf.path.as_str()))
+            .find_map(|path| path.chars().find(|c| *c == '/' || *c == '\\'))
+            .unwrap_or('/');
+
+        let split: Vec<(Vec<String>, TreeLeaf)> = leaves
+            .into_iter()
+            .map(|leaf| (path_components(&leaf.path), leaf))
+            .collect();
+
+        let root = match root {
+            Some(root) => path_components(root),
+            None => common_ancestor(&split),
+        };
+
+        let mut branch = Branch::default();
+        let mut other = Vec::new();
+        for (components, leaf) in split {
+            match components.strip_prefix(root.as_slice()) {
+                Some(relative) => {
+                    let directories = &relative[..relative.len().saturating_sub(1)];
+                    branch.insert(directories, leaf);
+                }
+                None => other.push(leaf),
+            }
+        }
+        other.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+
+        Self {
+            root,
+            separator,
+            branch,
+            other,
+        }
+    }
+
+    /// The root directory, or `None` when the projects have no common ancestor.
+    pub fn root(&self) -> Option<String> {
+        match self.root.split_first() {
+            None => None,
+            Some((first, rest)) if first == "/" => {
+                Some(format!("/{}", rest.join(&self.separator.to_string())))
+            }
+            Some(_) => Some(self.root.join(&self.separator.to_string())),
+        }
+    }
+
+    /// Number of projects in the tree, including those outside the root.
+    pub fn len(&self) -> usize {
+        self.branch.count() + self.other.len()
+    }
+
+    /// Whether the tree holds no project.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The tree as nested JSON objects, each directory with its `count` of projects.
+    pub fn to_json(&self) -> Value {
+        json!({
+            "root": self.root(),
+            "count": self.len(),
+            "children": self.branch.children_json(),
+            "other": {
+                "count": self.other.len(),
+                "projects": self.other.iter().map(TreeLeaf::to_json).collect::<Vec<_>>(),
+            },
+        })
+    }
+
+    /// The tree as indented text, one line per directory and project. Projects outside
+    /// the root are listed with their path under a last "other" branch.
+    pub fn render(&self) -> String {
+        let header = self
+            .root()
+            .unwrap_or_else(|| "Registered Projects".to_string());
+        let mut lines = vec![format!("{} ({})", header, self.len())];
+
+        self.branch.render("", !self.other.is_empty(), &mut lines);
+
+        if let Some((last, others)) = self.other.split_last() {
+            lines.push(format!("└── other ({})", self.other.len()));
+            for leaf in others {
+                lines.push(format!("    ├── {} ({})", leaf.label(), leaf.path));
+            }
+            lines.push(format!("    └── {} ({})", last.label(), last.path));
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Split `path` on both `/` and `\`. A leading separator is kept as a `/` component, so
+/// `/studio` and `studio` differ.
+pub fn path_components(path: &str) -> Vec<String> {
+    let mut components = Vec::new();
+    if path.starts_with(['/', '\\']) {
+        components.push("/".to_string());
+    }
+    components.extend(
+        path.split(['/', '\\'])
+            .filter(|c| !c.is_empty() && *c != ".")
+            .map(str::to_string),
+    );
+    components
+}
+
+/// The longest directory prefix shared by the parent directories of every path.
+fn common_ancestor(split: &[(Vec<String>, TreeLeaf)]) -> Vec<String> {
+    let mut parents = split
+        .iter()
+        .map(|(components, _)| &components[..components.len().saturating_sub(1)]);
+    let Some(first) = parents.next() else {
+        return Vec::new();
+    };
+
+    let mut common = first.len();
+    for parent in parents {
+        common = first
+            .iter()
+            .zip(parent)
+            .take(common)
+            .take_while(|(a, b)| a == b)
+            .count();
+    }
+    first[..common].to_vec()
+}
@@ -0,0 +1,79 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Synchronized destination of the output implementations.
+//!
+//! Commands may report progress from several worker threads at once. Every output
+//! implementation writes through an [`OutputWriter`], which holds a mutex for the whole
+//! of each write so lines from concurrent calls never interleave.
+
+use std::fmt;
+use std::io::{self, Write};
+use std::sync::Mutex;
+
+/// A writer shared by the threads of a command, writing whole lines at a time.
+pub struct OutputWriter {
+    inner: Mutex<Box<dyn Write + Send>>,
+}
+
+impl OutputWriter {
+    /// Create a writer writing to `writer`.
+    pub fn new(writer: impl Write + Send + 'static) -> Self {
+        Self {
+            inner: Mutex::new(Box::new(writer)),
+        }
+    }
+
+    /// Create a writer writing to stdout.
+    pub fn stdout() -> Self {
+        Self::new(io::stdout())
+    }
+
+    /// Write `line` followed by a newline.
+    pub fn write_line(&self, line: &str) {
+        self.write_lines([line]);
+    }
+
+    /// Write each of `lines` followed by a newline, as a single block no concurrent
+    /// write can interleave with.
+    ///
+    /// Write errors are ignored, output methods can't report them.
+    pub fn write_lines<I, S>(&self, lines: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut block = String::new();
+        for line in lines {
+            block.push_str(line.as_ref());
+            block.push('\n');
+        }
+
+        let mut writer = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = writer.write_all(block.as_bytes());
+        let _ = writer.flush();
+    }
+}
+
+impl Default for OutputWriter {
+    fn default() -> Self {
+        Self::stdout()
+    }
+}
+
+impl fmt::Debug for OutputWriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OutputWriter").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,303 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Local cache of the SDK schemas.
+//!
+//! `am sdk schemas sync` copies the schema files of the SDK into
+//! `<data_dir>/schemas/<sdk-version>/` and records that version as the active one in the
+//! configuration table. Validation then loads the cached copy rather than reading the
+//! SDK installation, so it stays fast on network installs and keeps working while the
+//! SDK is unmounted. When the SDK reports a newer version than the cached one, the cache
+//! is still used and the user is warned to sync again.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use log::debug;
+
+use crate::common::errors::{CliError, codes};
+use crate::common::files::safe_remove_within;
+use crate::common::paths;
+use crate::common::update::parse_version;
+use crate::config::sdk::{SDK_VERSION_FILE, SdkLocation, discover_sdk};
+use crate::database::{Database, db_get_config};
+
+/// Configuration key holding the SDK version of the active schema cache.
+pub const CONFIG_SCHEMA_CACHE_VERSION: &str = "schema_cache_version";
+
+/// Extensions of the schema files copied from the SDK.
+pub const SCHEMA_FILE_EXTENSIONS: &[&str] = &["bfbs", "json"];
+
+/// The cached schemas, one subdirectory per SDK version.
+#[derive(Debug, Clone)]
+pub struct SchemaCache {
+    dir: PathBuf,
+}
+
+/// The result of [`SchemaCache::sync`].
+#[derive(Debug, Clone)]
+pub struct SyncedSchemas {
+    /// Version of the SDK the schemas were copied from.
+    pub version: String,
+    /// Directory the schemas were copied to.
+    pub dir: PathBuf,
+    /// Names of the copied files, sorted.
+    pub files: Vec<String>,
+}
+
+impl SchemaCache {
+    /// A cache stored in `dir`, which doesn't need to exist yet.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The cache of the data directory of the current process.
+    pub fn open() -> Result<Self> {
+        Ok(Self::new(paths::resolve()?.schemas_dir()))
+    }
+
+    /// Directory of the cache.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Directory of the schemas cached for the SDK `version`.
+    pub fn version_dir(&self, version: &str) -> PathBuf {
+        self.dir.join(version)
+    }
+
+    /// Whether schemas are cached for the SDK `version`.
+    pub fn has_version(&self, version: &str) -> bool {
+        is_valid_version(version) && self.version_dir(version).is_dir()
+    }
+
+    /// The cached SDK versions, oldest first.
+    pub fn versions(&self) -> Result<Vec<String>> {
+        if !self.dir.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        let mut versions = Vec::new();
+        for entry in fs::read_dir(&self.dir)
+            .with_context(|| format!("Failed to read {}", self.dir.display()))?
+        {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().to_string();
+            if entry.file_type()?.is_dir() && is_valid_version(&name) {
+                versions.push(name);
+            }
+        }
+
+        versions.sort_by_key(|version| parse_version(version));
+        Ok(versions)
+    }
+
+    /// Copy the schema files of `sdk` into the directory of its version, replacing any
+    /// previous copy of that version.
+    ///
+    /// The files are copied to a staging directory first, so an interrupted sync never
+    /// leaves a partial version behind.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ERR_SDK_VERSION_UNKNOWN` error if the SDK doesn't report a usable
+    /// version, an `ERR_SDK_SCHEMA_LOAD_FAILED` error if it has no schema file, or the
+    /// underlying I/O error.
+    pub fn sync(&self, sdk: &SdkLocation) -> Result<SyncedSchemas> {
+        let version = sdk_version(sdk)?;
+
+        let mut sources = Vec::new();
+        for entry in fs::read_dir(sdk.schemas_dir())
+            .with_context(|| format!("Failed to read {}", sdk.schemas_dir().display()))?
+        {
+            let path = entry?.path();
+            if path.is_file()
+                && path
+                    .extension()
+                    .is_some_and(|ext| SCHEMA_FILE_EXTENSIONS.iter().any(|e| ext == *e))
+            {
+                sources.push(path);
+            }
+        }
+        sources.sort();
+
+        if sources.is_empty() {
+            return Err(CliError::new(
+                codes::ERR_SDK_SCHEMA_LOAD_FAILED,
+                "No schema files to cache",
+                format!(
+                    "The schemas directory of the SDK has no .bfbs or .json file: {}",
+                    sdk.schemas_dir().display()
+                ),
+            )
+            .into());
+        }
+
+        fs::create_dir_all(&self.dir)
+            .with_context(|| format!("Failed to create {}", self.dir.display()))?;
+
+        let staging = self.dir.join(format!(".{}.partial", version));
+        if staging.exists() {
+            safe_remove_within(&self.dir, &staging)?;
+        }
+        fs::create_dir(&staging)
+            .with_context(|| format!("Failed to create {}", staging.display()))?;
+
+        let mut files = Vec::with_capacity(sources.len());
+        for source in &sources {
+            let name = source.file_name().unwrap_or_default();
+            fs::copy(source, staging.join(name))
+                .with_context(|| format!("Failed to copy {}", source.display()))?;
+            files.push(name.to_string_lossy().to_string());
+        }
+
+        let dir = self.version_dir(&version);
+        if dir.exists() {
+            safe_remove_within(&self.dir, &dir)?;
+        }
+        fs::rename(&staging, &dir)
+            .with_context(|| format!("Failed to move the schemas to {}", dir.display()))?;
+
+        Ok(SyncedSchemas {
+            version,
+            dir,
+            files,
+        })
+    }
+
+    /// Delete every cached version. Returns the deleted versions, oldest first.
+    pub fn clear(&self) -> Result<Vec<String>> {
+        let versions = self.versions()?;
+        if !self.dir.is_dir() {
+            return Ok(versions);
+        }
+
+        for entry in fs::read_dir(&self.dir)
+            .with_context(|| format!("Failed to read {}", self.dir.display()))?
+        {
+            safe_remove_within(&self.dir, &entry?.path())?;
+        }
+
+        Ok(versions)
+    }
+}
+
+/// The version reported by `sdk`, checked to be usable as a directory name.
+fn sdk_version(sdk: &SdkLocation) -> Result<String, CliError> {
+    let version = sdk.version().ok_or_else(|| {
+        CliError::new(
+            codes::ERR_SDK_VERSION_UNKNOWN,
+            "Cannot cache the SDK schemas",
+            format!(
+                "The SDK doesn't report its version: no {} file in {}",
+                SDK_VERSION_FILE,
+                sdk.root().display()
+            ),
+        )
+    })?;
+
+    if !is_valid_version(&version) {
+        return Err(CliError::new(
+            codes::ERR_SDK_VERSION_UNKNOWN,
+            "Cannot cache the SDK schemas",
+            format!("The SDK reports an invalid version: '{}'", version),
+        )
+        .with_context(sdk.root().join(SDK_VERSION_FILE).display().to_string()));
+    }
+
+    Ok(version)
+}
+
+/// Whether `version` is a `major.minor.patch` version that can name a cache directory.
+fn is_valid_version(version: &str) -> bool {
+    parse_version(version).is_some()
+        && version
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+'))
+}
+
+/// Whether `version` is newer than `than`. Versions that can't be parsed are never newer.
+pub fn is_newer_version(version: &str, than: &str) -> bool {
+    match (parse_version(version), parse_version(than)) {
+        (Some(version), Some(than)) => version > than,
+        _ => false,
+    }
+}
+
+/// Where validation loads the schemas from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaSelection {
+    /// Directory of the schema files.
+    pub schemas_dir: PathBuf,
+    /// SDK version of the cached schemas, or `None` when they are loaded from the SDK.
+    pub cached_version: Option<String>,
+    /// Version reported by the SDK when it is newer than the cached schemas.
+    pub newer_sdk_version: Option<String>,
+}
+
+/// Choose between the cached schemas of `active_version` and the schemas of `sdk`.
+///
+/// The cache wins when it holds `active_version`, even if `sdk` reports a newer version,
+/// which is then returned in [`SchemaSelection::newer_sdk_version`]. Returns `None` when
+/// there are neither cached schemas nor an SDK.
+pub fn select_schemas(
+    cache: &SchemaCache,
+    active_version: Option<&str>,
+    sdk: Option<&SdkLocation>,
+) -> Option<SchemaSelection> {
+    if let Some(active) = active_version
+        && cache.has_version(active)
+    {
+        let newer_sdk_version = sdk
+            .and_then(|sdk| sdk.version())
+            .filter(|version| is_newer_version(version, active));
+
+        return Some(SchemaSelection {
+            schemas_dir: cache.version_dir(active),
+            cached_version: Some(active.to_string()),
+            newer_sdk_version,
+        });
+    }
+
+    sdk.map(|sdk| SchemaSelection {
+        schemas_dir: sdk.schemas_dir().to_path_buf(),
+        cached_version: None,
+        newer_sdk_version: None,
+    })
+}
+
+/// [`select_schemas`] for the current process: the cache of the data directory, the
+/// active version recorded in `database` and the discovered SDK.
+///
+/// Without a database or a data directory, only the SDK is considered.
+pub fn resolve_schemas(database: Option<Arc<Database>>) -> Option<SchemaSelection> {
+    let active_version = match database {
+        Some(_) => db_get_config(CONFIG_SCHEMA_CACHE_VERSION, database).unwrap_or_else(|e| {
+            debug!("Failed to read the active schema cache version: {}", e);
+            None
+        }),
+        None => None,
+    };
+    let sdk = discover_sdk().ok();
+
+    match SchemaCache::open() {
+        Ok(cache) => select_schemas(&cache, active_version.as_deref(), sdk.as_ref()),
+        Err(e) => {
+            debug!("Failed to locate the schema cache: {}", e);
+            select_schemas(&SchemaCache::new(PathBuf::new()), None, sdk.as_ref())
+        }
+    }
+}
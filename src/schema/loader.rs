@@ -158,8 +158,17 @@ fn leaf_name(fqn: &str) -> &str {
 /// Returns `Err` only for I/O failures that prevent scanning the schemas
 /// directory entirely (e.g., permission denied).
 pub fn load_schemas(sdk: &SdkLocation, output: &dyn Output) -> Result<SchemaRegistry, CliError> {
-    let schemas_dir = sdk.schemas_dir();
+    load_schemas_from(sdk.schemas_dir(), output)
+}
 
+/// Load all schemas from `schemas_dir`, like [`load_schemas`].
+///
+/// Used for the schemas cached by `am sdk schemas sync`, which aren't in an SDK
+/// installation.
+pub fn load_schemas_from(
+    schemas_dir: &Path,
+    output: &dyn Output,
+) -> Result<SchemaRegistry, CliError> {
     let entries = fs::read_dir(schemas_dir).map_err(|e| {
         CliError::new(
             codes::ERR_SDK_SCHEMA_LOAD_FAILED,
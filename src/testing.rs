@@ -0,0 +1,709 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration-test harness for the Amplitude CLI.
+//!
+//! This module is only compiled with the `test-util` feature. It gives test suites
+//! (both in this repository and downstream) a single place to get:
+//!
+//! - [`TestEnv`]: an isolated Amplitude home directory with a migrated [`Database`]
+//! - [`TestEnv::scaffold_project`]: a valid project directory with N assets per type
+//! - [`MockOutput`] / [`ScriptedInput`]: test doubles for the [`Output`] and [`Input`] traits
+//! - [`TestEnv::run`] / [`TestEnv::run_in`]: invoke a command handler and capture every output call
+//!
+//! # Example
+//! ```ignore
+//! let env = TestEnv::new().await?;
+//! let project = env.scaffold_project("demo", 2)?;
+//! let run = env
+//!     .run(
+//!         &ProjectCommands::Register {
+//!             path: Some(project),
+//!             recursive: false,
+//!             rename_on_conflict: false,
+//!             force: false,
+//!             name: None,
+//!             stdin: false,
+//!             strict: false,
+//!         },
+//!         ScriptedInput::new(),
+//!     )
+//!     .await;
+//! assert!(run.result.is_ok());
+//! assert_eq!(run.successes().len(), 1);
+//! ```
+
+use crate::assets::{
+    Collection, Effect, Event, EventActionType, Sound, Soundbank, Switch, SwitchContainer,
+};
+use crate::common::files::to_json_pretty;
+use crate::common::ignore::WalkOptions;
+use crate::common::utils::{ASSET_DIRECTORIES, write_amproject_file};
+use crate::database::Database;
+use crate::database::entities::ProjectConfiguration;
+use crate::input::{
+    Input, PromptBlocked, SelectOption, TextValidator, run_validators, validation_message,
+};
+use crate::presentation::{ColumnSpec, Output, OutputMode};
+use anyhow::{Result, anyhow};
+use inquire::validator::Validation;
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tempfile::TempDir;
+
+/// Serializes [`TestEnv::run_in`] calls, since the working directory is process-wide.
+static CWD_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+// =============================================================================
+// Test Environment
+// =============================================================================
+
+/// An isolated Amplitude environment backed by a temporary directory.
+///
+/// The temporary directory plays the role of `AM_HOME`: it holds the migrated `am.db`
+/// database and, by default, any project scaffolded through this environment. Everything
+/// is removed when the `TestEnv` is dropped.
+pub struct TestEnv {
+    home: TempDir,
+    database: Arc<Database>,
+}
+
+impl TestEnv {
+    /// Create a new environment with a freshly migrated database.
+    pub async fn new() -> Result<Self> {
+        let home = tempfile::tempdir()?;
+        let mut database = Database::new(home.path().join("am.db"))?;
+        database.run_migrations().await?;
+
+        Ok(Self {
+            home,
+            database: Arc::new(database),
+        })
+    }
+
+    /// The isolated Amplitude home directory (the value to use for `AM_HOME`).
+    pub fn home(&self) -> &Path {
+        self.home.path()
+    }
+
+    /// Environment variables to pass to a spawned `am` process so it uses this environment.
+    pub fn env_vars(&self) -> Vec<(&'static str, PathBuf)> {
+        vec![("AM_HOME", self.home.path().to_path_buf())]
+    }
+
+    /// The migrated database of this environment.
+    pub fn database(&self) -> Arc<Database> {
+        self.database.clone()
+    }
+
+    /// The database wrapped in `Some`, as expected by command handlers and CRUD helpers.
+    pub fn db(&self) -> Option<Arc<Database>> {
+        Some(self.database.clone())
+    }
+
+    /// Write a `.amproject` file and the standard directory layout under `home/<name>`,
+    /// without any assets.
+    ///
+    /// Returns the project root directory.
+    pub fn create_project_dir(&self, name: &str) -> Result<PathBuf> {
+        let root = self.home.path().join(name);
+        let config = Self::project_configuration(name);
+
+        for dir in ASSET_DIRECTORIES {
+            fs::create_dir_all(root.join(&config.sources_dir).join(dir))?;
+        }
+        fs::create_dir_all(root.join(&config.data_dir))?;
+        fs::create_dir_all(root.join(&config.build_dir))?;
+        write_amproject_file(&root, &config)?;
+
+        Ok(root)
+    }
+
+    /// Scaffold a project under `home/<name>` that passes `am project validate`, with
+    /// `assets_per_type` assets of every asset type.
+    ///
+    /// Sounds get an empty audio file in `data/`, events play the sound of the same index,
+    /// switch containers are driven by the switch of the same index, and soundbanks
+    /// include the sound of the same index.
+    ///
+    /// Returns the project root directory.
+    pub fn scaffold_project(&self, name: &str, assets_per_type: usize) -> Result<PathBuf> {
+        let root = self.create_project_dir(name)?;
+        let sources = root.join("sources");
+
+        for i in 1..=assets_per_type as u64 {
+            let sound_name = format!("sound_{}", i);
+            let audio_file = format!("{}.wav", sound_name);
+            fs::write(root.join("data").join(&audio_file), b"")?;
+
+            let sound = Sound::builder(1000 + i, &sound_name)
+                .path(&audio_file)
+                .build();
+            write_asset(&sources, "sounds", &sound_name, &sound)?;
+
+            let collection_name = format!("collection_{}", i);
+            let collection = Collection::builder(2000 + i, &collection_name).build();
+            write_asset(&sources, "collections", &collection_name, &collection)?;
+
+            let effect_name = format!("effect_{}", i);
+            let effect = Effect::builder(3000 + i, &effect_name).build();
+            write_asset(&sources, "effects", &effect_name, &effect)?;
+
+            let switch_name = format!("switch_{}", i);
+            let switch = Switch::builder(4000 + i, &switch_name)
+                .state(4100 + i, "default")
+                .build();
+            write_asset(&sources, "switches", &switch_name, &switch)?;
+
+            let container_name = format!("switch_container_{}", i);
+            let container = SwitchContainer::builder(5000 + i, &container_name)
+                .switch_group(4000 + i)
+                .default_state(4100 + i)
+                .build();
+            write_asset(&sources, "switch_containers", &container_name, &container)?;
+
+            let event_name = format!("event_{}", i);
+            let event = Event::builder(6000 + i, &event_name)
+                .action(EventActionType::Play, vec![1000 + i])
+                .build();
+            write_asset(&sources, "events", &event_name, &event)?;
+
+            let soundbank_name = format!("soundbank_{}", i);
+            let soundbank = Soundbank::builder(7000 + i, &soundbank_name)
+                .sound(format!("{}.json", sound_name))
+                .build();
+            write_asset(&sources, "soundbanks", &soundbank_name, &soundbank)?;
+        }
+
+        Ok(root)
+    }
+
+    /// The `.amproject` configuration written by [`TestEnv::create_project_dir`].
+    pub fn project_configuration(name: &str) -> ProjectConfiguration {
+        ProjectConfiguration {
+            name: name.to_string(),
+            default_configuration: "pc.config.amconfig".to_string(),
+            build_dir: "build".to_string(),
+            data_dir: "data".to_string(),
+            sources_dir: "sources".to_string(),
+            version: 1,
+            hooks: Default::default(),
+            allow_json_comments: false,
+            asset_dirs: Default::default(),
+        }
+    }
+
+    /// Run a command handler against this environment's database in JSON output mode.
+    pub async fn run<C: CommandHandler>(&self, command: &C, input: ScriptedInput) -> CommandRun {
+        self.run_with_mode(command, input, OutputMode::Json).await
+    }
+
+    /// Run a command handler with an explicit output mode.
+    pub async fn run_with_mode<C: CommandHandler>(
+        &self,
+        command: &C,
+        input: ScriptedInput,
+        mode: OutputMode,
+    ) -> CommandRun {
+        self.dispatch(command, None, input, mode).await
+    }
+
+    /// Run a command handler on the project at `project`, as given with `--project`.
+    pub async fn run_with_project<C: CommandHandler>(
+        &self,
+        command: &C,
+        project: &Path,
+        input: ScriptedInput,
+    ) -> CommandRun {
+        self.dispatch(command, Some(project), input, OutputMode::Json)
+            .await
+    }
+
+    async fn dispatch<C: CommandHandler>(
+        &self,
+        command: &C,
+        project: Option<&Path>,
+        input: ScriptedInput,
+        mode: OutputMode,
+    ) -> CommandRun {
+        let output = MockOutput::new(mode);
+        let result = command
+            .dispatch(project, WalkOptions::default(), self.db(), &input, &output)
+            .await;
+
+        CommandRun {
+            result,
+            calls: output.calls(),
+            unused_responses: input.remaining(),
+        }
+    }
+
+    /// Run a command handler with the process working directory set to `dir`.
+    ///
+    /// Most project and asset commands operate on the current directory. Calls to this
+    /// method are serialized and the previous working directory is restored afterwards.
+    pub async fn run_in<C: CommandHandler>(
+        &self,
+        dir: &Path,
+        command: &C,
+        input: ScriptedInput,
+    ) -> CommandRun {
+        self.run_in_with_mode(dir, command, input, OutputMode::Json)
+            .await
+    }
+
+    /// Run a command handler in `dir` with an explicit output mode.
+    pub async fn run_in_with_mode<C: CommandHandler>(
+        &self,
+        dir: &Path,
+        command: &C,
+        input: ScriptedInput,
+        mode: OutputMode,
+    ) -> CommandRun {
+        let _guard = CWD_LOCK.lock().await;
+        let previous = std::env::current_dir().ok();
+
+        if let Err(e) = std::env::set_current_dir(dir) {
+            return CommandRun {
+                result: Err(anyhow!(e).context(format!("Cannot enter {}", dir.display()))),
+                calls: Vec::new(),
+                unused_responses: input.remaining(),
+            };
+        }
+
+        let run = self.run_with_mode(command, input, mode).await;
+
+        if let Some(previous) = previous {
+            let _ = std::env::set_current_dir(previous);
+        }
+
+        run
+    }
+}
+
+/// Serialize an asset to `sources/<dir>/<name>.json`.
+fn write_asset<T: Serialize>(sources: &Path, dir: &str, name: &str, asset: &T) -> Result<()> {
+    fs::write(
+        sources.join(dir).join(format!("{}.json", name)),
+        to_json_pretty(asset)?,
+    )?;
+    Ok(())
+}
+
+// =============================================================================
+// Command Runner
+// =============================================================================
+
+pub use crate::commands::dispatch::{CommandHandler, HandlerFuture};
+
+/// The outcome of a handler invocation.
+#[derive(Debug)]
+pub struct CommandRun {
+    /// The value returned by the handler.
+    pub result: Result<()>,
+    /// Every call made to the output, in order.
+    pub calls: Vec<OutputCall>,
+    /// Scripted responses the handler never asked for.
+    pub unused_responses: usize,
+}
+
+impl CommandRun {
+    /// All data passed to `Output::success`.
+    pub fn successes(&self) -> Vec<&serde_json::Value> {
+        self.calls
+            .iter()
+            .filter_map(|c| match c {
+                OutputCall::Success(data) => Some(data),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The last data passed to `Output::success`, if any.
+    pub fn last_success(&self) -> Option<&serde_json::Value> {
+        self.successes().pop()
+    }
+
+    /// All messages passed to `Output::warning`.
+    pub fn warnings(&self) -> Vec<&str> {
+        self.calls
+            .iter()
+            .filter_map(|c| match c {
+                OutputCall::Warning(message) => Some(message.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// All tables passed to `Output::table`.
+    pub fn tables(&self) -> Vec<&serde_json::Value> {
+        self.calls
+            .iter()
+            .filter_map(|c| match c {
+                OutputCall::Table { data, .. } => Some(data),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The error code of the handler's error, if it returned a `CliError`.
+    pub fn error_code(&self) -> Option<i32> {
+        self.result
+            .as_ref()
+            .err()
+            .and_then(|e| e.downcast_ref::<crate::common::errors::CliError>())
+            .map(|e| e.code)
+    }
+}
+
+// =============================================================================
+// Mock Output
+// =============================================================================
+
+/// A single call recorded by [`MockOutput`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum OutputCall {
+    Success(serde_json::Value),
+    Error {
+        message: String,
+        code: i32,
+    },
+    Progress(String),
+    ProgressWith {
+        message: String,
+        current: usize,
+        total: usize,
+    },
+    Print(String),
+    Warning(String),
+    Table {
+        title: Option<String>,
+        /// Keys of the columns, those of the first row unless given explicitly.
+        columns: Vec<String>,
+        data: serde_json::Value,
+    },
+    BeginStages(Vec<String>),
+    EnterStage(usize),
+    ListTruncated {
+        shown: usize,
+        total: usize,
+    },
+}
+
+/// An [`Output`] implementation that records every call instead of printing.
+pub struct MockOutput {
+    mode: OutputMode,
+    calls: Mutex<Vec<OutputCall>>,
+}
+
+impl MockOutput {
+    /// Create a new mock output reporting the given mode.
+    pub fn new(mode: OutputMode) -> Self {
+        Self {
+            mode,
+            calls: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// A copy of every call recorded so far.
+    pub fn calls(&self) -> Vec<OutputCall> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    fn record(&self, call: OutputCall) {
+        self.calls.lock().unwrap().push(call);
+    }
+}
+
+impl Output for MockOutput {
+    fn success(&self, data: serde_json::Value, _request_id: Option<i64>) {
+        self.record(OutputCall::Success(data));
+    }
+
+    fn error(&self, err: &anyhow::Error, code: i32, _request_id: Option<i64>) {
+        self.record(OutputCall::Error {
+            message: err.to_string(),
+            code,
+        });
+    }
+
+    fn progress(&self, message: &str) {
+        self.record(OutputCall::Progress(message.to_string()));
+    }
+
+    fn progress_with(&self, message: &str, current: usize, total: usize) {
+        self.record(OutputCall::ProgressWith {
+            message: message.to_string(),
+            current,
+            total,
+        });
+    }
+
+    fn print(&self, message: &str) {
+        self.record(OutputCall::Print(message.to_string()));
+    }
+
+    fn warning(&self, message: &str) {
+        self.record(OutputCall::Warning(message.to_string()));
+    }
+
+    fn table(&self, title: Option<&str>, data: serde_json::Value) {
+        let columns = ColumnSpec::from_rows(&data);
+        self.table_with_columns(title, &columns, data);
+    }
+
+    fn table_with_columns(
+        &self,
+        title: Option<&str>,
+        columns: &[ColumnSpec],
+        rows: serde_json::Value,
+    ) {
+        self.record(OutputCall::Table {
+            title: title.map(|s| s.to_string()),
+            columns: columns.iter().map(|c| c.key.clone()).collect(),
+            data: rows,
+        });
+    }
+
+    fn mode(&self) -> OutputMode {
+        self.mode
+    }
+
+    fn begin_stages(&self, stages: &[&str]) {
+        self.record(OutputCall::BeginStages(
+            stages.iter().map(|s| s.to_string()).collect(),
+        ));
+    }
+
+    fn enter_stage(&self, index: usize) {
+        self.record(OutputCall::EnterStage(index));
+    }
+
+    fn list_truncated(&self, shown: usize, total: usize) {
+        self.record(OutputCall::ListTruncated { shown, total });
+    }
+}
+
+// =============================================================================
+// Scripted Input
+// =============================================================================
+
+/// A response queued in a [`ScriptedInput`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptedResponse {
+    /// Answer to `prompt_text` or `prompt_text_with_default`.
+    Text(String),
+    /// Answer to `select`; must match one of the offered options. For `select_rich`, may
+    /// also be the position of the option in the offered list.
+    Select(String),
+    /// Answer to `confirm`.
+    Confirm(bool),
+    /// Answer to `multi_select`; every item must match an offered option.
+    MultiSelect(Vec<String>),
+}
+
+/// An [`Input`] implementation that answers prompts from a queue of scripted responses.
+///
+/// Prompts fail when the queue is empty or when the next response does not match the
+/// kind of prompt being asked, so unexpected prompts surface as handler errors.
+#[derive(Default)]
+pub struct ScriptedInput {
+    responses: Mutex<VecDeque<ScriptedResponse>>,
+    non_interactive: bool,
+    stdin: Option<String>,
+}
+
+impl ScriptedInput {
+    /// Create an input with no scripted responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a text answer.
+    pub fn text(self, value: impl Into<String>) -> Self {
+        self.push(ScriptedResponse::Text(value.into()))
+    }
+
+    /// Queue a selection answer.
+    pub fn select(self, value: impl Into<String>) -> Self {
+        self.push(ScriptedResponse::Select(value.into()))
+    }
+
+    /// Queue a confirmation answer.
+    pub fn confirm(self, value: bool) -> Self {
+        self.push(ScriptedResponse::Confirm(value))
+    }
+
+    /// Queue a multi-selection answer.
+    pub fn multi_select(self, values: Vec<String>) -> Self {
+        self.push(ScriptedResponse::MultiSelect(values))
+    }
+
+    /// Report the input as non-interactive, like `--non-interactive` or `--json` would.
+    ///
+    /// Prompts left without scripted response then fail with the [`PromptBlocked`] error of
+    /// `NonInteractiveInput`.
+    pub fn non_interactive(mut self) -> Self {
+        self.non_interactive = true;
+        self
+    }
+
+    /// Content returned by [`Input::read_stdin`].
+    pub fn stdin(mut self, content: impl Into<String>) -> Self {
+        self.stdin = Some(content.into());
+        self
+    }
+
+    /// Number of responses not consumed yet.
+    pub fn remaining(&self) -> usize {
+        self.responses.lock().unwrap().len()
+    }
+
+    fn push(self, response: ScriptedResponse) -> Self {
+        self.responses.lock().unwrap().push_back(response);
+        self
+    }
+
+    fn next(&self, kind: &'static str, prompt: &str) -> Result<ScriptedResponse> {
+        let response = self.responses.lock().unwrap().pop_front();
+        match response {
+            Some(response) => Ok(response),
+            // Like `NonInteractiveInput`, so tests see the error users get
+            None if self.non_interactive => Err(PromptBlocked::new(kind, prompt).into()),
+            None => Err(anyhow!(
+                "Unexpected {} '{}': no scripted response left",
+                kind,
+                prompt
+            )),
+        }
+    }
+
+    fn next_text(&self, prompt: &str, validators: &[TextValidator<'_>]) -> Result<String> {
+        match self.next("text prompt", prompt)? {
+            ScriptedResponse::Text(value) => {
+                if let Validation::Invalid(reason) =
+                    run_validators(&value, validators).map_err(|e| anyhow!(e.to_string()))?
+                {
+                    return Err(anyhow!(
+                        "Scripted response '{}' for '{}' is invalid: {}",
+                        value,
+                        prompt,
+                        validation_message(&reason)
+                    ));
+                }
+                Ok(value)
+            }
+            other => Err(mismatch("text prompt", prompt, &other)),
+        }
+    }
+}
+
+fn mismatch(kind: &str, prompt: &str, response: &ScriptedResponse) -> anyhow::Error {
+    anyhow!(
+        "Expected a scripted response for {} '{}', found {:?}",
+        kind,
+        prompt,
+        response
+    )
+}
+
+impl Input for ScriptedInput {
+    fn prompt_text(
+        &self,
+        prompt: &str,
+        _placeholder: Option<&str>,
+        _formatter: Option<&dyn Fn(&str) -> String>,
+        validators: &[TextValidator<'_>],
+    ) -> Result<String> {
+        self.next_text(prompt, validators)
+    }
+
+    fn select(&self, prompt: &str, options: &[String]) -> Result<String> {
+        match self.next("selection", prompt)? {
+            ScriptedResponse::Select(value) if options.contains(&value) => Ok(value),
+            ScriptedResponse::Select(value) => Err(anyhow!(
+                "Scripted selection '{}' for '{}' is not one of {:?}",
+                value,
+                prompt,
+                options
+            )),
+            other => Err(mismatch("selection", prompt, &other)),
+        }
+    }
+
+    fn select_rich(&self, prompt: &str, options: &[SelectOption]) -> Result<usize> {
+        match self.next("selection", prompt)? {
+            ScriptedResponse::Select(value) => options
+                .iter()
+                .find(|o| o.label == value)
+                .or_else(|| value.parse::<usize>().ok().and_then(|i| options.get(i)))
+                .map(|o| o.value_index)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Scripted selection '{}' for '{}' is not one of {:?}",
+                        value,
+                        prompt,
+                        options.iter().map(|o| &o.label).collect::<Vec<_>>()
+                    )
+                }),
+            other => Err(mismatch("selection", prompt, &other)),
+        }
+    }
+
+    fn confirm(&self, prompt: &str, _default: Option<bool>) -> Result<bool> {
+        match self.next("confirmation", prompt)? {
+            ScriptedResponse::Confirm(value) => Ok(value),
+            other => Err(mismatch("confirmation", prompt, &other)),
+        }
+    }
+
+    fn prompt_text_with_default(
+        &self,
+        prompt: &str,
+        _default: &str,
+        validators: &[TextValidator<'_>],
+    ) -> Result<String> {
+        self.next_text(prompt, validators)
+    }
+
+    fn multi_select(&self, prompt: &str, options: &[String]) -> Result<Vec<String>> {
+        match self.next("multi-selection", prompt)? {
+            ScriptedResponse::MultiSelect(values) => {
+                if let Some(unknown) = values.iter().find(|v| !options.contains(v)) {
+                    return Err(anyhow!(
+                        "Scripted selection '{}' for '{}' is not one of {:?}",
+                        unknown,
+                        prompt,
+                        options
+                    ));
+                }
+                Ok(values)
+            }
+            other => Err(mismatch("multi-selection", prompt, &other)),
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        !self.non_interactive
+    }
+
+    fn read_stdin(&self) -> Result<String> {
+        self.stdin
+            .clone()
+            .ok_or_else(|| anyhow!("Unexpected stdin read: no scripted stdin content"))
+    }
+}
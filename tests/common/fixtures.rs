@@ -21,6 +21,7 @@ use std::sync::Arc;
 use tempfile::{TempDir, tempdir};
 
 use am::assets::ProjectValidator;
+use am::common::ignore::WalkOptions;
 use am::database::Database;
 
 /// Test fixture that provides an in-memory database for isolated testing.
@@ -93,7 +94,7 @@ impl MigratedDatabaseFixture {
 }
 
 /// Test fixture for isolated home directory operations.
-/// Useful for testing functions that use dirs::home_dir().
+/// Useful for testing functions that use `paths::user_home()`.
 pub struct IsolatedHomeFixture {
     _temp_dir: TempDir,
     amplitude_dir: PathBuf,
@@ -347,7 +348,11 @@ impl AssetTestFixture {
 
     /// Create a `ProjectValidator` that scans this fixture's project directory.
     pub fn create_project_validator(&self) -> anyhow::Result<ProjectValidator> {
-        ProjectValidator::new(self.project_root.clone())
+        ProjectValidator::new(
+            self.project_root.clone(),
+            WalkOptions::default(),
+            &CaptureOutput::json(),
+        )
     }
 
     /// Create an empty file in the `data/` directory.
@@ -462,6 +467,14 @@ impl Output for CaptureOutput {
             .push(message.to_string());
     }
 
+    fn print(&self, _message: &str) {
+        // Not needed for these tests
+    }
+
+    fn warning(&self, _message: &str) {
+        // Not needed for these tests
+    }
+
     fn table(&self, title: Option<&str>, data: serde_json::Value) {
         self.tables
             .write()
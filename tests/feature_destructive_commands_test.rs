@@ -0,0 +1,202 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Feature tests for the gate of destructive commands in non-interactive sessions.
+//!
+//! Tests cover:
+//! - Refusing `am sudo database reset --yes` and `import --yes` without the explicit flag
+//! - Allowing them with `--i-know-what-im-doing` or `AM_ALLOW_DESTRUCTIVE=1`
+
+use am::commands::sudo::ALLOW_DESTRUCTIVE_ENV;
+use am::common::errors::codes;
+use am::database::{db_get_config, db_set_config};
+use am::testing::TestEnv;
+use std::process::{Command, Output};
+
+/// Run `am <args>` with the home of `env` and the given extra environment variables.
+fn run_am(env: &TestEnv, args: &[&str], vars: &[(&str, &str)]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_am"))
+        .args(args)
+        .current_dir(env.home())
+        .envs(env.env_vars())
+        .env_remove("AM_SDK_PATH")
+        .env_remove(ALLOW_DESTRUCTIVE_ENV)
+        .envs(vars.iter().copied())
+        .output()
+        .expect("Failed to execute command")
+}
+
+fn envelope(output: &Output) -> serde_json::Value {
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    serde_json::from_str(stdout.trim()).unwrap_or_else(|e| {
+        panic!("Expected a JSON envelope ({}), got: {}", e, stdout);
+    })
+}
+
+/// A TestEnv whose registry holds a marker setting, to check it survives a refusal.
+async fn setup_env() -> TestEnv {
+    let env = TestEnv::new()
+        .await
+        .expect("Failed to create test environment");
+    db_set_config("marker", "kept", "string", env.db()).unwrap();
+    env
+}
+
+// =============================================================================
+// Refusal Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_reset_with_only_yes_is_refused_non_interactively() {
+    let env = setup_env().await;
+
+    let output = run_am(
+        &env,
+        &["--non-interactive", "sudo", "database", "reset", "--yes"],
+        &[],
+    );
+
+    assert_eq!(
+        output.status.code(),
+        Some(1),
+        "Expected exit code 1, stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let message = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(message.contains("--i-know-what-im-doing"), "{}", message);
+    assert!(message.contains(ALLOW_DESTRUCTIVE_ENV), "{}", message);
+    assert_eq!(
+        db_get_config("marker", env.db()).unwrap().as_deref(),
+        Some("kept")
+    );
+}
+
+#[tokio::test]
+async fn test_p0_reset_refusal_reports_error_code_in_json() {
+    let env = setup_env().await;
+
+    let output = run_am(&env, &["--json", "sudo", "database", "reset", "--yes"], &[]);
+
+    assert_eq!(output.status.code(), Some(1));
+    let envelope = envelope(&output);
+    assert_eq!(
+        envelope["error"]["code"],
+        codes::ERR_DESTRUCTIVE_NOT_ALLOWED
+    );
+    assert_eq!(
+        db_get_config("marker", env.db()).unwrap().as_deref(),
+        Some("kept")
+    );
+}
+
+#[tokio::test]
+async fn test_p0_import_with_only_yes_is_refused_non_interactively() {
+    let env = setup_env().await;
+    let file = env.home().join("registry.json");
+    let exported = run_am(
+        &env,
+        &[
+            "--json",
+            "sudo",
+            "database",
+            "export",
+            file.to_str().unwrap(),
+        ],
+        &[],
+    );
+    assert_eq!(exported.status.code(), Some(0));
+
+    let output = run_am(
+        &env,
+        &[
+            "--json",
+            "sudo",
+            "database",
+            "import",
+            file.to_str().unwrap(),
+            "--yes",
+        ],
+        &[],
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        envelope(&output)["error"]["code"],
+        codes::ERR_DESTRUCTIVE_NOT_ALLOWED
+    );
+}
+
+#[tokio::test]
+async fn test_p2_allow_destructive_env_requires_exactly_one() {
+    let env = setup_env().await;
+
+    let output = run_am(
+        &env,
+        &["--json", "sudo", "database", "reset", "--yes"],
+        &[(ALLOW_DESTRUCTIVE_ENV, "yes")],
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+}
+
+// =============================================================================
+// Explicit Permission Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_reset_runs_with_explicit_flag() {
+    let env = setup_env().await;
+
+    let output = run_am(
+        &env,
+        &[
+            "--json",
+            "sudo",
+            "database",
+            "reset",
+            "--yes",
+            "--i-know-what-im-doing",
+        ],
+        &[],
+    );
+
+    assert_eq!(
+        output.status.code(),
+        Some(0),
+        "Expected exit code 0, stdout: {}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[tokio::test]
+async fn test_p1_reset_runs_with_allow_destructive_env() {
+    let env = setup_env().await;
+
+    let output = run_am(
+        &env,
+        &["--json", "sudo", "database", "reset", "--yes"],
+        &[(ALLOW_DESTRUCTIVE_ENV, "1")],
+    );
+
+    assert_eq!(
+        output.status.code(),
+        Some(0),
+        "Expected exit code 0, stdout: {}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
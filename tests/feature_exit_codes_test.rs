@@ -250,6 +250,80 @@ fn test_p2_exit_code_system_error_sdk_not_found() {
     );
 }
 
+/// Run `am <args>` without any way to find a home directory.
+fn run_without_home(args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_am"))
+        .args(args)
+        .env_remove("AM_HOME")
+        .env_remove("HOME")
+        .env_remove("USERPROFILE")
+        .env_remove("XDG_DATA_HOME")
+        .env_remove("XDG_STATE_HOME")
+        .output()
+        .expect("Failed to execute command")
+}
+
+#[test]
+fn test_p1_exit_code_missing_home_is_one_structured_error() {
+    // GIVEN: No HOME, USERPROFILE or AM_HOME
+    // WHEN: Running a command that needs the database
+    let output = run_without_home(&["--json", "template", "list"]);
+
+    // THEN: Exit code should be 2 (system error)
+    assert_eq!(
+        output.status.code(),
+        Some(am::common::errors::exit_codes::SYSTEM_ERROR),
+        "Expected system error exit code for a missing home directory"
+    );
+
+    // AND: The only output is the error envelope, with the dedicated code
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().filter(|l| !l.trim().is_empty()).collect();
+    assert_eq!(
+        lines.len(),
+        1,
+        "Expected a single envelope, got: {}",
+        stdout
+    );
+    let envelope: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+    assert_eq!(envelope["ok"], false);
+    assert_eq!(
+        envelope["error"]["code"],
+        am::common::errors::codes::ERR_HOME_NOT_FOUND
+    );
+    assert!(
+        envelope["error"]["suggestion"]
+            .as_str()
+            .unwrap()
+            .contains("AM_HOME"),
+        "Expected a suggestion to set AM_HOME: {}",
+        envelope
+    );
+}
+
+#[test]
+fn test_p2_exit_code_missing_home_with_am_home_set_succeeds() {
+    // GIVEN: No HOME, but AM_HOME points to a directory
+    let am_home = tempfile::tempdir().unwrap();
+
+    // WHEN: Running a command that needs the database
+    let output = Command::new(env!("CARGO_BIN_EXE_am"))
+        .args(["--json", "template", "list"])
+        .env("AM_HOME", am_home.path())
+        .env_remove("HOME")
+        .env_remove("USERPROFILE")
+        .output()
+        .expect("Failed to execute command");
+
+    // THEN: The command runs
+    assert_eq!(
+        output.status.code(),
+        Some(am::common::errors::exit_codes::SUCCESS),
+        "stdout: {}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
 #[test]
 #[ignore] // Panic testing requires special setup
 fn test_p2_exit_code_system_error_panic() {
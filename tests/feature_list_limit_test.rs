@@ -0,0 +1,318 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Feature tests for the size guard of list responses.
+
+use am::app::{App, Commands};
+use am::assets::Sound;
+use am::commands::asset::{AssetCommands, SoundCommands};
+use am::commands::project::{ProjectCommands, ProjectSort};
+use am::database::{db_create_project, entities::Project};
+use am::presentation::{DEFAULT_LIST_LIMIT, ListLimit, OutputMode};
+use am::testing::{OutputCall, ScriptedInput, TestEnv};
+use clap::Parser;
+use std::fs;
+use std::path::Path;
+
+/// Helper to create an isolated environment with a migrated database.
+async fn setup_test_env() -> TestEnv {
+    TestEnv::new()
+        .await
+        .expect("Failed to create test environment")
+}
+
+/// Write `count` sounds named `sound_0001`, `sound_0002`, ... in the project at `root`.
+fn write_sounds(root: &Path, count: usize) {
+    for i in 1..=count {
+        let name = format!("sound_{:04}", i);
+        let sound = Sound::builder(1000 + i as u64, &name).build();
+        fs::write(
+            root.join("sources/sounds").join(format!("{}.json", name)),
+            serde_json::to_string_pretty(&sound).unwrap(),
+        )
+        .expect("Failed to write sound");
+    }
+}
+
+fn list_sounds(limit: ListLimit) -> AssetCommands {
+    AssetCommands::Sound {
+        command: SoundCommands::List { limit },
+    }
+}
+
+fn validate_sounds(limit: ListLimit) -> ProjectCommands {
+    ProjectCommands::Validate {
+        sounds_only: true,
+        collections_only: false,
+        effects_only: false,
+        switches_only: false,
+        switch_containers_only: false,
+        events_only: false,
+        soundbanks_only: false,
+        no_orphan_check: true,
+        summary: false,
+        limit,
+    }
+}
+
+// =============================================================================
+// Argument Parsing Tests
+// =============================================================================
+
+#[test]
+fn test_p0_list_limit_parses_limit_and_all() {
+    let app = App::try_parse_from(["am", "asset", "sound", "list", "--limit", "5"])
+        .expect("Should parse");
+    match app.command {
+        Commands::Asset {
+            command:
+                AssetCommands::Sound {
+                    command: SoundCommands::List { limit },
+                },
+        } => assert_eq!(limit, ListLimit::new(5)),
+        _ => panic!("Expected sound list command"),
+    }
+
+    let app = App::try_parse_from(["am", "project", "list", "--all"]).expect("Should parse");
+    match app.command {
+        Commands::Project {
+            command: ProjectCommands::List { limit, .. },
+        } => {
+            assert!(limit.all);
+            assert_eq!(limit.max_items(), None);
+        }
+        _ => panic!("Expected project list command"),
+    }
+}
+
+#[test]
+fn test_p1_list_limit_rejects_zero_and_conflicting_arguments() {
+    assert!(App::try_parse_from(["am", "asset", "sound", "list", "--limit", "0"]).is_err());
+    assert!(
+        App::try_parse_from(["am", "asset", "sound", "list", "--limit", "5", "--all"]).is_err()
+    );
+}
+
+#[test]
+fn test_p1_list_limit_defaults_to_the_size_guard() {
+    assert_eq!(ListLimit::default().max_items(), Some(DEFAULT_LIST_LIMIT));
+
+    let mut items: Vec<usize> = (0..10).collect();
+    let truncation = ListLimit::new(3).apply(&mut items);
+    assert_eq!(items, vec![0, 1, 2]);
+    assert!(truncation.is_truncated());
+    assert_eq!((truncation.shown, truncation.total), (3, 10));
+
+    let truncation = ListLimit::new(20).apply(&mut items);
+    assert!(!truncation.is_truncated());
+}
+
+// =============================================================================
+// Asset List Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_sound_list_is_capped_by_default() {
+    let env = setup_test_env().await;
+    let root = env.create_project_dir("big").unwrap();
+    write_sounds(&root, DEFAULT_LIST_LIMIT + 5);
+
+    let run = env
+        .run_in(
+            &root,
+            &list_sounds(ListLimit::default()),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "{:?}", run.result);
+    let result = run.last_success().expect("List should report success");
+    assert_eq!(result["count"], DEFAULT_LIST_LIMIT);
+    assert_eq!(
+        result["sounds"].as_array().unwrap().len(),
+        DEFAULT_LIST_LIMIT
+    );
+    assert_eq!(result["truncated"], true);
+    assert_eq!(result["total"], DEFAULT_LIST_LIMIT + 5);
+}
+
+#[tokio::test]
+async fn test_p0_sound_list_honors_limit_and_all() {
+    let env = setup_test_env().await;
+    let root = env.create_project_dir("small").unwrap();
+    write_sounds(&root, 8);
+
+    let run = env
+        .run_in(&root, &list_sounds(ListLimit::new(3)), ScriptedInput::new())
+        .await;
+    let result = run.last_success().expect("List should report success");
+    let names: Vec<&str> = result["sounds"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|s| s["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, vec!["sound_0001", "sound_0002", "sound_0003"]);
+    assert_eq!(result["truncated"], true);
+    assert_eq!(result["total"], 8);
+
+    let all = ListLimit {
+        limit: None,
+        all: true,
+    };
+    let run = env
+        .run_in(&root, &list_sounds(all), ScriptedInput::new())
+        .await;
+    let result = run.last_success().expect("List should report success");
+    assert_eq!(result["count"], 8);
+    assert!(result.get("truncated").is_none());
+    assert!(result.get("total").is_none());
+}
+
+#[tokio::test]
+async fn test_p1_sound_list_interactive_tells_how_to_see_more() {
+    let env = setup_test_env().await;
+    let root = env.create_project_dir("notice").unwrap();
+    write_sounds(&root, 4);
+
+    let run = env
+        .run_in_with_mode(
+            &root,
+            &list_sounds(ListLimit::new(2)),
+            ScriptedInput::new(),
+            OutputMode::Interactive,
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "{:?}", run.result);
+    let notice = run.calls.iter().find_map(|call| match call {
+        OutputCall::Progress(message) if message.starts_with("Showing") => Some(message.clone()),
+        _ => None,
+    });
+    assert_eq!(
+        notice.as_deref(),
+        Some("Showing 2 of 4 sounds: use --limit N or --all to see more")
+    );
+}
+
+// =============================================================================
+// Project List Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_project_list_reports_truncation() {
+    let env = setup_test_env().await;
+    for i in 1..=5 {
+        let project = Project {
+            id: None,
+            name: format!("project_{}", i),
+            path: env
+                .home()
+                .join(format!("project_{}", i))
+                .to_str()
+                .unwrap()
+                .to_string(),
+            registered_at: None,
+            is_favorite: false,
+        };
+        db_create_project(&project, env.db()).expect("Registration should succeed");
+    }
+
+    let run = env
+        .run(
+            &ProjectCommands::List {
+                favorite: false,
+                no_favorite: false,
+                tree: None,
+                sort: ProjectSort::Name,
+                limit: ListLimit::new(2),
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "{:?}", run.result);
+    assert_eq!(run.tables()[0].as_array().unwrap().len(), 2);
+    assert!(
+        run.calls
+            .contains(&OutputCall::ListTruncated { shown: 2, total: 5 })
+    );
+
+    let run = env
+        .run(
+            &ProjectCommands::List {
+                favorite: false,
+                no_favorite: false,
+                tree: None,
+                sort: ProjectSort::Name,
+                limit: ListLimit::default(),
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert_eq!(run.tables()[0].as_array().unwrap().len(), 5);
+    assert!(
+        !run.calls
+            .iter()
+            .any(|call| matches!(call, OutputCall::ListTruncated { .. }))
+    );
+}
+
+// =============================================================================
+// Project Validate Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p1_project_validate_limits_listed_issues() {
+    let env = setup_test_env().await;
+    let root = env.create_project_dir("broken").unwrap();
+    for i in 1..=4 {
+        fs::write(
+            root.join("sources/sounds")
+                .join(format!("broken_{}.json", i)),
+            "{ not json",
+        )
+        .unwrap();
+    }
+
+    let run = env
+        .run_in(
+            &root,
+            &validate_sounds(ListLimit::new(2)),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    let result = run
+        .last_success()
+        .expect("Validate should report its result");
+    assert_eq!(result["valid"], false);
+    assert_eq!(result["errors"].as_array().unwrap().len(), 2);
+    assert_eq!(result["truncated"], true);
+    assert_eq!(result["total"], 4);
+    assert_eq!(result["issue_counts"]["invalid_asset"], 4);
+
+    let run = env
+        .run_in(
+            &root,
+            &validate_sounds(ListLimit::default()),
+            ScriptedInput::new(),
+        )
+        .await;
+    let result = run
+        .last_success()
+        .expect("Validate should report its result");
+    assert_eq!(result["errors"].as_array().unwrap().len(), 4);
+    assert!(result.get("truncated").is_none());
+}
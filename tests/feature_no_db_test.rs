@@ -0,0 +1,242 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Feature tests for running without the database.
+//!
+//! Tests cover:
+//! - Filesystem commands running with `--no-db`
+//! - The fallback when the database can't be opened
+//! - Registry commands rejecting `--no-db`
+//! - Registry commands failing with `ERR_DATABASE_UNAVAILABLE` without a database
+
+use am::app::App;
+use am::commands::dispatch::CommandHandler;
+use am::common::errors::{CliError, codes, determine_exit_code, error_type_name, exit_codes};
+use am::common::ignore::WalkOptions;
+use am::presentation::OutputMode;
+use am::testing::{MockOutput, ScriptedInput, TestEnv};
+use clap::Parser;
+use std::path::Path;
+use std::process::{Command, Output};
+
+/// Run `am --json <args>` in `dir`, with `AM_HOME` set to `am_home`.
+fn run_am(dir: &Path, am_home: &Path, args: &[&str]) -> (Output, serde_json::Value) {
+    let output = Command::new(env!("CARGO_BIN_EXE_am"))
+        .arg("--json")
+        .args(args)
+        .current_dir(dir)
+        .env("AM_HOME", am_home)
+        .env_remove("AM_SDK_PATH")
+        .output()
+        .expect("Failed to execute command");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let envelope = serde_json::from_str(stdout.trim()).unwrap_or_else(|e| {
+        panic!("Expected a JSON envelope ({}), got: {}", e, stdout);
+    });
+
+    (output, envelope)
+}
+
+/// An `AM_HOME` that is a regular file, so the database can't be created in it.
+fn unopenable_home(env: &TestEnv) -> std::path::PathBuf {
+    let path = env.home().join("not_a_directory");
+    std::fs::write(&path, b"").unwrap();
+    path
+}
+
+// =============================================================================
+// Flag Parsing Tests
+// =============================================================================
+
+#[test]
+fn test_p0_no_db_flag_is_global() {
+    let app = App::try_parse_from(["am", "project", "validate", "--no-db"]).expect("Should parse");
+
+    assert!(app.no_db);
+    assert!(!app.command.requires_database());
+}
+
+#[test]
+fn test_p1_registry_commands_require_database() {
+    for args in [
+        &["am", "project", "list"][..],
+        &["am", "project", "register", "."],
+        &["am", "project", "info", "my_game"],
+        &["am", "template", "list"],
+    ] {
+        let app = App::try_parse_from(args).expect("Should parse");
+        assert!(app.command.requires_database(), "{:?}", args);
+    }
+
+    for args in [
+        &["am", "project", "info"][..],
+        &["am", "project", "build"],
+        &["am", "asset", "sound", "list"],
+    ] {
+        let app = App::try_parse_from(args).expect("Should parse");
+        assert!(!app.command.requires_database(), "{:?}", args);
+    }
+}
+
+// =============================================================================
+// Filesystem Commands Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_validate_runs_when_database_cannot_be_opened() {
+    // GIVEN: A project and an AM_HOME where the database can't be created
+    let env = TestEnv::new().await.unwrap();
+    let project_path = env.scaffold_project("game", 1).unwrap();
+    let am_home = unopenable_home(&env);
+
+    // WHEN: Validating the project
+    let (output, envelope) = run_am(&project_path, &am_home, &["project", "validate"]);
+
+    // THEN: The filesystem validation still runs
+    assert_eq!(output.status.code(), Some(0), "{}", envelope);
+    assert_eq!(envelope["ok"], true);
+}
+
+#[tokio::test]
+async fn test_p0_validate_runs_with_no_db() {
+    let env = TestEnv::new().await.unwrap();
+    let project_path = env.scaffold_project("game", 1).unwrap();
+    let am_home = env.home().join("fresh_home");
+
+    let (output, envelope) = run_am(&project_path, &am_home, &["--no-db", "project", "validate"]);
+
+    assert_eq!(output.status.code(), Some(0), "{}", envelope);
+    assert_eq!(envelope["ok"], true);
+    assert!(
+        !am_home.join("am.db").exists(),
+        "--no-db must not create the database"
+    );
+}
+
+#[tokio::test]
+async fn test_p1_info_reports_unknown_registration_with_no_db() {
+    let env = TestEnv::new().await.unwrap();
+    let project_path = env.scaffold_project("game", 1).unwrap();
+
+    let (output, envelope) = run_am(&project_path, env.home(), &["--no-db", "project", "info"]);
+
+    assert_eq!(output.status.code(), Some(0), "{}", envelope);
+    assert_eq!(envelope["value"]["name"], "game");
+    assert!(envelope["value"]["registered"].is_null());
+    assert_eq!(envelope["value"]["assets"]["sounds"], 1);
+}
+
+// =============================================================================
+// Registry Commands Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_list_rejects_no_db() {
+    let env = TestEnv::new().await.unwrap();
+
+    let (output, envelope) = run_am(env.home(), env.home(), &["--no-db", "project", "list"]);
+
+    assert_eq!(output.status.code(), Some(1), "{}", envelope);
+    assert_eq!(envelope["ok"], false);
+    assert_eq!(envelope["error"]["code"], codes::ERR_VALIDATION_FIELD);
+    assert!(
+        envelope["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("--no-db")
+    );
+}
+
+#[tokio::test]
+async fn test_p1_register_rejects_no_db() {
+    let env = TestEnv::new().await.unwrap();
+    let project_path = env.create_project_dir("game").unwrap();
+
+    let (output, envelope) = run_am(
+        env.home(),
+        env.home(),
+        &[
+            "--no-db",
+            "project",
+            "register",
+            project_path.to_str().unwrap(),
+        ],
+    );
+
+    assert_eq!(output.status.code(), Some(1), "{}", envelope);
+    assert_eq!(envelope["error"]["code"], codes::ERR_VALIDATION_FIELD);
+}
+
+// =============================================================================
+// Unavailable Database Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_registry_handlers_without_database_fail_with_structured_error() {
+    for args in [
+        &["am", "project", "list"][..],
+        &["am", "project", "info", "my_game"],
+        &["am", "project", "favorite", "my_game"],
+        &["am", "stats", "usage"],
+        &["am", "sudo", "database", "maintain"],
+    ] {
+        let app = App::try_parse_from(args).expect("Should parse");
+        let output = MockOutput::new(OutputMode::Json);
+
+        // GIVEN no database, as when it failed to open at startup
+        let result = app
+            .command
+            .dispatch(
+                None,
+                WalkOptions::default(),
+                None,
+                &ScriptedInput::new(),
+                &output,
+            )
+            .await;
+
+        // THEN the handler returns an error instead of panicking
+        let error = result.expect_err(&format!("{:?} should fail", args));
+        let cli_error = error
+            .downcast_ref::<CliError>()
+            .unwrap_or_else(|| panic!("{:?} should fail with a CliError: {}", args, error));
+        assert_eq!(
+            cli_error.code,
+            codes::ERR_DATABASE_UNAVAILABLE,
+            "{:?}",
+            args
+        );
+        assert_eq!(error_type_name(cli_error.code), "database_unavailable");
+        assert_eq!(determine_exit_code(&error), exit_codes::SYSTEM_ERROR);
+    }
+}
+
+#[tokio::test]
+async fn test_p0_list_reports_unavailable_database_when_it_cannot_be_opened() {
+    let env = TestEnv::new().await.unwrap();
+    let am_home = unopenable_home(&env);
+
+    let (output, envelope) = run_am(env.home(), &am_home, &["project", "list"]);
+
+    assert_eq!(
+        output.status.code(),
+        Some(exit_codes::SYSTEM_ERROR),
+        "{}",
+        envelope
+    );
+    assert_eq!(envelope["ok"], false);
+    assert_eq!(envelope["error"]["code"], codes::ERR_DATABASE_UNAVAILABLE);
+    assert_eq!(envelope["error"]["type"], "database_unavailable");
+}
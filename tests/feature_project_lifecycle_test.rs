@@ -14,21 +14,22 @@
 
 //! Feature tests for project lifecycle operations.
 
+use am::commands::asset::{AssetCommands, SoundCommands};
+use am::commands::project::{ProjectCommands, ProjectSort};
 use am::database::{
-    Database, db_create_project, db_forget_project, db_get_all_projects, db_get_project_by_name,
+    db_create_project, db_forget_project, db_get_all_projects, db_get_project_alias_of_file_name,
+    db_get_project_by_name, db_set_project_favorite,
     entities::{Project, ProjectConfiguration},
 };
+use am::testing::{OutputCall, ScriptedInput, TestEnv};
 use std::fs;
-use std::sync::Arc;
 use tempfile::tempdir;
 
-/// Helper to create a test database with migrations applied.
-async fn setup_test_database() -> (Arc<Database>, tempfile::TempDir) {
-    let temp_dir = tempdir().expect("Failed to create temp dir");
-    let db_path = temp_dir.path().join("test.db");
-    let mut db = Database::new(&db_path).expect("Failed to create database");
-    db.run_migrations().await.expect("Failed to run migrations");
-    (Arc::new(db), temp_dir)
+/// Helper to create an isolated environment with a migrated database.
+async fn setup_test_env() -> TestEnv {
+    TestEnv::new()
+        .await
+        .expect("Failed to create test environment")
 }
 
 // =============================================================================
@@ -79,6 +80,9 @@ async fn test_p0_project_init_creates_amproject_file() {
         data_dir: "data".to_string(),
         sources_dir: "sources".to_string(),
         version: 1,
+        hooks: Default::default(),
+        allow_json_comments: false,
+        asset_dirs: Default::default(),
     };
 
     let amproject_path = project_path.join(".amproject");
@@ -100,8 +104,9 @@ async fn test_p0_project_init_creates_amproject_file() {
 
 #[tokio::test]
 async fn test_p0_project_registration_stores_in_database() {
-    let (db, temp_dir) = setup_test_database().await;
-    let project_path = temp_dir.path().join("registered_project");
+    let env = setup_test_env().await;
+    let db = env.database();
+    let project_path = env.home().join("registered_project");
     fs::create_dir_all(&project_path).expect("Failed to create project dir");
 
     let config = ProjectConfiguration {
@@ -111,6 +116,9 @@ async fn test_p0_project_registration_stores_in_database() {
         data_dir: "data".to_string(),
         sources_dir: "sources".to_string(),
         version: 1,
+        hooks: Default::default(),
+        allow_json_comments: false,
+        asset_dirs: Default::default(),
     };
 
     let project = config.to_project(project_path.to_str().unwrap());
@@ -126,17 +134,13 @@ async fn test_p0_project_registration_stores_in_database() {
 
 #[tokio::test]
 async fn test_p1_project_registration_prevents_duplicate_names() {
-    let (db, temp_dir) = setup_test_database().await;
+    let env = setup_test_env().await;
+    let db = env.database();
 
     let project1 = Project {
         id: None,
         name: "unique_name".to_string(),
-        path: temp_dir
-            .path()
-            .join("project1")
-            .to_str()
-            .unwrap()
-            .to_string(),
+        path: env.home().join("project1").to_str().unwrap().to_string(),
         registered_at: None,
         is_favorite: false,
     };
@@ -145,12 +149,7 @@ async fn test_p1_project_registration_prevents_duplicate_names() {
     let project2 = Project {
         id: None,
         name: "unique_name".to_string(),
-        path: temp_dir
-            .path()
-            .join("project2")
-            .to_str()
-            .unwrap()
-            .to_string(),
+        path: env.home().join("project2").to_str().unwrap().to_string(),
         registered_at: None,
         is_favorite: false,
     };
@@ -165,7 +164,8 @@ async fn test_p1_project_registration_prevents_duplicate_names() {
 
 #[tokio::test]
 async fn test_p0_project_unregistration_removes_from_database() {
-    let (db, _temp_dir) = setup_test_database().await;
+    let env = setup_test_env().await;
+    let db = env.database();
 
     let project = Project {
         id: None,
@@ -189,8 +189,9 @@ async fn test_p0_project_unregistration_removes_from_database() {
 
 #[tokio::test]
 async fn test_p1_project_unregistration_does_not_delete_files_by_default() {
-    let (db, temp_dir) = setup_test_database().await;
-    let project_path = temp_dir.path().join("project_with_files");
+    let env = setup_test_env().await;
+    let db = env.database();
+    let project_path = env.home().join("project_with_files");
     fs::create_dir_all(&project_path).expect("Failed to create project dir");
 
     let amproject_path = project_path.join(".amproject");
@@ -224,36 +225,34 @@ async fn test_p1_project_unregistration_does_not_delete_files_by_default() {
 
 #[tokio::test]
 async fn test_p0_full_project_lifecycle() {
-    let (db, temp_dir) = setup_test_database().await;
+    let env = setup_test_env().await;
+    let db = env.database();
     let project_name = "lifecycle_test";
-    let project_path = temp_dir.path().join(project_name);
 
     // Step 1 - Initialize project
-    fs::create_dir_all(project_path.join("sources")).expect("Failed to create sources");
-    fs::create_dir_all(project_path.join("build")).expect("Failed to create build");
-    fs::create_dir_all(project_path.join("data")).expect("Failed to create data");
-
-    let config = ProjectConfiguration {
-        name: project_name.to_string(),
-        default_configuration: "pc.config.amconfig".to_string(),
-        build_dir: "build".to_string(),
-        data_dir: "data".to_string(),
-        sources_dir: "sources".to_string(),
-        version: 1,
-    };
-
-    fs::write(
-        project_path.join(".amproject"),
-        serde_json::to_string(&config).unwrap(),
-    )
-    .expect("Failed to write .amproject");
+    let project_path = env
+        .create_project_dir(project_name)
+        .expect("Failed to create project");
 
     assert!(project_path.join(".amproject").exists());
     assert!(project_path.join("sources").exists());
 
     // Step 2 - Register project
-    let project = config.to_project(project_path.to_str().unwrap());
-    db_create_project(&project, Some(db.clone())).expect("Registration should succeed");
+    let run = env
+        .run(
+            &ProjectCommands::Register {
+                path: Some(project_path.clone()),
+                recursive: false,
+                rename_on_conflict: false,
+                force: false,
+                name: None,
+                stdin: false,
+                strict: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok(), "Registration should succeed");
 
     let found = db_get_project_by_name(project_name, Some(db.clone()))
         .expect("Query should succeed")
@@ -261,7 +260,19 @@ async fn test_p0_full_project_lifecycle() {
     assert_eq!(found.name, project_name);
 
     // Step 3 - Unregister project
-    db_forget_project(found.id.unwrap(), Some(db.clone())).expect("Unregister should succeed");
+    let run = env
+        .run(
+            &ProjectCommands::Unregister {
+                name: Some(project_name.to_string()),
+                pattern: None,
+                delete_files: false,
+                yes: false,
+                fail_empty: false,
+            },
+            ScriptedInput::new().confirm(true),
+        )
+        .await;
+    assert!(run.result.is_ok(), "Unregister should succeed");
 
     let check =
         db_get_project_by_name(project_name, Some(db.clone())).expect("Query should succeed");
@@ -272,17 +283,13 @@ async fn test_p0_full_project_lifecycle() {
 
 #[tokio::test]
 async fn test_p1_re_register_after_unregister() {
-    let (db, temp_dir) = setup_test_database().await;
+    let env = setup_test_env().await;
+    let db = env.database();
 
     let project = Project {
         id: None,
         name: "re_register_test".to_string(),
-        path: temp_dir
-            .path()
-            .join("re_register")
-            .to_str()
-            .unwrap()
-            .to_string(),
+        path: env.home().join("re_register").to_str().unwrap().to_string(),
         registered_at: None,
         is_favorite: false,
     };
@@ -305,20 +312,21 @@ async fn test_p1_re_register_after_unregister() {
 
 #[tokio::test]
 async fn test_p0_project_list_shows_registered_projects() {
-    let (db, temp_dir) = setup_test_database().await;
+    let env = setup_test_env().await;
+    let db = env.database();
 
     // Register two projects
     let project1 = Project {
         id: None,
         name: "alpha_project".to_string(),
-        path: temp_dir.path().join("alpha").to_str().unwrap().to_string(),
+        path: env.home().join("alpha").to_str().unwrap().to_string(),
         registered_at: None,
         is_favorite: false,
     };
     let project2 = Project {
         id: None,
         name: "beta_project".to_string(),
-        path: temp_dir.path().join("beta").to_str().unwrap().to_string(),
+        path: env.home().join("beta").to_str().unwrap().to_string(),
         registered_at: None,
         is_favorite: false,
     };
@@ -339,7 +347,8 @@ async fn test_p0_project_list_shows_registered_projects() {
 
 #[tokio::test]
 async fn test_p1_project_list_empty_database_returns_empty_vec() {
-    let (db, _temp_dir) = setup_test_database().await;
+    let env = setup_test_env().await;
+    let db = env.database();
 
     let projects = db_get_all_projects(Some(db.clone())).expect("Query should succeed");
 
@@ -351,8 +360,9 @@ async fn test_p1_project_list_empty_database_returns_empty_vec() {
 
 #[tokio::test]
 async fn test_p1_project_list_includes_path_and_date() {
-    let (db, temp_dir) = setup_test_database().await;
-    let project_path = temp_dir.path().join("test_project");
+    let env = setup_test_env().await;
+    let db = env.database();
+    let project_path = env.home().join("test_project");
 
     let project = Project {
         id: None,
@@ -371,6 +381,152 @@ async fn test_p1_project_list_includes_path_and_date() {
     assert!(projects[0].registered_at.is_some());
 }
 
+// =============================================================================
+// Favorite Tests
+// =============================================================================
+
+async fn register_dated(env: &TestEnv, name: &str, registered_at: &str, favorite: bool) {
+    let db = env.database();
+    let project = Project {
+        id: None,
+        name: name.to_string(),
+        path: env.home().join(name).to_str().unwrap().to_string(),
+        registered_at: None,
+        is_favorite: favorite,
+    };
+    db_create_project(&project, Some(db.clone())).expect("Registration should succeed");
+    db.execute(
+        "UPDATE projects SET created_at = ?1, is_favorite = ?2 WHERE name = ?3",
+        rusqlite::params![registered_at, favorite, name],
+    )
+    .expect("Update should succeed");
+}
+
+fn list_sorted(sort: ProjectSort, favorite: bool) -> ProjectCommands {
+    ProjectCommands::List {
+        favorite,
+        no_favorite: false,
+        tree: None,
+        sort,
+        limit: Default::default(),
+    }
+}
+
+fn listed_names(run: &am::testing::CommandRun) -> Vec<String> {
+    let tables = run.tables();
+    tables
+        .last()
+        .expect("Expected a table")
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|row| row["name"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_p0_favorite_and_unfavorite_toggle_the_flag() {
+    let env = setup_test_env().await;
+    register_dated(&env, "game", "2026-01-01 00:00:00", false).await;
+
+    let run = env
+        .run(
+            &ProjectCommands::Favorite {
+                name: "game".to_string(),
+                set: false,
+                unset: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(
+        run.result.is_ok(),
+        "Favorite should succeed: {:?}",
+        run.result
+    );
+    let project = db_get_project_by_name("game", env.db()).unwrap().unwrap();
+    assert!(project.is_favorite);
+
+    let run = env
+        .run(
+            &ProjectCommands::Unfavorite {
+                name: "game".to_string(),
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(
+        run.result.is_ok(),
+        "Unfavorite should succeed: {:?}",
+        run.result
+    );
+    let project = db_get_project_by_name("game", env.db()).unwrap().unwrap();
+    assert!(!project.is_favorite);
+}
+
+#[tokio::test]
+async fn test_p0_list_puts_favorites_first() {
+    let env = setup_test_env().await;
+    register_dated(&env, "alpha", "2026-01-01 00:00:00", false).await;
+    register_dated(&env, "zulu", "2026-01-02 00:00:00", true).await;
+
+    let run = env
+        .run(&list_sorted(ProjectSort::Name, false), ScriptedInput::new())
+        .await;
+
+    assert!(run.result.is_ok());
+    assert_eq!(listed_names(&run), vec!["zulu", "alpha"]);
+    let tables = run.tables();
+    let table = tables.last().unwrap();
+    assert_eq!(table[0]["favorite"], true);
+    assert_eq!(table[1]["favorite"], false);
+}
+
+#[tokio::test]
+async fn test_p1_sort_recent_keeps_favorites_first() {
+    let env = setup_test_env().await;
+    register_dated(&env, "old_favorite", "2025-01-01 00:00:00", true).await;
+    register_dated(&env, "new_favorite", "2026-03-01 00:00:00", true).await;
+    register_dated(&env, "oldest", "2024-01-01 00:00:00", false).await;
+    register_dated(&env, "newest", "2026-06-01 00:00:00", false).await;
+
+    let run = env
+        .run(
+            &list_sorted(ProjectSort::Recent, false),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    // Favorites come first, each group from the most recently registered
+    assert!(run.result.is_ok());
+    assert_eq!(
+        listed_names(&run),
+        vec!["new_favorite", "old_favorite", "newest", "oldest"]
+    );
+
+    // --favorites only keeps the favorites, in the same order
+    let run = env
+        .run(
+            &list_sorted(ProjectSort::Recent, true),
+            ScriptedInput::new(),
+        )
+        .await;
+    assert_eq!(listed_names(&run), vec!["new_favorite", "old_favorite"]);
+}
+
+#[test]
+fn test_p1_list_accepts_favorites_and_sort() {
+    use am::app::App;
+    use clap::Parser;
+
+    let parse =
+        |args: &[&str]| App::try_parse_from(["am", "project", "list"].iter().chain(args.iter()));
+
+    assert!(parse(&["--favorites"]).is_ok());
+    assert!(parse(&["--favorite", "--sort", "recent"]).is_ok());
+    assert!(parse(&["--sort", "size"]).is_err());
+}
+
 // =============================================================================
 // Project Info Command Tests
 // =============================================================================
@@ -380,8 +536,9 @@ use am::database::db_get_project_by_path;
 
 #[tokio::test]
 async fn test_p0_project_info_registered_project_has_date() {
-    let (db, temp_dir) = setup_test_database().await;
-    let project_path = temp_dir.path().join("info_test");
+    let env = setup_test_env().await;
+    let db = env.database();
+    let project_path = env.home().join("info_test");
     fs::create_dir_all(&project_path).expect("Failed to create project dir");
 
     // Create .amproject file
@@ -392,6 +549,9 @@ async fn test_p0_project_info_registered_project_has_date() {
         data_dir: "data".to_string(),
         sources_dir: "sources".to_string(),
         version: 1,
+        hooks: Default::default(),
+        allow_json_comments: false,
+        asset_dirs: Default::default(),
     };
     fs::write(
         project_path.join(".amproject"),
@@ -417,8 +577,9 @@ async fn test_p0_project_info_registered_project_has_date() {
 
 #[tokio::test]
 async fn test_p0_project_info_unregistered_project_not_found_by_path() {
-    let (db, temp_dir) = setup_test_database().await;
-    let project_path = temp_dir.path().join("unregistered_project");
+    let env = setup_test_env().await;
+    let db = env.database();
+    let project_path = env.home().join("unregistered_project");
     fs::create_dir_all(&project_path).expect("Failed to create project dir");
 
     // Create .amproject file but don't register
@@ -429,6 +590,9 @@ async fn test_p0_project_info_unregistered_project_not_found_by_path() {
         data_dir: "data".to_string(),
         sources_dir: "sources".to_string(),
         version: 1,
+        hooks: Default::default(),
+        allow_json_comments: false,
+        asset_dirs: Default::default(),
     };
     fs::write(
         project_path.join(".amproject"),
@@ -458,6 +622,9 @@ async fn test_p0_project_info_reads_amproject_correctly() {
         data_dir: "assets".to_string(),
         sources_dir: "src".to_string(),
         version: 2,
+        hooks: Default::default(),
+        allow_json_comments: false,
+        asset_dirs: Default::default(),
     };
     fs::write(
         project_path.join(".amproject"),
@@ -499,12 +666,13 @@ async fn test_p0_project_info_counts_assets_correctly() {
 
 #[tokio::test]
 async fn test_p1_project_info_named_lookup_finds_registered() {
-    let (db, temp_dir) = setup_test_database().await;
+    let env = setup_test_env().await;
+    let db = env.database();
 
     let project = Project {
         id: None,
         name: "named_lookup_test".to_string(),
-        path: temp_dir.path().join("named").to_str().unwrap().to_string(),
+        path: env.home().join("named").to_str().unwrap().to_string(),
         registered_at: None,
         is_favorite: false,
     };
@@ -519,10 +687,4743 @@ async fn test_p1_project_info_named_lookup_finds_registered() {
 
 #[tokio::test]
 async fn test_p1_project_info_named_lookup_not_found() {
-    let (db, _temp_dir) = setup_test_database().await;
+    let env = setup_test_env().await;
+    let db = env.database();
 
     let result =
         db_get_project_by_name("does_not_exist", Some(db.clone())).expect("Query should succeed");
 
     assert!(result.is_none(), "Non-existent project should return None");
 }
+
+// =============================================================================
+// Handler Runner Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_project_register_handler_reports_success() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .create_project_dir("handler_project")
+        .expect("Failed to create project");
+
+    let run = env
+        .run(
+            &ProjectCommands::Register {
+                path: Some(project_path),
+                recursive: false,
+                rename_on_conflict: false,
+                force: false,
+                name: None,
+                stdin: false,
+                strict: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Register should succeed: {:?}",
+        run.result
+    );
+    assert_eq!(
+        run.last_success(),
+        Some(&serde_json::json!(
+            "Project handler_project registered successfully"
+        ))
+    );
+}
+
+#[tokio::test]
+async fn test_p1_project_register_handler_declined_replacement_fails() {
+    let env = setup_test_env().await;
+    let first = env
+        .create_project_dir("shared_name")
+        .expect("Failed to create first project");
+    let second = env.home().join("elsewhere");
+    fs::create_dir_all(&second).expect("Failed to create second project");
+    fs::copy(first.join(".amproject"), second.join(".amproject"))
+        .expect("Failed to copy .amproject");
+
+    let run = env
+        .run(
+            &ProjectCommands::Register {
+                path: Some(first),
+                recursive: false,
+                rename_on_conflict: false,
+                force: false,
+                name: None,
+                stdin: false,
+                strict: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok());
+
+    let run = env
+        .run(
+            &ProjectCommands::Register {
+                path: Some(second),
+                recursive: false,
+                rename_on_conflict: false,
+                force: false,
+                name: None,
+                stdin: false,
+                strict: false,
+            },
+            ScriptedInput::new().confirm(false),
+        )
+        .await;
+
+    assert!(run.result.is_err(), "Declined replacement should fail");
+    assert_eq!(run.warnings().len(), 1);
+    assert_eq!(run.unused_responses, 0);
+}
+
+#[tokio::test]
+async fn test_p0_project_list_handler_outputs_table() {
+    let env = setup_test_env().await;
+    for name in ["beta_project", "alpha_project"] {
+        let path = env.create_project_dir(name).expect("Failed to create");
+        let run = env
+            .run(
+                &ProjectCommands::Register {
+                    path: Some(path),
+                    recursive: false,
+                    rename_on_conflict: false,
+                    force: false,
+                    name: None,
+                    stdin: false,
+                    strict: false,
+                },
+                ScriptedInput::new(),
+            )
+            .await;
+        assert!(run.result.is_ok());
+    }
+
+    let run = env
+        .run(
+            &ProjectCommands::List {
+                favorite: false,
+                no_favorite: false,
+                tree: None,
+                sort: ProjectSort::Name,
+                limit: Default::default(),
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok());
+    let tables = run.tables();
+    assert_eq!(tables.len(), 1);
+    let rows = tables[0].as_array().expect("Table should be an array");
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0]["name"], "alpha_project");
+    assert_eq!(rows[1]["name"], "beta_project");
+}
+
+#[tokio::test]
+async fn test_p1_project_list_declares_its_columns() {
+    let env = setup_test_env().await;
+
+    let run = env
+        .run(
+            &ProjectCommands::List {
+                favorite: false,
+                no_favorite: false,
+                tree: None,
+                sort: ProjectSort::Name,
+                limit: Default::default(),
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok());
+    let Some(OutputCall::Table { columns, .. }) = run
+        .calls
+        .iter()
+        .find(|call| matches!(call, OutputCall::Table { .. }))
+    else {
+        panic!("Expected a table: {:?}", run.calls);
+    };
+    assert_eq!(columns, &["name", "path", "registered_at", "favorite"]);
+}
+
+/// Register a project named `name` at `path` without creating its directory.
+fn register_at(env: &TestEnv, name: &str, path: &std::path::Path) {
+    let project = Project {
+        id: None,
+        name: name.to_string(),
+        path: path.to_str().unwrap().to_string(),
+        registered_at: None,
+        is_favorite: false,
+    };
+    db_create_project(&project, env.db()).expect("Registration should succeed");
+}
+
+#[tokio::test]
+async fn test_p0_project_list_tree_groups_projects_by_directory() {
+    let env = setup_test_env().await;
+    let studio = env.home().join("studio");
+    register_at(&env, "racer", &studio.join("client_b").join("racer"));
+    register_at(&env, "puzzle", &studio.join("client_a").join("puzzle"));
+    register_at(&env, "arcade", &studio.join("client_a").join("arcade"));
+
+    let run = env
+        .run(
+            &ProjectCommands::List {
+                favorite: false,
+                no_favorite: false,
+                tree: Some(None),
+                sort: ProjectSort::Name,
+                limit: Default::default(),
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "{:?}", run.result);
+    let tree = run.last_success().expect("Expected the tree");
+    assert_eq!(tree["root"], studio.to_str().unwrap());
+    assert_eq!(tree["count"], 3);
+    assert_eq!(tree["children"][0]["name"], "client_a");
+    assert_eq!(tree["children"][0]["count"], 2);
+    assert_eq!(tree["children"][0]["children"][0]["name"], "arcade");
+    assert_eq!(tree["children"][1]["name"], "client_b");
+}
+
+#[tokio::test]
+async fn test_p1_project_list_tree_prints_indented_tree() {
+    let env = setup_test_env().await;
+    let studio = env.home().join("studio");
+    register_at(&env, "puzzle", &studio.join("client_a").join("puzzle"));
+    register_at(&env, "racer", &studio.join("client_b").join("racer"));
+
+    let run = env
+        .run_with_mode(
+            &ProjectCommands::List {
+                favorite: false,
+                no_favorite: false,
+                tree: Some(None),
+                sort: ProjectSort::Name,
+                limit: Default::default(),
+            },
+            ScriptedInput::new(),
+            am::presentation::OutputMode::Interactive,
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "{:?}", run.result);
+    let expected = format!(
+        "{} (2)\n├── client_a (1)\n│   └── puzzle\n└── client_b (1)\n    └── racer",
+        studio.display()
+    );
+    assert!(
+        run.calls.contains(&OutputCall::Print(expected)),
+        "{:?}",
+        run.calls
+    );
+}
+
+#[tokio::test]
+async fn test_p0_project_info_handler_counts_scaffolded_assets() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .scaffold_project("info_assets", 2)
+        .expect("Failed to scaffold project");
+    let run = env
+        .run(
+            &ProjectCommands::Register {
+                path: Some(project_path),
+                recursive: false,
+                rename_on_conflict: false,
+                force: false,
+                name: None,
+                stdin: false,
+                strict: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok());
+
+    let run = env
+        .run(
+            &ProjectCommands::Info {
+                name: Some("info_assets".to_string()),
+                path: None,
+                paths: false,
+                check_paths: false,
+                create_missing: false,
+                strict: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Info should succeed: {:?}", run.result);
+    let info = run.last_success().expect("Info should report success");
+    assert_eq!(info["name"], "info_assets");
+    assert_eq!(info["assets"]["sounds"], 2);
+    assert_eq!(info["assets"]["events"], 2);
+}
+
+#[tokio::test]
+async fn test_p1_project_info_path_accepts_amproject_file() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .scaffold_project("info_by_path", 1)
+        .expect("Failed to scaffold project");
+
+    // GIVEN the .amproject file a file association passes
+    // WHEN info runs from another directory
+    let run = env
+        .run_in(
+            env.home(),
+            &ProjectCommands::Info {
+                name: None,
+                path: Some(project_path.join(".amproject")),
+                paths: false,
+                check_paths: false,
+                create_missing: false,
+                strict: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    // THEN the project of that file is shown
+    assert!(run.result.is_ok(), "Info should succeed: {:?}", run.result);
+    let info = run.last_success().expect("Info should report success");
+    assert_eq!(info["name"], "info_by_path");
+}
+
+#[tokio::test]
+async fn test_p0_project_info_from_subdirectory_finds_project() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .scaffold_project("info_nested", 1)
+        .expect("Failed to scaffold project");
+    let nested = project_path.join("sources").join("sounds");
+    std::fs::create_dir_all(&nested).unwrap();
+
+    // GIVEN a directory inside the project
+    // WHEN info runs there without a name
+    let run = env
+        .run_in(&nested, &info_current_dir(), ScriptedInput::new())
+        .await;
+
+    // THEN the project holding it is shown
+    assert!(run.result.is_ok(), "Info should succeed: {:?}", run.result);
+    let info = run.last_success().expect("Info should report success");
+    assert_eq!(info["name"], "info_nested");
+    assert_eq!(info["registered"], false);
+    assert_eq!(info["assets"]["sounds"], 1);
+}
+
+#[tokio::test]
+async fn test_p1_project_info_interactive_shows_asset_counts_table() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .scaffold_project("info_table", 2)
+        .expect("Failed to scaffold project");
+
+    let run = env
+        .run_in_with_mode(
+            &project_path,
+            &info_current_dir(),
+            ScriptedInput::new().confirm(false),
+            am::presentation::OutputMode::Interactive,
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Info should succeed: {:?}", run.result);
+    let tables = run.tables();
+    let rows = tables
+        .first()
+        .and_then(|table| table.as_array())
+        .expect("Asset counts should be shown as a table");
+    assert!(rows.contains(&serde_json::json!({ "type": "Sounds", "count": 2 })));
+    assert!(rows.contains(&serde_json::json!({ "type": "Events", "count": 2 })));
+    // Asset types without any file are left out
+    assert!(!rows.iter().any(|row| row["type"] == "Pipelines"));
+}
+
+// =============================================================================
+// Picker Tests
+// =============================================================================
+
+async fn register_scaffolded(env: &TestEnv, name: &str) {
+    let project_path = env
+        .scaffold_project(name, 1)
+        .expect("Failed to scaffold project");
+    let run = env
+        .run(
+            &ProjectCommands::Register {
+                path: Some(project_path),
+                recursive: false,
+                rename_on_conflict: false,
+                force: false,
+                name: None,
+                stdin: false,
+                strict: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok());
+}
+
+fn info_current_dir() -> ProjectCommands {
+    ProjectCommands::Info {
+        name: None,
+        path: None,
+        paths: false,
+        check_paths: false,
+        create_missing: false,
+        strict: false,
+    }
+}
+
+#[tokio::test]
+async fn test_p0_project_info_outside_project_picks_registered_project_by_label() {
+    let env = setup_test_env().await;
+    register_scaffolded(&env, "alpha_project").await;
+    register_scaffolded(&env, "beta_project").await;
+
+    // GIVEN a directory without .amproject
+    // WHEN the user picks a registered project
+    let run = env
+        .run_in(
+            env.home(),
+            &info_current_dir(),
+            ScriptedInput::new().select("beta_project"),
+        )
+        .await;
+
+    // THEN the info of the picked project is shown
+    assert!(run.result.is_ok(), "Info should succeed: {:?}", run.result);
+    assert_eq!(run.unused_responses, 0);
+    let info = run.last_success().expect("Info should report success");
+    assert_eq!(info["name"], "beta_project");
+}
+
+#[tokio::test]
+async fn test_p1_project_info_outside_project_picks_registered_project_by_index() {
+    let env = setup_test_env().await;
+    register_scaffolded(&env, "alpha_project").await;
+    register_scaffolded(&env, "beta_project").await;
+
+    let run = env
+        .run_in(
+            env.home(),
+            &info_current_dir(),
+            ScriptedInput::new().select("0"),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Info should succeed: {:?}", run.result);
+    let info = run.last_success().expect("Info should report success");
+    assert_eq!(info["name"], "alpha_project");
+}
+
+#[tokio::test]
+async fn test_p1_project_info_outside_project_without_selection_fails() {
+    let env = setup_test_env().await;
+    register_scaffolded(&env, "alpha_project").await;
+
+    // GIVEN no scripted selection, as in non-interactive mode
+    let run = env
+        .run_in(env.home(), &info_current_dir(), ScriptedInput::new())
+        .await;
+
+    // THEN the command reports that no project was found
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_PROJECT_NOT_INITIALIZED)
+    );
+}
+
+#[tokio::test]
+async fn test_p1_project_init_picks_template_from_rich_options() {
+    let env = setup_test_env().await;
+
+    let run = env
+        .run_in(
+            env.home(),
+            &ProjectCommands::Init {
+                name: Some("picked_template".to_string()),
+                template: None,
+                asset_dirs: vec![],
+                no_register: true,
+                with_examples: false,
+                vars: vec![],
+                overwrite_dir: false,
+                replace_registration: false,
+                verify: false,
+                dry_run: false,
+            },
+            ScriptedInput::new().select("default"),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Init should succeed: {:?}", run.result);
+    assert_eq!(run.unused_responses, 0);
+    assert!(
+        env.home()
+            .join("picked_template")
+            .join(".amproject")
+            .exists()
+    );
+}
+
+#[tokio::test]
+async fn test_p1_project_init_enters_each_stage_in_order() {
+    let env = setup_test_env().await;
+
+    let run = env
+        .run_in(
+            env.home(),
+            &ProjectCommands::Init {
+                name: Some("staged".to_string()),
+                template: Some("default".to_string()),
+                asset_dirs: vec![],
+                no_register: true,
+                with_examples: false,
+                vars: vec![],
+                overwrite_dir: false,
+                replace_registration: false,
+                verify: false,
+                dry_run: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Init should succeed: {:?}", run.result);
+    assert_eq!(
+        run.calls.first(),
+        Some(&OutputCall::BeginStages(vec![
+            "Check".to_string(),
+            "Create".to_string(),
+            "Finalize".to_string(),
+        ]))
+    );
+    let entered: Vec<usize> = run
+        .calls
+        .iter()
+        .filter_map(|c| match c {
+            OutputCall::EnterStage(index) => Some(*index),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(entered, [0, 1, 2]);
+}
+
+#[tokio::test]
+async fn test_p1_project_init_stores_the_transformed_name_everywhere() {
+    let env = setup_test_env().await;
+
+    let run = env
+        .run_in(
+            env.home(),
+            &ProjectCommands::Init {
+                name: Some("  My Game-Two ".to_string()),
+                template: Some("default".to_string()),
+                asset_dirs: vec![],
+                no_register: false,
+                with_examples: false,
+                vars: vec![],
+                overwrite_dir: false,
+                replace_registration: false,
+                verify: false,
+                dry_run: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Init should succeed: {:?}", run.result);
+    let project_path = env.home().join("my_game_two");
+    assert_eq!(
+        read_amproject_file(&project_path).unwrap().name,
+        "my_game_two"
+    );
+    let registered = db_get_project_by_name("my_game_two", env.db()).unwrap();
+    assert_eq!(
+        registered.map(|p| p.path),
+        Some(project_path.display().to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_p1_project_init_rejects_invalid_name_argument() {
+    let env = setup_test_env().await;
+
+    for name in ["bad/name", "---", "   ", "con"] {
+        let run = env
+            .run_in(
+                env.home(),
+                &ProjectCommands::Init {
+                    name: Some(name.to_string()),
+                    template: Some("default".to_string()),
+                    asset_dirs: vec![],
+                    no_register: true,
+                    with_examples: false,
+                    vars: vec![],
+                    overwrite_dir: false,
+                    replace_registration: false,
+                    verify: false,
+                    dry_run: false,
+                },
+                ScriptedInput::new(),
+            )
+            .await;
+
+        assert_eq!(
+            run.error_code(),
+            Some(am::common::errors::codes::ERR_VALIDATION_FIELD),
+            "Init with '{}' should fail validation",
+            name
+        );
+    }
+    assert!(!env.home().join("bad").exists());
+}
+
+#[tokio::test]
+async fn test_p0_scaffolded_project_passes_validation() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .scaffold_project("valid_project", 3)
+        .expect("Failed to scaffold project");
+
+    let run = env
+        .run_in(
+            &project_path,
+            &ProjectCommands::Validate {
+                sounds_only: false,
+                collections_only: false,
+                effects_only: false,
+                switches_only: false,
+                switch_containers_only: false,
+                events_only: false,
+                soundbanks_only: false,
+                no_orphan_check: false,
+                summary: false,
+                limit: Default::default(),
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Validate should succeed: {:?}",
+        run.result
+    );
+    let result = run.last_success().expect("Validate should report success");
+    assert_eq!(
+        result["valid"], true,
+        "Unexpected errors: {}",
+        result["errors"]
+    );
+    assert_eq!(result["total_validated"], 21);
+}
+
+#[tokio::test]
+async fn test_p1_unexpected_prompt_fails_without_scripted_response() {
+    let env = setup_test_env().await;
+
+    let run = env
+        .run(
+            &ProjectCommands::Init {
+                name: None,
+                template: Some("default".to_string()),
+                asset_dirs: vec![],
+                no_register: true,
+                with_examples: false,
+                vars: vec![],
+                overwrite_dir: false,
+                replace_registration: false,
+                verify: false,
+                dry_run: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_err(), "Missing scripted response should fail");
+    assert!(
+        !run.calls
+            .iter()
+            .any(|c| matches!(c, OutputCall::Success(_))),
+        "No success should be reported"
+    );
+}
+
+// =============================================================================
+// Project Info --paths Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_project_info_paths_outputs_absolute_directories() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .create_project_dir("paths_project")
+        .expect("Failed to create project");
+
+    let run = env
+        .run_in(
+            &project_path,
+            &ProjectCommands::Info {
+                name: None,
+                path: None,
+                paths: true,
+                check_paths: false,
+                create_missing: false,
+                strict: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Info should succeed: {:?}", run.result);
+    let paths = run.last_success().expect("Should report paths");
+    let root = paths["root"].as_str().expect("root should be a string");
+    assert!(std::path::Path::new(root).is_absolute());
+    assert!(root.ends_with("paths_project"));
+    assert_eq!(
+        paths["sources_dir"],
+        std::path::Path::new(root).join("sources").to_str().unwrap()
+    );
+    assert_eq!(
+        paths["build_dir"],
+        std::path::Path::new(root).join("build").to_str().unwrap()
+    );
+    assert_eq!(
+        paths["data_dir"],
+        std::path::Path::new(root).join("data").to_str().unwrap()
+    );
+    assert_eq!(paths.as_object().unwrap().len(), 4);
+}
+
+#[tokio::test]
+async fn test_p1_project_info_paths_keeps_absolute_directories() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .create_project_dir("absolute_build")
+        .expect("Failed to create project");
+    let shared_build = env.home().join("shared").join("build");
+
+    let mut config = TestEnv::project_configuration("absolute_build");
+    config.build_dir = shared_build.join("..").join("build").display().to_string();
+    fs::write(
+        project_path.join(".amproject"),
+        serde_json::to_string(&config).unwrap(),
+    )
+    .expect("Failed to write .amproject");
+
+    let run = env
+        .run_in(
+            &project_path,
+            &ProjectCommands::Info {
+                name: None,
+                path: None,
+                paths: true,
+                check_paths: false,
+                create_missing: false,
+                strict: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Info should succeed: {:?}", run.result);
+    let paths = run.last_success().expect("Should report paths");
+    assert_eq!(paths["build_dir"], shared_build.to_str().unwrap());
+}
+
+#[tokio::test]
+async fn test_p1_project_info_paths_interactive_prints_key_value_lines() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .create_project_dir("paths_lines")
+        .expect("Failed to create project");
+    let run = env
+        .run(
+            &ProjectCommands::Register {
+                path: Some(project_path.clone()),
+                recursive: false,
+                rename_on_conflict: false,
+                force: false,
+                name: None,
+                stdin: false,
+                strict: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok());
+
+    let run = env
+        .run_with_mode(
+            &ProjectCommands::Info {
+                name: Some("paths_lines".to_string()),
+                path: None,
+                paths: true,
+                check_paths: false,
+                create_missing: false,
+                strict: false,
+            },
+            ScriptedInput::new(),
+            am::presentation::OutputMode::Interactive,
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Info should succeed: {:?}", run.result);
+    let lines: Vec<&str> = run
+        .calls
+        .iter()
+        .filter_map(|c| match c {
+            OutputCall::Print(line) => Some(line.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(lines.len(), 4);
+    assert!(lines[0].starts_with("root="));
+    assert_eq!(
+        lines[1],
+        format!("sources_dir={}", project_path.join("sources").display())
+    );
+    assert!(lines[2].starts_with("build_dir="));
+    assert!(lines[3].starts_with("data_dir="));
+}
+
+#[tokio::test]
+async fn test_p0_project_info_paths_rejects_escaping_directory() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .create_project_dir("escaping_project")
+        .expect("Failed to create project");
+
+    let mut config = TestEnv::project_configuration("escaping_project");
+    config.build_dir = "../outside".to_string();
+    fs::write(
+        project_path.join(".amproject"),
+        serde_json::to_string(&config).unwrap(),
+    )
+    .expect("Failed to write .amproject");
+
+    let run = env
+        .run_in(
+            &project_path,
+            &ProjectCommands::Info {
+                name: None,
+                path: None,
+                paths: true,
+                check_paths: false,
+                create_missing: false,
+                strict: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_err(), "Escaping build_dir should be rejected");
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_VALIDATION_FIELD)
+    );
+    assert!(run.successes().is_empty());
+}
+
+// =============================================================================
+// Project Info --check-paths Tests
+// =============================================================================
+
+fn info_check_paths(create_missing: bool) -> ProjectCommands {
+    ProjectCommands::Info {
+        name: None,
+        path: None,
+        paths: false,
+        check_paths: true,
+        create_missing,
+        strict: false,
+    }
+}
+
+#[tokio::test]
+async fn test_p0_project_info_check_paths_reports_deleted_data_dir() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .create_project_dir("missing_data")
+        .expect("Failed to create project");
+    fs::remove_dir_all(project_path.join("data")).unwrap();
+
+    let run = env
+        .run_in(
+            &project_path,
+            &info_check_paths(false),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Info should succeed: {:?}", run.result);
+    let info = run.last_success().expect("Should report the project");
+    let checks = info["path_checks"].as_array().expect("path_checks array");
+    assert_eq!(checks.len(), 3);
+    let status = |field: &str| {
+        checks
+            .iter()
+            .find(|c| c["field"] == field)
+            .map(|c| c["status"].as_str().unwrap().to_string())
+            .unwrap()
+    };
+    assert_eq!(status("sources_dir"), "ok");
+    assert_eq!(status("data_dir"), "missing");
+    assert_eq!(status("build_dir"), "ok");
+
+    let warnings = info["warnings"].as_array().expect("warnings array");
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].as_str().unwrap().contains("data_dir"));
+    assert!(
+        !project_path.join("data").exists(),
+        "Nothing should be created"
+    );
+}
+
+#[tokio::test]
+async fn test_p0_project_info_check_paths_flags_build_dir_outside_root() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .create_project_dir("outside_build")
+        .expect("Failed to create project");
+
+    let mut config = TestEnv::project_configuration("outside_build");
+    config.build_dir = "../outside".to_string();
+    fs::write(
+        project_path.join(".amproject"),
+        serde_json::to_string(&config).unwrap(),
+    )
+    .expect("Failed to write .amproject");
+
+    let run = env
+        .run_in(&project_path, &info_check_paths(true), ScriptedInput::new())
+        .await;
+
+    assert!(run.result.is_ok(), "Info should succeed: {:?}", run.result);
+    let info = run.last_success().expect("Should report the project");
+    let build = info["path_checks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c["field"] == "build_dir")
+        .expect("build_dir should be checked");
+    assert_eq!(build["status"], "outside_root");
+    assert_eq!(build["value"], "../outside");
+    assert!(
+        info["warnings"][0]
+            .as_str()
+            .unwrap()
+            .contains("outside of the project directory")
+    );
+    assert!(
+        !env.home().join("outside").exists(),
+        "Directories outside the project must never be created"
+    );
+}
+
+#[tokio::test]
+async fn test_p1_project_info_create_missing_creates_directories() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .create_project_dir("recreated_dirs")
+        .expect("Failed to create project");
+    fs::remove_dir_all(project_path.join("data")).unwrap();
+    fs::remove_dir_all(project_path.join("build")).unwrap();
+
+    let run = env
+        .run_in(&project_path, &info_check_paths(true), ScriptedInput::new())
+        .await;
+
+    assert!(run.result.is_ok(), "Info should succeed: {:?}", run.result);
+    let info = run.last_success().expect("Should report the project");
+    for check in info["path_checks"].as_array().unwrap() {
+        let expected = if check["field"] == "sources_dir" {
+            "ok"
+        } else {
+            "created"
+        };
+        assert_eq!(check["status"], expected, "{}", check);
+    }
+    assert!(info.get("warnings").is_none());
+    assert!(project_path.join("data").is_dir());
+    assert!(project_path.join("build").is_dir());
+}
+
+#[tokio::test]
+async fn test_p1_project_info_check_paths_annotates_interactive_output() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .create_project_dir("annotated_paths")
+        .expect("Failed to create project");
+    fs::remove_dir_all(project_path.join("data")).unwrap();
+
+    let run = env
+        .run_in_with_mode(
+            &project_path,
+            &info_check_paths(false),
+            ScriptedInput::new().confirm(false),
+            am::presentation::OutputMode::Interactive,
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Info should succeed: {:?}", run.result);
+    let data_line = run
+        .calls
+        .iter()
+        .find_map(|c| match c {
+            OutputCall::Progress(line) if line.trim_start().starts_with("Data:") => Some(line),
+            _ => None,
+        })
+        .expect("Data path should be shown");
+    assert!(data_line.contains("missing"), "{}", data_line);
+    assert_eq!(run.warnings().len(), 1);
+}
+
+#[tokio::test]
+async fn test_p1_project_validate_warns_about_missing_data_dir() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .create_project_dir("validate_missing_data")
+        .expect("Failed to create project");
+    fs::remove_dir_all(project_path.join("data")).unwrap();
+
+    let run = env
+        .run_in(
+            &project_path,
+            &ProjectCommands::Validate {
+                sounds_only: false,
+                collections_only: false,
+                effects_only: false,
+                switches_only: false,
+                switch_containers_only: false,
+                events_only: false,
+                soundbanks_only: false,
+                no_orphan_check: false,
+                summary: false,
+                limit: Default::default(),
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Validate should succeed: {:?}",
+        run.result
+    );
+    let result = run.last_success().expect("Validate should report success");
+    let warnings = result["warnings"].as_array().expect("warnings array");
+    assert!(
+        warnings
+            .iter()
+            .any(|w| w.as_str().unwrap().contains("'data_dir'")),
+        "{:?}",
+        warnings
+    );
+}
+
+// =============================================================================
+// Strict .amproject Tests
+// =============================================================================
+
+/// Rename the `sources_dir` field of the `.amproject` of `project_path` to `source_dir`.
+fn misspell_sources_dir(project_path: &std::path::Path) {
+    let amproject = project_path.join(".amproject");
+    let content = fs::read_to_string(&amproject).unwrap();
+    fs::write(
+        &amproject,
+        content.replace("\"sources_dir\"", "\"source_dir\""),
+    )
+    .unwrap();
+}
+
+/// Add an unknown `owner` field to the `.amproject` of `project_path`.
+fn add_unknown_amproject_field(project_path: &std::path::Path) {
+    let amproject = project_path.join(".amproject");
+    let mut value: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&amproject).unwrap()).unwrap();
+    value["owner"] = serde_json::json!("audio");
+    fs::write(&amproject, value.to_string()).unwrap();
+}
+
+#[tokio::test]
+async fn test_p0_project_validate_suggests_misspelled_amproject_field() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .create_project_dir("validate_typo")
+        .expect("Failed to create project");
+    misspell_sources_dir(&project_path);
+
+    let run = env
+        .run_in(
+            &project_path,
+            &ProjectCommands::Validate {
+                sounds_only: false,
+                collections_only: false,
+                effects_only: false,
+                switches_only: false,
+                switch_containers_only: false,
+                events_only: false,
+                soundbanks_only: false,
+                no_orphan_check: false,
+                summary: false,
+                limit: Default::default(),
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_VALIDATION_FIELD)
+    );
+    let err = run.result.unwrap_err();
+    let cli_err = err
+        .downcast_ref::<am::common::errors::CliError>()
+        .expect("Expected CliError");
+    assert!(
+        cli_err.why.contains("did you mean 'sources_dir'?"),
+        "{}",
+        cli_err.why
+    );
+}
+
+#[tokio::test]
+async fn test_p1_project_info_strict_rejects_unknown_field() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .create_project_dir("info_strict")
+        .expect("Failed to create project");
+    add_unknown_amproject_field(&project_path);
+
+    let lenient = env
+        .run_in(&project_path, &info_current_dir(), ScriptedInput::new())
+        .await;
+    let strict = env
+        .run_in(
+            &project_path,
+            &ProjectCommands::Info {
+                name: None,
+                path: None,
+                paths: false,
+                check_paths: false,
+                create_missing: false,
+                strict: true,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(
+        lenient.result.is_ok(),
+        "Info should succeed: {:?}",
+        lenient.result
+    );
+    assert_eq!(
+        strict.error_code(),
+        Some(am::common::errors::codes::ERR_VALIDATION_FIELD)
+    );
+}
+
+#[tokio::test]
+async fn test_p1_project_register_strict_registers_nothing_on_unknown_field() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .create_project_dir("register_strict")
+        .expect("Failed to create project");
+    add_unknown_amproject_field(&project_path);
+
+    let run = env
+        .run(
+            &ProjectCommands::Register {
+                path: Some(project_path.clone()),
+                recursive: false,
+                rename_on_conflict: false,
+                force: false,
+                name: None,
+                stdin: false,
+                strict: true,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_VALIDATION_FIELD)
+    );
+    assert!(
+        db_get_project_by_name("register_strict", env.db())
+            .unwrap()
+            .is_none()
+    );
+}
+
+// =============================================================================
+// .amignore Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_amignore_excludes_files_from_walker_based_commands() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .scaffold_project("ignoring_project", 2)
+        .expect("Failed to scaffold project");
+    let sounds_dir = project_path.join("sources").join("sounds");
+    fs::write(sounds_dir.join("broken_draft.json"), "{ not json").unwrap();
+    fs::write(sounds_dir.join("sound_1.json.bak"), "{}").unwrap();
+    fs::write(sounds_dir.join(".DS_Store"), "").unwrap();
+    fs::write(project_path.join(".amignore"), "# drafts\n*_draft.json\n").unwrap();
+
+    let run = env
+        .run(
+            &ProjectCommands::Register {
+                path: Some(project_path.clone()),
+                recursive: false,
+                rename_on_conflict: false,
+                force: false,
+                name: None,
+                stdin: false,
+                strict: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok());
+
+    let run = env
+        .run(
+            &ProjectCommands::Info {
+                name: Some("ignoring_project".to_string()),
+                path: None,
+                paths: false,
+                check_paths: false,
+                create_missing: false,
+                strict: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok(), "Info should succeed: {:?}", run.result);
+    assert_eq!(run.last_success().unwrap()["assets"]["sounds"], 2);
+
+    let run = env
+        .run_in(
+            &project_path,
+            &ProjectCommands::Validate {
+                sounds_only: false,
+                collections_only: false,
+                effects_only: false,
+                switches_only: false,
+                switch_containers_only: false,
+                events_only: false,
+                soundbanks_only: false,
+                no_orphan_check: false,
+                summary: false,
+                limit: Default::default(),
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(
+        run.result.is_ok(),
+        "Validate should succeed: {:?}",
+        run.result
+    );
+    let result = run.last_success().unwrap();
+    assert_eq!(
+        result["valid"], true,
+        "Unexpected errors: {}",
+        result["errors"]
+    );
+    assert_eq!(result["total_validated"], 14);
+
+    let run = env
+        .run_in(
+            &project_path,
+            &AssetCommands::Sound {
+                command: SoundCommands::List {
+                    limit: Default::default(),
+                },
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok(), "List should succeed: {:?}", run.result);
+    let result = run.last_success().unwrap();
+    assert_eq!(result["count"], 2);
+    assert_eq!(result["warnings"].as_array().unwrap().len(), 0);
+}
+
+// =============================================================================
+// Project Hook Tests
+// =============================================================================
+
+/// Scaffold a project declaring `hook` as an executable shell script with `body`.
+#[cfg(unix)]
+fn scaffold_project_with_hook(
+    env: &TestEnv,
+    name: &str,
+    hook: &str,
+    body: &str,
+) -> std::path::PathBuf {
+    use am::common::utils::{read_amproject_file, write_amproject_file};
+    use std::os::unix::fs::PermissionsExt;
+
+    let project_path = env
+        .scaffold_project(name, 1)
+        .expect("Failed to scaffold project");
+
+    let script = project_path.join("scripts").join(format!("{}.sh", hook));
+    fs::create_dir_all(script.parent().unwrap()).unwrap();
+    fs::write(&script, format!("#!/bin/sh\n{}", body)).unwrap();
+    fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+
+    let mut config = read_amproject_file(&project_path).unwrap();
+    config
+        .hooks
+        .insert(hook.to_string(), format!("scripts/{}.sh", hook));
+    write_amproject_file(&project_path, &config).unwrap();
+
+    project_path
+}
+
+#[cfg(unix)]
+fn progress_lines(calls: &[OutputCall]) -> Vec<&str> {
+    calls
+        .iter()
+        .filter_map(|c| match c {
+            OutputCall::Progress(m) => Some(m.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(unix)]
+fn validate_all() -> ProjectCommands {
+    ProjectCommands::Validate {
+        sounds_only: false,
+        collections_only: false,
+        effects_only: false,
+        switches_only: false,
+        switch_containers_only: false,
+        events_only: false,
+        soundbanks_only: false,
+        no_orphan_check: false,
+        summary: false,
+        limit: Default::default(),
+    }
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_p0_run_hook_streams_output_with_project_env() {
+    let env = setup_test_env().await;
+    let project_path = scaffold_project_with_hook(
+        &env,
+        "hooked_project",
+        "generate-ids",
+        "echo \"name=$AM_PROJECT_NAME command=$AM_COMMAND\"\npwd -P\necho \"path=$AM_PROJECT_PATH\" >&2\n",
+    );
+
+    let run = env
+        .run_in(
+            &project_path,
+            &ProjectCommands::RunHook {
+                name: "generate-ids".to_string(),
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Hook should succeed: {:?}", run.result);
+    let lines = progress_lines(&run.calls);
+    assert!(lines.contains(&"name=hooked_project command=run-hook"));
+    let cwd = project_path.canonicalize().unwrap();
+    assert!(lines.contains(&cwd.to_str().unwrap()), "{:?}", lines);
+    let path_line = format!("path={}", cwd.display());
+    assert!(lines.contains(&path_line.as_str()), "{:?}", lines);
+
+    let result = run.last_success().unwrap();
+    assert_eq!(result["hook"], "generate-ids");
+    assert_eq!(result["script"], "scripts/generate-ids.sh");
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_p0_failing_hook_reports_exit_code() {
+    let env = setup_test_env().await;
+    let project_path =
+        scaffold_project_with_hook(&env, "failing_hook", "lint", "echo 'lint failed'\nexit 3\n");
+
+    let run = env
+        .run_in(
+            &project_path,
+            &ProjectCommands::RunHook {
+                name: "lint".to_string(),
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(-29010)); // ERR_HOOK_FAILED
+    let err = run.result.unwrap_err();
+    let cli_err = err
+        .downcast_ref::<am::common::errors::CliError>()
+        .expect("Expected CliError");
+    assert_eq!(cli_err.context.as_deref(), Some("exit code 3"));
+    assert!(progress_lines(&run.calls).contains(&"lint failed"));
+}
+
+#[tokio::test]
+async fn test_p1_run_hook_rejects_undeclared_hook() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .scaffold_project("no_hooks", 1)
+        .expect("Failed to scaffold project");
+
+    let run = env
+        .run_in(
+            &project_path,
+            &ProjectCommands::RunHook {
+                name: "post-init".to_string(),
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(-31002)); // ERR_VALIDATION_FIELD
+}
+
+#[tokio::test]
+async fn test_p1_run_hook_rejects_script_outside_project() {
+    use am::common::utils::{read_amproject_file, write_amproject_file};
+
+    let env = setup_test_env().await;
+    let project_path = env
+        .scaffold_project("escaping_hook", 1)
+        .expect("Failed to scaffold project");
+    let mut config = read_amproject_file(&project_path).unwrap();
+    config
+        .hooks
+        .insert("lint".to_string(), "../outside.sh".to_string());
+    write_amproject_file(&project_path, &config).unwrap();
+
+    let run = env
+        .run_in(
+            &project_path,
+            &ProjectCommands::RunHook {
+                name: "lint".to_string(),
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(-31002)); // ERR_VALIDATION_FIELD
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_p0_validate_runs_post_validate_hook() {
+    let env = setup_test_env().await;
+    let project_path = scaffold_project_with_hook(
+        &env,
+        "post_validate_project",
+        "post-validate",
+        "echo \"after $AM_COMMAND\"\n",
+    );
+
+    let run = env
+        .run_in(&project_path, &validate_all(), ScriptedInput::new())
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Validate should succeed: {:?}",
+        run.result
+    );
+    assert!(progress_lines(&run.calls).contains(&"after validate"));
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_p1_failing_post_validate_hook_fails_validate() {
+    let env = setup_test_env().await;
+    let project_path =
+        scaffold_project_with_hook(&env, "failing_post_validate", "post-validate", "exit 1\n");
+
+    let run = env
+        .run_in(&project_path, &validate_all(), ScriptedInput::new())
+        .await;
+
+    assert_eq!(run.error_code(), Some(-29010)); // ERR_HOOK_FAILED
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_p0_hooks_get_the_amenv_variables() {
+    let env = setup_test_env().await;
+    let project_path = scaffold_project_with_hook(
+        &env,
+        "env_hook",
+        "upload",
+        "echo \"server=$ASSET_SERVER_URL command=$AM_COMMAND\"\n",
+    );
+    fs::write(
+        project_path.join(".amenv"),
+        "# Asset server\nexport ASSET_SERVER_URL=\"https://assets.local/#main\"\nAM_COMMAND=overridden\n",
+    )
+    .unwrap();
+
+    let run = env
+        .run_in(
+            &project_path,
+            &ProjectCommands::RunHook {
+                name: "upload".to_string(),
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Hook should succeed: {:?}", run.result);
+    let lines = progress_lines(&run.calls);
+    assert!(
+        lines.contains(&"server=https://assets.local/#main command=run-hook"),
+        "{:?}",
+        lines
+    );
+}
+
+// =============================================================================
+// Project Env Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_project_env_lists_variables_and_masks_secrets() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .create_project_dir("env_project")
+        .expect("Failed to create project");
+    fs::write(
+        project_path.join(".amenv"),
+        "PLATFORM=switch\r\nASSET_SERVER_TOKEN='abc123'\r\nnot a variable\r\n",
+    )
+    .unwrap();
+
+    let run = env
+        .run_in(
+            &project_path,
+            &ProjectCommands::Env { mask: true },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Env should succeed: {:?}", run.result);
+    let result = run.last_success().expect("Should report the variables");
+    assert_eq!(result["exists"], true);
+    assert_eq!(
+        result["variables"],
+        serde_json::json!([
+            { "key": "PLATFORM", "value": "switch", "line": 1 },
+            { "key": "ASSET_SERVER_TOKEN", "value": "********", "line": 2 },
+        ])
+    );
+    let warnings = result["warnings"].as_array().unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].as_str().unwrap().contains("line 3"));
+}
+
+#[tokio::test]
+async fn test_p1_project_env_without_file_is_empty() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .create_project_dir("no_env_project")
+        .expect("Failed to create project");
+
+    let run = env
+        .run_in(
+            &project_path,
+            &ProjectCommands::Env { mask: false },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Env should succeed: {:?}", run.result);
+    let result = run.last_success().unwrap();
+    assert_eq!(result["exists"], false);
+    assert_eq!(result["variables"], serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn test_p1_project_validate_warns_about_malformed_amenv_lines() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .scaffold_project("malformed_env", 1)
+        .expect("Failed to scaffold project");
+    fs::write(
+        project_path.join(".amenv"),
+        "GOOD=1\n1BAD=2\nQUOTED=\"unterminated\n",
+    )
+    .unwrap();
+
+    let run = env
+        .run_in(
+            &project_path,
+            &ProjectCommands::Validate {
+                sounds_only: false,
+                collections_only: false,
+                effects_only: false,
+                switches_only: false,
+                switch_containers_only: false,
+                events_only: false,
+                soundbanks_only: false,
+                no_orphan_check: false,
+                summary: false,
+                limit: Default::default(),
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Validate should succeed: {:?}",
+        run.result
+    );
+    let result = run.last_success().unwrap();
+    let warnings: Vec<&str> = result["warnings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|w| w.as_str())
+        .filter(|w| w.starts_with(".amenv"))
+        .collect();
+    assert_eq!(warnings.len(), 2, "{:?}", warnings);
+    assert!(warnings[0].starts_with(".amenv line 2:"));
+    assert!(warnings[1].starts_with(".amenv line 3:"));
+}
+
+// =============================================================================
+// Commented JSON Tests
+// =============================================================================
+
+/// Scaffold a project whose first sound has a comment and a trailing comma.
+fn scaffold_project_with_commented_sound(
+    env: &TestEnv,
+    name: &str,
+    allow_json_comments: bool,
+) -> std::path::PathBuf {
+    use am::common::utils::{read_amproject_file, write_amproject_file};
+
+    let project_path = env
+        .scaffold_project(name, 1)
+        .expect("Failed to scaffold project");
+
+    let sound = project_path.join("sources/sounds/sound_1.json");
+    let content = fs::read_to_string(&sound).unwrap();
+    let commented = format!(
+        "// Edited by hand\n{},\n}}\n",
+        content.trim_end().trim_end_matches('}').trim_end()
+    );
+    fs::write(&sound, commented).unwrap();
+
+    let mut config = read_amproject_file(&project_path).unwrap();
+    config.allow_json_comments = allow_json_comments;
+    write_amproject_file(&project_path, &config).unwrap();
+
+    project_path
+}
+
+fn validate_sounds() -> ProjectCommands {
+    ProjectCommands::Validate {
+        sounds_only: true,
+        collections_only: false,
+        effects_only: false,
+        switches_only: false,
+        switch_containers_only: false,
+        events_only: false,
+        soundbanks_only: false,
+        no_orphan_check: false,
+        summary: false,
+        limit: Default::default(),
+    }
+}
+
+#[tokio::test]
+async fn test_p0_strict_validation_flags_comments_as_warnings() {
+    let env = setup_test_env().await;
+    let project_path = scaffold_project_with_commented_sound(&env, "strict_json", false);
+
+    let run = env
+        .run_in(&project_path, &validate_sounds(), ScriptedInput::new())
+        .await;
+
+    assert!(run.result.is_err(), "Strict mode should reject comments");
+    let result = run.last_success().unwrap();
+    assert_eq!(result["valid"], false);
+    let warnings: Vec<&str> = result["warnings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|w| w.as_str())
+        .collect();
+    assert!(
+        warnings
+            .iter()
+            .any(|w| w.contains("sources/sounds/sound_1.json contains comments")),
+        "Expected a comment warning, got: {:?}",
+        warnings
+    );
+}
+
+#[cfg(feature = "json-comments")]
+#[tokio::test]
+async fn test_p0_commented_assets_validate_when_project_allows_comments() {
+    let env = setup_test_env().await;
+    let project_path = scaffold_project_with_commented_sound(&env, "lenient_json", true);
+
+    let run = env
+        .run_in(&project_path, &validate_sounds(), ScriptedInput::new())
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Validate should succeed: {:?}",
+        run.result
+    );
+    let result = run.last_success().unwrap();
+    assert_eq!(
+        result["valid"], true,
+        "Unexpected errors: {}",
+        result["errors"]
+    );
+    assert_eq!(result["warnings"].as_array().unwrap().len(), 0);
+
+    let run = env
+        .run_in(
+            &project_path,
+            &AssetCommands::Sound {
+                command: SoundCommands::List {
+                    limit: Default::default(),
+                },
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok(), "List should succeed: {:?}", run.result);
+    assert_eq!(run.last_success().unwrap()["count"], 1);
+}
+
+#[cfg(not(feature = "json-comments"))]
+#[tokio::test]
+async fn test_p1_allow_json_comments_without_feature_warns() {
+    let env = setup_test_env().await;
+    let project_path = scaffold_project_with_commented_sound(&env, "unsupported_json", true);
+
+    let run = env
+        .run_in(&project_path, &validate_sounds(), ScriptedInput::new())
+        .await;
+
+    assert!(run.result.is_err());
+    let result = run.last_success().unwrap();
+    let warnings = result["warnings"].to_string();
+    assert!(warnings.contains("json-comments feature"), "{}", warnings);
+}
+
+// =============================================================================
+// Asset Directory Override Tests
+// =============================================================================
+
+/// Scaffold a project storing its sounds in `sources/sfx` and its events in
+/// `sources/triggers`.
+fn scaffold_project_with_asset_dirs(env: &TestEnv, name: &str) -> std::path::PathBuf {
+    use am::assets::AssetType;
+    use am::common::utils::write_amproject_file;
+
+    let project_path = env
+        .scaffold_project(name, 2)
+        .expect("Failed to scaffold project");
+
+    let sources = project_path.join("sources");
+    fs::rename(sources.join("sounds"), sources.join("sfx")).unwrap();
+    fs::rename(sources.join("events"), sources.join("triggers")).unwrap();
+
+    let mut config = read_amproject_file(&project_path).unwrap();
+    config
+        .asset_dirs
+        .insert(AssetType::Sound, "sfx".to_string());
+    config
+        .asset_dirs
+        .insert(AssetType::Event, "triggers".to_string());
+    write_amproject_file(&project_path, &config).unwrap();
+
+    project_path
+}
+
+fn validate_all() -> ProjectCommands {
+    ProjectCommands::Validate {
+        sounds_only: false,
+        collections_only: false,
+        effects_only: false,
+        switches_only: false,
+        switch_containers_only: false,
+        events_only: false,
+        soundbanks_only: false,
+        no_orphan_check: false,
+        summary: false,
+        limit: Default::default(),
+    }
+}
+
+#[tokio::test]
+async fn test_p0_project_init_writes_asset_dir_overrides() {
+    use am::assets::AssetType;
+
+    let env = setup_test_env().await;
+
+    let run = env
+        .run_in(
+            env.home(),
+            &ProjectCommands::Init {
+                name: Some("mapped_project".to_string()),
+                template: Some("default".to_string()),
+                asset_dirs: vec!["sound=sfx".to_string(), "event=triggers".to_string()],
+                no_register: true,
+                with_examples: false,
+                vars: vec![],
+                overwrite_dir: false,
+                replace_registration: false,
+                verify: false,
+                dry_run: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok(), "Init should succeed: {:?}", run.result);
+
+    let project_path = env.home().join("mapped_project");
+    let config = read_amproject_file(&project_path).unwrap();
+    assert_eq!(config.asset_dir(AssetType::Sound), "sfx");
+    assert_eq!(config.asset_dir(AssetType::Event), "triggers");
+    assert_eq!(config.asset_dir(AssetType::Collection), "collections");
+
+    let amproject = fs::read_to_string(project_path.join(".amproject")).unwrap();
+    let raw: serde_json::Value = serde_json::from_str(&amproject).unwrap();
+    assert_eq!(
+        raw["asset_dirs"],
+        serde_json::json!({ "sound": "sfx", "event": "triggers" })
+    );
+
+    let sources = project_path.join("sources");
+    assert!(sources.join("sfx").is_dir());
+    assert!(sources.join("triggers").is_dir());
+    assert!(sources.join("collections").is_dir());
+    assert!(!sources.join("sounds").exists());
+    assert!(!sources.join("events").exists());
+}
+
+#[tokio::test]
+async fn test_p1_project_init_rejects_invalid_asset_dir() {
+    let env = setup_test_env().await;
+
+    for spec in ["sound", "voice=vo", "sound=../sfx", "sound=events"] {
+        let run = env
+            .run_in(
+                env.home(),
+                &ProjectCommands::Init {
+                    name: Some("invalid_mapping".to_string()),
+                    template: Some("default".to_string()),
+                    asset_dirs: vec![spec.to_string()],
+                    no_register: true,
+                    with_examples: false,
+                    vars: vec![],
+                    overwrite_dir: false,
+                    replace_registration: false,
+                    verify: false,
+                    dry_run: false,
+                },
+                ScriptedInput::new(),
+            )
+            .await;
+
+        assert!(run.result.is_err(), "'{}' should be rejected", spec);
+        assert_eq!(run.error_code(), Some(-31002), "{}", spec); // ERR_VALIDATION_FIELD
+    }
+
+    assert!(!env.home().join("invalid_mapping").exists());
+}
+
+#[tokio::test]
+async fn test_p0_overridden_asset_dirs_are_counted_listed_and_validated() {
+    let env = setup_test_env().await;
+    let project_path = scaffold_project_with_asset_dirs(&env, "mapped_assets");
+
+    let counts = count_assets_by_type(&project_path).expect("Should count assets");
+    assert_eq!(counts.get("sounds"), Some(&2));
+    assert_eq!(counts.get("events"), Some(&2));
+    assert_eq!(counts.get("collections"), Some(&2));
+
+    let run = env
+        .run_in(
+            &project_path,
+            &AssetCommands::Sound {
+                command: SoundCommands::List {
+                    limit: Default::default(),
+                },
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok(), "List should succeed: {:?}", run.result);
+    assert_eq!(run.last_success().unwrap()["count"], 2);
+
+    let run = env
+        .run_in(&project_path, &validate_all(), ScriptedInput::new())
+        .await;
+    assert!(
+        run.result.is_ok(),
+        "Validate should succeed: {:?}",
+        run.result
+    );
+    let result = run.last_success().unwrap();
+    assert_eq!(
+        result["valid"], true,
+        "Unexpected errors: {}",
+        result["errors"]
+    );
+    assert_eq!(result["total_validated"], 14);
+    assert_eq!(result["summary"]["sounds"], 2);
+    assert_eq!(result["summary"]["events"], 2);
+    assert_eq!(result["warnings"].as_array().unwrap().len(), 0);
+}
+
+#[tokio::test]
+async fn test_p0_sound_create_writes_to_overridden_dir() {
+    let env = setup_test_env().await;
+    let project_path = scaffold_project_with_asset_dirs(&env, "mapped_create");
+
+    let run = env
+        .run_in(
+            &project_path,
+            &AssetCommands::Sound {
+                command: SoundCommands::Create {
+                    name: "explosion".to_string(),
+                    file: Some("sound_1.wav".to_string()),
+                    gain: Some(1.0),
+                    bus: None,
+                    priority: Some(128),
+                    stream: true,
+                    loop_enabled: true,
+                    loop_count: None,
+                    spatialization: Some("none".to_string()),
+                },
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(
+        run.result.is_ok(),
+        "Create should succeed: {:?}",
+        run.result
+    );
+
+    let sources = project_path.join("sources");
+    assert!(sources.join("sfx/explosion.json").is_file());
+    assert!(!sources.join("sounds").exists());
+}
+
+#[tokio::test]
+async fn test_p0_validation_flags_assets_left_in_default_dirs() {
+    let env = setup_test_env().await;
+    let project_path = scaffold_project_with_asset_dirs(&env, "mapped_stray");
+
+    let sources = project_path.join("sources");
+    fs::create_dir_all(sources.join("sounds")).unwrap();
+    fs::copy(
+        sources.join("sfx/sound_1.json"),
+        sources.join("sounds/sound_1.json"),
+    )
+    .unwrap();
+
+    let run = env
+        .run_in(&project_path, &validate_all(), ScriptedInput::new())
+        .await;
+
+    let result = run.last_success().unwrap();
+    let warnings: Vec<&str> = result["warnings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|w| w.as_str())
+        .collect();
+    assert_eq!(warnings.len(), 1, "{:?}", warnings);
+    assert!(
+        warnings[0].contains("sources/sounds contains 1 asset file(s)")
+            && warnings[0].contains("sources/sfx"),
+        "{}",
+        warnings[0]
+    );
+    assert_eq!(result["summary"]["sounds"], 2);
+}
+
+#[tokio::test]
+async fn test_p1_validation_reports_conflicting_asset_dirs() {
+    use am::assets::AssetType;
+    use am::common::utils::write_amproject_file;
+
+    let env = setup_test_env().await;
+    let project_path = env
+        .scaffold_project("conflicting_dirs", 1)
+        .expect("Failed to scaffold project");
+
+    let mut config = read_amproject_file(&project_path).unwrap();
+    config
+        .asset_dirs
+        .insert(AssetType::Sound, "collections".to_string());
+    write_amproject_file(&project_path, &config).unwrap();
+
+    let run = env
+        .run_in(&project_path, &validate_all(), ScriptedInput::new())
+        .await;
+
+    assert!(run.result.is_err(), "Conflicting directories should fail");
+    let result = run.last_success().unwrap();
+    assert_eq!(result["valid"], false);
+    let errors = result["errors"].as_array().unwrap();
+    assert!(
+        errors
+            .iter()
+            .any(|e| e["file"] == ".amproject" && e["field"] == "asset_dirs"),
+        "Expected an asset_dirs error, got: {}",
+        result["errors"]
+    );
+}
+
+// =============================================================================
+// Registration Name Mismatch Tests
+// =============================================================================
+
+/// Register a project as `registered_name`, then rename it to `file_name` in its
+/// `.amproject`, as left behind by a half-done rename.
+async fn register_with_renamed_file(
+    env: &TestEnv,
+    registered_name: &str,
+    file_name: &str,
+) -> std::path::PathBuf {
+    use am::common::utils::write_amproject_file;
+
+    let project_path = env
+        .create_project_dir(registered_name)
+        .expect("Failed to create project");
+    let run = env
+        .run(
+            &ProjectCommands::Register {
+                path: Some(project_path.clone()),
+                recursive: false,
+                rename_on_conflict: false,
+                force: false,
+                name: None,
+                stdin: false,
+                strict: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok());
+
+    let mut config = read_amproject_file(&project_path).unwrap();
+    config.name = file_name.to_string();
+    write_amproject_file(&project_path, &config).unwrap();
+
+    project_path
+}
+
+fn registered_name(env: &TestEnv, project_path: &std::path::Path) -> String {
+    db_get_project_by_path(project_path.to_str().unwrap(), env.db())
+        .expect("Query should succeed")
+        .expect("Project should be registered")
+        .name
+}
+
+fn sync_registration(prefer_db: bool) -> ProjectCommands {
+    ProjectCommands::SyncRegistration {
+        prefer_file: !prefer_db,
+        prefer_db,
+    }
+}
+
+#[tokio::test]
+async fn test_p0_project_info_json_reports_name_mismatch_without_changes() {
+    let env = setup_test_env().await;
+    let project_path = register_with_renamed_file(&env, "foo_old", "foo").await;
+
+    let run = env
+        .run_in(&project_path, &info_current_dir(), ScriptedInput::new())
+        .await;
+
+    assert!(run.result.is_ok(), "Info should succeed: {:?}", run.result);
+    let info = run.last_success().expect("Info should report success");
+    assert_eq!(info["name"], "foo");
+    let warnings = info["warnings"].as_array().expect("Expected warnings");
+    assert_eq!(warnings.len(), 1);
+    let warning = warnings[0].as_str().unwrap();
+    assert!(warning.contains("'foo'") && warning.contains("'foo_old'"));
+
+    assert_eq!(registered_name(&env, &project_path), "foo_old");
+    assert_eq!(read_amproject_file(&project_path).unwrap().name, "foo");
+}
+
+#[tokio::test]
+async fn test_p1_project_info_json_without_mismatch_has_no_warnings() {
+    let env = setup_test_env().await;
+    let project_path = register_with_renamed_file(&env, "foo", "foo").await;
+
+    let run = env
+        .run_in(&project_path, &info_current_dir(), ScriptedInput::new())
+        .await;
+
+    let info = run.last_success().expect("Info should report success");
+    assert!(info.get("warnings").is_none());
+}
+
+#[tokio::test]
+async fn test_p0_project_info_interactive_syncs_registration_to_file() {
+    let env = setup_test_env().await;
+    let project_path = register_with_renamed_file(&env, "foo_old", "foo").await;
+
+    // GIVEN a project registered under another name
+    // WHEN the user chooses to keep the .amproject name
+    let run = env
+        .run_in_with_mode(
+            &project_path,
+            &info_current_dir(),
+            ScriptedInput::new().select("0"),
+            am::presentation::OutputMode::Interactive,
+        )
+        .await;
+
+    // THEN the registration is renamed
+    assert!(run.result.is_ok(), "Info should succeed: {:?}", run.result);
+    assert_eq!(run.warnings().len(), 1);
+    assert_eq!(registered_name(&env, &project_path), "foo");
+    assert_eq!(read_amproject_file(&project_path).unwrap().name, "foo");
+}
+
+#[tokio::test]
+async fn test_p0_project_info_interactive_syncs_file_to_registration() {
+    let env = setup_test_env().await;
+    let project_path = register_with_renamed_file(&env, "foo_old", "foo").await;
+
+    let run = env
+        .run_in_with_mode(
+            &project_path,
+            &info_current_dir(),
+            ScriptedInput::new().select("1"),
+            am::presentation::OutputMode::Interactive,
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Info should succeed: {:?}", run.result);
+    assert_eq!(registered_name(&env, &project_path), "foo_old");
+    assert_eq!(read_amproject_file(&project_path).unwrap().name, "foo_old");
+    assert!(!project_path.join(".amproject.lock").exists());
+}
+
+#[tokio::test]
+async fn test_p1_project_info_interactive_without_selection_changes_nothing() {
+    let env = setup_test_env().await;
+    let project_path = register_with_renamed_file(&env, "foo_old", "foo").await;
+
+    let run = env
+        .run_in_with_mode(
+            &project_path,
+            &info_current_dir(),
+            ScriptedInput::new(),
+            am::presentation::OutputMode::Interactive,
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Info should succeed: {:?}", run.result);
+    assert_eq!(run.warnings().len(), 1);
+    assert_eq!(registered_name(&env, &project_path), "foo_old");
+    assert_eq!(read_amproject_file(&project_path).unwrap().name, "foo");
+}
+
+#[tokio::test]
+async fn test_p0_sync_registration_prefer_file_renames_registration() {
+    let env = setup_test_env().await;
+    let project_path = register_with_renamed_file(&env, "foo_old", "foo").await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &sync_registration(false),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Sync should succeed: {:?}", run.result);
+    assert_eq!(registered_name(&env, &project_path), "foo");
+    assert_eq!(read_amproject_file(&project_path).unwrap().name, "foo");
+}
+
+#[tokio::test]
+async fn test_p0_sync_registration_prefer_db_renames_amproject() {
+    let env = setup_test_env().await;
+    let project_path = register_with_renamed_file(&env, "foo_old", "foo").await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &sync_registration(true),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Sync should succeed: {:?}", run.result);
+    assert_eq!(registered_name(&env, &project_path), "foo_old");
+    assert_eq!(read_amproject_file(&project_path).unwrap().name, "foo_old");
+}
+
+#[tokio::test]
+async fn test_p1_sync_registration_prefer_file_fails_when_name_is_taken() {
+    let env = setup_test_env().await;
+    let project_path = register_with_renamed_file(&env, "foo_old", "foo").await;
+    let other = env.create_project_dir("foo").expect("Failed to create");
+    let run = env
+        .run(
+            &ProjectCommands::Register {
+                path: Some(other),
+                recursive: false,
+                rename_on_conflict: false,
+                force: false,
+                name: None,
+                stdin: false,
+                strict: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok());
+
+    let run = env
+        .run_in(
+            &project_path,
+            &sync_registration(false),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_PROJECT_ALREADY_EXISTS)
+    );
+    assert_eq!(registered_name(&env, &project_path), "foo_old");
+}
+
+#[tokio::test]
+async fn test_p1_sync_registration_unregistered_project_fails() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .create_project_dir("unregistered")
+        .expect("Failed to create project");
+
+    let run = env
+        .run_in(
+            &project_path,
+            &sync_registration(false),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_PROJECT_NOT_REGISTERED)
+    );
+}
+
+#[test]
+fn test_p1_sync_registration_requires_exactly_one_preference() {
+    use am::app::App;
+    use clap::Parser;
+
+    assert!(App::try_parse_from(["am", "project", "sync-registration"]).is_err());
+    assert!(
+        App::try_parse_from([
+            "am",
+            "project",
+            "sync-registration",
+            "--prefer-file",
+            "--prefer-db"
+        ])
+        .is_err()
+    );
+    assert!(App::try_parse_from(["am", "project", "sync-registration", "--prefer-db"]).is_ok());
+}
+
+// =============================================================================
+// Non-UTF-8 Path Tests
+// =============================================================================
+//
+// Linux only: macOS filesystems refuse to create non-UTF-8 names.
+
+#[cfg(target_os = "linux")]
+fn non_utf8_dir(env: &TestEnv) -> std::path::PathBuf {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let dir = env.home().join(OsStr::from_bytes(b"audio_\xff_projects"));
+    fs::create_dir_all(&dir).expect("Failed to create non-UTF-8 directory");
+    dir
+}
+
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn test_p1_project_register_rejects_non_utf8_path() {
+    let env = setup_test_env().await;
+    let template = env
+        .create_project_dir("utf8_template")
+        .expect("Failed to create project");
+    let project_path = non_utf8_dir(&env);
+    fs::copy(template.join(".amproject"), project_path.join(".amproject"))
+        .expect("Failed to copy .amproject");
+
+    let run = env
+        .run(
+            &ProjectCommands::Register {
+                path: Some(project_path),
+                recursive: false,
+                rename_on_conflict: false,
+                force: false,
+                name: None,
+                stdin: false,
+                strict: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_VALIDATION_FORMAT)
+    );
+    assert!(
+        db_get_all_projects(env.db()).unwrap().is_empty(),
+        "Nothing should be registered"
+    );
+}
+
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn test_p1_project_init_in_non_utf8_directory_fails_before_writing() {
+    let env = setup_test_env().await;
+    let parent = non_utf8_dir(&env);
+
+    let run = env
+        .run_in(
+            &parent,
+            &ProjectCommands::Init {
+                name: Some("utf8_name".to_string()),
+                template: Some("default".to_string()),
+                asset_dirs: vec![],
+                no_register: false,
+                with_examples: false,
+                vars: vec![],
+                overwrite_dir: false,
+                replace_registration: false,
+                verify: false,
+                dry_run: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_VALIDATION_FORMAT)
+    );
+    assert!(!parent.join("utf8_name").exists());
+    assert!(db_get_all_projects(env.db()).unwrap().is_empty());
+}
+
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn test_p1_project_info_in_non_utf8_directory_fails_without_panicking() {
+    let env = setup_test_env().await;
+    let template = env
+        .create_project_dir("utf8_template")
+        .expect("Failed to create project");
+    let project_path = non_utf8_dir(&env);
+    fs::copy(template.join(".amproject"), project_path.join(".amproject"))
+        .expect("Failed to copy .amproject");
+
+    let run = env
+        .run_in(&project_path, &info_current_dir(), ScriptedInput::new())
+        .await;
+
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_VALIDATION_FORMAT)
+    );
+}
+
+// =============================================================================
+// Bulk Unregister Tests
+// =============================================================================
+
+async fn register_named(env: &TestEnv, names: &[&str]) {
+    for name in names {
+        let path = env.create_project_dir(name).expect("Failed to create");
+        let run = env
+            .run(
+                &ProjectCommands::Register {
+                    path: Some(path),
+                    recursive: false,
+                    rename_on_conflict: false,
+                    force: false,
+                    name: None,
+                    stdin: false,
+                    strict: false,
+                },
+                ScriptedInput::new(),
+            )
+            .await;
+        assert!(run.result.is_ok());
+    }
+}
+
+fn unregister_matching(pattern: &str, yes: bool) -> ProjectCommands {
+    ProjectCommands::Unregister {
+        name: None,
+        pattern: Some(pattern.to_string()),
+        delete_files: false,
+        yes,
+        fail_empty: false,
+    }
+}
+
+fn registered_names(env: &TestEnv) -> Vec<String> {
+    let mut names: Vec<String> = db_get_all_projects(env.db())
+        .unwrap()
+        .into_iter()
+        .map(|p| p.name)
+        .collect();
+    names.sort();
+    names
+}
+
+#[tokio::test]
+async fn test_p0_unregister_match_with_yes_removes_matching_projects() {
+    let env = setup_test_env().await;
+    register_named(&env, &["test_a", "test_b", "game"]).await;
+
+    let run = env
+        .run(&unregister_matching("test_*", true), ScriptedInput::new())
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Unregister should succeed: {:?}",
+        run.result
+    );
+    assert_eq!(registered_names(&env), vec!["game"]);
+
+    let result = run.last_success().expect("Expected a result");
+    let projects = result["projects"].as_array().unwrap();
+    assert_eq!(projects.len(), 2);
+    assert_eq!(projects[0]["name"], "test_a");
+    assert_eq!(projects[0]["unregistered"], true);
+    assert_eq!(projects[1]["name"], "test_b");
+}
+
+#[tokio::test]
+async fn test_p0_unregister_match_asks_confirmation() {
+    let env = setup_test_env().await;
+    register_named(&env, &["test_a", "test_b"]).await;
+
+    // GIVEN the user declines the confirmation
+    let run = env
+        .run(
+            &unregister_matching("test_*", false),
+            ScriptedInput::new().confirm(false),
+        )
+        .await;
+
+    // THEN nothing is unregistered
+    assert!(run.result.is_ok());
+    assert_eq!(run.unused_responses, 0);
+    assert_eq!(registered_names(&env), vec!["test_a", "test_b"]);
+
+    // WHEN the user confirms
+    let run = env
+        .run(
+            &unregister_matching("test_*", false),
+            ScriptedInput::new().confirm(true),
+        )
+        .await;
+
+    // THEN every matched project is unregistered
+    assert!(run.result.is_ok());
+    assert!(registered_names(&env).is_empty());
+}
+
+#[tokio::test]
+async fn test_p1_unregister_match_without_confirmation_fails_non_interactively() {
+    let env = setup_test_env().await;
+    register_named(&env, &["test_a"]).await;
+
+    let run = env
+        .run(&unregister_matching("test_*", false), ScriptedInput::new())
+        .await;
+
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_VALIDATION_FIELD)
+    );
+    assert_eq!(registered_names(&env), vec!["test_a"]);
+}
+
+#[tokio::test]
+async fn test_p1_unregister_match_nothing_is_not_an_error() {
+    let env = setup_test_env().await;
+    register_named(&env, &["game"]).await;
+
+    let run = env
+        .run(&unregister_matching("test_*", false), ScriptedInput::new())
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Empty match should succeed: {:?}",
+        run.result
+    );
+    let result = run.last_success().expect("Expected a result");
+    assert_eq!(result["projects"], serde_json::json!([]));
+    assert_eq!(registered_names(&env), vec!["game"]);
+}
+
+#[tokio::test]
+async fn test_p1_unregister_match_nothing_with_fail_empty_fails() {
+    let env = setup_test_env().await;
+
+    let run = env
+        .run(
+            &ProjectCommands::Unregister {
+                name: None,
+                pattern: Some("test_*".to_string()),
+                delete_files: false,
+                yes: true,
+                fail_empty: true,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_PROJECT_NOT_REGISTERED)
+    );
+}
+
+#[tokio::test]
+async fn test_p1_unregister_match_deletes_files_per_project() {
+    let env = setup_test_env().await;
+    register_named(&env, &["test_a", "test_b"]).await;
+
+    let run = env
+        .run(
+            &ProjectCommands::Unregister {
+                name: None,
+                pattern: Some("test_[a]".to_string()),
+                delete_files: true,
+                yes: true,
+                fail_empty: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Unregister should succeed: {:?}",
+        run.result
+    );
+    let result = run.last_success().expect("Expected a result");
+    assert_eq!(result["projects"][0]["files_deleted"], true);
+    assert!(!env.home().join("test_a").exists());
+    assert!(env.home().join("test_b").exists());
+    assert_eq!(registered_names(&env), vec!["test_b"]);
+}
+
+#[test]
+fn test_p1_unregister_takes_a_name_or_a_match() {
+    use am::app::App;
+    use clap::Parser;
+
+    let parse = |args: &[&str]| {
+        App::try_parse_from(["am", "project", "unregister"].iter().chain(args.iter()))
+    };
+
+    assert!(parse(&["game"]).is_ok());
+    assert!(parse(&["game", "--yes"]).is_ok());
+    assert!(parse(&[]).is_ok());
+    assert!(parse(&["--match", "test_*", "--yes", "--fail-empty"]).is_ok());
+    assert!(parse(&["game", "--match", "test_*"]).is_err());
+    assert!(parse(&["game", "--fail-empty"]).is_err());
+}
+
+fn unregister_named(name: Option<&str>, yes: bool) -> ProjectCommands {
+    ProjectCommands::Unregister {
+        name: name.map(str::to_string),
+        pattern: None,
+        delete_files: false,
+        yes,
+        fail_empty: false,
+    }
+}
+
+#[tokio::test]
+async fn test_p0_unregister_reports_name_and_path() {
+    let env = setup_test_env().await;
+    register_named(&env, &["game"]).await;
+
+    let run = env
+        .run(&unregister_named(Some("game"), true), ScriptedInput::new())
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Unregister should succeed: {:?}",
+        run.result
+    );
+    let result = run.last_success().expect("Expected a result");
+    assert_eq!(result["name"], "game");
+    assert!(result["path"].as_str().unwrap().ends_with("game"));
+    assert_eq!(result["files_deleted"], false);
+    assert!(registered_names(&env).is_empty());
+    assert!(env.home().join("game").exists(), "Files should remain");
+}
+
+#[tokio::test]
+async fn test_p0_unregister_unknown_project_fails() {
+    let env = setup_test_env().await;
+
+    let run = env
+        .run(
+            &unregister_named(Some("missing"), true),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_PROJECT_NOT_REGISTERED)
+    );
+}
+
+#[tokio::test]
+async fn test_p1_unregister_declined_keeps_project() {
+    let env = setup_test_env().await;
+    register_named(&env, &["game"]).await;
+
+    let run = env
+        .run(
+            &unregister_named(Some("game"), false),
+            ScriptedInput::new().confirm(false),
+        )
+        .await;
+
+    assert!(run.result.is_ok());
+    assert_eq!(registered_names(&env), vec!["game"]);
+
+    // Without a confirmation prompt, --yes is required
+    let run = env
+        .run(&unregister_named(Some("game"), false), ScriptedInput::new())
+        .await;
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_VALIDATION_FIELD)
+    );
+    assert_eq!(registered_names(&env), vec!["game"]);
+}
+
+#[tokio::test]
+async fn test_p1_unregister_without_name_selects_project() {
+    let env = setup_test_env().await;
+    register_named(&env, &["alpha", "beta"]).await;
+
+    let run = env
+        .run(
+            &unregister_named(None, false),
+            ScriptedInput::new().select("beta").confirm(true),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Unregister should succeed: {:?}",
+        run.result
+    );
+    assert_eq!(registered_names(&env), vec!["alpha"]);
+}
+
+// =============================================================================
+// Example Assets Tests
+// =============================================================================
+
+fn init_with_examples(name: &str, asset_dirs: Vec<String>) -> ProjectCommands {
+    ProjectCommands::Init {
+        name: Some(name.to_string()),
+        template: Some("default".to_string()),
+        asset_dirs,
+        no_register: true,
+        with_examples: true,
+        vars: vec![],
+        overwrite_dir: false,
+        replace_registration: false,
+        verify: false,
+        dry_run: false,
+    }
+}
+
+#[tokio::test]
+async fn test_p0_project_init_with_examples_passes_validation() {
+    let env = setup_test_env().await;
+
+    // GIVEN a project initialized with the example assets
+    let run = env
+        .run_in(
+            env.home(),
+            &init_with_examples("examples_project", vec![]),
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok(), "Init should succeed: {:?}", run.result);
+
+    let project_path = env.home().join("examples_project");
+    let sources = project_path.join("sources");
+    assert!(sources.join("sounds").join("example_sound.json").is_file());
+    assert!(sources.join("events").join("example_event.json").is_file());
+    assert!(
+        sources
+            .join("collections")
+            .join("example_collection.json")
+            .is_file()
+    );
+    assert!(
+        project_path
+            .join("data")
+            .join("example_sound.wav")
+            .is_file()
+    );
+
+    // WHEN the project is validated
+    let run = env
+        .run_in(&project_path, &validate_all(), ScriptedInput::new())
+        .await;
+
+    // THEN every example asset is valid
+    assert!(
+        run.result.is_ok(),
+        "Validate should succeed: {:?}",
+        run.result
+    );
+    let result = run.last_success().expect("Validate should report success");
+    assert_eq!(
+        result["valid"], true,
+        "Unexpected errors: {}",
+        result["errors"]
+    );
+    assert_eq!(result["total_validated"], 3);
+}
+
+#[tokio::test]
+async fn test_p1_project_init_with_examples_honors_asset_dir_overrides() {
+    let env = setup_test_env().await;
+
+    let run = env
+        .run_in(
+            env.home(),
+            &init_with_examples(
+                "mapped_examples",
+                vec!["sound=sfx".to_string(), "event=triggers".to_string()],
+            ),
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok(), "Init should succeed: {:?}", run.result);
+
+    let project_path = env.home().join("mapped_examples");
+    let sources = project_path.join("sources");
+    assert!(sources.join("sfx").join("example_sound.json").is_file());
+    assert!(
+        sources
+            .join("triggers")
+            .join("example_event.json")
+            .is_file()
+    );
+    assert!(!sources.join("sounds").exists());
+
+    let run = env
+        .run_in(&project_path, &validate_all(), ScriptedInput::new())
+        .await;
+    let result = run.last_success().expect("Validate should report success");
+    assert_eq!(
+        result["valid"], true,
+        "Unexpected errors: {}",
+        result["errors"]
+    );
+    assert_eq!(result["total_validated"], 3);
+}
+
+#[tokio::test]
+async fn test_p1_project_init_without_examples_has_no_assets() {
+    let env = setup_test_env().await;
+
+    let run = env
+        .run_in(
+            env.home(),
+            &ProjectCommands::Init {
+                name: Some("empty_project".to_string()),
+                template: Some("default".to_string()),
+                asset_dirs: vec![],
+                no_register: true,
+                with_examples: false,
+                vars: vec![],
+                overwrite_dir: false,
+                replace_registration: false,
+                verify: false,
+                dry_run: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok(), "Init should succeed: {:?}", run.result);
+
+    let counts = count_assets_by_type(&env.home().join("empty_project")).unwrap();
+    assert!(counts.values().all(|&count| count == 0), "{:?}", counts);
+    assert!(
+        !env.home()
+            .join("empty_project")
+            .join("data")
+            .join("example_sound.wav")
+            .exists()
+    );
+}
+
+// =============================================================================
+// Dry Run Tests
+// =============================================================================
+
+fn init_dry_run(name: &str, dry_run: bool) -> ProjectCommands {
+    ProjectCommands::Init {
+        name: Some(name.to_string()),
+        template: Some("default".to_string()),
+        asset_dirs: vec!["sound=sfx".to_string()],
+        no_register: false,
+        with_examples: true,
+        vars: vec![],
+        overwrite_dir: true,
+        replace_registration: false,
+        verify: false,
+        dry_run,
+    }
+}
+
+/// The directories and files, with their size, below `root`, as the plan lists them.
+fn project_tree(root: &std::path::Path) -> (Vec<String>, Vec<serde_json::Value>) {
+    fn visit(
+        root: &std::path::Path,
+        dir: &std::path::Path,
+        directories: &mut Vec<String>,
+        files: &mut Vec<serde_json::Value>,
+    ) {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            let relative = path
+                .strip_prefix(root)
+                .unwrap()
+                .to_string_lossy()
+                .replace('\\', "/");
+            if path.is_dir() {
+                directories.push(relative);
+                visit(root, &path, directories, files);
+            } else {
+                let size = fs::metadata(&path).unwrap().len();
+                files.push(serde_json::json!({ "path": relative, "size": size }));
+            }
+        }
+    }
+
+    let (mut directories, mut files) = (Vec::new(), Vec::new());
+    visit(root, root, &mut directories, &mut files);
+    directories.sort();
+    files.sort_by(|a, b| a["path"].as_str().cmp(&b["path"].as_str()));
+    (directories, files)
+}
+
+#[tokio::test]
+async fn test_p0_project_init_dry_run_creates_nothing() {
+    let env = setup_test_env().await;
+
+    let run = env
+        .run_in(
+            env.home(),
+            &init_dry_run("planned", true),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Dry run should succeed: {:?}",
+        run.result
+    );
+    let plan = run.last_success().expect("Dry run should report a plan");
+    assert_eq!(plan["dry_run"], true);
+    assert_eq!(plan["registered"], true);
+    assert!(!env.home().join("planned").exists());
+    assert!(registered_names(&env).is_empty());
+}
+
+#[tokio::test]
+async fn test_p0_project_init_dry_run_plan_matches_created_project() {
+    let env = setup_test_env().await;
+
+    let run = env
+        .run_in(
+            env.home(),
+            &init_dry_run("planned", true),
+            ScriptedInput::new(),
+        )
+        .await;
+    let plan = run.last_success().expect("Dry run should report a plan");
+
+    let run = env
+        .run_in(
+            env.home(),
+            &init_dry_run("planned", false),
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok(), "Init should succeed: {:?}", run.result);
+
+    let (directories, files) = project_tree(&env.home().join("planned"));
+    let mut planned_directories: Vec<String> = plan["directories"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|dir| dir.as_str().unwrap().to_string())
+        .collect();
+    planned_directories.sort();
+    let mut planned_files = plan["files"].as_array().unwrap().clone();
+    planned_files.sort_by(|a, b| a["path"].as_str().cmp(&b["path"].as_str()));
+    assert_eq!(planned_directories, directories);
+    assert_eq!(planned_files, files);
+    assert_eq!(registered_names(&env), vec!["planned"]);
+}
+
+#[tokio::test]
+async fn test_p1_project_init_dry_run_lists_deletions_without_deleting() {
+    let env = setup_test_env().await;
+    let existing = env.home().join("planned");
+    fs::create_dir_all(&existing).unwrap();
+    fs::write(existing.join("keep.txt"), "keep").unwrap();
+
+    let run = env
+        .run_in(
+            env.home(),
+            &init_dry_run("planned", true),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    let plan = run.last_success().expect("Dry run should report a plan");
+    assert_eq!(plan["deleted"].as_array().unwrap().len(), 1);
+    assert!(existing.join("keep.txt").is_file());
+    assert!(!existing.join(".amproject").exists());
+}
+
+#[tokio::test]
+async fn test_p2_project_init_dry_run_prints_tree_interactively() {
+    let env = setup_test_env().await;
+
+    let run = env
+        .run_in_with_mode(
+            env.home(),
+            &init_dry_run("planned", true),
+            ScriptedInput::new(),
+            am::presentation::OutputMode::Interactive,
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Dry run should succeed: {:?}",
+        run.result
+    );
+    let printed: Vec<&str> = run
+        .calls
+        .iter()
+        .filter_map(|call| match call {
+            OutputCall::Print(text) => Some(text.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert!(
+        printed
+            .iter()
+            .any(|text| text.contains("\n    pc.config.json ("))
+    );
+    assert!(!env.home().join("planned").exists());
+}
+
+// =============================================================================
+// Init Conflict Tests
+// =============================================================================
+
+fn init_resolving(name: &str, overwrite_dir: bool, replace_registration: bool) -> ProjectCommands {
+    ProjectCommands::Init {
+        name: Some(name.to_string()),
+        template: Some("default".to_string()),
+        asset_dirs: vec![],
+        no_register: false,
+        with_examples: false,
+        vars: vec![],
+        overwrite_dir,
+        replace_registration,
+        verify: false,
+        dry_run: false,
+    }
+}
+
+/// Register `name` at another location and leave a non-empty `home/<name>` directory, so
+/// that initializing `name` in `home` hits both conflicts.
+fn setup_init_conflicts(env: &TestEnv, name: &str) -> std::path::PathBuf {
+    let elsewhere = env.home().join("elsewhere");
+    db_create_project(
+        &Project {
+            id: None,
+            name: name.to_string(),
+            path: elsewhere.to_str().unwrap().to_string(),
+            registered_at: None,
+            is_favorite: false,
+        },
+        env.db(),
+    )
+    .expect("Registration should succeed");
+
+    let project_path = env.home().join(name);
+    fs::create_dir_all(&project_path).unwrap();
+    fs::write(project_path.join("notes.txt"), "keep me").unwrap();
+    project_path
+}
+
+#[tokio::test]
+async fn test_p0_init_reports_conflicts_without_resolving_flags() {
+    let env = setup_test_env().await;
+    let project_path = setup_init_conflicts(&env, "clash");
+
+    // WHEN init runs non-interactively without resolving flags
+    let run = env
+        .run_in(
+            env.home(),
+            &init_resolving("clash", false, false),
+            ScriptedInput::new().non_interactive(),
+        )
+        .await;
+
+    // THEN every conflict is reported, in order, with the flag resolving it
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_PROJECT_ALREADY_EXISTS)
+    );
+    let result = run.last_success().expect("Conflicts should be reported");
+    assert_eq!(result["created"], false);
+    let conflicts = result["conflicts"].as_array().unwrap();
+    assert_eq!(conflicts.len(), 2);
+    assert_eq!(conflicts[0]["kind"], "registered_name");
+    assert_eq!(conflicts[0]["resolution"], "--replace-registration");
+    assert_eq!(conflicts[1]["kind"], "existing_directory");
+    assert_eq!(conflicts[1]["resolution"], "--overwrite-dir");
+
+    // AND nothing was changed
+    assert!(project_path.join("notes.txt").exists());
+    let registered = db_get_project_by_name("clash", env.db()).unwrap().unwrap();
+    assert!(registered.path.ends_with("elsewhere"));
+}
+
+#[tokio::test]
+async fn test_p0_init_resolves_conflicts_with_flags() {
+    let env = setup_test_env().await;
+    let project_path = setup_init_conflicts(&env, "clash");
+
+    let run = env
+        .run_in(
+            env.home(),
+            &init_resolving("clash", true, true),
+            ScriptedInput::new().non_interactive(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Init should succeed: {:?}", run.result);
+    assert!(!project_path.join("notes.txt").exists());
+    assert!(project_path.join(".amproject").is_file());
+    let registered = db_get_project_by_name("clash", env.db()).unwrap().unwrap();
+    assert!(registered.path.ends_with("clash"), "{}", registered.path);
+}
+
+#[tokio::test]
+async fn test_p1_init_reports_only_conflicts_left_by_flags() {
+    let env = setup_test_env().await;
+    let project_path = setup_init_conflicts(&env, "clash");
+
+    let run = env
+        .run_in(
+            env.home(),
+            &init_resolving("clash", true, false),
+            ScriptedInput::new().non_interactive(),
+        )
+        .await;
+
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_PROJECT_ALREADY_EXISTS)
+    );
+    let conflicts = run.last_success().unwrap()["conflicts"].clone();
+    assert_eq!(conflicts.as_array().unwrap().len(), 1);
+    assert_eq!(conflicts[0]["kind"], "registered_name");
+    assert!(
+        project_path.join("notes.txt").exists(),
+        "No resolution should run while a conflict is left"
+    );
+}
+
+#[tokio::test]
+async fn test_p1_init_prompts_for_each_conflict_interactively() {
+    let env = setup_test_env().await;
+    let project_path = setup_init_conflicts(&env, "clash");
+
+    let run = env
+        .run_in(
+            env.home(),
+            &init_resolving("clash", false, false),
+            ScriptedInput::new().confirm(true).confirm(true),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Init should succeed: {:?}", run.result);
+    assert_eq!(run.unused_responses, 0);
+    assert!(!project_path.join("notes.txt").exists());
+}
+
+#[tokio::test]
+async fn test_p1_init_declined_conflict_changes_nothing() {
+    let env = setup_test_env().await;
+    let project_path = setup_init_conflicts(&env, "clash");
+
+    // GIVEN the user accepts to replace the registration but keeps the directory
+    let run = env
+        .run_in(
+            env.home(),
+            &init_resolving("clash", false, false),
+            ScriptedInput::new().confirm(true).confirm(false),
+        )
+        .await;
+
+    // THEN init fails before resolving anything
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_PROJECT_ALREADY_EXISTS)
+    );
+    assert!(project_path.join("notes.txt").exists());
+    let registered = db_get_project_by_name("clash", env.db()).unwrap().unwrap();
+    assert!(registered.path.ends_with("elsewhere"));
+}
+
+#[tokio::test]
+async fn test_p1_init_reports_stale_template_as_unresolvable() {
+    use am::database::{
+        db_create_template,
+        entities::{Template, TemplateSource},
+    };
+
+    let env = setup_test_env().await;
+    db_create_template(
+        &Template {
+            id: None,
+            name: "moved".to_string(),
+            path: env.home().join("missing_template").display().to_string(),
+            engine: None,
+            description: None,
+            source: TemplateSource::Custom,
+        },
+        env.db(),
+    )
+    .expect("Template registration should succeed");
+
+    let run = env
+        .run_in(
+            env.home(),
+            &ProjectCommands::Init {
+                name: Some("from_moved".to_string()),
+                template: Some("moved".to_string()),
+                asset_dirs: vec![],
+                no_register: true,
+                with_examples: false,
+                vars: vec![],
+                overwrite_dir: true,
+                replace_registration: false,
+                verify: false,
+                dry_run: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(-31002)); // ERR_VALIDATION_FIELD
+    let conflicts = run.last_success().unwrap()["conflicts"].clone();
+    assert_eq!(conflicts[0]["kind"], "stale_template");
+    assert_eq!(conflicts[0]["template"], "moved");
+    assert!(conflicts[0]["resolution"].is_null());
+    assert!(!env.home().join("from_moved").exists());
+}
+
+#[test]
+fn test_p2_replace_registration_conflicts_with_no_register() {
+    use am::app::App;
+    use clap::Parser;
+
+    let result = App::try_parse_from([
+        "am",
+        "project",
+        "init",
+        "game",
+        "--no-register",
+        "--replace-registration",
+    ]);
+    assert!(result.is_err());
+}
+
+// =============================================================================
+// Registration Name Conflict Tests
+// =============================================================================
+
+fn register_command(
+    path: std::path::PathBuf,
+    recursive: bool,
+    rename_on_conflict: bool,
+) -> ProjectCommands {
+    ProjectCommands::Register {
+        path: Some(path),
+        recursive,
+        rename_on_conflict,
+        force: false,
+        name: None,
+        stdin: false,
+        strict: false,
+    }
+}
+
+/// Write a `.amproject` declaring `name` in `dir`.
+fn declare_project(dir: &std::path::Path, name: &str) {
+    use am::common::utils::write_amproject_file;
+
+    fs::create_dir_all(dir).unwrap();
+    let config = ProjectConfiguration {
+        name: name.to_string(),
+        sources_dir: "sources".to_string(),
+        data_dir: "data".to_string(),
+        build_dir: "build".to_string(),
+        version: 1,
+        ..Default::default()
+    };
+    write_amproject_file(dir, &config).unwrap();
+}
+
+#[tokio::test]
+async fn test_p0_register_recursive_registers_every_project() {
+    let env = setup_test_env().await;
+    let root = env.home().join("batch");
+    declare_project(&root.join("alpha"), "alpha");
+    declare_project(&root.join("nested").join("beta"), "beta");
+    declare_project(&root.join(".hidden").join("gamma"), "gamma");
+
+    let run = env
+        .run(
+            &register_command(root.clone(), true, false),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Register should succeed: {:?}",
+        run.result
+    );
+    let result = run.last_success().unwrap();
+    let projects = result["projects"].as_array().unwrap();
+    assert_eq!(projects.len(), 2);
+    assert_eq!(projects[0]["name"], "alpha");
+    assert_eq!(projects[1]["name"], "beta");
+    assert!(result["conflicts"].as_array().unwrap().is_empty());
+    assert_eq!(registered_names(&env), vec!["alpha", "beta"]);
+}
+
+#[tokio::test]
+async fn test_p0_register_recursive_reports_collisions_within_the_batch() {
+    let env = setup_test_env().await;
+    let root = env.home().join("batch");
+    declare_project(&root.join("a"), "game");
+    declare_project(&root.join("b"), "game");
+    declare_project(&root.join("c"), "tools");
+
+    let run = env
+        .run(
+            &register_command(root.clone(), true, false),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    // THEN the collision is reported and nothing is registered
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_PROJECT_ALREADY_EXISTS)
+    );
+    let conflicts = run.last_success().unwrap()["conflicts"].clone();
+    assert_eq!(conflicts.as_array().unwrap().len(), 1);
+    assert_eq!(conflicts[0]["name"], "game");
+    assert!(conflicts[0]["registered_path"].is_null());
+    assert_eq!(
+        conflicts[0]["conflicting_paths"],
+        serde_json::json!([
+            root.join("a").display().to_string(),
+            root.join("b").display().to_string()
+        ])
+    );
+    assert!(registered_names(&env).is_empty());
+}
+
+#[tokio::test]
+async fn test_p0_register_recursive_reports_collisions_with_the_registry() {
+    let env = setup_test_env().await;
+    let existing = env.create_project_dir("game").expect("Failed to create");
+    let run = env
+        .run(
+            &register_command(existing.clone(), false, false),
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok());
+
+    let root = env.home().join("batch");
+    declare_project(&root.join("copy"), "game");
+
+    let run = env
+        .run(
+            &register_command(root.clone(), true, false),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_PROJECT_ALREADY_EXISTS)
+    );
+    let conflicts = run.last_success().unwrap()["conflicts"].clone();
+    assert_eq!(
+        conflicts[0]["registered_path"],
+        existing.display().to_string()
+    );
+    assert_eq!(
+        conflicts[0]["conflicting_paths"],
+        serde_json::json!([root.join("copy").display().to_string()])
+    );
+    assert_eq!(registered_names(&env), vec!["game"]);
+}
+
+#[tokio::test]
+async fn test_p0_register_recursive_renames_later_duplicates() {
+    let env = setup_test_env().await;
+    let root = env.home().join("batch");
+    declare_project(&root.join("a"), "game");
+    declare_project(&root.join("b"), "game");
+    declare_project(&root.join("c"), "game_2");
+
+    let run = env
+        .run(
+            &register_command(root.clone(), true, true),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Register should succeed: {:?}",
+        run.result
+    );
+    let result = run.last_success().unwrap();
+    let projects = result["projects"].as_array().unwrap();
+    assert_eq!(projects[1]["name"], "game_3");
+    assert_eq!(projects[1]["renamed_from"], "game");
+    assert_eq!(read_amproject_file(&root.join("b")).unwrap().name, "game_3");
+    assert_eq!(registered_names(&env), vec!["game", "game_2", "game_3"]);
+}
+
+#[tokio::test]
+async fn test_p1_register_recursive_keeps_projects_already_registered() {
+    let env = setup_test_env().await;
+    let root = env.home().join("batch");
+    declare_project(&root.join("a"), "game");
+    declare_project(&root.join("b"), "game");
+    let run = env
+        .run(
+            &register_command(root.join("a"), false, false),
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok());
+
+    let run = env
+        .run(
+            &register_command(root.clone(), true, true),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Register should succeed: {:?}",
+        run.result
+    );
+    let projects = run.last_success().unwrap()["projects"].clone();
+    assert_eq!(projects[0]["registered"], false);
+    assert_eq!(projects[0]["name"], "game");
+    assert_eq!(projects[1]["name"], "game_2");
+    assert_eq!(read_amproject_file(&root.join("a")).unwrap().name, "game");
+}
+
+#[tokio::test]
+async fn test_p1_register_reports_conflict_when_non_interactive() {
+    let env = setup_test_env().await;
+    let first = env.home().join("first");
+    let second = env.home().join("second");
+    declare_project(&first, "game");
+    declare_project(&second, "game");
+    let run = env
+        .run(
+            &register_command(first.clone(), false, false),
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok());
+
+    let run = env
+        .run(
+            &register_command(second.clone(), false, false),
+            ScriptedInput::new().non_interactive(),
+        )
+        .await;
+
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_PROJECT_ALREADY_EXISTS)
+    );
+    let conflicts = run.last_success().unwrap()["conflicts"].clone();
+    assert_eq!(conflicts[0]["registered_path"], first.display().to_string());
+    assert_eq!(
+        conflicts[0]["conflicting_paths"],
+        serde_json::json!([second.display().to_string()])
+    );
+}
+
+#[tokio::test]
+async fn test_p1_register_conflict_suggests_force() {
+    let env = setup_test_env().await;
+    let first = env.home().join("first");
+    let second = env.home().join("second");
+    declare_project(&first, "game");
+    declare_project(&second, "game");
+    env.run(
+        &register_command(first.clone(), false, false),
+        ScriptedInput::new(),
+    )
+    .await;
+
+    let run = env
+        .run(
+            &register_command(second, false, false),
+            ScriptedInput::new().non_interactive(),
+        )
+        .await;
+
+    let error = run
+        .result
+        .as_ref()
+        .unwrap_err()
+        .downcast_ref::<am::common::errors::CliError>()
+        .expect("Expected a CliError");
+    assert_eq!(
+        error.code,
+        am::common::errors::codes::ERR_PROJECT_ALREADY_EXISTS
+    );
+    assert!(error.suggestion.contains("--force"));
+}
+
+#[tokio::test]
+async fn test_p0_register_force_points_registration_to_new_directory() {
+    let env = setup_test_env().await;
+    let first = env.home().join("first");
+    let second = env.home().join("second");
+    declare_project(&first, "game");
+    declare_project(&second, "game");
+    env.run(
+        &register_command(first.clone(), false, false),
+        ScriptedInput::new(),
+    )
+    .await;
+    db_set_project_favorite(
+        db_get_project_by_name("game", env.db())
+            .unwrap()
+            .unwrap()
+            .id
+            .unwrap(),
+        true,
+        env.db(),
+    )
+    .unwrap();
+
+    let run = env
+        .run(
+            &ProjectCommands::Register {
+                path: Some(second.clone()),
+                recursive: false,
+                rename_on_conflict: false,
+                force: true,
+                name: None,
+                stdin: false,
+                strict: false,
+            },
+            ScriptedInput::new().non_interactive(),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Register should succeed: {:?}",
+        run.result
+    );
+    let project = db_get_project_by_name("game", env.db()).unwrap().unwrap();
+    assert_eq!(
+        std::path::Path::new(&project.path),
+        second.canonicalize().unwrap()
+    );
+    assert!(project.is_favorite, "The favorite flag should be kept");
+}
+
+/// `am project register` without a path.
+fn register_without_path() -> ProjectCommands {
+    ProjectCommands::Register {
+        path: None,
+        recursive: false,
+        rename_on_conflict: false,
+        force: false,
+        name: None,
+        stdin: false,
+        strict: false,
+    }
+}
+
+#[tokio::test]
+async fn test_p0_register_without_path_registers_current_directory() {
+    let env = setup_test_env().await;
+    let dir = env.home().join("game");
+    declare_project(&dir, "game");
+
+    let run = env
+        .run_in(&dir, &register_without_path(), ScriptedInput::new())
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Register should succeed: {:?}",
+        run.result
+    );
+    let project = db_get_project_by_name("game", env.db()).unwrap().unwrap();
+    assert_eq!(
+        std::path::Path::new(&project.path),
+        dir.canonicalize().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_p1_register_without_path_prompts_for_directory() {
+    let env = setup_test_env().await;
+    let dir = env.home().join("game");
+    declare_project(&dir, "game");
+
+    let run = env
+        .run_in(
+            env.home(),
+            &register_without_path(),
+            ScriptedInput::new().text("game/"),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Register should succeed: {:?}",
+        run.result
+    );
+    assert_eq!(run.unused_responses, 0);
+    assert!(db_get_project_by_name("game", env.db()).unwrap().is_some());
+}
+
+#[tokio::test]
+async fn test_p1_register_without_path_fails_when_non_interactive() {
+    let env = setup_test_env().await;
+
+    let run = env
+        .run_in(
+            env.home(),
+            &register_without_path(),
+            ScriptedInput::new().non_interactive(),
+        )
+        .await;
+
+    let error = run
+        .result
+        .as_ref()
+        .unwrap_err()
+        .downcast_ref::<am::common::errors::CliError>()
+        .expect("Expected a CliError");
+    assert_eq!(error.code, am::common::errors::codes::ERR_VALIDATION_FIELD);
+    assert!(error.suggestion.contains("am project register <path>"));
+}
+
+#[tokio::test]
+async fn test_p1_register_normalizes_relative_path_with_trailing_slash() {
+    let env = setup_test_env().await;
+    let dir = env.home().join("game");
+    declare_project(&dir, "game");
+    fs::create_dir_all(env.home().join("other")).unwrap();
+
+    let run = env
+        .run_in(
+            &env.home().join("other"),
+            &register_command("../game/./".into(), false, false),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Register should succeed: {:?}",
+        run.result
+    );
+    let project = db_get_project_by_name("game", env.db()).unwrap().unwrap();
+    assert_eq!(
+        std::path::Path::new(&project.path),
+        dir.canonicalize().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_p1_register_renames_on_conflict() {
+    let env = setup_test_env().await;
+    let first = env.home().join("first");
+    let second = env.home().join("second");
+    declare_project(&first, "game");
+    declare_project(&second, "game");
+    let run = env
+        .run(&register_command(first, false, false), ScriptedInput::new())
+        .await;
+    assert!(run.result.is_ok());
+
+    let run = env
+        .run(
+            &register_command(second.clone(), false, true),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Register should succeed: {:?}",
+        run.result
+    );
+    assert_eq!(read_amproject_file(&second).unwrap().name, "game_2");
+    assert_eq!(registered_names(&env), vec!["game", "game_2"]);
+}
+
+fn register_as(path: std::path::PathBuf, name: &str) -> ProjectCommands {
+    ProjectCommands::Register {
+        path: Some(path),
+        recursive: false,
+        rename_on_conflict: false,
+        force: false,
+        name: Some(name.to_string()),
+        stdin: false,
+        strict: false,
+    }
+}
+
+#[tokio::test]
+async fn test_p0_register_with_name_aliases_the_same_directory() {
+    let env = setup_test_env().await;
+    let project = env.home().join("game");
+    declare_project(&project, "game");
+    let run = env
+        .run(
+            &register_command(project.clone(), false, false),
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok());
+
+    // WHEN the same directory is registered again under another name
+    let run = env
+        .run(
+            &register_as(project.clone(), "Game Feature"),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    // THEN both registrations resolve to the same path, and only the registry changed
+    assert!(
+        run.result.is_ok(),
+        "Register should succeed: {:?}",
+        run.result
+    );
+    assert_eq!(registered_names(&env), vec!["game", "game_feature"]);
+    let original = db_get_project_by_name("game", env.db()).unwrap().unwrap();
+    let alias = db_get_project_by_name("game_feature", env.db())
+        .unwrap()
+        .unwrap();
+    assert_eq!(original.path, alias.path);
+    assert_eq!(read_amproject_file(&project).unwrap().name, "game");
+    assert_eq!(
+        db_get_project_alias_of_file_name("game_feature", env.db()).unwrap(),
+        Some("game".to_string())
+    );
+    assert_eq!(
+        db_get_project_alias_of_file_name("game", env.db()).unwrap(),
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_p0_project_info_does_not_report_an_alias_as_mismatch() {
+    let env = setup_test_env().await;
+    let project = env.home().join("game");
+    declare_project(&project, "game");
+    let run = env
+        .run(
+            &register_as(project.clone(), "game_feature"),
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok());
+
+    let run = env
+        .run_in(&project, &info_current_dir(), ScriptedInput::new())
+        .await;
+
+    assert!(run.result.is_ok(), "Info should succeed: {:?}", run.result);
+    let info = run.last_success().expect("Info should report success");
+    assert!(info.get("warnings").is_none());
+}
+
+#[tokio::test]
+async fn test_p1_register_with_invalid_name_fails() {
+    let env = setup_test_env().await;
+    let project = env.home().join("game");
+    declare_project(&project, "game");
+
+    let run = env
+        .run(
+            &register_as(project.clone(), "game/feature"),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_VALIDATION_FIELD)
+    );
+    assert!(registered_names(&env).is_empty());
+}
+
+#[tokio::test]
+async fn test_p1_register_with_taken_name_renames_only_the_registration() {
+    let env = setup_test_env().await;
+    let first = env.home().join("first");
+    let second = env.home().join("second");
+    declare_project(&first, "game");
+    declare_project(&second, "tools");
+    let run = env
+        .run(&register_command(first, false, false), ScriptedInput::new())
+        .await;
+    assert!(run.result.is_ok());
+
+    let run = env
+        .run(
+            &ProjectCommands::Register {
+                path: Some(second.clone()),
+                recursive: false,
+                rename_on_conflict: true,
+                force: false,
+                name: Some("game".to_string()),
+                stdin: false,
+                strict: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Register should succeed: {:?}",
+        run.result
+    );
+    assert_eq!(registered_names(&env), vec!["game", "game_2"]);
+    assert_eq!(read_amproject_file(&second).unwrap().name, "tools");
+    assert_eq!(
+        db_get_project_alias_of_file_name("game_2", env.db()).unwrap(),
+        Some("tools".to_string())
+    );
+}
+
+#[test]
+fn test_p1_register_name_conflicts_with_recursive() {
+    use am::App;
+    use clap::Parser;
+
+    let result = App::try_parse_from([
+        "am",
+        "project",
+        "register",
+        ".",
+        "--recursive",
+        "--name",
+        "game",
+    ]);
+    assert!(result.is_err());
+}
+
+// =============================================================================
+// Register From Stdin Tests
+// =============================================================================
+
+fn register_stdin(rename_on_conflict: bool) -> ProjectCommands {
+    ProjectCommands::Register {
+        path: None,
+        recursive: false,
+        rename_on_conflict,
+        force: false,
+        name: None,
+        stdin: true,
+        strict: false,
+    }
+}
+
+#[tokio::test]
+async fn test_p0_register_stdin_reports_a_result_per_entry() {
+    let env = setup_test_env().await;
+    let alpha = env.home().join("alpha");
+    let beta = env.home().join("beta");
+    declare_project(&alpha, "alpha");
+    declare_project(&beta, "beta");
+    let missing = env.home().join("missing");
+
+    let stdin = serde_json::json!([
+        { "path": alpha, "name": "alpha" },
+        { "path": missing },
+        { "path": beta },
+    ])
+    .to_string();
+
+    let run = env
+        .run(&register_stdin(false), ScriptedInput::new().stdin(stdin))
+        .await;
+
+    // THEN valid entries are registered and the failed one is reported on its own
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_PROJECT_NOT_REGISTERED)
+    );
+    let projects = run.last_success().unwrap()["projects"].clone();
+    assert_eq!(projects.as_array().unwrap().len(), 3);
+    assert_eq!(projects[0]["registered"], true);
+    assert_eq!(projects[0]["name"], "alpha");
+    assert_eq!(projects[1]["registered"], false);
+    assert_eq!(
+        projects[1]["error"]["code"],
+        am::common::errors::codes::ERR_PROJECT_NOT_INITIALIZED
+    );
+    assert_eq!(projects[2]["registered"], true);
+    assert_eq!(registered_names(&env), vec!["alpha", "beta"]);
+}
+
+#[tokio::test]
+async fn test_p0_register_stdin_accepts_a_single_object() {
+    let env = setup_test_env().await;
+    let alpha = env.home().join("alpha");
+    declare_project(&alpha, "alpha");
+
+    let stdin = serde_json::json!({ "path": alpha }).to_string();
+    let run = env
+        .run(&register_stdin(false), ScriptedInput::new().stdin(stdin))
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Register should succeed: {:?}",
+        run.result
+    );
+    assert_eq!(run.last_success().unwrap()["projects"][0]["name"], "alpha");
+}
+
+#[tokio::test]
+async fn test_p0_register_stdin_rejects_malformed_json_as_a_whole() {
+    let env = setup_test_env().await;
+    let alpha = env.home().join("alpha");
+    declare_project(&alpha, "alpha");
+
+    let stdin = format!(
+        r#"[{{"path": {:?}}}, {{"path": 42}}]"#,
+        alpha.display().to_string()
+    );
+    let run = env
+        .run(&register_stdin(false), ScriptedInput::new().stdin(stdin))
+        .await;
+
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_VALIDATION_SCHEMA)
+    );
+    assert!(run.successes().is_empty());
+    assert!(registered_names(&env).is_empty());
+}
+
+#[tokio::test]
+async fn test_p1_register_stdin_checks_the_expected_name() {
+    let env = setup_test_env().await;
+    let alpha = env.home().join("alpha");
+    declare_project(&alpha, "alpha");
+
+    let stdin = serde_json::json!({ "path": alpha, "name": "beta" }).to_string();
+    let run = env
+        .run(&register_stdin(false), ScriptedInput::new().stdin(stdin))
+        .await;
+
+    let projects = run.last_success().unwrap()["projects"].clone();
+    assert_eq!(
+        projects[0]["error"]["code"],
+        am::common::errors::codes::ERR_VALIDATION_FIELD
+    );
+    assert!(registered_names(&env).is_empty());
+}
+
+#[tokio::test]
+async fn test_p1_register_stdin_renames_on_conflict() {
+    let env = setup_test_env().await;
+    let first = env.home().join("first");
+    let second = env.home().join("second");
+    declare_project(&first, "game");
+    declare_project(&second, "game");
+
+    let stdin = serde_json::json!([{ "path": first }, { "path": second }]).to_string();
+    let run = env
+        .run(&register_stdin(true), ScriptedInput::new().stdin(stdin))
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Register should succeed: {:?}",
+        run.result
+    );
+    let projects = run.last_success().unwrap()["projects"].clone();
+    assert_eq!(projects[1]["name"], "game_2");
+    assert_eq!(projects[1]["renamed_from"], "game");
+    assert_eq!(registered_names(&env), vec!["game", "game_2"]);
+}
+
+#[tokio::test]
+async fn test_p1_register_stdin_requires_json_mode() {
+    let env = setup_test_env().await;
+
+    let run = env
+        .run_with_mode(
+            &register_stdin(false),
+            ScriptedInput::new().stdin("{}"),
+            am::presentation::OutputMode::Interactive,
+        )
+        .await;
+
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_VALIDATION_FIELD)
+    );
+}
+
+#[test]
+fn test_p2_register_stdin_conflicts_with_path_and_recursive() {
+    use am::app::App;
+    use clap::Parser;
+
+    assert!(App::try_parse_from(["am", "project", "register", "--stdin"]).is_ok());
+    assert!(App::try_parse_from(["am", "project", "register", "dir", "--stdin"]).is_err());
+    assert!(App::try_parse_from(["am", "project", "register", "--stdin", "-r"]).is_err());
+}
+
+// =============================================================================
+// Reference Check Tests
+// =============================================================================
+
+fn validate_with(events_only: bool, no_orphan_check: bool) -> ProjectCommands {
+    ProjectCommands::Validate {
+        sounds_only: false,
+        collections_only: false,
+        effects_only: false,
+        switches_only: false,
+        switch_containers_only: false,
+        events_only,
+        soundbanks_only: false,
+        no_orphan_check,
+        summary: false,
+    }
+}
+
+/// Scaffold a project where sound_1 was deleted and event_1 also targets the missing
+/// asset 998.
+async fn scaffold_project_with_dangling_references(env: &TestEnv) -> std::path::PathBuf {
+    let project_path = env
+        .scaffold_project("dangling_project", 1)
+        .expect("Failed to scaffold project");
+    let sources = project_path.join("sources");
+    fs::remove_file(sources.join("sounds").join("sound_1.json")).unwrap();
+
+    let event_path = sources.join("events").join("event_1.json");
+    let mut event: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&event_path).unwrap()).unwrap();
+    event["actions"][0]["targets"]
+        .as_array_mut()
+        .unwrap()
+        .push(serde_json::json!(998));
+    fs::write(&event_path, serde_json::to_string_pretty(&event).unwrap()).unwrap();
+
+    project_path
+}
+
+fn error_entries<'a>(result: &'a serde_json::Value, file: &str) -> Vec<&'a serde_json::Value> {
+    result["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|e| e["file"] == file)
+        .collect()
+}
+
+#[tokio::test]
+async fn test_p0_validate_reports_dangling_references() {
+    let env = setup_test_env().await;
+    let project_path = scaffold_project_with_dangling_references(&env).await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &validate_with(false, false),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(
+        run.result.is_err(),
+        "Dangling references should fail validation"
+    );
+    let result = run.last_success().unwrap();
+    assert_eq!(result["valid"], false);
+    assert_eq!(result["issue_counts"]["dangling_reference"], 3);
+    assert_eq!(result["issue_counts"]["invalid_asset"], 0);
+
+    // The first broken target is caught by the event rules, the second one only by the
+    // reference check: both are categorized the same way.
+    let event_errors = error_entries(result, "sources/events/event_1.json");
+    let fields: Vec<&str> = event_errors
+        .iter()
+        .map(|e| e["field"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        fields,
+        vec!["actions[0].targets[0]", "actions[0].targets[1]"]
+    );
+    assert!(
+        event_errors
+            .iter()
+            .all(|e| e["category"] == "dangling_reference")
+    );
+
+    let soundbank_errors = error_entries(result, "sources/soundbanks/soundbank_1.json");
+    assert_eq!(soundbank_errors.len(), 1);
+    assert_eq!(soundbank_errors[0]["category"], "dangling_reference");
+}
+
+#[tokio::test]
+async fn test_p0_validate_reports_orphans_as_information() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .scaffold_project("orphan_project", 1)
+        .expect("Failed to scaffold project");
+
+    let run = env
+        .run_in(
+            &project_path,
+            &validate_with(false, false),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Orphans should not fail validation: {:?}",
+        run.result
+    );
+    let result = run.last_success().unwrap();
+    assert_eq!(result["valid"], true);
+    assert_eq!(result["orphan_check"], true);
+    assert_eq!(result["issue_counts"]["orphan"], 3);
+
+    let mut orphans: Vec<&str> = result["orphans"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .inspect(|o| assert_eq!(o["category"], "orphan"))
+        .map(|o| o["file"].as_str().unwrap())
+        .collect();
+    orphans.sort();
+    // Sounds and switches are referenced, events and soundbanks are never orphans.
+    assert_eq!(
+        orphans,
+        vec![
+            "sources/collections/collection_1.json",
+            "sources/effects/effect_1.json",
+            "sources/switch_containers/switch_container_1.json",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_p1_validate_no_orphan_check_skips_orphans() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .scaffold_project("intentional_orphans", 1)
+        .expect("Failed to scaffold project");
+
+    let run = env
+        .run_in(
+            &project_path,
+            &validate_with(false, true),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Validate should succeed: {:?}",
+        run.result
+    );
+    let result = run.last_success().unwrap();
+    assert_eq!(result["orphan_check"], false);
+    assert_eq!(result["orphans"], serde_json::json!([]));
+    assert_eq!(result["issue_counts"]["orphan"], 0);
+}
+
+#[tokio::test]
+async fn test_p1_reference_checks_follow_type_filter() {
+    let env = setup_test_env().await;
+    let project_path = scaffold_project_with_dangling_references(&env).await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &validate_with(true, false),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    let result = run.last_success().unwrap();
+    assert_eq!(result["issue_counts"]["dangling_reference"], 2);
+    assert!(error_entries(result, "sources/soundbanks/soundbank_1.json").is_empty());
+    assert_eq!(result["orphans"], serde_json::json!([]));
+}
+
+// =============================================================================
+// Validation Summary Tests
+// =============================================================================
+
+fn validate_summary() -> ProjectCommands {
+    ProjectCommands::Validate {
+        sounds_only: false,
+        collections_only: false,
+        effects_only: false,
+        switches_only: false,
+        switch_containers_only: false,
+        events_only: false,
+        soundbanks_only: false,
+        no_orphan_check: false,
+        summary: true,
+        limit: Default::default(),
+    }
+}
+
+/// Scaffold a project with `count` sound files that aren't valid JSON.
+async fn scaffold_project_with_broken_sounds(env: &TestEnv, count: usize) -> std::path::PathBuf {
+    let project_path = env
+        .scaffold_project("broken_project", 1)
+        .expect("Failed to scaffold project");
+    let sounds = project_path.join("sources").join("sounds");
+    for i in 0..count {
+        fs::write(sounds.join(format!("broken_{}.json", i)), "{").unwrap();
+    }
+
+    project_path
+}
+
+#[test]
+fn test_p1_validate_summary_flag_parses() {
+    use am::app::{App, Commands};
+    use clap::Parser;
+
+    let app =
+        App::try_parse_from(["am", "project", "validate", "--summary"]).expect("Should parse");
+    assert!(matches!(
+        app.command,
+        Commands::Project {
+            command: ProjectCommands::Validate { summary: true, .. }
+        }
+    ));
+}
+
+#[tokio::test]
+async fn test_p0_validate_summary_prints_a_single_line_for_hundreds_of_issues() {
+    let env = setup_test_env().await;
+    let project_path = scaffold_project_with_broken_sounds(&env, 300).await;
+
+    let run = env
+        .run_in_with_mode(
+            &project_path,
+            &validate_summary(),
+            ScriptedInput::new(),
+            am::presentation::OutputMode::Interactive,
+        )
+        .await;
+
+    assert_eq!(run.calls.len(), 1, "Expected one call, got {:?}", run.calls);
+    let OutputCall::Print(line) = &run.calls[0] else {
+        panic!("Expected the summary line, got {:?}", run.calls[0]);
+    };
+    assert!(!line.contains('\n'));
+    assert!(line.starts_with("validate: "), "{}", line);
+    assert!(
+        line.contains(" assets, ") && line.contains(" warnings ("),
+        "{}",
+        line
+    );
+    assert!(line.ends_with("s)"), "{}", line);
+}
+
+#[tokio::test]
+async fn test_p0_validate_summary_keeps_the_exit_code_of_a_failed_run() {
+    let env = setup_test_env().await;
+    let project_path = scaffold_project_with_broken_sounds(&env, 300).await;
+
+    let run = env
+        .run_in(&project_path, &validate_summary(), ScriptedInput::new())
+        .await;
+
+    let error = run
+        .result
+        .as_ref()
+        .unwrap_err()
+        .downcast_ref::<am::common::errors::CliError>()
+        .expect("Expected a CliError");
+    assert_eq!(error.code, am::common::errors::codes::ERR_VALIDATION_SCHEMA);
+    assert!(error.reported, "The summary already reports the failure");
+
+    assert_eq!(run.calls.len(), 1, "Expected one call, got {:?}", run.calls);
+    let result = run.last_success().unwrap();
+    assert!(result["errors"].as_u64().unwrap() >= 300);
+    assert!(result["warnings"].is_u64());
+    assert!(result["duration_ms"].is_u64());
+    assert_eq!(result.as_object().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_p1_validate_summary_of_a_valid_project_succeeds() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .scaffold_project("valid_project", 1)
+        .expect("Failed to scaffold project");
+
+    let run = env
+        .run_in(&project_path, &validate_summary(), ScriptedInput::new())
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Validate should succeed: {:?}",
+        run.result
+    );
+    assert_eq!(run.calls.len(), 1, "Expected one call, got {:?}", run.calls);
+    assert_eq!(run.last_success().unwrap()["errors"], 0);
+}
+
+// =============================================================================
+// Project Size Tests
+// =============================================================================
+
+/// Create a project with files of known sizes in each of its parts.
+fn create_sized_project(env: &TestEnv, name: &str) -> std::path::PathBuf {
+    let project_path = env
+        .create_project_dir(name)
+        .expect("Failed to create project");
+
+    let files: [(&str, usize); 7] = [
+        ("sources/sounds/hit.json", 100),
+        ("sources/pc.config.json", 10),
+        ("data/hit.wav", 1000),
+        ("data/music.ogg", 500),
+        ("build/sounds/hit.amsnd", 200),
+        ("plugins/reverb.so", 50),
+        ("snapshots/before/hit.json", 25),
+    ];
+    for (path, size) in files {
+        let path = project_path.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, vec![b'x'; size]).unwrap();
+    }
+
+    project_path
+}
+
+fn project_size(by_type: bool, human: bool, threshold: Option<u64>) -> ProjectCommands {
+    ProjectCommands::Size {
+        by_type,
+        human,
+        threshold,
+    }
+}
+
+#[tokio::test]
+async fn test_p0_project_size_reports_each_part() {
+    let env = setup_test_env().await;
+    let project_path = create_sized_project(&env, "sized_project");
+
+    let run = env
+        .run_in(
+            &project_path,
+            &project_size(false, false, None),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Size should succeed: {:?}", run.result);
+    let size = run.last_success().expect("Should report the size");
+    assert_eq!(size["total"], 1885);
+    let sections = &size["sections"];
+    assert_eq!(sections["sources"], serde_json::json!({ "size": 110 }));
+    assert_eq!(sections["data"]["size"], 1500);
+    assert_eq!(sections["build"]["size"], 200);
+    assert_eq!(sections["plugins"]["size"], 50);
+    assert_eq!(sections["snapshots"]["size"], 25);
+}
+
+#[tokio::test]
+async fn test_p0_project_size_by_type_breaks_sources_and_data_down() {
+    let env = setup_test_env().await;
+    let project_path = create_sized_project(&env, "typed_project");
+
+    let run = env
+        .run_in(
+            &project_path,
+            &project_size(true, false, None),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Size should succeed: {:?}", run.result);
+    let sections = run.last_success().unwrap()["sections"].clone();
+    assert_eq!(
+        sections["sources"]["entries"],
+        serde_json::json!({ "sounds": 100, "other": 10 })
+    );
+    assert_eq!(
+        sections["data"]["entries"],
+        serde_json::json!({ "wav": 1000, "ogg": 500 })
+    );
+    assert!(sections["build"].get("entries").is_none());
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_p1_project_size_counts_hard_links_once() {
+    let env = setup_test_env().await;
+    let project_path = create_sized_project(&env, "linked_project");
+    fs::hard_link(
+        project_path.join("data").join("hit.wav"),
+        project_path.join("build").join("hit.wav"),
+    )
+    .unwrap();
+    std::os::unix::fs::symlink(
+        project_path.join("data").join("music.ogg"),
+        project_path.join("plugins").join("music.ogg"),
+    )
+    .unwrap();
+
+    let run = env
+        .run_in(
+            &project_path,
+            &project_size(false, false, None),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Size should succeed: {:?}", run.result);
+    let size = run.last_success().unwrap();
+    assert_eq!(size["total"], 1885);
+    assert_eq!(size["sections"]["build"]["size"], 200);
+    assert_eq!(size["sections"]["plugins"]["size"], 50);
+}
+
+#[tokio::test]
+async fn test_p1_project_size_table_is_sorted_and_filtered() {
+    let env = setup_test_env().await;
+    let project_path = create_sized_project(&env, "table_project");
+
+    let run = env
+        .run_in_with_mode(
+            &project_path,
+            &project_size(true, true, Some(150)),
+            ScriptedInput::new(),
+            am::presentation::OutputMode::Interactive,
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Size should succeed: {:?}", run.result);
+    let rows = run
+        .calls
+        .iter()
+        .find_map(|call| match call {
+            OutputCall::Table { data, .. } => Some(data.clone()),
+            _ => None,
+        })
+        .expect("Should print a table");
+    assert_eq!(
+        rows,
+        serde_json::json!([
+            { "entry": "data", "size": "1.5 KiB" },
+            { "entry": "data/*.wav", "size": "1000 B" },
+            { "entry": "data/*.ogg", "size": "500 B" },
+            { "entry": "build", "size": "200 B" },
+        ])
+    );
+    assert!(matches!(
+        run.calls.last(),
+        Some(OutputCall::Success(message)) if message == "Project table_project uses 1.8 KiB"
+    ));
+}
+
+#[tokio::test]
+async fn test_p1_project_size_outside_a_project_fails() {
+    let env = setup_test_env().await;
+
+    let run = env
+        .run_in(
+            env.home(),
+            &project_size(false, false, None),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_err());
+}
+
+#[tokio::test]
+async fn test_p1_project_size_warns_about_each_skipped_directory_once_per_run() {
+    let env = setup_test_env().await;
+    let project_path = create_sized_project(&env, "deep_project");
+    let mut deep = project_path.join("data");
+    for _ in 0..=am::common::ignore::DEFAULT_MAX_WALK_DEPTH {
+        deep.push("d");
+    }
+    fs::create_dir_all(&deep).unwrap();
+    fs::write(deep.join("deep.wav"), b"x").unwrap();
+
+    // The skipped directories aren't kept from one run to the next.
+    for _ in 0..2 {
+        let run = env
+            .run_in(
+                &project_path,
+                &project_size(false, false, None),
+                ScriptedInput::new(),
+            )
+            .await;
+
+        assert!(run.result.is_ok(), "Size should succeed: {:?}", run.result);
+        let warnings = run.warnings();
+        assert_eq!(warnings.len(), 1, "{:?}", warnings);
+        assert!(warnings[0].contains("--max-depth"));
+    }
+}
+
+// =============================================================================
+// Non-Interactive Prompt Hint Tests
+// =============================================================================
+
+/// The error of a project command run without being able to prompt.
+async fn blocked_prompt_error(
+    env: &TestEnv,
+    dir: &std::path::Path,
+    command: &ProjectCommands,
+) -> String {
+    let run = env
+        .run_in(dir, command, ScriptedInput::new().non_interactive())
+        .await;
+    run.result
+        .expect_err("The command should fail on its prompt")
+        .to_string()
+}
+
+#[tokio::test]
+async fn test_p1_init_prompt_failures_name_their_flag() {
+    let env = setup_test_env().await;
+    let init = |name: Option<&str>| ProjectCommands::Init {
+        name: name.map(str::to_string),
+        template: None,
+        asset_dirs: vec![],
+        no_register: true,
+        with_examples: false,
+        vars: vec![],
+        overwrite_dir: false,
+        replace_registration: false,
+        verify: false,
+        dry_run: false,
+    };
+
+    let error = blocked_prompt_error(&env, env.home(), &init(None)).await;
+    assert!(
+        error.contains("'Project Name'") && error.contains("Pass the <name> argument"),
+        "Unexpected error: {}",
+        error
+    );
+
+    let error = blocked_prompt_error(&env, env.home(), &init(Some("hinted"))).await;
+    assert!(
+        error.contains("'Project Template'") && error.contains("Pass --template <name>"),
+        "Unexpected error: {}",
+        error
+    );
+}
+
+#[tokio::test]
+async fn test_p1_lint_names_fix_prompt_failure_names_yes() {
+    let env = setup_test_env().await;
+    let project_path = env
+        .scaffold_project("hinted_lint", 1)
+        .expect("Failed to scaffold project");
+    let sounds = project_path.join("sources").join("sounds");
+    fs::rename(sounds.join("sound_1.json"), sounds.join("Sound One.json")).unwrap();
+
+    let error = blocked_prompt_error(
+        &env,
+        &project_path,
+        &ProjectCommands::LintNames {
+            asset_type: Some("sound".to_string()),
+            fix: true,
+            skip_confirmation: false,
+        },
+    )
+    .await;
+
+    assert!(
+        error.contains("Pass --yes to avoid this prompt"),
+        "Unexpected error: {}",
+        error
+    );
+    assert!(sounds.join("Sound One.json").exists());
+}
+
+// =============================================================================
+// Verify Registration Tests
+// =============================================================================
+
+fn verify_registration(prune_missing: bool, sync_names: bool) -> ProjectCommands {
+    ProjectCommands::VerifyRegistration {
+        prune_missing,
+        sync_names,
+    }
+}
+
+/// The rows of the table printed by `am project verify-registration`, by project name.
+fn verification_rows(
+    run: &am::testing::CommandRun,
+) -> std::collections::BTreeMap<String, serde_json::Value> {
+    let rows = run
+        .calls
+        .iter()
+        .find_map(|call| match call {
+            OutputCall::Table { data, .. } => Some(data.clone()),
+            _ => None,
+        })
+        .expect("Should print a table");
+
+    rows.as_array()
+        .unwrap()
+        .iter()
+        .map(|row| (row["name"].as_str().unwrap().to_string(), row.clone()))
+        .collect()
+}
+
+fn issue_categories(row: &serde_json::Value) -> Vec<&str> {
+    row["issues"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|issue| issue["category"].as_str().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_p0_verify_registration_reports_healthy_projects() {
+    let env = setup_test_env().await;
+    register_with_renamed_file(&env, "alpha", "alpha").await;
+    register_with_renamed_file(&env, "beta", "beta").await;
+
+    let run = env
+        .run(&verify_registration(false, false), ScriptedInput::new())
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Verify should succeed: {:?}",
+        run.result
+    );
+    let rows = verification_rows(&run);
+    assert_eq!(rows.len(), 2);
+    for row in rows.values() {
+        assert_eq!(row["status"], "ok");
+        assert_eq!(row["issues"], serde_json::json!([]));
+    }
+}
+
+#[tokio::test]
+async fn test_p0_verify_registration_fails_on_missing_directory() {
+    let env = setup_test_env().await;
+    register_with_renamed_file(&env, "alpha", "alpha").await;
+    register_at(&env, "gone", &env.home().join("gone"));
+
+    let run = env
+        .run(&verify_registration(false, false), ScriptedInput::new())
+        .await;
+
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_VALIDATION_SCHEMA)
+    );
+    let rows = verification_rows(&run);
+    assert_eq!(rows["alpha"]["status"], "ok");
+    assert_eq!(rows["gone"]["status"], "failed");
+    assert_eq!(issue_categories(&rows["gone"]), vec!["missing_directory"]);
+    assert!(db_get_project_by_name("gone", env.db()).unwrap().is_some());
+}
+
+#[tokio::test]
+async fn test_p0_verify_registration_prunes_missing_projects() {
+    let env = setup_test_env().await;
+    register_at(&env, "gone", &env.home().join("gone"));
+
+    let run = env
+        .run(&verify_registration(true, false), ScriptedInput::new())
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Verify should succeed: {:?}",
+        run.result
+    );
+    assert_eq!(verification_rows(&run)["gone"]["status"], "pruned");
+    assert!(db_get_project_by_name("gone", env.db()).unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_p0_verify_registration_syncs_name_mismatches() {
+    let env = setup_test_env().await;
+    let project_path = register_with_renamed_file(&env, "foo_old", "foo").await;
+
+    let run = env
+        .run(&verify_registration(false, false), ScriptedInput::new())
+        .await;
+    assert!(run.result.is_err());
+    assert_eq!(
+        issue_categories(&verification_rows(&run)["foo_old"]),
+        vec!["name_mismatch"]
+    );
+
+    let run = env
+        .run(&verify_registration(false, true), ScriptedInput::new())
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Verify should succeed: {:?}",
+        run.result
+    );
+    assert_eq!(verification_rows(&run)["foo_old"]["status"], "synced");
+    assert_eq!(registered_name(&env, &project_path), "foo");
+    assert_eq!(read_amproject_file(&project_path).unwrap().name, "foo");
+}
+
+#[tokio::test]
+async fn test_p1_verify_registration_reports_unreadable_and_newer_configs() {
+    use am::common::utils::{AMPROJECT_VERSION, write_amproject_file};
+
+    let env = setup_test_env().await;
+    let broken = register_with_renamed_file(&env, "broken", "broken").await;
+    fs::write(broken.join(".amproject"), "{ not json").unwrap();
+    let newer = register_with_renamed_file(&env, "newer", "newer").await;
+    let mut config = read_amproject_file(&newer).unwrap();
+    config.version = AMPROJECT_VERSION + 1;
+    write_amproject_file(&newer, &config).unwrap();
+
+    let run = env
+        .run(&verify_registration(true, true), ScriptedInput::new())
+        .await;
+
+    assert!(run.result.is_err());
+    let rows = verification_rows(&run);
+    assert_eq!(rows["broken"]["status"], "failed");
+    assert_eq!(issue_categories(&rows["broken"]), vec!["invalid_config"]);
+    assert_eq!(rows["newer"]["status"], "failed");
+    assert_eq!(
+        issue_categories(&rows["newer"]),
+        vec!["unsupported_version"]
+    );
+}
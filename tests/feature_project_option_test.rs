@@ -0,0 +1,410 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Feature tests for the global `--project <NAME|PATH>` option.
+//!
+//! Tests cover:
+//! - Telling project names from paths
+//! - Resolving names, relative paths, absolute paths and `.amproject` files
+//! - Project-scoped commands run from outside of the project
+//! - Conflicts with a project name argument and commands without a project
+//! - The project handed to the handlers, which doesn't outlive the command
+
+use am::app::App;
+use am::commands::asset::{AssetCommands, SoundCommands};
+use am::common::errors::{CliError, codes};
+use am::common::project_option::{ProjectSelector, resolve_project_option};
+use am::database::db_create_project;
+use am::database::entities::Project;
+use am::testing::{ScriptedInput, TestEnv};
+use clap::Parser;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// Run `am --json <args>` in `dir`, with `AM_HOME` set to `am_home`.
+fn run_am(dir: &Path, am_home: &Path, args: &[&str]) -> (Output, serde_json::Value) {
+    let output = Command::new(env!("CARGO_BIN_EXE_am"))
+        .arg("--json")
+        .args(args)
+        .current_dir(dir)
+        .env("AM_HOME", am_home)
+        .env_remove("AM_SDK_PATH")
+        .output()
+        .expect("Failed to execute command");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let envelope = serde_json::from_str(stdout.trim()).unwrap_or_else(|e| {
+        panic!("Expected a JSON envelope ({}), got: {}", e, stdout);
+    });
+
+    (output, envelope)
+}
+
+/// Register the project at `path` under `name`.
+fn register(env: &TestEnv, name: &str, path: &Path) {
+    let project = Project {
+        id: None,
+        name: name.to_string(),
+        path: path.canonicalize().unwrap().display().to_string(),
+        registered_at: None,
+        is_favorite: false,
+    };
+    db_create_project(&project, env.db()).expect("Failed to register the project");
+}
+
+/// A directory of the home holding no project, where `game` isn't a relative path.
+fn outside_dir(env: &TestEnv) -> PathBuf {
+    let dir = env.home().join("outside");
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap()
+}
+
+fn error_code(error: &anyhow::Error) -> i32 {
+    error
+        .downcast_ref::<CliError>()
+        .expect("Should fail with a CliError")
+        .code
+}
+
+// =============================================================================
+// Selector Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_selector_tells_names_from_paths() {
+    let env = TestEnv::new().await.unwrap();
+    std::fs::create_dir(env.home().join("local_dir")).unwrap();
+
+    let parse = |value: &str| ProjectSelector::parse(value, env.home());
+
+    assert_eq!(
+        parse("my_game"),
+        ProjectSelector::Name("my_game".to_string())
+    );
+    assert_eq!(
+        parse("./my_game"),
+        ProjectSelector::Path(PathBuf::from("./my_game"))
+    );
+    assert_eq!(
+        parse("games/my_game"),
+        ProjectSelector::Path(PathBuf::from("games/my_game"))
+    );
+    // An existing directory is a path even without a separator
+    assert_eq!(
+        parse("local_dir"),
+        ProjectSelector::Path(PathBuf::from("local_dir"))
+    );
+    assert_eq!(parse("."), ProjectSelector::Path(PathBuf::from(".")));
+}
+
+// =============================================================================
+// Resolution Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_resolves_registered_name() {
+    let env = TestEnv::new().await.unwrap();
+    let root = env.create_project_dir("game").unwrap();
+    register(&env, "my_game", &root);
+
+    let dir = resolve_project_option("my_game", Path::new("/"), env.db()).unwrap();
+
+    assert_eq!(canonical(&dir), canonical(&root));
+}
+
+#[tokio::test]
+async fn test_p0_resolves_relative_path_against_base() {
+    let env = TestEnv::new().await.unwrap();
+    let root = env.create_project_dir("game").unwrap();
+    let base = env.home().join("elsewhere");
+    std::fs::create_dir(&base).unwrap();
+
+    let dir = resolve_project_option("../game", &base, None).unwrap();
+
+    assert!(dir.is_absolute());
+    assert_eq!(canonical(&dir), canonical(&root));
+}
+
+#[tokio::test]
+async fn test_p0_resolves_absolute_path() {
+    let env = TestEnv::new().await.unwrap();
+    let root = env.create_project_dir("game").unwrap();
+
+    let value = root.display().to_string();
+    let dir = resolve_project_option(&value, Path::new("/"), None).unwrap();
+
+    assert_eq!(dir, root);
+}
+
+#[tokio::test]
+async fn test_p1_resolves_amproject_file_to_its_directory() {
+    let env = TestEnv::new().await.unwrap();
+    let root = env.create_project_dir("game").unwrap();
+
+    let value = root.join(".amproject").display().to_string();
+    let dir = resolve_project_option(&value, Path::new("/"), None).unwrap();
+
+    assert_eq!(dir, root);
+}
+
+#[tokio::test]
+async fn test_p1_path_without_project_is_not_initialized() {
+    let env = TestEnv::new().await.unwrap();
+    let root = env.create_project_dir("game").unwrap();
+
+    // A subdirectory of a project doesn't fall back to the project
+    let value = root.join("sources").display().to_string();
+    let error = resolve_project_option(&value, Path::new("/"), env.db()).unwrap_err();
+
+    assert_eq!(error_code(&error), codes::ERR_PROJECT_NOT_INITIALIZED);
+}
+
+#[tokio::test]
+async fn test_p1_unknown_name_is_not_registered() {
+    let env = TestEnv::new().await.unwrap();
+
+    let error = resolve_project_option("missing", env.home(), env.db()).unwrap_err();
+
+    assert_eq!(error_code(&error), codes::ERR_PROJECT_NOT_REGISTERED);
+}
+
+#[tokio::test]
+async fn test_p2_name_without_database_is_unavailable() {
+    let env = TestEnv::new().await.unwrap();
+
+    let error = resolve_project_option("my_game", env.home(), None).unwrap_err();
+
+    assert_eq!(error_code(&error), codes::ERR_DATABASE_UNAVAILABLE);
+}
+
+// =============================================================================
+// Command Tests
+// =============================================================================
+
+#[test]
+fn test_p0_project_option_is_global() {
+    let app = App::try_parse_from(["am", "asset", "sound", "list", "--project", "my_game"])
+        .expect("Should parse");
+
+    assert_eq!(app.project.as_deref(), Some("my_game"));
+    assert!(app.command.is_project_scoped());
+}
+
+#[tokio::test]
+async fn test_p0_info_uses_project_by_name_from_another_directory() {
+    let env = TestEnv::new().await.unwrap();
+    let root = env.scaffold_project("game", 2).unwrap();
+    register(&env, "game", &root);
+    let outside = outside_dir(&env);
+
+    let (output, envelope) = run_am(
+        &outside,
+        env.home(),
+        &["--project", "game", "project", "info"],
+    );
+
+    assert_eq!(output.status.code(), Some(0), "{}", envelope);
+    assert_eq!(envelope["value"]["name"], "game");
+    assert_eq!(envelope["value"]["registered"], true);
+    assert_eq!(envelope["value"]["assets"]["sounds"], 2);
+}
+
+#[tokio::test]
+async fn test_p0_validate_uses_project_by_relative_path() {
+    let env = TestEnv::new().await.unwrap();
+    env.scaffold_project("game", 1).unwrap();
+
+    let (output, envelope) = run_am(
+        env.home(),
+        env.home(),
+        &["project", "validate", "--project", "./game"],
+    );
+
+    assert_eq!(output.status.code(), Some(0), "{}", envelope);
+    assert_eq!(envelope["ok"], true);
+}
+
+#[tokio::test]
+async fn test_p0_asset_list_uses_project_by_absolute_path() {
+    let env = TestEnv::new().await.unwrap();
+    let root = env.scaffold_project("game", 3).unwrap();
+    let outside = outside_dir(&env);
+
+    let (output, envelope) = run_am(
+        &outside,
+        env.home(),
+        &[
+            "asset",
+            "sound",
+            "list",
+            "--project",
+            &root.display().to_string(),
+        ],
+    );
+
+    assert_eq!(output.status.code(), Some(0), "{}", envelope);
+    assert_eq!(envelope["ok"], true, "{}", envelope);
+    assert!(envelope.to_string().contains("sound_3"), "{}", envelope);
+}
+
+#[tokio::test]
+async fn test_p0_project_option_takes_precedence_over_current_directory() {
+    let env = TestEnv::new().await.unwrap();
+    let game = env.scaffold_project("game", 1).unwrap();
+    let demo = env.scaffold_project("demo", 2).unwrap();
+
+    let (output, envelope) = run_am(
+        &game,
+        env.home(),
+        &[
+            "--project",
+            &demo.display().to_string(),
+            "--no-db",
+            "project",
+            "info",
+        ],
+    );
+
+    assert_eq!(output.status.code(), Some(0), "{}", envelope);
+    assert_eq!(envelope["value"]["name"], "demo");
+    assert_eq!(envelope["value"]["assets"]["sounds"], 2);
+}
+
+#[tokio::test]
+async fn test_p0_project_option_conflicts_with_project_name() {
+    let env = TestEnv::new().await.unwrap();
+    let root = env.scaffold_project("game", 1).unwrap();
+    register(&env, "game", &root);
+
+    for args in [
+        &["project", "info", "game", "--project", "game"][..],
+        &["project", "info", "--path", "game", "--project", "game"],
+    ] {
+        let (output, envelope) = run_am(env.home(), env.home(), args);
+
+        assert_eq!(output.status.code(), Some(1), "{}", envelope);
+        assert_eq!(envelope["error"]["code"], codes::ERR_VALIDATION_FIELD);
+        assert!(
+            envelope["error"]["message"]
+                .as_str()
+                .unwrap()
+                .contains("given twice")
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_p1_project_option_rejected_by_commands_without_project() {
+    let env = TestEnv::new().await.unwrap();
+
+    let (output, envelope) = run_am(
+        env.home(),
+        env.home(),
+        &["--project", "game", "project", "list"],
+    );
+
+    assert_eq!(output.status.code(), Some(1), "{}", envelope);
+    assert_eq!(envelope["error"]["code"], codes::ERR_VALIDATION_FIELD);
+}
+
+#[tokio::test]
+async fn test_p1_unknown_project_fails_before_the_command() {
+    let env = TestEnv::new().await.unwrap();
+
+    let (output, envelope) = run_am(
+        env.home(),
+        env.home(),
+        &["--project", "missing", "project", "validate"],
+    );
+
+    assert_eq!(output.status.code(), Some(1), "{}", envelope);
+    assert_eq!(envelope["error"]["code"], codes::ERR_PROJECT_NOT_REGISTERED);
+}
+
+#[tokio::test]
+async fn test_p1_stats_usage_counts_only_the_given_project() {
+    let env = TestEnv::new().await.unwrap();
+    let root = env.scaffold_project("game", 1).unwrap();
+    register(&env, "game", &root);
+    let outside = outside_dir(&env);
+
+    run_am(
+        &outside,
+        env.home(),
+        &["--project", "game", "project", "validate"],
+    );
+    run_am(&outside, env.home(), &["project", "list"]);
+
+    let (output, envelope) = run_am(
+        &outside,
+        env.home(),
+        &["--project", "game", "stats", "usage"],
+    );
+
+    assert_eq!(output.status.code(), Some(0), "{}", envelope);
+    assert_eq!(envelope["value"]["project"], "game");
+    assert_eq!(envelope["value"]["runs"], 1, "{}", envelope);
+    assert_eq!(
+        envelope["value"]["commands"][0]["command"],
+        "project validate"
+    );
+}
+
+// =============================================================================
+// Handler Tests
+// =============================================================================
+
+fn list_sounds() -> AssetCommands {
+    AssetCommands::Sound {
+        command: SoundCommands::List {
+            limit: Default::default(),
+        },
+    }
+}
+
+#[tokio::test]
+async fn test_p0_handler_uses_the_given_project() {
+    let env = TestEnv::new().await.unwrap();
+    let demo = env.scaffold_project("demo", 2).unwrap();
+
+    let run = env
+        .run_with_project(&list_sounds(), &demo, ScriptedInput::new())
+        .await;
+
+    assert!(run.result.is_ok(), "{:?}", run.result);
+    assert_eq!(run.last_success().unwrap()["count"], 2);
+}
+
+#[tokio::test]
+async fn test_p1_given_project_does_not_outlive_the_command() {
+    let env = TestEnv::new().await.unwrap();
+    let game = env.scaffold_project("game", 1).unwrap();
+    let demo = env.scaffold_project("demo", 2).unwrap();
+
+    let with_project = env
+        .run_with_project(&list_sounds(), &demo, ScriptedInput::new())
+        .await;
+    let in_game = env
+        .run_in(&game, &list_sounds(), ScriptedInput::new())
+        .await;
+
+    assert_eq!(with_project.last_success().unwrap()["count"], 2);
+    // The next command works on the current directory again
+    assert!(in_game.result.is_ok(), "{:?}", in_game.result);
+    assert_eq!(in_game.last_success().unwrap()["count"], 1);
+}
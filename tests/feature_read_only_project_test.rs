@@ -0,0 +1,228 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Feature tests for projects that can't be written to.
+//!
+//! Tests cover:
+//! - The writability probe of `ensure_writable`
+//! - Commands modifying a read-only project failing before changing anything
+//! - Read-only commands still working on a read-only project
+//! - Filesystem errors naming the path they failed on
+//!
+//! The read-only tests make the project read-only with file permissions, which don't
+//! apply to root: they pass without checking anything when run as root.
+
+use am::common::files::ensure_writable;
+use std::fs;
+
+#[test]
+fn test_p0_ensure_writable_leaves_no_probe_behind() {
+    let dir = tempfile::tempdir().unwrap();
+
+    assert!(ensure_writable(dir.path()).is_ok());
+    assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+}
+
+#[test]
+fn test_p1_ensure_writable_fails_on_a_missing_directory() {
+    let dir = tempfile::tempdir().unwrap();
+
+    assert!(ensure_writable(&dir.path().join("missing")).is_err());
+}
+
+#[cfg(unix)]
+mod read_only {
+    use am::common::errors::{CliError, codes, exit_codes};
+    use am::common::files::ensure_writable;
+    use am::common::fs_ctx;
+    use am::testing::TestEnv;
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::{Path, PathBuf};
+    use std::process::{Command, Output};
+
+    /// Makes a project read-only until dropped.
+    struct ReadOnlyProject {
+        root: PathBuf,
+    }
+
+    impl ReadOnlyProject {
+        /// Make the project at `root` read-only, or return `None` when permissions aren't
+        /// enforced for the current user.
+        fn new(root: &Path) -> Option<Self> {
+            set_mode(root, 0o555);
+            let project = Self {
+                root: root.to_path_buf(),
+            };
+            ensure_writable(root).is_err().then_some(project)
+        }
+    }
+
+    impl Drop for ReadOnlyProject {
+        fn drop(&mut self) {
+            set_mode(&self.root, 0o755);
+        }
+    }
+
+    /// Set the permissions of the directories of the tree at `root`.
+    fn set_mode(root: &Path, mode: u32) {
+        for entry in walkdir::WalkDir::new(root) {
+            let entry = entry.unwrap();
+            if entry.file_type().is_dir() {
+                fs::set_permissions(entry.path(), fs::Permissions::from_mode(mode)).unwrap();
+            }
+        }
+    }
+
+    /// Run `am --json <args>` in `dir` with the home of `env`.
+    fn run_am(env: &TestEnv, dir: &Path, args: &[&str]) -> (Output, serde_json::Value) {
+        let output = Command::new(env!("CARGO_BIN_EXE_am"))
+            .arg("--json")
+            .args(args)
+            .current_dir(dir)
+            .envs(env.env_vars())
+            .env_remove("AM_SDK_PATH")
+            .output()
+            .expect("Failed to execute command");
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let envelope = serde_json::from_str(stdout.trim()).unwrap_or_else(|e| {
+            panic!("Expected a JSON envelope ({}), got: {}", e, stdout);
+        });
+
+        (output, envelope)
+    }
+
+    #[tokio::test]
+    async fn test_p0_ensure_writable_reports_permission_denied() {
+        let env = TestEnv::new().await.unwrap();
+        let project_path = env.create_project_dir("locked_down").unwrap();
+        let Some(_read_only) = ReadOnlyProject::new(&project_path) else {
+            return;
+        };
+
+        let error = ensure_writable(&project_path).unwrap_err();
+        let error = error
+            .downcast_ref::<CliError>()
+            .expect("Expected a CliError");
+        assert_eq!(error.code, codes::ERR_IO_PERMISSION_DENIED);
+        assert_eq!(
+            error.context.as_deref(),
+            Some(project_path.to_str().unwrap())
+        );
+        assert!(error.suggestion.contains("read-only mount"));
+    }
+
+    #[tokio::test]
+    async fn test_p0_asset_create_fails_before_writing() {
+        let env = TestEnv::new().await.unwrap();
+        let project_path = env.create_project_dir("locked_down").unwrap();
+        let Some(_read_only) = ReadOnlyProject::new(&project_path) else {
+            return;
+        };
+
+        let (output, envelope) = run_am(
+            &env,
+            &project_path,
+            &["asset", "collection", "create", "footsteps"],
+        );
+
+        assert_eq!(output.status.code(), Some(exit_codes::SYSTEM_ERROR));
+        assert_eq!(envelope["error"]["code"], codes::ERR_IO_PERMISSION_DENIED);
+        assert!(
+            !project_path
+                .join("sources")
+                .join("collections")
+                .join("footsteps.json")
+                .exists()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_p0_fs_ctx_error_names_the_path() {
+        let env = TestEnv::new().await.unwrap();
+        let parent = env.create_project_dir("locked_down").unwrap();
+        let Some(_read_only) = ReadOnlyProject::new(&parent) else {
+            return;
+        };
+        let target = parent.join("plugins").join("reverb");
+
+        let error = fs_ctx::create_dir_all(&target).unwrap_err();
+
+        let cli_error = error
+            .downcast_ref::<CliError>()
+            .expect("Expected a CliError");
+        assert_eq!(cli_error.code, codes::ERR_IO_PERMISSION_DENIED);
+        assert_eq!(
+            cli_error.what,
+            format!("Failed to create directory '{}'", target.display())
+        );
+        assert_eq!(cli_error.context.as_deref(), Some(target.to_str().unwrap()));
+        assert!(
+            error.downcast_ref::<std::io::Error>().is_some(),
+            "The I/O error should stay the source"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_p0_init_in_read_only_directory_reports_the_path() {
+        let env = TestEnv::new().await.unwrap();
+        let parent = env.home().join("workspace");
+        fs::create_dir_all(&parent).unwrap();
+        let Some(_read_only) = ReadOnlyProject::new(&parent) else {
+            return;
+        };
+
+        let (output, envelope) = run_am(
+            &env,
+            &parent,
+            &[
+                "project",
+                "init",
+                "new_game",
+                "--template",
+                "default",
+                "--no-register",
+            ],
+        );
+
+        assert_eq!(output.status.code(), Some(exit_codes::SYSTEM_ERROR));
+        assert_eq!(envelope["error"]["code"], codes::ERR_IO_PERMISSION_DENIED);
+        let context = envelope["error"]["context"].as_str().unwrap_or_default();
+        assert!(
+            context.ends_with("new_game"),
+            "The context should be the failing path: {}",
+            envelope
+        );
+        assert!(!parent.join("new_game").exists());
+    }
+
+    #[tokio::test]
+    async fn test_p0_validate_does_not_probe() {
+        let env = TestEnv::new().await.unwrap();
+        let project_path = env.scaffold_project("locked_down", 1).unwrap();
+        let Some(_read_only) = ReadOnlyProject::new(&project_path) else {
+            return;
+        };
+
+        let (output, envelope) = run_am(&env, &project_path, &["project", "validate"]);
+
+        assert_eq!(
+            output.status.code(),
+            Some(exit_codes::SUCCESS),
+            "Validate should succeed: {}",
+            envelope
+        );
+    }
+}
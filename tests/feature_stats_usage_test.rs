@@ -0,0 +1,279 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Feature tests for `am stats usage`.
+//!
+//! Tests cover:
+//! - Recording commands and their project usage in the history
+//! - Counts, failure rates and median durations per command
+//! - The `--days` window and the `--by-project` breakdown
+//! - Notices when the history may be incomplete
+
+use am::commands::stats::StatsCommands;
+use am::database::entities::Project;
+use am::database::history::{CONFIG_HISTORY_ENABLED, CommandRecord, median, record_command};
+use am::database::retention::max_age_days_key;
+use am::database::{db_create_project, db_get_project_by_name, db_set_config};
+use am::presentation::OutputMode;
+use am::testing::{OutputCall, ScriptedInput, TestEnv};
+
+async fn setup_test_env() -> TestEnv {
+    TestEnv::new()
+        .await
+        .expect("Failed to create test environment")
+}
+
+/// Add a run of `command` to the history.
+fn record(env: &TestEnv, command: &str, success: bool, duration_ms: u64, project_id: Option<i32>) {
+    record_command(
+        &env.database(),
+        &CommandRecord {
+            command: command.to_string(),
+            success,
+            duration_ms,
+            project_id,
+        },
+    )
+    .expect("Failed to record the command");
+}
+
+/// Register the project `name` and return its id.
+fn register_project(env: &TestEnv, name: &str) -> i32 {
+    let project = Project {
+        id: None,
+        name: name.to_string(),
+        path: env.home().join(name).display().to_string(),
+        registered_at: None,
+        is_favorite: false,
+    };
+    db_create_project(&project, env.db()).expect("Failed to register the project");
+    db_get_project_by_name(name, env.db())
+        .unwrap()
+        .and_then(|project| project.id)
+        .expect("The project should have an id")
+}
+
+/// Move every run of the history `days` days back.
+fn age_history(env: &TestEnv, days: u32) {
+    let modifier = format!("-{} days", days);
+    for table in ["command_history", "project_usage"] {
+        env.database()
+            .execute(
+                &format!("UPDATE {} SET created_at = datetime('now', ?1)", table),
+                [&modifier],
+            )
+            .unwrap();
+    }
+}
+
+fn usage(days: u32, by_project: bool) -> StatsCommands {
+    StatsCommands::Usage { days, by_project }
+}
+
+// =============================================================================
+// Usage Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_usage_summarizes_runs_failures_and_durations() {
+    let env = setup_test_env().await;
+    record(&env, "project init", true, 100, None);
+    record(&env, "project init", false, 300, None);
+    record(&env, "project init", true, 200, None);
+    record(&env, "asset sound create", true, 40, None);
+
+    let run = env.run(&usage(30, false), ScriptedInput::new()).await;
+
+    assert!(run.result.is_ok(), "Usage should succeed: {:?}", run.result);
+    let data = run.last_success().expect("Usage should report success");
+    assert_eq!(data["runs"], 4);
+    let commands = data["commands"].as_array().unwrap();
+    assert_eq!(commands.len(), 2);
+    // Most run first
+    assert_eq!(commands[0]["command"], "project init");
+    assert_eq!(commands[0]["runs"], 3);
+    assert_eq!(commands[0]["failures"], 1);
+    assert_eq!(commands[0]["median_ms"], 200);
+    assert!((commands[0]["failure_rate"].as_f64().unwrap() - 1.0 / 3.0).abs() < 1e-9);
+    assert_eq!(commands[1]["command"], "asset sound create");
+    assert_eq!(commands[1]["failure_rate"], 0.0);
+    assert!(data.get("projects").is_none());
+}
+
+#[tokio::test]
+async fn test_p0_usage_by_project_breaks_down_registered_projects() {
+    let env = setup_test_env().await;
+    let game = register_project(&env, "game");
+    let demo = register_project(&env, "demo");
+    record(&env, "project build", true, 1_000, Some(game));
+    record(&env, "project build", false, 3_000, Some(game));
+    record(&env, "project validate", true, 500, Some(demo));
+    record(&env, "template list", true, 10, None);
+
+    let run = env.run(&usage(30, true), ScriptedInput::new()).await;
+
+    assert!(run.result.is_ok(), "Usage should succeed: {:?}", run.result);
+    let data = run.last_success().unwrap();
+    assert_eq!(data["runs"], 4);
+    assert_eq!(
+        data["projects"],
+        serde_json::json!([
+            {
+                "project": "demo",
+                "command": "project validate",
+                "runs": 1,
+                "failures": 0,
+                "failure_rate": 0.0,
+                "median_ms": 500,
+            },
+            {
+                "project": "game",
+                "command": "project build",
+                "runs": 2,
+                "failures": 1,
+                "failure_rate": 0.5,
+                "median_ms": 2_000,
+            },
+        ])
+    );
+}
+
+#[tokio::test]
+async fn test_p1_usage_only_counts_runs_of_the_window() {
+    let env = setup_test_env().await;
+    let game = register_project(&env, "game");
+    record(&env, "project build", true, 1_000, Some(game));
+    age_history(&env, 10);
+    record(&env, "project info", true, 20, None);
+
+    let run = env.run(&usage(7, true), ScriptedInput::new()).await;
+
+    let data = run.last_success().unwrap();
+    assert_eq!(data["days"], 7);
+    assert_eq!(data["runs"], 1);
+    assert_eq!(data["commands"][0]["command"], "project info");
+    assert_eq!(data["projects"], serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn test_p1_usage_interactive_shows_tables() {
+    let env = setup_test_env().await;
+    let game = register_project(&env, "game");
+    record(&env, "project build", true, 1_500, Some(game));
+
+    let run = env
+        .run_with_mode(
+            &usage(30, true),
+            ScriptedInput::new(),
+            OutputMode::Interactive,
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Usage should succeed: {:?}", run.result);
+    let titles: Vec<_> = run
+        .calls
+        .iter()
+        .filter_map(|call| match call {
+            OutputCall::Table { title, .. } => title.clone(),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(titles, ["Command usage, last 30 days", "Usage by project"]);
+    let tables = run.tables();
+    assert_eq!(tables[0][0]["failures"], "0 (0.0%)");
+    assert_eq!(tables[0][0]["median"], "1.5s");
+    assert_eq!(tables[1][0]["project"], "game");
+}
+
+#[tokio::test]
+async fn test_p2_usage_without_history_reports_nothing_recorded() {
+    let env = setup_test_env().await;
+
+    let run = env
+        .run_with_mode(
+            &usage(14, false),
+            ScriptedInput::new(),
+            OutputMode::Interactive,
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Usage should succeed: {:?}", run.result);
+    assert!(run.calls.contains(&OutputCall::Progress(
+        "No commands recorded in the last 14 days".to_string()
+    )));
+    assert!(run.tables().is_empty());
+}
+
+// =============================================================================
+// Notice Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_usage_notices_history_disabled_during_the_window() {
+    let env = setup_test_env().await;
+    record(&env, "project list", true, 5, None);
+    db_set_config(CONFIG_HISTORY_ENABLED, "false", "boolean", env.db()).unwrap();
+
+    let run = env.run(&usage(30, false), ScriptedInput::new()).await;
+
+    // The recorded runs are still summarized
+    assert!(run.result.is_ok(), "Usage should succeed: {:?}", run.result);
+    let data = run.last_success().unwrap();
+    assert_eq!(data["runs"], 1);
+    let notices: Vec<&str> = data["notices"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|notice| notice.as_str())
+        .collect();
+    assert_eq!(notices.len(), 2, "{:?}", notices);
+    assert!(notices[0].contains("disabled"));
+    assert!(notices[1].contains("changed on"));
+}
+
+#[tokio::test]
+async fn test_p1_usage_notices_window_beyond_retention() {
+    let env = setup_test_env().await;
+    db_set_config(
+        &max_age_days_key("command_history"),
+        "30",
+        "number",
+        env.db(),
+    )
+    .unwrap();
+
+    let within = env.run(&usage(30, false), ScriptedInput::new()).await;
+    let beyond = env.run(&usage(60, false), ScriptedInput::new()).await;
+
+    assert_eq!(
+        within.last_success().unwrap()["notices"],
+        serde_json::json!([])
+    );
+    assert_eq!(
+        beyond.last_success().unwrap()["notices"],
+        serde_json::json!(["History older than 30 days is pruned by the retention limits"])
+    );
+}
+
+// =============================================================================
+// Median Tests
+// =============================================================================
+
+#[test]
+fn test_p1_median_of_odd_and_even_counts() {
+    assert_eq!(median(&mut [300, 100, 200]), 200);
+    assert_eq!(median(&mut [400, 100, 300, 200]), 250);
+    assert_eq!(median(&mut [7]), 7);
+    assert_eq!(median(&mut []), 0);
+}
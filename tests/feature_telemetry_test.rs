@@ -0,0 +1,140 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Feature tests for trace spans.
+//!
+//! The span tests only run with the `otel` feature:
+//!
+//! ```bash
+//! cargo test --features otel --test feature_telemetry_test
+//! ```
+
+use am::common::telemetry;
+
+// =============================================================================
+// Span Hierarchy Tests
+// =============================================================================
+
+#[cfg(feature = "otel")]
+mod spans {
+    use am::commands::project::{ProjectCommands, ProjectSort};
+    use am::common::telemetry::{command_span, subscriber};
+    use am::testing::{ScriptedInput, TestEnv};
+    use opentelemetry::trace::SpanId;
+    use opentelemetry_sdk::trace::{InMemorySpanExporter, SdkTracerProvider, SpanData};
+    use tracing::Instrument;
+
+    fn attribute(span: &SpanData, key: &str) -> Option<String> {
+        span.attributes
+            .iter()
+            .find(|attribute| attribute.key.as_str() == key)
+            .map(|attribute| attribute.value.to_string())
+    }
+
+    #[tokio::test]
+    async fn test_p0_project_list_queries_are_children_of_the_command_span() {
+        let env = TestEnv::new().await.unwrap();
+        let exporter = InMemorySpanExporter::default();
+        let provider = SdkTracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+        let _subscriber = tracing::subscriber::set_default(subscriber(&provider));
+
+        let run = env
+            .run(
+                &ProjectCommands::List {
+                    favorite: false,
+                    no_favorite: false,
+                    tree: None,
+                    sort: ProjectSort::Name,
+                    limit: Default::default(),
+                },
+                ScriptedInput::new(),
+            )
+            .instrument(command_span(["am", "--json", "project", "list"]))
+            .await;
+        assert!(run.result.is_ok(), "List failed: {:?}", run.result);
+        provider.force_flush().unwrap();
+
+        let spans = exporter.get_finished_spans().unwrap();
+        let command = spans
+            .iter()
+            .find(|span| span.name == "command")
+            .expect("The command span should be exported");
+        assert_eq!(command.parent_span_id, SpanId::INVALID);
+        assert_eq!(
+            attribute(command, "command").as_deref(),
+            Some("am project list")
+        );
+
+        let queries: Vec<&SpanData> = spans
+            .iter()
+            .filter(|span| span.name == "db.query")
+            .collect();
+        assert!(
+            !queries.is_empty(),
+            "Listing projects should query the database"
+        );
+        for query in &queries {
+            assert_eq!(query.parent_span_id, command.span_context.span_id());
+            assert_eq!(attribute(query, "db.system").as_deref(), Some("sqlite"));
+        }
+        assert!(queries.iter().any(|query| {
+            attribute(query, "db.query.text").is_some_and(|text| text.contains("FROM projects"))
+        }));
+    }
+
+    #[tokio::test]
+    async fn test_p1_spans_outside_a_command_are_roots() {
+        let exporter = InMemorySpanExporter::default();
+        let provider = SdkTracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+        let _subscriber = tracing::subscriber::set_default(subscriber(&provider));
+
+        let _env = TestEnv::new().await.unwrap();
+        provider.force_flush().unwrap();
+
+        let spans = exporter.get_finished_spans().unwrap();
+        let migrate = spans
+            .iter()
+            .find(|span| span.name == "db.migrate")
+            .expect("Opening the test database should run the migrations");
+        assert_eq!(migrate.parent_span_id, SpanId::INVALID);
+        assert!(spans.iter().any(|span| span.name == "db.migration"
+            && span.parent_span_id == migrate.span_context.span_id()));
+    }
+}
+
+// =============================================================================
+// Configuration Tests
+// =============================================================================
+
+#[test]
+fn test_p0_trace_flag_requests_tracing() {
+    assert!(telemetry::tracing_requested(true));
+}
+
+#[cfg(not(feature = "otel"))]
+#[test]
+fn test_p1_trace_flag_without_otel_feature_fails() {
+    use am::common::errors::{CliError, codes};
+
+    let error = telemetry::init(true).err().expect("--trace should fail");
+
+    assert_eq!(
+        error.downcast_ref::<CliError>().map(|e| e.code),
+        Some(codes::ERR_VALIDATION_FIELD)
+    );
+}
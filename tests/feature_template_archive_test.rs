@@ -0,0 +1,374 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Feature tests for sharing templates as archives.
+//!
+//! Tests cover:
+//! - Exporting a custom template with `am template export`
+//! - Importing it back with `am template import`
+//! - Creating a project from an imported template
+
+use am::app::{App, Commands};
+use am::commands::project::ProjectCommands;
+use am::commands::template::{TemplateCommands, handle_template_import_command};
+use am::common::errors::{CliError, codes};
+use am::common::utils::read_amproject_file;
+use am::database::db_get_template_by_name;
+use am::presentation::OutputMode;
+use am::testing::{CommandRun, MockOutput, OutputCall, ScriptedInput, TestEnv};
+use clap::Parser;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Create a valid template directory at `home/source_template` and register it as
+/// `shared`.
+async fn register_template(env: &TestEnv) -> PathBuf {
+    let template_path = env.home().join("source_template");
+    fs::create_dir_all(template_path.join("sources").join("sounds")).unwrap();
+    fs::write(
+        template_path.join(".amproject"),
+        serde_json::json!({
+            "name": "shared",
+            "default_configuration": "pc.config.amconfig",
+            "sources_dir": "sources",
+            "data_dir": "data",
+            "build_dir": "build",
+            "version": 1
+        })
+        .to_string(),
+    )
+    .unwrap();
+    fs::write(template_path.join("pc.buses.json"), "{}").unwrap();
+    fs::write(template_path.join("pc.config.json"), "{}").unwrap();
+    fs::write(
+        template_path
+            .join("sources")
+            .join("sounds")
+            .join("hit.json"),
+        r#"{"id":1}"#,
+    )
+    .unwrap();
+
+    let run = env
+        .run(
+            &TemplateCommands::Register {
+                path: template_path.display().to_string(),
+                name: Some("shared".to_string()),
+                force: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok(), "Register failed: {:?}", run.result);
+
+    template_path
+}
+
+async fn export(env: &TestEnv, name: &str, archive: &Path) -> CommandRun {
+    env.run(
+        &TemplateCommands::Export {
+            name: name.to_string(),
+            output: Some(archive.to_path_buf()),
+            force: false,
+            verify: false,
+        },
+        ScriptedInput::new(),
+    )
+    .await
+}
+
+/// Import `archive` into the templates directory of `env`.
+fn import(env: &TestEnv, archive: &Path, name: Option<&str>) -> (anyhow::Result<()>, MockOutput) {
+    let output = MockOutput::new(OutputMode::Json);
+    let result = handle_template_import_command(
+        archive,
+        name,
+        false,
+        false,
+        &env.home().join("templates"),
+        env.db(),
+        &output,
+    );
+    (result, output)
+}
+
+fn error_code(result: &anyhow::Result<()>) -> Option<i32> {
+    result
+        .as_ref()
+        .err()
+        .and_then(|e| e.downcast_ref::<CliError>())
+        .map(|e| e.code)
+}
+
+// =============================================================================
+// Command Parsing Tests
+// =============================================================================
+
+#[test]
+fn test_p0_export_and_import_commands_parse() {
+    let app = App::try_parse_from([
+        "am",
+        "template",
+        "export",
+        "shared",
+        "--output",
+        "out.amtpl",
+    ])
+    .expect("Should parse");
+    match app.command {
+        Commands::Template {
+            command: TemplateCommands::Export { name, output, .. },
+        } => {
+            assert_eq!(name, "shared");
+            assert_eq!(output, Some(PathBuf::from("out.amtpl")));
+        }
+        _ => panic!("Expected Template Export command"),
+    }
+
+    let app = App::try_parse_from(["am", "template", "import", "out.amtpl", "--name", "team"])
+        .expect("Should parse");
+    match app.command {
+        Commands::Template {
+            command: TemplateCommands::Import { archive, name, .. },
+        } => {
+            assert_eq!(archive, PathBuf::from("out.amtpl"));
+            assert_eq!(name.as_deref(), Some("team"));
+        }
+        _ => panic!("Expected Template Import command"),
+    }
+}
+
+// =============================================================================
+// Round-Trip Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_exported_template_can_be_imported_and_used_by_init() {
+    let env = TestEnv::new().await.unwrap();
+    let template_path = register_template(&env).await;
+    let archive = env.home().join("shared.amtpl");
+
+    let exported = export(&env, "shared", &archive).await;
+    assert!(
+        exported.result.is_ok(),
+        "Export failed: {:?}",
+        exported.result
+    );
+    let export_data = exported.last_success().unwrap();
+    assert_eq!(export_data["cli_version"], env!("CARGO_PKG_VERSION"));
+    assert!(archive.is_file());
+
+    let unregistered = env
+        .run(
+            &TemplateCommands::Unregister {
+                name: "shared".to_string(),
+                force: true,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(unregistered.result.is_ok());
+    fs::remove_dir_all(&template_path).unwrap();
+
+    let (result, output) = import(&env, &archive, None);
+    assert!(result.is_ok(), "Import failed: {:?}", result);
+    let imported_path = env.home().join("templates").join("shared");
+    assert!(
+        imported_path
+            .join("sources")
+            .join("sounds")
+            .join("hit.json")
+            .is_file()
+    );
+    let registered = db_get_template_by_name("shared", env.db())
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        PathBuf::from(&registered.path),
+        imported_path.canonicalize().unwrap()
+    );
+    assert!(matches!(
+        output.calls().last(),
+        Some(OutputCall::Success(data)) if data["checksum"] == export_data["checksum"]
+    ));
+
+    let init = env
+        .run_in(
+            env.home(),
+            &ProjectCommands::Init {
+                name: Some("from_shared".to_string()),
+                template: Some("shared".to_string()),
+                asset_dirs: vec![],
+                no_register: true,
+                with_examples: false,
+                vars: vec![],
+                overwrite_dir: false,
+                replace_registration: false,
+                verify: false,
+                dry_run: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(init.result.is_ok(), "Init failed: {:?}", init.result);
+    let project_path = env.home().join("from_shared");
+    assert!(project_path.join("pc.buses.json").is_file());
+    assert!(
+        project_path
+            .join("sources")
+            .join("sounds")
+            .join("hit.json")
+            .is_file()
+    );
+    assert_eq!(
+        read_amproject_file(&project_path).unwrap().name,
+        "from_shared"
+    );
+}
+
+#[tokio::test]
+async fn test_p1_verified_export_import_and_init_report_verified_files() {
+    let env = TestEnv::new().await.unwrap();
+    register_template(&env).await;
+    let archive = env.home().join("shared.amtpl");
+
+    let exported = env
+        .run(
+            &TemplateCommands::Export {
+                name: "shared".to_string(),
+                output: Some(archive.clone()),
+                force: false,
+                verify: true,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(
+        exported.result.is_ok(),
+        "Export failed: {:?}",
+        exported.result
+    );
+    let verified = &exported.last_success().unwrap()["verified"];
+    assert!(verified["files"].as_u64().unwrap() > 0);
+    assert!(verified["bytes"].as_u64().unwrap() > 0);
+
+    let output = MockOutput::new(OutputMode::Json);
+    handle_template_import_command(
+        &archive,
+        Some("verified"),
+        false,
+        true,
+        &env.home().join("templates"),
+        env.db(),
+        &output,
+    )
+    .expect("Import should succeed");
+    assert!(matches!(
+        output.calls().last(),
+        Some(OutputCall::Success(data)) if data["verified"] == *verified
+    ));
+
+    let init = env
+        .run_in(
+            env.home(),
+            &ProjectCommands::Init {
+                name: Some("from_verified".to_string()),
+                template: Some("verified".to_string()),
+                asset_dirs: vec![],
+                no_register: true,
+                with_examples: false,
+                vars: vec![],
+                overwrite_dir: false,
+                replace_registration: false,
+                verify: true,
+                dry_run: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(init.result.is_ok(), "Init failed: {:?}", init.result);
+    assert!(init.calls.iter().any(|call| matches!(
+        call,
+        OutputCall::Progress(message) if message.starts_with("Verified ")
+    )));
+}
+
+#[tokio::test]
+async fn test_p1_import_under_another_name() {
+    let env = TestEnv::new().await.unwrap();
+    register_template(&env).await;
+    let archive = env.home().join("shared.amtpl");
+    assert!(export(&env, "shared", &archive).await.result.is_ok());
+
+    let (result, _output) = import(&env, &archive, Some("team_shared"));
+
+    assert!(result.is_ok(), "Import failed: {:?}", result);
+    assert!(
+        db_get_template_by_name("team_shared", env.db())
+            .unwrap()
+            .is_some()
+    );
+    assert!(env.home().join("templates").join("team_shared").is_dir());
+}
+
+#[tokio::test]
+async fn test_p1_import_refuses_existing_template_without_force() {
+    let env = TestEnv::new().await.unwrap();
+    register_template(&env).await;
+    let archive = env.home().join("shared.amtpl");
+    assert!(export(&env, "shared", &archive).await.result.is_ok());
+
+    let (result, _output) = import(&env, &archive, None);
+
+    assert_eq!(error_code(&result), Some(codes::ERR_TEMPLATE_NAME_CONFLICT));
+    assert!(!env.home().join("templates").join("shared").exists());
+}
+
+// =============================================================================
+// Export Error Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p1_export_refuses_embedded_template() {
+    let env = TestEnv::new().await.unwrap();
+
+    let run = export(&env, "default", &env.home().join("default.amtpl")).await;
+
+    assert_eq!(
+        run.error_code(),
+        Some(codes::ERR_TEMPLATE_OPERATION_NOT_ALLOWED)
+    );
+}
+
+#[tokio::test]
+async fn test_p1_export_refuses_to_overwrite_archive_without_force() {
+    let env = TestEnv::new().await.unwrap();
+    register_template(&env).await;
+    let archive = env.home().join("shared.amtpl");
+    fs::write(&archive, "keep me").unwrap();
+
+    let run = export(&env, "shared", &archive).await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_VALIDATION_FIELD));
+    assert_eq!(fs::read_to_string(&archive).unwrap(), "keep me");
+}
+
+#[tokio::test]
+async fn test_p1_export_of_unknown_template_fails() {
+    let env = TestEnv::new().await.unwrap();
+
+    let run = export(&env, "missing", &env.home().join("missing.amtpl")).await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_TEMPLATE_NOT_FOUND));
+}
@@ -0,0 +1,320 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Feature tests for projects created from a registered template.
+//!
+//! Tests cover:
+//! - Copying the nested and empty directories of a template, without `.git` and `build`
+//! - Empty templates and templates whose directory is gone
+//! - Progress reported for each copied file
+//! - Cleaning up the project directory when the copy or the setup of the copy fails
+//! - The `--dry-run` plan of a template-based init
+//! - Keeping the registration of a project of the same name, as the template-based
+//!   project isn't registered
+
+use am::commands::project::ProjectCommands;
+use am::common::errors::{CliError, codes};
+use am::database::entities::{Project, Template, TemplateSource};
+use am::database::{db_create_project, db_create_template, db_get_project_by_name};
+use am::testing::{CommandRun, OutputCall, ScriptedInput, TestEnv};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Register the directory `path` as the template `name`, without validating it.
+fn register_template(env: &TestEnv, name: &str, path: &Path) {
+    let template = Template {
+        id: None,
+        name: name.to_string(),
+        path: path.display().to_string(),
+        engine: None,
+        description: None,
+        source: TemplateSource::Custom,
+    };
+    db_create_template(&template, env.db()).unwrap();
+}
+
+/// Create a template with nested, empty and ignored directories at `home/nested`.
+fn nested_template(env: &TestEnv) -> PathBuf {
+    let root = env.home().join("nested");
+    let sounds = root.join("sources").join("sounds").join("ui");
+    fs::create_dir_all(&sounds).unwrap();
+    fs::create_dir_all(root.join("sources").join("events")).unwrap();
+    fs::create_dir_all(root.join(".git").join("objects")).unwrap();
+    fs::create_dir_all(root.join("build")).unwrap();
+    fs::create_dir_all(root.join("data").join("build")).unwrap();
+
+    fs::write(
+        root.join(".amproject"),
+        serde_json::json!({
+            "name": "nested",
+            "default_configuration": "pc.config.amconfig",
+            "sources_dir": "sources",
+            "data_dir": "data",
+            "build_dir": "build",
+            "version": 1
+        })
+        .to_string(),
+    )
+    .unwrap();
+    fs::write(sounds.join("click.json"), r#"{"id":1}"#).unwrap();
+    fs::write(root.join(".git").join("HEAD"), "ref: refs/heads/main\n").unwrap();
+    fs::write(root.join("build").join("game.ambank"), "built").unwrap();
+
+    root
+}
+
+async fn init(env: &TestEnv, template: &str, dry_run: bool) -> CommandRun {
+    env.run_in(
+        env.home(),
+        &ProjectCommands::Init {
+            name: Some("my_game".to_string()),
+            template: Some(template.to_string()),
+            asset_dirs: vec![],
+            no_register: true,
+            with_examples: false,
+            vars: vec![],
+            overwrite_dir: false,
+            replace_registration: false,
+            verify: false,
+            dry_run,
+        },
+        ScriptedInput::new(),
+    )
+    .await
+}
+
+fn cli_error(run: &CommandRun) -> &CliError {
+    run.result
+        .as_ref()
+        .unwrap_err()
+        .downcast_ref::<CliError>()
+        .expect("Expected a CliError")
+}
+
+// =============================================================================
+// P0: Directory Structure
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_init_copies_nested_template_directories() {
+    let env = TestEnv::new().await.unwrap();
+    let template = nested_template(&env);
+    register_template(&env, "nested", &template);
+
+    let run = init(&env, "nested", false).await;
+
+    assert!(run.result.is_ok(), "Init should succeed: {:?}", run.result);
+    let project = env.home().join("my_game");
+    assert!(project.join("sources/sounds/ui/click.json").is_file());
+    assert!(project.join("sources/events").is_dir());
+    assert!(project.join("data/build").is_dir());
+}
+
+#[tokio::test]
+async fn test_p0_init_leaves_git_and_build_directories_out() {
+    let env = TestEnv::new().await.unwrap();
+    let template = nested_template(&env);
+    register_template(&env, "nested", &template);
+
+    let run = init(&env, "nested", false).await;
+
+    assert!(run.result.is_ok(), "Init should succeed: {:?}", run.result);
+    let project = env.home().join("my_game");
+    assert!(!project.join(".git").exists());
+    assert!(!project.join("build").join("game.ambank").exists());
+}
+
+#[tokio::test]
+async fn test_p1_init_reports_progress_for_each_file() {
+    let env = TestEnv::new().await.unwrap();
+    let template = nested_template(&env);
+    register_template(&env, "nested", &template);
+
+    let run = init(&env, "nested", false).await;
+
+    let progress: Vec<(&str, usize, usize)> = run
+        .calls
+        .iter()
+        .filter_map(|call| match call {
+            OutputCall::ProgressWith {
+                message,
+                current,
+                total,
+            } => Some((message.as_str(), *current, *total)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        progress,
+        vec![
+            ("Copying .amproject", 1, 2),
+            ("Copying sources/sounds/ui/click.json", 2, 2),
+        ]
+    );
+}
+
+// =============================================================================
+// P1: Empty and Missing Templates
+// =============================================================================
+
+#[tokio::test]
+async fn test_p1_init_from_empty_template_creates_empty_project() {
+    let env = TestEnv::new().await.unwrap();
+    let template = env.home().join("empty");
+    fs::create_dir_all(&template).unwrap();
+    register_template(&env, "empty", &template);
+
+    let run = init(&env, "empty", false).await;
+
+    assert!(run.result.is_ok(), "Init should succeed: {:?}", run.result);
+    let project = env.home().join("my_game");
+    assert!(project.is_dir());
+    assert_eq!(fs::read_dir(&project).unwrap().count(), 0);
+}
+
+#[tokio::test]
+async fn test_p1_init_from_missing_template_fails_without_creating_project() {
+    let env = TestEnv::new().await.unwrap();
+    let template = env.home().join("gone");
+    register_template(&env, "gone", &template);
+
+    let run = init(&env, "gone", false).await;
+
+    let error = cli_error(&run);
+    assert_eq!(error.code, codes::ERR_VALIDATION_FIELD);
+    assert_eq!(error.context.as_deref(), Some(template.to_str().unwrap()));
+    assert!(!env.home().join("my_game").exists());
+}
+
+// =============================================================================
+// P1: Dry Run
+// =============================================================================
+
+#[tokio::test]
+async fn test_p1_init_dry_run_plans_template_copy() {
+    let env = TestEnv::new().await.unwrap();
+    let template = nested_template(&env);
+    register_template(&env, "nested", &template);
+
+    let run = init(&env, "nested", true).await;
+
+    let plan = run.last_success().expect("Dry run should report a plan");
+    let files: Vec<&str> = plan["files"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|file| file["path"].as_str().unwrap())
+        .collect();
+    assert_eq!(files, vec![".amproject", "sources/sounds/ui/click.json"]);
+    assert!(
+        plan["directories"]
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!("data/build"))
+    );
+    assert!(!env.home().join("my_game").exists());
+}
+
+// =============================================================================
+// P1: Failed Init
+// =============================================================================
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_p1_init_removes_project_when_copy_fails() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let env = TestEnv::new().await.unwrap();
+    let template = nested_template(&env);
+    let unreadable = template.join("sources").join("secret.json");
+    fs::write(&unreadable, "{}").unwrap();
+    fs::set_permissions(&unreadable, fs::Permissions::from_mode(0o000)).unwrap();
+    // Permissions don't apply to root: nothing fails then.
+    if fs::read(&unreadable).is_ok() {
+        return;
+    }
+    register_template(&env, "nested", &template);
+
+    let run = init(&env, "nested", false).await;
+
+    fs::set_permissions(&unreadable, fs::Permissions::from_mode(0o644)).unwrap();
+    let error = cli_error(&run);
+    assert_eq!(error.code, codes::ERR_TEMPLATE_COPY_FAILED);
+    assert_eq!(error.context.as_deref(), Some(unreadable.to_str().unwrap()));
+    assert!(!env.home().join("my_game").exists());
+}
+
+#[tokio::test]
+async fn test_p1_init_removes_project_when_template_config_is_invalid() {
+    let env = TestEnv::new().await.unwrap();
+    let template = nested_template(&env);
+    // Copied fine, but only fails once read back to name the project
+    fs::write(template.join(".amproject"), "{ not json").unwrap();
+    register_template(&env, "nested", &template);
+
+    let run = init(&env, "nested", false).await;
+
+    assert!(run.result.is_err(), "Init should fail: {:?}", run.result);
+    assert!(!env.home().join("my_game").exists());
+}
+
+// =============================================================================
+// P1: Registration
+// =============================================================================
+
+#[tokio::test]
+async fn test_p1_init_from_template_keeps_registration_of_same_name() {
+    let env = TestEnv::new().await.unwrap();
+    let template = nested_template(&env);
+    register_template(&env, "nested", &template);
+    let existing = env.create_project_dir("existing").unwrap();
+    let project = Project {
+        id: None,
+        name: "my_game".to_string(),
+        path: existing.display().to_string(),
+        registered_at: None,
+        is_favorite: false,
+    };
+    db_create_project(&project, env.db()).unwrap();
+
+    let run = env
+        .run_in(
+            env.home(),
+            &ProjectCommands::Init {
+                name: Some("my_game".to_string()),
+                template: Some("nested".to_string()),
+                asset_dirs: vec![],
+                no_register: false,
+                with_examples: false,
+                vars: vec![],
+                overwrite_dir: false,
+                replace_registration: false,
+                verify: false,
+                dry_run: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Init should succeed: {:?}", run.result);
+    assert!(env.home().join("my_game").join(".amproject").is_file());
+    let registered = db_get_project_by_name("my_game", env.db())
+        .unwrap()
+        .expect("The existing registration should be kept");
+    assert!(
+        Path::new(&registered.path).ends_with("existing"),
+        "Registration should still point to the existing project: {}",
+        registered.path
+    );
+}
@@ -20,9 +20,8 @@
 use am::commands::template::{TemplateCommands, handler};
 use am::database::entities::TemplateSource;
 use am::database::{Database, db_get_templates};
-use am::input::{Input, NonInteractiveInput};
+use am::input::{Input, NonInteractiveInput, TextValidator};
 use am::presentation::{Output, OutputMode, create_output};
-use inquire::validator::Validation;
 use serde_json::Value;
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -103,6 +102,14 @@ impl Output for CaptureOutput {
         self.progress_calls.borrow_mut().push(message.to_string());
     }
 
+    fn print(&self, _message: &str) {
+        // Not needed for these tests
+    }
+
+    fn warning(&self, _message: &str) {
+        // Not needed for these tests
+    }
+
     fn table(&self, title: Option<&str>, data: Value) {
         self.table_calls
             .borrow_mut()
@@ -140,7 +147,7 @@ impl Input for MockInput {
         prompt: &str,
         _placeholder: Option<&str>,
         _formatter: Option<&dyn Fn(&str) -> String>,
-        _validator: Option<&dyn Fn(&str) -> anyhow::Result<Validation, inquire::CustomUserError>>,
+        _validators: &[TextValidator<'_>],
     ) -> anyhow::Result<String> {
         Err(anyhow::anyhow!(
             "MockInput: prompt_text not implemented for '{}'",
@@ -163,7 +170,7 @@ impl Input for MockInput {
         &self,
         prompt: &str,
         _default: &str,
-        _validator: Option<&dyn Fn(&str) -> anyhow::Result<Validation, inquire::CustomUserError>>,
+        _validators: &[TextValidator<'_>],
     ) -> anyhow::Result<String> {
         Err(anyhow::anyhow!(
             "MockInput: prompt_text_with_default not implemented for '{}'",
@@ -2806,3 +2813,306 @@ fn test_p0_template_unregister_cli_explicit_non_interactive_with_force_succeeds(
         String::from_utf8_lossy(&output.stdout)
     );
 }
+
+// =============================================================================
+// Template Update Handler Tests
+// =============================================================================
+
+/// Create a valid template directory at `parent/dir_name`.
+fn create_template_dir(parent: &std::path::Path, dir_name: &str) -> std::path::PathBuf {
+    let template_path = parent.join(dir_name);
+    std::fs::create_dir_all(&template_path).unwrap();
+    std::fs::write(
+        template_path.join(".amproject"),
+        r#"{"name":"test","version":1}"#,
+    )
+    .unwrap();
+    std::fs::write(template_path.join("test.buses.json"), "{}").unwrap();
+    std::fs::write(template_path.join("test.config.json"), "{}").unwrap();
+    template_path
+}
+
+/// Register a template directly in the database.
+fn insert_template(db: &Arc<Database>, name: &str, path: &str) {
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap();
+    conn.execute(
+        "INSERT INTO templates (name, path, engine, description) VALUES (?1, ?2, ?3, ?4)",
+        rusqlite::params![name, path, "o3de", "Kept across updates"],
+    )
+    .unwrap();
+}
+
+fn update_command(name: &str, path: &std::path::Path, rename: Option<&str>) -> TemplateCommands {
+    TemplateCommands::Update {
+        name: Some(name.to_string()),
+        path: Some(path.to_str().unwrap().to_string()),
+        rename: rename.map(String::from),
+        revalidate_all: false,
+    }
+}
+
+#[tokio::test]
+async fn test_p0_template_update_changes_path() {
+    // GIVEN: A template registered at a path that has moved
+    let temp_dir = tempfile::tempdir().unwrap();
+    let new_path = create_template_dir(temp_dir.path(), "moved");
+    let (db_arc, _db_temp_dir) = setup_test_database().await;
+    insert_template(&db_arc, "my-template", "/old/location");
+    let output = CaptureOutput::new(OutputMode::Json);
+
+    // WHEN: Updating its path
+    let command = update_command("my-template", &new_path, None);
+    let result = handler(
+        &command,
+        Some(db_arc.clone()),
+        &NonInteractiveInput::new(),
+        &output,
+    )
+    .await;
+
+    // THEN: The template points to the new directory
+    assert!(result.is_ok(), "Handler should succeed: {:?}", result.err());
+    let canonical = new_path.canonicalize().unwrap();
+    let success_data = output.last_success().expect("Should have success output");
+    assert_eq!(success_data["name"], "my-template");
+    assert_eq!(success_data["previous_path"], "/old/location");
+    assert_eq!(success_data["path"], canonical.to_str().unwrap());
+
+    // AND: Its metadata was kept
+    let template = am::database::db_get_template_by_name("my-template", Some(db_arc))
+        .unwrap()
+        .expect("Template should still be registered");
+    assert_eq!(template.path, canonical.to_str().unwrap());
+    assert_eq!(template.engine.as_deref(), Some("o3de"));
+    assert_eq!(template.description.as_deref(), Some("Kept across updates"));
+}
+
+#[tokio::test]
+async fn test_p0_template_update_with_rename() {
+    // GIVEN: A registered template
+    let temp_dir = tempfile::tempdir().unwrap();
+    let new_path = create_template_dir(temp_dir.path(), "moved");
+    let (db_arc, _db_temp_dir) = setup_test_database().await;
+    insert_template(&db_arc, "old-name", "/old/location");
+    let output = CaptureOutput::new(OutputMode::Json);
+
+    // WHEN: Updating its path and renaming it
+    let command = update_command("old-name", &new_path, Some("new-name"));
+    let result = handler(
+        &command,
+        Some(db_arc.clone()),
+        &NonInteractiveInput::new(),
+        &output,
+    )
+    .await;
+
+    // THEN: The template is registered under its new name only
+    assert!(result.is_ok(), "Handler should succeed: {:?}", result.err());
+    let success_data = output.last_success().unwrap();
+    assert_eq!(success_data["name"], "new-name");
+    assert_eq!(success_data["previous_name"], "old-name");
+
+    let templates = db_get_templates(Some(db_arc)).unwrap();
+    assert_eq!(templates.len(), 1);
+    assert_eq!(templates[0].name, "new-name");
+}
+
+#[tokio::test]
+async fn test_p0_template_update_rejects_invalid_directory() {
+    // GIVEN: A registered template and a directory that is not a template
+    let temp_dir = tempfile::tempdir().unwrap();
+    let invalid_path = temp_dir.path().join("not-a-template");
+    std::fs::create_dir_all(&invalid_path).unwrap();
+    std::fs::write(invalid_path.join("test.buses.json"), "{}").unwrap();
+    let (db_arc, _db_temp_dir) = setup_test_database().await;
+    insert_template(&db_arc, "my-template", "/old/location");
+    let output = CaptureOutput::new(OutputMode::Json);
+
+    // WHEN: Updating the template to that directory
+    let command = update_command("my-template", &invalid_path, None);
+    let result = handler(
+        &command,
+        Some(db_arc.clone()),
+        &NonInteractiveInput::new(),
+        &output,
+    )
+    .await;
+
+    // THEN: The same validation as register rejects it
+    let err = result.expect_err("Handler should fail for invalid template");
+    let cli_err = err
+        .downcast_ref::<am::common::errors::CliError>()
+        .expect("Error should be a CliError");
+    assert_eq!(cli_err.code, -29007); // ERR_INVALID_TEMPLATE_STRUCTURE
+
+    // AND: The registered path is unchanged
+    let template = am::database::db_get_template_by_name("my-template", Some(db_arc))
+        .unwrap()
+        .unwrap();
+    assert_eq!(template.path, "/old/location");
+}
+
+#[tokio::test]
+async fn test_p0_template_update_not_found_returns_error() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let new_path = create_template_dir(temp_dir.path(), "moved");
+    let (db_arc, _db_temp_dir) = setup_test_database().await;
+    let output = CaptureOutput::new(OutputMode::Json);
+
+    let command = update_command("missing", &new_path, None);
+    let result = handler(&command, Some(db_arc), &NonInteractiveInput::new(), &output).await;
+
+    let err = result.expect_err("Handler should fail for unknown template");
+    let cli_err = err.downcast_ref::<am::common::errors::CliError>().unwrap();
+    assert_eq!(cli_err.code, -29005); // ERR_TEMPLATE_NOT_FOUND
+}
+
+#[tokio::test]
+async fn test_p0_template_update_embedded_template_returns_error() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let new_path = create_template_dir(temp_dir.path(), "moved");
+    let (db_arc, _db_temp_dir) = setup_test_database().await;
+    let output = CaptureOutput::new(OutputMode::Json);
+
+    let command = update_command("default", &new_path, None);
+    let result = handler(&command, Some(db_arc), &NonInteractiveInput::new(), &output).await;
+
+    let err = result.expect_err("Handler should fail for embedded template");
+    let cli_err = err.downcast_ref::<am::common::errors::CliError>().unwrap();
+    assert_eq!(cli_err.code, -29008); // ERR_TEMPLATE_OPERATION_NOT_ALLOWED
+}
+
+#[tokio::test]
+async fn test_p1_template_update_rename_conflict_returns_error() {
+    // GIVEN: Two registered templates
+    let temp_dir = tempfile::tempdir().unwrap();
+    let new_path = create_template_dir(temp_dir.path(), "moved");
+    let (db_arc, _db_temp_dir) = setup_test_database().await;
+    insert_template(&db_arc, "first", "/path/first");
+    insert_template(&db_arc, "second", "/path/second");
+    let output = CaptureOutput::new(OutputMode::Json);
+
+    // WHEN: Renaming one to the name of the other, or of an embedded template
+    let taken = update_command("first", &new_path, Some("second"));
+    let taken_result = handler(
+        &taken,
+        Some(db_arc.clone()),
+        &NonInteractiveInput::new(),
+        &output,
+    )
+    .await;
+    let builtin = update_command("first", &new_path, Some("default"));
+    let builtin_result = handler(
+        &builtin,
+        Some(db_arc.clone()),
+        &NonInteractiveInput::new(),
+        &output,
+    )
+    .await;
+
+    // THEN: Both are rejected as name conflicts
+    for result in [taken_result, builtin_result] {
+        let err = result.expect_err("Rename should fail");
+        let cli_err = err.downcast_ref::<am::common::errors::CliError>().unwrap();
+        assert_eq!(cli_err.code, -29006); // ERR_TEMPLATE_NAME_CONFLICT
+    }
+
+    // AND: The template kept its name and path
+    let first = am::database::db_get_template_by_name("first", Some(db_arc))
+        .unwrap()
+        .expect("Template should keep its name");
+    assert_eq!(first.path, "/path/first");
+}
+
+#[tokio::test]
+async fn test_p0_template_revalidate_all_reports_every_broken_template() {
+    // GIVEN: One valid and two broken registered templates
+    let temp_dir = tempfile::tempdir().unwrap();
+    let valid_path = create_template_dir(temp_dir.path(), "valid");
+    let emptied_path = temp_dir.path().join("emptied");
+    std::fs::create_dir_all(&emptied_path).unwrap();
+    let (db_arc, _db_temp_dir) = setup_test_database().await;
+    insert_template(&db_arc, "valid", valid_path.to_str().unwrap());
+    insert_template(&db_arc, "moved", "/nonexistent/template/path");
+    insert_template(&db_arc, "emptied", emptied_path.to_str().unwrap());
+    let output = CaptureOutput::new(OutputMode::Json);
+
+    // WHEN: Revalidating all templates
+    let command = TemplateCommands::Update {
+        name: None,
+        path: None,
+        rename: None,
+        revalidate_all: true,
+    };
+    let result = handler(&command, Some(db_arc), &NonInteractiveInput::new(), &output).await;
+
+    // THEN: Both broken templates are reported in one pass
+    let success_data = output.last_success().expect("Should have a report");
+    assert_eq!(success_data["valid"], false);
+    assert_eq!(success_data["checked"], 3);
+    let broken = success_data["broken"].as_array().unwrap();
+    let names: Vec<&str> = broken.iter().map(|b| b["name"].as_str().unwrap()).collect();
+    assert_eq!(names, vec!["emptied", "moved"]);
+    assert!(
+        broken[1]["error"]
+            .as_str()
+            .unwrap()
+            .contains("does not exist")
+    );
+
+    // AND: The command fails
+    let err = result.expect_err("Revalidation should fail with broken templates");
+    let cli_err = err.downcast_ref::<am::common::errors::CliError>().unwrap();
+    assert_eq!(cli_err.code, -29007); // ERR_INVALID_TEMPLATE_STRUCTURE
+    assert!(cli_err.what.starts_with("2 "), "{}", cli_err.what);
+}
+
+#[tokio::test]
+async fn test_p1_template_revalidate_all_succeeds_when_all_valid() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let valid_path = create_template_dir(temp_dir.path(), "valid");
+    let (db_arc, _db_temp_dir) = setup_test_database().await;
+    insert_template(&db_arc, "valid", valid_path.to_str().unwrap());
+    let output = CaptureOutput::new(OutputMode::Json);
+
+    let command = TemplateCommands::Update {
+        name: None,
+        path: None,
+        rename: None,
+        revalidate_all: true,
+    };
+    let result = handler(&command, Some(db_arc), &NonInteractiveInput::new(), &output).await;
+
+    assert!(result.is_ok(), "Handler should succeed: {:?}", result.err());
+    let success_data = output.last_success().unwrap();
+    assert_eq!(success_data["valid"], true);
+    assert_eq!(success_data["checked"], 1);
+    assert!(success_data["broken"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn test_p1_template_update_cli_argument_rules() {
+    use am::app::App;
+    use clap::Parser;
+
+    // Name and path are required, unless --revalidate-all is set
+    assert!(App::try_parse_from(["am", "template", "update", "t", "--path", "/p"]).is_ok());
+    assert!(App::try_parse_from(["am", "template", "update", "--revalidate-all"]).is_ok());
+    assert!(App::try_parse_from(["am", "template", "update", "t"]).is_err());
+    assert!(App::try_parse_from(["am", "template", "update", "--path", "/p"]).is_err());
+
+    // --revalidate-all doesn't combine with a single update
+    assert!(
+        App::try_parse_from([
+            "am",
+            "template",
+            "update",
+            "t",
+            "--path",
+            "/p",
+            "--revalidate-all"
+        ])
+        .is_err()
+    );
+}
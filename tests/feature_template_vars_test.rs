@@ -0,0 +1,295 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Feature tests for template variables.
+//!
+//! Tests cover:
+//! - Substituting built-in and given variables across the nested files of a template
+//! - Prompting for the variables declared in `template.vars.json`
+//! - Failing on unresolved variables before the project is written
+
+use am::app::{App, Commands};
+use am::commands::project::ProjectCommands;
+use am::commands::template::TemplateCommands;
+use am::common::errors::codes;
+use am::testing::{CommandRun, OutputCall, ScriptedInput, TestEnv};
+use clap::Parser;
+use std::fs;
+
+/// Bytes of a binary file holding `{{`, which must be copied untouched.
+const BINARY_CONTENT: &[u8] = &[0xff, 0xfe, b'{', b'{', b'a', b'}', b'}'];
+
+/// Create a template using several variables at `home/studio_template` and register it
+/// as `studio`.
+async fn register_template(env: &TestEnv) {
+    let template_path = env.home().join("studio_template");
+    let sounds = template_path.join("sources").join("sounds");
+    let credits = template_path.join("sources").join("events").join("credits");
+    fs::create_dir_all(&sounds).unwrap();
+    fs::create_dir_all(&credits).unwrap();
+
+    fs::write(
+        template_path.join(".amproject"),
+        serde_json::json!({
+            "name": "{{project_name}}",
+            "default_configuration": "pc.config.amconfig",
+            "sources_dir": "sources",
+            "data_dir": "data",
+            "build_dir": "build",
+            "version": 1
+        })
+        .to_string(),
+    )
+    .unwrap();
+    fs::write(template_path.join("pc.buses.json"), "{}").unwrap();
+    fs::write(template_path.join("pc.config.json"), "{}").unwrap();
+    fs::write(
+        template_path.join("README.md"),
+        "# {{ project_name }}\nBy {{author}} at {{studio}}, am {{cli_version}}.\nWrite \\{{author}} to use a variable.\n",
+    )
+    .unwrap();
+    fs::write(
+        sounds.join("hit.json"),
+        r#"{"id":1,"name":"{{author}}_hit"}"#,
+    )
+    .unwrap();
+    fs::write(sounds.join("hit.wav"), BINARY_CONTENT).unwrap();
+    fs::write(credits.join("license.txt"), "License: {{license}}\n").unwrap();
+    fs::write(
+        template_path.join("template.vars.json"),
+        serde_json::json!({
+            "variables": [
+                { "name": "author", "description": "Author of the project" },
+                { "name": "studio", "default": "Sparky Studios" },
+                { "name": "license", "required": false }
+            ]
+        })
+        .to_string(),
+    )
+    .unwrap();
+
+    let run = env
+        .run(
+            &TemplateCommands::Register {
+                path: template_path.display().to_string(),
+                name: Some("studio".to_string()),
+                force: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok(), "Register failed: {:?}", run.result);
+}
+
+async fn init(env: &TestEnv, vars: &[&str], input: ScriptedInput) -> CommandRun {
+    env.run_in(
+        env.home(),
+        &ProjectCommands::Init {
+            name: Some("my_game".to_string()),
+            template: Some("studio".to_string()),
+            asset_dirs: vec![],
+            no_register: true,
+            with_examples: false,
+            vars: vars.iter().map(|var| var.to_string()).collect(),
+            overwrite_dir: false,
+            replace_registration: false,
+            verify: false,
+            dry_run: false,
+        },
+        input,
+    )
+    .await
+}
+
+// =============================================================================
+// Command Parsing Tests
+// =============================================================================
+
+#[test]
+fn test_p0_var_flag_parses() {
+    let app = App::try_parse_from([
+        "am",
+        "project",
+        "init",
+        "my_game",
+        "--var",
+        "author=Jane",
+        "--var",
+        "motto=a=b",
+    ])
+    .expect("Should parse");
+
+    match app.command {
+        Commands::Project {
+            command: ProjectCommands::Init { vars, .. },
+        } => assert_eq!(vars, vec!["author=Jane", "motto=a=b"]),
+        _ => panic!("Expected project init"),
+    }
+}
+
+// =============================================================================
+// Substitution Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_init_substitutes_variables_in_nested_files() {
+    let env = TestEnv::new().await.unwrap();
+    register_template(&env).await;
+
+    let run = init(
+        &env,
+        &["author=Jane Doe"],
+        ScriptedInput::new().non_interactive(),
+    )
+    .await;
+
+    assert!(run.result.is_ok(), "Init failed: {:?}", run.result);
+    let project_path = env.home().join("my_game");
+    let sources = project_path.join("sources");
+    assert_eq!(
+        fs::read_to_string(project_path.join("README.md")).unwrap(),
+        format!(
+            "# my_game\nBy Jane Doe at Sparky Studios, am {}.\nWrite {{{{author}}}} to use a variable.\n",
+            env!("CARGO_PKG_VERSION")
+        )
+    );
+    assert_eq!(
+        fs::read_to_string(sources.join("sounds").join("hit.json")).unwrap(),
+        r#"{"id":1,"name":"Jane Doe_hit"}"#
+    );
+    assert_eq!(
+        fs::read_to_string(sources.join("events").join("credits").join("license.txt")).unwrap(),
+        "License: \n"
+    );
+    assert_eq!(
+        fs::read(sources.join("sounds").join("hit.wav")).unwrap(),
+        BINARY_CONTENT
+    );
+    assert!(!project_path.join("template.vars.json").exists());
+}
+
+#[tokio::test]
+async fn test_p0_init_prompts_for_declared_variables() {
+    let env = TestEnv::new().await.unwrap();
+    register_template(&env).await;
+
+    let run = init(
+        &env,
+        &[],
+        ScriptedInput::new().text("Jane").text("Indie").text("MIT"),
+    )
+    .await;
+
+    assert!(run.result.is_ok(), "Init failed: {:?}", run.result);
+    assert_eq!(run.unused_responses, 0);
+    let project_path = env.home().join("my_game");
+    let readme = fs::read_to_string(project_path.join("README.md")).unwrap();
+    assert!(readme.contains("By Jane at Indie"));
+    assert_eq!(
+        fs::read_to_string(
+            project_path
+                .join("sources")
+                .join("events")
+                .join("credits")
+                .join("license.txt")
+        )
+        .unwrap(),
+        "License: MIT\n"
+    );
+}
+
+#[tokio::test]
+async fn test_p1_unused_variable_is_reported() {
+    let env = TestEnv::new().await.unwrap();
+    register_template(&env).await;
+
+    let run = init(
+        &env,
+        &["author=Jane", "mascot=Sparky"],
+        ScriptedInput::new().non_interactive(),
+    )
+    .await;
+
+    assert!(run.result.is_ok(), "Init failed: {:?}", run.result);
+    assert!(
+        run.calls.iter().any(
+            |call| matches!(call, OutputCall::Warning(message) if message.contains("'mascot'"))
+        )
+    );
+}
+
+// =============================================================================
+// Unresolved Variable Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_unresolved_required_variable_fails_before_writing() {
+    let env = TestEnv::new().await.unwrap();
+    register_template(&env).await;
+
+    let run = init(&env, &[], ScriptedInput::new().non_interactive()).await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_VALIDATION_FIELD));
+    let message = format!("{:?}", run.result.unwrap_err());
+    assert!(message.contains("author"));
+    assert!(!env.home().join("my_game").exists());
+}
+
+#[tokio::test]
+async fn test_p1_undeclared_placeholder_is_required() {
+    let env = TestEnv::new().await.unwrap();
+    register_template(&env).await;
+    fs::write(
+        env.home().join("studio_template").join("engine.txt"),
+        "{{engine}}",
+    )
+    .unwrap();
+
+    let run = init(
+        &env,
+        &["author=Jane"],
+        ScriptedInput::new().non_interactive(),
+    )
+    .await;
+    assert_eq!(run.error_code(), Some(codes::ERR_VALIDATION_FIELD));
+    assert!(!env.home().join("my_game").exists());
+
+    let run = init(
+        &env,
+        &["author=Jane", "engine=o3de"],
+        ScriptedInput::new().non_interactive(),
+    )
+    .await;
+    assert!(run.result.is_ok(), "Init failed: {:?}", run.result);
+    assert_eq!(
+        fs::read_to_string(env.home().join("my_game").join("engine.txt")).unwrap(),
+        "o3de"
+    );
+}
+
+#[tokio::test]
+async fn test_p1_builtin_variable_cannot_be_given() {
+    let env = TestEnv::new().await.unwrap();
+    register_template(&env).await;
+
+    let run = init(
+        &env,
+        &["author=Jane", "project_name=other"],
+        ScriptedInput::new().non_interactive(),
+    )
+    .await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_VALIDATION_FIELD));
+    assert!(!env.home().join("my_game").exists());
+}
@@ -165,7 +165,7 @@ fn test_p0_collection_list_command_parses() {
         Commands::Asset {
             command:
                 AssetCommands::Collection {
-                    command: CollectionCommands::List {},
+                    command: CollectionCommands::List { .. },
                 },
         } => {} // Success - it parsed correctly
         _ => panic!("Expected Asset Collection List command"),
@@ -371,6 +371,7 @@ fn test_p1_collection_create_with_json_flag() {
 
 mod common;
 use am::commands::asset::handle_collection_command;
+use am::common::ignore::WalkOptions;
 use am::input::NonInteractiveInput;
 use common::fixtures::{CaptureOutput, TestProjectFixture};
 
@@ -451,6 +452,8 @@ async fn test_p0_collection_create_with_defaults() {
             spatialization: None,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -509,6 +512,8 @@ async fn test_p0_collection_create_with_all_flags() {
             spatialization: Some("position".to_string()),
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -567,6 +572,8 @@ async fn test_p1_collection_create_duplicate_name_fails() {
             spatialization: None,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -605,8 +612,17 @@ async fn test_p0_collection_list_empty_directory() {
     let input = NonInteractiveInput;
     let output = CaptureOutput::json();
 
-    let result =
-        handle_collection_command(&CollectionCommands::List {}, None, &input, &output).await;
+    let result = handle_collection_command(
+        &CollectionCommands::List {
+            limit: Default::default(),
+        },
+        None,
+        WalkOptions::default(),
+        None,
+        &input,
+        &output,
+    )
+    .await;
 
     if let Some(dir) = original_dir {
         let _ = std::env::set_current_dir(dir);
@@ -639,8 +655,17 @@ async fn test_p0_collection_list_multiple_collections() {
     let input = NonInteractiveInput;
     let output = CaptureOutput::json();
 
-    let result =
-        handle_collection_command(&CollectionCommands::List {}, None, &input, &output).await;
+    let result = handle_collection_command(
+        &CollectionCommands::List {
+            limit: Default::default(),
+        },
+        None,
+        WalkOptions::default(),
+        None,
+        &input,
+        &output,
+    )
+    .await;
 
     if let Some(dir) = original_dir {
         let _ = std::env::set_current_dir(dir);
@@ -702,6 +727,8 @@ async fn test_p0_collection_update_with_flags() {
             spatialization: None,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -764,6 +791,8 @@ async fn test_p1_collection_update_nonexistent_fails() {
             spatialization: None,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -813,6 +842,8 @@ async fn test_p0_collection_delete_with_force() {
             force: true,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -855,6 +886,8 @@ async fn test_p1_collection_delete_nonexistent_fails() {
             force: true,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -897,6 +930,8 @@ async fn test_p1_collection_delete_without_force_in_non_interactive_fails() {
             force: false,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -947,8 +982,17 @@ async fn test_p1_collection_list_json_output_format() {
     let input = NonInteractiveInput;
     let output = CaptureOutput::json();
 
-    let result =
-        handle_collection_command(&CollectionCommands::List {}, None, &input, &output).await;
+    let result = handle_collection_command(
+        &CollectionCommands::List {
+            limit: Default::default(),
+        },
+        None,
+        WalkOptions::default(),
+        None,
+        &input,
+        &output,
+    )
+    .await;
 
     if let Some(dir) = original_dir {
         let _ = std::env::set_current_dir(dir);
@@ -1001,6 +1045,8 @@ async fn test_p1_collection_create_json_output_format() {
             spatialization: None,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -1043,8 +1089,17 @@ async fn test_p1_collection_list_interactive_output() {
     let input = NonInteractiveInput;
     let output = CaptureOutput::interactive();
 
-    let result =
-        handle_collection_command(&CollectionCommands::List {}, None, &input, &output).await;
+    let result = handle_collection_command(
+        &CollectionCommands::List {
+            limit: Default::default(),
+        },
+        None,
+        WalkOptions::default(),
+        None,
+        &input,
+        &output,
+    )
+    .await;
 
     if let Some(dir) = original_dir {
         let _ = std::env::set_current_dir(dir);
@@ -1095,6 +1150,8 @@ async fn test_p1_collection_create_empty_name_fails() {
             spatialization: None,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -1139,6 +1196,8 @@ async fn test_p1_collection_create_whitespace_name_fails() {
             spatialization: None,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -0,0 +1,402 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for `am asset create --from-file` and `--from-stdin`.
+
+use am::app::{App, Commands};
+use am::assets::Switch;
+use am::commands::asset::AssetCommands;
+use am::common::errors::codes;
+use am::testing::{ScriptedInput, TestEnv};
+use clap::Parser;
+use serde_json::{Value, json};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+async fn setup_project() -> (TestEnv, PathBuf) {
+    let env = TestEnv::new()
+        .await
+        .expect("Failed to create test environment");
+    let project_path = env
+        .scaffold_project("create_project", 1)
+        .expect("Failed to scaffold project");
+    (env, project_path)
+}
+
+fn switch_file(project_path: &Path, name: &str) -> PathBuf {
+    project_path
+        .join("sources")
+        .join("switches")
+        .join(format!("{}.json", name))
+}
+
+fn read_switch(project_path: &Path, name: &str) -> Value {
+    serde_json::from_str(&fs::read_to_string(switch_file(project_path, name)).unwrap()).unwrap()
+}
+
+/// The definition of a switch with a single state.
+fn switch_body(id: u64, name: &str) -> Value {
+    serde_json::to_value(Switch::builder(id, name).state(id + 100, "default").build()).unwrap()
+}
+
+fn create_switch(name: &str, on_conflict: &str, rename_body: bool) -> AssetCommands {
+    AssetCommands::Create {
+        asset_type: "switch".to_string(),
+        name: name.to_string(),
+        from_file: None,
+        from_stdin: true,
+        rename_body,
+        on_conflict: on_conflict.to_string(),
+    }
+}
+
+fn stdin(body: &Value) -> ScriptedInput {
+    ScriptedInput::new().stdin(body.to_string())
+}
+
+// =============================================================================
+// Parsing Tests
+// =============================================================================
+
+#[test]
+fn test_p0_asset_create_requires_a_source() {
+    assert!(App::try_parse_from(["am", "asset", "create", "switch", "surface"]).is_err());
+    assert!(
+        App::try_parse_from([
+            "am",
+            "asset",
+            "create",
+            "switch",
+            "surface",
+            "--from-stdin",
+            "--from-file",
+            "surface.json",
+        ])
+        .is_err()
+    );
+}
+
+#[test]
+fn test_p1_asset_create_defaults_to_error_on_conflict() {
+    let app = App::try_parse_from(["am", "asset", "create", "switch", "surface", "--from-stdin"])
+        .expect("Should parse");
+
+    match app.command {
+        Commands::Asset {
+            command: AssetCommands::Create { on_conflict, .. },
+        } => assert_eq!(on_conflict, "error"),
+        _ => panic!("Expected asset create"),
+    }
+}
+
+// =============================================================================
+// Creation Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_asset_create_writes_body_from_stdin() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &create_switch("surface", "error", false),
+            stdin(&switch_body(9001, "surface")),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Create should succeed: {:?}",
+        run.result
+    );
+    let result = run.last_success().expect("Create should report success");
+    assert_eq!(result["status"], "created");
+    assert_eq!(result["id"], 9001);
+    let written = read_switch(&project_path, "surface");
+    assert_eq!(written["id"], 9001);
+    assert_eq!(written["name"], "surface");
+}
+
+#[tokio::test]
+async fn test_p0_asset_create_rejects_mismatched_name() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &create_switch("surface", "error", false),
+            stdin(&switch_body(9001, "material")),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_VALIDATION_FIELD));
+    assert!(!switch_file(&project_path, "surface").exists());
+}
+
+#[tokio::test]
+async fn test_p1_asset_create_rename_body_replaces_name() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &create_switch("surface", "error", true),
+            stdin(&switch_body(9001, "material")),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Create should succeed: {:?}",
+        run.result
+    );
+    assert_eq!(read_switch(&project_path, "surface")["name"], "surface");
+}
+
+#[tokio::test]
+async fn test_p1_asset_create_sets_missing_name() {
+    let (env, project_path) = setup_project().await;
+    let mut body = switch_body(9001, "surface");
+    body.as_object_mut().unwrap().remove("name");
+
+    let run = env
+        .run_in(
+            &project_path,
+            &create_switch("surface", "error", false),
+            stdin(&body),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Create should succeed: {:?}",
+        run.result
+    );
+    assert_eq!(read_switch(&project_path, "surface")["name"], "surface");
+}
+
+#[tokio::test]
+async fn test_p1_asset_create_reads_body_from_file() {
+    let (env, project_path) = setup_project().await;
+    let body_path = env.home().join("surface.json");
+    fs::write(&body_path, switch_body(9001, "surface").to_string()).unwrap();
+
+    let run = env
+        .run_in(
+            &project_path,
+            &AssetCommands::Create {
+                asset_type: "switch".to_string(),
+                name: "surface".to_string(),
+                from_file: Some(body_path),
+                from_stdin: false,
+                rename_body: false,
+                on_conflict: "error".to_string(),
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Create should succeed: {:?}",
+        run.result
+    );
+    assert!(switch_file(&project_path, "surface").exists());
+}
+
+// =============================================================================
+// Validation Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p1_asset_create_rejects_invalid_json() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &create_switch("surface", "error", false),
+            ScriptedInput::new().stdin("{ not json"),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_VALIDATION_SCHEMA));
+    assert!(!switch_file(&project_path, "surface").exists());
+}
+
+#[tokio::test]
+async fn test_p1_asset_create_rejects_body_of_another_type() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &create_switch("surface", "error", false),
+            stdin(&json!({ "name": "surface", "states": "not a list" })),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_VALIDATION_SCHEMA));
+    assert!(!switch_file(&project_path, "surface").exists());
+}
+
+#[tokio::test]
+async fn test_p1_asset_create_rejects_id_of_another_asset() {
+    let (env, project_path) = setup_project().await;
+
+    // switch_1 of the scaffolded project has ID 4001
+    let run = env
+        .run_in(
+            &project_path,
+            &create_switch("surface", "error", false),
+            stdin(&switch_body(4001, "surface")),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_ASSET_ALREADY_EXISTS));
+    assert!(!switch_file(&project_path, "surface").exists());
+}
+
+// =============================================================================
+// Conflict Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_asset_create_fails_on_existing_asset_by_default() {
+    let (env, project_path) = setup_project().await;
+    let before = fs::read_to_string(switch_file(&project_path, "switch_1")).unwrap();
+
+    let run = env
+        .run_in(
+            &project_path,
+            &create_switch("switch_1", "error", false),
+            stdin(&switch_body(9001, "switch_1")),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_ASSET_ALREADY_EXISTS));
+    assert_eq!(
+        fs::read_to_string(switch_file(&project_path, "switch_1")).unwrap(),
+        before
+    );
+}
+
+#[tokio::test]
+async fn test_p1_asset_create_skip_keeps_existing_asset() {
+    let (env, project_path) = setup_project().await;
+    let before = fs::read_to_string(switch_file(&project_path, "switch_1")).unwrap();
+
+    let run = env
+        .run_in(
+            &project_path,
+            &create_switch("switch_1", "skip", false),
+            stdin(&switch_body(9001, "switch_1")),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Create should succeed: {:?}",
+        run.result
+    );
+    assert_eq!(run.last_success().unwrap()["status"], "skipped");
+    assert_eq!(
+        fs::read_to_string(switch_file(&project_path, "switch_1")).unwrap(),
+        before
+    );
+}
+
+#[tokio::test]
+async fn test_p1_asset_create_overwrite_replaces_existing_asset() {
+    let (env, project_path) = setup_project().await;
+
+    // The replaced asset may keep its own ID
+    let run = env
+        .run_in(
+            &project_path,
+            &create_switch("switch_1", "overwrite", false),
+            stdin(&switch_body(4001, "switch_1")),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Create should succeed: {:?}",
+        run.result
+    );
+    assert_eq!(run.last_success().unwrap()["status"], "overwritten");
+    let written = read_switch(&project_path, "switch_1");
+    assert_eq!(written["states"][0]["id"], 4101);
+}
+
+#[tokio::test]
+async fn test_p2_asset_create_rejects_unknown_conflict_policy() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &create_switch("surface", "merge", false),
+            stdin(&switch_body(9001, "surface")),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_VALIDATION_FIELD));
+}
+
+// =============================================================================
+// Binary Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_asset_create_reads_body_piped_to_the_binary() {
+    let (env, project_path) = setup_project().await;
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_am"))
+        .args([
+            "--json",
+            "asset",
+            "create",
+            "switch",
+            "surface",
+            "--from-stdin",
+        ])
+        .current_dir(&project_path)
+        .envs(env.env_vars())
+        .env_remove("AM_SDK_PATH")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to execute command");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(switch_body(9001, "surface").to_string().as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    assert_eq!(
+        output.status.code(),
+        Some(0),
+        "stdout: {}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    let envelope: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(envelope["value"]["status"], "created");
+    assert_eq!(read_switch(&project_path, "surface")["id"], 9001);
+}
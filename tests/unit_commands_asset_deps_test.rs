@@ -0,0 +1,226 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for `am asset deps` and the reference checks of asset deletion.
+
+use am::app::{App, Commands};
+use am::commands::asset::{AssetCommands, EffectCommands, SwitchCommands};
+use am::common::errors::codes;
+use am::testing::{ScriptedInput, TestEnv};
+use clap::Parser;
+use std::path::PathBuf;
+
+async fn setup_project() -> (TestEnv, PathBuf) {
+    let env = TestEnv::new()
+        .await
+        .expect("Failed to create test environment");
+    let project_path = env
+        .scaffold_project("deps_project", 2)
+        .expect("Failed to scaffold project");
+    (env, project_path)
+}
+
+fn deps(asset_type: &str, name: &str, transitive: bool) -> AssetCommands {
+    AssetCommands::Deps {
+        asset_type: asset_type.to_string(),
+        name: name.to_string(),
+        transitive,
+    }
+}
+
+// =============================================================================
+// Deps Command Parsing Tests
+// =============================================================================
+
+#[test]
+fn test_p0_deps_command_parses() {
+    let app = App::try_parse_from(["am", "asset", "deps", "sound", "footstep", "--transitive"])
+        .expect("Should parse");
+
+    match app.command {
+        Commands::Asset {
+            command:
+                AssetCommands::Deps {
+                    asset_type,
+                    name,
+                    transitive,
+                },
+        } => {
+            assert_eq!(asset_type, "sound");
+            assert_eq!(name, "footstep");
+            assert!(transitive);
+        }
+        _ => panic!("Expected Asset Deps command"),
+    }
+}
+
+// =============================================================================
+// Deps Command Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_deps_lists_direct_dependents() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &deps("sound", "sound_1", false),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Deps should succeed: {:?}", run.result);
+    let data = run.last_success().expect("Should have success output");
+    assert_eq!(data["asset"]["name"], "sound_1");
+    assert_eq!(
+        data["dependents"]["sound/sound_1"],
+        serde_json::json!(["soundbank/soundbank_1", "event/event_1"])
+    );
+    assert!(data["nodes"]["event/event_1"]["path"].is_string());
+}
+
+#[tokio::test]
+async fn test_p0_deps_transitive_follows_the_whole_chain() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &deps("switch", "switch_1", true),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Deps should succeed: {:?}", run.result);
+    let data = run.last_success().unwrap();
+    assert_eq!(data["transitive"], true);
+    assert_eq!(
+        data["dependents"]["switch/switch_1"],
+        serde_json::json!(["switch_container/switch_container_1"])
+    );
+    assert_eq!(data["dependencies"], serde_json::json!({}));
+}
+
+#[tokio::test]
+async fn test_p1_deps_accepts_dashed_asset_types() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &deps("switch-container", "switch_container_2", false),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Deps should succeed: {:?}", run.result);
+    let data = run.last_success().unwrap();
+    assert_eq!(
+        data["dependencies"]["switch_container/switch_container_2"],
+        serde_json::json!(["switch/switch_2"])
+    );
+}
+
+#[tokio::test]
+async fn test_p1_deps_rejects_unknown_asset_type() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &deps("banana", "sound_1", false),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_VALIDATION_FIELD));
+}
+
+#[tokio::test]
+async fn test_p1_deps_unknown_asset_is_not_found() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &deps("sound", "missing", false),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_ASSET_NOT_FOUND));
+}
+
+// =============================================================================
+// Delete Reference Check Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_delete_refuses_referenced_asset_even_with_force() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &AssetCommands::Switch {
+                command: SwitchCommands::Delete {
+                    name: "switch_1".to_string(),
+                    force: true,
+                },
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_ASSET_IN_USE));
+    assert!(
+        project_path
+            .join("sources")
+            .join("switches")
+            .join("switch_1.json")
+            .exists()
+    );
+}
+
+#[tokio::test]
+async fn test_p0_delete_unreferenced_asset_succeeds() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &AssetCommands::Effect {
+                command: EffectCommands::Delete {
+                    name: "effect_1".to_string(),
+                    force: true,
+                },
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Delete should succeed: {:?}",
+        run.result
+    );
+    assert!(
+        !project_path
+            .join("sources")
+            .join("effects")
+            .join("effect_1.json")
+            .exists()
+    );
+}
@@ -107,7 +107,7 @@ fn test_p0_effect_list_command_parses() {
         Commands::Asset {
             command:
                 AssetCommands::Effect {
-                    command: EffectCommands::List {},
+                    command: EffectCommands::List { .. },
                 },
         } => {} // Success - it parsed correctly
         _ => panic!("Expected Asset Effect List command"),
@@ -294,6 +294,7 @@ fn test_p1_effect_create_with_json_flag() {
 
 mod common;
 use am::commands::asset::handle_effect_command;
+use am::common::ignore::WalkOptions;
 use am::input::NonInteractiveInput;
 use common::fixtures::{CaptureOutput, TestProjectFixture};
 
@@ -361,6 +362,8 @@ async fn test_p0_effect_create_with_defaults() {
             param: None,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -411,6 +414,8 @@ async fn test_p0_effect_create_with_all_flags() {
             param: Some(vec![0.8, 0.5]),
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -462,6 +467,8 @@ async fn test_p1_effect_create_duplicate_name_fails() {
             param: None,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -500,7 +507,17 @@ async fn test_p0_effect_list_empty_directory() {
     let input = NonInteractiveInput;
     let output = CaptureOutput::json();
 
-    let result = handle_effect_command(&EffectCommands::List {}, None, &input, &output).await;
+    let result = handle_effect_command(
+        &EffectCommands::List {
+            limit: Default::default(),
+        },
+        None,
+        WalkOptions::default(),
+        None,
+        &input,
+        &output,
+    )
+    .await;
 
     if let Some(dir) = original_dir {
         let _ = std::env::set_current_dir(dir);
@@ -533,7 +550,17 @@ async fn test_p0_effect_list_multiple_effects() {
     let input = NonInteractiveInput;
     let output = CaptureOutput::json();
 
-    let result = handle_effect_command(&EffectCommands::List {}, None, &input, &output).await;
+    let result = handle_effect_command(
+        &EffectCommands::List {
+            limit: Default::default(),
+        },
+        None,
+        WalkOptions::default(),
+        None,
+        &input,
+        &output,
+    )
+    .await;
 
     if let Some(dir) = original_dir {
         let _ = std::env::set_current_dir(dir);
@@ -589,6 +616,8 @@ async fn test_p0_effect_update_with_flags() {
             param: Some(vec![1.0, 0.3]),
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -646,6 +675,8 @@ async fn test_p1_effect_update_nonexistent_fails() {
             param: None,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -695,6 +726,8 @@ async fn test_p0_effect_delete_with_force() {
             force: true,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -737,6 +770,8 @@ async fn test_p1_effect_delete_nonexistent_fails() {
             force: true,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -779,6 +814,8 @@ async fn test_p1_effect_delete_without_force_in_non_interactive_fails() {
             force: false,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -829,7 +866,17 @@ async fn test_p1_effect_list_json_output_format() {
     let input = NonInteractiveInput;
     let output = CaptureOutput::json();
 
-    let result = handle_effect_command(&EffectCommands::List {}, None, &input, &output).await;
+    let result = handle_effect_command(
+        &EffectCommands::List {
+            limit: Default::default(),
+        },
+        None,
+        WalkOptions::default(),
+        None,
+        &input,
+        &output,
+    )
+    .await;
 
     if let Some(dir) = original_dir {
         let _ = std::env::set_current_dir(dir);
@@ -876,6 +923,8 @@ async fn test_p1_effect_create_json_output_format() {
             param: Some(vec![0.9]),
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -917,7 +966,17 @@ async fn test_p1_effect_list_interactive_output() {
     let input = NonInteractiveInput;
     let output = CaptureOutput::interactive();
 
-    let result = handle_effect_command(&EffectCommands::List {}, None, &input, &output).await;
+    let result = handle_effect_command(
+        &EffectCommands::List {
+            limit: Default::default(),
+        },
+        None,
+        WalkOptions::default(),
+        None,
+        &input,
+        &output,
+    )
+    .await;
 
     if let Some(dir) = original_dir {
         let _ = std::env::set_current_dir(dir);
@@ -964,6 +1023,8 @@ async fn test_p1_effect_create_empty_name_fails() {
             param: None,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -1004,6 +1065,8 @@ async fn test_p1_effect_create_whitespace_name_fails() {
             param: None,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -0,0 +1,237 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for `am asset graph`.
+//!
+//! The DOT export of a small fixture project is compared with
+//! `tests/snapshots/asset_graph.dot`. After an intended change, refresh the snapshot with:
+//!
+//! ```bash
+//! UPDATE_SNAPSHOTS=1 cargo test --test unit_commands_asset_graph_test
+//! ```
+
+use am::app::{App, Commands};
+use am::commands::asset::AssetCommands;
+use am::common::errors::codes;
+use am::presentation::OutputMode;
+use am::testing::{CommandRun, OutputCall, ScriptedInput, TestEnv};
+use clap::Parser;
+use serde_json::{Value, json};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const SNAPSHOT: &str = "tests/snapshots/asset_graph.dot";
+
+/// Scaffold a project with one asset of each type, where `event_1` also targets the
+/// missing asset 999.
+async fn setup_project() -> (TestEnv, PathBuf) {
+    let env = TestEnv::new()
+        .await
+        .expect("Failed to create test environment");
+    let project_path = env
+        .scaffold_project("graph_project", 1)
+        .expect("Failed to scaffold project");
+
+    let event_path = project_path
+        .join("sources")
+        .join("events")
+        .join("event_1.json");
+    let mut event: Value = serde_json::from_str(&fs::read_to_string(&event_path).unwrap()).unwrap();
+    event["actions"][0]["targets"]
+        .as_array_mut()
+        .unwrap()
+        .push(json!(999));
+    fs::write(&event_path, serde_json::to_string_pretty(&event).unwrap()).unwrap();
+
+    (env, project_path)
+}
+
+fn graph(format: &str, focus: Option<&str>) -> AssetCommands {
+    AssetCommands::Graph {
+        format: format.to_string(),
+        focus: focus.map(str::to_string),
+    }
+}
+
+fn printed(run: &CommandRun) -> String {
+    run.calls
+        .iter()
+        .filter_map(|c| match c {
+            OutputCall::Print(text) => Some(format!("{}\n", text)),
+            _ => None,
+        })
+        .collect()
+}
+
+async fn run_interactive(env: &TestEnv, project_path: &Path, command: &AssetCommands) -> String {
+    let run = env
+        .run_in_with_mode(
+            project_path,
+            command,
+            ScriptedInput::new(),
+            OutputMode::Interactive,
+        )
+        .await;
+    assert!(run.result.is_ok(), "Graph should succeed: {:?}", run.result);
+    printed(&run)
+}
+
+// =============================================================================
+// Graph Command Parsing Tests
+// =============================================================================
+
+#[test]
+fn test_p0_graph_command_defaults_to_dot() {
+    let app = App::try_parse_from(["am", "asset", "graph"]).expect("Should parse");
+
+    match app.command {
+        Commands::Asset {
+            command: AssetCommands::Graph { format, focus },
+        } => {
+            assert_eq!(format, "dot");
+            assert!(focus.is_none());
+        }
+        _ => panic!("Expected Asset Graph command"),
+    }
+}
+
+// =============================================================================
+// Export Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_dot_export_matches_snapshot() {
+    let (env, project_path) = setup_project().await;
+
+    let actual = run_interactive(&env, &project_path, &graph("dot", None)).await;
+
+    let snapshot_path = Path::new(env!("CARGO_MANIFEST_DIR")).join(SNAPSHOT);
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&snapshot_path, &actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&snapshot_path).expect("Snapshot file should exist");
+    assert_eq!(
+        actual, expected,
+        "DOT export changed, run with UPDATE_SNAPSHOTS=1 if intended"
+    );
+}
+
+#[tokio::test]
+async fn test_p1_mermaid_export_marks_dangling_references() {
+    let (env, project_path) = setup_project().await;
+
+    let mermaid = run_interactive(&env, &project_path, &graph("mermaid", None)).await;
+
+    assert!(mermaid.starts_with("flowchart LR\n"));
+    assert!(mermaid.contains("n0([\"sound_1<br/>sound\"])"));
+    assert!(mermaid.contains("m0[\"missing<br/>ID 999\"]:::dangling"));
+    assert!(mermaid.contains("n5 -.->|\"actions[0].targets[1]\"| m0"));
+    assert!(mermaid.contains("classDef dangling"));
+}
+
+#[tokio::test]
+async fn test_p0_json_export_lists_nodes_and_edges() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(&project_path, &graph("dot", None), ScriptedInput::new())
+        .await;
+
+    assert!(run.result.is_ok(), "Graph should succeed: {:?}", run.result);
+    let data = run.last_success().unwrap();
+    assert_eq!(data["focus"], Value::Null);
+    assert_eq!(data["nodes"].as_array().unwrap().len(), 7);
+    assert_eq!(data["nodes"][0]["type"], "sound");
+    assert_eq!(data["edges"].as_array().unwrap().len(), 3);
+    assert!(data["edges"].as_array().unwrap().contains(&json!({
+        "from": "event/event_1",
+        "to": "sound/sound_1",
+        "field": "actions[0].targets[0]",
+    })));
+    assert_eq!(
+        data["unresolved"],
+        json!([{
+            "from": "event/event_1",
+            "field": "actions[0].targets[1]",
+            "target": "ID 999",
+        }])
+    );
+}
+
+// =============================================================================
+// Focus Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_focus_limits_the_graph_to_direct_neighbors() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &graph("dot", Some("sound/sound_1")),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Graph should succeed: {:?}", run.result);
+    let data = run.last_success().unwrap();
+    assert_eq!(data["focus"], "sound/sound_1");
+    let names: Vec<&str> = data["nodes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|n| n["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, vec!["sound_1", "soundbank_1", "event_1"]);
+    assert_eq!(data["edges"].as_array().unwrap().len(), 2);
+    // The dangling reference belongs to event_1, which is part of the neighborhood.
+    assert_eq!(data["unresolved"].as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_p1_focus_on_unknown_asset_is_not_found() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &graph("dot", Some("event/missing")),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_ASSET_NOT_FOUND));
+}
+
+#[tokio::test]
+async fn test_p1_invalid_format_and_focus_are_rejected() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(&project_path, &graph("svg", None), ScriptedInput::new())
+        .await;
+    assert_eq!(run.error_code(), Some(codes::ERR_VALIDATION_FIELD));
+
+    let run = env
+        .run_in(
+            &project_path,
+            &graph("dot", Some("sound_1")),
+            ScriptedInput::new(),
+        )
+        .await;
+    assert_eq!(run.error_code(), Some(codes::ERR_VALIDATION_FIELD));
+}
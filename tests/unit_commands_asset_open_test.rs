@@ -0,0 +1,295 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for `am asset open`.
+
+use am::app::{App, Commands};
+use am::commands::asset::AssetCommands;
+use am::common::editor::EditorCommand;
+use am::common::errors::codes;
+use am::testing::{ScriptedInput, TestEnv};
+use clap::Parser;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+async fn setup_project() -> (TestEnv, PathBuf) {
+    let env = TestEnv::new()
+        .await
+        .expect("Failed to create test environment");
+    let project_path = env
+        .scaffold_project("open_project", 1)
+        .expect("Failed to scaffold project");
+    (env, project_path)
+}
+
+fn asset_file(project_path: &Path, dir: &str, name: &str) -> PathBuf {
+    project_path
+        .join("sources")
+        .join(dir)
+        .join(format!("{}.json", name))
+}
+
+fn open(asset_type: &str, name: &str, wait: bool, print_path: bool) -> AssetCommands {
+    AssetCommands::Open {
+        asset_type: asset_type.to_string(),
+        name: name.to_string(),
+        wait,
+        print_path,
+    }
+}
+
+/// The editor of the environment variables `vars`.
+fn editor(vars: &[(&str, &str)], wait: bool) -> EditorCommand {
+    let vars: HashMap<&str, &str> = vars.iter().copied().collect();
+    EditorCommand::resolve(|name| vars.get(name).map(|v| v.to_string()), wait)
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap()
+}
+
+// =============================================================================
+// Open Command Parsing Tests
+// =============================================================================
+
+#[test]
+fn test_p0_open_command_parses() {
+    let app = App::try_parse_from(["am", "asset", "open", "sound", "footstep", "--wait"])
+        .expect("Should parse");
+
+    match app.command {
+        Commands::Asset {
+            command:
+                AssetCommands::Open {
+                    asset_type,
+                    name,
+                    wait,
+                    print_path,
+                },
+        } => {
+            assert_eq!(asset_type, "sound");
+            assert_eq!(name, "footstep");
+            assert!(wait);
+            assert!(!print_path);
+        }
+        _ => panic!("Expected Asset Open command"),
+    }
+}
+
+#[test]
+fn test_p1_print_path_conflicts_with_wait() {
+    let result = App::try_parse_from([
+        "am",
+        "asset",
+        "open",
+        "sound",
+        "footstep",
+        "--wait",
+        "--print-path",
+    ]);
+    assert!(result.is_err());
+}
+
+// =============================================================================
+// Editor Resolution Tests
+// =============================================================================
+
+#[test]
+fn test_p0_visual_takes_precedence_over_editor() {
+    let command = editor(&[("VISUAL", "code --wait"), ("EDITOR", "vim")], false);
+
+    assert_eq!(command.program, "code");
+    assert_eq!(command.args, ["--wait"]);
+    assert!(!command.platform_opener);
+}
+
+#[test]
+fn test_p1_blank_visual_falls_back_to_editor() {
+    let command = editor(&[("VISUAL", "  "), ("EDITOR", "nano")], false);
+
+    assert_eq!(command.program, "nano");
+    assert!(command.args.is_empty());
+}
+
+#[test]
+fn test_p1_without_editor_uses_platform_opener() {
+    let command = editor(&[], true);
+
+    assert!(command.platform_opener);
+    assert!(!command.program.is_empty());
+}
+
+// =============================================================================
+// Path Resolution Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_print_path_resolves_asset_file() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &open("sound", "sound_1", false, true),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Open should succeed: {:?}", run.result);
+    let data = run.last_success().expect("Should have success output");
+    assert_eq!(data["type"], "sound");
+    assert_eq!(data["name"], "sound_1");
+    assert_eq!(
+        canonical(Path::new(data["path"].as_str().unwrap())),
+        canonical(&asset_file(&project_path, "sounds", "sound_1"))
+    );
+}
+
+#[tokio::test]
+async fn test_p1_print_path_finds_asset_in_subdirectory() {
+    let (env, project_path) = setup_project().await;
+    let mut sound: Value = serde_json::from_str(
+        &fs::read_to_string(asset_file(&project_path, "sounds", "sound_1")).unwrap(),
+    )
+    .unwrap();
+    sound["id"] = 1999.into();
+    sound["name"] = "wind".into();
+    let nested = project_path.join("sources/sounds/ambience/wind_loop.json");
+    fs::create_dir_all(nested.parent().unwrap()).unwrap();
+    fs::write(&nested, serde_json::to_string_pretty(&sound).unwrap()).unwrap();
+
+    let run = env
+        .run_in(
+            &project_path,
+            &open("sound", "wind", false, true),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Open should succeed: {:?}", run.result);
+    let data = run.last_success().unwrap();
+    assert_eq!(
+        canonical(Path::new(data["path"].as_str().unwrap())),
+        canonical(&nested)
+    );
+}
+
+#[tokio::test]
+async fn test_p1_open_unknown_asset_fails() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &open("sound", "missing", false, true),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_ASSET_NOT_FOUND));
+}
+
+#[tokio::test]
+async fn test_p0_open_without_print_path_requires_interactive_input() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &open("sound", "sound_1", true, false),
+            ScriptedInput::new().non_interactive(),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_VALIDATION_FIELD));
+}
+
+// =============================================================================
+// Post-Edit Validation Tests
+// =============================================================================
+
+#[cfg(unix)]
+mod editing {
+    use super::*;
+    use am::testing::CommandRun;
+    use std::os::unix::fs::PermissionsExt;
+    use tokio::sync::Mutex;
+
+    /// Serializes the tests setting `VISUAL`, as the environment is shared by the process.
+    static VISUAL_LOCK: Mutex<()> = Mutex::const_new(());
+
+    /// Write an executable editor script running `body` with the file as `$1`.
+    fn editor_script(dir: &Path, body: &str) -> PathBuf {
+        let script = dir.join("editor.sh");
+        fs::write(&script, format!("#!/bin/sh\n{}\n", body)).unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+        script
+    }
+
+    /// Run `am asset open --wait` on `sound_1` with `VISUAL` set to the script `body`.
+    async fn edit_with(body: &str) -> (CommandRun, PathBuf) {
+        let _guard = VISUAL_LOCK.lock().await;
+        let (env, project_path) = setup_project().await;
+        let script = editor_script(env.home(), body);
+
+        // SAFETY: The tests reading or writing VISUAL hold VISUAL_LOCK.
+        unsafe { std::env::set_var("VISUAL", &script) };
+        let run = env
+            .run_in(
+                &project_path,
+                &open("sound", "sound_1", true, false),
+                ScriptedInput::new(),
+            )
+            .await;
+        unsafe { std::env::remove_var("VISUAL") };
+
+        (run, asset_file(&project_path, "sounds", "sound_1"))
+    }
+
+    #[tokio::test]
+    async fn test_p0_edit_leaving_valid_file_succeeds() {
+        let (run, _) = edit_with("exit 0").await;
+
+        assert!(run.result.is_ok(), "Open should succeed: {:?}", run.result);
+        let message = run.last_success().unwrap().as_str().unwrap().to_string();
+        assert!(message.contains("is valid"), "{}", message);
+    }
+
+    #[tokio::test]
+    async fn test_p0_edit_mangling_json_is_reported() {
+        let (run, file) = edit_with("printf '{ \"id\": ' > \"$1\"").await;
+
+        assert_eq!(run.error_code(), Some(codes::ERR_VALIDATION_SCHEMA));
+        let error = run.result.unwrap_err().to_string();
+        assert!(error.contains("Invalid JSON"), "{}", error);
+        // The edit is kept for the user to fix
+        assert_eq!(fs::read_to_string(file).unwrap(), "{ \"id\": ");
+    }
+
+    #[tokio::test]
+    async fn test_p1_edit_breaking_the_asset_type_is_reported() {
+        let (run, _) = edit_with("printf '{ \"name\": 42 }' > \"$1\"").await;
+
+        assert_eq!(run.error_code(), Some(codes::ERR_VALIDATION_SCHEMA));
+    }
+
+    #[tokio::test]
+    async fn test_p1_failing_editor_is_reported() {
+        let (run, _) = edit_with("exit 3").await;
+
+        assert_eq!(run.error_code(), Some(codes::ERR_EDITOR_FAILED));
+    }
+}
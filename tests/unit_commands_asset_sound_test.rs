@@ -541,7 +541,7 @@ fn test_p0_sound_list_command_parses() {
         Commands::Asset {
             command:
                 AssetCommands::Sound {
-                    command: SoundCommands::List {},
+                    command: SoundCommands::List { .. },
                 },
         } => {
             // Success - command parsed correctly
@@ -560,7 +560,7 @@ fn test_p1_sound_list_command_with_json_flag() {
         Commands::Asset {
             command:
                 AssetCommands::Sound {
-                    command: SoundCommands::List {},
+                    command: SoundCommands::List { .. },
                 },
         } => {
             // Success - command parsed correctly
@@ -575,6 +575,7 @@ fn test_p1_sound_list_command_with_json_flag() {
 
 mod common;
 use am::commands::asset::handle_sound_command;
+use am::common::ignore::WalkOptions;
 use am::input::NonInteractiveInput;
 use am::presentation::{Output, OutputMode};
 use common::fixtures::{CaptureOutput, TestProjectFixture};
@@ -650,7 +651,17 @@ async fn test_p0_sound_list_multiple_sounds_displays_all() {
     let output = CaptureOutput::json();
 
     // Run the list command
-    let result = handle_sound_command(&SoundCommands::List {}, None, &input, &output).await;
+    let result = handle_sound_command(
+        &SoundCommands::List {
+            limit: Default::default(),
+        },
+        None,
+        WalkOptions::default(),
+        None,
+        &input,
+        &output,
+    )
+    .await;
 
     // Restore original directory if we had one
     if let Some(dir) = original_dir {
@@ -705,7 +716,17 @@ async fn test_p0_sound_list_empty_directory_shows_message() {
     let output = CaptureOutput::json();
 
     // Run the list command
-    let result = handle_sound_command(&SoundCommands::List {}, None, &input, &output).await;
+    let result = handle_sound_command(
+        &SoundCommands::List {
+            limit: Default::default(),
+        },
+        None,
+        WalkOptions::default(),
+        None,
+        &input,
+        &output,
+    )
+    .await;
 
     // Restore original directory if we had one
     if let Some(dir) = original_dir {
@@ -758,7 +779,17 @@ async fn test_p1_sound_list_invalid_json_warns_but_continues() {
     let output = CaptureOutput::json();
 
     // Run the list command
-    let result = handle_sound_command(&SoundCommands::List {}, None, &input, &output).await;
+    let result = handle_sound_command(
+        &SoundCommands::List {
+            limit: Default::default(),
+        },
+        None,
+        WalkOptions::default(),
+        None,
+        &input,
+        &output,
+    )
+    .await;
 
     // Restore original directory if we had one
     if let Some(dir) = original_dir {
@@ -822,7 +853,17 @@ async fn test_p1_sound_list_json_output_envelope_format() {
     assert_eq!(output.mode(), OutputMode::Json);
 
     // Run the list command
-    let result = handle_sound_command(&SoundCommands::List {}, None, &input, &output).await;
+    let result = handle_sound_command(
+        &SoundCommands::List {
+            limit: Default::default(),
+        },
+        None,
+        WalkOptions::default(),
+        None,
+        &input,
+        &output,
+    )
+    .await;
 
     // Restore original directory if we had one
     if let Some(dir) = original_dir {
@@ -901,7 +942,17 @@ async fn test_p1_sound_list_table_format_has_correct_columns() {
     let output = CaptureOutput::interactive();
 
     // Run the list command
-    let result = handle_sound_command(&SoundCommands::List {}, None, &input, &output).await;
+    let result = handle_sound_command(
+        &SoundCommands::List {
+            limit: Default::default(),
+        },
+        None,
+        WalkOptions::default(),
+        None,
+        &input,
+        &output,
+    )
+    .await;
 
     // Restore original directory if we had one
     if let Some(dir) = original_dir {
@@ -970,7 +1021,17 @@ async fn test_p1_sound_list_no_sounds_directory() {
     let output = CaptureOutput::json();
 
     // Run the list command
-    let result = handle_sound_command(&SoundCommands::List {}, None, &input, &output).await;
+    let result = handle_sound_command(
+        &SoundCommands::List {
+            limit: Default::default(),
+        },
+        None,
+        WalkOptions::default(),
+        None,
+        &input,
+        &output,
+    )
+    .await;
 
     // Restore original directory if we had one
     if let Some(dir) = original_dir {
@@ -1014,7 +1075,17 @@ async fn test_p1_sound_list_all_invalid_shows_warnings_in_interactive() {
     let output = CaptureOutput::interactive();
 
     // Run the list command
-    let result = handle_sound_command(&SoundCommands::List {}, None, &input, &output).await;
+    let result = handle_sound_command(
+        &SoundCommands::List {
+            limit: Default::default(),
+        },
+        None,
+        WalkOptions::default(),
+        None,
+        &input,
+        &output,
+    )
+    .await;
 
     // Restore original directory
     if let Some(dir) = original_dir {
@@ -1284,6 +1355,8 @@ async fn test_p0_sound_update_changes_gain_preserves_other_fields() {
             spatialization: None,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -1355,6 +1428,8 @@ async fn test_p0_sound_update_not_found_returns_error() {
             spatialization: None,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -1423,6 +1498,8 @@ async fn test_p1_sound_update_invalid_path_does_not_modify_original() {
             spatialization: None,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -1490,6 +1567,8 @@ async fn test_p1_sound_update_json_output_envelope_format() {
             spatialization: None,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -1578,6 +1657,8 @@ async fn test_p1_sound_update_only_updates_specified_flags() {
             spatialization: None,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -1690,6 +1771,8 @@ async fn test_p1_sound_update_preserves_sound_id() {
             spatialization: Some("hrtf".to_string()),
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -1756,6 +1839,8 @@ async fn test_p1_sound_update_invalid_gain_returns_error() {
             spatialization: None,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -1819,6 +1904,8 @@ async fn test_p1_sound_update_bus_field_updates_correctly() {
             spatialization: None,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -1888,6 +1975,8 @@ async fn test_p1_sound_update_json_includes_updated_fields() {
             spatialization: None,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
@@ -1973,7 +2062,17 @@ async fn test_p1_sound_list_table_truncates_long_paths() {
     let output = CaptureOutput::interactive();
 
     // Run the list command
-    let result = handle_sound_command(&SoundCommands::List {}, None, &input, &output).await;
+    let result = handle_sound_command(
+        &SoundCommands::List {
+            limit: Default::default(),
+        },
+        None,
+        WalkOptions::default(),
+        None,
+        &input,
+        &output,
+    )
+    .await;
 
     // Restore original directory if we had one
     if let Some(dir) = original_dir {
@@ -86,7 +86,7 @@ fn test_p0_switch_list_command_parses() {
         Commands::Asset {
             command:
                 AssetCommands::Switch {
-                    command: SwitchCommands::List {},
+                    command: SwitchCommands::List { .. },
                 },
         } => {
             // Command parsed successfully
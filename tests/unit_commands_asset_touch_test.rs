@@ -0,0 +1,335 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for `am asset touch --bump-version`.
+
+use am::app::{App, Commands};
+use am::commands::asset::AssetCommands;
+use am::common::errors::codes;
+use am::testing::{ScriptedInput, TestEnv};
+use clap::Parser;
+use serde_json::{Value, json};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+async fn setup_project() -> (TestEnv, PathBuf) {
+    let env = TestEnv::new()
+        .await
+        .expect("Failed to create test environment");
+    let project_path = env
+        .scaffold_project("touch_project", 1)
+        .expect("Failed to scaffold project");
+    (env, project_path)
+}
+
+fn asset_file(project_path: &Path, dir: &str, name: &str) -> PathBuf {
+    project_path
+        .join("sources")
+        .join(dir)
+        .join(format!("{}.json", name))
+}
+
+fn read_asset(project_path: &Path, dir: &str, name: &str) -> Value {
+    let content = fs::read_to_string(asset_file(project_path, dir, name)).unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+/// Set `field` of an asset file to `value`.
+fn set_field(project_path: &Path, dir: &str, name: &str, field: &str, value: Value) {
+    let mut asset = read_asset(project_path, dir, name);
+    asset[field] = value;
+    fs::write(
+        asset_file(project_path, dir, name),
+        serde_json::to_string_pretty(&asset).unwrap(),
+    )
+    .unwrap();
+}
+
+fn touch(asset_type: &str, name: &str) -> AssetCommands {
+    AssetCommands::Touch {
+        asset_type: Some(asset_type.to_string()),
+        name: Some(name.to_string()),
+        bump_version: true,
+        create: false,
+        all_referencing: None,
+    }
+}
+
+fn touch_all_referencing(asset: &str) -> AssetCommands {
+    AssetCommands::Touch {
+        asset_type: None,
+        name: None,
+        bump_version: true,
+        create: false,
+        all_referencing: Some(asset.to_string()),
+    }
+}
+
+// =============================================================================
+// Touch Command Parsing Tests
+// =============================================================================
+
+#[test]
+fn test_p0_touch_command_parses() {
+    let app = App::try_parse_from([
+        "am",
+        "asset",
+        "touch",
+        "--bump-version",
+        "--create",
+        "sound",
+        "footstep",
+    ])
+    .expect("Should parse");
+
+    match app.command {
+        Commands::Asset {
+            command:
+                AssetCommands::Touch {
+                    asset_type,
+                    name,
+                    bump_version,
+                    create,
+                    all_referencing,
+                },
+        } => {
+            assert_eq!(asset_type.as_deref(), Some("sound"));
+            assert_eq!(name.as_deref(), Some("footstep"));
+            assert!(bump_version);
+            assert!(create);
+            assert!(all_referencing.is_none());
+        }
+        _ => panic!("Expected Asset Touch command"),
+    }
+}
+
+#[test]
+fn test_p1_touch_requires_bump_version() {
+    let result = App::try_parse_from(["am", "asset", "touch", "sound", "footstep"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_p1_touch_all_referencing_replaces_the_asset_arguments() {
+    let app = App::try_parse_from([
+        "am",
+        "asset",
+        "touch",
+        "--bump-version",
+        "--all-referencing",
+        "sound/footstep",
+    ])
+    .expect("Should parse");
+    assert!(matches!(
+        app.command,
+        Commands::Asset {
+            command: AssetCommands::Touch {
+                all_referencing: Some(_),
+                ..
+            }
+        }
+    ));
+
+    let result = App::try_parse_from([
+        "am",
+        "asset",
+        "touch",
+        "--bump-version",
+        "--all-referencing",
+        "sound/footstep",
+        "event",
+        "play_footstep",
+    ]);
+    assert!(result.is_err());
+}
+
+// =============================================================================
+// Touch Command Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_touch_increments_version() {
+    let (env, project_path) = setup_project().await;
+    set_field(&project_path, "sounds", "sound_1", "version", json!(3));
+
+    let run = env
+        .run_in(
+            &project_path,
+            &touch("sound", "sound_1"),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Touch should succeed: {:?}", run.result);
+    let data = run.last_success().expect("Should have success output");
+    assert_eq!(
+        data["assets"],
+        json!([{
+            "type": "sound",
+            "name": "sound_1",
+            "path": "sounds/sound_1.json",
+            "field": "version",
+            "from": 3,
+            "to": 4,
+        }])
+    );
+    let asset = read_asset(&project_path, "sounds", "sound_1");
+    assert_eq!(asset["version"], 4);
+    assert_eq!(asset["name"], "sound_1");
+}
+
+#[tokio::test]
+async fn test_p1_touch_increments_revision() {
+    let (env, project_path) = setup_project().await;
+    set_field(&project_path, "effects", "effect_1", "revision", json!(0));
+
+    let run = env
+        .run_in(
+            &project_path,
+            &touch("effect", "effect_1"),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Touch should succeed: {:?}", run.result);
+    let data = run.last_success().unwrap();
+    assert_eq!(data["assets"][0]["field"], "revision");
+    assert_eq!(
+        read_asset(&project_path, "effects", "effect_1")["revision"],
+        1
+    );
+}
+
+#[tokio::test]
+async fn test_p0_touch_refuses_asset_without_version_field() {
+    let (env, project_path) = setup_project().await;
+    let before = fs::read_to_string(asset_file(&project_path, "sounds", "sound_1")).unwrap();
+
+    let run = env
+        .run_in(
+            &project_path,
+            &touch("sound", "sound_1"),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_VALIDATION_FIELD));
+    let after = fs::read_to_string(asset_file(&project_path, "sounds", "sound_1")).unwrap();
+    assert_eq!(before, after);
+}
+
+#[tokio::test]
+async fn test_p1_touch_rejects_non_integer_version() {
+    let (env, project_path) = setup_project().await;
+    set_field(&project_path, "sounds", "sound_1", "version", json!("1.2"));
+
+    let run = env
+        .run_in(
+            &project_path,
+            &touch("sound", "sound_1"),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_VALIDATION_FIELD));
+}
+
+#[tokio::test]
+async fn test_p1_touch_unknown_asset_is_not_found() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &touch("sound", "missing"),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_ASSET_NOT_FOUND));
+}
+
+#[tokio::test]
+async fn test_p0_touch_all_referencing_bumps_dependents() {
+    let (env, project_path) = setup_project().await;
+    set_field(&project_path, "sounds", "sound_1", "version", json!(7));
+    set_field(&project_path, "events", "event_1", "version", json!(1));
+    set_field(
+        &project_path,
+        "soundbanks",
+        "soundbank_1",
+        "version",
+        json!(2),
+    );
+
+    let run = env
+        .run_in(
+            &project_path,
+            &touch_all_referencing("sound/sound_1"),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Touch should succeed: {:?}", run.result);
+    let data = run.last_success().unwrap();
+    let bumped: Vec<&str> = data["assets"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|a| a["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(bumped.len(), 2);
+    assert!(bumped.contains(&"event_1"));
+    assert!(bumped.contains(&"soundbank_1"));
+
+    assert_eq!(read_asset(&project_path, "events", "event_1")["version"], 2);
+    assert_eq!(
+        read_asset(&project_path, "soundbanks", "soundbank_1")["version"],
+        3
+    );
+    // The referenced asset itself is left alone.
+    assert_eq!(read_asset(&project_path, "sounds", "sound_1")["version"], 7);
+}
+
+#[tokio::test]
+async fn test_p1_touch_all_referencing_writes_nothing_when_one_asset_fails() {
+    let (env, project_path) = setup_project().await;
+    set_field(&project_path, "events", "event_1", "version", json!(1));
+
+    let run = env
+        .run_in(
+            &project_path,
+            &touch_all_referencing("sound/sound_1"),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_VALIDATION_FIELD));
+    assert_eq!(read_asset(&project_path, "events", "event_1")["version"], 1);
+}
+
+#[tokio::test]
+async fn test_p1_touch_all_referencing_rejects_malformed_asset() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &touch_all_referencing("sound_1"),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_VALIDATION_FIELD));
+}
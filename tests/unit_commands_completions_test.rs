@@ -0,0 +1,218 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for `am completions --install`: shell detection, the target of each shell, and
+//! installing into a temporary home.
+
+use am::commands::completions::{completion_target, detect_shell, install_completions};
+use am::common::errors::{CliError, codes};
+use am::common::paths::HomeEnv;
+use am::presentation::OutputMode;
+use am::testing::{MockOutput, OutputCall};
+use clap_complete::Shell;
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::tempdir;
+
+fn home_env(home: &Path) -> HomeEnv {
+    HomeEnv {
+        home: Some(home.to_path_buf()),
+        ..Default::default()
+    }
+}
+
+fn last_success(output: &MockOutput) -> Value {
+    output
+        .calls()
+        .into_iter()
+        .rev()
+        .find_map(|call| match call {
+            OutputCall::Success(data) => Some(data),
+            _ => None,
+        })
+        .expect("Should report success")
+}
+
+// =============================================================================
+// Shell Detection Tests
+// =============================================================================
+
+#[test]
+fn test_p0_detects_shell_from_shell_variable() {
+    assert_eq!(detect_shell(Some("/usr/bin/zsh"), None), Some(Shell::Zsh));
+    assert_eq!(
+        detect_shell(Some("/bin/bash"), Some("fish")),
+        Some(Shell::Bash)
+    );
+}
+
+#[test]
+fn test_p1_falls_back_to_parent_process() {
+    assert_eq!(
+        detect_shell(None, Some("pwsh.exe")),
+        Some(Shell::PowerShell)
+    );
+    assert_eq!(
+        detect_shell(Some("/bin/tcsh"), Some("-fish")),
+        Some(Shell::Fish)
+    );
+    assert_eq!(detect_shell(None, Some("cargo")), None);
+    assert_eq!(detect_shell(None, None), None);
+}
+
+// =============================================================================
+// Target Tests
+// =============================================================================
+
+#[test]
+fn test_p0_each_shell_has_its_conventional_location() {
+    let home = PathBuf::from("/home/dev");
+    let env = home_env(&home);
+
+    let target = |shell| completion_target(shell, &env).unwrap();
+
+    assert_eq!(
+        target(Shell::Bash).path,
+        home.join(".local/share/bash-completion/completions/am")
+    );
+    assert_eq!(target(Shell::Zsh).path, home.join(".zfunc/_am"));
+    assert!(target(Shell::Zsh).manual_step.unwrap().contains("fpath"));
+    assert_eq!(
+        target(Shell::Fish).path,
+        home.join(".config/fish/completions/am.fish")
+    );
+    assert!(target(Shell::Fish).manual_step.is_none());
+    assert!(
+        target(Shell::PowerShell)
+            .manual_step
+            .unwrap()
+            .contains("$PROFILE")
+    );
+    assert!(completion_target(Shell::Elvish, &env).is_none());
+}
+
+#[test]
+fn test_p1_bash_target_follows_xdg_data_home() {
+    let env = HomeEnv {
+        xdg_data_home: Some(PathBuf::from("/data")),
+        ..home_env(Path::new("/home/dev"))
+    };
+
+    assert_eq!(
+        completion_target(Shell::Bash, &env).unwrap().path,
+        PathBuf::from("/data/bash-completion/completions/am")
+    );
+}
+
+// =============================================================================
+// Install Tests
+// =============================================================================
+
+#[test]
+fn test_p0_install_writes_script_for_each_shell() {
+    for shell in [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell] {
+        let home = tempdir().unwrap();
+        let env = home_env(home.path());
+        let output = MockOutput::new(OutputMode::Json);
+
+        install_completions(shell, &env, false, &output).unwrap();
+
+        let path = completion_target(shell, &env).unwrap().path;
+        let script = fs::read_to_string(&path).unwrap();
+        assert!(script.contains("am"), "{} script should be written", shell);
+
+        let data = last_success(&output);
+        assert_eq!(data["shell"], shell.to_string());
+        assert_eq!(data["path"], path.to_str().unwrap());
+        assert_eq!(data["backup"], Value::Null);
+        assert_eq!(data["dry_run"], false);
+    }
+}
+
+#[test]
+fn test_p0_install_backs_up_existing_file() {
+    let home = tempdir().unwrap();
+    let env = home_env(home.path());
+    let path = completion_target(Shell::Zsh, &env).unwrap().path;
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, "# custom completions").unwrap();
+    let output = MockOutput::new(OutputMode::Json);
+
+    install_completions(Shell::Zsh, &env, false, &output).unwrap();
+
+    let backup = home.path().join(".zfunc").join("_am.bak");
+    assert_eq!(fs::read_to_string(&backup).unwrap(), "# custom completions");
+    assert_ne!(fs::read_to_string(&path).unwrap(), "# custom completions");
+    let data = last_success(&output);
+    assert_eq!(data["backup"], backup.to_str().unwrap());
+    assert!(data["manual_step"].as_str().unwrap().contains("fpath"));
+}
+
+#[test]
+fn test_p0_dry_run_writes_nothing() {
+    let home = tempdir().unwrap();
+    let env = home_env(home.path());
+    let output = MockOutput::new(OutputMode::Json);
+
+    install_completions(Shell::Bash, &env, true, &output).unwrap();
+
+    let path = completion_target(Shell::Bash, &env).unwrap().path;
+    assert!(!path.exists());
+    assert!(fs::read_dir(home.path()).unwrap().next().is_none());
+    let data = last_success(&output);
+    assert_eq!(data["dry_run"], true);
+    assert_eq!(data["path"], path.to_str().unwrap());
+}
+
+#[test]
+fn test_p1_unsupported_shell_fails() {
+    let home = tempdir().unwrap();
+    let output = MockOutput::new(OutputMode::Json);
+
+    let err =
+        install_completions(Shell::Elvish, &home_env(home.path()), false, &output).unwrap_err();
+
+    assert_eq!(
+        err.downcast_ref::<CliError>().map(|e| e.code),
+        Some(codes::ERR_VALIDATION_FIELD)
+    );
+}
+
+#[test]
+fn test_p1_install_without_home_fails() {
+    let output = MockOutput::new(OutputMode::Json);
+
+    let err = install_completions(Shell::Bash, &HomeEnv::default(), false, &output).unwrap_err();
+
+    assert_eq!(
+        err.downcast_ref::<CliError>().map(|e| e.code),
+        Some(codes::ERR_HOME_NOT_FOUND)
+    );
+}
+
+#[test]
+fn test_p1_install_is_optional_for_the_shell_argument() {
+    use am::app::App;
+    use clap::Parser;
+
+    let parse =
+        |args: &[&str]| App::try_parse_from(["am", "completions"].iter().chain(args.iter()));
+
+    assert!(parse(&["bash"]).is_ok());
+    assert!(parse(&["--install"]).is_ok());
+    assert!(parse(&["zsh", "--install", "--dry-run"]).is_ok());
+    assert!(parse(&[]).is_err());
+    assert!(parse(&["bash", "--dry-run"]).is_err());
+}
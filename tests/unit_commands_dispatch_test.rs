@@ -0,0 +1,134 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the dispatch of parsed commands to their handlers.
+
+use am::app::{App, Commands};
+use am::commands::dispatch::method_name;
+use am::commands::introspect::{IntrospectCommands, describe_cli};
+use am::testing::{ScriptedInput, TestEnv};
+use clap::{Command, CommandFactory};
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// Collect the path of every visible command under `command`.
+fn command_paths(command: &Command, parent_path: &str, paths: &mut Vec<String>) {
+    for subcommand in command.get_subcommands().filter(|c| !c.is_hide_set()) {
+        let path = format!("{} {}", parent_path, subcommand.get_name());
+        paths.push(path.clone());
+        command_paths(subcommand, &path, paths);
+    }
+}
+
+/// Collect the `method` of every command of an introspection description.
+fn described_methods(commands: &Value, methods: &mut Vec<String>) {
+    for command in commands.as_array().unwrap() {
+        methods.push(command["method"].as_str().unwrap().to_string());
+        described_methods(&command["subcommands"], methods);
+    }
+}
+
+// =============================================================================
+// Method Name Tests
+// =============================================================================
+
+#[test]
+fn test_p0_method_name_joins_the_command_path() {
+    assert_eq!(method_name("am project init"), "project.init");
+    assert_eq!(
+        method_name("am sudo database migrate"),
+        "sudo.database.migrate"
+    );
+    assert_eq!(method_name("am explain"), "explain");
+}
+
+#[test]
+fn test_p0_every_command_has_a_unique_method() {
+    let mut paths = Vec::new();
+    command_paths(&App::command(), "am", &mut paths);
+
+    let mut methods = HashSet::new();
+    for path in &paths {
+        let method = method_name(path);
+        assert!(!method.is_empty(), "No method for {}", path);
+        assert!(
+            methods.insert(method.clone()),
+            "Duplicate method {}",
+            method
+        );
+    }
+}
+
+#[test]
+fn test_p0_introspection_reports_the_dispatch_method() {
+    let mut paths = Vec::new();
+    command_paths(&App::command(), "am", &mut paths);
+
+    let mut methods = Vec::new();
+    described_methods(&describe_cli(&App::command())["commands"], &mut methods);
+
+    let expected: Vec<String> = paths.iter().map(|path| method_name(path)).collect();
+    assert_eq!(methods, expected);
+}
+
+// =============================================================================
+// Dispatch Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_top_level_command_dispatches_to_its_group_handler() {
+    let env = TestEnv::new().await.unwrap();
+
+    let direct = env
+        .run(&IntrospectCommands::ErrorCodes, ScriptedInput::new())
+        .await;
+    let routed = env
+        .run(
+            &Commands::Introspect {
+                command: IntrospectCommands::ErrorCodes,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(routed.result.is_ok());
+    assert_eq!(routed.calls, direct.calls);
+}
+
+#[tokio::test]
+async fn test_p1_commands_without_a_group_are_dispatched() {
+    let env = TestEnv::new().await.unwrap();
+
+    let run = env
+        .run(
+            &Commands::Explain {
+                code: "-29001".to_string(),
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok());
+    assert!(run.last_success().is_some());
+
+    let run = env
+        .run(
+            &Commands::Examples {
+                command: vec!["project".to_string(), "init".to_string()],
+                copy: None,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok());
+}
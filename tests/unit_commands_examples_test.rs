@@ -0,0 +1,269 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the example registry and the `am examples` command.
+
+use am::app::{App, Commands};
+use am::commands::examples::{EXAMPLES, HELP_EXAMPLES, examples_for, handler, with_examples};
+use am::common::errors::{CliError, codes};
+use am::presentation::OutputMode;
+use am::testing::{MockOutput, OutputCall};
+use clap::{Command, CommandFactory, Parser};
+
+/// Split the `am` invocation of an example line into arguments.
+///
+/// Shell pipes and redirections around the invocation are dropped, and single quotes
+/// are removed like a shell would.
+fn am_arguments(line: &str) -> Vec<String> {
+    let start = line.find("am ").expect("An example must run am");
+    let invocation = line[start..]
+        .split(" | ")
+        .next()
+        .unwrap()
+        .split(" > ")
+        .next()
+        .unwrap();
+
+    let mut arguments = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    for c in invocation.chars() {
+        match c {
+            '\'' => quoted = !quoted,
+            ' ' if !quoted => {
+                if !current.is_empty() {
+                    arguments.push(std::mem::take(&mut current));
+                }
+            }
+            _ => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        arguments.push(current);
+    }
+    arguments
+}
+
+/// Paths of the commands without subcommands, e.g. `project init`.
+fn leaf_commands(command: &Command, path: &str, leaves: &mut Vec<String>) {
+    for subcommand in command.get_subcommands() {
+        let subcommand_path = format!("{} {}", path, subcommand.get_name())
+            .trim()
+            .to_string();
+        if subcommand.has_subcommands() {
+            leaf_commands(subcommand, &subcommand_path, leaves);
+        } else {
+            leaves.push(subcommand_path);
+        }
+    }
+}
+
+fn find_subcommand<'a>(command: &'a Command, path: &str) -> &'a Command {
+    path.split(' ').fold(command, |command, name| {
+        command
+            .find_subcommand(name)
+            .unwrap_or_else(|| panic!("No command '{}'", path))
+    })
+}
+
+fn examples(
+    command: &[&str],
+    copy: Option<u32>,
+    mode: OutputMode,
+) -> (anyhow::Result<()>, MockOutput) {
+    let output = MockOutput::new(mode);
+    let command: Vec<String> = command.iter().map(|word| word.to_string()).collect();
+    let result = handler(&command, copy, &output);
+    (result, output)
+}
+
+fn error_code(result: &anyhow::Result<()>) -> Option<i32> {
+    result
+        .as_ref()
+        .err()
+        .and_then(|e| e.downcast_ref::<CliError>())
+        .map(|e| e.code)
+}
+
+// =============================================================================
+// Registry Tests
+// =============================================================================
+
+#[test]
+fn test_p0_every_example_is_a_valid_command_line() {
+    for example in EXAMPLES {
+        let arguments = am_arguments(example.line);
+        assert!(
+            App::try_parse_from(&arguments).is_ok(),
+            "Example '{}' doesn't parse",
+            example.line
+        );
+
+        let path = arguments[1..]
+            .iter()
+            .filter(|argument| !argument.starts_with('-'))
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(" ");
+        assert!(
+            format!("{} ", path).starts_with(&format!("{} ", example.command)),
+            "Example '{}' doesn't run '{}'",
+            example.line,
+            example.command
+        );
+    }
+}
+
+#[test]
+fn test_p0_every_command_has_examples() {
+    let mut leaves = Vec::new();
+    leaf_commands(&App::command(), "", &mut leaves);
+
+    for leaf in leaves {
+        assert!(
+            !examples_for(&leaf).is_empty(),
+            "'{}' has no examples",
+            leaf
+        );
+    }
+}
+
+#[test]
+fn test_p1_examples_for_includes_subcommands() {
+    let database = examples_for("sudo database");
+    assert!(database.iter().any(|e| e.command == "sudo database reset"));
+    assert!(
+        database
+            .iter()
+            .any(|e| e.command == "sudo database migrate")
+    );
+
+    assert_eq!(
+        examples_for("am project init"),
+        examples_for("project init")
+    );
+    assert!(examples_for("project in").is_empty());
+    assert_eq!(examples_for("").len(), EXAMPLES.len());
+}
+
+// =============================================================================
+// Help Tests
+// =============================================================================
+
+#[test]
+fn test_p0_help_shows_the_first_examples() {
+    let cli = with_examples(App::command());
+    let after_help = find_subcommand(&cli, "project init")
+        .get_after_help()
+        .unwrap()
+        .to_string();
+
+    let init = examples_for("project init");
+    for example in &init[..HELP_EXAMPLES] {
+        assert!(after_help.contains(example.line));
+        assert!(after_help.contains(example.description));
+    }
+    assert!(!after_help.contains(init[HELP_EXAMPLES].line));
+    assert!(after_help.contains("am examples project init"));
+}
+
+#[test]
+fn test_p1_help_keeps_existing_after_help() {
+    let cli = with_examples(App::command());
+    let after_help = find_subcommand(&cli, "project run-hook")
+        .get_after_help()
+        .unwrap()
+        .to_string();
+
+    assert!(after_help.starts_with("Examples:"));
+    assert!(after_help.contains("am project run-hook post-validate"));
+    assert!(after_help.contains("Hooks are declared in .amproject"));
+}
+
+// =============================================================================
+// Command Tests
+// =============================================================================
+
+#[test]
+fn test_p0_examples_command_parses() {
+    let app = App::try_parse_from(["am", "examples", "project", "init", "--copy", "2"])
+        .expect("Should parse");
+    match app.command {
+        Commands::Examples { command, copy } => {
+            assert_eq!(command, vec!["project", "init"]);
+            assert_eq!(copy, Some(2));
+        }
+        _ => panic!("Expected Examples command"),
+    }
+
+    assert!(App::try_parse_from(["am", "examples", "--copy", "0"]).is_err());
+}
+
+#[test]
+fn test_p0_json_lists_structured_examples() {
+    let (result, output) = examples(&["project", "init"], None, OutputMode::Json);
+
+    assert!(result.is_ok());
+    let calls = output.calls();
+    let Some(OutputCall::Success(data)) = calls.last() else {
+        panic!("Expected a success, got {:?}", calls);
+    };
+    let listed = data.as_array().unwrap();
+    assert_eq!(listed.len(), examples_for("project init").len());
+    assert_eq!(listed[0]["command"], "project init");
+    assert_eq!(listed[0]["line"], "am project init my_game");
+    assert!(listed[0]["description"].is_string());
+}
+
+#[test]
+fn test_p1_interactive_lists_examples_as_table() {
+    let (result, output) = examples(&["project", "validate"], None, OutputMode::Interactive);
+
+    assert!(result.is_ok());
+    let calls = output.calls();
+    let Some(OutputCall::Table { data, .. }) = calls.last() else {
+        panic!("Expected a table, got {:?}", calls);
+    };
+    assert_eq!(data[1]["#"], 2);
+    assert_eq!(data[1]["command"], examples_for("project validate")[1].line);
+}
+
+#[test]
+fn test_p0_copy_prints_only_the_command_line() {
+    let (result, output) = examples(&["project", "init"], Some(2), OutputMode::Interactive);
+
+    assert!(result.is_ok());
+    assert_eq!(
+        output.calls(),
+        vec![OutputCall::Print(
+            "am project init my_game --template o3de".to_string()
+        )]
+    );
+}
+
+#[test]
+fn test_p1_copy_out_of_range_fails() {
+    let count = examples_for("project init").len() as u32;
+
+    let (result, _output) = examples(&["project", "init"], Some(count + 1), OutputMode::Json);
+
+    assert_eq!(error_code(&result), Some(codes::ERR_VALIDATION_FIELD));
+}
+
+#[test]
+fn test_p1_unknown_command_fails() {
+    let (result, _output) = examples(&["project", "explode"], None, OutputMode::Json);
+
+    assert_eq!(error_code(&result), Some(codes::ERR_VALIDATION_FIELD));
+}
@@ -0,0 +1,173 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the `am explain` command.
+
+use am::app::{App, Commands};
+use am::commands::explain::handler;
+use am::common::errors::{ERROR_CODE_RANGES, codes, exit_codes};
+use am::presentation::OutputMode;
+use am::testing::{MockOutput, OutputCall};
+use clap::Parser;
+
+fn explain(query: &str, mode: OutputMode) -> MockOutput {
+    let output = MockOutput::new(mode);
+    handler(query, &output).expect("Explaining never fails");
+    output
+}
+
+fn success(output: &MockOutput) -> serde_json::Value {
+    output
+        .calls()
+        .into_iter()
+        .find_map(|call| match call {
+            OutputCall::Success(value) => Some(value),
+            _ => None,
+        })
+        .expect("A success should be reported")
+}
+
+fn printed(output: &MockOutput) -> String {
+    output
+        .calls()
+        .into_iter()
+        .filter_map(|call| match call {
+            OutputCall::Print(line) => Some(line),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// =============================================================================
+// Explain Command Parsing Tests
+// =============================================================================
+
+#[test]
+fn test_p0_explain_parses_a_negative_code() {
+    let app = App::try_parse_from(["am", "explain", "-29002", "--json"]).expect("Should parse");
+    assert!(app.json);
+    match app.command {
+        Commands::Explain { code } => assert_eq!(code, "-29002"),
+        _ => panic!("Expected explain command"),
+    }
+}
+
+// =============================================================================
+// Known Code Tests
+// =============================================================================
+
+#[test]
+fn test_p0_explain_accepts_the_code_with_or_without_sign_or_its_type() {
+    for query in [
+        "-29002",
+        "29002",
+        "project_not_registered",
+        " ERR_PROJECT_NOT_REGISTERED ",
+    ] {
+        let record = success(&explain(query, OutputMode::Json));
+        assert_eq!(record["known"], true, "{}", query);
+        assert_eq!(
+            record["code"],
+            codes::ERR_PROJECT_NOT_REGISTERED,
+            "{}",
+            query
+        );
+    }
+}
+
+#[test]
+fn test_p0_explain_emits_the_structured_record() {
+    let record = success(&explain("-29002", OutputMode::Json));
+
+    assert_eq!(record["name"], "ERR_PROJECT_NOT_REGISTERED");
+    assert_eq!(record["type"], "project_not_registered");
+    assert_eq!(record["exit_code"], exit_codes::USER_ERROR);
+    assert_eq!(
+        record["suggestion"],
+        "Register the project with 'am project register <path>'"
+    );
+    assert!(
+        record["causes"]
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!(
+                "The project directory exists but is not tracked in the database"
+            ))
+    );
+    assert!(
+        record["related_commands"]
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!("am project register"))
+    );
+    assert!(
+        record["category"]
+            .as_str()
+            .unwrap()
+            .starts_with("Project errors")
+    );
+}
+
+#[test]
+fn test_p1_explain_prints_causes_and_remediation() {
+    colored::control::set_override(false);
+    let text = printed(&explain("-28001", OutputMode::Interactive));
+
+    assert!(text.contains("Error -28001 (sdk_not_found)"), "{}", text);
+    assert!(text.contains("Exit code:  2 (system error)"), "{}", text);
+    assert!(text.contains("Typical causes:"), "{}", text);
+    assert!(text.contains("Suggestion:"), "{}", text);
+    assert!(text.contains("am sdk check"), "{}", text);
+}
+
+// =============================================================================
+// Unknown Code Tests
+// =============================================================================
+
+#[test]
+fn test_p1_explain_unknown_code_describes_its_range() {
+    let record = success(&explain("29999", OutputMode::Json));
+
+    assert_eq!(record["known"], false);
+    assert_eq!(record["code"], -29999);
+    assert_eq!(record["range"]["start"], -29999);
+    assert_eq!(record["range"]["end"], -29000);
+    assert_eq!(
+        record["ranges"].as_array().unwrap().len(),
+        ERROR_CODE_RANGES.len()
+    );
+}
+
+#[test]
+fn test_p2_explain_unknown_type_lists_the_ranges() {
+    let record = success(&explain("not_an_error", OutputMode::Json));
+    assert_eq!(record["known"], false);
+    assert!(record["code"].is_null());
+    assert!(record["range"].is_null());
+
+    colored::control::set_override(false);
+    let text = printed(&explain("not_an_error", OutputMode::Interactive));
+    assert!(
+        text.contains("'not_an_error' is not a known error code"),
+        "{}",
+        text
+    );
+    assert!(
+        text.contains("Error codes are negative and grouped by range"),
+        "{}",
+        text
+    );
+    assert!(text.contains("am introspect error-codes"), "{}", text);
+}
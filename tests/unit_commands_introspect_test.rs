@@ -0,0 +1,229 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the `am introspect` command.
+//!
+//! The snapshot test compares the description of the whole CLI with
+//! `tests/snapshots/introspect_commands.json`, so every change to the CLI surface shows up
+//! in review. After an intended change, refresh the snapshot with:
+//!
+//! ```bash
+//! UPDATE_SNAPSHOTS=1 cargo test --test unit_commands_introspect_test
+//! ```
+
+use am::app::App;
+use am::commands::introspect::{INTROSPECTION_SCHEMA_VERSION, describe_cli, describe_error_codes};
+use am::common::errors::{ERROR_CODES, codes, exit_codes};
+use clap::CommandFactory;
+use serde_json::Value;
+use std::path::Path;
+
+const SNAPSHOT: &str = "tests/snapshots/introspect_commands.json";
+
+fn describe() -> Value {
+    describe_cli(&App::command())
+}
+
+/// Find the description of a command by its path (e.g. `am asset sound create`).
+fn find_command<'a>(commands: &'a Value, path: &str) -> Option<&'a Value> {
+    commands.as_array()?.iter().find_map(|command| {
+        if command["path"] == path {
+            Some(command)
+        } else {
+            find_command(&command["subcommands"], path)
+        }
+    })
+}
+
+fn find_argument<'a>(command: &'a Value, name: &str) -> &'a Value {
+    command["arguments"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|arg| arg["name"] == name)
+        .unwrap_or_else(|| panic!("No argument '{}' in {}", name, command["path"]))
+}
+
+// =============================================================================
+// Snapshot Tests
+// =============================================================================
+
+#[test]
+fn test_p0_cli_surface_matches_snapshot() {
+    let mut description = describe();
+    // The version changes on every release without changing the surface.
+    description.as_object_mut().unwrap().remove("version");
+    let actual = serde_json::to_string_pretty(&description).unwrap() + "\n";
+
+    let snapshot_path = Path::new(env!("CARGO_MANIFEST_DIR")).join(SNAPSHOT);
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::write(&snapshot_path, &actual).unwrap();
+        return;
+    }
+
+    let snapshot: Value = serde_json::from_str(
+        &std::fs::read_to_string(&snapshot_path).expect("Snapshot file should exist"),
+    )
+    .expect("Snapshot should be valid JSON");
+    let expected = serde_json::to_string_pretty(&snapshot).unwrap() + "\n";
+
+    assert_eq!(
+        actual, expected,
+        "The CLI surface changed. If this is intended, run the test with UPDATE_SNAPSHOTS=1 \
+         and commit {}",
+        SNAPSHOT
+    );
+}
+
+// =============================================================================
+// Description Tests
+// =============================================================================
+
+#[test]
+fn test_p0_description_is_versioned() {
+    let description = describe();
+
+    assert_eq!(description["schema"], INTROSPECTION_SCHEMA_VERSION);
+    assert_eq!(description["name"], "am");
+    assert_eq!(description["version"], env!("CARGO_PKG_VERSION"));
+}
+
+#[test]
+fn test_p0_global_flags_are_reported_once() {
+    let description = describe();
+
+    let globals: Vec<&str> = description["global_flags"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|arg| arg["long"].as_str().unwrap())
+        .collect();
+    assert!(globals.contains(&"json"));
+    assert!(globals.contains(&"non-interactive"));
+
+    let create = find_command(&description["commands"], "am asset sound create").unwrap();
+    assert!(
+        create["arguments"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|arg| arg["name"] != "json")
+    );
+}
+
+#[test]
+fn test_p0_arguments_describe_type_requirement_and_default() {
+    let description = describe();
+    let create = find_command(&description["commands"], "am asset sound create")
+        .expect("Sound create should be described");
+
+    let name = find_argument(create, "name");
+    assert_eq!(name["kind"], "positional");
+    assert_eq!(name["type"], "string");
+    assert_eq!(name["required"], true);
+    assert_eq!(name["help"], "Name of the sound asset");
+
+    let gain = find_argument(create, "gain");
+    assert_eq!(gain["kind"], "option");
+    assert_eq!(gain["type"], "number");
+    assert_eq!(gain["long"], "gain");
+    assert_eq!(gain["short"], "g");
+    assert_eq!(gain["required"], false);
+
+    let looping = find_argument(create, "loop_enabled");
+    assert_eq!(looping["kind"], "flag");
+    assert_eq!(looping["type"], "boolean");
+    assert_eq!(looping["long"], "loop");
+    assert_eq!(looping["default"], "false");
+}
+
+#[test]
+fn test_p1_repeatable_and_enum_arguments() {
+    let description = describe();
+
+    let event_create = find_command(&description["commands"], "am asset event create").unwrap();
+    assert_eq!(find_argument(event_create, "action")["multiple"], true);
+
+    let completions = find_command(&description["commands"], "am completions").unwrap();
+    let shell = find_argument(completions, "shell");
+    assert_eq!(shell["type"], "enum");
+    let values = shell["possible_values"].as_array().unwrap();
+    assert!(values.contains(&Value::from("bash")));
+    assert!(values.contains(&Value::from("zsh")));
+}
+
+#[test]
+fn test_p1_every_subcommand_is_described() {
+    let description = describe();
+
+    for path in [
+        "am project run-hook",
+        "am sudo database migrate",
+        "am template update",
+        "am introspect commands",
+        "am introspect error-codes",
+    ] {
+        assert!(
+            find_command(&description["commands"], path).is_some(),
+            "Missing {}",
+            path
+        );
+    }
+}
+
+// =============================================================================
+// Error Codes Tests
+// =============================================================================
+
+#[test]
+fn test_p0_error_codes_lists_every_registered_code() {
+    let description = describe_error_codes();
+
+    assert_eq!(description["schema"], INTROSPECTION_SCHEMA_VERSION);
+    assert_eq!(
+        description["exit_codes"]["system_error"],
+        exit_codes::SYSTEM_ERROR
+    );
+    let listed = description["error_codes"].as_array().unwrap();
+    assert_eq!(listed.len(), ERROR_CODES.len());
+
+    let not_registered = listed
+        .iter()
+        .find(|entry| entry["code"] == codes::ERR_PROJECT_NOT_REGISTERED)
+        .expect("-29002 should be listed");
+    assert_eq!(not_registered["name"], "ERR_PROJECT_NOT_REGISTERED");
+    assert_eq!(not_registered["type"], "project_not_registered");
+    assert_eq!(not_registered["exit_code"], exit_codes::USER_ERROR);
+    assert!(
+        not_registered["suggestion"]
+            .as_str()
+            .unwrap()
+            .contains("am project register")
+    );
+}
+
+#[test]
+fn test_p1_exit_codes_alias_parses() {
+    use am::app::{App, Commands};
+    use am::commands::introspect::IntrospectCommands;
+    use clap::Parser;
+
+    let app = App::try_parse_from(["am", "introspect", "exit-codes"]).expect("Should parse");
+    assert!(matches!(
+        app.command,
+        Commands::Introspect {
+            command: IntrospectCommands::ErrorCodes
+        }
+    ));
+}
@@ -0,0 +1,341 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for `am project copy`.
+
+use am::app::{App, Commands};
+use am::commands::project::ProjectCommands;
+use am::common::errors::codes;
+use am::common::utils::read_amproject_file;
+use am::database::db_get_project_by_name;
+use am::presentation::OutputMode;
+use am::testing::{OutputCall, ScriptedInput, TestEnv};
+use clap::Parser;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Scaffold and register `game`, with build output and a snapshot.
+async fn setup_project() -> (TestEnv, PathBuf) {
+    let env = TestEnv::new()
+        .await
+        .expect("Failed to create test environment");
+    let project_path = env
+        .scaffold_project("game", 1)
+        .expect("Failed to scaffold project");
+    fs::write(project_path.join("build").join("game.ambank"), b"built").unwrap();
+    fs::create_dir_all(project_path.join("snapshots")).unwrap();
+    fs::write(project_path.join("snapshots").join("first.zip"), b"zip").unwrap();
+
+    let run = env
+        .run(
+            &ProjectCommands::Register {
+                path: Some(project_path.clone()),
+                recursive: false,
+                rename_on_conflict: false,
+                force: false,
+                name: None,
+                stdin: false,
+                strict: false,
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+    assert!(run.result.is_ok(), "Register failed: {:?}", run.result);
+
+    (env, project_path)
+}
+
+fn copy(source: &str, new_name: &str, path: Option<&Path>, no_register: bool) -> ProjectCommands {
+    ProjectCommands::Copy {
+        source: source.to_string(),
+        new_name: new_name.to_string(),
+        path: path.map(Path::to_path_buf),
+        register: false,
+        no_register,
+        overwrite_dir: false,
+        replace_registration: false,
+        verify: false,
+    }
+}
+
+// =============================================================================
+// Copy Command Parsing Tests
+// =============================================================================
+
+#[test]
+fn test_p0_copy_command_parses() {
+    let app = App::try_parse_from(["am", "project", "copy", "my_game", "my_prototype"])
+        .expect("Should parse");
+
+    match app.command {
+        Commands::Project {
+            command:
+                ProjectCommands::Copy {
+                    source,
+                    new_name,
+                    path,
+                    no_register,
+                    ..
+                },
+        } => {
+            assert_eq!(source, "my_game");
+            assert_eq!(new_name, "my_prototype");
+            assert!(path.is_none());
+            assert!(!no_register);
+        }
+        _ => panic!("Expected Project Copy command"),
+    }
+}
+
+#[test]
+fn test_p1_copy_command_last_register_flag_wins() {
+    let app = App::try_parse_from([
+        "am",
+        "project",
+        "copy",
+        "my_game",
+        "my_prototype",
+        "--no-register",
+        "--register",
+    ])
+    .expect("Should parse");
+
+    match app.command {
+        Commands::Project {
+            command: ProjectCommands::Copy { no_register, .. },
+        } => assert!(!no_register),
+        _ => panic!("Expected Project Copy command"),
+    }
+}
+
+// =============================================================================
+// Copy Command Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_copy_duplicates_project_next_to_source() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run(
+            &copy("game", "prototype", None, false),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Copy should succeed: {:?}", run.result);
+    let destination = env.home().join("prototype");
+    let data = run.last_success().unwrap();
+    assert_eq!(data["source"], project_path.display().to_string());
+    assert_eq!(data["destination"], destination.display().to_string());
+    assert!(data["bytes_copied"].as_u64().unwrap() > 0);
+    assert_eq!(data["registered"], true);
+
+    assert!(
+        destination
+            .join("sources")
+            .join("sounds")
+            .join("sound_1.json")
+            .is_file()
+    );
+    assert!(destination.join("data").join("sound_1.wav").is_file());
+    assert!(destination.join("sources").join("attenuators").is_dir());
+    assert!(!destination.join("build").exists());
+    assert!(!destination.join("snapshots").exists());
+    assert_eq!(read_amproject_file(&destination).unwrap().name, "prototype");
+    assert_eq!(read_amproject_file(&project_path).unwrap().name, "game");
+
+    let registered = db_get_project_by_name("prototype", env.db()).unwrap();
+    assert_eq!(
+        registered.map(|p| p.path),
+        Some(destination.display().to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_p1_copy_with_verify_reports_verified_bytes() {
+    let (env, _project_path) = setup_project().await;
+    let command = ProjectCommands::Copy {
+        source: "game".to_string(),
+        new_name: "prototype".to_string(),
+        path: None,
+        register: false,
+        no_register: true,
+        overwrite_dir: false,
+        replace_registration: false,
+        verify: true,
+    };
+
+    let run = env.run(&command, ScriptedInput::new()).await;
+
+    assert!(run.result.is_ok(), "Copy should succeed: {:?}", run.result);
+    let data = run.last_success().unwrap();
+    assert_eq!(data["verified"]["bytes"], data["bytes_copied"]);
+    assert_eq!(data["verified"]["files"], data["files_copied"]);
+}
+
+#[tokio::test]
+async fn test_p2_copy_without_verify_has_no_verified_stats() {
+    let (env, _project_path) = setup_project().await;
+
+    let run = env
+        .run(&copy("game", "prototype", None, true), ScriptedInput::new())
+        .await;
+
+    assert!(run.result.is_ok(), "Copy should succeed: {:?}", run.result);
+    assert!(run.last_success().unwrap()["verified"].is_null());
+}
+
+#[tokio::test]
+async fn test_p0_copy_reports_progress_per_top_level_directory() {
+    let (env, _project_path) = setup_project().await;
+
+    let run = env
+        .run_with_mode(
+            &copy("game", "prototype", None, false),
+            ScriptedInput::new(),
+            OutputMode::Interactive,
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Copy should succeed: {:?}", run.result);
+    let progress: Vec<&str> = run
+        .calls
+        .iter()
+        .filter_map(|c| match c {
+            OutputCall::Progress(message) => Some(message.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert!(progress.contains(&"Copied data/ (1 file(s))"));
+    assert!(progress.contains(&"Copied sources/ (7 file(s))"));
+    assert!(!progress.iter().any(|p| p.contains("build/")));
+}
+
+#[tokio::test]
+async fn test_p1_copy_to_path_without_registering() {
+    let (env, _project_path) = setup_project().await;
+    let destination = env.home().join("elsewhere").join("copy");
+
+    let run = env
+        .run(
+            &copy("game", "prototype", Some(&destination), true),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Copy should succeed: {:?}", run.result);
+    assert!(destination.join(".amproject").is_file());
+    assert_eq!(run.last_success().unwrap()["registered"], false);
+    assert!(
+        db_get_project_by_name("prototype", env.db())
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_p1_copy_rejects_invalid_name() {
+    let (env, _project_path) = setup_project().await;
+
+    let run = env
+        .run(&copy("game", "bad/name", None, false), ScriptedInput::new())
+        .await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_VALIDATION_FIELD));
+}
+
+#[tokio::test]
+async fn test_p1_copy_of_unknown_project_fails() {
+    let (env, _project_path) = setup_project().await;
+
+    let run = env
+        .run(
+            &copy("missing", "prototype", None, false),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_PROJECT_NOT_REGISTERED));
+}
+
+#[tokio::test]
+async fn test_p1_copy_refuses_existing_destination() {
+    let (env, _project_path) = setup_project().await;
+    let destination = env.home().join("prototype");
+    fs::create_dir_all(&destination).unwrap();
+    fs::write(destination.join("notes.txt"), b"keep me").unwrap();
+
+    let run = env
+        .run(
+            &copy("game", "prototype", None, false),
+            ScriptedInput::new().non_interactive(),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_PROJECT_ALREADY_EXISTS));
+    assert!(destination.join("notes.txt").is_file());
+}
+
+#[tokio::test]
+async fn test_p1_copy_refuses_destination_inside_project() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run(
+            &copy(
+                "game",
+                "prototype",
+                Some(&project_path.join("nested")),
+                false,
+            ),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_VALIDATION_FIELD));
+    assert!(!project_path.join("nested").exists());
+}
+
+#[tokio::test]
+async fn test_p1_copy_declares_and_enters_stages() {
+    let (env, _project_path) = setup_project().await;
+
+    let run = env
+        .run(
+            &copy("game", "prototype", None, false),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Copy should succeed: {:?}", run.result);
+    let stages: Vec<&OutputCall> = run
+        .calls
+        .iter()
+        .filter(|c| matches!(c, OutputCall::BeginStages(_) | OutputCall::EnterStage(_)))
+        .collect();
+    assert_eq!(
+        stages,
+        [
+            &OutputCall::BeginStages(vec![
+                "Scan".to_string(),
+                "Copy".to_string(),
+                "Register".to_string()
+            ]),
+            &OutputCall::EnterStage(0),
+            &OutputCall::EnterStage(1),
+            &OutputCall::EnterStage(2),
+        ]
+    );
+}
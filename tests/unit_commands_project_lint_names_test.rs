@@ -0,0 +1,356 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for `am project lint-names`.
+
+use am::app::{App, Commands};
+use am::commands::project::ProjectCommands;
+use am::common::errors::codes;
+use am::common::utils::transform_name;
+use am::presentation::OutputMode;
+use am::testing::{OutputCall, ScriptedInput, TestEnv};
+use clap::Parser;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Scaffold a project whose `sound_1` file is `Footstep Loud.json`, listed as such by
+/// `soundbank_1`, and whose `event_1` file is `Event-1.json`.
+async fn setup_project() -> (TestEnv, PathBuf) {
+    let env = TestEnv::new()
+        .await
+        .expect("Failed to create test environment");
+    let project_path = env
+        .scaffold_project("lint_project", 1)
+        .expect("Failed to scaffold project");
+    let sources = project_path.join("sources");
+
+    fs::rename(
+        sources.join("sounds").join("sound_1.json"),
+        sources.join("sounds").join("Footstep Loud.json"),
+    )
+    .unwrap();
+    fs::rename(
+        sources.join("events").join("event_1.json"),
+        sources.join("events").join("Event-1.json"),
+    )
+    .unwrap();
+
+    let soundbank_path = sources.join("soundbanks").join("soundbank_1.json");
+    let mut soundbank = read_json(&soundbank_path);
+    soundbank["sounds"] = serde_json::json!(["Footstep Loud.json"]);
+    fs::write(&soundbank_path, soundbank.to_string()).unwrap();
+
+    (env, project_path)
+}
+
+fn read_json(path: &Path) -> serde_json::Value {
+    serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+}
+
+fn lint_names(asset_type: Option<&str>, fix: bool, skip_confirmation: bool) -> ProjectCommands {
+    ProjectCommands::LintNames {
+        asset_type: asset_type.map(str::to_string),
+        fix,
+        skip_confirmation,
+    }
+}
+
+// =============================================================================
+// Name Transformation Tests
+// =============================================================================
+
+#[test]
+fn test_p0_transform_name_applies_the_convention() {
+    assert_eq!(transform_name("Footstep Loud"), "footstep_loud");
+    assert_eq!(transform_name("  Event-1 "), "event_1");
+    assert_eq!(transform_name("already_fine"), "already_fine");
+    assert_eq!(
+        transform_name(&transform_name("Mixed Case-Name")),
+        "mixed_case_name"
+    );
+}
+
+// =============================================================================
+// Lint Names Command Parsing Tests
+// =============================================================================
+
+#[test]
+fn test_p0_lint_names_command_parses() {
+    let app = App::try_parse_from([
+        "am",
+        "project",
+        "lint-names",
+        "--type",
+        "sound",
+        "--fix",
+        "-y",
+    ])
+    .expect("Should parse");
+
+    match app.command {
+        Commands::Project {
+            command:
+                ProjectCommands::LintNames {
+                    asset_type,
+                    fix,
+                    skip_confirmation,
+                },
+        } => {
+            assert_eq!(asset_type.as_deref(), Some("sound"));
+            assert!(fix);
+            assert!(skip_confirmation);
+        }
+        _ => panic!("Expected Project LintNames command"),
+    }
+}
+
+#[test]
+fn test_p1_lint_names_yes_requires_fix() {
+    let result = App::try_parse_from(["am", "project", "lint-names", "--yes"]);
+    assert!(result.is_err(), "--yes without --fix should be rejected");
+}
+
+#[test]
+fn test_p1_lint_names_fix_takes_the_project_lock() {
+    assert!(lint_names(None, true, true).mutates_project_files());
+    assert!(!lint_names(None, false, false).mutates_project_files());
+    assert!(!lint_names(None, true, true).requires_database());
+}
+
+// =============================================================================
+// Lint Names Report Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_lint_names_reports_violations_with_proposed_names() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &lint_names(None, false, false),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Lint should succeed: {:?}", run.result);
+    let data = run.last_success().expect("Should have success output");
+    assert_eq!(data["fixed"], false);
+    assert_eq!(
+        data["counts"],
+        serde_json::json!({ "event": 1, "sound": 1 })
+    );
+
+    let violations = data["violations"].as_array().unwrap();
+    assert_eq!(violations.len(), 2);
+    let sound = violations
+        .iter()
+        .find(|v| v["type"] == "sound")
+        .expect("The sound should be reported");
+    assert_eq!(sound["name"], "sound_1");
+    assert_eq!(sound["path"], "sounds/Footstep Loud.json");
+    assert_eq!(sound["current"], "Footstep Loud");
+    assert_eq!(sound["proposed"], "footstep_loud");
+
+    assert!(
+        project_path
+            .join("sources/sounds/Footstep Loud.json")
+            .exists(),
+        "Reporting should not rename anything"
+    );
+}
+
+#[tokio::test]
+async fn test_p0_lint_names_filters_by_type() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &lint_names(Some("event"), false, false),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Lint should succeed: {:?}", run.result);
+    let data = run.last_success().unwrap();
+    assert_eq!(data["counts"], serde_json::json!({ "event": 1 }));
+    assert_eq!(data["violations"][0]["proposed"], "event_1");
+}
+
+#[tokio::test]
+async fn test_p1_lint_names_clean_project_reports_nothing() {
+    let env = TestEnv::new().await.unwrap();
+    let project_path = env.scaffold_project("clean_project", 2).unwrap();
+
+    let run = env
+        .run_in(
+            &project_path,
+            &lint_names(None, false, false),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Lint should succeed: {:?}", run.result);
+    let data = run.last_success().unwrap();
+    assert_eq!(data["violations"], serde_json::json!([]));
+    assert_eq!(data["counts"], serde_json::json!({}));
+}
+
+#[tokio::test]
+async fn test_p1_lint_names_rejects_unknown_type() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &lint_names(Some("banana"), false, false),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_err(), "Unknown type should be rejected");
+}
+
+#[tokio::test]
+async fn test_p2_lint_names_interactive_prints_a_table() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in_with_mode(
+            &project_path,
+            &lint_names(None, false, false),
+            ScriptedInput::new(),
+            OutputMode::Interactive,
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Lint should succeed: {:?}", run.result);
+    assert!(
+        run.calls.iter().any(|call| matches!(
+            call,
+            OutputCall::Table { title: Some(title), .. } if title == "Naming violations"
+        )),
+        "Expected a naming violations table: {:?}",
+        run.calls
+    );
+}
+
+// =============================================================================
+// Lint Names Fix Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_lint_names_fix_renames_files_and_updates_soundbanks() {
+    let (env, project_path) = setup_project().await;
+    let sources = project_path.join("sources");
+
+    let run = env
+        .run_in(
+            &project_path,
+            &lint_names(None, true, true),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Fix should succeed: {:?}", run.result);
+    let data = run.last_success().unwrap();
+    assert_eq!(data["fixed"], true);
+
+    assert!(sources.join("sounds/footstep_loud.json").exists());
+    assert!(!sources.join("sounds/Footstep Loud.json").exists());
+    assert!(sources.join("events/event_1.json").exists());
+    assert!(!sources.join("events/Event-1.json").exists());
+
+    let soundbank = read_json(&sources.join("soundbanks/soundbank_1.json"));
+    assert_eq!(
+        soundbank["sounds"],
+        serde_json::json!(["footstep_loud.json"])
+    );
+
+    let sound = data["violations"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|v| v["type"] == "sound")
+        .unwrap();
+    assert_eq!(
+        sound["updated_references"],
+        serde_json::json!(["soundbank/soundbank_1 (sounds[0])"])
+    );
+
+    let rerun = env
+        .run_in(
+            &project_path,
+            &lint_names(None, false, false),
+            ScriptedInput::new(),
+        )
+        .await;
+    assert_eq!(
+        rerun.last_success().unwrap()["violations"],
+        serde_json::json!([])
+    );
+}
+
+#[tokio::test]
+async fn test_p0_lint_names_fix_declined_changes_nothing() {
+    let (env, project_path) = setup_project().await;
+
+    let run = env
+        .run_in(
+            &project_path,
+            &lint_names(None, true, false),
+            ScriptedInput::new().confirm(false),
+        )
+        .await;
+
+    assert!(
+        run.result.is_ok(),
+        "Declining should succeed: {:?}",
+        run.result
+    );
+    assert_eq!(run.unused_responses, 0);
+    assert!(
+        project_path
+            .join("sources/sounds/Footstep Loud.json")
+            .exists()
+    );
+    let soundbank = read_json(&project_path.join("sources/soundbanks/soundbank_1.json"));
+    assert_eq!(
+        soundbank["sounds"],
+        serde_json::json!(["Footstep Loud.json"])
+    );
+}
+
+#[tokio::test]
+async fn test_p1_lint_names_fix_refuses_to_overwrite_a_file() {
+    let (env, project_path) = setup_project().await;
+    let sounds = project_path.join("sources/sounds");
+    fs::write(sounds.join("footstep_loud.json"), b"{}").unwrap();
+
+    let run = env
+        .run_in(
+            &project_path,
+            &lint_names(Some("sound"), true, true),
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert_eq!(run.error_code(), Some(codes::ERR_ASSET_ALREADY_EXISTS));
+    assert!(sounds.join("Footstep Loud.json").exists());
+    assert_eq!(
+        fs::read_to_string(sounds.join("footstep_loud.json")).unwrap(),
+        "{}"
+    );
+}
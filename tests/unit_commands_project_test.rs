@@ -16,7 +16,13 @@
 
 use am::app::{App, Commands};
 use am::commands::project::ProjectCommands;
+use am::common::utils::{
+    MAX_NAME_BYTES, expand_home, format_size, parse_size, transform_project_name as transform_name,
+    validate_project_name,
+};
 use clap::Parser;
+use proptest::prelude::*;
+use std::path::{Component, Path};
 
 // =============================================================================
 // Info Command Parsing Tests
@@ -29,7 +35,7 @@ fn test_p0_project_info_command_parses_without_name() {
 
     match app.command {
         Commands::Project {
-            command: ProjectCommands::Info { name },
+            command: ProjectCommands::Info { name, .. },
         } => {
             assert!(name.is_none(), "Name should be None when not provided");
         }
@@ -44,7 +50,7 @@ fn test_p0_project_info_command_parses_with_name() {
 
     match app.command {
         Commands::Project {
-            command: ProjectCommands::Info { name },
+            command: ProjectCommands::Info { name, .. },
         } => {
             assert_eq!(name, Some("my_project".to_string()), "Name should match");
         }
@@ -52,6 +58,79 @@ fn test_p0_project_info_command_parses_with_name() {
     }
 }
 
+#[test]
+fn test_p1_project_info_command_parses_paths_flag() {
+    let args = ["am", "project", "info", "my_project", "--paths"];
+    let app = App::try_parse_from(args).expect("Should parse");
+
+    match app.command {
+        Commands::Project {
+            command: ProjectCommands::Info { name, paths, .. },
+        } => {
+            assert_eq!(name, Some("my_project".to_string()));
+            assert!(paths, "--paths should be set");
+        }
+        _ => panic!("Expected Project Info command"),
+    }
+}
+
+// =============================================================================
+// Size Command Parsing Tests
+// =============================================================================
+
+#[test]
+fn test_p0_project_size_command_parses_threshold() {
+    let args = [
+        "am",
+        "project",
+        "size",
+        "--by-type",
+        "--human",
+        "--threshold",
+        "10MiB",
+    ];
+    let app = App::try_parse_from(args).expect("Should parse");
+
+    match app.command {
+        Commands::Project {
+            command:
+                ProjectCommands::Size {
+                    by_type,
+                    human,
+                    threshold,
+                },
+        } => {
+            assert!(by_type && human);
+            assert_eq!(threshold, Some(10 * 1024 * 1024));
+        }
+        _ => panic!("Expected Project Size command"),
+    }
+
+    assert!(App::try_parse_from(["am", "project", "size", "--threshold", "10XB"]).is_err());
+}
+
+#[test]
+fn test_p1_parse_size_accepts_binary_units() {
+    assert_eq!(parse_size("500"), Ok(500));
+    assert_eq!(parse_size("500B"), Ok(500));
+    assert_eq!(parse_size("64K"), Ok(64 * 1024));
+    assert_eq!(parse_size("64kb"), Ok(64 * 1024));
+    assert_eq!(parse_size("1.5MiB"), Ok(1536 * 1024));
+    assert_eq!(parse_size("2 G"), Ok(2 * 1024 * 1024 * 1024));
+    assert!(parse_size("").is_err());
+    assert!(parse_size("MiB").is_err());
+    assert!(parse_size("-1K").is_err());
+}
+
+#[test]
+fn test_p1_format_size_uses_the_largest_unit() {
+    assert_eq!(format_size(0), "0 B");
+    assert_eq!(format_size(1023), "1023 B");
+    assert_eq!(format_size(1536), "1.5 KiB");
+    assert_eq!(format_size(40 * 1024 * 1024 * 1024), "40.0 GiB");
+    assert_eq!(format_size(u64::MAX), "16777216.0 TiB");
+}
+
 // =============================================================================
 // transform_name Tests
 // =============================================================================
@@ -170,13 +249,127 @@ fn test_p1_validate_name_rejects_whitespace_only() {
 }
 
 // =============================================================================
-// Helper Functions (Mirror of source implementation)
+// Name Invariant Tests
 // =============================================================================
 
-fn transform_name(name: &str) -> String {
-    name.to_lowercase().replace(' ', "_").replace('-', "_")
+#[test]
+fn test_p1_transform_name_trims_surrounding_whitespace() {
+    assert_eq!(transform_name("  My Game\t"), "my_game");
 }
 
+#[test]
+fn test_p1_validate_name_rejects_names_without_alphanumeric_characters() {
+    for name in ["---", "___", "- _ -"] {
+        assert!(
+            validate_project_name(&transform_name(name)).is_err(),
+            "Name '{}' should be invalid",
+            name
+        );
+    }
+}
+
+#[test]
+fn test_p1_validate_name_rejects_reserved_device_names() {
+    for name in ["con", "CON", "nul", "com1", "Lpt9"] {
+        assert!(
+            validate_project_name(&transform_name(name)).is_err(),
+            "Name '{}' should be invalid",
+            name
+        );
+    }
+    assert!(validate_project_name("console").is_ok());
+}
+
+#[test]
+fn test_p2_validate_name_rejects_names_longer_than_a_path_component() {
+    let name = "a".repeat(MAX_NAME_BYTES + 1);
+    assert!(validate_project_name(&name).is_err());
+    assert!(validate_project_name(&name[1..]).is_ok());
+}
+
+/// Whether `name` is a single normal path component, the name of a directory.
+fn is_single_path_component(name: &str) -> bool {
+    let mut components = Path::new(name).components();
+    matches!(components.next(), Some(Component::Normal(c)) if c == name)
+        && components.next().is_none()
+}
+
+proptest! {
+    #[test]
+    fn test_p1_transform_name_is_idempotent(name in any::<String>()) {
+        let transformed = transform_name(&name);
+        prop_assert_eq!(transform_name(&transformed), transformed);
+    }
+
+    #[test]
+    fn test_p1_transform_name_never_introduces_path_separators(name in any::<String>()) {
+        let transformed = transform_name(&name);
+        prop_assert!(!transformed.chars().any(|c| c.is_whitespace() || c == '-'));
+        for separator in ['/', '\\'] {
+            prop_assert!(name.contains(separator) || !transformed.contains(separator));
+        }
+    }
+
+    #[test]
+    fn test_p1_accepted_names_are_filesystem_safe(name in any::<String>()) {
+        let transformed = transform_name(&name);
+        if validate_project_name(&transformed).is_ok() {
+            prop_assert!(!transformed.is_empty());
+            prop_assert!(transformed.len() <= MAX_NAME_BYTES);
+            prop_assert!(transformed.chars().any(char::is_alphanumeric));
+            prop_assert!(
+                transformed
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '_')
+            );
+            prop_assert!(is_single_path_component(&transformed));
+        }
+    }
+
+    #[test]
+    fn test_p1_ascii_names_are_accepted_once_transformed(
+        name in "[a-zA-Z0-9]{5}[a-zA-Z0-9 _-]{0,30}"
+    ) {
+        let transformed = transform_name(&name);
+        prop_assert!(validate_project_name(&transformed).is_ok(), "{:?}", transformed);
+        prop_assert!(is_single_path_component(&transformed));
+    }
+
+    #[test]
+    fn test_p2_separator_only_names_are_rejected(name in "[ _\\-]{0,10}") {
+        prop_assert!(validate_project_name(&transform_name(&name)).is_err());
+    }
+}
+
+// =============================================================================
+// expand_home Tests
+// =============================================================================
+
+#[test]
+fn test_p1_expand_home_replaces_leading_tilde() {
+    let home = Path::new("/home/user");
+
+    assert_eq!(expand_home(Path::new("~"), Some(home)), home);
+    assert_eq!(
+        expand_home(Path::new("~/games/my_game"), Some(home)),
+        home.join("games/my_game")
+    );
+}
+
+#[test]
+fn test_p2_expand_home_leaves_other_paths_unchanged() {
+    let home = Path::new("/home/user");
+
+    for path in ["games/~", "~user/game", "./~", "/abs/path"] {
+        assert_eq!(expand_home(Path::new(path), Some(home)), Path::new(path));
+    }
+    assert_eq!(expand_home(Path::new("~/game"), None), Path::new("~/game"));
+}
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
 struct ValidationResult {
     valid: bool,
 }
@@ -188,24 +381,16 @@ impl ValidationResult {
 }
 
 fn validate_name(name: &str) -> ValidationResult {
-    let trimmed = name.trim();
-
-    if trimmed.is_empty() {
-        return ValidationResult { valid: false };
+    ValidationResult {
+        valid: validate_project_name(name).is_ok(),
     }
-
-    let valid = !trimmed
-        .chars()
-        .any(|c| !c.is_alphanumeric() && c != '_' && c != '-' && c != ' ');
-
-    ValidationResult { valid }
 }
 
 // =============================================================================
 // read_amproject_file Tests
 // =============================================================================
 
-use am::common::utils::{count_assets_by_type, read_amproject_file};
+use am::common::utils::{count_assets_by_type, read_amproject_file, write_amproject_file};
 use am::database::entities::ProjectConfiguration;
 use std::collections::HashMap;
 use std::fs;
@@ -273,6 +458,202 @@ fn test_p1_read_amproject_file_returns_error_for_incomplete_json() {
     assert!(result.is_err(), "Should error for incomplete JSON");
 }
 
+// =============================================================================
+// write_amproject_file Tests
+// =============================================================================
+
+fn sample_project_configuration() -> ProjectConfiguration {
+    ProjectConfiguration {
+        name: "test_project".to_string(),
+        default_configuration: "pc.config.amconfig".to_string(),
+        sources_dir: "sources".to_string(),
+        data_dir: "data".to_string(),
+        build_dir: "build".to_string(),
+        version: 1,
+        hooks: Default::default(),
+        allow_json_comments: false,
+        asset_dirs: Default::default(),
+    }
+}
+
+#[test]
+fn test_p0_write_amproject_file_is_pretty_printed_in_struct_order() {
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+
+    write_amproject_file(temp_dir.path(), &sample_project_configuration())
+        .expect("Should write .amproject");
+
+    let content = fs::read_to_string(temp_dir.path().join(".amproject")).unwrap();
+    assert_eq!(
+        content,
+        "{\n  \"name\": \"test_project\",\n  \"default_configuration\": \"pc.config.amconfig\",\n  \"sources_dir\": \"sources\",\n  \"data_dir\": \"data\",\n  \"build_dir\": \"build\",\n  \"version\": 1\n}\n"
+    );
+}
+
+#[test]
+fn test_p0_write_amproject_file_read_modify_write_only_changes_edited_field() {
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    write_amproject_file(temp_dir.path(), &sample_project_configuration()).unwrap();
+    let before = fs::read_to_string(temp_dir.path().join(".amproject")).unwrap();
+
+    let mut config = read_amproject_file(temp_dir.path()).unwrap();
+    config.build_dir = "out".to_string();
+    write_amproject_file(temp_dir.path(), &config).unwrap();
+    let after = fs::read_to_string(temp_dir.path().join(".amproject")).unwrap();
+
+    assert_eq!(
+        after,
+        before.replace("\"build_dir\": \"build\"", "\"build_dir\": \"out\"")
+    );
+
+    // Rewriting unchanged configuration is byte-stable
+    write_amproject_file(
+        temp_dir.path(),
+        &read_amproject_file(temp_dir.path()).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        fs::read_to_string(temp_dir.path().join(".amproject")).unwrap(),
+        after
+    );
+}
+
+#[test]
+fn test_p1_write_amproject_file_preserves_unknown_fields() {
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let amproject_path = temp_dir.path().join(".amproject");
+    fs::write(
+        &amproject_path,
+        r#"{"version": 1, "name": "test_project", "plugins_dir": "plugins", "default_configuration": "pc.config.amconfig", "sources_dir": "sources", "data_dir": "data", "build_dir": "build", "custom": {"owner": "audio"}}"#,
+    )
+    .unwrap();
+
+    let mut config = read_amproject_file(temp_dir.path()).unwrap();
+    config.version = 2;
+    write_amproject_file(temp_dir.path(), &config).unwrap();
+
+    let content = fs::read_to_string(&amproject_path).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&content).unwrap();
+    assert_eq!(value["version"], 2);
+    assert_eq!(value["plugins_dir"], "plugins");
+    assert_eq!(value["custom"]["owner"], "audio");
+
+    // Known fields come first in struct order, unknown fields after them
+    let name = content.find("\"name\"").unwrap();
+    let version = content.find("\"version\"").unwrap();
+    let custom = content.find("\"custom\"").unwrap();
+    let plugins = content.find("\"plugins_dir\"").unwrap();
+    assert!(name < version && version < custom && custom < plugins);
+    assert!(content.ends_with("}\n"));
+}
+
+// =============================================================================
+// read_amproject_file_strict Tests
+// =============================================================================
+
+use am::common::errors::{CliError, codes};
+use am::common::utils::{
+    closest_match, edit_distance, read_amproject_file_strict, unknown_amproject_fields,
+};
+
+#[test]
+fn test_p0_strict_read_suggests_misspelled_field() {
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join(".amproject"),
+        r#"{"version": 1, "name": "test_project", "default_configuration": "pc.config.amconfig", "source_dir": "sources", "data_dir": "data", "build_dir": "build"}"#,
+    )
+    .unwrap();
+
+    let err = read_amproject_file_strict(temp_dir.path()).unwrap_err();
+    let cli_error = err
+        .downcast_ref::<CliError>()
+        .expect("Should be a CliError");
+
+    assert_eq!(cli_error.code, codes::ERR_VALIDATION_FIELD);
+    assert!(
+        cli_error
+            .why
+            .contains("'source_dir' is not a known field, did you mean 'sources_dir'?"),
+        "Unexpected reason: {}",
+        cli_error.why
+    );
+}
+
+#[test]
+fn test_p0_strict_read_accepts_known_fields() {
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    write_amproject_file(temp_dir.path(), &sample_project_configuration()).unwrap();
+
+    let config = read_amproject_file_strict(temp_dir.path()).unwrap();
+
+    assert_eq!(config.name, sample_project_configuration().name);
+}
+
+#[test]
+fn test_p1_lenient_read_ignores_unknown_fields() {
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join(".amproject"),
+        r#"{"version": 1, "name": "test_project", "default_configuration": "pc.config.amconfig", "sources_dir": "sources", "data_dir": "data", "build_dir": "build", "hook": {}}"#,
+    )
+    .unwrap();
+
+    assert!(read_amproject_file(temp_dir.path()).is_ok());
+    assert!(read_amproject_file_strict(temp_dir.path()).is_err());
+}
+
+#[test]
+fn test_p1_unknown_fields_without_close_match_have_no_suggestion() {
+    let value = serde_json::json!({
+        "name": "test_project",
+        "allow_json_comment": true,
+        "owner": "audio",
+    });
+
+    assert_eq!(
+        unknown_amproject_fields(&value),
+        vec![
+            (
+                "allow_json_comment".to_string(),
+                Some("allow_json_comments")
+            ),
+            ("owner".to_string(), None),
+        ]
+    );
+}
+
+#[test]
+fn test_p1_strict_read_reports_missing_file_like_lenient_read() {
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+
+    let err = read_amproject_file_strict(temp_dir.path()).unwrap_err();
+
+    assert_eq!(
+        err.downcast_ref::<CliError>().map(|e| e.code),
+        Some(codes::ERR_PROJECT_NOT_INITIALIZED)
+    );
+}
+
+#[test]
+fn test_p2_edit_distance_counts_single_character_edits() {
+    assert_eq!(edit_distance("", ""), 0);
+    assert_eq!(edit_distance("data_dir", "data_dir"), 0);
+    assert_eq!(edit_distance("source_dir", "sources_dir"), 1);
+    assert_eq!(edit_distance("bulid_dir", "build_dir"), 2);
+    assert_eq!(edit_distance("kitten", "sitting"), 3);
+    assert_eq!(edit_distance("", "name"), 4);
+}
+
+#[test]
+fn test_p2_closest_match_ignores_distant_candidates() {
+    let candidates = ["name", "version", "build_dir"];
+
+    assert_eq!(closest_match("nme", &candidates), Some("name"));
+    assert_eq!(closest_match("vesion", &candidates), Some("version"));
+    assert_eq!(closest_match("owner", &candidates), None);
+}
+
 // =============================================================================
 // count_assets_by_type Tests
 // =============================================================================
@@ -375,3 +756,92 @@ fn test_p1_count_assets_by_type_returns_empty_when_no_sources_dir() {
         "All counts should be 0"
     );
 }
+
+// =============================================================================
+// Template Choice Tests
+// =============================================================================
+
+use am::commands::project::{TemplateChoice, prompt_template_choice};
+use am::database::db_create_template;
+use am::database::entities::{Template, TemplateSource};
+use am::testing::{ScriptedInput, TestEnv};
+
+fn register_template(env: &TestEnv, name: &str, path: &str) {
+    db_create_template(
+        &Template {
+            id: None,
+            name: name.to_string(),
+            path: path.to_string(),
+            engine: None,
+            description: None,
+            source: TemplateSource::Custom,
+        },
+        env.db(),
+    )
+    .unwrap();
+}
+
+fn choice_names(choices: &[TemplateChoice]) -> Vec<(&str, bool)> {
+    choices
+        .iter()
+        .map(|c| (c.name(), matches!(c, TemplateChoice::BuiltinDefault)))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_p0_template_choices_start_with_the_builtin_default() {
+    let env = TestEnv::new().await.unwrap();
+    register_template(&env, "studio", "/templates/studio");
+    register_template(&env, "arcade", "/templates/arcade");
+
+    let choices = TemplateChoice::load_all(env.db()).unwrap();
+
+    assert_eq!(
+        choice_names(&choices),
+        vec![("default", true), ("arcade", false), ("studio", false)]
+    );
+}
+
+#[tokio::test]
+async fn test_p0_builtin_default_never_shadows_a_registered_default() {
+    let env = TestEnv::new().await.unwrap();
+    register_template(&env, "default", "/templates/legacy_default");
+    let choices = TemplateChoice::load_all(env.db()).unwrap();
+
+    // Both are offered, and --template default always means the bundled template.
+    assert_eq!(
+        choice_names(&choices),
+        vec![("default", true), ("default", false)]
+    );
+    assert!(matches!(
+        TemplateChoice::find(&choices, "default"),
+        Some(TemplateChoice::BuiltinDefault)
+    ));
+
+    // The registered one stays reachable at the prompt, by its own option.
+    let input = ScriptedInput::new().select("1");
+    match prompt_template_choice(&choices, &input).unwrap() {
+        TemplateChoice::Registered(template) => {
+            assert_eq!(template.path, "/templates/legacy_default")
+        }
+        TemplateChoice::BuiltinDefault => panic!("Expected the registered template"),
+    }
+    let input = ScriptedInput::new().select("0");
+    assert!(matches!(
+        prompt_template_choice(&choices, &input).unwrap(),
+        TemplateChoice::BuiltinDefault
+    ));
+}
+
+#[tokio::test]
+async fn test_p1_find_template_choice_by_name() {
+    let env = TestEnv::new().await.unwrap();
+    register_template(&env, "studio", "/templates/studio");
+    let choices = TemplateChoice::load_all(env.db()).unwrap();
+
+    match TemplateChoice::find(&choices, "studio") {
+        Some(TemplateChoice::Registered(template)) => assert_eq!(template.name, "studio"),
+        other => panic!("Expected the registered template, found {:?}", other),
+    }
+    assert!(TemplateChoice::find(&choices, "missing").is_none());
+}
@@ -0,0 +1,211 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the first-run setup wizard and `am sudo setup`.
+
+use am::app::{App, Commands};
+use am::commands::setup::{
+    CONFIG_COLOR, CONFIG_PROJECTS_ROOT, CONFIG_SDK_PATH, CONFIG_SETUP_COMPLETED, CONFIG_VERBOSITY,
+    needs_setup, offer_first_run_setup,
+};
+use am::commands::sudo::SudoCommands;
+use am::database::{db_get_config, db_get_project_by_name};
+use am::presentation::OutputMode;
+use am::testing::{MockOutput, ScriptedInput, TestEnv};
+use clap::Parser;
+use std::fs;
+use std::path::PathBuf;
+
+/// Create a fake SDK installation in the environment home.
+fn create_sdk(env: &TestEnv) -> PathBuf {
+    let sdk = env.home().join("sdk");
+    fs::create_dir_all(sdk.join("schemas")).unwrap();
+    sdk
+}
+
+fn config(env: &TestEnv, key: &str) -> Option<String> {
+    db_get_config(key, env.db()).unwrap()
+}
+
+// =============================================================================
+// Setup Command Parsing Tests
+// =============================================================================
+
+#[test]
+fn test_p0_setup_command_parses_rerun() {
+    let app = App::try_parse_from(["am", "sudo", "setup", "--rerun"]).expect("Should parse");
+
+    match app.command {
+        Commands::Sudo {
+            command: SudoCommands::Setup { rerun },
+        } => assert!(rerun),
+        _ => panic!("Expected Sudo Setup command"),
+    }
+}
+
+// =============================================================================
+// Wizard Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_fresh_database_needs_setup() {
+    let env = TestEnv::new().await.unwrap();
+
+    assert!(needs_setup(env.db()).unwrap());
+}
+
+#[tokio::test]
+async fn test_p0_wizard_persists_answers_and_registers_projects() {
+    let env = TestEnv::new().await.unwrap();
+    let sdk = create_sdk(&env);
+    env.create_project_dir("first_game").unwrap();
+
+    let input = ScriptedInput::new()
+        .text(sdk.display().to_string())
+        .confirm(true)
+        .text(env.home().display().to_string())
+        .select("never")
+        .select("quiet");
+    let run = env
+        .run_with_mode(
+            &SudoCommands::Setup { rerun: false },
+            input,
+            OutputMode::Interactive,
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Setup should succeed: {:?}", run.result);
+    assert_eq!(run.unused_responses, 0);
+    assert_eq!(
+        config(&env, CONFIG_SDK_PATH),
+        Some(sdk.display().to_string())
+    );
+    assert_eq!(
+        config(&env, CONFIG_PROJECTS_ROOT),
+        Some(env.home().display().to_string())
+    );
+    assert_eq!(config(&env, CONFIG_COLOR).as_deref(), Some("never"));
+    assert_eq!(config(&env, CONFIG_VERBOSITY).as_deref(), Some("quiet"));
+    assert_eq!(
+        config(&env, CONFIG_SETUP_COMPLETED).as_deref(),
+        Some("true")
+    );
+    assert!(!needs_setup(env.db()).unwrap());
+
+    assert!(
+        db_get_project_by_name("first_game", env.db())
+            .unwrap()
+            .is_some()
+    );
+    let summary = run.last_success().unwrap();
+    assert_eq!(summary["color"], "never");
+}
+
+#[tokio::test]
+async fn test_p1_wizard_can_skip_sdk_and_projects() {
+    let env = TestEnv::new().await.unwrap();
+
+    let input = ScriptedInput::new()
+        .text("")
+        .confirm(false)
+        .select("auto")
+        .select("normal");
+    let run = env
+        .run_with_mode(
+            &SudoCommands::Setup { rerun: false },
+            input,
+            OutputMode::Interactive,
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Setup should succeed: {:?}", run.result);
+    assert_eq!(config(&env, CONFIG_SDK_PATH), None);
+    assert_eq!(config(&env, CONFIG_PROJECTS_ROOT), None);
+    assert_eq!(
+        config(&env, CONFIG_SETUP_COMPLETED).as_deref(),
+        Some("true")
+    );
+}
+
+#[tokio::test]
+async fn test_p1_wizard_rejects_a_directory_without_schemas() {
+    let env = TestEnv::new().await.unwrap();
+
+    let input = ScriptedInput::new().text(env.home().display().to_string());
+    let run = env
+        .run_with_mode(
+            &SudoCommands::Setup { rerun: false },
+            input,
+            OutputMode::Interactive,
+        )
+        .await;
+
+    assert!(run.result.is_err());
+    assert!(needs_setup(env.db()).unwrap());
+}
+
+#[tokio::test]
+async fn test_p1_completed_setup_only_runs_again_with_rerun() {
+    let env = TestEnv::new().await.unwrap();
+    let answers = || {
+        ScriptedInput::new()
+            .text("")
+            .confirm(false)
+            .select("always")
+            .select("verbose")
+    };
+
+    let first = env
+        .run(&SudoCommands::Setup { rerun: false }, answers())
+        .await;
+    assert!(first.result.is_ok(), "{:?}", first.result);
+
+    let second = env
+        .run(&SudoCommands::Setup { rerun: false }, ScriptedInput::new())
+        .await;
+    assert!(second.result.is_ok(), "{:?}", second.result);
+    assert!(
+        second
+            .last_success()
+            .unwrap()
+            .as_str()
+            .unwrap()
+            .contains("--rerun"),
+        "Should point to --rerun"
+    );
+
+    let rerun = env
+        .run(&SudoCommands::Setup { rerun: true }, answers())
+        .await;
+    assert!(rerun.result.is_ok(), "{:?}", rerun.result);
+    assert_eq!(rerun.unused_responses, 0);
+}
+
+#[tokio::test]
+async fn test_p0_declining_the_first_run_offer_marks_setup_completed() {
+    let env = TestEnv::new().await.unwrap();
+    let input = ScriptedInput::new().confirm(false);
+    let output = MockOutput::new(OutputMode::Interactive);
+
+    offer_first_run_setup(env.db(), &input, &output)
+        .await
+        .expect("Declining should succeed");
+
+    assert_eq!(input.remaining(), 0);
+    assert_eq!(
+        config(&env, CONFIG_SETUP_COMPLETED).as_deref(),
+        Some("true")
+    );
+    assert_eq!(config(&env, CONFIG_COLOR), None);
+}
@@ -21,10 +21,13 @@
 //! - P1: Command routing, progress output, error handling
 //! - P2: Edge cases, WAL/journal cleanup
 
-use am::commands::sudo::{DatabaseCommands, SudoCommands};
+use am::commands::sudo::{DatabaseCommands, LogsCommands, SudoCommands};
+use am::common::ignore::WalkOptions;
 use am::database::Database;
+use am::database::migrations::MigrationManager;
 use am::input::{Input, NonInteractiveInput};
 use am::presentation::{InteractiveOutput, Output};
+use clap::Parser;
 use serde_json::json;
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -77,6 +80,14 @@ impl Output for MockOutput {
         self.progress_calls.borrow_mut().push(message.to_string());
     }
 
+    fn print(&self, _message: &str) {
+        // Not needed for these tests
+    }
+
+    fn warning(&self, _message: &str) {
+        // Not needed for these tests
+    }
+
     fn table(&self, _title: Option<&str>, _data: serde_json::Value) {
         // Mock implementation - does nothing for testing
     }
@@ -106,6 +117,7 @@ fn test_p1_sudo_commands_database_variant_exists() {
     // GIVEN: A DatabaseCommands value
     let db_cmd = DatabaseCommands::Reset {
         skip_confirmation: true,
+        allow_destructive: false,
     };
 
     // WHEN: Wrapping in SudoCommands
@@ -114,6 +126,7 @@ fn test_p1_sudo_commands_database_variant_exists() {
     // THEN: Should create valid SudoCommands variant
     match cmd {
         SudoCommands::Database { command: _ } => assert!(true),
+        _ => panic!("Expected Database variant"),
     }
 }
 
@@ -122,13 +135,17 @@ fn test_p1_database_commands_reset_variant_exists() {
     // GIVEN: Reset command with skip_confirmation
     let cmd = DatabaseCommands::Reset {
         skip_confirmation: true,
+        allow_destructive: false,
     };
 
     // THEN: Should match Reset variant
     match cmd {
-        DatabaseCommands::Reset { skip_confirmation } => {
+        DatabaseCommands::Reset {
+            skip_confirmation, ..
+        } => {
             assert!(skip_confirmation, "skip_confirmation should be true");
         }
+        _ => panic!("Expected Reset variant"),
     }
 }
 
@@ -137,13 +154,17 @@ fn test_p2_database_commands_reset_default_no_skip() {
     // GIVEN: Reset command without skip
     let cmd = DatabaseCommands::Reset {
         skip_confirmation: false,
+        allow_destructive: false,
     };
 
     // THEN: Should have skip_confirmation as false
     match cmd {
-        DatabaseCommands::Reset { skip_confirmation } => {
+        DatabaseCommands::Reset {
+            skip_confirmation, ..
+        } => {
             assert!(!skip_confirmation, "Default should not skip confirmation");
         }
+        _ => panic!("Expected Reset variant"),
     }
 }
 
@@ -157,6 +178,7 @@ fn test_p2_sudo_commands_implements_debug() {
     let cmd = SudoCommands::Database {
         command: DatabaseCommands::Reset {
             skip_confirmation: true,
+            allow_destructive: false,
         },
     };
 
@@ -173,6 +195,7 @@ fn test_p2_database_commands_implements_debug() {
     // GIVEN: A DatabaseCommands value
     let cmd = DatabaseCommands::Reset {
         skip_confirmation: false,
+        allow_destructive: false,
     };
 
     // WHEN: Formatting with Debug
@@ -196,6 +219,7 @@ async fn test_p0_handler_routes_database_commands() {
     let cmd = SudoCommands::Database {
         command: DatabaseCommands::Reset {
             skip_confirmation: true,
+            allow_destructive: false,
         },
     };
     let temp_dir = tempdir().expect("Failed to create temp dir");
@@ -209,7 +233,15 @@ async fn test_p0_handler_routes_database_commands() {
     // Note: This will fail because it tries to use the real home directory
     // but it validates the routing works
     let input = test_input();
-    let result = am::commands::sudo::handler(&cmd, Some(db_arc), &input, &output).await;
+    let result = am::commands::sudo::handler(
+        &cmd,
+        None,
+        WalkOptions::default(),
+        Some(db_arc),
+        &input,
+        &output,
+    )
+    .await;
 
     // THEN: Handler should execute (may fail due to file system access)
     // The important thing is it routes correctly to reset_database
@@ -225,6 +257,7 @@ async fn test_p1_handler_shows_warning_messages() {
     let cmd = SudoCommands::Database {
         command: DatabaseCommands::Reset {
             skip_confirmation: true,
+            allow_destructive: false,
         },
     };
     let temp_dir = tempdir().expect("Failed to create temp dir");
@@ -236,7 +269,15 @@ async fn test_p1_handler_shows_warning_messages() {
 
     // WHEN: Calling the handler
     let input = test_input();
-    let _ = am::commands::sudo::handler(&cmd, Some(db_arc), &input, &output).await;
+    let _ = am::commands::sudo::handler(
+        &cmd,
+        None,
+        WalkOptions::default(),
+        Some(db_arc),
+        &input,
+        &output,
+    )
+    .await;
 
     // THEN: Should show warning messages about the operation
     let messages = output.progress_messages();
@@ -313,6 +354,56 @@ fn test_p2_journal_file_cleanup_concept() {
     assert!(!journal_path.exists(), "Journal file should be removed");
 }
 
+#[test]
+fn test_p1_reset_wal_check_accepts_missing_or_idle_log() {
+    // GIVEN: No WAL file, then one no process writes to
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let wal_path = temp_dir.path().join("test.db-wal");
+    let interval = std::time::Duration::from_millis(50);
+
+    // THEN: Neither blocks the reset
+    assert!(am::commands::sudo::ensure_wal_idle(&wal_path, interval).is_ok());
+
+    std::fs::write(&wal_path, b"leftover frames").expect("Failed to write wal");
+    assert!(am::commands::sudo::ensure_wal_idle(&wal_path, interval).is_ok());
+}
+
+#[test]
+fn test_p0_reset_wal_check_refuses_growing_log() {
+    // GIVEN: A WAL file another writer keeps appending to
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let wal_path = temp_dir.path().join("test.db-wal");
+    std::fs::write(&wal_path, b"frames").expect("Failed to write wal");
+
+    let writer_path = wal_path.clone();
+    let writer = std::thread::spawn(move || {
+        use std::io::Write;
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&writer_path)
+            .expect("Failed to open wal");
+        for _ in 0..20 {
+            file.write_all(b"more frames").expect("Failed to append");
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+    });
+
+    // WHEN: Checking the log while it grows
+    let result =
+        am::commands::sudo::ensure_wal_idle(&wal_path, std::time::Duration::from_millis(100));
+    writer.join().unwrap();
+
+    // THEN: The reset is refused
+    let error = result.expect_err("A growing WAL file should block the reset");
+    let cli_error = error
+        .downcast_ref::<am::common::errors::CliError>()
+        .expect("Should be a CliError");
+    assert_eq!(
+        cli_error.code,
+        am::common::errors::codes::ERR_DATABASE_IN_USE
+    );
+}
+
 // =============================================================================
 // Skip Confirmation Tests
 // =============================================================================
@@ -322,16 +413,20 @@ fn test_p1_skip_confirmation_flag_true() {
     // GIVEN: Reset command with skip_confirmation = true
     let cmd = DatabaseCommands::Reset {
         skip_confirmation: true,
+        allow_destructive: false,
     };
 
     // THEN: Should skip the interactive prompt
     match cmd {
-        DatabaseCommands::Reset { skip_confirmation } => {
+        DatabaseCommands::Reset {
+            skip_confirmation, ..
+        } => {
             assert!(
                 skip_confirmation,
                 "Should skip confirmation when flag is set"
             );
         }
+        _ => panic!("Expected Reset variant"),
     }
 }
 
@@ -340,16 +435,20 @@ fn test_p1_skip_confirmation_flag_false() {
     // GIVEN: Reset command with skip_confirmation = false
     let cmd = DatabaseCommands::Reset {
         skip_confirmation: false,
+        allow_destructive: false,
     };
 
     // THEN: Should require interactive confirmation
     match cmd {
-        DatabaseCommands::Reset { skip_confirmation } => {
+        DatabaseCommands::Reset {
+            skip_confirmation, ..
+        } => {
             assert!(
                 !skip_confirmation,
                 "Should require confirmation when flag is not set"
             );
         }
+        _ => panic!("Expected Reset variant"),
     }
 }
 
@@ -390,3 +489,488 @@ fn test_p1_mock_output_captures_success() {
         Some(json!("Database reset successful"))
     );
 }
+
+// =============================================================================
+// Migrate Command Tests
+// =============================================================================
+
+#[test]
+fn test_p1_migrate_command_parses_target_version() {
+    // GIVEN: Migrate arguments with a target version
+    let args = ["am", "sudo", "database", "migrate", "--to", "4", "--yes"];
+
+    // WHEN: Parsing the command line
+    let app = am::app::App::try_parse_from(args).expect("Should parse");
+
+    // THEN: Should produce a Migrate command with the target version
+    match app.command {
+        am::app::Commands::Sudo {
+            command:
+                SudoCommands::Database {
+                    command:
+                        DatabaseCommands::Migrate {
+                            target_version,
+                            skip_confirmation,
+                        },
+                },
+        } => {
+            assert_eq!(target_version, Some(4));
+            assert!(skip_confirmation);
+        }
+        _ => panic!("Expected Migrate command"),
+    }
+}
+
+#[tokio::test]
+async fn test_p0_migrate_handler_applies_migrations_up_to_target() {
+    // GIVEN: A database migrated to version 2
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let mut db = Database::new(temp_dir.path().join("test.db")).expect("Failed to create db");
+    db.run_migrations_to(2)
+        .await
+        .expect("Failed to run migrations");
+    let db_arc = Arc::new(db);
+    let output = MockOutput::new();
+    let cmd = SudoCommands::Database {
+        command: DatabaseCommands::Migrate {
+            target_version: Some(4),
+            skip_confirmation: true,
+        },
+    };
+
+    // WHEN: Migrating to version 4
+    let result = am::commands::sudo::handler(
+        &cmd,
+        None,
+        WalkOptions::default(),
+        Some(db_arc.clone()),
+        &test_input(),
+        &output,
+    )
+    .await;
+
+    // THEN: Migrations 3 and 4 are listed and applied
+    assert!(result.is_ok(), "Migrate should succeed: {:?}", result);
+    let messages = output.progress_messages();
+    assert!(
+        messages
+            .iter()
+            .any(|m| m.contains("3: Create templates table"))
+    );
+    assert!(
+        messages
+            .iter()
+            .any(|m| m.contains("4: Create configuration table"))
+    );
+    let success = output.last_success().expect("Should report success");
+    assert_eq!(success["from_version"], 2);
+    assert_eq!(success["to_version"], 4);
+    assert_eq!(success["applied"].as_array().unwrap().len(), 2);
+    assert_eq!(
+        MigrationManager::new()
+            .get_current_version(&db_arc)
+            .unwrap(),
+        4
+    );
+}
+
+#[tokio::test]
+async fn test_p1_migrate_handler_requires_confirmation_in_non_interactive_mode() {
+    // GIVEN: A fresh database and no --yes flag
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let db = Database::new(temp_dir.path().join("test.db")).expect("Failed to create db");
+    let db_arc = Arc::new(db);
+    let output = MockOutput::new();
+    let cmd = SudoCommands::Database {
+        command: DatabaseCommands::Migrate {
+            target_version: Some(2),
+            skip_confirmation: false,
+        },
+    };
+
+    // WHEN: Migrating without confirmation
+    let result = am::commands::sudo::handler(
+        &cmd,
+        None,
+        WalkOptions::default(),
+        Some(db_arc.clone()),
+        &test_input(),
+        &output,
+    )
+    .await;
+
+    // THEN: Should fail on the confirmation prompt without applying anything
+    assert!(result.is_err(), "Confirmation prompt should be blocked");
+    assert_eq!(output.success_count(), 0);
+    assert_eq!(
+        MigrationManager::new()
+            .get_current_version(&db_arc)
+            .unwrap(),
+        0
+    );
+}
+
+#[tokio::test]
+async fn test_p1_migrate_handler_rejects_older_target() {
+    // GIVEN: A fully migrated database
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let mut db = Database::new(temp_dir.path().join("test.db")).expect("Failed to create db");
+    db.run_migrations().await.expect("Failed to run migrations");
+    let output = MockOutput::new();
+    let cmd = SudoCommands::Database {
+        command: DatabaseCommands::Migrate {
+            target_version: Some(1),
+            skip_confirmation: true,
+        },
+    };
+
+    // WHEN: Migrating to an older version
+    let result = am::commands::sudo::handler(
+        &cmd,
+        None,
+        WalkOptions::default(),
+        Some(Arc::new(db)),
+        &test_input(),
+        &output,
+    )
+    .await;
+
+    // THEN: Should fail with a validation error
+    let err = result.expect_err("Backward migration should fail");
+    assert_eq!(
+        err.downcast_ref::<am::common::errors::CliError>()
+            .map(|e| e.code),
+        Some(am::common::errors::codes::ERR_VALIDATION_FIELD)
+    );
+}
+
+// =============================================================================
+// Diagnose Database Lock Tests
+// =============================================================================
+
+fn cli_process(pid: u32) -> am::common::process::ProcessInfo {
+    am::common::process::ProcessInfo {
+        pid,
+        name: "am".to_string(),
+        command: "am project build".to_string(),
+    }
+}
+
+#[test]
+fn test_p1_diagnose_db_lock_command_parses() {
+    let app = am::app::App::try_parse_from(["am", "sudo", "self", "diagnose-db-lock"])
+        .expect("Should parse");
+
+    assert!(matches!(
+        app.command,
+        am::app::Commands::Sudo {
+            command: SudoCommands::Cli {
+                command: am::commands::sudo::SelfCommands::DiagnoseDbLock
+            }
+        }
+    ));
+}
+
+#[tokio::test]
+async fn test_p0_diagnose_db_lock_reports_free_database() {
+    // GIVEN: A database nobody is writing to
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join("am.db");
+    let mut db = Database::new(&db_path).expect("Failed to create db");
+    db.run_migrations().await.expect("Failed to run migrations");
+
+    // WHEN: Diagnosing the database lock
+    let diagnosis = am::commands::sudo::diagnose_db_lock(&db_path, temp_dir.path(), &[]);
+
+    // THEN: The write lock is free and no remediation is needed
+    assert_eq!(diagnosis["write_lock"]["status"], "free");
+    assert_eq!(diagnosis["database"]["exists"], true);
+    assert_eq!(diagnosis["project_lock"], serde_json::Value::Null);
+    assert_eq!(diagnosis["suggestions"], json!([]));
+}
+
+#[test]
+fn test_p0_diagnose_db_lock_detects_held_write_lock() {
+    // GIVEN: Another connection holding a write transaction
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join("am.db");
+    let holder = rusqlite::Connection::open(&db_path).unwrap();
+    holder
+        .execute_batch("PRAGMA journal_mode = WAL; CREATE TABLE t (x); BEGIN IMMEDIATE;")
+        .unwrap();
+
+    // WHEN: Diagnosing while another am process is running
+    let diagnosis =
+        am::commands::sudo::diagnose_db_lock(&db_path, temp_dir.path(), &[cli_process(4242)]);
+
+    // THEN: The lock is reported as held, pointing at the other process
+    assert_eq!(diagnosis["write_lock"]["status"], "held");
+    assert_eq!(diagnosis["processes"][0]["pid"], 4242);
+    assert!(
+        diagnosis["suggestions"][0]
+            .as_str()
+            .unwrap()
+            .contains("4242"),
+        "{}",
+        diagnosis
+    );
+}
+
+#[test]
+fn test_p1_diagnose_db_lock_blames_external_process_when_no_cli_runs() {
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join("am.db");
+    let holder = rusqlite::Connection::open(&db_path).unwrap();
+    holder
+        .execute_batch("CREATE TABLE t (x); BEGIN EXCLUSIVE;")
+        .unwrap();
+
+    let diagnosis = am::commands::sudo::diagnose_db_lock(&db_path, temp_dir.path(), &[]);
+
+    assert_eq!(diagnosis["write_lock"]["status"], "held");
+    assert!(
+        diagnosis["findings"][0]
+            .as_str()
+            .unwrap()
+            .contains("other than am"),
+        "{}",
+        diagnosis
+    );
+}
+
+#[test]
+fn test_p1_diagnose_db_lock_reports_missing_database() {
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join("am.db");
+
+    let diagnosis = am::commands::sudo::diagnose_db_lock(&db_path, temp_dir.path(), &[]);
+
+    assert_eq!(diagnosis["write_lock"]["status"], "missing");
+    assert_eq!(diagnosis["database"]["exists"], false);
+    assert!(!db_path.exists(), "Diagnosing must not create the database");
+}
+
+#[test]
+fn test_p2_diagnose_db_lock_reports_stale_project_lock() {
+    // GIVEN: A project lock left behind by a process that no longer runs
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let stale = am::common::lock::LockHolder {
+        pid: 999_999_999,
+        command: "am asset sound create crashed".to_string(),
+        acquired_at: "2026-01-01T00:00:00+00:00".to_string(),
+    };
+    std::fs::write(
+        temp_dir.path().join(am::common::lock::LOCK_FILE),
+        serde_json::to_string(&stale).unwrap(),
+    )
+    .unwrap();
+
+    // WHEN: Diagnosing from the project directory
+    let diagnosis =
+        am::commands::sudo::diagnose_db_lock(&temp_dir.path().join("am.db"), temp_dir.path(), &[]);
+
+    // THEN: The project lock is reported as stale
+    assert_eq!(diagnosis["project_lock"]["stale"], true);
+    assert_eq!(
+        diagnosis["project_lock"]["holder"]["command"],
+        "am asset sound create crashed"
+    );
+}
+
+// =============================================================================
+// Migrate Home Tests
+// =============================================================================
+
+/// A Linux environment whose home directory is `home`, without XDG variables.
+fn xdg_env(home: &std::path::Path) -> am::common::paths::HomeEnv {
+    am::common::paths::HomeEnv {
+        home: Some(home.to_path_buf()),
+        use_xdg: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_p0_migrate_home_command_parses() {
+    let app = am::app::App::try_parse_from(["am", "sudo", "migrate-home", "--yes"])
+        .expect("Should parse");
+
+    match app.command {
+        am::app::Commands::Sudo {
+            command: SudoCommands::MigrateHome { skip_confirmation },
+        } => assert!(skip_confirmation),
+        _ => panic!("Expected Sudo MigrateHome command"),
+    }
+}
+
+#[test]
+fn test_p0_migrate_home_moves_legacy_directory_to_xdg_layout() {
+    // GIVEN: A legacy home with a database, a crash log and an SDK
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let legacy = temp_dir.path().join(".amplitude");
+    std::fs::create_dir_all(legacy.join("sdk").join("schemas")).unwrap();
+    std::fs::write(legacy.join("am.db"), b"db").unwrap();
+    std::fs::write(legacy.join("am.db-wal"), b"wal").unwrap();
+    std::fs::write(legacy.join("20260101_120000.000.log"), b"crash").unwrap();
+    let env = xdg_env(temp_dir.path());
+    let output = MockOutput::new();
+
+    // WHEN: Migrating the home
+    let result = am::commands::sudo::migrate_home(&env, &[], true, &test_input(), &output);
+
+    // THEN: Data and logs are moved to their XDG directories
+    assert!(result.is_ok(), "Migration should succeed: {:?}", result);
+    let data_dir = temp_dir.path().join(".local/share/amplitude");
+    let state_dir = temp_dir.path().join(".local/state/amplitude");
+    assert!(data_dir.join("am.db").is_file());
+    assert!(data_dir.join("am.db-wal").is_file());
+    assert!(data_dir.join("sdk").join("schemas").is_dir());
+    assert!(state_dir.join("20260101_120000.000.log").is_file());
+    assert!(!legacy.exists(), "The legacy directory should be removed");
+
+    let summary = output.last_success().unwrap();
+    assert_eq!(summary["moved"].as_array().unwrap().len(), 4);
+    assert_eq!(
+        am::common::paths::resolve_from(&env).unwrap().layout,
+        am::common::paths::HomeLayout::Xdg
+    );
+}
+
+#[test]
+fn test_p1_migrate_home_without_legacy_directory_does_nothing() {
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let output = MockOutput::new();
+
+    let result = am::commands::sudo::migrate_home(
+        &xdg_env(temp_dir.path()),
+        &[],
+        true,
+        &test_input(),
+        &output,
+    );
+
+    assert!(result.is_ok());
+    assert!(
+        output
+            .last_success()
+            .unwrap()
+            .as_str()
+            .unwrap()
+            .contains("Nothing to migrate")
+    );
+    assert!(!temp_dir.path().join(".local").exists());
+}
+
+#[test]
+fn test_p1_migrate_home_refuses_to_overwrite_existing_files() {
+    // GIVEN: A database in both the legacy and the XDG data directories
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let legacy = temp_dir.path().join(".amplitude");
+    let data_dir = temp_dir.path().join(".local/share/amplitude");
+    std::fs::create_dir_all(&legacy).unwrap();
+    std::fs::create_dir_all(&data_dir).unwrap();
+    std::fs::write(legacy.join("am.db"), b"legacy").unwrap();
+    std::fs::write(legacy.join("notes.txt"), b"notes").unwrap();
+    std::fs::write(data_dir.join("am.db"), b"xdg").unwrap();
+
+    // WHEN: Migrating the home
+    let result = am::commands::sudo::migrate_home(
+        &xdg_env(temp_dir.path()),
+        &[],
+        true,
+        &test_input(),
+        &MockOutput::new(),
+    );
+
+    // THEN: Nothing is moved
+    assert!(result.is_err());
+    assert_eq!(std::fs::read(legacy.join("am.db")).unwrap(), b"legacy");
+    assert!(legacy.join("notes.txt").is_file());
+    assert_eq!(std::fs::read(data_dir.join("am.db")).unwrap(), b"xdg");
+}
+
+#[test]
+fn test_p1_migrate_home_refuses_while_other_processes_run() {
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let legacy = temp_dir.path().join(".amplitude");
+    std::fs::create_dir_all(&legacy).unwrap();
+    std::fs::write(legacy.join("am.db"), b"db").unwrap();
+    let running = am::common::process::ProcessInfo {
+        pid: 4242,
+        name: "am".to_string(),
+        command: "am project build".to_string(),
+    };
+
+    let result = am::commands::sudo::migrate_home(
+        &xdg_env(temp_dir.path()),
+        &[running],
+        true,
+        &test_input(),
+        &MockOutput::new(),
+    );
+
+    let message = result.unwrap_err().to_string();
+    assert!(
+        message.contains("4242"),
+        "Should name the process: {}",
+        message
+    );
+    assert!(legacy.join("am.db").is_file());
+}
+
+#[test]
+fn test_p2_migrate_home_refuses_when_am_home_is_set() {
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    std::fs::create_dir_all(temp_dir.path().join(".amplitude")).unwrap();
+    let env = am::common::paths::HomeEnv {
+        am_home: Some(temp_dir.path().join("custom")),
+        ..xdg_env(temp_dir.path())
+    };
+
+    let result =
+        am::commands::sudo::migrate_home(&env, &[], true, &test_input(), &MockOutput::new());
+
+    assert!(result.is_err());
+    assert!(temp_dir.path().join(".amplitude").is_dir());
+}
+
+// =============================================================================
+// Logs Command Tests
+// =============================================================================
+
+#[test]
+fn test_p1_logs_tail_command_parses_flags() {
+    let app = am::app::App::try_parse_from(["am", "sudo", "logs", "tail", "-f", "-n", "50"])
+        .expect("Should parse");
+
+    match app.command {
+        am::app::Commands::Sudo {
+            command:
+                SudoCommands::Logs {
+                    command: LogsCommands::Tail { follow, lines },
+                },
+        } => {
+            assert!(follow);
+            assert_eq!(lines, 50);
+        }
+        _ => panic!("Expected Tail command"),
+    }
+}
+
+#[test]
+fn test_p2_logs_tail_command_defaults() {
+    let app = am::app::App::try_parse_from(["am", "sudo", "logs", "tail"]).expect("Should parse");
+
+    match app.command {
+        am::app::Commands::Sudo {
+            command:
+                SudoCommands::Logs {
+                    command: LogsCommands::Tail { follow, lines },
+                },
+        } => {
+            assert!(!follow);
+            assert_eq!(lines, 10);
+        }
+        _ => panic!("Expected Tail command"),
+    }
+}
@@ -0,0 +1,248 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for `am template add --scan`, the bulk registration of templates.
+
+use am::app::{App, Commands};
+use am::commands::template::TemplateCommands;
+use am::database::{db_get_template_by_name, db_get_templates};
+use am::testing::{OutputCall, ScriptedInput, TestEnv};
+use clap::Parser;
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Write a minimal valid template to `dir`.
+fn write_template(dir: &Path) {
+    fs::create_dir_all(dir).unwrap();
+    fs::write(dir.join(".amproject"), r#"{"name":"test","version":1}"#).unwrap();
+    fs::write(dir.join("pc.buses.json"), "{}").unwrap();
+    fs::write(dir.join("pc.config.json"), "{}").unwrap();
+}
+
+/// Create `home/shared` with the valid templates `Studio Game` and `mobile-game`, the
+/// invalid `broken` (no `.amproject`) and the hidden `.git`.
+async fn setup_shared_templates() -> (TestEnv, PathBuf) {
+    let env = TestEnv::new()
+        .await
+        .expect("Failed to create test environment");
+    let root = env.home().join("shared");
+    write_template(&root.join("Studio Game"));
+    write_template(&root.join("mobile-game"));
+    fs::create_dir_all(root.join("broken")).unwrap();
+    fs::write(root.join("broken").join("pc.buses.json"), "{}").unwrap();
+    write_template(&root.join(".git"));
+    fs::write(root.join("README.md"), "Shared templates").unwrap();
+    (env, root)
+}
+
+fn scan(root: &Path, prune_missing: bool) -> TemplateCommands {
+    TemplateCommands::Add {
+        scan: root.to_path_buf(),
+        prune_missing,
+    }
+}
+
+/// The result row of the directory `directory`.
+fn result_of<'a>(data: &'a Value, directory: &str) -> &'a Value {
+    data["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|r| r["directory"] == directory)
+        .unwrap_or_else(|| panic!("No result for {}", directory))
+}
+
+// =============================================================================
+// Add Command Parsing Tests
+// =============================================================================
+
+#[test]
+fn test_p0_template_add_scan_parses() {
+    let app = App::try_parse_from([
+        "am",
+        "template",
+        "add",
+        "--scan",
+        "/shared/amplitude-templates",
+        "--prune-missing",
+    ])
+    .expect("Should parse");
+
+    match app.command {
+        Commands::Template {
+            command:
+                TemplateCommands::Add {
+                    scan,
+                    prune_missing,
+                },
+        } => {
+            assert_eq!(scan, PathBuf::from("/shared/amplitude-templates"));
+            assert!(prune_missing);
+        }
+        _ => panic!("Expected Template Add command"),
+    }
+}
+
+#[test]
+fn test_p1_template_add_requires_scan() {
+    assert!(App::try_parse_from(["am", "template", "add"]).is_err());
+}
+
+// =============================================================================
+// Scan Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_template_scan_registers_valid_directories() {
+    let (env, root) = setup_shared_templates().await;
+
+    let run = env.run(&scan(&root, false), ScriptedInput::new()).await;
+
+    assert!(run.result.is_ok(), "Scan should succeed: {:?}", run.result);
+    let data = run.last_success().expect("Should have success output");
+    assert_eq!(data["counts"]["registered"], 2);
+    assert_eq!(data["counts"]["invalid"], 1);
+    assert_eq!(data["results"].as_array().unwrap().len(), 3);
+
+    let studio = result_of(&data, "Studio Game");
+    assert_eq!(studio["name"], "studio_game");
+    assert_eq!(studio["status"], "registered");
+    assert_eq!(result_of(&data, "mobile-game")["name"], "mobile_game");
+    assert_eq!(result_of(&data, "broken")["status"], "invalid");
+    assert!(result_of(&data, "broken")["reason"].is_string());
+
+    let template = db_get_template_by_name("studio_game", env.db())
+        .unwrap()
+        .expect("Template should be registered");
+    assert!(template.path.ends_with("Studio Game"));
+    assert!(
+        db_get_template_by_name("broken", env.db())
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_p0_template_scan_skips_registered_names() {
+    let (env, root) = setup_shared_templates().await;
+    let first = env.run(&scan(&root, false), ScriptedInput::new()).await;
+    assert!(first.result.is_ok());
+
+    let run = env.run(&scan(&root, false), ScriptedInput::new()).await;
+
+    assert!(
+        run.result.is_ok(),
+        "Rescan should succeed: {:?}",
+        run.result
+    );
+    let data = run.last_success().unwrap();
+    assert_eq!(data["counts"]["registered"], 0);
+    assert_eq!(data["counts"]["skipped"], 2);
+    assert_eq!(result_of(&data, "mobile-game")["status"], "skipped");
+    assert_eq!(db_get_templates(env.db()).unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_p1_template_scan_rejects_embedded_template_names() {
+    let (env, root) = setup_shared_templates().await;
+    write_template(&root.join("Default"));
+
+    let run = env.run(&scan(&root, false), ScriptedInput::new()).await;
+
+    assert!(run.result.is_ok(), "Scan should succeed: {:?}", run.result);
+    let data = run.last_success().unwrap();
+    assert_eq!(result_of(&data, "Default")["status"], "invalid");
+}
+
+#[tokio::test]
+async fn test_p1_template_scan_rejects_missing_directory() {
+    let (env, root) = setup_shared_templates().await;
+
+    let run = env
+        .run(&scan(&root.join("missing"), false), ScriptedInput::new())
+        .await;
+
+    assert_eq!(
+        run.error_code(),
+        Some(am::common::errors::codes::ERR_INVALID_TEMPLATE_STRUCTURE)
+    );
+}
+
+#[tokio::test]
+async fn test_p0_template_scan_prunes_missing_directories() {
+    let (env, root) = setup_shared_templates().await;
+    let first = env.run(&scan(&root, false), ScriptedInput::new()).await;
+    assert!(first.result.is_ok());
+    fs::remove_dir_all(root.join("mobile-game")).unwrap();
+
+    let run = env.run(&scan(&root, true), ScriptedInput::new()).await;
+
+    assert!(run.result.is_ok(), "Scan should succeed: {:?}", run.result);
+    let data = run.last_success().unwrap();
+    assert_eq!(data["counts"]["pruned"], 1);
+    assert_eq!(result_of(&data, "mobile-game")["status"], "pruned");
+    assert!(
+        db_get_template_by_name("mobile_game", env.db())
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        db_get_template_by_name("studio_game", env.db())
+            .unwrap()
+            .is_some()
+    );
+}
+
+#[tokio::test]
+async fn test_p1_template_scan_keeps_missing_directories_without_prune() {
+    let (env, root) = setup_shared_templates().await;
+    let first = env.run(&scan(&root, false), ScriptedInput::new()).await;
+    assert!(first.result.is_ok());
+    fs::remove_dir_all(root.join("mobile-game")).unwrap();
+
+    let run = env.run(&scan(&root, false), ScriptedInput::new()).await;
+
+    assert!(run.result.is_ok());
+    assert!(
+        db_get_template_by_name("mobile_game", env.db())
+            .unwrap()
+            .is_some()
+    );
+}
+
+#[tokio::test]
+async fn test_p1_template_scan_interactive_shows_result_table() {
+    let (env, root) = setup_shared_templates().await;
+
+    let run = env
+        .run_with_mode(
+            &scan(&root, false),
+            ScriptedInput::new(),
+            am::presentation::OutputMode::Interactive,
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "Scan should succeed: {:?}", run.result);
+    let table = run
+        .calls
+        .iter()
+        .find_map(|call| match call {
+            OutputCall::Table { title, data, .. } => Some((title.clone(), data.clone())),
+            _ => None,
+        })
+        .expect("Should render a table");
+    assert_eq!(table.0.as_deref(), Some("Template scan"));
+    assert_eq!(table.1.as_array().unwrap().len(), 3);
+}
@@ -0,0 +1,166 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the parsing of `.amenv` files.
+
+use am::common::env_file::{AMENV_FILE, EnvFile, is_secret_key};
+use tempfile::tempdir;
+
+fn values(file: &EnvFile) -> Vec<(&str, &str)> {
+    file.pairs().collect()
+}
+
+// =============================================================================
+// Parsing Tests
+// =============================================================================
+
+#[test]
+fn test_p0_parses_key_value_lines_and_skips_comments() {
+    let file = EnvFile::parse("# Asset server\n\nURL=https://assets.local\n  PLATFORM = pc  \n");
+
+    assert_eq!(
+        values(&file),
+        vec![("URL", "https://assets.local"), ("PLATFORM", "pc")]
+    );
+    assert!(file.issues.is_empty());
+    assert_eq!(file.vars[1].line, 4);
+}
+
+#[test]
+fn test_p0_quoting_rules() {
+    let file = EnvFile::parse(concat!(
+        "SINGLE='literal \\n $HOME # kept'\n",
+        "DOUBLE=\"tab\\there \\\"quoted\\\" # kept\"\n",
+        "UNQUOTED=value # comment\n",
+        "HASH=color#1\n",
+        "EMPTY=\n",
+        "TRAILING=\"value\"   # comment\n",
+    ));
+
+    assert_eq!(
+        values(&file),
+        vec![
+            ("SINGLE", "literal \\n $HOME # kept"),
+            ("DOUBLE", "tab\there \"quoted\" # kept"),
+            ("UNQUOTED", "value"),
+            ("HASH", "color#1"),
+            ("EMPTY", ""),
+            ("TRAILING", "value"),
+        ]
+    );
+    assert!(file.issues.is_empty());
+}
+
+#[test]
+fn test_p0_export_prefix_is_accepted() {
+    let file = EnvFile::parse("export URL=https://assets.local\nexport\tMODE='ci'\nexport_DIR=x\n");
+
+    assert_eq!(
+        values(&file),
+        vec![
+            ("URL", "https://assets.local"),
+            ("MODE", "ci"),
+            ("export_DIR", "x")
+        ]
+    );
+}
+
+#[test]
+fn test_p0_crlf_line_endings_are_accepted() {
+    let file = EnvFile::parse("URL=https://assets.local\r\nMODE=\"ci\"\r\n# comment\r\nLAST=1\r");
+
+    assert_eq!(
+        values(&file),
+        vec![
+            ("URL", "https://assets.local"),
+            ("MODE", "ci"),
+            ("LAST", "1")
+        ]
+    );
+    assert!(file.issues.is_empty());
+}
+
+#[test]
+fn test_p1_last_definition_wins() {
+    let file = EnvFile::parse("MODE=debug\nURL=x\nMODE=release\n");
+
+    assert_eq!(values(&file), vec![("URL", "x"), ("MODE", "release")]);
+    assert_eq!(file.vars[1].line, 3);
+}
+
+#[test]
+fn test_p0_malformed_lines_are_reported_with_their_number() {
+    let file = EnvFile::parse(concat!(
+        "GOOD=1\n",
+        "no equals sign\n",
+        "1BAD=2\n",
+        "BAD KEY=3\n",
+        "OPEN=\"unterminated\n",
+        "OPEN2='unterminated\n",
+        "JUNK=\"value\" trailing\n",
+    ));
+
+    assert_eq!(values(&file), vec![("GOOD", "1")]);
+    let lines: Vec<usize> = file.issues.iter().map(|issue| issue.line).collect();
+    assert_eq!(lines, vec![2, 3, 4, 5, 6, 7]);
+    assert_eq!(
+        file.issues[0].to_string(),
+        format!(
+            "{} line 2: expected KEY=VALUE, found 'no equals sign'",
+            AMENV_FILE
+        )
+    );
+}
+
+// =============================================================================
+// Loading Tests
+// =============================================================================
+
+#[test]
+fn test_p1_load_without_file_is_empty() {
+    let dir = tempdir().unwrap();
+
+    assert_eq!(EnvFile::load(dir.path()).unwrap(), EnvFile::default());
+}
+
+#[test]
+fn test_p1_load_reads_the_project_file() {
+    let dir = tempdir().unwrap();
+    std::fs::write(dir.path().join(AMENV_FILE), "MODE=ci\n").unwrap();
+
+    assert_eq!(
+        values(&EnvFile::load(dir.path()).unwrap()),
+        vec![("MODE", "ci")]
+    );
+}
+
+// =============================================================================
+// Secret Key Tests
+// =============================================================================
+
+#[test]
+fn test_p1_secret_keys_are_detected_by_their_parts() {
+    for key in [
+        "API_KEY",
+        "ASSET_SERVER_TOKEN",
+        "db_password",
+        "AUTH",
+        "PRIVATE_KEY_PATH",
+    ] {
+        assert!(is_secret_key(key), "{}", key);
+    }
+    for key in ["PLATFORM", "KEYBOARD_LAYOUT", "MONKEY", "ASSET_SERVER_URL"] {
+        assert!(!is_secret_key(key), "{}", key);
+    }
+}
@@ -0,0 +1,328 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the file associations of `am sudo register-file-types`: the changes planned
+//! on each platform, and the command driving a backend.
+
+use am::commands::sudo::register_file_types;
+use am::common::errors::{CliError, codes};
+use am::common::file_types::{
+    AssociationChange, FILE_TYPES, FileAssociations, FileType, LinuxAssociations,
+    UnsupportedAssociations, WindowsAssociations,
+};
+use am::presentation::OutputMode;
+use am::testing::{MockOutput, OutputCall};
+use anyhow::{Result, anyhow};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// A backend planning fixed changes and recording the ones applied.
+struct MockAssociations {
+    changes: Option<Vec<AssociationChange>>,
+    failing: Option<AssociationChange>,
+    applied: Mutex<Vec<AssociationChange>>,
+}
+
+impl MockAssociations {
+    fn new(changes: Vec<AssociationChange>) -> Self {
+        Self {
+            changes: Some(changes),
+            failing: None,
+            applied: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn failing_on(mut self, change: AssociationChange) -> Self {
+        self.failing = Some(change);
+        self
+    }
+
+    fn applied(&self) -> Vec<AssociationChange> {
+        self.applied.lock().unwrap().clone()
+    }
+}
+
+impl FileAssociations for MockAssociations {
+    fn platform(&self) -> &'static str {
+        "mock"
+    }
+
+    fn plan(
+        &self,
+        _exe: &Path,
+        _types: &[FileType],
+        _unregister: bool,
+    ) -> Option<Vec<AssociationChange>> {
+        self.changes.clone()
+    }
+
+    fn apply(&self, change: &AssociationChange) -> Result<()> {
+        if self.failing.as_ref() == Some(change) {
+            return Err(anyhow!("mock failure"));
+        }
+        self.applied.lock().unwrap().push(change.clone());
+        Ok(())
+    }
+}
+
+fn write_change() -> AssociationChange {
+    AssociationChange::WriteFile {
+        path: PathBuf::from("/data/applications/amplitude-cli-amproject.desktop"),
+        contents: "[Desktop Entry]\n".to_string(),
+    }
+}
+
+fn refresh_change() -> AssociationChange {
+    AssociationChange::RunCommand {
+        program: "update-desktop-database".to_string(),
+        args: vec!["/data/applications".to_string()],
+    }
+}
+
+fn last_success(output: &MockOutput) -> serde_json::Value {
+    output
+        .calls()
+        .into_iter()
+        .rev()
+        .find_map(|call| match call {
+            OutputCall::Success(data) => Some(data),
+            _ => None,
+        })
+        .expect("Should report success")
+}
+
+fn warnings(output: &MockOutput) -> Vec<String> {
+    output
+        .calls()
+        .into_iter()
+        .filter_map(|call| match call {
+            OutputCall::Warning(message) => Some(message),
+            _ => None,
+        })
+        .collect()
+}
+
+// =============================================================================
+// Platform Plan Tests
+// =============================================================================
+
+#[test]
+fn test_p0_windows_plan_opens_amproject_with_project_info() {
+    let changes = WindowsAssociations
+        .plan(Path::new(r"C:\Tools\am.exe"), FILE_TYPES, false)
+        .unwrap();
+
+    assert!(changes.contains(&AssociationChange::SetRegistryValue {
+        key: r"Software\Classes\.amproject".to_string(),
+        value: "amplitude-cli-amproject".to_string(),
+    }));
+    assert!(changes.contains(&AssociationChange::SetRegistryValue {
+        key: r"Software\Classes\amplitude-cli-amproject\shell\open\command".to_string(),
+        value: r#""C:\Tools\am.exe" project info --path "%1""#.to_string(),
+    }));
+}
+
+#[test]
+fn test_p1_windows_unregister_deletes_every_key() {
+    let changes = WindowsAssociations
+        .plan(Path::new(r"C:\Tools\am.exe"), FILE_TYPES, true)
+        .unwrap();
+
+    assert_eq!(changes.len(), FILE_TYPES.len() * 2);
+    assert!(
+        changes
+            .iter()
+            .all(|c| matches!(c, AssociationChange::DeleteRegistryKey { .. }))
+    );
+    assert!(changes.contains(&AssociationChange::DeleteRegistryKey {
+        key: r"Software\Classes\.amtpl".to_string(),
+    }));
+}
+
+#[test]
+fn test_p0_linux_plan_writes_mime_package_and_desktop_entries() {
+    let backend = LinuxAssociations {
+        data_home: PathBuf::from("/home/dev/.local/share"),
+    };
+    let changes = backend
+        .plan(Path::new("/usr/bin/am"), FILE_TYPES, false)
+        .unwrap();
+
+    let written: Vec<(&PathBuf, &String)> = changes
+        .iter()
+        .filter_map(|c| match c {
+            AssociationChange::WriteFile { path, contents } => Some((path, contents)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(written.len(), 1 + FILE_TYPES.len());
+
+    let (_, mime) = written
+        .iter()
+        .find(|(path, _)| path.ends_with("mime/packages/amplitude-cli.xml"))
+        .expect("Should write the MIME package");
+    assert!(mime.contains(r#"<mime-type type="application/x-amplitude-project">"#));
+    assert!(mime.contains(r#"<glob pattern="*.amtpl"/>"#));
+
+    let (_, desktop) = written
+        .iter()
+        .find(|(path, _)| path.ends_with("applications/amplitude-cli-amproject.desktop"))
+        .expect("Should write the .amproject desktop entry");
+    assert!(desktop.contains("Exec=\"/usr/bin/am\" project info --path %f\n"));
+    assert!(desktop.contains("MimeType=application/x-amplitude-project;\n"));
+
+    assert!(changes.contains(&AssociationChange::RunCommand {
+        program: "xdg-mime".to_string(),
+        args: vec![
+            "default".to_string(),
+            "amplitude-cli-amproject.desktop".to_string(),
+            "application/x-amplitude-project".to_string(),
+        ],
+    }));
+}
+
+#[test]
+fn test_p1_linux_unregister_removes_written_files() {
+    let backend = LinuxAssociations {
+        data_home: PathBuf::from("/data"),
+    };
+    let registered: Vec<PathBuf> = backend
+        .plan(Path::new("/usr/bin/am"), FILE_TYPES, false)
+        .unwrap()
+        .into_iter()
+        .filter_map(|c| match c {
+            AssociationChange::WriteFile { path, .. } => Some(path),
+            _ => None,
+        })
+        .collect();
+    let removed: Vec<PathBuf> = backend
+        .plan(Path::new("/usr/bin/am"), FILE_TYPES, true)
+        .unwrap()
+        .into_iter()
+        .filter_map(|c| match c {
+            AssociationChange::RemoveFile { path } => Some(path),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(registered, removed);
+}
+
+#[test]
+fn test_p1_linux_apply_writes_and_removes_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let backend = LinuxAssociations {
+        data_home: dir.path().to_path_buf(),
+    };
+    let path = dir.path().join("applications").join("entry.desktop");
+
+    backend
+        .apply(&AssociationChange::WriteFile {
+            path: path.clone(),
+            contents: "[Desktop Entry]\n".to_string(),
+        })
+        .unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "[Desktop Entry]\n");
+
+    let remove = AssociationChange::RemoveFile { path: path.clone() };
+    backend.apply(&remove).unwrap();
+    assert!(!path.exists());
+    // Removing a missing file is already the wanted state
+    backend.apply(&remove).unwrap();
+}
+
+#[test]
+fn test_p2_unsupported_platform_plans_nothing() {
+    let backend = UnsupportedAssociations { platform: "macos" };
+    assert!(backend.plan(Path::new("am"), FILE_TYPES, false).is_none());
+}
+
+// =============================================================================
+// Command Tests
+// =============================================================================
+
+#[test]
+fn test_p0_register_applies_every_change() {
+    let backend = MockAssociations::new(vec![write_change(), refresh_change()]);
+    let output = MockOutput::new(OutputMode::Json);
+
+    register_file_types(&backend, Path::new("am"), false, false, &output).unwrap();
+
+    assert_eq!(backend.applied(), vec![write_change(), refresh_change()]);
+    let data = last_success(&output);
+    assert_eq!(data["platform"], "mock");
+    assert_eq!(data["action"], "register");
+    assert_eq!(data["supported"], true);
+    assert_eq!(data["changes"][0]["action"], "write_file");
+    assert_eq!(data["changes"][1]["program"], "update-desktop-database");
+}
+
+#[test]
+fn test_p0_dry_run_applies_nothing() {
+    let backend = MockAssociations::new(vec![write_change()]);
+    let output = MockOutput::new(OutputMode::Json);
+
+    register_file_types(&backend, Path::new("am"), true, true, &output).unwrap();
+
+    assert!(backend.applied().is_empty());
+    let data = last_success(&output);
+    assert_eq!(data["dry_run"], true);
+    assert_eq!(data["action"], "unregister");
+    assert_eq!(data["changes"][0]["contents"], "[Desktop Entry]\n");
+}
+
+#[test]
+fn test_p1_failed_cache_refresh_only_warns() {
+    let backend =
+        MockAssociations::new(vec![write_change(), refresh_change()]).failing_on(refresh_change());
+    let output = MockOutput::new(OutputMode::Json);
+
+    register_file_types(&backend, Path::new("am"), false, false, &output).unwrap();
+
+    assert_eq!(backend.applied(), vec![write_change()]);
+    let warnings = warnings(&output);
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("update-desktop-database"));
+}
+
+#[test]
+fn test_p1_failed_write_stops_registration() {
+    let backend =
+        MockAssociations::new(vec![write_change(), refresh_change()]).failing_on(write_change());
+    let output = MockOutput::new(OutputMode::Json);
+
+    let err = register_file_types(&backend, Path::new("am"), false, false, &output).unwrap_err();
+
+    assert_eq!(
+        err.downcast_ref::<CliError>().map(|e| e.code),
+        Some(codes::ERR_BUILD_IO)
+    );
+    assert!(backend.applied().is_empty());
+}
+
+#[test]
+fn test_p1_unsupported_platform_reports_skip() {
+    let backend = MockAssociations {
+        changes: None,
+        failing: None,
+        applied: Mutex::new(Vec::new()),
+    };
+    let output = MockOutput::new(OutputMode::Json);
+
+    register_file_types(&backend, Path::new("am"), false, false, &output).unwrap();
+
+    let data = last_success(&output);
+    assert_eq!(data["supported"], false);
+    assert!(data["message"].as_str().unwrap().contains("not supported"));
+}
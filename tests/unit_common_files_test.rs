@@ -0,0 +1,210 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the guarded deletions of `safe_remove_within` and `safe_remove_in_parent`.
+//!
+//! Every hostile path must be refused with a validation error while leaving both the
+//! target and whatever it points to untouched.
+
+use am::common::errors::{CliError, codes};
+use am::common::files::{safe_remove_in_parent, safe_remove_within};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::tempdir;
+
+/// A `project` root next to an `outside` directory, each holding a file.
+fn setup_tree(base: &Path) -> (PathBuf, PathBuf) {
+    let root = base.join("project");
+    let outside = base.join("outside");
+    fs::create_dir_all(root.join("build")).unwrap();
+    fs::create_dir_all(&outside).unwrap();
+    fs::write(root.join("build").join("bank.ambank"), "bank").unwrap();
+    fs::write(outside.join("precious.txt"), "precious").unwrap();
+    (root, outside)
+}
+
+fn assert_refused(result: anyhow::Result<()>) {
+    let error = result.expect_err("Deletion should be refused");
+    let error = error
+        .downcast_ref::<CliError>()
+        .expect("Expected a CliError");
+    assert_eq!(error.code, codes::ERR_VALIDATION_FIELD);
+}
+
+// =============================================================================
+// Allowed Deletions
+// =============================================================================
+
+#[test]
+fn test_p0_safe_remove_within_deletes_a_directory_inside_root() {
+    let dir = tempdir().unwrap();
+    let (root, outside) = setup_tree(dir.path());
+
+    safe_remove_within(&root, &root.join("build")).unwrap();
+
+    assert!(!root.join("build").exists());
+    assert!(root.exists());
+    assert!(outside.join("precious.txt").exists());
+}
+
+#[test]
+fn test_p0_safe_remove_within_deletes_a_file_inside_root() {
+    let dir = tempdir().unwrap();
+    let (root, _) = setup_tree(dir.path());
+    let file = root.join("build").join("bank.ambank");
+
+    safe_remove_within(&root, &file).unwrap();
+
+    assert!(!file.exists());
+    assert!(root.join("build").exists());
+}
+
+#[test]
+fn test_p1_safe_remove_within_accepts_dot_dot_staying_inside_root() {
+    let dir = tempdir().unwrap();
+    let (root, _) = setup_tree(dir.path());
+
+    safe_remove_within(&root, &root.join("build").join("..").join("build")).unwrap();
+
+    assert!(!root.join("build").exists());
+}
+
+#[test]
+fn test_p1_safe_remove_in_parent_deletes_the_target() {
+    let dir = tempdir().unwrap();
+    let (root, _) = setup_tree(dir.path());
+
+    safe_remove_in_parent(&root).unwrap();
+
+    assert!(!root.exists());
+}
+
+// =============================================================================
+// Hostile Paths
+// =============================================================================
+
+#[test]
+fn test_p0_safe_remove_within_refuses_dot_dot_escape() {
+    let dir = tempdir().unwrap();
+    let (root, outside) = setup_tree(dir.path());
+
+    assert_refused(safe_remove_within(
+        &root,
+        &root.join("build").join("..").join("..").join("outside"),
+    ));
+
+    assert!(outside.join("precious.txt").exists());
+}
+
+#[test]
+fn test_p0_safe_remove_within_refuses_absolute_path_outside_root() {
+    let dir = tempdir().unwrap();
+    let (root, outside) = setup_tree(dir.path());
+
+    assert_refused(safe_remove_within(&root, &outside));
+
+    assert!(outside.join("precious.txt").exists());
+}
+
+#[test]
+fn test_p0_safe_remove_within_refuses_root_itself() {
+    let dir = tempdir().unwrap();
+    let (root, _) = setup_tree(dir.path());
+
+    assert_refused(safe_remove_within(&root, &root));
+    assert_refused(safe_remove_within(&root, &root.join(".")));
+    assert_refused(safe_remove_within(&root, &root.join("build").join("..")));
+
+    assert!(root.join("build").join("bank.ambank").exists());
+}
+
+#[test]
+fn test_p0_safe_remove_in_parent_refuses_dot_dot_target() {
+    let dir = tempdir().unwrap();
+    let (root, outside) = setup_tree(dir.path());
+
+    assert_refused(safe_remove_in_parent(&root.join("build").join("..")));
+    assert_refused(safe_remove_in_parent(&root.join("..")));
+
+    assert!(root.join("build").join("bank.ambank").exists());
+    assert!(outside.join("precious.txt").exists());
+}
+
+#[test]
+fn test_p1_safe_remove_within_fails_on_missing_target() {
+    let dir = tempdir().unwrap();
+    let (root, _) = setup_tree(dir.path());
+
+    let error = safe_remove_within(&root, &root.join("missing")).unwrap_err();
+
+    assert!(error.downcast_ref::<CliError>().is_none());
+    assert!(root.exists());
+}
+
+#[cfg(unix)]
+mod symlinks {
+    use super::*;
+    use std::os::unix::fs::symlink;
+
+    #[test]
+    fn test_p0_safe_remove_within_refuses_symlink_out_of_root() {
+        let dir = tempdir().unwrap();
+        let (root, outside) = setup_tree(dir.path());
+        let link = root.join("linked_build");
+        symlink(&outside, &link).unwrap();
+
+        assert_refused(safe_remove_within(&root, &link));
+
+        assert!(fs::symlink_metadata(&link).is_ok());
+        assert!(outside.join("precious.txt").exists());
+    }
+
+    #[test]
+    fn test_p0_safe_remove_within_refuses_symlink_inside_root() {
+        let dir = tempdir().unwrap();
+        let (root, _) = setup_tree(dir.path());
+        let link = root.join("linked_build");
+        symlink(root.join("build"), &link).unwrap();
+
+        assert_refused(safe_remove_within(&root, &link));
+
+        assert!(root.join("build").join("bank.ambank").exists());
+    }
+
+    #[test]
+    fn test_p0_safe_remove_within_refuses_path_through_symlinked_directory() {
+        let dir = tempdir().unwrap();
+        let (root, outside) = setup_tree(dir.path());
+        symlink(&outside, root.join("escape")).unwrap();
+
+        assert_refused(safe_remove_within(
+            &root,
+            &root.join("escape").join("precious.txt"),
+        ));
+
+        assert!(outside.join("precious.txt").exists());
+    }
+
+    #[test]
+    fn test_p1_safe_remove_in_parent_refuses_symlinked_project() {
+        let dir = tempdir().unwrap();
+        let (_, outside) = setup_tree(dir.path());
+        let link = dir.path().join("linked_project");
+        symlink(&outside, &link).unwrap();
+
+        assert_refused(safe_remove_in_parent(&link));
+
+        assert!(outside.join("precious.txt").exists());
+    }
+}
@@ -0,0 +1,198 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the formatting helpers of interactive output.
+
+use am::common::format::{
+    ELLIPSIS, HUMAN_TIME_FORMAT, humanize_time, humanize_timestamp, parse_timestamp, truncate_path,
+    utc_timestamp,
+};
+use chrono::{DateTime, Duration, Local, TimeZone, Utc};
+
+fn width(value: &str) -> usize {
+    value.chars().count()
+}
+
+// =============================================================================
+// truncate_path Tests
+// =============================================================================
+
+#[test]
+fn test_p0_short_paths_are_unchanged() {
+    assert_eq!(truncate_path("/home/u/game", 40), "/home/u/game");
+    assert_eq!(truncate_path("/home/u/game", 12), "/home/u/game");
+}
+
+#[test]
+fn test_p0_long_paths_keep_the_root_and_final_components() {
+    let path = "/home/u/work/studio/clients/acme/projects/mygame";
+
+    let truncated = truncate_path(path, 30);
+
+    assert_eq!(truncated, "/home/u/…/acme/projects/mygame");
+    assert!(width(&truncated) <= 30);
+}
+
+#[test]
+fn test_p0_truncation_never_exceeds_the_maximum() {
+    let path = "/home/user/some/deeply/nested/directory/structure/of/projects/game";
+
+    for max in 0..=width(path) {
+        let truncated = truncate_path(path, max);
+        assert!(
+            width(&truncated) <= max,
+            "{:?} is wider than {}",
+            truncated,
+            max
+        );
+    }
+}
+
+#[test]
+fn test_p0_multi_byte_characters_are_never_split() {
+    let path = "/home/ユーザー/プロジェクト/音楽/ゲーム/サウンド";
+
+    for max in 1..=width(path) {
+        // Slicing a String at a non-char boundary panics, so a result built from
+        // characters is enough to prove no codepoint is split.
+        let truncated = truncate_path(path, max);
+        assert!(width(&truncated) <= max, "{:?} for {}", truncated, max);
+        assert!(truncated.ends_with(|c: char| c == 'ド' || c == ELLIPSIS));
+    }
+    assert_eq!(truncate_path(path, 16), "/…/音楽/ゲーム/サウンド");
+}
+
+#[test]
+fn test_p1_windows_drive_prefix_is_kept() {
+    let path = r"C:\Users\me\Documents\Amplitude\projects\game";
+
+    assert_eq!(truncate_path(path, 18), r"C:\…\projects\game");
+    assert_eq!(truncate_path(path, 24), r"C:\Users\…\projects\game");
+}
+
+#[test]
+fn test_p1_unc_prefix_is_kept() {
+    let path = r"\\server\share\team\audio\projects\game";
+
+    assert_eq!(truncate_path(path, 20), r"\\…\projects\game");
+}
+
+#[test]
+fn test_p1_last_component_too_long_keeps_its_end() {
+    let truncated = truncate_path("/projects/a_really_long_project_name", 12);
+
+    assert_eq!(truncated, "…roject_name");
+}
+
+#[test]
+fn test_p2_relative_paths_and_plain_names() {
+    assert_eq!(
+        truncate_path("sounds/ambience/forest/birds.json", 20),
+        "…/forest/birds.json"
+    );
+    assert_eq!(truncate_path("abcdefghij", 5), "…ghij");
+    assert_eq!(truncate_path("abcdefghij", 0), "");
+}
+
+// =============================================================================
+// Timestamp Tests
+// =============================================================================
+
+fn now() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, 10, 12, 0, 0).unwrap()
+}
+
+#[test]
+fn test_p0_utc_timestamp_is_rfc3339_to_the_second() {
+    let time = Utc.with_ymd_and_hms(2026, 3, 4, 5, 6, 7).unwrap() + Duration::milliseconds(89);
+
+    assert_eq!(utc_timestamp(time), "2026-03-04T05:06:07Z");
+}
+
+#[test]
+fn test_p0_parse_timestamp_accepts_stored_forms() {
+    let expected = Utc.with_ymd_and_hms(2026, 3, 4, 5, 6, 7).unwrap();
+
+    assert_eq!(parse_timestamp("2026-03-04T05:06:07Z"), Some(expected));
+    assert_eq!(parse_timestamp("2026-03-04T06:06:07+01:00"), Some(expected));
+    assert_eq!(parse_timestamp("2026-03-04 05:06:07"), Some(expected));
+    assert_eq!(
+        parse_timestamp("2026-03-04"),
+        Some(Utc.with_ymd_and_hms(2026, 3, 4, 0, 0, 0).unwrap())
+    );
+    assert_eq!(parse_timestamp("yesterday"), None);
+}
+
+#[test]
+fn test_p0_humanize_time_is_relative_under_a_week() {
+    let now = now();
+
+    assert_eq!(humanize_time(now - Duration::seconds(30), now), "just now");
+    assert_eq!(
+        humanize_time(now - Duration::minutes(1), now),
+        "1 minute ago"
+    );
+    assert_eq!(
+        humanize_time(now - Duration::minutes(5), now),
+        "5 minutes ago"
+    );
+    assert_eq!(humanize_time(now - Duration::hours(1), now), "1 hour ago");
+    assert_eq!(
+        humanize_time(now - Duration::hours(23), now),
+        "23 hours ago"
+    );
+    assert_eq!(humanize_time(now - Duration::days(1), now), "1 day ago");
+    assert_eq!(humanize_time(now - Duration::days(3), now), "3 days ago");
+    assert_eq!(
+        humanize_time(now - Duration::days(6) - Duration::hours(23), now),
+        "6 days ago"
+    );
+}
+
+#[test]
+fn test_p0_humanize_time_shows_older_times_in_local_time() {
+    let now = now();
+    let time = now - Duration::days(7);
+
+    assert_eq!(
+        humanize_time(time, now),
+        time.with_timezone(&Local)
+            .format(HUMAN_TIME_FORMAT)
+            .to_string()
+    );
+}
+
+#[test]
+fn test_p1_humanize_time_shows_future_times_in_local_time() {
+    let now = now();
+    let time = now + Duration::hours(2);
+
+    assert_eq!(
+        humanize_time(time, now),
+        time.with_timezone(&Local)
+            .format(HUMAN_TIME_FORMAT)
+            .to_string()
+    );
+}
+
+#[test]
+fn test_p1_humanize_timestamp_keeps_unparsable_values() {
+    let now = now();
+
+    assert_eq!(
+        humanize_timestamp("2026-03-08T12:00:00Z", now),
+        "2 days ago"
+    );
+    assert_eq!(humanize_timestamp("not a date", now), "not a date");
+}
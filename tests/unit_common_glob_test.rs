@@ -0,0 +1,55 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for glob matching of names.
+
+use am::common::glob::glob_match;
+
+// =============================================================================
+// Wildcard Tests
+// =============================================================================
+
+#[test]
+fn test_p0_star_matches_any_suffix() {
+    assert!(glob_match("test_*", "test_"));
+    assert!(glob_match("test_*", "test_reverb"));
+    assert!(!glob_match("test_*", "my_test_reverb"));
+}
+
+#[test]
+fn test_p0_whole_name_must_match() {
+    assert!(glob_match("game", "game"));
+    assert!(!glob_match("game", "game_old"));
+    assert!(glob_match("*_old", "game_old"));
+}
+
+#[test]
+fn test_p1_question_mark_matches_one_character() {
+    assert!(glob_match("test_?", "test_1"));
+    assert!(!glob_match("test_?", "test_12"));
+    assert!(!glob_match("test_?", "test_"));
+}
+
+// =============================================================================
+// Character Class Tests
+// =============================================================================
+
+#[test]
+fn test_p1_character_classes_and_ranges() {
+    assert!(glob_match("test_[ab]", "test_a"));
+    assert!(!glob_match("test_[ab]", "test_c"));
+    assert!(glob_match("v[0-9]", "v7"));
+    assert!(glob_match("v[!0-9]", "vx"));
+    assert!(!glob_match("v[^0-9]", "v7"));
+}
@@ -0,0 +1,138 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the file digests and copy verification of `--verify`.
+
+use am::common::errors::codes;
+use am::common::hashing::{
+    CopyVerifier, VerifyReport, copy_file_hashed, sha256_bytes, sha256_file,
+};
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+// =============================================================================
+// Digest Tests
+// =============================================================================
+
+#[test]
+fn test_p0_sha256_of_file_matches_sha256_of_its_content() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("hit.json");
+    let content = vec![7u8; 200_000];
+    fs::write(&path, &content).unwrap();
+
+    assert_eq!(sha256_file(&path).unwrap(), sha256_bytes(&content));
+    assert_eq!(
+        sha256_bytes(b""),
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+}
+
+#[test]
+fn test_p0_copy_file_hashed_returns_the_source_digest() {
+    let dir = tempdir().unwrap();
+    let source = dir.path().join("source.wav");
+    let destination = dir.path().join("destination.wav");
+    fs::write(&source, b"audio").unwrap();
+
+    let (bytes, hash) = copy_file_hashed(&source, &destination).unwrap();
+
+    assert_eq!(bytes, 5);
+    assert_eq!(hash, sha256_bytes(b"audio"));
+    assert_eq!(fs::read(&destination).unwrap(), b"audio");
+}
+
+// =============================================================================
+// CopyVerifier Tests
+// =============================================================================
+
+#[test]
+fn test_p0_verifier_counts_the_bytes_of_matching_copies() {
+    let dir = tempdir().unwrap();
+    let verifier = CopyVerifier::start();
+    for name in ["a.json", "b.json"] {
+        let source = dir.path().join(name);
+        fs::write(&source, name).unwrap();
+        let destination = dir.path().join(format!("copy_{}", name));
+        let (bytes, hash) = copy_file_hashed(&source, &destination).unwrap();
+        verifier.check(name, destination, hash, bytes);
+    }
+
+    let report = verifier.finish();
+
+    assert_eq!(report.files, 2);
+    assert_eq!(report.bytes, 12);
+    assert!(report.mismatches.is_empty());
+}
+
+#[test]
+fn test_p0_verifier_reports_truncated_and_missing_copies() {
+    let dir = tempdir().unwrap();
+    let truncated = dir.path().join("truncated.wav");
+    fs::write(&truncated, b"aud").unwrap();
+
+    let verifier = CopyVerifier::start();
+    verifier.check("truncated.wav", &truncated, sha256_bytes(b"audio"), 5);
+    verifier.check(
+        "missing.wav",
+        dir.path().join("missing.wav"),
+        sha256_bytes(b"x"),
+        1,
+    );
+    let report = verifier.finish();
+
+    assert_eq!(report.files, 2);
+    assert_eq!(report.bytes, 0);
+    assert_eq!(report.mismatches, ["truncated.wav", "missing.wav"]);
+}
+
+// =============================================================================
+// VerifyReport Tests
+// =============================================================================
+
+#[test]
+fn test_p0_report_with_mismatches_lists_them_in_the_error() {
+    let report = VerifyReport {
+        files: 3,
+        bytes: 10,
+        mismatches: vec!["sources/hit.json".to_string(), "data/hit.wav".to_string()],
+    };
+
+    let error = report.into_result(Path::new("/projects/copy")).unwrap_err();
+
+    assert_eq!(error.code, codes::ERR_IO_CHECKSUM_MISMATCH);
+    assert_eq!(error.what, "2 of 3 copied file(s) don't match their source");
+    assert!(
+        error.why.contains("sources/hit.json, data/hit.wav"),
+        "{}",
+        error.why
+    );
+    assert_eq!(error.context.as_deref(), Some("/projects/copy"));
+}
+
+#[test]
+fn test_p1_report_without_mismatches_is_ok() {
+    let report = VerifyReport {
+        files: 1,
+        bytes: 4,
+        mismatches: vec![],
+    };
+
+    let report = report.into_result(Path::new("/projects/copy")).unwrap();
+    assert_eq!(
+        report.to_json(),
+        serde_json::json!({ "files": 1, "bytes": 4 })
+    );
+}
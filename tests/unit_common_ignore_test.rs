@@ -0,0 +1,341 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for `.amignore` pattern matching and the shared project walker.
+
+use am::common::ignore::{
+    AMIGNORE_FILE, DEFAULT_MAX_WALK_DEPTH, IgnoreRules, SkipReason, SkippedPath, WalkOptions,
+    list_project_files, walk_project_files,
+};
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+fn rules(patterns: &[&str]) -> IgnoreRules {
+    IgnoreRules::from_patterns(Path::new("/project"), patterns.iter().copied())
+}
+
+// =============================================================================
+// Pattern Matching Tests
+// =============================================================================
+
+#[test]
+fn test_p0_unanchored_pattern_matches_at_any_depth() {
+    let rules = rules(&["*.bak"]);
+
+    assert!(rules.is_ignored(Path::new("/project/a.bak"), false));
+    assert!(rules.is_ignored(Path::new("/project/sources/sounds/a.bak"), false));
+    assert!(!rules.is_ignored(Path::new("/project/sources/sounds/a.json"), false));
+}
+
+#[test]
+fn test_p0_anchored_pattern_matches_from_project_root() {
+    let rules = rules(&["/sources/sounds/draft.json", "data/*.wav"]);
+
+    assert!(rules.is_ignored(Path::new("/project/sources/sounds/draft.json"), false));
+    assert!(!rules.is_ignored(Path::new("/project/other/sources/sounds/draft.json"), false));
+    assert!(rules.is_ignored(Path::new("/project/data/loop.wav"), false));
+    assert!(!rules.is_ignored(Path::new("/project/data/music/loop.wav"), false));
+}
+
+#[test]
+fn test_p0_directory_pattern_only_matches_directories_and_their_contents() {
+    let rules = rules(&["drafts/"]);
+
+    assert!(rules.is_ignored(Path::new("/project/sources/sounds/drafts"), true));
+    assert!(!rules.is_ignored(Path::new("/project/sources/sounds/drafts"), false));
+    assert!(rules.is_ignored(Path::new("/project/sources/sounds/drafts/a.json"), false));
+}
+
+#[test]
+fn test_p0_last_matching_pattern_wins() {
+    let rules = rules(&["*.json", "!keep.json", "sources/**/keep.json"]);
+
+    assert!(rules.is_ignored(Path::new("/project/a.json"), false));
+    assert!(!rules.is_ignored(Path::new("/project/keep.json"), false));
+    assert!(rules.is_ignored(Path::new("/project/sources/sounds/keep.json"), false));
+}
+
+#[test]
+fn test_p1_negation_cannot_reinclude_file_in_ignored_directory() {
+    let rules = rules(&["drafts/", "!drafts/keep.json"]);
+
+    assert!(rules.is_ignored(Path::new("/project/drafts/keep.json"), false));
+}
+
+#[test]
+fn test_p1_double_star_matches_any_number_of_directories() {
+    let rules = rules(&["sources/**/old_*.json"]);
+
+    assert!(rules.is_ignored(Path::new("/project/sources/old_a.json"), false));
+    assert!(rules.is_ignored(Path::new("/project/sources/sounds/fx/old_b.json"), false));
+    assert!(!rules.is_ignored(Path::new("/project/data/old_c.json"), false));
+}
+
+#[test]
+fn test_p1_character_classes_and_question_mark() {
+    let rules = rules(&["take[0-9].json", "v?.json", "[!a]*.tmp.json"]);
+
+    assert!(rules.is_ignored(Path::new("/project/take3.json"), false));
+    assert!(!rules.is_ignored(Path::new("/project/takeX.json"), false));
+    assert!(rules.is_ignored(Path::new("/project/v2.json"), false));
+    assert!(!rules.is_ignored(Path::new("/project/v10.json"), false));
+    assert!(rules.is_ignored(Path::new("/project/b.tmp.json"), false));
+    assert!(!rules.is_ignored(Path::new("/project/a.tmp.json"), false));
+}
+
+#[test]
+fn test_p1_comments_and_blank_lines_are_skipped() {
+    let rules = rules(&["# *.json", "", "   "]);
+
+    assert!(!rules.is_ignored(Path::new("/project/a.json"), false));
+}
+
+#[test]
+fn test_p1_relative_paths_are_resolved_against_root() {
+    let rules = rules(&["/build/"]);
+
+    assert!(rules.is_ignored(Path::new("build/out.amsound"), false));
+    assert!(!rules.is_ignored(Path::new("sources/build/out.json"), false));
+}
+
+// =============================================================================
+// Loading Tests
+// =============================================================================
+
+#[test]
+fn test_p0_load_applies_builtin_defaults_without_amignore() {
+    let dir = tempdir().unwrap();
+    let rules = IgnoreRules::load(dir.path()).unwrap();
+
+    assert!(rules.is_ignored(&dir.path().join(".DS_Store"), false));
+    assert!(rules.is_ignored(&dir.path().join("sources/Thumbs.db"), false));
+    assert!(rules.is_ignored(&dir.path().join("sources/sounds/a.json.bak"), false));
+    assert!(!rules.is_ignored(&dir.path().join("sources/sounds/a.json"), false));
+}
+
+#[test]
+fn test_p0_amignore_can_disable_builtin_defaults() {
+    let dir = tempdir().unwrap();
+    fs::write(dir.path().join(AMIGNORE_FILE), "!*.bak\nnotes/\n").unwrap();
+    let rules = IgnoreRules::load(dir.path()).unwrap();
+
+    assert!(!rules.is_ignored(&dir.path().join("a.bak"), false));
+    assert!(rules.is_ignored(&dir.path().join(".DS_Store"), false));
+    assert!(rules.is_ignored(&dir.path().join("notes/todo.json"), false));
+}
+
+#[test]
+fn test_p1_none_ignores_nothing() {
+    assert!(!IgnoreRules::none().is_ignored(Path::new(".DS_Store"), false));
+}
+
+// =============================================================================
+// Walker Tests
+// =============================================================================
+
+#[test]
+fn test_p0_walk_project_files_skips_ignored_files_and_directories() {
+    let dir = tempdir().unwrap();
+    let sounds = dir.path().join("sources/sounds");
+    fs::create_dir_all(sounds.join("fx")).unwrap();
+    fs::create_dir_all(sounds.join("drafts")).unwrap();
+    fs::write(sounds.join("a.json"), "{}").unwrap();
+    fs::write(sounds.join("a.json.bak"), "{}").unwrap();
+    fs::write(sounds.join(".DS_Store"), "").unwrap();
+    fs::write(sounds.join("fx/b.json"), "{}").unwrap();
+    fs::write(sounds.join("drafts/c.json"), "{}").unwrap();
+    fs::write(dir.path().join(AMIGNORE_FILE), "drafts/\n").unwrap();
+
+    let rules = IgnoreRules::load(dir.path()).unwrap();
+    let walk = walk_project_files(&sounds, &rules, WalkOptions::default());
+
+    assert_eq!(
+        walk.files,
+        vec![sounds.join("a.json"), sounds.join("fx/b.json")]
+    );
+}
+
+#[test]
+fn test_p1_list_project_files_is_not_recursive() {
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("nested")).unwrap();
+    fs::write(dir.path().join("a.json"), "{}").unwrap();
+    fs::write(dir.path().join("nested/b.json"), "{}").unwrap();
+
+    let files = list_project_files(dir.path(), &IgnoreRules::none());
+
+    assert_eq!(files, vec![dir.path().join("a.json")]);
+}
+
+#[test]
+fn test_p1_walk_project_files_returns_empty_for_missing_directory() {
+    let dir = tempdir().unwrap();
+
+    let walk = walk_project_files(
+        &dir.path().join("missing"),
+        &IgnoreRules::none(),
+        WalkOptions::default(),
+    );
+
+    assert_eq!(walk, Default::default());
+}
+
+// =============================================================================
+// Walker Safety Tests
+// =============================================================================
+
+/// A sounds directory with a nested directory holding a symlink back to the sounds
+/// directory.
+#[cfg(unix)]
+fn symlink_loop_fixture(root: &Path) -> std::path::PathBuf {
+    let sounds = root.join("sources/sounds");
+    fs::create_dir_all(sounds.join("nested")).unwrap();
+    fs::write(sounds.join("a.json"), "{}").unwrap();
+    fs::write(sounds.join("nested/b.json"), "{}").unwrap();
+    std::os::unix::fs::symlink(&sounds, sounds.join("nested/loop")).unwrap();
+    sounds
+}
+
+#[cfg(unix)]
+#[test]
+fn test_p0_symlink_loop_terminates_with_a_cycle_warning() {
+    let dir = tempdir().unwrap();
+    let sounds = symlink_loop_fixture(dir.path());
+    let options = WalkOptions {
+        follow_symlinks: true,
+        ..WalkOptions::default()
+    };
+
+    let walk = walk_project_files(&sounds, &IgnoreRules::none(), options);
+
+    assert_eq!(
+        walk.files,
+        vec![sounds.join("a.json"), sounds.join("nested/b.json")]
+    );
+    assert_eq!(
+        walk.skipped,
+        vec![SkippedPath {
+            path: sounds.join("nested/loop"),
+            reason: SkipReason::Cycle,
+        }]
+    );
+    assert!(walk.skipped[0].to_string().contains("symlink cycle"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_p0_symlinked_directories_are_not_followed_by_default() {
+    let dir = tempdir().unwrap();
+    let sounds = symlink_loop_fixture(dir.path());
+    let outside = dir.path().join("outside");
+    fs::create_dir_all(&outside).unwrap();
+    fs::write(outside.join("c.json"), "{}").unwrap();
+    std::os::unix::fs::symlink(&outside, sounds.join("linked")).unwrap();
+
+    let walk = walk_project_files(&sounds, &IgnoreRules::none(), WalkOptions::default());
+
+    assert_eq!(
+        walk.files,
+        vec![sounds.join("a.json"), sounds.join("nested/b.json")]
+    );
+    assert!(walk.skipped.is_empty());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_p1_directory_linked_twice_is_walked_once() {
+    let dir = tempdir().unwrap();
+    let sounds = dir.path().join("sounds");
+    let shared = dir.path().join("shared");
+    fs::create_dir_all(&sounds).unwrap();
+    fs::create_dir_all(&shared).unwrap();
+    fs::write(shared.join("c.json"), "{}").unwrap();
+    std::os::unix::fs::symlink(&shared, sounds.join("first")).unwrap();
+    std::os::unix::fs::symlink(&shared, sounds.join("second")).unwrap();
+    let options = WalkOptions {
+        follow_symlinks: true,
+        ..WalkOptions::default()
+    };
+
+    let walk = walk_project_files(&sounds, &IgnoreRules::none(), options);
+
+    assert_eq!(walk.files, vec![sounds.join("first/c.json")]);
+    assert_eq!(walk.skipped.len(), 1);
+    assert_eq!(walk.skipped[0].path, sounds.join("second"));
+}
+
+#[test]
+fn test_p0_max_depth_skips_deeper_directories_with_a_warning() {
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("a/b/c")).unwrap();
+    fs::write(dir.path().join("a/top.json"), "{}").unwrap();
+    fs::write(dir.path().join("a/b/c/deep.json"), "{}").unwrap();
+    let options = WalkOptions {
+        follow_symlinks: false,
+        max_depth: 2,
+    };
+
+    let walk = walk_project_files(dir.path(), &IgnoreRules::none(), options);
+
+    assert_eq!(walk.files, vec![dir.path().join("a/top.json")]);
+    assert_eq!(
+        walk.skipped,
+        vec![SkippedPath {
+            path: dir.path().join("a/b"),
+            reason: SkipReason::MaxDepth,
+        }]
+    );
+    assert!(walk.skipped[0].to_string().contains("--max-depth"));
+}
+
+#[test]
+fn test_p1_walk_project_files_returns_skipped_directories() {
+    let dir = tempdir().unwrap();
+    let mut deep = dir.path().to_path_buf();
+    for _ in 0..=DEFAULT_MAX_WALK_DEPTH {
+        deep.push("d");
+    }
+    fs::create_dir_all(&deep).unwrap();
+    fs::write(deep.join("deep.json"), "{}").unwrap();
+
+    let first = walk_project_files(dir.path(), &IgnoreRules::none(), WalkOptions::default());
+    // Nothing is carried over from one walk to the next.
+    let second = walk_project_files(dir.path(), &IgnoreRules::none(), WalkOptions::default());
+
+    assert!(first.files.is_empty());
+    assert_eq!(first.skipped.len(), 1);
+    assert_eq!(first.skipped[0].reason, SkipReason::MaxDepth);
+    assert_eq!(second, first);
+}
+
+#[test]
+fn test_p1_walk_flags_are_global_and_validated() {
+    use am::app::App;
+    use clap::Parser;
+
+    let app = App::try_parse_from([
+        "am",
+        "project",
+        "validate",
+        "--follow-symlinks",
+        "--max-depth",
+        "4",
+    ])
+    .unwrap();
+    assert!(app.follow_symlinks);
+    assert_eq!(app.max_depth, Some(4));
+
+    assert!(App::try_parse_from(["am", "project", "validate", "--max-depth", "0"]).is_err());
+}
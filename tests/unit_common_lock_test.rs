@@ -0,0 +1,250 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the advisory project lock.
+
+use am::app::App;
+use am::common::errors::{CliError, codes};
+use am::common::lock::{LOCK_FILE, LockHolder, ProjectLock};
+use am::presentation::OutputMode;
+use am::testing::{MockOutput, OutputCall};
+use clap::Parser;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+use tempfile::tempdir;
+
+const SHORT_TIMEOUT: Duration = Duration::from_millis(300);
+
+fn read_holder(project_root: &Path) -> LockHolder {
+    let content = fs::read_to_string(project_root.join(LOCK_FILE)).unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+/// PID of a process that has already exited.
+fn dead_pid() -> u32 {
+    let mut child = std::process::Command::new(std::env::current_exe().unwrap())
+        .arg("--list")
+        .stdout(std::process::Stdio::null())
+        .spawn()
+        .unwrap();
+    let pid = child.id();
+    child.wait().unwrap();
+    pid
+}
+
+// =============================================================================
+// Acquire / Release Tests
+// =============================================================================
+
+#[test]
+fn test_p0_acquire_writes_holder_and_drop_releases() {
+    let dir = tempdir().unwrap();
+    let output = MockOutput::new(OutputMode::Json);
+
+    let lock = ProjectLock::acquire(
+        dir.path(),
+        "am asset sound create a",
+        SHORT_TIMEOUT,
+        &output,
+    )
+    .unwrap();
+
+    let holder = read_holder(dir.path());
+    assert_eq!(holder.pid, std::process::id());
+    assert_eq!(holder.command, "am asset sound create a");
+
+    drop(lock);
+    assert!(!dir.path().join(LOCK_FILE).exists());
+}
+
+#[test]
+fn test_p1_drop_keeps_lock_taken_over_by_another_holder() {
+    let dir = tempdir().unwrap();
+    let output = MockOutput::new(OutputMode::Json);
+    let lock =
+        ProjectLock::acquire(dir.path(), "am project build", SHORT_TIMEOUT, &output).unwrap();
+
+    let other = LockHolder {
+        pid: std::process::id(),
+        command: "am asset event update e".to_string(),
+        acquired_at: "2026-01-01T00:00:00+00:00".to_string(),
+    };
+    fs::write(
+        dir.path().join(LOCK_FILE),
+        serde_json::to_string(&other).unwrap(),
+    )
+    .unwrap();
+
+    drop(lock);
+    assert_eq!(read_holder(dir.path()), other);
+}
+
+// =============================================================================
+// Contention Tests
+// =============================================================================
+
+#[test]
+fn test_p0_contention_times_out_naming_the_holder() {
+    let dir = tempdir().unwrap();
+    let output = MockOutput::new(OutputMode::Json);
+    let _held =
+        ProjectLock::acquire(dir.path(), "am project build", SHORT_TIMEOUT, &output).unwrap();
+
+    let waiter = MockOutput::new(OutputMode::Json);
+    let err = ProjectLock::acquire(
+        dir.path(),
+        "am asset sound update a",
+        SHORT_TIMEOUT,
+        &waiter,
+    )
+    .unwrap_err();
+
+    let cli_err = err.downcast_ref::<CliError>().expect("Expected CliError");
+    assert_eq!(cli_err.code, codes::ERR_PROJECT_LOCKED);
+    assert!(cli_err.why.contains("am project build"), "{}", cli_err.why);
+    assert!(
+        cli_err.why.contains(&std::process::id().to_string()),
+        "{}",
+        cli_err.why
+    );
+    assert!(
+        waiter.calls().iter().any(
+            |c| matches!(c, OutputCall::Progress(m) if m.contains("Waiting for 'am project build'"))
+        ),
+        "Expected a waiting progress message"
+    );
+}
+
+#[test]
+fn test_p0_waiter_acquires_once_holder_releases() {
+    let dir = tempdir().unwrap();
+    let root = dir.path().to_path_buf();
+    let output = MockOutput::new(OutputMode::Json);
+    let held = ProjectLock::acquire(&root, "am project build", SHORT_TIMEOUT, &output).unwrap();
+
+    let releaser = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(200));
+        drop(held);
+    });
+
+    let waiter = MockOutput::new(OutputMode::Json);
+    let lock = ProjectLock::acquire(
+        dir.path(),
+        "am asset sound update a",
+        Duration::from_secs(10),
+        &waiter,
+    );
+    releaser.join().unwrap();
+
+    assert!(lock.is_ok(), "Waiter should get the lock: {:?}", lock.err());
+    assert_eq!(read_holder(dir.path()).command, "am asset sound update a");
+}
+
+// =============================================================================
+// Stale Lock Tests
+// =============================================================================
+
+#[test]
+fn test_p0_lock_of_dead_process_is_reclaimed() {
+    let dir = tempdir().unwrap();
+    let stale = LockHolder {
+        pid: dead_pid(),
+        command: "am asset sound create crashed".to_string(),
+        acquired_at: "2026-01-01T00:00:00+00:00".to_string(),
+    };
+    fs::write(
+        dir.path().join(LOCK_FILE),
+        serde_json::to_string(&stale).unwrap(),
+    )
+    .unwrap();
+
+    let output = MockOutput::new(OutputMode::Json);
+    let lock = ProjectLock::acquire(dir.path(), "am project build", SHORT_TIMEOUT, &output);
+
+    assert!(
+        lock.is_ok(),
+        "Stale lock should be reclaimed: {:?}",
+        lock.err()
+    );
+    assert_eq!(read_holder(dir.path()).pid, std::process::id());
+}
+
+#[test]
+fn test_p1_old_unreadable_lock_is_reclaimed() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join(LOCK_FILE);
+    fs::write(&path, "").unwrap();
+    fs::File::options()
+        .write(true)
+        .open(&path)
+        .unwrap()
+        .set_modified(SystemTime::now() - Duration::from_secs(60))
+        .unwrap();
+
+    let output = MockOutput::new(OutputMode::Json);
+    let lock = ProjectLock::acquire(dir.path(), "am project build", SHORT_TIMEOUT, &output);
+
+    assert!(
+        lock.is_ok(),
+        "Abandoned lock should be reclaimed: {:?}",
+        lock.err()
+    );
+}
+
+#[test]
+fn test_p1_fresh_unreadable_lock_is_respected() {
+    let dir = tempdir().unwrap();
+    fs::write(dir.path().join(LOCK_FILE), "").unwrap();
+
+    let output = MockOutput::new(OutputMode::Json);
+    let err =
+        ProjectLock::acquire(dir.path(), "am project build", SHORT_TIMEOUT, &output).unwrap_err();
+
+    let cli_err = err.downcast_ref::<CliError>().expect("Expected CliError");
+    assert_eq!(cli_err.code, codes::ERR_PROJECT_LOCKED);
+}
+
+// =============================================================================
+// Command Classification Tests
+// =============================================================================
+
+#[test]
+fn test_p0_only_mutating_commands_take_the_lock() {
+    let mutates = |args: &[&str]| {
+        App::try_parse_from(std::iter::once("am").chain(args.iter().copied()))
+            .unwrap()
+            .command
+            .mutates_project_files()
+    };
+
+    assert!(mutates(&["asset", "sound", "create", "a"]));
+    assert!(mutates(&["asset", "event", "delete", "e"]));
+    assert!(mutates(&["project", "build"]));
+
+    assert!(!mutates(&["asset", "sound", "list"]));
+    assert!(!mutates(&["asset", "soundbank", "list"]));
+    assert!(!mutates(&["project", "validate"]));
+    assert!(!mutates(&["project", "info"]));
+    assert!(!mutates(&["template", "list"]));
+}
+
+#[test]
+fn test_p1_no_lock_flag_is_global() {
+    let app = App::try_parse_from(["am", "asset", "sound", "create", "a", "--no-lock"]).unwrap();
+    assert!(app.no_lock);
+
+    let app = App::try_parse_from(["am", "project", "build"]).unwrap();
+    assert!(!app.no_lock);
+}
@@ -0,0 +1,172 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for reading and following log files.
+
+use am::common::log_tail::{LogFollower, latest_log_file, log_line_to_json};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+use tempfile::tempdir;
+
+fn append(path: &Path, content: &str) {
+    let mut file = OpenOptions::new().append(true).open(path).unwrap();
+    file.write_all(content.as_bytes()).unwrap();
+}
+
+// =============================================================================
+// Follower Tests
+// =============================================================================
+
+#[test]
+fn test_p0_follower_reads_existing_lines_first() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("am.log");
+    fs::write(&path, "first\nsecond\n").unwrap();
+
+    let mut follower = LogFollower::new(&path).unwrap();
+
+    assert_eq!(follower.read_new_lines().unwrap(), ["first", "second"]);
+    assert!(follower.read_new_lines().unwrap().is_empty());
+}
+
+#[test]
+fn test_p0_follower_picks_up_lines_written_by_another_thread() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("am.log");
+    fs::write(&path, "existing\n").unwrap();
+    let mut follower = LogFollower::new(&path).unwrap();
+    follower.read_new_lines().unwrap();
+
+    let writer_path = path.clone();
+    let writer = thread::spawn(move || {
+        for i in 0..5 {
+            append(&writer_path, &format!("line {}\n", i));
+            thread::sleep(Duration::from_millis(20));
+        }
+    });
+
+    let mut lines = Vec::new();
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while lines.len() < 5 && Instant::now() < deadline {
+        lines.extend(follower.read_new_lines().unwrap());
+        thread::sleep(Duration::from_millis(10));
+    }
+    writer.join().unwrap();
+
+    assert_eq!(lines, ["line 0", "line 1", "line 2", "line 3", "line 4"]);
+}
+
+#[test]
+fn test_p1_follower_waits_for_the_end_of_partial_lines() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("am.log");
+    fs::write(&path, "").unwrap();
+    let mut follower = LogFollower::new(&path).unwrap();
+
+    append(&path, "half a ");
+    assert!(follower.read_new_lines().unwrap().is_empty());
+
+    append(&path, "line\nnext");
+    assert_eq!(follower.read_new_lines().unwrap(), ["half a line"]);
+}
+
+#[test]
+fn test_p1_follower_restarts_after_truncation() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("am.log");
+    fs::write(&path, "an old line\n").unwrap();
+    let mut follower = LogFollower::new(&path).unwrap();
+    follower.read_new_lines().unwrap();
+
+    fs::write(&path, "new\n").unwrap();
+
+    assert_eq!(follower.read_new_lines().unwrap(), ["new"]);
+}
+
+#[test]
+fn test_p2_follower_fails_on_a_missing_file() {
+    let dir = tempdir().unwrap();
+
+    assert!(LogFollower::new(&dir.path().join("missing.log")).is_err());
+}
+
+// =============================================================================
+// Log File Tests
+// =============================================================================
+
+#[test]
+fn test_p1_latest_log_file_picks_the_most_recent_log() {
+    let dir = tempdir().unwrap();
+    fs::write(dir.path().join("20260101_120000.000.log"), "old").unwrap();
+    thread::sleep(Duration::from_millis(50));
+    fs::write(dir.path().join("20260102_120000.000.log"), "new").unwrap();
+    fs::write(dir.path().join("am.db"), "not a log").unwrap();
+
+    assert_eq!(
+        latest_log_file(dir.path()).unwrap(),
+        Some(dir.path().join("20260102_120000.000.log"))
+    );
+}
+
+#[test]
+fn test_p2_latest_log_file_without_logs() {
+    let dir = tempdir().unwrap();
+
+    assert_eq!(latest_log_file(dir.path()).unwrap(), None);
+    assert_eq!(latest_log_file(&dir.path().join("missing")).unwrap(), None);
+}
+
+// =============================================================================
+// JSON Tests
+// =============================================================================
+
+#[test]
+fn test_p0_log_line_to_json_reuses_the_log_entry_fields() {
+    let value = log_line_to_json("[2026-03-04T05:06:07.089Z] [WARN] [am::commands] Slow disk");
+
+    assert_eq!(value["level"], "WARN");
+    assert_eq!(value["target"], "am::commands");
+    assert_eq!(value["message"], "Slow disk");
+    assert_eq!(value["timestamp"], "2026-03-04T05:06:07.089Z");
+}
+
+#[test]
+fn test_p1_log_line_to_json_converts_legacy_local_lines_to_utc() {
+    let value = log_line_to_json("[2026-03-04 05:06:07.089] [WARN] [am::commands] Slow disk");
+
+    let expected = chrono::NaiveDate::from_ymd_opt(2026, 3, 4)
+        .unwrap()
+        .and_hms_milli_opt(5, 6, 7, 89)
+        .unwrap()
+        .and_local_timezone(chrono::Local)
+        .earliest()
+        .unwrap()
+        .with_timezone(&chrono::Utc)
+        .to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+    assert_eq!(value["timestamp"], expected);
+    assert_eq!(value["message"], "Slow disk");
+}
+
+#[test]
+fn test_p1_log_line_to_json_keeps_other_lines_as_messages() {
+    let value = log_line_to_json("=== AMPLITUDE CLI CRASH LOG ===");
+
+    assert_eq!(
+        value,
+        serde_json::json!({ "message": "=== AMPLITUDE CLI CRASH LOG ===" })
+    );
+}
@@ -93,6 +93,48 @@ fn test_p1_log_entry_format_for_file_includes_all_fields() {
     assert!(formatted.ends_with('\n'), "Should end with newline");
 }
 
+#[test]
+fn test_p0_log_entry_parse_file_line_round_trips() {
+    let entry = LogEntry::new_success("my_target".to_string(), "done [ok]".to_string());
+    let line = entry.format_for_file();
+
+    let parsed = LogEntry::parse_file_line(line.trim_end()).expect("Should parse");
+
+    assert_eq!(parsed.format_for_file(), line);
+    assert_eq!(parsed.to_json()["level"], "SUCCESS");
+    assert_eq!(parsed.to_json()["message"], "done [ok]");
+}
+
+#[test]
+fn test_p0_log_entry_format_for_file_uses_utc_rfc3339() {
+    let entry = LogEntry::new_success("module".to_string(), "message".to_string());
+    let line = entry.format_for_file();
+
+    let timestamp = line
+        .strip_prefix('[')
+        .and_then(|rest| rest.split_once(']'))
+        .map(|(timestamp, _)| timestamp)
+        .expect("Should start with a timestamp");
+    assert!(
+        chrono::DateTime::parse_from_rfc3339(timestamp).is_ok(),
+        "Not RFC 3339: {}",
+        timestamp
+    );
+    assert!(timestamp.ends_with('Z'), "Not UTC: {}", timestamp);
+}
+
+#[test]
+fn test_p1_log_entry_parse_file_line_rejects_other_lines() {
+    for line in [
+        "=== AMPLITUDE CLI CRASH LOG ===",
+        "[not a date] [INFO] [am] message",
+        "[2026-03-04 05:06:07.089] [LOUD] [am] message",
+        "  at src/main.rs:42",
+    ] {
+        assert!(LogEntry::parse_file_line(line).is_none(), "{}", line);
+    }
+}
+
 // =============================================================================
 // Logger Static Methods Tests
 // =============================================================================
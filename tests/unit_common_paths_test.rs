@@ -0,0 +1,140 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the resolution of the Amplitude home directories.
+
+use am::common::errors::codes;
+use am::common::paths::{HomeEnv, HomeLayout, resolve_from};
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+fn linux_env(home: &Path) -> HomeEnv {
+    HomeEnv {
+        home: Some(home.to_path_buf()),
+        use_xdg: true,
+        ..Default::default()
+    }
+}
+
+// =============================================================================
+// Resolution Tests
+// =============================================================================
+
+#[test]
+fn test_p0_am_home_takes_precedence() {
+    let home = tempdir().unwrap();
+    fs::create_dir_all(home.path().join(".amplitude")).unwrap();
+    let env = HomeEnv {
+        am_home: Some(home.path().join("custom")),
+        ..linux_env(home.path())
+    };
+
+    let paths = resolve_from(&env).unwrap();
+
+    assert_eq!(paths.layout, HomeLayout::AmHome);
+    assert_eq!(paths.data_dir, home.path().join("custom"));
+    assert_eq!(paths.state_dir, home.path().join("custom"));
+    assert_eq!(
+        paths.database_path(),
+        home.path().join("custom").join("am.db")
+    );
+}
+
+#[test]
+fn test_p0_existing_legacy_directory_is_kept() {
+    let home = tempdir().unwrap();
+    fs::create_dir_all(home.path().join(".amplitude")).unwrap();
+
+    let paths = resolve_from(&linux_env(home.path())).unwrap();
+
+    assert_eq!(paths.layout, HomeLayout::Legacy);
+    assert_eq!(paths.data_dir, home.path().join(".amplitude"));
+    assert_eq!(paths.state_dir, home.path().join(".amplitude"));
+}
+
+#[test]
+fn test_p0_xdg_defaults_without_legacy_directory() {
+    let home = tempdir().unwrap();
+
+    let paths = resolve_from(&linux_env(home.path())).unwrap();
+
+    assert_eq!(paths.layout, HomeLayout::Xdg);
+    assert_eq!(
+        paths.data_dir,
+        home.path().join(".local/share").join("amplitude")
+    );
+    assert_eq!(
+        paths.state_dir,
+        home.path().join(".local/state").join("amplitude")
+    );
+}
+
+#[test]
+fn test_p0_xdg_variables_are_honored() {
+    let home = tempdir().unwrap();
+    let xdg = tempdir().unwrap();
+    let env = HomeEnv {
+        xdg_data_home: Some(xdg.path().join("data")),
+        xdg_state_home: Some(xdg.path().join("state")),
+        ..linux_env(home.path())
+    };
+
+    let paths = resolve_from(&env).unwrap();
+
+    assert_eq!(paths.data_dir, xdg.path().join("data").join("amplitude"));
+    assert_eq!(paths.state_dir, xdg.path().join("state").join("amplitude"));
+    assert_eq!(
+        paths.database_path(),
+        xdg.path().join("data").join("amplitude").join("am.db")
+    );
+}
+
+#[test]
+fn test_p1_relative_xdg_variables_are_ignored() {
+    let home = tempdir().unwrap();
+    let env = HomeEnv {
+        xdg_data_home: Some("relative/data".into()),
+        ..linux_env(home.path())
+    };
+
+    let paths = resolve_from(&env).unwrap();
+
+    assert_eq!(
+        paths.data_dir,
+        home.path().join(".local/share").join("amplitude")
+    );
+}
+
+#[test]
+fn test_p1_legacy_layout_without_xdg() {
+    let home = tempdir().unwrap();
+    let env = HomeEnv {
+        use_xdg: false,
+        ..linux_env(home.path())
+    };
+
+    let paths = resolve_from(&env).unwrap();
+
+    assert_eq!(paths.layout, HomeLayout::Legacy);
+    assert_eq!(paths.data_dir, home.path().join(".amplitude"));
+}
+
+#[test]
+fn test_p2_missing_home_directory_fails() {
+    let error = resolve_from(&HomeEnv::default()).unwrap_err();
+
+    assert_eq!(error.code, codes::ERR_HOME_NOT_FOUND);
+    assert!(error.suggestion.contains("AM_HOME"));
+}
@@ -0,0 +1,212 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the resolution of the effective settings.
+
+use am::app::App;
+use am::commands::sudo::{ConfigCommands, SudoCommands};
+use am::common::settings::{
+    CONFIG_COLOR, CONFIG_SDK_PATH, CONFIG_VERBOSITY, SETTINGS, SettingSource, Settings,
+};
+use am::common::update::{CONFIG_AUTO_UPDATE, DEFAULT_UPDATE_CHECK_URL};
+use am::database::db_set_config;
+use am::presentation::OutputMode;
+use am::testing::{OutputCall, ScriptedInput, TestEnv};
+use clap::Parser;
+use std::collections::{BTreeMap, HashMap};
+
+/// Resolve the settings from the given flags, environment variables and configuration.
+fn resolve(flags: &[(&str, &str)], env: &[(&str, &str)], config: &[(&str, &str)]) -> Settings {
+    let flags: BTreeMap<&str, String> = flags.iter().map(|(k, v)| (*k, v.to_string())).collect();
+    let env: HashMap<String, String> = env
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    let config: BTreeMap<String, String> = config
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+
+    Settings::resolve(&flags, |name| env.get(name).cloned(), &config)
+}
+
+/// Check the value and source of `key` for each combination of a flag, an environment
+/// variable and a configuration value being set.
+fn check_precedence(key: &str, env_var: &str, default: Option<&str>) {
+    for mask in 0..8 {
+        let flag = mask & 1 != 0;
+        let env = mask & 2 != 0;
+        let config = mask & 4 != 0;
+
+        let settings = resolve(
+            if flag { &[(key, "from-flag")] } else { &[] },
+            if env { &[(env_var, "from-env")] } else { &[] },
+            if config { &[(key, "from-config")] } else { &[] },
+        );
+
+        let (value, source) = if flag {
+            (Some("from-flag"), SettingSource::Flag)
+        } else if env {
+            (Some("from-env"), SettingSource::Env)
+        } else if config {
+            (Some("from-config"), SettingSource::Config)
+        } else {
+            (default, SettingSource::Default)
+        };
+        assert_eq!(
+            settings.get(key),
+            value,
+            "{} (flag: {}, env: {}, config: {})",
+            key,
+            flag,
+            env,
+            config
+        );
+        assert_eq!(settings.source(key), Some(source), "{}", key);
+    }
+}
+
+// =============================================================================
+// Precedence Tests
+// =============================================================================
+
+#[test]
+fn test_p0_verbosity_precedence() {
+    check_precedence(CONFIG_VERBOSITY, "AM_VERBOSITY", Some("normal"));
+}
+
+#[test]
+fn test_p0_auto_update_precedence() {
+    check_precedence(CONFIG_AUTO_UPDATE, "AM_AUTO_UPDATE", Some("true"));
+}
+
+#[test]
+fn test_p0_color_precedence() {
+    check_precedence(CONFIG_COLOR, "AM_COLOR", Some("auto"));
+}
+
+#[test]
+fn test_p0_sdk_path_precedence() {
+    check_precedence(CONFIG_SDK_PATH, "AM_SDK_PATH", None);
+}
+
+#[test]
+fn test_p1_empty_env_var_is_ignored() {
+    let settings = resolve(&[], &[("AM_COLOR", "")], &[(CONFIG_COLOR, "never")]);
+
+    assert_eq!(settings.color(), "never");
+    assert_eq!(settings.source(CONFIG_COLOR), Some(SettingSource::Config));
+}
+
+#[test]
+fn test_p1_every_setting_is_resolved_in_order() {
+    let settings = resolve(&[], &[], &[("unrelated", "value")]);
+
+    let keys: Vec<&str> = settings.all().iter().map(|s| s.key).collect();
+    let expected: Vec<&str> = SETTINGS.iter().map(|def| def.key).collect();
+    assert_eq!(keys, expected);
+    assert_eq!(settings.get("unrelated"), None);
+    assert_eq!(
+        settings.get("update_check_url"),
+        Some(DEFAULT_UPDATE_CHECK_URL)
+    );
+}
+
+// =============================================================================
+// Getter Tests
+// =============================================================================
+
+#[test]
+fn test_p1_typed_getters() {
+    let settings = resolve(
+        &[(CONFIG_VERBOSITY, "quiet")],
+        &[("AM_SDK_PATH", "/opt/amplitude")],
+        &[(CONFIG_AUTO_UPDATE, "FALSE"), (CONFIG_COLOR, "always")],
+    );
+
+    assert_eq!(settings.verbosity(), "quiet");
+    assert_eq!(settings.sdk_path(), Some("/opt/amplitude"));
+    assert_eq!(settings.color(), "always");
+    assert!(!settings.auto_update());
+    assert!(resolve(&[], &[], &[]).auto_update());
+}
+
+#[test]
+fn test_p1_global_flags_set_settings() {
+    let app = App::try_parse_from(["am", "--quiet", "--no-update-check", "sdk", "check"]).unwrap();
+    let settings = Settings::resolve(&app.setting_flags(), |_| None, &BTreeMap::new());
+
+    assert_eq!(settings.verbosity(), "quiet");
+    assert!(!settings.auto_update());
+    assert_eq!(settings.source(CONFIG_VERBOSITY), Some(SettingSource::Flag));
+    assert_eq!(settings.source(CONFIG_COLOR), Some(SettingSource::Default));
+}
+
+// =============================================================================
+// Config List Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_config_list_effective_shows_sources() {
+    let env = TestEnv::new().await.unwrap();
+    db_set_config(CONFIG_COLOR, "never", "string", env.db()).unwrap();
+
+    let run = env
+        .run_with_mode(
+            &SudoCommands::Config {
+                command: ConfigCommands::List { effective: true },
+            },
+            ScriptedInput::new(),
+            OutputMode::Interactive,
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "{:?}", run.result);
+    let Some(OutputCall::Table { columns, data, .. }) = run.calls.last() else {
+        panic!("Expected a table: {:?}", run.calls);
+    };
+    assert_eq!(columns, &["key", "value", "source"]);
+    let color = data
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|row| row["key"] == CONFIG_COLOR)
+        .expect("The color setting should be listed");
+    assert_eq!(color["value"], "never");
+    assert_eq!(color["source"], "config");
+}
+
+#[tokio::test]
+async fn test_p1_config_list_shows_the_configuration_table() {
+    let env = TestEnv::new().await.unwrap();
+    db_set_config(CONFIG_VERBOSITY, "verbose", "string", env.db()).unwrap();
+
+    let run = env
+        .run(
+            &SudoCommands::Config {
+                command: ConfigCommands::List { effective: false },
+            },
+            ScriptedInput::new(),
+        )
+        .await;
+
+    assert!(run.result.is_ok(), "{:?}", run.result);
+    let tables = run.tables();
+    let rows = tables[0].as_array().unwrap();
+    assert!(
+        rows.iter()
+            .any(|row| row["key"] == CONFIG_VERBOSITY && row["value"] == "verbose")
+    );
+    assert!(rows.iter().all(|row| row.get("source").is_none()));
+}
@@ -0,0 +1,90 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for command entries read from stdin.
+
+use am::common::errors::codes;
+use am::common::stdin::parse_json_entries;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+struct Entry {
+    path: String,
+    #[serde(default)]
+    name: Option<String>,
+}
+
+fn entry(path: &str, name: Option<&str>) -> Entry {
+    Entry {
+        path: path.to_string(),
+        name: name.map(String::from),
+    }
+}
+
+// =============================================================================
+// Accepted Input Tests
+// =============================================================================
+
+#[test]
+fn test_p0_single_object_is_one_entry() {
+    let entries: Vec<Entry> = parse_json_entries(r#"{"path": "/a", "name": "a"}"#).unwrap();
+    assert_eq!(entries, vec![entry("/a", Some("a"))]);
+}
+
+#[test]
+fn test_p0_array_keeps_entry_order() {
+    let entries: Vec<Entry> =
+        parse_json_entries(r#"[{"path": "/b"}, {"path": "/a", "name": "a"}]"#).unwrap();
+    assert_eq!(entries, vec![entry("/b", None), entry("/a", Some("a"))]);
+}
+
+#[test]
+fn test_p1_empty_array_has_no_entries() {
+    let entries: Vec<Entry> = parse_json_entries(" [ ]\n").unwrap();
+    assert!(entries.is_empty());
+}
+
+// =============================================================================
+// Rejected Input Tests
+// =============================================================================
+
+#[test]
+fn test_p0_malformed_json_is_a_schema_error() {
+    let err = parse_json_entries::<Entry>(r#"{"path": "/a""#).unwrap_err();
+    assert_eq!(err.code, codes::ERR_VALIDATION_SCHEMA);
+    assert_eq!(err.what, "Invalid JSON on stdin");
+}
+
+#[test]
+fn test_p0_invalid_entry_rejects_the_whole_input() {
+    let err = parse_json_entries::<Entry>(r#"[{"path": "/a"}, {"name": "b"}]"#).unwrap_err();
+    assert_eq!(err.code, codes::ERR_VALIDATION_SCHEMA);
+    assert_eq!(err.what, "Invalid entry 1 on stdin");
+    assert!(err.why.contains("path"), "{}", err.why);
+}
+
+#[test]
+fn test_p1_unknown_field_is_rejected() {
+    let err = parse_json_entries::<Entry>(r#"{"path": "/a", "nmae": "a"}"#).unwrap_err();
+    assert_eq!(err.what, "Invalid entry on stdin");
+}
+
+#[test]
+fn test_p1_scalar_is_rejected() {
+    for content in ["\"/a\"", "42", "null", "[\"/a\"]"] {
+        let err = parse_json_entries::<Entry>(content).unwrap_err();
+        assert_eq!(err.code, codes::ERR_VALIDATION_SCHEMA, "{}", content);
+    }
+}
@@ -0,0 +1,252 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for template archives.
+
+use am::common::errors::{CliError, codes};
+use am::common::template_archive::{
+    TEMPLATE_ARCHIVE_FORMAT, TemplateArchiveManifest, check_archive_cli_version, checksum,
+    read_template_archive, verify_template_archive, write_template_archive,
+};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::tempdir;
+
+/// Create a template directory with a nested file at `parent/template`.
+fn create_template(parent: &Path) -> PathBuf {
+    let template = parent.join("template");
+    fs::create_dir_all(template.join("sources").join("sounds")).unwrap();
+    fs::write(
+        template.join(".amproject"),
+        r#"{"name":"test","version":1}"#,
+    )
+    .unwrap();
+    fs::write(template.join("test.buses.json"), "{}").unwrap();
+    fs::write(template.join("test.config.json"), "{}").unwrap();
+    fs::write(
+        template.join("sources").join("sounds").join("hit.json"),
+        r#"{"id":1}"#,
+    )
+    .unwrap();
+    template
+}
+
+fn manifest(cli_version: &str, checksum: &str) -> TemplateArchiveManifest {
+    TemplateArchiveManifest {
+        format: TEMPLATE_ARCHIVE_FORMAT,
+        name: "shared".to_string(),
+        cli_version: cli_version.to_string(),
+        checksum: checksum.to_string(),
+        engine: None,
+        description: None,
+    }
+}
+
+/// Write an archive holding `manifest` and `entries`, as (path in archive, content).
+fn write_raw_archive(path: &Path, manifest: &TemplateArchiveManifest, entries: &[(&str, &[u8])]) {
+    let file = fs::File::create(path).unwrap();
+    let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(
+        file,
+        flate2::Compression::default(),
+    ));
+    let manifest = serde_json::to_vec(manifest).unwrap();
+    let all =
+        std::iter::once(("manifest.json", manifest.as_slice())).chain(entries.iter().copied());
+    for (name, content) in all {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(0o644);
+        header.set_entry_type(tar::EntryType::Regular);
+        // `set_path` refuses `..`, write the raw name to build malicious archives.
+        header.as_old_mut().name[..name.len()].copy_from_slice(name.as_bytes());
+        header.set_cksum();
+        builder.append(&header, content).unwrap();
+    }
+    builder.into_inner().unwrap().finish().unwrap();
+}
+
+fn error_code(result: anyhow::Result<impl std::fmt::Debug>) -> Option<i32> {
+    result
+        .unwrap_err()
+        .downcast_ref::<CliError>()
+        .map(|e| e.code)
+}
+
+// =============================================================================
+// Round-Trip Tests
+// =============================================================================
+
+#[test]
+fn test_p0_archive_round_trips_template_files() {
+    let dir = tempdir().unwrap();
+    let template = create_template(dir.path());
+    let archive = dir.path().join("shared.amtpl");
+
+    let written = write_template_archive(
+        &template,
+        "shared",
+        Some("o3de"),
+        Some("Shared template"),
+        &archive,
+    )
+    .unwrap();
+    let read = read_template_archive(&archive).unwrap();
+
+    assert_eq!(read.manifest, written);
+    assert_eq!(read.manifest.cli_version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(read.manifest.engine.as_deref(), Some("o3de"));
+    assert_eq!(read.file_count(), 4);
+
+    let extracted = dir.path().join("extracted");
+    read.extract(&extracted).unwrap();
+    assert_eq!(
+        fs::read_to_string(extracted.join("sources").join("sounds").join("hit.json")).unwrap(),
+        r#"{"id":1}"#
+    );
+    assert!(extracted.join(".amproject").is_file());
+}
+
+#[test]
+fn test_p1_checksum_depends_on_paths_and_content() {
+    let files = vec![(PathBuf::from("a.json"), b"{}".to_vec())];
+    let renamed = vec![(PathBuf::from("b.json"), b"{}".to_vec())];
+    let changed = vec![(PathBuf::from("a.json"), b"[]".to_vec())];
+
+    assert_eq!(checksum(&files), checksum(&files.clone()));
+    assert_ne!(checksum(&files), checksum(&renamed));
+    assert_ne!(checksum(&files), checksum(&changed));
+}
+
+// =============================================================================
+// Verification Tests
+// =============================================================================
+
+#[test]
+fn test_p0_verify_template_archive_checks_every_template_file() {
+    let dir = tempdir().unwrap();
+    let template = create_template(dir.path());
+    let archive = dir.path().join("shared.amtpl");
+    write_template_archive(&template, "shared", None, None, &archive).unwrap();
+
+    let report = verify_template_archive(&archive, &template).unwrap();
+    assert_eq!(report.files, 4);
+    assert!(report.mismatches.is_empty());
+
+    // A template file changed since the archive was written
+    fs::write(template.join("test.buses.json"), "{\"buses\":[]}").unwrap();
+    let report = verify_template_archive(&archive, &template).unwrap();
+    assert_eq!(report.mismatches, ["test.buses.json"]);
+}
+
+#[test]
+fn test_p1_verify_template_archive_refuses_a_truncated_archive() {
+    let dir = tempdir().unwrap();
+    let template = create_template(dir.path());
+    let archive = dir.path().join("shared.amtpl");
+    write_template_archive(&template, "shared", None, None, &archive).unwrap();
+    let content = fs::read(&archive).unwrap();
+    fs::write(&archive, &content[..content.len() / 2]).unwrap();
+
+    assert_eq!(
+        error_code(verify_template_archive(&archive, &template)),
+        Some(codes::ERR_IO_CHECKSUM_MISMATCH)
+    );
+}
+
+#[test]
+fn test_p1_verify_extracted_reports_altered_files() {
+    let dir = tempdir().unwrap();
+    let template = create_template(dir.path());
+    let archive = dir.path().join("shared.amtpl");
+    write_template_archive(&template, "shared", None, None, &archive).unwrap();
+    let read = read_template_archive(&archive).unwrap();
+    let extracted = dir.path().join("extracted");
+    read.extract(&extracted).unwrap();
+
+    assert!(read.verify_extracted(&extracted).mismatches.is_empty());
+
+    fs::write(
+        extracted.join("sources").join("sounds").join("hit.json"),
+        "{",
+    )
+    .unwrap();
+    assert_eq!(
+        read.verify_extracted(&extracted).mismatches,
+        ["sources/sounds/hit.json"]
+    );
+}
+
+#[test]
+fn test_p0_archive_with_wrong_checksum_is_refused() {
+    let dir = tempdir().unwrap();
+    let archive = dir.path().join("tampered.amtpl");
+    let files = vec![(PathBuf::from("a.json"), b"{}".to_vec())];
+    write_raw_archive(
+        &archive,
+        &manifest(env!("CARGO_PKG_VERSION"), &checksum(&files)),
+        &[("template/a.json", b"{\"changed\":true}")],
+    );
+
+    assert_eq!(
+        error_code(read_template_archive(&archive)),
+        Some(codes::ERR_INVALID_TEMPLATE_STRUCTURE)
+    );
+}
+
+#[test]
+fn test_p1_archive_escaping_the_template_directory_is_refused() {
+    let dir = tempdir().unwrap();
+    let archive = dir.path().join("escaping.amtpl");
+    let files = vec![(PathBuf::from("../evil.json"), b"{}".to_vec())];
+    write_raw_archive(
+        &archive,
+        &manifest(env!("CARGO_PKG_VERSION"), &checksum(&files)),
+        &[("template/../evil.json", b"{}")],
+    );
+
+    assert_eq!(
+        error_code(read_template_archive(&archive)),
+        Some(codes::ERR_INVALID_TEMPLATE_STRUCTURE)
+    );
+    assert!(!dir.path().join("evil.json").exists());
+}
+
+#[test]
+fn test_p1_file_that_is_not_an_archive_is_refused() {
+    let dir = tempdir().unwrap();
+    let archive = dir.path().join("plain.amtpl");
+    fs::write(&archive, "not an archive").unwrap();
+
+    assert_eq!(
+        error_code(read_template_archive(&archive)),
+        Some(codes::ERR_INVALID_TEMPLATE_STRUCTURE)
+    );
+}
+
+#[test]
+fn test_p0_archive_from_newer_cli_is_refused() {
+    assert!(check_archive_cli_version(&manifest("1.2.3", ""), "1.2.3").is_ok());
+    assert!(check_archive_cli_version(&manifest("1.2.0", ""), "1.10.0").is_ok());
+    assert_eq!(
+        error_code(check_archive_cli_version(&manifest("1.3.0", ""), "1.2.9")),
+        Some(codes::ERR_TEMPLATE_OPERATION_NOT_ALLOWED)
+    );
+    assert_eq!(
+        error_code(check_archive_cli_version(
+            &manifest("not a version", ""),
+            "1.2.9"
+        )),
+        Some(codes::ERR_TEMPLATE_OPERATION_NOT_ALLOWED)
+    );
+}
@@ -0,0 +1,161 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the substitution of template variables.
+
+use am::common::errors::{CliError, codes};
+use am::common::template_vars::{
+    VARS_MANIFEST, builtin_variables, parse_var_specs, placeholders, read_vars_manifest, render,
+};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use tempfile::tempdir;
+
+fn values(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+    pairs
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+fn error_code<T>(result: &anyhow::Result<T>) -> Option<i32> {
+    result
+        .as_ref()
+        .err()
+        .and_then(|e| e.downcast_ref::<CliError>())
+        .map(|e| e.code)
+}
+
+// =============================================================================
+// Rendering Tests
+// =============================================================================
+
+#[test]
+fn test_p0_render_substitutes_placeholders() {
+    let values = values(&[("name", "game"), ("author", "Jane")]);
+
+    assert_eq!(
+        render("{{name}} by {{ author }}, {{name}}", &values),
+        "game by Jane, game"
+    );
+}
+
+#[test]
+fn test_p0_render_unescapes_braces() {
+    let values = values(&[("name", "game")]);
+
+    assert_eq!(
+        render(r"\{{name}} is {{name}}", &values),
+        "{{name}} is game"
+    );
+    assert_eq!(render(r"\{\{name}}", &values), "{{name}}");
+}
+
+#[test]
+fn test_p1_render_leaves_other_braces_untouched() {
+    let values = values(&[("name", "game")]);
+
+    for text in [
+        r#"{"nested": {"id": 1}}"#,
+        "{{not a name}}",
+        "{{unknown}}",
+        "{{name",
+        r"C:\path\{x}",
+    ] {
+        assert_eq!(render(text, &values), text);
+    }
+    assert_eq!(render("{{{name}}}", &values), "{game}");
+}
+
+#[test]
+fn test_p1_placeholders_lists_used_variables() {
+    assert_eq!(
+        placeholders(r"{{b}} {{ a }} {{b}} \{{c}} {{not valid}}"),
+        BTreeSet::from(["a".to_string(), "b".to_string()])
+    );
+}
+
+// =============================================================================
+// Variable Tests
+// =============================================================================
+
+#[test]
+fn test_p0_parse_var_specs() {
+    let specs = vec!["author=Jane Doe".to_string(), "motto= a=b".to_string()];
+
+    assert_eq!(
+        parse_var_specs(&specs).unwrap(),
+        values(&[("author", "Jane Doe"), ("motto", " a=b")])
+    );
+}
+
+#[test]
+fn test_p1_parse_var_specs_rejects_invalid_specs() {
+    for spec in ["author", "=Jane", "first name=Jane", "date=2026-01-01"] {
+        let result = parse_var_specs(&[spec.to_string()]);
+        assert_eq!(
+            error_code(&result),
+            Some(codes::ERR_VALIDATION_FIELD),
+            "'{}' should be rejected",
+            spec
+        );
+    }
+}
+
+#[test]
+fn test_p1_builtin_variables() {
+    let builtins = builtin_variables("my_game");
+
+    assert_eq!(builtins["project_name"], "my_game");
+    assert_eq!(builtins["cli_version"], env!("CARGO_PKG_VERSION"));
+    assert!(chrono::NaiveDate::parse_from_str(&builtins["date"], "%Y-%m-%d").is_ok());
+}
+
+// =============================================================================
+// Manifest Tests
+// =============================================================================
+
+#[test]
+fn test_p1_manifest_is_optional() {
+    let dir = tempdir().unwrap();
+
+    assert!(read_vars_manifest(dir.path()).unwrap().variables.is_empty());
+}
+
+#[test]
+fn test_p1_manifest_variables_are_required_by_default() {
+    let dir = tempdir().unwrap();
+    fs::write(
+        dir.path().join(VARS_MANIFEST),
+        r#"{"variables": [{"name": "author"}, {"name": "license", "required": false}]}"#,
+    )
+    .unwrap();
+
+    let manifest = read_vars_manifest(dir.path()).unwrap();
+    assert!(manifest.variables[0].required);
+    assert!(!manifest.variables[1].required);
+}
+
+#[test]
+fn test_p2_invalid_manifest_fails() {
+    let dir = tempdir().unwrap();
+    for content in ["{", r#"{"variables": [{"name": "first name"}]}"#] {
+        fs::write(dir.path().join(VARS_MANIFEST), content).unwrap();
+
+        assert_eq!(
+            error_code(&read_vars_manifest(dir.path())),
+            Some(codes::ERR_INVALID_TEMPLATE_STRUCTURE)
+        );
+    }
+}
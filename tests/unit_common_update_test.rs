@@ -0,0 +1,225 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the new version check.
+
+use am::common::update::{
+    CONFIG_AUTO_UPDATE, CONFIG_LAST_UPDATE_CHECK, CONFIG_UPDATE_CHECK_URL,
+    DEFAULT_UPDATE_CHECK_URL, FetchFuture, VersionFetcher, check_for_update,
+};
+use am::database::{db_get_config, db_set_config};
+use am::testing::TestEnv;
+use chrono::{DateTime, Duration, Utc};
+use std::cell::RefCell;
+
+/// Fetcher returning a fixed version and recording the requested URLs.
+struct StubFetcher {
+    version: &'static str,
+    urls: RefCell<Vec<String>>,
+}
+
+impl StubFetcher {
+    fn new(version: &'static str) -> Self {
+        Self {
+            version,
+            urls: RefCell::new(Vec::new()),
+        }
+    }
+
+    fn fetches(&self) -> usize {
+        self.urls.borrow().len()
+    }
+}
+
+impl VersionFetcher for StubFetcher {
+    fn fetch_latest_version<'a>(&'a self, url: &'a str) -> FetchFuture<'a> {
+        self.urls.borrow_mut().push(url.to_string());
+        Box::pin(async move { Ok(self.version.to_string()) })
+    }
+}
+
+fn now() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2026-10-16T12:00:00+00:00")
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+// =============================================================================
+// Version Comparison Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_newer_version_is_reported() {
+    let env = TestEnv::new().await.unwrap();
+    let fetcher = StubFetcher::new("v0.2.0\n");
+
+    let latest = check_for_update("0.1.0", now(), &fetcher, env.db())
+        .await
+        .unwrap();
+
+    assert_eq!(latest.as_deref(), Some("0.2.0"));
+    assert_eq!(*fetcher.urls.borrow(), vec![DEFAULT_UPDATE_CHECK_URL]);
+}
+
+#[tokio::test]
+async fn test_p0_same_or_older_version_is_not_reported() {
+    for version in ["0.1.0", "0.0.9", "0.1.0-beta.1"] {
+        let env = TestEnv::new().await.unwrap();
+        let fetcher = StubFetcher::new(version);
+
+        let latest = check_for_update("0.1.0", now(), &fetcher, env.db())
+            .await
+            .unwrap();
+
+        assert_eq!(latest, None, "{} should not be reported", version);
+        assert_eq!(fetcher.fetches(), 1);
+    }
+}
+
+#[tokio::test]
+async fn test_p1_unrecognized_version_is_ignored() {
+    let env = TestEnv::new().await.unwrap();
+    let fetcher = StubFetcher::new("<html>Not Found</html>");
+
+    let latest = check_for_update("0.1.0", now(), &fetcher, env.db())
+        .await
+        .unwrap();
+
+    assert_eq!(latest, None);
+}
+
+// =============================================================================
+// Configuration Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_disabled_auto_update_never_fetches() {
+    let env = TestEnv::new().await.unwrap();
+    db_set_config(CONFIG_AUTO_UPDATE, "false", "boolean", env.db()).unwrap();
+    let fetcher = StubFetcher::new("9.0.0");
+
+    let latest = check_for_update("0.1.0", now(), &fetcher, env.db())
+        .await
+        .unwrap();
+
+    assert_eq!(latest, None);
+    assert_eq!(fetcher.fetches(), 0);
+    assert_eq!(
+        db_get_config(CONFIG_LAST_UPDATE_CHECK, env.db()).unwrap(),
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_p1_configured_url_is_used() {
+    let env = TestEnv::new().await.unwrap();
+    db_set_config(
+        CONFIG_UPDATE_CHECK_URL,
+        "https://mirror.example.com/VERSION",
+        "string",
+        env.db(),
+    )
+    .unwrap();
+    let fetcher = StubFetcher::new("0.2.0");
+
+    check_for_update("0.1.0", now(), &fetcher, env.db())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *fetcher.urls.borrow(),
+        vec!["https://mirror.example.com/VERSION"]
+    );
+}
+
+#[tokio::test]
+async fn test_p1_empty_url_disables_the_check() {
+    let env = TestEnv::new().await.unwrap();
+    db_set_config(CONFIG_UPDATE_CHECK_URL, "", "string", env.db()).unwrap();
+    let fetcher = StubFetcher::new("0.2.0");
+
+    let latest = check_for_update("0.1.0", now(), &fetcher, env.db())
+        .await
+        .unwrap();
+
+    assert_eq!(latest, None);
+    assert_eq!(fetcher.fetches(), 0);
+}
+
+// =============================================================================
+// Check Frequency Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_check_runs_at_most_once_per_day() {
+    let env = TestEnv::new().await.unwrap();
+    let fetcher = StubFetcher::new("0.2.0");
+
+    check_for_update("0.1.0", now(), &fetcher, env.db())
+        .await
+        .unwrap();
+    let later = check_for_update("0.1.0", now() + Duration::hours(23), &fetcher, env.db())
+        .await
+        .unwrap();
+
+    assert_eq!(later, None);
+    assert_eq!(fetcher.fetches(), 1);
+
+    let next_day = check_for_update("0.1.0", now() + Duration::hours(25), &fetcher, env.db())
+        .await
+        .unwrap();
+
+    assert_eq!(next_day.as_deref(), Some("0.2.0"));
+    assert_eq!(fetcher.fetches(), 2);
+}
+
+#[tokio::test]
+async fn test_p1_failed_fetch_is_retried_on_the_next_command() {
+    struct FailingFetcher;
+    impl VersionFetcher for FailingFetcher {
+        fn fetch_latest_version<'a>(&'a self, _url: &'a str) -> FetchFuture<'a> {
+            Box::pin(async { anyhow::bail!("unreachable") })
+        }
+    }
+
+    let env = TestEnv::new().await.unwrap();
+
+    assert!(
+        check_for_update("0.1.0", now(), &FailingFetcher, env.db())
+            .await
+            .is_err()
+    );
+    assert_eq!(
+        db_get_config(CONFIG_LAST_UPDATE_CHECK, env.db()).unwrap(),
+        None
+    );
+
+    let fetcher = StubFetcher::new("0.2.0");
+    let latest = check_for_update("0.1.0", now() + Duration::minutes(1), &fetcher, env.db())
+        .await
+        .unwrap();
+    assert_eq!(latest.as_deref(), Some("0.2.0"));
+}
+
+#[test]
+fn test_p1_no_update_check_flag_is_global() {
+    use am::app::App;
+    use clap::Parser;
+
+    let app = App::try_parse_from(["am", "project", "list", "--no-update-check"]).unwrap();
+    assert!(app.no_update_check);
+
+    let app = App::try_parse_from(["am", "project", "list"]).unwrap();
+    assert!(!app.no_update_check);
+}
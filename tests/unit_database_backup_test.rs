@@ -0,0 +1,267 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the database backups made before migrations.
+
+use std::path::Path;
+use std::process::Command;
+use std::sync::Arc;
+
+use am::common::errors::{CliError, codes};
+use am::database::backup::{
+    CONFIG_AUTO_BACKUP_BEFORE_MIGRATION, PRE_MIGRATION_BACKUPS_KEPT, backup_before_migration,
+    backup_database, migration_failed, prune_pre_migration_backups,
+};
+use am::database::migrations::MigrationManager;
+use am::database::{Database, db_set_config};
+use tempfile::tempdir;
+
+/// A database at `path` migrated up to `version`.
+fn database_at_version(path: &Path, version: u32) -> Database {
+    let database = Database::new(path).expect("Failed to create database");
+    MigrationManager::new()
+        .run_migrations_to(&database, version)
+        .expect("Failed to migrate the database");
+    database
+}
+
+/// The schema version of the SQLite file at `path`.
+fn schema_version(path: &Path) -> u32 {
+    let database = Database::new(path).expect("The backup should be a SQLite database");
+    MigrationManager::new()
+        .get_current_version(&database)
+        .unwrap()
+}
+
+// =============================================================================
+// Backup Tests
+// =============================================================================
+
+#[test]
+fn test_p0_backup_holds_the_old_schema_version() {
+    let temp_dir = tempdir().unwrap();
+    let database = database_at_version(&temp_dir.path().join("am.db"), 6);
+    let backups_dir = temp_dir.path().join("backups");
+
+    let backup = backup_before_migration(&database, &backups_dir, 7)
+        .unwrap()
+        .expect("A database with a schema should be backed up");
+
+    assert!(backup.is_file());
+    assert_eq!(backup.parent(), Some(backups_dir.as_path()));
+    let name = backup.file_name().unwrap().to_string_lossy();
+    assert!(name.starts_with("pre-migration-6-7-"), "{}", name);
+    assert!(name.ends_with(".db"), "{}", name);
+    assert_eq!(schema_version(&backup), 6);
+}
+
+#[test]
+fn test_p0_backup_keeps_the_rows() {
+    let temp_dir = tempdir().unwrap();
+    let database = Arc::new(database_at_version(&temp_dir.path().join("am.db"), 6));
+    db_set_config(
+        "sdk_path",
+        "/opt/amplitude",
+        "string",
+        Some(database.clone()),
+    )
+    .unwrap();
+
+    let backup = backup_before_migration(&database, &temp_dir.path().join("backups"), 7)
+        .unwrap()
+        .unwrap();
+
+    let copy = Database::new(&backup).unwrap();
+    let values: Vec<String> = copy
+        .prepare("SELECT value FROM configuration WHERE key = 'sdk_path'")
+        .unwrap()
+        .query_map([], |row| row.get(0))
+        .unwrap();
+    assert_eq!(values, ["/opt/amplitude"]);
+}
+
+#[test]
+fn test_p1_successive_backups_get_distinct_files() {
+    let temp_dir = tempdir().unwrap();
+    let database = database_at_version(&temp_dir.path().join("am.db"), 6);
+    let backups_dir = temp_dir.path().join("backups");
+
+    let first = backup_before_migration(&database, &backups_dir, 7)
+        .unwrap()
+        .unwrap();
+    let second = backup_before_migration(&database, &backups_dir, 7)
+        .unwrap()
+        .unwrap();
+
+    assert_ne!(first, second);
+    assert!(first.is_file() && second.is_file());
+    assert_eq!(schema_version(&second), 6);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_p1_backup_to_non_utf8_path_is_rejected() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let temp_dir = tempdir().unwrap();
+    let database = database_at_version(&temp_dir.path().join("am.db"), 6);
+    let dest = temp_dir.path().join(OsStr::from_bytes(b"backup_\xff.db"));
+
+    let error = backup_database(&database, &dest).unwrap_err();
+
+    let error = error
+        .downcast_ref::<CliError>()
+        .expect("Expected a CliError");
+    assert_eq!(error.code, codes::ERR_VALIDATION_FORMAT);
+    assert!(!dest.exists());
+}
+
+#[test]
+fn test_p1_no_backup_of_empty_or_current_database() {
+    let temp_dir = tempdir().unwrap();
+    let backups_dir = temp_dir.path().join("backups");
+    let latest = MigrationManager::new().latest_version();
+
+    let empty = Database::new(temp_dir.path().join("empty.db")).unwrap();
+    let current = database_at_version(&temp_dir.path().join("current.db"), latest);
+
+    assert_eq!(
+        backup_before_migration(&empty, &backups_dir, latest).unwrap(),
+        None
+    );
+    assert_eq!(
+        backup_before_migration(&current, &backups_dir, latest).unwrap(),
+        None
+    );
+    assert!(!backups_dir.exists());
+}
+
+#[test]
+fn test_p1_no_backup_when_disabled() {
+    let temp_dir = tempdir().unwrap();
+    let database = Arc::new(database_at_version(&temp_dir.path().join("am.db"), 6));
+    db_set_config(
+        CONFIG_AUTO_BACKUP_BEFORE_MIGRATION,
+        "false",
+        "boolean",
+        Some(database.clone()),
+    )
+    .unwrap();
+    let backups_dir = temp_dir.path().join("backups");
+
+    let backup = backup_before_migration(&database, &backups_dir, 7).unwrap();
+
+    assert_eq!(backup, None);
+    assert!(!backups_dir.exists());
+}
+
+// =============================================================================
+// Retention Tests
+// =============================================================================
+
+#[test]
+fn test_p0_prune_keeps_the_most_recent_backups() {
+    let temp_dir = tempdir().unwrap();
+    let dir = temp_dir.path();
+    let names: Vec<String> = (1..=PRE_MIGRATION_BACKUPS_KEPT + 2)
+        .map(|day| {
+            format!(
+                "pre-migration-{}-7-202601{:02}T120000Z.db",
+                day % 3 + 1,
+                day
+            )
+        })
+        .collect();
+    for name in &names {
+        std::fs::write(dir.join(name), b"").unwrap();
+    }
+    std::fs::write(dir.join("unrelated.db"), b"").unwrap();
+
+    let deleted = prune_pre_migration_backups(dir, PRE_MIGRATION_BACKUPS_KEPT).unwrap();
+
+    // The oldest by timestamp, whatever their versions
+    assert_eq!(deleted, [dir.join(&names[0]), dir.join(&names[1])]);
+    for name in &names[2..] {
+        assert!(dir.join(name).exists(), "{} should be kept", name);
+    }
+    assert!(dir.join("unrelated.db").exists());
+}
+
+#[test]
+fn test_p1_prune_orders_backups_of_the_same_millisecond() {
+    let temp_dir = tempdir().unwrap();
+    let dir = temp_dir.path();
+    let names = [
+        "pre-migration-6-7-20260101T120000.250Z.db",
+        "pre-migration-6-7-20260101T120000.250Z_1.db",
+        "pre-migration-6-7-20260101T120000.251Z.db",
+    ];
+    for name in names {
+        std::fs::write(dir.join(name), b"").unwrap();
+    }
+
+    let deleted = prune_pre_migration_backups(dir, 1).unwrap();
+
+    assert_eq!(deleted, [dir.join(names[0]), dir.join(names[1])]);
+    assert!(dir.join(names[2]).exists());
+}
+
+// =============================================================================
+// Failure Tests
+// =============================================================================
+
+#[test]
+fn test_p1_migration_failure_suggests_restoring_the_backup() {
+    let backup = Path::new("/data/backups/pre-migration-6-7-20260101T120000Z.db");
+    let database_path = Path::new("/data/am.db");
+
+    let error = migration_failed(anyhow::anyhow!("disk full"), backup, database_path);
+
+    assert_eq!(error.code, codes::ERR_DATABASE_MIGRATION_FAILED);
+    assert!(error.why.contains("disk full"));
+    assert!(error.suggestion.contains(&backup.display().to_string()));
+    assert!(
+        error
+            .suggestion
+            .contains(&database_path.display().to_string())
+    );
+}
+
+// =============================================================================
+// Startup Tests
+// =============================================================================
+
+#[test]
+fn test_p0_startup_backs_up_before_migrating() {
+    let am_home = tempdir().unwrap();
+    drop(database_at_version(&am_home.path().join("am.db"), 6));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_am"))
+        .args(["--json", "project", "list"])
+        .env("AM_HOME", am_home.path())
+        .env_remove("AM_AUTO_BACKUP_BEFORE_MIGRATION")
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success(), "{:?}", output);
+    let backups: Vec<_> = std::fs::read_dir(am_home.path().join("backups"))
+        .expect("The backups directory should exist")
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    assert_eq!(backups.len(), 1);
+    assert_eq!(schema_version(&backups[0]), 6);
+    let latest = MigrationManager::new().latest_version();
+    assert_eq!(schema_version(&am_home.path().join("am.db")), latest);
+}
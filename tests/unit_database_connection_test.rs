@@ -16,7 +16,7 @@
 //!
 //! Tests Database, DatabaseStatement, and DatabaseTransaction functionality.
 
-use am::database::Database;
+use am::database::{Database, STATEMENT_CACHE_CAPACITY};
 use tempfile::tempdir;
 
 // =============================================================================
@@ -79,11 +79,7 @@ fn test_p1_database_path_returns_correct_path() {
     let returned_path = db.path();
 
     // THEN: Should match the original path
-    assert_eq!(
-        returned_path,
-        db_path.to_str().unwrap(),
-        "Database path should match"
-    );
+    assert_eq!(returned_path, db_path, "Database path should match");
 }
 
 // =============================================================================
@@ -183,6 +179,80 @@ fn test_p1_database_prepare_returns_statement() {
     assert!(result.is_ok(), "Prepare should succeed");
 }
 
+#[test]
+fn test_p1_database_prepare_rejects_invalid_sql() {
+    // GIVEN: A database
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let db = Database::new(temp_dir.path().join("test.db")).expect("Failed to create database");
+
+    // WHEN: Preparing invalid SQL
+    let result = db.prepare("SELEC name FROM users");
+
+    // THEN: It fails before the statement ever runs
+    assert!(result.is_err(), "Prepare should validate the SQL");
+}
+
+#[test]
+fn test_p0_repeated_query_map_reuses_compiled_statement() {
+    // GIVEN: A prepared query
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let db = Database::new(temp_dir.path().join("test.db")).expect("Failed to create database");
+    db.execute_batch(
+        "CREATE TABLE users (id INTEGER, name TEXT); INSERT INTO users VALUES (1, 'a');",
+    )
+    .expect("Failed to create table");
+    let statement = db
+        .prepare("SELECT name FROM users WHERE id = ?1")
+        .expect("Failed to prepare");
+
+    // WHEN: Running it many times, as a listing or a scan would
+    const RUNS: u32 = 1_000;
+    for _ in 0..RUNS {
+        let names: Vec<String> = statement.query_map([1], |row| row.get(0)).unwrap();
+        assert_eq!(names, ["a"]);
+    }
+
+    // THEN: Every run used the same compiled statement, which a statement compiled
+    // again for each run would have counted once
+    assert_eq!(statement.run_count().unwrap(), RUNS);
+
+    // AND: Preparing the same SQL again shares it
+    let again = db
+        .prepare("SELECT name FROM users WHERE id = ?1")
+        .expect("Failed to prepare");
+    again.query_map([1], |row| row.get::<_, String>(0)).unwrap();
+    assert_eq!(statement.run_count().unwrap(), RUNS + 1);
+}
+
+#[test]
+fn test_p1_statement_evicted_from_cache_still_runs() {
+    // GIVEN: A prepared statement pushed out of the cache by other statements
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let db = Database::new(temp_dir.path().join("test.db")).expect("Failed to create database");
+    db.execute("CREATE TABLE notes (text TEXT)", [])
+        .expect("Failed to create table");
+    let insert = db
+        .prepare("INSERT INTO notes (text) VALUES (?1)")
+        .expect("Failed to prepare");
+    insert.execute(["first"]).unwrap();
+    for i in 0..=STATEMENT_CACHE_CAPACITY {
+        db.prepare(&format!("SELECT {}", i))
+            .expect("Failed to prepare");
+    }
+
+    // WHEN: Running it again
+    insert.execute(["second"]).unwrap();
+
+    // THEN: It was compiled again, and still works
+    assert_eq!(insert.run_count().unwrap(), 1);
+    let count: Vec<i64> = db
+        .prepare("SELECT COUNT(*) FROM notes")
+        .unwrap()
+        .query_map([], |row| row.get(0))
+        .unwrap();
+    assert_eq!(count, [2]);
+}
+
 // =============================================================================
 // Database::transaction() Tests
 // =============================================================================
@@ -284,7 +354,7 @@ fn test_p2_database_close_releases_connection() {
     let db = Database::new(&db_path).expect("Failed to create database");
 
     // WHEN: Closing the database
-    db.close();
+    db.close().expect("Close should succeed");
 
     // THEN: Should be able to open a new connection to the same file
     let db2 = Database::new(&db_path);
@@ -293,3 +363,251 @@ fn test_p2_database_close_releases_connection() {
         "Should be able to open new connection after close"
     );
 }
+
+#[test]
+fn test_p0_database_close_removes_wal_file() {
+    // GIVEN: A database with writes in its write-ahead log
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join("test.db");
+    let wal_path = db_path.with_extension("db-wal");
+    let db = Database::new(&db_path).expect("Failed to create database");
+    db.execute_batch("CREATE TABLE notes (text TEXT); INSERT INTO notes VALUES ('a');")
+        .expect("Failed to write");
+    assert!(wal_path.exists(), "Writes should go to the WAL file");
+
+    // WHEN: Closing the database
+    let result = db.close();
+
+    // THEN: The log is checkpointed and its file removed
+    assert!(result.is_ok(), "Close should succeed: {:?}", result);
+    assert!(!wal_path.exists(), "WAL file should be removed on close");
+
+    let reopened = Database::new(&db_path).expect("Failed to reopen database");
+    let count: i64 = reopened
+        .get_connection()
+        .lock()
+        .unwrap()
+        .query_row("SELECT COUNT(*) FROM notes", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(count, 1, "Checkpointed writes should be kept");
+}
+
+#[test]
+fn test_p1_database_checkpoint_truncates_wal_file() {
+    // GIVEN: A database with writes in its write-ahead log
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join("test.db");
+    let wal_path = db_path.with_extension("db-wal");
+    let db = Database::new(&db_path).expect("Failed to create database");
+    db.execute_batch("CREATE TABLE notes (text TEXT); INSERT INTO notes VALUES ('a');")
+        .expect("Failed to write");
+
+    // WHEN: Checkpointing with the connection still open
+    db.checkpoint().expect("Checkpoint should succeed");
+
+    // THEN: The log is empty
+    let size = std::fs::metadata(&wal_path).map_or(0, |m| m.len());
+    assert_eq!(size, 0, "WAL file should be truncated");
+}
+
+#[test]
+fn test_p1_database_close_reports_open_handles() {
+    // GIVEN: A database with a prepared statement still alive
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join("test.db");
+    let db = Database::new(&db_path).expect("Failed to create database");
+    let statement = db.prepare("SELECT 1").expect("Failed to prepare");
+
+    // WHEN: Closing the database
+    let result = db.close();
+
+    // THEN: The remaining handle is reported instead of silently ignored
+    let error = result.expect_err("Close should report the open handle");
+    assert!(
+        error.to_string().contains("still used"),
+        "Unexpected error: {}",
+        error
+    );
+    drop(statement);
+}
+
+// =============================================================================
+// File Replacement Guard Tests
+// =============================================================================
+
+/// Delete the database file at `db_path` and create a fresh migrated one, like
+/// `am sudo database reset` does from another process.
+#[cfg(unix)]
+async fn reset_database_file(db_path: &std::path::Path) {
+    for extension in ["db", "db-wal", "db-shm"] {
+        let _ = std::fs::remove_file(db_path.with_extension(extension));
+    }
+
+    let mut fresh = Database::new(db_path).expect("Failed to recreate database");
+    fresh
+        .run_migrations()
+        .await
+        .expect("Failed to migrate database");
+    fresh.close().expect("Failed to close database");
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_p1_guarded_database_writes_to_replaced_file() {
+    use am::database::{db_get_config, db_set_config};
+    use std::sync::Arc;
+
+    // GIVEN: A migrated database guarding against file replacement
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join("am.db");
+    let mut db = Database::new(&db_path).expect("Failed to create database");
+    db.run_migrations()
+        .await
+        .expect("Failed to migrate database");
+    db.guard_file_replacement();
+    let db = Arc::new(db);
+    db_set_config("before", "1", "string", Some(db.clone())).unwrap();
+
+    // WHEN: The file is deleted and recreated underneath it, then written to
+    reset_database_file(&db_path).await;
+    db_set_config("after", "2", "string", Some(db.clone())).unwrap();
+
+    // THEN: The write lands in the new file, which was migrated
+    let reader = Arc::new(Database::new(&db_path).expect("Failed to open database"));
+    assert_eq!(
+        db_get_config("after", Some(reader.clone()))
+            .unwrap()
+            .as_deref(),
+        Some("2")
+    );
+    assert_eq!(db_get_config("before", Some(reader)).unwrap(), None);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_p2_guarded_database_recreates_deleted_file() {
+    use am::database::{db_get_config, db_set_config};
+    use std::sync::Arc;
+
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join("am.db");
+    let mut db = Database::new(&db_path).expect("Failed to create database");
+    db.run_migrations()
+        .await
+        .expect("Failed to migrate database");
+    db.guard_file_replacement();
+    let db = Arc::new(db);
+
+    std::fs::remove_file(&db_path).unwrap();
+    db_set_config("key", "value", "string", Some(db.clone())).unwrap();
+
+    assert!(db_path.is_file(), "The database file should be recreated");
+    assert_eq!(
+        db_get_config("key", Some(db)).unwrap().as_deref(),
+        Some("value")
+    );
+}
+
+// =============================================================================
+// Non-UTF-8 Path Tests
+// =============================================================================
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_p1_database_new_accepts_non_utf8_path() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    // GIVEN: A database path that is not valid UTF-8
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join(OsStr::from_bytes(b"am_\xff.db"));
+
+    // WHEN: Creating a new database
+    let db = Database::new(&db_path).expect("Failed to create database");
+
+    // THEN: The file is created at the exact path
+    assert!(db_path.exists(), "Database file should exist");
+    assert_eq!(db.path(), db_path);
+}
+
+// =============================================================================
+// Unmigrated Database Tests
+// =============================================================================
+
+/// Write an empty but valid SQLite file at `path`, without any table.
+fn create_empty_database_file(path: &std::path::Path) {
+    let conn = rusqlite::Connection::open(path).expect("Failed to create database file");
+    conn.execute_batch("PRAGMA user_version = 0; VACUUM;")
+        .expect("Failed to write database file");
+}
+
+async fn list_projects(database: Database) -> anyhow::Result<()> {
+    use am::commands::project::{ProjectCommands, ProjectSort, handler};
+    use am::presentation::OutputMode;
+    use am::testing::{MockOutput, ScriptedInput};
+    use std::sync::Arc;
+
+    let command = ProjectCommands::List {
+        favorite: false,
+        no_favorite: false,
+        tree: None,
+        sort: ProjectSort::Name,
+        limit: Default::default(),
+    };
+    handler(
+        &command,
+        Some(Arc::new(database)),
+        &ScriptedInput::new(),
+        &MockOutput::new(OutputMode::Json),
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_p0_project_list_on_unmigrated_database_reports_outdated_schema() {
+    use am::common::errors::{CliError, codes};
+
+    // GIVEN: An empty SQLite file opened without running the migrations
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join("am.db");
+    create_empty_database_file(&db_path);
+    let db = Database::new(&db_path).expect("Failed to open database");
+
+    // WHEN: Listing the projects
+    let error = list_projects(db).await.expect_err("Listing should fail");
+
+    // THEN: The missing table is reported as an outdated schema, not as SQLite's error
+    let cli_error = error
+        .downcast_ref::<CliError>()
+        .unwrap_or_else(|| panic!("Expected a CliError, got: {:?}", error));
+    assert_eq!(cli_error.code, codes::ERR_DATABASE_SCHEMA_OUTDATED);
+    assert!(cli_error.why.contains("projects"), "{}", cli_error.why);
+    assert!(cli_error.suggestion.contains("am sudo database migrate"));
+}
+
+#[tokio::test]
+async fn test_p0_project_list_on_migrated_empty_database_file_succeeds() {
+    // GIVEN: An empty SQLite file, migrated when opened like the CLI does
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join("am.db");
+    create_empty_database_file(&db_path);
+    let mut db = Database::new(&db_path).expect("Failed to open database");
+    db.run_migrations().await.expect("Migrations should run");
+
+    // WHEN/THEN: Listing the projects works
+    list_projects(db).await.expect("Listing should succeed");
+}
+
+#[test]
+fn test_p1_other_query_errors_are_not_reported_as_outdated_schema() {
+    use am::common::errors::CliError;
+
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let db = Database::new(temp_dir.path().join("test.db")).expect("Failed to open database");
+
+    let error = db
+        .execute("SELEC 1", [])
+        .expect_err("A syntax error should fail");
+
+    assert!(error.downcast_ref::<CliError>().is_none());
+}
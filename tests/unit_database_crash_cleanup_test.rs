@@ -0,0 +1,89 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the database cleanup run when the process ends abruptly.
+//!
+//! Kept in their own test binary because the panic hook is process-global.
+
+use am::database::{Database, emergency_cleanup, setup_crash_db_cleanup};
+use std::sync::Arc;
+use tempfile::tempdir;
+
+// =============================================================================
+// Panic Hook Tests
+// =============================================================================
+
+#[test]
+fn test_p0_panic_hook_checkpoints_shared_database() {
+    // GIVEN: A database shared with a worker thread and the crash cleanup installed
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join("test.db");
+    let wal_path = db_path.with_extension("db-wal");
+    let db = Arc::new(Database::new(&db_path).expect("Failed to create database"));
+    setup_crash_db_cleanup(Some(&db));
+
+    // WHEN: The worker writes to the database and then panics
+    let worker_db = db.clone();
+    let result = std::thread::spawn(move || {
+        worker_db
+            .execute_batch("CREATE TABLE notes (text TEXT); INSERT INTO notes VALUES ('a');")
+            .expect("Failed to write");
+        panic!("worker failed");
+    })
+    .join();
+
+    // THEN: The panic hook checkpointed the WAL while the database was still shared
+    assert!(result.is_err(), "The worker should have panicked");
+    let size = std::fs::metadata(&wal_path).map_or(0, |m| m.len());
+    assert_eq!(size, 0, "WAL file should be truncated by the panic hook");
+}
+
+// =============================================================================
+// emergency_cleanup() Tests
+// =============================================================================
+
+#[test]
+fn test_p1_emergency_cleanup_skips_busy_connection() {
+    // GIVEN: A database whose connection is locked by this thread
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let db = Arc::new(
+        Database::new(temp_dir.path().join("test.db")).expect("Failed to create database"),
+    );
+    db.execute_batch("CREATE TABLE notes (text TEXT); INSERT INTO notes VALUES ('a');")
+        .expect("Failed to write");
+    let connection = db.get_connection();
+    let _guard = connection.lock().unwrap();
+
+    // WHEN/THEN: The cleanup returns instead of waiting for the connection
+    emergency_cleanup(&Arc::downgrade(&db));
+    assert!(db.emergency_checkpoint().is_err());
+}
+
+#[test]
+fn test_p1_emergency_cleanup_ignores_closed_database() {
+    // GIVEN: A weak reference to a database that has since been closed
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let db = Arc::new(
+        Database::new(temp_dir.path().join("test.db")).expect("Failed to create database"),
+    );
+    let weak = Arc::downgrade(&db);
+    Arc::try_unwrap(db)
+        .ok()
+        .expect("The weak reference should not keep the database shared")
+        .close()
+        .expect("Failed to close database");
+
+    // WHEN/THEN: The cleanup has nothing to do
+    emergency_cleanup(&weak);
+}
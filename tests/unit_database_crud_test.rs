@@ -14,9 +14,11 @@
 
 //! Unit tests for database CRUD operations.
 
+use am::common::errors::{CliError, codes};
 use am::database::{
-    Database, db_create_project, db_forget_project, db_get_all_projects, db_get_project_by_name,
-    db_get_template_by_name, db_get_templates, entities::Project,
+    Database, db_check_schema_drift, db_create_project, db_forget_project, db_forget_projects,
+    db_get_all_projects, db_get_project_by_name, db_get_project_by_path, db_get_template_by_name,
+    db_get_templates, db_set_project_favorite, db_update_template, entities::Project,
 };
 use std::sync::Arc;
 use tempfile::tempdir;
@@ -78,6 +80,83 @@ async fn test_p0_db_create_project_fails_on_duplicate_name() {
     assert!(result.is_err(), "Duplicate name should fail");
 }
 
+#[tokio::test]
+async fn test_p0_db_create_project_duplicate_reports_existing_path() {
+    let (db, _temp_dir) = setup_test_database().await;
+
+    let project1 = Project {
+        id: None,
+        name: "duplicate_project".to_string(),
+        path: "/path/one".to_string(),
+        registered_at: None,
+        is_favorite: false,
+    };
+    db_create_project(&project1, Some(db.clone())).expect("First insert should succeed");
+
+    let project2 = Project {
+        id: None,
+        name: "duplicate_project".to_string(),
+        path: "/path/two".to_string(),
+        registered_at: None,
+        is_favorite: false,
+    };
+
+    let err = db_create_project(&project2, Some(db.clone())).unwrap_err();
+    let cli_err = err
+        .downcast_ref::<CliError>()
+        .expect("Conflict should be a CliError");
+
+    assert_eq!(cli_err.code, codes::ERR_PROJECT_ALREADY_EXISTS);
+    assert_eq!(cli_err.context.as_deref(), Some("/path/one"));
+}
+
+#[tokio::test]
+async fn test_p0_db_create_project_concurrent_same_name_one_wins() {
+    let (db, _temp_dir) = setup_test_database().await;
+
+    let handles: Vec<_> = ["/path/one", "/path/two"]
+        .into_iter()
+        .map(|path| {
+            let db = db.clone();
+            std::thread::spawn(move || {
+                let project = Project {
+                    id: None,
+                    name: "raced_project".to_string(),
+                    path: path.to_string(),
+                    registered_at: None,
+                    is_favorite: false,
+                };
+                db_create_project(&project, Some(db))
+            })
+        })
+        .collect();
+
+    let results: Vec<_> = handles
+        .into_iter()
+        .map(|h| h.join().expect("Thread should not panic"))
+        .collect();
+
+    assert_eq!(
+        results.iter().filter(|r| r.is_ok()).count(),
+        1,
+        "Exactly one registration should succeed"
+    );
+
+    let err = results
+        .into_iter()
+        .find_map(|r| r.err())
+        .expect("One registration should fail");
+    let cli_err = err
+        .downcast_ref::<CliError>()
+        .expect("Conflict should be a CliError, not a raw constraint error");
+    assert_eq!(cli_err.code, codes::ERR_PROJECT_ALREADY_EXISTS);
+
+    let winner = db_get_project_by_name("raced_project", Some(db.clone()))
+        .expect("Query should succeed")
+        .expect("Project should exist");
+    assert_eq!(cli_err.context.as_deref(), Some(winner.path.as_str()));
+}
+
 #[tokio::test]
 async fn test_p1_db_create_project_allows_same_path_different_name() {
     let (db, _temp_dir) = setup_test_database().await;
@@ -149,6 +228,17 @@ async fn test_p0_db_get_project_by_name_returns_none_for_nonexistent() {
     );
 }
 
+#[tokio::test]
+async fn test_p1_db_get_project_lookups_fail_on_sql_error() {
+    let (db, _temp_dir) = setup_test_database().await;
+    db.execute_batch("DROP TABLE projects")
+        .expect("Failed to drop the projects table");
+
+    // A failing query must not be mistaken for a missing project
+    assert!(db_get_project_by_name("any_project", Some(db.clone())).is_err());
+    assert!(db_get_project_by_path("/path/to/project", Some(db.clone())).is_err());
+}
+
 #[tokio::test]
 async fn test_p1_db_get_project_by_name_is_case_sensitive() {
     let (db, _temp_dir) = setup_test_database().await;
@@ -201,6 +291,37 @@ async fn test_p0_db_forget_project_removes_project() {
     assert!(check.is_none(), "Project should no longer exist");
 }
 
+#[tokio::test]
+async fn test_p0_db_forget_projects_removes_all_given_projects() {
+    let (db, _temp_dir) = setup_test_database().await;
+    let mut ids = Vec::new();
+    for name in ["bulk_a", "bulk_b", "bulk_keep"] {
+        let project = Project {
+            id: None,
+            name: name.to_string(),
+            path: format!("/path/to/{}", name),
+            registered_at: None,
+            is_favorite: false,
+        };
+        db_create_project(&project, Some(db.clone())).expect("Insert should succeed");
+        ids.push(
+            db_get_project_by_name(name, Some(db.clone()))
+                .unwrap()
+                .unwrap()
+                .id
+                .unwrap(),
+        );
+    }
+
+    let removed = db_forget_projects(&[ids[0], ids[1], 99999], Some(db.clone()))
+        .expect("Forget should succeed");
+
+    assert_eq!(removed, 2);
+    let remaining = db_get_all_projects(Some(db.clone())).unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].name, "bulk_keep");
+}
+
 #[tokio::test]
 async fn test_p1_db_forget_project_with_invalid_id_succeeds() {
     let (db, _temp_dir) = setup_test_database().await;
@@ -288,6 +409,132 @@ async fn test_p1_db_get_template_by_name_returns_none_for_nonexistent() {
     );
 }
 
+#[tokio::test]
+async fn test_p1_db_get_template_by_name_fails_on_sql_error() {
+    let (db, _temp_dir) = setup_test_database().await;
+    db.execute_batch("DROP TABLE templates")
+        .expect("Failed to drop the templates table");
+
+    assert!(db_get_template_by_name("any_template", Some(db.clone())).is_err());
+}
+
+// =============================================================================
+// db_update_template Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_db_update_template_changes_path_in_place() {
+    // GIVEN: A registered template
+    let (db, _temp_dir) = setup_test_database().await;
+    db.execute(
+        "INSERT INTO templates (name, path, created_at) VALUES ('my_template', '/old/path', '2025-01-01 00:00:00')",
+        [],
+    )
+    .expect("Insert should succeed");
+    let before = db_get_template_by_name("my_template", Some(db.clone()))
+        .unwrap()
+        .unwrap();
+
+    // WHEN: Updating its path
+    let updated = db_update_template("my_template", "my_template", "/new/path", Some(db.clone()))
+        .expect("Update should succeed");
+
+    // THEN: The same row points to the new path and keeps its creation date
+    assert!(updated);
+    let after = db_get_template_by_name("my_template", Some(db.clone()))
+        .unwrap()
+        .unwrap();
+    assert_eq!(after.id, before.id);
+    assert_eq!(after.path, "/new/path");
+
+    let created_at: String = db
+        .get_connection()
+        .lock()
+        .unwrap()
+        .query_row(
+            "SELECT created_at FROM templates WHERE name = 'my_template'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(created_at, "2025-01-01 00:00:00");
+}
+
+#[tokio::test]
+async fn test_p0_db_update_template_renames() {
+    // GIVEN: A registered template
+    let (db, _temp_dir) = setup_test_database().await;
+    db.execute(
+        "INSERT INTO templates (name, path) VALUES ('old_name', '/old/path')",
+        [],
+    )
+    .expect("Insert should succeed");
+
+    // WHEN: Updating its path and name
+    let updated = db_update_template("old_name", "new_name", "/new/path", Some(db.clone()))
+        .expect("Update should succeed");
+
+    // THEN: The template is only registered under its new name
+    assert!(updated);
+    assert!(
+        db_get_template_by_name("old_name", Some(db.clone()))
+            .unwrap()
+            .is_none()
+    );
+    let renamed = db_get_template_by_name("new_name", Some(db.clone()))
+        .unwrap()
+        .expect("Renamed template should exist");
+    assert_eq!(renamed.path, "/new/path");
+}
+
+#[tokio::test]
+async fn test_p0_db_update_template_returns_false_for_missing_name() {
+    // GIVEN: A database without the template
+    let (db, _temp_dir) = setup_test_database().await;
+
+    // WHEN: Updating it
+    let updated = db_update_template("missing", "missing", "/new/path", Some(db.clone()))
+        .expect("Update should not error");
+
+    // THEN: Nothing was updated
+    assert!(!updated);
+    assert!(db_get_templates(Some(db)).unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_p0_db_update_template_rejects_duplicate_rename() {
+    // GIVEN: Two registered templates
+    let (db, _temp_dir) = setup_test_database().await;
+    db.execute(
+        "INSERT INTO templates (name, path) VALUES ('first', '/path/first')",
+        [],
+    )
+    .expect("Insert should succeed");
+    db.execute(
+        "INSERT INTO templates (name, path) VALUES ('second', '/path/second')",
+        [],
+    )
+    .expect("Insert should succeed");
+
+    // WHEN: Renaming one to the name of the other
+    let err = db_update_template("first", "second", "/new/path", Some(db.clone())).unwrap_err();
+
+    // THEN: The conflict is reported with the path of the existing template
+    let cli_err = err.downcast_ref::<CliError>().expect("Expected CliError");
+    assert_eq!(cli_err.code, codes::ERR_TEMPLATE_NAME_CONFLICT);
+    assert_eq!(cli_err.context.as_deref(), Some("/path/second"));
+
+    // AND: Neither template changed
+    let first = db_get_template_by_name("first", Some(db.clone()))
+        .unwrap()
+        .unwrap();
+    assert_eq!(first.path, "/path/first");
+    let second = db_get_template_by_name("second", Some(db))
+        .unwrap()
+        .unwrap();
+    assert_eq!(second.path, "/path/second");
+}
+
 // =============================================================================
 // db_get_all_projects Tests
 // =============================================================================
@@ -405,11 +652,12 @@ async fn test_p1_db_get_all_projects_includes_registered_at() {
         projects[0].registered_at.is_some(),
         "Project should have a registered_at date"
     );
-    // The date should be in ISO 8601 format (YYYY-MM-DD)
+    // The date should be an RFC 3339 UTC timestamp (YYYY-MM-DDTHH:MM:SSZ)
     let date = projects[0].registered_at.as_ref().unwrap();
     assert!(
-        date.len() == 10 && date.contains('-'),
-        "Date should be in YYYY-MM-DD format"
+        chrono::DateTime::parse_from_rfc3339(date).is_ok() && date.ends_with('Z'),
+        "Date should be an RFC 3339 UTC timestamp: {}",
+        date
     );
 }
 
@@ -455,6 +703,7 @@ async fn test_p0_db_get_project_by_path_returns_none_for_nonexistent() {
     assert!(found.is_none(), "Non-existent path should return None");
 }
 
+#[cfg(not(windows))]
 #[tokio::test]
 async fn test_p1_db_get_project_by_path_is_case_sensitive() {
     let (db, _temp_dir) = setup_test_database().await;
@@ -475,6 +724,26 @@ async fn test_p1_db_get_project_by_path_is_case_sensitive() {
     assert!(found.is_none(), "Case-different path should not match");
 }
 
+#[cfg(windows)]
+#[tokio::test]
+async fn test_p1_db_get_project_by_path_is_case_insensitive_on_windows() {
+    let (db, _temp_dir) = setup_test_database().await;
+
+    let project = Project {
+        id: None,
+        name: "case_path_project".to_string(),
+        path: r"C:\Home\User\Project".to_string(),
+        registered_at: None,
+        is_favorite: false,
+    };
+    db_create_project(&project, Some(db.clone())).expect("Insert should succeed");
+
+    let result = db_get_project_by_path(r"c:\home\user\project", Some(db.clone()));
+
+    let found = result.unwrap().expect("Case-different path should match");
+    assert_eq!(found.name, "case_path_project");
+}
+
 #[tokio::test]
 async fn test_p1_db_get_project_by_path_includes_registered_at() {
     let (db, _temp_dir) = setup_test_database().await;
@@ -497,3 +766,295 @@ async fn test_p1_db_get_project_by_path_includes_registered_at() {
         "Project should have registered_at date"
     );
 }
+
+fn project_at(name: &str, path: &str) -> Project {
+    Project {
+        id: None,
+        name: name.to_string(),
+        path: path.to_string(),
+        registered_at: None,
+        is_favorite: false,
+    }
+}
+
+#[tokio::test]
+async fn test_p0_db_get_project_by_path_ignores_trailing_separator() {
+    let (db, temp_dir) = setup_test_database().await;
+    let project_dir = temp_dir.path().join("trailing");
+    std::fs::create_dir(&project_dir).unwrap();
+    let project_path = project_dir.to_str().unwrap();
+    db_create_project(&project_at("trailing", project_path), Some(db.clone())).unwrap();
+
+    let with_separator = format!("{}{}", project_path, std::path::MAIN_SEPARATOR);
+    let found = db_get_project_by_path(&with_separator, Some(db.clone())).unwrap();
+
+    assert_eq!(found.map(|p| p.name).as_deref(), Some("trailing"));
+    assert!(
+        db_get_project_by_path("/removed/project/", Some(db.clone()))
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_p0_db_get_project_by_path_resolves_relative_paths() {
+    let (db, _temp_dir) = setup_test_database().await;
+    let cwd = std::env::current_dir().unwrap();
+    let absolute = cwd.join("not_created").join("relative_project");
+    db_create_project(
+        &project_at("relative_project", absolute.to_str().unwrap()),
+        Some(db.clone()),
+    )
+    .unwrap();
+
+    let found = db_get_project_by_path("./not_created/other/../relative_project", Some(db.clone()))
+        .unwrap();
+
+    assert_eq!(found.map(|p| p.name).as_deref(), Some("relative_project"));
+}
+
+#[tokio::test]
+async fn test_p1_db_create_project_stores_relative_paths_as_absolute() {
+    let (db, _temp_dir) = setup_test_database().await;
+    db_create_project(
+        &project_at("stored_relative", "not_created/stored_relative/"),
+        Some(db.clone()),
+    )
+    .unwrap();
+
+    let stored = db_get_project_by_name("stored_relative", Some(db.clone()))
+        .unwrap()
+        .unwrap();
+
+    let expected = std::env::current_dir()
+        .unwrap()
+        .join("not_created")
+        .join("stored_relative");
+    assert_eq!(stored.path, expected.to_str().unwrap());
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_p1_db_get_project_by_path_follows_symlinks() {
+    let (db, temp_dir) = setup_test_database().await;
+    let project_dir = temp_dir.path().join("real_project");
+    std::fs::create_dir(&project_dir).unwrap();
+    let link = temp_dir.path().join("linked_project");
+    std::os::unix::fs::symlink(&project_dir, &link).unwrap();
+
+    db_create_project(
+        &project_at("linked", link.to_str().unwrap()),
+        Some(db.clone()),
+    )
+    .unwrap();
+
+    let found = db_get_project_by_path(project_dir.to_str().unwrap(), Some(db.clone()))
+        .unwrap()
+        .expect("Project should be found through its real path");
+    assert_eq!(found.name, "linked");
+    assert_eq!(
+        found.path,
+        project_dir.canonicalize().unwrap().to_str().unwrap()
+    );
+    assert!(
+        db_get_project_by_path(link.to_str().unwrap(), Some(db.clone()))
+            .unwrap()
+            .is_some()
+    );
+}
+
+#[tokio::test]
+async fn test_p1_db_get_project_by_path_matches_non_canonical_stored_paths() {
+    let (db, _temp_dir) = setup_test_database().await;
+    db.get_connection()
+        .lock()
+        .unwrap()
+        .execute(
+            "INSERT INTO projects (name, path) VALUES ('legacy', '/legacy/./project/')",
+            [],
+        )
+        .unwrap();
+
+    let found = db_get_project_by_path("/legacy/project", Some(db.clone())).unwrap();
+
+    assert_eq!(found.map(|p| p.name).as_deref(), Some("legacy"));
+}
+
+// =============================================================================
+// db_rename_project Tests
+// =============================================================================
+
+use am::database::db_rename_project;
+
+fn registered(db: &Arc<Database>, name: &str, path: &str) -> Project {
+    let project = Project {
+        id: None,
+        name: name.to_string(),
+        path: path.to_string(),
+        registered_at: None,
+        is_favorite: false,
+    };
+    db_create_project(&project, Some(db.clone())).expect("Insert should succeed");
+
+    db_get_project_by_name(name, Some(db.clone()))
+        .expect("Query should succeed")
+        .expect("Project should exist")
+}
+
+#[tokio::test]
+async fn test_p0_db_rename_project_keeps_path_and_favorite() {
+    let (db, _temp_dir) = setup_test_database().await;
+    let project = registered(&db, "old_name", "/path/to/renamed");
+    let id = project.id.unwrap();
+    db_set_project_favorite(id, true, Some(db.clone())).expect("Update should succeed");
+
+    let renamed =
+        db_rename_project(id, "new_name", Some(db.clone())).expect("Rename should succeed");
+
+    assert!(renamed, "An existing project should be renamed");
+    assert!(
+        db_get_project_by_name("old_name", Some(db.clone()))
+            .unwrap()
+            .is_none()
+    );
+    let found = db_get_project_by_name("new_name", Some(db.clone()))
+        .unwrap()
+        .expect("Project should exist under its new name");
+    assert_eq!(found.id, Some(id));
+    assert_eq!(found.path, "/path/to/renamed");
+    assert!(found.is_favorite);
+}
+
+#[tokio::test]
+async fn test_p0_db_rename_project_rejects_taken_name() {
+    let (db, _temp_dir) = setup_test_database().await;
+    let project = registered(&db, "first", "/path/to/first");
+    registered(&db, "second", "/path/to/second");
+
+    let err = db_rename_project(project.id.unwrap(), "second", Some(db.clone()))
+        .expect_err("Rename to a taken name should fail");
+
+    let cli_err = err.downcast_ref::<CliError>().expect("Expected CliError");
+    assert_eq!(cli_err.code, codes::ERR_PROJECT_ALREADY_EXISTS);
+    assert_eq!(cli_err.context.as_deref(), Some("/path/to/second"));
+}
+
+#[tokio::test]
+async fn test_p1_db_rename_project_with_unknown_id_returns_false() {
+    let (db, _temp_dir) = setup_test_database().await;
+
+    let renamed =
+        db_rename_project(99999, "anything", Some(db.clone())).expect("Query should succeed");
+
+    assert!(!renamed, "Unknown ID should not be renamed");
+}
+
+// =============================================================================
+// Row Mapping Tests
+// =============================================================================
+
+/// Helper to create a test database migrated up to `version` only.
+async fn setup_test_database_at(version: u32) -> (Arc<Database>, tempfile::TempDir) {
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join("test.db");
+    let mut db = Database::new(&db_path).expect("Failed to create database");
+    db.run_migrations_to(version)
+        .await
+        .expect("Failed to run migrations");
+    (Arc::new(db), temp_dir)
+}
+
+#[tokio::test]
+async fn test_p0_registered_project_round_trips_every_field() {
+    // GIVEN: A registered project
+    let (db, _temp_dir) = setup_test_database().await;
+    let project = Project {
+        id: None,
+        name: "round_trip".to_string(),
+        path: "/path/to/round_trip".to_string(),
+        registered_at: None,
+        is_favorite: false,
+    };
+    db_create_project(&project, Some(db.clone())).expect("Insert should succeed");
+
+    // WHEN: The project is read back through every lookup
+    let by_name = db_get_project_by_name("round_trip", Some(db.clone()))
+        .unwrap()
+        .expect("Project should exist by name");
+    let by_path = db_get_project_by_path("/path/to/round_trip", Some(db.clone()))
+        .unwrap()
+        .expect("Project should exist by path");
+    let all = db_get_all_projects(Some(db.clone())).unwrap();
+
+    // THEN: Every field lands in the right place
+    for found in [&by_name, &by_path, &all[0]] {
+        assert!(found.id.is_some(), "id should be populated");
+        assert_eq!(found.name, "round_trip");
+        assert_eq!(found.path, "/path/to/round_trip");
+        let registered_at = found.registered_at.as_deref().expect("date should be set");
+        assert_eq!(registered_at.len(), 10, "date should be YYYY-MM-DD");
+        assert_eq!(&registered_at[4..5], "-");
+        assert_eq!(&registered_at[7..8], "-");
+        assert!(!found.is_favorite, "new projects are not favorites");
+    }
+
+    // AND: The favorite flag round-trips too
+    db_set_project_favorite(by_name.id.unwrap(), true, Some(db.clone())).unwrap();
+    let favorite = db_get_project_by_name("round_trip", Some(db.clone()))
+        .unwrap()
+        .unwrap();
+    assert!(favorite.is_favorite);
+    assert_eq!(favorite.id, by_name.id);
+}
+
+// =============================================================================
+// Schema Drift Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_schema_drift_is_empty_on_migrated_database() {
+    let (db, _temp_dir) = setup_test_database().await;
+
+    let drift = db_check_schema_drift(Some(db)).unwrap();
+
+    assert!(drift.is_empty(), "Unexpected drift: {:?}", drift);
+}
+
+#[tokio::test]
+async fn test_p1_schema_drift_reports_missing_table() {
+    // GIVEN: A database without the templates table
+    let (db, _temp_dir) = setup_test_database_at(2).await;
+
+    // WHEN: Checking for drift
+    let drift = db_check_schema_drift(Some(db)).unwrap();
+
+    // THEN: The missing table is reported
+    assert!(
+        drift
+            .iter()
+            .any(|m| m.contains("'templates' does not exist")),
+        "Expected missing templates table, got: {:?}",
+        drift
+    );
+}
+
+#[tokio::test]
+async fn test_p1_schema_drift_reports_missing_column() {
+    // GIVEN: A database from before is_favorite was added
+    let (db, _temp_dir) = setup_test_database_at(5).await;
+
+    // WHEN: Checking for drift
+    let drift = db_check_schema_drift(Some(db)).unwrap();
+
+    // THEN: The missing column is reported
+    assert!(
+        drift.iter().any(|m| m.contains("'projects.is_favorite'")),
+        "Expected missing is_favorite column, got: {:?}",
+        drift
+    );
+}
+
+#[test]
+fn test_p1_schema_drift_requires_database() {
+    assert!(db_check_schema_drift(None).is_err());
+}
@@ -14,6 +14,7 @@
 
 //! Unit tests for database entities module.
 
+use am::assets::AssetType;
 use am::database::entities::{Project, ProjectConfiguration, Template, TemplateSource};
 
 // =============================================================================
@@ -29,6 +30,9 @@ fn test_p1_project_configuration_to_project_converts_correctly() {
         data_dir: "data".to_string(),
         build_dir: "build".to_string(),
         version: 1,
+        hooks: Default::default(),
+        allow_json_comments: false,
+        asset_dirs: Default::default(),
     };
 
     let project = config.to_project("/path/to/project");
@@ -59,6 +63,9 @@ fn test_p2_project_configuration_serializes_to_json() {
         data_dir: "data".to_string(),
         build_dir: "build".to_string(),
         version: 1,
+        hooks: Default::default(),
+        allow_json_comments: false,
+        asset_dirs: Default::default(),
     };
 
     let json = serde_json::to_string(&config);
@@ -89,6 +96,51 @@ fn test_p2_project_configuration_deserializes_from_json() {
     assert_eq!(config.version, 2);
 }
 
+#[test]
+fn test_p0_project_configuration_asset_dirs_override_defaults() {
+    let json = r#"{
+        "name": "mapped_project",
+        "default_configuration": "pc.config.amconfig",
+        "sources_dir": "sources",
+        "data_dir": "data",
+        "build_dir": "build",
+        "version": 1,
+        "asset_dirs": { "sound": "sfx", "switch_container": "containers" }
+    }"#;
+
+    let config: ProjectConfiguration = serde_json::from_str(json).unwrap();
+
+    assert_eq!(config.asset_dir(AssetType::Sound), "sfx");
+    assert_eq!(config.asset_dir(AssetType::SwitchContainer), "containers");
+    assert_eq!(config.asset_dir(AssetType::Event), "events");
+}
+
+#[test]
+fn test_p1_project_configuration_omits_empty_asset_dirs() {
+    let config = ProjectConfiguration::default();
+
+    assert_eq!(config.asset_dir(AssetType::Sound), "sounds");
+    let json_str = serde_json::to_string(&config).unwrap();
+    assert!(!json_str.contains("asset_dirs"));
+}
+
+#[test]
+fn test_p2_project_configuration_rejects_unknown_asset_dir_type() {
+    let json = r#"{
+        "name": "mapped_project",
+        "default_configuration": "pc.config.amconfig",
+        "sources_dir": "sources",
+        "data_dir": "data",
+        "build_dir": "build",
+        "version": 1,
+        "asset_dirs": { "voice": "vo" }
+    }"#;
+
+    let config: Result<ProjectConfiguration, _> = serde_json::from_str(json);
+
+    assert!(config.is_err());
+}
+
 // =============================================================================
 // Project Tests
 // =============================================================================
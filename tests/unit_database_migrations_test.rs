@@ -14,7 +14,9 @@
 
 //! Unit tests for database migrations module.
 
+use am::common::errors::{CliError, codes};
 use am::database::Database;
+use am::database::migrations::MigrationManager;
 use tempfile::tempdir;
 
 // =============================================================================
@@ -186,7 +188,7 @@ async fn test_p0_run_migrations_is_idempotent() {
     let count: Vec<i32> = stmt
         .query_map([], |row| row.get(0))
         .expect("Failed to query");
-    assert_eq!(count[0], 6, "Should still have exactly 6 migrations");
+    assert_eq!(count[0], 7, "Should still have exactly 7 migrations");
 }
 
 // =============================================================================
@@ -297,3 +299,257 @@ async fn test_p2_projects_table_has_unique_name_constraint() {
         "Duplicate project name should fail unique constraint"
     );
 }
+
+// =============================================================================
+// Targeted Migration Tests
+// =============================================================================
+
+/// Helper to check whether a table exists in the database.
+fn table_exists(db: &Database, table: &str) -> bool {
+    let stmt = db
+        .prepare(&format!(
+            "SELECT name FROM sqlite_master WHERE type='table' AND name='{}'",
+            table
+        ))
+        .expect("Failed to prepare");
+    let tables: Vec<String> = stmt
+        .query_map([], |row| row.get(0))
+        .expect("Failed to query");
+    !tables.is_empty()
+}
+
+#[tokio::test]
+async fn test_p0_run_migrations_to_stops_at_target_then_completes_to_latest() {
+    // GIVEN: A fresh database
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join("test.db");
+    let mut db = Database::new(&db_path).expect("Failed to create database");
+    let manager = MigrationManager::new();
+
+    // WHEN: Migrating to version 2
+    db.run_migrations_to(2)
+        .await
+        .expect("Migration to version 2 should succeed");
+
+    // THEN: Only the first two migrations are applied
+    assert_eq!(manager.get_current_version(&db).unwrap(), 2);
+    assert!(table_exists(&db, "projects"), "projects table should exist");
+    assert!(
+        !table_exists(&db, "templates"),
+        "templates table should not exist yet"
+    );
+    assert!(
+        !table_exists(&db, "configuration"),
+        "configuration table should not exist yet"
+    );
+
+    // WHEN: Completing the migration to the latest version
+    db.run_migrations_to(manager.latest_version())
+        .await
+        .expect("Migration to latest should succeed");
+
+    // THEN: All tables exist and every migration is recorded once
+    assert_eq!(
+        manager.get_current_version(&db).unwrap(),
+        manager.latest_version()
+    );
+    assert!(
+        table_exists(&db, "templates"),
+        "templates table should exist"
+    );
+    assert!(
+        table_exists(&db, "configuration"),
+        "configuration table should exist"
+    );
+    let stmt = db
+        .prepare("SELECT COUNT(*) FROM schema_migrations")
+        .expect("Failed to prepare");
+    let count: Vec<u32> = stmt
+        .query_map([], |row| row.get(0))
+        .expect("Failed to query");
+    assert_eq!(count[0], manager.latest_version());
+}
+
+#[tokio::test]
+async fn test_p0_pending_migrations_to_lists_migrations_in_order() {
+    // GIVEN: A database migrated to version 2
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let mut db = Database::new(temp_dir.path().join("test.db")).expect("Failed to create db");
+    db.run_migrations_to(2)
+        .await
+        .expect("Migration should succeed");
+    let manager = MigrationManager::new();
+
+    // WHEN: Listing migrations up to version 4
+    let pending = manager
+        .pending_migrations_to(&db, 4)
+        .expect("Target should be valid");
+
+    // THEN: Versions 3 and 4 are pending
+    let versions: Vec<u32> = pending.iter().map(|m| m.version).collect();
+    assert_eq!(versions, vec![3, 4]);
+}
+
+#[tokio::test]
+async fn test_p1_run_migrations_to_current_version_is_noop() {
+    // GIVEN: A database migrated to version 3
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let mut db = Database::new(temp_dir.path().join("test.db")).expect("Failed to create db");
+    db.run_migrations_to(3)
+        .await
+        .expect("Migration should succeed");
+
+    // WHEN/THEN: Migrating to the same version succeeds without changes
+    assert!(db.run_migrations_to(3).await.is_ok());
+    assert_eq!(MigrationManager::new().get_current_version(&db).unwrap(), 3);
+}
+
+#[tokio::test]
+async fn test_p1_run_migrations_to_rejects_older_version() {
+    // GIVEN: A database migrated to version 4
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let mut db = Database::new(temp_dir.path().join("test.db")).expect("Failed to create db");
+    db.run_migrations_to(4)
+        .await
+        .expect("Migration should succeed");
+
+    // WHEN: Migrating backwards
+    let result = db.run_migrations_to(2).await;
+
+    // THEN: Should fail with a validation error and leave the schema untouched
+    let err = result.expect_err("Backward migration should fail");
+    let cli_err = err
+        .downcast_ref::<CliError>()
+        .expect("Should be a CliError");
+    assert_eq!(cli_err.code, codes::ERR_VALIDATION_FIELD);
+    assert_eq!(MigrationManager::new().get_current_version(&db).unwrap(), 4);
+}
+
+#[tokio::test]
+async fn test_p1_run_migrations_to_rejects_unknown_version() {
+    // GIVEN: A fresh database
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let mut db = Database::new(temp_dir.path().join("test.db")).expect("Failed to create db");
+    let latest = MigrationManager::new().latest_version();
+
+    // WHEN: Migrating to a version that doesn't exist
+    let result = db.run_migrations_to(latest + 1).await;
+
+    // THEN: Should fail before applying anything
+    let err = result.expect_err("Unknown version should fail");
+    assert_eq!(
+        err.downcast_ref::<CliError>().map(|e| e.code),
+        Some(codes::ERR_VALIDATION_FIELD)
+    );
+    assert!(!table_exists(&db, "schema_migrations"));
+}
+
+// =============================================================================
+// Cached Schema Version Tests
+// =============================================================================
+
+/// SQL statements traced on the connection under test.
+static TRACED_STATEMENTS: std::sync::Mutex<Vec<String>> = std::sync::Mutex::new(Vec::new());
+
+fn record_statement(sql: &str) {
+    TRACED_STATEMENTS.lock().unwrap().push(sql.to_string());
+}
+
+/// Run migrations on `db` and return the SQL statements it issued.
+fn traced_run_migrations(db: &Database) -> Vec<String> {
+    let conn = db.get_connection();
+    TRACED_STATEMENTS.lock().unwrap().clear();
+    conn.lock().unwrap().trace(Some(record_statement));
+
+    MigrationManager::new()
+        .run_migrations(db)
+        .expect("Migrations should succeed");
+
+    conn.lock().unwrap().trace(None);
+    TRACED_STATEMENTS.lock().unwrap().clone()
+}
+
+fn is_migration_query(sql: &str) -> bool {
+    sql.contains("schema_migrations") || sql.contains("sqlite_master")
+}
+
+#[tokio::test]
+async fn test_p0_migrations_cache_latest_version() {
+    // GIVEN: A fresh database
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let mut db = Database::new(temp_dir.path().join("test.db")).expect("Failed to create database");
+    let manager = MigrationManager::new();
+    assert_eq!(manager.get_cached_version(&db).unwrap(), 0);
+
+    // WHEN: Migrating step by step
+    db.run_migrations_to(2).await.unwrap();
+    assert_eq!(manager.get_cached_version(&db).unwrap(), 2);
+    db.run_migrations().await.unwrap();
+
+    // THEN: The cached version follows the applied migrations
+    assert_eq!(
+        manager.get_cached_version(&db).unwrap(),
+        manager.latest_version()
+    );
+}
+
+#[tokio::test]
+async fn test_p0_up_to_date_database_skips_migration_queries() {
+    // GIVEN: A migrated database whose cached version was lost (as for databases
+    // migrated before the cache existed)
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let mut db = Database::new(temp_dir.path().join("test.db")).expect("Failed to create database");
+    db.run_migrations().await.unwrap();
+    db.execute_batch("PRAGMA user_version = 0").unwrap();
+
+    // WHEN: Running migrations on the next startup
+    let slow_path = traced_run_migrations(&db);
+
+    // THEN: The migrations table is checked and the cached version is backfilled
+    assert!(
+        slow_path.iter().any(|sql| is_migration_query(sql)),
+        "Expected the full migration check, got: {:?}",
+        slow_path
+    );
+    let manager = MigrationManager::new();
+    assert_eq!(
+        manager.get_cached_version(&db).unwrap(),
+        manager.latest_version()
+    );
+
+    // WHEN: Running migrations again
+    let fast_path = traced_run_migrations(&db);
+
+    // THEN: Only the cached version is read
+    assert!(
+        !fast_path.iter().any(|sql| is_migration_query(sql)),
+        "Expected no migration queries, got: {:?}",
+        fast_path
+    );
+    assert_eq!(fast_path, vec!["PRAGMA user_version".to_string()]);
+}
+
+#[tokio::test]
+async fn test_p1_stale_cached_version_falls_back_to_full_check() {
+    // GIVEN: A database at version 3 whose cache claims an older version
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let mut db = Database::new(temp_dir.path().join("test.db")).expect("Failed to create database");
+    db.run_migrations_to(3).await.unwrap();
+    db.execute_batch("PRAGMA user_version = 1").unwrap();
+
+    // WHEN: Running migrations
+    db.run_migrations()
+        .await
+        .expect("Migrations should succeed");
+
+    // THEN: Only the missing migrations were applied
+    let manager = MigrationManager::new();
+    assert_eq!(
+        manager.get_current_version(&db).unwrap(),
+        manager.latest_version()
+    );
+    assert_eq!(
+        manager.get_cached_version(&db).unwrap(),
+        manager.latest_version()
+    );
+}
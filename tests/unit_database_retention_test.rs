@@ -0,0 +1,260 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the retention limits of the history tables.
+
+use am::commands::sudo::{DatabaseCommands, SudoCommands};
+use am::database::retention::{
+    DEFAULT_MAX_AGE_DAYS, DEFAULT_MAX_ROWS, RETAINED_TABLES, RetentionPolicy, max_age_days_key,
+    max_rows_key, retention_policy, run_maintenance, run_maintenance_in_batches,
+};
+use am::database::{Database, db_set_config};
+use am::testing::{ScriptedInput, TestEnv};
+
+async fn setup_test_env() -> TestEnv {
+    TestEnv::new()
+        .await
+        .expect("Failed to create test environment")
+}
+
+/// Create `table` as an append-only history table, replacing the one of the schema.
+fn create_history_table(database: &Database, table: &str) {
+    database
+        .execute_batch(&format!(
+            "DROP TABLE IF EXISTS {table};
+            CREATE TABLE {table} (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                payload TEXT NOT NULL,
+                created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+            );"
+        ))
+        .unwrap();
+}
+
+/// Insert `count` rows into `table`, created `age_days` days ago, in one transaction.
+fn seed_rows(database: &Database, table: &str, count: usize, age_days: u64) {
+    let conn = database.get_connection();
+    let conn = conn.lock().unwrap();
+    let transaction = conn.unchecked_transaction().unwrap();
+    {
+        let mut insert = transaction
+            .prepare(&format!(
+                "INSERT INTO {} (payload, created_at) VALUES (?1, datetime('now', ?2))",
+                table
+            ))
+            .unwrap();
+        for n in 0..count {
+            insert
+                .execute(rusqlite::params![
+                    format!("row {}", n),
+                    format!("-{} days", age_days)
+                ])
+                .unwrap();
+        }
+    }
+    transaction.commit().unwrap();
+}
+
+fn maintain() -> SudoCommands {
+    SudoCommands::Database {
+        command: DatabaseCommands::Maintain,
+    }
+}
+
+fn row_count(database: &Database, table: &str) -> usize {
+    let conn = database.get_connection();
+    let conn = conn.lock().unwrap();
+    conn.query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| {
+        row.get(0)
+    })
+    .unwrap()
+}
+
+fn payloads(database: &Database, table: &str) -> Vec<String> {
+    let conn = database.get_connection();
+    let conn = conn.lock().unwrap();
+    let mut statement = conn
+        .prepare(&format!("SELECT payload FROM {} ORDER BY rowid", table))
+        .unwrap();
+    statement
+        .query_map([], |row| row.get(0))
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+}
+
+// =============================================================================
+// Retention Policy Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_policy_defaults_and_configuration() {
+    let env = setup_test_env().await;
+    let database = env.database();
+
+    assert_eq!(
+        retention_policy(&database, "command_history").unwrap(),
+        RetentionPolicy {
+            max_rows: Some(DEFAULT_MAX_ROWS),
+            max_age_days: Some(DEFAULT_MAX_AGE_DAYS),
+        }
+    );
+
+    db_set_config(&max_rows_key("command_history"), "50", "number", env.db()).unwrap();
+    db_set_config(
+        &max_age_days_key("command_history"),
+        "0",
+        "number",
+        env.db(),
+    )
+    .unwrap();
+    assert_eq!(
+        retention_policy(&database, "command_history").unwrap(),
+        RetentionPolicy {
+            max_rows: Some(50),
+            max_age_days: None,
+        }
+    );
+}
+
+#[test]
+fn test_p1_every_retained_table_has_a_timestamp_column() {
+    assert!(!RETAINED_TABLES.is_empty());
+    for table in RETAINED_TABLES {
+        assert!(!table.timestamp_column.is_empty(), "{}", table.name);
+    }
+}
+
+// =============================================================================
+// Maintenance Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_maintenance_keeps_the_newest_rows_within_the_row_limit() {
+    let env = setup_test_env().await;
+    let database = env.database();
+    create_history_table(&database, "command_history");
+    seed_rows(&database, "command_history", 1_250, 0);
+    db_set_config(&max_rows_key("command_history"), "100", "number", env.db()).unwrap();
+
+    let pruned = run_maintenance(&database).unwrap();
+
+    assert_eq!(pruned["command_history"], 1_150);
+    assert_eq!(row_count(&database, "command_history"), 100);
+    let remaining = payloads(&database, "command_history");
+    assert_eq!(remaining.first().unwrap(), "row 1150");
+    assert_eq!(remaining.last().unwrap(), "row 1249");
+}
+
+#[tokio::test]
+async fn test_p0_maintenance_deletes_rows_older_than_the_age_limit() {
+    let env = setup_test_env().await;
+    let database = env.database();
+    create_history_table(&database, "audit_log");
+    seed_rows(&database, "audit_log", 30, 40);
+    seed_rows(&database, "audit_log", 20, 1);
+    db_set_config(&max_age_days_key("audit_log"), "30", "number", env.db()).unwrap();
+
+    let pruned = run_maintenance(&database).unwrap();
+
+    assert_eq!(pruned["audit_log"], 30);
+    assert_eq!(row_count(&database, "audit_log"), 20);
+}
+
+#[tokio::test]
+async fn test_p1_maintenance_deletes_in_bounded_batches() {
+    let env = setup_test_env().await;
+    let database = env.database();
+    create_history_table(&database, "telemetry_events");
+    seed_rows(&database, "telemetry_events", 95, 200);
+    seed_rows(&database, "telemetry_events", 10, 0);
+
+    let pruned = run_maintenance_in_batches(&database, 7).unwrap();
+
+    assert_eq!(pruned["telemetry_events"], 95);
+    assert_eq!(row_count(&database, "telemetry_events"), 10);
+}
+
+#[tokio::test]
+async fn test_p1_maintenance_leaves_unlimited_tables_alone() {
+    let env = setup_test_env().await;
+    let database = env.database();
+    create_history_table(&database, "project_usage");
+    seed_rows(&database, "project_usage", 40, 365);
+    db_set_config(&max_rows_key("project_usage"), "0", "number", env.db()).unwrap();
+    db_set_config(&max_age_days_key("project_usage"), "0", "number", env.db()).unwrap();
+
+    let pruned = run_maintenance(&database).unwrap();
+
+    assert_eq!(pruned["project_usage"], 0);
+    assert_eq!(row_count(&database, "project_usage"), 40);
+}
+
+#[tokio::test]
+async fn test_p1_maintenance_skips_missing_tables() {
+    let env = setup_test_env().await;
+    let database = env.database();
+
+    // Only the command history tables are in the schema so far
+    let pruned = run_maintenance(&database).unwrap();
+
+    assert_eq!(
+        pruned.keys().collect::<Vec<_>>(),
+        vec!["command_history", "project_usage"]
+    );
+}
+
+// =============================================================================
+// Maintain Command Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_maintain_reports_rows_pruned_per_table() {
+    let env = setup_test_env().await;
+    let database = env.database();
+    create_history_table(&database, "command_history");
+    create_history_table(&database, "audit_log");
+    seed_rows(&database, "command_history", 12, 0);
+    db_set_config(&max_rows_key("command_history"), "5", "number", env.db()).unwrap();
+
+    let run = env.run(&maintain(), ScriptedInput::new()).await;
+
+    assert!(
+        run.result.is_ok(),
+        "Maintain should succeed: {:?}",
+        run.result
+    );
+    let data = run.last_success().expect("Should have success output");
+    assert_eq!(
+        data["pruned"],
+        serde_json::json!({ "audit_log": 0, "command_history": 7, "project_usage": 0 })
+    );
+}
+
+#[tokio::test]
+async fn test_p2_maintain_on_fresh_database_prunes_nothing() {
+    let env = setup_test_env().await;
+
+    let run = env.run(&maintain(), ScriptedInput::new()).await;
+
+    assert!(
+        run.result.is_ok(),
+        "Maintain should succeed: {:?}",
+        run.result
+    );
+    assert_eq!(
+        run.last_success().unwrap()["pruned"],
+        serde_json::json!({ "command_history": 0, "project_usage": 0 })
+    );
+}
@@ -0,0 +1,274 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the size check of the database file: its thresholds, the warning listing
+//! the largest tables, and the `db_oversized` flag of the JSON envelopes.
+
+use am::common::errors::{CliError, codes};
+use am::common::settings::Settings;
+use am::database::size::{
+    CONFIG_DB_SIZE_LIMIT, CONFIG_DB_SIZE_WARNING, SizeReport, SizeStatus, SizeThresholds,
+    check_database_size, database_file_size, present_size_report,
+};
+use am::database::{Database, db_set_config};
+use am::presentation::{JsonOutput, Output, OutputMode};
+use am::testing::{MockOutput, OutputCall};
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use tempfile::TempDir;
+
+const MIB: u64 = 1024 * 1024;
+
+async fn migrated_database() -> (TempDir, Arc<Database>) {
+    let dir = tempfile::tempdir().unwrap();
+    let mut database = Database::new(dir.path().join("am.db")).unwrap();
+    database.run_migrations().await.unwrap();
+    (dir, Arc::new(database))
+}
+
+/// Grow the file at `path` to `size` bytes without writing them.
+fn grow_sparse(path: &Path, size: u64) {
+    OpenOptions::new()
+        .write(true)
+        .open(path)
+        .unwrap()
+        .set_len(size)
+        .unwrap();
+}
+
+fn set_thresholds(database: &Arc<Database>, warning: &str, limit: &str) {
+    db_set_config(
+        CONFIG_DB_SIZE_WARNING,
+        warning,
+        "string",
+        Some(database.clone()),
+    )
+    .unwrap();
+    db_set_config(
+        CONFIG_DB_SIZE_LIMIT,
+        limit,
+        "string",
+        Some(database.clone()),
+    )
+    .unwrap();
+}
+
+fn thresholds(config: &[(&str, &str)], env: &[(&str, &str)]) -> SizeThresholds {
+    let config: BTreeMap<String, String> = config
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    let env: BTreeMap<String, String> = env
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    let settings = Settings::resolve(&BTreeMap::new(), |name| env.get(name).cloned(), &config);
+    SizeThresholds::from_settings(&settings)
+}
+
+/// A writer whose contents stay readable after it is moved into a `JsonOutput`.
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+// =============================================================================
+// Threshold Tests
+// =============================================================================
+
+#[test]
+fn test_p0_thresholds_default_to_256mib_and_1gib() {
+    assert_eq!(
+        thresholds(&[], &[]),
+        SizeThresholds {
+            warning: 256 * MIB,
+            limit: 1024 * MIB,
+        }
+    );
+}
+
+#[test]
+fn test_p0_environment_overrides_configured_thresholds() {
+    let thresholds = thresholds(
+        &[
+            (CONFIG_DB_SIZE_WARNING, "10MiB"),
+            (CONFIG_DB_SIZE_LIMIT, "20M"),
+        ],
+        &[("AM_DB_SIZE_WARNING", "5M")],
+    );
+
+    assert_eq!(thresholds.warning, 5 * MIB);
+    assert_eq!(thresholds.limit, 20 * MIB);
+}
+
+#[test]
+fn test_p1_invalid_threshold_falls_back_to_its_default() {
+    let thresholds = thresholds(&[(CONFIG_DB_SIZE_WARNING, "lots")], &[]);
+
+    assert_eq!(thresholds.warning, 256 * MIB);
+}
+
+// =============================================================================
+// Size Check Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_fresh_database_is_normal() {
+    let (_dir, database) = migrated_database().await;
+
+    let report = check_database_size(&database).unwrap();
+
+    assert_eq!(report.status, SizeStatus::Normal);
+    assert!(report.largest_tables.is_empty());
+}
+
+#[tokio::test]
+async fn test_p0_database_above_warning_threshold_lists_largest_tables() {
+    let (_dir, database) = migrated_database().await;
+    set_thresholds(&database, "2MiB", "1GiB");
+    grow_sparse(database.path(), 4 * MIB);
+
+    let report = check_database_size(&database).unwrap();
+
+    assert_eq!(report.status, SizeStatus::Large);
+    assert!(report.size >= 4 * MIB);
+    assert_eq!(report.largest_tables.len(), 3);
+    assert!(
+        report
+            .largest_tables
+            .windows(2)
+            .all(|pair| pair[0].1 >= pair[1].1),
+        "Tables should be sorted by row count: {:?}",
+        report.largest_tables
+    );
+}
+
+#[tokio::test]
+async fn test_p0_database_above_limit_is_oversized() {
+    let (_dir, database) = migrated_database().await;
+    set_thresholds(&database, "1MiB", "2MiB");
+    grow_sparse(database.path(), 4 * MIB);
+
+    let report = check_database_size(&database).unwrap();
+
+    assert_eq!(report.status, SizeStatus::Oversized);
+}
+
+#[test]
+fn test_p1_file_size_includes_the_write_ahead_log() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("am.db");
+    std::fs::write(&path, vec![0u8; 100]).unwrap();
+    std::fs::write(dir.path().join("am.db-wal"), vec![0u8; 50]).unwrap();
+
+    assert_eq!(database_file_size(&path), 150);
+    assert_eq!(database_file_size(&dir.path().join("missing.db")), 0);
+}
+
+// =============================================================================
+// Presentation Tests
+// =============================================================================
+
+fn report(status: SizeStatus) -> SizeReport {
+    SizeReport {
+        size: 300 * MIB,
+        status,
+        largest_tables: vec![
+            ("telemetry_events".to_string(), 90_000),
+            ("command_history".to_string(), 12_000),
+        ],
+    }
+}
+
+#[test]
+fn test_p0_large_database_warning_suggests_maintenance() {
+    let output = MockOutput::new(OutputMode::Interactive);
+
+    present_size_report(&report(SizeStatus::Large), &output);
+
+    let warnings: Vec<String> = output
+        .calls()
+        .into_iter()
+        .filter_map(|call| match call {
+            OutputCall::Warning(message) => Some(message),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("telemetry_events (90000 rows)"));
+    assert!(warnings[0].contains("am sudo database maintain"));
+}
+
+#[test]
+fn test_p1_normal_database_is_not_reported() {
+    let output = MockOutput::new(OutputMode::Interactive);
+
+    present_size_report(&report(SizeStatus::Normal), &output);
+
+    assert!(output.calls().is_empty());
+}
+
+#[test]
+fn test_p0_oversized_database_is_flagged_in_json_meta() {
+    let buffer = SharedBuffer::default();
+    let output = JsonOutput::new().with_writer(buffer.clone());
+
+    present_size_report(&report(SizeStatus::Oversized), &output);
+    output.success(json!("done"), None);
+    output.error(
+        &anyhow::Error::from(CliError::new(
+            codes::ERR_VALIDATION_FIELD,
+            "failed",
+            "because",
+        )),
+        codes::ERR_VALIDATION_FIELD,
+        None,
+    );
+
+    let contents = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+    let envelopes: Vec<Value> = serde_json::Deserializer::from_str(&contents)
+        .into_iter()
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(envelopes.len(), 2);
+    for envelope in envelopes {
+        assert_eq!(envelope["meta"]["db_oversized"], true);
+    }
+}
+
+#[test]
+fn test_p1_json_meta_has_no_flag_by_default() {
+    let buffer = SharedBuffer::default();
+    let output = JsonOutput::new().with_writer(buffer.clone());
+
+    present_size_report(&report(SizeStatus::Large), &output);
+    output.success(json!("done"), None);
+
+    let contents = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+    let envelope: Value = serde_json::from_str(&contents).unwrap();
+    assert!(envelope.get("meta").is_none());
+}
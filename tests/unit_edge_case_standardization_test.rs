@@ -29,6 +29,7 @@ mod common;
 use am::assets::{AssetType, ProjectContext};
 use am::commands::asset::{SoundCommands, handle_sound_command};
 use am::common::errors::codes;
+use am::common::ignore::WalkOptions;
 use am::input::NonInteractiveInput;
 use common::fixtures::{AssetTestFixture, CaptureOutput, TestProjectFixture};
 use serde_json::json;
@@ -247,7 +248,17 @@ async fn test_p1_list_warns_about_missing_audio_files() {
     let input = NonInteractiveInput;
     let output = CaptureOutput::json();
 
-    let result = handle_sound_command(&SoundCommands::List {}, None, &input, &output).await;
+    let result = handle_sound_command(
+        &SoundCommands::List {
+            limit: Default::default(),
+        },
+        None,
+        WalkOptions::default(),
+        None,
+        &input,
+        &output,
+    )
+    .await;
 
     if let Some(dir) = original_dir {
         let _ = std::env::set_current_dir(dir);
@@ -298,7 +309,17 @@ async fn test_p1_list_missing_sounds_dir_returns_info_message() {
     let input = NonInteractiveInput;
     let output = CaptureOutput::json();
 
-    let result = handle_sound_command(&SoundCommands::List {}, None, &input, &output).await;
+    let result = handle_sound_command(
+        &SoundCommands::List {
+            limit: Default::default(),
+        },
+        None,
+        WalkOptions::default(),
+        None,
+        &input,
+        &output,
+    )
+    .await;
 
     if let Some(dir) = original_dir {
         let _ = std::env::set_current_dir(dir);
@@ -360,6 +381,8 @@ async fn test_p1_create_rejects_invalid_gain_value() {
             spatialization: None,
         },
         None,
+        WalkOptions::default(),
+        None,
         &input,
         &output,
     )
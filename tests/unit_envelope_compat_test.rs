@@ -0,0 +1,208 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compatibility tests of the `--json` envelopes.
+//!
+//! Each test writes an envelope and compares it byte for byte with its golden file in
+//! `tests/snapshots/envelopes/`. A failure means tools parsing the output may break:
+//! removing or renaming a field requires bumping `ENVELOPE_SCHEMA_VERSION`, while an
+//! added field only requires refreshing the golden files with:
+//!
+//! ```bash
+//! UPDATE_SNAPSHOTS=1 cargo test --test unit_envelope_compat_test
+//! ```
+
+use am::common::errors::{CliError, codes};
+use am::common::log_tail::log_line_to_json;
+use am::presentation::{ColumnSpec, ENVELOPE_SCHEMA_VERSION, JsonOutput, Output};
+use serde_json::{Value, json};
+use std::io::Write;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+const GOLDEN_DIR: &str = "tests/snapshots/envelopes";
+
+/// A writer whose contents stay readable after it is moved into a `JsonOutput`.
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl SharedBuffer {
+    fn contents(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The envelopes written by `write` to a `JsonOutput`.
+fn capture(write: impl FnOnce(&JsonOutput)) -> String {
+    let buffer = SharedBuffer::default();
+    let output = JsonOutput::new().with_writer(buffer.clone());
+    write(&output);
+    buffer.contents()
+}
+
+fn assert_matches_golden(name: &str, actual: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join(GOLDEN_DIR)
+        .join(name);
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path).expect("Golden file should exist");
+    assert_eq!(
+        actual, expected,
+        "The envelope differs from {}. Bump ENVELOPE_SCHEMA_VERSION if a field was removed \
+         or renamed, then refresh the golden files with UPDATE_SNAPSHOTS=1",
+        name
+    );
+}
+
+// =============================================================================
+// Golden Envelopes
+// =============================================================================
+
+#[test]
+fn test_p0_success_envelope_matches_golden() {
+    let actual = capture(|output| {
+        output.success(
+            json!({ "name": "footstep", "id": 42, "tags": ["foley", "player"] }),
+            None,
+        )
+    });
+
+    assert_matches_golden("success.json", &actual);
+}
+
+#[test]
+fn test_p0_error_envelope_with_every_optional_field_matches_golden() {
+    let error = CliError::new(
+        codes::ERR_ASSET_NOT_FOUND,
+        "Sound not found",
+        "No sound named 'footstep' in the project",
+    )
+    .with_suggestion("Run 'am asset sound list' to see the sounds")
+    .with_context("sources/sounds/footstep.json");
+
+    let actual = capture(|output| {
+        output.begin_stages(&["validate", "build", "package"]);
+        output.enter_stage(0);
+        output.enter_stage(1);
+        output.error(
+            &anyhow::Error::from(error),
+            codes::ERR_ASSET_NOT_FOUND,
+            None,
+        );
+    });
+
+    assert_matches_golden("error.json", &actual);
+}
+
+#[test]
+fn test_p0_table_envelope_matches_golden() {
+    let columns = [ColumnSpec::new("name", "Name"), ColumnSpec::new("id", "ID")];
+    let rows = json!([
+        { "id": 42, "name": "footstep", "bus": "sfx" },
+        { "id": 7, "name": "ambience", "bus": "music" },
+    ]);
+
+    let actual = capture(|output| output.table_with_columns(Some("Sounds"), &columns, rows));
+
+    assert_matches_golden("table.json", &actual);
+}
+
+#[test]
+fn test_p0_stream_events_match_golden() {
+    let mut entry =
+        log_line_to_json("[2026-05-04 10:20:30.123] [INFO] [am::commands::project] Build started");
+    // The timestamp is rendered in the local time zone of the machine.
+    let timestamp = entry["timestamp"].as_str().unwrap().to_string();
+    assert!(timestamp.starts_with("2026-05-04T10:20:30.123"));
+    entry["timestamp"] = json!("<timestamp>");
+    let other = log_line_to_json("stack backtrace:");
+
+    let actual = format!("{}\n{}\n", entry, other);
+
+    assert_matches_golden("stream_events.jsonl", &actual);
+}
+
+// =============================================================================
+// Versioning Tests
+// =============================================================================
+
+#[test]
+fn test_p0_every_envelope_carries_the_schema_version() {
+    let success = JsonOutput::build_success_response(json!(null));
+    let error = JsonOutput::build_error_response(&anyhow::anyhow!("boom"), 1);
+
+    assert_eq!(success.schema, ENVELOPE_SCHEMA_VERSION);
+    assert_eq!(error.schema, ENVELOPE_SCHEMA_VERSION);
+    assert_eq!(ENVELOPE_SCHEMA_VERSION, 1);
+}
+
+#[test]
+fn test_p1_golden_envelopes_match_the_schema_version() {
+    for name in ["success.json", "error.json", "table.json"] {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join(GOLDEN_DIR)
+            .join(name);
+        let envelope: Value =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+
+        assert_eq!(envelope["schema"], ENVELOPE_SCHEMA_VERSION, "{}", name);
+    }
+}
+
+// =============================================================================
+// JSON Schema Tests
+// =============================================================================
+
+#[test]
+fn test_p0_envelope_schema_describes_every_field() {
+    let schema = JsonOutput::envelope_schema();
+
+    let properties = schema["properties"].as_object().unwrap();
+    for field in ["schema", "ok", "value", "error", "meta"] {
+        assert!(properties.contains_key(field), "Missing {}", field);
+    }
+    let required: Vec<&str> = schema["required"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|field| field.as_str().unwrap())
+        .collect();
+    assert!(required.contains(&"schema"));
+    assert!(required.contains(&"ok"));
+    assert!(!required.contains(&"error"));
+}
+
+#[test]
+fn test_p1_envelope_schema_uses_the_serialized_error_fields() {
+    let schema = JsonOutput::envelope_schema().to_string();
+
+    assert!(schema.contains("\"type\""));
+    assert!(schema.contains("\"suggestion\""));
+    assert!(!schema.contains("type_"));
+}
@@ -26,9 +26,12 @@
 //! - P2: Edge cases, generic fallbacks, unknown codes
 
 use am::common::errors::{
-    CliError, codes, error_suggestion, error_type_name, project_already_exists,
-    project_not_registered, validation_error,
+    CliError, ERROR_CODE_RANGES, ERROR_CODES, asset_already_exists, asset_not_found, codes,
+    determine_exit_code, error_code_info, error_code_info_by_name, error_code_range,
+    error_suggestion, error_type_name, exit_code_for, exit_codes, project_already_exists,
+    project_not_initialized, project_not_registered, validation_error,
 };
+use std::collections::HashSet;
 
 // =============================================================================
 // P0: Core CliError Contract Tests
@@ -306,6 +309,10 @@ fn test_p1_error_type_name_sdk_specific() {
         error_type_name(codes::ERR_SDK_SCHEMA_LOAD_FAILED),
         "schema_load_failed"
     );
+    assert_eq!(
+        error_type_name(codes::ERR_SDK_VERSION_UNKNOWN),
+        "sdk_version_unknown"
+    );
 }
 
 // =============================================================================
@@ -456,3 +463,149 @@ fn test_p2_json_output_error_structure() {
     assert_eq!(error_details.suggestion, "Run 'am project register'");
     assert_eq!(error_details.context, Some("test/project".to_string()));
 }
+
+// =============================================================================
+// P0: Error Code Registry Tests
+// =============================================================================
+
+/// Names and values of the `pub const` items of the `codes` module, read from the source
+/// so a new constant can't be left out of `ERROR_CODES`.
+fn declared_codes() -> Vec<(String, i32)> {
+    let source = include_str!("../src/common/errors.rs");
+    let start = source.find("pub mod codes {").unwrap();
+    let end = start + source[start..].find("\n}\n").unwrap();
+
+    source[start..end]
+        .lines()
+        .filter_map(|line| {
+            let declaration = line.trim().strip_prefix("pub const ")?;
+            let (name, rest) = declaration.split_once(':')?;
+            let value = rest.split_once('=')?.1.trim().trim_end_matches(';');
+            Some((name.trim().to_string(), value.parse().unwrap()))
+        })
+        .collect()
+}
+
+#[test]
+fn test_p0_every_code_constant_is_registered_exactly_once() {
+    let declared = declared_codes();
+    assert!(
+        declared.len() >= 28,
+        "Parsed too few constants: {:?}",
+        declared
+    );
+
+    for (name, code) in &declared {
+        let entries: Vec<_> = ERROR_CODES
+            .iter()
+            .filter(|info| info.name == name)
+            .collect();
+        assert_eq!(
+            entries.len(),
+            1,
+            "{} should be registered exactly once",
+            name
+        );
+        assert_eq!(entries[0].code, *code, "{} has the wrong code", name);
+    }
+    assert_eq!(
+        ERROR_CODES.len(),
+        declared.len(),
+        "ERROR_CODES lists codes that aren't in the codes module"
+    );
+
+    let unique: HashSet<i32> = ERROR_CODES.iter().map(|info| info.code).collect();
+    assert_eq!(
+        unique.len(),
+        ERROR_CODES.len(),
+        "Error codes must be unique"
+    );
+}
+
+#[test]
+fn test_p0_mapping_functions_read_the_registry() {
+    for info in ERROR_CODES {
+        assert_eq!(error_type_name(info.code), info.type_name);
+        assert_eq!(error_suggestion(info.code), info.suggestion);
+        assert_eq!(exit_code_for(info.code), info.exit_code);
+
+        let err: anyhow::Error = CliError::new(info.code, "what", "why").into();
+        assert_eq!(determine_exit_code(&err), info.exit_code, "{}", info.name);
+    }
+    assert_eq!(
+        error_code_info(codes::ERR_PROJECT_NOT_REGISTERED).map(|info| info.name),
+        Some("ERR_PROJECT_NOT_REGISTERED")
+    );
+}
+
+#[test]
+fn test_p1_unregistered_codes_map_by_range() {
+    assert!(error_code_info(-26050).is_none());
+    assert_eq!(exit_code_for(-26050), exit_codes::SYSTEM_ERROR);
+    assert_eq!(exit_code_for(-28050), exit_codes::SYSTEM_ERROR);
+    assert_eq!(exit_code_for(-27050), exit_codes::USER_ERROR);
+    assert_eq!(exit_code_for(-30050), exit_codes::USER_ERROR);
+    assert_eq!(exit_code_for(12345), exit_codes::USER_ERROR);
+}
+
+#[test]
+fn test_p1_every_registered_code_is_explained() {
+    for info in ERROR_CODES {
+        assert!(!info.causes.is_empty(), "{} has no cause", info.name);
+        assert!(
+            error_code_range(info.code).is_some(),
+            "{} is outside of the ranges",
+            info.name
+        );
+        for command in info.related_commands {
+            assert!(command.starts_with("am "), "{}: {}", info.name, command);
+        }
+    }
+}
+
+#[test]
+fn test_p1_convenience_constructors_use_registered_causes() {
+    for error in [
+        project_not_registered("p"),
+        project_already_exists("p"),
+        project_not_initialized("/p"),
+        asset_not_found("Sound", "s"),
+        asset_already_exists("Sound", "s"),
+    ] {
+        let info = error_code_info(error.code).unwrap();
+        assert!(
+            info.causes.contains(&error.why.as_str()),
+            "'{}' is not a cause of {}",
+            error.why,
+            info.name
+        );
+    }
+}
+
+#[test]
+fn test_p1_codes_are_found_by_type_or_constant_name() {
+    let by_type = error_code_info_by_name("project_not_registered").unwrap();
+    assert_eq!(by_type.code, codes::ERR_PROJECT_NOT_REGISTERED);
+    assert_eq!(
+        error_code_info_by_name("err_project_not_registered"),
+        Some(by_type)
+    );
+    assert!(error_code_info_by_name("no_such_error").is_none());
+}
+
+#[test]
+fn test_p2_code_ranges_are_disjoint() {
+    for (i, range) in ERROR_CODE_RANGES.iter().enumerate() {
+        assert!(range.start < range.end);
+        for other in &ERROR_CODE_RANGES[i + 1..] {
+            assert!(other.start > range.end || other.end < range.start);
+        }
+    }
+    assert!(
+        error_code_range(-29500)
+            .unwrap()
+            .description
+            .contains("Project")
+    );
+    assert!(error_code_range(-25000).is_none());
+}
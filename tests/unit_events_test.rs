@@ -0,0 +1,226 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the lifecycle events of the library.
+
+use am::commands::project::ProjectCommands;
+use am::database::migrations::MigrationManager;
+use am::events::{self, CliEvent, EventSink, NoopSink};
+use am::testing::{ScriptedInput, TestEnv};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Serializes the tests of this file, since the sink is process-wide.
+static SINK_LOCK: Mutex<()> = Mutex::new(());
+
+/// Keeps every event it receives.
+#[derive(Default)]
+struct CollectingSink {
+    events: Mutex<Vec<CliEvent>>,
+}
+
+impl CollectingSink {
+    fn events(&self) -> Vec<CliEvent> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+impl EventSink for CollectingSink {
+    fn on_event(&self, event: &CliEvent) {
+        self.events.lock().unwrap().push(event.clone());
+    }
+}
+
+/// Install a new collecting sink, for as long as the returned guard is held.
+fn collect_events() -> (MutexGuard<'static, ()>, Arc<CollectingSink>) {
+    let guard = SINK_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let sink = Arc::new(CollectingSink::default());
+    events::install(sink.clone());
+    (guard, sink)
+}
+
+fn init_command(name: &str, no_register: bool) -> ProjectCommands {
+    ProjectCommands::Init {
+        name: Some(name.to_string()),
+        template: Some("default".to_string()),
+        asset_dirs: vec![],
+        no_register,
+        with_examples: false,
+        vars: vec![],
+        overwrite_dir: false,
+        replace_registration: false,
+        verify: false,
+        dry_run: false,
+    }
+}
+
+/// The events of `events` after the database migrations, with file paths reduced to
+/// their file names.
+fn describe(events: &[CliEvent]) -> Vec<String> {
+    events
+        .iter()
+        .filter(|event| !matches!(event, CliEvent::MigrationApplied { .. }))
+        .map(|event| match event {
+            CliEvent::FileWritten { path } => format!(
+                "file_written {}",
+                path.file_name().unwrap().to_string_lossy()
+            ),
+            CliEvent::ProjectRegistered { name, .. } => format!("project_registered {}", name),
+            other => other.name().to_string(),
+        })
+        .collect()
+}
+
+// =============================================================================
+// Event Sequence Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_project_init_emits_events_in_order() {
+    // GIVEN: A collecting sink installed before the environment is created
+    let (_guard, sink) = collect_events();
+    let env = TestEnv::new().await.unwrap();
+
+    // WHEN: Initializing a registered project from the default template
+    let run = env
+        .run_in(
+            env.home(),
+            &init_command("evented", false),
+            ScriptedInput::new(),
+        )
+        .await;
+    events::install(Arc::new(NoopSink));
+
+    // THEN: The migrations come first, then the files and the registration in the order
+    // they happen
+    assert!(run.result.is_ok(), "Init should succeed: {:?}", run.result);
+    let events = sink.events();
+    let migrations: Vec<u32> = events
+        .iter()
+        .take_while(|event| matches!(event, CliEvent::MigrationApplied { .. }))
+        .map(|event| match event {
+            CliEvent::MigrationApplied { version, .. } => *version,
+            _ => unreachable!(),
+        })
+        .collect();
+    let latest = MigrationManager::new().latest_version();
+    assert_eq!(migrations, (1..=latest).collect::<Vec<_>>());
+
+    assert_eq!(
+        describe(&events),
+        vec![
+            "file_written pc.config.json",
+            "file_written pc.buses.json",
+            "file_written pc.pipeline.json",
+            "project_registered evented",
+            "file_written .amproject",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_p0_registered_path_is_canonical() {
+    // GIVEN: A migrated environment and a collecting sink
+    let env = TestEnv::new().await.unwrap();
+    let (_guard, sink) = collect_events();
+
+    // WHEN: Initializing a registered project
+    let run = env
+        .run_in(
+            env.home(),
+            &init_command("located", false),
+            ScriptedInput::new(),
+        )
+        .await;
+    events::install(Arc::new(NoopSink));
+
+    // THEN: The registration reports the path stored in the registry
+    assert!(run.result.is_ok(), "Init should succeed: {:?}", run.result);
+    let registered = sink
+        .events()
+        .into_iter()
+        .find_map(|event| match event {
+            CliEvent::ProjectRegistered { name, path } => Some((name, path)),
+            _ => None,
+        })
+        .expect("The registration should be reported");
+    let project = am::database::db_get_project_by_name("located", env.db())
+        .unwrap()
+        .unwrap();
+    assert_eq!(registered, ("located".to_string(), project.path));
+}
+
+#[tokio::test]
+async fn test_p1_unregistered_init_emits_no_registration() {
+    // GIVEN: A migrated environment and a collecting sink
+    let env = TestEnv::new().await.unwrap();
+    let (_guard, sink) = collect_events();
+
+    // WHEN: Initializing a project with --no-register
+    let run = env
+        .run_in(
+            env.home(),
+            &init_command("unlisted", true),
+            ScriptedInput::new(),
+        )
+        .await;
+    events::install(Arc::new(NoopSink));
+
+    // THEN: Only the written files are reported
+    assert!(run.result.is_ok(), "Init should succeed: {:?}", run.result);
+    assert_eq!(
+        describe(&sink.events()),
+        vec![
+            "file_written pc.config.json",
+            "file_written pc.buses.json",
+            "file_written pc.pipeline.json",
+            "file_written .amproject",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_p1_migrated_database_emits_no_migration() {
+    // GIVEN: A migrated environment and a collecting sink
+    let env = TestEnv::new().await.unwrap();
+    let (_guard, sink) = collect_events();
+
+    // WHEN: Running the migrations again
+    let mut database = am::database::Database::new(env.home().join("am.db")).unwrap();
+    database.run_migrations().await.unwrap();
+    events::install(Arc::new(NoopSink));
+
+    // THEN: Nothing is reported
+    assert!(sink.events().is_empty());
+}
+
+// =============================================================================
+// JSON Tests
+// =============================================================================
+
+#[test]
+fn test_p1_event_json_names_the_event() {
+    let event = CliEvent::CommandFinished {
+        command: "am project init".to_string(),
+        success: true,
+    };
+
+    assert_eq!(
+        event.to_json(),
+        serde_json::json!({
+            "event": "command_finished",
+            "command": "am project init",
+            "success": true,
+        })
+    );
+}
@@ -197,7 +197,8 @@ fn test_no_json_flag_selects_interactive_output_mode() {
 
 #[cfg(test)]
 mod non_interactive_tests {
-    use am::input::{Input, InputMode, create_input};
+    use am::input::{Input, InputMode, PromptBlocked, SelectOption, create_input, with_flag_hint};
+    use am::testing::ScriptedInput;
 
     #[test]
     fn test_create_input_interactive_by_default() {
@@ -210,7 +211,7 @@ mod non_interactive_tests {
     fn test_create_input_non_interactive_when_flag_set() {
         // --non-interactive: NonInteractiveInput
         let input = create_input(InputMode::NonInteractive);
-        let result = input.prompt_text("Test prompt", None, None, None);
+        let result = input.prompt_text("Test prompt", None, None, &[]);
         assert!(
             result.is_err(),
             "Expected prompt to fail in non-interactive mode"
@@ -242,12 +243,81 @@ mod non_interactive_tests {
         };
 
         let input = create_input(mode);
-        let result = input.prompt_text("Test prompt", None, None, None);
+        let result = input.prompt_text("Test prompt", None, None, &[]);
         assert!(
             result.is_err(),
             "Expected prompt to fail when --json implies non-interactive input"
         );
     }
+    #[test]
+    fn test_non_interactive_select_rich_is_blocked() {
+        let input = create_input(InputMode::NonInteractive);
+        let options = [SelectOption::new("default", 0).with_description("bundled")];
+        let result = input.select_rich("Project Template", &options);
+        assert!(
+            result.is_err(),
+            "Expected rich selection to fail in non-interactive mode"
+        );
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("non-interactive mode")
+        );
+    }
+
+    #[test]
+    fn test_input_reports_whether_it_is_interactive() {
+        assert!(create_input(InputMode::Interactive).is_interactive());
+        assert!(!create_input(InputMode::NonInteractive).is_interactive());
+    }
+
+    #[test]
+    fn test_flag_hint_names_the_flag_in_non_interactive_errors() {
+        let input = create_input(InputMode::NonInteractive);
+        let hinted = with_flag_hint(input.as_ref(), "--template <name>");
+        let options = [SelectOption::new("default", 0)];
+
+        let error = hinted
+            .select_rich("Project Template", &options)
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Interactive selection 'Project Template' blocked: non-interactive mode is active. \
+             Pass --template <name> to avoid this prompt."
+        );
+        let blocked = error
+            .downcast::<PromptBlocked>()
+            .expect("Should stay typed");
+        assert_eq!(blocked.flag_hint.as_deref(), Some("--template <name>"));
+    }
+
+    #[test]
+    fn test_flag_hint_closest_to_the_prompt_wins() {
+        let input = create_input(InputMode::NonInteractive);
+        let outer = with_flag_hint(input.as_ref(), "--outer");
+        let inner = with_flag_hint(&outer, "--yes");
+
+        let error = inner.confirm("Proceed?", None).unwrap_err().to_string();
+        assert!(
+            error.contains("Pass --yes to avoid this prompt"),
+            "{}",
+            error
+        );
+        assert!(!error.contains("--outer"), "{}", error);
+    }
+
+    #[test]
+    fn test_flag_hint_keeps_answers_and_other_errors() {
+        let scripted = ScriptedInput::new().confirm(true);
+        let hinted = with_flag_hint(&scripted, "--yes");
+        assert!(hinted.confirm("Proceed?", None).unwrap());
+
+        // Not a blocked prompt: the error is left unchanged
+        let error = hinted.confirm("Again?", None).unwrap_err().to_string();
+        assert!(error.contains("no scripted response left"), "{}", error);
+        assert!(!error.contains("--yes"), "{}", error);
+    }
 }
 
 // =============================================================================
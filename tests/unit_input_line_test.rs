@@ -0,0 +1,229 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the line-based input used when the terminal cannot render prompts, and for
+//! the detection of such terminals.
+
+use am::common::utils::validate_name;
+use am::input::{Input, LineInput, SelectOption, TerminalInfo, to_validation};
+use std::io::Cursor;
+
+type ScriptedLines = LineInput<Cursor<Vec<u8>>, Vec<u8>>;
+
+fn line_input(answers: &str) -> ScriptedLines {
+    LineInput::new(Cursor::new(answers.as_bytes().to_vec()), Vec::new())
+}
+
+fn written(input: ScriptedLines) -> String {
+    String::from_utf8(input.into_inner().1).unwrap()
+}
+
+fn terminal(term: Option<&str>) -> TerminalInfo {
+    TerminalInfo {
+        term: term.map(str::to_string),
+        inside_emacs: false,
+        stdin_tty: true,
+        stdout_tty: true,
+        stderr_tty: true,
+    }
+}
+
+// =============================================================================
+// Terminal Detection Tests
+// =============================================================================
+
+#[test]
+fn test_p0_regular_terminal_supports_prompt_ui() {
+    assert!(terminal(Some("xterm-256color")).supports_prompt_ui());
+}
+
+#[test]
+fn test_p0_dumb_terminal_does_not_support_prompt_ui() {
+    assert!(!terminal(Some("dumb")).supports_prompt_ui());
+    assert!(!terminal(Some("")).supports_prompt_ui());
+}
+
+#[test]
+fn test_p1_emacs_shell_does_not_support_prompt_ui() {
+    let info = TerminalInfo {
+        inside_emacs: true,
+        ..terminal(Some("xterm"))
+    };
+    assert!(!info.supports_prompt_ui());
+}
+
+#[test]
+fn test_p1_redirected_stdout_does_not_support_prompt_ui() {
+    let info = TerminalInfo {
+        stdout_tty: false,
+        ..terminal(Some("xterm"))
+    };
+    assert!(!info.supports_prompt_ui());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_p1_missing_term_does_not_support_prompt_ui() {
+    assert!(!terminal(None).supports_prompt_ui());
+}
+
+// =============================================================================
+// Text Prompt Tests
+// =============================================================================
+
+#[test]
+fn test_p0_text_prompt_reads_a_line() {
+    let input = line_input("my_project\n");
+
+    let answer = input
+        .prompt_text("Project name:", Some("demo"), None, &[])
+        .unwrap();
+
+    assert_eq!(answer, "my_project");
+    assert_eq!(written(input), "Project name: (e.g. demo) ");
+}
+
+#[test]
+fn test_p0_text_prompt_enforces_validators() {
+    let input = line_input("bad name!\nr\ngood_name\n");
+    let not_short = |value: &str| {
+        to_validation(if value.len() < 2 {
+            Err("Too short".to_string())
+        } else {
+            Ok(())
+        })
+    };
+    let name = |value: &str| to_validation(validate_name(value));
+
+    let answer = input
+        .prompt_text("Name:", None, None, &[&not_short, &name])
+        .unwrap();
+
+    assert_eq!(answer, "good_name");
+    let written = written(input);
+    assert!(written.contains("Too short"));
+    assert_eq!(written.matches("Name: ").count(), 3);
+}
+
+#[test]
+fn test_p0_text_with_default_uses_it_on_empty_answer() {
+    let input = line_input("\r\n");
+
+    let answer = input
+        .prompt_text_with_default("Name:", "demo", &[])
+        .unwrap();
+
+    assert_eq!(answer, "demo");
+    assert_eq!(written(input), "Name: [demo] ");
+}
+
+#[test]
+fn test_p1_end_of_input_fails_the_prompt() {
+    let input = line_input("");
+
+    let error = input.prompt_text("Name:", None, None, &[]).unwrap_err();
+
+    assert!(error.to_string().contains("'Name:'"));
+}
+
+// =============================================================================
+// Selection Tests
+// =============================================================================
+
+#[test]
+fn test_p0_select_reads_the_number_of_the_choice() {
+    let options = vec!["default".to_string(), "minimal".to_string()];
+    let input = line_input("2\n");
+
+    let answer = input.select("Template:", &options).unwrap();
+
+    assert_eq!(answer, "minimal");
+    assert_eq!(
+        written(input),
+        "Template:\n  1) default\n  2) minimal\nEnter a number (1-2): "
+    );
+}
+
+#[test]
+fn test_p0_select_asks_again_for_an_invalid_number() {
+    let options = vec!["default".to_string(), "minimal".to_string()];
+    let input = line_input("3\nminimal\n\n1\n");
+
+    let answer = input.select("Template:", &options).unwrap();
+
+    assert_eq!(answer, "default");
+    let written = written(input);
+    assert_eq!(written.matches("is not a valid choice").count(), 3);
+}
+
+#[test]
+fn test_p1_select_rich_returns_the_value_index() {
+    let options = vec![
+        SelectOption::new("alpha", 7).with_description("/projects/alpha"),
+        SelectOption::new("beta", 3),
+    ];
+    let input = line_input("1\n");
+
+    let answer = input.select_rich("Project:", &options).unwrap();
+
+    assert_eq!(answer, 7);
+    assert!(written(input).contains("  1) alpha  /projects/alpha\n"));
+}
+
+#[test]
+fn test_p1_select_without_options_fails() {
+    let input = line_input("1\n");
+
+    assert!(input.select("Template:", &[]).is_err());
+}
+
+#[test]
+fn test_p1_multi_select_reads_several_numbers() {
+    let options = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+
+    let input = line_input("3, 1 3\n");
+    assert_eq!(
+        input.multi_select("Pick:", &options).unwrap(),
+        vec!["a", "c"]
+    );
+
+    let input = line_input("\n");
+    assert!(input.multi_select("Pick:", &options).unwrap().is_empty());
+}
+
+// =============================================================================
+// Confirmation Tests
+// =============================================================================
+
+#[test]
+fn test_p0_confirm_reads_yes_or_no() {
+    assert!(line_input("y\n").confirm("Continue?", None).unwrap());
+    assert!(line_input("YES\n").confirm("Continue?", None).unwrap());
+    assert!(!line_input("n\n").confirm("Continue?", None).unwrap());
+}
+
+#[test]
+fn test_p0_confirm_uses_the_default_on_empty_answer() {
+    let input = line_input("\n");
+    assert!(!input.confirm("Delete?", Some(false)).unwrap());
+    assert_eq!(written(input), "Delete? [y/N] ");
+}
+
+#[test]
+fn test_p1_confirm_without_default_asks_again() {
+    let input = line_input("\nmaybe\ny\n");
+
+    assert!(input.confirm("Continue?", None).unwrap());
+    assert_eq!(written(input).matches("Answer 'y' or 'n'").count(), 2);
+}
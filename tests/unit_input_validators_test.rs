@@ -0,0 +1,204 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the validators of text prompts: their ordering, the reported failure, and how
+//! each `Input` implementation applies them.
+
+use am::common::utils::{
+    MAX_NAME_BYTES, check_name_characters, check_name_length, check_name_not_reserved,
+    check_name_present, validate_name,
+};
+use am::input::{
+    Input, NonInteractiveInput, TextValidator, run_validators, to_validation, validation_message,
+};
+use am::testing::ScriptedInput;
+use inquire::validator::Validation;
+use std::cell::RefCell;
+
+fn message(validation: Validation) -> String {
+    match validation {
+        Validation::Valid => panic!("Expected the value to be rejected"),
+        Validation::Invalid(reason) => validation_message(&reason).to_string(),
+    }
+}
+
+// =============================================================================
+// Ordering Tests
+// =============================================================================
+
+#[test]
+fn test_p0_validators_run_in_order_and_stop_at_the_first_failure() {
+    let calls = RefCell::new(Vec::new());
+    let first = |_: &str| {
+        calls.borrow_mut().push("first");
+        to_validation(Ok(()))
+    };
+    let second = |_: &str| {
+        calls.borrow_mut().push("second");
+        to_validation(Err("second failed".to_string()))
+    };
+    let third = |_: &str| {
+        calls.borrow_mut().push("third");
+        to_validation(Err("third failed".to_string()))
+    };
+
+    let validation = run_validators("value", &[&first, &second, &third]).unwrap();
+
+    assert_eq!(message(validation), "second failed");
+    assert_eq!(*calls.borrow(), vec!["first", "second"]);
+}
+
+#[test]
+fn test_p0_value_passing_every_validator_is_valid() {
+    let not_empty = |value: &str| to_validation(check_name_present(value, "project"));
+    let length = |value: &str| to_validation(check_name_length(value, "project"));
+
+    assert_eq!(
+        run_validators("footsteps", &[&not_empty, &length]).unwrap(),
+        Validation::Valid
+    );
+    assert_eq!(run_validators("", &[]).unwrap(), Validation::Valid);
+}
+
+#[test]
+fn test_p1_validator_error_is_returned() {
+    let broken = |_: &str| -> Result<Validation, inquire::CustomUserError> {
+        Err("validator crashed".into())
+    };
+    let never = |_: &str| -> Result<Validation, inquire::CustomUserError> {
+        panic!("Validators after an error must not run")
+    };
+
+    let error = run_validators("value", &[&broken, &never]).unwrap_err();
+
+    assert_eq!(error.to_string(), "validator crashed");
+}
+
+// =============================================================================
+// Name Check Tests
+// =============================================================================
+
+#[test]
+fn test_p0_name_checks_report_their_own_message() {
+    assert_eq!(
+        check_name_present("  ", "project").unwrap_err(),
+        "project name is required"
+    );
+    assert_eq!(
+        check_name_characters("a/b", false, "template").unwrap_err(),
+        "The template name must only contain alphanumeric characters, underscores, and hyphens."
+    );
+    assert_eq!(
+        check_name_characters("--", true, "project").unwrap_err(),
+        "The project name must contain at least one letter or digit."
+    );
+    assert_eq!(
+        check_name_not_reserved("CON", "project").unwrap_err(),
+        "'CON' is reserved by Windows and can't be used as a project name."
+    );
+    assert!(check_name_length(&"a".repeat(MAX_NAME_BYTES + 1), "project").is_err());
+    assert!(check_name_length(&"a".repeat(MAX_NAME_BYTES), "project").is_ok());
+}
+
+#[test]
+fn test_p1_validate_name_reports_the_first_failing_check() {
+    // Too long and with invalid characters: the character check comes first.
+    let name = format!("{}!", "a".repeat(MAX_NAME_BYTES));
+
+    assert_eq!(
+        validate_name(&name, true, "project"),
+        check_name_characters(&name, true, "project")
+    );
+    assert_eq!(
+        validate_name("", true, "project"),
+        check_name_present("", "project")
+    );
+}
+
+// =============================================================================
+// Input Implementation Tests
+// =============================================================================
+
+fn name_validators() -> (
+    impl Fn(&str) -> Result<Validation, inquire::CustomUserError>,
+    impl Fn(&str) -> Result<Validation, inquire::CustomUserError>,
+) {
+    (
+        |value: &str| to_validation(check_name_present(value, "template")),
+        |value: &str| to_validation(check_name_not_reserved(value, "template")),
+    )
+}
+
+#[test]
+fn test_p0_non_interactive_default_passing_validators_is_returned() {
+    let (present, not_reserved) = name_validators();
+    let validators: [TextValidator; 2] = [&present, &not_reserved];
+
+    let value = NonInteractiveInput::new()
+        .prompt_text_with_default("Name", "footsteps", &validators)
+        .unwrap();
+
+    assert_eq!(value, "footsteps");
+}
+
+#[test]
+fn test_p0_non_interactive_invalid_default_reports_the_failure() {
+    let (present, not_reserved) = name_validators();
+    let validators: [TextValidator; 2] = [&present, &not_reserved];
+
+    let error = NonInteractiveInput::new()
+        .prompt_text_with_default("Name", "nul", &validators)
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        error.contains("'nul' is reserved by Windows"),
+        "Unexpected error: {}",
+        error
+    );
+    assert!(error.contains("command-line arguments"));
+}
+
+#[test]
+fn test_p1_non_interactive_prompt_without_default_stays_blocked() {
+    let (present, _) = name_validators();
+
+    let error = NonInteractiveInput::new()
+        .prompt_text("Name", None, None, &[&present])
+        .unwrap_err();
+
+    assert!(error.to_string().contains("non-interactive mode"));
+}
+
+#[test]
+fn test_p1_scripted_input_applies_every_validator() {
+    let (present, not_reserved) = name_validators();
+    let input = ScriptedInput::new().text("footsteps").text("aux");
+
+    assert_eq!(
+        input
+            .prompt_text("Name", None, None, &[&present, &not_reserved])
+            .unwrap(),
+        "footsteps"
+    );
+    let error = input
+        .prompt_text("Name", None, None, &[&present, &not_reserved])
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("'aux' is reserved by Windows"),
+        "Unexpected error: {}",
+        error
+    );
+}
@@ -67,6 +67,14 @@ mod test_support {
             // Intentionally no-op: JSON mode is quiet except for the final result envelope.
         }
 
+        fn print(&self, _message: &str) {
+            // Intentionally no-op: JSON mode is quiet except for the final result envelope.
+        }
+
+        fn warning(&self, _message: &str) {
+            // Intentionally no-op: JSON mode is quiet except for the final result envelope.
+        }
+
         fn table(&self, _title: Option<&str>, _data: serde_json::Value) {
             // Intentionally no-op for test implementation
         }
@@ -709,6 +717,111 @@ fn test_p2_json_output_multiple_responses_to_buffer() {
     assert_eq!(parsed_error["ok"], false);
 }
 
+// ============================================================================
+// P1: Query Tests
+// ============================================================================
+
+#[test]
+fn test_p1_apply_query_prints_strings_raw() {
+    let data = json!([{"name": "alpha", "path": "/projects/alpha"}]);
+
+    let text = JsonOutput::apply_query(&data, "/0/path").expect("Query should match");
+
+    assert_eq!(text, "/projects/alpha");
+}
+
+#[test]
+fn test_p1_apply_query_prints_other_scalars_as_json() {
+    let data = json!({"count": 3, "valid": true, "parent": null});
+
+    assert_eq!(JsonOutput::apply_query(&data, "/count").unwrap(), "3");
+    assert_eq!(JsonOutput::apply_query(&data, "/valid").unwrap(), "true");
+    assert_eq!(JsonOutput::apply_query(&data, "/parent").unwrap(), "null");
+}
+
+#[test]
+fn test_p1_apply_query_pretty_prints_objects_and_arrays() {
+    let data = json!({"paths": {"sources": "/p/sources"}});
+
+    let text = JsonOutput::apply_query(&data, "/paths").unwrap();
+    assert_eq!(
+        serde_json::from_str::<Value>(&text).unwrap(),
+        json!({"sources": "/p/sources"})
+    );
+
+    let whole = JsonOutput::apply_query(&data, "").unwrap();
+    assert_eq!(serde_json::from_str::<Value>(&whole).unwrap(), data);
+}
+
+#[test]
+fn test_p1_apply_query_unescapes_tokens() {
+    let data = json!({"a/b": {"m~n": 1}});
+
+    assert_eq!(JsonOutput::apply_query(&data, "/a~1b/m~0n").unwrap(), "1");
+}
+
+#[test]
+fn test_p1_apply_query_missing_target_is_validation_error() {
+    let data = json!([{"name": "alpha"}]);
+
+    let err = JsonOutput::apply_query(&data, "/1/name").expect_err("Query should not match");
+
+    assert_eq!(err.code, am::common::errors::codes::ERR_VALIDATION_FIELD);
+}
+
+#[test]
+fn test_p1_with_query_rejects_invalid_pointers() {
+    for query in ["0/path", "/a~2b", "/trailing~"] {
+        let err = JsonOutput::with_query(query).expect_err(query);
+        assert_eq!(
+            err.code,
+            am::common::errors::codes::ERR_VALIDATION_FORMAT,
+            "{}",
+            query
+        );
+    }
+
+    assert!(JsonOutput::with_query("").is_ok());
+    assert!(JsonOutput::with_query("/0/path").is_ok());
+    assert!(JsonOutput::with_query("/a~0b/c~1d").is_ok());
+}
+
+#[test]
+fn test_p1_finish_reports_unmatched_query() {
+    let output = JsonOutput::with_query("/missing").unwrap();
+
+    output.success(json!({"name": "alpha"}), None);
+
+    let err = output.finish().expect_err("Unmatched query should fail");
+    let cli_err = err
+        .downcast_ref::<am::common::errors::CliError>()
+        .expect("Expected CliError");
+    assert_eq!(
+        cli_err.code,
+        am::common::errors::codes::ERR_VALIDATION_FIELD
+    );
+    assert!(output.finish().is_ok(), "The error is only reported once");
+}
+
+#[test]
+fn test_p1_finish_without_query_succeeds() {
+    let output = JsonOutput::new();
+
+    assert!(output.finish().is_ok());
+}
+
+#[test]
+fn test_p1_query_flag_requires_json() {
+    use am::app::App;
+    use clap::Parser;
+
+    let app = App::try_parse_from(["am", "--json", "project", "list", "--query", "/0/path"])
+        .expect("--query with --json should parse");
+    assert_eq!(app.query.as_deref(), Some("/0/path"));
+
+    assert!(App::try_parse_from(["am", "project", "list", "--query", "/0/path"]).is_err());
+}
+
 // ============================================================================
 // P2: Default Trait Implementation Tests
 // ============================================================================
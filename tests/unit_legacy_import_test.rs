@@ -0,0 +1,202 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the import of the project registry of the legacy Amplitude editor.
+
+use am::commands::sudo::import_legacy_registry;
+use am::common::errors::{CliError, codes};
+use am::common::utils::read_amproject_file;
+use am::database::legacy::parse_legacy_registry;
+use am::database::{db_get_all_projects, db_get_project_by_name};
+use am::presentation::OutputMode;
+use am::testing::{MockOutput, OutputCall, TestEnv};
+use serde_json::json;
+use std::fs;
+use std::path::Path;
+
+async fn setup_test_env() -> TestEnv {
+    TestEnv::new()
+        .await
+        .expect("Failed to create test environment")
+}
+
+/// Write a legacy `projects.json` holding `entries` in the home of `env`.
+fn write_legacy_file(env: &TestEnv, entries: serde_json::Value) -> std::path::PathBuf {
+    let file = env.home().join("projects.json");
+    fs::write(&file, entries.to_string()).unwrap();
+    file
+}
+
+fn path_str(path: &Path) -> &str {
+    path.to_str().unwrap()
+}
+
+/// The `projects` of the success payload of the import.
+fn imported_entries(output: &MockOutput) -> Vec<serde_json::Value> {
+    output
+        .calls()
+        .into_iter()
+        .find_map(|call| match call {
+            OutputCall::Success(payload) => payload["projects"].as_array().cloned(),
+            _ => None,
+        })
+        .expect("Expected the per-entry report")
+}
+
+// =============================================================================
+// parse_legacy_registry() Tests
+// =============================================================================
+
+#[test]
+fn test_p0_parse_legacy_registry_reads_projects_and_optional_fields() {
+    let projects = parse_legacy_registry(
+        r#"[
+            {"name": "racer", "path": "/games/racer", "favorite": true, "lastOpened": "2019-05-01"},
+            {"name": "puzzle", "path": "/games/puzzle", "editorLayout": "compact"}
+        ]"#,
+    )
+    .expect("Legacy registry should parse");
+
+    assert_eq!(projects.len(), 2);
+    assert_eq!(projects[0].name, "racer");
+    assert!(projects[0].favorite);
+    assert_eq!(projects[0].last_opened.as_deref(), Some("2019-05-01"));
+    assert_eq!(projects[1].path, Path::new("/games/puzzle"));
+    assert!(!projects[1].favorite);
+}
+
+#[test]
+fn test_p0_parse_legacy_registry_names_the_malformed_entry() {
+    let error =
+        parse_legacy_registry(r#"[{"name": "racer", "path": "/games/racer"}, {"name": "puzzle"}]"#)
+            .unwrap_err();
+
+    assert_eq!(error.code, codes::ERR_VALIDATION_SCHEMA);
+    assert_eq!(error.what, "Invalid entry 1 of the legacy registry");
+    assert!(error.why.contains("path"), "{}", error.why);
+}
+
+#[test]
+fn test_p1_parse_legacy_registry_rejects_non_array_files() {
+    let error = parse_legacy_registry(r#"{"name": "racer", "path": "/games/racer"}"#).unwrap_err();
+    assert_eq!(error.code, codes::ERR_VALIDATION_SCHEMA);
+
+    let error = parse_legacy_registry("not json").unwrap_err();
+    assert_eq!(error.code, codes::ERR_VALIDATION_SCHEMA);
+}
+
+// =============================================================================
+// Import Command Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_import_legacy_registers_each_project() {
+    let env = setup_test_env().await;
+    let racer = env.create_project_dir("racer").unwrap();
+    let puzzle = env.create_project_dir("puzzle").unwrap();
+    let file = write_legacy_file(
+        &env,
+        json!([
+            {"name": "Racer", "path": path_str(&racer), "favorite": true},
+            {"name": "Puzzle", "path": path_str(&puzzle)},
+        ]),
+    );
+    let output = MockOutput::new(OutputMode::Json);
+
+    import_legacy_registry(&file, false, env.db(), &output).expect("Import should succeed");
+
+    let entries = imported_entries(&output);
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0]["index"], 0);
+    assert_eq!(entries[0]["name"], "racer");
+    assert_eq!(entries[0]["registered"], true);
+    assert_eq!(entries[1]["name"], "puzzle");
+
+    assert_eq!(db_get_all_projects(env.db()).unwrap().len(), 2);
+    let racer = db_get_project_by_name("racer", env.db()).unwrap().unwrap();
+    assert!(racer.is_favorite);
+}
+
+#[tokio::test]
+async fn test_p0_import_legacy_reports_entries_without_amproject() {
+    let env = setup_test_env().await;
+    let racer = env.create_project_dir("racer").unwrap();
+    let bare = env.home().join("old_prototype");
+    fs::create_dir_all(&bare).unwrap();
+    let file = write_legacy_file(
+        &env,
+        json!([
+            {"name": "Prototype", "path": path_str(&bare)},
+            {"name": "Racer", "path": path_str(&racer)},
+        ]),
+    );
+    let output = MockOutput::new(OutputMode::Json);
+
+    let error = import_legacy_registry(&file, false, env.db(), &output).unwrap_err();
+
+    assert_eq!(
+        error.downcast_ref::<CliError>().unwrap().code,
+        codes::ERR_PROJECT_NOT_REGISTERED
+    );
+    let entries = imported_entries(&output);
+    assert_eq!(entries[0]["registered"], false);
+    assert_eq!(
+        entries[0]["error"]["code"],
+        codes::ERR_PROJECT_NOT_INITIALIZED
+    );
+    assert_eq!(entries[1]["registered"], true);
+    assert!(!bare.join(".amproject").exists());
+}
+
+#[tokio::test]
+async fn test_p1_import_legacy_initializes_missing_projects() {
+    let env = setup_test_env().await;
+    let bare = env.home().join("Old Prototype");
+    fs::create_dir_all(&bare).unwrap();
+    let file = write_legacy_file(
+        &env,
+        json!([{"name": "Prototype", "path": path_str(&bare)}]),
+    );
+    let output = MockOutput::new(OutputMode::Json);
+
+    import_legacy_registry(&file, true, env.db(), &output).expect("Import should succeed");
+
+    let config = read_amproject_file(&bare).expect("A .amproject should be written");
+    assert_eq!(config.name, "old_prototype");
+    let entries = imported_entries(&output);
+    assert_eq!(entries[0]["initialized"], true);
+    assert!(
+        db_get_project_by_name("old_prototype", env.db())
+            .unwrap()
+            .is_some()
+    );
+}
+
+#[tokio::test]
+async fn test_p1_import_legacy_rejects_a_malformed_file_before_registering() {
+    let env = setup_test_env().await;
+    let racer = env.create_project_dir("racer").unwrap();
+    let file = write_legacy_file(
+        &env,
+        json!([{"name": "Racer", "path": path_str(&racer)}, {"path": 42}]),
+    );
+    let output = MockOutput::new(OutputMode::Json);
+
+    let error = import_legacy_registry(&file, false, env.db(), &output).unwrap_err();
+
+    let error = error.downcast_ref::<CliError>().unwrap();
+    assert_eq!(error.code, codes::ERR_VALIDATION_SCHEMA);
+    assert_eq!(error.what, "Invalid entry 1 of the legacy registry");
+    assert!(db_get_all_projects(env.db()).unwrap().is_empty());
+}
@@ -0,0 +1,227 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the sanitization of user data in the JSON output.
+//!
+//! Hostile strings, with control characters, separators and invalid UTF-8, go through
+//! the success, error and table paths of `JsonOutput`, and every envelope must parse.
+
+use am::common::errors::{CliError, codes};
+use am::presentation::{
+    ColumnSpec, JsonOutput, Output, escape_control_characters, insert_os_str, lossy_os_str,
+    to_string_pretty,
+};
+use proptest::prelude::*;
+use serde_json::{Value, json};
+use std::ffi::OsStr;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+/// A writer whose contents stay readable after it is moved into a `JsonOutput`.
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The envelope written by `write` to a `JsonOutput`, as text.
+fn capture(write: impl FnOnce(&JsonOutput)) -> String {
+    let buffer = SharedBuffer::default();
+    let output = JsonOutput::new().with_writer(buffer.clone());
+    write(&output);
+    let bytes = buffer.0.lock().unwrap().clone();
+    String::from_utf8(bytes).expect("Envelopes are valid UTF-8")
+}
+
+/// Whether `text` holds a character that strict or JavaScript parsers may reject raw.
+fn has_raw_hostile_character(text: &str) -> bool {
+    text.chars()
+        .any(|c| (c.is_control() && c != '\n') || c == '\u{2028}' || c == '\u{2029}')
+}
+
+/// Strings mixing printable characters with controls and separators.
+fn hostile_string() -> impl Strategy<Value = String> {
+    prop::collection::vec(
+        prop_oneof![
+            any::<char>(),
+            prop::char::range('\u{0}', '\u{1f}'),
+            prop::char::range('\u{7f}', '\u{9f}'),
+            Just('\u{2028}'),
+            Just('\u{2029}'),
+            Just('"'),
+            Just('\\'),
+        ],
+        0..32,
+    )
+    .prop_map(|chars| chars.into_iter().collect())
+}
+
+// ============================================================================
+// P0: Envelopes Always Parse
+// ============================================================================
+
+proptest! {
+    #[test]
+    fn test_p0_success_envelope_parses_for_hostile_strings(text in hostile_string()) {
+        let envelope = capture(|output| output.success(json!({ "name": text }), None));
+
+        prop_assert!(!has_raw_hostile_character(&envelope));
+        let parsed: Value = serde_json::from_str(&envelope).expect("Valid JSON");
+        prop_assert_eq!(parsed["value"]["name"].as_str(), Some(text.as_str()));
+    }
+
+    #[test]
+    fn test_p0_message_envelope_parses_for_hostile_strings(text in hostile_string()) {
+        let envelope = capture(|output| output.success(json!(text), None));
+
+        prop_assert!(!has_raw_hostile_character(&envelope));
+        let parsed: Value = serde_json::from_str(&envelope).expect("Valid JSON");
+        let message = parsed["value"].as_str().unwrap();
+        prop_assert!(!message.chars().any(|c| c.is_control() && c != '\n' && c != '\t'));
+    }
+
+    #[test]
+    fn test_p0_error_envelope_parses_for_hostile_strings(text in hostile_string()) {
+        let error = CliError::new(codes::ERR_IO_FAILED, text.clone(), text.clone())
+            .with_suggestion(text.clone())
+            .with_context(text.clone());
+        let envelope = capture(|output| output.error(&error.into(), codes::ERR_IO_FAILED, None));
+
+        prop_assert!(!has_raw_hostile_character(&envelope));
+        let parsed: Value = serde_json::from_str(&envelope).expect("Valid JSON");
+        for field in ["message", "why", "suggestion", "context"] {
+            let value = parsed["error"][field].as_str().unwrap();
+            prop_assert!(!value.chars().any(|c| c.is_control() && c != '\n' && c != '\t'));
+        }
+    }
+
+    #[test]
+    fn test_p0_table_envelope_parses_for_hostile_strings(text in hostile_string()) {
+        let columns = [ColumnSpec::new("name", "Name")];
+        let mut row = json!({ "name": text });
+        row[format!("k{}", text)] = json!(1);
+        let envelope = capture(|output| output.table_with_columns(None, &columns, json!([row])));
+
+        prop_assert!(!has_raw_hostile_character(&envelope));
+        let parsed: Value = serde_json::from_str(&envelope).expect("Valid JSON");
+        prop_assert_eq!(parsed["value"][0]["name"].as_str(), Some(text.as_str()));
+    }
+}
+
+#[cfg(unix)]
+proptest! {
+    #[test]
+    fn test_p0_os_str_envelope_parses_for_arbitrary_bytes(
+        bytes in prop::collection::vec(any::<u8>(), 0..32)
+    ) {
+        use std::os::unix::ffi::OsStrExt;
+
+        let mut value = json!({});
+        insert_os_str(&mut value, "path", OsStr::from_bytes(&bytes));
+        let envelope = capture(|output| output.success(value, None));
+
+        let parsed: Value = serde_json::from_str(&envelope).expect("Valid JSON");
+        prop_assert!(parsed["value"]["path"].is_string());
+        prop_assert_eq!(
+            parsed["value"]["path_raw_bytes_b64"].is_string(),
+            std::str::from_utf8(&bytes).is_err()
+        );
+    }
+}
+
+// ============================================================================
+// P1: Lossy Conversion
+// ============================================================================
+
+#[test]
+fn test_p1_lossy_os_str_keeps_valid_strings() {
+    let lossy = lossy_os_str(OsStr::new("sounds/é.wav"));
+
+    assert_eq!(lossy.text, "sounds/é.wav");
+    assert_eq!(lossy.raw_bytes_b64, None);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_p1_lossy_os_str_keeps_raw_bytes_of_invalid_utf8() {
+    use std::os::unix::ffi::OsStrExt;
+
+    let lossy = lossy_os_str(OsStr::from_bytes(b"a\xffb"));
+
+    assert_eq!(lossy.text, "a\u{fffd}b");
+    assert_eq!(lossy.raw_bytes_b64.as_deref(), Some("Yf9i"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_p1_insert_os_str_adds_raw_bytes_field_only_when_lossy() {
+    use std::os::unix::ffi::OsStrExt;
+
+    let mut value = json!({});
+    insert_os_str(&mut value, "path", OsStr::new("valid"));
+    insert_os_str(&mut value, "file", OsStr::from_bytes(b"\xfe\xff"));
+
+    assert_eq!(value["path"], "valid");
+    assert!(value.get("path_raw_bytes_b64").is_none());
+    assert_eq!(value["file"], "\u{fffd}\u{fffd}");
+    assert_eq!(value["file_raw_bytes_b64"], "/v8=");
+}
+
+#[test]
+fn test_p2_insert_os_str_ignores_non_objects() {
+    let mut value = json!([]);
+    insert_os_str(&mut value, "path", OsStr::new("valid"));
+
+    assert_eq!(value, json!([]));
+}
+
+// ============================================================================
+// P1: Control Characters
+// ============================================================================
+
+#[test]
+fn test_p1_escape_control_characters_keeps_line_feeds_and_tabs() {
+    assert_eq!(
+        escape_control_characters("\x1b[31mred\x1b[0m\n\tdone\u{7f}"),
+        "\\u{1b}[31mred\\u{1b}[0m\n\tdone\\u{7f}"
+    );
+}
+
+#[test]
+fn test_p1_to_string_pretty_escapes_controls_and_separators() {
+    let value = json!({ "text": "a\u{7f}b\u{85}c\u{2028}d\u{2029}e\u{1}" });
+
+    let text = to_string_pretty(&value).unwrap();
+
+    assert!(text.contains(r"a\u007fb\u0085c\u2028d\u2029e\u0001"));
+    assert_eq!(serde_json::from_str::<Value>(&text).unwrap(), value);
+}
+
+#[test]
+fn test_p2_to_string_pretty_matches_serde_json_for_plain_data() {
+    let value = json!({ "name": "game", "tags": ["a", "b"], "nested": { "n": 1 } });
+
+    assert_eq!(
+        to_string_pretty(&value).unwrap(),
+        serde_json::to_string_pretty(&value).unwrap()
+    );
+}
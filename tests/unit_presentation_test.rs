@@ -21,12 +21,17 @@
 //! - P1: Output capture and verification, error handling
 //! - P2: Edge cases, multiple calls tracking
 
-use am::presentation::{InteractiveOutput, Output, OutputMode};
+use am::presentation::{
+    Alignment, ColumnSpec, InteractiveOutput, JsonOutput, Output, OutputMode, SummaryOutput,
+};
 use anyhow::anyhow;
 use serde::Serialize;
 use serde_json::json;
 use std::cell::RefCell;
+use std::collections::HashSet;
+use std::io::Write;
 use std::rc::Rc;
+use std::sync::{Arc, Mutex};
 
 #[derive(Serialize)]
 struct TestData {
@@ -93,6 +98,14 @@ impl Output for MockOutput {
         self.progress_calls.borrow_mut().push(message.to_string());
     }
 
+    fn print(&self, _message: &str) {
+        // Not needed for these tests
+    }
+
+    fn warning(&self, _message: &str) {
+        // Not needed for these tests
+    }
+
     fn table(&self, _title: Option<&str>, _data: serde_json::Value) {
         // Mock implementation - does nothing for testing
     }
@@ -397,3 +410,353 @@ fn test_p2_create_output_result_is_send_sync() {
     fn assert_send_sync<T: Send + Sync + ?Sized>(_: &T) {}
     assert_send_sync(&*output);
 }
+
+// ============================================================================
+// P0: Concurrency Tests - Outputs shared by parallel workers
+// ============================================================================
+
+const STRESS_THREADS: usize = 16;
+const STRESS_CALLS_PER_THREAD: usize = 250;
+
+/// A writer accepting at most a few bytes per `write` call, yielding in between, so
+/// unsynchronized writers interleave their fragments.
+#[derive(Clone, Default)]
+struct ChoppyBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl ChoppyBuffer {
+    fn contents(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).expect("Output should be UTF-8")
+    }
+}
+
+impl Write for ChoppyBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let len = buf.len().min(7);
+        self.0.lock().unwrap().extend_from_slice(&buf[..len]);
+        std::thread::yield_now();
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Call `emit(worker, index)` from many threads at once.
+fn stress(emit: impl Fn(usize, usize) + Sync) {
+    std::thread::scope(|scope| {
+        for worker in 0..STRESS_THREADS {
+            let emit = &emit;
+            scope.spawn(move || {
+                for index in 0..STRESS_CALLS_PER_THREAD {
+                    emit(worker, index);
+                }
+            });
+        }
+    });
+}
+
+#[test]
+fn test_p0_interactive_output_lines_stay_intact_under_concurrent_progress() {
+    // GIVEN: InteractiveOutput writing to a buffer
+    let buffer = ChoppyBuffer::default();
+    let output = InteractiveOutput::new().with_writer(buffer.clone());
+    let message = |worker: usize, index: usize| {
+        format!(
+            "worker {:02} reports progress {:04} of the job",
+            worker, index
+        )
+    };
+
+    // WHEN: Many threads report progress at once
+    stress(|worker, index| output.progress(&message(worker, index)));
+
+    // THEN: Every line holds exactly one whole message
+    let mut expected: HashSet<String> = (0..STRESS_THREADS)
+        .flat_map(|w| (0..STRESS_CALLS_PER_THREAD).map(move |i| (w, i)))
+        .map(|(w, i)| message(w, i))
+        .collect();
+    let contents = buffer.contents();
+    for line in contents.lines() {
+        let start = line
+            .find("worker ")
+            .unwrap_or_else(|| panic!("Torn line: {:?}", line));
+        assert!(
+            expected.remove(&line[start..]),
+            "Torn or duplicated line: {:?}",
+            line
+        );
+    }
+    assert!(
+        expected.is_empty(),
+        "{} messages are missing",
+        expected.len()
+    );
+}
+
+#[test]
+fn test_p0_json_output_envelopes_stay_intact_under_concurrent_writes() {
+    // GIVEN: JsonOutput writing to a buffer
+    let buffer = ChoppyBuffer::default();
+    let output = JsonOutput::new().with_writer(buffer.clone());
+
+    // WHEN: Many threads write results at once, progress included
+    stress(|worker, index| {
+        output.progress("ignored");
+        output.success(json!({ "worker": worker, "index": index }), None);
+    });
+
+    // THEN: The output is a sequence of whole envelopes, one per call
+    let contents = buffer.contents();
+    let mut seen = HashSet::new();
+    for envelope in serde_json::Deserializer::from_str(&contents).into_iter::<serde_json::Value>() {
+        let envelope = envelope.expect("Every envelope should be valid JSON");
+        assert_eq!(envelope["ok"], true);
+        let value = &envelope["value"];
+        assert!(seen.insert((value["worker"].as_u64(), value["index"].as_u64())));
+    }
+    assert_eq!(seen.len(), STRESS_THREADS * STRESS_CALLS_PER_THREAD);
+}
+
+// ============================================================================
+// P1: Stage Tests - Step indicators for external tools
+// ============================================================================
+
+#[test]
+fn test_p1_stages_are_no_ops_by_default() {
+    // GIVEN: An output that doesn't implement the stage methods
+    let output = MockOutput::new();
+
+    // WHEN: Declaring and entering stages
+    output.begin_stages(&["Scan", "Copy"]);
+    output.enter_stage(1);
+
+    // THEN: Nothing is recorded
+    assert_eq!(output.progress_count(), 0);
+}
+
+#[test]
+fn test_p1_interactive_output_prints_entered_stage() {
+    let buffer = ChoppyBuffer::default();
+    let output = InteractiveOutput::new().with_writer(buffer.clone());
+
+    output.begin_stages(&["Scan", "Copy", "Register"]);
+    output.enter_stage(1);
+    output.enter_stage(7);
+
+    let contents = buffer.contents();
+    assert_eq!(contents.lines().count(), 1, "{}", contents);
+    assert!(contents.contains("[2/3]"), "{}", contents);
+    assert!(contents.contains("Copy…"), "{}", contents);
+}
+
+#[test]
+fn test_p1_json_output_lists_completed_stages_in_meta() {
+    // GIVEN: JsonOutput with declared stages
+    let buffer = ChoppyBuffer::default();
+    let output = JsonOutput::new().with_writer(buffer.clone());
+    output.begin_stages(&["Scan", "Copy", "Register"]);
+
+    // WHEN: Succeeding in the second stage
+    output.enter_stage(0);
+    output.enter_stage(1);
+    output.success(json!("done"), None);
+
+    // THEN: The envelope lists the declared and completed stages
+    let envelope: serde_json::Value = serde_json::from_str(buffer.contents().trim()).unwrap();
+    assert_eq!(
+        envelope["meta"]["stages"],
+        json!(["Scan", "Copy", "Register"])
+    );
+    assert_eq!(
+        envelope["meta"]["completed_stages"],
+        json!(["Scan", "Copy"])
+    );
+    assert!(envelope["meta"].get("failed_stage").is_none());
+}
+
+#[test]
+fn test_p1_json_output_reports_failed_stage() {
+    let buffer = ChoppyBuffer::default();
+    let output = JsonOutput::new().with_writer(buffer.clone());
+    output.begin_stages(&["Scan", "Copy", "Register"]);
+
+    output.enter_stage(1);
+    output.error(&anyhow!("disk full"), -1, None);
+
+    let envelope: serde_json::Value = serde_json::from_str(buffer.contents().trim()).unwrap();
+    assert_eq!(envelope["meta"]["completed_stages"], json!(["Scan"]));
+    assert_eq!(envelope["meta"]["failed_stage"], "Copy");
+}
+
+#[test]
+fn test_p2_json_output_without_stages_has_no_meta() {
+    let buffer = ChoppyBuffer::default();
+    let output = JsonOutput::new().with_writer(buffer.clone());
+
+    output.success(json!("done"), None);
+
+    let envelope: serde_json::Value = serde_json::from_str(buffer.contents().trim()).unwrap();
+    assert!(envelope.get("meta").is_none());
+}
+
+// ============================================================================
+// P1: Table Column Tests
+// ============================================================================
+
+fn table_columns() -> Vec<ColumnSpec> {
+    vec![
+        ColumnSpec::new("name", "Name"),
+        ColumnSpec::new("size", "Size")
+            .with_alignment(Alignment::Right)
+            .with_priority(1),
+        ColumnSpec::new("path", "Location").with_priority(2),
+    ]
+}
+
+fn table_rows() -> serde_json::Value {
+    json!([
+        { "path": "/projects/alpha", "name": "alpha", "size": 12 },
+        { "path": "/projects/beta", "name": "beta", "size": 3456 },
+    ])
+}
+
+#[test]
+fn test_p1_interactive_table_uses_column_order_and_headers() {
+    colored::control::set_override(false);
+    let buffer = ChoppyBuffer::default();
+    let output = InteractiveOutput::new()
+        .with_writer(buffer.clone())
+        .with_width(200);
+
+    output.table_with_columns(Some("Projects"), &table_columns(), table_rows());
+
+    let contents = buffer.contents();
+    let lines: Vec<&str> = contents.lines().collect();
+    assert!(lines[2].contains("Name   Size  Location"), "{}", contents);
+    assert!(
+        lines[4].contains("alpha    12  /projects/alpha"),
+        "{}",
+        contents
+    );
+    assert!(
+        lines[5].contains("beta   3456  /projects/beta"),
+        "{}",
+        contents
+    );
+}
+
+#[test]
+fn test_p1_interactive_table_drops_columns_by_priority_when_narrow() {
+    colored::control::set_override(false);
+    let buffer = ChoppyBuffer::default();
+    let output = InteractiveOutput::new()
+        .with_writer(buffer.clone())
+        .with_width(20);
+
+    output.table_with_columns(None, &table_columns(), table_rows());
+
+    let contents = buffer.contents();
+    assert!(contents.contains("Size"), "{}", contents);
+    assert!(!contents.contains("Location"), "{}", contents);
+
+    let buffer = ChoppyBuffer::default();
+    let output = InteractiveOutput::new()
+        .with_writer(buffer.clone())
+        .with_width(5);
+
+    output.table_with_columns(None, &table_columns(), table_rows());
+
+    let contents = buffer.contents();
+    assert!(
+        contents.contains("alpha"),
+        "Priority 0 columns stay: {}",
+        contents
+    );
+    assert!(!contents.contains("Size"), "{}", contents);
+}
+
+#[test]
+fn test_p1_interactive_table_shortens_paths_before_dropping_columns() {
+    colored::control::set_override(false);
+    let long_path = "/home/user/work/studio/clients/acme/projects/alpha_game_with_long_name";
+    let columns = vec![
+        ColumnSpec::new("name", "Name"),
+        ColumnSpec::new("path", "Path")
+            .with_priority(1)
+            .with_path_truncation(),
+    ];
+    let rows = json!([{ "name": "alpha", "path": long_path }]);
+
+    let buffer = ChoppyBuffer::default();
+    let output = InteractiveOutput::new()
+        .with_writer(buffer.clone())
+        .with_width(50);
+    output.table_with_columns(None, &columns, rows.clone());
+
+    let contents = buffer.contents();
+    assert!(
+        contents.contains("/…/projects/alpha_game_with_long_name"),
+        "{}",
+        contents
+    );
+    assert!(
+        contents.lines().all(|line| line.chars().count() <= 50),
+        "{}",
+        contents
+    );
+
+    let buffer = ChoppyBuffer::default();
+    let output = JsonOutput::new().with_writer(buffer.clone());
+    output.table_with_columns(None, &columns, rows);
+    assert!(
+        buffer.contents().contains(long_path),
+        "JSON keeps full paths"
+    );
+}
+
+#[test]
+fn test_p1_json_table_orders_fields_by_columns() {
+    let buffer = ChoppyBuffer::default();
+    let output = JsonOutput::new().with_writer(buffer.clone());
+
+    output.table_with_columns(Some("Projects"), &table_columns(), table_rows());
+
+    let contents = buffer.contents();
+    let name = contents.find("\"name\"").unwrap();
+    let size = contents.find("\"size\"").unwrap();
+    let path = contents.find("\"path\"").unwrap();
+    assert!(name < size && size < path, "{}", contents);
+
+    let envelope: serde_json::Value = serde_json::from_str(contents.trim()).unwrap();
+    assert_eq!(envelope["value"], table_rows());
+}
+
+#[test]
+fn test_p2_column_specs_from_rows_use_the_first_row() {
+    let columns = ColumnSpec::from_rows(&table_rows());
+
+    let keys: Vec<&str> = columns.iter().map(|c| c.key.as_str()).collect();
+    assert_eq!(keys, ["name", "path", "size"]);
+    assert!(columns.iter().all(|c| c.header == c.key && c.priority == 0));
+    assert!(ColumnSpec::from_rows(&json!([])).is_empty());
+}
+
+// ============================================================================
+// P1: SummaryOutput Tests
+// ============================================================================
+
+#[test]
+fn test_p1_summary_output_drops_everything_but_errors() {
+    let inner = MockOutput::new();
+    let output = SummaryOutput::new(&inner);
+
+    output.progress("Validating...");
+    output.success(json!({"valid": true}), None);
+    output.error(&anyhow!("broken"), -1, Some(3));
+
+    assert_eq!(inner.progress_count(), 0);
+    assert_eq!(inner.success_count(), 0);
+    assert_eq!(inner.last_error(), Some(("broken".to_string(), -1)));
+    assert_eq!(output.mode(), OutputMode::Interactive);
+}
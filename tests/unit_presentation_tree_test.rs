@@ -0,0 +1,179 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the directory tree of `am project list --tree`.
+
+use am::presentation::{PathTree, TreeLeaf, path_components};
+
+fn leaf(name: &str, path: &str) -> TreeLeaf {
+    TreeLeaf {
+        name: name.to_string(),
+        path: path.to_string(),
+        favorite: false,
+    }
+}
+
+/// Names of the entries of a JSON tree level.
+fn names(children: &serde_json::Value) -> Vec<&str> {
+    children
+        .as_array()
+        .expect("children should be an array")
+        .iter()
+        .map(|child| child["name"].as_str().unwrap())
+        .collect()
+}
+
+// =============================================================================
+// path_components() Tests
+// =============================================================================
+
+#[test]
+fn test_p0_path_components_split_on_both_separators() {
+    assert_eq!(
+        path_components("C:\\studio/client_a\\game"),
+        ["C:", "studio", "client_a", "game"]
+    );
+    assert_eq!(
+        path_components("/studio/client_a/"),
+        ["/", "studio", "client_a"]
+    );
+}
+
+// =============================================================================
+// PathTree::build() Tests
+// =============================================================================
+
+#[test]
+fn test_p0_tree_defaults_to_the_deepest_common_ancestor() {
+    let tree = PathTree::build(
+        vec![
+            leaf("racer", "/studio/client_b/racer"),
+            leaf("puzzle", "/studio/client_a/puzzle"),
+            leaf("arcade", "/studio/client_a/arcade"),
+        ],
+        None,
+    );
+
+    let json = tree.to_json();
+    assert_eq!(json["root"], "/studio");
+    assert_eq!(json["count"], 3);
+    assert_eq!(names(&json["children"]), ["client_a", "client_b"]);
+    assert_eq!(json["children"][0]["type"], "directory");
+    assert_eq!(json["children"][0]["count"], 2);
+    assert_eq!(
+        names(&json["children"][0]["children"]),
+        ["arcade", "puzzle"]
+    );
+    assert_eq!(json["children"][0]["children"][0]["type"], "project");
+    assert_eq!(json["other"]["count"], 0);
+}
+
+#[test]
+fn test_p0_tree_puts_projects_outside_the_root_under_other() {
+    let tree = PathTree::build(
+        vec![
+            leaf("racer", "/studio/client_b/racer"),
+            leaf("demo", "/tmp/demo"),
+        ],
+        Some("/studio"),
+    );
+
+    let json = tree.to_json();
+    assert_eq!(json["root"], "/studio");
+    assert_eq!(json["count"], 2);
+    assert_eq!(names(&json["children"]), ["client_b"]);
+    assert_eq!(json["other"]["count"], 1);
+    assert_eq!(json["other"]["projects"][0]["path"], "/tmp/demo");
+}
+
+#[test]
+fn test_p1_tree_groups_windows_paths_with_mixed_separators() {
+    let tree = PathTree::build(
+        vec![
+            leaf("racer", "C:\\studio\\client_b\\racer"),
+            leaf("puzzle", "C:/studio/client_a/puzzle"),
+            leaf("arcade", "C:\\studio/client_a\\arcade"),
+        ],
+        None,
+    );
+
+    let json = tree.to_json();
+    assert_eq!(json["root"], "C:\\studio");
+    assert_eq!(names(&json["children"]), ["client_a", "client_b"]);
+    assert_eq!(json["children"][0]["count"], 2);
+}
+
+#[test]
+fn test_p1_tree_without_common_ancestor_starts_at_each_root() {
+    let tree = PathTree::build(
+        vec![
+            leaf("racer", "D:\\games\\racer"),
+            leaf("puzzle", "/home/dev/puzzle"),
+        ],
+        None,
+    );
+
+    let json = tree.to_json();
+    assert!(json["root"].is_null());
+    assert_eq!(names(&json["children"]), ["/", "D:"]);
+    assert_eq!(json["other"]["count"], 0);
+}
+
+#[test]
+fn test_p1_tree_sorts_each_level_alphabetically() {
+    let tree = PathTree::build(
+        vec![
+            leaf("Zeta", "/studio/Zeta"),
+            leaf("beta", "/studio/b/beta"),
+            leaf("alpha", "/studio/alpha"),
+        ],
+        Some("/studio"),
+    );
+
+    assert_eq!(names(&tree.to_json()["children"]), ["alpha", "b", "Zeta"]);
+}
+
+// =============================================================================
+// PathTree::render() Tests
+// =============================================================================
+
+#[test]
+fn test_p0_render_indents_branches_with_counts() {
+    let mut favorite = leaf("puzzle", "/studio/client_a/puzzle");
+    favorite.favorite = true;
+    let tree = PathTree::build(
+        vec![
+            favorite,
+            leaf("arcade", "/studio/client_a/arcade"),
+            leaf("racer", "/studio/client_b/racer"),
+            leaf("demo", "/tmp/demo"),
+        ],
+        Some("/studio"),
+    );
+
+    assert_eq!(
+        tree.render(),
+        [
+            "/studio (4)",
+            "├── client_a (2)",
+            "│   ├── arcade",
+            "│   └── ★ puzzle",
+            "├── client_b (1)",
+            "│   └── racer",
+            "└── other (1)",
+            "    └── demo (/tmp/demo)",
+        ]
+        .join("\n")
+    );
+}
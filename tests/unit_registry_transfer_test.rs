@@ -0,0 +1,405 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the export and import of the project registry.
+
+use am::commands::sudo::{export_registry, import_registry};
+use am::common::cancellation::CancellationToken;
+use am::common::errors::{CliError, codes};
+use am::database::transfer::{REGISTRY_TABLES, RegistryTransfer, TRANSFER_BATCH_SIZE};
+use am::database::{Database, db_get_all_projects, db_get_templates};
+use am::presentation::OutputMode;
+use am::testing::{MockOutput, OutputCall, ScriptedInput, TestEnv};
+use std::cell::RefCell;
+use std::fs;
+
+const LARGE_REGISTRY: usize = 10_000;
+
+async fn setup_test_env() -> TestEnv {
+    TestEnv::new()
+        .await
+        .expect("Failed to create test environment")
+}
+
+/// Register `count` generated projects named `<prefix>_<n>`, in one transaction.
+fn generate_projects(database: &Database, prefix: &str, count: usize) {
+    let conn = database.get_connection();
+    let conn = conn.lock().unwrap();
+    let transaction = conn.unchecked_transaction().unwrap();
+    {
+        let mut insert = transaction
+            .prepare("INSERT INTO projects (name, path, is_favorite) VALUES (?1, ?2, ?3)")
+            .unwrap();
+        for n in 0..count {
+            insert
+                .execute(rusqlite::params![
+                    format!("{}_{}", prefix, n),
+                    format!("/projects/{}/{}", prefix, n),
+                    n % 7 == 0
+                ])
+                .unwrap();
+        }
+    }
+    transaction.commit().unwrap();
+}
+
+fn project_names(env: &TestEnv) -> Vec<String> {
+    let mut names: Vec<String> = db_get_all_projects(env.db())
+        .unwrap()
+        .into_iter()
+        .map(|p| p.name)
+        .collect();
+    names.sort();
+    names
+}
+
+fn export_to_vec(database: &Database) -> Vec<u8> {
+    let mut exported = Vec::new();
+    RegistryTransfer::new(database)
+        .export(&mut exported)
+        .expect("Export should succeed");
+    exported
+}
+
+fn error_code(error: &anyhow::Error) -> i32 {
+    error
+        .downcast_ref::<CliError>()
+        .expect("Expected a CliError")
+        .code
+}
+
+fn staging_tables(database: &Database) -> Vec<String> {
+    let conn = database.get_connection();
+    let conn = conn.lock().unwrap();
+    let mut statement = conn
+        .prepare("SELECT name FROM sqlite_master WHERE type = 'table' AND name LIKE '\\_import\\_%' ESCAPE '\\'")
+        .unwrap();
+    statement
+        .query_map([], |row| row.get(0))
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+}
+
+// =============================================================================
+// Export Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_export_writes_every_registry_table() {
+    let env = setup_test_env().await;
+    generate_projects(&env.database(), "game", 3);
+
+    let exported: serde_json::Value =
+        serde_json::from_slice(&export_to_vec(&env.database())).unwrap();
+
+    assert_eq!(exported["format"], 1);
+    assert!(exported["schema_version"].as_u64().unwrap() > 0);
+    for table in REGISTRY_TABLES {
+        assert!(exported["tables"][table].is_array(), "Missing {}", table);
+    }
+    let projects = exported["tables"]["projects"].as_array().unwrap();
+    assert_eq!(projects.len(), 3);
+    assert_eq!(projects[0]["name"], "game_0");
+    assert_eq!(projects[0]["path"], "/projects/game/0");
+    assert_eq!(projects[0]["is_favorite"], 1);
+}
+
+#[tokio::test]
+async fn test_p0_export_reports_progress_per_batch() {
+    let env = setup_test_env().await;
+    generate_projects(&env.database(), "game", LARGE_REGISTRY);
+    let events = RefCell::new(Vec::new());
+
+    let counts = RegistryTransfer::new(&env.database())
+        .on_progress(|table, current, total| {
+            events
+                .borrow_mut()
+                .push((table.to_string(), current, total))
+        })
+        .export(&mut std::io::sink())
+        .unwrap();
+
+    assert_eq!(counts["projects"], LARGE_REGISTRY);
+    let events = events.into_inner();
+    let projects: Vec<_> = events.iter().filter(|e| e.0 == "projects").collect();
+    assert_eq!(projects.len(), LARGE_REGISTRY / TRANSFER_BATCH_SIZE);
+    assert_eq!(projects[0].1, TRANSFER_BATCH_SIZE);
+    assert_eq!(
+        *projects.last().unwrap(),
+        &("projects".to_string(), LARGE_REGISTRY, LARGE_REGISTRY)
+    );
+}
+
+#[tokio::test]
+async fn test_p1_cancelled_export_stops_before_the_next_batch() {
+    let env = setup_test_env().await;
+    generate_projects(&env.database(), "game", LARGE_REGISTRY);
+    let token = CancellationToken::new();
+    let batches = RefCell::new(0);
+
+    let error = RegistryTransfer::new(&env.database())
+        .with_cancellation(token.clone())
+        .on_progress(|_, _, _| {
+            *batches.borrow_mut() += 1;
+            token.cancel();
+        })
+        .export(&mut std::io::sink())
+        .unwrap_err();
+
+    assert_eq!(error_code(&error), codes::ERR_IO_CANCELLED);
+    assert_eq!(*batches.borrow(), 1);
+}
+
+// =============================================================================
+// Import Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_import_replaces_the_registry() {
+    let source = setup_test_env().await;
+    generate_projects(&source.database(), "game", LARGE_REGISTRY);
+    let exported = export_to_vec(&source.database());
+    let target = setup_test_env().await;
+    generate_projects(&target.database(), "old", 3);
+    let events = RefCell::new(Vec::new());
+
+    let counts = RegistryTransfer::new(&target.database())
+        .on_progress(|table, current, total| {
+            events
+                .borrow_mut()
+                .push((table.to_string(), current, total))
+        })
+        .import(exported.as_slice())
+        .unwrap();
+
+    assert_eq!(counts["projects"], LARGE_REGISTRY);
+    assert_eq!(project_names(&target), project_names(&source));
+    assert_eq!(
+        db_get_all_projects(target.db())
+            .unwrap()
+            .iter()
+            .filter(|p| p.is_favorite)
+            .count(),
+        LARGE_REGISTRY.div_ceil(7)
+    );
+    assert_eq!(
+        events.borrow().last().unwrap(),
+        &("projects".to_string(), LARGE_REGISTRY, LARGE_REGISTRY)
+    );
+    assert!(staging_tables(&target.database()).is_empty());
+}
+
+#[tokio::test]
+async fn test_p0_cancelled_import_leaves_the_registry_untouched() {
+    let source = setup_test_env().await;
+    generate_projects(&source.database(), "game", LARGE_REGISTRY);
+    let exported = export_to_vec(&source.database());
+    let target = setup_test_env().await;
+    generate_projects(&target.database(), "old", 3);
+    let token = CancellationToken::new();
+    let progress = RefCell::new(Vec::new());
+
+    // WHEN the import is cancelled halfway through the projects
+    let error = RegistryTransfer::new(&target.database())
+        .with_cancellation(token.clone())
+        .on_progress(|_, current, _| {
+            progress.borrow_mut().push(current);
+            if current >= LARGE_REGISTRY / 2 {
+                token.cancel();
+            }
+        })
+        .import(exported.as_slice())
+        .unwrap_err();
+
+    // THEN the original rows remain and the staged rows are gone
+    assert_eq!(error_code(&error), codes::ERR_IO_CANCELLED);
+    assert_eq!(*progress.borrow().last().unwrap(), LARGE_REGISTRY / 2);
+    assert_eq!(project_names(&target), vec!["old_0", "old_1", "old_2"]);
+    assert!(staging_tables(&target.database()).is_empty());
+}
+
+#[tokio::test]
+async fn test_p1_import_drops_staging_tables_of_an_interrupted_import() {
+    let source = setup_test_env().await;
+    generate_projects(&source.database(), "game", 2);
+    let exported = export_to_vec(&source.database());
+    let target = setup_test_env().await;
+    target
+        .database()
+        .execute_batch("CREATE TABLE _import_projects (name TEXT)")
+        .unwrap();
+
+    RegistryTransfer::new(&target.database())
+        .import(exported.as_slice())
+        .unwrap();
+
+    assert_eq!(project_names(&target), vec!["game_0", "game_1"]);
+    assert!(staging_tables(&target.database()).is_empty());
+}
+
+#[tokio::test]
+async fn test_p1_failed_import_leaves_the_registry_untouched() {
+    let target = setup_test_env().await;
+    generate_projects(&target.database(), "old", 2);
+    let exported: serde_json::Value =
+        serde_json::from_slice(&export_to_vec(&target.database())).unwrap();
+    let duplicated = serde_json::json!({
+        "format": 1,
+        "schema_version": exported["schema_version"],
+        "tables": {
+            "projects": [
+                { "name": "game", "path": "/a" },
+                { "name": "game", "path": "/b" },
+            ],
+            "templates": [],
+        },
+    });
+
+    let error = RegistryTransfer::new(&target.database())
+        .import(duplicated.to_string().as_bytes())
+        .unwrap_err();
+
+    assert!(error.to_string().contains("projects"), "{}", error);
+    assert_eq!(project_names(&target), vec!["old_0", "old_1"]);
+    assert!(staging_tables(&target.database()).is_empty());
+}
+
+#[tokio::test]
+async fn test_p1_import_rejects_an_export_of_another_schema_version() {
+    let target = setup_test_env().await;
+    generate_projects(&target.database(), "old", 1);
+    let mut exported: serde_json::Value =
+        serde_json::from_slice(&export_to_vec(&target.database())).unwrap();
+    exported["schema_version"] = serde_json::json!(1);
+    exported["tables"]["projects"] = serde_json::json!([]);
+
+    let error = RegistryTransfer::new(&target.database())
+        .import(exported.to_string().as_bytes())
+        .unwrap_err();
+
+    assert_eq!(error_code(&error), codes::ERR_VALIDATION_FORMAT);
+    assert_eq!(project_names(&target), vec!["old_0"]);
+}
+
+#[tokio::test]
+async fn test_p1_import_rejects_unknown_tables_and_columns() {
+    let target = setup_test_env().await;
+    let mut exported: serde_json::Value =
+        serde_json::from_slice(&export_to_vec(&target.database())).unwrap();
+
+    let mut unknown_table = exported.clone();
+    unknown_table["tables"]["audit"] = serde_json::json!([]);
+    let error = RegistryTransfer::new(&target.database())
+        .import(unknown_table.to_string().as_bytes())
+        .unwrap_err();
+    assert_eq!(error_code(&error), codes::ERR_VALIDATION_FORMAT);
+
+    exported["tables"]["projects"] =
+        serde_json::json!([{ "name": "game", "path": "/a", "owner": "me" }]);
+    let error = RegistryTransfer::new(&target.database())
+        .import(exported.to_string().as_bytes())
+        .unwrap_err();
+    assert_eq!(error_code(&error), codes::ERR_VALIDATION_FORMAT);
+    assert!(
+        error
+            .downcast_ref::<CliError>()
+            .unwrap()
+            .why
+            .contains("'owner'")
+    );
+
+    assert!(
+        RegistryTransfer::new(&target.database())
+            .import("not json".as_bytes())
+            .is_err()
+    );
+    assert!(staging_tables(&target.database()).is_empty());
+}
+
+// =============================================================================
+// Command Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_p0_export_and_import_commands_round_trip_through_a_file() {
+    let source = setup_test_env().await;
+    generate_projects(&source.database(), "game", 1_200);
+    let file = source.home().join("registry.json");
+    let output = MockOutput::new(OutputMode::Json);
+
+    export_registry(&file, source.db(), &output).unwrap();
+
+    assert!(file.exists());
+    assert!(!file.with_extension("tmp").exists());
+    let progress: Vec<(usize, usize)> = output
+        .calls()
+        .iter()
+        .filter_map(|call| match call {
+            OutputCall::ProgressWith {
+                message,
+                current,
+                total,
+            } if message == "Exporting projects" => Some((*current, *total)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(progress, vec![(500, 1_200), (1_000, 1_200), (1_200, 1_200)]);
+
+    let target = setup_test_env().await;
+    let output = MockOutput::new(OutputMode::Json);
+    import_registry(
+        &file,
+        true,
+        false,
+        target.db(),
+        &ScriptedInput::new(),
+        &output,
+    )
+    .unwrap();
+
+    assert_eq!(project_names(&target), project_names(&source));
+    assert!(
+        output
+            .calls()
+            .contains(&OutputCall::Success(serde_json::json!({
+                "path": file.to_string_lossy(),
+                "tables": { "projects": 1_200, "templates": 0 },
+            })))
+    );
+    assert!(db_get_templates(target.db()).unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_p1_declined_import_changes_nothing() {
+    let source = setup_test_env().await;
+    generate_projects(&source.database(), "game", 2);
+    let file = source.home().join("registry.json");
+    export_registry(&file, source.db(), &MockOutput::new(OutputMode::Json)).unwrap();
+    let target = setup_test_env().await;
+    generate_projects(&target.database(), "old", 1);
+
+    import_registry(
+        &file,
+        false,
+        false,
+        target.db(),
+        &ScriptedInput::new().confirm(false),
+        &MockOutput::new(OutputMode::Interactive),
+    )
+    .unwrap();
+
+    assert_eq!(project_names(&target), vec!["old_0"]);
+    fs::remove_file(file).unwrap();
+}
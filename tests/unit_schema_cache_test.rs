@@ -0,0 +1,283 @@
+// Copyright (c) 2026-present Sparky Studios. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the local cache of the SDK schemas.
+//!
+//! Each test fabricates fake SDK installations: a `VERSION` file and a `schemas/`
+//! directory whose files hold the version they belong to.
+
+use am::commands::sdk::clear_schemas_command;
+use am::common::errors::{CliError, codes};
+use am::config::sdk::{SDK_VERSION_FILE, SdkLocation};
+use am::database::{db_get_config, db_set_config};
+use am::presentation::OutputMode;
+use am::schema::cache::{
+    CONFIG_SCHEMA_CACHE_VERSION, SchemaCache, is_newer_version, select_schemas,
+};
+use am::testing::{MockOutput, OutputCall, TestEnv};
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+/// A fake SDK in `base/sdk-<version>` reporting `version`.
+fn fake_sdk(base: &Path, version: &str) -> SdkLocation {
+    let root = base.join(format!("sdk-{}", version));
+    let schemas = root.join("schemas");
+    fs::create_dir_all(&schemas).unwrap();
+    fs::write(root.join(SDK_VERSION_FILE), format!("{}\n", version)).unwrap();
+    fs::write(schemas.join("sound_definition.bfbs"), version).unwrap();
+    fs::write(schemas.join("engine_config.json"), version).unwrap();
+    fs::write(schemas.join("README.md"), "not a schema").unwrap();
+    SdkLocation::new_for_test(root)
+}
+
+fn error_code(error: &anyhow::Error) -> i32 {
+    error
+        .downcast_ref::<CliError>()
+        .expect("Expected a CliError")
+        .code
+}
+
+// =============================================================================
+// Sync Tests
+// =============================================================================
+
+#[test]
+fn test_p0_sync_copies_schema_files_under_the_sdk_version() {
+    let dir = tempdir().unwrap();
+    let sdk = fake_sdk(dir.path(), "1.2.0");
+    let cache = SchemaCache::new(dir.path().join("cache"));
+
+    let synced = cache.sync(&sdk).unwrap();
+
+    assert_eq!(synced.version, "1.2.0");
+    assert_eq!(synced.dir, cache.version_dir("1.2.0"));
+    assert_eq!(
+        synced.files,
+        vec!["engine_config.json", "sound_definition.bfbs"]
+    );
+    assert_eq!(
+        fs::read_to_string(synced.dir.join("sound_definition.bfbs")).unwrap(),
+        "1.2.0"
+    );
+    assert!(!synced.dir.join("README.md").exists());
+    assert_eq!(cache.versions().unwrap(), vec!["1.2.0"]);
+}
+
+#[test]
+fn test_p0_sync_keeps_every_version_sorted() {
+    let dir = tempdir().unwrap();
+    let cache = SchemaCache::new(dir.path().join("cache"));
+
+    cache.sync(&fake_sdk(dir.path(), "1.10.0")).unwrap();
+    cache.sync(&fake_sdk(dir.path(), "1.9.0")).unwrap();
+
+    assert_eq!(cache.versions().unwrap(), vec!["1.9.0", "1.10.0"]);
+}
+
+#[test]
+fn test_p1_sync_replaces_the_same_version() {
+    let dir = tempdir().unwrap();
+    let sdk = fake_sdk(dir.path(), "1.2.0");
+    let cache = SchemaCache::new(dir.path().join("cache"));
+    cache.sync(&sdk).unwrap();
+
+    fs::remove_file(sdk.schemas_dir().join("engine_config.json")).unwrap();
+    fs::write(sdk.schemas_dir().join("sound_definition.bfbs"), "patched").unwrap();
+    let synced = cache.sync(&sdk).unwrap();
+
+    assert_eq!(synced.files, vec!["sound_definition.bfbs"]);
+    assert!(!synced.dir.join("engine_config.json").exists());
+    assert_eq!(
+        fs::read_to_string(synced.dir.join("sound_definition.bfbs")).unwrap(),
+        "patched"
+    );
+    assert_eq!(fs::read_dir(cache.dir()).unwrap().count(), 1);
+}
+
+#[test]
+fn test_p0_sync_requires_an_sdk_version() {
+    let dir = tempdir().unwrap();
+    let sdk = fake_sdk(dir.path(), "1.2.0");
+    fs::remove_file(sdk.root().join(SDK_VERSION_FILE)).unwrap();
+    let cache = SchemaCache::new(dir.path().join("cache"));
+
+    let error = cache.sync(&sdk).unwrap_err();
+
+    assert_eq!(error_code(&error), codes::ERR_SDK_VERSION_UNKNOWN);
+    assert!(!cache.dir().exists());
+}
+
+#[test]
+fn test_p1_sync_rejects_a_version_that_is_not_a_directory_name() {
+    let dir = tempdir().unwrap();
+    let sdk = fake_sdk(dir.path(), "1.2.0");
+    fs::write(sdk.root().join(SDK_VERSION_FILE), "1.2.0/../../escape").unwrap();
+    let cache = SchemaCache::new(dir.path().join("cache"));
+
+    let error = cache.sync(&sdk).unwrap_err();
+
+    assert_eq!(error_code(&error), codes::ERR_SDK_VERSION_UNKNOWN);
+    assert!(!dir.path().join("escape").exists());
+}
+
+#[test]
+fn test_p1_sync_fails_without_schema_files() {
+    let dir = tempdir().unwrap();
+    let sdk = fake_sdk(dir.path(), "1.2.0");
+    fs::remove_file(sdk.schemas_dir().join("sound_definition.bfbs")).unwrap();
+    fs::remove_file(sdk.schemas_dir().join("engine_config.json")).unwrap();
+    let cache = SchemaCache::new(dir.path().join("cache"));
+
+    let error = cache.sync(&sdk).unwrap_err();
+
+    assert_eq!(error_code(&error), codes::ERR_SDK_SCHEMA_LOAD_FAILED);
+    assert!(cache.versions().unwrap().is_empty());
+}
+
+// =============================================================================
+// Selection Tests
+// =============================================================================
+
+#[test]
+fn test_p0_select_prefers_the_active_cached_version() {
+    let dir = tempdir().unwrap();
+    let cache = SchemaCache::new(dir.path().join("cache"));
+    cache.sync(&fake_sdk(dir.path(), "1.0.0")).unwrap();
+    let sdk = fake_sdk(dir.path(), "1.0.0");
+
+    let selection = select_schemas(&cache, Some("1.0.0"), Some(&sdk)).unwrap();
+
+    assert_eq!(selection.schemas_dir, cache.version_dir("1.0.0"));
+    assert_eq!(selection.cached_version.as_deref(), Some("1.0.0"));
+    assert_eq!(selection.newer_sdk_version, None);
+}
+
+#[test]
+fn test_p0_select_reports_a_newer_sdk_as_stale_cache() {
+    let dir = tempdir().unwrap();
+    let cache = SchemaCache::new(dir.path().join("cache"));
+    cache.sync(&fake_sdk(dir.path(), "1.0.0")).unwrap();
+    let newer = fake_sdk(dir.path(), "1.1.0");
+
+    let selection = select_schemas(&cache, Some("1.0.0"), Some(&newer)).unwrap();
+
+    assert_eq!(selection.schemas_dir, cache.version_dir("1.0.0"));
+    assert_eq!(selection.newer_sdk_version.as_deref(), Some("1.1.0"));
+}
+
+#[test]
+fn test_p1_select_does_not_report_an_older_sdk() {
+    let dir = tempdir().unwrap();
+    let cache = SchemaCache::new(dir.path().join("cache"));
+    cache.sync(&fake_sdk(dir.path(), "1.1.0")).unwrap();
+    let older = fake_sdk(dir.path(), "1.0.0");
+
+    let selection = select_schemas(&cache, Some("1.1.0"), Some(&older)).unwrap();
+
+    assert_eq!(selection.cached_version.as_deref(), Some("1.1.0"));
+    assert_eq!(selection.newer_sdk_version, None);
+}
+
+#[test]
+fn test_p0_select_uses_the_cache_when_the_sdk_is_unmounted() {
+    let dir = tempdir().unwrap();
+    let cache = SchemaCache::new(dir.path().join("cache"));
+    cache.sync(&fake_sdk(dir.path(), "1.0.0")).unwrap();
+
+    let selection = select_schemas(&cache, Some("1.0.0"), None).unwrap();
+
+    assert_eq!(selection.schemas_dir, cache.version_dir("1.0.0"));
+}
+
+#[test]
+fn test_p0_select_falls_back_to_the_sdk() {
+    let dir = tempdir().unwrap();
+    let cache = SchemaCache::new(dir.path().join("cache"));
+    cache.sync(&fake_sdk(dir.path(), "1.0.0")).unwrap();
+    let sdk = fake_sdk(dir.path(), "1.1.0");
+
+    // No active version
+    let selection = select_schemas(&cache, None, Some(&sdk)).unwrap();
+    assert_eq!(selection.schemas_dir, sdk.schemas_dir());
+    assert_eq!(selection.cached_version, None);
+
+    // Active version no longer cached
+    let selection = select_schemas(&cache, Some("0.9.0"), Some(&sdk)).unwrap();
+    assert_eq!(selection.schemas_dir, sdk.schemas_dir());
+
+    // Nothing at all
+    assert!(select_schemas(&cache, Some("0.9.0"), None).is_none());
+}
+
+#[test]
+fn test_p1_is_newer_version() {
+    assert!(is_newer_version("1.1.0", "1.0.0"));
+    assert!(is_newer_version("2.0.0", "1.10.3"));
+    assert!(!is_newer_version("1.0.0", "1.0.0"));
+    assert!(!is_newer_version("1.0.0", "1.1.0"));
+    assert!(!is_newer_version("unknown", "1.0.0"));
+}
+
+// =============================================================================
+// Clear Tests
+// =============================================================================
+
+#[test]
+fn test_p0_clear_deletes_every_version() {
+    let dir = tempdir().unwrap();
+    let cache = SchemaCache::new(dir.path().join("cache"));
+    cache.sync(&fake_sdk(dir.path(), "1.0.0")).unwrap();
+    cache.sync(&fake_sdk(dir.path(), "1.1.0")).unwrap();
+
+    let cleared = cache.clear().unwrap();
+
+    assert_eq!(cleared, vec!["1.0.0", "1.1.0"]);
+    assert!(cache.versions().unwrap().is_empty());
+    assert!(dir.path().join("sdk-1.0.0").join("schemas").exists());
+}
+
+#[test]
+fn test_p1_clear_without_cache_does_nothing() {
+    let dir = tempdir().unwrap();
+    let cache = SchemaCache::new(dir.path().join("cache"));
+
+    assert!(cache.clear().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_p0_clear_command_forgets_the_active_version() {
+    let env = TestEnv::new().await.unwrap();
+    let dir = tempdir().unwrap();
+    let cache = SchemaCache::new(dir.path().join("cache"));
+    let synced = cache.sync(&fake_sdk(dir.path(), "1.0.0")).unwrap();
+    db_set_config(
+        CONFIG_SCHEMA_CACHE_VERSION,
+        &synced.version,
+        "string",
+        env.db(),
+    )
+    .unwrap();
+    let output = MockOutput::new(OutputMode::Json);
+
+    clear_schemas_command(&cache, env.db(), &output).unwrap();
+
+    assert_eq!(
+        db_get_config(CONFIG_SCHEMA_CACHE_VERSION, env.db()).unwrap(),
+        None
+    );
+    assert!(output.calls().contains(&OutputCall::Success(
+        serde_json::json!({ "cleared": ["1.0.0"] })
+    )));
+}